@@ -35,6 +35,15 @@ struct Args {
     #[arg(long)]
     archive_missing: bool,
 
+    /// Publish the docs as this version of the bundle rooted at `slug_prefix`
+    /// (e.g. a release name or git SHA)
+    #[arg(long)]
+    bundle_version: Option<String>,
+
+    /// Publish the bundle version at the canonical slugs, as the latest docs
+    #[arg(long)]
+    bundle_latest: bool,
+
     /// Show what would be done without making any changes
     #[arg(long)]
     dry_run: bool,
@@ -67,6 +76,12 @@ struct LektonConfig {
     /// Archive documents not found locally (can be overridden by --archive-missing flag)
     #[serde(default)]
     archive_missing: Option<bool>,
+    /// Bundle version of the docs (overridden by --bundle-version)
+    #[serde(default)]
+    bundle_version: Option<String>,
+    /// Publish the bundle version as the latest docs (or pass --bundle-latest)
+    #[serde(default)]
+    bundle_latest: Option<bool>,
     /// Maximum attachment file size in MB (default: 10)
     #[serde(default)]
     max_attachment_size_mb: Option<u32>,
@@ -104,6 +119,8 @@ struct FrontMatter {
     order: Option<i32>,
     #[serde(alias = "is-hidden", alias = "isHidden")]
     is_hidden: Option<bool>,
+    /// Keep the document out of search engines.
+    noindex: Option<bool>,
     /// Must be `true` for the file to be synced to Lekton.
     #[serde(
         rename = "lekton-import",
@@ -186,6 +203,15 @@ struct SyncRequest {
     service_token: String,
     documents: Vec<SyncDocEntry>,
     archive_missing: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bundle: Option<SyncBundle>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+struct SyncBundle {
+    root: String,
+    version: String,
+    latest: bool,
 }
 
 #[derive(Serialize)]
@@ -268,11 +294,25 @@ struct IngestRequest {
     parent_slug: Option<String>,
     order: i32,
     is_hidden: bool,
+    noindex: bool,
 }
 
 #[derive(Deserialize)]
 struct IngestResponse {
     changed: bool,
+    /// The slug is protected: the document waits for admin approval.
+    #[serde(default)]
+    pending_approval: bool,
+    /// Style findings from the server's Markdown lint, if enabled.
+    #[serde(default)]
+    lint: Vec<LintFinding>,
+}
+
+#[derive(Deserialize)]
+struct LintFinding {
+    rule: String,
+    line: usize,
+    message: String,
 }
 
 #[derive(Serialize)]
@@ -384,6 +424,7 @@ struct DocumentInfo {
     parent_slug: Option<String>,
     order: i32,
     is_hidden: bool,
+    noindex: bool,
     attachments: Vec<AttachmentInfo>,
 }
 
@@ -440,16 +481,21 @@ fn compute_metadata_hash(
     parent_slug: Option<&str>,
     order: i32,
     is_hidden: bool,
+    noindex: bool,
 ) -> String {
     let mut sorted_tags: Vec<&str> = tags.iter().map(|s| s.as_str()).collect();
     sorted_tags.sort_unstable();
-    let canonical = format!(
+    let mut canonical = format!(
         "title={title}\nsummary={}\naccess_level={}\nservice_owner={service_owner}\ntags={}\nparent_slug={}\norder={order}\nis_hidden={is_hidden}",
         summary.unwrap_or(""),
         access_level.to_lowercase(),
         sorted_tags.join(","),
         parent_slug.unwrap_or(""),
     );
+    // Only when set, like the server, so existing hashes don't change
+    if noindex {
+        canonical.push_str("\nnoindex=true");
+    }
     compute_hash(&canonical)
 }
 
@@ -748,6 +794,7 @@ struct ScannedDoc {
     parent_slug: Option<String>,
     explicit_order: Option<i32>,
     is_hidden: bool,
+    noindex: bool,
     attachments: Vec<AttachmentInfo>,
 }
 
@@ -863,6 +910,7 @@ fn scan_documents(root: &Path, config: &LektonConfig) -> Result<HashMap<String,
             .unwrap_or_default();
         let tags = fm.tags.unwrap_or_default();
         let is_hidden = fm.is_hidden.unwrap_or(false);
+        let noindex = fm.noindex.unwrap_or(false);
 
         let md_file_dir = path.parent().unwrap_or(root);
         let local_refs = extract_local_file_refs(&body, md_file_dir);
@@ -888,6 +936,7 @@ fn scan_documents(root: &Path, config: &LektonConfig) -> Result<HashMap<String,
             parent_slug,
             explicit_order: fm.order,
             is_hidden,
+            noindex,
             attachments,
         });
     }
@@ -941,6 +990,7 @@ fn scan_documents(root: &Path, config: &LektonConfig) -> Result<HashMap<String,
             doc.parent_slug.as_deref(),
             order,
             doc.is_hidden,
+            doc.noindex,
         );
         docs.insert(
             doc.source_path.clone(),
@@ -960,6 +1010,7 @@ fn scan_documents(root: &Path, config: &LektonConfig) -> Result<HashMap<String,
                 parent_slug: doc.parent_slug,
                 order,
                 is_hidden: doc.is_hidden,
+                noindex: doc.noindex,
                 attachments: doc.attachments,
             },
         );
@@ -1250,6 +1301,34 @@ async fn backoff_on_429(
     }
 }
 
+/// Resolve the bundle version to publish, if any. Prior versions are
+/// published under `<slug_prefix>/@<version>`, so the slug prefix is updated
+/// accordingly.
+fn resolve_bundle(args: &Args, config: &mut LektonConfig) -> Result<Option<SyncBundle>> {
+    let Some(version) = args
+        .bundle_version
+        .clone()
+        .or_else(|| config.bundle_version.clone())
+        .filter(|v| !v.trim().is_empty())
+    else {
+        return Ok(None);
+    };
+    let root = config
+        .slug_prefix
+        .clone()
+        .filter(|p| !p.is_empty())
+        .context("'slug_prefix' in .lekton.yml is required to publish a bundle version")?;
+    let latest = args.bundle_latest || config.bundle_latest.unwrap_or(false);
+    if !latest {
+        config.slug_prefix = Some(format!("{root}/@{version}"));
+    }
+    Ok(Some(SyncBundle {
+        root,
+        version,
+        latest,
+    }))
+}
+
 // ── Entry point ───────────────────────────────────────────────────────────────
 
 #[tokio::main]
@@ -1262,7 +1341,7 @@ async fn main() -> Result<()> {
         .clone()
         .unwrap_or_else(|| args.root.join(".lekton.yml"));
 
-    let config: LektonConfig = if config_path.exists() {
+    let mut config: LektonConfig = if config_path.exists() {
         let content = std::fs::read_to_string(&config_path)
             .with_context(|| format!("Failed to read config {}", config_path.display()))?;
         serde_yaml::from_str(&content)
@@ -1286,6 +1365,8 @@ async fn main() -> Result<()> {
     let archive_missing_schemas =
         args.archive_missing || config.archive_missing_schemas.unwrap_or(false);
 
+    let bundle = resolve_bundle(&args, &mut config)?;
+
     // ── Scan documents ────────────────────────────────────────────────────────
     let root = args
         .root
@@ -1337,6 +1418,7 @@ async fn main() -> Result<()> {
                 service_token: token.clone(),
                 documents: sync_entries,
                 archive_missing,
+                bundle: bundle.clone(),
             })
             .send()
             .await
@@ -1706,6 +1788,7 @@ async fn main() -> Result<()> {
             parent_slug: doc.parent_slug.clone(),
             order: doc.order,
             is_hidden: doc.is_hidden,
+            noindex: doc.noindex,
         };
 
         let mut attempt = 0u32;
@@ -1719,10 +1802,24 @@ async fn main() -> Result<()> {
 
         match result {
             Ok(r) if r.status().is_success() => {
-                let ingest: IngestResponse =
-                    r.json().await.unwrap_or(IngestResponse { changed: true });
+                let ingest: IngestResponse = r.json().await.unwrap_or(IngestResponse {
+                    changed: true,
+                    pending_approval: false,
+                    lint: vec![],
+                });
                 uploaded += 1;
-                if args.verbose {
+                for finding in &ingest.lint {
+                    println!(
+                        "  lint: {}:{}: {} ({})",
+                        upload_entry.source_path, finding.line, finding.message, finding.rule
+                    );
+                }
+                if ingest.pending_approval {
+                    println!(
+                        "  pending approval: {} (slug: {})",
+                        upload_entry.source_path, upload_entry.actual_slug
+                    );
+                } else if args.verbose {
                     let note = if ingest.changed {
                         "updated"
                     } else {
@@ -2044,6 +2141,39 @@ mod tests {
         assert_eq!(slug, "protocols/my-service/intro");
     }
 
+    #[test]
+    fn bundle_version_scopes_slug_prefix() {
+        let config = || LektonConfig {
+            slug_prefix: Some("protocols/my-service".to_string()),
+            ..LektonConfig::default()
+        };
+
+        let mut prior = config();
+        let args = Args::parse_from(["lekton-sync", "--bundle-version", "1.x"]);
+        let bundle = resolve_bundle(&args, &mut prior).unwrap().unwrap();
+        assert_eq!(bundle.root, "protocols/my-service");
+        assert!(!bundle.latest);
+        assert_eq!(
+            prior.slug_prefix.as_deref(),
+            Some("protocols/my-service/@1.x")
+        );
+
+        let mut latest = config();
+        let args = Args::parse_from([
+            "lekton-sync",
+            "--bundle-version",
+            "abc123",
+            "--bundle-latest",
+        ]);
+        assert!(resolve_bundle(&args, &mut latest).unwrap().unwrap().latest);
+        assert_eq!(latest.slug_prefix.as_deref(), Some("protocols/my-service"));
+
+        let args = Args::parse_from(["lekton-sync"]);
+        assert!(resolve_bundle(&args, &mut config()).unwrap().is_none());
+        let args = Args::parse_from(["lekton-sync", "--bundle-version", "1.x"]);
+        assert!(resolve_bundle(&args, &mut LektonConfig::default()).is_err());
+    }
+
     #[test]
     fn scan_skips_files_without_front_matter() {
         use std::io::Write;
@@ -0,0 +1,91 @@
+(function () {
+  var HEADINGS = 'article.prose :is(h2, h3, h4, h5, h6)[id]';
+  var LINK_ICON =
+    '<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">' +
+    '<path d="M10 13a5 5 0 0 0 7.54.54l3-3a5 5 0 0 0-7.07-7.07l-1.72 1.71"/>' +
+    '<path d="M14 11a5 5 0 0 0-7.54-.54l-3 3a5 5 0 0 0 7.07 7.07l1.71-1.71"/></svg>';
+
+  var observer = null;
+
+  // Append a permalink button to every document heading that has an id.
+  function addAnchors() {
+    document.querySelectorAll(HEADINGS).forEach(function (heading) {
+      if (heading.querySelector('.heading-anchor')) return;
+      var anchor = document.createElement('a');
+      anchor.className = 'heading-anchor';
+      anchor.href = '#' + heading.id;
+      anchor.setAttribute('aria-label', 'Copy link to this section');
+      anchor.title = 'Copy link to this section';
+      anchor.innerHTML = LINK_ICON;
+      heading.appendChild(anchor);
+    });
+  }
+
+  function setActive(id) {
+    document.querySelectorAll('nav[data-toc] a.toc-link').forEach(function (link) {
+      link.classList.toggle('toc-active', link.getAttribute('href') === '#' + id);
+    });
+  }
+
+  // Highlight the table-of-contents entry for the section currently in view.
+  function watchSections() {
+    if (observer) observer.disconnect();
+    if (!('IntersectionObserver' in window)) return;
+
+    var headings = Array.prototype.slice.call(document.querySelectorAll(HEADINGS));
+    if (headings.length === 0) return;
+
+    var visible = {};
+    observer = new IntersectionObserver(function (entries) {
+      entries.forEach(function (entry) {
+        visible[entry.target.id] = entry.isIntersecting;
+      });
+      // First heading in document order that is inside the activation band
+      for (var i = 0; i < headings.length; i++) {
+        if (visible[headings[i].id]) {
+          setActive(headings[i].id);
+          return;
+        }
+      }
+    }, { rootMargin: '-80px 0px -70% 0px' });
+
+    headings.forEach(function (h) { observer.observe(h); });
+  }
+
+  document.addEventListener('click', function (ev) {
+    var anchor = ev.target.closest && ev.target.closest('.heading-anchor');
+    if (!anchor) return;
+    ev.preventDefault();
+
+    var hash = anchor.getAttribute('href');
+    var url = window.location.href.split('#')[0] + hash;
+    history.replaceState(null, '', hash);
+    anchor.parentElement.scrollIntoView({ behavior: 'smooth' });
+
+    if (navigator.clipboard) {
+      navigator.clipboard.writeText(url).then(function () {
+        anchor.classList.add('copied');
+        setTimeout(function () { anchor.classList.remove('copied'); }, 1500);
+      }).catch(function (err) {
+        console.error('[doc] failed to copy link:', err);
+      });
+    }
+  });
+
+  // Code tab groups rendered from `tab=` fences (src/rendering/markdown.rs)
+  document.addEventListener('click', function (ev) {
+    var tab = ev.target.closest && ev.target.closest('.code-tabs .code-tab');
+    if (!tab) return;
+    var group = tab.closest('.code-tabs');
+    var index = tab.getAttribute('data-tab');
+    group.querySelectorAll('.code-tab, .code-tab-panel').forEach(function (el) {
+      el.classList.toggle('active', el.getAttribute('data-tab') === index);
+    });
+  });
+
+  // Called by DocPage after the document HTML has been mounted.
+  window.lektonEnhanceDoc = function () {
+    addAnchors();
+    watchSections();
+  };
+})();
@@ -1,18 +1,25 @@
-// Login form handler for demo mode authentication.
-document.getElementById('login-form').addEventListener('submit', async (e) => {
+// Login form handler for demo mode, local account and LDAP authentication.
+// The form's `data-endpoint` selects the login endpoint and `data-next` the
+// page to return to (validated server-side when rendering the form).
+const loginForm = document.getElementById('login-form');
+loginForm.addEventListener('submit', async (e) => {
     e.preventDefault();
     const username = document.getElementById('login-username').value;
     const password = document.getElementById('login-password').value;
     const errorEl = document.getElementById('login-error');
+    const endpoint = loginForm.dataset.endpoint || '/api/auth/login';
+    const next = loginForm.dataset.next || '/';
 
     try {
-        const res = await fetch('/api/auth/login', {
+        const res = await fetch(endpoint, {
             method: 'POST',
             headers: { 'Content-Type': 'application/json' },
             body: JSON.stringify({ username, password })
         });
         if (res.ok) {
-            window.location.href = '/';
+            const body = await res.json().catch(() => ({}));
+            // Passwords set by an administrator must be changed first
+            window.location.href = body.password_change_required ? '/profile' : next;
         } else {
             errorEl.classList.remove('hidden');
         }
@@ -0,0 +1,38 @@
+// Print view (/docs/<slug>/print): render the diagrams, wait for every image
+// and diagram, then open the print dialog.
+(function () {
+  var TIMEOUT_MS = 10000;
+
+  function imageLoaded(img) {
+    if (img.complete) return Promise.resolve();
+    return new Promise(function (resolve) {
+      img.addEventListener('load', resolve, { once: true });
+      img.addEventListener('error', resolve, { once: true });
+    });
+  }
+
+  function diagramsRendered() {
+    return new Promise(function (resolve) {
+      (function poll() {
+        var pending = document.querySelector(
+          'pre.mermaid:not([data-processed]), .mermaid-spinner'
+        );
+        if (!pending) return resolve();
+        setTimeout(poll, 100);
+      })();
+    });
+  }
+
+  window.addEventListener('load', function () {
+    if (window.renderMermaid) window.renderMermaid();
+    var ready = Promise.all(
+      Array.from(document.images).map(imageLoaded).concat([diagramsRendered()])
+    );
+    var timeout = new Promise(function (resolve) {
+      setTimeout(resolve, TIMEOUT_MS);
+    });
+    Promise.race([ready, timeout]).then(function () {
+      window.print();
+    });
+  });
+})();
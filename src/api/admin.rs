@@ -9,9 +9,15 @@
 //! | PUT    | `/api/v1/admin/access-levels/{name}`        | Update an access level              |
 //! | DELETE | `/api/v1/admin/access-levels/{name}`        | Delete an access level              |
 //! | GET    | `/api/v1/admin/users`                       | List all users                      |
+//! | POST   | `/api/v1/admin/users`                       | Create a local account              |
+//! | PUT    | `/api/v1/admin/users/{user_id}`             | Update name and admin flag          |
+//! | DELETE | `/api/v1/admin/users/{user_id}`             | Delete a user, revoke its sessions  |
+//! | PUT    | `/api/v1/admin/users/{user_id}/password`    | Reset a local account's password    |
 //! | GET    | `/api/v1/admin/users/{user_id}/permissions` | Get user's permissions              |
 //! | PUT    | `/api/v1/admin/users/{user_id}/permissions` | Replace a user's permission set     |
 //! | DELETE | `/api/v1/admin/users/{user_id}/permissions/{level}` | Remove one permission         |
+//! | GET    | `/api/v1/admin/usage`                       | Documents and storage per owner     |
+//! | POST   | `/api/v1/admin/tags/merge`                  | Rewrite tags on existing documents  |
 
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -118,13 +124,14 @@ pub async fn update_access_level_handler(
 
     state.access_level_repo.update(updated.clone()).await?;
 
-    // Spawn cascade recompute if the inheritance structure changed
+    // Queue a cascade recompute if the inheritance structure changed
     if existing.inherits_from != updated.inherits_from {
-        crate::jobs::recompute_access_levels::spawn_recompute_for_level(
-            updated.name.clone(),
-            state.access_level_repo.clone(),
-            state.user_repo.clone(),
-        );
+        state
+            .job_queue
+            .enqueue(crate::jobs::Job::RecomputeAccessLevels {
+                level: updated.name.clone(),
+            })
+            .await;
     }
 
     Ok(Json(updated))
@@ -150,7 +157,9 @@ pub async fn list_users_handler(
 ) -> Result<Json<Vec<User>>, AppError> {
     require_admin(&user)?;
     let users = state.user_repo.list_users().await?;
-    Ok(Json(users))
+    Ok(Json(
+        users.into_iter().map(User::without_credentials).collect(),
+    ))
 }
 
 /// `GET /api/v1/admin/users/{user_id}`
@@ -165,7 +174,121 @@ pub async fn get_user_handler(
         .find_user_by_id(&user_id)
         .await?
         .ok_or_else(|| AppError::NotFound(format!("User '{user_id}' not found")))?;
-    Ok(Json(user))
+    Ok(Json(user.without_credentials()))
+}
+
+/// `POST /api/v1/admin/users`
+///
+/// Creates a local account (requires `auth.local_accounts`). The user must
+/// change the password after the first login.
+pub async fn create_user_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(caller): RequiredAuthUser,
+    Json(req): Json<crate::auth::local_accounts::NewLocalUser>,
+) -> Result<(StatusCode, Json<User>), AppError> {
+    require_admin(&caller)?;
+    if !state.local_accounts {
+        return Err(AppError::BadRequest(
+            "Local accounts are disabled (auth.local_accounts)".into(),
+        ));
+    }
+    let user =
+        crate::auth::local_accounts::create_local_user(state.user_repo.as_ref(), req, true).await?;
+    Ok((StatusCode::CREATED, Json(user.without_credentials())))
+}
+
+/// Request body for updating a user's profile.
+#[derive(Debug, Deserialize)]
+pub struct UpdateUserRequest {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub is_admin: bool,
+}
+
+/// Update a user's display name and admin flag. Admins cannot revoke their
+/// own admin flag, so an instance always keeps at least one administrator.
+pub async fn update_user(
+    user_repo: &dyn crate::db::user_repository::UserRepository,
+    caller: &crate::auth::models::AuthenticatedUser,
+    user_id: &str,
+    req: UpdateUserRequest,
+) -> Result<(), AppError> {
+    if caller.user_id == user_id && !req.is_admin {
+        return Err(AppError::BadRequest(
+            "You cannot remove your own admin privileges".into(),
+        ));
+    }
+    let name = req
+        .name
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+    user_repo
+        .update_user_profile(user_id, name, req.is_admin)
+        .await
+}
+
+/// Delete a user and revoke their refresh tokens. Admins cannot delete
+/// themselves.
+pub async fn delete_user(
+    user_repo: &dyn crate::db::user_repository::UserRepository,
+    caller: &crate::auth::models::AuthenticatedUser,
+    user_id: &str,
+) -> Result<(), AppError> {
+    if caller.user_id == user_id {
+        return Err(AppError::BadRequest("You cannot delete yourself".into()));
+    }
+    user_repo.delete_user(user_id).await?;
+    user_repo.revoke_all_user_tokens(user_id).await
+}
+
+/// `PUT /api/v1/admin/users/{user_id}`
+pub async fn update_user_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(caller): RequiredAuthUser,
+    Path(user_id): Path<String>,
+    Json(req): Json<UpdateUserRequest>,
+) -> Result<Json<User>, AppError> {
+    require_admin(&caller)?;
+    update_user(state.user_repo.as_ref(), &caller, &user_id, req).await?;
+    let updated = state
+        .user_repo
+        .find_user_by_id(&user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("User '{user_id}' not found")))?;
+    Ok(Json(updated.without_credentials()))
+}
+
+/// `DELETE /api/v1/admin/users/{user_id}`
+pub async fn delete_user_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(caller): RequiredAuthUser,
+    Path(user_id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    require_admin(&caller)?;
+    delete_user(state.user_repo.as_ref(), &caller, &user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Request body for resetting a local account's password.
+#[derive(Debug, Deserialize)]
+pub struct ResetPasswordRequest {
+    pub password: String,
+}
+
+/// `PUT /api/v1/admin/users/{user_id}/password`
+///
+/// Sets a temporary password, signs the user out everywhere and requires a
+/// password change after the next login.
+pub async fn reset_user_password_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(caller): RequiredAuthUser,
+    Path(user_id): Path<String>,
+    Json(req): Json<ResetPasswordRequest>,
+) -> Result<StatusCode, AppError> {
+    require_admin(&caller)?;
+    crate::auth::local_accounts::reset_password(state.user_repo.as_ref(), &user_id, req.password)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
 }
 
 /// Request body for assigning access levels to a user.
@@ -235,7 +358,7 @@ pub async fn set_user_access_levels_handler(
         .find_user_by_id(&user_id)
         .await?
         .ok_or_else(|| AppError::NotFound(format!("User '{user_id}' not found")))?;
-    Ok(Json(updated))
+    Ok(Json(updated.without_credentials()))
 }
 
 // ── Service token management ─────────────────────────────────────────────────
@@ -243,9 +366,25 @@ pub async fn set_user_access_levels_handler(
 #[derive(Debug, Deserialize)]
 pub struct CreateServiceTokenRequest {
     pub name: String,
+    /// Scopes; may be omitted for a project token (defaults to the whole project).
+    #[serde(default)]
     pub allowed_scopes: Vec<String>,
     #[serde(default)]
     pub can_write: bool,
+    /// Bind the token to a project, confining its scopes to the project namespace.
+    #[serde(default)]
+    pub project: Option<String>,
+    /// `"service"` (default) or `"bot"` for a read-only token used by chatbots
+    /// and MCP servers.
+    #[serde(default = "default_token_type")]
+    pub token_type: String,
+    /// Access levels a bot token can read. Required for bot tokens.
+    #[serde(default)]
+    pub access_levels: Vec<String>,
+}
+
+fn default_token_type() -> String {
+    "service".to_string()
 }
 
 #[derive(Debug, Serialize)]
@@ -256,6 +395,9 @@ pub struct CreateServiceTokenResponse {
     pub raw_token: String,
     pub allowed_scopes: Vec<String>,
     pub can_write: bool,
+    pub project: Option<String>,
+    pub token_type: String,
+    pub access_levels: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -268,11 +410,18 @@ pub struct ServiceTokenSummary {
     pub created_at: chrono::DateTime<Utc>,
     pub last_used_at: Option<chrono::DateTime<Utc>>,
     pub is_active: bool,
+    pub project: Option<String>,
+    pub token_type: String,
+    pub access_levels: Vec<String>,
 }
 
 /// `POST /api/v1/admin/service-tokens`
 ///
 /// Creates a new scoped service token. Returns the raw token value once.
+///
+/// Bot tokens are read-only: they are limited to `access_levels` instead of
+/// write scopes, and `allowed_scopes` (optional) narrows which documents they
+/// can read.
 pub async fn create_service_token_handler(
     State(state): State<AppState>,
     RequiredAuthUser(user): RequiredAuthUser,
@@ -284,21 +433,49 @@ pub async fn create_service_token_handler(
     if name.is_empty() {
         return Err(AppError::BadRequest("Token name cannot be empty".into()));
     }
-    if req.allowed_scopes.is_empty() {
-        return Err(AppError::BadRequest(
-            "At least one scope is required".into(),
-        ));
-    }
+    let (project, allowed_scopes) = crate::db::project_repository::resolve_token_project(
+        state.project_repo.as_ref(),
+        req.project.as_deref(),
+        req.allowed_scopes,
+    )
+    .await?;
+    let is_bot = match req.token_type.as_str() {
+        "service" => false,
+        "bot" => true,
+        other => {
+            return Err(AppError::BadRequest(format!(
+                "Unsupported token type '{other}' (expected 'service' or 'bot')"
+            )))
+        }
+    };
 
-    // Check for scope overlap with existing tokens
-    let has_overlap = state
-        .service_token_repo
-        .check_scope_overlap(&req.allowed_scopes, None)
+    let mut access_levels = Vec::new();
+    if is_bot {
+        if req.can_write {
+            return Err(AppError::BadRequest("Bot tokens are read-only".into()));
+        }
+        access_levels = crate::api::bot::resolve_key_access_levels(
+            state.access_level_repo.as_ref(),
+            &req.access_levels,
+        )
         .await?;
-    if has_overlap {
-        return Err(AppError::BadRequest(
-            "Scopes overlap with an existing service token".into(),
-        ));
+    } else {
+        if allowed_scopes.is_empty() {
+            return Err(AppError::BadRequest(
+                "At least one scope is required".into(),
+            ));
+        }
+
+        // Check for scope overlap with existing tokens
+        let has_overlap = state
+            .service_token_repo
+            .check_scope_overlap(&allowed_scopes, None)
+            .await?;
+        if has_overlap {
+            return Err(AppError::BadRequest(
+                "Scopes overlap with an existing service token".into(),
+            ));
+        }
     }
 
     // Generate raw token and hash it
@@ -310,14 +487,16 @@ pub async fn create_service_token_handler(
         id: id.clone(),
         name: name.clone(),
         token_hash,
-        allowed_scopes: req.allowed_scopes.clone(),
-        token_type: "service".to_string(),
+        allowed_scopes: allowed_scopes.clone(),
+        token_type: req.token_type.clone(),
         user_id: None,
         can_write: req.can_write,
         created_by: user.user_id,
         created_at: Utc::now(),
         last_used_at: None,
         is_active: true,
+        project: project.clone(),
+        access_levels: access_levels.clone(),
     };
 
     state.service_token_repo.create(token).await?;
@@ -328,8 +507,11 @@ pub async fn create_service_token_handler(
             id,
             name,
             raw_token,
-            allowed_scopes: req.allowed_scopes,
+            allowed_scopes,
             can_write: req.can_write,
+            project,
+            token_type: req.token_type,
+            access_levels,
         }),
     ))
 }
@@ -353,6 +535,9 @@ pub async fn list_service_tokens_handler(
             created_at: t.created_at,
             last_used_at: t.last_used_at,
             is_active: t.is_active,
+            project: t.project,
+            token_type: t.token_type,
+            access_levels: t.access_levels,
         })
         .collect();
 
@@ -370,6 +555,124 @@ pub async fn deactivate_service_token_handler(
     Ok(StatusCode::NO_CONTENT)
 }
 
+// ── Usage and quotas ──────────────────────────────────────────────────────────
+
+/// Usage of every service owner with documents or a configured quota
+/// override, with its effective limits.
+pub async fn owner_quota_usage(
+    state: &AppState,
+) -> Result<Vec<crate::db::models::OwnerQuotaUsage>, AppError> {
+    let mut usage = state.document_repo.usage_by_service_owner(None).await?;
+    for owner in state.quotas.owners.keys() {
+        if !usage
+            .iter()
+            .any(|u| u.service_owner.eq_ignore_ascii_case(owner))
+        {
+            usage.push(crate::db::models::OwnerUsage {
+                service_owner: owner.clone(),
+                ..Default::default()
+            });
+        }
+    }
+    usage.sort_by(|a, b| a.service_owner.cmp(&b.service_owner));
+
+    Ok(usage
+        .into_iter()
+        .map(|u| {
+            let limits = state.quotas.limits_for(&u.service_owner);
+            crate::db::models::OwnerQuotaUsage {
+                service_owner: u.service_owner,
+                document_count: u.document_count,
+                storage_bytes: u.storage_bytes,
+                max_documents: limits.max_document_count(),
+                max_storage_bytes: limits.max_storage_bytes(),
+            }
+        })
+        .collect())
+}
+
+/// `GET /api/v1/admin/usage`
+pub async fn owner_usage_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+) -> Result<Json<Vec<crate::db::models::OwnerQuotaUsage>>, AppError> {
+    require_admin(&user)?;
+    Ok(Json(owner_quota_usage(&state).await?))
+}
+
+// ── Tags ──────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct MergeTagsRequest {
+    /// Tags to replace, matched exactly.
+    pub tags: Vec<String>,
+    /// Tag they are replaced with.
+    pub into: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MergeTagsResponse {
+    /// Slugs of the rewritten documents.
+    pub updated: Vec<String>,
+}
+
+/// Replace `tags` with `into` on every document carrying one of them and
+/// refresh their search entries. Returns the slugs of the rewritten
+/// documents.
+///
+/// Metadata hashes are left alone, so syncs don't re-upload the documents;
+/// a synonym in `[taxonomy.tags]` keeps later ingests from restoring the old
+/// tags.
+pub async fn merge_tags(
+    state: &AppState,
+    tags: &[String],
+    into: &str,
+) -> Result<Vec<String>, AppError> {
+    let into = into.trim();
+    if into.is_empty() {
+        return Err(AppError::BadRequest("Target tag cannot be empty".into()));
+    }
+
+    let mut updated = vec![];
+    for mut doc in state.document_repo.list_all().await? {
+        if !doc.tags.iter().any(|t| tags.contains(t)) {
+            continue;
+        }
+        let mut merged: Vec<String> = Vec::with_capacity(doc.tags.len());
+        for tag in &doc.tags {
+            let tag = if tags.contains(tag) { into } else { tag };
+            if !merged.iter().any(|t| t == tag) {
+                merged.push(tag.to_string());
+            }
+        }
+        doc.tags = merged;
+        state.document_repo.create_or_update(doc.clone()).await?;
+
+        if let Some(search) = state
+            .search_service
+            .as_deref()
+            .filter(|_| !doc.is_archived && !doc.is_hidden)
+        {
+            crate::search::reindex::index_from_storage(&doc, state.storage_client.as_ref(), search)
+                .await;
+        }
+        updated.push(doc.slug);
+    }
+    Ok(updated)
+}
+
+/// `POST /api/v1/admin/tags/merge`
+pub async fn merge_tags_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Json(req): Json<MergeTagsRequest>,
+) -> Result<Json<MergeTagsResponse>, AppError> {
+    require_admin(&user)?;
+    let updated = merge_tags(&state, &req.tags, &req.into).await?;
+    tracing::info!(into = %req.into, count = updated.len(), "Merged tags");
+    Ok(Json(MergeTagsResponse { updated }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,200 @@
+//! Approval queue for ingests to protected slug prefixes.
+//!
+//! All routes require an authenticated admin user (`is_admin = true`).
+//!
+//! | Method | Path                                          | Description                         |
+//! |--------|-----------------------------------------------|-------------------------------------|
+//! | GET    | `/api/v1/admin/protected-prefixes`            | List the protected prefixes         |
+//! | PUT    | `/api/v1/admin/protected-prefixes`            | Replace the protected prefixes      |
+//! | GET    | `/api/v1/admin/pending-ingests`               | List ingests waiting for approval   |
+//! | GET    | `/api/v1/admin/pending-ingests/{id}`          | Get a pending ingest with content   |
+//! | POST   | `/api/v1/admin/pending-ingests/{id}/approve`  | Publish a pending ingest            |
+//! | DELETE | `/api/v1/admin/pending-ingests/{id}`          | Reject (discard) a pending ingest   |
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::auth::extractor::RequiredAuthUser;
+use crate::auth::models::AuthenticatedUser;
+use crate::db::models::IngestResponse;
+use crate::db::pending_ingest_repository::PendingIngest;
+use crate::error::AppError;
+
+fn require_admin(user: &AuthenticatedUser) -> Result<(), AppError> {
+    if user.is_admin {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden("Admin privileges required".into()))
+    }
+}
+
+/// Body of the protected prefix endpoints.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProtectedPrefixes {
+    pub prefixes: Vec<String>,
+}
+
+fn not_found(id: &str) -> AppError {
+    AppError::NotFound(format!("Pending ingest '{id}' not found"))
+}
+
+/// The user behind the token that submitted `pending`: a personal access
+/// token's user, or the admin who created a service token. `None` for the
+/// legacy token and deleted tokens.
+async fn submitter(state: &AppState, pending: &PendingIngest) -> Result<Option<String>, AppError> {
+    Ok(state
+        .service_token_repo
+        .find_by_name(&pending.submitted_by)
+        .await?
+        .map(|token| token.user_id.unwrap_or(token.created_by)))
+}
+
+/// Tell the submitter of `pending` that it was approved or rejected.
+async fn notify_decision(
+    state: &AppState,
+    pending: &PendingIngest,
+    approved: bool,
+    decided_by: &AuthenticatedUser,
+) {
+    match submitter(state, pending).await {
+        Ok(Some(owner)) => {
+            state
+                .inbox
+                .approval_decided(&owner, pending, approved, decided_by)
+                .await
+        }
+        Ok(None) => {}
+        Err(e) => tracing::warn!(slug = %pending.slug, "Failed to resolve ingest submitter: {e}"),
+    }
+}
+
+/// Publish a pending ingest and remove it from the queue.
+pub async fn approve_pending_ingest(
+    state: &AppState,
+    id: &str,
+    approved_by: &AuthenticatedUser,
+) -> Result<IngestResponse, AppError> {
+    use crate::api::ingest::{publish_pending, IngestContext};
+
+    let pending = state
+        .pending_ingest_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| not_found(id))?;
+    let ctx = IngestContext {
+        repo: state.document_repo.as_ref(),
+        storage: state.storage_client.as_ref(),
+        search: state.search_service.as_deref(),
+        access_level_repo: state.access_level_repo.as_ref(),
+        project_repo: Some(state.project_repo.as_ref()),
+        service_token_repo: state.service_token_repo.as_ref(),
+        version_repo: state.document_version_repo.as_ref(),
+        rag: state.rag_service.as_deref(),
+        events: Some(&state.activity_events),
+        legacy_token: Some(&state.service_token),
+        dry_run: false,
+        approval: None,
+        quotas: Some(&state.quotas),
+        taxonomy: Some(&state.taxonomy),
+        lint: Some(&state.lint),
+        spell_checker: state.spell_checker.as_deref(),
+        secrets: state.secret_guard.as_deref(),
+        mentions: state.mentions.as_deref(),
+    };
+    let response = publish_pending(&ctx, state.pending_ingest_repo.as_ref(), id).await?;
+    tracing::info!(slug = %response.slug, approved_by = %approved_by.email, "Approved pending ingest");
+    notify_decision(state, &pending, true, approved_by).await;
+    Ok(response)
+}
+
+/// Discard a pending ingest.
+pub async fn reject_pending_ingest(
+    state: &AppState,
+    id: &str,
+    rejected_by: &AuthenticatedUser,
+) -> Result<(), AppError> {
+    let pending = state
+        .pending_ingest_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| not_found(id))?;
+    state.pending_ingest_repo.delete(id).await?;
+    tracing::info!(id, rejected_by = %rejected_by.email, "Rejected pending ingest");
+    notify_decision(state, &pending, false, rejected_by).await;
+    Ok(())
+}
+
+/// `GET /api/v1/admin/protected-prefixes`
+pub async fn get_protected_prefixes_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+) -> Result<Json<ProtectedPrefixes>, AppError> {
+    require_admin(&user)?;
+    let prefixes = state.settings_repo.get_settings().await?.protected_prefixes;
+    Ok(Json(ProtectedPrefixes { prefixes }))
+}
+
+/// `PUT /api/v1/admin/protected-prefixes`
+///
+/// Ingests already queued stay pending when their prefix is unprotected.
+pub async fn put_protected_prefixes_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Json(body): Json<ProtectedPrefixes>,
+) -> Result<Json<ProtectedPrefixes>, AppError> {
+    require_admin(&user)?;
+    let prefixes = crate::db::settings_repository::normalize_protected_prefixes(body.prefixes)?;
+    state
+        .settings_repo
+        .set_protected_prefixes(&prefixes)
+        .await?;
+    Ok(Json(ProtectedPrefixes { prefixes }))
+}
+
+/// `GET /api/v1/admin/pending-ingests`
+pub async fn list_pending_ingests_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+) -> Result<Json<Vec<PendingIngest>>, AppError> {
+    require_admin(&user)?;
+    Ok(Json(state.pending_ingest_repo.list_all().await?))
+}
+
+/// `GET /api/v1/admin/pending-ingests/{id}`
+pub async fn get_pending_ingest_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<PendingIngest>, AppError> {
+    require_admin(&user)?;
+    state
+        .pending_ingest_repo
+        .find_by_id(&id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| not_found(&id))
+}
+
+/// `POST /api/v1/admin/pending-ingests/{id}/approve`
+pub async fn approve_pending_ingest_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<IngestResponse>, AppError> {
+    require_admin(&user)?;
+    Ok(Json(approve_pending_ingest(&state, &id, &user).await?))
+}
+
+/// `DELETE /api/v1/admin/pending-ingests/{id}`
+pub async fn reject_pending_ingest_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    require_admin(&user)?;
+    reject_pending_ingest(&state, &id, &user).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,340 @@
+//! Access control and download audit for attachments and images.
+//!
+//! Documents remember the files their content embeds (`Document::asset_refs`).
+//! A file embedded by active documents follows the most restrictive of them:
+//! it is only served to callers who can read every one, so embedding a
+//! restricted file in a public document does not make it public. Downloads
+//! of files that a restricted or draft document embeds are recorded in the
+//! audit log.
+//!
+//! References of documents saved before they were tracked are backfilled
+//! from the stored content at startup ([`spawn_backfill`]), retried until it
+//! succeeds. Until then, files no active document embeds are only served to
+//! callers who can read every document (administrators), since they may
+//! belong to a restricted document not backfilled yet; afterwards they are
+//! readable by authenticated callers, since the editor uploads files before
+//! the document embedding them is saved. Orphaned and freshly uploaded files
+//! are never public.
+//!
+//! The page of a share link adds its token to the file URLs it contains, so
+//! anonymous viewers can fetch what the shared document embeds.
+//!
+//! | Method | Path                             | Description                                        |
+//! |--------|----------------------------------|----------------------------------------------------|
+//! | GET    | `/api/v1/admin/asset-downloads`  | Recorded downloads (`?key=<storage key>&limit=<n>`) |
+
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use serde::Deserialize;
+
+use crate::api::events::Caller;
+use crate::app::AppState;
+use crate::auth::extractor::RequiredAuthUser;
+use crate::auth::models::AuthenticatedUser;
+use crate::db::asset_download_repository::AssetDownload;
+use crate::db::models::Document;
+use crate::db::repository::DocumentRepository;
+use crate::error::AppError;
+use crate::storage::client::StorageClient;
+
+/// Downloads returned when `limit` is not given.
+const DEFAULT_DOWNLOAD_LIMIT: i64 = 100;
+/// Upper bound of `limit`.
+const MAX_DOWNLOAD_LIMIT: i64 = 1000;
+
+/// Delay before the first retry of a failed backfill, doubled on each retry.
+const BACKFILL_RETRY_BASE: Duration = Duration::from_secs(5);
+/// Longest delay between backfill retries.
+const BACKFILL_RETRY_MAX: Duration = Duration::from_secs(300);
+
+/// URL prefixes of served files and the storage prefix they map to.
+const SERVED_PREFIXES: [(&str, &str); 2] = [
+    ("/api/v1/assets/", "assets/"),
+    ("/api/v1/image/", "images/"),
+];
+
+/// Storage keys of the attachments and images linked from `content`, in
+/// order of first appearance.
+///
+/// Links are recognized by their path, so relative and absolute URLs of this
+/// instance both count.
+pub fn referenced_asset_keys(content: &str) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for (url_prefix, storage_prefix) in SERVED_PREFIXES {
+        for (start, _) in content.match_indices(url_prefix) {
+            let rest = &content[start + url_prefix.len()..];
+            let end = rest
+                .find(|c: char| {
+                    c.is_whitespace() || matches!(c, ')' | ']' | '"' | '\'' | '<' | '>' | '?' | '#')
+                })
+                .unwrap_or(rest.len());
+            let path = &rest[..end];
+            if path.is_empty() {
+                continue;
+            }
+            let path = urlencoding::decode(path)
+                .map(|p| p.into_owned())
+                .unwrap_or_else(|_| path.to_string());
+            let key = format!("{storage_prefix}{path}");
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    keys
+}
+
+/// Bring the `asset_refs` of every document, archived ones included, in line
+/// with its stored content. Returns the number of documents updated.
+pub async fn backfill_asset_refs(
+    document_repo: &dyn DocumentRepository,
+    storage: &dyn StorageClient,
+) -> Result<usize, AppError> {
+    let mut updated = 0;
+    for doc in document_repo.list_all().await? {
+        let content = match storage.get_object(&doc.s3_key).await? {
+            Some(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            None => continue,
+        };
+        let refs = referenced_asset_keys(&content);
+        if refs != doc.asset_refs {
+            document_repo.set_asset_refs(&doc.slug, &refs).await?;
+            updated += 1;
+        }
+    }
+    Ok(updated)
+}
+
+/// Run [`backfill_asset_refs`] in the background, retrying with exponential
+/// backoff until it succeeds, then start serving files no document embeds to
+/// authenticated callers (`AppState::asset_refs_ready`).
+pub fn spawn_backfill(state: AppState) {
+    tokio::spawn(async move {
+        let mut delay = BACKFILL_RETRY_BASE;
+        loop {
+            match backfill_asset_refs(state.document_repo.as_ref(), state.storage_client.as_ref())
+                .await
+            {
+                Ok(updated) => {
+                    tracing::info!(updated, "Backfilled document asset references");
+                    state.asset_refs_ready.store(true, Ordering::Release);
+                    return;
+                }
+                Err(e) => {
+                    tracing::error!(
+                        retry_in_secs = delay.as_secs(),
+                        "Asset reference backfill failed, files no document embeds stay \
+                         admin-only until it succeeds: {e}"
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay = (delay * 2).min(BACKFILL_RETRY_MAX);
+                }
+            }
+        }
+    });
+}
+
+/// Whether the caller may download the file stored under `s3_key`; see the
+/// module docs. Allowed downloads of restricted files are recorded.
+///
+/// `share_token` is the token of a share link (the `share` query parameter
+/// of the URLs on the shared page). It grants a file that the shared
+/// document embeds, unless another restricted document embeds it too.
+///
+/// Callers answer `false` with the same `404` as a missing file, so the
+/// file's existence is not revealed.
+pub(crate) async fn authorize_download(
+    state: &AppState,
+    headers: &HeaderMap,
+    user: Option<AuthenticatedUser>,
+    share_token: Option<&str>,
+    s3_key: &str,
+) -> Result<bool, AppError> {
+    let docs: Vec<_> = state
+        .document_repo
+        .find_by_asset_ref(s3_key)
+        .await?
+        .into_iter()
+        .filter(|d| !d.is_archived)
+        .collect();
+    if docs.is_empty() {
+        let caller = crate::api::events::identify_caller(state, headers, user).await?;
+        let refs_ready = state.asset_refs_ready.load(Ordering::Acquire);
+        return Ok(unreferenced_file_readable(&caller, refs_ready));
+    }
+    let restricted: Vec<_> = docs
+        .iter()
+        .filter(|d| d.access_level != "public" || d.is_draft)
+        .collect();
+    let Some(doc) = restricted.first() else {
+        return Ok(true);
+    };
+
+    if let Some(token) = share_token {
+        match crate::api::share::resolve_share_link(state, token).await {
+            Ok((link, shared)) if restricted.iter().all(|d| d.slug == shared.slug) => {
+                record_download(state, s3_key, doc, format!("share link {}", link.id)).await;
+                return Ok(true);
+            }
+            Ok(_) | Err(AppError::NotFound(_)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let caller = crate::api::events::identify_caller(state, headers, user).await?;
+    let readable = restricted.iter().all(|d| {
+        crate::app::doc_is_accessible(
+            &d.access_level,
+            d.is_draft,
+            caller.allowed_levels.as_deref(),
+            caller.include_draft,
+        )
+    });
+    if !readable {
+        return Ok(false);
+    }
+
+    let downloaded_by = caller.email.unwrap_or_else(|| "anonymous".to_string());
+    record_download(state, s3_key, doc, downloaded_by).await;
+    Ok(true)
+}
+
+/// Whether `caller` may read a file no active document embeds. Before the
+/// backfill has run (`refs_ready`), the file may still belong to a restricted
+/// document, so only callers who can read every document qualify.
+fn unreferenced_file_readable(caller: &Caller, refs_ready: bool) -> bool {
+    if refs_ready {
+        caller.email.is_some()
+    } else {
+        caller.allowed_levels.is_none() && caller.include_draft
+    }
+}
+
+/// Record a download of `s3_key`, embedded by the restricted document `doc`.
+async fn record_download(state: &AppState, s3_key: &str, doc: &Document, downloaded_by: String) {
+    let download = AssetDownload {
+        s3_key: s3_key.to_string(),
+        slug: doc.slug.clone(),
+        access_level: doc.access_level.clone(),
+        downloaded_by,
+        downloaded_at: chrono::Utc::now(),
+    };
+    if let Err(e) = state.asset_download_repo.record(download).await {
+        tracing::warn!(s3_key, "Failed to record asset download: {e}");
+    }
+}
+
+/// Query parameters of the attachment and image routes.
+#[derive(Debug, Default, Deserialize)]
+pub struct DownloadQuery {
+    /// Share link token, set on the URLs of a shared page.
+    pub share: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssetDownloadsQuery {
+    /// Storage key of the file, e.g. `assets/guides/diagram.png`.
+    pub key: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/admin/asset-downloads`
+pub async fn list_asset_downloads_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Query(query): Query<AssetDownloadsQuery>,
+) -> Result<Json<Vec<AssetDownload>>, AppError> {
+    if !user.is_admin {
+        return Err(AppError::Forbidden("Admin privileges required".into()));
+    }
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_DOWNLOAD_LIMIT)
+        .clamp(1, MAX_DOWNLOAD_LIMIT);
+    let downloads = state
+        .asset_download_repo
+        .list_recent(query.key.as_deref(), limit)
+        .await?;
+    Ok(Json(downloads))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, InMemoryDocumentRepository, InMemoryStorage};
+
+    fn doc(slug: &str) -> Document {
+        Document {
+            access_level: "internal".to_string(),
+            ..testing::document(slug)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backfill_asset_refs_from_stored_content() {
+        let repo = InMemoryDocumentRepository::with_documents(vec![doc("runbook"), doc("empty")]);
+        let storage = InMemoryStorage::new();
+        storage
+            .put_object(
+                "docs/runbook.md",
+                b"[Runbook](/api/v1/assets/ops/runbook.pdf)".to_vec(),
+            )
+            .await
+            .unwrap();
+        storage
+            .put_object("docs/empty.md", b"No files".to_vec())
+            .await
+            .unwrap();
+
+        assert_eq!(backfill_asset_refs(&repo, &storage).await.unwrap(), 1);
+        let docs = repo
+            .find_by_asset_ref("assets/ops/runbook.pdf")
+            .await
+            .unwrap();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].slug, "runbook");
+        // Already up to date
+        assert_eq!(backfill_asset_refs(&repo, &storage).await.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_unreferenced_files_are_admin_only_until_backfilled() {
+        let caller = |email: Option<&str>, allowed_levels: Option<Vec<String>>| Caller {
+            email: email.map(str::to_string),
+            include_draft: allowed_levels.is_none(),
+            allowed_levels,
+        };
+        let admin = caller(Some("admin@example.com"), None);
+        let reader = caller(Some("reader@example.com"), Some(vec!["internal".into()]));
+        let anonymous = caller(None, Some(vec!["public".into()]));
+
+        assert!(unreferenced_file_readable(&admin, false));
+        assert!(!unreferenced_file_readable(&reader, false));
+        assert!(!unreferenced_file_readable(&anonymous, false));
+
+        assert!(unreferenced_file_readable(&admin, true));
+        assert!(unreferenced_file_readable(&reader, true));
+        assert!(!unreferenced_file_readable(&anonymous, true));
+    }
+
+    #[test]
+    fn test_referenced_asset_keys_maps_urls_to_storage_keys() {
+        let content = "![Diagram](/api/v1/image/1700_diagram.png \"Flow\")\n\
+            See [the runbook](https://docs.example.com/api/v1/assets/attachments/ops/run%20book.pdf?download=1).\n\
+            <img src=\"/api/v1/assets/editor/1_logo.svg\"> and again ![x](/api/v1/image/1700_diagram.png)\n\
+            [Docs](/docs/api/v1/guide)";
+
+        assert_eq!(
+            referenced_asset_keys(content),
+            vec![
+                "assets/attachments/ops/run book.pdf".to_string(),
+                "assets/editor/1_logo.svg".to_string(),
+                "images/1700_diagram.png".to_string(),
+            ]
+        );
+    }
+}
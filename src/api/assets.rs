@@ -65,6 +65,7 @@ pub async fn process_upload_asset(
     legacy_token: Option<&str>,
     service_token: &str,
     max_size: u64,
+    scanner: Option<&crate::scanning::UploadScanner>,
 ) -> Result<AssetUploadResponse, AppError> {
     // Validate token (legacy or DB-backed)
     crate::api::token_validation::validate_service_token(
@@ -100,6 +101,17 @@ pub async fn process_upload_asset(
     let s3_key = format!("assets/{}", key);
     let content_hash = Some(compute_content_hash(&data));
 
+    if let Some(scanner) = scanner {
+        scanner
+            .screen(crate::scanning::Upload {
+                target_key: &s3_key,
+                content_type,
+                uploaded_by,
+                data: &data,
+            })
+            .await?;
+    }
+
     // Upload to S3
     storage.put_object(&s3_key, data).await?;
 
@@ -132,22 +144,154 @@ pub async fn process_upload_asset(
     })
 }
 
-/// Core serve logic — returns (content_type, data).
+/// A byte range requested through the `Range` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// Serve bytes `start..=end`.
+    Satisfiable { start: u64, end: u64 },
+    /// The range starts past the end of the asset (`416`).
+    Unsatisfiable,
+}
+
+/// Parse a `Range` header against an asset of `size` bytes.
+///
+/// Single `bytes=` ranges are honored (`a-b`, `a-`, `-suffix`); anything else
+/// returns `None` and the whole asset is served, as RFC 9110 allows.
+pub fn parse_range(header: &str, size: u64) -> Option<ByteRange> {
+    let spec = header.trim().strip_prefix("bytes=")?;
+    if spec.contains(',') {
+        return None;
+    }
+    let (first, last) = spec.trim().split_once('-')?;
+    let (first, last) = (first.trim(), last.trim());
+
+    if first.is_empty() {
+        let suffix: u64 = last.parse().ok()?;
+        if suffix == 0 || size == 0 {
+            return Some(ByteRange::Unsatisfiable);
+        }
+        return Some(ByteRange::Satisfiable {
+            start: size.saturating_sub(suffix),
+            end: size - 1,
+        });
+    }
+
+    let start: u64 = first.parse().ok()?;
+    let end: u64 = if last.is_empty() {
+        u64::MAX
+    } else {
+        last.parse().ok()?
+    };
+    if end < start {
+        return None;
+    }
+    if start >= size {
+        return Some(ByteRange::Unsatisfiable);
+    }
+    Some(ByteRange::Satisfiable {
+        start,
+        end: end.min(size - 1),
+    })
+}
+
+/// Asset content returned by [`process_serve_asset`].
+#[derive(Debug)]
+pub struct ServedAsset {
+    pub content_type: String,
+    /// Size of the whole asset.
+    pub size_bytes: u64,
+    /// The honored `Range`, if any; `data` then only holds those bytes
+    /// (nothing for an unsatisfiable range).
+    pub range: Option<ByteRange>,
+    pub data: Vec<u8>,
+}
+
+impl ServedAsset {
+    /// Serve the honored part of `data`, the whole content of a file read
+    /// in one piece, for the `Range` header value `range`.
+    pub fn sliced(content_type: String, data: Vec<u8>, range: Option<&str>) -> Self {
+        let size_bytes = data.len() as u64;
+        let range = range.and_then(|header| parse_range(header, size_bytes));
+        let data = match range {
+            None => data,
+            Some(ByteRange::Satisfiable { start, end }) => {
+                data[start as usize..=end as usize].to_vec()
+            }
+            Some(ByteRange::Unsatisfiable) => Vec::new(),
+        };
+        Self {
+            content_type,
+            size_bytes,
+            range,
+            data,
+        }
+    }
+
+    /// `200` with the whole file, `206` with the range, or `416`; all
+    /// advertise `Accept-Ranges: bytes`.
+    #[cfg(feature = "ssr")]
+    pub fn into_response(self) -> axum::response::Response {
+        use axum::http::{header, StatusCode};
+        use axum::response::IntoResponse;
+
+        let size = self.size_bytes;
+        match self.range {
+            None => (
+                [
+                    (header::CONTENT_TYPE, self.content_type),
+                    (header::ACCEPT_RANGES, "bytes".to_string()),
+                ],
+                self.data,
+            )
+                .into_response(),
+            Some(ByteRange::Satisfiable { start, end }) => (
+                StatusCode::PARTIAL_CONTENT,
+                [
+                    (header::CONTENT_TYPE, self.content_type),
+                    (header::ACCEPT_RANGES, "bytes".to_string()),
+                    (header::CONTENT_RANGE, format!("bytes {start}-{end}/{size}")),
+                ],
+                self.data,
+            )
+                .into_response(),
+            Some(ByteRange::Unsatisfiable) => (
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                [(header::CONTENT_RANGE, format!("bytes */{size}"))],
+            )
+                .into_response(),
+        }
+    }
+}
+
+/// Core serve logic. With a `Range` header value only the requested bytes
+/// are read from storage.
 pub async fn process_serve_asset(
     asset_repo: &dyn AssetRepository,
     storage: &dyn StorageClient,
     key: &str,
-) -> Result<(String, Vec<u8>), AppError> {
+    range: Option<&str>,
+) -> Result<ServedAsset, AppError> {
     let asset = asset_repo
         .find_by_key(key)
         .await?
         .ok_or_else(|| AppError::NotFound(format!("Asset '{}' not found", key)))?;
 
-    let data = storage.get_object(&asset.s3_key).await?.ok_or_else(|| {
-        AppError::Storage(format!("Asset content missing in storage for '{}'", key))
-    })?;
+    let range = range.and_then(|header| parse_range(header, asset.size_bytes));
+    let data = match range {
+        None => storage.get_object(&asset.s3_key).await?,
+        Some(ByteRange::Satisfiable { start, end }) => {
+            storage.get_object_range(&asset.s3_key, start, end).await?
+        }
+        Some(ByteRange::Unsatisfiable) => Some(Vec::new()),
+    }
+    .ok_or_else(|| AppError::Storage(format!("Asset content missing in storage for '{}'", key)))?;
 
-    Ok((asset.content_type, data))
+    Ok(ServedAsset {
+        content_type: asset.content_type,
+        size_bytes: asset.size_bytes,
+        range,
+        data,
+    })
 }
 
 /// Core list logic.
@@ -253,12 +397,17 @@ pub async fn process_check_hashes(
 }
 
 /// Core editor upload logic — no token validation, generates key from filename.
+///
+/// `uploaded_by` is the authenticated uploader, see [`crate::api::uploader`].
+#[cfg(feature = "ssr")]
 pub async fn process_editor_upload(
     asset_repo: &dyn AssetRepository,
     storage: &dyn StorageClient,
     file_name: &str,
     content_type: &str,
     data: Vec<u8>,
+    uploaded_by: &str,
+    scanner: Option<&crate::scanning::UploadScanner>,
 ) -> Result<EditorUploadResponse, AppError> {
     let sanitized_name: String = file_name
         .chars()
@@ -277,6 +426,17 @@ pub async fn process_editor_upload(
     let size_bytes = data.len() as u64;
     let content_hash = Some(compute_content_hash(&data));
 
+    if let Some(scanner) = scanner {
+        scanner
+            .screen(crate::scanning::Upload {
+                target_key: &s3_key,
+                content_type,
+                uploaded_by,
+                data: &data,
+            })
+            .await?;
+    }
+
     storage.put_object(&s3_key, data).await?;
 
     let asset = Asset {
@@ -285,7 +445,7 @@ pub async fn process_editor_upload(
         size_bytes,
         s3_key,
         uploaded_at: Utc::now(),
-        uploaded_by: "web-editor".to_string(),
+        uploaded_by: uploaded_by.to_string(),
         referenced_by: vec![],
         content_hash,
     };
@@ -375,17 +535,30 @@ pub async fn upload_asset_handler(
     let data = file_data.ok_or_else(|| AppError::BadRequest("Missing file field".into()))?;
     let content_type = content_type.unwrap_or_else(|| "application/octet-stream".to_string());
 
-    let response = process_upload_asset(
-        state.asset_repo.as_ref(),
-        state.storage_client.as_ref(),
-        &key,
-        &content_type,
-        data,
-        &service_token, // use token as uploader identity for now
+    let uploader = crate::api::token_validation::authenticate_service_token(
         state.service_token_repo.as_ref(),
         Some(&state.service_token),
         &service_token,
-        state.max_attachment_size_bytes,
+    )
+    .await?;
+    let size_bytes = data.len() as u64;
+    let response = crate::api::uploader::with_upload_quota(
+        &state,
+        &uploader,
+        size_bytes,
+        process_upload_asset(
+            state.asset_repo.as_ref(),
+            state.storage_client.as_ref(),
+            &key,
+            &content_type,
+            data,
+            &uploader,
+            state.service_token_repo.as_ref(),
+            Some(&state.service_token),
+            &service_token,
+            state.max_attachment_size_bytes,
+            state.upload_scanner.as_deref(),
+        ),
     )
     .await?;
 
@@ -393,21 +566,45 @@ pub async fn upload_asset_handler(
 }
 
 /// Axum handler for `GET /api/v1/assets/{*key}`.
+///
+/// Supports single-range `Range` requests (`206 Partial Content`), so
+/// browsers can stream large PDFs and videos. Assets embedded by documents
+/// are only served to callers who can read one of them (see
+/// [`crate::api::asset_access`]).
 #[cfg(feature = "ssr")]
 pub async fn serve_asset_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
     axum::extract::Path(key): axum::extract::Path<String>,
+    crate::auth::extractor::OptionalAuthUser(user): crate::auth::extractor::OptionalAuthUser,
+    axum::extract::Query(query): axum::extract::Query<crate::api::asset_access::DownloadQuery>,
+    headers: axum::http::HeaderMap,
 ) -> Result<axum::response::Response, AppError> {
-    use axum::response::IntoResponse;
-
-    let (content_type, data) = process_serve_asset(
+    use axum::http::header;
+
+    let s3_key = format!("assets/{key}");
+    if !crate::api::asset_access::authorize_download(
+        &state,
+        &headers,
+        user,
+        query.share.as_deref(),
+        &s3_key,
+    )
+    .await?
+    {
+        return Err(AppError::NotFound(format!("Asset '{}' not found", key)));
+    }
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok());
+    let served = process_serve_asset(
         state.asset_repo.as_ref(),
         state.storage_client.as_ref(),
         &key,
+        range,
     )
     .await?;
 
-    Ok(([(axum::http::header::CONTENT_TYPE, content_type)], data).into_response())
+    Ok(served.into_response())
 }
 
 /// Axum handler for `GET /api/v1/assets`.
@@ -451,12 +648,15 @@ pub async fn delete_asset_handler(
 
 /// Axum handler for `POST /api/v1/editor/upload-asset`.
 ///
-/// Editor-based upload — no service token required. Accepts multipart with `file` field.
+/// Editor-based upload. Accepts multipart with a `file` field, from a
+/// logged-in user or with a `service_token` field.
 #[cfg(feature = "ssr")]
 pub async fn editor_upload_asset_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    crate::auth::extractor::OptionalAuthUser(user): crate::auth::extractor::OptionalAuthUser,
     mut multipart: axum::extract::Multipart,
 ) -> Result<axum::Json<EditorUploadResponse>, AppError> {
+    let mut service_token = None;
     let mut file_data = None;
     let mut content_type = None;
     let mut file_name = None;
@@ -467,34 +667,55 @@ pub async fn editor_upload_asset_handler(
         .map_err(|e| AppError::BadRequest(format!("Multipart error: {e}")))?
     {
         let name = field.name().unwrap_or("").to_string();
-        if name == "file" {
-            file_name = Some(field.file_name().unwrap_or("upload.bin").to_string());
-            content_type = Some(
-                field
-                    .content_type()
-                    .unwrap_or("application/octet-stream")
-                    .to_string(),
-            );
-            file_data = Some(
-                field
-                    .bytes()
-                    .await
-                    .map_err(|e| AppError::BadRequest(format!("Failed to read file: {e}")))?
-                    .to_vec(),
-            );
+        match name.as_str() {
+            "service_token" => {
+                service_token = Some(
+                    field
+                        .text()
+                        .await
+                        .map_err(|e| AppError::BadRequest(format!("Failed to read token: {e}")))?,
+                );
+            }
+            "file" => {
+                file_name = Some(field.file_name().unwrap_or("upload.bin").to_string());
+                content_type = Some(
+                    field
+                        .content_type()
+                        .unwrap_or("application/octet-stream")
+                        .to_string(),
+                );
+                file_data = Some(
+                    field
+                        .bytes()
+                        .await
+                        .map_err(|e| AppError::BadRequest(format!("Failed to read file: {e}")))?
+                        .to_vec(),
+                );
+            }
+            _ => {}
         }
     }
 
+    let uploader =
+        crate::api::uploader::resolve_uploader(&state, user, service_token.as_deref()).await?;
     let data = file_data.ok_or_else(|| AppError::BadRequest("Missing file field".into()))?;
     let content_type = content_type.unwrap_or_else(|| "application/octet-stream".to_string());
     let file_name = file_name.unwrap_or_else(|| "upload.bin".to_string());
 
-    let response = process_editor_upload(
-        state.asset_repo.as_ref(),
-        state.storage_client.as_ref(),
-        &file_name,
-        &content_type,
-        data,
+    let size_bytes = data.len() as u64;
+    let response = crate::api::uploader::with_upload_quota(
+        &state,
+        &uploader,
+        size_bytes,
+        process_editor_upload(
+            state.asset_repo.as_ref(),
+            state.storage_client.as_ref(),
+            &file_name,
+            &content_type,
+            data,
+            &uploader,
+            state.upload_scanner.as_deref(),
+        ),
     )
     .await?;
 
@@ -509,7 +730,7 @@ mod tests {
 
     use crate::db::service_token_models::ServiceToken;
     use crate::db::service_token_repository::ServiceTokenRepository;
-    use crate::test_utils::MockStorage;
+    use crate::testing::InMemoryStorage;
 
     struct MockServiceTokenRepo;
 
@@ -621,7 +842,7 @@ mod tests {
     #[tokio::test]
     async fn test_upload_asset_success() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
         let data = b"hello world".to_vec();
 
         let result = process_upload_asset(
@@ -635,6 +856,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await;
 
@@ -658,7 +880,7 @@ mod tests {
     #[tokio::test]
     async fn test_upload_asset_invalid_token() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         let result = process_upload_asset(
             &repo,
@@ -671,6 +893,7 @@ mod tests {
             Some("valid-token"),
             "wrong-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await;
 
@@ -684,7 +907,7 @@ mod tests {
     #[tokio::test]
     async fn test_upload_asset_empty_key() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         let result = process_upload_asset(
             &repo,
@@ -697,6 +920,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await;
 
@@ -710,7 +934,7 @@ mod tests {
     #[tokio::test]
     async fn test_upload_asset_key_with_dotdot_rejected() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         let result = process_upload_asset(
             &repo,
@@ -723,6 +947,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await;
 
@@ -736,7 +961,7 @@ mod tests {
     #[tokio::test]
     async fn test_upload_asset_key_starting_with_slash_rejected() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         let result = process_upload_asset(
             &repo,
@@ -749,6 +974,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await;
 
@@ -762,7 +988,7 @@ mod tests {
     #[tokio::test]
     async fn test_upload_asset_replaces_existing_preserves_referenced_by() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         // Upload initial version
         process_upload_asset(
@@ -776,6 +1002,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await
         .unwrap();
@@ -798,6 +1025,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await
         .unwrap();
@@ -810,7 +1038,7 @@ mod tests {
     #[tokio::test]
     async fn test_serve_asset_success() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
         let content = b"PDF content here".to_vec();
 
         // Upload first
@@ -825,25 +1053,79 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await
         .unwrap();
 
         // Serve
-        let (ct, data) = process_serve_asset(&repo, &storage, "docs/manual.pdf")
+        let served = process_serve_asset(&repo, &storage, "docs/manual.pdf", None)
+            .await
+            .unwrap();
+
+        assert_eq!(served.content_type, "application/pdf");
+        assert_eq!(served.data, content);
+        assert_eq!(served.range, None);
+
+        // Serve a range
+        let served = process_serve_asset(&repo, &storage, "docs/manual.pdf", Some("bytes=1-3"))
             .await
             .unwrap();
+        assert_eq!(
+            served.range,
+            Some(ByteRange::Satisfiable { start: 1, end: 3 })
+        );
+        assert_eq!(served.data, content[1..=3]);
+        assert_eq!(served.size_bytes, content.len() as u64);
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let range = |start, end| Some(ByteRange::Satisfiable { start, end });
+        assert_eq!(parse_range("bytes=0-99", 1000), range(0, 99));
+        assert_eq!(parse_range("bytes=900-", 1000), range(900, 999));
+        assert_eq!(parse_range("bytes=900-5000", 1000), range(900, 999));
+        assert_eq!(parse_range("bytes=-100", 1000), range(900, 999));
+        assert_eq!(parse_range("bytes=-5000", 1000), range(0, 999));
+        assert_eq!(
+            parse_range("bytes=1000-", 1000),
+            Some(ByteRange::Unsatisfiable)
+        );
+        assert_eq!(
+            parse_range("bytes=-0", 1000),
+            Some(ByteRange::Unsatisfiable)
+        );
+        // Unsupported or malformed ranges fall back to the whole asset
+        assert_eq!(parse_range("bytes=0-1,5-9", 1000), None);
+        assert_eq!(parse_range("bytes=9-1", 1000), None);
+        assert_eq!(parse_range("items=0-1", 1000), None);
+        assert_eq!(parse_range("bytes=a-b", 1000), None);
+    }
 
-        assert_eq!(ct, "application/pdf");
-        assert_eq!(data, content);
+    #[test]
+    fn test_sliced_serves_the_requested_bytes() {
+        let sliced = |range| ServedAsset::sliced("image/png".into(), b"0123456789".to_vec(), range);
+
+        let whole = sliced(None);
+        assert_eq!((whole.range, whole.data.len()), (None, 10));
+        let part = sliced(Some("bytes=2-4"));
+        assert_eq!(
+            part.range,
+            Some(ByteRange::Satisfiable { start: 2, end: 4 })
+        );
+        assert_eq!(part.data, b"234");
+        assert_eq!(part.size_bytes, 10);
+        let past_end = sliced(Some("bytes=10-"));
+        assert_eq!(past_end.range, Some(ByteRange::Unsatisfiable));
+        assert!(past_end.data.is_empty());
     }
 
     #[tokio::test]
     async fn test_serve_asset_not_found() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
-        let result = process_serve_asset(&repo, &storage, "nonexistent.txt").await;
+        let result = process_serve_asset(&repo, &storage, "nonexistent.txt", None).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -855,7 +1137,7 @@ mod tests {
     #[tokio::test]
     async fn test_list_assets_all() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         for name in &["a/file1.txt", "b/file2.txt", "c/file3.txt"] {
             process_upload_asset(
@@ -869,6 +1151,7 @@ mod tests {
                 Some("valid-token"),
                 "valid-token",
                 DEFAULT_MAX_ATTACHMENT_SIZE,
+                None,
             )
             .await
             .unwrap();
@@ -881,7 +1164,7 @@ mod tests {
     #[tokio::test]
     async fn test_list_assets_with_prefix() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         for name in &[
             "project-a/config.yaml",
@@ -899,6 +1182,7 @@ mod tests {
                 Some("valid-token"),
                 "valid-token",
                 DEFAULT_MAX_ATTACHMENT_SIZE,
+                None,
             )
             .await
             .unwrap();
@@ -913,7 +1197,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_asset_success() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         process_upload_asset(
             &repo,
@@ -926,6 +1210,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await
         .unwrap();
@@ -957,7 +1242,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_asset_not_found() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         let result = process_delete_asset(
             &repo,
@@ -979,7 +1264,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_asset_invalid_token() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         process_upload_asset(
             &repo,
@@ -992,6 +1277,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await
         .unwrap();
@@ -1016,7 +1302,7 @@ mod tests {
     #[tokio::test]
     async fn test_editor_upload_success() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         let result = process_editor_upload(
             &repo,
@@ -1024,6 +1310,8 @@ mod tests {
             "test image.png",
             "image/png",
             vec![0x89, 0x50, 0x4E, 0x47],
+            "alice@example.com",
+            None,
         )
         .await;
 
@@ -1037,7 +1325,7 @@ mod tests {
 
         // Verify asset was stored in repo
         let asset = repo.find_by_key(&response.key).await.unwrap().unwrap();
-        assert_eq!(asset.uploaded_by, "web-editor");
+        assert_eq!(asset.uploaded_by, "alice@example.com");
         assert_eq!(asset.content_type, "image/png");
 
         // Verify in storage
@@ -1048,7 +1336,7 @@ mod tests {
     #[tokio::test]
     async fn test_editor_upload_sanitizes_filename() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         let result = process_editor_upload(
             &repo,
@@ -1056,6 +1344,8 @@ mod tests {
             "my file (1).png",
             "image/png",
             vec![1, 2, 3],
+            "alice@example.com",
+            None,
         )
         .await
         .unwrap();
@@ -1067,7 +1357,7 @@ mod tests {
     #[tokio::test]
     async fn test_check_hashes_identifies_missing_and_changed() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         // Upload an asset so it exists with a known hash
         process_upload_asset(
@@ -1081,6 +1371,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await
         .unwrap();
@@ -1153,7 +1444,7 @@ mod tests {
     #[tokio::test]
     async fn test_check_hashes_changed_content() {
         let repo = MockAssetRepo::new();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
 
         process_upload_asset(
             &repo,
@@ -1166,6 +1457,7 @@ mod tests {
             Some("valid-token"),
             "valid-token",
             DEFAULT_MAX_ATTACHMENT_SIZE,
+            None,
         )
         .await
         .unwrap();
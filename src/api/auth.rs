@@ -39,7 +39,13 @@ pub struct RefreshResponse {
     pub user: AuthenticatedUser,
 }
 
-// ── Callback query params ─────────────────────────────────────────────────────
+// ── Login / callback query params ─────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct LoginParams {
+    /// Page to return to after login; ignored unless it is a local path.
+    pub next: Option<String>,
+}
 
 #[derive(Debug, Deserialize)]
 pub struct CallbackParams {
@@ -127,12 +133,14 @@ pub async fn issue_token_pair(
 
 // ── Handlers ──────────────────────────────────────────────────────────────────
 
-/// `GET /auth/login` — Redirect the browser to the identity provider.
+/// `GET /auth/login?next=<path>` — Redirect the browser to the identity provider.
 ///
 /// Sets a short-lived `lekton_auth_state` cookie with the CSRF token (and
-/// OIDC nonce when applicable) for verification in `/auth/callback`.
+/// OIDC nonce when applicable) for verification in `/auth/callback`, plus the
+/// page to return to afterwards.
 pub async fn login_handler(
     axum::extract::State(state): axum::extract::State<AppState>,
+    axum::extract::Query(params): axum::extract::Query<LoginParams>,
     jar: CookieJar,
 ) -> Result<(CookieJar, axum::response::Redirect), AppError> {
     let provider = state
@@ -140,7 +148,11 @@ pub async fn login_handler(
         .as_ref()
         .ok_or_else(|| AppError::Auth("Auth provider not configured".into()))?;
 
-    let (url, flow_state) = provider.login_url()?;
+    let (url, mut flow_state) = provider.login_url()?;
+    flow_state.next = params
+        .next
+        .as_deref()
+        .and_then(crate::auth::redirect::safe_next);
 
     let state_json = serde_json::to_string(&flow_state)
         .map_err(|e| AppError::Internal(format!("Failed to serialize auth state: {e}")))?;
@@ -153,7 +165,8 @@ pub async fn login_handler(
 /// `GET /auth/callback?code=...&state=...` — Exchange the authorization code.
 ///
 /// Verifies the CSRF state, exchanges the code for user identity, upserts the
-/// user record, issues JWT + refresh token, and redirects to the portal root.
+/// user record, issues JWT + refresh token, and redirects to the page passed
+/// to `/auth/login` (the portal root by default).
 pub async fn callback_handler(
     axum::extract::State(app_state): axum::extract::State<AppState>,
     axum::extract::Query(params): axum::extract::Query<CallbackParams>,
@@ -207,7 +220,12 @@ pub async fn callback_handler(
         .add(refresh_token_cookie(refresh_token, ttl_days, secure))
         .add(logged_in_cookie(ttl_days, secure));
 
-    Ok((jar, axum::response::Redirect::temporary("/")))
+    let next = flow_state
+        .next
+        .as_deref()
+        .and_then(crate::auth::redirect::safe_next)
+        .unwrap_or_else(|| "/".to_string());
+    Ok((jar, axum::response::Redirect::temporary(&next)))
 }
 
 /// `POST /auth/refresh` — Rotate the refresh token and issue a new JWT.
@@ -226,10 +244,7 @@ pub async fn refresh_handler(
 
     match refresh_handler_inner(&app_state, jar).await {
         Ok(resp) => resp.into_response(),
-        Err((jar, err)) => {
-            let body = serde_json::json!({ "error": err.to_string() });
-            (StatusCode::UNAUTHORIZED, jar, axum::Json(body)).into_response()
-        }
+        Err((jar, err)) => (jar, AppError::Auth(err.to_string())).into_response(),
     }
 }
 
@@ -0,0 +1,579 @@
+//! Backstage `catalog-info.yaml` importer.
+//!
+//! `POST /api/v1/admin/import/backstage` (admin only) accepts catalog YAML
+//! inline and/or a list of URLs pointing at catalog files. Each entity is
+//! mapped onto Lekton resources:
+//!
+//! | Backstage kind | Lekton resource                                            |
+//! |----------------|------------------------------------------------------------|
+//! | `Component`    | Service landing document at `{slug_prefix}/{name}`         |
+//! | `System`       | Service landing document at `{slug_prefix}/{name}`         |
+//! | `API`          | Schema registry entry from `spec.definition`               |
+//!
+//! `spec.definition` may be inline or a `$text` reference (absolute, or
+//! relative to the catalog URL it was loaded from). Entities of other kinds,
+//! and APIs of types Lekton cannot render (e.g. `grpc`, `graphql`), are
+//! reported as skipped.
+
+use axum::extract::State;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::auth::extractor::RequiredAuthUser;
+use crate::db::models::IngestRequest;
+use crate::error::AppError;
+
+/// Request payload for `POST /api/v1/admin/import/backstage`.
+#[derive(Debug, Deserialize)]
+pub struct BackstageImportRequest {
+    /// Raw `catalog-info.yaml` content (may contain multiple `---` documents).
+    #[serde(default)]
+    pub content: Option<String>,
+    /// URLs of catalog files to fetch and import.
+    #[serde(default)]
+    pub urls: Vec<String>,
+    /// Slug prefix for generated landing documents.
+    #[serde(default = "default_slug_prefix")]
+    pub slug_prefix: String,
+    /// Access level for generated documents and schema versions.
+    #[serde(default = "default_access_level")]
+    pub access_level: String,
+}
+
+fn default_slug_prefix() -> String {
+    "services".to_string()
+}
+
+fn default_access_level() -> String {
+    "public".to_string()
+}
+
+/// An entity that could not be imported.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SkippedEntity {
+    /// `kind:name` reference, or the catalog URL for fetch/parse failures.
+    pub entity: String,
+    pub reason: String,
+}
+
+/// Response from a Backstage import.
+#[derive(Debug, Default, Serialize)]
+pub struct BackstageImportResponse {
+    /// Slugs of landing documents created or updated.
+    pub documents: Vec<String>,
+    /// `name@version` of schema versions created or updated.
+    pub schemas: Vec<String>,
+    pub skipped: Vec<SkippedEntity>,
+}
+
+/// A Backstage catalog entity (only the fields Lekton uses).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogEntity {
+    pub kind: String,
+    pub metadata: EntityMetadata,
+    #[serde(default)]
+    pub spec: serde_yaml::Value,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EntityMetadata {
+    pub name: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub links: Vec<EntityLink>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EntityLink {
+    pub url: String,
+    #[serde(default)]
+    pub title: Option<String>,
+}
+
+/// Where an API definition comes from.
+#[derive(Debug, PartialEq)]
+pub enum DefinitionSource {
+    Inline(String),
+    Url(String),
+}
+
+/// Parse a (possibly multi-document) catalog file into entities.
+///
+/// Documents that are empty or not valid entities are reported as skipped.
+pub fn parse_catalog(source: &str, origin: &str) -> (Vec<CatalogEntity>, Vec<SkippedEntity>) {
+    use serde::de::Deserialize as _;
+
+    let mut entities = Vec::new();
+    let mut skipped = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(source) {
+        let value = match serde_yaml::Value::deserialize(document) {
+            Ok(serde_yaml::Value::Null) => continue,
+            Ok(value) => value,
+            Err(e) => {
+                skipped.push(SkippedEntity {
+                    entity: origin.to_string(),
+                    reason: format!("Invalid YAML: {e}"),
+                });
+                break;
+            }
+        };
+        match serde_yaml::from_value::<CatalogEntity>(value) {
+            Ok(entity) => entities.push(entity),
+            Err(e) => skipped.push(SkippedEntity {
+                entity: origin.to_string(),
+                reason: format!("Not a catalog entity: {e}"),
+            }),
+        }
+    }
+    (entities, skipped)
+}
+
+fn spec_str<'a>(entity: &'a CatalogEntity, key: &str) -> Option<&'a str> {
+    entity.spec.get(key).and_then(|v| v.as_str())
+}
+
+/// Strip the Backstage entity-ref kind prefix (`group:default/payments` → `payments`).
+fn ref_name(entity_ref: &str) -> &str {
+    let without_kind = entity_ref.split_once(':').map_or(entity_ref, |(_, r)| r);
+    without_kind
+        .rsplit_once('/')
+        .map_or(without_kind, |(_, name)| name)
+}
+
+/// Map a Backstage API `spec.type` onto a Lekton schema type.
+pub fn schema_type_for(api_type: &str) -> Option<&'static str> {
+    match api_type.to_lowercase().as_str() {
+        "openapi" => Some("openapi"),
+        "asyncapi" => Some("asyncapi"),
+        "json-schema" | "jsonschema" => Some("jsonschema"),
+        _ => None,
+    }
+}
+
+/// Resolve the `spec.definition` of an API entity.
+pub fn definition_source(
+    entity: &CatalogEntity,
+    base_url: Option<&str>,
+) -> Option<DefinitionSource> {
+    let definition = entity.spec.get("definition")?;
+    if let Some(text) = definition.as_str() {
+        return Some(DefinitionSource::Inline(text.to_string()));
+    }
+    let target = definition.get("$text")?.as_str()?;
+    if target.starts_with("http://") || target.starts_with("https://") {
+        return Some(DefinitionSource::Url(target.to_string()));
+    }
+    let base = url::Url::parse(base_url?).ok()?;
+    base.join(target)
+        .ok()
+        .map(|u| DefinitionSource::Url(u.to_string()))
+}
+
+/// Read `info.version` from an OpenAPI/AsyncAPI definition, defaulting to `1.0.0`.
+pub fn definition_version(content: &str) -> String {
+    serde_yaml::from_str::<serde_yaml::Value>(content)
+        .ok()
+        .and_then(|v| {
+            let version = v.get("info")?.get("version")?;
+            version
+                .as_str()
+                .map(str::to_string)
+                .or_else(|| version.as_f64().map(|f| f.to_string()))
+        })
+        .unwrap_or_else(|| "1.0.0".to_string())
+}
+
+/// Render the markdown body of a service landing document.
+pub fn render_landing_page(entity: &CatalogEntity, provided_apis: &[String]) -> String {
+    let mut md = String::new();
+    if let Some(ref description) = entity.metadata.description {
+        md.push_str(description.trim());
+        md.push_str("\n\n");
+    }
+
+    md.push_str("| | |\n|---|---|\n");
+    md.push_str(&format!("| **Kind** | {} |\n", entity.kind));
+    for (label, key) in [
+        ("Owner", "owner"),
+        ("Type", "type"),
+        ("Lifecycle", "lifecycle"),
+        ("System", "system"),
+    ] {
+        if let Some(value) = spec_str(entity, key) {
+            md.push_str(&format!("| **{label}** | {} |\n", ref_name(value)));
+        }
+    }
+
+    if !provided_apis.is_empty() {
+        md.push_str("\n## APIs\n\n");
+        for api in provided_apis {
+            md.push_str(&format!("- [{api}](/schemas/{api})\n"));
+        }
+    }
+
+    if !entity.metadata.links.is_empty() {
+        md.push_str("\n## Links\n\n");
+        for link in &entity.metadata.links {
+            let title = link.title.as_deref().unwrap_or(&link.url);
+            md.push_str(&format!("- [{title}]({})\n", link.url));
+        }
+    }
+
+    md
+}
+
+fn provided_apis(entity: &CatalogEntity) -> Vec<String> {
+    entity
+        .spec
+        .get("providesApis")
+        .and_then(|v| v.as_sequence())
+        .map(|seq| {
+            seq.iter()
+                .filter_map(|v| v.as_str())
+                .map(|r| ref_name(r).to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn entity_ref(entity: &CatalogEntity) -> String {
+    format!("{}:{}", entity.kind.to_lowercase(), entity.metadata.name)
+}
+
+async fn fetch_text(client: &reqwest::Client, url: &str) -> Result<String, AppError> {
+    let response = client
+        .get(url)
+        .header(reqwest::header::USER_AGENT, "lekton")
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(response.text().await?)
+}
+
+/// Import one catalog's entities. Failures are collected per entity.
+async fn import_entities(
+    state: &AppState,
+    client: &reqwest::Client,
+    request: &BackstageImportRequest,
+    entities: Vec<CatalogEntity>,
+    base_url: Option<&str>,
+    response: &mut BackstageImportResponse,
+) {
+    use crate::api::ingest::{process_ingest, IngestContext};
+    use crate::api::schemas::{process_schema_ingest, IngestSchemaRequest, SchemaIngestContext};
+
+    let ingest_ctx = IngestContext {
+        repo: state.document_repo.as_ref(),
+        storage: state.storage_client.as_ref(),
+        search: state.search_service.as_deref(),
+        access_level_repo: state.access_level_repo.as_ref(),
+        project_repo: Some(state.project_repo.as_ref()),
+        service_token_repo: state.service_token_repo.as_ref(),
+        version_repo: state.document_version_repo.as_ref(),
+        rag: state.rag_service.as_deref(),
+        events: Some(&state.activity_events),
+        legacy_token: Some(&state.service_token),
+        dry_run: false,
+        approval: None,
+        quotas: Some(&state.quotas),
+        taxonomy: Some(&state.taxonomy),
+        lint: Some(&state.lint),
+        spell_checker: state.spell_checker.as_deref(),
+        secrets: state.secret_guard.as_deref(),
+        mentions: state.mentions.as_deref(),
+    };
+    let schema_ctx = SchemaIngestContext {
+        schema_repo: state.schema_repo.as_ref(),
+        storage: state.storage_client.as_ref(),
+        access_level_repo: state.access_level_repo.as_ref(),
+        project_repo: Some(state.project_repo.as_ref()),
+        service_token_repo: state.service_token_repo.as_ref(),
+        events: Some(&state.activity_events),
+        legacy_token: Some(&state.service_token),
+        dry_run: false,
+    };
+
+    for entity in entities {
+        let reference = entity_ref(&entity);
+        let owner = spec_str(&entity, "owner")
+            .map(ref_name)
+            .unwrap_or_default()
+            .to_string();
+        let skip = |response: &mut BackstageImportResponse, reason: String| {
+            response.skipped.push(SkippedEntity {
+                entity: reference.clone(),
+                reason,
+            })
+        };
+
+        match entity.kind.as_str() {
+            "Component" | "System" => {
+                let slug = format!(
+                    "{}/{}",
+                    request.slug_prefix.trim_matches('/'),
+                    entity.metadata.name
+                );
+                let ingest = IngestRequest {
+                    service_token: state.service_token.clone(),
+                    slug: slug.trim_start_matches('/').to_string(),
+                    title: entity
+                        .metadata
+                        .title
+                        .clone()
+                        .unwrap_or_else(|| entity.metadata.name.clone()),
+                    summary: entity.metadata.description.clone(),
+                    content: render_landing_page(&entity, &provided_apis(&entity)),
+                    access_level: request.access_level.clone(),
+                    is_draft: false,
+                    service_owner: owner,
+                    tags: entity.metadata.tags.clone(),
+                    parent_slug: None,
+                    order: 0,
+                    is_hidden: false,
+                    noindex: false,
+                    edit_access_level: None,
+                    relations: Default::default(),
+                    source_path: format!("backstage/{reference}"),
+                    expected_last_updated: None,
+                    expected_content_hash: None,
+                };
+                match process_ingest(&ingest_ctx, ingest).await {
+                    Ok(resp) => response.documents.push(resp.slug),
+                    Err(e) => skip(response, e.to_string()),
+                }
+            }
+            "API" => {
+                let api_type = spec_str(&entity, "type").unwrap_or_default();
+                let Some(schema_type) = schema_type_for(api_type) else {
+                    skip(response, format!("Unsupported API type '{api_type}'"));
+                    continue;
+                };
+                let content = match definition_source(&entity, base_url) {
+                    Some(DefinitionSource::Inline(text)) => text,
+                    Some(DefinitionSource::Url(url)) => match fetch_text(client, &url).await {
+                        Ok(text) => text,
+                        Err(e) => {
+                            skip(
+                                response,
+                                format!("Failed to fetch definition from {url}: {e}"),
+                            );
+                            continue;
+                        }
+                    },
+                    None => {
+                        skip(response, "Missing or unresolvable spec.definition".into());
+                        continue;
+                    }
+                };
+                let schema_request = IngestSchemaRequest {
+                    service_token: state.service_token.clone(),
+                    name: entity.metadata.name.clone(),
+                    schema_type: schema_type.to_string(),
+                    version: definition_version(&content),
+                    status: match spec_str(&entity, "lifecycle") {
+                        Some("deprecated") => "deprecated".into(),
+                        Some("experimental") => "beta".into(),
+                        _ => "stable".into(),
+                    },
+                    access_level: request.access_level.clone(),
+                    owner_access_level: None,
+                    service_owner: owner,
+                    tags: entity.metadata.tags.clone(),
+                    description: entity.metadata.description.clone(),
+                    contact: None,
+                    repository_url: None,
+                    content,
+                };
+                match process_schema_ingest(&schema_ctx, schema_request).await {
+                    Ok(resp) => response
+                        .schemas
+                        .push(format!("{}@{}", resp.name, resp.version)),
+                    Err(e) => skip(response, e.to_string()),
+                }
+            }
+            other => skip(response, format!("Unsupported kind '{other}'")),
+        }
+    }
+}
+
+/// `POST /api/v1/admin/import/backstage`
+pub async fn backstage_import_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Json(request): Json<BackstageImportRequest>,
+) -> Result<Json<BackstageImportResponse>, AppError> {
+    if !user.is_admin {
+        return Err(AppError::Forbidden("Admin privileges required".into()));
+    }
+    if request.content.as_deref().is_none_or(str::is_empty) && request.urls.is_empty() {
+        return Err(AppError::BadRequest(
+            "Provide catalog `content` or at least one URL".into(),
+        ));
+    }
+
+    let client = reqwest::Client::new();
+    let mut response = BackstageImportResponse::default();
+
+    if let Some(ref content) = request.content {
+        let (entities, skipped) = parse_catalog(content, "upload");
+        response.skipped.extend(skipped);
+        import_entities(&state, &client, &request, entities, None, &mut response).await;
+    }
+
+    for url in &request.urls {
+        let source = match fetch_text(&client, url).await {
+            Ok(source) => source,
+            Err(e) => {
+                response.skipped.push(SkippedEntity {
+                    entity: url.clone(),
+                    reason: format!("Failed to fetch catalog: {e}"),
+                });
+                continue;
+            }
+        };
+        let (entities, skipped) = parse_catalog(&source, url);
+        response.skipped.extend(skipped);
+        import_entities(
+            &state,
+            &client,
+            &request,
+            entities,
+            Some(url),
+            &mut response,
+        )
+        .await;
+    }
+
+    tracing::info!(
+        user = %user.email,
+        documents = response.documents.len(),
+        schemas = response.schemas.len(),
+        skipped = response.skipped.len(),
+        "Backstage catalog import finished"
+    );
+
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATALOG: &str = r#"
+apiVersion: backstage.io/v1alpha1
+kind: Component
+metadata:
+  name: payments
+  title: Payments Service
+  description: Handles card payments.
+  tags: [payments, java]
+  links:
+    - url: https://grafana.example.com/d/payments
+      title: Dashboard
+spec:
+  type: service
+  lifecycle: production
+  owner: group:default/payments-team
+  providesApis: [payments-api]
+---
+apiVersion: backstage.io/v1alpha1
+kind: API
+metadata:
+  name: payments-api
+spec:
+  type: openapi
+  lifecycle: production
+  owner: payments-team
+  definition:
+    $text: ./openapi.yaml
+---
+apiVersion: backstage.io/v1alpha1
+kind: Group
+metadata:
+  name: payments-team
+spec:
+  type: team
+"#;
+
+    #[test]
+    fn parse_catalog_reads_all_documents() {
+        let (entities, skipped) = parse_catalog(CATALOG, "upload");
+        assert!(skipped.is_empty());
+        let kinds: Vec<_> = entities.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["Component", "API", "Group"]);
+    }
+
+    #[test]
+    fn parse_catalog_reports_non_entities() {
+        let (entities, skipped) = parse_catalog("foo: bar\n", "upload");
+        assert!(entities.is_empty());
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].entity, "upload");
+    }
+
+    #[test]
+    fn ref_name_strips_kind_and_namespace() {
+        assert_eq!(ref_name("group:default/payments-team"), "payments-team");
+        assert_eq!(ref_name("user:jane"), "jane");
+        assert_eq!(ref_name("payments-team"), "payments-team");
+    }
+
+    #[test]
+    fn schema_type_mapping() {
+        assert_eq!(schema_type_for("openapi"), Some("openapi"));
+        assert_eq!(schema_type_for("AsyncAPI"), Some("asyncapi"));
+        assert_eq!(schema_type_for("json-schema"), Some("jsonschema"));
+        assert_eq!(schema_type_for("grpc"), None);
+    }
+
+    #[test]
+    fn relative_definition_resolves_against_catalog_url() {
+        let (entities, _) = parse_catalog(CATALOG, "upload");
+        let api = &entities[1];
+        assert_eq!(
+            definition_source(
+                api,
+                Some("https://raw.example.com/acme/payments/main/catalog-info.yaml")
+            ),
+            Some(DefinitionSource::Url(
+                "https://raw.example.com/acme/payments/main/openapi.yaml".into()
+            ))
+        );
+        // Relative references cannot be resolved for inline uploads.
+        assert_eq!(definition_source(api, None), None);
+    }
+
+    #[test]
+    fn inline_definition_is_used_verbatim() {
+        let yaml = "kind: API\nmetadata:\n  name: a\nspec:\n  type: openapi\n  definition: |\n    openapi: 3.0.0\n    info:\n      version: 2.1.0\n";
+        let (entities, _) = parse_catalog(yaml, "upload");
+        let Some(DefinitionSource::Inline(text)) = definition_source(&entities[0], None) else {
+            panic!("expected inline definition");
+        };
+        assert_eq!(definition_version(&text), "2.1.0");
+    }
+
+    #[test]
+    fn definition_version_defaults() {
+        assert_eq!(definition_version("openapi: 3.0.0\n"), "1.0.0");
+    }
+
+    #[test]
+    fn landing_page_lists_owner_apis_and_links() {
+        let (entities, _) = parse_catalog(CATALOG, "upload");
+        let component = &entities[0];
+        let md = render_landing_page(component, &provided_apis(component));
+        assert!(md.starts_with("Handles card payments."));
+        assert!(md.contains("| **Owner** | payments-team |"));
+        assert!(md.contains("| **Lifecycle** | production |"));
+        assert!(md.contains("- [payments-api](/schemas/payments-api)"));
+        assert!(md.contains("- [Dashboard](https://grafana.example.com/d/payments)"));
+    }
+}
@@ -0,0 +1,390 @@
+//! Read-only API for chatbots, MCP servers and dashboards.
+//!
+//! Authenticated with a read-only API key (`Authorization: Bearer <token>`),
+//! stored as a service token with `token_type = "bot"` and created in
+//! Admin → API keys or via `POST /api/v1/admin/service-tokens` with
+//! `"token_type": "bot"`. A key only sees published documents in its access
+//! levels (and their inherited levels); when it has `allowed_scopes`, only
+//! documents within those scopes. The same keys are accepted by
+//! `GET /api/v1/search`.
+//!
+//! | Method | Path                         | Description                               |
+//! |--------|------------------------------|-------------------------------------------|
+//! | GET    | `/api/v1/bot/search`         | Search documents visible to the token     |
+//! | GET    | `/api/v1/bot/docs/{*slug}`   | Fetch a document as markdown or HTML      |
+//! | GET    | `/api/v1/bot/relations/{*slug}` | Typed relations of a document          |
+
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::db::models::Document;
+use crate::db::service_token_models::ServiceToken;
+use crate::error::AppError;
+use crate::relations::DocRelations;
+use crate::search::client::{SearchFilter, SearchHit};
+
+/// Maximum number of search results returned to a bot.
+const MAX_RESULTS: usize = 20;
+
+// ── Request / response types ─────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct BotSearchQuery {
+    pub q: String,
+    /// Restrict results to one project namespace.
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_limit() -> usize {
+    10
+}
+
+#[derive(Debug, Serialize)]
+pub struct BotSearchHit {
+    pub slug: String,
+    pub title: String,
+    pub content_preview: String,
+    /// Path of the document page (e.g. `/docs/security/signing-keys`).
+    pub url: String,
+}
+
+/// Content representation returned by the fetch endpoint.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentFormat {
+    /// The raw markdown source.
+    #[default]
+    Markdown,
+    /// Rendered, sanitized HTML.
+    Html,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BotDocumentQuery {
+    #[serde(default)]
+    pub format: ContentFormat,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BotDocument {
+    pub slug: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub access_level: String,
+    pub tags: Vec<String>,
+    pub last_updated: DateTime<Utc>,
+    pub url: String,
+    pub format: ContentFormat,
+    pub content: String,
+}
+
+// ── Authentication ───────────────────────────────────────────────────────────
+
+/// A validated bot token together with the access levels it can read.
+struct BotAccess {
+    token: ServiceToken,
+    levels: Vec<String>,
+}
+
+impl BotAccess {
+    fn can_read_slug(&self, slug: &str) -> bool {
+        self.token.allowed_scopes.is_empty() || self.token.matches_slug(slug)
+    }
+
+    fn can_read(&self, doc: &Document) -> bool {
+        !doc.is_draft
+            && !doc.is_archived
+            && self.levels.contains(&doc.access_level)
+            && self.can_read_slug(&doc.slug)
+    }
+}
+
+async fn authenticate_bot(state: &AppState, headers: &HeaderMap) -> Result<BotAccess, AppError> {
+    let raw_token = crate::mcp::auth::extract_bearer(headers)
+        .ok_or_else(|| AppError::Auth("Missing bearer token".into()))?;
+
+    let token_hash = crate::auth::token_service::TokenService::hash_token(raw_token);
+    let token = state
+        .service_token_repo
+        .find_by_hash(&token_hash)
+        .await?
+        .ok_or_else(|| AppError::Auth("Invalid bot token".into()))?;
+
+    if !token.is_active {
+        return Err(AppError::Auth("Bot token is deactivated".into()));
+    }
+    if !token.is_bot() {
+        return Err(AppError::Forbidden("Token is not a bot token".into()));
+    }
+
+    if let Err(err) = state.service_token_repo.touch_last_used(&token.id).await {
+        tracing::warn!(
+            "Failed to update last_used_at for token {}: {err}",
+            token.id
+        );
+    }
+
+    let levels = state
+        .access_level_repo
+        .compute_effective_levels(&token.access_levels)
+        .await?;
+    Ok(BotAccess { token, levels })
+}
+
+/// Whether `headers` carry a read-only API key as bearer (rather than a
+/// personal access token or nothing).
+pub(crate) async fn is_bot_key(state: &AppState, headers: &HeaderMap) -> Result<bool, AppError> {
+    let Some(raw_token) = crate::mcp::auth::extract_bearer(headers) else {
+        return Ok(false);
+    };
+    let token_hash = crate::auth::token_service::TokenService::hash_token(raw_token);
+    Ok(state
+        .service_token_repo
+        .find_by_hash(&token_hash)
+        .await?
+        .is_some_and(|token| token.is_bot()))
+}
+
+/// The document at `slug` if the read-only API key in `headers` may read it.
+pub(crate) async fn readable_document_with_key(
+    state: &AppState,
+    headers: &HeaderMap,
+    slug: &str,
+) -> Result<Option<Document>, AppError> {
+    let access = authenticate_bot(state, headers).await?;
+    Ok(state
+        .document_repo
+        .find_by_slug(slug)
+        .await?
+        .filter(|doc| access.can_read(doc)))
+}
+
+fn document_url(slug: &str) -> String {
+    format!("/docs/{slug}")
+}
+
+/// Search as the read-only API key in `headers`: results are limited to the
+/// key's access levels and scopes.
+pub(crate) async fn search_with_key(
+    state: &AppState,
+    headers: &HeaderMap,
+    query: &str,
+    filter: &SearchFilter,
+) -> Result<Vec<SearchHit>, AppError> {
+    let access = authenticate_bot(state, headers).await?;
+    let search_service = state
+        .search_service
+        .as_ref()
+        .ok_or_else(|| AppError::Internal("Search service not available".into()))?;
+
+    let hits = crate::features::search(
+        state,
+        search_service.as_ref(),
+        query,
+        Some(access.levels.as_slice()),
+        false,
+        filter,
+    )
+    .await?;
+    Ok(hits
+        .into_iter()
+        .filter(|hit| access.can_read_slug(&hit.slug))
+        .collect())
+}
+
+/// Normalize and validate the access levels of a new read-only API key.
+pub async fn resolve_key_access_levels(
+    access_level_repo: &dyn crate::db::access_level_repository::AccessLevelRepository,
+    requested: &[String],
+) -> Result<Vec<String>, AppError> {
+    let mut levels = Vec::new();
+    for level in requested {
+        let level = level.trim().to_lowercase();
+        if level.is_empty() {
+            continue;
+        }
+        if !access_level_repo.exists(&level).await? {
+            return Err(AppError::BadRequest(format!(
+                "Access level '{level}' does not exist"
+            )));
+        }
+        if !levels.contains(&level) {
+            levels.push(level);
+        }
+    }
+    if levels.is_empty() {
+        return Err(AppError::BadRequest(
+            "Bot tokens require at least one access level".into(),
+        ));
+    }
+    Ok(levels)
+}
+
+// ── Handlers ─────────────────────────────────────────────────────────────────
+
+/// `GET /api/v1/bot/search?q=<query>&project=<project>&limit=<n>`
+pub async fn search_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<BotSearchQuery>,
+) -> Result<Json<Vec<BotSearchHit>>, AppError> {
+    let filter = SearchFilter::project(params.project.as_deref());
+    let hits = search_with_key(&state, &headers, &params.q, &filter).await?;
+
+    let results = hits
+        .into_iter()
+        .take(params.limit.clamp(1, MAX_RESULTS))
+        .map(|hit| BotSearchHit {
+            url: document_url(&hit.slug),
+            slug: hit.slug,
+            title: hit.title,
+            content_preview: hit.content_preview,
+        })
+        .collect();
+
+    Ok(Json(results))
+}
+
+/// `GET /api/v1/bot/docs/{*slug}?format=markdown|html`
+///
+/// Documents the token cannot read are reported as not found.
+pub async fn get_document_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+    Query(params): Query<BotDocumentQuery>,
+) -> Result<Json<BotDocument>, AppError> {
+    let access = authenticate_bot(&state, &headers).await?;
+    let not_found = || AppError::NotFound(format!("Document '{slug}' not found"));
+
+    let doc = state
+        .document_repo
+        .find_by_slug(&slug)
+        .await?
+        .filter(|doc| access.can_read(doc))
+        .ok_or_else(not_found)?;
+
+    let bytes = state
+        .storage_client
+        .get_object(&doc.s3_key)
+        .await?
+        .ok_or_else(not_found)?;
+    let markdown = String::from_utf8(bytes)
+        .map_err(|e| AppError::Internal(format!("Invalid UTF-8 content: {e}")))?;
+
+    let content = match params.format {
+        ContentFormat::Markdown => markdown,
+        ContentFormat::Html => {
+            let embeds = crate::server::embeds::embeds(&state).await;
+            state.render_pipeline.render(
+                &markdown,
+                &crate::rendering::pipeline::RenderContext {
+                    embeds: embeds.as_ref(),
+                    ..Default::default()
+                },
+            )
+        }
+    };
+
+    Ok(Json(BotDocument {
+        url: document_url(&doc.slug),
+        slug: doc.slug,
+        title: doc.title,
+        summary: doc.summary,
+        access_level: doc.access_level,
+        tags: doc.tags,
+        last_updated: doc.last_updated,
+        format: params.format,
+        content,
+    }))
+}
+
+/// `GET /api/v1/bot/relations/{*slug}`
+///
+/// Relations in both directions, limited to documents the token can read.
+/// Documents the token cannot read are reported as not found.
+pub async fn get_relations_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+) -> Result<Json<DocRelations>, AppError> {
+    let access = authenticate_bot(&state, &headers).await?;
+
+    let doc = state
+        .document_repo
+        .find_by_slug(&slug)
+        .await?
+        .filter(|doc| access.can_read(doc))
+        .ok_or_else(|| AppError::NotFound(format!("Document '{slug}' not found")))?;
+
+    let relations =
+        crate::relations::resolve_relations(state.document_repo.as_ref(), &doc, |other| {
+            access.can_read(other)
+        })
+        .await?;
+    Ok(Json(relations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    fn bot(scopes: Vec<&str>, levels: Vec<&str>) -> BotAccess {
+        BotAccess {
+            token: ServiceToken {
+                id: "bot-1".to_string(),
+                name: "slack-bot".to_string(),
+                token_hash: String::new(),
+                allowed_scopes: scopes.into_iter().map(String::from).collect(),
+                token_type: "bot".to_string(),
+                user_id: None,
+                can_write: false,
+                created_by: "admin".to_string(),
+                created_at: Utc::now(),
+                last_used_at: None,
+                is_active: true,
+                project: None,
+                access_levels: vec![],
+            },
+            levels: levels.into_iter().map(String::from).collect(),
+        }
+    }
+
+    fn doc(slug: &str, access_level: &str) -> Document {
+        Document {
+            access_level: access_level.to_string(),
+            ..testing::document(slug)
+        }
+    }
+
+    #[test]
+    fn bot_reads_only_published_documents_in_its_levels() {
+        let access = bot(vec![], vec!["public", "internal"]);
+        assert!(access.can_read(&doc("security/signing-keys", "internal")));
+        assert!(!access.can_read(&doc("security/incident", "restricted")));
+
+        let mut draft = doc("security/draft", "public");
+        draft.is_draft = true;
+        assert!(!access.can_read(&draft));
+
+        let mut archived = doc("security/old", "public");
+        archived.is_archived = true;
+        assert!(!access.can_read(&archived));
+    }
+
+    #[test]
+    fn bot_scopes_narrow_readable_documents() {
+        let access = bot(vec!["security/*"], vec!["public"]);
+        assert!(access.can_read(&doc("security/signing-keys", "public")));
+        assert!(!access.can_read(&doc("payments/retries", "public")));
+        assert!(access.can_read_slug("security"));
+    }
+}
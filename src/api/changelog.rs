@@ -0,0 +1,220 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::db::access_level_repository::AccessLevelRepository;
+#[cfg(feature = "ssr")]
+use crate::db::changelog_repository::{ChangelogEntry, ChangelogRepository};
+#[cfg(feature = "ssr")]
+use crate::db::service_token_repository::ServiceTokenRepository;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+
+/// Maximum size of the markdown notes of a single entry.
+#[cfg(feature = "ssr")]
+const MAX_NOTES_BYTES: usize = 64 * 1024;
+
+/// Request payload for `POST /api/v1/changelog`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogRequest {
+    pub service_token: String,
+    pub service: String,
+    pub version: String,
+    /// Release date (`YYYY-MM-DD`); defaults to today.
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    /// Markdown release notes.
+    pub notes: String,
+    #[serde(default = "default_public")]
+    pub access_level: String,
+}
+
+fn default_public() -> String {
+    "public".to_string()
+}
+
+/// Response from a successful changelog publication.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogResponse {
+    pub message: String,
+    pub service: String,
+    pub version: String,
+}
+
+/// Bundles the service references needed by [`process_changelog`].
+#[cfg(feature = "ssr")]
+pub struct ChangelogContext<'a> {
+    pub changelog_repo: &'a dyn ChangelogRepository,
+    pub access_level_repo: &'a dyn AccessLevelRepository,
+    pub service_token_repo: &'a dyn ServiceTokenRepository,
+    /// The legacy global token from the `SERVICE_TOKEN` env var (if set).
+    pub legacy_token: Option<&'a str>,
+}
+
+/// Service names appear in `/changelog/<service>` URLs: letters, digits and
+/// `-_./`, without empty or `..` segments.
+pub fn is_valid_service_name(service: &str) -> bool {
+    !service.is_empty()
+        && service
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./".contains(c))
+        && service
+            .split('/')
+            .all(|segment| !segment.is_empty() && segment != "." && segment != "..")
+}
+
+/// Validate the token for publishing to `service` and return its name.
+///
+/// Scoped tokens must have write permission and a scope matching the service
+/// name, as for schemas.
+#[cfg(feature = "ssr")]
+async fn authorize_publisher(
+    ctx: &ChangelogContext<'_>,
+    raw_token: &str,
+    service: &str,
+) -> Result<String, AppError> {
+    if let Some(legacy) = ctx.legacy_token {
+        if !legacy.is_empty() && raw_token == legacy {
+            return Ok("legacy".to_string());
+        }
+    }
+
+    let token_hash = crate::auth::token_service::TokenService::hash_token(raw_token);
+    let token = ctx
+        .service_token_repo
+        .find_by_hash(&token_hash)
+        .await?
+        .ok_or_else(|| AppError::Auth("Invalid service token".into()))?;
+
+    if !token.is_active {
+        return Err(AppError::Auth("Service token is deactivated".into()));
+    }
+    if !token.can_write {
+        return Err(AppError::Forbidden(
+            "Token does not have write permission".into(),
+        ));
+    }
+    if !token.matches_slug(service) {
+        return Err(AppError::Forbidden(
+            "Token does not have access to this service scope".into(),
+        ));
+    }
+
+    if let Err(err) = ctx.service_token_repo.touch_last_used(&token.id).await {
+        tracing::warn!(
+            "Failed to update last_used_at for token {}: {err}",
+            token.id
+        );
+    }
+
+    Ok(token.name)
+}
+
+/// Core changelog publication logic — separated from the HTTP layer for testability.
+#[cfg(feature = "ssr")]
+pub async fn process_changelog(
+    ctx: &ChangelogContext<'_>,
+    request: ChangelogRequest,
+) -> Result<ChangelogResponse, AppError> {
+    let service = request.service.trim().to_string();
+    let version = request.version.trim().to_string();
+
+    let published_by = authorize_publisher(ctx, &request.service_token, &service).await?;
+
+    if !is_valid_service_name(&service) {
+        return Err(AppError::BadRequest(format!(
+            "Invalid service name '{service}'. Use letters, digits and -_./"
+        )));
+    }
+    if version.is_empty() {
+        return Err(AppError::BadRequest("Version cannot be empty".into()));
+    }
+    if request.notes.trim().is_empty() {
+        return Err(AppError::BadRequest("Notes cannot be empty".into()));
+    }
+    if request.notes.len() > MAX_NOTES_BYTES {
+        return Err(AppError::BadRequest(format!(
+            "Notes exceed the maximum size of {} KB",
+            MAX_NOTES_BYTES / 1024
+        )));
+    }
+
+    let access_level = request.access_level.trim().to_lowercase();
+    if !ctx.access_level_repo.exists(&access_level).await? {
+        return Err(AppError::BadRequest(format!(
+            "Unknown access level: '{access_level}'"
+        )));
+    }
+
+    let now = chrono::Utc::now();
+    ctx.changelog_repo
+        .upsert(ChangelogEntry {
+            service: service.clone(),
+            version: version.clone(),
+            date: request.date.unwrap_or_else(|| now.date_naive()),
+            notes: request.notes,
+            access_level,
+            published_by,
+            created_at: now,
+        })
+        .await?;
+
+    Ok(ChangelogResponse {
+        message: "Changelog entry published".to_string(),
+        service,
+        version,
+    })
+}
+
+/// Axum handler for `POST /api/v1/changelog`.
+#[cfg(feature = "ssr")]
+pub async fn changelog_handler(
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    axum::Json(request): axum::Json<ChangelogRequest>,
+) -> Result<axum::Json<ChangelogResponse>, AppError> {
+    let response = process_changelog(
+        &ChangelogContext {
+            changelog_repo: state.changelog_repo.as_ref(),
+            access_level_repo: state.access_level_repo.as_ref(),
+            service_token_repo: state.service_token_repo.as_ref(),
+            legacy_token: Some(&state.service_token),
+        },
+        request,
+    )
+    .await?;
+
+    Ok(axum::Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_name_validation() {
+        assert!(is_valid_service_name("payments-api"));
+        assert!(is_valid_service_name("platform/auth_v2.1"));
+        assert!(!is_valid_service_name(""));
+        assert!(!is_valid_service_name("/payments"));
+        assert!(!is_valid_service_name("payments/"));
+        assert!(!is_valid_service_name("a/../b"));
+        assert!(!is_valid_service_name("payments api"));
+        assert!(!is_valid_service_name("payments?x=1"));
+    }
+
+    #[test]
+    fn test_request_defaults() {
+        let request: ChangelogRequest = serde_json::from_str(
+            r#"{"service_token":"t","service":"svc","version":"1.0.0","notes":"- Fixed"}"#,
+        )
+        .unwrap();
+        assert_eq!(request.access_level, "public");
+        assert!(request.date.is_none());
+
+        let request: ChangelogRequest = serde_json::from_str(
+            r#"{"service_token":"t","service":"svc","version":"1.0.0","notes":"x","date":"2026-05-04"}"#,
+        )
+        .unwrap();
+        assert_eq!(request.date, NaiveDate::from_ymd_opt(2026, 5, 4));
+    }
+}
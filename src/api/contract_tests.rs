@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use crate::db::contract_test_repository::ContractTestOutcome;
+#[cfg(feature = "ssr")]
+use crate::db::contract_test_repository::{ContractTestReport, ContractTestRepository};
+#[cfg(feature = "ssr")]
+use crate::db::schema_repository::SchemaRepository;
+#[cfg(feature = "ssr")]
+use crate::db::service_token_repository::ServiceTokenRepository;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+
+/// Request payload for `POST /api/v1/contract-tests`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractTestRequest {
+    pub service_token: String,
+    /// Name of the tested schema.
+    pub schema: String,
+    /// Tested schema version; it must already be registered.
+    pub version: String,
+    /// Service whose expectations were verified.
+    pub consumer: String,
+    /// Tool that produced the results (e.g. `pact`, `schemathesis`).
+    pub tool: String,
+    pub outcome: ContractTestOutcome,
+    #[serde(default)]
+    pub passed: Option<u32>,
+    #[serde(default)]
+    pub failed: Option<u32>,
+    /// Link to the full report; `http(s)` only.
+    #[serde(default)]
+    pub report_url: Option<String>,
+}
+
+/// Response from a successful contract-test report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractTestResponse {
+    pub message: String,
+    pub schema: String,
+    pub version: String,
+    pub consumer: String,
+}
+
+/// Bundles the service references needed by [`process_contract_test_report`].
+#[cfg(feature = "ssr")]
+pub struct ContractTestContext<'a> {
+    pub contract_test_repo: &'a dyn ContractTestRepository,
+    pub schema_repo: &'a dyn SchemaRepository,
+    pub service_token_repo: &'a dyn ServiceTokenRepository,
+    /// The legacy global token from the `SERVICE_TOKEN` env var (if set).
+    pub legacy_token: Option<&'a str>,
+}
+
+/// Validate the token for reporting on `schema` and return its name.
+///
+/// Scoped tokens must have write permission and a scope matching the schema
+/// name, as for schema ingestion: results are published by the provider's
+/// pipeline or by a consumer granted that scope.
+#[cfg(feature = "ssr")]
+async fn authorize_reporter(
+    ctx: &ContractTestContext<'_>,
+    raw_token: &str,
+    schema: &str,
+) -> Result<String, AppError> {
+    if let Some(legacy) = ctx.legacy_token {
+        if !legacy.is_empty() && raw_token == legacy {
+            return Ok("legacy".to_string());
+        }
+    }
+
+    let token_hash = crate::auth::token_service::TokenService::hash_token(raw_token);
+    let token = ctx
+        .service_token_repo
+        .find_by_hash(&token_hash)
+        .await?
+        .ok_or_else(|| AppError::Auth("Invalid service token".into()))?;
+
+    if !token.is_active {
+        return Err(AppError::Auth("Service token is deactivated".into()));
+    }
+    if !token.can_write {
+        return Err(AppError::Forbidden(
+            "Token does not have write permission".into(),
+        ));
+    }
+    if !token.matches_slug(schema) {
+        return Err(AppError::Forbidden(
+            "Token does not have access to this schema scope".into(),
+        ));
+    }
+
+    if let Err(err) = ctx.service_token_repo.touch_last_used(&token.id).await {
+        tracing::warn!(
+            "Failed to update last_used_at for token {}: {err}",
+            token.id
+        );
+    }
+
+    Ok(token.name)
+}
+
+/// Core contract-test report logic — separated from the HTTP layer for testability.
+#[cfg(feature = "ssr")]
+pub async fn process_contract_test_report(
+    ctx: &ContractTestContext<'_>,
+    request: ContractTestRequest,
+) -> Result<ContractTestResponse, AppError> {
+    let schema = request.schema.trim().to_string();
+    let version = request.version.trim().to_string();
+    let consumer = request.consumer.trim().to_string();
+    let tool = request.tool.trim().to_lowercase();
+
+    let published_by = authorize_reporter(ctx, &request.service_token, &schema).await?;
+
+    if !crate::api::changelog::is_valid_service_name(&consumer) {
+        return Err(AppError::BadRequest(format!(
+            "Invalid consumer name '{consumer}'. Use letters, digits and -_./"
+        )));
+    }
+    if tool.is_empty() {
+        return Err(AppError::BadRequest("Tool cannot be empty".into()));
+    }
+    let report_url = request
+        .report_url
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty());
+    if let Some(url) = &report_url {
+        if !crate::api::schemas::is_http_url(url) {
+            return Err(AppError::BadRequest(
+                "report_url must be an http(s) URL".into(),
+            ));
+        }
+    }
+
+    let registered = ctx
+        .schema_repo
+        .find_by_name_summary(&schema)
+        .await?
+        .is_some_and(|s| s.versions.iter().any(|v| v.version == version));
+    if !registered {
+        return Err(AppError::NotFound(format!(
+            "Schema '{schema}' version '{version}' not found"
+        )));
+    }
+
+    ctx.contract_test_repo
+        .upsert(ContractTestReport {
+            schema: schema.clone(),
+            version: version.clone(),
+            consumer: consumer.clone(),
+            tool,
+            outcome: request.outcome,
+            passed: request.passed,
+            failed: request.failed,
+            report_url,
+            published_by,
+            reported_at: chrono::Utc::now(),
+        })
+        .await?;
+
+    Ok(ContractTestResponse {
+        message: "Contract test report published".to_string(),
+        schema,
+        version,
+        consumer,
+    })
+}
+
+/// Axum handler for `POST /api/v1/contract-tests`.
+#[cfg(feature = "ssr")]
+pub async fn contract_test_handler(
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    axum::Json(request): axum::Json<ContractTestRequest>,
+) -> Result<axum::Json<ContractTestResponse>, AppError> {
+    let response = process_contract_test_report(
+        &ContractTestContext {
+            contract_test_repo: state.contract_test_repo.as_ref(),
+            schema_repo: state.schema_repo.as_ref(),
+            service_token_repo: state.service_token_repo.as_ref(),
+            legacy_token: Some(&state.service_token),
+        },
+        request,
+    )
+    .await?;
+
+    Ok(axum::Json(response))
+}
@@ -0,0 +1,572 @@
+//! Bulk document operations for admins, raw document downloads and
+//! document relations.
+//!
+//! | Method | Path                                  | Description                                  |
+//! |--------|---------------------------------------|----------------------------------------------|
+//! | PATCH  | `/api/v1/documents:batch`             | Change owner, access level, tags or parent of several documents, or archive them |
+//! | GET    | `/api/v1/documents/{*slug}/raw`       | Markdown of a document (`?download=true` for an attachment) |
+//! | GET    | `/api/v1/documents/{*slug}/relations` | Relations of a document in both directions, limited to readable documents |
+
+use std::collections::HashSet;
+
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap};
+use axum::response::IntoResponse;
+use axum::Json;
+use serde::Deserialize;
+
+use crate::api::events::Caller;
+use crate::api::events::{ActivityEvent, ActivityKind};
+use crate::app::AppState;
+use crate::auth::extractor::{OptionalAuthUser, RequiredAuthUser};
+use crate::db::models::{Document, DocumentBatchResult, DocumentBatchUpdate};
+use crate::db::repository::DocumentRepository;
+use crate::error::AppError;
+use crate::relations::DocRelations;
+
+/// Most documents a single batch may touch.
+pub const MAX_BATCH_SIZE: usize = 500;
+
+/// Apply `update` to every active document it names, on behalf of `actor`.
+///
+/// The whole batch is validated before any document is written. Like
+/// [`merge_tags`](crate::api::admin::merge_tags), metadata hashes are left
+/// alone: the next ingest from a document's source restores the values it
+/// sends.
+pub async fn batch_update_documents(
+    state: &AppState,
+    update: DocumentBatchUpdate,
+    actor: &str,
+) -> Result<DocumentBatchResult, AppError> {
+    let mut slugs: Vec<String> = Vec::with_capacity(update.slugs.len());
+    for slug in &update.slugs {
+        if !slugs.contains(slug) {
+            slugs.push(slug.clone());
+        }
+    }
+    if slugs.is_empty() {
+        return Err(AppError::BadRequest("No documents selected".into()));
+    }
+    if slugs.len() > MAX_BATCH_SIZE {
+        return Err(AppError::BadRequest(format!(
+            "At most {MAX_BATCH_SIZE} documents can be changed at once"
+        )));
+    }
+    let changes_metadata = update.service_owner.is_some()
+        || update.access_level.is_some()
+        || !update.add_tags.is_empty()
+        || !update.remove_tags.is_empty()
+        || update.parent_slug.is_some();
+    if update.delete && changes_metadata {
+        return Err(AppError::BadRequest(
+            "Deleting documents cannot be combined with other changes".into(),
+        ));
+    }
+    if !update.delete && !changes_metadata {
+        return Err(AppError::BadRequest("No changes requested".into()));
+    }
+
+    let mut docs: Vec<Document> = state
+        .document_repo
+        .find_by_slugs(&slugs)
+        .await?
+        .into_iter()
+        .filter(|d| !d.is_archived)
+        .collect();
+    docs.sort_by_key(|d| slugs.iter().position(|s| *s == d.slug));
+    let not_found: Vec<String> = slugs
+        .iter()
+        .filter(|s| !docs.iter().any(|d| d.slug == **s))
+        .cloned()
+        .collect();
+
+    if update.delete {
+        let mut updated = Vec::with_capacity(docs.len());
+        for doc in docs {
+            state.document_repo.set_archived(&doc.slug, true).await?;
+            remove_from_indexes(state, &doc).await;
+            state.activity_events.publish(ActivityEvent::document(
+                ActivityKind::DocumentArchived,
+                &doc,
+                actor,
+            ));
+            updated.push(doc.slug);
+        }
+        return Ok(DocumentBatchResult { updated, not_found });
+    }
+
+    let service_owner = match update.service_owner.as_deref().map(str::trim) {
+        None => None,
+        Some("") => {
+            return Err(AppError::BadRequest("Service owner cannot be empty".into()));
+        }
+        Some(owner) => Some(
+            state
+                .taxonomy
+                .normalize_owner(owner)
+                .map_err(AppError::BadRequest)?,
+        ),
+    };
+    let access_level = match update.access_level.as_deref().map(str::trim) {
+        None => None,
+        Some(level) => {
+            let level = level.to_lowercase();
+            if !state.access_level_repo.exists(&level).await? {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown access level: '{level}'"
+                )));
+            }
+            for doc in &docs {
+                crate::db::project_repository::check_project_access_level(
+                    state.project_repo.as_ref(),
+                    &doc.slug,
+                    &level,
+                )
+                .await?;
+            }
+            Some(level)
+        }
+    };
+    let add_tags = state
+        .taxonomy
+        .normalize_tags(&update.add_tags)
+        .map_err(AppError::BadRequest)?;
+    let parent_slug = match update
+        .parent_slug
+        .as_deref()
+        .map(|p| p.trim().trim_matches('/'))
+    {
+        None => None,
+        Some("") => Some(None),
+        Some(parent) => {
+            check_parent(state, parent, &slugs).await?;
+            Some(Some(parent.to_string()))
+        }
+    };
+
+    let mut updated = Vec::with_capacity(docs.len());
+    for mut doc in docs {
+        if let Some(owner) = &service_owner {
+            doc.service_owner = owner.clone();
+        }
+        if let Some(level) = &access_level {
+            doc.access_level = level.clone();
+        }
+        doc.tags.retain(|t| !update.remove_tags.contains(t));
+        for tag in &add_tags {
+            if !doc.tags.contains(tag) {
+                doc.tags.push(tag.clone());
+            }
+        }
+        if let Some(parent) = &parent_slug {
+            doc.parent_slug = parent.clone();
+        }
+        state.document_repo.create_or_update(doc.clone()).await?;
+        refresh_indexes(state, &doc).await;
+        state.activity_events.publish(ActivityEvent::document(
+            ActivityKind::DocumentUpdated,
+            &doc,
+            actor,
+        ));
+        updated.push(doc.slug);
+    }
+    Ok(DocumentBatchResult { updated, not_found })
+}
+
+/// Reject a new parent that doesn't exist or lies below one of the moved
+/// documents, which would cut the subtree off the navigation.
+async fn check_parent(state: &AppState, parent: &str, slugs: &[String]) -> Result<(), AppError> {
+    let mut seen = HashSet::new();
+    let mut current = Some(parent.to_string());
+    while let Some(slug) = current {
+        if slugs.contains(&slug) {
+            return Err(AppError::BadRequest(format!(
+                "Cannot move '{slug}' under itself or one of its descendants"
+            )));
+        }
+        if !seen.insert(slug.clone()) {
+            break;
+        }
+        current = match state.document_repo.find_by_slug(&slug).await? {
+            Some(doc) if !doc.is_archived => doc.parent_slug,
+            _ if slug == parent => {
+                return Err(AppError::BadRequest(format!(
+                    "Parent document '{parent}' not found"
+                )));
+            }
+            _ => None,
+        };
+    }
+    Ok(())
+}
+
+/// Re-index a changed document in search and RAG from its stored content.
+async fn refresh_indexes(state: &AppState, doc: &Document) {
+    if state.search_service.is_none() && state.rag_service.is_none() {
+        return;
+    }
+    let content = match state.storage_client.get_object(&doc.s3_key).await {
+        Ok(Some(bytes)) => String::from_utf8_lossy(&bytes).into_owned(),
+        Ok(None) => {
+            tracing::warn!(slug = %doc.slug, "Content not found in storage, indexes not refreshed");
+            return;
+        }
+        Err(e) => {
+            tracing::warn!(slug = %doc.slug, "Failed to read content, indexes not refreshed: {e}");
+            return;
+        }
+    };
+    if let Some(search) = state.search_service.as_deref() {
+        if let Err(e) = crate::search::client::sync_search_document(search, doc, &content).await {
+            tracing::warn!(slug = %doc.slug, "Failed to update document in search: {e}");
+        }
+    }
+    if let Some(rag) = state.rag_service.as_deref() {
+        if let Err(e) = rag
+            .index_document(
+                &doc.slug,
+                &doc.title,
+                &content,
+                &doc.access_level,
+                doc.is_draft,
+                &doc.tags,
+            )
+            .await
+        {
+            tracing::warn!(slug = %doc.slug, "Failed to index document in RAG: {e}");
+        }
+    }
+}
+
+async fn remove_from_indexes(state: &AppState, doc: &Document) {
+    if let Some(search) = state.search_service.as_deref() {
+        if let Err(e) = search.delete_document(&doc.slug).await {
+            tracing::warn!(slug = %doc.slug, "Failed to deindex archived document from search: {e}");
+        }
+    }
+    if let Some(rag) = state.rag_service.as_deref() {
+        if let Err(e) = rag.delete_document(&doc.slug).await {
+            tracing::warn!(slug = %doc.slug, "Failed to remove archived document from RAG: {e}");
+        }
+    }
+}
+
+/// `PATCH /api/v1/documents:batch`
+pub async fn batch_update_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Json(update): Json<DocumentBatchUpdate>,
+) -> Result<Json<DocumentBatchResult>, AppError> {
+    if !user.is_admin {
+        return Err(AppError::Forbidden("Admin privileges required".into()));
+    }
+    let actor = user.name.clone().unwrap_or_else(|| user.email.clone());
+    let result = batch_update_documents(&state, update, &actor).await?;
+    tracing::info!(
+        actor = %actor,
+        updated = result.updated.len(),
+        "Applied batch document update"
+    );
+    Ok(Json(result))
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RawDocumentQuery {
+    /// Serve as an attachment instead of inline text.
+    #[serde(default)]
+    pub download: bool,
+}
+
+/// `Content-Type` and `Content-Disposition` of the raw content of `slug`.
+///
+/// Text is served as `text/markdown`, inline unless a download is
+/// requested; content that is not UTF-8 text is always an
+/// `application/octet-stream` attachment.
+fn raw_document_headers(
+    slug: &str,
+    content: &[u8],
+    download: bool,
+) -> [(axum::http::HeaderName, String); 2] {
+    let is_text = crate::rendering::limits::check_text(content).is_ok();
+    let base = slug.rsplit('/').next().unwrap_or(slug);
+    let filename: String = (if is_text {
+        format!("{base}.md")
+    } else {
+        base.to_string()
+    })
+    .chars()
+    .map(|c| {
+        if c == '"' || c == '\\' || c.is_control() {
+            '_'
+        } else {
+            c
+        }
+    })
+    .collect();
+    let (content_type, disposition) = match (is_text, download) {
+        (true, false) => ("text/markdown; charset=utf-8", "inline"),
+        (true, true) => ("text/markdown; charset=utf-8", "attachment"),
+        (false, _) => ("application/octet-stream", "attachment"),
+    };
+    [
+        (header::CONTENT_TYPE, content_type.to_string()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("{disposition}; filename=\"{filename}\""),
+        ),
+    ]
+}
+
+/// The document at `slug` if the caller may read it: a read-only API key or
+/// personal access token sent as bearer, otherwise the session user, otherwise
+/// anonymous (public documents only). Archived documents are never returned.
+pub(crate) async fn readable_document(
+    state: &AppState,
+    headers: &HeaderMap,
+    user: Option<crate::auth::models::AuthenticatedUser>,
+    slug: &str,
+) -> Result<Option<Document>, AppError> {
+    if crate::api::bot::is_bot_key(state, headers).await? {
+        return crate::api::bot::readable_document_with_key(state, headers, slug).await;
+    }
+    let caller = crate::api::events::identify_caller(state, headers, user).await?;
+    Ok(state.document_repo.find_by_slug(slug).await?.filter(|doc| {
+        !doc.is_archived
+            && crate::app::doc_is_accessible(
+                &doc.access_level,
+                doc.is_draft,
+                caller.allowed_levels.as_deref(),
+                caller.include_draft,
+            )
+    }))
+}
+
+/// The content of `doc` rendered to HTML, as a preview when it is larger
+/// than [`AppState::max_render_bytes`].
+/// For pages served outside the app, such as shared and embedded documents.
+pub(crate) async fn render_document(state: &AppState, doc: &Document) -> Result<String, AppError> {
+    let content = state
+        .storage_client
+        .get_object(&doc.s3_key)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Document '{}' not found", doc.slug)))?;
+
+    let raw = crate::rendering::limits::decode_text(content)
+        .map_err(|e| AppError::Internal(format!("Cannot display '{}': {e}", doc.slug)))?;
+    let preview = crate::rendering::limits::preview(&raw, state.max_render_bytes);
+    let embeds = crate::server::embeds::embeds(state).await;
+    let mut html = state.render_pipeline.render(
+        preview.unwrap_or(&raw),
+        &crate::rendering::pipeline::RenderContext {
+            embeds: embeds.as_ref(),
+            ..Default::default()
+        },
+    );
+    if preview.is_some() {
+        html.push_str("<p><em>This document is too large to display in full.</em></p>");
+    }
+    Ok(html)
+}
+
+/// Relations of the document at `slug` for `caller`, limited to unarchived
+/// documents within the caller's access levels (and drafts only when the
+/// caller may read them). `None` when the document itself is not readable.
+pub(crate) async fn readable_relations(
+    repo: &dyn DocumentRepository,
+    caller: &Caller,
+    slug: &str,
+) -> Result<Option<DocRelations>, AppError> {
+    let can_read = |doc: &Document| {
+        !doc.is_archived
+            && crate::app::doc_is_accessible(
+                &doc.access_level,
+                doc.is_draft,
+                caller.allowed_levels.as_deref(),
+                caller.include_draft,
+            )
+    };
+    let Some(doc) = repo.find_by_slug(slug).await?.filter(|doc| can_read(doc)) else {
+        return Ok(None);
+    };
+    crate::relations::resolve_relations(repo, &doc, can_read)
+        .await
+        .map(Some)
+}
+
+/// `GET /api/v1/documents/{*slug}/{raw,relations}`
+///
+/// Slugs contain `/`, so the route captures `<slug>/<resource>` as a whole
+/// and dispatches on the last segment.
+pub async fn document_resource_handler(
+    State(state): State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    headers: HeaderMap,
+    Path(path): Path<String>,
+    Query(query): Query<RawDocumentQuery>,
+) -> Result<axum::response::Response, AppError> {
+    if let Some(slug) = path.strip_suffix("/raw") {
+        return raw_document(&state, &headers, user, slug, query).await;
+    }
+    if let Some(slug) = path.strip_suffix("/relations") {
+        return relations(&state, &headers, user, slug).await;
+    }
+    Err(AppError::NotFound(format!(
+        "No route for '/api/v1/documents/{path}'"
+    )))
+}
+
+/// `GET /api/v1/documents/{*slug}/relations`
+///
+/// Relations in both directions for the session user, a personal access
+/// token or an anonymous caller; read-only API keys use
+/// `/api/v1/bot/relations/{*slug}`. Documents the caller cannot read are
+/// reported as not found.
+async fn relations(
+    state: &AppState,
+    headers: &HeaderMap,
+    user: Option<crate::auth::models::AuthenticatedUser>,
+    slug: &str,
+) -> Result<axum::response::Response, AppError> {
+    let caller = crate::api::events::identify_caller(state, headers, user).await?;
+    let relations = readable_relations(state.document_repo.as_ref(), &caller, slug)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Document '{slug}' not found")))?;
+    Ok(Json(relations).into_response())
+}
+
+/// `GET /api/v1/documents/{*slug}/raw`
+///
+/// Returns the Markdown a document was ingested or saved with, so tooling
+/// can read sources without storage credentials. Documents the caller
+/// cannot read are reported as not found.
+async fn raw_document(
+    state: &AppState,
+    headers: &HeaderMap,
+    user: Option<crate::auth::models::AuthenticatedUser>,
+    slug: &str,
+    query: RawDocumentQuery,
+) -> Result<axum::response::Response, AppError> {
+    let not_found = || AppError::NotFound(format!("Document '{slug}' not found"));
+
+    let doc = readable_document(state, headers, user, slug)
+        .await?
+        .ok_or_else(not_found)?;
+    let content = state
+        .storage_client
+        .get_object(&doc.s3_key)
+        .await?
+        .ok_or_else(not_found)?;
+
+    let [content_type, disposition] = raw_document_headers(&doc.slug, &content, query.download);
+    Ok((
+        [
+            content_type,
+            disposition,
+            (header::CACHE_CONTROL, "private, no-cache".to_string()),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        ],
+        content,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::DocumentRelations;
+    use crate::relations::RelatedDoc;
+    use crate::testing::{self, InMemoryDocumentRepository};
+
+    fn caller(levels: &[&str], include_draft: bool) -> Caller {
+        Caller {
+            email: Some("dev@acme.com".to_string()),
+            allowed_levels: Some(levels.iter().map(|l| l.to_string()).collect()),
+            include_draft,
+        }
+    }
+
+    fn related(slug: &str) -> RelatedDoc {
+        RelatedDoc {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn relations_are_limited_to_readable_documents() {
+        let repo = InMemoryDocumentRepository::with_documents(vec![
+            Document {
+                relations: DocumentRelations {
+                    see_also: vec![
+                        "ops/public".to_string(),
+                        "ops/internal".to_string(),
+                        "ops/draft".to_string(),
+                        "ops/archived".to_string(),
+                    ],
+                    ..Default::default()
+                },
+                ..testing::document("ops/runbook")
+            },
+            testing::document("ops/public"),
+            Document {
+                access_level: "internal".to_string(),
+                ..testing::document("ops/internal")
+            },
+            Document {
+                is_draft: true,
+                ..testing::document("ops/draft")
+            },
+            Document {
+                is_archived: true,
+                ..testing::document("ops/archived")
+            },
+            Document {
+                access_level: "internal".to_string(),
+                relations: DocumentRelations {
+                    prerequisite: vec!["ops/runbook".to_string()],
+                    ..Default::default()
+                },
+                ..testing::document("ops/onboarding")
+            },
+        ]);
+
+        let public = readable_relations(&repo, &caller(&["public"], false), "ops/runbook")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(public.see_also, vec![related("ops/public")]);
+        assert!(public.prerequisite_of.is_empty());
+
+        let internal =
+            readable_relations(&repo, &caller(&["public", "internal"], true), "ops/runbook")
+                .await
+                .unwrap()
+                .unwrap();
+        assert_eq!(
+            internal.see_also,
+            vec![
+                related("ops/public"),
+                related("ops/internal"),
+                related("ops/draft")
+            ]
+        );
+        assert_eq!(internal.prerequisite_of, vec![related("ops/onboarding")]);
+    }
+
+    #[tokio::test]
+    async fn relations_of_unreadable_documents_are_not_found() {
+        let repo = InMemoryDocumentRepository::with_documents(vec![Document {
+            access_level: "internal".to_string(),
+            ..testing::document("ops/internal")
+        }]);
+        let public = caller(&["public"], false);
+        assert!(readable_relations(&repo, &public, "ops/internal")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(readable_relations(&repo, &public, "ops/missing")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
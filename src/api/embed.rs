@@ -0,0 +1,314 @@
+//! Documents embedded in other sites, such as wikis and dashboards.
+//!
+//! | Method | Path                   | Auth    | Description                                        |
+//! |--------|------------------------|---------|----------------------------------------------------|
+//! | GET    | `/embed/docs/{*slug}`  | Session | A document without the portal chrome, for iframes  |
+//! | GET    | `/api/v1/oembed?url=…` | Public  | oEmbed `rich` response framing a document URL      |
+//!
+//! Embedded documents are read with the viewer's own session, so readers
+//! only see what they could see in the portal. Session cookies are
+//! `SameSite=Strict`: they reach frames on sites sharing the portal's
+//! registrable domain, and readers elsewhere see public documents only.
+
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{Html, IntoResponse, Response};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::auth::extractor::OptionalAuthUser;
+use crate::config::EmbedConfig;
+use crate::error::AppError;
+use crate::rendering::markdown::escape_html;
+
+/// Frame size when the consumer sets no maximum.
+const DEFAULT_WIDTH: u32 = 800;
+const DEFAULT_HEIGHT: u32 = 600;
+
+/// `GET /embed/docs/{*slug}`
+///
+/// Renders the document alone, with links opening outside the frame.
+/// Documents the viewer cannot read get a `404` page inviting them to open
+/// the document in the portal, where they can sign in.
+pub async fn embed_document_handler(
+    State(state): State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+) -> Result<Response, AppError> {
+    if !state.embed.enabled {
+        return Err(AppError::NotFound(format!(
+            "No route for '/embed/docs/{slug}'"
+        )));
+    }
+    let portal_name = portal_name(&state).await?;
+    let portal_link = format!(
+        "<a href=\"/docs/{}\" class=\"link link-hover\">Open in {}</a>",
+        escape_html(&slug),
+        escape_html(&portal_name)
+    );
+
+    let doc = crate::api::documents::readable_document(&state, &headers, user, &slug).await?;
+    let (status, title, body) = match doc {
+        Some(doc) => {
+            let html = crate::api::documents::render_document(&state, &doc).await?;
+            (StatusCode::OK, doc.title, html)
+        }
+        None => (
+            StatusCode::NOT_FOUND,
+            "Document unavailable".to_string(),
+            "<p>This document does not exist or you are not allowed to read it. If it is restricted, sign in to the portal and reload this page.</p>".to_string(),
+        ),
+    };
+    Ok(page(&state.embed, status, &title, &body, &portal_link))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OEmbedQuery {
+    pub url: String,
+    #[serde(default)]
+    pub maxwidth: Option<u32>,
+    #[serde(default)]
+    pub maxheight: Option<u32>,
+    #[serde(default)]
+    pub format: Option<String>,
+}
+
+/// An oEmbed 1.0 `rich` response.
+#[derive(Debug, Serialize)]
+pub struct OEmbedResponse {
+    pub version: &'static str,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub provider_name: String,
+    pub provider_url: String,
+    /// Only given when the caller may read the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub html: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// `GET /api/v1/oembed?url=<document URL>`
+///
+/// `url` is a `/docs/...` or `/embed/docs/...` URL on this server's host.
+/// The frame is returned whether or not the caller can read the document,
+/// since access is checked when each reader loads it; the title is only
+/// included for documents the caller can read.
+pub async fn oembed_handler(
+    State(state): State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    headers: HeaderMap,
+    Query(query): Query<OEmbedQuery>,
+) -> Result<Response, AppError> {
+    if !state.embed.enabled {
+        return Err(AppError::NotFound("No route for '/api/v1/oembed'".into()));
+    }
+    if query.format.as_deref().is_some_and(|f| f != "json") {
+        return Ok(StatusCode::NOT_IMPLEMENTED.into_response());
+    }
+    let (origin, slug) = embed_target(&query.url, request_host(&headers)).ok_or_else(|| {
+        AppError::NotFound(format!("'{}' is not a document of this portal", query.url))
+    })?;
+
+    let title = crate::api::documents::readable_document(&state, &headers, user, &slug)
+        .await?
+        .map(|doc| doc.title);
+    let provider_name = portal_name(&state).await?;
+    let width = query
+        .maxwidth
+        .map_or(DEFAULT_WIDTH, |w| w.min(DEFAULT_WIDTH));
+    let height = query
+        .maxheight
+        .map_or(DEFAULT_HEIGHT, |h| h.min(DEFAULT_HEIGHT));
+    let html = format!(
+        "<iframe src=\"{}/embed/docs/{}\" width=\"{width}\" height=\"{height}\" title=\"{}\" loading=\"lazy\" style=\"border:0\"></iframe>",
+        escape_html(&origin),
+        escape_html(&slug),
+        escape_html(title.as_deref().unwrap_or(&provider_name)),
+    );
+
+    Ok(Json(OEmbedResponse {
+        version: "1.0",
+        kind: "rich",
+        provider_name,
+        provider_url: origin,
+        title,
+        html,
+        width,
+        height,
+    })
+    .into_response())
+}
+
+/// The oEmbed URL describing `slug`, for discovery links on document pages.
+pub fn oembed_discovery_url(state: &AppState, headers: &HeaderMap, slug: &str) -> Option<String> {
+    if !state.embed.enabled {
+        return None;
+    }
+    let host = request_host(headers)?;
+    let scheme = headers
+        .get("x-forwarded-proto")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or(if state.insecure_cookies {
+            "http"
+        } else {
+            "https"
+        });
+    let origin = format!("{scheme}://{host}");
+    let url: String =
+        url::form_urlencoded::byte_serialize(format!("{origin}/docs/{slug}").as_bytes()).collect();
+    Some(format!("{origin}/api/v1/oembed?url={url}"))
+}
+
+/// The host the client reached, as reported by the proxy or `Host`.
+fn request_host(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get("x-forwarded-host")
+        .or_else(|| headers.get(header::HOST))
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(',').next())
+        .map(str::trim)
+        .filter(|host| !host.is_empty())
+}
+
+/// The origin and slug of a document URL on `host`.
+fn embed_target(url: &str, host: Option<&str>) -> Option<(String, String)> {
+    let url = url::Url::parse(url).ok()?;
+    let authority = match url.port() {
+        Some(port) => format!("{}:{port}", url.host_str()?),
+        None => url.host_str()?.to_string(),
+    };
+    if !matches!(url.scheme(), "http" | "https") || !authority.eq_ignore_ascii_case(host?) {
+        return None;
+    }
+    let path = url.path();
+    let slug = path
+        .strip_prefix("/embed/docs/")
+        .or_else(|| path.strip_prefix("/docs/"))?
+        .trim_end_matches('/');
+    if slug.is_empty() {
+        return None;
+    }
+    Some((url.origin().ascii_serialization(), slug.to_string()))
+}
+
+async fn portal_name(state: &AppState) -> Result<String, AppError> {
+    let name = state
+        .settings_repo
+        .get_settings()
+        .await?
+        .branding
+        .portal_name;
+    Ok(if name.trim().is_empty() {
+        "Lekton".to_string()
+    } else {
+        name
+    })
+}
+
+/// The `Content-Security-Policy` letting the configured sites frame a page.
+fn frame_ancestors(config: &EmbedConfig) -> String {
+    if config.frame_ancestors.is_empty() {
+        "frame-ancestors *".to_string()
+    } else {
+        format!("frame-ancestors {}", config.frame_ancestors.join(" "))
+    }
+}
+
+/// A page for frames: the document under a one-line header.
+fn page(config: &EmbedConfig, status: StatusCode, title: &str, body: &str, link: &str) -> Response {
+    let html = format!(
+        "<!DOCTYPE html>\
+         <html lang=\"en\">\
+         <head>\
+         <meta charset=\"utf-8\"/>\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\"/>\
+         <meta name=\"robots\" content=\"noindex\"/>\
+         <base target=\"_blank\"/>\
+         <title>{title}</title>\
+         <link rel=\"stylesheet\" href=\"/pkg/lekton.css\"/>\
+         <link rel=\"stylesheet\" href=\"/custom.css\"/>\
+         </head>\
+         <body class=\"bg-base-100\">\
+         <header class=\"flex items-center justify-between gap-4 px-6 py-2 border-b border-base-200 text-sm\">\
+         <span class=\"font-semibold truncate\">{title}</span>\
+         <span class=\"shrink-0 text-base-content/60\">{link}</span>\
+         </header>\
+         <main class=\"px-6 py-6\">\
+         <article class=\"prose max-w-none\">{body}</article>\
+         </main>\
+         </body>\
+         </html>",
+        title = escape_html(title),
+    );
+    let csp = HeaderValue::from_str(&frame_ancestors(config))
+        .unwrap_or_else(|_| HeaderValue::from_static("frame-ancestors 'none'"));
+    (
+        status,
+        [
+            (header::CONTENT_SECURITY_POLICY, csp),
+            (
+                header::CACHE_CONTROL,
+                HeaderValue::from_static("private, no-cache"),
+            ),
+            (header::VARY, HeaderValue::from_static("Cookie")),
+            (
+                header::X_CONTENT_TYPE_OPTIONS,
+                HeaderValue::from_static("nosniff"),
+            ),
+        ],
+        Html(html),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embed_target_accepts_document_urls_on_this_host() {
+        let host = Some("docs.acme.com");
+        assert_eq!(
+            embed_target("https://docs.acme.com/docs/guides/setup/", host),
+            Some(("https://docs.acme.com".into(), "guides/setup".into()))
+        );
+        assert_eq!(
+            embed_target(
+                "http://localhost:3000/embed/docs/runbook",
+                Some("localhost:3000")
+            ),
+            Some(("http://localhost:3000".into(), "runbook".into()))
+        );
+        assert_eq!(
+            embed_target("https://evil.example/docs/runbook", host),
+            None
+        );
+        assert_eq!(
+            embed_target("https://docs.acme.com/schemas/accounts", host),
+            None
+        );
+        assert_eq!(embed_target("https://docs.acme.com/docs/", host), None);
+        assert_eq!(
+            embed_target("https://docs.acme.com/docs/runbook", None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_frame_ancestors_defaults_to_any_site() {
+        let mut config = EmbedConfig::default();
+        assert_eq!(frame_ancestors(&config), "frame-ancestors *");
+        config.frame_ancestors = vec![
+            "https://wiki.acme.com".into(),
+            "https://grafana.acme.com".into(),
+        ];
+        assert_eq!(
+            frame_ancestors(&config),
+            "frame-ancestors https://wiki.acme.com https://grafana.acme.com"
+        );
+    }
+}
@@ -1,3 +1,24 @@
+//! RFC 7807 problem responses.
+//!
+//! Every `/api/v1` error is returned as `application/problem+json`:
+//!
+//! ```json
+//! {
+//!   "type": "urn:lekton:problem:not_found",
+//!   "title": "Not found",
+//!   "status": 404,
+//!   "detail": "Document 'a/b' not found",
+//!   "code": "not_found",
+//!   "error": "Document 'a/b' not found",
+//!   "request_id": "6f1c2a9e-0d4b-4c1e-9a57-3b2f8e61d0c4"
+//! }
+//! ```
+//!
+//! `code` is stable and meant for scripts to branch on; `error` repeats
+//! `detail` for clients written against the former `{"error": ...}` body.
+//! `request_id` matches the `x-request-id` response header and the request's
+//! log span.
+
 #[cfg(feature = "ssr")]
 use crate::error::AppError;
 #[cfg(feature = "ssr")]
@@ -5,42 +26,103 @@ use axum::http::StatusCode;
 #[cfg(feature = "ssr")]
 use axum::response::{IntoResponse, Response};
 
+/// Media type of problem responses.
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/// An RFC 7807 problem details body.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Problem {
+    /// `urn:lekton:problem:<code>`.
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    /// Stable machine-readable error code (e.g. `not_found`, `conflict`).
+    pub code: String,
+    /// Same as `detail`, kept for backward compatibility.
+    pub error: String,
+    /// Correlation ID of the failed request (also sent as `x-request-id`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl Problem {
+    pub fn new(status: u16, code: &str, title: &str, detail: impl Into<String>) -> Self {
+        let detail = detail.into();
+        Self {
+            problem_type: format!("urn:lekton:problem:{code}"),
+            title: title.to_string(),
+            status,
+            error: detail.clone(),
+            detail,
+            code: code.to_string(),
+            request_id: None,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<&AppError> for Problem {
+    fn from(err: &AppError) -> Self {
+        Problem::new(err.status(), err.code(), err.title(), err.public_detail())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl IntoResponse for Problem {
+    fn into_response(mut self) -> Response {
+        if self.request_id.is_none() {
+            self.request_id = crate::telemetry::current_request_id().filter(|id| !id.is_empty());
+        }
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (
+            status,
+            [(axum::http::header::CONTENT_TYPE, PROBLEM_CONTENT_TYPE)],
+            axum::Json(self),
+        )
+            .into_response()
+    }
+}
+
 /// API-specific error wrapper that converts AppError into HTTP responses.
 #[cfg(feature = "ssr")]
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, message) = match &self {
-            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
-            AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg.clone()),
-            AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
-            AppError::Auth(msg) => (StatusCode::UNAUTHORIZED, msg.clone()),
-            AppError::Database(msg) => {
-                tracing::error!("Database error: {msg}");
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal server error".to_string(),
-                )
-            }
-            AppError::Storage(msg) => {
-                tracing::error!("Storage error: {msg}");
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal server error".to_string(),
-                )
-            }
-            AppError::Internal(msg) => {
-                tracing::error!("Internal error: {msg}");
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal server error".to_string(),
-                )
-            }
-        };
-
-        let body = serde_json::json!({
-            "error": message
-        });
-
-        (status, axum::Json(body)).into_response()
+        self.log();
+        Problem::from(&self).into_response()
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_errors_keep_their_message() {
+        let problem = Problem::from(&AppError::Conflict("Slug already exists".into()));
+        assert_eq!(problem.status, 409);
+        assert_eq!(problem.code, "conflict");
+        assert_eq!(problem.problem_type, "urn:lekton:problem:conflict");
+        assert_eq!(problem.detail, "Slug already exists");
+        assert_eq!(problem.error, problem.detail);
+    }
+
+    #[test]
+    fn server_errors_hide_internal_details() {
+        let problem = Problem::from(&AppError::Database("connection refused to 10.0.0.3".into()));
+        assert_eq!(problem.status, 500);
+        assert_eq!(problem.code, "database_error");
+        assert_eq!(problem.detail, "Internal server error");
+    }
+
+    #[test]
+    fn response_uses_problem_json_content_type() {
+        let response = AppError::NotFound("missing".into()).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers()[axum::http::header::CONTENT_TYPE],
+            PROBLEM_CONTENT_TYPE
+        );
     }
 }
@@ -0,0 +1,506 @@
+//! Real-time portal activity over server-sent events.
+//!
+//! | Method | Path                               | Description                                  |
+//! |--------|------------------------------------|----------------------------------------------|
+//! | GET    | `/api/v1/events/stream`            | All activity visible to the caller           |
+//! | GET    | `/api/v1/docs/events?slug=<slug>`  | `updated` events for one open document page  |
+//!
+//! Writes (API ingests, GitHub syncs, web editor saves, archiving, schema
+//! ingests) publish an [`ActivityEvent`] on an in-process broadcast channel.
+//! Each subscriber only receives events whose access level (and draft state)
+//! it may read; events never carry content, which is fetched through the
+//! usual access-checked endpoints. With several replicas behind a load
+//! balancer, events are relayed between them when Redis coordination is
+//! configured ([`crate::coordination`]); otherwise subscribers only see writes
+//! handled by the replica they are connected to.
+//!
+//! Callers authenticate with the session cookie or, for bots and dashboards,
+//! an `Authorization: Bearer <PAT>` header; anonymous callers see public
+//! activity only.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::app::AppState;
+use crate::auth::extractor::OptionalAuthUser;
+use crate::error::AppError;
+
+/// Buffered events per subscriber before it starts lagging.
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Targets remembered by [`ActivityEvents::deliver_unannounced`] before the
+/// oldest are forgotten.
+const MAX_REMEMBERED_TARGETS: usize = 4096;
+
+/// What happened. Serialized as the SSE event name (e.g. `document.updated`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActivityKind {
+    #[serde(rename = "document.created")]
+    DocumentCreated,
+    #[serde(rename = "document.updated")]
+    DocumentUpdated,
+    #[serde(rename = "document.archived")]
+    DocumentArchived,
+    #[serde(rename = "schema.ingested")]
+    SchemaIngested,
+}
+
+impl ActivityKind {
+    pub const ALL: [ActivityKind; 4] = [
+        ActivityKind::DocumentCreated,
+        ActivityKind::DocumentUpdated,
+        ActivityKind::DocumentArchived,
+        ActivityKind::SchemaIngested,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ActivityKind::DocumentCreated => "document.created",
+            ActivityKind::DocumentUpdated => "document.updated",
+            ActivityKind::DocumentArchived => "document.archived",
+            ActivityKind::SchemaIngested => "schema.ingested",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == value)
+    }
+
+    fn is_document_write(self) -> bool {
+        matches!(
+            self,
+            ActivityKind::DocumentCreated | ActivityKind::DocumentUpdated
+        )
+    }
+}
+
+/// A change to the portal's content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityEvent {
+    pub kind: ActivityKind,
+    /// Document slug or schema name.
+    pub target: String,
+    /// Schema version, for schema events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Document title, for document events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub access_level: String,
+    /// Owner access level of the schema, for schema events: callers must be
+    /// able to read it as well as `access_level`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_access_level: Option<String>,
+    #[serde(default)]
+    pub is_draft: bool,
+    /// Service token or user that made the change.
+    pub actor: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ActivityEvent {
+    /// Event for a change to `doc`.
+    pub fn document(kind: ActivityKind, doc: &crate::db::models::Document, actor: &str) -> Self {
+        Self {
+            kind,
+            target: doc.slug.clone(),
+            version: None,
+            title: Some(doc.title.clone()),
+            access_level: doc.access_level.clone(),
+            owner_access_level: None,
+            is_draft: doc.is_draft,
+            actor: actor.to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// Event for ingesting `version` of the schema `name`.
+    pub fn schema_ingested(
+        name: &str,
+        version: &crate::db::models::SchemaVersion,
+        owner_access_level: Option<&str>,
+        actor: &str,
+    ) -> Self {
+        Self {
+            kind: ActivityKind::SchemaIngested,
+            target: name.to_string(),
+            version: Some(version.version.clone()),
+            title: None,
+            access_level: version.access_level.clone(),
+            owner_access_level: owner_access_level.map(str::to_string),
+            is_draft: false,
+            actor: actor.to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// Whether a caller with the given document visibility may see this event.
+    ///
+    /// `allowed_levels = None` means admin (unrestricted).
+    pub fn visible_to(&self, allowed_levels: Option<&[String]>, include_draft: bool) -> bool {
+        if self.is_draft && !include_draft {
+            return false;
+        }
+        let readable = |level: &String| allowed_levels.is_none_or(|levels| levels.contains(level));
+        readable(&self.access_level) && self.owner_access_level.as_ref().is_none_or(readable)
+    }
+}
+
+/// Broadcast hub for [`ActivityEvent`]s, shared through `AppState`.
+#[derive(Clone)]
+pub struct ActivityEvents {
+    sender: broadcast::Sender<ActivityEvent>,
+    /// Events published by this replica only.
+    local: broadcast::Sender<ActivityEvent>,
+    /// Forwards locally published events to the other replicas.
+    relay: Option<mpsc::UnboundedSender<ActivityEvent>>,
+    /// When an event for each target was last delivered.
+    delivered: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl ActivityEvents {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        let (local, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            sender,
+            local,
+            relay: None,
+            delivered: Arc::default(),
+        }
+    }
+
+    /// Also hand every published event to `relay`.
+    pub fn with_relay(mut self, relay: mpsc::UnboundedSender<ActivityEvent>) -> Self {
+        self.relay = Some(relay);
+        self
+    }
+
+    /// Publish an event; a no-op when nobody is listening.
+    pub fn publish(&self, event: ActivityEvent) {
+        if let Some(relay) = &self.relay {
+            let _ = relay.send(event.clone());
+        }
+        let _ = self.local.send(event.clone());
+        self.deliver(event);
+    }
+
+    /// Hand an event received from another replica to local subscribers,
+    /// without relaying it again.
+    pub fn deliver(&self, event: ActivityEvent) {
+        self.remember(&event.target);
+        let _ = self.sender.send(event);
+    }
+
+    /// Deliver an event for a change noticed in the database (see
+    /// [`crate::db::change_streams`]) unless an event for the same target
+    /// was delivered within `window`, as writes through the API announce
+    /// themselves. Returns whether the event was delivered.
+    pub fn deliver_unannounced(&self, event: ActivityEvent, window: Duration) -> bool {
+        let announced = self
+            .delivered
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&event.target)
+            .is_some_and(|at| at.elapsed() < window);
+        if !announced {
+            self.deliver(event);
+        }
+        !announced
+    }
+
+    fn remember(&self, target: &str) {
+        let mut delivered = self.delivered.lock().unwrap_or_else(|e| e.into_inner());
+        if delivered.len() >= MAX_REMEMBERED_TARGETS {
+            let mut times: Vec<Instant> = delivered.values().copied().collect();
+            times.sort_unstable();
+            let cutoff = times[times.len() / 2];
+            delivered.retain(|_, at| *at > cutoff);
+        }
+        delivered.insert(target.to_string(), Instant::now());
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ActivityEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Receive only the events published by this replica, for work that
+    /// must happen once per event however many replicas run.
+    pub fn subscribe_local(&self) -> broadcast::Receiver<ActivityEvent> {
+        self.local.subscribe()
+    }
+}
+
+impl Default for ActivityEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The caller of a request and the documents it may read.
+pub(crate) struct Caller {
+    /// Email of the PAT or session user; `None` when anonymous.
+    pub email: Option<String>,
+    pub allowed_levels: Option<Vec<String>>,
+    pub include_draft: bool,
+}
+
+/// Identify the caller: a bearer PAT if present, otherwise the session user,
+/// otherwise anonymous (public, no drafts).
+pub(crate) async fn identify_caller(
+    state: &AppState,
+    headers: &HeaderMap,
+    user: Option<crate::auth::models::AuthenticatedUser>,
+) -> Result<Caller, AppError> {
+    if let Some(raw_token) = crate::mcp::auth::extract_bearer(headers) {
+        let auth = crate::mcp::auth::McpAuthState {
+            service_token_repo: state.service_token_repo.clone(),
+            user_repo: state.user_repo.clone(),
+        };
+        let ctx = auth
+            .user_context_for_pat(raw_token)
+            .await
+            .map_err(|_| AppError::Auth("Invalid personal access token".into()))?;
+        let (allowed_levels, include_draft) = ctx.document_visibility();
+        return Ok(Caller {
+            email: Some(ctx.user.email),
+            allowed_levels,
+            include_draft,
+        });
+    }
+
+    let email = user.as_ref().map(|u| u.email.clone());
+    let (allowed_levels, include_draft) = match user {
+        Some(user) if state.demo_mode && !user.is_admin && user.user_id.starts_with("demo-") => {
+            (Some(vec!["public".to_string()]), false)
+        }
+        Some(user) => crate::api::rag::build_user_context(state, &user)
+            .await?
+            .document_visibility(),
+        None => (Some(vec!["public".to_string()]), false),
+    };
+    Ok(Caller {
+        email,
+        allowed_levels,
+        include_draft,
+    })
+}
+
+/// Document visibility of the caller, see [`identify_caller`].
+async fn caller_visibility(
+    state: &AppState,
+    headers: &HeaderMap,
+    user: Option<crate::auth::models::AuthenticatedUser>,
+) -> Result<(Option<Vec<String>>, bool), AppError> {
+    let caller = identify_caller(state, headers, user).await?;
+    Ok((caller.allowed_levels, caller.include_draft))
+}
+
+fn sse_event(name: &str, event: &ActivityEvent) -> Event {
+    let data = serde_json::to_string(event).unwrap_or_default();
+    Event::default().event(name).data(data)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ActivityStreamQuery {
+    /// Comma-separated event kinds to receive (default: all).
+    #[serde(default)]
+    pub kinds: Option<String>,
+}
+
+/// Parse the `kinds` filter; unknown kinds are rejected so typos don't
+/// silently produce an empty stream.
+pub fn parse_kinds(kinds: Option<&str>) -> Result<Vec<ActivityKind>, AppError> {
+    let Some(kinds) = kinds.filter(|k| !k.trim().is_empty()) else {
+        return Ok(ActivityKind::ALL.to_vec());
+    };
+    kinds
+        .split(',')
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .map(|k| {
+            ActivityKind::parse(k)
+                .ok_or_else(|| AppError::BadRequest(format!("Unknown event kind: '{k}'")))
+        })
+        .collect()
+}
+
+/// `GET /api/v1/events/stream?kinds=<kinds>` — SSE stream of portal activity.
+///
+/// Each SSE event is named after its kind and carries the [`ActivityEvent`]
+/// as JSON. A `lagged` event (data: number of skipped events) signals that a
+/// slow consumer missed events and should resynchronise.
+pub async fn activity_stream_handler(
+    State(state): State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    headers: HeaderMap,
+    Query(query): Query<ActivityStreamQuery>,
+) -> Result<Sse<impl futures::Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let kinds = parse_kinds(query.kinds.as_deref())?;
+    let (allowed_levels, include_draft) = caller_visibility(&state, &headers, user).await?;
+    let mut receiver = state.activity_events.subscribe();
+
+    let stream = async_stream::stream! {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if kinds.contains(&event.kind)
+                        && event.visible_to(allowed_levels.as_deref(), include_draft)
+                    {
+                        yield Ok(sse_event(event.kind.as_str(), &event));
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    yield Ok(Event::default().event("lagged").data(skipped.to_string()));
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    };
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DocumentEventsQuery {
+    pub slug: String,
+}
+
+/// `GET /api/v1/docs/events?slug=<slug>` — SSE stream of `updated` events for one document.
+pub async fn document_events_handler(
+    State(state): State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    headers: HeaderMap,
+    Query(query): Query<DocumentEventsQuery>,
+) -> Result<Sse<impl futures::Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let (allowed_levels, include_draft) = caller_visibility(&state, &headers, user).await?;
+    let mut receiver = state.activity_events.subscribe();
+
+    let stream = async_stream::stream! {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if event.kind.is_document_write()
+                        && event.target == query.slug
+                        && event.visible_to(allowed_levels.as_deref(), include_draft)
+                    {
+                        yield Ok(sse_event("updated", &event));
+                    }
+                }
+                // Missed events may include ours; the page can refresh
+                // cheaply, so report a possible update rather than drop it.
+                Err(broadcast::error::RecvError::Lagged(_)) => {
+                    yield Ok(Event::default().event("updated").data("{}"));
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    };
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(access_level: &str, is_draft: bool) -> ActivityEvent {
+        ActivityEvent {
+            kind: ActivityKind::DocumentUpdated,
+            target: "runbooks/db".into(),
+            version: None,
+            title: None,
+            access_level: access_level.into(),
+            owner_access_level: None,
+            is_draft,
+            actor: "ci".into(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_reaches_subscribers() {
+        let events = ActivityEvents::new();
+        // Publishing without subscribers must not fail
+        events.publish(event("public", false));
+
+        let mut receiver = events.subscribe();
+        events.publish(event("internal", false));
+        assert_eq!(receiver.recv().await.unwrap().access_level, "internal");
+    }
+
+    #[tokio::test]
+    async fn test_relay_forwards_published_but_not_delivered_events() {
+        let (relay, mut relayed) = mpsc::unbounded_channel();
+        let events = ActivityEvents::new().with_relay(relay);
+        let mut receiver = events.subscribe();
+        let mut local = events.subscribe_local();
+
+        events.publish(event("public", false));
+        events.deliver(event("internal", false));
+
+        assert_eq!(relayed.recv().await.unwrap().access_level, "public");
+        assert!(relayed.try_recv().is_err());
+        assert_eq!(receiver.recv().await.unwrap().access_level, "public");
+        assert_eq!(receiver.recv().await.unwrap().access_level, "internal");
+        assert_eq!(local.recv().await.unwrap().access_level, "public");
+        assert!(local.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_deliver_unannounced_skips_recently_published_targets() {
+        let events = ActivityEvents::new();
+        let mut receiver = events.subscribe();
+        let window = Duration::from_secs(60);
+
+        events.publish(event("public", false));
+        assert!(!events.deliver_unannounced(event("public", false), window));
+
+        let mut other = event("internal", false);
+        other.target = "runbooks/cache".into();
+        assert!(events.deliver_unannounced(other, window));
+
+        assert_eq!(receiver.recv().await.unwrap().target, "runbooks/db");
+        assert_eq!(receiver.recv().await.unwrap().target, "runbooks/cache");
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_visibility_filter() {
+        let public = vec!["public".to_string()];
+        assert!(event("public", false).visible_to(Some(&public), false));
+        assert!(!event("internal", false).visible_to(Some(&public), false));
+        assert!(!event("public", true).visible_to(Some(&public), false));
+        assert!(event("public", true).visible_to(Some(&public), true));
+        assert!(event("internal", true).visible_to(None, true));
+
+        let mut restricted_owner = event("public", false);
+        restricted_owner.owner_access_level = Some("internal".into());
+        assert!(!restricted_owner.visible_to(Some(&public), false));
+        let internal = vec!["public".to_string(), "internal".to_string()];
+        assert!(restricted_owner.visible_to(Some(&internal), false));
+    }
+
+    #[test]
+    fn test_parse_kinds() {
+        assert_eq!(parse_kinds(None).unwrap(), ActivityKind::ALL.to_vec());
+        assert_eq!(
+            parse_kinds(Some("schema.ingested, document.archived")).unwrap(),
+            vec![ActivityKind::SchemaIngested, ActivityKind::DocumentArchived]
+        );
+        assert!(parse_kinds(Some("document.deleted")).is_err());
+        assert_eq!(
+            serde_json::to_value(ActivityKind::DocumentCreated).unwrap(),
+            "document.created"
+        );
+    }
+}
@@ -0,0 +1,742 @@
+//! GitHub push-webhook receiver.
+//!
+//! `POST /api/v1/integrations/github/webhook` verifies the `X-Hub-Signature-256`
+//! header against `integrations.github.webhook_secret`. On `push` events to a
+//! repository/branch listed in `integrations.github.repos`, the markdown files
+//! touched by the push are fetched from the GitHub contents API and re-ingested
+//! (or archived when removed) in the background, so docs update within seconds
+//! of a merge instead of waiting for the next CI `lekton-sync` run.
+
+use std::collections::{BTreeSet, HashMap};
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use hmac::{Hmac, KeyInit, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::app::AppState;
+use crate::config::{GitHubConfig, GitHubRepoConfig};
+use crate::db::models::{Document, DocumentRelations, IngestRequest};
+use crate::db::repository::DocumentRepository;
+use crate::error::AppError;
+
+const SIGNATURE_HEADER: &str = "x-hub-signature-256";
+const EVENT_HEADER: &str = "x-github-event";
+
+/// Subset of the GitHub `push` event payload used for re-syncing.
+#[derive(Debug, Deserialize)]
+pub struct PushEvent {
+    /// Full git ref that was pushed (e.g. `refs/heads/main`).
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// Commit SHA the ref points to after the push.
+    pub after: String,
+    /// `true` when the push deleted the ref.
+    #[serde(default)]
+    pub deleted: bool,
+    pub repository: PushRepository,
+    #[serde(default)]
+    pub commits: Vec<PushCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushRepository {
+    /// `owner/name` of the repository.
+    pub full_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushCommit {
+    #[serde(default)]
+    pub added: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<String>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+}
+
+/// Response returned to GitHub for every delivery.
+#[derive(Debug, Serialize)]
+pub struct GitHubWebhookResponse {
+    /// `"pong"`, `"accepted"` or `"ignored"`.
+    pub status: String,
+    /// Number of markdown files scheduled for re-ingestion.
+    pub to_sync: usize,
+    /// Number of markdown files scheduled for archiving.
+    pub to_archive: usize,
+}
+
+impl GitHubWebhookResponse {
+    fn without_changes(status: &str) -> Self {
+        Self {
+            status: status.to_string(),
+            to_sync: 0,
+            to_archive: 0,
+        }
+    }
+}
+
+/// Markdown files touched by a push, relative to the repository root.
+#[derive(Debug, Default, PartialEq)]
+pub struct PushChanges {
+    pub upserted: BTreeSet<String>,
+    pub removed: BTreeSet<String>,
+}
+
+/// Verify a `sha256=<hex>` signature header against the raw request body.
+pub fn verify_signature(secret: &str, signature_header: &str, body: &[u8]) -> bool {
+    let Some(hex_sig) = signature_header.strip_prefix("sha256=") else {
+        return false;
+    };
+    let Some(expected) = decode_hex(hex_sig) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+    // `verify_slice` performs a constant-time comparison.
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Collect the markdown files under `docs_dir` touched by the pushed commits.
+///
+/// Commits are applied in order, so a file removed and re-added within the
+/// same push ends up in `upserted`, and vice versa.
+pub fn collect_changes(commits: &[PushCommit], docs_dir: &str) -> PushChanges {
+    let mut changes = PushChanges::default();
+    for commit in commits {
+        for path in commit.added.iter().chain(&commit.modified) {
+            if is_tracked_markdown(path, docs_dir) {
+                changes.removed.remove(path);
+                changes.upserted.insert(path.clone());
+            }
+        }
+        for path in &commit.removed {
+            if is_tracked_markdown(path, docs_dir) {
+                changes.upserted.remove(path);
+                changes.removed.insert(path.clone());
+            }
+        }
+    }
+    changes
+}
+
+fn is_tracked_markdown(path: &str, docs_dir: &str) -> bool {
+    path.ends_with(".md") && strip_docs_dir(path, docs_dir).is_some()
+}
+
+/// Strip the configured docs directory from a repository path, yielding the
+/// `source_path` used by `lekton-sync` (relative to its scan root).
+fn strip_docs_dir<'a>(path: &'a str, docs_dir: &str) -> Option<&'a str> {
+    let dir = docs_dir.trim_matches('/');
+    if dir.is_empty() {
+        return Some(path);
+    }
+    path.strip_prefix(dir)?.strip_prefix('/')
+}
+
+/// YAML front matter fields honoured by the webhook re-sync.
+///
+/// Mirrors the subset of `lekton-sync`'s front matter that maps directly onto
+/// an [`IngestRequest`].
+#[derive(Debug, Default, Deserialize)]
+struct FrontMatter {
+    slug: Option<String>,
+    title: Option<String>,
+    summary: Option<String>,
+    #[serde(alias = "access-level", alias = "accessLevel")]
+    access_level: Option<String>,
+    #[serde(alias = "service-owner", alias = "serviceOwner")]
+    service_owner: Option<String>,
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+    #[serde(alias = "parent-slug", alias = "parentSlug")]
+    parent_slug: Option<String>,
+    order: Option<u32>,
+    #[serde(alias = "is-hidden", alias = "isHidden")]
+    is_hidden: Option<bool>,
+    noindex: Option<bool>,
+    #[serde(alias = "edit-access-level", alias = "editAccessLevel")]
+    edit_access_level: Option<String>,
+    #[serde(default)]
+    relations: DocumentRelations,
+    #[serde(
+        rename = "lekton-import",
+        alias = "lektonImport",
+        alias = "lekton_import",
+        default
+    )]
+    lekton_import: bool,
+}
+
+/// Split YAML front matter from the markdown body.
+fn parse_front_matter(source: &str) -> (FrontMatter, &str) {
+    let after_open = source
+        .strip_prefix("---\r\n")
+        .or_else(|| source.strip_prefix("---\n"));
+    let Some(after_open) = after_open else {
+        return (FrontMatter::default(), source);
+    };
+    match after_open.find("\n---") {
+        Some(idx) => {
+            let after_marker = &after_open[idx + 4..];
+            let body = after_marker
+                .strip_prefix("\r\n")
+                .or_else(|| after_marker.strip_prefix('\n'))
+                .unwrap_or(after_marker);
+            let fm = serde_yaml::from_str(&after_open[..idx]).unwrap_or_default();
+            (fm, body)
+        }
+        None => (FrontMatter::default(), source),
+    }
+}
+
+/// Normalise a title into a URL-safe slug segment (same rules as `lekton-sync`).
+fn slug_from_title(title: &str) -> String {
+    let mut result = String::with_capacity(title.len());
+    let mut prev_sep = true;
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            result.extend(c.to_lowercase());
+            prev_sep = false;
+        } else if !prev_sep {
+            result.push('-');
+            prev_sep = true;
+        }
+    }
+    result.trim_end_matches('-').to_string()
+}
+
+fn apply_prefix(prefix: &str, slug: &str) -> String {
+    let prefix = prefix.trim_matches('/');
+    if prefix.is_empty() {
+        slug.to_string()
+    } else {
+        format!("{prefix}/{slug}")
+    }
+}
+
+/// Whether `slug` lies in `repo_cfg`'s namespace: under its slug prefix and
+/// not under the longer prefix of another configured repository.
+fn in_repo_namespace(repos: &[GitHubRepoConfig], repo_cfg: &GitHubRepoConfig, slug: &str) -> bool {
+    let under = |prefix: &str| {
+        let prefix = prefix.trim_matches('/');
+        prefix.is_empty()
+            || slug == prefix
+            || slug
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('/'))
+    };
+    let depth = |cfg: &GitHubRepoConfig| cfg.slug_prefix.trim_matches('/').len();
+    under(&repo_cfg.slug_prefix)
+        && !repos
+            .iter()
+            .any(|other| depth(other) > depth(repo_cfg) && under(&other.slug_prefix))
+}
+
+/// Active documents synced from `repo_cfg`, by source path.
+///
+/// Source paths are relative to their repository, so several repositories
+/// can have a `guide.md`: a push only resolves them among the documents of
+/// its own repository's namespace.
+async fn repo_documents(
+    document_repo: &dyn DocumentRepository,
+    repos: &[GitHubRepoConfig],
+    repo_cfg: &GitHubRepoConfig,
+) -> Result<HashMap<String, Document>, AppError> {
+    let documents = document_repo
+        .find_by_slug_prefix(repo_cfg.slug_prefix.trim_matches('/'))
+        .await?;
+    Ok(documents
+        .into_iter()
+        .filter(|doc| in_repo_namespace(repos, repo_cfg, &doc.slug))
+        .filter_map(|doc| Some((doc.source_path.clone()?, doc)))
+        .collect())
+}
+
+/// Build the ingest request for a fetched markdown file.
+///
+/// Returns `None` when the file does not opt in with `lekton-import: true`.
+fn build_ingest_request(
+    repo_cfg: &GitHubRepoConfig,
+    source_path: &str,
+    existing_slug: Option<String>,
+    service_token: &str,
+    source: &str,
+) -> Option<IngestRequest> {
+    let (fm, body) = parse_front_matter(source);
+    if !fm.lekton_import {
+        return None;
+    }
+
+    let path_slug = source_path.trim_end_matches(".md").to_string();
+    let title = fm.title.clone().unwrap_or_else(|| {
+        path_slug
+            .rsplit('/')
+            .next()
+            .unwrap_or(&path_slug)
+            .to_string()
+    });
+    let slug = existing_slug.unwrap_or_else(|| {
+        let raw = fm
+            .slug
+            .clone()
+            .or_else(|| fm.title.as_deref().map(slug_from_title))
+            .unwrap_or(path_slug);
+        apply_prefix(&repo_cfg.slug_prefix, &raw)
+    });
+
+    Some(IngestRequest {
+        service_token: service_token.to_string(),
+        slug,
+        title,
+        summary: fm.summary,
+        content: body.to_string(),
+        access_level: fm
+            .access_level
+            .unwrap_or_else(|| repo_cfg.default_access_level.clone()),
+        is_draft: false,
+        service_owner: fm
+            .service_owner
+            .unwrap_or_else(|| repo_cfg.default_service_owner.clone()),
+        tags: fm.tags.unwrap_or_default(),
+        parent_slug: fm
+            .parent_slug
+            .map(|p| apply_prefix(&repo_cfg.slug_prefix, &p)),
+        order: fm.order.unwrap_or(0),
+        is_hidden: fm.is_hidden.unwrap_or(false),
+        noindex: fm.noindex.unwrap_or(false),
+        edit_access_level: fm.edit_access_level,
+        relations: {
+            let prefixed = |slugs: Vec<String>| {
+                slugs
+                    .iter()
+                    .map(|s| apply_prefix(&repo_cfg.slug_prefix, s))
+                    .collect()
+            };
+            DocumentRelations {
+                see_also: prefixed(fm.relations.see_also),
+                prerequisite: prefixed(fm.relations.prerequisite),
+                supersedes: prefixed(fm.relations.supersedes),
+                superseded_by: prefixed(fm.relations.superseded_by),
+            }
+        },
+        source_path: source_path.to_string(),
+        expected_last_updated: None,
+        expected_content_hash: None,
+    })
+}
+
+/// Fetch the raw content of `path` at `git_ref` from the GitHub contents API.
+async fn fetch_file(
+    client: &reqwest::Client,
+    github: &GitHubConfig,
+    repo_full_name: &str,
+    path: &str,
+    git_ref: &str,
+) -> Result<String, AppError> {
+    let url = format!(
+        "{}/repos/{repo_full_name}/contents/{path}",
+        github.api_url.trim_end_matches('/')
+    );
+    let mut request = client
+        .get(url)
+        .query(&[("ref", git_ref)])
+        .header(reqwest::header::ACCEPT, "application/vnd.github.raw")
+        .header(reqwest::header::USER_AGENT, "lekton");
+    if !github.token.is_empty() {
+        request = request.bearer_auth(&github.token);
+    }
+    let response = request.send().await?.error_for_status()?;
+    Ok(response.text().await?)
+}
+
+/// Re-ingest upserted files and archive removed ones for a single push.
+///
+/// Errors are logged per file so one broken document does not block the rest.
+async fn sync_push(
+    state: AppState,
+    github: std::sync::Arc<GitHubConfig>,
+    repo_cfg: GitHubRepoConfig,
+    git_ref: String,
+    changes: PushChanges,
+) {
+    use crate::api::events::{ActivityEvent, ActivityKind};
+    use crate::api::ingest::{process_ingest, ApprovalGate, IngestContext};
+
+    // Protected slugs wait for approval, like any other CI ingest
+    let approval = match ApprovalGate::from_state(&state).await {
+        Ok(approval) => approval,
+        Err(e) => {
+            tracing::warn!(repo = %repo_cfg.full_name, "Failed to load protected prefixes, skipping push: {e}");
+            return;
+        }
+    };
+    let client = reqwest::Client::new();
+    let ctx = IngestContext {
+        repo: state.document_repo.as_ref(),
+        storage: state.storage_client.as_ref(),
+        search: state.search_service.as_deref(),
+        access_level_repo: state.access_level_repo.as_ref(),
+        project_repo: Some(state.project_repo.as_ref()),
+        service_token_repo: state.service_token_repo.as_ref(),
+        version_repo: state.document_version_repo.as_ref(),
+        rag: state.rag_service.as_deref(),
+        events: Some(&state.activity_events),
+        legacy_token: Some(&state.service_token),
+        dry_run: false,
+        approval,
+        quotas: Some(&state.quotas),
+        taxonomy: Some(&state.taxonomy),
+        lint: Some(&state.lint),
+        spell_checker: state.spell_checker.as_deref(),
+        secrets: state.secret_guard.as_deref(),
+        mentions: state.mentions.as_deref(),
+    };
+    let documents = match repo_documents(state.document_repo.as_ref(), &github.repos, &repo_cfg)
+        .await
+    {
+        Ok(documents) => documents,
+        Err(e) => {
+            tracing::warn!(repo = %repo_cfg.full_name, "Failed to load the repository's documents, skipping push: {e}");
+            return;
+        }
+    };
+
+    for path in &changes.upserted {
+        let Some(source_path) = strip_docs_dir(path, &repo_cfg.docs_dir) else {
+            continue;
+        };
+        let source = match fetch_file(&client, &github, &repo_cfg.full_name, path, &git_ref).await {
+            Ok(source) => source,
+            Err(e) => {
+                tracing::warn!(repo = %repo_cfg.full_name, path = %path, "Failed to fetch file from GitHub: {e}");
+                continue;
+            }
+        };
+        let existing_slug = documents.get(source_path).map(|d| d.slug.clone());
+        let Some(request) = build_ingest_request(
+            &repo_cfg,
+            source_path,
+            existing_slug,
+            &state.service_token,
+            &source,
+        ) else {
+            tracing::debug!(source_path, "Skipping file without lekton-import: true");
+            continue;
+        };
+        match process_ingest(&ctx, request).await {
+            Ok(resp) if resp.pending_approval => {
+                tracing::info!(slug = %resp.slug, "Document from GitHub push queued for admin approval")
+            }
+            Ok(resp) => {
+                tracing::info!(slug = %resp.slug, changed = resp.changed, "Re-synced document from GitHub push")
+            }
+            Err(e) => tracing::warn!(
+                source_path,
+                "Failed to ingest document from GitHub push: {e}"
+            ),
+        }
+    }
+
+    for path in &changes.removed {
+        let Some(source_path) = strip_docs_dir(path, &repo_cfg.docs_dir) else {
+            continue;
+        };
+        let Some(doc) = documents.get(source_path) else {
+            continue;
+        };
+        if let Err(e) = state.document_repo.set_archived(&doc.slug, true).await {
+            tracing::warn!(slug = %doc.slug, "Failed to archive removed document: {e}");
+            continue;
+        }
+        state.activity_events.publish(ActivityEvent::document(
+            ActivityKind::DocumentArchived,
+            doc,
+            "github",
+        ));
+        if let Some(ref search) = state.search_service {
+            if let Err(e) = search.delete_document(&doc.slug).await {
+                tracing::warn!(slug = %doc.slug, "Failed to deindex archived document from search: {e}");
+            }
+        }
+        if let Some(ref rag) = state.rag_service {
+            if let Err(e) = rag.delete_document(&doc.slug).await {
+                tracing::warn!(slug = %doc.slug, "Failed to remove archived document from RAG: {e}");
+            }
+        }
+        tracing::info!(slug = %doc.slug, "Archived document removed in GitHub push");
+    }
+}
+
+/// Axum handler for `POST /api/v1/integrations/github/webhook`.
+///
+/// Responds `202 Accepted` as soon as the push is validated; the actual
+/// re-sync runs in a background task.
+pub async fn github_webhook_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<(StatusCode, axum::Json<GitHubWebhookResponse>), AppError> {
+    let github = state
+        .github
+        .clone()
+        .ok_or_else(|| AppError::NotFound("GitHub integration is not configured".into()))?;
+
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| AppError::Auth("Missing webhook signature".into()))?;
+    if !verify_signature(&github.webhook_secret, signature, &body) {
+        return Err(AppError::Auth("Invalid webhook signature".into()));
+    }
+
+    let event = headers
+        .get(EVENT_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    match event {
+        "ping" => {
+            return Ok((
+                StatusCode::OK,
+                axum::Json(GitHubWebhookResponse::without_changes("pong")),
+            ))
+        }
+        "push" => {}
+        _ => {
+            return Ok((
+                StatusCode::ACCEPTED,
+                axum::Json(GitHubWebhookResponse::without_changes("ignored")),
+            ))
+        }
+    }
+
+    let push: PushEvent = serde_json::from_slice(&body)
+        .map_err(|e| AppError::BadRequest(format!("Invalid push payload: {e}")))?;
+
+    let repo_cfg = github
+        .repos
+        .iter()
+        .find(|r| {
+            r.full_name.eq_ignore_ascii_case(&push.repository.full_name)
+                && push.git_ref == format!("refs/heads/{}", r.branch)
+        })
+        .cloned();
+    let Some(repo_cfg) = repo_cfg.filter(|_| !push.deleted) else {
+        return Ok((
+            StatusCode::ACCEPTED,
+            axum::Json(GitHubWebhookResponse::without_changes("ignored")),
+        ));
+    };
+
+    let changes = collect_changes(&push.commits, &repo_cfg.docs_dir);
+    let response = GitHubWebhookResponse {
+        status: "accepted".to_string(),
+        to_sync: changes.upserted.len(),
+        to_archive: changes.removed.len(),
+    };
+
+    tracing::info!(
+        repo = %repo_cfg.full_name,
+        after = %push.after,
+        to_sync = response.to_sync,
+        to_archive = response.to_archive,
+        "GitHub push received"
+    );
+
+    tokio::spawn(sync_push(state, github, repo_cfg, push.after, changes));
+
+    Ok((StatusCode::ACCEPTED, axum::Json(response)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        let bytes = mac.finalize().into_bytes();
+        let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+        format!("sha256={hex}")
+    }
+
+    fn repo_cfg() -> GitHubRepoConfig {
+        GitHubRepoConfig {
+            full_name: "acme/payments".into(),
+            branch: "main".into(),
+            docs_dir: "docs".into(),
+            slug_prefix: "payments".into(),
+            default_access_level: "internal".into(),
+            default_service_owner: "payments-team".into(),
+        }
+    }
+
+    fn commit(added: &[&str], modified: &[&str], removed: &[&str]) -> PushCommit {
+        let to_vec = |v: &[&str]| v.iter().map(|s| s.to_string()).collect();
+        PushCommit {
+            added: to_vec(added),
+            modified: to_vec(modified),
+            removed: to_vec(removed),
+        }
+    }
+
+    #[test]
+    fn valid_signature_is_accepted() {
+        let body = br#"{"zen":"Keep it logically awesome."}"#;
+        let header = sign("s3cret", body);
+        assert!(verify_signature("s3cret", &header, body));
+    }
+
+    #[test]
+    fn wrong_secret_or_tampered_body_is_rejected() {
+        let body = br#"{"ref":"refs/heads/main"}"#;
+        let header = sign("s3cret", body);
+        assert!(!verify_signature("other", &header, body));
+        assert!(!verify_signature("s3cret", &header, b"{}"));
+    }
+
+    #[test]
+    fn malformed_signature_header_is_rejected() {
+        assert!(!verify_signature("s3cret", "sha1=abcd", b"{}"));
+        assert!(!verify_signature("s3cret", "sha256=zz", b"{}"));
+        assert!(!verify_signature("s3cret", "sha256=abc", b"{}"));
+    }
+
+    #[test]
+    fn collect_changes_filters_by_docs_dir_and_extension() {
+        let commits = vec![commit(
+            &["docs/intro.md", "src/lib.rs", "README.md"],
+            &["docs/guides/setup.md"],
+            &["docs/old.md"],
+        )];
+        let changes = collect_changes(&commits, "docs");
+        assert_eq!(
+            changes.upserted.into_iter().collect::<Vec<_>>(),
+            vec!["docs/guides/setup.md", "docs/intro.md"]
+        );
+        assert_eq!(
+            changes.removed.into_iter().collect::<Vec<_>>(),
+            vec!["docs/old.md"]
+        );
+    }
+
+    #[test]
+    fn collect_changes_later_commits_win() {
+        let commits = vec![
+            commit(&["docs/a.md"], &[], &["docs/b.md"]),
+            commit(&["docs/b.md"], &[], &["docs/a.md"]),
+        ];
+        let changes = collect_changes(&commits, "docs/");
+        assert!(changes.upserted.contains("docs/b.md"));
+        assert!(changes.removed.contains("docs/a.md"));
+        assert!(!changes.upserted.contains("docs/a.md"));
+    }
+
+    #[test]
+    fn build_request_uses_front_matter_and_repo_defaults() {
+        let source =
+            "---\ntitle: Getting Started\nlekton-import: true\ntags: [intro]\n---\n# Hello\n";
+        let req = build_ingest_request(&repo_cfg(), "guides/start.md", None, "tok", source)
+            .expect("file opts in");
+        assert_eq!(req.slug, "payments/getting-started");
+        assert_eq!(req.title, "Getting Started");
+        assert_eq!(req.content, "# Hello\n");
+        assert_eq!(req.access_level, "internal");
+        assert_eq!(req.service_owner, "payments-team");
+        assert_eq!(req.tags, vec!["intro"]);
+        assert_eq!(req.source_path, "guides/start.md");
+    }
+
+    #[test]
+    fn build_request_keeps_existing_slug() {
+        let source = "---\ntitle: Renamed\nlekton-import: true\n---\nbody";
+        let req = build_ingest_request(
+            &repo_cfg(),
+            "guides/start.md",
+            Some("payments/getting-started".into()),
+            "tok",
+            source,
+        )
+        .unwrap();
+        assert_eq!(req.slug, "payments/getting-started");
+    }
+
+    #[test]
+    fn build_request_skips_files_without_import_flag() {
+        let source = "---\ntitle: Internal notes\n---\nbody";
+        assert!(build_ingest_request(&repo_cfg(), "notes.md", None, "tok", source).is_none());
+        assert!(build_ingest_request(&repo_cfg(), "notes.md", None, "tok", "no fm").is_none());
+    }
+
+    fn synced_doc(slug: &str, source_path: &str) -> Document {
+        Document {
+            access_level: "internal".to_string(),
+            source_path: Some(source_path.to_string()),
+            ..testing::document(slug)
+        }
+    }
+
+    #[tokio::test]
+    async fn repo_documents_are_scoped_to_the_repository() {
+        let billing = GitHubRepoConfig {
+            full_name: "acme/billing".into(),
+            slug_prefix: "billing".into(),
+            ..repo_cfg()
+        };
+        let handbook = GitHubRepoConfig {
+            full_name: "acme/handbook".into(),
+            slug_prefix: String::new(),
+            ..repo_cfg()
+        };
+        let repos = vec![repo_cfg(), billing.clone(), handbook.clone()];
+        let document_repo = testing::InMemoryDocumentRepository::with_documents(vec![
+            synced_doc("payments/guide", "guide.md"),
+            synced_doc("billing/guide", "guide.md"),
+            synced_doc("guide", "guide.md"),
+        ]);
+
+        for (repo_cfg, slug) in [
+            (repo_cfg(), "payments/guide"),
+            (billing, "billing/guide"),
+            (handbook, "guide"),
+        ] {
+            let documents = repo_documents(&document_repo, &repos, &repo_cfg)
+                .await
+                .unwrap();
+            assert_eq!(documents.len(), 1, "{}", repo_cfg.full_name);
+            assert_eq!(documents["guide.md"].slug, slug);
+        }
+    }
+
+    #[test]
+    fn repo_namespace_requires_a_whole_prefix_segment() {
+        let repos = vec![repo_cfg()];
+        assert!(in_repo_namespace(&repos, &repo_cfg(), "payments"));
+        assert!(in_repo_namespace(
+            &repos,
+            &repo_cfg(),
+            "payments/guides/start"
+        ));
+        assert!(!in_repo_namespace(
+            &repos,
+            &repo_cfg(),
+            "payments-legacy/start"
+        ));
+        assert!(!in_repo_namespace(&repos, &repo_cfg(), "billing/start"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Grafana proxy for embedded dashboards and panels.
+//!
+//! `/grafana/render/...` serves the images Grafana's renderer produces for
+//! the dashboards allow-listed under `integrations.grafana.dashboards`, so
+//! documents can embed them without readers needing a Grafana login (see
+//! [`crate::rendering::embeds`]). Each dashboard is visible to the readers
+//! of its access level. The browser's headers and Lekton's cookies are never
+//! forwarded; every request carries the read-only service account token
+//! instead.
+//!
+//! Only images come back: Grafana's HTML and scripts are never served from
+//! the portal's origin, and no other Grafana endpoint (`api/ds/query`, the
+//! dashboard API, ...) is reachable, so readers can only render the queries
+//! saved in the allow-listed dashboards.
+
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::extract::{Path, RawQuery, State};
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use crate::app::AppState;
+use crate::auth::extractor::RequiredAuthUser;
+use crate::error::AppError;
+use crate::rendering::embeds::GrafanaRender;
+
+/// `Content-Security-Policy` of the served images, in case a browser is
+/// made to open one as a document.
+const IMAGE_CSP: &str = "default-src 'none'; sandbox";
+
+fn bad_gateway(detail: String) -> Response {
+    crate::api::errors::Problem::new(
+        StatusCode::BAD_GATEWAY.as_u16(),
+        "upstream_error",
+        "Bad gateway",
+        detail,
+    )
+    .into_response()
+}
+
+/// Axum handler for `GET /grafana/render/{*path}`.
+///
+/// Returns `404` when the proxy is disabled or the path is not a dashboard
+/// or panel, `403` for dashboards outside the allow-list or above the
+/// reader's access level, and `502` when Grafana cannot be reached or does
+/// not answer with an image.
+pub async fn grafana_render_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Path(path): Path<String>,
+    RawQuery(query): RawQuery,
+) -> Result<Response, AppError> {
+    let config = state
+        .grafana
+        .clone()
+        .ok_or_else(|| AppError::NotFound("Grafana proxy is not configured".into()))?;
+
+    let render = GrafanaRender::parse(&path, query.as_deref())
+        .ok_or_else(|| AppError::NotFound("Not a Grafana dashboard or panel".into()))?;
+    let dashboard = config.dashboard(&render.uid).ok_or_else(|| {
+        AppError::Forbidden(format!(
+            "Grafana dashboard '{}' is not embeddable",
+            render.uid
+        ))
+    })?;
+    let user_ctx = crate::api::rag::build_user_context(&state, &user).await?;
+    if !user_ctx.can_read(&dashboard.access_level) {
+        return Err(AppError::Forbidden(
+            crate::auth::models::access_denied_message(&dashboard.access_level),
+        ));
+    }
+
+    let target = format!(
+        "{}/render/{}?{}",
+        config.url.trim_end_matches('/'),
+        render.path,
+        render.query
+    );
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(Duration::from_secs(config.timeout_secs))
+        .build()?;
+
+    tracing::debug!(user = %user.email, uid = %render.uid, "Rendering Grafana image");
+
+    let upstream = match client
+        .get(target)
+        .bearer_auth(&config.token)
+        .header(header::ACCEPT, "image/png")
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!("Grafana render request failed: {e}");
+            return Ok(bad_gateway(format!("Grafana request failed: {e}")));
+        }
+    };
+
+    let status = upstream.status();
+    let content_type = upstream
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .filter(|value| value.as_bytes().starts_with(b"image/"))
+        .cloned();
+    let Some(content_type) = content_type.filter(|_| status.is_success()) else {
+        tracing::warn!(uid = %render.uid, status = %status, "Grafana did not return an image");
+        return Ok(bad_gateway(format!(
+            "Grafana did not return an image (status {status})"
+        )));
+    };
+    let body = upstream.bytes().await.map_err(|e| {
+        tracing::warn!("Failed to read Grafana response: {e}");
+        AppError::Internal(format!("Failed to read Grafana response: {e}"))
+    })?;
+
+    let mut response = Response::new(Body::from(body));
+    let headers = response.headers_mut();
+    headers.insert(header::CONTENT_TYPE, content_type);
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static("private, max-age=60"),
+    );
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    headers.insert(
+        header::CONTENT_SECURITY_POLICY,
+        HeaderValue::from_static(IMAGE_CSP),
+    );
+    Ok(response)
+}
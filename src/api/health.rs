@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
+
 use axum::{extract::State, http::StatusCode, Json};
 use serde::Serialize;
 
 use crate::app::AppState;
+use crate::resilience::CircuitState;
 
 #[derive(Serialize)]
 pub struct HealthResponse {
@@ -14,6 +17,9 @@ pub struct ReadyResponse {
     pub mongo: &'static str,
     pub rag: &'static str,
     pub search: &'static str,
+    /// Circuit breaker state of `mongo`, `storage` and, when enabled,
+    /// `search` (`closed`, `open`, `half_open`).
+    pub circuits: BTreeMap<&'static str, CircuitState>,
 }
 
 pub async fn liveness_handler() -> (StatusCode, Json<HealthResponse>) {
@@ -38,7 +44,20 @@ pub async fn readiness_handler(State(state): State<AppState>) -> (StatusCode, Js
         "disabled"
     };
 
-    let status = if mongo == "ok" { "ok" } else { "degraded" };
+    let circuits: BTreeMap<&'static str, CircuitState> = state
+        .circuit_breakers
+        .iter()
+        .map(|breaker| (breaker.name(), breaker.state()))
+        .collect();
+    let circuit_open = circuits.values().any(|s| *s != CircuitState::Closed);
+
+    // An open circuit degrades the instance but does not take it out of
+    // rotation: the dependency is shared, so other instances see it too.
+    let status = if mongo == "ok" && !circuit_open {
+        "ok"
+    } else {
+        "degraded"
+    };
     let code = if mongo == "ok" {
         StatusCode::OK
     } else {
@@ -52,6 +71,7 @@ pub async fn readiness_handler(State(state): State<AppState>) -> (StatusCode, Js
             mongo,
             rag,
             search,
+            circuits,
         }),
     )
 }
@@ -0,0 +1,198 @@
+//! Report and delete images no document embeds any more.
+//!
+//! Images are the files uploaded through `POST /api/v1/upload-image`
+//! (stored under `images/`) and image assets (`image/*` content type, e.g.
+//! pasted in the editor). An image is orphaned when no document, archived
+//! ones included, lists it in its `asset_refs`. Images uploaded in the last
+//! `older_than_hours` (default [`DEFAULT_GRACE_HOURS`]) are not reported,
+//! since the editor uploads them before the document is saved.
+//!
+//! All routes require an authenticated admin user (`is_admin = true`).
+//!
+//! | Method | Path                              | Description                                        |
+//! |--------|-----------------------------------|----------------------------------------------------|
+//! | GET    | `/api/v1/admin/images/orphaned`   | Orphaned images (`?older_than_hours=<n>`), after refreshing document references |
+//! | DELETE | `/api/v1/admin/images/orphaned`   | Delete the given images if they are still orphaned |
+
+use std::collections::HashSet;
+
+use axum::extract::{Query, State};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::auth::extractor::RequiredAuthUser;
+use crate::error::AppError;
+
+/// Minimum age of reported images when `older_than_hours` is not given.
+pub const DEFAULT_GRACE_HOURS: i64 = 24;
+
+fn default_grace_hours() -> i64 {
+    DEFAULT_GRACE_HOURS
+}
+
+/// An image no document embeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanedImage {
+    /// Storage key (`images/<file>` or `assets/<key>`).
+    pub s3_key: String,
+    /// Where the image is served.
+    pub url: String,
+    pub size_bytes: u64,
+    pub uploaded_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrphanedImagesReport {
+    pub images: Vec<OrphanedImage>,
+    /// Documents whose stored references were out of date and got refreshed.
+    pub refreshed_documents: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OrphanedImagesQuery {
+    #[serde(default = "default_grace_hours")]
+    pub older_than_hours: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteOrphanedImagesRequest {
+    /// Storage keys as listed in the report.
+    pub keys: Vec<String>,
+    /// Same as in the report; younger images are kept.
+    #[serde(default = "default_grace_hours")]
+    pub older_than_hours: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteOrphanedImagesResult {
+    pub deleted: Vec<String>,
+    /// Requested keys that are referenced again, too recent or unknown.
+    pub kept: Vec<String>,
+}
+
+/// Images uploaded more than `older_than_hours` ago that no document
+/// references, sorted by storage key.
+pub async fn find_orphaned_images(
+    state: &AppState,
+    older_than_hours: i64,
+) -> Result<Vec<OrphanedImage>, AppError> {
+    let referenced: HashSet<String> = state
+        .document_repo
+        .list_all()
+        .await?
+        .into_iter()
+        .flat_map(|doc| doc.asset_refs)
+        .collect();
+    let cutoff = Utc::now() - chrono::Duration::hours(older_than_hours.max(0));
+
+    let mut images: Vec<OrphanedImage> = state
+        .storage_client
+        .list_objects("images/")
+        .await?
+        .into_iter()
+        .map(|object| OrphanedImage {
+            url: format!("/api/v1/image/{}", object.key.trim_start_matches("images/")),
+            s3_key: object.key,
+            size_bytes: object.size_bytes,
+            uploaded_at: object.last_modified,
+        })
+        .collect();
+    images.extend(
+        state
+            .asset_repo
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|asset| asset.content_type.starts_with("image/"))
+            .map(|asset| OrphanedImage {
+                url: format!("/api/v1/assets/{}", asset.key),
+                s3_key: asset.s3_key,
+                size_bytes: asset.size_bytes,
+                uploaded_at: Some(asset.uploaded_at),
+            }),
+    );
+    images.retain(|image| {
+        !referenced.contains(&image.s3_key)
+            && image.uploaded_at.is_none_or(|uploaded| uploaded < cutoff)
+    });
+    images.sort_by(|a, b| a.s3_key.cmp(&b.s3_key));
+    Ok(images)
+}
+
+/// Delete the images among `keys` that are still orphaned.
+pub async fn delete_orphaned_images(
+    state: &AppState,
+    keys: &[String],
+    older_than_hours: i64,
+) -> Result<DeleteOrphanedImagesResult, AppError> {
+    let orphaned: HashSet<String> = find_orphaned_images(state, older_than_hours)
+        .await?
+        .into_iter()
+        .map(|image| image.s3_key)
+        .collect();
+
+    let mut result = DeleteOrphanedImagesResult {
+        deleted: Vec::new(),
+        kept: Vec::new(),
+    };
+    for key in keys {
+        if !orphaned.contains(key) || result.deleted.contains(key) {
+            result.kept.push(key.clone());
+            continue;
+        }
+        state.storage_client.delete_object(key).await?;
+        if let Some(asset_key) = key.strip_prefix("assets/") {
+            state.asset_repo.delete(asset_key).await?;
+        }
+        result.deleted.push(key.clone());
+    }
+    Ok(result)
+}
+
+fn require_admin(user: &crate::auth::models::AuthenticatedUser) -> Result<(), AppError> {
+    if user.is_admin {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden("Admin privileges required".into()))
+    }
+}
+
+/// `GET /api/v1/admin/images/orphaned`
+pub async fn list_orphaned_images_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Query(query): Query<OrphanedImagesQuery>,
+) -> Result<Json<OrphanedImagesReport>, AppError> {
+    require_admin(&user)?;
+
+    // Documents saved before references were tracked count too
+    let refreshed_documents = crate::api::asset_access::backfill_asset_refs(
+        state.document_repo.as_ref(),
+        state.storage_client.as_ref(),
+    )
+    .await?;
+    let images = find_orphaned_images(&state, query.older_than_hours).await?;
+    Ok(Json(OrphanedImagesReport {
+        images,
+        refreshed_documents,
+    }))
+}
+
+/// `DELETE /api/v1/admin/images/orphaned`
+pub async fn delete_orphaned_images_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Json(request): Json<DeleteOrphanedImagesRequest>,
+) -> Result<Json<DeleteOrphanedImagesResult>, AppError> {
+    require_admin(&user)?;
+
+    let result = delete_orphaned_images(&state, &request.keys, request.older_than_hours).await?;
+    tracing::info!(
+        actor = %user.email,
+        deleted = result.deleted.len(),
+        "Deleted orphaned images"
+    );
+    Ok(Json(result))
+}
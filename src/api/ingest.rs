@@ -3,12 +3,20 @@ use crate::db::models::{IngestRequest, IngestResponse};
 #[cfg(feature = "ssr")]
 use crate::error::AppError;
 
+#[cfg(feature = "ssr")]
+use crate::api::events::{ActivityEvent, ActivityEvents, ActivityKind};
+#[cfg(feature = "ssr")]
+use crate::config::{LintConfig, QuotaConfig, TaxonomyConfig};
 #[cfg(feature = "ssr")]
 use crate::db::access_level_repository::AccessLevelRepository;
 #[cfg(feature = "ssr")]
 use crate::db::document_version_repository::DocumentVersionRepository;
 #[cfg(feature = "ssr")]
-use crate::db::models::Document;
+use crate::db::models::{merge_contributors, Document, DocumentRelations};
+#[cfg(feature = "ssr")]
+use crate::db::pending_ingest_repository::{PendingIngest, PendingIngestRepository};
+#[cfg(feature = "ssr")]
+use crate::db::project_repository::ProjectRepository;
 #[cfg(feature = "ssr")]
 use crate::db::repository::DocumentRepository;
 #[cfg(feature = "ssr")]
@@ -18,6 +26,8 @@ use crate::rag::service::RagService;
 #[cfg(feature = "ssr")]
 use crate::rendering::links::extract_internal_links;
 #[cfg(feature = "ssr")]
+use crate::rendering::mentions::extract_mentions;
+#[cfg(feature = "ssr")]
 use crate::search::client::SearchService;
 #[cfg(feature = "ssr")]
 use crate::storage::client::StorageClient;
@@ -28,6 +38,9 @@ use chrono::Utc;
 pub const SUMMARY_RECOMMENDED_MIN_CHARS: usize = 50;
 #[cfg(feature = "ssr")]
 pub const SUMMARY_RECOMMENDED_MAX_CHARS: usize = 200;
+/// Maximum length of `Document::preview`, sized for page meta descriptions.
+#[cfg(feature = "ssr")]
+const PREVIEW_MAX_CHARS: usize = 160;
 
 /// Bundles the service references needed by [`process_ingest`].
 #[cfg(feature = "ssr")]
@@ -36,17 +49,74 @@ pub struct IngestContext<'a> {
     pub storage: &'a dyn StorageClient,
     pub search: Option<&'a dyn SearchService>,
     pub access_level_repo: &'a dyn AccessLevelRepository,
+    /// Projects, whose allowed access levels restrict documents in their namespace.
+    pub project_repo: Option<&'a dyn ProjectRepository>,
     pub service_token_repo: &'a dyn ServiceTokenRepository,
     pub version_repo: &'a dyn DocumentVersionRepository,
     pub rag: Option<&'a dyn RagService>,
+    /// Activity stream notifications (also drives live reload of open pages).
+    pub events: Option<&'a ActivityEvents>,
     /// The legacy global token from the `SERVICE_TOKEN` env var (if set).
     pub legacy_token: Option<&'a str>,
+    /// Validate and report what would change without writing anything.
+    pub dry_run: bool,
+    /// Hold ingests to protected slugs for admin approval instead of
+    /// publishing them. `None` publishes everything directly.
+    pub approval: Option<ApprovalGate<'a>>,
+    /// Per-service-owner limits; `None` skips quota enforcement.
+    pub quotas: Option<&'a QuotaConfig>,
+    /// Tag and service owner normalization; `None` stores them as sent.
+    pub taxonomy: Option<&'a TaxonomyConfig>,
+    /// Markdown style checks; `None` skips linting.
+    pub lint: Option<&'a LintConfig>,
+    /// Reports misspellings on dry runs; `None` skips spell checking.
+    pub spell_checker: Option<&'a crate::spellcheck::SpellChecker>,
+    /// Refuses content containing credentials; `None` skips the check.
+    pub secrets: Option<&'a crate::secrets::SecretGuard>,
+    /// Notifies people `@mentioned` in new content; `None` skips mentions.
+    pub mentions: Option<&'a crate::mentions::MentionNotifier>,
+}
+
+/// Protected slug prefixes and the queue their ingests are held in.
+#[cfg(feature = "ssr")]
+pub struct ApprovalGate<'a> {
+    pub protected_prefixes: Vec<String>,
+    pub pending_repo: &'a dyn PendingIngestRepository,
+    /// Emails the approval recipients about each queued ingest.
+    pub notifier: Option<std::sync::Arc<crate::email::EmailNotifier>>,
+    /// Asks the admins to review each queued ingest in their inbox.
+    pub inbox: Option<std::sync::Arc<crate::notifications::Inbox>>,
+}
+
+#[cfg(feature = "ssr")]
+impl<'a> ApprovalGate<'a> {
+    /// Gate configured from the instance settings, or `None` when no prefix
+    /// is protected.
+    pub async fn from_state(state: &'a crate::app::AppState) -> Result<Option<Self>, AppError> {
+        let protected_prefixes = state.settings_repo.get_settings().await?.protected_prefixes;
+        Ok((!protected_prefixes.is_empty()).then(|| Self {
+            protected_prefixes,
+            pending_repo: state.pending_ingest_repo.as_ref(),
+            notifier: state.email.clone(),
+            inbox: Some(state.inbox.clone()),
+        }))
+    }
+}
+
+/// Query parameters accepted by the ingest endpoints.
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct IngestQuery {
+    /// `?dry_run=true`: validate and report without writing.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 /// Core ingestion logic — separated from the HTTP layer for testability.
 ///
 /// Validates the request, uploads content to S3, upserts metadata in MongoDB,
-/// and optionally indexes the document in Meilisearch.
+/// and optionally indexes the document in Meilisearch. With
+/// [`IngestContext::dry_run`] set, returns after validation and change
+/// detection without writing anything.
 #[cfg(feature = "ssr")]
 pub async fn process_ingest(
     ctx: &IngestContext<'_>,
@@ -55,6 +125,40 @@ pub async fn process_ingest(
     // 1. Validate the service token (legacy or scoped)
     validate_token(ctx, &request.service_token, &request.slug).await?;
 
+    ingest_document(ctx, request, None).await
+}
+
+/// Publish a pending ingest approved by an admin: runs the stored request
+/// without the approval gate, attributed to the token that submitted it, and
+/// removes it from the queue.
+#[cfg(feature = "ssr")]
+pub async fn publish_pending(
+    ctx: &IngestContext<'_>,
+    pending_repo: &dyn PendingIngestRepository,
+    id: &str,
+) -> Result<IngestResponse, AppError> {
+    let pending = pending_repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Pending ingest '{id}' not found")))?;
+    let ctx = IngestContext {
+        approval: None,
+        dry_run: false,
+        ..*ctx
+    };
+    let response = ingest_document(&ctx, pending.request, Some(pending.submitted_by)).await?;
+    pending_repo.delete(id).await?;
+    Ok(response)
+}
+
+/// Everything in [`process_ingest`] after token validation. `author` is
+/// recorded as the updater; `None` resolves it from the request's token.
+#[cfg(feature = "ssr")]
+async fn ingest_document(
+    ctx: &IngestContext<'_>,
+    request: IngestRequest,
+    author: Option<String>,
+) -> Result<IngestResponse, AppError> {
     // 2. Validate the slug
     if request.slug.is_empty() {
         return Err(AppError::BadRequest("Slug cannot be empty".into()));
@@ -79,12 +183,77 @@ pub async fn process_ingest(
             "Unknown access level: '{access_level}'"
         )));
     }
+    let edit_access_level = match request.edit_access_level.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some(level) => {
+            let level = level.to_lowercase();
+            if !ctx.access_level_repo.exists(&level).await? {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown edit access level: '{level}'"
+                )));
+            }
+            Some(level)
+        }
+    };
+    if let Some(project_repo) = ctx.project_repo {
+        crate::db::project_repository::check_project_access_level(
+            project_repo,
+            &request.slug,
+            &access_level,
+        )
+        .await?;
+    }
+
+    // Normalize tags and owner. The metadata hash below stays on the values
+    // as sent, which is what sync clients hash.
+    let (tags, service_owner) = match ctx.taxonomy {
+        Some(taxonomy) => (
+            taxonomy.normalize_tags(&request.tags).map_err(|tag| {
+                AppError::BadRequest(format!("Tag '{tag}' is not in the allowed tags"))
+            })?,
+            taxonomy
+                .normalize_owner(&request.service_owner)
+                .map_err(|owner| {
+                    AppError::BadRequest(format!(
+                        "Service owner '{owner}' is not in the allowed owners"
+                    ))
+                })?,
+        ),
+        None => (request.tags.clone(), request.service_owner.clone()),
+    };
+
+    let relations = request.relations.normalized(&request.slug);
+
+    // Refuse credentials before anything is stored (dry runs report it too)
+    if let Some(guard) = ctx.secrets {
+        let submitted_by = match &author {
+            Some(author) => author.clone(),
+            None => {
+                resolve_token_name(
+                    ctx.service_token_repo,
+                    ctx.legacy_token,
+                    &request.service_token,
+                )
+                .await
+            }
+        };
+        guard
+            .screen(
+                crate::secrets::Submission {
+                    slug: &request.slug,
+                    title: &request.title,
+                    content: &request.content,
+                    content_type: "text/markdown",
+                    submitted_by: &submitted_by,
+                },
+                ctx.dry_run,
+            )
+            .await?;
+    }
 
     // 4. Compute content hash (used for S3 upload decision)
-    let new_hash = format!(
-        "sha256:{}",
-        crate::auth::token_service::TokenService::hash_token(&request.content)
-    );
+    let new_hash = content_hash(&request.content);
+    let content_size = request.content.len() as u64;
 
     // Compute metadata hash (sent by CLI alongside content_hash; stored separately
     // so that metadata-only changes can be detected during sync without requiring
@@ -98,32 +267,33 @@ pub async fn process_ingest(
         parent_slug: request.parent_slug.as_deref(),
         order: request.order,
         is_hidden: request.is_hidden,
+        noindex: request.noindex,
+        edit_access_level: edit_access_level.as_deref(),
+        relations: &relations,
     });
 
     // 5. Extract internal links from content
-    let links_out = extract_internal_links(&request.content);
+    let links_out = ContentFormat::Markdown.links(&request.content);
 
-    // 6. Get old document to diff backlinks and detect changes
+    // 6. Get old document to detect changes
     let old_doc = ctx.repo.find_by_slug(&request.slug).await?;
-
-    let (old_links, old_backlinks, old_parent_slug, old_order, old_is_hidden, old_hash) =
-        match &old_doc {
-            Some(d) => (
-                d.links_out.clone(),
-                d.backlinks.clone(),
-                d.parent_slug.clone(),
-                d.order,
-                d.is_hidden,
-                d.content_hash.clone(),
-            ),
-            None => (vec![], vec![], None, 0, false, None),
-        };
+    check_preconditions(&request, old_doc.as_ref())?;
+
+    let (old_parent_slug, old_order, old_is_hidden, old_hash) = match &old_doc {
+        Some(d) => (
+            d.parent_slug.clone(),
+            d.order,
+            d.is_hidden,
+            d.content_hash.clone(),
+        ),
+        None => (None, 0, false, None),
+    };
 
     let source_path_changed = old_doc
         .as_ref()
         .is_none_or(|d| d.source_path.as_deref() != Some(&request.source_path));
 
-    let content_changed = old_hash.as_deref() != Some(&new_hash);
+    let content_changed = old_hash.as_deref() != Some(new_hash.as_str());
 
     // Determine effective metadata values
     let effective_parent_slug = if request.parent_slug.is_some() {
@@ -142,66 +312,397 @@ pub async fn process_ingest(
         old_is_hidden
     };
 
-    // Check if metadata changed (compared to existing doc)
-    let metadata_changed = old_doc.as_ref().is_none_or(|d| {
-        d.title != request.title
-            || d.summary != summary
-            || d.access_level != access_level
-            || d.is_draft != request.is_draft
-            || d.service_owner != request.service_owner
-            || d.tags != request.tags
-            || d.parent_slug != effective_parent_slug
-            || d.order != effective_order
-            || d.is_hidden != effective_is_hidden
-            || d.links_out != links_out
+    // List what changed compared to the existing doc
+    let changes: Vec<String> = match &old_doc {
+        None => vec!["created".to_string()],
+        Some(d) => [
+            ("content", content_changed),
+            ("title", d.title != request.title),
+            ("summary", d.summary != summary),
+            ("access_level", d.access_level != access_level),
+            ("is_draft", d.is_draft != request.is_draft),
+            ("service_owner", d.service_owner != service_owner),
+            ("tags", d.tags != tags),
+            ("parent_slug", d.parent_slug != effective_parent_slug),
+            ("order", d.order != effective_order),
+            ("is_hidden", d.is_hidden != effective_is_hidden),
+            ("noindex", d.noindex != request.noindex),
+            (
+                "edit_access_level",
+                d.edit_access_level != edit_access_level,
+            ),
+            ("relations", d.relations != relations),
+            ("links", d.links_out != links_out),
+            ("source_path", source_path_changed),
+        ]
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(field, _)| field.to_string())
+        .collect(),
+    };
+
+    let needs_approval = ctx.approval.as_ref().is_some_and(|gate| {
+        crate::db::pending_ingest_repository::is_protected(&gate.protected_prefixes, &request.slug)
     });
 
+    // Style findings are reported, never enforced
+    let lint = ctx
+        .lint
+        .and_then(|config| config.options_for(&request.slug))
+        .map(|options| crate::rendering::lint::lint_markdown(&request.content, &options))
+        .unwrap_or_default();
+
+    // Dry runs also report broken internal links, which a real ingest accepts
+    let warnings = if ctx.dry_run {
+        let mut warnings: Vec<String> =
+            crate::rendering::links::validate_links(&request.content, ctx.repo)
+                .await?
+                .broken_links
+                .into_iter()
+                .map(|link| format!("Broken internal link: /docs/{link}"))
+                .collect();
+        if needs_approval {
+            warnings.push(format!(
+                "'{}' is protected: publication requires admin approval",
+                request.slug
+            ));
+        }
+        warnings
+    } else {
+        vec![]
+    };
+    let misspellings = match ctx.spell_checker {
+        Some(checker) if ctx.dry_run => checker.check_markdown(&request.content).await?,
+        _ => vec![],
+    };
+
     // If nothing changed, return early
-    if !content_changed && !metadata_changed && !source_path_changed {
-        let s3_key = format!("docs/{}.md", request.slug.replace('/', "_"));
+    if changes.is_empty() {
+        let s3_key = match &old_doc {
+            Some(doc) => doc.s3_key.clone(),
+            None => ctx.storage.key_layout().document_key(&request.slug),
+        };
         return Ok(IngestResponse {
             message: "Document unchanged".to_string(),
             slug: request.slug,
             s3_key,
             changed: false,
+            dry_run: ctx.dry_run,
+            pending_approval: false,
+            changes,
+            warnings,
+            lint,
+            misspellings,
+            last_updated: old_doc.as_ref().map(|d| d.last_updated),
+            content_hash: old_hash,
         });
     }
 
-    // 7. Build the S3 key
-    let s3_key = format!("docs/{}.md", request.slug.replace('/', "_"));
+    // Enforce the service owner's quota (dry runs report it too)
+    if let Some(quotas) = ctx.quotas {
+        check_quota(
+            ctx.repo,
+            quotas,
+            &service_owner,
+            old_doc.as_ref(),
+            content_size,
+        )
+        .await?;
+    }
+
+    if ctx.dry_run {
+        let s3_key = ctx.storage.key_layout().document_key(&request.slug);
+        let action = if old_doc.is_some() {
+            "updated"
+        } else {
+            "created"
+        };
+        return Ok(IngestResponse {
+            message: format!("Dry run: document would be {action}"),
+            slug: request.slug,
+            s3_key,
+            changed: true,
+            dry_run: true,
+            pending_approval: false,
+            changes,
+            warnings,
+            lint,
+            misspellings,
+            last_updated: old_doc.as_ref().map(|d| d.last_updated),
+            content_hash: old_hash,
+        });
+    }
+
+    // Determine who is updating (token name or "legacy")
+    let updated_by = match author {
+        Some(author) => author,
+        None => {
+            resolve_token_name(
+                ctx.service_token_repo,
+                ctx.legacy_token,
+                &request.service_token,
+            )
+            .await
+        }
+    };
+
+    // Protected slugs are queued for approval instead of being published
+    if let Some(gate) = ctx.approval.as_ref().filter(|_| needs_approval) {
+        let slug = request.slug.clone();
+        let s3_key = ctx.storage.key_layout().document_key(&slug);
+        let pending = PendingIngest {
+            id: uuid::Uuid::new_v4().to_string(),
+            slug: slug.clone(),
+            request: IngestRequest {
+                service_token: String::new(),
+                ..request
+            },
+            submitted_by: updated_by,
+            changes: changes.clone(),
+            submitted_at: Utc::now(),
+        };
+        let notifier = gate.notifier.clone();
+        let inbox = gate.inbox.clone();
+        gate.pending_repo.submit(pending.clone()).await?;
+        if notifier.is_some() || inbox.is_some() {
+            tokio::spawn(async move {
+                if let Some(notifier) = notifier {
+                    notifier.notify_pending_approval(&pending).await;
+                }
+                if let Some(inbox) = inbox {
+                    inbox.request_approval(&pending).await;
+                }
+            });
+        }
+        return Ok(IngestResponse {
+            message: "Document queued for admin approval".to_string(),
+            slug,
+            s3_key,
+            changed: false,
+            dry_run: false,
+            pending_approval: true,
+            changes,
+            warnings,
+            lint,
+            misspellings,
+            last_updated: old_doc.as_ref().map(|d| d.last_updated),
+            content_hash: old_hash,
+        });
+    }
+
+    // 7. Store the revision
+    let saved = save_document(
+        ctx,
+        old_doc,
+        DocumentRevision {
+            slug: request.slug,
+            title: request.title,
+            summary,
+            access_level,
+            is_draft: request.is_draft,
+            service_owner,
+            tags,
+            parent_slug: effective_parent_slug,
+            order: effective_order,
+            is_hidden: effective_is_hidden,
+            noindex: request.noindex,
+            metadata_hash: Some(new_metadata_hash),
+            source_path: Some(request.source_path),
+            edit_access_level,
+            relations,
+            content: request.content,
+            format: ContentFormat::Markdown,
+            lint_findings: lint.clone(),
+        },
+        updated_by,
+    )
+    .await?;
+
+    Ok(IngestResponse {
+        message: "Document ingested successfully".to_string(),
+        slug: saved.slug,
+        s3_key: saved.s3_key,
+        changed: true,
+        dry_run: false,
+        pending_approval: false,
+        changes,
+        warnings,
+        lint,
+        misspellings,
+        last_updated: Some(saved.last_updated),
+        content_hash: saved.content_hash,
+    })
+}
+
+/// Format of stored document content.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ContentFormat {
+    /// Markdown, as published through the ingest API.
+    Markdown,
+    /// HTML saved by the web editor.
+    Html,
+}
+
+#[cfg(feature = "ssr")]
+impl ContentFormat {
+    /// Slugs of the internal links in `content`.
+    pub(crate) fn links(self, content: &str) -> Vec<String> {
+        match self {
+            Self::Markdown => extract_internal_links(content),
+            Self::Html => crate::rendering::links::extract_internal_links_from_html(content),
+        }
+    }
+
+    /// Plain-text opening of `content`, stored as `Document::preview`.
+    pub(crate) fn preview(self, content: &str) -> String {
+        let text = match self {
+            Self::Markdown => {
+                crate::search::client::strip_markdown_for_preview(content, PREVIEW_MAX_CHARS)
+            }
+            Self::Html => {
+                let mut in_tag = false;
+                let text: String = content
+                    .chars()
+                    .map(|ch| match ch {
+                        '<' => {
+                            in_tag = true;
+                            ' '
+                        }
+                        '>' => {
+                            in_tag = false;
+                            ' '
+                        }
+                        _ if in_tag => ' ',
+                        _ => ch,
+                    })
+                    .collect();
+                let mut text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                text.truncate(text.floor_char_boundary(PREVIEW_MAX_CHARS));
+                text
+            }
+        };
+        text.trim().to_string()
+    }
+
+    fn mentions(self, content: &str) -> Vec<String> {
+        match self {
+            Self::Markdown => extract_mentions(content),
+            Self::Html => crate::rendering::mentions::extract_mentions_from_html(content),
+        }
+    }
+}
+
+/// Hash of document content, as stored in `Document::content_hash`.
+#[cfg(feature = "ssr")]
+pub(crate) fn content_hash(content: &str) -> String {
+    format!(
+        "sha256:{}",
+        crate::auth::token_service::TokenService::hash_token(content)
+    )
+}
+
+/// A new revision of a document, as stored by [`save_document`]. Fields
+/// derived from the content, the previous revision or the author are
+/// filled in by the save.
+#[cfg(feature = "ssr")]
+pub(crate) struct DocumentRevision {
+    pub slug: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub access_level: String,
+    pub is_draft: bool,
+    pub service_owner: String,
+    pub tags: Vec<String>,
+    pub parent_slug: Option<String>,
+    pub order: u32,
+    pub is_hidden: bool,
+    pub noindex: bool,
+    pub metadata_hash: Option<String>,
+    pub source_path: Option<String>,
+    pub edit_access_level: Option<String>,
+    pub relations: DocumentRelations,
+    pub content: String,
+    pub format: ContentFormat,
+    /// Style findings of `content`, recorded on the document.
+    pub lint_findings: Vec<crate::rendering::lint::LintFinding>,
+}
 
-    // Keep raw content for search indexing
-    let raw_content = request.content.clone();
+/// Store a validated, authorized revision of a document and return it.
+///
+/// The single save pipeline of the ingest API and the web editor: archives
+/// the previous content in the version history, uploads the new content,
+/// upserts the metadata and backlinks, indexes the document for search and
+/// RAG, and notifies the activity stream and newly mentioned people.
+#[cfg(feature = "ssr")]
+pub(crate) async fn save_document(
+    ctx: &IngestContext<'_>,
+    old_doc: Option<Document>,
+    revision: DocumentRevision,
+    updated_by: String,
+) -> Result<Document, AppError> {
+    let DocumentRevision {
+        slug,
+        content,
+        format,
+        ..
+    } = &revision;
+    let new_hash = content_hash(content);
+    let links_out = format.links(content);
+    let content_changed =
+        old_doc.as_ref().and_then(|d| d.content_hash.as_deref()) != Some(new_hash.as_str());
+
+    // 1. Build the S3 key. Documents stored under another key (written with
+    //    a different `storage.key_layout`) are rewritten under the new one;
+    //    the old object is deleted once no colliding slug still uses it
+    let s3_key = ctx.storage.key_layout().document_key(slug);
+    let moved_from = old_doc
+        .as_ref()
+        .map(|d| d.s3_key.clone())
+        .filter(|old_key| *old_key != s3_key);
+
+    // Previous content, archived in the version history and compared for
+    // new mentions
+    let old_content = match &old_doc {
+        Some(old) if content_changed && (old.content_hash.is_some() || ctx.mentions.is_some()) => {
+            ctx.storage.get_object(&old.s3_key).await.ok().flatten()
+        }
+        _ => None,
+    };
+    let mentions = if ctx.mentions.is_some() && content_changed {
+        let previous = old_content
+            .as_deref()
+            .map(|old| format.mentions(&String::from_utf8_lossy(old)))
+            .unwrap_or_default();
+        crate::mentions::new_mentions(&previous, format.mentions(content))
+    } else {
+        vec![]
+    };
 
-    // 8. Create version history before overwriting (only when content changed and old doc exists)
+    // 2. Create version history before overwriting (only when content changed and old doc exists)
     if content_changed {
         if let Some(ref old) = old_doc {
             if let Some(ref old_content_hash) = old.content_hash {
-                // Copy old content to history
-                let version_num = ctx.version_repo.next_version_number(&request.slug).await?;
-                let history_key = format!(
-                    "docs/history/{}/{}.md",
-                    request.slug.replace('/', "_"),
-                    version_num
-                );
+                let version_num = ctx.version_repo.next_version_number(slug).await?;
+                let history_key = ctx
+                    .storage
+                    .key_layout()
+                    .document_version_key(slug, version_num);
 
-                // Read old content from S3 and copy to history
-                if let Ok(Some(old_content)) = ctx.storage.get_object(&old.s3_key).await {
+                // Copy old content to history
+                if let Some(old_content) = old_content {
                     if let Err(e) = ctx.storage.put_object(&history_key, old_content).await {
                         tracing::warn!("Failed to archive old version to S3: {e}");
                     }
                 }
 
-                // Determine who is updating (token name or "legacy")
-                let updated_by = resolve_token_name(ctx, &request.service_token).await;
-
                 let version = crate::db::document_version_repository::DocumentVersion {
                     id: uuid::Uuid::new_v4().to_string(),
-                    slug: request.slug.clone(),
+                    slug: slug.clone(),
                     version: version_num,
                     content_hash: old_content_hash.clone(),
                     s3_key: history_key,
-                    updated_by,
+                    updated_by: updated_by.clone(),
                     created_at: Utc::now(),
                 };
 
@@ -210,95 +711,236 @@ pub async fn process_ingest(
                 }
             }
         }
+    }
 
-        // 9. Upload new content to S3
+    // 3. Upload new content to S3
+    if content_changed || moved_from.is_some() {
         ctx.storage
-            .put_object(&s3_key, request.content.into_bytes())
+            .put_object(&s3_key, content.as_bytes().to_vec())
             .await?;
     }
 
-    // 9. Upsert document metadata in MongoDB
+    // 4. Upsert document metadata in MongoDB
+    let (old_links, backlinks, old_contributors) = match &old_doc {
+        Some(d) => (
+            d.links_out.clone(),
+            d.backlinks.clone(),
+            d.contributors.clone(),
+        ),
+        None => (vec![], vec![], vec![]),
+    };
+    let content_size = revision.content.len() as u64;
     let doc = Document {
-        slug: request.slug.clone(),
-        title: request.title,
-        summary,
-        s3_key: s3_key.clone(),
-        access_level,
-        is_draft: request.is_draft,
-        service_owner: request.service_owner,
+        slug: revision.slug,
+        title: revision.title,
+        summary: revision.summary,
+        s3_key,
+        access_level: revision.access_level,
+        is_draft: revision.is_draft,
+        service_owner: revision.service_owner,
         last_updated: Utc::now(),
-        tags: request.tags,
+        tags: revision.tags,
         links_out: links_out.clone(),
-        backlinks: old_backlinks,
-        parent_slug: effective_parent_slug,
-        order: effective_order,
-        is_hidden: effective_is_hidden,
+        backlinks,
+        parent_slug: revision.parent_slug,
+        order: revision.order,
+        is_hidden: revision.is_hidden,
+        noindex: revision.noindex,
         content_hash: Some(new_hash),
-        metadata_hash: Some(new_metadata_hash),
+        metadata_hash: revision.metadata_hash,
         is_archived: false,
-        source_path: Some(request.source_path.clone()),
+        source_path: revision.source_path,
+        contributors: merge_contributors(&old_contributors, &updated_by),
+        last_updated_by: Some(updated_by.clone()),
+        edit_access_level: revision.edit_access_level,
+        content_size,
+        relations: revision.relations,
+        asset_refs: crate::api::asset_access::referenced_asset_keys(&revision.content),
+        lint_findings: revision.lint_findings,
+        preview: revision.format.preview(&revision.content),
     };
+    let content = revision.content;
 
-    // 10. Build search document before ownership transfer
-    let search_doc = ctx
-        .search
-        .as_ref()
-        .map(|_| crate::search::client::build_search_document(&doc, &raw_content));
+    let activity_kind = if old_doc.is_some() {
+        ActivityKind::DocumentUpdated
+    } else {
+        ActivityKind::DocumentCreated
+    };
+    let activity = ActivityEvent::document(activity_kind, &doc, &updated_by);
 
-    // Capture fields for RAG indexing before doc is consumed
-    let rag_slug = doc.slug.clone();
-    let rag_title = doc.title.clone();
-    let rag_access_level = doc.access_level.clone();
-    let rag_is_draft = doc.is_draft;
-    let rag_tags = doc.tags.clone();
-    let rag_is_archived = doc.is_archived;
+    ctx.repo.create_or_update(doc.clone()).await?;
 
-    ctx.repo.create_or_update(doc).await?;
+    if let Some(old_key) = moved_from {
+        delete_unreferenced_object(ctx, &old_key).await;
+    }
 
-    // 11. Update backlinks on referenced documents.
+    // 5. Update backlinks on referenced documents.
     //     Note: this is not atomic with the create_or_update above.
     //     Both operations are idempotent, so partial failure leaves
     //     consistent (if stale) state that self-heals on re-ingest.
     ctx.repo
-        .update_backlinks(&request.slug, &old_links, &links_out)
+        .update_backlinks(&doc.slug, &old_links, &links_out)
         .await?;
 
-    // 12. Index in Meilisearch (if available)
-    if let (Some(search_svc), Some(search_doc)) = (ctx.search, search_doc) {
-        if let Err(e) = search_svc.index_document(&search_doc).await {
-            tracing::warn!("Failed to index document in search: {e}");
+    // 6. Index in Meilisearch (if available), or drop the entry of a
+    //    document that is now hidden
+    if let Some(search_svc) = ctx.search {
+        if let Err(e) =
+            crate::search::client::sync_search_document(search_svc, &doc, &content).await
+        {
+            tracing::warn!("Failed to update document in search: {e}");
         }
     }
 
-    // 13. Index in RAG vector store (if available)
+    // 7. Index in RAG vector store (if available)
     if let Some(rag) = ctx.rag {
-        if rag_is_archived {
-            if let Err(e) = rag.delete_document(&rag_slug).await {
-                tracing::warn!("Failed to remove archived document from RAG: {e}");
-            }
-        } else {
-            if let Err(e) = rag
-                .index_document(
-                    &rag_slug,
-                    &rag_title,
-                    &raw_content,
-                    &rag_access_level,
-                    rag_is_draft,
-                    &rag_tags,
-                )
-                .await
-            {
-                tracing::warn!("Failed to index document in RAG: {e}");
+        if let Err(e) = rag
+            .index_document(
+                &doc.slug,
+                &doc.title,
+                &content,
+                &doc.access_level,
+                doc.is_draft,
+                &doc.tags,
+            )
+            .await
+        {
+            tracing::warn!("Failed to index document in RAG: {e}");
+        }
+    }
+
+    // 8. Publish to the activity stream
+    if let Some(events) = ctx.events {
+        events.publish(activity);
+    }
+
+    // 9. Notify newly mentioned people
+    if let Some(notifier) = ctx.mentions.filter(|_| !mentions.is_empty()) {
+        let notifier = notifier.clone();
+        let slug = doc.slug.clone();
+        let title = doc.title.clone();
+        tokio::spawn(async move {
+            let source = crate::email::MentionSource {
+                slug: &slug,
+                title: &title,
+                actor: &updated_by,
+            };
+            notifier.notify(&mentions, source).await;
+        });
+    }
+
+    Ok(doc)
+}
+
+/// Delete the object a moved document was stored under, unless a slug
+/// colliding under the old key layout still points at it. Failures only
+/// leave an orphan behind, so they are logged rather than returned.
+#[cfg(feature = "ssr")]
+async fn delete_unreferenced_object(ctx: &IngestContext<'_>, old_key: &str) {
+    match ctx.repo.find_by_s3_key(old_key).await {
+        Ok(users) if users.is_empty() => {
+            if let Err(e) = ctx.storage.delete_object(old_key).await {
+                tracing::warn!("Failed to delete moved document object {old_key}: {e}");
             }
         }
+        Ok(_) => {}
+        Err(e) => tracing::warn!("Failed to check references of {old_key}: {e}"),
     }
+}
 
-    Ok(IngestResponse {
-        message: "Document ingested successfully".to_string(),
-        slug: request.slug,
-        s3_key,
-        changed: true,
-    })
+/// Reject the ingest with `403 quota_exceeded` when it would push the service
+/// owner past its document or storage limit.
+///
+/// Ingests that don't grow the owner's usage always pass, so an owner above a
+/// lowered limit can still update and shrink its documents.
+#[cfg(feature = "ssr")]
+pub(crate) async fn check_quota(
+    repo: &dyn DocumentRepository,
+    quotas: &QuotaConfig,
+    service_owner: &str,
+    existing: Option<&Document>,
+    content_size: u64,
+) -> Result<(), AppError> {
+    let limits = quotas.limits_for(service_owner);
+    let max_documents = limits.max_document_count();
+    let max_bytes = limits.max_storage_bytes();
+    if max_documents.is_none() && max_bytes.is_none() {
+        return Ok(());
+    }
+
+    let usage = repo
+        .usage_by_service_owner(Some(service_owner))
+        .await?
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    // The stored version is already part of the usage when it is active and
+    // belongs to the same owner
+    let (added_documents, replaced_bytes) =
+        match existing.filter(|d| !d.is_archived && d.service_owner == service_owner) {
+            Some(d) => (0, d.content_size),
+            None => (1, 0),
+        };
+
+    if let Some(max) = max_documents {
+        if added_documents > 0 && usage.document_count + added_documents > max {
+            return Err(AppError::QuotaExceeded(format!(
+                "Service owner '{service_owner}' has reached its quota of {max} documents"
+            )));
+        }
+    }
+    if let Some(max) = max_bytes {
+        let projected = usage.storage_bytes.saturating_sub(replaced_bytes) + content_size;
+        if content_size > replaced_bytes && projected > max {
+            return Err(AppError::QuotaExceeded(format!(
+                "Service owner '{service_owner}' would use {projected} bytes, above its storage quota of {} MB",
+                limits.max_storage_mb
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Reject the ingest with `409 Conflict` when the stored document no longer
+/// matches the caller's `expected_last_updated` / `expected_content_hash`,
+/// so concurrent pipelines publishing the same slug don't clobber each other.
+///
+/// `last_updated` is compared at millisecond precision, as stored in MongoDB.
+/// The check runs before any write but is not atomic with it.
+#[cfg(feature = "ssr")]
+fn check_preconditions(
+    request: &IngestRequest,
+    existing: Option<&Document>,
+) -> Result<(), AppError> {
+    if request.expected_last_updated.is_none() && request.expected_content_hash.is_none() {
+        return Ok(());
+    }
+    let Some(doc) = existing else {
+        return Err(AppError::Conflict(format!(
+            "Document '{}' does not exist",
+            request.slug
+        )));
+    };
+    if let Some(expected) = request.expected_last_updated {
+        if doc.last_updated.timestamp_millis() > expected.timestamp_millis() {
+            return Err(AppError::Conflict(format!(
+                "Document '{}' was modified at {} (expected {})",
+                request.slug,
+                doc.last_updated.to_rfc3339(),
+                expected.to_rfc3339()
+            )));
+        }
+    }
+    if let Some(expected) = request.expected_content_hash.as_deref() {
+        if doc.content_hash.as_deref() != Some(expected) {
+            return Err(AppError::Conflict(format!(
+                "Document '{}' has content hash {} (expected {expected})",
+                request.slug,
+                doc.content_hash.as_deref().unwrap_or("none")
+            )));
+        }
+    }
+    Ok(())
 }
 
 /// Input for [`compute_metadata_hash`].
@@ -312,6 +954,9 @@ pub(crate) struct MetadataHashInput<'a> {
     pub parent_slug: Option<&'a str>,
     pub order: u32,
     pub is_hidden: bool,
+    pub noindex: bool,
+    pub edit_access_level: Option<&'a str>,
+    pub relations: &'a DocumentRelations,
 }
 
 /// Build a canonical string from document metadata and hash it.
@@ -320,13 +965,14 @@ pub(crate) struct MetadataHashInput<'a> {
 /// so the server and client always agree on what "metadata unchanged" means.
 ///
 /// Fields included: title, summary, access_level (already lowercase), service_owner,
-/// tags (sorted), parent_slug, order, is_hidden.
+/// tags (sorted), parent_slug, order, is_hidden, then noindex, edit_access_level
+/// and relations only when set, so hashes of documents without them are unchanged.
 /// `is_draft` is intentionally excluded because the CLI does not expose it yet.
 #[cfg(feature = "ssr")]
 pub(crate) fn compute_metadata_hash(input: MetadataHashInput<'_>) -> String {
     let mut sorted_tags: Vec<&str> = input.tags.iter().map(|s| s.as_str()).collect();
     sorted_tags.sort_unstable();
-    let canonical = format!(
+    let mut canonical = format!(
         "title={}\nsummary={}\naccess_level={}\nservice_owner={}\ntags={}\nparent_slug={}\norder={}\nis_hidden={}",
         input.title,
         input.summary.unwrap_or(""),
@@ -337,6 +983,28 @@ pub(crate) fn compute_metadata_hash(input: MetadataHashInput<'_>) -> String {
         input.order,
         input.is_hidden,
     );
+    if input.noindex {
+        canonical.push_str("\nnoindex=true");
+    }
+    if let Some(level) = input.edit_access_level {
+        canonical.push_str(&format!("\nedit_access_level={level}"));
+    }
+    if !input.relations.is_empty() {
+        let relations = input.relations;
+        canonical.push_str(&format!(
+            "\nrelations=see_also:{};prerequisite:{};supersedes:{}",
+            relations.see_also.join(","),
+            relations.prerequisite.join(","),
+            relations.supersedes.join(","),
+        ));
+        // Only when set, so hashes of existing documents don't change
+        if !relations.superseded_by.is_empty() {
+            canonical.push_str(&format!(
+                ";superseded_by:{}",
+                relations.superseded_by.join(",")
+            ));
+        }
+    }
     format!(
         "sha256:{}",
         crate::auth::token_service::TokenService::hash_token(&canonical)
@@ -425,14 +1093,18 @@ async fn validate_token(
 
 /// Resolve the human-readable name for the token used in this request.
 #[cfg(feature = "ssr")]
-async fn resolve_token_name(ctx: &IngestContext<'_>, raw_token: &str) -> String {
-    if let Some(legacy) = ctx.legacy_token {
+pub(crate) async fn resolve_token_name(
+    service_token_repo: &dyn ServiceTokenRepository,
+    legacy_token: Option<&str>,
+    raw_token: &str,
+) -> String {
+    if let Some(legacy) = legacy_token {
         if !legacy.is_empty() && raw_token == legacy {
             return "legacy".to_string();
         }
     }
     let hash = crate::auth::token_service::TokenService::hash_token(raw_token);
-    match ctx.service_token_repo.find_by_hash(&hash).await {
+    match service_token_repo.find_by_hash(&hash).await {
         Ok(Some(token)) => token.name,
         _ => "unknown".to_string(),
     }
@@ -444,6 +1116,7 @@ async fn resolve_token_name(ctx: &IngestContext<'_>, raw_token: &str) -> String
 #[cfg(feature = "ssr")]
 pub async fn ingest_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    axum::extract::Query(query): axum::extract::Query<IngestQuery>,
     axum::Json(request): axum::Json<IngestRequest>,
 ) -> Result<axum::Json<IngestResponse>, AppError> {
     let ctx = IngestContext {
@@ -451,10 +1124,20 @@ pub async fn ingest_handler(
         storage: state.storage_client.as_ref(),
         search: state.search_service.as_deref(),
         access_level_repo: state.access_level_repo.as_ref(),
+        project_repo: Some(state.project_repo.as_ref()),
         service_token_repo: state.service_token_repo.as_ref(),
         version_repo: state.document_version_repo.as_ref(),
         rag: state.rag_service.as_deref(),
+        events: Some(&state.activity_events),
         legacy_token: Some(&state.service_token),
+        dry_run: query.dry_run,
+        approval: ApprovalGate::from_state(&state).await?,
+        quotas: Some(&state.quotas),
+        taxonomy: Some(&state.taxonomy),
+        lint: Some(&state.lint),
+        spell_checker: state.spell_checker.as_deref(),
+        secrets: state.secret_guard.as_deref(),
+        mentions: state.mentions.as_deref(),
     };
 
     let response = process_ingest(&ctx, request).await?;
@@ -471,7 +1154,7 @@ mod tests {
     use crate::db::auth_models::AccessLevelEntity;
     use crate::db::service_token_models::ServiceToken;
     use crate::db::service_token_repository::ServiceTokenRepository;
-    use crate::test_utils::MockStorage;
+    use crate::testing::{self, InMemoryDocumentRepository, InMemoryStorage};
 
     /// A mock access level repo that accepts any non-empty level name.
     struct MockAccessLevelRepo;
@@ -597,149 +1280,16 @@ mod tests {
         }
     }
 
-    struct MockRepo {
-        documents: Mutex<Vec<Document>>,
-    }
-
-    impl MockRepo {
-        fn new() -> Self {
-            Self {
-                documents: Mutex::new(vec![]),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl DocumentRepository for MockRepo {
-        async fn create_or_update(&self, doc: Document) -> Result<(), AppError> {
-            let mut docs = self.documents.lock().unwrap();
-            docs.retain(|d| d.slug != doc.slug);
-            docs.push(doc);
-            Ok(())
-        }
-
-        async fn find_by_slug(&self, slug: &str) -> Result<Option<Document>, AppError> {
-            Ok(self
-                .documents
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|d| d.slug == slug)
-                .cloned())
-        }
-
-        async fn list_all(&self) -> Result<Vec<Document>, AppError> {
-            Ok(self.documents.lock().unwrap().clone())
-        }
-
-        async fn list_by_access_levels(
-            &self,
-            allowed_levels: Option<&[String]>,
-            include_draft: bool,
-        ) -> Result<Vec<Document>, AppError> {
-            Ok(self
-                .documents
-                .lock()
-                .unwrap()
-                .iter()
-                .filter(|d| {
-                    let level_ok = allowed_levels
-                        .map(|lvls| lvls.contains(&d.access_level))
-                        .unwrap_or(true);
-                    let draft_ok = include_draft || !d.is_draft;
-                    level_ok && draft_ok
-                })
-                .cloned()
-                .collect())
-        }
-
-        async fn update_backlinks(
-            &self,
-            source_slug: &str,
-            old_links: &[String],
-            new_links: &[String],
-        ) -> Result<(), AppError> {
-            let mut docs = self.documents.lock().unwrap();
-
-            let removed: Vec<String> = old_links
-                .iter()
-                .filter(|l| !new_links.contains(l))
-                .cloned()
-                .collect();
-
-            for doc in docs.iter_mut() {
-                if removed.contains(&doc.slug) {
-                    doc.backlinks.retain(|b| b != source_slug);
-                }
-            }
-
-            let added: Vec<String> = new_links
-                .iter()
-                .filter(|l| !old_links.contains(l))
-                .cloned()
-                .collect();
-
-            for doc in docs.iter_mut() {
-                if added.contains(&doc.slug) && !doc.backlinks.contains(&source_slug.to_string()) {
-                    doc.backlinks.push(source_slug.to_string());
-                }
-            }
-
-            Ok(())
-        }
-
-        async fn find_by_slug_prefix(&self, prefix: &str) -> Result<Vec<Document>, AppError> {
-            Ok(self
-                .documents
-                .lock()
-                .unwrap()
-                .iter()
-                .filter(|d| {
-                    !d.is_archived
-                        && (prefix.is_empty()
-                            || d.slug == prefix
-                            || d.slug.starts_with(&format!("{prefix}/")))
-                })
-                .cloned()
-                .collect())
-        }
-
-        async fn set_archived(&self, slug: &str, archived: bool) -> Result<(), AppError> {
-            let mut docs = self.documents.lock().unwrap();
-            if let Some(doc) = docs.iter_mut().find(|d| d.slug == slug) {
-                doc.is_archived = archived;
-            }
-            Ok(())
-        }
-        async fn find_by_source_path(
-            &self,
-            source_path: &str,
-        ) -> Result<Option<Document>, AppError> {
-            Ok(self
-                .documents
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|d| d.source_path.as_deref() == Some(source_path))
-                .cloned())
-        }
-    }
-
     fn make_request(token: &str, slug: &str) -> IngestRequest {
         IngestRequest {
             service_token: token.to_string(),
-            source_path: format!("{slug}.md"),
-            slug: slug.to_string(),
             title: "Test Doc".to_string(),
             summary: Some("A test document used to exercise ingestion behavior.".to_string()),
             content: "# Hello\nWorld".to_string(),
             access_level: "internal".to_string(),
-            is_draft: false,
             service_owner: "test-team".to_string(),
             tags: vec!["test".to_string()],
-            parent_slug: None,
-            order: 0,
-            is_hidden: false,
+            ..testing::ingest_request(slug)
         }
     }
 
@@ -772,9 +1322,40 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct MockPendingRepo {
+        pending: Mutex<Vec<PendingIngest>>,
+    }
+
+    #[async_trait]
+    impl PendingIngestRepository for MockPendingRepo {
+        async fn submit(&self, pending: PendingIngest) -> Result<(), AppError> {
+            let mut all = self.pending.lock().unwrap();
+            all.retain(|p| p.slug != pending.slug);
+            all.push(pending);
+            Ok(())
+        }
+        async fn list_all(&self) -> Result<Vec<PendingIngest>, AppError> {
+            Ok(self.pending.lock().unwrap().clone())
+        }
+        async fn find_by_id(&self, id: &str) -> Result<Option<PendingIngest>, AppError> {
+            Ok(self
+                .pending
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|p| p.id == id)
+                .cloned())
+        }
+        async fn delete(&self, id: &str) -> Result<(), AppError> {
+            self.pending.lock().unwrap().retain(|p| p.id != id);
+            Ok(())
+        }
+    }
+
     fn make_ctx<'a>(
-        repo: &'a MockRepo,
-        storage: &'a MockStorage,
+        repo: &'a InMemoryDocumentRepository,
+        storage: &'a InMemoryStorage,
         token_repo: &'a dyn ServiceTokenRepository,
         legacy_token: Option<&'a str>,
     ) -> IngestContext<'a> {
@@ -783,10 +1364,20 @@ mod tests {
             storage,
             search: None,
             access_level_repo: &MockAccessLevelRepo,
+            project_repo: None,
             service_token_repo: token_repo,
             version_repo: &MockVersionRepo,
             rag: None,
+            events: None,
             legacy_token,
+            dry_run: false,
+            approval: None,
+            quotas: None,
+            taxonomy: None,
+            lint: None,
+            spell_checker: None,
+            secrets: None,
+            mentions: None,
         }
     }
 
@@ -804,13 +1395,15 @@ mod tests {
             created_at: Utc::now(),
             last_used_at: None,
             is_active: true,
+            project: None,
+            access_levels: vec![],
         }
     }
 
     #[tokio::test]
     async fn test_ingest_success() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
         let request = make_request("valid-token", "docs/hello");
@@ -846,8 +1439,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_draft_flag_preserved() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
         let mut request = make_request("valid-token", "docs/wip");
@@ -859,10 +1452,26 @@ mod tests {
         assert!(doc.is_draft);
     }
 
+    #[tokio::test]
+    async fn test_ingest_records_contributor() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+
+        process_ingest(&ctx, make_request("valid-token", "docs/owned"))
+            .await
+            .unwrap();
+
+        let doc = repo.find_by_slug("docs/owned").await.unwrap().unwrap();
+        assert_eq!(doc.last_updated_by.as_deref(), Some("legacy"));
+        assert_eq!(doc.contributors, vec!["legacy".to_string()]);
+    }
+
     #[tokio::test]
     async fn test_ingest_invalid_token() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
         let request = make_request("wrong-token", "docs/hello");
@@ -877,8 +1486,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_empty_slug() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
         let request = make_request("valid-token", "");
@@ -893,8 +1502,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_empty_access_level() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
         let mut request = make_request("valid-token", "docs/hello");
@@ -910,8 +1519,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_normalises_access_level_to_lowercase() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
         let mut request = make_request("valid-token", "docs/hello");
@@ -925,8 +1534,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_upsert() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -950,8 +1559,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_rejects_path_traversal() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -962,8 +1571,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_rejects_absolute_slug() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -976,8 +1585,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_scoped_token_success() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let scoped = make_scoped_token("scoped-secret", vec!["docs/*"]);
         let token_repo = MockServiceTokenRepo::with_token(scoped);
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("legacy-token"));
@@ -989,8 +1598,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_scoped_token_out_of_scope() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let scoped = make_scoped_token("scoped-secret", vec!["protocols/*"]);
         let token_repo = MockServiceTokenRepo::with_token(scoped);
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("legacy-token"));
@@ -1006,8 +1615,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_legacy_token_bypasses_scopes() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new(); // no scoped tokens
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("legacy-token"));
 
@@ -1019,8 +1628,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_inactive_token_rejected() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let mut scoped = make_scoped_token("inactive-secret", vec!["docs/*"]);
         scoped.is_active = false;
         let token_repo = MockServiceTokenRepo::with_token(scoped);
@@ -1037,8 +1646,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_read_only_token_rejected() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let mut scoped = make_scoped_token("readonly-secret", vec!["docs/*"]);
         scoped.can_write = false;
         let token_repo = MockServiceTokenRepo::with_token(scoped);
@@ -1057,8 +1666,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_unchanged_content_skips_upload() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -1082,10 +1691,50 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_ingest_moves_document_from_old_key() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+
+        // Two documents stored under the same key by an older layout
+        for slug in ["docs/hello", "docs/other"] {
+            process_ingest(&ctx, make_request("valid-token", slug))
+                .await
+                .unwrap();
+            repo.set_s3_key(slug, "docs/legacy.md").await.unwrap();
+        }
+        storage
+            .put_object("docs/legacy.md", b"# Legacy".to_vec())
+            .await
+            .unwrap();
+
+        let mut request = make_request("valid-token", "docs/hello");
+        request.content = "# Moved".to_string();
+        let response = process_ingest(&ctx, request).await.unwrap();
+        assert_eq!(response.s3_key, "docs/docs_hello.md");
+        // Still the key of docs/other
+        assert!(storage
+            .get_object("docs/legacy.md")
+            .await
+            .unwrap()
+            .is_some());
+
+        let mut request = make_request("valid-token", "docs/other");
+        request.content = "# Moved too".to_string();
+        process_ingest(&ctx, request).await.unwrap();
+        assert_eq!(storage.get_object("docs/legacy.md").await.unwrap(), None);
+        assert_eq!(
+            storage.get_object("docs/docs_other.md").await.unwrap(),
+            Some(b"# Moved too".to_vec())
+        );
+    }
+
     #[tokio::test]
     async fn test_ingest_changed_content_uploads() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -1105,10 +1754,60 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_editor_save_shares_ingest_pipeline() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+        process_ingest(&ctx, make_request("valid-token", "docs/hello"))
+            .await
+            .unwrap();
+
+        let old_doc = repo.find_by_slug("docs/hello").await.unwrap();
+        let html = r#"<p>See <a href="/docs/other">other</a></p>"#;
+        let saved = save_document(
+            &ctx,
+            old_doc,
+            DocumentRevision {
+                slug: "docs/hello".into(),
+                title: "Edited".into(),
+                summary: None,
+                access_level: "internal".into(),
+                is_draft: false,
+                service_owner: "test-team".into(),
+                tags: vec![],
+                parent_slug: None,
+                order: 0,
+                is_hidden: false,
+                metadata_hash: None,
+                source_path: None,
+                edit_access_level: None,
+                relations: Default::default(),
+                content: html.into(),
+                format: ContentFormat::Html,
+                lint_findings: vec![],
+                noindex: false,
+            },
+            "Alice".into(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(saved.links_out, vec!["other".to_string()]);
+        assert_eq!(saved.content_hash, Some(content_hash(html)));
+        assert_eq!(saved.contributors, vec!["Alice", "legacy"]);
+        // Initial upload + history copy of the ingested revision + editor upload
+        assert_eq!(
+            storage.put_count.load(std::sync::atomic::Ordering::Relaxed),
+            3
+        );
+    }
+
     #[tokio::test]
     async fn test_ingest_same_content_different_metadata_updates_db() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -1132,10 +1831,375 @@ mod tests {
         assert_eq!(doc.title, "New Title");
     }
 
+    // ── Dry run tests ────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_ingest_dry_run_writes_nothing() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let mut ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+        ctx.dry_run = true;
+
+        let mut request = make_request("valid-token", "docs/hello");
+        request.content = "See [setup](/docs/setup).".to_string();
+        let response = process_ingest(&ctx, request).await.unwrap();
+
+        assert!(response.dry_run);
+        assert!(response.changed);
+        assert_eq!(response.changes, vec!["created"]);
+        assert_eq!(response.warnings, vec!["Broken internal link: /docs/setup"]);
+        assert_eq!(
+            storage.put_count.load(std::sync::atomic::Ordering::Relaxed),
+            0
+        );
+        assert!(repo.find_by_slug("docs/hello").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ingest_dry_run_reports_changed_fields() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+        process_ingest(&ctx, make_request("valid-token", "docs/hello"))
+            .await
+            .unwrap();
+
+        let dry_ctx = IngestContext {
+            dry_run: true,
+            ..make_ctx(&repo, &storage, &token_repo, Some("valid-token"))
+        };
+        let mut request = make_request("valid-token", "docs/hello");
+        request.title = "New Title".to_string();
+        request.content = "# Updated".to_string();
+        let response = process_ingest(&dry_ctx, request).await.unwrap();
+
+        assert_eq!(response.changes, vec!["content", "title"]);
+        assert_eq!(response.message, "Dry run: document would be updated");
+        let doc = repo.find_by_slug("docs/hello").await.unwrap().unwrap();
+        assert_eq!(doc.title, "Test Doc");
+    }
+
+    #[tokio::test]
+    async fn test_ingest_protected_slug_waits_for_approval() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let pending_repo = MockPendingRepo::default();
+        let ctx = IngestContext {
+            approval: Some(ApprovalGate {
+                protected_prefixes: vec!["security/*".to_string()],
+                pending_repo: &pending_repo,
+                notifier: None,
+                inbox: None,
+            }),
+            ..make_ctx(&repo, &storage, &token_repo, Some("valid-token"))
+        };
+
+        let response = process_ingest(&ctx, make_request("valid-token", "security/policy"))
+            .await
+            .unwrap();
+        assert!(response.pending_approval);
+        assert!(repo
+            .find_by_slug("security/policy")
+            .await
+            .unwrap()
+            .is_none());
+
+        let queued = pending_repo.list_all().await.unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].submitted_by, "legacy");
+        assert!(queued[0].request.service_token.is_empty());
+
+        let response = publish_pending(&ctx, &pending_repo, &queued[0].id)
+            .await
+            .unwrap();
+        assert!(response.changed);
+        let doc = repo.find_by_slug("security/policy").await.unwrap().unwrap();
+        // Attributed to the submitting token, not the approver
+        assert_eq!(doc.last_updated_by.as_deref(), Some("legacy"));
+        assert!(pending_repo.list_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ingest_enforces_service_owner_quota() {
+        use crate::config::{QuotaConfig, QuotaLimits};
+
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let quotas = QuotaConfig {
+            default: QuotaLimits {
+                max_documents: 2,
+                max_storage_mb: 0,
+            },
+            ..Default::default()
+        };
+        let ctx = IngestContext {
+            quotas: Some(&quotas),
+            ..make_ctx(&repo, &storage, &token_repo, Some("valid-token"))
+        };
+
+        for slug in ["docs/a", "docs/b"] {
+            process_ingest(&ctx, make_request("valid-token", slug))
+                .await
+                .unwrap();
+        }
+        let result = process_ingest(&ctx, make_request("valid-token", "docs/c")).await;
+        assert!(matches!(result, Err(AppError::QuotaExceeded(_))));
+        assert!(repo.find_by_slug("docs/c").await.unwrap().is_none());
+
+        // Updating an existing document does not add to the count
+        let mut request = make_request("valid-token", "docs/a");
+        request.content = "# Updated".to_string();
+        process_ingest(&ctx, request).await.unwrap();
+
+        // Other owners have their own count
+        let mut request = make_request("valid-token", "docs/c");
+        request.service_owner = "other-team".to_string();
+        process_ingest(&ctx, request).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ingest_reports_and_records_lint_findings() {
+        use crate::config::LintConfig;
+        use crate::rendering::lint::LintRule;
+
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let lint = LintConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let ctx = IngestContext {
+            lint: Some(&lint),
+            ..make_ctx(&repo, &storage, &token_repo, Some("valid-token"))
+        };
+
+        let mut request = make_request("valid-token", "docs/lint");
+        request.content = "## Setup\n\nTODO: see https://example.com\n".to_string();
+        let response = process_ingest(&ctx, request).await.unwrap();
+
+        let rules: Vec<LintRule> = response.lint.iter().map(|f| f.rule).collect();
+        assert_eq!(
+            rules,
+            vec![LintRule::MissingH1, LintRule::BareUrl, LintRule::Todo]
+        );
+        let doc = repo.find_by_slug("docs/lint").await.unwrap().unwrap();
+        assert_eq!(doc.lint_findings, response.lint);
+
+        // Linting is off without a config
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+        let response = process_ingest(&ctx, make_request("valid-token", "docs/plain"))
+            .await
+            .unwrap();
+        assert!(response.lint.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ingest_normalizes_tags_and_owner() {
+        use crate::config::TaxonomyConfig;
+
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let mut taxonomy = TaxonomyConfig::default();
+        taxonomy.tags.lowercase = true;
+        taxonomy
+            .tags
+            .synonyms
+            .insert("k8s".to_string(), "kubernetes".to_string());
+        taxonomy.owners.kebab_case = true;
+        let ctx = IngestContext {
+            taxonomy: Some(&taxonomy),
+            ..make_ctx(&repo, &storage, &token_repo, Some("valid-token"))
+        };
+
+        let mut request = make_request("valid-token", "docs/k8s");
+        request.tags = vec!["K8s".to_string(), "Kubernetes".to_string()];
+        request.service_owner = "Platform Team".to_string();
+        let raw_hash = compute_metadata_hash(MetadataHashInput {
+            title: &request.title,
+            summary: request.summary.as_deref(),
+            access_level: &request.access_level,
+            service_owner: &request.service_owner,
+            tags: &request.tags,
+            parent_slug: None,
+            order: 0,
+            is_hidden: false,
+            noindex: false,
+            edit_access_level: None,
+            relations: &Default::default(),
+        });
+        process_ingest(&ctx, request.clone()).await.unwrap();
+
+        let doc = repo.find_by_slug("docs/k8s").await.unwrap().unwrap();
+        assert_eq!(doc.tags, ["kubernetes"]);
+        assert_eq!(doc.service_owner, "platform-team");
+        // Sync clients hash the values they send
+        assert_eq!(doc.metadata_hash, Some(raw_hash));
+        let response = process_ingest(&ctx, request.clone()).await.unwrap();
+        assert!(!response.changed);
+
+        taxonomy.tags.allowed = vec!["kubernetes".to_string()];
+        let ctx = IngestContext {
+            taxonomy: Some(&taxonomy),
+            ..make_ctx(&repo, &storage, &token_repo, Some("valid-token"))
+        };
+        request.tags.push("misc".to_string());
+        let result = process_ingest(&ctx, request).await;
+        assert!(matches!(result, Err(AppError::BadRequest(msg)) if msg.contains("'misc'")));
+    }
+
+    #[tokio::test]
+    async fn test_ingest_stores_a_content_preview() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+        let mut request = make_request("valid-token", "docs/preview");
+        request.content = "# Deploying\n\nRun `just deploy` from the **repo** root.".to_string();
+        process_ingest(&ctx, request).await.unwrap();
+
+        let doc = repo.find_by_slug("docs/preview").await.unwrap().unwrap();
+        assert_eq!(doc.preview, "Deploying Run just deploy from the repo root.");
+    }
+
+    #[test]
+    fn test_html_preview_drops_tags_and_is_bounded() {
+        let html = "<h1>Runbook</h1>\n<p>Restart the <a href=\"/docs/x\">worker</a>.</p>";
+        assert_eq!(
+            ContentFormat::Html.preview(html),
+            "Runbook Restart the worker ."
+        );
+
+        let long = format!("<p>{}</p>", "é".repeat(200));
+        let preview = ContentFormat::Html.preview(&long);
+        assert!(preview.len() <= PREVIEW_MAX_CHARS);
+        assert!(preview.starts_with('é'));
+    }
+
+    #[tokio::test]
+    async fn test_ingest_enforces_storage_quota_on_growth_only() {
+        use crate::config::{QuotaConfig, QuotaLimits};
+
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+        let mut request = make_request("valid-token", "docs/big");
+        request.content = "x".repeat(2 * 1024 * 1024);
+        process_ingest(&ctx, request).await.unwrap();
+        assert_eq!(
+            repo.find_by_slug("docs/big")
+                .await
+                .unwrap()
+                .unwrap()
+                .content_size,
+            2 * 1024 * 1024
+        );
+
+        let mut quotas = QuotaConfig::default();
+        quotas.owners.insert(
+            "test-team".to_string(),
+            QuotaLimits {
+                max_documents: 0,
+                max_storage_mb: 1,
+            },
+        );
+        let ctx = IngestContext {
+            quotas: Some(&quotas),
+            ..make_ctx(&repo, &storage, &token_repo, Some("valid-token"))
+        };
+
+        // Already above the lowered quota: growing is rejected, shrinking is not
+        let mut request = make_request("valid-token", "docs/big");
+        request.content = "x".repeat(3 * 1024 * 1024);
+        let result = process_ingest(&ctx, request).await;
+        assert!(matches!(result, Err(AppError::QuotaExceeded(_))));
+
+        let mut request = make_request("valid-token", "docs/big");
+        request.content = "x".repeat(512 * 1024);
+        process_ingest(&ctx, request).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ingest_rejects_stale_expected_last_updated() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+        let first = process_ingest(&ctx, make_request("valid-token", "docs/hello"))
+            .await
+            .unwrap();
+        let seen = first.last_updated.unwrap();
+
+        // Another pipeline publishes in between
+        let mut other = make_request("valid-token", "docs/hello");
+        other.expected_last_updated = Some(seen);
+        other.content = "# From pipeline A".to_string();
+        let second = process_ingest(&ctx, other).await.unwrap();
+        assert!(second.last_updated.unwrap() >= seen);
+
+        let mut stale = make_request("valid-token", "docs/hello");
+        stale.expected_last_updated = Some(seen - chrono::Duration::seconds(1));
+        stale.content = "# From pipeline B".to_string();
+        let result = process_ingest(&ctx, stale).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        let content = storage.get_object("docs/docs_hello.md").await.unwrap();
+        assert_eq!(content, Some(b"# From pipeline A".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_ingest_checks_expected_content_hash() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+
+        let mut missing = make_request("valid-token", "docs/hello");
+        missing.expected_content_hash = Some("sha256:abc".to_string());
+        let result = process_ingest(&ctx, missing).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        let first = process_ingest(&ctx, make_request("valid-token", "docs/hello"))
+            .await
+            .unwrap();
+
+        let mut wrong = make_request("valid-token", "docs/hello");
+        wrong.expected_content_hash = Some("sha256:abc".to_string());
+        let result = process_ingest(&ctx, wrong).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        let mut matching = make_request("valid-token", "docs/hello");
+        matching.expected_content_hash = first.content_hash;
+        matching.content = "# Updated".to_string();
+        let response = process_ingest(&ctx, matching).await.unwrap();
+        assert!(response.changed);
+    }
+
+    #[tokio::test]
+    async fn test_ingest_dry_run_still_validates() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let mut ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+        ctx.dry_run = true;
+
+        let result = process_ingest(&ctx, make_request("wrong-token", "docs/hello")).await;
+        assert!(matches!(result, Err(AppError::Auth(_))));
+
+        let result = process_ingest(&ctx, make_request("valid-token", "../etc")).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
     #[tokio::test]
     async fn test_ingest_stores_content_hash() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -1149,8 +2213,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_stores_metadata_hash() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -1167,8 +2231,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_metadata_hash_changes_when_access_level_changes() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -1199,10 +2263,61 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_ingest_stores_edit_access_level() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+
+        let request = make_request("valid-token", "docs/hello");
+        process_ingest(&ctx, request).await.unwrap();
+        let doc = repo.find_by_slug("docs/hello").await.unwrap().unwrap();
+        assert_eq!(doc.edit_access_level, None);
+        let hash1 = doc.metadata_hash.unwrap();
+
+        let mut request = make_request("valid-token", "docs/hello");
+        request.edit_access_level = Some(" Team-Docs ".to_string());
+        let resp = process_ingest(&ctx, request).await.unwrap();
+        assert_eq!(resp.changes, vec!["edit_access_level".to_string()]);
+        let doc = repo.find_by_slug("docs/hello").await.unwrap().unwrap();
+        assert_eq!(doc.edit_access_level.as_deref(), Some("team-docs"));
+        assert_ne!(doc.metadata_hash.unwrap(), hash1);
+    }
+
+    #[tokio::test]
+    async fn test_ingest_stores_noindex() {
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
+        let token_repo = MockServiceTokenRepo::new();
+        let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
+
+        let request = make_request("valid-token", "docs/hello");
+        process_ingest(&ctx, request).await.unwrap();
+        let doc = repo.find_by_slug("docs/hello").await.unwrap().unwrap();
+        assert!(!doc.noindex);
+        let hash1 = doc.metadata_hash.unwrap();
+
+        let mut request = make_request("valid-token", "docs/hello");
+        request.noindex = true;
+        let resp = process_ingest(&ctx, request).await.unwrap();
+        assert_eq!(resp.changes, vec!["noindex".to_string()]);
+        let doc = repo.find_by_slug("docs/hello").await.unwrap().unwrap();
+        assert!(doc.noindex);
+        assert_ne!(doc.metadata_hash.unwrap(), hash1);
+
+        let request = make_request("valid-token", "docs/hello");
+        let resp = process_ingest(&ctx, request).await.unwrap();
+        assert_eq!(resp.changes, vec!["noindex".to_string()]);
+        let doc = repo.find_by_slug("docs/hello").await.unwrap().unwrap();
+        assert!(!doc.noindex);
+        assert_eq!(doc.metadata_hash.unwrap(), hash1);
+    }
+
     #[tokio::test]
     async fn test_ingest_metadata_hash_stable_when_nothing_changes() {
-        let storage = MockStorage::new();
-        let repo = MockRepo::new();
+        let storage = InMemoryStorage::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo::new();
         let ctx = make_ctx(&repo, &storage, &token_repo, Some("valid-token"));
 
@@ -0,0 +1,115 @@
+//! Scheduled jobs and their run history.
+//!
+//! All routes require an authenticated admin user (`is_admin = true`).
+//!
+//! | Method | Path                              | Description                                 |
+//! |--------|-----------------------------------|---------------------------------------------|
+//! | GET    | `/api/v1/admin/jobs`              | Jobs with their schedule, next and last run |
+//! | GET    | `/api/v1/admin/jobs/runs`         | Run history (`?job=<job>&limit=<n>`)        |
+//! | POST   | `/api/v1/admin/jobs/{job}/run`    | Run a job now and return the recorded run   |
+
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::auth::extractor::RequiredAuthUser;
+use crate::db::job_run_repository::JobRun;
+use crate::error::AppError;
+use crate::jobs::scheduler::{self, ScheduledJob};
+
+/// Runs returned when `limit` is not given.
+const DEFAULT_RUN_LIMIT: i64 = 50;
+/// Upper bound of `limit`.
+const MAX_RUN_LIMIT: i64 = 500;
+
+fn require_admin(user: &crate::auth::models::AuthenticatedUser) -> Result<(), AppError> {
+    if user.is_admin {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden("Admin privileges required".into()))
+    }
+}
+
+fn parse_job(name: &str) -> Result<ScheduledJob, AppError> {
+    ScheduledJob::parse(name).ok_or_else(|| AppError::NotFound(format!("Unknown job '{name}'")))
+}
+
+/// A scheduled job as listed by `GET /api/v1/admin/jobs`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduledJobInfo {
+    pub job: ScheduledJob,
+    /// Cron expression; empty when the job only runs on demand.
+    pub schedule: String,
+    pub next_run: Option<DateTime<Utc>>,
+    pub last_run: Option<JobRun>,
+}
+
+/// `GET /api/v1/admin/jobs`
+pub async fn list_jobs_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+) -> Result<Json<Vec<ScheduledJobInfo>>, AppError> {
+    require_admin(&user)?;
+
+    let mut jobs = Vec::new();
+    for job in ScheduledJob::ALL {
+        let last_run = state
+            .job_run_repo
+            .list_recent(Some(job.as_str()), 1)
+            .await?
+            .into_iter()
+            .next();
+        jobs.push(ScheduledJobInfo {
+            job,
+            schedule: job.schedule(&state.scheduler).to_string(),
+            next_run: scheduler::next_run(job, &state.scheduler),
+            last_run,
+        });
+    }
+    Ok(Json(jobs))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JobRunsQuery {
+    pub job: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// `GET /api/v1/admin/jobs/runs`
+pub async fn list_job_runs_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Query(query): Query<JobRunsQuery>,
+) -> Result<Json<Vec<JobRun>>, AppError> {
+    require_admin(&user)?;
+
+    let job = query.job.as_deref().map(parse_job).transpose()?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_RUN_LIMIT)
+        .clamp(1, MAX_RUN_LIMIT);
+    let runs = state
+        .job_run_repo
+        .list_recent(job.map(ScheduledJob::as_str), limit)
+        .await?;
+    Ok(Json(runs))
+}
+
+/// `POST /api/v1/admin/jobs/{job}/run`
+///
+/// Runs the job before answering; a failing job is returned as a run with
+/// status `failed`.
+pub async fn run_job_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Path(job): Path<String>,
+) -> Result<Json<JobRun>, AppError> {
+    require_admin(&user)?;
+
+    let job = parse_job(&job)?;
+    let run = scheduler::run_job(&state, job, Utc::now(), &user.email).await?;
+    tracing::info!(job = job.as_str(), triggered_by = %user.email, "Ran job on demand");
+    Ok(Json(run))
+}
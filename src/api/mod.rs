@@ -1,24 +1,58 @@
 #[cfg(feature = "ssr")]
 pub mod admin;
+#[cfg(feature = "ssr")]
+pub mod approvals;
+#[cfg(feature = "ssr")]
+pub mod asset_access;
 pub mod assets;
 #[cfg(feature = "ssr")]
 pub mod auth;
+#[cfg(feature = "ssr")]
+pub mod backstage;
+#[cfg(feature = "ssr")]
+pub mod bot;
+pub mod changelog;
+pub mod contract_tests;
+#[cfg(feature = "ssr")]
+pub mod documents;
+#[cfg(feature = "ssr")]
+pub mod embed;
 pub mod errors;
 #[cfg(feature = "ssr")]
+pub mod events;
+#[cfg(feature = "ssr")]
+pub mod github;
+#[cfg(feature = "ssr")]
+pub mod grafana;
+#[cfg(feature = "ssr")]
 pub mod health;
+#[cfg(feature = "ssr")]
+pub mod image_cleanup;
 pub mod ingest;
 #[cfg(feature = "ssr")]
+pub mod jobs;
+#[cfg(feature = "ssr")]
 pub mod pat;
 #[cfg(feature = "ssr")]
+pub mod print;
+#[cfg(feature = "ssr")]
 pub mod prompts;
 #[cfg(feature = "ssr")]
+pub mod quarantine;
+#[cfg(feature = "ssr")]
 pub mod rag;
 pub mod schemas;
 #[cfg(feature = "ssr")]
 pub mod search;
 #[cfg(feature = "ssr")]
+pub mod share;
+#[cfg(feature = "ssr")]
 pub mod sync;
 #[cfg(feature = "ssr")]
 pub mod token_validation;
 #[cfg(feature = "ssr")]
+pub mod try_it;
+#[cfg(feature = "ssr")]
 pub mod upload;
+#[cfg(feature = "ssr")]
+pub mod uploader;
@@ -142,6 +142,8 @@ pub async fn create_user_pat_handler(
         created_at: now,
         last_used_at: None,
         is_active: true,
+        project: None,
+        access_levels: vec![],
     };
 
     state.service_token_repo.create(token).await?;
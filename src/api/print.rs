@@ -0,0 +1,227 @@
+//! Print view of documents, for printing and saving as PDF.
+//!
+//! | Method | Path                  | Auth    | Description                                    |
+//! |--------|-----------------------|---------|------------------------------------------------|
+//! | GET    | `/docs/{*slug}/print` | Session | The document alone, laid out for paper         |
+//!
+//! The app serves `/docs/*slug` and a route cannot continue after a
+//! wildcard, so [`print_view`] answers these requests as a middleware in
+//! front of it. A document whose own slug ends in `/print` keeps its page.
+//!
+//! Documents are read with the caller's session or personal access token,
+//! like the document page. The page loads the images and diagrams of the
+//! document from the portal, then opens the browser's print dialog.
+
+use axum::extract::{FromRequestParts, Request, State};
+use axum::http::{header, HeaderMap, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{Html, IntoResponse, Response};
+use axum_extra::extract::CookieJar;
+
+use crate::app::AppState;
+use crate::auth::extractor::OptionalAuthUser;
+use crate::db::models::Document;
+use crate::i18n::{negotiate, translate, translate_with, Locale, LOCALE_COOKIE};
+use crate::rendering::markdown::escape_html;
+
+/// `GET /docs/{*slug}/print`
+///
+/// Renders the document without the portal chrome. Documents the caller
+/// cannot read get a `404` page; every other request goes on to the app.
+pub async fn print_view(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let slug = match print_slug(request.method(), request.uri().path()) {
+        Some(slug) => slug,
+        None => return next.run(request).await,
+    };
+    match state
+        .document_repo
+        .find_by_slug(&format!("{slug}/print"))
+        .await
+    {
+        Ok(Some(doc)) if !doc.is_archived => return next.run(request).await,
+        Ok(_) => {}
+        Err(e) => return e.into_response(),
+    }
+
+    let (mut parts, _) = request.into_parts();
+    let Ok(OptionalAuthUser(user)) = OptionalAuthUser::from_request_parts(&mut parts, &state).await;
+    let locale = request_locale(&parts.headers);
+    let doc =
+        match crate::api::documents::readable_document(&state, &parts.headers, user, &slug).await {
+            Ok(doc) => doc,
+            Err(e) => return e.into_response(),
+        };
+    let Some(doc) = doc else {
+        let body = format!(
+            "<p>{}</p>",
+            escape_html(translate(locale, "print_page.unavailable"))
+        );
+        return page(
+            StatusCode::NOT_FOUND,
+            locale,
+            translate(locale, "print_page.unavailable_title"),
+            &body,
+            None,
+        );
+    };
+    match crate::api::documents::render_document(&state, &doc).await {
+        Ok(html) => page(
+            StatusCode::OK,
+            locale,
+            &doc.title,
+            &html,
+            Some(&footer(locale, &doc)),
+        ),
+        Err(e) => e.into_response(),
+    }
+}
+
+/// The slug of a `GET /docs/<slug>/print` request.
+fn print_slug(method: &Method, path: &str) -> Option<String> {
+    if method != Method::GET {
+        return None;
+    }
+    let slug = path.strip_prefix("/docs/")?.strip_suffix("/print")?;
+    let slug = urlencoding::decode(slug).ok()?;
+    let slug = slug.trim_matches('/');
+    (!slug.is_empty()).then(|| slug.to_string())
+}
+
+fn request_locale(headers: &HeaderMap) -> Locale {
+    let cookies = CookieJar::from_headers(headers);
+    negotiate(
+        cookies.get(LOCALE_COOKIE).map(|c| c.value()),
+        headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok()),
+    )
+}
+
+/// When and by whom the document was last updated, and where it lives.
+fn footer(locale: Locale, doc: &Document) -> String {
+    let date = doc.last_updated.format("%Y-%m-%d").to_string();
+    let updated = match &doc.last_updated_by {
+        Some(author) => translate_with(
+            locale,
+            "doc.last_updated_by",
+            &[("date", &date), ("author", author)],
+        ),
+        None => translate_with(locale, "doc.last_updated", &[("date", &date)]),
+    };
+    let source = translate_with(
+        locale,
+        "print_page.source",
+        &[("url", &format!("/docs/{}", doc.slug))],
+    );
+    format!("{} · {}", escape_html(&updated), escape_html(&source))
+}
+
+/// A standalone page in `locale` for paper, kept out of search engines and
+/// shared caches.
+fn page(
+    status: StatusCode,
+    locale: Locale,
+    title: &str,
+    body: &str,
+    footer: Option<&str>,
+) -> Response {
+    let footer = footer
+        .map(|text| {
+            format!(
+                "<footer class=\"mt-12 pt-4 border-t border-base-200 text-sm text-base-content/60\">{text}</footer>"
+            )
+        })
+        .unwrap_or_default();
+    let html = format!(
+        "<!DOCTYPE html>\
+         <html lang=\"{lang}\" data-theme=\"light\">\
+         <head>\
+         <meta charset=\"utf-8\"/>\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\"/>\
+         <meta name=\"robots\" content=\"noindex, nofollow\"/>\
+         <title>{title}</title>\
+         <link rel=\"stylesheet\" href=\"/pkg/lekton.css\"/>\
+         <link rel=\"stylesheet\" href=\"/custom.css\"/>\
+         <script src=\"/js/mermaid-loader.js\"></script>\
+         <script src=\"/js/print-view.js\" defer></script>\
+         </head>\
+         <body class=\"bg-base-100 print-view\">\
+         <main class=\"max-w-4xl mx-auto px-6 py-10\">\
+         <article class=\"prose max-w-none\">{body}</article>\
+         {footer}\
+         </main>\
+         </body>\
+         </html>",
+        lang = locale.code(),
+        title = escape_html(title),
+    );
+    (
+        status,
+        [
+            (
+                header::HeaderName::from_static("x-robots-tag"),
+                "noindex, nofollow",
+            ),
+            (header::CACHE_CONTROL, "private, no-cache"),
+            (header::VARY, "Cookie"),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff"),
+        ],
+        Html(html),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print_slug_matches_print_paths_only() {
+        assert_eq!(
+            print_slug(&Method::GET, "/docs/guides/setup/print"),
+            Some("guides/setup".into())
+        );
+        assert_eq!(
+            print_slug(&Method::GET, "/docs/caf%C3%A9/print"),
+            Some("café".into())
+        );
+        assert_eq!(print_slug(&Method::GET, "/docs/guides/setup"), None);
+        assert_eq!(print_slug(&Method::GET, "/docs/print"), None);
+        assert_eq!(print_slug(&Method::GET, "/docs//print"), None);
+        assert_eq!(print_slug(&Method::GET, "/embed/docs/a/print"), None);
+        assert_eq!(print_slug(&Method::POST, "/docs/guides/print"), None);
+    }
+
+    #[test]
+    fn test_footer_names_the_author_and_source() {
+        let doc = Document {
+            last_updated_by: Some("Ada <ops>".into()),
+            ..crate::testing::document("ops/runbook")
+        };
+        let footer = footer(Locale::En, &doc);
+        assert!(footer.contains("by Ada &lt;ops&gt;"));
+        assert!(footer.ends_with("Source: /docs/ops/runbook"));
+    }
+
+    #[tokio::test]
+    async fn test_page_loads_print_script_without_chrome() {
+        let response = page(
+            StatusCode::OK,
+            Locale::It,
+            "<Runbook>",
+            "<h1>Runbook</h1>",
+            Some("footer"),
+        );
+        assert_eq!(response.headers()["x-robots-tag"], "noindex, nofollow");
+        assert_eq!(response.headers()[header::VARY], "Cookie");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("<html lang=\"it\" data-theme=\"light\">"));
+        assert!(body.contains("<title>&lt;Runbook&gt;</title>"));
+        assert!(body.contains("<script src=\"/js/print-view.js\" defer></script>"));
+        assert!(!body.contains("<nav"));
+    }
+}
@@ -107,9 +107,16 @@ pub async fn process_prompt_ingest(
         crate::auth::token_service::TokenService::hash_token(&request.prompt_body)
     );
     let new_metadata_hash = compute_prompt_metadata_hash(&request);
-    let s3_key = format!("prompts/{}.yaml", request.slug.replace('/', "_"));
+    let s3_key = ctx.storage.key_layout().prompt_key(&request.slug);
 
     let existing = ctx.repo.find_by_slug(&request.slug).await?;
+    // Prompts stored under another key (a different `storage.key_layout`)
+    // are rewritten under the new one, and the old object deleted once no
+    // colliding slug still uses it
+    let moved_from = existing
+        .as_ref()
+        .map(|prompt| prompt.s3_key.clone())
+        .filter(|old_key| *old_key != s3_key);
     let old_content_hash = existing
         .as_ref()
         .and_then(|prompt| prompt.content_hash.clone());
@@ -132,20 +139,19 @@ pub async fn process_prompt_ingest(
         return Ok(PromptIngestResponse {
             message: "Prompt unchanged".to_string(),
             slug: request.slug,
-            s3_key,
+            s3_key: existing.map_or(s3_key, |prompt| prompt.s3_key),
             changed: false,
         });
     }
 
-    if content_changed {
-        if let Some(old_prompt) = existing.as_ref() {
+    if content_changed || moved_from.is_some() {
+        if let Some(old_prompt) = existing.as_ref().filter(|_| content_changed) {
             if let Some(old_hash) = old_prompt.content_hash.as_ref() {
                 let version_num = ctx.version_repo.next_version_number(&request.slug).await?;
-                let history_key = format!(
-                    "prompts/history/{}/{}.yaml",
-                    request.slug.replace('/', "_"),
-                    version_num
-                );
+                let history_key = ctx
+                    .storage
+                    .key_layout()
+                    .prompt_version_key(&request.slug, version_num);
 
                 if let Ok(Some(old_content)) = ctx.storage.get_object(&old_prompt.s3_key).await {
                     if let Err(err) = ctx.storage.put_object(&history_key, old_content).await {
@@ -212,6 +218,20 @@ pub async fn process_prompt_ingest(
 
     ctx.repo.create_or_update(prompt).await?;
 
+    if let Some(old_key) = moved_from {
+        let still_used = ctx
+            .repo
+            .find_by_slug_prefix("")
+            .await?
+            .iter()
+            .any(|prompt| prompt.s3_key == old_key);
+        if !still_used {
+            if let Err(err) = ctx.storage.delete_object(&old_key).await {
+                tracing::warn!("Failed to delete moved prompt object {old_key}: {err}");
+            }
+        }
+    }
+
     Ok(PromptIngestResponse {
         message: "Prompt ingested successfully".to_string(),
         slug: request.slug,
@@ -560,7 +580,7 @@ mod tests {
     use crate::db::service_token_models::ServiceToken;
     use crate::db::service_token_repository::ServiceTokenRepository;
     use crate::storage::client::StorageClient;
-    use crate::test_utils::MockStorage;
+    use crate::testing::InMemoryStorage;
 
     struct MockAccessLevelRepo;
 
@@ -731,6 +751,8 @@ mod tests {
                     created_at: Utc::now(),
                     last_used_at: None,
                     is_active: true,
+                    project: None,
+                    access_levels: vec![],
                 },
                 touched: Mutex::new(vec![]),
             }
@@ -810,7 +832,7 @@ mod tests {
 
     fn make_context<'a>(
         repo: &'a MockPromptRepo,
-        storage: &'a MockStorage,
+        storage: &'a InMemoryStorage,
         access_levels: &'a MockAccessLevelRepo,
         tokens: &'a MockServiceTokenRepo,
         versions: &'a MockPromptVersionRepo,
@@ -828,7 +850,7 @@ mod tests {
     #[tokio::test]
     async fn prompt_ingest_stores_hashes_and_blob() {
         let repo = MockPromptRepo::default();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
         let access_levels = MockAccessLevelRepo;
         let tokens = MockServiceTokenRepo::new();
         let versions = MockPromptVersionRepo::default();
@@ -852,7 +874,7 @@ mod tests {
     #[tokio::test]
     async fn prompt_ingest_unchanged_returns_changed_false() {
         let repo = MockPromptRepo::default();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
         let access_levels = MockAccessLevelRepo;
         let tokens = MockServiceTokenRepo::new();
         let versions = MockPromptVersionRepo::default();
@@ -875,7 +897,7 @@ mod tests {
     #[tokio::test]
     async fn prompt_ingest_body_change_creates_version() {
         let repo = MockPromptRepo::default();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
         let access_levels = MockAccessLevelRepo;
         let tokens = MockServiceTokenRepo::new();
         let versions = MockPromptVersionRepo::default();
@@ -981,7 +1003,7 @@ mod tests {
     #[tokio::test]
     async fn prompt_ingest_rejects_duplicate_variables() {
         let repo = MockPromptRepo::default();
-        let storage = MockStorage::new();
+        let storage = InMemoryStorage::new();
         let access_levels = MockAccessLevelRepo;
         let tokens = MockServiceTokenRepo::new();
         let versions = MockPromptVersionRepo::default();
@@ -0,0 +1,76 @@
+//! Uploads rejected by the malware scanner (see [`crate::scanning`]) and
+//! documents refused for containing credentials (see [`crate::secrets`]).
+//!
+//! All routes require an authenticated admin user (`is_admin = true`).
+//!
+//! | Method | Path                               | Description                                    |
+//! |--------|------------------------------------|------------------------------------------------|
+//! | GET    | `/api/v1/admin/quarantine`         | Quarantined uploads, newest first (`?limit=<n>`) |
+//! | DELETE | `/api/v1/admin/quarantine/{id}`    | Delete a quarantined upload and its content    |
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use crate::app::AppState;
+use crate::auth::extractor::RequiredAuthUser;
+use crate::db::quarantine_repository::QuarantinedUpload;
+use crate::error::AppError;
+
+/// Uploads returned when `limit` is not given.
+const DEFAULT_QUARANTINE_LIMIT: i64 = 100;
+/// Upper bound of `limit`.
+const MAX_QUARANTINE_LIMIT: i64 = 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct QuarantineQuery {
+    pub limit: Option<i64>,
+}
+
+fn require_admin(user: &crate::auth::models::AuthenticatedUser) -> Result<(), AppError> {
+    if user.is_admin {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden("Admin privileges required".into()))
+    }
+}
+
+/// `GET /api/v1/admin/quarantine`
+pub async fn list_quarantine_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Query(query): Query<QuarantineQuery>,
+) -> Result<Json<Vec<QuarantinedUpload>>, AppError> {
+    require_admin(&user)?;
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_QUARANTINE_LIMIT)
+        .clamp(1, MAX_QUARANTINE_LIMIT);
+    Ok(Json(state.quarantine_repo.list_recent(limit).await?))
+}
+
+/// `DELETE /api/v1/admin/quarantine/{id}`
+pub async fn delete_quarantined_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    require_admin(&user)?;
+
+    let upload = state
+        .quarantine_repo
+        .find_by_id(&id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Quarantined upload '{id}' not found")))?;
+    state.storage_client.delete_object(&upload.s3_key).await?;
+    state.quarantine_repo.delete(&id).await?;
+    tracing::info!(
+        actor = %user.email,
+        id = %id,
+        target_key = %upload.target_key,
+        "Deleted quarantined upload"
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
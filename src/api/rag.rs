@@ -6,6 +6,7 @@
 //! | GET    | `/api/v1/admin/rag/reindex/status`        | Poll re-index progress               |
 //! | GET    | `/api/v1/admin/rag/feedback`              | Export feedback (paginated, filtered)|
 //! | POST   | `/api/v1/rag/chat`                        | Chat with RAG (SSE stream)           |
+//! | POST   | `/api/v1/ask`                             | Answer a question with citations     |
 //! | GET    | `/api/v1/rag/sessions`                    | List user's chat sessions            |
 //! | DELETE | `/api/v1/rag/sessions/{id}`               | Delete a chat session                |
 //! | GET    | `/api/v1/rag/sessions/{id}/messages`      | Get messages for a session           |
@@ -149,6 +150,8 @@ pub async fn chat_handler(
     State(state): State<AppState>,
     Json(request): Json<ChatRequest>,
 ) -> Result<Sse<impl futures::Stream<Item = Result<Event, Infallible>>>, AppError> {
+    crate::features::require_chat(&state).await?;
+
     let chat_svc = state
         .chat_service
         .as_ref()
@@ -169,6 +172,36 @@ pub async fn chat_handler(
     Ok(Sse::new(sse_stream))
 }
 
+#[derive(Deserialize)]
+pub struct AskRequest {
+    pub question: String,
+}
+
+/// `POST /api/v1/ask` — answer a single question from the documentation the
+/// caller can read, with numbered citations (requires auth). Unlike the chat
+/// endpoint the answer is returned as one JSON object and nothing is stored.
+pub async fn ask_handler(
+    RequiredAuthUser(user): RequiredAuthUser,
+    State(state): State<AppState>,
+    Json(request): Json<AskRequest>,
+) -> Result<Json<crate::rag::chat::Answer>, AppError> {
+    crate::features::require_chat(&state).await?;
+
+    let chat_svc = state
+        .chat_service
+        .as_ref()
+        .ok_or_else(|| AppError::BadRequest("RAG chat is not enabled".into()))?;
+
+    let question = request.question.trim();
+    if question.is_empty() {
+        return Err(AppError::BadRequest("Question must not be empty".into()));
+    }
+
+    let user_ctx = build_user_context(&state, &user).await?;
+    let answer = chat_svc.answer(&user_ctx, question).await?;
+    Ok(Json(answer))
+}
+
 /// `GET /api/v1/rag/sessions` — list the authenticated user's chat sessions.
 pub async fn list_sessions_handler(
     RequiredAuthUser(user): RequiredAuthUser,
@@ -494,7 +527,7 @@ pub async fn admin_list_feedback_handler(
 }
 
 /// Build a [`UserContext`] from the authenticated user for access-level filtering.
-async fn build_user_context(
+pub(crate) async fn build_user_context(
     state: &AppState,
     user: &crate::auth::models::AuthenticatedUser,
 ) -> Result<UserContext, AppError> {
@@ -526,30 +559,28 @@ async fn filter_source_references(
     sources: Vec<SourceReference>,
 ) -> Result<Vec<SourceReference>, AppError> {
     let (allowed_levels, include_draft) = user_ctx.document_visibility();
-    let mut filtered = Vec::with_capacity(sources.len());
-
-    for source in sources {
-        let Some(document) = state
-            .document_repo
-            .find_by_slug(&source.document_slug)
-            .await?
-        else {
-            continue;
-        };
-
-        if document.is_archived {
-            continue;
-        }
-
-        if crate::app::doc_is_accessible(
-            &document.access_level,
-            document.is_draft,
-            allowed_levels.as_deref(),
-            include_draft,
-        ) {
-            filtered.push(source);
-        }
-    }
+    let mut slugs: Vec<String> = sources.iter().map(|s| s.document_slug.clone()).collect();
+    slugs.sort();
+    slugs.dedup();
+    let readable: Vec<String> = state
+        .document_repo
+        .find_by_slugs(&slugs)
+        .await?
+        .into_iter()
+        .filter(|document| {
+            !document.is_archived
+                && crate::app::doc_is_accessible(
+                    &document.access_level,
+                    document.is_draft,
+                    allowed_levels.as_deref(),
+                    include_draft,
+                )
+        })
+        .map(|document| document.slug)
+        .collect();
 
-    Ok(filtered)
+    Ok(sources
+        .into_iter()
+        .filter(|source| readable.contains(&source.document_slug))
+        .collect())
 }
@@ -4,8 +4,9 @@ use serde::{Deserialize, Serialize};
 use crate::db::access_level_repository::AccessLevelRepository;
 #[cfg(feature = "ssr")]
 use crate::db::models::{Schema, SchemaVersion};
+use crate::db::schema_repository::SchemaSort;
 #[cfg(feature = "ssr")]
-use crate::db::schema_repository::SchemaRepository;
+use crate::db::schema_repository::{SchemaListParams, SchemaRepository};
 #[cfg(feature = "ssr")]
 use crate::error::AppError;
 #[cfg(feature = "ssr")]
@@ -22,10 +23,23 @@ pub struct IngestSchemaRequest {
     pub status: String,
     #[serde(default = "default_public")]
     pub access_level: String,
+    /// Restrict the whole schema to holders of this access level (typically
+    /// the owning team's), on top of each version's `access_level`.
+    #[serde(default)]
+    pub owner_access_level: Option<String>,
     #[serde(default)]
     pub service_owner: String,
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Short summary of what the API is for.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Email address or URL of the people to ask about the API.
+    #[serde(default)]
+    pub contact: Option<String>,
+    /// Repository holding the API definition (`http(s)` URL).
+    #[serde(default)]
+    pub repository_url: Option<String>,
     pub content: String,
 }
 
@@ -46,6 +60,15 @@ pub struct IngestSchemaResponse {
     pub s3_key: String,
     #[serde(default = "default_true")]
     pub changed: bool,
+    /// `true` when the request was a dry run and nothing was written.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    /// What changed (or would change, on a dry run).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<String>,
+    /// Non-fatal problems found during validation (dry runs only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 fn default_true() -> bool {
@@ -77,7 +100,7 @@ pub struct SchemaSyncResponse {
 }
 
 /// Response for listing schemas.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaListItem {
     pub name: String,
     pub schema_type: String,
@@ -86,6 +109,50 @@ pub struct SchemaListItem {
     pub version_count: usize,
     #[serde(default)]
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub contact: Option<String>,
+    #[serde(default)]
+    pub repository_url: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Filters, sort order and paging of a schema listing
+/// (`GET /api/v1/schemas?type=openapi&owner=payments&tag=billing&q=ledger&sort=updated_at&page=0&per_page=24`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaListQuery {
+    /// Case-insensitive substring of the schema name.
+    #[serde(default)]
+    pub q: Option<String>,
+    #[serde(default, rename = "type")]
+    pub schema_type: Option<String>,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub sort: SchemaSort,
+    /// Zero-based page index.
+    #[serde(default)]
+    pub page: u64,
+    /// Items per page, at most 100; every match when unset.
+    #[serde(default)]
+    pub per_page: Option<u64>,
+}
+
+/// A page of the schema listing, with the owners and tags to filter by.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaListResult {
+    pub items: Vec<SchemaListItem>,
+    /// Schemas matching the filters, across all pages.
+    pub total: u64,
+    pub page: u64,
+    /// Effective page size; equals `total` when unpaginated.
+    pub per_page: u64,
+    pub service_owners: Vec<String>,
+    pub tags: Vec<String>,
 }
 
 /// Response for a single schema with all versions.
@@ -96,6 +163,14 @@ pub struct SchemaDetail {
     pub service_owner: String,
     #[serde(default)]
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub contact: Option<String>,
+    #[serde(default)]
+    pub repository_url: Option<String>,
+    #[serde(default)]
+    pub owner_access_level: Option<String>,
     pub versions: Vec<SchemaVersionInfo>,
 }
 
@@ -108,6 +183,91 @@ pub struct SchemaVersionInfo {
     pub s3_key: String,
 }
 
+/// Link target for a schema `contact`: URLs are used as-is, anything else is
+/// treated as an email address.
+pub fn contact_href(contact: &str) -> String {
+    if contact.starts_with("https://")
+        || contact.starts_with("http://")
+        || contact.starts_with("mailto:")
+    {
+        contact.to_string()
+    } else {
+        format!("mailto:{contact}")
+    }
+}
+
+/// Path of the raw download of a schema version.
+pub fn schema_raw_path(name: &str, version: &str) -> String {
+    format!("/api/v1/schemas/{name}/{version}/raw")
+}
+
+/// Fragment identifying an operation in the schema viewer, e.g.
+/// `get-/users/{id}`.
+pub fn operation_anchor(method: &str, path: &str) -> String {
+    format!("{}-{path}", method.to_lowercase())
+}
+
+/// An operation of the latest visible version of a schema, matching a
+/// search.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaOperationHit {
+    pub schema: String,
+    pub schema_type: String,
+    pub version: String,
+    pub method: String,
+    pub path: String,
+    pub summary: Option<String>,
+}
+
+impl SchemaOperationHit {
+    /// Viewer deep link scrolled to the operation,
+    /// `/schemas/<name>/<version>#<method>-<path>`.
+    pub fn href(&self) -> String {
+        format!(
+            "/schemas/{}/{}#{}",
+            self.schema,
+            self.version,
+            operation_anchor(&self.method, &self.path)
+        )
+    }
+}
+
+/// `Content-Type` and `Content-Disposition` headers for downloading `content`
+/// as `<last name segment>-<version>.json|yaml`.
+#[cfg(feature = "ssr")]
+fn raw_download_headers(
+    name: &str,
+    version: &str,
+    content: &str,
+) -> [(axum::http::HeaderName, String); 2] {
+    let (content_type, extension) = if content.trim_start().starts_with('{') {
+        ("application/json", "json")
+    } else {
+        ("application/yaml", "yaml")
+    };
+    let base = name.rsplit('/').next().unwrap_or(name);
+    let filename: String = format!("{base}-{version}.{extension}")
+        .chars()
+        .map(|c| {
+            if c == '"' || c == '\\' || c.is_control() {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    [
+        (
+            axum::http::header::CONTENT_TYPE,
+            format!("{content_type}; charset=utf-8"),
+        ),
+        (
+            axum::http::header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{filename}\""),
+        ),
+    ]
+}
+
 #[cfg(feature = "ssr")]
 const VALID_SCHEMA_TYPES: &[&str] = &["openapi", "asyncapi", "jsonschema"];
 #[cfg(feature = "ssr")]
@@ -118,8 +278,14 @@ pub struct SchemaIngestContext<'a> {
     pub schema_repo: &'a dyn SchemaRepository,
     pub storage: &'a dyn StorageClient,
     pub access_level_repo: &'a dyn AccessLevelRepository,
+    /// Projects, whose allowed access levels restrict schemas in their namespace.
+    pub project_repo: Option<&'a dyn crate::db::project_repository::ProjectRepository>,
     pub service_token_repo: &'a dyn crate::db::service_token_repository::ServiceTokenRepository,
+    /// Activity stream notifications.
+    pub events: Option<&'a crate::api::events::ActivityEvents>,
     pub legacy_token: Option<&'a str>,
+    /// Validate and report what would change without writing anything.
+    pub dry_run: bool,
 }
 
 #[cfg(feature = "ssr")]
@@ -135,11 +301,20 @@ fn schema_level_visible(access_level: &str, allowed_levels: Option<&[String]>) -
     }
 }
 
+/// Whether callers with `allowed_levels` pass a schema's owner restriction.
+#[cfg(feature = "ssr")]
+fn owner_visible(owner_access_level: Option<&str>, allowed_levels: Option<&[String]>) -> bool {
+    owner_access_level.is_none_or(|level| schema_level_visible(level, allowed_levels))
+}
+
 #[cfg(feature = "ssr")]
-fn visible_versions<'a>(
+pub(crate) fn visible_versions<'a>(
     schema: &'a Schema,
     allowed_levels: Option<&[String]>,
 ) -> Vec<&'a SchemaVersion> {
+    if !owner_visible(schema.owner_access_level.as_deref(), allowed_levels) {
+        return vec![];
+    }
     schema
         .versions
         .iter()
@@ -148,8 +323,67 @@ fn visible_versions<'a>(
         .collect()
 }
 
+/// The version readers see by default: the latest visible one that is not
+/// deprecated, else the latest visible one.
+#[cfg(feature = "ssr")]
+pub(crate) fn latest_visible_version<'a>(
+    schema: &'a Schema,
+    allowed_levels: Option<&[String]>,
+) -> Option<&'a SchemaVersion> {
+    let visible = visible_versions(schema, allowed_levels);
+    visible
+        .iter()
+        .rfind(|v| v.status != "deprecated")
+        .or_else(|| visible.last())
+        .copied()
+}
+
+/// Trim optional metadata, treating blank values as unset.
+#[cfg(feature = "ssr")]
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn is_http_url(value: &str) -> bool {
+    (value.starts_with("https://") || value.starts_with("http://"))
+        && !value.contains(char::is_whitespace)
+}
+
+/// Contacts and repositories are rendered as links, so only `http(s)`,
+/// `mailto:` and plain email addresses are accepted.
+#[cfg(feature = "ssr")]
+fn validate_schema_links(
+    contact: Option<&str>,
+    repository_url: Option<&str>,
+) -> Result<(), AppError> {
+    if let Some(contact) = contact {
+        let email = contact.strip_prefix("mailto:").unwrap_or(contact);
+        let is_email = email
+            .split_once('@')
+            .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'))
+            && !email.contains(char::is_whitespace)
+            && !email.contains(':');
+        if !is_email && !is_http_url(contact) {
+            return Err(AppError::BadRequest(format!(
+                "Invalid contact '{contact}': expected an email address or an http(s) URL"
+            )));
+        }
+    }
+    if let Some(url) = repository_url {
+        if !is_http_url(url) {
+            return Err(AppError::BadRequest(format!(
+                "Invalid repository URL '{url}': expected an http(s) URL"
+            )));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(feature = "ssr")]
-fn compute_schema_content_hash(content: &str) -> String {
+pub(crate) fn compute_schema_content_hash(content: &str) -> String {
     format!(
         "sha256:{}",
         crate::auth::token_service::TokenService::hash_token(content)
@@ -170,10 +404,10 @@ async fn validate_schema_token(
     ctx: &SchemaIngestContext<'_>,
     raw_token: &str,
     schema_name: &str,
-) -> Result<(), AppError> {
+) -> Result<String, AppError> {
     if let Some(legacy) = ctx.legacy_token {
         if !legacy.is_empty() && raw_token == legacy {
-            return Ok(());
+            return Ok("legacy".to_string());
         }
     }
 
@@ -207,7 +441,7 @@ async fn validate_schema_token(
         );
     }
 
-    Ok(())
+    Ok(token.name)
 }
 
 /// Core schema ingestion logic.
@@ -216,7 +450,7 @@ pub async fn process_schema_ingest(
     ctx: &SchemaIngestContext<'_>,
     request: IngestSchemaRequest,
 ) -> Result<IngestSchemaResponse, AppError> {
-    validate_schema_token(ctx, &request.service_token, &request.name).await?;
+    let actor = validate_schema_token(ctx, &request.service_token, &request.name).await?;
 
     if request.name.trim().is_empty() {
         return Err(AppError::BadRequest("Schema name cannot be empty".into()));
@@ -258,6 +492,37 @@ pub async fn process_schema_ingest(
             "Unknown access level: '{access_level}'"
         )));
     }
+    if let Some(project_repo) = ctx.project_repo {
+        crate::db::project_repository::check_project_access_level(
+            project_repo,
+            &request.name,
+            &access_level,
+        )
+        .await?;
+    }
+    let owner_access_level = request
+        .owner_access_level
+        .as_deref()
+        .map(|level| level.trim().to_lowercase())
+        .filter(|level| !level.is_empty());
+    if let Some(level) = &owner_access_level {
+        if !ctx.access_level_repo.exists(level).await? {
+            return Err(AppError::BadRequest(format!(
+                "Unknown owner access level: '{level}'"
+            )));
+        }
+    }
+
+    let request = IngestSchemaRequest {
+        description: non_blank(request.description),
+        contact: non_blank(request.contact),
+        repository_url: non_blank(request.repository_url),
+        ..request
+    };
+    validate_schema_links(
+        request.contact.as_deref(),
+        request.repository_url.as_deref(),
+    )?;
 
     let new_content_hash = compute_schema_content_hash(&request.content);
     let new_metadata_hash = compute_schema_metadata_hash(&request.status, &access_level);
@@ -296,7 +561,12 @@ pub async fn process_schema_ingest(
             || version.metadata_hash.as_deref() != Some(new_metadata_hash.as_str())
     });
     let schema_metadata_changed = existing.as_ref().is_none_or(|schema| {
-        schema.service_owner != request.service_owner || schema.tags != request.tags
+        schema.service_owner != request.service_owner
+            || schema.tags != request.tags
+            || schema.description != request.description
+            || schema.contact != request.contact
+            || schema.repository_url != request.repository_url
+            || schema.owner_access_level != owner_access_level
     });
 
     if !content_changed && !version_metadata_changed && !schema_metadata_changed {
@@ -306,6 +576,32 @@ pub async fn process_schema_ingest(
             version: request.version,
             s3_key,
             changed: false,
+            dry_run: ctx.dry_run,
+            changes: vec![],
+            warnings: vec![],
+        });
+    }
+
+    let changes = describe_schema_changes(
+        existing.as_ref(),
+        existing_version,
+        content_changed,
+        &request,
+        &access_level,
+        owner_access_level.as_deref(),
+    );
+
+    if ctx.dry_run {
+        let warnings = schema_content_warnings(&request.content);
+        return Ok(IngestSchemaResponse {
+            message: "Dry run: schema version would be ingested".to_string(),
+            name: request.name,
+            version: request.version,
+            s3_key,
+            changed: true,
+            dry_run: true,
+            changes,
+            warnings,
         });
     }
 
@@ -332,6 +628,12 @@ pub async fn process_schema_ingest(
         is_archived: false,
         endpoints,
     };
+    let activity = crate::api::events::ActivityEvent::schema_ingested(
+        &request.name,
+        &updated_version,
+        owner_access_level.as_deref(),
+        &actor,
+    );
 
     if let Some(version) = versions.iter_mut().find(|v| v.version == request.version) {
         *version = updated_version;
@@ -344,19 +646,98 @@ pub async fn process_schema_ingest(
         schema_type: request.schema_type,
         service_owner: request.service_owner,
         tags: request.tags,
+        description: request.description,
+        contact: request.contact,
+        repository_url: request.repository_url,
+        owner_access_level,
+        updated_at: Some(activity.timestamp),
         versions,
     };
     ctx.schema_repo.create_or_update(schema).await?;
 
+    if let Some(events) = ctx.events {
+        events.publish(activity);
+    }
+
     Ok(IngestSchemaResponse {
         message: "Schema version ingested successfully".to_string(),
         name: request.name,
         version: request.version,
         s3_key,
         changed: true,
+        dry_run: false,
+        changes,
+        warnings: vec![],
     })
 }
 
+/// List what an ingest changes: `created` for a new schema, `version_added`
+/// for a new version, otherwise the changed fields.
+#[cfg(feature = "ssr")]
+fn describe_schema_changes(
+    existing: Option<&Schema>,
+    existing_version: Option<&SchemaVersion>,
+    content_changed: bool,
+    request: &IngestSchemaRequest,
+    access_level: &str,
+    owner_access_level: Option<&str>,
+) -> Vec<String> {
+    let Some(schema) = existing else {
+        return vec!["created".to_string()];
+    };
+    let mut changes: Vec<String> = match existing_version {
+        None => vec!["version_added".to_string()],
+        Some(version) => [
+            ("content", content_changed),
+            ("status", version.status != request.status),
+            ("access_level", version.access_level != access_level),
+            ("unarchived", version.is_archived),
+        ]
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(field, _)| field.to_string())
+        .collect(),
+    };
+    if schema.service_owner != request.service_owner {
+        changes.push("service_owner".to_string());
+    }
+    if schema.tags != request.tags {
+        changes.push("tags".to_string());
+    }
+    for (field, changed) in [
+        ("description", schema.description != request.description),
+        ("contact", schema.contact != request.contact),
+        (
+            "repository_url",
+            schema.repository_url != request.repository_url,
+        ),
+    ] {
+        if changed {
+            changes.push(field.to_string());
+        }
+    }
+    if schema.owner_access_level.as_deref() != owner_access_level {
+        changes.push("owner_access_level".to_string());
+    }
+    changes
+}
+
+/// Check that schema content parses; real ingests accept unparseable content
+/// (it is stored as-is), so this is only reported by dry runs.
+#[cfg(feature = "ssr")]
+fn schema_content_warnings(content: &str) -> Vec<String> {
+    let parsed = serde_json::from_str::<serde_json::Value>(content)
+        .ok()
+        .or_else(|| serde_yaml::from_str::<serde_json::Value>(content).ok());
+    match parsed {
+        None => vec!["Content is neither valid JSON nor valid YAML".to_string()],
+        Some(value) if !value.is_object() => {
+            vec!["Content is not a JSON/YAML object".to_string()]
+        }
+        Some(_) => vec![],
+    }
+}
+
 /// Core sync logic for schemas.
 #[cfg(feature = "ssr")]
 pub async fn process_schema_sync(
@@ -494,29 +875,113 @@ pub async fn process_list_schemas(
     let schemas = schema_repo.list_summaries().await?;
 
     Ok(schemas
-        .into_iter()
-        .filter_map(|schema| {
-            let visible = visible_versions(&schema, allowed_levels);
-            if visible.is_empty() {
-                return None;
-            }
+        .iter()
+        .filter_map(|schema| schema_list_item(schema, allowed_levels))
+        .collect())
+}
 
-            let latest = visible
+/// Core logic to list one filtered, sorted page of the visible schemas.
+#[cfg(feature = "ssr")]
+pub async fn process_list_schemas_page(
+    schema_repo: &dyn SchemaRepository,
+    query: SchemaListQuery,
+    allowed_levels: Option<&[String]>,
+) -> Result<SchemaListResult, AppError> {
+    let params = SchemaListParams {
+        allowed_levels: allowed_levels.map(<[String]>::to_vec),
+        schema_type: non_blank(query.schema_type),
+        service_owner: non_blank(query.owner),
+        tag: non_blank(query.tag),
+        name_query: non_blank(query.q),
+        sort: query.sort,
+        page: query.page,
+        per_page: query
+            .per_page
+            .map(|n| n.clamp(1, crate::db::schema_repository::MAX_SCHEMAS_PER_PAGE)),
+    };
+    let page = schema_repo.list_page(&params).await?;
+
+    Ok(SchemaListResult {
+        items: page
+            .items
+            .iter()
+            .filter_map(|schema| schema_list_item(schema, allowed_levels))
+            .collect(),
+        total: page.total,
+        page: params.page,
+        per_page: params.per_page.unwrap_or(page.total),
+        service_owners: page.service_owners,
+        tags: page.tags,
+    })
+}
+
+/// List entry for `schema`, or `None` when the caller sees none of its
+/// versions.
+#[cfg(feature = "ssr")]
+fn schema_list_item(schema: &Schema, allowed_levels: Option<&[String]>) -> Option<SchemaListItem> {
+    let visible = visible_versions(schema, allowed_levels);
+    if visible.is_empty() {
+        return None;
+    }
+
+    let latest = visible
+        .iter()
+        .rfind(|v| v.status != "deprecated")
+        .or_else(|| visible.last())
+        .map(|v| v.version.clone());
+
+    Some(SchemaListItem {
+        name: schema.name.clone(),
+        schema_type: schema.schema_type.clone(),
+        service_owner: schema.service_owner.clone(),
+        latest_version: latest,
+        version_count: visible.len(),
+        tags: schema.tags.clone(),
+        description: schema.description.clone(),
+        contact: schema.contact.clone(),
+        repository_url: schema.repository_url.clone(),
+        updated_at: schema.updated_at,
+    })
+}
+
+/// Maximum operations returned by [`process_search_operations`].
+pub const MAX_OPERATION_HITS: usize = 20;
+
+/// Core logic to search the operations (OpenAPI paths, AsyncAPI channels)
+/// of the latest visible version of every schema, by path or summary.
+#[cfg(feature = "ssr")]
+pub async fn process_search_operations(
+    schema_repo: &dyn SchemaRepository,
+    query: &str,
+    allowed_levels: Option<&[String]>,
+) -> Result<Vec<SchemaOperationHit>, AppError> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+    let schemas = schema_repo.list_all().await?;
+    let hits = schemas
+        .iter()
+        .filter_map(|schema| {
+            latest_visible_version(schema, allowed_levels).map(|version| (schema, version))
+        })
+        .flat_map(|(schema, version)| {
+            version
+                .endpoints
                 .iter()
-                .rfind(|v| v.status != "deprecated")
-                .or_else(|| visible.last())
-                .map(|v| v.version.clone());
-
-            Some(SchemaListItem {
-                name: schema.name.clone(),
-                schema_type: schema.schema_type.clone(),
-                service_owner: schema.service_owner.clone(),
-                latest_version: latest,
-                version_count: visible.len(),
-                tags: schema.tags.clone(),
-            })
+                .filter(|endpoint| endpoint.matches(query))
+                .map(move |endpoint| SchemaOperationHit {
+                    schema: schema.name.clone(),
+                    schema_type: schema.schema_type.clone(),
+                    version: version.version.clone(),
+                    method: endpoint.method.clone(),
+                    path: endpoint.path.clone(),
+                    summary: endpoint.summary.clone(),
+                })
         })
-        .collect())
+        .take(MAX_OPERATION_HITS)
+        .collect();
+    Ok(hits)
 }
 
 /// Core logic to get a schema's details.
@@ -540,6 +1005,10 @@ pub async fn process_get_schema(
         schema_type: schema.schema_type.clone(),
         service_owner: schema.service_owner.clone(),
         tags: schema.tags.clone(),
+        description: schema.description.clone(),
+        contact: schema.contact.clone(),
+        repository_url: schema.repository_url.clone(),
+        owner_access_level: schema.owner_access_level.clone(),
         versions: versions
             .into_iter()
             .map(|v| SchemaVersionInfo {
@@ -564,7 +1033,11 @@ pub async fn process_get_schema_content(
     let ver = schema_repo
         .find_version_s3_key(name, version)
         .await?
-        .filter(|v| !v.is_archived && schema_level_visible(&v.access_level, allowed_levels))
+        .filter(|v| {
+            !v.is_archived
+                && schema_level_visible(&v.access_level, allowed_levels)
+                && owner_visible(v.owner_access_level.as_deref(), allowed_levels)
+        })
         .ok_or_else(|| {
             AppError::NotFound(format!(
                 "Version '{}' not found for schema '{}'",
@@ -691,7 +1164,8 @@ fn extract_asyncapi_endpoints(spec: &serde_json::Value) -> Vec<crate::db::models
         }
     }
 
-    // AsyncAPI v3: top-level operations with action + channel $ref
+    // AsyncAPI v3: top-level operations with action + channel $ref, named
+    // by the channel's address (topic) when it has one
     if let Some(operations) = spec.get("operations").and_then(|o| o.as_object()) {
         for (op_name, operation) in operations {
             let action = operation
@@ -699,12 +1173,18 @@ fn extract_asyncapi_endpoints(spec: &serde_json::Value) -> Vec<crate::db::models
                 .and_then(|a| a.as_str())
                 .unwrap_or("send")
                 .to_string();
-            let channel = operation
+            let channel_id = operation
                 .get("channel")
                 .and_then(|c| c.get("$ref"))
                 .and_then(|r| r.as_str())
                 .and_then(|r| r.strip_prefix("#/channels/"))
-                .unwrap_or(op_name.as_str())
+                .unwrap_or(op_name.as_str());
+            let channel = spec
+                .get("channels")
+                .and_then(|c| c.get(channel_id))
+                .and_then(|c| c.get("address"))
+                .and_then(|a| a.as_str())
+                .unwrap_or(channel_id)
                 .to_string();
             let summary = operation
                 .get("summary")
@@ -726,6 +1206,7 @@ fn extract_asyncapi_endpoints(spec: &serde_json::Value) -> Vec<crate::db::models
 #[cfg(feature = "ssr")]
 pub async fn ingest_schema_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    axum::extract::Query(query): axum::extract::Query<crate::api::ingest::IngestQuery>,
     axum::Json(request): axum::Json<IngestSchemaRequest>,
 ) -> Result<axum::Json<IngestSchemaResponse>, AppError> {
     let response = process_schema_ingest(
@@ -733,8 +1214,11 @@ pub async fn ingest_schema_handler(
             schema_repo: state.schema_repo.as_ref(),
             storage: state.storage_client.as_ref(),
             access_level_repo: state.access_level_repo.as_ref(),
+            project_repo: Some(state.project_repo.as_ref()),
             service_token_repo: state.service_token_repo.as_ref(),
+            events: Some(&state.activity_events),
             legacy_token: Some(&state.service_token),
+            dry_run: query.dry_run,
         },
         request,
     )
@@ -760,16 +1244,25 @@ pub async fn schema_sync_handler(
     Ok(axum::Json(response))
 }
 
-/// Axum handler for `GET /api/v1/schemas`.
+/// Axum handler for `GET /api/v1/schemas`: filtered by [`SchemaListQuery`],
+/// with the number of matches in `x-total-count`.
 #[cfg(feature = "ssr")]
 pub async fn list_schemas_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
     crate::auth::extractor::OptionalAuthUser(user): crate::auth::extractor::OptionalAuthUser,
-) -> Result<axum::Json<Vec<SchemaListItem>>, AppError> {
+    axum::extract::Query(query): axum::extract::Query<SchemaListQuery>,
+) -> Result<axum::response::Response, AppError> {
+    use axum::response::IntoResponse;
+
     let allowed_levels = schema_visibility_from_request(&state, user.as_ref()).await?;
-    let result =
-        process_list_schemas(state.schema_repo.as_ref(), allowed_levels.as_deref()).await?;
-    Ok(axum::Json(result))
+    let page =
+        process_list_schemas_page(state.schema_repo.as_ref(), query, allowed_levels.as_deref())
+            .await?;
+    Ok((
+        [("x-total-count", page.total.to_string())],
+        axum::Json(page.items),
+    )
+        .into_response())
 }
 
 /// Axum handler for `GET /api/v1/schemas/:name`.
@@ -815,7 +1308,8 @@ pub async fn get_schema_version_handler(
 ///
 /// Resolution order:
 /// 1. Treat the full path as the schema name and return schema detail.
-/// 2. If not found, split on the last `/` and treat the suffix as `version`.
+/// 2. If it ends in `/raw`, download `<name>/<version>` as a file.
+/// 3. Otherwise split on the last `/` and treat the suffix as `version`.
 #[cfg(feature = "ssr")]
 pub async fn get_schema_route_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
@@ -832,6 +1326,40 @@ pub async fn get_schema_route_handler(
         Err(err) => return Err(err),
     }
 
+    if let Some((name, version)) = rest
+        .strip_suffix("/raw")
+        .and_then(|path| path.rsplit_once('/'))
+    {
+        match process_get_schema_content(
+            state.schema_repo.as_ref(),
+            state.storage_client.as_ref(),
+            name,
+            version,
+            allowed_levels.as_deref(),
+        )
+        .await
+        {
+            Ok(content) => {
+                let [content_type, disposition] = raw_download_headers(name, version, &content);
+                return Ok((
+                    [
+                        content_type,
+                        disposition,
+                        (
+                            axum::http::header::CACHE_CONTROL,
+                            "private, max-age=3600".to_string(),
+                        ),
+                    ],
+                    content,
+                )
+                    .into_response());
+            }
+            // A version literally named `raw`
+            Err(AppError::NotFound(_)) => {}
+            Err(err) => return Err(err),
+        }
+    }
+
     let Some((name, version)) = rest.rsplit_once('/') else {
         return Err(AppError::NotFound(format!("Schema '{}' not found", rest)));
     };
@@ -917,261 +1445,31 @@ pub async fn schema_endpoint_reindex_status_handler(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use async_trait::async_trait;
-    use std::sync::Mutex;
+    use std::sync::LazyLock;
 
     use crate::db::auth_models::AccessLevelEntity;
-    use crate::db::service_token_models::ServiceToken;
-    use crate::db::service_token_repository::ServiceTokenRepository;
-    use crate::test_utils::MockStorage;
-    use chrono::Utc;
-
-    struct MockAccessLevelRepo;
-
-    #[async_trait]
-    impl AccessLevelRepository for MockAccessLevelRepo {
-        async fn create(&self, _: AccessLevelEntity) -> Result<(), AppError> {
-            Ok(())
-        }
-        async fn find_by_name(&self, name: &str) -> Result<Option<AccessLevelEntity>, AppError> {
-            Ok(if name.is_empty() {
-                None
-            } else {
-                Some(AccessLevelEntity {
-                    name: name.to_string(),
-                    label: name.to_string(),
-                    description: String::new(),
-                    inherits_from: vec![],
-                    is_system: false,
-                    created_at: Utc::now(),
-                })
-            })
-        }
-        async fn list_all(&self) -> Result<Vec<AccessLevelEntity>, AppError> {
-            Ok(vec![])
-        }
-        async fn update(&self, _: AccessLevelEntity) -> Result<(), AppError> {
-            Ok(())
-        }
-        async fn delete(&self, _: &str) -> Result<(), AppError> {
-            Ok(())
-        }
-        async fn exists(&self, name: &str) -> Result<bool, AppError> {
-            Ok(!name.trim().is_empty() && name != "unknown")
-        }
-        async fn seed_defaults(&self) -> Result<(), AppError> {
-            Ok(())
-        }
-        async fn compute_effective_levels(
-            &self,
-            roots: &[String],
-        ) -> Result<Vec<String>, AppError> {
-            Ok(roots.to_vec())
-        }
-    }
-
-    struct MockServiceTokenRepo;
-
-    #[async_trait]
-    impl ServiceTokenRepository for MockServiceTokenRepo {
-        async fn create(&self, _: ServiceToken) -> Result<(), AppError> {
-            unimplemented!()
-        }
-        async fn find_by_hash(&self, _: &str) -> Result<Option<ServiceToken>, AppError> {
-            Ok(None)
-        }
-        async fn find_by_name(&self, _: &str) -> Result<Option<ServiceToken>, AppError> {
-            Ok(None)
-        }
-        async fn find_by_id(&self, _: &str) -> Result<Option<ServiceToken>, AppError> {
-            Ok(None)
-        }
-        async fn list_all(&self) -> Result<Vec<ServiceToken>, AppError> {
-            Ok(vec![])
-        }
-        async fn deactivate(&self, _: &str) -> Result<(), AppError> {
-            Ok(())
-        }
-        async fn touch_last_used(&self, _: &str) -> Result<(), AppError> {
-            Ok(())
-        }
-        async fn check_scope_overlap(
-            &self,
-            _: &[String],
-            _: Option<&str>,
-        ) -> Result<bool, AppError> {
-            Ok(false)
-        }
-        async fn set_active(&self, _: &str, _: bool) -> Result<(), AppError> {
-            Ok(())
-        }
-        async fn list_by_user_id(&self, _: &str) -> Result<Vec<ServiceToken>, AppError> {
-            Ok(vec![])
-        }
-        async fn list_pats_paginated(
-            &self,
-            _: u64,
-            _: u64,
-        ) -> Result<(Vec<ServiceToken>, u64), AppError> {
-            Ok((vec![], 0))
-        }
-        async fn delete_pat(&self, _: &str, _: &str) -> Result<(), AppError> {
-            Ok(())
-        }
-    }
-
-    struct MockSchemaRepo {
-        schemas: Mutex<Vec<Schema>>,
-    }
-
-    impl MockSchemaRepo {
-        fn new() -> Self {
-            Self {
-                schemas: Mutex::new(vec![]),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl SchemaRepository for MockSchemaRepo {
-        async fn create_or_update(&self, schema: Schema) -> Result<(), AppError> {
-            let mut schemas = self.schemas.lock().unwrap();
-            schemas.retain(|s| s.name != schema.name);
-            schemas.push(schema);
-            Ok(())
-        }
-
-        async fn find_by_name(&self, name: &str) -> Result<Option<Schema>, AppError> {
-            Ok(self
-                .schemas
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|s| s.name == name)
-                .cloned())
-        }
-
-        async fn find_by_name_summary(&self, name: &str) -> Result<Option<Schema>, AppError> {
-            Ok(self
-                .schemas
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|s| s.name == name)
-                .cloned()
-                .map(|mut s| {
-                    for v in s.versions.iter_mut() {
-                        v.endpoints = vec![];
-                    }
-                    s
-                }))
-        }
-
-        async fn list_all(&self) -> Result<Vec<Schema>, AppError> {
-            Ok(self.schemas.lock().unwrap().clone())
-        }
-
-        async fn list_summaries(&self) -> Result<Vec<Schema>, AppError> {
-            Ok(self
-                .schemas
-                .lock()
-                .unwrap()
-                .iter()
-                .cloned()
-                .map(|mut s| {
-                    for v in s.versions.iter_mut() {
-                        v.endpoints = vec![];
-                    }
-                    s
-                })
-                .collect())
-        }
-
-        async fn find_by_name_prefix(&self, prefix: &str) -> Result<Vec<Schema>, AppError> {
-            let schemas = self.schemas.lock().unwrap();
-            Ok(schemas
-                .iter()
-                .filter(|schema| {
-                    prefix.is_empty()
-                        || schema.name == prefix
-                        || schema.name.starts_with(&format!("{prefix}/"))
-                })
-                .cloned()
-                .collect())
-        }
-
-        async fn add_version(
-            &self,
-            schema_name: &str,
-            version: SchemaVersion,
-        ) -> Result<(), AppError> {
-            let mut schemas = self.schemas.lock().unwrap();
-            let schema = schemas
-                .iter_mut()
-                .find(|s| s.name == schema_name)
-                .ok_or_else(|| AppError::NotFound(format!("Schema '{}' not found", schema_name)))?;
-
-            if schema.versions.iter().any(|v| v.version == version.version) {
-                return Err(AppError::BadRequest(format!(
-                    "Version '{}' already exists",
-                    version.version
-                )));
-            }
-
-            schema.versions.push(version);
-            Ok(())
-        }
+    use crate::testing::{
+        InMemoryAccessLevelRepository, InMemorySchemaRepository, InMemoryServiceTokenRepository,
+        InMemoryStorage,
+    };
 
-        async fn set_version_archived(
-            &self,
-            schema_name: &str,
-            version: &str,
-            archived: bool,
-        ) -> Result<(), AppError> {
-            let mut schemas = self.schemas.lock().unwrap();
-            let schema = schemas
-                .iter_mut()
-                .find(|s| s.name == schema_name)
-                .ok_or_else(|| AppError::NotFound(format!("Schema '{}' not found", schema_name)))?;
-            let version = schema
-                .versions
-                .iter_mut()
-                .find(|v| v.version == version)
-                .ok_or_else(|| AppError::NotFound("Version not found".into()))?;
-            version.is_archived = archived;
-            Ok(())
-        }
+    /// The default access levels plus a team level; shared read-only.
+    static ACCESS_LEVELS: LazyLock<InMemoryAccessLevelRepository> = LazyLock::new(|| {
+        let repo = InMemoryAccessLevelRepository::with_defaults();
+        repo.levels.lock().unwrap().push(AccessLevelEntity {
+            name: "team-payments".to_string(),
+            label: "Payments team".to_string(),
+            description: String::new(),
+            inherits_from: vec![],
+            is_system: false,
+            created_at: chrono::Utc::now(),
+        });
+        repo
+    });
 
-        async fn delete(&self, name: &str) -> Result<(), AppError> {
-            let mut schemas = self.schemas.lock().unwrap();
-            let len_before = schemas.len();
-            schemas.retain(|s| s.name != name);
-            if schemas.len() == len_before {
-                return Err(AppError::NotFound(format!("Schema '{}' not found", name)));
-            }
-            Ok(())
-        }
-
-        async fn find_version_s3_key(
-            &self,
-            schema_name: &str,
-            version: &str,
-        ) -> Result<Option<crate::db::schema_repository::SchemaVersionRef>, AppError> {
-            use crate::db::schema_repository::SchemaVersionRef;
-            Ok(self
-                .schemas
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|s| s.name == schema_name)
-                .and_then(|s| s.versions.iter().find(|v| v.version == version))
-                .map(|v| SchemaVersionRef {
-                    s3_key: v.s3_key.clone(),
-                    access_level: v.access_level.clone(),
-                    is_archived: v.is_archived,
-                }))
-        }
-    }
+    /// No scoped tokens: requests authenticate with the legacy token.
+    static SERVICE_TOKENS: LazyLock<InMemoryServiceTokenRepository> =
+        LazyLock::new(InMemoryServiceTokenRepository::new);
 
     fn make_schema_request(token: &str, name: &str, version: &str) -> IngestSchemaRequest {
         IngestSchemaRequest {
@@ -1181,30 +1479,37 @@ mod tests {
             version: version.to_string(),
             status: "stable".to_string(),
             access_level: "public".to_string(),
+            owner_access_level: None,
             service_owner: "payments".to_string(),
             tags: vec!["payments".to_string()],
+            description: None,
+            contact: None,
+            repository_url: None,
             content: r#"{"openapi": "3.0.0", "info": {"title": "Test", "version": "1.0.0"}}"#
                 .to_string(),
         }
     }
 
     fn ingest_context<'a>(
-        repo: &'a MockSchemaRepo,
-        storage: &'a MockStorage,
+        repo: &'a InMemorySchemaRepository,
+        storage: &'a InMemoryStorage,
     ) -> SchemaIngestContext<'a> {
         SchemaIngestContext {
             schema_repo: repo,
             storage,
-            access_level_repo: &MockAccessLevelRepo,
-            service_token_repo: &MockServiceTokenRepo,
+            access_level_repo: &*ACCESS_LEVELS,
+            project_repo: None,
+            service_token_repo: &*SERVICE_TOKENS,
+            events: None,
             legacy_token: Some("valid-token"),
+            dry_run: false,
         }
     }
 
     #[tokio::test]
     async fn test_ingest_schema_success() {
-        let repo = MockSchemaRepo::new();
-        let storage = MockStorage::new();
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
         let request = make_schema_request("valid-token", "test-api", "1.0.0");
 
         let result = process_schema_ingest(&ingest_context(&repo, &storage), request).await;
@@ -1226,8 +1531,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_schema_invalid_token() {
-        let repo = MockSchemaRepo::new();
-        let storage = MockStorage::new();
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
         let request = make_schema_request("wrong-token", "test-api", "1.0.0");
 
         let result = process_schema_ingest(&ingest_context(&repo, &storage), request).await;
@@ -1241,8 +1546,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_schema_invalid_type() {
-        let repo = MockSchemaRepo::new();
-        let storage = MockStorage::new();
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
         let mut request = make_schema_request("valid-token", "test-api", "1.0.0");
         request.schema_type = "graphql".to_string();
 
@@ -1257,8 +1562,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_schema_invalid_access_level() {
-        let repo = MockSchemaRepo::new();
-        let storage = MockStorage::new();
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
         let mut request = make_schema_request("valid-token", "test-api", "1.0.0");
         request.access_level = "unknown".to_string();
 
@@ -1273,8 +1578,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_ingest_schema_unchanged_short_circuits() {
-        let repo = MockSchemaRepo::new();
-        let storage = MockStorage::new();
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
         let request = make_schema_request("valid-token", "test-api", "1.0.0");
 
         process_schema_ingest(&ingest_context(&repo, &storage), request.clone())
@@ -1287,10 +1592,43 @@ mod tests {
         assert!(!result.changed);
     }
 
+    #[tokio::test]
+    async fn test_ingest_schema_dry_run_writes_nothing() {
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
+        process_schema_ingest(
+            &ingest_context(&repo, &storage),
+            make_schema_request("valid-token", "test-api", "1.0.0"),
+        )
+        .await
+        .unwrap();
+
+        let dry_ctx = SchemaIngestContext {
+            dry_run: true,
+            ..ingest_context(&repo, &storage)
+        };
+        let mut request = make_schema_request("valid-token", "test-api", "2.0.0");
+        request.content = "openapi: [unclosed".to_string();
+        let response = process_schema_ingest(&dry_ctx, request).await.unwrap();
+
+        assert!(response.dry_run);
+        assert_eq!(response.changes, vec!["version_added"]);
+        assert_eq!(
+            response.warnings,
+            vec!["Content is neither valid JSON nor valid YAML"]
+        );
+        let schema = repo.find_by_name("test-api").await.unwrap().unwrap();
+        assert_eq!(schema.versions.len(), 1);
+        assert_eq!(
+            storage.put_count.load(std::sync::atomic::Ordering::Relaxed),
+            1
+        );
+    }
+
     #[tokio::test]
     async fn test_schema_sync_archives_missing_versions() {
-        let repo = MockSchemaRepo::new();
-        let storage = MockStorage::new();
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
         process_schema_ingest(
             &ingest_context(&repo, &storage),
             make_schema_request("valid-token", "payments/api", "1.0.0"),
@@ -1306,7 +1644,7 @@ mod tests {
 
         let result = process_schema_sync(
             &repo,
-            &MockServiceTokenRepo,
+            &*SERVICE_TOKENS,
             Some("valid-token"),
             SchemaSyncRequest {
                 service_token: "valid-token".to_string(),
@@ -1338,8 +1676,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_schemas_filters_by_access_level() {
-        let repo = MockSchemaRepo::new();
-        let storage = MockStorage::new();
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
         process_schema_ingest(
             &ingest_context(&repo, &storage),
             make_schema_request("valid-token", "public-api", "1.0.0"),
@@ -1361,10 +1699,78 @@ mod tests {
         assert_eq!(list[0].name, "public-api");
     }
 
+    #[tokio::test]
+    async fn test_list_schemas_page_filters_sorts_and_paginates() {
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
+        for (name, owner, tag, access_level) in [
+            ("payments/ledger", "payments", "billing", "public"),
+            ("payments/cards", "payments", "cards", "public"),
+            ("orders/api", "orders", "billing", "public"),
+            ("hr/payroll", "hr", "billing", "internal"),
+        ] {
+            let mut request = make_schema_request("valid-token", name, "1.0.0");
+            request.service_owner = owner.to_string();
+            request.tags = vec![tag.to_string()];
+            request.access_level = access_level.to_string();
+            process_schema_ingest(&ingest_context(&repo, &storage), request)
+                .await
+                .unwrap();
+        }
+        let public_only = vec!["public".to_string()];
+        let names = |page: &SchemaListResult| -> Vec<String> {
+            page.items.iter().map(|s| s.name.clone()).collect()
+        };
+
+        let page = process_list_schemas_page(
+            &repo,
+            SchemaListQuery {
+                tag: Some("billing".to_string()),
+                per_page: Some(1),
+                ..Default::default()
+            },
+            Some(&public_only),
+        )
+        .await
+        .unwrap();
+        assert_eq!(names(&page), ["orders/api"]);
+        assert_eq!(page.total, 2);
+        assert_eq!(page.service_owners, ["orders", "payments"]);
+        assert_eq!(page.tags, ["billing", "cards"]);
+
+        let page = process_list_schemas_page(
+            &repo,
+            SchemaListQuery {
+                q: Some("PAY".to_string()),
+                owner: Some("payments".to_string()),
+                sort: SchemaSort::UpdatedAt,
+                ..Default::default()
+            },
+            Some(&public_only),
+        )
+        .await
+        .unwrap();
+        // Ingested last, so updated most recently
+        assert_eq!(names(&page), ["payments/cards", "payments/ledger"]);
+        assert_eq!(page.per_page, 2);
+
+        let page = process_list_schemas_page(
+            &repo,
+            SchemaListQuery {
+                q: Some("payroll".to_string()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(names(&page), ["hr/payroll"]);
+    }
+
     #[tokio::test]
     async fn test_get_schema_content_respects_access_level() {
-        let repo = MockSchemaRepo::new();
-        let storage = MockStorage::new();
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
         let mut request = make_schema_request("valid-token", "internal-api", "1.0.0");
         request.access_level = "internal".to_string();
         process_schema_ingest(&ingest_context(&repo, &storage), request)
@@ -1383,4 +1789,196 @@ mod tests {
 
         assert!(matches!(result, Err(AppError::NotFound(_))));
     }
+
+    #[tokio::test]
+    async fn test_owner_access_level_restricts_whole_schema() {
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
+        let mut request = make_schema_request("valid-token", "payments/ledger", "1.0.0");
+        request.owner_access_level = Some(" Team-Payments ".to_string());
+        let response = process_schema_ingest(&ingest_context(&repo, &storage), request)
+            .await
+            .unwrap();
+        assert_eq!(response.changes, vec!["created".to_string()]);
+
+        let public_only = vec!["public".to_string()];
+        assert!(process_list_schemas(&repo, Some(&public_only))
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            process_get_schema(&repo, "payments/ledger", Some(&public_only)).await,
+            Err(AppError::NotFound(_))
+        ));
+        assert!(matches!(
+            process_get_schema_content(
+                &repo,
+                &storage,
+                "payments/ledger",
+                "1.0.0",
+                Some(&public_only)
+            )
+            .await,
+            Err(AppError::NotFound(_))
+        ));
+
+        let team = vec!["public".to_string(), "team-payments".to_string()];
+        let detail = process_get_schema(&repo, "payments/ledger", Some(&team))
+            .await
+            .unwrap();
+        assert_eq!(detail.owner_access_level.as_deref(), Some("team-payments"));
+        assert!(process_get_schema_content(
+            &repo,
+            &storage,
+            "payments/ledger",
+            "1.0.0",
+            Some(&team)
+        )
+        .await
+        .is_ok());
+
+        let mut unknown = make_schema_request("valid-token", "payments/ledger", "1.0.0");
+        unknown.owner_access_level = Some("unknown".to_string());
+        assert!(matches!(
+            process_schema_ingest(&ingest_context(&repo, &storage), unknown).await,
+            Err(AppError::BadRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_ingest_contact_metadata() {
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
+        let mut request = make_schema_request("valid-token", "payments/ledger", "1.0.0");
+        request.description = Some("  Ledger entries  ".to_string());
+        request.contact = Some("ledger-team@example.com".to_string());
+        request.repository_url = Some(" ".to_string());
+        process_schema_ingest(&ingest_context(&repo, &storage), request)
+            .await
+            .unwrap();
+
+        let detail = process_get_schema(&repo, "payments/ledger", None)
+            .await
+            .unwrap();
+        assert_eq!(detail.description.as_deref(), Some("Ledger entries"));
+        assert_eq!(detail.contact.as_deref(), Some("ledger-team@example.com"));
+        assert_eq!(detail.repository_url, None);
+
+        let mut request = make_schema_request("valid-token", "payments/ledger", "1.0.0");
+        request.description = Some("Ledger entries".to_string());
+        request.contact = Some("https://chat.example.com/ledger".to_string());
+        request.repository_url = Some("https://git.example.com/ledger".to_string());
+        let response = process_schema_ingest(&ingest_context(&repo, &storage), request)
+            .await
+            .unwrap();
+        assert_eq!(response.changes, vec!["contact", "repository_url"]);
+
+        for (contact, repository_url) in [
+            (Some("javascript:alert(1)"), None),
+            (Some("not a contact"), None),
+            (None, Some("ftp://git.example.com/ledger")),
+        ] {
+            let mut request = make_schema_request("valid-token", "payments/ledger", "1.0.0");
+            request.contact = contact.map(str::to_string);
+            request.repository_url = repository_url.map(str::to_string);
+            assert!(matches!(
+                process_schema_ingest(&ingest_context(&repo, &storage), request).await,
+                Err(AppError::BadRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_contact_href() {
+        assert_eq!(contact_href("team@example.com"), "mailto:team@example.com");
+        assert_eq!(
+            contact_href("https://chat.example.com/team"),
+            "https://chat.example.com/team"
+        );
+    }
+
+    #[test]
+    fn test_raw_download_headers() {
+        let [content_type, disposition] =
+            raw_download_headers("payments/ledger", "1.0.0", "  {\"openapi\": \"3.0.0\"}");
+        assert_eq!(content_type.1, "application/json; charset=utf-8");
+        assert_eq!(disposition.1, "attachment; filename=\"ledger-1.0.0.json\"");
+
+        let [content_type, disposition] =
+            raw_download_headers("orders", "2.0\"", "asyncapi: 2.6.0");
+        assert_eq!(content_type.1, "application/yaml; charset=utf-8");
+        assert_eq!(disposition.1, "attachment; filename=\"orders-2.0_.yaml\"");
+        assert_eq!(
+            schema_raw_path("orders", "2.0.0"),
+            "/api/v1/schemas/orders/2.0.0/raw"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_operations_deep_links_latest_version() {
+        let repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
+        let spec = |summary: &str| {
+            format!(
+                r#"{{"openapi": "3.0.0", "paths": {{"/users/{{id}}": {{"get": {{"summary": "{summary}"}}}}}}}}"#
+            )
+        };
+        for (version, summary, access_level) in [
+            ("1.0.0", "Fetch a user", "public"),
+            ("2.0.0", "Get a user by id", "public"),
+            ("3.0.0", "Get a user (beta)", "internal"),
+        ] {
+            let mut request = make_schema_request("valid-token", "accounts", version);
+            request.access_level = access_level.to_string();
+            request.content = spec(summary);
+            process_schema_ingest(&ingest_context(&repo, &storage), request)
+                .await
+                .unwrap();
+        }
+        let public_only = vec!["public".to_string()];
+
+        let hits = process_search_operations(&repo, "USER BY", Some(&public_only))
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].version, "2.0.0");
+        assert_eq!(hits[0].href(), "/schemas/accounts/2.0.0#get-/users/{id}");
+
+        // Only the latest visible version is searched
+        assert!(
+            process_search_operations(&repo, "fetch", Some(&public_only))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let hits = process_search_operations(&repo, "/users", None)
+            .await
+            .unwrap();
+        assert_eq!(hits[0].version, "3.0.0");
+        assert!(process_search_operations(&repo, "  ", None)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_extract_asyncapi_v3_channel_address() {
+        let spec = r##"{
+            "asyncapi": "3.0.0",
+            "channels": {
+                "orderCreated": {"address": "orders.created"},
+                "audit": {}
+            },
+            "operations": {
+                "publishOrder": {"action": "send", "channel": {"$ref": "#/channels/orderCreated"}},
+                "readAudit": {"action": "receive", "channel": {"$ref": "#/channels/audit"}}
+            }
+        }"##;
+        let endpoints = extract_schema_endpoints("asyncapi", spec);
+        let channels: Vec<(&str, &str)> = endpoints
+            .iter()
+            .map(|e| (e.method.as_str(), e.path.as_str()))
+            .collect();
+        assert_eq!(channels, [("send", "orders.created"), ("receive", "audit")]);
+    }
 }
@@ -22,6 +22,43 @@ pub struct SearchQuery {
     /// Defaults to `"public"` (anonymous access).
     #[serde(default = "default_access_level")]
     pub access_levels: String,
+    /// Restrict results to one project namespace.
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Comma-separated tags every result must carry.
+    #[serde(default)]
+    pub tags: Option<String>,
+    /// Restrict results to one service owner.
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Only documents not updated for the scheduler's `stale_after_days`.
+    #[serde(default)]
+    pub stale: bool,
+}
+
+#[cfg(feature = "ssr")]
+impl SearchQuery {
+    /// The document filter of the query parameters.
+    fn filter(&self, stale_after_days: u32) -> crate::search::client::SearchFilter {
+        let filters = crate::search::query::QuickFilters {
+            tags: self
+                .tags
+                .iter()
+                .flat_map(|tags| tags.split(','))
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect(),
+            owner: self.owner.clone().filter(|owner| !owner.is_empty()),
+            result_type: None,
+            stale: self.stale,
+        };
+        filters.to_search_filter(
+            self.project.as_deref(),
+            stale_after_days,
+            chrono::Utc::now().timestamp(),
+        )
+    }
 }
 
 #[cfg(feature = "ssr")]
@@ -29,12 +66,26 @@ fn default_access_level() -> String {
     "public".to_string()
 }
 
-/// Axum handler for `GET /api/v1/search?q=<query>&access_levels=<levels>`.
+/// Axum handler for `GET /api/v1/search?q=<query>&access_levels=<levels>&project=<project>`.
+///
+/// `tags`, `owner` and `stale=true` narrow the results like the search box's
+/// `tag:`, `owner:` and `is:stale` filters.
+///
+/// With `Authorization: Bearer <api key>`, results are bound to the read-only
+/// key's access levels and scopes and `access_levels` is ignored.
 #[cfg(feature = "ssr")]
 pub async fn search_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    headers: axum::http::HeaderMap,
     axum::extract::Query(params): axum::extract::Query<SearchQuery>,
 ) -> Result<axum::Json<Vec<SearchHit>>, crate::error::AppError> {
+    let filter = params.filter(state.scheduler.stale_after_days);
+    if headers.contains_key(axum::http::header::AUTHORIZATION) {
+        let results =
+            crate::api::bot::search_with_key(&state, &headers, &params.q, &filter).await?;
+        return Ok(axum::Json(results));
+    }
+
     let search_service = state
         .search_service
         .as_ref()
@@ -48,9 +99,22 @@ pub async fn search_handler(
         .filter(|s| !s.is_empty())
         .collect();
 
-    let results = search_service
-        .search(&params.q, Some(levels.as_slice()), false)
-        .await?;
+    let mut results = crate::features::search(
+        &state,
+        search_service.as_ref(),
+        &params.q,
+        Some(levels.as_slice()),
+        false,
+        &filter,
+    )
+    .await?;
+    crate::schema::deprecation::annotate_search_hits(
+        state.document_repo.as_ref(),
+        state.schema_repo.as_ref(),
+        &mut results,
+        Some(levels.as_slice()),
+    )
+    .await;
 
     Ok(axum::Json(results))
 }
@@ -92,14 +156,28 @@ pub async fn trigger_reindex_handler(
         ));
     }
 
+    let settings = match state.settings_repo.get_settings().await {
+        Ok(settings) => settings.search,
+        Err(e) => {
+            reindex.is_running.store(false, Ordering::Release);
+            return Err(e);
+        }
+    };
+
     let reindex_clone = reindex.clone();
     let document_repo = state.document_repo.clone();
     let storage = state.storage_client.clone();
     let search_clone = search.clone();
 
     tokio::spawn(async move {
-        crate::search::reindex::run_reindex(reindex_clone, document_repo, storage, search_clone)
-            .await;
+        crate::search::reindex::run_reindex(
+            reindex_clone,
+            document_repo,
+            storage,
+            search_clone,
+            settings,
+        )
+        .await;
     });
 
     Ok((
@@ -139,3 +217,45 @@ pub async fn reindex_status_handler(
         search_enabled,
     }))
 }
+
+/// `GET /api/v1/admin/search/settings` — current search relevance tuning.
+#[cfg(feature = "ssr")]
+pub async fn get_search_settings_handler(
+    crate::auth::extractor::RequiredAuthUser(user): crate::auth::extractor::RequiredAuthUser,
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+) -> Result<axum::Json<crate::db::settings_repository::SearchSettings>, crate::error::AppError> {
+    if !user.is_admin {
+        return Err(crate::error::AppError::Forbidden(
+            "Admin privileges required".into(),
+        ));
+    }
+
+    let settings = state.settings_repo.get_settings().await?;
+    Ok(axum::Json(settings.search))
+}
+
+/// `PUT /api/v1/admin/search/settings` — replace the search relevance tuning
+/// (ranking rules, synonyms, stop words, typo tolerance) and apply it to the
+/// Meilisearch index. Meilisearch re-ranks existing documents by itself, so
+/// no re-index is needed.
+#[cfg(feature = "ssr")]
+pub async fn put_search_settings_handler(
+    crate::auth::extractor::RequiredAuthUser(user): crate::auth::extractor::RequiredAuthUser,
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    axum::Json(settings): axum::Json<crate::db::settings_repository::SearchSettings>,
+) -> Result<axum::Json<crate::db::settings_repository::SearchSettings>, crate::error::AppError> {
+    if !user.is_admin {
+        return Err(crate::error::AppError::Forbidden(
+            "Admin privileges required".into(),
+        ));
+    }
+
+    let settings = settings.normalized()?;
+    state.settings_repo.set_search_settings(&settings).await?;
+
+    if let Some(search) = &state.search_service {
+        search.configure_index(&settings).await?;
+    }
+
+    Ok(axum::Json(settings))
+}
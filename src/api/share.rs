@@ -0,0 +1,259 @@
+//! Documents shared by link (see [`crate::server::share_links`]).
+//!
+//! | Method | Path             | Auth       | Description                                  |
+//! |--------|------------------|------------|----------------------------------------------|
+//! | GET    | `/share/{token}` | Share link | The shared document as a standalone page     |
+//!
+//! Shared pages are never indexed or cached, whatever the document's
+//! `noindex` flag, and send no referrer, so the token does not leak to the
+//! sites the document links to.
+
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{Html, IntoResponse, Response};
+use axum_extra::extract::CookieJar;
+use chrono::Utc;
+
+use crate::app::AppState;
+use crate::db::models::Document;
+use crate::db::share_link_repository::{ShareLink, ShareLinkAccess};
+use crate::error::AppError;
+use crate::i18n::{negotiate, translate, translate_with, Locale, LOCALE_COOKIE};
+use crate::rendering::markdown::escape_html;
+
+/// `GET /share/{token}`
+///
+/// Renders the shared document without the portal chrome and records the
+/// access. Invalid, expired and revoked links, and links to documents that
+/// were archived since, get a `404` page.
+pub async fn shared_document_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(token): Path<String>,
+) -> Result<Response, AppError> {
+    let cookies = CookieJar::from_headers(&headers);
+    let locale = negotiate(
+        cookies.get(LOCALE_COOKIE).map(|c| c.value()),
+        headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok()),
+    );
+    let (link, doc, html) = match shared_document(&state, &token).await {
+        Ok(shared) => shared,
+        Err(AppError::NotFound(_)) => {
+            let body = format!(
+                "<p>{}</p>",
+                escape_html(translate(locale, "share_page.unavailable"))
+            );
+            return Ok(page(
+                StatusCode::NOT_FOUND,
+                locale,
+                translate(locale, "share_page.unavailable_title"),
+                &body,
+                None,
+            ));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let header_value = |name: &header::HeaderName| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let access = ShareLinkAccess {
+        link_id: link.id.clone(),
+        slug: link.slug.clone(),
+        forwarded_for: header_value(&header::HeaderName::from_static("x-forwarded-for")),
+        user_agent: header_value(&header::USER_AGENT),
+        accessed_at: Utc::now(),
+    };
+    if let Err(e) = state.share_link_repo.record_access(access).await {
+        tracing::warn!(link_id = %link.id, slug = %link.slug, "Failed to record share link access: {e}");
+    }
+
+    let expires_at = link.expires_at.format("%Y-%m-%d %H:%M UTC").to_string();
+    let footer = escape_html(&translate_with(
+        locale,
+        "share_page.footer",
+        &[("author", &link.created_by), ("date", &expires_at)],
+    ));
+    Ok(page(
+        StatusCode::OK,
+        locale,
+        &doc.title,
+        &html,
+        Some(&footer),
+    ))
+}
+
+/// The active link behind `token`, its document and the rendered content.
+///
+/// Attachment and image URLs in the content carry the token, so the viewer
+/// can fetch the files the document embeds (see
+/// [`crate::api::asset_access::authorize_download`]).
+async fn shared_document(
+    state: &AppState,
+    token: &str,
+) -> Result<(ShareLink, Document, String), AppError> {
+    let (link, doc) = resolve_share_link(state, token).await?;
+    let html = crate::api::documents::render_document(state, &doc).await?;
+    Ok((link, doc, with_share_token(&html, token)))
+}
+
+/// The active link behind `token` and its document, unless archived.
+/// Fails with [`AppError::NotFound`] for any unusable link.
+pub(crate) async fn resolve_share_link(
+    state: &AppState,
+    token: &str,
+) -> Result<(ShareLink, Document), AppError> {
+    let not_found = || AppError::NotFound("Share link not found".into());
+    if !state.share_links.enabled {
+        return Err(not_found());
+    }
+    let claims = state
+        .token_service
+        .validate_share_token(token)
+        .map_err(|_| not_found())?;
+    let link = state
+        .share_link_repo
+        .find_by_id(&claims.jti)
+        .await?
+        .filter(|link| link.slug == claims.sub && link.is_active(Utc::now()))
+        .ok_or_else(not_found)?;
+    let doc = state
+        .document_repo
+        .find_by_slug(&link.slug)
+        .await?
+        .filter(|doc| !doc.is_archived)
+        .ok_or_else(not_found)?;
+    Ok((link, doc))
+}
+
+/// Add `share=<token>` to the `src` and `href` attributes of `html` that point
+/// at this instance's attachments or images. Absolute URLs are left alone, so
+/// the token is never sent to another host.
+fn with_share_token(html: &str, token: &str) -> String {
+    let query = format!("share={}", urlencoding::encode(token));
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find("=\"") {
+        let (before, after) = rest.split_at(start + 2);
+        out.push_str(before);
+        let end = after.find('"').unwrap_or(after.len());
+        let (value, tail) = after.split_at(end);
+        let is_url_attr = before.ends_with(" src=\"") || before.ends_with(" href=\"");
+        let is_served_file = ASSET_URL_PREFIXES
+            .iter()
+            .any(|prefix| value.starts_with(prefix));
+        if is_url_attr && is_served_file {
+            let (path, fragment) = value.split_at(value.find('#').unwrap_or(value.len()));
+            let separator = if path.contains('?') { "&amp;" } else { "?" };
+            out.push_str(&format!("{path}{separator}{query}{fragment}"));
+        } else {
+            out.push_str(value);
+        }
+        rest = tail;
+    }
+    out.push_str(rest);
+    out
+}
+
+/// URL prefixes of the attachment and image routes.
+const ASSET_URL_PREFIXES: [&str; 2] = ["/api/v1/assets/", "/api/v1/image/"];
+
+/// A standalone page in `locale`, kept out of search engines and caches.
+fn page(
+    status: StatusCode,
+    locale: Locale,
+    title: &str,
+    body: &str,
+    footer: Option<&str>,
+) -> Response {
+    let footer = footer
+        .map(|text| {
+            format!(
+                "<footer class=\"mt-12 pt-4 border-t border-base-200 text-sm text-base-content/60\">{text}</footer>"
+            )
+        })
+        .unwrap_or_default();
+    let html = format!(
+        "<!DOCTYPE html>\
+         <html lang=\"{lang}\">\
+         <head>\
+         <meta charset=\"utf-8\"/>\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\"/>\
+         <meta name=\"robots\" content=\"noindex, nofollow\"/>\
+         <title>{title}</title>\
+         <link rel=\"stylesheet\" href=\"/pkg/lekton.css\"/>\
+         <link rel=\"stylesheet\" href=\"/custom.css\"/>\
+         </head>\
+         <body class=\"bg-base-100\">\
+         <main class=\"max-w-4xl mx-auto px-6 py-10\">\
+         <article class=\"prose prose-lg max-w-none\">{body}</article>\
+         {footer}\
+         </main>\
+         </body>\
+         </html>",
+        lang = locale.code(),
+        title = escape_html(title),
+    );
+    (
+        status,
+        [
+            (
+                header::HeaderName::from_static("x-robots-tag"),
+                "noindex, nofollow",
+            ),
+            (header::CACHE_CONTROL, "private, no-store"),
+            (header::REFERRER_POLICY, "no-referrer"),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff"),
+        ],
+        Html(html),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_page_is_not_indexed_and_escapes_title() {
+        let response = page(
+            StatusCode::OK,
+            Locale::It,
+            "<Runbook>",
+            "<h1>Runbook</h1>",
+            Some("Shared"),
+        );
+        assert_eq!(response.headers()["x-robots-tag"], "noindex, nofollow");
+        assert_eq!(response.headers()[header::REFERRER_POLICY], "no-referrer");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("<html lang=\"it\">"));
+        assert!(body.contains("<title>&lt;Runbook&gt;</title>"));
+        assert!(body.contains("<meta name=\"robots\" content=\"noindex, nofollow\"/>"));
+        assert!(body.contains("<footer"));
+    }
+
+    #[test]
+    fn test_with_share_token_signs_local_asset_urls_only() {
+        let html = "<p><img src=\"/api/v1/image/1_diagram.png\" alt=\"/api/v1/image/x\"/>\
+            <a href=\"/api/v1/assets/ops/run.pdf?download=1#page=2\">Runbook</a>\
+            <a href=\"https://elsewhere.example/api/v1/assets/x.pdf\">Other</a>\
+            <code>/api/v1/assets/ops/run.pdf</code></p>";
+
+        assert_eq!(
+            with_share_token(html, "a.b.c"),
+            "<p><img src=\"/api/v1/image/1_diagram.png?share=a.b.c\" alt=\"/api/v1/image/x\"/>\
+            <a href=\"/api/v1/assets/ops/run.pdf?download=1&amp;share=a.b.c#page=2\">Runbook</a>\
+            <a href=\"https://elsewhere.example/api/v1/assets/x.pdf\">Other</a>\
+            <code>/api/v1/assets/ops/run.pdf</code></p>"
+        );
+    }
+}
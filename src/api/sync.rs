@@ -34,6 +34,58 @@ pub struct SyncRequest {
     /// client list will be automatically archived.
     #[serde(default)]
     pub archive_missing: bool,
+    /// Publish the documents as a version of a doc bundle. Archiving is then
+    /// limited to that version of the bundle.
+    #[serde(default)]
+    pub bundle: Option<SyncBundle>,
+}
+
+/// Target bundle version of a sync (see [`crate::db::bundle_repository`]).
+#[derive(Debug, Clone, Deserialize)]
+pub struct SyncBundle {
+    /// Slug root of the bundle (e.g. `protocols/billing`).
+    pub root: String,
+    /// Source version of the docs: a release name or git SHA.
+    pub version: String,
+    /// Publish at the canonical slugs, replacing the latest docs, rather
+    /// than under `<root>/@<version>`.
+    #[serde(default)]
+    pub latest: bool,
+}
+
+impl SyncBundle {
+    /// Slug prefix of the documents in this bundle version.
+    pub fn prefix(&self) -> String {
+        let version = (!self.latest).then_some(self.version.as_str());
+        crate::db::bundle_repository::bundle_slug(&self.root, version, "")
+    }
+
+    /// Whether `slug` belongs to this bundle version.
+    pub fn contains(&self, slug: &str) -> bool {
+        let prefix = self.prefix();
+        let in_prefix = slug == prefix || slug.starts_with(&format!("{prefix}/"));
+        in_prefix && (!self.latest || !crate::db::bundle_repository::is_versioned_slug(slug))
+    }
+
+    fn validate(&self) -> Result<(), AppError> {
+        if self.root.is_empty()
+            || self.root.starts_with('/')
+            || self.root.ends_with('/')
+            || self.root.contains('@')
+        {
+            return Err(AppError::BadRequest(format!(
+                "Invalid bundle root '{}'",
+                self.root
+            )));
+        }
+        if !crate::db::bundle_repository::is_valid_bundle_version(&self.version) {
+            return Err(AppError::BadRequest(format!(
+                "Invalid bundle version '{}'. Use letters, digits and .-_+",
+                self.version
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// A single entry in the `to_upload` list returned by the sync endpoint.
@@ -77,7 +129,17 @@ pub async fn process_sync(
     let scopes =
         validate_sync_token(service_token_repo, legacy_token, &request.service_token).await?;
 
-    // 2. Validate all request slugs fall within the token's scopes
+    // 2. Validate all request slugs fall within the token's scopes (and the
+    // target bundle version, if any)
+    if let Some(bundle) = &request.bundle {
+        bundle.validate()?;
+        if !scope_matches_any(&bundle.prefix(), &scopes) {
+            return Err(AppError::Forbidden(format!(
+                "Token does not have access to bundle '{}'",
+                bundle.root
+            )));
+        }
+    }
     for entry in &request.documents {
         if !scope_matches_any(&entry.slug, &scopes) {
             return Err(AppError::Forbidden(format!(
@@ -85,6 +147,15 @@ pub async fn process_sync(
                 entry.slug
             )));
         }
+        if let Some(bundle) = &request.bundle {
+            if !bundle.contains(&entry.slug) {
+                return Err(AppError::BadRequest(format!(
+                    "Slug '{}' is outside bundle '{}'",
+                    entry.slug,
+                    bundle.prefix()
+                )));
+            }
+        }
     }
 
     // 3. Fetch all server documents within the token's scopes.
@@ -106,6 +177,15 @@ pub async fn process_sync(
         };
 
         for doc in docs {
+            // Each bundle version is synced on its own: prior versions are
+            // out of scope unless targeted, as is everything else when one is
+            let in_target = match &request.bundle {
+                Some(bundle) => bundle.contains(&doc.slug),
+                None => !crate::db::bundle_repository::is_versioned_slug(&doc.slug),
+            };
+            if !in_target {
+                continue;
+            }
             if let Some(ref sp) = doc.source_path {
                 server_by_source_path.insert(sp.clone(), doc.slug.clone());
             }
@@ -270,6 +350,16 @@ pub async fn sync_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
     axum::Json(request): axum::Json<SyncRequest>,
 ) -> Result<axum::Json<SyncResponse>, AppError> {
+    use crate::api::events::{ActivityEvent, ActivityKind};
+
+    let archive_missing = request.archive_missing;
+    let bundle = request.bundle.clone();
+    let actor = crate::api::ingest::resolve_token_name(
+        state.service_token_repo.as_ref(),
+        Some(&state.service_token),
+        &request.service_token,
+    )
+    .await;
     let response = process_sync(
         state.document_repo.as_ref(),
         state.service_token_repo.as_ref(),
@@ -278,6 +368,33 @@ pub async fn sync_handler(
         request,
     )
     .await?;
+
+    if let Some(bundle) = bundle {
+        state
+            .bundle_repo
+            .record_version(&bundle.root, &bundle.version, bundle.latest)
+            .await?;
+    }
+
+    if archive_missing && !response.to_archive.is_empty() {
+        match state
+            .document_repo
+            .find_by_slugs(&response.to_archive)
+            .await
+        {
+            Ok(docs) => {
+                for doc in docs {
+                    state.activity_events.publish(ActivityEvent::document(
+                        ActivityKind::DocumentArchived,
+                        &doc,
+                        &actor,
+                    ));
+                }
+            }
+            Err(e) => tracing::warn!("Failed to load archived documents: {e}"),
+        }
+    }
+
     Ok(axum::Json(response))
 }
 
@@ -291,6 +408,7 @@ mod tests {
     use crate::db::repository::DocumentRepository;
     use crate::db::service_token_models::ServiceToken;
     use crate::db::service_token_repository::ServiceTokenRepository;
+    use crate::testing::{self, InMemoryDocumentRepository};
     use chrono::Utc;
 
     // ── Mocks ────────────────────────────────────────────────────────────
@@ -328,100 +446,20 @@ mod tests {
             _: &str,
             _: Option<&[String]>,
             _: bool,
+            _: &crate::search::client::SearchFilter,
         ) -> Result<Vec<crate::search::client::SearchHit>, AppError> {
             Ok(vec![])
         }
-        async fn configure_index(&self) -> Result<(), AppError> {
-            Ok(())
-        }
-    }
-
-    struct MockRepo {
-        documents: Mutex<Vec<Document>>,
-    }
-
-    impl MockRepo {
-        fn new() -> Self {
-            Self {
-                documents: Mutex::new(vec![]),
-            }
-        }
-
-        fn with_docs(docs: Vec<Document>) -> Self {
-            Self {
-                documents: Mutex::new(docs),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl DocumentRepository for MockRepo {
-        async fn create_or_update(&self, doc: Document) -> Result<(), AppError> {
-            let mut docs = self.documents.lock().unwrap();
-            docs.retain(|d| d.slug != doc.slug);
-            docs.push(doc);
-            Ok(())
-        }
-        async fn find_by_slug(&self, slug: &str) -> Result<Option<Document>, AppError> {
-            Ok(self
-                .documents
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|d| d.slug == slug)
-                .cloned())
-        }
-        async fn list_all(&self) -> Result<Vec<Document>, AppError> {
-            Ok(self.documents.lock().unwrap().clone())
-        }
-        async fn list_by_access_levels(
+        async fn configure_index(
             &self,
-            _: Option<&[String]>,
-            _: bool,
-        ) -> Result<Vec<Document>, AppError> {
-            Ok(self.documents.lock().unwrap().clone())
-        }
-        async fn update_backlinks(
-            &self,
-            _: &str,
-            _: &[String],
-            _: &[String],
+            _: &crate::db::settings_repository::SearchSettings,
         ) -> Result<(), AppError> {
             Ok(())
         }
-        async fn find_by_slug_prefix(&self, prefix: &str) -> Result<Vec<Document>, AppError> {
-            Ok(self
-                .documents
-                .lock()
-                .unwrap()
-                .iter()
-                .filter(|d| {
-                    !d.is_archived
-                        && (prefix.is_empty()
-                            || d.slug == prefix
-                            || d.slug.starts_with(&format!("{prefix}/")))
-                })
-                .cloned()
-                .collect())
-        }
-        async fn set_archived(&self, slug: &str, archived: bool) -> Result<(), AppError> {
-            let mut docs = self.documents.lock().unwrap();
-            if let Some(doc) = docs.iter_mut().find(|d| d.slug == slug) {
-                doc.is_archived = archived;
-            }
-            Ok(())
-        }
-        async fn find_by_source_path(
+        async fn list_indexed_documents(
             &self,
-            source_path: &str,
-        ) -> Result<Option<Document>, AppError> {
-            Ok(self
-                .documents
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|d| d.source_path.as_deref() == Some(source_path))
-                .cloned())
+        ) -> Result<Vec<crate::search::client::SearchDocument>, AppError> {
+            Ok(vec![])
         }
     }
 
@@ -477,24 +515,11 @@ mod tests {
 
     fn make_doc(slug: &str, hash: &str) -> Document {
         Document {
-            slug: slug.to_string(),
-            title: slug.to_string(),
-            summary: None,
             s3_key: format!("docs/{}.md", slug.replace('/', "_")),
             access_level: "internal".to_string(),
-            is_draft: false,
-            service_owner: "test".to_string(),
-            last_updated: Utc::now(),
-            tags: vec![],
-            links_out: vec![],
-            backlinks: vec![],
-            parent_slug: None,
-            order: 0,
-            is_hidden: false,
             content_hash: Some(hash.to_string()),
-            metadata_hash: None,
-            is_archived: false,
             source_path: Some(format!("{slug}.md")),
+            ..testing::document(slug)
         }
     }
 
@@ -521,12 +546,13 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_identifies_uploads_for_new_docs() {
-        let repo = MockRepo::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = MockServiceTokenRepo;
         let request = SyncRequest {
             service_token: "legacy".to_string(),
             documents: vec![entry("docs/new", "sha256:abc")],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -539,12 +565,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_identifies_unchanged() {
-        let repo = MockRepo::with_docs(vec![make_doc("docs/a", "sha256:abc")]);
+        let repo =
+            InMemoryDocumentRepository::with_documents(vec![make_doc("docs/a", "sha256:abc")]);
         let token_repo = MockServiceTokenRepo;
         let request = SyncRequest {
             service_token: "legacy".to_string(),
             documents: vec![entry("docs/a", "sha256:abc")],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -557,12 +585,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_identifies_changed_hash() {
-        let repo = MockRepo::with_docs(vec![make_doc("docs/a", "sha256:old")]);
+        let repo =
+            InMemoryDocumentRepository::with_documents(vec![make_doc("docs/a", "sha256:old")]);
         let token_repo = MockServiceTokenRepo;
         let request = SyncRequest {
             service_token: "legacy".to_string(),
             documents: vec![entry("docs/a", "sha256:new")],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -574,7 +604,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_identifies_archives() {
-        let repo = MockRepo::with_docs(vec![
+        let repo = InMemoryDocumentRepository::with_documents(vec![
             make_doc("docs/a", "sha256:abc"),
             make_doc("docs/old", "sha256:def"),
         ]);
@@ -583,6 +613,7 @@ mod tests {
             service_token: "legacy".to_string(),
             documents: vec![entry("docs/a", "sha256:abc")],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -594,7 +625,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_archive_missing_sets_flag() {
-        let repo = MockRepo::with_docs(vec![
+        let repo = InMemoryDocumentRepository::with_documents(vec![
             make_doc("docs/a", "sha256:abc"),
             make_doc("docs/old", "sha256:def"),
         ]);
@@ -603,6 +634,7 @@ mod tests {
             service_token: "legacy".to_string(),
             documents: vec![entry("docs/a", "sha256:abc")],
             archive_missing: true,
+            bundle: None,
         };
 
         process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -629,6 +661,8 @@ mod tests {
             created_at: Utc::now(),
             last_used_at: None,
             is_active: true,
+            project: None,
+            access_levels: vec![],
         };
 
         struct ScopedTokenRepo(ServiceToken);
@@ -684,12 +718,13 @@ mod tests {
             }
         }
 
-        let repo = MockRepo::new();
+        let repo = InMemoryDocumentRepository::new();
         let token_repo = ScopedTokenRepo(scoped);
         let request = SyncRequest {
             service_token: "scoped-tok".to_string(),
             documents: vec![entry("docs/outside", "sha256:abc")],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("other-legacy"), request).await;
@@ -702,7 +737,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_archive_deindexes_from_search() {
-        let repo = MockRepo::with_docs(vec![
+        let repo = InMemoryDocumentRepository::with_documents(vec![
             make_doc("docs/a", "sha256:abc"),
             make_doc("docs/old", "sha256:def"),
         ]);
@@ -712,6 +747,7 @@ mod tests {
             service_token: "legacy".to_string(),
             documents: vec![entry("docs/a", "sha256:abc")],
             archive_missing: true,
+            bundle: None,
         };
 
         process_sync(&repo, &token_repo, Some(&search), Some("legacy"), request)
@@ -723,7 +759,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_no_archive_does_not_deindex() {
-        let repo = MockRepo::with_docs(vec![
+        let repo = InMemoryDocumentRepository::with_documents(vec![
             make_doc("docs/a", "sha256:abc"),
             make_doc("docs/old", "sha256:def"),
         ]);
@@ -733,6 +769,7 @@ mod tests {
             service_token: "legacy".to_string(),
             documents: vec![entry("docs/a", "sha256:abc")],
             archive_missing: false,
+            bundle: None,
         };
 
         process_sync(&repo, &token_repo, Some(&search), Some("legacy"), request)
@@ -752,7 +789,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_metadata_hash_match_is_unchanged() {
-        let repo = MockRepo::with_docs(vec![make_doc_with_meta(
+        let repo = InMemoryDocumentRepository::with_documents(vec![make_doc_with_meta(
             "docs/a",
             "sha256:content",
             "sha256:meta",
@@ -768,6 +805,7 @@ mod tests {
                 legacy_slug: None,
             }],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -782,7 +820,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_metadata_hash_mismatch_triggers_upload() {
-        let repo = MockRepo::with_docs(vec![make_doc_with_meta(
+        let repo = InMemoryDocumentRepository::with_documents(vec![make_doc_with_meta(
             "docs/a",
             "sha256:content",
             "sha256:old-meta",
@@ -798,6 +836,7 @@ mod tests {
                 legacy_slug: None,
             }],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -813,7 +852,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_metadata_hash_absent_on_server_triggers_upload() {
-        let repo = MockRepo::with_docs(vec![make_doc("docs/a", "sha256:content")]);
+        let repo =
+            InMemoryDocumentRepository::with_documents(vec![make_doc("docs/a", "sha256:content")]);
         let token_repo = MockServiceTokenRepo;
         let request = SyncRequest {
             service_token: "legacy".to_string(),
@@ -825,6 +865,7 @@ mod tests {
                 legacy_slug: None,
             }],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -839,7 +880,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_sync_no_metadata_hash_from_client_is_backwards_compat() {
-        let repo = MockRepo::with_docs(vec![make_doc_with_meta(
+        let repo = InMemoryDocumentRepository::with_documents(vec![make_doc_with_meta(
             "docs/a",
             "sha256:content",
             "sha256:meta",
@@ -849,6 +890,7 @@ mod tests {
             service_token: "legacy".to_string(),
             documents: vec![entry("docs/a", "sha256:content")],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -867,7 +909,7 @@ mod tests {
         // New CLI sends desired title-derived slug + legacy_slug for migration.
         let mut old_doc = make_doc("docs/my-guide", "sha256:content");
         old_doc.source_path = None; // simulate old document without source_path
-        let repo = MockRepo::with_docs(vec![old_doc]);
+        let repo = InMemoryDocumentRepository::with_documents(vec![old_doc]);
         let token_repo = MockServiceTokenRepo;
         let request = SyncRequest {
             service_token: "legacy".to_string(),
@@ -879,6 +921,7 @@ mod tests {
                 legacy_slug: Some("docs/my-guide".to_string()), // path-derived (old)
             }],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -899,7 +942,10 @@ mod tests {
     #[tokio::test]
     async fn test_sync_source_path_lookup_after_migration() {
         // After migration, doc has source_path set. Next sync should find it by source_path.
-        let repo = MockRepo::with_docs(vec![make_doc("docs/my-guide", "sha256:content")]);
+        let repo = InMemoryDocumentRepository::with_documents(vec![make_doc(
+            "docs/my-guide",
+            "sha256:content",
+        )]);
         let token_repo = MockServiceTokenRepo;
         let request = SyncRequest {
             service_token: "legacy".to_string(),
@@ -911,6 +957,7 @@ mod tests {
                 legacy_slug: Some("docs/my-guide".to_string()),
             }],
             archive_missing: false,
+            bundle: None,
         };
 
         let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
@@ -920,4 +967,55 @@ mod tests {
         assert!(result.to_upload.is_empty());
         assert_eq!(result.unchanged, vec!["docs/my-guide.md"]);
     }
+
+    #[tokio::test]
+    async fn test_sync_bundle_versions_are_archived_separately() {
+        let repo = InMemoryDocumentRepository::with_documents(vec![
+            make_doc("billing/setup", "sha256:a"),
+            make_doc("billing/@v1/setup", "sha256:a"),
+            make_doc("billing/@v1/legacy", "sha256:b"),
+        ]);
+        let token_repo = MockServiceTokenRepo;
+        let bundle = |latest| SyncBundle {
+            root: "billing".to_string(),
+            version: "v1".to_string(),
+            latest,
+        };
+
+        // A prior version only considers its own documents
+        let request = SyncRequest {
+            service_token: "legacy".to_string(),
+            documents: vec![entry("billing/@v1/setup", "sha256:a")],
+            archive_missing: false,
+            bundle: Some(bundle(false)),
+        };
+        let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
+            .await
+            .unwrap();
+        assert_eq!(result.to_archive, vec!["billing/@v1/legacy"]);
+
+        // The latest version, like an untagged sync, leaves prior versions alone
+        let request = SyncRequest {
+            service_token: "legacy".to_string(),
+            documents: vec![entry("billing/setup", "sha256:a")],
+            archive_missing: false,
+            bundle: Some(bundle(true)),
+        };
+        let result = process_sync(&repo, &token_repo, None, Some("legacy"), request)
+            .await
+            .unwrap();
+        assert!(result.to_archive.is_empty());
+
+        // Slugs must lie in the target version
+        let request = SyncRequest {
+            service_token: "legacy".to_string(),
+            documents: vec![entry("billing/setup", "sha256:a")],
+            archive_missing: false,
+            bundle: Some(bundle(false)),
+        };
+        let err = process_sync(&repo, &token_repo, None, Some("legacy"), request)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::BadRequest(_)));
+    }
 }
@@ -5,16 +5,32 @@ use crate::error::AppError;
 /// database of scoped service tokens.
 ///
 /// Returns `Ok(())` when the token is valid (either legacy match or active DB
-/// token). Returns an appropriate `AppError` otherwise.
+/// token other than a read-only bot token). Returns an appropriate `AppError` otherwise.
 pub async fn validate_service_token(
     service_token_repo: &dyn ServiceTokenRepository,
     legacy_token: Option<&str>,
     raw_token: &str,
 ) -> Result<(), AppError> {
+    authenticate_service_token(service_token_repo, legacy_token, raw_token)
+        .await
+        .map(|_| ())
+}
+
+/// Name of the legacy env-var token, as returned by [`authenticate_service_token`].
+pub const LEGACY_TOKEN_NAME: &str = "service-token";
+
+/// Like [`validate_service_token`], returning the name of the token to
+/// attribute the caller's actions to ([`LEGACY_TOKEN_NAME`] for the legacy
+/// token).
+pub async fn authenticate_service_token(
+    service_token_repo: &dyn ServiceTokenRepository,
+    legacy_token: Option<&str>,
+    raw_token: &str,
+) -> Result<String, AppError> {
     // 1. Legacy token bypass
     if let Some(legacy) = legacy_token {
         if !legacy.is_empty() && raw_token == legacy {
-            return Ok(());
+            return Ok(LEGACY_TOKEN_NAME.to_string());
         }
     }
 
@@ -28,11 +44,14 @@ pub async fn validate_service_token(
     if !token.is_active {
         return Err(AppError::Auth("Service token is deactivated".into()));
     }
+    if token.is_bot() {
+        return Err(AppError::Forbidden("Bot tokens are read-only".into()));
+    }
 
     // Fire-and-forget last_used update
     if let Err(e) = service_token_repo.touch_last_used(&token.id).await {
         tracing::warn!("Failed to update last_used_at for token {}: {e}", token.id);
     }
 
-    Ok(())
+    Ok(token.name)
 }
@@ -0,0 +1,533 @@
+//! "Try it" proxy for the OpenAPI viewer.
+//!
+//! `/api/v1/try-it?scalar_url=<target>` forwards requests issued from the
+//! Scalar "Test Request" panel to internal services, so developers can call
+//! APIs from the portal without the target having to allow the portal origin
+//! via CORS. Only authenticated users may use it, and only hosts listed in
+//! `integrations.try_it.allowed_hosts` are reachable. The caller's identity is
+//! injected as `X-Lekton-User-*` headers; Lekton's own cookies are never
+//! forwarded, nor is an `Authorization` header carrying a Lekton credential
+//! (service token, personal access token or access JWT).
+//!
+//! Responses are served from the portal's origin, so they can never act
+//! there: upstream cookies, security policies and site-data headers are
+//! dropped, and every response is sandboxed and not content-sniffed, so an
+//! HTML or script body opened directly cannot run with the portal session.
+
+use std::time::Duration;
+
+use axum::body::{Body, Bytes};
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use url::Url;
+
+use crate::app::AppState;
+use crate::auth::extractor::RequiredAuthUser;
+use crate::auth::models::AuthenticatedUser;
+use crate::auth::token_service::TokenService;
+use crate::db::service_token_repository::ServiceTokenRepository;
+use crate::error::AppError;
+
+/// Headers carrying the identity of the portal user that issued the request.
+pub const USER_ID_HEADER: &str = "x-lekton-user-id";
+pub const USER_EMAIL_HEADER: &str = "x-lekton-user-email";
+pub const USER_NAME_HEADER: &str = "x-lekton-user-name";
+
+/// Request headers that are never forwarded upstream: hop-by-hop headers,
+/// headers recomputed by the HTTP client, and the portal session.
+const STRIPPED_REQUEST_HEADERS: &[&str] = &[
+    "host",
+    "cookie",
+    "origin",
+    "referer",
+    "content-length",
+    "connection",
+    "keep-alive",
+    "proxy-authorization",
+    "proxy-connection",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// Upstream response headers that are not passed back to the browser:
+/// headers that would act on the portal's origin, and hop-by-hop headers.
+const STRIPPED_RESPONSE_HEADERS: &[&str] = &[
+    "set-cookie",
+    "set-cookie2",
+    "clear-site-data",
+    "content-security-policy",
+    "content-security-policy-report-only",
+    "strict-transport-security",
+    "service-worker-allowed",
+    "refresh",
+    "link",
+    "alt-svc",
+    "x-frame-options",
+    "x-content-type-options",
+    "access-control-allow-origin",
+    "access-control-allow-credentials",
+    "access-control-allow-methods",
+    "access-control-allow-headers",
+    "access-control-expose-headers",
+    "access-control-max-age",
+    "content-length",
+    "connection",
+    "keep-alive",
+    "transfer-encoding",
+    "trailer",
+    "upgrade",
+];
+
+/// `Content-Security-Policy` of every proxied response, in case a browser is
+/// made to open one as a document.
+const RESPONSE_CSP: &str = "default-src 'none'; sandbox";
+
+#[derive(Debug, Deserialize)]
+pub struct TryItQuery {
+    /// Target URL, as sent by Scalar's `proxyUrl` protocol.
+    pub scalar_url: String,
+}
+
+/// Whether `url` targets one of the `allowed` hosts.
+///
+/// Entries match the host case-insensitively; `*.example.com` matches any
+/// subdomain of `example.com` (but not `example.com` itself) and a `:port`
+/// suffix additionally restricts the port. Only `http` and `https` URLs are
+/// accepted.
+pub fn is_allowed_host(allowed: &[String], url: &Url) -> bool {
+    if !matches!(url.scheme(), "http" | "https") {
+        return false;
+    }
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return false;
+    };
+    let host = host.to_ascii_lowercase();
+
+    allowed.iter().any(|entry| {
+        let entry = entry.trim().to_ascii_lowercase();
+        let (pattern, entry_port) = match entry.rsplit_once(':') {
+            Some((pattern, p)) => match p.parse::<u16>() {
+                Ok(p) => (pattern.to_string(), Some(p)),
+                Err(_) => (entry.clone(), None),
+            },
+            None => (entry.clone(), None),
+        };
+        let host_matches = match pattern.strip_prefix("*.") {
+            Some(domain) => host
+                .strip_suffix(domain)
+                .is_some_and(|sub| sub.len() > 1 && sub.ends_with('.')),
+            None => host == pattern,
+        };
+        host_matches && entry_port.is_none_or(|p| p == port)
+    })
+}
+
+/// Whether the `Authorization` value is a credential issued by Lekton: the
+/// legacy service token, a service token or PAT (active or not), or an access
+/// JWT. The `Bearer` scheme is optional and matched case-insensitively.
+///
+/// Lookup failures count as a Lekton credential, so the header is dropped
+/// rather than leaked when the token store is unavailable.
+pub async fn is_lekton_credential(
+    service_token_repo: &dyn ServiceTokenRepository,
+    legacy_token: &str,
+    token_service: &TokenService,
+    authorization: &HeaderValue,
+) -> bool {
+    let Ok(value) = authorization.to_str() else {
+        return false;
+    };
+    let value = value.trim();
+    let raw = match value.split_once(' ') {
+        Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") => token.trim(),
+        _ => value,
+    };
+    if raw.is_empty() {
+        return false;
+    }
+    if !legacy_token.is_empty() && raw == legacy_token {
+        return true;
+    }
+    if token_service.validate_access_token(raw).is_ok() {
+        return true;
+    }
+    match service_token_repo
+        .find_by_hash(&TokenService::hash_token(raw))
+        .await
+    {
+        Ok(token) => token.is_some(),
+        Err(e) => {
+            tracing::warn!("Could not check try-it Authorization header, dropping it: {e}");
+            true
+        }
+    }
+}
+
+/// Build the headers sent upstream: the browser's headers minus
+/// [`STRIPPED_REQUEST_HEADERS`] and any client-supplied identity headers,
+/// plus the identity of `user`. `Authorization` is dropped unless
+/// `forward_authorization` is set (see [`is_lekton_credential`]).
+pub fn upstream_request_headers(
+    headers: &HeaderMap,
+    user: &AuthenticatedUser,
+    forward_authorization: bool,
+) -> HeaderMap {
+    let identity = [USER_ID_HEADER, USER_EMAIL_HEADER, USER_NAME_HEADER];
+    let mut forwarded: HeaderMap = headers
+        .iter()
+        .filter(|(name, _)| {
+            !STRIPPED_REQUEST_HEADERS.contains(&name.as_str())
+                && !identity.contains(&name.as_str())
+                && (forward_authorization || *name != header::AUTHORIZATION)
+        })
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+
+    let values = [
+        (USER_ID_HEADER, Some(user.user_id.as_str())),
+        (USER_EMAIL_HEADER, Some(user.email.as_str())),
+        (USER_NAME_HEADER, user.name.as_deref()),
+    ];
+    for (name, value) in values {
+        // Values that are not valid header text (e.g. non-ASCII names) are skipped
+        if let Some(value) = value.and_then(|v| HeaderValue::from_str(v).ok()) {
+            forwarded.insert(HeaderName::from_static(name), value);
+        }
+    }
+    forwarded
+}
+
+/// Build the headers returned to the browser: the upstream headers minus
+/// [`STRIPPED_RESPONSE_HEADERS`], plus `nosniff` and a sandboxing
+/// `Content-Security-Policy`.
+pub fn downstream_response_headers(headers: &HeaderMap) -> HeaderMap {
+    let mut returned: HeaderMap = headers
+        .iter()
+        .filter(|(name, _)| !STRIPPED_RESPONSE_HEADERS.contains(&name.as_str()))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    returned.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    returned.insert(
+        header::CONTENT_SECURITY_POLICY,
+        HeaderValue::from_static(RESPONSE_CSP),
+    );
+    returned
+}
+
+/// Axum handler for `/api/v1/try-it` (any method).
+///
+/// Returns `404` when the proxy is disabled, `403` for hosts outside the
+/// allowlist and `502` when the upstream cannot be reached. Upstream
+/// redirects are returned to the browser rather than followed, so they
+/// cannot lead outside the allowlist.
+pub async fn try_it_proxy_handler(
+    State(state): State<AppState>,
+    RequiredAuthUser(user): RequiredAuthUser,
+    Query(query): Query<TryItQuery>,
+    method: Method,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, AppError> {
+    let config = state
+        .try_it
+        .clone()
+        .ok_or_else(|| AppError::NotFound("Try-it proxy is not configured".into()))?;
+
+    let target = Url::parse(&query.scalar_url)
+        .map_err(|e| AppError::BadRequest(format!("Invalid target URL: {e}")))?;
+    if !is_allowed_host(&config.allowed_hosts, &target) {
+        return Err(AppError::Forbidden(format!(
+            "Host '{}' is not allowed for try-it requests",
+            target.host_str().unwrap_or_default()
+        )));
+    }
+
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(Duration::from_secs(config.timeout_secs))
+        .build()?;
+
+    tracing::info!(
+        user = %user.email,
+        method = %method,
+        host = %target.host_str().unwrap_or_default(),
+        path = %target.path(),
+        "Proxying try-it request"
+    );
+
+    let forward_authorization = match headers.get(header::AUTHORIZATION) {
+        Some(authorization) => {
+            !is_lekton_credential(
+                state.service_token_repo.as_ref(),
+                &state.service_token,
+                &state.token_service,
+                authorization,
+            )
+            .await
+        }
+        None => true,
+    };
+    if !forward_authorization {
+        tracing::warn!(
+            user = %user.email,
+            "Dropping try-it Authorization header carrying a Lekton credential"
+        );
+    }
+
+    let upstream = client
+        .request(method, target)
+        .headers(upstream_request_headers(
+            &headers,
+            &user,
+            forward_authorization,
+        ))
+        .body(body)
+        .send()
+        .await;
+    let upstream = match upstream {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!("Try-it upstream request failed: {e}");
+            return Ok(crate::api::errors::Problem::new(
+                StatusCode::BAD_GATEWAY.as_u16(),
+                "upstream_error",
+                "Bad gateway",
+                format!("Upstream request failed: {e}"),
+            )
+            .into_response());
+        }
+    };
+
+    let status = upstream.status();
+    let response_headers = downstream_response_headers(upstream.headers());
+    let body = upstream.bytes().await.map_err(|e| {
+        tracing::warn!("Failed to read try-it upstream response: {e}");
+        AppError::Internal(format!("Failed to read upstream response: {e}"))
+    })?;
+
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = status;
+    *response.headers_mut() = response_headers;
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    use crate::db::service_token_models::ServiceToken;
+    use crate::testing::InMemoryServiceTokenRepository;
+
+    fn allowed(entries: &[&str]) -> Vec<String> {
+        entries.iter().map(|e| e.to_string()).collect()
+    }
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    fn user() -> AuthenticatedUser {
+        AuthenticatedUser {
+            user_id: "u-1".to_string(),
+            email: "dev@acme.com".to_string(),
+            name: Some("Dev".to_string()),
+            is_admin: false,
+        }
+    }
+
+    #[test]
+    fn exact_and_wildcard_hosts_match() {
+        let hosts = allowed(&["payments.internal", "*.svc.cluster.local"]);
+        assert!(is_allowed_host(
+            &hosts,
+            &url("https://payments.internal/v1")
+        ));
+        assert!(is_allowed_host(
+            &hosts,
+            &url("http://PAYMENTS.internal:8080/")
+        ));
+        assert!(is_allowed_host(
+            &hosts,
+            &url("http://api.svc.cluster.local/x")
+        ));
+        assert!(is_allowed_host(
+            &hosts,
+            &url("http://a.b.svc.cluster.local/x")
+        ));
+        assert!(!is_allowed_host(&hosts, &url("http://svc.cluster.local/x")));
+        assert!(!is_allowed_host(
+            &hosts,
+            &url("http://evilsvc.cluster.local/x")
+        ));
+        assert!(!is_allowed_host(
+            &hosts,
+            &url("http://payments.internal.evil.com/")
+        ));
+        assert!(!is_allowed_host(&hosts, &url("ftp://payments.internal/")));
+        assert!(!is_allowed_host(&[], &url("https://payments.internal/")));
+    }
+
+    #[test]
+    fn port_restricted_entries_match_only_that_port() {
+        let hosts = allowed(&["payments.internal:8443", "[::1]:9000"]);
+        assert!(is_allowed_host(
+            &hosts,
+            &url("https://payments.internal:8443/")
+        ));
+        assert!(!is_allowed_host(&hosts, &url("https://payments.internal/")));
+        assert!(is_allowed_host(&hosts, &url("http://[::1]:9000/")));
+        assert!(!is_allowed_host(&hosts, &url("http://[::1]:9001/")));
+    }
+
+    #[test]
+    fn upstream_headers_drop_session_and_inject_identity() {
+        let mut headers = HeaderMap::new();
+        headers.insert("cookie", HeaderValue::from_static("lekton_access_token=x"));
+        headers.insert("host", HeaderValue::from_static("portal.acme.com"));
+        headers.insert("authorization", HeaderValue::from_static("Bearer api"));
+        headers.insert(USER_ID_HEADER, HeaderValue::from_static("spoofed"));
+
+        let forwarded = upstream_request_headers(&headers, &user(), true);
+        assert!(forwarded.get("cookie").is_none());
+        assert!(forwarded.get("host").is_none());
+        assert_eq!(forwarded["authorization"], "Bearer api");
+        assert_eq!(forwarded[USER_ID_HEADER], "u-1");
+        assert_eq!(forwarded[USER_EMAIL_HEADER], "dev@acme.com");
+        assert_eq!(forwarded[USER_NAME_HEADER], "Dev");
+
+        let forwarded = upstream_request_headers(&headers, &user(), false);
+        assert!(forwarded.get("authorization").is_none());
+        assert_eq!(forwarded[USER_ID_HEADER], "u-1");
+    }
+
+    /// A token store that cannot be reached.
+    struct UnavailableTokenRepo;
+
+    fn unavailable<T>() -> Result<T, AppError> {
+        Err(AppError::Database("unavailable".into()))
+    }
+
+    #[async_trait]
+    impl ServiceTokenRepository for UnavailableTokenRepo {
+        async fn create(&self, _: ServiceToken) -> Result<(), AppError> {
+            unavailable()
+        }
+        async fn find_by_hash(&self, _: &str) -> Result<Option<ServiceToken>, AppError> {
+            unavailable()
+        }
+        async fn find_by_name(&self, _: &str) -> Result<Option<ServiceToken>, AppError> {
+            unavailable()
+        }
+        async fn find_by_id(&self, _: &str) -> Result<Option<ServiceToken>, AppError> {
+            unavailable()
+        }
+        async fn list_all(&self) -> Result<Vec<ServiceToken>, AppError> {
+            unavailable()
+        }
+        async fn deactivate(&self, _: &str) -> Result<(), AppError> {
+            unavailable()
+        }
+        async fn touch_last_used(&self, _: &str) -> Result<(), AppError> {
+            unavailable()
+        }
+        async fn check_scope_overlap(
+            &self,
+            _: &[String],
+            _: Option<&str>,
+        ) -> Result<bool, AppError> {
+            unavailable()
+        }
+        async fn set_active(&self, _: &str, _: bool) -> Result<(), AppError> {
+            unavailable()
+        }
+        async fn list_by_user_id(&self, _: &str) -> Result<Vec<ServiceToken>, AppError> {
+            unavailable()
+        }
+        async fn list_pats_paginated(
+            &self,
+            _: u64,
+            _: u64,
+        ) -> Result<(Vec<ServiceToken>, u64), AppError> {
+            unavailable()
+        }
+        async fn delete_pat(&self, _: &str, _: &str) -> Result<(), AppError> {
+            unavailable()
+        }
+    }
+
+    #[tokio::test]
+    async fn lekton_credentials_are_recognised() {
+        // Deactivated tokens still count as Lekton credentials
+        let repo = InMemoryServiceTokenRepository::with_tokens(vec![ServiceToken {
+            id: "t-1".into(),
+            name: "my-pat".into(),
+            token_hash: TokenService::hash_token("pat-secret"),
+            allowed_scopes: vec![],
+            token_type: "pat".into(),
+            user_id: Some("u-1".into()),
+            can_write: false,
+            created_by: "dev@acme.com".into(),
+            created_at: chrono::Utc::now(),
+            last_used_at: None,
+            is_active: false,
+            project: None,
+            access_levels: vec![],
+        }]);
+        let tokens = TokenService::new("a-test-secret-of-sufficient-length", 900, 7);
+        let jwt = tokens.generate_access_token(&user()).unwrap();
+        let is_lekton = |value: String| {
+            let repo = &repo;
+            let tokens = &tokens;
+            async move {
+                is_lekton_credential(repo, "legacy-secret", tokens, &value.parse().unwrap()).await
+            }
+        };
+
+        assert!(is_lekton("Bearer pat-secret".into()).await);
+        assert!(is_lekton("bearer  pat-secret".into()).await);
+        assert!(is_lekton("pat-secret".into()).await);
+        assert!(is_lekton("Bearer legacy-secret".into()).await);
+        assert!(is_lekton(format!("Bearer {jwt}")).await);
+        assert!(!is_lekton("Bearer upstream-api-key".into()).await);
+        assert!(!is_lekton("Basic dXNlcjpwYXNz".into()).await);
+
+        assert!(
+            is_lekton_credential(
+                &UnavailableTokenRepo,
+                "",
+                &tokens,
+                &HeaderValue::from_static("Bearer upstream-api-key")
+            )
+            .await
+        );
+    }
+
+    #[test]
+    fn downstream_headers_drop_cookies_and_sandbox_the_body() {
+        let mut headers = HeaderMap::new();
+        headers.insert("set-cookie", HeaderValue::from_static("session=x"));
+        headers.insert("clear-site-data", HeaderValue::from_static("\"*\""));
+        headers.insert(
+            "content-security-policy",
+            HeaderValue::from_static("default-src *"),
+        );
+        headers.insert("content-type", HeaderValue::from_static("text/html"));
+        headers.insert("x-request-id", HeaderValue::from_static("r-1"));
+
+        let returned = downstream_response_headers(&headers);
+        assert!(returned.get("set-cookie").is_none());
+        assert!(returned.get("clear-site-data").is_none());
+        assert_eq!(returned["content-security-policy"], RESPONSE_CSP);
+        assert_eq!(returned["x-content-type-options"], "nosniff");
+        assert_eq!(returned["content-type"], "text/html");
+        assert_eq!(returned["x-request-id"], "r-1");
+    }
+}
@@ -12,79 +12,121 @@ pub struct UploadResponse {
 
 /// Axum handler for `POST /api/v1/upload-image`.
 ///
-/// Accepts a multipart form with a single file field named "file".
-/// Uploads the image to S3 under the `images/` prefix.
+/// Accepts a multipart form with a single file field named "file", from a
+/// logged-in user or with a `service_token` field (see
+/// [`crate::api::uploader`]). Uploads the image to S3 under the `images/` prefix.
 pub async fn upload_image_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    crate::auth::extractor::OptionalAuthUser(user): crate::auth::extractor::OptionalAuthUser,
     mut multipart: Multipart,
 ) -> Result<axum::Json<UploadResponse>, AppError> {
+    let mut service_token = None;
+    let mut file = None;
+
     while let Some(field) = multipart
         .next_field()
         .await
         .map_err(|e| AppError::BadRequest(format!("Multipart error: {e}")))?
     {
         let name = field.name().unwrap_or("").to_string();
-        if name != "file" {
-            continue;
+        match name.as_str() {
+            "service_token" => {
+                service_token = Some(
+                    field
+                        .text()
+                        .await
+                        .map_err(|e| AppError::BadRequest(format!("Failed to read token: {e}")))?,
+                );
+            }
+            "file" => {
+                let file_name = field.file_name().unwrap_or("upload.bin").to_string();
+                let content_type = field
+                    .content_type()
+                    .unwrap_or("application/octet-stream")
+                    .to_string();
+                let data = field
+                    .bytes()
+                    .await
+                    .map_err(|e| AppError::BadRequest(format!("Failed to read file: {e}")))?;
+                file = Some((file_name, content_type, data));
+            }
+            _ => {}
         }
+    }
 
-        let file_name = field.file_name().unwrap_or("upload.bin").to_string();
+    let uploader =
+        crate::api::uploader::resolve_uploader(&state, user, service_token.as_deref()).await?;
+    let (file_name, content_type, data) =
+        file.ok_or_else(|| AppError::BadRequest("No file field found in request".into()))?;
 
-        let content_type = field
-            .content_type()
-            .unwrap_or("application/octet-stream")
-            .to_string();
+    // Only allow image types
+    if !content_type.starts_with("image/") {
+        return Err(AppError::BadRequest("Only image files are allowed".into()));
+    }
 
-        // Only allow image types
-        if !content_type.starts_with("image/") {
-            return Err(AppError::BadRequest("Only image files are allowed".into()));
+    // Generate a unique key
+    let timestamp = chrono::Utc::now().timestamp_millis();
+    let sanitized_name = file_name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    let s3_key = format!("images/{}_{}", timestamp, sanitized_name);
+
+    crate::api::uploader::with_upload_quota(&state, &uploader, data.len() as u64, async {
+        if let Some(scanner) = &state.upload_scanner {
+            scanner
+                .screen(crate::scanning::Upload {
+                    target_key: &s3_key,
+                    content_type: &content_type,
+                    uploaded_by: &uploader,
+                    data: &data,
+                })
+                .await?;
         }
-
-        let data = field
-            .bytes()
-            .await
-            .map_err(|e| AppError::BadRequest(format!("Failed to read file: {e}")))?;
-
-        // Generate a unique key
-        let timestamp = chrono::Utc::now().timestamp_millis();
-        let sanitized_name = file_name
-            .chars()
-            .map(|c| {
-                if c.is_alphanumeric() || c == '.' || c == '-' {
-                    c
-                } else {
-                    '_'
-                }
-            })
-            .collect::<String>();
-        let s3_key = format!("images/{}_{}", timestamp, sanitized_name);
-
         state
             .storage_client
             .put_object(&s3_key, data.to_vec())
-            .await?;
-
-        // Return the URL path (served through a future image proxy or direct S3 access)
-        let url = format!("/api/v1/image/{}", s3_key.trim_start_matches("images/"));
+            .await
+    })
+    .await?;
+    tracing::info!(uploader = %uploader, s3_key = %s3_key, "Uploaded image");
 
-        return Ok(axum::Json(UploadResponse { url }));
-    }
+    // Return the URL path (served through a future image proxy or direct S3 access)
+    let url = format!("/api/v1/image/{}", s3_key.trim_start_matches("images/"));
 
-    Err(AppError::BadRequest(
-        "No file field found in request".into(),
-    ))
+    Ok(axum::Json(UploadResponse { url }))
 }
 
 /// Axum handler for `GET /api/v1/image/:filename`.
 ///
-/// Serves an image from S3 storage.
+/// Serves an image from S3 storage to callers who can read a document
+/// embedding it (see [`crate::api::asset_access`]). Single-range `Range`
+/// requests are honored like on the asset route.
 pub async fn serve_image_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
     axum::extract::Path(filename): axum::extract::Path<String>,
+    crate::auth::extractor::OptionalAuthUser(user): crate::auth::extractor::OptionalAuthUser,
+    axum::extract::Query(query): axum::extract::Query<crate::api::asset_access::DownloadQuery>,
+    headers: axum::http::HeaderMap,
 ) -> Result<axum::response::Response, AppError> {
-    use axum::response::IntoResponse;
-
     let s3_key = format!("images/{}", filename);
+    if !crate::api::asset_access::authorize_download(
+        &state,
+        &headers,
+        user,
+        query.share.as_deref(),
+        &s3_key,
+    )
+    .await?
+    {
+        return Err(AppError::NotFound("Image not found".into()));
+    }
 
     let data = state
         .storage_client
@@ -107,5 +149,11 @@ pub async fn serve_image_handler(
         "application/octet-stream"
     };
 
-    Ok(([(axum::http::header::CONTENT_TYPE, content_type)], data).into_response())
+    let range = headers
+        .get(axum::http::header::RANGE)
+        .and_then(|value| value.to_str().ok());
+    Ok(
+        crate::api::assets::ServedAsset::sliced(content_type.to_string(), data, range)
+            .into_response(),
+    )
 }
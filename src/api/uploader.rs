@@ -0,0 +1,142 @@
+//! Who uploads attachments and images, and how much they may upload per day.
+//!
+//! Uploads need a logged-in session or a service token (`service_token`
+//! multipart field) and are attributed to the user's email or the token's
+//! name. Each uploader may store up to `[quotas.uploads]` megabytes per UTC
+//! day; larger uploads fail with `403 quota_exceeded`.
+
+use std::future::Future;
+
+use chrono::Utc;
+
+use crate::app::AppState;
+use crate::auth::models::AuthenticatedUser;
+use crate::db::upload_usage_repository::UploadUsageRepository;
+use crate::error::AppError;
+
+/// The identity uploads are attributed to: the session user's email, or the
+/// name of the service token sent with the upload.
+pub(crate) async fn resolve_uploader(
+    state: &AppState,
+    user: Option<AuthenticatedUser>,
+    service_token: Option<&str>,
+) -> Result<String, AppError> {
+    if let Some(user) = user {
+        return Ok(user.email);
+    }
+    match service_token {
+        Some(raw_token) => {
+            crate::api::token_validation::authenticate_service_token(
+                state.service_token_repo.as_ref(),
+                Some(&state.service_token),
+                raw_token,
+            )
+            .await
+        }
+        None => Err(AppError::Auth("Authentication required".into())),
+    }
+}
+
+/// Count `bytes` against the uploader's daily quota, failing when they don't
+/// fit in `daily_limit` (`None` = unlimited).
+pub(crate) async fn charge_upload(
+    repo: &dyn UploadUsageRepository,
+    uploader: &str,
+    bytes: u64,
+    daily_limit: Option<u64>,
+) -> Result<(), AppError> {
+    let Some(limit) = daily_limit else {
+        return Ok(());
+    };
+    let today = Utc::now().date_naive();
+    let bytes = bytes as i64;
+    let total = repo.add(uploader, today, bytes).await?;
+    if total > limit as i64 {
+        repo.add(uploader, today, -bytes).await?;
+        return Err(AppError::QuotaExceeded(format!(
+            "'{uploader}' has reached the daily upload quota of {} MB",
+            limit / (1024 * 1024)
+        )));
+    }
+    Ok(())
+}
+
+/// Run `upload` within the uploader's daily quota: its size is charged
+/// first and given back when the upload fails.
+pub(crate) async fn with_upload_quota<T>(
+    state: &AppState,
+    uploader: &str,
+    bytes: u64,
+    upload: impl Future<Output = Result<T, AppError>>,
+) -> Result<T, AppError> {
+    let daily_limit = state.quotas.uploads.daily_limit_bytes(uploader);
+    charge_upload(
+        state.upload_usage_repo.as_ref(),
+        uploader,
+        bytes,
+        daily_limit,
+    )
+    .await?;
+
+    let result = upload.await;
+    if result.is_err() && daily_limit.is_some() {
+        let today = Utc::now().date_naive();
+        if let Err(e) = state
+            .upload_usage_repo
+            .add(uploader, today, -(bytes as i64))
+            .await
+        {
+            tracing::warn!(uploader, "Failed to give back upload quota: {e}");
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryUsage(Mutex<HashMap<(String, NaiveDate), i64>>);
+
+    #[async_trait]
+    impl UploadUsageRepository for MemoryUsage {
+        async fn add(&self, uploader: &str, day: NaiveDate, bytes: i64) -> Result<i64, AppError> {
+            let mut usage = self.0.lock().unwrap();
+            let total = usage.entry((uploader.to_string(), day)).or_default();
+            *total += bytes;
+            Ok(*total)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_charge_upload_enforces_daily_limit() {
+        let repo = MemoryUsage::default();
+        let limit = Some(1024 * 1024);
+
+        charge_upload(&repo, "alice@example.com", 600 * 1024, limit)
+            .await
+            .unwrap();
+        let err = charge_upload(&repo, "alice@example.com", 600 * 1024, limit)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::QuotaExceeded(_)));
+
+        // The rejected upload is not counted, and other uploaders are separate
+        charge_upload(&repo, "alice@example.com", 400 * 1024, limit)
+            .await
+            .unwrap();
+        charge_upload(&repo, "bob@example.com", 600 * 1024, limit)
+            .await
+            .unwrap();
+        // Unlimited uploaders are not tracked
+        charge_upload(&repo, "docs-ci", u64::MAX / 2, None)
+            .await
+            .unwrap();
+        assert_eq!(repo.0.lock().unwrap().len(), 2);
+    }
+}
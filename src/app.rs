@@ -1,38 +1,78 @@
 use leptos::prelude::*;
 use leptos_meta::*;
 use leptos_router::components::*;
-use leptos_router::path;
+use leptos_router::{lazy_route, path, Lazy, LazyRoute, SsrMode};
 use serde::{Deserialize, Serialize};
 
-use crate::components::Layout;
+use crate::components::{AppErrorBoundary, Layout, RequireLogin, ToastHost};
 use crate::editor::component::EditorPage;
 use crate::pages::{
-    AdminSettingsPage, ChatPage, DocPage, HomePage, LoginPage, NotFound, ProfilePage, PromptsPage,
+    AdminSettingsPage, BrowsePage, ChangelogPage, ChatPage, CollectionPage, CollectionsPage,
+    DocPage, GlossaryPage, HomePage, LoginPage, NotFound, NotificationsPage, ProfilePage,
+    PromptsPage, ServiceChangelogPage,
 };
+use crate::schema::catalog::EventCatalogPage;
 use crate::schema::component::{SchemaListPage, SchemaViewerPage};
 // Re-export server functions so existing `use crate::app::*` imports keep working.
 pub use crate::server::access_levels::*;
+pub use crate::server::admin_documents::*;
+pub use crate::server::approvals::*;
 pub use crate::server::auth_fns::*;
+pub use crate::server::branding::*;
+pub use crate::server::changelog::*;
+pub use crate::server::collections::*;
 pub use crate::server::custom_css::*;
+pub use crate::server::doc_ratings::*;
 pub use crate::server::docs::*;
+pub use crate::server::edit_locks::*;
+pub use crate::server::embeds::*;
 pub use crate::server::feedback::*;
+pub use crate::server::glossary::*;
+pub use crate::server::incident_mode::*;
+pub use crate::server::issue_tracker::*;
+pub use crate::server::ldap::*;
+pub use crate::server::locale::*;
 pub use crate::server::nav::NavigationOrderEntry;
 pub use crate::server::nav::*;
+pub use crate::server::notifications::*;
+pub use crate::server::on_call::*;
 pub use crate::server::pats::*;
+pub use crate::server::portal_config::*;
+pub use crate::server::projects::*;
 pub use crate::server::prompts::*;
 pub use crate::server::reindex::*;
 pub use crate::server::search::*;
 pub use crate::server::service_tokens::*;
+pub use crate::server::share_links::*;
+pub use crate::server::spelling::*;
+pub use crate::server::usage::*;
 pub use crate::server::users::*;
+pub use crate::server::watches::*;
 
 /// Newtype wrapper for the demo-mode signal, used as Leptos context.
 #[derive(Clone, Copy)]
 pub struct IsDemoMode(pub Signal<bool>);
 
+/// Newtype wrapper for the "current user has been loaded" signal, used as
+/// Leptos context. `false` while the session is still being resolved, so the
+/// current user being `None` does not yet mean "anonymous".
+#[derive(Clone, Copy)]
+pub struct IsSessionResolved(pub Signal<bool>);
+
+/// Newtype wrapper for the instance branding signal, used as Leptos context.
+#[derive(Clone, Copy)]
+pub struct InstanceBranding(pub Signal<BrandingSettings>);
+
 /// Newtype wrapper for the RAG-enabled signal, used as Leptos context.
+/// `false` when RAG is not configured or the chat feature flag is off.
 #[derive(Clone, Copy)]
 pub struct IsRagEnabled(pub Signal<bool>);
 
+/// Newtype wrapper for the portal configuration signal (feature flags),
+/// used as Leptos context.
+#[derive(Clone, Copy)]
+pub struct PortalFeatures(pub Signal<PortalConfig>);
+
 #[cfg(feature = "ssr")]
 impl axum::extract::FromRef<AppState> for crate::auth::extractor::DemoMode {
     fn from_ref(state: &AppState) -> Self {
@@ -61,6 +101,12 @@ pub struct AppState {
     pub user_prompt_preference_repo:
         Arc<dyn crate::db::user_prompt_preference_repository::UserPromptPreferenceRepository>,
     pub demo_mode: bool,
+    /// Local accounts (`auth.local_accounts`) can sign in and be managed.
+    #[from_ref(skip)]
+    pub local_accounts: bool,
+    /// LDAP sign-in (`auth.ldap`); `None` without a directory URL.
+    #[from_ref(skip)]
+    pub ldap: Option<Arc<crate::config::LdapConfig>>,
     pub leptos_options: LeptosOptions,
     // ── Auth (phase 5) ────────────────────────────────────────────────────────
     pub user_repo: Arc<dyn crate::db::user_repository::UserRepository>,
@@ -78,12 +124,108 @@ pub struct AppState {
     pub feedback_repo: Option<Arc<dyn crate::db::feedback_repository::FeedbackRepository>>,
     pub documentation_feedback_repo:
         Arc<dyn crate::db::documentation_feedback_repository::DocumentationFeedbackRepository>,
+    pub glossary_repo: Arc<dyn crate::db::glossary_repository::GlossaryRepository>,
+    pub spelling_dictionary_repo:
+        Arc<dyn crate::db::spelling_dictionary_repository::SpellingDictionaryRepository>,
+    pub changelog_repo: Arc<dyn crate::db::changelog_repository::ChangelogRepository>,
+    pub contract_test_repo: Arc<dyn crate::db::contract_test_repository::ContractTestRepository>,
+    pub bundle_repo: Arc<dyn crate::db::bundle_repository::BundleRepository>,
+    pub project_repo: Arc<dyn crate::db::project_repository::ProjectRepository>,
+    pub edit_lock_repo: Arc<dyn crate::db::edit_lock_repository::EditLockRepository>,
+    pub pending_ingest_repo: Arc<dyn crate::db::pending_ingest_repository::PendingIngestRepository>,
+    pub job_run_repo: Arc<dyn crate::db::job_run_repository::JobRunRepository>,
+    pub watch_repo: Arc<dyn crate::db::watch_repository::WatchRepository>,
+    pub notification_repo: Arc<dyn crate::db::notification_repository::NotificationRepository>,
+    pub asset_download_repo: Arc<dyn crate::db::asset_download_repository::AssetDownloadRepository>,
+    pub quarantine_repo: Arc<dyn crate::db::quarantine_repository::QuarantineRepository>,
+    pub share_link_repo: Arc<dyn crate::db::share_link_repository::ShareLinkRepository>,
+    pub collection_repo: Arc<dyn crate::db::collection_repository::CollectionRepository>,
+    pub doc_rating_repo: Arc<dyn crate::db::doc_rating_repository::DocRatingRepository>,
+    pub upload_usage_repo: Arc<dyn crate::db::upload_usage_repository::UploadUsageRepository>,
+    pub activity_events: crate::api::events::ActivityEvents,
     pub embedding_cache_repo:
         Option<Arc<dyn crate::db::embedding_cache_repository::EmbeddingCacheRepository>>,
     #[from_ref(skip)]
     pub insecure_cookies: bool,
+    /// Lifetime and `SameSite` attribute of demo session cookies.
+    #[from_ref(skip)]
+    pub demo_session: crate::auth::demo_auth::DemoSessionConfig,
     #[from_ref(skip)]
     pub max_attachment_size_bytes: u64,
+    /// Documents larger than this are rendered as a preview
+    /// (`rendering.max_render_bytes`); `0` renders everything.
+    #[from_ref(skip)]
+    pub max_render_bytes: usize,
+    /// Renders stored documents to HTML, with the standard transforms and any
+    /// registered by the deployment.
+    #[from_ref(skip)]
+    pub render_pipeline: Arc<crate::rendering::pipeline::RenderPipeline>,
+    /// Set once the startup backfill of document asset references has
+    /// finished; until then files no document embeds are not served.
+    #[from_ref(skip)]
+    pub asset_refs_ready: Arc<std::sync::atomic::AtomicBool>,
+    /// GitHub webhook integration; `None` when no webhook secret is configured.
+    #[from_ref(skip)]
+    pub github: Option<Arc<crate::config::GitHubConfig>>,
+    /// "Try it" proxy for the OpenAPI viewer; `None` when no host is allowed.
+    #[from_ref(skip)]
+    pub try_it: Option<Arc<crate::config::TryItConfig>>,
+    /// Grafana embed proxy; `None` without a Grafana URL and token.
+    #[from_ref(skip)]
+    pub grafana: Option<Arc<crate::config::GrafanaProxyConfig>>,
+    /// Schema viewer bundles; `None` selects the built-in viewer.
+    #[from_ref(skip)]
+    pub spec_viewer_assets: Option<crate::schema::component::SpecViewerAssets>,
+    /// Circuit breakers of the downstream services, reported by `/readyz`.
+    #[from_ref(skip)]
+    pub circuit_breakers: Vec<Arc<crate::resilience::CircuitBreaker>>,
+    /// Per-service-owner document and storage limits enforced at ingest.
+    #[from_ref(skip)]
+    pub quotas: Arc<crate::config::QuotaConfig>,
+    /// Tag and service owner normalization applied at ingest.
+    #[from_ref(skip)]
+    pub taxonomy: Arc<crate::config::TaxonomyConfig>,
+    /// Markdown style checks run at ingest.
+    #[from_ref(skip)]
+    pub lint: Arc<crate::config::LintConfig>,
+    /// Signed links to restricted documents (`[share_links]` config).
+    #[from_ref(skip)]
+    pub share_links: Arc<crate::config::ShareLinksConfig>,
+    /// Documents framed by other sites (`[embed]` config).
+    #[from_ref(skip)]
+    pub embed: Arc<crate::config::EmbedConfig>,
+    /// Where background jobs are submitted.
+    #[from_ref(skip)]
+    pub job_queue: Arc<dyn crate::jobs::JobQueue>,
+    /// Periodic job schedules (`[scheduler]` config).
+    #[from_ref(skip)]
+    pub scheduler: Arc<crate::config::SchedulerConfig>,
+    /// Notification emails; `None` when no SMTP relay is configured.
+    #[from_ref(skip)]
+    pub email: Option<Arc<crate::email::EmailNotifier>>,
+    /// Notifies people `@mentioned` in documents; `None` disables mentions.
+    #[from_ref(skip)]
+    pub mentions: Option<Arc<crate::mentions::MentionNotifier>>,
+    /// Delivers in-app notifications with the configured retention.
+    #[from_ref(skip)]
+    pub inbox: Arc<crate::notifications::Inbox>,
+    /// Malware scanning of uploads; `None` when no scanner is configured.
+    #[from_ref(skip)]
+    pub upload_scanner: Option<Arc<crate::scanning::UploadScanner>>,
+    /// Spell checking of dry-run ingests and editor content; `None` when
+    /// `[spellcheck]` is disabled.
+    #[from_ref(skip)]
+    pub spell_checker: Option<Arc<crate::spellcheck::SpellChecker>>,
+    /// Refuses ingests and editor saves containing credentials; `None` with
+    /// `[secrets] action = "off"`.
+    #[from_ref(skip)]
+    pub secret_guard: Option<Arc<crate::secrets::SecretGuard>>,
+    /// Current on-call lookups for the schedules set in the admin settings.
+    #[from_ref(skip)]
+    pub on_call: Arc<crate::on_call::OnCallDirectory>,
+    /// Cached statuses of the issues linked from documents and comments.
+    #[from_ref(skip)]
+    pub issue_tracker: Arc<crate::issue_tracker::IssueTracker>,
 }
 
 #[cfg(feature = "ssr")]
@@ -99,12 +241,12 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
                 </script>
                 <AutoReload options=options.clone() />
                 <HydrationScripts options=options />
-                <Meta name="description" content="Lekton: A dynamic, high-performance Internal Developer Portal with RBAC and unified schema registry." />
                 <Stylesheet id="leptos" href="/pkg/lekton.css" />
                 <Link rel="stylesheet" href="/custom.css" />
                 <script type="module" src="/js/tiptap-bundle.min.js"></script>
                 <script type="module" src="/js/tiptap.js"></script>
                 <script src="/js/mermaid-loader.js"></script>
+                <script src="/js/doc-enhancements.js"></script>
             </head>
             <body>
                 <App />
@@ -149,11 +291,15 @@ pub fn App() -> impl IntoView {
     let user_resource =
         LocalResource::new(|| crate::auth::refresh_client::with_auth_bootstrap(get_current_user));
     let demo_mode_resource = LocalResource::new(get_is_demo_mode);
-    let rag_resource = LocalResource::new(get_is_rag_enabled);
+    let portal_resource = Resource::new(|| (), |_| get_portal_config());
+    let branding_resource = Resource::new(|| (), |_| get_branding());
+    let locale_resource = Resource::new(|| (), |_| get_locale());
 
     let current_user: Signal<Option<crate::auth::models::AuthenticatedUser>> =
         Signal::derive(move || user_resource.get().and_then(|res| res.ok()).flatten());
 
+    let is_session_resolved: Signal<bool> = Signal::derive(move || user_resource.get().is_some());
+
     let is_demo_mode: Signal<bool> = Signal::derive(move || {
         demo_mode_resource
             .get()
@@ -161,36 +307,109 @@ pub fn App() -> impl IntoView {
             .unwrap_or(true)
     });
 
-    let is_rag_enabled: Signal<bool> =
-        Signal::derive(move || rag_resource.get().and_then(|res| res.ok()).unwrap_or(false));
+    let portal: Signal<PortalConfig> = Signal::derive(move || {
+        portal_resource
+            .get()
+            .and_then(|res| res.ok())
+            .unwrap_or_default()
+    });
+
+    let is_rag_enabled: Signal<bool> = Signal::derive(move || portal.get().chat_enabled);
+
+    let branding: Signal<BrandingSettings> = Signal::derive(move || {
+        branding_resource
+            .get()
+            .and_then(|res| res.ok())
+            .unwrap_or_default()
+    });
+
+    let locale: Signal<Locale> = Signal::derive(move || {
+        locale_resource
+            .get()
+            .and_then(|res| res.ok())
+            .unwrap_or_default()
+    });
 
     provide_context(current_user);
+    provide_context(IsSessionResolved(is_session_resolved));
     provide_context(IsDemoMode(is_demo_mode));
     provide_context(IsRagEnabled(is_rag_enabled));
+    provide_context(PortalFeatures(portal));
+    provide_context(InstanceBranding(branding));
+    provide_context(crate::i18n::I18n(locale));
     provide_context(crate::pages::chat::ChatContext::new());
+    provide_context(crate::components::Toasts::new());
 
     view! {
-        <Title text="Lekton — Internal Developer Portal" />
+        <Html {..} lang=move || locale.get().code() />
+        <Title text=move || format!("{} — Internal Developer Portal", branding.get().portal_name) />
+        <Meta
+            name="description"
+            content=move || branding.get().meta_description(crate::i18n::translate(locale.get(), "home.subtitle"))
+        />
 
         <Router>
             <Layout>
-                <Routes fallback=|| view! { <NotFound /> }>
-                    <Route path=path!("/") view=HomePage />
-                    <Route path=path!("/login") view=LoginPage />
-                    <Route path=path!("/docs/*slug") view=DocPage />
-                    <Route path=path!("/edit/*slug") view=EditorPage />
-                    <Route path=path!("/schemas") view=SchemaListPage />
-                    <Route path=path!("/schemas/*name") view=SchemaViewerPage />
-                    <Route path=path!("/chat") view=ChatPage />
-                    <Route path=path!("/prompts") view=PromptsPage />
-                    <Route path=path!("/profile") view=ProfilePage />
-                    <Route path=path!("/admin/:section") view=AdminSettingsPage />
-                </Routes>
+                <AppErrorBoundary>
+                    <Routes fallback=|| view! { <NotFound /> }>
+                        <Route path=path!("/") view=HomePage />
+                        <Route path=path!("/login") view=LoginPage />
+                        // Stream the shell first; document content and navigation
+                        // follow as their resources resolve
+                        <Route path=path!("/docs/*slug") view=DocPage ssr=SsrMode::OutOfOrder />
+                        <Route path=path!("/browse") view=BrowsePage />
+                        <Route path=path!("/glossary") view=GlossaryPage />
+                        <Route path=path!("/changelog") view=ChangelogPage />
+                        <Route path=path!("/changelog/*service") view=ServiceChangelogPage />
+                        <Route path=path!("/collections") view=CollectionsPage />
+                        <Route path=path!("/collections/:id") view=CollectionPage />
+                        // Lazy routes: with `cargo leptos build --split` their code is
+                        // left out of the main bundle that document pages download
+                        <Route path=path!("/edit/*slug") view={Lazy::<EditorRoute>::new()} />
+                        <Route path=path!("/schemas") view={Lazy::<SchemaListRoute>::new()} />
+                        <Route path=path!("/schemas/*name") view={Lazy::<SchemaViewerRoute>::new()} />
+                        <Route path=path!("/events") view={Lazy::<EventCatalogRoute>::new()} />
+                        <Route path=path!("/chat") view={Lazy::<ChatRoute>::new()} />
+                        <Route path=path!("/prompts") view={Lazy::<PromptsRoute>::new()} />
+                        <Route path=path!("/profile") view=ProfilePage />
+                        <Route path=path!("/notifications") view=NotificationsPage />
+                        <Route path=path!("/admin/:section") view={Lazy::<AdminSettingsRoute>::new()} />
+                    </Routes>
+                </AppErrorBoundary>
             </Layout>
+            <ToastHost />
         </Router>
     }
 }
 
+/// Declare a route whose page is code-split into its own WASM chunk, loaded
+/// the first time the route is visited.
+macro_rules! lazy_page {
+    ($route:ident => $view:expr) => {
+        #[derive(Debug)]
+        struct $route;
+
+        #[lazy_route]
+        impl LazyRoute for $route {
+            fn data() -> Self {
+                $route
+            }
+
+            fn view(_this: Self) -> AnyView {
+                $view.into_any()
+            }
+        }
+    };
+}
+
+lazy_page!(EditorRoute => view! { <RequireLogin><EditorPage /></RequireLogin> });
+lazy_page!(AdminSettingsRoute => view! { <RequireLogin><AdminSettingsPage /></RequireLogin> });
+lazy_page!(ChatRoute => view! { <ChatPage /> });
+lazy_page!(PromptsRoute => view! { <PromptsPage /> });
+lazy_page!(SchemaListRoute => view! { <SchemaListPage /> });
+lazy_page!(SchemaViewerRoute => view! { <SchemaViewerPage /> });
+lazy_page!(EventCatalogRoute => view! { <EventCatalogPage /> });
+
 #[cfg(test)]
 mod prompt_library_tests {
     use crate::server::prompts::build_prompt_library_state;
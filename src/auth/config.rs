@@ -93,6 +93,11 @@ mod tests {
         crate::config::AuthConfig {
             demo_mode: false,
             allow_demo_in_production: false,
+            demo_session_ttl_secs: 28800,
+            demo_session_same_site: crate::config::CookieSameSite::Strict,
+            local_accounts: false,
+            local_admin_email: None,
+            local_admin_password: None,
             service_token: None,
             jwt_secret: None,
             jwt_access_ttl_secs: 900,
@@ -110,6 +115,7 @@ mod tests {
             userinfo_sub_field: None,
             userinfo_email_field: None,
             userinfo_name_field: None,
+            ldap: Default::default(),
         }
     }
 
@@ -1,14 +1,46 @@
 //! Built-in demo authentication used when `DEMO_MODE=true`.
 //!
 //! Provides a simple username/password login that issues a session cookie
-//! (`lekton_demo_user`) carrying a serialized [`AuthenticatedUser`].
+//! (`lekton_demo_user`) carrying a signed, expiring session token (see
+//! [`TokenService::generate_session_token`]). Sessions used after half of
+//! their lifetime are renewed by [`renew_session`].
 //! This mechanism is intentionally simple and is **not** for production use.
 
+use axum_extra::extract::cookie::{Cookie, SameSite};
+use axum_extra::extract::CookieJar;
 use serde::{Deserialize, Serialize};
 
+use crate::auth::extractor::{DEMO_USER_COOKIE, LOGGED_IN_COOKIE};
 use crate::auth::models::AuthenticatedUser;
+use crate::auth::token_service::{SessionClaims, TokenService};
+use crate::config::CookieSameSite;
 use crate::error::AppError;
 
+/// Lifetime and `SameSite` attribute of demo session cookies.
+#[derive(Debug, Clone, Copy)]
+pub struct DemoSessionConfig {
+    pub ttl_secs: u64,
+    pub same_site: CookieSameSite,
+}
+
+impl DemoSessionConfig {
+    pub fn from_auth_config(auth: &crate::config::AuthConfig) -> Self {
+        Self {
+            ttl_secs: auth.demo_session_ttl_secs,
+            same_site: auth.demo_session_same_site,
+        }
+    }
+}
+
+impl Default for DemoSessionConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 8 * 60 * 60,
+            same_site: CookieSameSite::Strict,
+        }
+    }
+}
+
 /// A hard-coded demo user definition.
 #[derive(Debug, Clone)]
 struct DemoUser {
@@ -75,6 +107,59 @@ pub fn authenticate_demo_user(
         .ok_or_else(|| AppError::Auth("Invalid username or password".into()))
 }
 
+/// Validate the demo session cookie and return its claims.
+pub fn session_claims(token_service: &TokenService, jar: &CookieJar) -> Option<SessionClaims> {
+    jar.get(DEMO_USER_COOKIE)
+        .and_then(|c| token_service.validate_session_token(c.value()).ok())
+}
+
+/// The user of a valid demo session cookie, if any.
+pub fn session_user(token_service: &TokenService, jar: &CookieJar) -> Option<AuthenticatedUser> {
+    session_claims(token_service, jar).map(|claims| claims.user())
+}
+
+/// Whether a session has used up half of its lifetime.
+fn needs_renewal(claims: &SessionClaims, ttl_secs: u64, now: u64) -> bool {
+    claims.exp.saturating_sub(now) < ttl_secs / 2
+}
+
+/// Add a fresh session cookie for `user`, with the logged-in indicator, to `jar`.
+fn start_session(
+    state: &crate::app::AppState,
+    jar: CookieJar,
+    user: &AuthenticatedUser,
+) -> Result<CookieJar, AppError> {
+    let config = state.demo_session;
+    let token = state
+        .token_service
+        .generate_session_token(user, config.ttl_secs)?;
+    let same_site = match config.same_site {
+        CookieSameSite::Strict => SameSite::Strict,
+        CookieSameSite::Lax => SameSite::Lax,
+    };
+    let max_age = time::Duration::seconds(config.ttl_secs as i64);
+
+    let cookie = Cookie::build((DEMO_USER_COOKIE, token))
+        .path("/")
+        .http_only(true)
+        .secure(!state.insecure_cookies)
+        .same_site(same_site)
+        .max_age(max_age)
+        .build();
+
+    // Also set the logged-in indicator cookie for consistency with the
+    // production OAuth flow, expiring together with the session.
+    let logged_in = Cookie::build((LOGGED_IN_COOKIE, "1"))
+        .path("/")
+        .http_only(false)
+        .secure(!state.insecure_cookies)
+        .same_site(same_site)
+        .max_age(max_age)
+        .build();
+
+    Ok(jar.add(cookie).add(logged_in))
+}
+
 /// `POST /api/auth/demo/login` — Demo login handler.
 ///
 /// Validates credentials against the built-in user table.
@@ -82,33 +167,11 @@ pub fn authenticate_demo_user(
 #[cfg(feature = "ssr")]
 pub async fn login_handler(
     axum::extract::State(state): axum::extract::State<crate::app::AppState>,
-    jar: axum_extra::extract::CookieJar,
+    jar: CookieJar,
     axum::Json(req): axum::Json<LoginRequest>,
-) -> Result<(axum_extra::extract::CookieJar, axum::Json<LoginResponse>), AppError> {
+) -> Result<(CookieJar, axum::Json<LoginResponse>), AppError> {
     let user = authenticate_demo_user(&req.username, &req.password)?;
-
-    let user_json = serde_json::to_string(&user)
-        .map_err(|e| AppError::Internal(format!("Failed to serialize user: {}", e)))?;
-
-    let cookie = axum_extra::extract::cookie::Cookie::build(("lekton_demo_user", user_json))
-        .path("/")
-        .http_only(true)
-        .secure(!state.insecure_cookies)
-        .same_site(axum_extra::extract::cookie::SameSite::Strict)
-        .build();
-
-    // Also set the logged-in indicator cookie for consistency with the
-    // production OAuth flow.  Use a session cookie (no max-age) to match
-    // the demo user cookie lifetime.
-    let logged_in =
-        axum_extra::extract::cookie::Cookie::build((crate::auth::extractor::LOGGED_IN_COOKIE, "1"))
-            .path("/")
-            .http_only(false)
-            .secure(!state.insecure_cookies)
-            .same_site(axum_extra::extract::cookie::SameSite::Strict)
-            .build();
-
-    let jar = jar.add(cookie).add(logged_in);
+    let jar = start_session(&state, jar, &user)?;
 
     Ok((
         jar,
@@ -122,22 +185,21 @@ pub async fn login_handler(
 /// `GET /api/auth/me` — Returns the current demo user from cookie.
 #[cfg(feature = "ssr")]
 pub async fn me_handler(
-    jar: axum_extra::extract::CookieJar,
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    jar: CookieJar,
 ) -> Result<axum::Json<AuthenticatedUser>, AppError> {
     let cookie = jar
-        .get("lekton_demo_user")
+        .get(DEMO_USER_COOKIE)
         .ok_or_else(|| AppError::Auth("Not logged in".into()))?;
 
-    let user: AuthenticatedUser = serde_json::from_str(cookie.value())
-        .map_err(|e| AppError::Auth(format!("Invalid session: {}", e)))?;
-
-    Ok(axum::Json(user))
+    let claims = state.token_service.validate_session_token(cookie.value())?;
+    Ok(axum::Json(claims.user()))
 }
 
 /// `POST /api/auth/logout` — Clears the demo session cookie and logged-in indicator.
 #[cfg(feature = "ssr")]
-pub async fn logout_handler(jar: axum_extra::extract::CookieJar) -> axum_extra::extract::CookieJar {
-    let cookie = axum_extra::extract::cookie::Cookie::build(("lekton_demo_user", ""))
+pub async fn logout_handler(jar: CookieJar) -> CookieJar {
+    let cookie = Cookie::build((DEMO_USER_COOKIE, ""))
         .path("/")
         .removal()
         .build();
@@ -146,6 +208,51 @@ pub async fn logout_handler(jar: axum_extra::extract::CookieJar) -> axum_extra::
         .remove(crate::auth::extractor::clear_logged_in_cookie())
 }
 
+/// Middleware renewing demo sessions (sliding expiry): a valid session used
+/// after half of its lifetime gets a fresh cookie on the response. No-op
+/// outside demo mode.
+#[cfg(feature = "ssr")]
+pub async fn renew_session(
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    jar: CookieJar,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    let renewal = if state.demo_mode {
+        let now = chrono::Utc::now().timestamp() as u64;
+        session_claims(&state.token_service, &jar)
+            .filter(|claims| needs_renewal(claims, state.demo_session.ttl_secs, now))
+    } else {
+        None
+    };
+
+    let response = next.run(request).await;
+    let Some(claims) = renewal else {
+        return response;
+    };
+    // Logging out or in during this request replaces the session; keep that
+    let sets_session = response
+        .headers()
+        .get_all(axum::http::header::SET_COOKIE)
+        .iter()
+        .any(|v| {
+            v.to_str()
+                .is_ok_and(|v| v.starts_with(&format!("{DEMO_USER_COOKIE}=")))
+        });
+    if sets_session {
+        return response;
+    }
+    match start_session(&state, CookieJar::new(), &claims.user()) {
+        Ok(jar) => (jar, response).into_response(),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to renew demo session");
+            response
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +290,16 @@ mod tests {
         let result = authenticate_demo_user("nobody", "nothing");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_session_renewed_after_half_its_lifetime() {
+        let svc = TokenService::new("test-secret-key-at-least-32-bytes!!", 900, 30);
+        let user = authenticate_demo_user("demo", "demo").unwrap();
+        let token = svc.generate_session_token(&user, 3600).unwrap();
+        let claims = svc.validate_session_token(&token).unwrap();
+
+        assert!(!needs_renewal(&claims, 3600, claims.iat));
+        assert!(!needs_renewal(&claims, 3600, claims.iat + 1700));
+        assert!(needs_renewal(&claims, 3600, claims.iat + 1900));
+    }
 }
@@ -8,7 +8,8 @@
 //!   when the user is not authenticated.
 //!
 //! Both extractors support demo mode: when [`DemoMode`] is `true`, they also
-//! accept the `lekton_demo_user` session cookie as a valid authentication source.
+//! accept a valid signed `lekton_demo_user` session cookie as an
+//! authentication source.
 
 use axum::extract::{FromRef, FromRequestParts};
 use axum::http::request::Parts;
@@ -75,10 +76,8 @@ where
 
         // Fall back to demo session cookie when demo mode is active
         if demo_mode {
-            if let Some(cookie) = jar.get(DEMO_USER_COOKIE) {
-                if let Ok(user) = serde_json::from_str::<AuthenticatedUser>(cookie.value()) {
-                    return Ok(OptionalAuthUser(Some(user)));
-                }
+            if let Some(user) = crate::auth::demo_auth::session_user(&token_service, &jar) {
+                return Ok(OptionalAuthUser(Some(user)));
             }
         }
 
@@ -98,11 +97,7 @@ pub struct Unauthenticated;
 
 impl IntoResponse for Unauthenticated {
     fn into_response(self) -> Response {
-        (
-            axum::http::StatusCode::UNAUTHORIZED,
-            axum::Json(serde_json::json!({ "error": "Authentication required" })),
-        )
-            .into_response()
+        crate::error::AppError::Auth("Authentication required".into()).into_response()
     }
 }
 
@@ -130,10 +125,8 @@ where
 
         // Fall back to demo session cookie when demo mode is active
         if demo_mode {
-            if let Some(cookie) = jar.get(DEMO_USER_COOKIE) {
-                if let Ok(user) = serde_json::from_str::<AuthenticatedUser>(cookie.value()) {
-                    return Ok(RequiredAuthUser(user));
-                }
+            if let Some(user) = crate::auth::demo_auth::session_user(&token_service, &jar) {
+                return Ok(RequiredAuthUser(user));
             }
         }
 
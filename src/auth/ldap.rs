@@ -0,0 +1,331 @@
+//! LDAP / Active Directory sign-in.
+//!
+//! Enabled with `auth.ldap.url`, for installs whose directory is not behind
+//! an OIDC provider. A login searches the user with the service account
+//! (`auth.ldap.bind_dn`), then binds as the user with the submitted password.
+//! The user record (`provider_type = "ldap"`) is created on first login, and
+//! the group memberships are mapped to access levels and the admin flag by
+//! the [`LdapSettings`] edited under **Admin → LDAP**. A successful login
+//! issues the same JWT and refresh-token cookies as the OIDC callback.
+//!
+//! | Method | Path               | Description                          |
+//! |--------|--------------------|--------------------------------------|
+//! | POST   | `/auth/ldap/login` | Bind as the user, set token cookies  |
+
+use std::time::Duration;
+
+use axum_extra::extract::CookieJar;
+use ldap3::{ldap_escape, LdapConnAsync, LdapConnSettings, LdapError, Scope, SearchEntry};
+use serde::Deserialize;
+
+use crate::auth::models::AuthenticatedUser;
+use crate::config::LdapConfig;
+use crate::db::access_level_repository::AccessLevelRepository;
+use crate::db::settings_repository::LdapSettings;
+use crate::db::user_repository::UserRepository;
+use crate::error::AppError;
+
+/// `provider_type` of LDAP users.
+pub const PROVIDER_TYPE: &str = "ldap";
+
+/// LDAP result code of a bind with wrong credentials.
+const INVALID_CREDENTIALS: u32 = 49;
+
+/// A user found in the directory and bound with their password.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectoryUser {
+    /// Distinguished name of the user entry.
+    pub dn: String,
+    pub email: String,
+    pub name: Option<String>,
+    /// DNs of the user's groups.
+    pub groups: Vec<String>,
+}
+
+fn invalid_credentials() -> AppError {
+    AppError::Auth("Invalid username or password".into())
+}
+
+fn directory_error(e: LdapError) -> AppError {
+    match e {
+        LdapError::LdapResult { result } if result.rc == INVALID_CREDENTIALS => {
+            invalid_credentials()
+        }
+        e => AppError::Unavailable(format!("LDAP directory: {e}")),
+    }
+}
+
+/// The user search filter with `{username}` replaced by the escaped login name.
+pub fn user_filter(template: &str, username: &str) -> String {
+    template.replace("{username}", &ldap_escape(username))
+}
+
+/// Values of `attribute` in `entry`; servers may return attribute names in
+/// another case than requested.
+fn attribute_values<'a>(entry: &'a SearchEntry, attribute: &str) -> &'a [String] {
+    entry
+        .attrs
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(attribute))
+        .map(|(_, values)| values.as_slice())
+        .unwrap_or_default()
+}
+
+/// The [`DirectoryUser`] of a search result. Entries without an email fall
+/// back to the login name when it is an email (`userPrincipalName` logins).
+fn directory_user(
+    config: &LdapConfig,
+    entry: &SearchEntry,
+    username: &str,
+) -> Result<DirectoryUser, AppError> {
+    let email = attribute_values(entry, &config.email_attribute)
+        .first()
+        .cloned()
+        .or_else(|| username.contains('@').then(|| username.to_string()))
+        .map(|email| email.trim().to_lowercase())
+        .ok_or_else(|| {
+            AppError::Auth(format!(
+                "Directory entry has no '{}' attribute",
+                config.email_attribute
+            ))
+        })?;
+    let name = attribute_values(entry, &config.name_attribute)
+        .first()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    Ok(DirectoryUser {
+        dn: entry.dn.clone(),
+        email,
+        name,
+        groups: attribute_values(entry, &config.group_attribute).to_vec(),
+    })
+}
+
+/// Look `username` up in the directory and bind as them with `password`.
+pub async fn bind_user(
+    config: &LdapConfig,
+    username: &str,
+    password: &str,
+) -> Result<DirectoryUser, AppError> {
+    let username = username.trim();
+    // An empty password would be an unauthenticated bind, which succeeds
+    if username.is_empty() || password.is_empty() {
+        return Err(invalid_credentials());
+    }
+
+    let timeout = Duration::from_secs(config.timeout_secs);
+    let settings = LdapConnSettings::new()
+        .set_conn_timeout(timeout)
+        .set_starttls(config.starttls);
+    let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &config.url)
+        .await
+        .map_err(directory_error)?;
+    ldap3::drive!(conn);
+
+    if !config.bind_dn.is_empty() {
+        ldap.with_timeout(timeout)
+            .simple_bind(&config.bind_dn, &config.bind_password)
+            .await
+            .and_then(|result| result.success())
+            .map_err(|e| {
+                tracing::error!("LDAP service account bind failed: {e}");
+                AppError::Unavailable("LDAP service account bind failed".into())
+            })?;
+    }
+
+    let attributes = vec![
+        config.email_attribute.as_str(),
+        config.name_attribute.as_str(),
+        config.group_attribute.as_str(),
+    ];
+    let (entries, _) = ldap
+        .with_timeout(timeout)
+        .search(
+            &config.user_base_dn,
+            Scope::Subtree,
+            &user_filter(&config.user_filter, username),
+            attributes,
+        )
+        .await
+        .and_then(|result| result.success())
+        .map_err(directory_error)?;
+    let mut entries = entries.into_iter().map(SearchEntry::construct);
+    let (Some(entry), None) = (entries.next(), entries.next()) else {
+        // Unknown, or ambiguous: the filter must match a single user
+        return Err(invalid_credentials());
+    };
+    let user = directory_user(config, &entry, username)?;
+
+    ldap.with_timeout(timeout)
+        .simple_bind(&entry.dn, password)
+        .await
+        .and_then(|result| result.success())
+        .map_err(directory_error)?;
+    let _ = ldap.unbind().await;
+    Ok(user)
+}
+
+/// Create or update the Lekton user of a bound directory user, applying the
+/// group mappings, and record the login.
+pub async fn sign_in(
+    user_repo: &dyn UserRepository,
+    access_level_repo: &dyn AccessLevelRepository,
+    settings: &LdapSettings,
+    directory_user: DirectoryUser,
+) -> Result<AuthenticatedUser, AppError> {
+    let mut user = crate::api::auth::upsert_user_after_login(
+        user_repo,
+        &directory_user.dn.to_lowercase(),
+        &directory_user.email,
+        directory_user.name.clone(),
+        PROVIDER_TYPE,
+    )
+    .await?;
+    let stored = user_repo
+        .find_user_by_id(&user.user_id)
+        .await?
+        .ok_or_else(|| AppError::Internal(format!("User '{}' vanished", user.user_id)))?;
+
+    if !settings.group_mappings.is_empty() {
+        let mut assigned = Vec::new();
+        for level in settings.access_levels_for(&directory_user.groups) {
+            if access_level_repo.exists(&level).await? {
+                assigned.push(level);
+            } else {
+                tracing::warn!(level = %level, "LDAP group mapping names an unknown access level");
+            }
+        }
+        if assigned != stored.assigned_access_levels {
+            let effective = access_level_repo
+                .compute_effective_levels(&assigned)
+                .await?;
+            user_repo
+                .set_user_access_levels(
+                    &user.user_id,
+                    assigned,
+                    effective,
+                    stored.can_write,
+                    stored.can_read_draft,
+                    stored.can_write_draft,
+                )
+                .await?;
+        }
+    }
+
+    let is_admin = settings
+        .is_admin_for(&directory_user.groups)
+        .unwrap_or(stored.is_admin);
+    if is_admin != stored.is_admin || directory_user.name != stored.name {
+        user_repo
+            .update_user_profile(&user.user_id, directory_user.name.clone(), is_admin)
+            .await?;
+        user.name = directory_user.name;
+        user.is_admin = is_admin;
+    }
+    Ok(user)
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+/// Login request body.
+#[derive(Debug, Deserialize)]
+pub struct LdapLoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+/// `POST /auth/ldap/login` — Sign in with a directory account.
+pub async fn login_handler(
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    jar: CookieJar,
+    axum::Json(req): axum::Json<LdapLoginRequest>,
+) -> Result<(CookieJar, axum::Json<AuthenticatedUser>), AppError> {
+    use crate::auth::extractor::{access_token_cookie, logged_in_cookie, refresh_token_cookie};
+
+    let config = state
+        .ldap
+        .clone()
+        .ok_or_else(|| AppError::NotFound("LDAP sign-in is not configured".into()))?;
+    let directory_user = bind_user(&config, &req.username, &req.password).await?;
+    let settings = state.settings_repo.get_settings().await?.ldap;
+    let user = sign_in(
+        state.user_repo.as_ref(),
+        state.access_level_repo.as_ref(),
+        &settings,
+        directory_user,
+    )
+    .await?;
+
+    let (access_token, refresh_token) =
+        crate::api::auth::issue_token_pair(state.user_repo.as_ref(), &state.token_service, &user)
+            .await?;
+
+    let ttl_secs = state.token_service.access_token_ttl_secs();
+    let ttl_days = state.token_service.refresh_token_ttl_days();
+    let secure = !state.insecure_cookies;
+    let jar = jar
+        .add(access_token_cookie(access_token, ttl_secs, secure))
+        .add(refresh_token_cookie(refresh_token, ttl_days, secure))
+        .add(logged_in_cookie(ttl_days, secure));
+
+    Ok((jar, axum::Json(user)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_user_filter_escapes_the_username() {
+        assert_eq!(
+            user_filter("(sAMAccountName={username})", "ada"),
+            "(sAMAccountName=ada)"
+        );
+        assert_eq!(
+            user_filter("(uid={username})", "*)(uid=admin"),
+            "(uid=\\2a\\29\\28uid=admin)"
+        );
+    }
+
+    #[test]
+    fn test_directory_user_from_entry() {
+        let config = LdapConfig::default();
+        let entry = SearchEntry {
+            dn: "uid=ada,ou=people,dc=acme,dc=com".into(),
+            attrs: HashMap::from([
+                ("mail".to_string(), vec!["Ada@Acme.com".to_string()]),
+                ("displayName".to_string(), vec!["Ada Lovelace".to_string()]),
+                (
+                    "memberof".to_string(),
+                    vec!["cn=payments,ou=groups,dc=acme,dc=com".to_string()],
+                ),
+            ]),
+            bin_attrs: HashMap::new(),
+        };
+        assert_eq!(
+            directory_user(&config, &entry, "ada").unwrap(),
+            DirectoryUser {
+                dn: "uid=ada,ou=people,dc=acme,dc=com".into(),
+                email: "ada@acme.com".into(),
+                name: Some("Ada Lovelace".into()),
+                groups: vec!["cn=payments,ou=groups,dc=acme,dc=com".into()],
+            }
+        );
+
+        let bare = SearchEntry {
+            attrs: HashMap::new(),
+            ..entry
+        };
+        assert_eq!(
+            directory_user(&config, &bare, "ada@acme.com")
+                .unwrap()
+                .email,
+            "ada@acme.com"
+        );
+        assert!(matches!(
+            directory_user(&config, &bare, "ada"),
+            Err(AppError::Auth(_))
+        ));
+    }
+}
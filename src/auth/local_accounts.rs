@@ -0,0 +1,476 @@
+//! Local accounts: email + password users managed by administrators.
+//!
+//! Enabled with `auth.local_accounts = true`, for installs without an OIDC
+//! provider. Passwords are hashed with Argon2id and stored on the [`User`]
+//! record (`provider_type = "local"`). A successful login issues the same JWT
+//! and refresh-token cookies as the OIDC callback, so refresh, logout and
+//! access-level checks work unchanged.
+//!
+//! | Method | Path                   | Description                          |
+//! |--------|------------------------|--------------------------------------|
+//! | POST   | `/auth/local/login`    | Check credentials, set token cookies |
+//! | POST   | `/auth/local/password` | Change the caller's own password     |
+
+use std::sync::OnceLock;
+
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use axum_extra::extract::CookieJar;
+use serde::{Deserialize, Serialize};
+
+use crate::auth::models::AuthenticatedUser;
+use crate::db::auth_models::{LocalPassword, User};
+use crate::db::user_repository::UserRepository;
+use crate::error::AppError;
+
+/// `provider_type` of local accounts.
+pub const PROVIDER_TYPE: &str = "local";
+
+/// Minimum password length, in characters.
+pub const MIN_PASSWORD_LENGTH: usize = 12;
+
+/// Upper bound keeping hashing cost predictable.
+const MAX_PASSWORD_LENGTH: usize = 256;
+
+/// Check a new password against the length policy.
+pub fn validate_password(password: &str) -> Result<(), AppError> {
+    let length = password.chars().count();
+    if length < MIN_PASSWORD_LENGTH {
+        return Err(AppError::BadRequest(format!(
+            "Password must be at least {MIN_PASSWORD_LENGTH} characters long"
+        )));
+    }
+    if length > MAX_PASSWORD_LENGTH {
+        return Err(AppError::BadRequest(format!(
+            "Password must be at most {MAX_PASSWORD_LENGTH} characters long"
+        )));
+    }
+    Ok(())
+}
+
+/// Lower-cased, trimmed email used as the local account identifier.
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+fn hash_password_blocking(password: &str) -> Result<String, AppError> {
+    use rand::Rng;
+
+    let mut salt = [0u8; 16];
+    rand::rng().fill(&mut salt);
+    let salt = SaltString::encode_b64(&salt)
+        .map_err(|e| AppError::Internal(format!("Failed to encode salt: {e}")))?;
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| AppError::Internal(format!("Failed to hash password: {e}")))
+}
+
+fn verify_password_blocking(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .map(|parsed| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &parsed)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+/// Hash `password` with Argon2id off the async runtime.
+pub async fn hash_password(password: String) -> Result<String, AppError> {
+    tokio::task::spawn_blocking(move || hash_password_blocking(&password))
+        .await
+        .map_err(|e| AppError::Internal(format!("Password hashing task failed: {e}")))?
+}
+
+/// Check `password` against a PHC hash off the async runtime.
+pub async fn verify_password(password: String, hash: String) -> bool {
+    tokio::task::spawn_blocking(move || verify_password_blocking(&password, &hash))
+        .await
+        .unwrap_or(false)
+}
+
+/// Hash checked when the account does not exist, so unknown emails take as
+/// long to reject as wrong passwords.
+fn dummy_hash() -> &'static str {
+    static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+    DUMMY_HASH.get_or_init(|| {
+        hash_password_blocking(&uuid::Uuid::new_v4().to_string()).unwrap_or_default()
+    })
+}
+
+/// A local account to create.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewLocalUser {
+    pub email: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub password: String,
+    #[serde(default)]
+    pub is_admin: bool,
+}
+
+/// Create a local account. With `change_required` the user is asked to pick
+/// a new password after signing in.
+pub async fn create_local_user(
+    user_repo: &dyn UserRepository,
+    new_user: NewLocalUser,
+    change_required: bool,
+) -> Result<User, AppError> {
+    let email = normalize_email(&new_user.email);
+    if !email.contains('@') {
+        return Err(AppError::BadRequest(format!("Invalid email '{email}'")));
+    }
+    validate_password(&new_user.password)?;
+    if user_repo.find_user_by_email(&email).await?.is_some() {
+        return Err(AppError::Conflict(format!(
+            "A user with email '{email}' already exists"
+        )));
+    }
+
+    let name = new_user
+        .name
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+    let mut user = crate::auth::middleware::build_user_from_claims(
+        uuid::Uuid::new_v4().to_string(),
+        email.clone(),
+        name,
+        email,
+        PROVIDER_TYPE,
+    );
+    user.is_admin = new_user.is_admin;
+    user.password = Some(LocalPassword {
+        hash: hash_password(new_user.password).await?,
+        change_required,
+        updated_at: chrono::Utc::now(),
+    });
+
+    user_repo.create_user(user.clone()).await?;
+    Ok(user)
+}
+
+/// Check local credentials and record the login.
+///
+/// Returns the user and whether they still have to change their password.
+pub async fn authenticate(
+    user_repo: &dyn UserRepository,
+    email: &str,
+    password: &str,
+) -> Result<(AuthenticatedUser, bool), AppError> {
+    let invalid = || AppError::Auth("Invalid email or password".into());
+
+    let user = user_repo
+        .find_user_by_provider_sub(&normalize_email(email), PROVIDER_TYPE)
+        .await?;
+    let Some((user, stored)) = user.and_then(|u| u.password.clone().map(|p| (u, p))) else {
+        let password = password.to_string();
+        let _ =
+            tokio::task::spawn_blocking(move || verify_password_blocking(&password, dummy_hash()))
+                .await;
+        return Err(invalid());
+    };
+    if !verify_password(password.to_string(), stored.hash).await {
+        return Err(invalid());
+    }
+
+    user_repo.touch_last_login(&user.id).await?;
+    Ok((
+        AuthenticatedUser {
+            user_id: user.id,
+            email: user.email,
+            name: user.name,
+            is_admin: user.is_admin,
+        },
+        stored.change_required,
+    ))
+}
+
+async fn find_local_user(user_repo: &dyn UserRepository, user_id: &str) -> Result<User, AppError> {
+    let user = user_repo
+        .find_user_by_id(user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("User '{user_id}' not found")))?;
+    if user.provider_type != PROVIDER_TYPE {
+        return Err(AppError::BadRequest(
+            "Only local accounts have a password".into(),
+        ));
+    }
+    Ok(user)
+}
+
+/// Set a new password chosen by an administrator and sign the user out
+/// everywhere. The user must change it after the next login.
+pub async fn reset_password(
+    user_repo: &dyn UserRepository,
+    user_id: &str,
+    new_password: String,
+) -> Result<(), AppError> {
+    validate_password(&new_password)?;
+    find_local_user(user_repo, user_id).await?;
+    user_repo
+        .set_user_password(
+            user_id,
+            LocalPassword {
+                hash: hash_password(new_password).await?,
+                change_required: true,
+                updated_at: chrono::Utc::now(),
+            },
+        )
+        .await?;
+    user_repo.revoke_all_user_tokens(user_id).await
+}
+
+/// Change the caller's own password after checking the current one.
+pub async fn change_password(
+    user_repo: &dyn UserRepository,
+    user_id: &str,
+    current_password: String,
+    new_password: String,
+) -> Result<(), AppError> {
+    let user = find_local_user(user_repo, user_id).await?;
+    let stored = user
+        .password
+        .ok_or_else(|| AppError::BadRequest("Account has no password".into()))?;
+    if !verify_password(current_password.clone(), stored.hash).await {
+        return Err(AppError::BadRequest("Current password is incorrect".into()));
+    }
+    if new_password == current_password {
+        return Err(AppError::BadRequest(
+            "New password must differ from the current one".into(),
+        ));
+    }
+    validate_password(&new_password)?;
+    user_repo
+        .set_user_password(
+            user_id,
+            LocalPassword {
+                hash: hash_password(new_password).await?,
+                change_required: false,
+                updated_at: chrono::Utc::now(),
+            },
+        )
+        .await
+}
+
+/// Create the configured administrator unless a local account with that email
+/// already exists. Returns `true` when the account was created.
+pub async fn ensure_bootstrap_admin(
+    user_repo: &dyn UserRepository,
+    email: &str,
+    password: &str,
+) -> Result<bool, AppError> {
+    if user_repo
+        .find_user_by_provider_sub(&normalize_email(email), PROVIDER_TYPE)
+        .await?
+        .is_some()
+    {
+        return Ok(false);
+    }
+    create_local_user(
+        user_repo,
+        NewLocalUser {
+            email: email.to_string(),
+            name: Some("Administrator".to_string()),
+            password: password.to_string(),
+            is_admin: true,
+        },
+        true,
+    )
+    .await?;
+    Ok(true)
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+/// Login request body. `username` is accepted as an alias of `email`.
+#[derive(Debug, Deserialize)]
+pub struct LocalLoginRequest {
+    #[serde(alias = "username")]
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LocalLoginResponse {
+    pub user: AuthenticatedUser,
+    /// The password was set by an administrator and must be changed.
+    pub password_change_required: bool,
+}
+
+/// `POST /auth/local/login` — Sign in with a local account.
+pub async fn login_handler(
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    jar: CookieJar,
+    axum::Json(req): axum::Json<LocalLoginRequest>,
+) -> Result<(CookieJar, axum::Json<LocalLoginResponse>), AppError> {
+    use crate::auth::extractor::{access_token_cookie, logged_in_cookie, refresh_token_cookie};
+
+    let (user, password_change_required) =
+        authenticate(state.user_repo.as_ref(), &req.email, &req.password).await?;
+
+    let (access_token, refresh_token) =
+        crate::api::auth::issue_token_pair(state.user_repo.as_ref(), &state.token_service, &user)
+            .await?;
+
+    let ttl_secs = state.token_service.access_token_ttl_secs();
+    let ttl_days = state.token_service.refresh_token_ttl_days();
+    let secure = !state.insecure_cookies;
+    let jar = jar
+        .add(access_token_cookie(access_token, ttl_secs, secure))
+        .add(refresh_token_cookie(refresh_token, ttl_days, secure))
+        .add(logged_in_cookie(ttl_days, secure));
+
+    Ok((
+        jar,
+        axum::Json(LocalLoginResponse {
+            user,
+            password_change_required,
+        }),
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+/// `POST /auth/local/password` — Change the caller's own password.
+pub async fn change_password_handler(
+    axum::extract::State(state): axum::extract::State<crate::app::AppState>,
+    crate::auth::extractor::RequiredAuthUser(user): crate::auth::extractor::RequiredAuthUser,
+    axum::Json(req): axum::Json<ChangePasswordRequest>,
+) -> Result<axum::http::StatusCode, AppError> {
+    change_password(
+        state.user_repo.as_ref(),
+        &user.user_id,
+        req.current_password,
+        req.new_password,
+    )
+    .await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockUserRepository;
+
+    fn new_user(email: &str, password: &str) -> NewLocalUser {
+        NewLocalUser {
+            email: email.to_string(),
+            name: None,
+            password: password.to_string(),
+            is_admin: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn created_user_can_sign_in_with_normalized_email() {
+        let repo = MockUserRepository::default();
+        let user = create_local_user(&repo, new_user(" Ada@Example.com", "correct horse"), false)
+            .await
+            .unwrap();
+        assert_eq!(user.email, "ada@example.com");
+        assert_eq!(user.provider_type, PROVIDER_TYPE);
+        assert!(user
+            .password
+            .as_ref()
+            .unwrap()
+            .hash
+            .starts_with("$argon2id$"));
+
+        let (auth, change_required) = authenticate(&repo, "ADA@example.com", "correct horse")
+            .await
+            .unwrap();
+        assert_eq!(auth.user_id, user.id);
+        assert!(!change_required);
+
+        assert!(matches!(
+            authenticate(&repo, "ada@example.com", "wrong password").await,
+            Err(AppError::Auth(_))
+        ));
+        assert!(matches!(
+            authenticate(&repo, "nobody@example.com", "correct horse").await,
+            Err(AppError::Auth(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_rejects_short_passwords_and_duplicate_emails() {
+        let repo = MockUserRepository::default();
+        assert!(matches!(
+            create_local_user(&repo, new_user("a@example.com", "short"), false).await,
+            Err(AppError::BadRequest(_))
+        ));
+
+        create_local_user(&repo, new_user("a@example.com", "long enough pw"), false)
+            .await
+            .unwrap();
+        assert!(matches!(
+            create_local_user(&repo, new_user("A@example.com", "long enough pw"), false).await,
+            Err(AppError::Conflict(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn reset_requires_change_and_change_clears_it() {
+        let repo = MockUserRepository::default();
+        let user = create_local_user(&repo, new_user("a@example.com", "initial password"), false)
+            .await
+            .unwrap();
+
+        reset_password(&repo, &user.id, "temporary password".into())
+            .await
+            .unwrap();
+        let (_, change_required) = authenticate(&repo, "a@example.com", "temporary password")
+            .await
+            .unwrap();
+        assert!(change_required);
+
+        assert!(matches!(
+            change_password(
+                &repo,
+                &user.id,
+                "not the password".into(),
+                "my own password".into()
+            )
+            .await,
+            Err(AppError::BadRequest(_))
+        ));
+        change_password(
+            &repo,
+            &user.id,
+            "temporary password".into(),
+            "my own password".into(),
+        )
+        .await
+        .unwrap();
+        let (_, change_required) = authenticate(&repo, "a@example.com", "my own password")
+            .await
+            .unwrap();
+        assert!(!change_required);
+    }
+
+    #[tokio::test]
+    async fn bootstrap_admin_is_created_once() {
+        let repo = MockUserRepository::default();
+        assert!(
+            ensure_bootstrap_admin(&repo, "root@example.com", "bootstrap password")
+                .await
+                .unwrap()
+        );
+        assert!(
+            !ensure_bootstrap_admin(&repo, "root@example.com", "bootstrap password")
+                .await
+                .unwrap()
+        );
+
+        let (user, change_required) = authenticate(&repo, "root@example.com", "bootstrap password")
+            .await
+            .unwrap();
+        assert!(user.is_admin);
+        assert!(change_required);
+    }
+}
@@ -32,6 +32,7 @@ pub fn build_user_from_claims(
         can_write_draft: false,
         created_at: Utc::now(),
         last_login_at: None,
+        password: None,
     }
 }
 
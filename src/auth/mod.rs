@@ -1,6 +1,7 @@
 pub mod config;
 pub mod middleware;
 pub mod models;
+pub mod redirect;
 pub mod refresh_client;
 
 #[cfg(feature = "ssr")]
@@ -8,6 +9,10 @@ pub mod demo_auth;
 #[cfg(feature = "ssr")]
 pub mod extractor;
 #[cfg(feature = "ssr")]
+pub mod ldap;
+#[cfg(feature = "ssr")]
+pub mod local_accounts;
+#[cfg(feature = "ssr")]
 pub mod provider;
 #[cfg(feature = "ssr")]
 pub mod token_service;
@@ -13,6 +13,21 @@ use serde::{Deserialize, Serialize};
 /// between the server helpers that emit it and the client code that detects it.
 pub const UNAUTHORIZED_SENTINEL: &str = "unauthorized";
 
+/// Prefix of the error returned by server functions when the caller is not
+/// allowed to read an existing document or schema. The access level(s)
+/// required to read it follow the prefix.
+pub const ACCESS_DENIED_PREFIX: &str = "access_denied:";
+
+/// Error message telling the client that `required_level` is needed.
+pub fn access_denied_message(required_level: &str) -> String {
+    format!("{ACCESS_DENIED_PREFIX}{required_level}")
+}
+
+/// Required access level carried by an [`access_denied_message`], if any.
+pub fn required_access_level(message: &str) -> Option<&str> {
+    message.strip_prefix(ACCESS_DENIED_PREFIX)
+}
+
 /// Minimal user identity carried in the JWT and returned to clients.
 ///
 /// Does **not** include permissions — those are loaded from the database on
@@ -111,6 +126,20 @@ impl UserContext {
         self.can_write_draft && self.accessible_levels().contains(level)
     }
 
+    /// Returns `true` if the user may save a document at `level`, which
+    /// needs draft write permission when the document is a draft and, when
+    /// the document sets one, membership of its `edit_level`.
+    pub fn can_edit_document(&self, level: &str, is_draft: bool, edit_level: Option<&str>) -> bool {
+        let can_write = if is_draft {
+            self.can_write_draft(level)
+        } else {
+            self.can_write(level)
+        };
+        can_write
+            && (self.user.is_admin
+                || edit_level.is_none_or(|edit| self.accessible_levels().contains(edit)))
+    }
+
     /// Collects the access level names the user can read (published docs).
     ///
     /// Returns `None` for admin users (meaning: no restriction).
@@ -159,6 +188,14 @@ mod tests {
         assert_eq!(UNAUTHORIZED_SENTINEL, "unauthorized");
     }
 
+    #[test]
+    fn test_access_denied_message_roundtrip() {
+        let message = access_denied_message("internal");
+        assert_eq!(required_access_level(&message), Some("internal"));
+        assert_eq!(required_access_level("Document not found"), None);
+        assert_eq!(required_access_level(UNAUTHORIZED_SENTINEL), None);
+    }
+
     #[test]
     fn test_authenticated_user_roundtrip() {
         let user = AuthenticatedUser {
@@ -231,6 +268,25 @@ mod tests {
             assert!(!ctx.can_write("secret")); // not accessible
         }
 
+        #[test]
+        fn test_can_edit_document_checks_draft_permission() {
+            let ctx = make_context(false, vec!["internal".to_string()], true, true);
+            assert!(ctx.can_edit_document("internal", false, None));
+            assert!(!ctx.can_edit_document("internal", true, None)); // can_write_draft=false
+            assert!(!ctx.can_edit_document("secret", false, None));
+            let reader = make_context(false, vec!["internal".to_string()], false, true);
+            assert!(!reader.can_edit_document("internal", false, None));
+        }
+
+        #[test]
+        fn test_can_edit_document_requires_edit_level() {
+            let ctx = make_context(false, vec!["team-docs".to_string()], true, false);
+            assert!(ctx.can_edit_document("public", false, Some("team-docs")));
+            assert!(!ctx.can_edit_document("public", false, Some("team-payments")));
+            let admin = make_context(true, vec![], false, false);
+            assert!(admin.can_edit_document("public", false, Some("team-payments")));
+        }
+
         #[test]
         fn test_readable_levels_always_includes_implicit() {
             let ctx = make_context(false, vec!["internal".to_string()], false, false);
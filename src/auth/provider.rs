@@ -44,6 +44,9 @@ pub struct AuthFlowState {
     pub csrf_token: String,
     /// OIDC nonce (only set for OIDC providers).
     pub nonce: Option<String>,
+    /// Page to return to after the callback (already validated).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
 }
 
 impl AuthFlowState {
@@ -51,6 +54,7 @@ impl AuthFlowState {
         Self {
             csrf_token,
             nonce: None,
+            next: None,
         }
     }
 
@@ -58,6 +62,7 @@ impl AuthFlowState {
         Self {
             csrf_token,
             nonce: Some(nonce),
+            next: None,
         }
     }
 }
@@ -552,6 +557,20 @@ mod tests {
         assert_eq!(de.nonce.as_deref(), Some("n"));
     }
 
+    #[test]
+    fn test_auth_flow_state_keeps_next_page() {
+        let mut state = AuthFlowState::new_oauth2("tok".to_string());
+        state.next = Some("/edit/guide".to_string());
+        let json = serde_json::to_string(&state).unwrap();
+        let de: AuthFlowState = serde_json::from_str(&json).unwrap();
+        assert_eq!(de.next.as_deref(), Some("/edit/guide"));
+
+        // State cookies set before `next` existed still parse
+        let de: AuthFlowState =
+            serde_json::from_str(r#"{"csrf_token":"tok","nonce":null}"#).unwrap();
+        assert!(de.next.is_none());
+    }
+
     fn make_oauth2_config() -> AuthProviderConfig {
         AuthProviderConfig {
             provider_type: "oauth2".to_string(),
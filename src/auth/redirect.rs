@@ -0,0 +1,100 @@
+//! Post-login redirect targets (`/login?next=...`).
+//!
+//! Available in both SSR and hydrate builds: the login page, the route guard
+//! and the OAuth2/OIDC callback all validate `next` with [`safe_next`], so a
+//! crafted link can only send the user to a page of this portal.
+
+/// Return `next` when it is a safe same-origin path to land on after login.
+///
+/// Rejects absolute and protocol-relative URLs (`//host`, `/\host`), control
+/// characters and the login/auth endpoints themselves (to avoid loops).
+pub fn safe_next(next: &str) -> Option<String> {
+    // Checked before trimming, which would drop a trailing newline
+    if next.chars().any(char::is_control) {
+        return None;
+    }
+    let next = next.trim();
+    let safe = next.starts_with('/')
+        && !next.starts_with("//")
+        && !next.contains('\\')
+        && next != "/login"
+        && !next.starts_with("/login?")
+        && !next.starts_with("/auth/");
+    safe.then(|| next.to_string())
+}
+
+/// Path of the login page returning to `next` after a successful login.
+pub fn login_path(next: &str) -> String {
+    with_next("/login", next)
+}
+
+/// Path starting the OAuth2/OIDC flow, returning to `next` after the callback.
+pub fn oauth_login_path(next: &str) -> String {
+    with_next("/auth/login", next)
+}
+
+fn with_next(path: &str, next: &str) -> String {
+    match safe_next(next) {
+        Some(next) if next != "/" => format!("{path}?next={}", encode_query_value(&next)),
+        _ => path.to_string(),
+    }
+}
+
+/// Percent-encode a query parameter value, keeping unreserved characters and `/`.
+fn encode_query_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_local_paths() {
+        assert_eq!(safe_next("/docs/guide").as_deref(), Some("/docs/guide"));
+        assert_eq!(
+            safe_next("/edit/a/b?draft=1#top").as_deref(),
+            Some("/edit/a/b?draft=1#top")
+        );
+    }
+
+    #[test]
+    fn rejects_external_and_looping_targets() {
+        for next in [
+            "https://evil.example",
+            "//evil.example",
+            "/\\evil.example",
+            "javascript:alert(1)",
+            "/docs\n",
+            "",
+            "/login",
+            "/login?next=/x",
+            "/auth/login",
+        ] {
+            assert_eq!(safe_next(next), None, "{next:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn login_path_encodes_next() {
+        assert_eq!(
+            login_path("/edit/a b?x=1&y=2"),
+            "/login?next=/edit/a%20b%3Fx%3D1%26y%3D2"
+        );
+        assert_eq!(login_path("/"), "/login");
+        assert_eq!(login_path("//evil.example"), "/login");
+        assert_eq!(
+            oauth_login_path("/admin/users"),
+            "/auth/login?next=/admin/users"
+        );
+    }
+}
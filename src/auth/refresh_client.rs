@@ -185,13 +185,49 @@ mod inner {
         }
     }
 
-    /// Navigate to `/login` via `window.location.href`.
+    /// Recover resources loaded during server rendering, where no refresh is
+    /// possible: once `failed()` reports an [`UNAUTHORIZED_SENTINEL`] error on
+    /// the client, refresh the session and `refetch`, or redirect to `/login`
+    /// like [`with_auth_retry`]. Refreshes at most once per failure.
+    pub fn refresh_on_auth_error(
+        failed: impl Fn() -> bool + 'static,
+        refetch: impl Fn() + 'static,
+    ) {
+        let refetch = std::rc::Rc::new(refetch);
+        let mut attempted = false;
+        leptos::prelude::Effect::new(move |_| {
+            if !failed() {
+                attempted = false;
+                return;
+            }
+            if attempted {
+                return;
+            }
+            attempted = true;
+            let refetch = refetch.clone();
+            leptos::task::spawn_local(async move {
+                match try_refresh().await {
+                    Ok(()) => refetch(),
+                    Err(_) => redirect_to_login(),
+                }
+            });
+        });
+    }
+
+    /// Navigate to `/login` via `window.location.href`, returning to the
+    /// current page after the user signs in again.
     ///
     /// Works from any async context (unlike Leptos `use_navigate` which
     /// requires component context).
     fn redirect_to_login() {
         if let Some(window) = web_sys::window() {
-            let _ = window.location().set_href("/login");
+            let location = window.location();
+            let current = format!(
+                "{}{}",
+                location.pathname().unwrap_or_default(),
+                location.search().unwrap_or_default()
+            );
+            let _ = location.set_href(&crate::auth::redirect::login_path(&current));
         }
     }
 
@@ -202,7 +238,9 @@ mod inner {
 
 // Re-export the public API at module level when the hydrate feature is active.
 #[cfg(feature = "hydrate")]
-pub use inner::{is_auth_error, try_refresh, with_auth_bootstrap, with_auth_retry};
+pub use inner::{
+    is_auth_error, refresh_on_auth_error, try_refresh, with_auth_bootstrap, with_auth_retry,
+};
 
 // ── SSR passthrough ───────────────────────────────────────────────────────────
 //
@@ -230,6 +268,11 @@ where
     f().await
 }
 
+/// SSR stub: resources fail for good on the server; the hydrated client
+/// refreshes them.
+#[cfg(not(feature = "hydrate"))]
+pub fn refresh_on_auth_error(_failed: impl Fn() -> bool + 'static, _refetch: impl Fn() + 'static) {}
+
 /// SSR stub: bootstrap reads are also a passthrough on the server.
 #[cfg(not(feature = "hydrate"))]
 pub async fn with_auth_bootstrap<T, F, Fut>(
@@ -2,6 +2,7 @@
 //!
 //! `TokenService` is responsible for:
 //! - Signing and verifying short-lived JWT access tokens (HS256).
+//! - Signing and verifying expiring document share links.
 //! - Generating cryptographically random refresh tokens and storing only
 //!   their SHA-256 hash in the database.
 
@@ -18,6 +19,11 @@ const DEFAULT_ACCESS_ISSUER: &str = "lekton";
 const DEFAULT_ACCESS_AUDIENCE: &str = "lekton";
 /// Alphanumeric token length required to reach at least 256 bits of entropy.
 const OPAQUE_TOKEN_LENGTH: usize = 43;
+/// Audience of demo session tokens, so they are never accepted as access
+/// tokens (and vice versa).
+const SESSION_AUDIENCE: &str = "lekton-demo-session";
+/// Audience of document share link tokens.
+const SHARE_AUDIENCE: &str = "lekton-share";
 
 /// Claims embedded in the JWT access token.
 #[derive(Debug, Serialize, Deserialize)]
@@ -40,6 +46,56 @@ pub struct JwtClaims {
     pub exp: u64,
 }
 
+/// Claims embedded in a signed demo session cookie.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionClaims {
+    /// Demo user ID (`user_id`).
+    pub sub: String,
+    /// JWT issuer.
+    pub iss: String,
+    /// Session audience, distinct from the access token audience.
+    pub aud: String,
+    /// Demo user's email address.
+    pub email: String,
+    /// Demo user's display name.
+    pub name: Option<String>,
+    /// Whether the demo user has admin privileges.
+    pub is_admin: bool,
+    /// Issued-at timestamp (Unix seconds).
+    pub iat: u64,
+    /// Expiry timestamp (Unix seconds).
+    pub exp: u64,
+}
+
+impl SessionClaims {
+    /// The user carried by the session.
+    pub fn user(&self) -> AuthenticatedUser {
+        AuthenticatedUser {
+            user_id: self.sub.clone(),
+            email: self.email.clone(),
+            name: self.name.clone(),
+            is_admin: self.is_admin,
+        }
+    }
+}
+
+/// Claims embedded in a signed document share link.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareClaims {
+    /// ID of the share link record, checked for revocation on every use.
+    pub jti: String,
+    /// Slug of the shared document.
+    pub sub: String,
+    /// JWT issuer.
+    pub iss: String,
+    /// Share audience, distinct from the access and session audiences.
+    pub aud: String,
+    /// Issued-at timestamp (Unix seconds).
+    pub iat: u64,
+    /// Expiry timestamp (Unix seconds).
+    pub exp: u64,
+}
+
 /// Service for JWT access tokens and refresh token lifecycle.
 #[cfg(feature = "ssr")]
 #[derive(Clone)]
@@ -142,6 +198,89 @@ impl TokenService {
             })
     }
 
+    /// Sign a demo session for the given user, valid for `ttl_secs`.
+    pub fn generate_session_token(
+        &self,
+        user: &AuthenticatedUser,
+        ttl_secs: u64,
+    ) -> Result<String, AppError> {
+        let now = Utc::now().timestamp() as u64;
+        let claims = SessionClaims {
+            sub: user.user_id.clone(),
+            iss: self.access_token_issuer.clone(),
+            aud: SESSION_AUDIENCE.to_string(),
+            email: user.email.clone(),
+            name: user.name.clone(),
+            is_admin: user.is_admin,
+            iat: now,
+            exp: now + ttl_secs,
+        };
+
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &self.encoding_key,
+        )
+        .map_err(|e| AppError::Internal(format!("Failed to sign session: {e}")))
+    }
+
+    /// Validate a demo session token and return its claims.
+    ///
+    /// Returns `AppError::Auth` if the session is expired, tampered with or
+    /// is not a session token.
+    pub fn validate_session_token(&self, token: &str) -> Result<SessionClaims, AppError> {
+        let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS256);
+        validation.validate_exp = true;
+        validation.leeway = 0;
+        validation.set_issuer(&[self.access_token_issuer.as_str()]);
+        validation.set_audience(&[SESSION_AUDIENCE]);
+
+        jsonwebtoken::decode::<SessionClaims>(token, &self.decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| AppError::Auth(format!("Invalid session: {e}")))
+    }
+
+    /// Sign the token of share link `link_id` to document `slug`, valid
+    /// until `expires_at`.
+    pub fn generate_share_token(
+        &self,
+        link_id: &str,
+        slug: &str,
+        expires_at: chrono::DateTime<Utc>,
+    ) -> Result<String, AppError> {
+        let claims = ShareClaims {
+            jti: link_id.to_string(),
+            sub: slug.to_string(),
+            iss: self.access_token_issuer.clone(),
+            aud: SHARE_AUDIENCE.to_string(),
+            iat: Utc::now().timestamp() as u64,
+            exp: expires_at.timestamp() as u64,
+        };
+
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &self.encoding_key,
+        )
+        .map_err(|e| AppError::Internal(format!("Failed to sign share link: {e}")))
+    }
+
+    /// Validate a share link token and return its claims.
+    ///
+    /// Returns `AppError::Auth` if the link is expired, tampered with or is
+    /// not a share token. Revocation is checked by the caller.
+    pub fn validate_share_token(&self, token: &str) -> Result<ShareClaims, AppError> {
+        let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS256);
+        validation.validate_exp = true;
+        validation.leeway = 0;
+        validation.set_issuer(&[self.access_token_issuer.as_str()]);
+        validation.set_audience(&[SHARE_AUDIENCE]);
+
+        jsonwebtoken::decode::<ShareClaims>(token, &self.decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| AppError::Auth(format!("Invalid share link: {e}")))
+    }
+
     /// Generate a fresh refresh token pair: `(raw_token, hash)`.
     ///
     /// The raw token is a 43-character alphanumeric secret (~256 bits of
@@ -245,6 +384,71 @@ mod tests {
         assert!(claims.is_admin);
     }
 
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn test_session_token_round_trip() {
+        let svc = make_service();
+        let user = make_user(true);
+
+        let token = svc.generate_session_token(&user, 600).unwrap();
+        let claims = svc.validate_session_token(&token).unwrap();
+        assert_eq!(claims.exp - claims.iat, 600);
+        let restored = claims.user();
+        assert_eq!(restored.user_id, "u-test-1");
+        assert_eq!(restored.name.as_deref(), Some("Test User"));
+        assert!(restored.is_admin);
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn test_session_and_access_tokens_are_not_interchangeable() {
+        let svc = make_service();
+        let user = make_user(false);
+
+        let access = svc.generate_access_token(&user).unwrap();
+        assert!(svc.validate_session_token(&access).is_err());
+
+        let session = svc.generate_session_token(&user, 600).unwrap();
+        assert!(svc.validate_access_token(&session).is_err());
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn test_share_token_round_trip_and_audience() {
+        let svc = make_service();
+        let expires_at = Utc::now() + chrono::Duration::days(7);
+
+        let token = svc
+            .generate_share_token("link-1", "ops/runbook", expires_at)
+            .unwrap();
+        let claims = svc.validate_share_token(&token).unwrap();
+        assert_eq!(claims.jti, "link-1");
+        assert_eq!(claims.sub, "ops/runbook");
+        assert_eq!(claims.exp, expires_at.timestamp() as u64);
+        assert!(svc.validate_access_token(&token).is_err());
+        assert!(svc.validate_session_token(&token).is_err());
+
+        let access = svc.generate_access_token(&make_user(true)).unwrap();
+        assert!(svc.validate_share_token(&access).is_err());
+
+        let expired = svc
+            .generate_share_token(
+                "link-2",
+                "ops/runbook",
+                Utc::now() - chrono::Duration::hours(1),
+            )
+            .unwrap();
+        assert!(svc.validate_share_token(&expired).is_err());
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn test_session_signed_with_other_key_rejected() {
+        let other = TokenService::new("another-secret-key-at-least-32-bytes", 3600, 30);
+        let forged = other.generate_session_token(&make_user(true), 600).unwrap();
+        assert!(make_service().validate_session_token(&forged).is_err());
+    }
+
     #[cfg(feature = "ssr")]
     #[test]
     fn test_expired_token_rejected() {
@@ -247,6 +247,7 @@ mod ssr {
                 chunk_overlap_tokens: self.chunk_overlap_tokens,
                 expand_to_parent: self.expand_to_parent,
                 hybrid_search_enabled: self.hybrid_search_enabled,
+                semantic_search_enabled: false,
                 reranker_url: self.reranker_url.clone(),
                 reranker_model: self.reranker_model.clone(),
                 reranker_api_key: self.reranker_api_key.clone(),
@@ -284,6 +285,7 @@ mod ssr {
             SearchConfig {
                 url: self.meilisearch_url.clone(),
                 api_key: self.meilisearch_api_key.clone(),
+                reconcile_on_startup: false,
             }
         }
     }
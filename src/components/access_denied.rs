@@ -0,0 +1,58 @@
+use leptos::prelude::*;
+use leptos::server_fn::error::ServerFnError;
+use leptos_router::hooks::use_location;
+
+use crate::auth::models::required_access_level;
+use crate::i18n::use_i18n;
+
+/// Access level required by an "access denied" server function error, or
+/// `None` for any other error.
+pub fn access_denied_level(err: &ServerFnError) -> Option<String> {
+    match err {
+        ServerFnError::ServerError(msg) => required_access_level(msg).map(str::to_string),
+        _ => None,
+    }
+}
+
+/// 403 view for a document or schema above the caller's access level.
+///
+/// Names the required level, offers to sign in to anonymous visitors and
+/// links to the access request page configured in the branding settings.
+#[component]
+pub fn AccessDenied(required_level: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let location = use_location();
+    let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
+    let branding = use_context::<crate::app::InstanceBranding>();
+
+    let signed_in = move || current_user.map(|u| u.get().is_some()).unwrap_or(false);
+    let request_url = move || branding.and_then(|b| b.0.get().access_request_url);
+
+    view! {
+        <div class="max-w-xl mx-auto text-center py-16">
+            <svg class="w-12 h-12 mx-auto text-warning" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M12 15v2m-6 4h12a2 2 0 002-2v-6a2 2 0 00-2-2H6a2 2 0 00-2 2v6a2 2 0 002 2zm10-10V7a4 4 0 00-8 0v4h8z" />
+            </svg>
+            <h1 class="text-2xl font-bold mt-4">{i18n.t("access.denied_title")}</h1>
+            <p class="text-base-content/70 mt-3">
+                {i18n.around("access.denied_text", "level").0}
+                <span class="badge badge-ghost font-mono">{required_level}</span>
+                {i18n.around("access.denied_text", "level").1}
+            </p>
+            <div class="flex flex-wrap justify-center gap-2 mt-6">
+                <Show when=move || !signed_in()>
+                    <a
+                        href=move || crate::auth::redirect::login_path(&location.pathname.get())
+                        class="btn btn-primary btn-sm"
+                    >
+                        {i18n.t("user.login")}
+                    </a>
+                </Show>
+                {move || request_url().map(|url| view! {
+                    <a href=url class="btn btn-outline btn-sm">{i18n.t("access.request")}</a>
+                })}
+                <a href="/" class="btn btn-ghost btn-sm">{i18n.t("access.home")}</a>
+            </div>
+        </div>
+    }
+}
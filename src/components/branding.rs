@@ -0,0 +1,70 @@
+use leptos::prelude::*;
+
+use crate::app::InstanceBranding;
+
+/// Applies the admin-configured primary colour and default theme.
+#[component]
+pub fn BrandingStyles() -> impl IntoView {
+    let branding = expect_context::<InstanceBranding>().0;
+
+    // Only fall back to the configured theme when the user hasn't picked one
+    // with the theme toggle.
+    #[cfg(feature = "hydrate")]
+    Effect::new(move |_| {
+        if let Some(theme) = branding.get().default_theme {
+            let has_user_choice = js_sys::eval("localStorage.getItem('lekton-theme') !== null")
+                .ok()
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            if !has_user_choice {
+                if let Some(root) = leptos::web_sys::window()
+                    .and_then(|w| w.document())
+                    .and_then(|d| d.document_element())
+                {
+                    let _ = root.set_attribute("data-theme", &theme);
+                }
+            }
+        }
+    });
+
+    move || {
+        branding.get().primary_color.map(|color| {
+            view! {
+                <style>{format!(":root, [data-theme] {{ --color-primary: {color}; }}")}</style>
+            }
+        })
+    }
+}
+
+/// Footer with admin-configured links; renders nothing when none are set.
+#[component]
+pub fn BrandingFooter() -> impl IntoView {
+    let branding = expect_context::<InstanceBranding>().0;
+
+    move || {
+        let branding = branding.get();
+        if branding.footer_links.is_empty() {
+            return None;
+        }
+        Some(view! {
+            <footer class="footer footer-center gap-3 p-6 border-t border-base-200 text-sm text-base-content/60 print:hidden">
+                <nav class="flex flex-wrap justify-center gap-x-6 gap-y-2">
+                    {branding.footer_links.into_iter().map(|link| {
+                        let external = link.url.starts_with("http");
+                        view! {
+                            <a
+                                href=link.url
+                                class="link link-hover"
+                                target=external.then_some("_blank")
+                                rel=external.then_some("noopener noreferrer")
+                            >
+                                {link.label}
+                            </a>
+                        }
+                    }).collect::<Vec<_>>()}
+                </nav>
+                <p>{branding.portal_name}</p>
+            </footer>
+        })
+    }
+}
@@ -1,7 +1,8 @@
 #[allow(unused_imports)]
 use crate::api::schemas::SchemaListItem;
 #[allow(unused_imports)]
-use crate::app::{get_navigation, NavItem};
+use crate::app::{get_incident_status, get_nav_links, get_navigation, NavItem};
+use crate::i18n::use_i18n;
 use crate::pages::chat::ChatContext;
 use crate::schema::component::list_schemas;
 use leptos::prelude::*;
@@ -79,10 +80,11 @@ fn SchemaTreeItem(node: SchemaTreeNode, #[prop(optional)] level: u32) -> impl In
     let children = node.children.clone();
     let is_schema = node.is_schema;
     let location = leptos_router::hooks::use_location();
+    let i18n = use_i18n();
 
     let is_active = {
         let full_name = full_name.clone();
-        move || location.pathname.get() == format!("/schemas/{full_name}")
+        Memo::new(move |_| location.pathname.get() == format!("/schemas/{full_name}"))
     };
     let is_in_active_branch = {
         let full_name = full_name.clone();
@@ -107,12 +109,13 @@ fn SchemaTreeItem(node: SchemaTreeNode, #[prop(optional)] level: u32) -> impl In
                                 <li>
                                     <a
                                         href=href
+                                        aria-current=move || is_active.get().then_some("page")
                                         class=move || format!(
                                             "hover:bg-base-200/50 hover:text-primary transition-colors text-base-content/70 data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium text-sm py-1.5 {}",
-                                            if is_active() { "text-primary font-medium bg-primary/10" } else { "" }
+                                            if is_active.get() { "text-primary font-medium bg-primary/10" } else { "" }
                                         )
                                     >
-                                        {"Overview"}
+                                        {move || i18n.t("sidebar.schema_overview")}
                                     </a>
                                 </li>
                             }.into_any()
@@ -132,9 +135,10 @@ fn SchemaTreeItem(node: SchemaTreeNode, #[prop(optional)] level: u32) -> impl In
             <li>
                 <a
                     href=href
+                    aria-current=move || is_active.get().then_some("page")
                     class=move || format!(
                         "hover:bg-base-200/50 hover:text-primary transition-colors text-base-content/70 data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium text-sm py-1.5 {}",
-                        if is_active() { "text-primary font-medium bg-primary/10" } else { "" }
+                        if is_active.get() { "text-primary font-medium bg-primary/10" } else { "" }
                     )
                 >
                     {title}
@@ -149,28 +153,135 @@ fn SchemaTreeItem(node: SchemaTreeNode, #[prop(optional)] level: u32) -> impl In
 #[component]
 pub fn DocsSidebar() -> impl IntoView {
     use super::navigation::NavigationTree;
+    let i18n = use_i18n();
     view! {
         <ul class="flex flex-col gap-1 mt-6">
-            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mb-1">"Navigation"</li>
+            <IncidentRunbookLinks />
+            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mb-1">{i18n.t("nav.navigation")}</li>
+            <li>
+                <a href="/browse" class="gap-3 text-base-content/70">
+                    <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                        <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 10h16M4 14h16M4 18h16" />
+                    </svg>
+                    {i18n.t("nav.browse")}
+                </a>
+            </li>
+            <li>
+                <a href="/glossary" class="gap-3 text-base-content/70">
+                    <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                        <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 19.5v-15A2.5 2.5 0 0 1 6.5 2H20v20H6.5a2.5 2.5 0 0 1 0-5H20" />
+                    </svg>
+                    {i18n.t("nav.glossary")}
+                </a>
+            </li>
+            <li>
+                <a href="/collections" class="gap-3 text-base-content/70">
+                    <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                        <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M9 5h10M9 12h10M9 19h10M5 5h.01M5 12h.01M5 19h.01" />
+                    </svg>
+                    {i18n.t("nav.collections")}
+                </a>
+            </li>
+            <li>
+                <a href="/changelog" class="gap-3 text-base-content/70">
+                    <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                        <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M12 8v4l3 3m6-3a9 9 0 1 1-18 0 9 9 0 0 1 18 0z" />
+                    </svg>
+                    {i18n.t("nav.changelog")}
+                </a>
+            </li>
             <NavigationTree />
+            <CustomSidebarLinks />
         </ul>
     }
 }
 
+/// Runbooks pinned above the navigation while incident mode is on.
+#[component]
+fn IncidentRunbookLinks() -> impl IntoView {
+    let status_resource = Resource::new(|| (), |_| get_incident_status());
+    let i18n = use_i18n();
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || {
+                status_resource
+                    .get()
+                    .and_then(|res| res.ok())
+                    .filter(|status| status.active && !status.runbooks.is_empty())
+                    .map(|status| view! {
+                        <li class="menu-title text-xs font-semibold tracking-wider text-error uppercase mb-1">{i18n.t("incident.runbooks")}</li>
+                        {status.runbooks.into_iter().map(|runbook| view! {
+                            <li>
+                                <a href=format!("/docs/{}", runbook.slug) class="gap-3 font-medium text-error">
+                                    <span class="flex-1 truncate">{runbook.title}</span>
+                                </a>
+                            </li>
+                        }).collect::<Vec<_>>()}
+                        <li class="mb-4"></li>
+                    })
+            }}
+        </Suspense>
+    }
+}
+
+/// Admin-defined external links listed below the document tree.
+#[component]
+fn CustomSidebarLinks() -> impl IntoView {
+    let links_resource = Resource::new(|| (), |_| get_nav_links());
+    let i18n = use_i18n();
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || {
+                links_resource
+                    .get()
+                    .and_then(|res| res.ok())
+                    .filter(|l| l.show_in_sidebar && !l.groups.is_empty())
+                    .map(|links| {
+                        links.groups.into_iter().map(|group| {
+                            let title = if group.title.is_empty() { i18n.t("nav.links").to_string() } else { group.title };
+                            view! {
+                                <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mt-4 mb-1">{title}</li>
+                                {group.links.into_iter().map(|link| {
+                                    let external = link.url.starts_with("http");
+                                    view! {
+                                        <li>
+                                            <a href=link.url
+                                               target=external.then_some("_blank")
+                                               rel=external.then_some("noopener noreferrer")
+                                               class="gap-3">
+                                                <span class="flex-1 truncate">{link.label}</span>
+                                                {external.then(|| view! {
+                                                    <svg class="w-3 h-3 opacity-50" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M18 13v6a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V8a2 2 0 0 1 2-2h6"/><polyline points="15 3 21 3 21 9"/><line x1="10" y1="14" x2="21" y2="3"/></svg>
+                                                })}
+                                            </a>
+                                        </li>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            }
+                        }).collect::<Vec<_>>()
+                    })
+            }}
+        </Suspense>
+    }
+}
+
 /// Sidebar for Schema Registry.
 /// Shows a searchable list of all registered schemas.
 #[component]
 pub fn RegistrySidebar() -> impl IntoView {
+    let i18n = use_i18n();
     let schemas_resource = Resource::new(|| (), |_| list_schemas());
 
     view! {
         <ul class="flex flex-col gap-1 mt-6">
-            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mb-1">"Schemas"</li>
+            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mb-1">{move || i18n.t("sidebar.schemas")}</li>
             <Suspense fallback=move || view! { <li><span class="loading loading-spinner loading-sm"></span></li> }>
                 {move || schemas_resource.get().map(|result| match result {
                     Ok(schemas) => {
                         if schemas.is_empty() {
-                            view! { <li class="px-3 py-2 text-xs italic opacity-50">"No schemas found"</li> }.into_any()
+                            view! { <li class="px-3 py-2 text-xs italic opacity-50">{move || i18n.t("sidebar.no_schemas")}</li> }.into_any()
                         } else {
                             let tree = build_schema_tree(schemas);
                             view! {
@@ -180,9 +291,15 @@ pub fn RegistrySidebar() -> impl IntoView {
                             }.into_any()
                         }
                     }
-                    Err(_) => view! { <li class="text-error italic text-xs px-3 py-2">"Error loading schemas"</li> }.into_any(),
+                    Err(_) => view! { <li class="text-error italic text-xs px-3 py-2">{move || i18n.t("sidebar.schemas_error")}</li> }.into_any(),
                 })}
             </Suspense>
+            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mt-4 mb-1">{move || i18n.t("sidebar.events")}</li>
+            <li>
+                <a href="/events" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    {move || i18n.t("sidebar.event_catalog")}
+                </a>
+            </li>
         </ul>
     }
 }
@@ -191,55 +308,164 @@ pub fn RegistrySidebar() -> impl IntoView {
 /// Shows navigation links to different admin sub-pages.
 #[component]
 pub fn AdminSidebar() -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <ul class="flex flex-col gap-1 mt-6">
-            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mb-1">"Administration"</li>
+            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mb-1">{move || i18n.t("admin.nav.title")}</li>
             <li>
                 <a href="/admin/tokens" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
                     <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M15 7a2 2 0 012 2m4 0a6 6 0 01-7.743 5.743L11 17H9v2H7v2H4a1 1 0 01-1-1v-2.586a1 1 0 01.293-.707l5.964-5.964A6 6 0 1121 9z"></path></svg>
-                    "Service Tokens"
+                    {move || i18n.t("admin.nav.tokens")}
                 </a>
             </li>
             <li>
                 <a href="/admin/pats" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
                     <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><circle cx="12" cy="8" r="4"/><path d="M20 21a8 8 0 1 0-16 0"/></svg>
-                    "Personal Tokens"
+                    {move || i18n.t("admin.nav.pats")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/api-keys" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M1 12s4-8 11-8 11 8 11 8-4 8-11 8-11-8-11-8z"/><circle cx="12" cy="12" r="3"/></svg>
+                    {move || i18n.t("admin.nav.api_keys")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/approvals" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M12 22s8-4 8-10V5l-8-3-8 3v7c0 6 8 10 8 10z"/><path d="M9 12l2 2 4-4"/></svg>
+                    {move || i18n.t("admin.nav.approvals")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/documents" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M14 2H6a2 2 0 0 0-2 2v16a2 2 0 0 0 2 2h12a2 2 0 0 0 2-2V8z"/><path d="M14 2v6h6"/><path d="M9 15l2 2 4-4"/></svg>
+                    {move || i18n.t("admin.nav.documents")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/share-links" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><circle cx="18" cy="5" r="3"/><circle cx="6" cy="12" r="3"/><circle cx="18" cy="19" r="3"/><path d="m8.59 13.51 6.83 3.98"/><path d="m15.41 6.51-6.82 3.98"/></svg>
+                    {move || i18n.t("admin.nav.share_links")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/usage" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><ellipse cx="12" cy="5" rx="9" ry="3"/><path d="M3 5v14c0 1.66 4 3 9 3s9-1.34 9-3V5"/><path d="M3 12c0 1.66 4 3 9 3s9-1.34 9-3"/></svg>
+                    {move || i18n.t("admin.nav.usage")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/doc-quality" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M9 11l3 3L22 4"/><path d="M21 12v7a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h11"/></svg>
+                    {move || i18n.t("admin.nav.doc_quality")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/doc-ratings" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M7 10v12"/><path d="M15 5.88 14 10h5.83a2 2 0 0 1 1.92 2.56l-2.33 8A2 2 0 0 1 17.5 22H4a2 2 0 0 1-2-2v-8a2 2 0 0 1 2-2h2.76a2 2 0 0 0 1.79-1.11L12 2a3.13 3.13 0 0 1 3 3.88Z"/></svg>
+                    {move || i18n.t("admin.nav.doc_ratings")}
                 </a>
             </li>
             <li>
                 <a href="/admin/documentation-feedback" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
                     <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2z"></path><path d="M8 10h8"></path><path d="M8 14h6"></path></svg>
-                    "Documentation Feedback"
+                    {move || i18n.t("admin.nav.documentation_feedback")}
                 </a>
             </li>
             <li>
                 <a href="/admin/access-levels" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
                     <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><rect x="3" y="11" width="18" height="11" rx="2" ry="2"/><path d="M7 11V7a5 5 0 0 1 10 0v4"/></svg>
-                    "Access Levels"
+                    {move || i18n.t("admin.nav.access_levels")}
                 </a>
             </li>
             <li>
                 <a href="/admin/users" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
                     <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M17 21v-2a4 4 0 0 0-4-4H5a4 4 0 0 0-4 4v2"/><circle cx="9" cy="7" r="4"/><path d="M23 21v-2a4 4 0 0 0-3-3.87"/><path d="M16 3.13a4 4 0 0 1 0 7.75"/></svg>
-                    "Users"
+                    {move || i18n.t("admin.nav.users")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/ldap" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><ellipse cx="12" cy="5" rx="9" ry="3"/><path d="M3 5v14a9 3 0 0 0 18 0V5"/><path d="M3 12a9 3 0 0 0 18 0"/></svg>
+                    {move || i18n.t("admin.nav.ldap")}
                 </a>
             </li>
             <li>
                 <a href="/admin/navigation" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
                     <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M4 6h16M4 10h16M4 14h16M4 18h16"></path></svg>
-                    "Nav Ordering"
+                    {move || i18n.t("admin.nav.navigation")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/links" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M10 13a5 5 0 0 0 7.54.54l3-3a5 5 0 0 0-7.07-7.07l-1.72 1.71"/><path d="M14 11a5 5 0 0 0-7.54-.54l-3 3a5 5 0 0 0 7.07 7.07l1.71-1.71"/></svg>
+                    {move || i18n.t("admin.nav.links")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/glossary" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M4 19.5v-15A2.5 2.5 0 0 1 6.5 2H20v20H6.5a2.5 2.5 0 0 1 0-5H20"/></svg>
+                    {move || i18n.t("admin.nav.glossary")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/dictionary" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="m4 16 4-10 4 10"/><path d="M5.5 12h5"/><path d="m14 17 2.5 2.5L21 15"/></svg>
+                    {move || i18n.t("admin.nav.dictionary")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/projects" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M3 7a2 2 0 0 1 2-2h4l2 2h8a2 2 0 0 1 2 2v8a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2Z"/></svg>
+                    {move || i18n.t("admin.nav.projects")}
                 </a>
             </li>
             <li>
                 <a href="/admin/css" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
                     <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M12 2L2 7l10 5 10-5-10-5Z"/><path d="M2 17l10 5 10-5"/><path d="M2 12l10 5 10-5"/></svg>
-                    "Custom CSS"
+                    {move || i18n.t("admin.nav.css")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/settings" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><circle cx="13.5" cy="6.5" r="1.5"/><circle cx="17.5" cy="10.5" r="1.5"/><circle cx="8.5" cy="7.5" r="1.5"/><circle cx="6.5" cy="12.5" r="1.5"/><path d="M12 2C6.5 2 2 6.5 2 12s4.5 10 10 10c.93 0 1.5-.75 1.5-1.69 0-.44-.18-.84-.44-1.13-.29-.29-.44-.65-.44-1.13a1.64 1.64 0 0 1 1.67-1.67h2c3.05 0 5.55-2.5 5.55-5.55C21.97 6.01 17.46 2 12 2z"/></svg>
+                    {move || i18n.t("admin.nav.settings")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/features" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><rect x="1" y="5" width="22" height="14" rx="7" ry="7"/><circle cx="16" cy="12" r="3"/></svg>
+                    {move || i18n.t("admin.nav.features")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/incident-mode" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="m21.73 18-8-14a2 2 0 0 0-3.48 0l-8 14A2 2 0 0 0 4 21h16a2 2 0 0 0 1.73-3"/><path d="M12 9v4"/><path d="M12 17h.01"/></svg>
+                    {move || i18n.t("admin.nav.incident_mode")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/on-call" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z"/></svg>
+                    {move || i18n.t("admin.nav.on_call")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/issue-tracker" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M9 11l3 3L22 4"/><path d="M21 12v7a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h11"/></svg>
+                    {move || i18n.t("admin.nav.issue_tracker")}
+                </a>
+            </li>
+            <li>
+                <a href="/admin/embeds" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
+                    <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><rect x="2" y="3" width="20" height="14" rx="2"/><path d="M8 21h8"/><path d="M12 17v4"/></svg>
+                    {move || i18n.t("admin.nav.embeds")}
                 </a>
             </li>
             <li>
                 <a href="/admin/rag" class="gap-3 group data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium transition-colors">
                     <svg class="w-4 h-4 opacity-70" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="m3 9 9-7 9 7v11a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2z"/><polyline points="9 22 9 12 15 12 15 22"/></svg>
-                    "Indexes"
+                    {move || i18n.t("admin.nav.rag")}
                 </a>
             </li>
         </ul>
@@ -249,11 +475,12 @@ pub fn AdminSidebar() -> impl IntoView {
 /// Sidebar for AI Chat history.
 #[component]
 pub fn ChatSidebar() -> impl IntoView {
+    let i18n = use_i18n();
     let context = use_context::<ChatContext>();
 
     view! {
         <ul class="flex flex-col gap-1 mt-6">
-            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mb-1">"AI Chat"</li>
+            <li class="menu-title text-xs font-semibold tracking-wider text-base-content/60 uppercase mb-1">{move || i18n.t("chat.sidebar_title")}</li>
             {move || match context {
                 Some(ctx) => {
                     let sessions = ctx.sessions;
@@ -292,7 +519,7 @@ pub fn ChatSidebar() -> impl IntoView {
                         <li class="mb-3">
                             <button class="btn btn-primary btn-sm w-full gap-2" on:click=start_new_session>
                                 <svg class="w-4 h-4" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M12 5v14"/><path d="M5 12h14"/></svg>
-                                "New Chat"
+                                {move || i18n.t("chat.new_chat")}
                             </button>
                         </li>
                         <For
@@ -355,7 +582,7 @@ pub fn ChatSidebar() -> impl IntoView {
                         />
                     }.into_any()
                 }
-                None => view! { <li class="px-3 py-2 text-xs italic opacity-50">"No chat session active"</li> }.into_any(),
+                None => view! { <li class="px-3 py-2 text-xs italic opacity-50">{move || i18n.t("chat.no_session")}</li> }.into_any(),
             }}
         </ul>
     }
@@ -0,0 +1,120 @@
+//! Keyboard focus handling for dialogs and toolbars.
+//!
+//! The helpers only act in the browser; server builds get no-ops so that
+//! components can call them unconditionally.
+
+use leptos::web_sys::{Element, KeyboardEvent};
+
+/// Elements reachable with Tab.
+#[cfg(feature = "hydrate")]
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+    textarea:not([disabled]), select:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+#[cfg(feature = "hydrate")]
+fn focusable(container: &Element) -> Vec<web_sys::HtmlElement> {
+    use wasm_bindgen::JsCast;
+
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return vec![];
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+#[cfg(feature = "hydrate")]
+fn active_element() -> Option<web_sys::HtmlElement> {
+    use wasm_bindgen::JsCast;
+
+    web_sys::window()?
+        .document()?
+        .active_element()?
+        .dyn_into::<web_sys::HtmlElement>()
+        .ok()
+}
+
+/// Position of the focused element among `items`.
+#[cfg(feature = "hydrate")]
+fn focused_index(items: &[web_sys::HtmlElement]) -> Option<usize> {
+    let active = active_element()?;
+    items.iter().position(|item| *item == active)
+}
+
+/// Keep Tab and Shift+Tab cycling through the controls of `container`, a
+/// modal dialog. Call from the dialog's `keydown` handler.
+pub fn trap_focus(ev: &KeyboardEvent, container: &Element) {
+    #[cfg(feature = "hydrate")]
+    {
+        if ev.key() != "Tab" {
+            return;
+        }
+        let items = focusable(container);
+        let (Some(first), Some(last)) = (items.first(), items.last()) else {
+            ev.prevent_default();
+            return;
+        };
+        let target = match (focused_index(&items), ev.shift_key()) {
+            (Some(0) | None, true) => last,
+            (Some(i), false) if i == items.len() - 1 => first,
+            (None, false) => first,
+            _ => return,
+        };
+        ev.prevent_default();
+        let _ = target.focus();
+    }
+    #[cfg(not(feature = "hydrate"))]
+    let _ = (ev, container);
+}
+
+/// Move focus between the controls of `container`, a `role="toolbar"`,
+/// with the arrow keys, Home and End. Call from its `keydown` handler.
+pub fn toolbar_arrow_keys(ev: &KeyboardEvent, container: &Element) {
+    #[cfg(feature = "hydrate")]
+    {
+        let items = focusable(container);
+        if items.is_empty() {
+            return;
+        }
+        let current = focused_index(&items).unwrap_or(0);
+        let next = match ev.key().as_str() {
+            "ArrowRight" | "ArrowDown" => (current + 1) % items.len(),
+            "ArrowLeft" | "ArrowUp" => (current + items.len() - 1) % items.len(),
+            "Home" => 0,
+            "End" => items.len() - 1,
+            _ => return,
+        };
+        ev.prevent_default();
+        let _ = items[next].focus();
+    }
+    #[cfg(not(feature = "hydrate"))]
+    let _ = (ev, container);
+}
+
+/// Focus the first control of `container`, if any.
+pub fn focus_first(container: &Element) {
+    #[cfg(feature = "hydrate")]
+    if let Some(first) = focusable(container).first() {
+        let _ = first.focus();
+    }
+    #[cfg(not(feature = "hydrate"))]
+    let _ = container;
+}
+
+/// Give focus back to whatever had it when `is_open` became true, once it
+/// turns false again (e.g. the button that opened a dialog).
+pub fn restore_focus_on_close(is_open: impl Fn() -> bool + 'static) {
+    #[cfg(feature = "hydrate")]
+    leptos::prelude::Effect::new(move |opener: Option<Option<web_sys::HtmlElement>>| {
+        if is_open() {
+            opener.flatten().or_else(active_element)
+        } else {
+            if let Some(opener) = opener.flatten() {
+                let _ = opener.focus();
+            }
+            None
+        }
+    });
+    #[cfg(not(feature = "hydrate"))]
+    let _ = is_open;
+}
@@ -0,0 +1,57 @@
+use leptos::prelude::*;
+
+use crate::i18n::{use_i18n, Locale};
+
+/// Language picker in the navbar.
+///
+/// Stores the choice in the `lekton_locale` cookie so the server renders the
+/// next page in that language, then reloads.
+#[component]
+pub fn LanguageSwitcher() -> impl IntoView {
+    let i18n = use_i18n();
+
+    let select_locale = move |locale: Locale| {
+        #[cfg(feature = "hydrate")]
+        {
+            let js_code = format!(
+                "document.cookie = '{}={}; path=/; max-age=31536000; samesite=lax'; window.location.reload();",
+                crate::i18n::LOCALE_COOKIE,
+                locale.code()
+            );
+            let _ = js_sys::eval(&js_code);
+        }
+        #[cfg(not(feature = "hydrate"))]
+        let _ = locale;
+    };
+
+    view! {
+        <div class="dropdown dropdown-end">
+            <div
+                tabindex="0"
+                role="button"
+                class="btn btn-ghost btn-sm font-medium uppercase"
+                title=move || i18n.t("language.label")
+                aria-label=move || i18n.t("language.label")
+            >
+                {move || i18n.locale().code()}
+            </div>
+            <ul tabindex="0" class="dropdown-content menu bg-base-100 rounded-box z-[1] w-40 p-2 shadow border border-base-200 mt-2">
+                {Locale::ALL
+                    .into_iter()
+                    .map(|locale| {
+                        view! {
+                            <li>
+                                <button
+                                    class=move || if i18n.locale() == locale { "active" } else { "" }
+                                    on:click=move |_| select_locale(locale)
+                                >
+                                    {locale.native_name()}
+                                </button>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ul>
+        </div>
+    }
+}
@@ -1,22 +1,31 @@
 use leptos::prelude::*;
 
+use super::branding::{BrandingFooter, BrandingStyles};
 use super::contextual_sidebars::{AdminSidebar, ChatSidebar, DocsSidebar, RegistrySidebar};
 use super::custom_css::RuntimeCustomCss;
+use super::language::LanguageSwitcher;
 use super::logo::BrandedLogo;
+use super::notification_bell::NotificationBell;
 use super::search::SearchModal;
 use super::theme::ThemeToggle;
 use super::user_menu::UserMenu;
-use crate::app::{get_navbar_groups, get_navigation};
-use crate::auth::refresh_client::with_auth_retry;
+use crate::app::{get_nav_links, get_navbar_groups, get_navigation};
+use crate::auth::refresh_client::{is_auth_error, refresh_on_auth_error};
+use crate::i18n::use_i18n;
 
 const MAX_DOCS_ITEMS: usize = 5;
 
 #[component]
 pub fn TopNavbarLinks() -> impl IntoView {
-    let nav_resource = LocalResource::new(|| with_auth_retry(get_navigation));
+    let nav_resource = Resource::new(|| (), |_| get_navigation());
+    refresh_on_auth_error(
+        move || matches!(nav_resource.get(), Some(Err(e)) if is_auth_error(&e)),
+        move || nav_resource.refetch(),
+    );
     let groups_resource = Resource::new(|| (), |_| get_navbar_groups());
     let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
     let is_rag = use_context::<crate::app::IsRagEnabled>();
+    let i18n = use_i18n();
 
     view! {
         <Suspense fallback=move || view! { <span class="loading loading-spinner loading-sm"></span> }>
@@ -54,7 +63,7 @@ pub fn TopNavbarLinks() -> impl IntoView {
                     let items_t3 = items;
 
                     view! {
-                        // ── TIER 1: xl+ — full text, max items, "Altro" overflow ──────────────
+                        // ── TIER 1: xl+ — full text, max items, "More" overflow ──────────────
                         <div class="hidden xl:flex items-center gap-2">
                             {t1_standalone.into_iter().map(|item| {
                                 view! {
@@ -89,13 +98,13 @@ pub fn TopNavbarLinks() -> impl IntoView {
                                 }.into_any()
                             }).collect::<Vec<_>>()}
 
-                            // "Altro" overflow dropdown
+                            // "More" overflow dropdown
                             {if has_overflow {
                                 view! {
                                     <div class="dropdown dropdown-hover dropdown-bottom">
                                         <div tabindex="0" role="button"
                                              class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50 m-1">
-                                            "Altro"
+                                            {i18n.t("nav.more")}
                                             <svg xmlns="http://www.w3.org/2000/svg" width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="ml-1 opacity-60"><path d="m6 9 6 6 6-6"/></svg>
                                         </div>
                                         <ul tabindex="0" class="dropdown-content z-[1] menu p-2 shadow bg-base-100 rounded-box w-52 border border-base-200">
@@ -126,13 +135,13 @@ pub fn TopNavbarLinks() -> impl IntoView {
                             <div class="w-px h-5 bg-base-300 mx-1 self-center"></div>
 
                             <a href="/schemas" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">
-                                "Registry"
+                                {i18n.t("nav.registry")}
                             </a>
                             {move || {
                                 let logged_in = current_user.map(|sig| sig.get().is_some()).unwrap_or(false);
                                 let rag_enabled = is_rag.map(|sig| sig.0.get()).unwrap_or(false);
                                 if logged_in && rag_enabled {
-                                    view! { <a href="/chat" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">"Chat"</a> }.into_any()
+                                    view! { <a href="/chat" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">{i18n.t("nav.chat")}</a> }.into_any()
                                 } else {
                                     view! { <span></span> }.into_any()
                                 }
@@ -140,7 +149,7 @@ pub fn TopNavbarLinks() -> impl IntoView {
                             {move || {
                                 let is_admin = current_user.and_then(|sig| sig.get()).map(|u| u.is_admin).unwrap_or(false);
                                 if is_admin {
-                                    view! { <a href="/admin/tokens" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">"Admin"</a> }.into_any()
+                                    view! { <a href="/admin/tokens" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">{i18n.t("nav.admin")}</a> }.into_any()
                                 } else {
                                     view! { <span></span> }.into_any()
                                 }
@@ -152,7 +161,7 @@ pub fn TopNavbarLinks() -> impl IntoView {
                             <div class="dropdown dropdown-hover dropdown-bottom">
                                 <div tabindex="0" role="button"
                                      class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50 m-1">
-                                    "Docs"
+                                    {i18n.t("nav.docs")}
                                     <svg xmlns="http://www.w3.org/2000/svg" width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="ml-1 opacity-60"><path d="m6 9 6 6 6-6"/></svg>
                                 </div>
                                 <ul tabindex="0" class="dropdown-content z-[1] menu p-2 shadow bg-base-100 rounded-box w-52 border border-base-200">
@@ -179,13 +188,13 @@ pub fn TopNavbarLinks() -> impl IntoView {
                             <div class="w-px h-5 bg-base-300 mx-1 self-center"></div>
 
                             <a href="/schemas" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">
-                                "Registry"
+                                {i18n.t("nav.registry")}
                             </a>
                             {move || {
                                 let logged_in = current_user.map(|sig| sig.get().is_some()).unwrap_or(false);
                                 let rag_enabled = is_rag.map(|sig| sig.0.get()).unwrap_or(false);
                                 if logged_in && rag_enabled {
-                                    view! { <a href="/chat" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">"Chat"</a> }.into_any()
+                                    view! { <a href="/chat" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">{i18n.t("nav.chat")}</a> }.into_any()
                                 } else {
                                     view! { <span></span> }.into_any()
                                 }
@@ -193,7 +202,7 @@ pub fn TopNavbarLinks() -> impl IntoView {
                             {move || {
                                 let is_admin = current_user.and_then(|sig| sig.get()).map(|u| u.is_admin).unwrap_or(false);
                                 if is_admin {
-                                    view! { <a href="/admin/tokens" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">"Admin"</a> }.into_any()
+                                    view! { <a href="/admin/tokens" class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">{i18n.t("nav.admin")}</a> }.into_any()
                                 } else {
                                     view! { <span></span> }.into_any()
                                 }
@@ -206,7 +215,7 @@ pub fn TopNavbarLinks() -> impl IntoView {
                             <div class="dropdown dropdown-hover dropdown-bottom">
                                 <div tabindex="0" role="button"
                                      class="btn btn-ghost btn-sm px-2 text-base-content/80 hover:text-base-content hover:bg-base-200/50 m-1"
-                                     title="Documentazione">
+                                     title=i18n.t("nav.docs")>
                                     // Book icon
                                     <svg xmlns="http://www.w3.org/2000/svg" width="18" height="18" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M4 19.5v-15A2.5 2.5 0 0 1 6.5 2H20v20H6.5a2.5 2.5 0 0 1 0-5H20"/></svg>
                                     <svg xmlns="http://www.w3.org/2000/svg" width="10" height="10" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="opacity-60"><path d="m6 9 6 6 6-6"/></svg>
@@ -234,7 +243,7 @@ pub fn TopNavbarLinks() -> impl IntoView {
                             // Registry icon
                             <a href="/schemas"
                                class="btn btn-ghost btn-sm px-2 text-base-content/80 hover:text-base-content hover:bg-base-200/50"
-                               title="Registry">
+                               title=i18n.t("nav.registry")>
                                 // File-list icon
                                 <svg xmlns="http://www.w3.org/2000/svg" width="18" height="18" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M14.5 2H6a2 2 0 0 0-2 2v16a2 2 0 0 0 2 2h12a2 2 0 0 0 2-2V7.5L14.5 2z"/><polyline points="14 2 14 8 20 8"/><line x1="16" y1="13" x2="8" y2="13"/><line x1="16" y1="17" x2="8" y2="17"/><line x1="10" y1="9" x2="8" y2="9"/></svg>
                             </a>
@@ -247,7 +256,7 @@ pub fn TopNavbarLinks() -> impl IntoView {
                                     view! {
                                         <a href="/chat"
                                            class="btn btn-ghost btn-sm px-2 text-base-content/80 hover:text-base-content hover:bg-base-200/50"
-                                           title="Chat">
+                                           title=i18n.t("nav.chat")>
                                             <svg xmlns="http://www.w3.org/2000/svg" width="18" height="18" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2z"/></svg>
                                         </a>
                                     }.into_any()
@@ -263,7 +272,7 @@ pub fn TopNavbarLinks() -> impl IntoView {
                                     view! {
                                         <a href="/admin/tokens"
                                            class="btn btn-ghost btn-sm px-2 text-base-content/80 hover:text-base-content hover:bg-base-200/50"
-                                           title="Admin">
+                                           title=i18n.t("nav.admin")>
                                             <svg xmlns="http://www.w3.org/2000/svg" width="18" height="18" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M12.22 2h-.44a2 2 0 0 0-2 2v.18a2 2 0 0 1-1 1.73l-.43.25a2 2 0 0 1-2 0l-.15-.08a2 2 0 0 0-2.73.73l-.22.38a2 2 0 0 0 .73 2.73l.15.1a2 2 0 0 1 1 1.72v.51a2 2 0 0 1-1 1.74l-.15.09a2 2 0 0 0-.73 2.73l.22.38a2 2 0 0 0 2.73.73l.15-.08a2 2 0 0 1 2 0l.43.25a2 2 0 0 1 1 1.73V20a2 2 0 0 0 2 2h.44a2 2 0 0 0 2-2v-.18a2 2 0 0 1 1-1.73l.43-.25a2 2 0 0 1 2 0l.15.08a2 2 0 0 0 2.73-.73l.22-.39a2 2 0 0 0-.73-2.73l-.15-.08a2 2 0 0 1-1-1.74v-.5a2 2 0 0 1 1-1.74l.15-.09a2 2 0 0 0 .73-2.73l-.22-.38a2 2 0 0 0-2.73-.73l-.15.08a2 2 0 0 1-2 0l-.43-.25a2 2 0 0 1-1-1.73V4a2 2 0 0 0-2-2z"/><circle cx="12" cy="12" r="3"/></svg>
                                         </a>
                                     }.into_any()
@@ -281,62 +290,158 @@ pub fn TopNavbarLinks() -> impl IntoView {
     }
 }
 
+/// Admin-defined external links shown in the navbar (lg+ only; smaller
+/// screens reach them through the sidebar).
+#[component]
+pub fn CustomNavbarLinks() -> impl IntoView {
+    let links_resource = Resource::new(|| (), |_| get_nav_links());
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || {
+                links_resource.get().and_then(|res| res.ok()).filter(|l| l.show_in_navbar).map(|links| {
+                    view! {
+                        <div class="hidden lg:flex items-center gap-1">
+                            {links.groups.into_iter().map(|group| {
+                                if group.title.is_empty() {
+                                    group.links.into_iter().map(|link| {
+                                        let external = link.url.starts_with("http");
+                                        view! {
+                                            <a href=link.url
+                                               target=external.then_some("_blank")
+                                               rel=external.then_some("noopener noreferrer")
+                                               class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50">
+                                                {link.label}
+                                            </a>
+                                        }
+                                    }).collect::<Vec<_>>().into_any()
+                                } else {
+                                    view! {
+                                        <div class="dropdown dropdown-hover dropdown-bottom">
+                                            <div tabindex="0" role="button"
+                                                 class="btn btn-ghost btn-sm font-normal text-base-content/80 hover:text-base-content hover:bg-base-200/50 m-1">
+                                                {group.title}
+                                                <svg xmlns="http://www.w3.org/2000/svg" width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="ml-1 opacity-60"><path d="m6 9 6 6 6-6"/></svg>
+                                            </div>
+                                            <ul tabindex="0" class="dropdown-content z-[1] menu p-2 shadow bg-base-100 rounded-box w-52 border border-base-200">
+                                                {group.links.into_iter().map(|link| {
+                                                    let external = link.url.starts_with("http");
+                                                    view! {
+                                                        <li>
+                                                            <a href=link.url
+                                                               target=external.then_some("_blank")
+                                                               rel=external.then_some("noopener noreferrer")>
+                                                                {link.label}
+                                                            </a>
+                                                        </li>
+                                                    }
+                                                }).collect::<Vec<_>>()}
+                                            </ul>
+                                        </div>
+                                    }.into_any()
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    }
+                })
+            }}
+        </Suspense>
+    }
+}
+
 /// Main layout: navbar + sidebar + content area.
 #[component]
 pub fn Layout(children: Children) -> impl IntoView {
     let (search_modal_open, set_search_modal_open) = signal(false);
+    let (drawer_open, set_drawer_open) = signal(false);
+    let i18n = use_i18n();
+    let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
+    let portal = use_context::<crate::app::PortalFeatures>();
+    let search_enabled = move || portal.map(|p| p.0.get().search_enabled).unwrap_or(true);
 
     use leptos::ev;
     window_event_listener(ev::keydown, move |ev| {
-        if (ev.ctrl_key() || ev.meta_key()) && ev.key() == "k" {
+        if (ev.ctrl_key() || ev.meta_key()) && ev.key() == "k" && search_enabled() {
             ev.prevent_default();
             ev.stop_propagation();
             set_search_modal_open.set(true);
+        } else if ev.key() == "Escape" && drawer_open.get_untracked() {
+            set_drawer_open.set(false);
         }
     });
+    // Close the mobile drawer once a link in it was followed
+    let location = leptos_router::hooks::use_location();
+    Effect::new(move |_| {
+        location.pathname.track();
+        set_drawer_open.set(false);
+    });
 
     view! {
         // Runtime custom CSS injection (loaded from MongoDB settings)
         <RuntimeCustomCss />
+        <BrandingStyles />
 
         <div class="min-h-screen bg-base-100/50">
+            <a
+                href="#main-content"
+                class="sr-only focus:not-sr-only focus:fixed focus:top-2 focus:left-2 focus:z-[100] btn btn-primary btn-sm"
+            >
+                {i18n.t("nav.skip_to_content")}
+            </a>
             // Navbar
-            <header class="bg-base-100/80 backdrop-blur-md fixed top-0 inset-x-0 z-50 border-b border-base-200 px-4 h-16 flex items-center gap-2 shadow-sm">
+            <header class="bg-base-100/80 backdrop-blur-md fixed top-0 inset-x-0 z-50 border-b border-base-200 px-4 h-16 flex items-center gap-2 shadow-sm print:hidden">
                 // Left — shrinks only when space is truly exhausted
                 <div class="flex items-center gap-2 shrink-0">
-                    <label for="sidebar-drawer" class="btn btn-square btn-ghost drawer-button lg:hidden">
-                        <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="inline-block w-5 h-5 stroke-current"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 12h16M4 18h16"></path></svg>
-                    </label>
+                    <button
+                        class="btn btn-square btn-ghost drawer-button lg:hidden"
+                        aria-controls="sidebar"
+                        aria-expanded=move || drawer_open.get().to_string()
+                        aria-label=move || i18n.t(if drawer_open.get() { "nav.close_sidebar" } else { "nav.open_sidebar" })
+                        on:click=move |_| set_drawer_open.update(|open| *open = !*open)
+                    >
+                        <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="inline-block w-5 h-5 stroke-current" aria-hidden="true"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 12h16M4 18h16"></path></svg>
+                    </button>
                     <BrandedLogo />
-                    <div class="flex items-center gap-1 ml-2 pl-2 sm:ml-4 sm:pl-4 border-l border-base-300">
+                    <nav class="flex items-center gap-1 ml-2 pl-2 sm:ml-4 sm:pl-4 border-l border-base-300" aria-label=i18n.t("nav.main")>
                         <TopNavbarLinks />
-                    </div>
+                        <CustomNavbarLinks />
+                    </nav>
                 </div>
                 // Center — visible at md+, replaced by icon on smaller screens
                 <div class="hidden md:flex flex-1 min-w-0 items-center justify-center">
-                    <div class="w-full max-w-md">
+                    <div class="w-full max-w-md" class:invisible=move || !search_enabled()>
                         <button
                             class="btn btn-ghost bg-base-200/50 hover:bg-base-200 border border-base-300 hover:border-primary/30 w-full justify-between shadow-sm flex-nowrap h-11 min-h-[2.75rem] px-4 transition-all font-normal text-base-content/80 group/btn"
                             on:click=move |_| set_search_modal_open.set(true)
                         >
                             <div class="flex items-center gap-3 overflow-hidden">
-                                <svg class="w-4 h-4 opacity-70 flex-shrink-0 group-hover/btn:text-primary transition-colors" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                <svg class="w-4 h-4 opacity-70 flex-shrink-0 group-hover/btn:text-primary transition-colors" fill="none" stroke="currentColor" viewBox="0 0 24 24" aria-hidden="true">
                                     <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z"></path>
                                 </svg>
-                                <span class="truncate">"Search documentation..."</span>
+                                <span class="truncate">{i18n.t("search.button")}</span>
                             </div>
-                            <kbd class="kbd kbd-sm bg-base-100 border-none shadow-sm opacity-80 flex-shrink-0 group-hover/btn:bg-primary group-hover/btn:text-primary-content transition-colors">"Ctrl K"</kbd>
+                            <kbd aria-hidden="true" class="kbd kbd-sm bg-base-100 border-none shadow-sm opacity-80 flex-shrink-0 group-hover/btn:bg-primary group-hover/btn:text-primary-content transition-colors">"Ctrl K"</kbd>
                         </button>
                     </div>
                 </div>
                 // Right — never shrinks
                 <div class="flex items-center gap-2 flex-nowrap shrink-0">
                     // Search icon — shown when full search bar is hidden
-                    <button class="btn btn-circle btn-ghost md:hidden" on:click=move |_| set_search_modal_open.set(true)>
-                        <svg class="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z"></path></svg>
-                    </button>
-                    // Theme toggle
-                    <ThemeToggle />
+                    <Show when=search_enabled>
+                        <button
+                            class="btn btn-circle btn-ghost md:hidden"
+                            aria-label=i18n.t("search.button")
+                            on:click=move |_| set_search_modal_open.set(true)
+                        >
+                            <svg class="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24" aria-hidden="true"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z"></path></svg>
+                        </button>
+                    </Show>
+                    // Theme toggle — signed-in users find it in the user menu
+                    <Show when=move || !current_user.map(|sig| sig.get().is_some()).unwrap_or(false)>
+                        <ThemeToggle />
+                    </Show>
+                    <LanguageSwitcher />
+                    <NotificationBell />
                     // User area — shows login button or user info
                     <UserMenu />
                 </div>
@@ -346,32 +451,41 @@ pub fn Layout(children: Children) -> impl IntoView {
             <SearchModal is_open=search_modal_open set_is_open=set_search_modal_open />
 
             // Main content area with sidebar
-            <div class="drawer lg:drawer-open pt-16">
-                <input id="sidebar-drawer" type="checkbox" class="drawer-toggle" />
+            <div class="drawer lg:drawer-open pt-16 print:pt-0">
+                <input
+                    id="sidebar-drawer"
+                    type="checkbox"
+                    class="drawer-toggle"
+                    tabindex="-1"
+                    aria-hidden="true"
+                    prop:checked=drawer_open
+                    on:change=move |ev| set_drawer_open.set(event_target_checked(&ev))
+                />
                 <div class="drawer-content lg:col-start-2 flex flex-col bg-base-100 min-w-0">
-                    <div class=move || {
+                    <main id="main-content" tabindex="-1" class=move || {
                         let path = leptos_router::hooks::use_location().pathname.get();
                         if path.starts_with("/chat") {
                             "w-full h-[calc(100vh-4rem)] flex flex-col overflow-hidden"
                         } else {
-                            "w-full max-w-6xl mx-auto p-6 lg:p-10 min-h-[calc(100vh-4rem)]"
+                            "w-full max-w-6xl mx-auto p-6 lg:p-10 min-h-[calc(100vh-4rem)] print:max-w-none print:p-0 print:min-h-0"
                         }
                     }>
                         {children()}
-                    </div>
+                    </main>
+                    <BrandingFooter />
                 </div>
 
                 // Sidebar
-                <div class="drawer-side z-40">
-                    <label for="sidebar-drawer" aria-label="close sidebar" class="drawer-overlay"></label>
-                    <div class="menu bg-base-200 min-h-full h-[calc(100vh-4rem)] w-64 p-4 text-base-content border-r border-base-300 pt-6 overflow-y-auto block">
+                <div class="drawer-side z-40 print:hidden">
+                    <label for="sidebar-drawer" aria-label=i18n.t("nav.close_sidebar") class="drawer-overlay"></label>
+                    <nav id="sidebar" aria-label=i18n.t("nav.navigation") class="menu bg-base-200 min-h-full h-[calc(100vh-4rem)] w-64 p-4 text-base-content border-r border-base-300 pt-6 overflow-y-auto block">
                         {move || {
                             let location = leptos_router::hooks::use_location();
                             let path = location.pathname.get();
 
-                            if path.starts_with("/docs") || path == "/" {
+                            if path.starts_with("/docs") || path == "/" || path == "/browse" || path == "/glossary" || path.starts_with("/changelog") || path.starts_with("/collections") {
                                 view! { <DocsSidebar /> }.into_any()
-                            } else if path.starts_with("/schemas") {
+                            } else if path.starts_with("/schemas") || path == "/events" {
                                 view! { <RegistrySidebar /> }.into_any()
                             } else if path.starts_with("/chat") {
                                 view! { <ChatSidebar /> }.into_any()
@@ -381,7 +495,7 @@ pub fn Layout(children: Children) -> impl IntoView {
                                 view! { <span></span> }.into_any()
                             }
                         }}
-                    </div>
+                    </nav>
                 </div>
             </div>
         </div>
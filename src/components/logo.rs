@@ -45,6 +45,12 @@ pub async fn check_logo_availability() -> Result<LogoConfig, ServerFnError> {
 #[component]
 pub fn BrandedLogo() -> impl IntoView {
     let logo_config = Resource::new(|| (), |_| check_logo_availability());
+    let branding = use_context::<crate::app::InstanceBranding>();
+    let portal_name = move || {
+        branding
+            .map(|b| b.0.get().portal_name)
+            .unwrap_or_else(|| "Lekton".to_string())
+    };
 
     // Diagnostics audit state — tracks rapid-click sequences on the brand mark
     #[cfg(feature = "hydrate")]
@@ -81,7 +87,13 @@ pub fn BrandedLogo() -> impl IntoView {
                     </svg>
                 }>
                     {move || {
-                        if let Some(Ok(config)) = logo_config.get() {
+                        let branded_logo = branding.and_then(|b| b.0.get().logo_url);
+                        if let Some(url) = branded_logo {
+                            // Admin-configured logo takes precedence over files in public/
+                            view! {
+                                <img src=url alt="Logo" class="w-full h-full object-contain" />
+                            }.into_any()
+                        } else if let Some(Ok(config)) = logo_config.get() {
                             let light_url = config.light_url;
                             let dark_url = config.dark_url;
 
@@ -144,7 +156,7 @@ pub fn BrandedLogo() -> impl IntoView {
 
             // Brand name
             <span class="hidden sm:inline truncate max-w-[150px] text-base-content">
-                "Lekton"
+                {portal_name}
             </span>
         </a>
     }
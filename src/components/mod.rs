@@ -1,20 +1,36 @@
+mod access_denied;
+mod branding;
 mod contextual_sidebars;
 mod custom_css;
 mod diagnostics;
+mod focus;
+mod language;
 mod layout;
 mod logo;
 mod markdown_content;
 mod navigation;
+mod notification_bell;
+mod on_call;
+mod require_login;
 mod search;
 mod theme;
+mod toast;
 mod user_menu;
 
+pub use access_denied::*;
+pub use branding::*;
 pub use contextual_sidebars::*;
 pub use custom_css::*;
+pub use focus::*;
+pub use language::*;
 pub use layout::*;
 pub use logo::*;
 pub use markdown_content::*;
 pub use navigation::*;
+pub use notification_bell::*;
+pub use on_call::*;
+pub use require_login::*;
 pub use search::*;
 pub use theme::*;
+pub use toast::*;
 pub use user_menu::*;
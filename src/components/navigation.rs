@@ -1,7 +1,9 @@
 use leptos::prelude::*;
 
 use crate::app::{get_navigation, NavItem};
-use crate::auth::refresh_client::with_auth_retry;
+use crate::auth::refresh_client::{is_auth_error, refresh_on_auth_error};
+use crate::db::bundle_repository::{bundle_slug, is_versioned_slug, split_versioned_slug};
+use crate::i18n::use_i18n;
 
 /// Recursive navigation item component for rendering tree structure.
 #[component]
@@ -38,10 +40,17 @@ pub fn NavigationItem(item: NavItem, #[prop(optional)] level: u32) -> impl IntoV
             }.into_any()
         }
     } else {
+        let href = format!("/docs/{}", slug);
+        let location = leptos_router::hooks::use_location();
+        let current = {
+            let href = href.clone();
+            move || (location.pathname.get() == href).then_some("page")
+        };
         view! {
             <li>
                 <a
-                    href=format!("/docs/{}", slug)
+                    href=href
+                    aria-current=current
                     class="hover:bg-base-200/50 hover:text-primary transition-colors text-base-content/70 data-[active]:bg-primary/10 data-[active]:text-primary data-[active]:font-medium text-sm py-1.5"
                 >
                     {item.title}
@@ -51,12 +60,41 @@ pub fn NavigationItem(item: NavItem, #[prop(optional)] level: u32) -> impl IntoV
     }
 }
 
+/// Find the item with `slug` in the tree.
+fn find_item(items: Vec<NavItem>, slug: &str) -> Option<NavItem> {
+    items.into_iter().find_map(|item| {
+        if item.slug == slug {
+            Some(item)
+        } else {
+            find_item(item.children, slug)
+        }
+    })
+}
+
+/// Drop the sections of prior doc bundle versions, which are navigated on
+/// their own.
+fn without_bundle_versions(items: Vec<NavItem>) -> Vec<NavItem> {
+    items
+        .into_iter()
+        .filter(|item| !is_versioned_slug(&item.slug))
+        .map(|mut item| {
+            item.children = without_bundle_versions(item.children);
+            item
+        })
+        .collect()
+}
+
 /// Navigation tree component that fetches and renders the sidebar navigation.
 #[component]
 pub fn NavigationTree() -> impl IntoView {
-    let nav_resource = LocalResource::new(|| with_auth_retry(get_navigation));
+    let nav_resource = Resource::new(|| (), |_| get_navigation());
+    refresh_on_auth_error(
+        move || matches!(nav_resource.get(), Some(Err(e)) if is_auth_error(&e)),
+        move || nav_resource.refetch(),
+    );
 
     let location = leptos_router::hooks::use_location();
+    let i18n = use_i18n();
 
     view! {
         <Suspense fallback=move || view! {
@@ -72,6 +110,27 @@ pub fn NavigationTree() -> impl IntoView {
                         } else {
                             String::new()
                         };
+                        // Pages of a prior bundle version navigate within
+                        // that version only
+                        let current_slug = if parts.first() == Some(&"docs") {
+                            parts[1..].join("/")
+                        } else {
+                            String::new()
+                        };
+                        if let Some((root, version, _)) = split_versioned_slug(&current_slug) {
+                            let version_items = find_item(items, &bundle_slug(root, Some(version), ""))
+                                .map(|item| item.children)
+                                .unwrap_or_default();
+                            let title = format!("{} {version}", i18n.t("nav.bundle_version"));
+                            return view! {
+                                <li class="menu-title font-mono">{title}</li>
+                                {version_items.into_iter().map(|item| {
+                                    view! {
+                                        <NavigationItem item=item level=0 />
+                                    }
+                                }).collect::<Vec<_>>()}
+                            }.into_any();
+                        }
                         // Root-level sections (docs, hackday, …) live in the
                         // navbar only.  The sidebar shows the *children* of
                         // whichever section is currently selected.
@@ -79,7 +138,7 @@ pub fn NavigationTree() -> impl IntoView {
                             vec![]
                         } else {
                             if let Some(root_item) = items.into_iter().find(|i| i.slug == current_root) {
-                                root_item.children
+                                without_bundle_versions(root_item.children)
                             } else {
                                 vec![]
                             }
@@ -0,0 +1,70 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+
+use crate::app::count_unread_notifications;
+use crate::i18n::use_i18n;
+
+/// How often the unread count is refreshed while a page stays open.
+#[cfg(feature = "hydrate")]
+const REFRESH_SECS: u64 = 60;
+
+/// Navbar bell linking to `/notifications`, with the caller's unread count.
+/// Hidden for anonymous visitors; the count is refreshed on navigation and
+/// every [`REFRESH_SECS`] seconds.
+#[component]
+pub fn NotificationBell() -> impl IntoView {
+    let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>()
+        .expect("NotificationBell must be inside App");
+    let i18n = use_i18n();
+    let location = use_location();
+    let tick = RwSignal::new(0u32);
+
+    let unread = LocalResource::new(move || {
+        let signed_in = current_user.get().is_some();
+        location.pathname.track();
+        tick.track();
+        async move {
+            if signed_in {
+                count_unread_notifications().await.unwrap_or_default()
+            } else {
+                0
+            }
+        }
+    });
+
+    #[cfg(feature = "hydrate")]
+    {
+        let refresh = set_interval_with_handle(
+            move || tick.update(|t| *t = t.wrapping_add(1)),
+            std::time::Duration::from_secs(REFRESH_SECS),
+        )
+        .ok();
+        on_cleanup(move || {
+            if let Some(handle) = refresh {
+                handle.clear();
+            }
+        });
+    }
+
+    view! {
+        <Show when=move || current_user.get().is_some()>
+            <a
+                href="/notifications"
+                class="btn btn-ghost btn-circle btn-sm"
+                title=move || i18n.t("user.notifications")
+                aria-label=move || i18n.t("user.notifications")
+            >
+                <div class="indicator">
+                    <svg class="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                        <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 17h5l-1.405-1.405A2.032 2.032 0 0118 14.158V11a6.002 6.002 0 00-4-5.659V5a2 2 0 10-4 0v.341C7.67 6.165 6 8.388 6 11v3.159c0 .538-.214 1.055-.595 1.436L4 17h5m6 0v1a3 3 0 11-6 0v-1m6 0H9" />
+                    </svg>
+                    {move || unread.get().filter(|count| *count > 0).map(|count| view! {
+                        <span class="badge badge-primary badge-xs indicator-item">
+                            {if count > 99 { "99+".to_string() } else { count.to_string() }}
+                        </span>
+                    })}
+                </div>
+            </a>
+        </Show>
+    }
+}
@@ -0,0 +1,30 @@
+use leptos::prelude::*;
+
+use crate::app::OnCallInfo;
+use crate::i18n::use_i18n;
+
+/// Who is on call for a service, with a link to the schedule.
+#[component]
+pub fn OnCallCard(info: OnCallInfo) -> impl IntoView {
+    let i18n = use_i18n();
+    view! {
+        <div class="flex flex-wrap items-center gap-x-3 gap-y-1 rounded-box border border-base-300 px-4 py-2 text-sm mb-6 print:hidden">
+            <span class="font-semibold">{i18n.t("on_call.current")}</span>
+            {if info.contacts.is_empty() {
+                view! { <span class="text-base-content/60">{i18n.t("on_call.nobody")}</span> }.into_any()
+            } else {
+                info.contacts.into_iter().map(|contact| match contact.email {
+                    Some(email) => view! {
+                        <a href=format!("mailto:{email}") class="link link-hover">{contact.name}</a>
+                    }.into_any(),
+                    None => view! { <span>{contact.name}</span> }.into_any(),
+                }).collect::<Vec<_>>().into_any()
+            }}
+            {info.schedule_url.map(|url| view! {
+                <a href=url target="_blank" rel="noopener noreferrer" class="link link-hover text-base-content/60 ml-auto">
+                    {i18n.t("on_call.schedule")}
+                </a>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,48 @@
+use leptos::prelude::*;
+
+use crate::app::IsSessionResolved;
+
+/// Route guard for pages that need a signed-in user (editor, admin).
+///
+/// Renders `children` once the session is known and a user is signed in;
+/// anonymous visitors are sent to `/login?next=<this page>` instead of seeing
+/// a page whose actions would fail on save.
+#[component]
+pub fn RequireLogin(children: ChildrenFn) -> impl IntoView {
+    let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>()
+        .expect("RequireLogin must be inside App");
+    let is_resolved = use_context::<IsSessionResolved>()
+        .expect("RequireLogin must be inside App")
+        .0;
+
+    Effect::new(move |_| {
+        if is_resolved.get() && current_user.get().is_none() {
+            #[cfg(feature = "hydrate")]
+            {
+                use leptos::web_sys::window;
+                if let Some(w) = window() {
+                    let location = w.location();
+                    let current = format!(
+                        "{}{}",
+                        location.pathname().unwrap_or_default(),
+                        location.search().unwrap_or_default()
+                    );
+                    let _ = location.replace(&crate::auth::redirect::login_path(&current));
+                }
+            }
+        }
+    });
+
+    view! {
+        <Show
+            when=move || current_user.get().is_some()
+            fallback=|| view! {
+                <div class="flex justify-center py-16">
+                    <span class="loading loading-spinner loading-lg"></span>
+                </div>
+            }
+        >
+            {children()}
+        </Show>
+    }
+}
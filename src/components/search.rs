@@ -1,26 +1,79 @@
 use leptos::prelude::*;
 
+use super::focus::{restore_focus_on_close, trap_focus};
 use crate::app::search_docs;
 use crate::auth::refresh_client::with_auth_retry;
+use crate::i18n::use_i18n;
+use crate::schema::component::search_schema_operations;
+use crate::search::client::SearchHit;
+use crate::search::query::{parse_query, ParsedQuery, ResultType};
+
+/// Whether `parsed` is worth searching documents for: at least two
+/// characters of text or a filter on documents, and not `type:schema`.
+fn searches_documents(parsed: &ParsedQuery) -> bool {
+    parsed.filters.result_type != Some(ResultType::Schema)
+        && (parsed.text.len() >= 2 || parsed.filters.restricts_documents())
+}
+
+/// Search documents for `parsed` (see [`searches_documents`]).
+async fn search_parsed(parsed: ParsedQuery) -> Result<Vec<SearchHit>, ServerFnError> {
+    if !searches_documents(&parsed) {
+        return Ok(vec![]);
+    }
+    with_auth_retry(|| search_docs(parsed.text.clone(), None, parsed.filters.clone())).await
+}
+
+/// Report failed searches as a toast that can retry them.
+fn toast_search_errors(search_resource: LocalResource<Result<Vec<SearchHit>, ServerFnError>>) {
+    let toasts = crate::components::use_toasts();
+    let i18n = use_i18n();
+    Effect::new(move |_| {
+        if let Some(Err(e)) = search_resource.get() {
+            toasts.error_with_retry(
+                i18n.tf("search.error", &[("error", &e.to_string())]),
+                Callback::new(move |_| search_resource.refetch()),
+            );
+        }
+    });
+}
 
 /// Global search modal triggered by Ctrl+K (or Cmd+K on Mac).
 #[component]
 pub fn SearchModal(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) -> impl IntoView {
     let (query, set_query) = signal(String::new());
+    let i18n = use_i18n();
+    let parsed = Memo::new(move |_| parse_query(&query.get()));
+    // Nothing to show until there is text or a filter
+    let is_blank = move || parsed.with(|p| p.text.len() < 2 && p.filters.labels().is_empty());
 
-    let search_resource = LocalResource::new(move || {
-        let q = query.get();
+    let search_resource = LocalResource::new(move || search_parsed(parsed.get()));
+    let operations_resource = LocalResource::new(move || {
+        let ParsedQuery { text, filters } = parsed.get();
         async move {
-            if q.len() < 2 {
+            if text.len() < 2 || filters.result_type == Some(ResultType::Doc) {
                 return Ok(vec![]);
             }
-            with_auth_retry(|| search_docs(q.clone())).await
+            with_auth_retry(|| search_schema_operations(text.clone())).await
         }
     });
 
+    toast_search_errors(search_resource);
+
+    let dialog_ref = NodeRef::<leptos::html::Div>::new();
+    let input_ref = NodeRef::<leptos::html::Input>::new();
+    // Focus the input whenever the modal opens, and the opener once it closes
+    Effect::new(move |_| {
+        if let Some(input) = input_ref.get() {
+            let _ = input.focus();
+        }
+    });
+    restore_focus_on_close(move || is_open.get());
+
     let on_keydown = move |ev: leptos::web_sys::KeyboardEvent| {
         if ev.key() == "Escape" {
             set_is_open.set(false);
+        } else if let Some(dialog) = dialog_ref.get() {
+            trap_focus(&ev, &dialog);
         }
     };
 
@@ -31,32 +84,49 @@ pub fn SearchModal(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) ->
                 on:click=move |_| set_is_open.set(false)
             >
                 <div
+                    node_ref=dialog_ref
+                    role="dialog"
+                    aria-modal="true"
+                    aria-label=i18n.t("search.button")
                     class="bg-base-100 rounded-lg shadow-2xl w-full max-w-2xl mx-4"
                     on:click=move |ev: leptos::web_sys::MouseEvent| ev.stop_propagation()
+                    on:keydown=on_keydown
                 >
                     // Search input
                     <div class="p-4 border-b border-base-200 bg-base-100/50 rounded-t-lg">
                         <div class="flex items-center gap-3">
-                            <svg class="w-6 h-6 text-primary" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                            <svg class="w-6 h-6 text-primary" fill="none" stroke="currentColor" viewBox="0 0 24 24" aria-hidden="true">
                                 <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z"></path>
                             </svg>
                             <input
-                                type="text"
-                                placeholder="Search documentation..."
+                                node_ref=input_ref
+                                type="search"
+                                aria-label=i18n.t("search.button")
+                                aria-controls="search-modal-results"
+                                placeholder=i18n.t("search.button")
                                 class="w-full bg-transparent focus:outline-none text-xl placeholder:text-base-content/30"
                                 prop:value=query
                                 on:input=move |ev| {
                                     set_query.set(event_target_value(&ev));
                                 }
-                                on:keydown=on_keydown
                                 autofocus
                             />
-                            <kbd class="kbd kbd-sm bg-base-200 border-none shadow-sm text-xs font-semibold">"ESC"</kbd>
+                            <kbd aria-hidden="true" class="kbd kbd-sm bg-base-200 border-none shadow-sm text-xs font-semibold">"ESC"</kbd>
                         </div>
+                        {move || {
+                            let labels = parsed.with(|p| p.filters.labels());
+                            (!labels.is_empty()).then(|| view! {
+                                <div class="flex flex-wrap gap-2 mt-3 ml-9" aria-label=i18n.t("search.active_filters")>
+                                    {labels.into_iter().map(|label| view! {
+                                        <span class="badge badge-primary badge-outline font-mono">{label}</span>
+                                    }).collect::<Vec<_>>()}
+                                </div>
+                            })
+                        }}
                     </div>
 
                     // Results area
-                    <div class="max-h-96 overflow-y-auto">
+                    <div id="search-modal-results" class="max-h-96 overflow-y-auto" aria-live="polite">
                         <Suspense fallback=move || view! {
                             <div class="flex justify-center p-8">
                                 <span class="loading loading-spinner loading-lg"></span>
@@ -64,19 +134,22 @@ pub fn SearchModal(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) ->
                         }>
                             {move || {
                                 let q = query.get();
-                                if q.len() < 2 {
+                                if is_blank() {
                                     return Some(view! {
                                         <div class="p-8 text-center text-base-content/50">
-                                            "Type at least 2 characters to search..."
+                                            {i18n.t("search.min_chars")}
                                         </div>
                                     }.into_any());
                                 }
+                                if !parsed.with(searches_documents) {
+                                    return None;
+                                }
 
                                 search_resource.get().map(|result| match result {
                                     Ok(hits) if hits.is_empty() => {
                                         view! {
                                             <div class="p-8 text-center text-base-content/50">
-                                                "No results found for \"" {q.clone()} "\""
+                                                {i18n.tf("search.no_results", &[("query", &q)])}
                                             </div>
                                         }.into_any()
                                     }
@@ -89,6 +162,9 @@ pub fn SearchModal(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) ->
                                                     let preview = hit.content_preview.clone();
                                                     let tags = hit.tags.clone();
                                                     let has_tags = !tags.is_empty();
+                                                    let deprecated = (!hit.deprecated_schemas.is_empty())
+                                                        .then(|| hit.deprecated_schemas.join(", "));
+                                                    let superseded = hit.superseded;
 
                                                     view! {
                                                         <a
@@ -96,7 +172,19 @@ pub fn SearchModal(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) ->
                                                             class="block p-4 hover:bg-base-200 transition-colors"
                                                             on:click=move |_| set_is_open.set(false)
                                                         >
-                                                            <div class="font-semibold text-lg mb-1">{title}</div>
+                                                            <div class="font-semibold text-lg mb-1 flex items-center gap-2">
+                                                                {title}
+                                                                {deprecated.map(|schemas| view! {
+                                                                    <span class="badge badge-warning badge-sm font-normal" title=schemas>
+                                                                        {i18n.t("search.deprecated_schemas")}
+                                                                    </span>
+                                                                })}
+                                                                {superseded.then(|| view! {
+                                                                    <span class="badge badge-ghost badge-sm font-normal">
+                                                                        {i18n.t("search.superseded")}
+                                                                    </span>
+                                                                })}
+                                                            </div>
                                                             <div class="text-sm text-base-content/70 mb-2">{preview}</div>
                                                             <Show when=move || has_tags>
                                                                 <div class="flex gap-2 flex-wrap">
@@ -114,24 +202,58 @@ pub fn SearchModal(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) ->
                                             </div>
                                         }.into_any()
                                     }
-                                    Err(e) => {
+                                    Err(_) => {
                                         view! {
-                                            <div class="p-8 text-center text-error">
-                                                "Search error: " {e.to_string()}
+                                            <div class="p-8 text-center text-base-content/50">
+                                                {i18n.t("search.failed")}
                                             </div>
                                         }.into_any()
                                     }
                                 })
                             }}
                         </Suspense>
+                        <Suspense fallback=|| ()>
+                            {move || {
+                                if is_blank() {
+                                    return None;
+                                }
+                                operations_resource
+                                    .get()
+                                    .and_then(|result| result.ok())
+                                    .filter(|hits| !hits.is_empty())
+                                    .map(|hits| view! {
+                                    <div class="border-t border-base-300">
+                                        <div class="px-4 pt-3 text-xs font-semibold uppercase tracking-wider text-base-content/50">
+                                            {i18n.t("search.operations")}
+                                        </div>
+                                        {hits.into_iter().map(|hit| {
+                                            let href = hit.href();
+                                            view! {
+                                                <a
+                                                    href=href
+                                                    class="flex items-center gap-3 px-4 py-2 hover:bg-base-200 transition-colors"
+                                                    on:click=move |_| set_is_open.set(false)
+                                                >
+                                                    <span class="badge badge-sm badge-outline font-mono">{hit.method}</span>
+                                                    <span class="font-mono text-sm truncate">{hit.path}</span>
+                                                    <span class="text-sm text-base-content/60 truncate">{hit.summary.unwrap_or_default()}</span>
+                                                    <span class="ml-auto text-xs text-base-content/50 whitespace-nowrap">{format!("{} {}", hit.schema, hit.version)}</span>
+                                                </a>
+                                            }
+                                        }).collect::<Vec<_>>()}
+                                    </div>
+                                })
+                            }}
+                        </Suspense>
                     </div>
 
                     // Footer with keyboard hints
                     <div class="p-3 border-t border-base-300 bg-base-200/50 rounded-b-lg">
                         <div class="flex items-center justify-between text-xs text-base-content/50">
                             <div class="flex items-center gap-4">
-                                <span>"Press ESC to close"</span>
+                                <span>{i18n.t("search.close_hint")}</span>
                             </div>
+                            <span class="font-mono">{i18n.t("search.filters_hint")}</span>
                         </div>
                     </div>
                 </div>
@@ -144,24 +266,19 @@ pub fn SearchModal(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) ->
 #[component]
 pub fn SearchBar() -> impl IntoView {
     let (query, set_query) = signal(String::new());
+    let i18n = use_i18n();
     let (show_results, set_show_results) = signal(false);
 
-    let search_resource = LocalResource::new(move || {
-        let q = query.get();
-        async move {
-            if q.len() < 2 {
-                return Ok(vec![]);
-            }
-            with_auth_retry(|| search_docs(q.clone())).await
-        }
-    });
+    let search_resource = LocalResource::new(move || search_parsed(parse_query(&query.get())));
+    toast_search_errors(search_resource);
 
     view! {
         <div class="dropdown dropdown-end">
             <div class="form-control">
                 <input
-                    type="text"
-                    placeholder="Search docs..."
+                    type="search"
+                    aria-label=i18n.t("search.placeholder_short")
+                    placeholder=i18n.t("search.placeholder_short")
                     class="input input-bordered w-24 md:w-64"
                     prop:value=query
                     on:input=move |ev| {
@@ -183,7 +300,7 @@ pub fn SearchBar() -> impl IntoView {
                             search_resource.get().map(|result| match result {
                                 Ok(hits) if hits.is_empty() => {
                                     view! {
-                                        <li class="text-base-content/50 p-2">"No results found"</li>
+                                        <li class="text-base-content/50 p-2">{move || i18n.t("search.empty")}</li>
                                     }.into_any()
                                 }
                                 Ok(hits) => {
@@ -205,7 +322,7 @@ pub fn SearchBar() -> impl IntoView {
                                 }
                                 Err(_) => {
                                     view! {
-                                        <li class="text-error p-2">"Search error"</li>
+                                        <li class="text-base-content/50 p-2">{i18n.t("search.failed")}</li>
                                     }.into_any()
                                 }
                             })
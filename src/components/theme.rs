@@ -1,11 +1,14 @@
 use leptos::prelude::*;
 
+use crate::i18n::use_i18n;
+
 /// Theme toggle component — cycles through system/light/dark themes.
 ///
 /// Persists choice in localStorage and applies it to the `<html>` element's `data-theme`.
 /// Uses three states: "system" (follows OS preference), "light", and "dark".
 #[component]
 pub fn ThemeToggle() -> impl IntoView {
+    let i18n = use_i18n();
     let (theme, set_theme) = signal("system".to_string());
 
     #[cfg(feature = "hydrate")]
@@ -49,9 +52,9 @@ pub fn ThemeToggle() -> impl IntoView {
     view! {
         <div class="tooltip tooltip-bottom" data-tip=move || {
             match theme.get().as_str() {
-                "light" => "Light mode (click for dark)",
-                "dark" => "Dark mode (click for system)",
-                _ => "System theme (click for light)",
+                "light" => i18n.t("theme.light"),
+                "dark" => i18n.t("theme.dark"),
+                _ => i18n.t("theme.system"),
             }
         }>
             <button
@@ -0,0 +1,219 @@
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+
+/// Toasts shown at once; older ones are dropped first.
+const MAX_TOASTS: usize = 4;
+/// How long success toasts stay visible. Errors stay until dismissed.
+#[cfg(feature = "hydrate")]
+const SUCCESS_TIMEOUT_SECS: u64 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Error,
+}
+
+#[derive(Clone)]
+pub struct Toast {
+    pub id: u64,
+    pub kind: ToastKind,
+    pub message: String,
+    /// Offered as a "Retry" button; runs after the toast is dismissed.
+    pub retry: Option<Callback<()>>,
+}
+
+/// Application-wide toast queue, provided by `App` and rendered by
+/// [`ToastHost`]. Used for the outcome of saves and admin actions and for
+/// failed requests, instead of inline status text.
+#[derive(Clone, Copy)]
+pub struct Toasts {
+    items: RwSignal<Vec<Toast>>,
+    next_id: StoredValue<u64>,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self {
+            items: RwSignal::new(Vec::new()),
+            next_id: StoredValue::new(0),
+        }
+    }
+
+    pub fn success(&self, message: impl Into<String>) {
+        self.push(ToastKind::Success, message.into(), None);
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        self.push(ToastKind::Error, message.into(), None);
+    }
+
+    /// An error toast whose "Retry" button runs `retry`.
+    pub fn error_with_retry(&self, message: impl Into<String>, retry: Callback<()>) {
+        self.push(ToastKind::Error, message.into(), Some(retry));
+    }
+
+    pub fn dismiss(&self, id: u64) {
+        self.items.update(|items| items.retain(|t| t.id != id));
+    }
+
+    fn push(&self, kind: ToastKind, message: String, retry: Option<Callback<()>>) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        self.items.update(|items| {
+            push_capped(
+                items,
+                Toast {
+                    id,
+                    kind,
+                    message,
+                    retry,
+                },
+            )
+        });
+
+        #[cfg(feature = "hydrate")]
+        if kind == ToastKind::Success {
+            let toasts = *self;
+            set_timeout(
+                move || toasts.dismiss(id),
+                std::time::Duration::from_secs(SUCCESS_TIMEOUT_SECS),
+            );
+        }
+    }
+}
+
+/// Add `toast`, replacing an identical visible one and dropping the oldest
+/// beyond [`MAX_TOASTS`].
+fn push_capped(items: &mut Vec<Toast>, toast: Toast) {
+    items.retain(|t| t.kind != toast.kind || t.message != toast.message);
+    items.push(toast);
+    if items.len() > MAX_TOASTS {
+        items.drain(..items.len() - MAX_TOASTS);
+    }
+}
+
+/// The [`Toasts`] provided by `App`.
+pub fn use_toasts() -> Toasts {
+    use_context::<Toasts>().expect("use_toasts must be called inside App")
+}
+
+/// Renders the toasts of [`use_toasts`] in the bottom-right corner.
+#[component]
+pub fn ToastHost() -> impl IntoView {
+    let toasts = use_toasts();
+    let i18n = use_i18n();
+
+    view! {
+        <div class="toast toast-end z-[300] print:hidden" role="status" aria-live="polite">
+            <For
+                each=move || toasts.items.get()
+                key=|toast| toast.id
+                children=move |toast| {
+                    let id = toast.id;
+                    let alert_class = match toast.kind {
+                        ToastKind::Success => "alert-success",
+                        ToastKind::Error => "alert-error",
+                    };
+                    view! {
+                        <div class=format!("alert {alert_class} shadow-lg max-w-md text-sm")>
+                            <span class="break-words">{toast.message}</span>
+                            <div class="flex gap-1">
+                                {toast.retry.map(|retry| view! {
+                                    <button
+                                        class="btn btn-sm"
+                                        on:click=move |_| {
+                                            toasts.dismiss(id);
+                                            retry.run(());
+                                        }
+                                    >
+                                        {i18n.t("toast.retry")}
+                                    </button>
+                                })}
+                                <button
+                                    class="btn btn-sm btn-ghost"
+                                    aria-label=i18n.t("toast.dismiss")
+                                    on:click=move |_| toasts.dismiss(id)
+                                >
+                                    "✕"
+                                </button>
+                            </div>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
+
+/// Catches errors raised while rendering `children` (views returning `Err`)
+/// and shows them with a reload button instead of a blank page.
+#[component]
+pub fn AppErrorBoundary(children: Children) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <ErrorBoundary fallback=move |errors| view! {
+            <div class="alert alert-error max-w-2xl mx-auto my-12 shadow-sm">
+                <div class="flex flex-col gap-2">
+                    <span class="font-semibold">{i18n.t("error.title")}</span>
+                    <ul class="text-sm list-disc list-inside">
+                        {move || errors.get()
+                            .into_iter()
+                            .map(|(_, e)| view! { <li>{e.to_string()}</li> })
+                            .collect::<Vec<_>>()}
+                    </ul>
+                </div>
+                <button
+                    class="btn btn-sm"
+                    on:click=move |_| {
+                        #[cfg(feature = "hydrate")]
+                        if let Some(window) = web_sys::window() {
+                            let _ = window.location().reload();
+                        }
+                    }
+                >
+                    {i18n.t("error.reload")}
+                </button>
+            </div>
+        }>
+            {children()}
+        </ErrorBoundary>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toast(id: u64, kind: ToastKind, message: &str) -> Toast {
+        Toast {
+            id,
+            kind,
+            message: message.to_string(),
+            retry: None,
+        }
+    }
+
+    #[test]
+    fn push_capped_deduplicates_and_drops_oldest() {
+        let mut items = Vec::new();
+        for id in 0..MAX_TOASTS as u64 {
+            push_capped(
+                &mut items,
+                toast(id, ToastKind::Success, &format!("saved {id}")),
+            );
+        }
+        push_capped(&mut items, toast(10, ToastKind::Error, "offline"));
+        push_capped(&mut items, toast(11, ToastKind::Error, "offline"));
+
+        let ids: Vec<u64> = items.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 11]);
+    }
+}
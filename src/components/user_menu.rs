@@ -1,16 +1,51 @@
 use leptos::prelude::*;
+use leptos_router::hooks::use_location;
 
-use crate::app::{logout_user, IsDemoMode};
+use super::theme::ThemeToggle;
+use crate::app::{get_own_access, logout_user};
+use crate::i18n::use_i18n;
 
-/// User menu in the navbar: shows login link for anonymous users, or a
-/// dropdown with the user's email and a logout button when authenticated.
+/// Up to two uppercase initials for the avatar: first letters of the first
+/// two words of `display`, or of the local part of an email.
+pub fn initials(display: &str) -> String {
+    let name = display.split('@').next().unwrap_or(display);
+    let words: Vec<&str> = name
+        .split(|c: char| c.is_whitespace() || matches!(c, '.' | '_' | '-'))
+        .filter(|w| !w.is_empty())
+        .collect();
+    let letters: String = match words.as_slice() {
+        [] => "?".to_string(),
+        [word] => word.chars().take(2).collect(),
+        [first, second, ..] => first
+            .chars()
+            .take(1)
+            .chain(second.chars().take(1))
+            .collect(),
+    };
+    letters.to_uppercase()
+}
+
+/// User menu in the navbar: shows a login link for anonymous users, or an
+/// avatar with the caller's access level and a dropdown (drafts, prompts,
+/// profile, notifications, theme, logout) once the session resolves to a user.
 #[component]
 pub fn UserMenu() -> impl IntoView {
     let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>()
         .expect("UserMenu must be inside App");
-    let is_demo_mode = use_context::<IsDemoMode>()
-        .expect("UserMenu must be inside App")
-        .0;
+    let i18n = use_i18n();
+    let location = use_location();
+
+    // Refetched whenever the signed-in user changes
+    let access = LocalResource::new(move || {
+        let signed_in = current_user.get().is_some();
+        async move {
+            if signed_in {
+                get_own_access().await.unwrap_or_default()
+            } else {
+                Default::default()
+            }
+        }
+    });
 
     let logout_action = Action::new(|_: &()| async move {
         let _ = logout_user().await;
@@ -28,28 +63,57 @@ pub fn UserMenu() -> impl IntoView {
             match current_user.get() {
                 Some(user) => {
                     let display = user.name.clone().unwrap_or_else(|| user.email.clone());
+                    let avatar = initials(&display);
                     let is_admin = user.is_admin;
+                    let level_badge = move || {
+                        if is_admin {
+                            return Some("Admin".to_string());
+                        }
+                        let levels = access.get()?.access_levels;
+                        let first = levels.first()?.clone();
+                        Some(match levels.len() {
+                            1 => first,
+                            n => format!("{first} +{}", n - 1),
+                        })
+                    };
                     view! {
                         <div class="dropdown dropdown-end">
                             <div tabindex="0" role="button" class="btn btn-ghost btn-sm gap-2 font-medium">
-                                // Icon on small screens, name on sm+
-                                <svg class="sm:hidden w-5 h-5" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><circle cx="12" cy="8" r="4"/><path d="M20 21a8 8 0 1 0-16 0"/></svg>
+                                <div class="avatar avatar-placeholder">
+                                    <div class="bg-primary text-primary-content w-7 rounded-full">
+                                        <span class="text-xs">{avatar}</span>
+                                    </div>
+                                </div>
                                 <span class="hidden sm:inline truncate max-w-[120px]">{display}</span>
-                                {if is_admin {
-                                    view! { <span class="hidden sm:inline badge badge-primary badge-xs">"Admin"</span> }.into_any()
-                                } else {
-                                    view! { <span /> }.into_any()
-                                }}
+                                {move || level_badge().map(|label| view! {
+                                    <span
+                                        class="hidden sm:inline badge badge-xs"
+                                        class:badge-primary=is_admin
+                                        class:badge-ghost=!is_admin
+                                    >
+                                        {label}
+                                    </span>
+                                })}
                             </div>
                             <ul tabindex="0" class="dropdown-content menu bg-base-100 rounded-box z-[1] w-52 p-2 shadow border border-base-200 mt-2">
                                 <li class="menu-title text-xs opacity-60 px-2 pb-1 truncate">{user.email.clone()}</li>
                                 <div class="divider my-1"></div>
+                                {move || access.get().filter(|a| a.can_read_draft).map(|_| view! {
+                                    <li>
+                                        <a href="/browse?drafts=mine">
+                                            <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z" />
+                                            </svg>
+                                            {i18n.t("user.my_drafts")}
+                                        </a>
+                                    </li>
+                                })}
                                 <li>
                                     <a href="/prompts">
                                         <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M9 12h6M9 16h6M9 8h6M5 6h.01M5 10h.01M5 14h.01M5 18h.01" />
                                         </svg>
-                                        "Prompt Library"
+                                        {i18n.t("user.prompts")}
                                     </a>
                                 </li>
                                 <li>
@@ -57,9 +121,24 @@ pub fn UserMenu() -> impl IntoView {
                                         <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 7a4 4 0 11-8 0 4 4 0 018 0zM21 21a9 9 0 10-18 0" />
                                         </svg>
-                                        "Profile & Tokens"
+                                        {i18n.t("user.profile")}
                                     </a>
                                 </li>
+                                <li>
+                                    <a href="/notifications">
+                                        <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                            <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 17h5l-1.405-1.405A2.032 2.032 0 0118 14.158V11a6.002 6.002 0 00-4-5.659V5a2 2 0 10-4 0v.341C7.67 6.165 6 8.388 6 11v3.159c0 .538-.214 1.055-.595 1.436L4 17h5m6 0v1a3 3 0 11-6 0v-1m6 0H9" />
+                                        </svg>
+                                        {i18n.t("user.notifications")}
+                                    </a>
+                                </li>
+                                <li>
+                                    <div class="flex items-center justify-between py-0 pr-1">
+                                        <span>{i18n.t("user.theme")}</span>
+                                        <ThemeToggle />
+                                    </div>
+                                </li>
+                                <div class="divider my-1"></div>
                                 <li>
                                     <button
                                         class="text-error"
@@ -68,7 +147,7 @@ pub fn UserMenu() -> impl IntoView {
                                         <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M17 16l4-4m0 0l-4-4m4 4H7m6 4v1a3 3 0 01-3 3H6a3 3 0 01-3-3V7a3 3 0 013-3h4a3 3 0 013 3v1" />
                                         </svg>
-                                        "Log Out"
+                                        {i18n.t("user.logout")}
                                     </button>
                                 </li>
                             </ul>
@@ -76,15 +155,13 @@ pub fn UserMenu() -> impl IntoView {
                     }.into_any()
                 }
                 None => {
-                    // In demo mode, link to the in-app login form.
-                    // In OAuth mode, link directly to the auth redirect endpoint.
-                    let is_demo = is_demo_mode.get();
-                    let href = if is_demo { "/login" } else { "/auth/login" };
-                    let rel = if is_demo { "" } else { "external" };
+                    // The login page shows the demo or local form, or starts
+                    // the OAuth flow, and returns to this page afterwards.
+                    let href = crate::auth::redirect::login_path(&location.pathname.get());
                     view! {
-                        <a href=href rel=rel class="btn btn-ghost btn-sm font-medium whitespace-nowrap">
+                        <a href=href class="btn btn-ghost btn-sm font-medium whitespace-nowrap">
                             <svg class="sm:hidden w-5 h-5" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><circle cx="12" cy="8" r="4"/><path d="M20 21a8 8 0 1 0-16 0"/></svg>
-                            <span class="hidden sm:inline">"Log In"</span>
+                            <span class="hidden sm:inline">{i18n.t("user.login")}</span>
                         </a>
                     }.into_any()
                 }
@@ -92,3 +169,16 @@ pub fn UserMenu() -> impl IntoView {
         }}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::initials;
+
+    #[test]
+    fn initials_from_names_and_emails() {
+        assert_eq!(initials("Ada Lovelace"), "AL");
+        assert_eq!(initials("grace"), "GR");
+        assert_eq!(initials("jane.doe@example.com"), "JD");
+        assert_eq!(initials(""), "?");
+    }
+}
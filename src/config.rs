@@ -12,6 +12,9 @@
 //! crate using its standard credential chain.
 //!
 //! `LEPTOS_SITE_ADDR` is also excluded: it is managed by `cargo-leptos`.
+//!
+//! Variables from a `.env` file in the working directory are loaded at
+//! startup (see [`load_dotenv`]); variables already set take precedence.
 
 use std::collections::HashMap;
 
@@ -25,9 +28,39 @@ pub struct AppConfig {
     pub database: DatabaseConfig,
     pub storage: StorageConfig,
     pub search: SearchConfig,
+    pub resilience: ResilienceConfig,
+    pub telemetry: TelemetryConfig,
     pub auth: AuthConfig,
     pub mcp: McpConfig,
     pub rag: RagConfig,
+    #[serde(default)]
+    pub integrations: IntegrationsConfig,
+    #[serde(default)]
+    pub rendering: RenderingConfig,
+    #[serde(default)]
+    pub quotas: QuotaConfig,
+    #[serde(default)]
+    pub taxonomy: TaxonomyConfig,
+    #[serde(default)]
+    pub lint: LintConfig,
+    #[serde(default)]
+    pub spellcheck: SpellcheckConfig,
+    #[serde(default)]
+    pub coordination: CoordinationConfig,
+    #[serde(default)]
+    pub scheduler: SchedulerConfig,
+    #[serde(default)]
+    pub email: EmailConfig,
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+    #[serde(default)]
+    pub scanner: ScannerConfig,
+    #[serde(default)]
+    pub secrets: SecretsConfig,
+    #[serde(default)]
+    pub share_links: ShareLinksConfig,
+    #[serde(default)]
+    pub embed: EmbedConfig,
 }
 
 // ── Server ────────────────────────────────────────────────────────────────────
@@ -44,6 +77,13 @@ pub struct ServerConfig {
     pub log_filter: String,
     /// Maximum attachment size in megabytes.
     pub max_attachment_size_mb: u64,
+    /// Where the schema viewer loads Scalar and AsyncAPI React from: empty for
+    /// the copies bundled under `site_root/js`, a URL prefix for a mirror, or
+    /// `"none"` to always use the built-in viewer.
+    #[serde(default)]
+    pub spec_viewer_assets: String,
+    /// Compress responses with brotli or gzip when the client accepts it.
+    pub compression: bool,
 }
 
 // ── Database ──────────────────────────────────────────────────────────────────
@@ -58,6 +98,40 @@ pub struct DatabaseConfig {
     pub username: Option<String>,
     /// Optional password — injected into the URI when set.
     pub password: Option<String>,
+    /// Follow the `documents` and `schemas` collections through change
+    /// streams, so writes that bypass the API reach search and live
+    /// subscribers (see [`crate::db::change_streams`]). Needs a replica set.
+    #[serde(default)]
+    pub watch_changes: bool,
+    /// How long to collect changes before handling them, in milliseconds.
+    #[serde(default = "default_change_debounce_ms")]
+    pub change_debounce_ms: u64,
+}
+
+fn default_change_debounce_ms() -> u64 {
+    2000
+}
+
+#[cfg(feature = "ssr")]
+impl DatabaseConfig {
+    /// Connection URI with `username` and `password` injected, when set.
+    /// Uses the `url` crate for correct percent-encoding and authority handling.
+    pub fn connection_uri(&self) -> String {
+        match (&self.username, &self.password) {
+            (Some(user), Some(pass)) if !user.is_empty() => match url::Url::parse(&self.uri) {
+                Ok(mut parsed) => {
+                    let _ = parsed.set_username(user);
+                    let _ = parsed.set_password(Some(pass));
+                    parsed.to_string()
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Could not parse MongoDB URI to inject credentials; using URI as-is");
+                    self.uri.clone()
+                }
+            },
+            _ => self.uri.clone(),
+        }
+    }
 }
 
 // ── Storage ───────────────────────────────────────────────────────────────────
@@ -68,6 +142,85 @@ pub struct StorageConfig {
     pub bucket: String,
     /// Custom S3 endpoint for MinIO / Garage / LocalStack.
     pub endpoint: Option<String>,
+    /// Prepended to every object key, e.g. `lekton/` to share a bucket.
+    #[serde(default)]
+    pub key_prefix: String,
+    /// How document and prompt slugs map to object keys.
+    #[serde(default)]
+    pub key_layout: KeyLayout,
+    /// Where schema specs are stored, when not with the documents.
+    #[serde(default)]
+    pub schemas: BucketOverride,
+    /// Where attachments, images and quarantined uploads are stored, when
+    /// not with the documents (e.g. a bucket with an expiry policy).
+    #[serde(default)]
+    pub uploads: BucketOverride,
+    /// Server-side encryption requested for every object written.
+    #[serde(default)]
+    pub encryption: EncryptionConfig,
+}
+
+/// Server-side encryption of stored objects (`[storage.encryption]`).
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EncryptionConfig {
+    #[serde(default)]
+    pub mode: EncryptionMode,
+    /// KMS key (id, ARN or alias) for `mode = "kms"`; the bucket's default
+    /// key when unset.
+    #[serde(default)]
+    pub kms_key_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptionMode {
+    /// The bucket's default encryption applies.
+    #[default]
+    None,
+    /// SSE-S3: keys managed by the storage service (`AES256`).
+    S3,
+    /// SSE-KMS: keys managed by AWS KMS (`aws:kms`).
+    Kms,
+}
+
+/// Bucket and key prefix of one content class; unset values are those of
+/// `[storage]`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BucketOverride {
+    pub bucket: Option<String>,
+    pub key_prefix: Option<String>,
+}
+
+impl StorageConfig {
+    /// Bucket and key prefix where objects of `class` are stored.
+    pub fn location(&self, class: crate::storage::client::ContentClass) -> (&str, &str) {
+        use crate::storage::client::ContentClass;
+
+        let over = match class {
+            ContentClass::Documents => return (&self.bucket, &self.key_prefix),
+            ContentClass::Schemas => &self.schemas,
+            ContentClass::Uploads => &self.uploads,
+        };
+        (
+            over.bucket.as_deref().unwrap_or(&self.bucket),
+            over.key_prefix.as_deref().unwrap_or(&self.key_prefix),
+        )
+    }
+}
+
+/// Object key scheme of documents and prompts; see [`crate::storage::keys`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyLayout {
+    /// Slug segments kept as key segments: `docs/guides/setup.md`.
+    Path,
+    /// Slug flattened with a hash suffix: `docs/guides_setup-1f3a9c0e5b7d2468.md`.
+    Hashed,
+    /// Slug flattened with `/` → `_`: `docs/guides_setup.md`. Used before
+    /// layouts were configurable, and still the default so existing
+    /// buckets keep working; `a/b_c` and `a_b/c` share a key.
+    #[default]
+    Flat,
 }
 
 // ── Search ────────────────────────────────────────────────────────────────────
@@ -78,16 +231,98 @@ pub struct SearchConfig {
     pub url: String,
     /// Meilisearch API key. Optional.
     pub api_key: String,
+    /// Compare the index with MongoDB at startup and, in the background,
+    /// index missing or stale documents and remove orphaned entries.
+    pub reconcile_on_startup: bool,
+}
+
+// ── Resilience ───────────────────────────────────────────────────────────────
+
+/// Retry, timeout and circuit-breaker policy applied to downstream calls
+/// (S3, Meilisearch and the core MongoDB repositories). MongoDB also takes
+/// `timeout_ms` as its server selection and connection timeout.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResilienceConfig {
+    /// Timeout of a single attempt, in milliseconds.
+    pub timeout_ms: u64,
+    /// Retries after a failed attempt (timeouts and server errors only).
+    pub max_retries: u32,
+    /// Base of the exponential backoff between retries, in milliseconds.
+    /// Each delay is drawn at random up to `base * 2^attempt` (full jitter).
+    pub retry_base_delay_ms: u64,
+    /// Consecutive failed attempts that open a service's circuit.
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects calls before letting a trial call through.
+    pub open_duration_secs: u64,
+}
+
+// ── Telemetry ────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct TelemetryConfig {
+    /// OTLP/HTTP traces endpoint (e.g. `http://otel-collector:4318/v1/traces`).
+    /// Empty disables export. Requires a build with the `otel` feature.
+    pub otlp_endpoint: String,
+    /// `service.name` resource attribute of exported spans.
+    pub service_name: String,
+}
+
+// ── Coordination ─────────────────────────────────────────────────────────────
+
+/// Coordination between replicas. Without `redis_url` each replica works alone.
+#[derive(Debug, Deserialize)]
+pub struct CoordinationConfig {
+    /// Redis URL (e.g. `redis://redis:6379`). Empty disables coordination.
+    /// Requires a build with the `redis` feature.
+    #[serde(default)]
+    pub redis_url: String,
+    /// Prefix of the Redis channel and keys, so several portals can share a server.
+    #[serde(default = "default_coordination_key_prefix")]
+    pub key_prefix: String,
+}
+
+impl Default for CoordinationConfig {
+    fn default() -> Self {
+        Self {
+            redis_url: String::new(),
+            key_prefix: default_coordination_key_prefix(),
+        }
+    }
+}
+
+fn default_coordination_key_prefix() -> String {
+    "lekton".to_string()
 }
 
 // ── Auth ──────────────────────────────────────────────────────────────────────
 
+/// `SameSite` attribute of a session cookie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CookieSameSite {
+    Strict,
+    Lax,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AuthConfig {
     /// Enable built-in demo authentication (not for production).
     pub demo_mode: bool,
     /// Allow demo mode even when a JWT secret is present.
     pub allow_demo_in_production: bool,
+    /// Lifetime of a demo session in seconds. Sessions used after half of
+    /// their lifetime are renewed (sliding expiry).
+    pub demo_session_ttl_secs: u64,
+    /// `SameSite` attribute of the demo session cookies.
+    pub demo_session_same_site: CookieSameSite,
+    /// Enable local accounts: users with Argon2-hashed passwords managed by
+    /// administrators, signing in without an external identity provider.
+    pub local_accounts: bool,
+    /// Email of the administrator created on startup when local accounts are
+    /// enabled and no local account with this email exists.
+    pub local_admin_email: Option<String>,
+    /// Initial password of that administrator (must be changed on first login).
+    pub local_admin_password: Option<String>,
     /// API service token for ingestion endpoints. Required in production.
     pub service_token: Option<String>,
     /// HMAC secret for JWT signing. Required when `demo_mode = false`.
@@ -122,6 +357,95 @@ pub struct AuthConfig {
     pub userinfo_email_field: Option<String>,
     /// Comma-separated dot-notation paths to name field(s) in the userinfo response.
     pub userinfo_name_field: Option<String>,
+    /// LDAP / Active Directory sign-in.
+    #[serde(default)]
+    pub ldap: LdapConfig,
+}
+
+/// LDAP bind authentication (`[auth.ldap]`, env `LKN__AUTH__LDAP__*`).
+///
+/// Users are looked up with the service account, then signed in by binding
+/// as themselves. Group to access level mappings are settings, edited under
+/// **Admin → LDAP**.
+#[derive(Debug, Deserialize, Clone)]
+pub struct LdapConfig {
+    /// `ldap://` or `ldaps://` URL of the directory. Empty disables LDAP
+    /// sign-in.
+    #[serde(default)]
+    pub url: String,
+    /// Upgrade `ldap://` connections with StartTLS.
+    #[serde(default)]
+    pub starttls: bool,
+    /// DN of the service account searching for users; empty to search
+    /// anonymously.
+    #[serde(default)]
+    pub bind_dn: String,
+    /// Password of the service account.
+    #[serde(default)]
+    pub bind_password: String,
+    /// Base DN under which users are searched.
+    #[serde(default)]
+    pub user_base_dn: String,
+    /// Filter finding a user, `{username}` standing for the escaped login
+    /// name. Active Directory: `(sAMAccountName={username})`.
+    #[serde(default = "default_ldap_user_filter")]
+    pub user_filter: String,
+    /// Attribute holding the user's email.
+    #[serde(default = "default_ldap_email_attribute")]
+    pub email_attribute: String,
+    /// Attribute holding the user's display name.
+    #[serde(default = "default_ldap_name_attribute")]
+    pub name_attribute: String,
+    /// Attribute listing the DNs of the user's groups.
+    #[serde(default = "default_ldap_group_attribute")]
+    pub group_attribute: String,
+    /// Connection and operation timeout.
+    #[serde(default = "default_ldap_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Default for LdapConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            starttls: false,
+            bind_dn: String::new(),
+            bind_password: String::new(),
+            user_base_dn: String::new(),
+            user_filter: default_ldap_user_filter(),
+            email_attribute: default_ldap_email_attribute(),
+            name_attribute: default_ldap_name_attribute(),
+            group_attribute: default_ldap_group_attribute(),
+            timeout_secs: default_ldap_timeout_secs(),
+        }
+    }
+}
+
+impl LdapConfig {
+    /// Returns `true` when a directory URL is set.
+    pub fn is_enabled(&self) -> bool {
+        !self.url.is_empty()
+    }
+}
+
+fn default_ldap_user_filter() -> String {
+    "(uid={username})".into()
+}
+
+fn default_ldap_email_attribute() -> String {
+    "mail".into()
+}
+
+fn default_ldap_name_attribute() -> String {
+    "displayName".into()
+}
+
+fn default_ldap_group_attribute() -> String {
+    "memberOf".into()
+}
+
+fn default_ldap_timeout_secs() -> u64 {
+    10
 }
 
 // ── MCP ─────────────────────────────────────────────────────────────────────
@@ -158,6 +482,869 @@ fn default_mcp_allowed_hosts() -> Vec<String> {
     vec!["localhost".into(), "127.0.0.1".into(), "::1".into()]
 }
 
+// ── Rendering ────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct RenderingConfig {
+    /// Bare links expanded into embedded widgets.
+    #[serde(default)]
+    pub embeds: EmbedsConfig,
+    /// Documents larger than this many bytes are rendered as a truncated
+    /// preview with a link to the raw file. `0` renders everything.
+    #[serde(default = "default_max_render_bytes")]
+    pub max_render_bytes: usize,
+}
+
+impl Default for RenderingConfig {
+    fn default() -> Self {
+        Self {
+            embeds: EmbedsConfig::default(),
+            max_render_bytes: default_max_render_bytes(),
+        }
+    }
+}
+
+fn default_max_render_bytes() -> usize {
+    crate::rendering::limits::DEFAULT_MAX_RENDER_BYTES
+}
+
+/// Initial embed settings, stored in the settings on first start; admins
+/// edit them afterwards (see [`crate::server::embeds`]).
+///
+/// ```toml
+/// [rendering.embeds]
+/// providers = ["youtube", "figma", "miro", "grafana"]
+/// grafana_hosts = ["grafana.internal.acme.com"]
+/// ```
+#[derive(Debug, Deserialize, Default)]
+pub struct EmbedsConfig {
+    /// Enabled providers: `youtube`, `figma`, `miro`, `grafana`. Empty disables embeds.
+    #[serde(default)]
+    pub providers: Vec<String>,
+    /// Hosts (optionally `host:port`) whose Grafana snapshots are embedded.
+    #[serde(default)]
+    pub grafana_hosts: Vec<String>,
+}
+
+// ── Quotas ───────────────────────────────────────────────────────────────────
+
+/// Per-`service_owner` limits enforced at ingest time.
+///
+/// Via env: `LKN__QUOTAS__DEFAULT__MAX_DOCUMENTS`. Overrides for single owners
+/// are easier to declare in `config/lekton.toml`:
+///
+/// ```toml
+/// [quotas.owners.payments-team]
+/// max_documents = 2000
+/// max_storage_mb = 500
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct QuotaConfig {
+    /// Limits applied to owners without an override.
+    #[serde(default)]
+    pub default: QuotaLimits,
+    /// Overrides keyed by service owner (matched case-insensitively).
+    #[serde(default)]
+    pub owners: HashMap<String, QuotaLimits>,
+    /// Daily limits on attachment and image uploads.
+    #[serde(default)]
+    pub uploads: UploadQuotaConfig,
+}
+
+/// Megabytes each uploader may upload per UTC day, enforced on attachment,
+/// editor and image uploads. Uploaders are identified by user email or
+/// service token name.
+///
+/// ```toml
+/// [quotas.uploads]
+/// daily_mb = 200
+///
+/// [quotas.uploads.uploaders]
+/// "docs-ci" = 2000
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct UploadQuotaConfig {
+    /// Limit of uploaders without an override. `0` means unlimited.
+    #[serde(default)]
+    pub daily_mb: u64,
+    /// Overrides keyed by uploader (matched case-insensitively).
+    #[serde(default)]
+    pub uploaders: HashMap<String, u64>,
+}
+
+/// Limits of one service owner. `0` means unlimited.
+#[derive(Debug, Clone, Copy, Deserialize, Default, PartialEq, Eq)]
+pub struct QuotaLimits {
+    /// Maximum number of active (non-archived) documents.
+    #[serde(default)]
+    pub max_documents: u64,
+    /// Maximum total Markdown content size of active documents, in megabytes.
+    #[serde(default)]
+    pub max_storage_mb: u64,
+}
+
+impl QuotaConfig {
+    /// Limits applying to `service_owner`.
+    pub fn limits_for(&self, service_owner: &str) -> QuotaLimits {
+        self.owners
+            .iter()
+            .find(|(owner, _)| owner.eq_ignore_ascii_case(service_owner))
+            .map(|(_, limits)| *limits)
+            .unwrap_or(self.default)
+    }
+}
+
+impl UploadQuotaConfig {
+    /// Daily upload limit of `uploader` in bytes, `None` when unlimited.
+    pub fn daily_limit_bytes(&self, uploader: &str) -> Option<u64> {
+        let mb = self
+            .uploaders
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(uploader))
+            .map(|(_, mb)| *mb)
+            .unwrap_or(self.daily_mb);
+        (mb > 0).then(|| mb * 1024 * 1024)
+    }
+}
+
+impl QuotaLimits {
+    /// Storage limit in bytes, `None` when unlimited.
+    pub fn max_storage_bytes(&self) -> Option<u64> {
+        (self.max_storage_mb > 0).then(|| self.max_storage_mb * 1024 * 1024)
+    }
+
+    /// Document limit, `None` when unlimited.
+    pub fn max_document_count(&self) -> Option<u64> {
+        (self.max_documents > 0).then_some(self.max_documents)
+    }
+}
+
+// ── Lint ─────────────────────────────────────────────────────────────────────
+
+/// Markdown style checks run on ingested documents (see
+/// [`crate::rendering::lint`]). Findings are reported, never enforced.
+///
+/// ```toml
+/// [lint]
+/// enabled = true
+/// max_line_length = 120
+/// rules = ["missing_h1", "heading_order", "line_length", "bare_url", "todo"]
+///
+/// [lint.projects.payments]
+/// max_line_length = 100
+/// rules = ["missing_h1", "heading_order"]
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct LintConfig {
+    /// Lint documents outside projects with an override.
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_lint_max_line_length")]
+    pub max_line_length: usize,
+    /// Rules to run; empty runs them all.
+    #[serde(default)]
+    pub rules: Vec<String>,
+    /// Overrides keyed by project name, for documents in its namespace.
+    #[serde(default)]
+    pub projects: HashMap<String, ProjectLintConfig>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_line_length: default_lint_max_line_length(),
+            rules: vec![],
+            projects: HashMap::new(),
+        }
+    }
+}
+
+fn default_lint_max_line_length() -> usize {
+    120
+}
+
+/// Lint settings of one project; unset fields fall back to `[lint]`.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ProjectLintConfig {
+    pub enabled: Option<bool>,
+    pub max_line_length: Option<usize>,
+    pub rules: Option<Vec<String>>,
+}
+
+impl LintConfig {
+    /// Checks to run on document `slug`, or `None` when linting is off for it.
+    pub fn options_for(&self, slug: &str) -> Option<crate::rendering::lint::LintOptions> {
+        use crate::rendering::lint::{LintOptions, LintRule};
+
+        let project = self
+            .projects
+            .get(crate::db::project_repository::project_namespace(slug));
+        if !project.and_then(|p| p.enabled).unwrap_or(self.enabled) {
+            return None;
+        }
+        let names = project
+            .and_then(|p| p.rules.as_ref())
+            .unwrap_or(&self.rules);
+        let rules = if names.is_empty() {
+            LintRule::ALL.to_vec()
+        } else {
+            names
+                .iter()
+                .filter_map(|name| LintRule::from_name(name))
+                .collect()
+        };
+        Some(LintOptions {
+            rules,
+            max_line_length: project
+                .and_then(|p| p.max_line_length)
+                .unwrap_or(self.max_line_length),
+        })
+    }
+
+    /// Configured rule names that match no rule.
+    pub fn unknown_rules(&self) -> Vec<&str> {
+        self.projects
+            .values()
+            .filter_map(|p| p.rules.as_ref())
+            .chain(std::iter::once(&self.rules))
+            .flatten()
+            .map(String::as_str)
+            .filter(|name| crate::rendering::lint::LintRule::from_name(name).is_none())
+            .collect()
+    }
+}
+
+// ── Spell check ──────────────────────────────────────────────────────────────
+
+/// Spell checking of documents against a Hunspell dictionary, plus the words
+/// admins add under **Admin → Dictionary** (see [`crate::spellcheck`]).
+///
+/// Via env: `LKN__SPELLCHECK__ENABLED`, `LKN__SPELLCHECK__DICTIONARY`,
+/// `LKN__SPELLCHECK__MAX_SUGGESTIONS`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpellcheckConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Path of the Hunspell dictionary without extension: `<path>.aff` and
+    /// `<path>.dic` are loaded.
+    #[serde(default = "default_spellcheck_dictionary")]
+    pub dictionary: String,
+    /// Suggestions returned per misspelled word.
+    #[serde(default = "default_spellcheck_max_suggestions")]
+    pub max_suggestions: usize,
+}
+
+impl Default for SpellcheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dictionary: default_spellcheck_dictionary(),
+            max_suggestions: default_spellcheck_max_suggestions(),
+        }
+    }
+}
+
+fn default_spellcheck_dictionary() -> String {
+    "/usr/share/hunspell/en_US".into()
+}
+
+fn default_spellcheck_max_suggestions() -> usize {
+    3
+}
+
+// ── Taxonomy ─────────────────────────────────────────────────────────────────
+
+/// Normalization of document tags and service owners, applied at ingest time
+/// so that spellings like `K8s`, `k8s` and `kubernetes` end up as one value.
+///
+/// ```toml
+/// [taxonomy.tags]
+/// lowercase = true
+/// kebab_case = true
+///
+/// [taxonomy.tags.synonyms]
+/// k8s = "kubernetes"
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct TaxonomyConfig {
+    #[serde(default)]
+    pub tags: NormalizationPolicy,
+    #[serde(default)]
+    pub owners: NormalizationPolicy,
+}
+
+/// How values of one kind are normalized. The default only trims them.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct NormalizationPolicy {
+    /// Lowercase values.
+    #[serde(default)]
+    pub lowercase: bool,
+    /// Lowercase values and join their words with `-` (`"Release Notes"` →
+    /// `"release-notes"`).
+    #[serde(default)]
+    pub kebab_case: bool,
+    /// Aliases mapped to their canonical value, compared after the case
+    /// rules above are applied to both.
+    #[serde(default)]
+    pub synonyms: HashMap<String, String>,
+    /// Accepted values after normalization. Empty accepts any value.
+    #[serde(default)]
+    pub allowed: Vec<String>,
+}
+
+impl NormalizationPolicy {
+    /// Apply the case rules only.
+    fn format(&self, value: &str) -> String {
+        let value = value.trim();
+        if self.kebab_case {
+            value
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(str::to_lowercase)
+                .collect::<Vec<_>>()
+                .join("-")
+        } else if self.lowercase {
+            value.to_lowercase()
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// Normalized form of `value`, or `Err` with it when the allow-list
+    /// rejects it.
+    pub fn normalize(&self, value: &str) -> Result<String, String> {
+        let formatted = self.format(value);
+        let normalized = self
+            .synonyms
+            .iter()
+            .find(|(alias, _)| self.format(alias) == formatted)
+            .map(|(_, canonical)| self.format(canonical))
+            .unwrap_or(formatted);
+        if self.allowed.is_empty() || self.allowed.iter().any(|a| self.format(a) == normalized) {
+            Ok(normalized)
+        } else {
+            Err(normalized)
+        }
+    }
+}
+
+impl TaxonomyConfig {
+    /// Normalized tags, without blanks and duplicates, in their original
+    /// order. `Err` carries the first tag the allow-list rejects.
+    pub fn normalize_tags(&self, tags: &[String]) -> Result<Vec<String>, String> {
+        let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            let tag = self.tags.normalize(tag)?;
+            if !tag.is_empty() && !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+        Ok(normalized)
+    }
+
+    /// Normalized service owner, or `Err` with it when the allow-list
+    /// rejects it.
+    pub fn normalize_owner(&self, owner: &str) -> Result<String, String> {
+        self.owners.normalize(owner)
+    }
+}
+
+// ── Scheduler ────────────────────────────────────────────────────────────────
+
+/// Periodic maintenance jobs. Each schedule is a cron expression with
+/// seconds (`sec min hour day-of-month month day-of-week`, UTC); an empty
+/// schedule disables the job.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SchedulerConfig {
+    /// Scan documents for internal links to missing or archived documents.
+    #[serde(default)]
+    pub link_check: String,
+    /// List documents not updated for `stale_after_days`.
+    #[serde(default)]
+    pub stale_docs: String,
+    /// Compare the search index with the database and repair it.
+    #[serde(default)]
+    pub search_reconciliation: String,
+    /// Post a digest of the latest link check and stale document findings
+    /// to `digest_webhook_url`.
+    #[serde(default)]
+    pub digest: String,
+    /// Email each service owner its broken links and stale documents
+    /// (recipients in `[email.owners]`).
+    #[serde(default)]
+    pub email_digest: String,
+    /// Delete notifications older than `[notifications] retention_days`.
+    #[serde(default)]
+    pub notification_cleanup: String,
+    #[serde(default = "default_stale_after_days")]
+    pub stale_after_days: u32,
+    /// Receives the digest as a JSON `POST` (e.g. a chat or email gateway).
+    #[serde(default)]
+    pub digest_webhook_url: String,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            link_check: String::new(),
+            stale_docs: String::new(),
+            search_reconciliation: String::new(),
+            digest: String::new(),
+            email_digest: String::new(),
+            notification_cleanup: String::new(),
+            stale_after_days: default_stale_after_days(),
+            digest_webhook_url: String::new(),
+        }
+    }
+}
+
+fn default_stale_after_days() -> u32 {
+    180
+}
+
+// ── Email ────────────────────────────────────────────────────────────────────
+
+/// How the SMTP connection is secured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with `STARTTLS` (usually port 587).
+    #[default]
+    Starttls,
+    /// Implicit TLS (usually port 465).
+    Tls,
+    /// No encryption; only for relays on a trusted network.
+    None,
+}
+
+/// SMTP relay and recipients of notification emails. Email is disabled while
+/// `smtp_host` is empty.
+///
+/// ```toml
+/// [email.owners]
+/// payments-team = ["payments@example.com"]
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailConfig {
+    #[serde(default)]
+    pub smtp_host: String,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub smtp_security: SmtpSecurity,
+    #[serde(default)]
+    pub smtp_username: String,
+    #[serde(default)]
+    pub smtp_password: String,
+    /// Sender mailbox, e.g. `Lekton <docs@example.com>`.
+    #[serde(default)]
+    pub from: String,
+    /// Notified of every ingest queued for approval.
+    #[serde(default)]
+    pub approval_recipients: Vec<String>,
+    /// Digest recipients keyed by service owner (matched case-insensitively).
+    #[serde(default)]
+    pub owners: HashMap<String, Vec<String>>,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            smtp_host: String::new(),
+            smtp_port: default_smtp_port(),
+            smtp_security: SmtpSecurity::default(),
+            smtp_username: String::new(),
+            smtp_password: String::new(),
+            from: String::new(),
+            approval_recipients: Vec::new(),
+            owners: HashMap::new(),
+        }
+    }
+}
+
+impl EmailConfig {
+    /// Digest recipients of `service_owner`.
+    pub fn owner_recipients(&self, service_owner: &str) -> &[String] {
+        self.owners
+            .iter()
+            .find(|(owner, _)| owner.eq_ignore_ascii_case(service_owner))
+            .map(|(_, recipients)| recipients.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+/// Retention of the in-app notification inbox.
+///
+/// Via env: `LKN__NOTIFICATIONS__MAX_PER_USER`, `LKN__NOTIFICATIONS__RETENTION_DAYS`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationConfig {
+    /// Notifications kept per user; the oldest are dropped beyond this.
+    #[serde(default = "default_max_notifications_per_user")]
+    pub max_per_user: u32,
+    /// Notifications older than this many days are deleted by the
+    /// `notification_cleanup` job. `0` keeps them until `max_per_user` drops them.
+    #[serde(default = "default_notification_retention_days")]
+    pub retention_days: u32,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            max_per_user: default_max_notifications_per_user(),
+            retention_days: default_notification_retention_days(),
+        }
+    }
+}
+
+fn default_max_notifications_per_user() -> u32 {
+    200
+}
+
+fn default_notification_retention_days() -> u32 {
+    90
+}
+
+// ── Upload scanning ──────────────────────────────────────────────────────────
+
+/// Malware scanner checking uploads before they are stored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScannerKind {
+    /// Uploads are stored unscanned.
+    #[default]
+    None,
+    /// A clamd daemon reached over TCP (`INSTREAM`).
+    Clamav,
+    /// An HTTP service receiving the file as a `POST` body.
+    Http,
+}
+
+/// Scanning of uploaded attachments and images. Infected files are moved to
+/// quarantine instead of being stored (see [`crate::scanning`]).
+///
+/// Via env: `LKN__SCANNER__KIND`, `LKN__SCANNER__CLAMAV_ADDRESS`,
+/// `LKN__SCANNER__HTTP_URL`, `LKN__SCANNER__HTTP_TOKEN`, `LKN__SCANNER__FAIL_OPEN`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScannerConfig {
+    #[serde(default)]
+    pub kind: ScannerKind,
+    /// `host:port` of clamd, for `kind = "clamav"`.
+    #[serde(default = "default_clamav_address")]
+    pub clamav_address: String,
+    /// Scanner endpoint, for `kind = "http"`.
+    #[serde(default)]
+    pub http_url: String,
+    /// Sent as bearer token to the HTTP scanner when set.
+    #[serde(default)]
+    pub http_token: String,
+    /// Longest wait for a verdict.
+    #[serde(default = "default_scanner_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Store uploads unscanned when the scanner fails, instead of rejecting
+    /// them with `503`.
+    #[serde(default)]
+    pub fail_open: bool,
+}
+
+impl Default for ScannerConfig {
+    fn default() -> Self {
+        Self {
+            kind: ScannerKind::default(),
+            clamav_address: default_clamav_address(),
+            http_url: String::new(),
+            http_token: String::new(),
+            timeout_secs: default_scanner_timeout_secs(),
+            fail_open: false,
+        }
+    }
+}
+
+fn default_clamav_address() -> String {
+    "localhost:3310".into()
+}
+
+fn default_scanner_timeout_secs() -> u64 {
+    30
+}
+
+// ── Secret scanning ──────────────────────────────────────────────────────────
+
+/// What happens to content in which credentials are found.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretAction {
+    /// Content is not checked.
+    Off,
+    /// The ingest or save fails with `400` and code `secret_detected`.
+    #[default]
+    Reject,
+    /// As `reject`, and the content is kept under `quarantine/` for review.
+    Quarantine,
+}
+
+/// Credential checks on ingested and edited documents (see
+/// [`crate::secrets`]). Admins are alerted whenever content is refused.
+///
+/// Via env: `LKN__SECRETS__ACTION`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SecretsConfig {
+    #[serde(default)]
+    pub action: SecretAction,
+    /// Values never reported, such as the placeholder keys of a tutorial.
+    #[serde(default)]
+    pub allow: Vec<String>,
+}
+
+// ── Share links ──────────────────────────────────────────────────────────────
+
+/// Signed links giving read access to one restricted document without an
+/// account.
+///
+/// Via env: `LKN__SHARE_LINKS__ENABLED`, `LKN__SHARE_LINKS__MAX_DAYS`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShareLinksConfig {
+    /// Let editors create share links.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Longest validity a link can be created with.
+    #[serde(default = "default_share_link_max_days")]
+    pub max_days: u32,
+}
+
+impl Default for ShareLinksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_days: default_share_link_max_days(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_share_link_max_days() -> u32 {
+    30
+}
+
+// ── Embedding ────────────────────────────────────────────────────────────────
+
+/// Documents framed by other sites through `/embed/docs/...` and oEmbed.
+///
+/// Via env: `LKN__EMBED__ENABLED`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmbedConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Origins allowed to frame embedded documents (CSP `frame-ancestors`),
+    /// e.g. `https://wiki.acme.com`. Empty allows any site.
+    #[serde(default)]
+    pub frame_ancestors: Vec<String>,
+}
+
+impl Default for EmbedConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            frame_ancestors: Vec::new(),
+        }
+    }
+}
+
+// ── Integrations ─────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, Default)]
+pub struct IntegrationsConfig {
+    /// GitHub push-webhook re-sync.
+    #[serde(default)]
+    pub github: GitHubConfig,
+    /// "Try it" proxy for the OpenAPI viewer.
+    #[serde(default)]
+    pub try_it: TryItConfig,
+    /// Proxy serving embedded Grafana dashboards with a read-only token.
+    #[serde(default)]
+    pub grafana: GrafanaProxyConfig,
+}
+
+/// Configuration for `POST /api/v1/integrations/github/webhook`.
+///
+/// Via env: `LKN__INTEGRATIONS__GITHUB__WEBHOOK_SECRET`, `LKN__INTEGRATIONS__GITHUB__TOKEN`.
+/// Repositories are easier to declare in `config/lekton.toml`:
+///
+/// ```toml
+/// [[integrations.github.repos]]
+/// full_name = "acme/payments"
+/// docs_dir = "docs"
+/// slug_prefix = "payments"
+/// ```
+#[derive(Debug, Deserialize, Default)]
+pub struct GitHubConfig {
+    /// Secret shared with the GitHub webhook. Empty disables the endpoint.
+    #[serde(default)]
+    pub webhook_secret: String,
+    /// GitHub REST API base URL (override for GitHub Enterprise).
+    #[serde(default = "default_github_api_url")]
+    pub api_url: String,
+    /// Token used to read repository contents. Required for private repositories.
+    #[serde(default)]
+    pub token: String,
+    /// Repositories whose pushes trigger a re-sync.
+    #[serde(default)]
+    pub repos: Vec<GitHubRepoConfig>,
+}
+
+impl GitHubConfig {
+    /// Returns `true` when a webhook secret is configured.
+    pub fn is_enabled(&self) -> bool {
+        !self.webhook_secret.is_empty()
+    }
+}
+
+/// A repository synced on push. Defaults mirror `.lekton.yml` in `lekton-sync`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct GitHubRepoConfig {
+    /// `owner/name` of the repository.
+    pub full_name: String,
+    /// Branch whose pushes are synced.
+    #[serde(default = "default_github_branch")]
+    pub branch: String,
+    /// Directory inside the repository that `lekton-sync` scans. Empty means the root.
+    #[serde(default)]
+    pub docs_dir: String,
+    /// Slug prefix prepended to every document slug.
+    #[serde(default)]
+    pub slug_prefix: String,
+    /// Access level applied when front matter has none.
+    #[serde(default = "default_github_access_level")]
+    pub default_access_level: String,
+    /// Service owner applied when front matter has none.
+    #[serde(default)]
+    pub default_service_owner: String,
+}
+
+/// Configuration for the `/api/v1/try-it` proxy used by the OpenAPI viewer.
+///
+/// Via env: `LKN__INTEGRATIONS__TRY_IT__TIMEOUT_SECS`. Hosts are easier to
+/// declare in `config/lekton.toml`:
+///
+/// ```toml
+/// [integrations.try_it]
+/// allowed_hosts = ["payments.internal.acme.com", "*.svc.cluster.local:8080"]
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct TryItConfig {
+    /// Hosts requests may be forwarded to. `*.` matches any subdomain; a
+    /// `:port` suffix restricts the port. Empty disables the proxy.
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+    /// Upstream request timeout.
+    #[serde(default = "default_try_it_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Default for TryItConfig {
+    fn default() -> Self {
+        Self {
+            allowed_hosts: Vec::new(),
+            timeout_secs: default_try_it_timeout_secs(),
+        }
+    }
+}
+
+impl TryItConfig {
+    /// Returns `true` when at least one host is allowed.
+    pub fn is_enabled(&self) -> bool {
+        !self.allowed_hosts.is_empty()
+    }
+}
+
+fn default_try_it_timeout_secs() -> u64 {
+    30
+}
+
+/// Configuration for the `/grafana/render/` proxy that serves images of
+/// embedded Grafana dashboards and panels to signed-in users.
+///
+/// Only the dashboards listed here can be rendered, each for the readers of
+/// its access level. Grafana needs the image renderer plugin. Via env:
+/// `LKN__INTEGRATIONS__GRAFANA__URL`, `LKN__INTEGRATIONS__GRAFANA__TOKEN`.
+///
+/// ```toml
+/// [integrations.grafana]
+/// url = "http://grafana.monitoring.svc:3000"
+///
+/// [[integrations.grafana.dashboards]]
+/// uid = "payments-overview"
+/// access_level = "internal"
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct GrafanaProxyConfig {
+    /// Grafana base URL as reached from Lekton, including the sub-path
+    /// Grafana serves from, if any. Empty disables the proxy.
+    #[serde(default)]
+    pub url: String,
+    /// Token of a Grafana service account with the Viewer role, sent with
+    /// every proxied request.
+    #[serde(default)]
+    pub token: String,
+    /// Upstream request timeout.
+    #[serde(default = "default_try_it_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Dashboards whose panels may be embedded.
+    #[serde(default)]
+    pub dashboards: Vec<GrafanaDashboardConfig>,
+}
+
+/// A Grafana dashboard allowed through the proxy.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GrafanaDashboardConfig {
+    /// Dashboard UID, as in `/d/<uid>/...`.
+    pub uid: String,
+    /// Access level a reader needs to see the dashboard's images.
+    pub access_level: String,
+}
+
+impl Default for GrafanaProxyConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            token: String::new(),
+            timeout_secs: default_try_it_timeout_secs(),
+            dashboards: vec![],
+        }
+    }
+}
+
+impl GrafanaProxyConfig {
+    /// Returns `true` when both the Grafana URL and the token are set.
+    pub fn is_enabled(&self) -> bool {
+        !self.url.is_empty() && !self.token.is_empty()
+    }
+
+    /// The allow-listed dashboard with `uid`.
+    pub fn dashboard(&self, uid: &str) -> Option<&GrafanaDashboardConfig> {
+        self.dashboards.iter().find(|d| d.uid == uid)
+    }
+}
+
+fn default_github_api_url() -> String {
+    "https://api.github.com".into()
+}
+
+fn default_github_branch() -> String {
+    "main".into()
+}
+
+fn default_github_access_level() -> String {
+    "public".into()
+}
+
 // ── RAG ──────────────────────────────────────────────────────────────────────
 
 /// Base LLM configuration shared across all RAG pipeline steps.
@@ -271,6 +1458,10 @@ pub struct RagConfig {
     /// When `true`, combines Qdrant vector search with Meilisearch full-text search via RRF.
     #[serde(default)]
     pub hybrid_search_enabled: bool,
+    /// When `true`, documentation search blends vector similarity over the
+    /// embedded chunks into Meilisearch results via RRF.
+    #[serde(default)]
+    pub semantic_search_enabled: bool,
     /// Cross-encoder reranker endpoint (Jina/Infinity/Cohere-compatible `/rerank` API).
     /// Empty string disables reranking.
     pub reranker_url: String,
@@ -442,6 +1633,11 @@ impl AppConfig {
     /// Panics with a clear message on deserialization failure — this is
     /// intentional: a misconfigured binary should fail fast at startup.
     pub fn load() -> Result<Self, config::ConfigError> {
+        Self::sources()?.try_deserialize()
+    }
+
+    /// The merged configuration sources, before deserialization.
+    pub fn sources() -> Result<config::Config, config::ConfigError> {
         config::Config::builder()
             // Embedded defaults — always present
             .add_source(config::File::from_str(
@@ -458,8 +1654,22 @@ impl AppConfig {
                     .separator("__")
                     .try_parsing(true),
             )
-            .build()?
-            .try_deserialize()
+            .build()
+    }
+}
+
+/// Load variables from a `.env` file in the working directory (or a parent)
+/// without overriding those already set, returning the file's path.
+#[cfg(feature = "ssr")]
+pub fn load_dotenv() -> Option<std::path::PathBuf> {
+    match dotenvy::dotenv() {
+        Ok(path) => Some(path),
+        Err(e) if e.not_found() => None,
+        Err(e) => {
+            // Tracing is not initialised yet
+            eprintln!("Ignoring invalid .env file: {e}");
+            None
+        }
     }
 }
 
@@ -531,6 +1741,138 @@ mod tests {
         assert_eq!(config.rag.llm.vertex_location, "europe-west1");
     }
 
+    #[test]
+    fn test_quota_limits_for_owner() {
+        let mut quotas = super::QuotaConfig {
+            default: super::QuotaLimits {
+                max_documents: 100,
+                max_storage_mb: 0,
+            },
+            ..Default::default()
+        };
+        quotas.owners.insert(
+            "payments-team".to_string(),
+            super::QuotaLimits {
+                max_documents: 10,
+                max_storage_mb: 2,
+            },
+        );
+
+        let limits = quotas.limits_for("Payments-Team");
+        assert_eq!(limits.max_document_count(), Some(10));
+        assert_eq!(limits.max_storage_bytes(), Some(2 * 1024 * 1024));
+
+        let limits = quotas.limits_for("search-team");
+        assert_eq!(limits.max_document_count(), Some(100));
+        assert_eq!(limits.max_storage_bytes(), None);
+    }
+
+    #[test]
+    fn test_upload_quota_for_uploader() {
+        let mut uploads = super::UploadQuotaConfig {
+            daily_mb: 50,
+            ..Default::default()
+        };
+        uploads.uploaders.insert("docs-ci".to_string(), 0);
+
+        assert_eq!(
+            uploads.daily_limit_bytes("alice@example.com"),
+            Some(50 * 1024 * 1024)
+        );
+        assert_eq!(uploads.daily_limit_bytes("Docs-CI"), None);
+    }
+
+    #[test]
+    fn test_lint_options_per_project() {
+        use crate::rendering::lint::LintRule;
+
+        let mut lint = super::LintConfig {
+            enabled: true,
+            rules: vec!["todo".to_string(), "spelling".to_string()],
+            ..Default::default()
+        };
+        lint.projects.insert(
+            "payments".to_string(),
+            super::ProjectLintConfig {
+                max_line_length: Some(80),
+                rules: Some(vec![]),
+                ..Default::default()
+            },
+        );
+        lint.projects.insert(
+            "legacy".to_string(),
+            super::ProjectLintConfig {
+                enabled: Some(false),
+                ..Default::default()
+            },
+        );
+
+        let options = lint.options_for("guides/intro").unwrap();
+        assert_eq!(options.rules, vec![LintRule::Todo]);
+        assert_eq!(options.max_line_length, 120);
+
+        let options = lint.options_for("payments/retries").unwrap();
+        assert_eq!(options.rules, LintRule::ALL.to_vec());
+        assert_eq!(options.max_line_length, 80);
+
+        assert!(lint.options_for("legacy/setup").is_none());
+        assert_eq!(lint.unknown_rules(), vec!["spelling"]);
+    }
+
+    #[test]
+    fn test_storage_location_per_content_class() {
+        use crate::storage::client::ContentClass;
+
+        let storage = super::StorageConfig {
+            bucket: "docs".to_string(),
+            endpoint: None,
+            key_prefix: "lekton/".to_string(),
+            key_layout: Default::default(),
+            schemas: Default::default(),
+            uploads: super::BucketOverride {
+                bucket: Some("uploads".to_string()),
+                key_prefix: Some(String::new()),
+            },
+            encryption: Default::default(),
+        };
+
+        assert_eq!(
+            storage.location(ContentClass::Documents),
+            ("docs", "lekton/")
+        );
+        assert_eq!(storage.location(ContentClass::Schemas), ("docs", "lekton/"));
+        assert_eq!(storage.location(ContentClass::Uploads), ("uploads", ""));
+    }
+
+    #[test]
+    fn test_taxonomy_normalizes_tags() {
+        let mut taxonomy = super::TaxonomyConfig::default();
+        taxonomy.tags.kebab_case = true;
+        taxonomy
+            .tags
+            .synonyms
+            .insert("K8s".to_string(), "Kubernetes".to_string());
+        let tags: Vec<String> = ["K8s", "kubernetes", "Release Notes", "  ", "release_notes"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(
+            taxonomy.normalize_tags(&tags).unwrap(),
+            ["kubernetes", "release-notes"]
+        );
+
+        taxonomy.tags.allowed = vec!["kubernetes".to_string()];
+        assert_eq!(
+            taxonomy.normalize_tags(&tags),
+            Err("release-notes".to_string())
+        );
+
+        // The default policy only trims
+        assert_eq!(
+            taxonomy.normalize_owner(" Payments Team "),
+            Ok("Payments Team".to_string())
+        );
+    }
+
     #[test]
     #[cfg(feature = "ssr")]
     fn test_rag_step_config_resolve() {
@@ -0,0 +1,456 @@
+//! Configuration validation and the `lekton check-config` report.
+//!
+//! [`validate`] lists every setting that keeps the server from starting, so
+//! they can be fixed in one go. [`run`] prints the effective configuration
+//! with secrets redacted, then checks connectivity to MongoDB, S3,
+//! Meilisearch and the OAuth2/OIDC provider.
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::config::AppConfig;
+
+/// Final key segments marking a setting as secret.
+const SECRET_KEY_SUFFIXES: &[&str] = &["password", "secret", "token", "api_key", "webhook_url"];
+
+/// Shown in place of secret values.
+const REDACTED: &str = "********";
+
+/// Settings problems that keep the server from starting.
+pub fn validate(config: &AppConfig) -> Vec<String> {
+    let mut problems = Vec::new();
+    let demo_mode = config.auth.demo_mode;
+
+    if config.database.uri.trim().is_empty() {
+        problems.push("database.uri is not set (LKN__DATABASE__URI)".to_string());
+    }
+    if config.storage.bucket.trim().is_empty() {
+        problems.push("storage.bucket is not set (LKN__STORAGE__BUCKET)".to_string());
+    }
+    if config.storage.encryption.kms_key_id.is_some()
+        && config.storage.encryption.mode != crate::config::EncryptionMode::Kms
+    {
+        problems.push(
+            "storage.encryption.kms_key_id is set but storage.encryption.mode is not \"kms\""
+                .to_string(),
+        );
+    }
+    for (name, over) in [
+        ("schemas", &config.storage.schemas),
+        ("uploads", &config.storage.uploads),
+    ] {
+        if over.bucket.as_deref().is_some_and(|b| b.trim().is_empty()) {
+            problems.push(format!(
+                "storage.{name}.bucket is empty; remove it to use storage.bucket"
+            ));
+        }
+    }
+    if demo_mode && config.auth.jwt_secret.is_some() && !config.auth.allow_demo_in_production {
+        problems.push(
+            "auth.demo_mode is enabled but auth.jwt_secret is set, which suggests a production \
+             environment; set auth.allow_demo_in_production = true \
+             (LKN__AUTH__ALLOW_DEMO_IN_PRODUCTION) to override this safety check"
+                .to_string(),
+        );
+    }
+    if !demo_mode {
+        if config
+            .auth
+            .service_token
+            .as_deref()
+            .is_none_or(str::is_empty)
+        {
+            problems.push(
+                "auth.service_token is required outside demo mode (LKN__AUTH__SERVICE_TOKEN)"
+                    .to_string(),
+            );
+        }
+        if config.auth.jwt_secret.is_none() {
+            problems.push(
+                "auth.jwt_secret is required outside demo mode (LKN__AUTH__JWT_SECRET)".to_string(),
+            );
+        }
+    }
+    if config.auth.ldap.is_enabled() && config.auth.ldap.user_base_dn.trim().is_empty() {
+        problems.push(
+            "auth.ldap.url is set but auth.ldap.user_base_dn is not (LKN__AUTH__LDAP__USER_BASE_DN)"
+                .to_string(),
+        );
+    }
+    if config.rag.is_enabled() {
+        if let Err(e) = config.rag.validate() {
+            problems.push(format!("Invalid RAG configuration: {e}"));
+        }
+        if config.rag.hybrid_search_enabled && config.search.url.is_empty() {
+            problems
+                .push("rag.hybrid_search_enabled = true requires search.url to be set".to_string());
+        }
+    }
+
+    if config.scanner.kind == crate::config::ScannerKind::Http
+        && config.scanner.http_url.trim().is_empty()
+    {
+        problems.push(
+            "scanner.kind = \"http\" requires scanner.http_url to be set (LKN__SCANNER__HTTP_URL)"
+                .to_string(),
+        );
+    }
+
+    if config.spellcheck.enabled {
+        let dictionary = &config.spellcheck.dictionary;
+        for extension in ["aff", "dic"] {
+            let path = format!("{dictionary}.{extension}");
+            if !std::path::Path::new(&path).is_file() {
+                problems.push(format!(
+                    "spellcheck.enabled = true but the dictionary file {path} does not exist (LKN__SPELLCHECK__DICTIONARY)"
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
+/// Whether the setting at `key` (e.g. `auth.jwt_secret`) holds a secret.
+/// Header values may carry credentials, so they are all treated as secret.
+pub fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    let last = key.rsplit('.').next().unwrap_or_default();
+    SECRET_KEY_SUFFIXES
+        .iter()
+        .any(|suffix| last.ends_with(suffix))
+        || key.split('.').any(|segment| segment.ends_with("headers"))
+}
+
+/// `value` with secrets hidden: the whole value for secret keys, the
+/// password of URLs with credentials otherwise.
+pub fn redact(key: &str, value: &str) -> String {
+    if value.is_empty() {
+        return String::new();
+    }
+    if is_secret_key(key) {
+        return REDACTED.to_string();
+    }
+    redact_url(value)
+}
+
+/// `value` with the password of its URL credentials hidden.
+pub fn redact_url(value: &str) -> String {
+    match url::Url::parse(value) {
+        Ok(mut parsed) if parsed.password().is_some() => {
+            let _ = parsed.set_password(Some(REDACTED));
+            parsed.to_string()
+        }
+        _ => value.to_string(),
+    }
+}
+
+/// Every setting of the merged configuration as `(key, redacted value)`,
+/// sorted by key.
+pub fn effective_settings(config: &config::Config) -> Vec<(String, String)> {
+    use config::Source;
+
+    fn flatten(prefix: &str, value: config::Value, out: &mut Vec<(String, String)>) {
+        match value.kind {
+            config::ValueKind::Table(table) => {
+                for (key, value) in table {
+                    let key = if prefix.is_empty() {
+                        key
+                    } else {
+                        format!("{prefix}.{key}")
+                    };
+                    flatten(&key, value, out);
+                }
+            }
+            config::ValueKind::Nil => out.push((prefix.to_string(), String::new())),
+            kind => {
+                let value = kind.to_string();
+                out.push((prefix.to_string(), redact(prefix, &value)));
+            }
+        }
+    }
+
+    let mut settings = Vec::new();
+    if let Ok(table) = config.collect() {
+        for (key, value) in table {
+            flatten(&key, value, &mut settings);
+        }
+    }
+    settings.sort();
+    settings
+}
+
+/// Outcome of a connectivity check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Ok,
+    Skipped,
+    Failed,
+}
+
+impl CheckStatus {
+    fn label(self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "ok",
+            CheckStatus::Skipped => "skipped",
+            CheckStatus::Failed => "FAILED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+async fn check_mongo(config: &AppConfig) -> Check {
+    const NAME: &str = "MongoDB";
+    let uri = config.database.connection_uri();
+    let mut options = match mongodb::options::ClientOptions::parse(&uri).await {
+        Ok(options) => options,
+        Err(e) => return Check::new(NAME, CheckStatus::Failed, format!("Invalid URI: {e}")),
+    };
+    let timeout = Duration::from_millis(config.resilience.timeout_ms);
+    options.server_selection_timeout.get_or_insert(timeout);
+    options.connect_timeout.get_or_insert(timeout);
+    let client = match mongodb::Client::with_options(options) {
+        Ok(client) => client,
+        Err(e) => return Check::new(NAME, CheckStatus::Failed, e.to_string()),
+    };
+    match client
+        .database(&config.database.name)
+        .run_command(mongodb::bson::doc! { "ping": 1 })
+        .await
+    {
+        Ok(_) => Check::new(
+            NAME,
+            CheckStatus::Ok,
+            format!("{} (database '{}')", redact_url(&uri), config.database.name),
+        ),
+        Err(e) => Check::new(NAME, CheckStatus::Failed, e.to_string()),
+    }
+}
+
+async fn check_storage(config: &AppConfig) -> Check {
+    use crate::storage::client::ContentClass;
+
+    const NAME: &str = "S3 storage";
+    let client =
+        match crate::storage::client::S3StorageClient::from_app_config(&config.storage).await {
+            Ok(client) => client,
+            Err(e) => return Check::new(NAME, CheckStatus::Failed, e.to_string()),
+        };
+    let mut buckets: Vec<String> = Vec::new();
+    for class in ContentClass::ALL {
+        let client = client.for_class(&config.storage, class);
+        if buckets.iter().any(|b| b == client.bucket()) {
+            continue;
+        }
+        if let Err(e) = client.check_bucket().await {
+            return Check::new(NAME, CheckStatus::Failed, e.to_string());
+        }
+        buckets.push(format!("'{}'", client.bucket()));
+    }
+    let label = if buckets.len() == 1 {
+        "bucket"
+    } else {
+        "buckets"
+    };
+    Check::new(
+        NAME,
+        CheckStatus::Ok,
+        format!("{label} {}", buckets.join(", ")),
+    )
+}
+
+async fn check_search(config: &AppConfig) -> Check {
+    const NAME: &str = "Meilisearch";
+    if config.search.url.is_empty() {
+        return Check::new(
+            NAME,
+            CheckStatus::Skipped,
+            "search.url is not set; search is disabled",
+        );
+    }
+    let result = match crate::search::client::MeilisearchService::from_app_config(&config.search) {
+        Ok(service) => service.check_health().await,
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(()) => Check::new(NAME, CheckStatus::Ok, redact_url(&config.search.url)),
+        Err(e) => Check::new(NAME, CheckStatus::Failed, e.to_string()),
+    }
+}
+
+async fn check_auth_provider(config: &AppConfig) -> Check {
+    const NAME: &str = "OAuth2/OIDC";
+    let auth = &config.auth;
+    if auth.client_id.is_none() && auth.authorization_endpoint.is_none() {
+        return Check::new(NAME, CheckStatus::Skipped, "no provider configured");
+    }
+    let provider = match crate::auth::config::AuthProviderConfig::from_app_config(auth) {
+        Ok(provider) => provider,
+        Err(e) => return Check::new(NAME, CheckStatus::Failed, e.to_string()),
+    };
+    if provider.provider_type != "oidc" {
+        return Check::new(
+            NAME,
+            CheckStatus::Skipped,
+            "OAuth2 provider endpoints are not probed",
+        );
+    }
+
+    let discovery_url = format!(
+        "{}/.well-known/openid-configuration",
+        provider.authorization_endpoint.trim_end_matches('/')
+    );
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_millis(config.resilience.timeout_ms))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => return Check::new(NAME, CheckStatus::Failed, e.to_string()),
+    };
+    let metadata = match client.get(&discovery_url).send().await {
+        Ok(response) if response.status().is_success() => {
+            response.json::<serde_json::Value>().await
+        }
+        Ok(response) => {
+            return Check::new(
+                NAME,
+                CheckStatus::Failed,
+                format!("{discovery_url} returned {}", response.status()),
+            )
+        }
+        Err(e) => return Check::new(NAME, CheckStatus::Failed, e.to_string()),
+    };
+    match metadata {
+        Ok(metadata) if metadata["token_endpoint"].is_string() => {
+            Check::new(NAME, CheckStatus::Ok, discovery_url)
+        }
+        Ok(_) => Check::new(
+            NAME,
+            CheckStatus::Failed,
+            format!("{discovery_url} has no token_endpoint"),
+        ),
+        Err(e) => Check::new(
+            NAME,
+            CheckStatus::Failed,
+            format!("Invalid discovery document: {e}"),
+        ),
+    }
+}
+
+/// Print the configuration report and run the connectivity checks.
+/// Returns whether the configuration is valid and every check passed.
+pub async fn run(dotenv_path: Option<&Path>) -> bool {
+    println!("Configuration sources");
+    match dotenv_path {
+        Some(path) => println!("  .env                {}", path.display()),
+        None => println!("  .env                not found"),
+    }
+    let local = Path::new("config/lekton.toml");
+    println!(
+        "  config/lekton.toml  {}",
+        if local.exists() { "found" } else { "not found" }
+    );
+
+    let sources = match AppConfig::sources() {
+        Ok(sources) => sources,
+        Err(e) => {
+            println!("\nInvalid configuration: {e}");
+            return false;
+        }
+    };
+    println!("\nEffective configuration (secrets redacted)");
+    for (key, value) in effective_settings(&sources) {
+        println!("  {key} = {value}");
+    }
+
+    let config: AppConfig = match sources.try_deserialize() {
+        Ok(config) => config,
+        Err(e) => {
+            println!("\nInvalid configuration: {e}");
+            return false;
+        }
+    };
+    let problems = validate(&config);
+    if !problems.is_empty() {
+        println!("\nProblems");
+        for problem in &problems {
+            println!("  - {problem}");
+        }
+    }
+
+    let (mongo, storage, search, auth) = tokio::join!(
+        check_mongo(&config),
+        check_storage(&config),
+        check_search(&config),
+        check_auth_provider(&config),
+    );
+    let checks = [mongo, storage, search, auth];
+    println!("\nConnectivity");
+    for check in &checks {
+        println!(
+            "  [{:<7}] {:<12} {}",
+            check.status.label(),
+            check.name,
+            check.detail
+        );
+    }
+
+    problems.is_empty() && checks.iter().all(|c| c.status != CheckStatus::Failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        assert!(is_secret_key("auth.jwt_secret"));
+        assert!(is_secret_key("auth.service_token"));
+        assert!(is_secret_key("email.smtp_password"));
+        assert!(is_secret_key("rag.llm.headers.authorization"));
+        assert!(!is_secret_key("auth.token_endpoint"));
+        assert!(!is_secret_key("auth.jwt_refresh_ttl_days"));
+
+        assert_eq!(redact("search.api_key", "master-key"), REDACTED);
+        assert_eq!(redact("search.api_key", ""), "");
+        assert_eq!(
+            redact("database.uri", "mongodb://admin:hunter2@db:27017/"),
+            "mongodb://admin:********@db:27017/"
+        );
+        assert_eq!(redact("database.name", "lekton"), "lekton");
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let mut config = AppConfig::load().unwrap();
+        config.auth.demo_mode = false;
+        config.auth.service_token = None;
+        config.auth.jwt_secret = None;
+        config.storage.bucket = String::new();
+        config.rag.qdrant_url = String::new();
+
+        let problems = validate(&config);
+        assert_eq!(problems.len(), 3, "{problems:?}");
+        assert!(problems[0].starts_with("storage.bucket"));
+
+        config.auth.demo_mode = true;
+        config.storage.bucket = "docs".into();
+        assert!(validate(&config).is_empty());
+    }
+}
@@ -0,0 +1,282 @@
+//! Coordination between replicas over Redis.
+//!
+//! Each replica publishes activity events on an in-process channel and runs
+//! background jobs on its own tasks, so with several replicas SSE subscribers
+//! (and the document pages that refresh from them) miss writes handled
+//! elsewhere. With the `redis` feature and `coordination.redis_url` set:
+//!
+//! - activity events are also published on the Redis channel
+//!   `<key_prefix>:events` and delivered to the local subscribers of every
+//!   other replica;
+//! - [`Job`](crate::jobs::Job)s are pushed to the Redis list
+//!   `<key_prefix>:jobs` and run by whichever replica pops them first. A job
+//!   that cannot be queued runs locally instead.
+//!
+//! Nothing is published for cache invalidation: replicas keep no navigation
+//! or rendered-HTML cache, every page is rendered from MongoDB and S3.
+//!
+//! When Redis is unreachable at startup the replica runs uncoordinated.
+
+use std::sync::Arc;
+
+use crate::api::events::ActivityEvents;
+use crate::config::CoordinationConfig;
+use crate::jobs::{JobQueue, JobRunner, LocalJobQueue};
+
+/// Activity hub and job queue to put in `AppState`.
+pub struct Coordination {
+    pub activity_events: ActivityEvents,
+    pub job_queue: Arc<dyn JobQueue>,
+}
+
+impl Coordination {
+    /// In-process only: events stay on this replica and jobs run locally.
+    pub fn local(runner: JobRunner) -> Self {
+        Self {
+            activity_events: ActivityEvents::new(),
+            job_queue: Arc::new(LocalJobQueue::new(runner)),
+        }
+    }
+}
+
+/// Connect to Redis when configured, falling back to [`Coordination::local`].
+pub async fn init(config: &CoordinationConfig, runner: JobRunner) -> Coordination {
+    if config.redis_url.is_empty() {
+        return Coordination::local(runner);
+    }
+
+    #[cfg(feature = "redis")]
+    {
+        match redis_coordination::connect(config, runner.clone()).await {
+            Ok(coordination) => {
+                tracing::info!(prefix = %config.key_prefix, "Coordinating replicas over Redis");
+                coordination
+            }
+            Err(e) => {
+                tracing::warn!("Redis not available: {e} — replicas are not coordinated");
+                Coordination::local(runner)
+            }
+        }
+    }
+
+    #[cfg(not(feature = "redis"))]
+    {
+        tracing::warn!(
+            "coordination.redis_url is set but this build lacks the `redis` feature — replicas are not coordinated"
+        );
+        Coordination::local(runner)
+    }
+}
+
+#[cfg(feature = "redis")]
+mod redis_coordination {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use futures::StreamExt;
+    use redis::AsyncCommands;
+    use serde::{Deserialize, Serialize};
+    use tokio::sync::mpsc;
+
+    use super::Coordination;
+    use crate::api::events::{ActivityEvent, ActivityEvents};
+    use crate::config::CoordinationConfig;
+    use crate::jobs::{Job, JobQueue, JobRunner, LocalJobQueue};
+
+    /// Delay before reconnecting a failed subscriber or job worker.
+    const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+    /// How long a job worker blocks on an empty queue before polling again.
+    const JOB_POLL_SECS: f64 = 5.0;
+
+    /// An activity event on the Redis channel, tagged with the replica that
+    /// published it so it is not delivered twice there.
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RelayedEvent {
+        origin: String,
+        event: ActivityEvent,
+    }
+
+    /// The event in `payload`, unless it was published by `instance_id`.
+    fn remote_event(payload: &str, instance_id: &str) -> Option<ActivityEvent> {
+        match serde_json::from_str::<RelayedEvent>(payload) {
+            Ok(relayed) if relayed.origin != instance_id => Some(relayed.event),
+            Ok(_) => None,
+            Err(e) => {
+                tracing::warn!("Ignoring malformed relayed activity event: {e}");
+                None
+            }
+        }
+    }
+
+    pub(super) async fn connect(
+        config: &CoordinationConfig,
+        runner: JobRunner,
+    ) -> Result<Coordination, redis::RedisError> {
+        let client = redis::Client::open(config.redis_url.as_str())?;
+        let connection = client.get_multiplexed_async_connection().await?;
+        let instance_id = uuid::Uuid::new_v4().to_string();
+        let events_channel = format!("{}:events", config.key_prefix);
+        let jobs_key = format!("{}:jobs", config.key_prefix);
+
+        let (relay, outgoing) = mpsc::unbounded_channel();
+        let activity_events = ActivityEvents::new().with_relay(relay);
+
+        tokio::spawn(publish_events(
+            connection.clone(),
+            events_channel.clone(),
+            instance_id.clone(),
+            outgoing,
+        ));
+        tokio::spawn(subscribe_events(
+            client.clone(),
+            events_channel,
+            instance_id,
+            activity_events.clone(),
+        ));
+        tokio::spawn(work_jobs(client, jobs_key.clone(), runner.clone()));
+
+        Ok(Coordination {
+            activity_events,
+            job_queue: Arc::new(RedisJobQueue {
+                connection,
+                key: jobs_key,
+                fallback: LocalJobQueue::new(runner),
+            }),
+        })
+    }
+
+    async fn publish_events(
+        mut connection: redis::aio::MultiplexedConnection,
+        channel: String,
+        instance_id: String,
+        mut outgoing: mpsc::UnboundedReceiver<ActivityEvent>,
+    ) {
+        while let Some(event) = outgoing.recv().await {
+            let relayed = RelayedEvent {
+                origin: instance_id.clone(),
+                event,
+            };
+            let Ok(payload) = serde_json::to_string(&relayed) else {
+                continue;
+            };
+            if let Err(e) = connection.publish::<_, _, ()>(&channel, payload).await {
+                tracing::warn!("Failed to relay activity event: {e}");
+            }
+        }
+    }
+
+    async fn subscribe_events(
+        client: redis::Client,
+        channel: String,
+        instance_id: String,
+        events: ActivityEvents,
+    ) {
+        loop {
+            if let Err(e) = relay_remote_events(&client, &channel, &instance_id, &events).await {
+                tracing::warn!("Activity event subscription lost: {e}");
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    async fn relay_remote_events(
+        client: &redis::Client,
+        channel: &str,
+        instance_id: &str,
+        events: &ActivityEvents,
+    ) -> Result<(), redis::RedisError> {
+        let mut pubsub = client.get_async_pubsub().await?;
+        pubsub.subscribe(channel).await?;
+        let mut messages = pubsub.on_message();
+        while let Some(message) = messages.next().await {
+            let payload: String = message.get_payload()?;
+            if let Some(event) = remote_event(&payload, instance_id) {
+                events.deliver(event);
+            }
+        }
+        Ok(())
+    }
+
+    async fn work_jobs(client: redis::Client, key: String, runner: JobRunner) {
+        loop {
+            if let Err(e) = pop_jobs(&client, &key, &runner).await {
+                tracing::warn!("Job queue connection lost: {e}");
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    async fn pop_jobs(
+        client: &redis::Client,
+        key: &str,
+        runner: &JobRunner,
+    ) -> Result<(), redis::RedisError> {
+        // Blocking pops hold the connection, so the worker gets its own.
+        let mut connection = client.get_multiplexed_async_connection().await?;
+        loop {
+            let popped: Option<(String, String)> = connection.brpop(key, JOB_POLL_SECS).await?;
+            let Some((_, payload)) = popped else {
+                continue;
+            };
+            match serde_json::from_str::<Job>(&payload) {
+                Ok(job) => runner.run_logged(job).await,
+                Err(e) => tracing::warn!("Dropping malformed job {payload:?}: {e}"),
+            }
+        }
+    }
+
+    /// Queue shared by all replicas; falls back to running the job locally.
+    struct RedisJobQueue {
+        connection: redis::aio::MultiplexedConnection,
+        key: String,
+        fallback: LocalJobQueue,
+    }
+
+    #[async_trait]
+    impl JobQueue for RedisJobQueue {
+        async fn enqueue(&self, job: Job) {
+            let payload = serde_json::to_string(&job).unwrap_or_default();
+            let mut connection = self.connection.clone();
+            if let Err(e) = connection.lpush::<_, _, ()>(&self.key, payload).await {
+                tracing::warn!(
+                    ?job,
+                    "Failed to queue job in Redis, running it locally: {e}"
+                );
+                self.fallback.enqueue(job).await;
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::api::events::ActivityKind;
+
+        #[test]
+        fn test_remote_event_skips_own_events() {
+            let relayed = RelayedEvent {
+                origin: "replica-a".into(),
+                event: ActivityEvent {
+                    kind: ActivityKind::DocumentCreated,
+                    target: "guides/intro".into(),
+                    version: None,
+                    title: None,
+                    access_level: "public".into(),
+                    owner_access_level: None,
+                    is_draft: false,
+                    actor: "ci".into(),
+                    timestamp: chrono::Utc::now(),
+                },
+            };
+            let payload = serde_json::to_string(&relayed).unwrap();
+
+            assert!(remote_event(&payload, "replica-a").is_none());
+            assert_eq!(
+                remote_event(&payload, "replica-b").unwrap().target,
+                "guides/intro"
+            );
+            assert!(remote_event("not json", "replica-b").is_none());
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Audit log of downloads of attachments and images embedded in restricted
+//! documents.
+//!
+//! Files only referenced by public, published documents are not recorded.
+//! Entries expire after [`RETENTION_DAYS`].
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// How long download records are kept.
+pub const RETENTION_DAYS: u64 = 90;
+
+/// One download of a restricted attachment or image.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetDownload {
+    /// Storage key of the file (`assets/<key>` or `images/<file>`).
+    pub s3_key: String,
+    /// The document that granted access to the file.
+    pub slug: String,
+    /// Access level of that document.
+    pub access_level: String,
+    /// Email of the downloading user.
+    pub downloaded_by: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub downloaded_at: DateTime<Utc>,
+}
+
+/// Storage for download records.
+#[async_trait]
+pub trait AssetDownloadRepository: Send + Sync {
+    async fn record(&self, download: AssetDownload) -> Result<(), AppError>;
+
+    /// Most recent downloads first, optionally of one file only.
+    async fn list_recent(
+        &self,
+        s3_key: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<AssetDownload>, AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoAssetDownloadRepository {
+    collection: mongodb::Collection<AssetDownload>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoAssetDownloadRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("asset_downloads"),
+        }
+    }
+
+    /// Ensure the lookup index and the TTL index enforcing the retention.
+    pub async fn ensure_indexes(&self) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::IndexOptions;
+        use mongodb::IndexModel;
+
+        self.collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "s3_key": 1, "downloaded_at": -1 })
+                    .build(),
+            )
+            .await
+            .map_err(|e| AppError::Database(format!("create asset_downloads key index: {e}")))?;
+
+        self.collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "downloaded_at": 1 })
+                    .options(
+                        IndexOptions::builder()
+                            .expire_after(std::time::Duration::from_secs(
+                                RETENTION_DAYS * 24 * 60 * 60,
+                            ))
+                            .build(),
+                    )
+                    .build(),
+            )
+            .await
+            .map_err(|e| AppError::Database(format!("create asset_downloads TTL index: {e}")))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl AssetDownloadRepository for MongoAssetDownloadRepository {
+    async fn record(&self, download: AssetDownload) -> Result<(), AppError> {
+        self.collection.insert_one(download).await?;
+        Ok(())
+    }
+
+    async fn list_recent(
+        &self,
+        s3_key: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<AssetDownload>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let filter = match s3_key {
+            Some(key) => doc! { "s3_key": key },
+            None => doc! {},
+        };
+        let downloads = self
+            .collection
+            .find(filter)
+            .sort(doc! { "downloaded_at": -1 })
+            .limit(limit)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(downloads)
+    }
+}
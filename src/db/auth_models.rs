@@ -66,7 +66,8 @@ pub struct AccessLevelEntity {
     pub created_at: DateTime<Utc>,
 }
 
-/// A user registered via OAuth2 or OIDC self-service login.
+/// A user registered via OAuth2 or OIDC self-service login, or a local
+/// account created by an administrator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     /// Internal UUID (primary key).
@@ -108,6 +109,31 @@ pub struct User {
         with = "option_bson_datetime"
     )]
     pub last_login_at: Option<DateTime<Utc>>,
+    /// Password credentials of a local account (`provider_type = "local"`).
+    /// Never returned by the admin API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<LocalPassword>,
+}
+
+impl User {
+    /// Copy of the user without password credentials, for API responses.
+    pub fn without_credentials(mut self) -> Self {
+        self.password = None;
+        self
+    }
+}
+
+/// Argon2 password hash of a local account.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LocalPassword {
+    /// PHC-format Argon2id hash.
+    pub hash: String,
+    /// Set when an administrator assigned the password; cleared when the user
+    /// chooses a new one.
+    #[serde(default)]
+    pub change_required: bool,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub updated_at: DateTime<Utc>,
 }
 
 /// A long-lived opaque token used to obtain new JWT access tokens.
@@ -182,6 +208,7 @@ mod tests {
             can_write_draft: false,
             created_at: Utc::now(),
             last_login_at: None,
+            password: None,
         };
         let json = serde_json::to_string(&user).unwrap();
         let de: User = serde_json::from_str(&json).unwrap();
@@ -212,6 +239,7 @@ mod tests {
             can_write_draft: false,          // default
             created_at: Utc::now(),
             last_login_at: None,
+            password: None,
         };
         let json = serde_json::to_string(&user).unwrap();
         let de: User = serde_json::from_str(&json).unwrap();
@@ -0,0 +1,212 @@
+//! Repository for documentation bundles — doc sets published from one
+//! repository under a common slug root, tagged with a source version.
+//!
+//! The latest bundle keeps the canonical slugs (`<root>/<page>`); prior
+//! versions, e.g. for maintained release branches, are published as ordinary
+//! documents under `<root>/@<version>/<page>`.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Maximum length of a bundle version tag.
+pub const MAX_BUNDLE_VERSION_LEN: usize = 64;
+
+/// Whether `version` is a valid bundle version tag: letters, digits and
+/// `.`, `-`, `_`, `+` (release names or git SHAs).
+pub fn is_valid_bundle_version(version: &str) -> bool {
+    !version.is_empty()
+        && version.len() <= MAX_BUNDLE_VERSION_LEN
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '+'))
+}
+
+/// Split a slug of a prior bundle version into `(root, version, page)`,
+/// e.g. `"billing/@v1/setup/install"` into `("billing", "v1", "setup/install")`.
+/// The page is empty for the version's own section.
+pub fn split_versioned_slug(slug: &str) -> Option<(&str, &str, &str)> {
+    let (root, rest) = slug.split_once("/@")?;
+    let (version, page) = rest.split_once('/').unwrap_or((rest, ""));
+    (!root.is_empty() && !version.is_empty()).then_some((root, version, page))
+}
+
+/// Whether the slug belongs to a prior bundle version.
+pub fn is_versioned_slug(slug: &str) -> bool {
+    split_versioned_slug(slug).is_some()
+}
+
+/// Slug of `page` in a version of the bundle at `root`; `None` is the latest.
+pub fn bundle_slug(root: &str, version: Option<&str>, page: &str) -> String {
+    let base = match version {
+        Some(version) => format!("{root}/@{version}"),
+        None => root.to_string(),
+    };
+    if page.is_empty() {
+        base
+    } else {
+        format!("{base}/{page}")
+    }
+}
+
+/// A published version of a bundle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleVersion {
+    /// Source version tag (release name or git SHA).
+    pub version: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub published_at: DateTime<Utc>,
+}
+
+/// The published versions of a doc set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocBundle {
+    /// Slug root of the bundle (e.g. `"protocols/billing"`).
+    #[serde(rename = "_id")]
+    pub root: String,
+    /// Source version of the docs at the canonical slugs.
+    #[serde(default)]
+    pub latest: Option<BundleVersion>,
+    /// Prior versions published under `<root>/@<version>`, newest first.
+    #[serde(default)]
+    pub versions: Vec<BundleVersion>,
+}
+
+impl DocBundle {
+    /// Record a published version: the latest one, or a prior version.
+    /// Publishing a prior version again moves it to the front.
+    pub fn record(&mut self, version: &str, latest: bool, published_at: DateTime<Utc>) {
+        let entry = BundleVersion {
+            version: version.to_string(),
+            published_at,
+        };
+        if latest {
+            self.latest = Some(entry);
+        } else {
+            self.versions.retain(|v| v.version != version);
+            self.versions.insert(0, entry);
+        }
+    }
+}
+
+/// Storage for documentation bundles.
+#[async_trait]
+pub trait BundleRepository: Send + Sync {
+    /// Record that `version` of the bundle at `root` was published.
+    async fn record_version(&self, root: &str, version: &str, latest: bool)
+        -> Result<(), AppError>;
+
+    async fn find(&self, root: &str) -> Result<Option<DocBundle>, AppError>;
+
+    async fn list_all(&self) -> Result<Vec<DocBundle>, AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoBundleRepository {
+    collection: mongodb::Collection<DocBundle>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoBundleRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("doc_bundles"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl BundleRepository for MongoBundleRepository {
+    async fn record_version(
+        &self,
+        root: &str,
+        version: &str,
+        latest: bool,
+    ) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::ReplaceOptions;
+
+        let mut bundle = self.find(root).await?.unwrap_or_else(|| DocBundle {
+            root: root.to_string(),
+            latest: None,
+            versions: vec![],
+        });
+        bundle.record(version, latest, Utc::now());
+
+        let options = ReplaceOptions::builder().upsert(true).build();
+        self.collection
+            .replace_one(doc! { "_id": root }, &bundle)
+            .with_options(options)
+            .await?;
+        Ok(())
+    }
+
+    async fn find(&self, root: &str) -> Result<Option<DocBundle>, AppError> {
+        use mongodb::bson::doc;
+
+        Ok(self.collection.find_one(doc! { "_id": root }).await?)
+    }
+
+    async fn list_all(&self) -> Result<Vec<DocBundle>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let bundles = self.collection.find(doc! {}).await?.try_collect().await?;
+        Ok(bundles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_versioned_slug() {
+        assert_eq!(
+            split_versioned_slug("billing/@v1/setup/install"),
+            Some(("billing", "v1", "setup/install"))
+        );
+        assert_eq!(
+            split_versioned_slug("protocols/billing/@2.3.0"),
+            Some(("protocols/billing", "2.3.0", ""))
+        );
+        assert_eq!(split_versioned_slug("billing/setup"), None);
+        assert_eq!(split_versioned_slug("billing/@"), None);
+        assert_eq!(
+            bundle_slug("billing", Some("v1"), "setup/install"),
+            "billing/@v1/setup/install"
+        );
+        assert_eq!(bundle_slug("billing", None, ""), "billing");
+    }
+
+    #[test]
+    fn test_bundle_version_validation() {
+        assert!(is_valid_bundle_version("v2.3.0"));
+        assert!(is_valid_bundle_version("1a2b3c4d"));
+        assert!(is_valid_bundle_version("1.0.0+build_7"));
+        assert!(!is_valid_bundle_version(""));
+        assert!(!is_valid_bundle_version("release/1.x"));
+        assert!(!is_valid_bundle_version(&"a".repeat(65)));
+    }
+
+    #[test]
+    fn test_record_moves_republished_version_first() {
+        let mut bundle = DocBundle {
+            root: "billing".into(),
+            latest: None,
+            versions: vec![],
+        };
+        bundle.record("v1", false, Utc::now());
+        bundle.record("v2", false, Utc::now());
+        bundle.record("v1", false, Utc::now());
+        bundle.record("abc123", true, Utc::now());
+
+        let versions: Vec<&str> = bundle.versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(versions, ["v1", "v2"]);
+        assert_eq!(bundle.latest.unwrap().version, "abc123");
+    }
+}
@@ -0,0 +1,330 @@
+//! Follow writes to the `documents` and `schemas` collections through MongoDB
+//! change streams, whoever makes them.
+//!
+//! Writes through the API index the document in search and publish an
+//! activity event themselves. Writes that bypass it — migrations, manual
+//! fixes in a Mongo shell, restores — left search stale and open document
+//! pages unaware until the next reconciliation. With
+//! `database.watch_changes` enabled, each replica follows both collections
+//! and, after collecting changes for `database.change_debounce_ms`:
+//!
+//! - reconciles the search entries of the changed documents, and runs a
+//!   full reconciliation when documents were deleted (their slugs are not
+//!   in the change event);
+//! - delivers a `document.updated` / `document.archived` or
+//!   `schema.ingested` event to its own subscribers, unless the API already
+//!   announced a change to the same target. These events are not relayed to
+//!   other replicas (each follows the streams itself) and don't notify
+//!   watchers.
+//!
+//! No cache is invalidated: the server keeps no navigation or rendered-HTML
+//! cache, so the next request already reads the changed data.
+//!
+//! Change streams need a replica set or a sharded cluster; on a standalone
+//! server the watcher logs a warning and stops.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use mongodb::bson::{self, Document as BsonDocument};
+use mongodb::change_stream::event::{OperationType, ResumeToken};
+use mongodb::options::FullDocumentType;
+use tokio::sync::mpsc;
+
+use crate::api::events::{ActivityEvent, ActivityEvents, ActivityKind};
+use crate::db::models::{Document, Schema};
+use crate::db::repository::DocumentRepository;
+use crate::search::client::SearchService;
+use crate::search::reindex::SearchReindexState;
+use crate::storage::client::StorageClient;
+
+/// Actor of the events delivered for changes noticed in the database.
+const CHANGE_ACTOR: &str = "database";
+
+/// Longest wait before reopening a change stream that failed.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// A change noticed in one of the followed collections.
+#[derive(Debug)]
+enum Change {
+    /// A document was inserted, updated or replaced.
+    Document(Box<Document>),
+    /// Documents were deleted or the collection dropped; the change events
+    /// don't say which slugs.
+    DocumentsRemoved,
+    /// A schema was inserted, updated or replaced.
+    Schema(Box<Schema>),
+}
+
+/// Follows the collections and applies their changes; see the module docs.
+pub struct ChangeWatcher {
+    pub db: mongodb::Database,
+    pub document_repo: Arc<dyn DocumentRepository>,
+    pub storage: Arc<dyn StorageClient>,
+    pub search: Option<Arc<dyn SearchService>>,
+    pub search_reindex_state: Option<Arc<SearchReindexState>>,
+    pub events: ActivityEvents,
+    pub debounce: Duration,
+}
+
+impl ChangeWatcher {
+    /// Follow both collections until the process exits.
+    pub fn spawn(self) {
+        let (changes, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(follow(
+            self.db.collection("documents"),
+            changes.clone(),
+            document_change,
+        ));
+        tokio::spawn(follow(
+            self.db.collection("schemas"),
+            changes,
+            schema_change,
+        ));
+        tokio::spawn(self.handle(receiver));
+    }
+
+    async fn handle(self, mut changes: mpsc::UnboundedReceiver<Change>) {
+        while let Some(first) = changes.recv().await {
+            tokio::time::sleep(self.debounce).await;
+            let mut batch = vec![first];
+            while let Ok(change) = changes.try_recv() {
+                batch.push(change);
+            }
+            self.apply(batch).await;
+        }
+    }
+
+    async fn apply(&self, batch: Vec<Change>) {
+        // Latest state per slug or schema name
+        let mut documents = BTreeMap::new();
+        let mut schemas = BTreeMap::new();
+        let mut removed = false;
+        for change in batch {
+            match change {
+                Change::Document(doc) => {
+                    documents.insert(doc.slug.clone(), doc);
+                }
+                Change::DocumentsRemoved => removed = true,
+                Change::Schema(schema) => {
+                    schemas.insert(schema.name.clone(), schema);
+                }
+            }
+        }
+
+        // Give API writes of the same documents time to announce themselves
+        let window = self.debounce * 2;
+        for doc in documents.values() {
+            let kind = if doc.is_archived {
+                ActivityKind::DocumentArchived
+            } else {
+                ActivityKind::DocumentUpdated
+            };
+            let event = ActivityEvent::document(kind, doc, CHANGE_ACTOR);
+            if self.events.deliver_unannounced(event, window) {
+                tracing::debug!(slug = %doc.slug, "Announced document changed outside the API");
+            }
+        }
+        for schema in schemas.values() {
+            let Some(version) = schema.versions.iter().rev().find(|v| !v.is_archived) else {
+                continue;
+            };
+            self.events.deliver_unannounced(
+                ActivityEvent::schema_ingested(
+                    &schema.name,
+                    version,
+                    schema.owner_access_level.as_deref(),
+                    CHANGE_ACTOR,
+                ),
+                window,
+            );
+        }
+
+        let Some(search) = &self.search else {
+            return;
+        };
+        if removed {
+            self.reconcile_all(search.clone()).await;
+        } else if !documents.is_empty() {
+            let slugs: Vec<String> = documents.into_keys().collect();
+            match crate::search::reindex::reconcile_documents(
+                self.document_repo.as_ref(),
+                self.storage.as_ref(),
+                search.as_ref(),
+                &slugs,
+            )
+            .await
+            {
+                Ok(0) => {}
+                Ok(fixed) => {
+                    tracing::info!(fixed, "Reconciled search entries of changed documents")
+                }
+                Err(e) => tracing::warn!("Failed to reconcile changed documents in search: {e}"),
+            }
+        }
+    }
+
+    async fn reconcile_all(&self, search: Arc<dyn SearchService>) {
+        let Some(reindex) = &self.search_reindex_state else {
+            return;
+        };
+        if reindex
+            .is_running
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            // A reindex or reconciliation already running covers the deletion
+            return;
+        }
+        crate::search::reindex::run_reconciliation(
+            reindex.clone(),
+            self.document_repo.clone(),
+            self.storage.clone(),
+            search,
+        )
+        .await;
+    }
+}
+
+/// Forward the changes of `collection` to `changes`, reopening the stream
+/// where it left off after errors.
+async fn follow(
+    collection: mongodb::Collection<BsonDocument>,
+    changes: mpsc::UnboundedSender<Change>,
+    parse: fn(OperationType, Option<BsonDocument>) -> Option<Change>,
+) {
+    let name = collection.name().to_string();
+    let mut resume_token: Option<ResumeToken> = None;
+    let mut opened = false;
+    let mut retry_delay = Duration::from_secs(1);
+    loop {
+        let mut watch = collection
+            .watch()
+            .full_document(FullDocumentType::UpdateLookup);
+        if let Some(token) = resume_token.clone() {
+            watch = watch.resume_after(token);
+        }
+        let mut stream = match watch.await {
+            Ok(stream) => stream,
+            Err(e) if !opened => {
+                tracing::warn!(
+                    collection = %name,
+                    "Change streams unavailable, writes outside the API are not followed (a replica set is required): {e}"
+                );
+                return;
+            }
+            Err(e) => {
+                tracing::warn!(collection = %name, "Failed to reopen change stream: {e}");
+                tokio::time::sleep(retry_delay).await;
+                retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+                continue;
+            }
+        };
+        if !opened {
+            tracing::info!(collection = %name, "Following changes");
+            opened = true;
+        }
+        retry_delay = Duration::from_secs(1);
+
+        while let Some(event) = stream.next().await {
+            match event {
+                Ok(event) => {
+                    resume_token = if event.operation_type == OperationType::Invalidate {
+                        // The stream cannot be resumed past an invalidation
+                        None
+                    } else {
+                        Some(event.id.clone())
+                    };
+                    if let Some(change) = parse(event.operation_type, event.full_document) {
+                        if changes.send(change).is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(collection = %name, "Change stream failed: {e}");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn document_change(operation: OperationType, full: Option<BsonDocument>) -> Option<Change> {
+    match operation {
+        OperationType::Insert | OperationType::Update | OperationType::Replace => {
+            // An update looked up after the document was deleted has no
+            // full document
+            let Some(full) = full else {
+                return Some(Change::DocumentsRemoved);
+            };
+            match bson::from_document::<Document>(full) {
+                Ok(doc) => Some(Change::Document(Box::new(doc))),
+                Err(e) => {
+                    tracing::warn!("Ignoring change to an unreadable document: {e}");
+                    None
+                }
+            }
+        }
+        OperationType::Delete
+        | OperationType::Drop
+        | OperationType::DropDatabase
+        | OperationType::Rename => Some(Change::DocumentsRemoved),
+        _ => None,
+    }
+}
+
+fn schema_change(operation: OperationType, full: Option<BsonDocument>) -> Option<Change> {
+    match operation {
+        OperationType::Insert | OperationType::Update | OperationType::Replace => {
+            match bson::from_document::<Schema>(full?) {
+                Ok(schema) => Some(Change::Schema(Box::new(schema))),
+                Err(e) => {
+                    tracing::warn!("Ignoring change to an unreadable schema: {e}");
+                    None
+                }
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::doc;
+
+    fn stored_document(slug: &str) -> BsonDocument {
+        doc! {
+            "slug": slug,
+            "title": "Setup",
+            "s3_key": format!("docs/{slug}.md"),
+            "access_level": "public",
+            "service_owner": "platform",
+            "last_updated": bson::DateTime::now(),
+            "tags": [],
+            "links_out": [],
+            "backlinks": [],
+        }
+    }
+
+    #[test]
+    fn test_document_change_parses_writes_and_removals() {
+        let change = document_change(OperationType::Update, Some(stored_document("guides/setup")));
+        assert!(matches!(change, Some(Change::Document(doc)) if doc.slug == "guides/setup"));
+
+        assert!(matches!(
+            document_change(OperationType::Update, None),
+            Some(Change::DocumentsRemoved)
+        ));
+        assert!(matches!(
+            document_change(OperationType::Delete, None),
+            Some(Change::DocumentsRemoved)
+        ));
+        assert!(document_change(OperationType::Insert, Some(doc! { "slug": 1 })).is_none());
+        assert!(document_change(OperationType::Invalidate, None).is_none());
+    }
+}
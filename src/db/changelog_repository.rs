@@ -0,0 +1,114 @@
+//! Repository for service changelog entries — short release notes published from CI.
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Release notes for one version of a service.
+///
+/// Entries are keyed by `(service, version)`: publishing the same version
+/// again replaces its notes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    /// Service name (e.g. `"payments-api"`).
+    pub service: String,
+    /// Released version, as the service names it (e.g. `"2.4.0"`).
+    pub version: String,
+    /// Release date; entries are listed newest first by this date.
+    pub date: NaiveDate,
+    /// Markdown release notes.
+    pub notes: String,
+    /// Access level name required to read the entry.
+    pub access_level: String,
+    /// Name of the service token that published the entry.
+    pub published_by: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Storage for changelog entries.
+#[async_trait]
+pub trait ChangelogRepository: Send + Sync {
+    /// Insert an entry, replacing any existing entry for the same service and version.
+    async fn upsert(&self, entry: ChangelogEntry) -> Result<(), AppError>;
+
+    /// List entries newest first, optionally for a single service.
+    ///
+    /// `allowed_levels = None` means admin (unrestricted).
+    async fn list(
+        &self,
+        allowed_levels: Option<&[String]>,
+        service: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<ChangelogEntry>, AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoChangelogRepository {
+    collection: mongodb::Collection<ChangelogEntry>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoChangelogRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("changelog_entries"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl ChangelogRepository for MongoChangelogRepository {
+    async fn upsert(&self, entry: ChangelogEntry) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::ReplaceOptions;
+
+        let options = ReplaceOptions::builder().upsert(true).build();
+        self.collection
+            .replace_one(
+                doc! { "service": &entry.service, "version": &entry.version },
+                &entry,
+            )
+            .with_options(options)
+            .await?;
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        allowed_levels: Option<&[String]>,
+        service: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<ChangelogEntry>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+        use mongodb::options::FindOptions;
+
+        let mut filter = doc! {};
+        if let Some(levels) = allowed_levels {
+            filter.insert("access_level", doc! { "$in": levels.to_vec() });
+        }
+        if let Some(service) = service {
+            filter.insert("service", service);
+        }
+
+        // `date` is stored as an ISO string, so it sorts chronologically
+        let options = FindOptions::builder()
+            .sort(doc! { "date": -1, "created_at": -1 })
+            .limit(limit)
+            .build();
+
+        let entries = self
+            .collection
+            .find(filter)
+            .with_options(options)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(entries)
+    }
+}
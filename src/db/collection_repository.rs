@@ -0,0 +1,232 @@
+//! Repository for document collections — ordered reading lists such as
+//! "New joiner onboarding" — and each user's progress through them.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Most documents a collection may list.
+pub const MAX_COLLECTION_DOCUMENTS: usize = 100;
+
+/// An ordered list of documents, shown at `/collections/<id>`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Collection {
+    /// URL segment, derived from the title when the collection is created.
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// Document slugs in reading order.
+    pub slugs: Vec<String>,
+    /// User who created the collection; they and admins can change it.
+    pub owner_id: String,
+    pub owner_email: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The documents of a collection a user marked as read.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionProgress {
+    pub user_id: String,
+    pub collection_id: String,
+    #[serde(default)]
+    pub read: Vec<String>,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// URL segment of a collection titled `title`: lowercase words joined by `-`.
+pub fn collection_id(title: &str) -> String {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Storage for collections and reading progress.
+#[async_trait]
+pub trait CollectionRepository: Send + Sync {
+    /// All collections, sorted by title.
+    async fn list_all(&self) -> Result<Vec<Collection>, AppError>;
+
+    async fn find(&self, id: &str) -> Result<Option<Collection>, AppError>;
+
+    /// Store a new collection. Returns `Conflict` if its ID is taken.
+    async fn insert(&self, collection: Collection) -> Result<(), AppError>;
+
+    /// Replace an existing collection. Returns `NotFound` if it does not exist.
+    async fn update(&self, collection: Collection) -> Result<(), AppError>;
+
+    /// Delete a collection and the progress of every user through it.
+    /// Returns `NotFound` if it does not exist.
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+
+    /// The user's progress through every collection they started.
+    async fn list_progress(&self, user_id: &str) -> Result<Vec<CollectionProgress>, AppError>;
+
+    /// Mark `slug` of a collection as read, or unread, for a user.
+    async fn set_read(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+        slug: &str,
+        read: bool,
+    ) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoCollectionRepository {
+    collections: mongodb::Collection<Collection>,
+    progress: mongodb::Collection<CollectionProgress>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoCollectionRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collections: db.collection("collections"),
+            progress: db.collection("collection_progress"),
+        }
+    }
+
+    /// Ensure one progress record per user and collection.
+    pub async fn ensure_indexes(&self) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::IndexOptions;
+        use mongodb::IndexModel;
+
+        self.progress
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "user_id": 1, "collection_id": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await
+            .map_err(|e| AppError::Database(format!("create collection_progress index: {e}")))?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl CollectionRepository for MongoCollectionRepository {
+    async fn list_all(&self) -> Result<Vec<Collection>, AppError> {
+        use futures::TryStreamExt;
+
+        let mut collections: Vec<Collection> = self
+            .collections
+            .find(mongodb::bson::doc! {})
+            .await?
+            .try_collect()
+            .await?;
+        collections.sort_by_cached_key(|c| c.title.to_lowercase());
+        Ok(collections)
+    }
+
+    async fn find(&self, id: &str) -> Result<Option<Collection>, AppError> {
+        Ok(self
+            .collections
+            .find_one(mongodb::bson::doc! { "_id": id })
+            .await?)
+    }
+
+    async fn insert(&self, collection: Collection) -> Result<(), AppError> {
+        let id = collection.id.clone();
+        match self.collections.insert_one(collection).await {
+            Ok(_) => Ok(()),
+            Err(e) if crate::db::edit_lock_repository::is_duplicate_key(&e) => {
+                Err(AppError::Conflict(format!(
+                    "A collection with the URL '/collections/{id}' already exists"
+                )))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn update(&self, collection: Collection) -> Result<(), AppError> {
+        let id = collection.id.clone();
+        let result = self
+            .collections
+            .replace_one(mongodb::bson::doc! { "_id": &id }, collection)
+            .await?;
+        if result.matched_count == 0 {
+            return Err(AppError::NotFound(format!("Collection '{id}' not found")));
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let result = self.collections.delete_one(doc! { "_id": id }).await?;
+        if result.deleted_count == 0 {
+            return Err(AppError::NotFound(format!("Collection '{id}' not found")));
+        }
+        self.progress
+            .delete_many(doc! { "collection_id": id })
+            .await?;
+        Ok(())
+    }
+
+    async fn list_progress(&self, user_id: &str) -> Result<Vec<CollectionProgress>, AppError> {
+        use futures::TryStreamExt;
+
+        Ok(self
+            .progress
+            .find(mongodb::bson::doc! { "user_id": user_id })
+            .await?
+            .try_collect()
+            .await?)
+    }
+
+    async fn set_read(
+        &self,
+        user_id: &str,
+        collection_id: &str,
+        slug: &str,
+        read: bool,
+    ) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let now = mongodb::bson::DateTime::from_chrono(Utc::now());
+        let change = if read {
+            doc! { "$addToSet": { "read": slug }, "$set": { "updated_at": now } }
+        } else {
+            doc! { "$pull": { "read": slug }, "$set": { "updated_at": now } }
+        };
+        self.progress
+            .update_one(
+                doc! { "user_id": user_id, "collection_id": collection_id },
+                change,
+            )
+            .upsert(true)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_id() {
+        assert_eq!(
+            collection_id("New joiner onboarding"),
+            "new-joiner-onboarding"
+        );
+        assert_eq!(
+            collection_id("  Incident response: the pack! "),
+            "incident-response-the-pack"
+        );
+        assert_eq!(collection_id("!!!"), "");
+    }
+}
@@ -0,0 +1,116 @@
+//! Repository for contract-test reports — results of Pact, Schemathesis and
+//! similar runs against a schema version, published from CI.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Outcome of a contract-test run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractTestOutcome {
+    Passed,
+    Failed,
+}
+
+/// The latest result of one consumer's contract tests against one version
+/// of a schema.
+///
+/// Reports are keyed by `(schema, version, consumer, tool)`: publishing
+/// again replaces the previous result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractTestReport {
+    /// Schema name (e.g. `"payments/ledger"`).
+    pub schema: String,
+    /// Tested schema version.
+    pub version: String,
+    /// Service whose expectations were verified: the consumer for Pact, the
+    /// provider itself for schema-driven tools such as Schemathesis.
+    pub consumer: String,
+    /// Tool that produced the results (e.g. `"pact"`, `"schemathesis"`).
+    pub tool: String,
+    pub outcome: ContractTestOutcome,
+    /// Number of passed checks, when the tool reports it.
+    #[serde(default)]
+    pub passed: Option<u32>,
+    /// Number of failed checks, when the tool reports it.
+    #[serde(default)]
+    pub failed: Option<u32>,
+    /// Link to the full report (CI run, Pact broker page).
+    #[serde(default)]
+    pub report_url: Option<String>,
+    /// Name of the service token that published the report.
+    pub published_by: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub reported_at: DateTime<Utc>,
+}
+
+/// Storage for contract-test reports.
+#[async_trait]
+pub trait ContractTestRepository: Send + Sync {
+    /// Insert a report, replacing any existing report for the same schema,
+    /// version, consumer and tool.
+    async fn upsert(&self, report: ContractTestReport) -> Result<(), AppError>;
+
+    /// All reports for a schema, across its versions.
+    async fn list_for_schema(&self, schema: &str) -> Result<Vec<ContractTestReport>, AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoContractTestRepository {
+    collection: mongodb::Collection<ContractTestReport>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoContractTestRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("contract_test_reports"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl ContractTestRepository for MongoContractTestRepository {
+    async fn upsert(&self, report: ContractTestReport) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::ReplaceOptions;
+
+        let options = ReplaceOptions::builder().upsert(true).build();
+        self.collection
+            .replace_one(
+                doc! {
+                    "schema": &report.schema,
+                    "version": &report.version,
+                    "consumer": &report.consumer,
+                    "tool": &report.tool,
+                },
+                &report,
+            )
+            .with_options(options)
+            .await?;
+        Ok(())
+    }
+
+    async fn list_for_schema(&self, schema: &str) -> Result<Vec<ContractTestReport>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+        use mongodb::options::FindOptions;
+
+        let options = FindOptions::builder()
+            .sort(doc! { "consumer": 1, "tool": 1 })
+            .build();
+        let reports = self
+            .collection
+            .find(doc! { "schema": schema })
+            .with_options(options)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(reports)
+    }
+}
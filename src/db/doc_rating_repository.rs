@@ -0,0 +1,230 @@
+//! Repository for "Was this helpful?" ratings of documents.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// A reader's rating of a document. Each reader has at most one rating per
+/// document; rating again replaces it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocRating {
+    pub slug: String,
+    pub user_id: String,
+    pub user_email: String,
+    pub helpful: bool,
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Ratings of one document, or of several summed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RatingCounts {
+    pub helpful: u64,
+    pub not_helpful: u64,
+}
+
+impl RatingCounts {
+    pub fn total(&self) -> u64 {
+        self.helpful + self.not_helpful
+    }
+
+    /// Share of helpful ratings, rounded, or `None` without ratings.
+    pub fn helpful_percent(&self) -> Option<u64> {
+        let total = self.total();
+        (total > 0).then(|| (self.helpful * 100 + total / 2) / total)
+    }
+
+    pub fn add(&mut self, other: RatingCounts) {
+        self.helpful += other.helpful;
+        self.not_helpful += other.not_helpful;
+    }
+}
+
+/// Ratings of a document, with how many came with a comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocRatingCounts {
+    pub slug: String,
+    pub counts: RatingCounts,
+    pub comment_count: u64,
+}
+
+#[async_trait]
+pub trait DocRatingRepository: Send + Sync {
+    /// Store a rating, replacing the reader's previous rating of the document.
+    async fn upsert(&self, rating: DocRating) -> Result<(), AppError>;
+
+    /// The rating a reader gave a document, if any.
+    async fn find(&self, slug: &str, user_id: &str) -> Result<Option<DocRating>, AppError>;
+
+    async fn counts(&self, slug: &str) -> Result<RatingCounts, AppError>;
+
+    /// Counts of every rated document, sorted by slug.
+    async fn counts_by_document(&self) -> Result<Vec<DocRatingCounts>, AppError>;
+
+    /// Most recent ratings of a document that came with a comment.
+    async fn list_comments(&self, slug: &str, limit: i64) -> Result<Vec<DocRating>, AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoDocRatingRepository {
+    collection: mongodb::Collection<DocRating>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoDocRatingRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("doc_ratings"),
+        }
+    }
+
+    /// Ensure one rating per reader and document.
+    pub async fn ensure_indexes(&self) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::IndexOptions;
+        use mongodb::IndexModel;
+
+        self.collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "slug": 1, "user_id": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await
+            .map_err(|e| AppError::Database(format!("create doc_ratings index: {e}")))?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Deserialize)]
+struct CountsRow {
+    #[serde(rename = "_id")]
+    slug: String,
+    helpful: i64,
+    not_helpful: i64,
+    comment_count: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl From<CountsRow> for DocRatingCounts {
+    fn from(row: CountsRow) -> Self {
+        Self {
+            slug: row.slug,
+            counts: RatingCounts {
+                helpful: row.helpful.max(0) as u64,
+                not_helpful: row.not_helpful.max(0) as u64,
+            },
+            comment_count: row.comment_count.max(0) as u64,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl MongoDocRatingRepository {
+    async fn aggregate_counts(
+        &self,
+        filter: mongodb::bson::Document,
+    ) -> Result<Vec<DocRatingCounts>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let pipeline = vec![
+            doc! { "$match": filter },
+            doc! { "$group": {
+                "_id": "$slug",
+                "helpful": { "$sum": { "$cond": ["$helpful", 1, 0] } },
+                "not_helpful": { "$sum": { "$cond": ["$helpful", 0, 1] } },
+                "comment_count": { "$sum": { "$cond": [{ "$eq": [{ "$type": "$comment" }, "string"] }, 1, 0] } },
+            } },
+            doc! { "$sort": { "_id": 1 } },
+        ];
+        let rows: Vec<CountsRow> = self
+            .collection
+            .aggregate(pipeline)
+            .with_type::<CountsRow>()
+            .await?
+            .try_collect()
+            .await?;
+        Ok(rows.into_iter().map(DocRatingCounts::from).collect())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl DocRatingRepository for MongoDocRatingRepository {
+    async fn upsert(&self, rating: DocRating) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.collection
+            .replace_one(
+                doc! { "slug": &rating.slug, "user_id": &rating.user_id },
+                &rating,
+            )
+            .upsert(true)
+            .await?;
+        Ok(())
+    }
+
+    async fn find(&self, slug: &str, user_id: &str) -> Result<Option<DocRating>, AppError> {
+        Ok(self
+            .collection
+            .find_one(mongodb::bson::doc! { "slug": slug, "user_id": user_id })
+            .await?)
+    }
+
+    async fn counts(&self, slug: &str) -> Result<RatingCounts, AppError> {
+        Ok(self
+            .aggregate_counts(mongodb::bson::doc! { "slug": slug })
+            .await?
+            .into_iter()
+            .next()
+            .map(|row| row.counts)
+            .unwrap_or_default())
+    }
+
+    async fn counts_by_document(&self) -> Result<Vec<DocRatingCounts>, AppError> {
+        self.aggregate_counts(mongodb::bson::doc! {}).await
+    }
+
+    async fn list_comments(&self, slug: &str, limit: i64) -> Result<Vec<DocRating>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        Ok(self
+            .collection
+            .find(doc! { "slug": slug, "comment": { "$type": "string" } })
+            .sort(doc! { "updated_at": -1 })
+            .limit(limit)
+            .await?
+            .try_collect()
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_helpful_percent_rounds() {
+        assert_eq!(RatingCounts::default().helpful_percent(), None);
+        let mut counts = RatingCounts {
+            helpful: 2,
+            not_helpful: 1,
+        };
+        assert_eq!(counts.helpful_percent(), Some(67));
+        counts.add(RatingCounts {
+            helpful: 0,
+            not_helpful: 1,
+        });
+        assert_eq!(counts.total(), 4);
+        assert_eq!(counts.helpful_percent(), Some(50));
+    }
+}
@@ -16,7 +16,7 @@ pub struct DocumentVersion {
     /// SHA-256 hash of the content at this version.
     pub content_hash: String,
     /// S3 key where the historical content is stored.
-    /// Format: `docs/history/{slug_escaped}/{version}.md`
+    /// See [`KeyLayout::document_version_key`](crate::config::KeyLayout::document_version_key).
     pub s3_key: String,
     /// Who triggered this version (token name or "legacy").
     pub updated_by: String,
@@ -0,0 +1,175 @@
+//! Repository for advisory editor locks — who is currently editing a document.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// An advisory lock on a document, held by the user whose editor is open.
+///
+/// The holder's editor refreshes the lock with a heartbeat; once it stops
+/// (tab closed, network lost), the lock expires and anyone may take it over.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EditLock {
+    /// Slug of the locked document; one lock per document.
+    #[serde(rename = "_id")]
+    pub slug: String,
+    pub user_id: String,
+    /// Display name of the holder, shown to other editors.
+    pub user_name: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+}
+
+impl EditLock {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Result of a lock acquisition attempt.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LockAttempt {
+    /// The caller now holds the lock (new, refreshed or taken over).
+    Acquired(EditLock),
+    /// Another user holds an unexpired lock.
+    Held(EditLock),
+}
+
+/// Storage for editor locks.
+#[async_trait]
+pub trait EditLockRepository: Send + Sync {
+    /// Acquire or refresh the lock on `slug` for `ttl`.
+    ///
+    /// Succeeds if the document is unlocked, the lock has expired, or the
+    /// caller already holds it; otherwise returns the current holder.
+    async fn acquire(
+        &self,
+        slug: &str,
+        user_id: &str,
+        user_name: &str,
+        ttl: Duration,
+    ) -> Result<LockAttempt, AppError>;
+
+    /// Release the lock on `slug` if `user_id` holds it.
+    async fn release(&self, slug: &str, user_id: &str) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+///
+/// The slug is the document `_id`, so the upsert in [`acquire`] cannot create
+/// a second lock: if another user holds a live lock, the filter misses and
+/// the insert fails with a duplicate key error.
+///
+/// [`acquire`]: EditLockRepository::acquire
+#[cfg(feature = "ssr")]
+pub struct MongoEditLockRepository {
+    collection: mongodb::Collection<EditLock>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoEditLockRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("edit_locks"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn is_duplicate_key(err: &mongodb::error::Error) -> bool {
+    use mongodb::error::{ErrorKind, WriteFailure};
+
+    const DUPLICATE_KEY: i32 = 11000;
+    match err.kind.as_ref() {
+        ErrorKind::Command(e) => e.code == DUPLICATE_KEY,
+        ErrorKind::Write(WriteFailure::WriteError(e)) => e.code == DUPLICATE_KEY,
+        _ => false,
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl EditLockRepository for MongoEditLockRepository {
+    async fn acquire(
+        &self,
+        slug: &str,
+        user_id: &str,
+        user_name: &str,
+        ttl: Duration,
+    ) -> Result<LockAttempt, AppError> {
+        use mongodb::bson::{doc, DateTime as BsonDateTime};
+        use mongodb::options::{FindOneAndUpdateOptions, ReturnDocument};
+
+        let now = Utc::now();
+        let filter = doc! {
+            "_id": slug,
+            "$or": [
+                { "user_id": user_id },
+                { "expires_at": { "$lte": BsonDateTime::from_chrono(now) } },
+            ],
+        };
+        let update = doc! {
+            "$set": {
+                "user_id": user_id,
+                "user_name": user_name,
+                "expires_at": BsonDateTime::from_chrono(now + ttl),
+            }
+        };
+        let options = FindOneAndUpdateOptions::builder()
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .build();
+
+        match self
+            .collection
+            .find_one_and_update(filter, update)
+            .with_options(options)
+            .await
+        {
+            Ok(Some(lock)) => Ok(LockAttempt::Acquired(lock)),
+            Ok(None) => Err(AppError::Internal(format!(
+                "edit lock upsert for '{slug}' returned no document"
+            ))),
+            Err(err) if is_duplicate_key(&err) => {
+                let holder = self
+                    .collection
+                    .find_one(doc! { "_id": slug })
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::Internal(format!("edit lock for '{slug}' vanished"))
+                    })?;
+                Ok(LockAttempt::Held(holder))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn release(&self, slug: &str, user_id: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.collection
+            .delete_one(doc! { "_id": slug, "user_id": user_id })
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_expiry() {
+        let now = Utc::now();
+        let lock = EditLock {
+            slug: "guides/setup".into(),
+            user_id: "u1".into(),
+            user_name: "Alice".into(),
+            expires_at: now,
+        };
+        assert!(lock.is_expired(now));
+        assert!(!lock.is_expired(now - Duration::seconds(1)));
+    }
+}
@@ -0,0 +1,129 @@
+//! Repository for glossary terms — shared definitions rendered as tooltips in documents.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// A glossary entry.
+///
+/// Terms are unique case-insensitively; the stored spelling is the one shown
+/// on the glossary page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlossaryTerm {
+    /// The term as written (e.g. `"SLO"`, `"Blue-green deployment"`).
+    pub term: String,
+    /// Plain-text definition, shown in tooltips and on `/glossary`.
+    pub definition: String,
+    /// Team or person responsible for the definition.
+    #[serde(default)]
+    pub owner: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl GlossaryTerm {
+    /// Anchor id of the term on the glossary page (`/glossary#<anchor>`).
+    pub fn anchor(&self) -> String {
+        glossary_anchor(&self.term)
+    }
+}
+
+/// Slugify a term into its `/glossary` anchor id.
+pub fn glossary_anchor(term: &str) -> String {
+    term.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// CRUD operations for glossary terms.
+#[async_trait]
+pub trait GlossaryRepository: Send + Sync {
+    /// List all terms, sorted alphabetically.
+    async fn list_all(&self) -> Result<Vec<GlossaryTerm>, AppError>;
+
+    /// Insert a term, or replace the existing entry with the same term
+    /// (compared case-insensitively).
+    async fn upsert(&self, term: GlossaryTerm) -> Result<(), AppError>;
+
+    /// Delete a term. Returns `NotFound` if it does not exist.
+    async fn delete(&self, term: &str) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoGlossaryRepository {
+    collection: mongodb::Collection<GlossaryTerm>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoGlossaryRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("glossary"),
+        }
+    }
+
+    fn term_filter(term: &str) -> mongodb::bson::Document {
+        mongodb::bson::doc! {
+            "term": {
+                "$regex": format!("^{}$", crate::db::repository::regex_escape(term)),
+                "$options": "i",
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl GlossaryRepository for MongoGlossaryRepository {
+    async fn list_all(&self) -> Result<Vec<GlossaryTerm>, AppError> {
+        use futures::TryStreamExt;
+
+        let mut terms: Vec<GlossaryTerm> = self
+            .collection
+            .find(mongodb::bson::doc! {})
+            .await?
+            .try_collect()
+            .await?;
+        terms.sort_by_cached_key(|t| t.term.to_lowercase());
+        Ok(terms)
+    }
+
+    async fn upsert(&self, term: GlossaryTerm) -> Result<(), AppError> {
+        use mongodb::options::ReplaceOptions;
+
+        self.collection
+            .replace_one(Self::term_filter(&term.term), &term)
+            .with_options(ReplaceOptions::builder().upsert(true).build())
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, term: &str) -> Result<(), AppError> {
+        let result = self.collection.delete_one(Self::term_filter(term)).await?;
+        if result.deleted_count == 0 {
+            return Err(AppError::NotFound(format!(
+                "glossary term '{term}' not found"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glossary_anchor() {
+        assert_eq!(glossary_anchor("SLO"), "slo");
+        assert_eq!(
+            glossary_anchor("Blue-green  deployment (BGD)"),
+            "blue-green-deployment-bgd"
+        );
+    }
+}
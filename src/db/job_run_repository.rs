@@ -0,0 +1,153 @@
+//! Repository for the run history of scheduled jobs.
+//!
+//! A run's ID is derived from the job and the scheduled time, so when several
+//! replicas wake up for the same occurrence only the one that records the run
+//! first executes it.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Outcome of a job run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobRunStatus {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// One execution of a scheduled job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRun {
+    /// `<job>:<scheduled_for as unix seconds>`.
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub job: String,
+    /// The occurrence this run belongs to (the trigger time for manual runs).
+    pub scheduled_for: DateTime<Utc>,
+    pub started_at: DateTime<Utc>,
+    #[serde(default)]
+    pub finished_at: Option<DateTime<Utc>>,
+    pub status: JobRunStatus,
+    /// Who started the run: `"scheduler"` or the admin's email.
+    pub triggered_by: String,
+    /// One-line result, e.g. "3 broken links in 2 documents".
+    #[serde(default)]
+    pub summary: String,
+    /// Individual findings (broken links, stale documents, ...).
+    #[serde(default)]
+    pub details: Vec<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl JobRun {
+    /// A new run of `job` for the occurrence at `scheduled_for`.
+    pub fn start(job: &str, scheduled_for: DateTime<Utc>, triggered_by: &str) -> Self {
+        Self {
+            id: format!("{job}:{}", scheduled_for.timestamp()),
+            job: job.to_string(),
+            scheduled_for,
+            started_at: Utc::now(),
+            finished_at: None,
+            status: JobRunStatus::Running,
+            triggered_by: triggered_by.to_string(),
+            summary: String::new(),
+            details: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+/// Storage for job runs.
+#[async_trait]
+pub trait JobRunRepository: Send + Sync {
+    /// Record a started run. Returns `false` when a run with the same ID
+    /// exists, i.e. another replica already claimed this occurrence.
+    async fn claim(&self, run: &JobRun) -> Result<bool, AppError>;
+
+    /// Store the final state of a run.
+    async fn finish(&self, run: &JobRun) -> Result<(), AppError>;
+
+    /// Most recent runs first, optionally of one job only.
+    async fn list_recent(&self, job: Option<&str>, limit: i64) -> Result<Vec<JobRun>, AppError>;
+
+    /// The most recent successful run of `job`.
+    async fn last_succeeded(&self, job: &str) -> Result<Option<JobRun>, AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoJobRunRepository {
+    collection: mongodb::Collection<JobRun>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoJobRunRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("job_runs"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl JobRunRepository for MongoJobRunRepository {
+    async fn claim(&self, run: &JobRun) -> Result<bool, AppError> {
+        match self.collection.insert_one(run).await {
+            Ok(_) => Ok(true),
+            Err(err) if crate::db::edit_lock_repository::is_duplicate_key(&err) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn finish(&self, run: &JobRun) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.collection
+            .replace_one(doc! { "_id": &run.id }, run)
+            .await?;
+        Ok(())
+    }
+
+    async fn list_recent(&self, job: Option<&str>, limit: i64) -> Result<Vec<JobRun>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+        use mongodb::options::FindOptions;
+
+        let filter = match job {
+            Some(job) => doc! { "job": job },
+            None => doc! {},
+        };
+        let options = FindOptions::builder()
+            .sort(doc! { "started_at": -1 })
+            .limit(limit)
+            .build();
+        let runs = self
+            .collection
+            .find(filter)
+            .with_options(options)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(runs)
+    }
+
+    async fn last_succeeded(&self, job: &str) -> Result<Option<JobRun>, AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::FindOneOptions;
+
+        let options = FindOneOptions::builder()
+            .sort(doc! { "started_at": -1 })
+            .build();
+        Ok(self
+            .collection
+            .find_one(doc! { "job": job, "status": "succeeded" })
+            .with_options(options)
+            .await?)
+    }
+}
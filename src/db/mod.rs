@@ -1,24 +1,43 @@
 pub mod access_level_repository;
+pub mod asset_download_repository;
 pub mod asset_repository;
 pub mod auth_models;
+pub mod bundle_repository;
+#[cfg(feature = "ssr")]
+pub mod change_streams;
+pub mod changelog_repository;
 pub mod chat_models;
 pub mod chat_repository;
+pub mod collection_repository;
+pub mod contract_test_repository;
+pub mod doc_rating_repository;
 pub mod document_version_repository;
 pub mod documentation_feedback_models;
 pub mod documentation_feedback_repository;
+pub mod edit_lock_repository;
 pub mod embedding_cache_repository;
 pub mod feedback_repository;
+pub mod glossary_repository;
+pub mod job_run_repository;
 pub mod migration;
 pub mod migrations;
 pub mod models;
 pub mod navigation_order_repository;
+pub mod notification_repository;
+pub mod pending_ingest_repository;
+pub mod project_repository;
 pub mod prompt_models;
 pub mod prompt_repository;
 pub mod prompt_version_repository;
+pub mod quarantine_repository;
 pub mod repository;
 pub mod schema_repository;
 pub mod service_token_models;
 pub mod service_token_repository;
 pub mod settings_repository;
+pub mod share_link_repository;
+pub mod spelling_dictionary_repository;
+pub mod upload_usage_repository;
 pub mod user_prompt_preference_repository;
 pub mod user_repository;
+pub mod watch_repository;
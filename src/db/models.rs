@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 /// Represents a documentation entry stored in MongoDB.
 ///
 /// Corresponds to the `documents` collection defined in REQUIREMENTS.md.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Document {
     /// The URL-safe slug path (e.g., `engineering/deployment-guide`).
     pub slug: String,
@@ -65,6 +65,167 @@ pub struct Document {
     /// was introduced.
     #[serde(default)]
     pub source_path: Option<String>,
+    /// Who made the most recent change: the service token name for API
+    /// ingests, the user's name (or email) for web editor saves.
+    #[serde(default)]
+    pub last_updated_by: Option<String>,
+    /// Distinct authors of this document's revisions, most recent first.
+    #[serde(default)]
+    pub contributors: Vec<String>,
+    /// Access level required to edit the document in the web editor, on top
+    /// of write permission (typically the owning team's level). `None` lets
+    /// every writer who can read the document edit it.
+    #[serde(default)]
+    pub edit_access_level: Option<String>,
+    /// Size of the Markdown content in bytes, counted against the service
+    /// owner's storage quota. `0` until the document is next ingested.
+    #[serde(default)]
+    pub content_size: u64,
+    /// Typed relations to other documents, declared in the metadata.
+    #[serde(default)]
+    pub relations: DocumentRelations,
+    /// Storage keys of the attachments and images the content embeds
+    /// (`assets/<key>`, `images/<file>`). Serving one of them requires read
+    /// access to a document referencing it.
+    #[serde(default)]
+    pub asset_refs: Vec<String>,
+    /// Style findings of the last ingested content (see
+    /// [`crate::rendering::lint`]); empty when linting is off.
+    #[serde(default)]
+    pub lint_findings: Vec<crate::rendering::lint::LintFinding>,
+    /// Keep the document out of search engines: its pages, shared ones
+    /// included, are served with a `noindex` robots directive.
+    #[serde(default)]
+    pub noindex: bool,
+    /// Plain-text opening of the content, used as the page meta description
+    /// when there is no `summary`. Empty until the document is next ingested.
+    #[serde(default)]
+    pub preview: String,
+}
+
+/// Relations a document declares to other documents by slug, for
+/// semantics that links in the content can't express.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentRelations {
+    /// Related reading.
+    #[serde(default)]
+    pub see_also: Vec<String>,
+    /// Documents to read first.
+    #[serde(default)]
+    pub prerequisite: Vec<String>,
+    /// Documents this one replaces.
+    #[serde(default)]
+    pub supersedes: Vec<String>,
+    /// Documents replacing this one, declared on the old document when the
+    /// replacement can't be edited to list it in `supersedes`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub superseded_by: Vec<String>,
+}
+
+impl DocumentRelations {
+    pub fn is_empty(&self) -> bool {
+        self.see_also.is_empty()
+            && self.prerequisite.is_empty()
+            && self.supersedes.is_empty()
+            && self.superseded_by.is_empty()
+    }
+
+    /// Every related slug, in declaration order (may repeat).
+    pub fn slugs(&self) -> impl Iterator<Item = &String> {
+        self.see_also
+            .iter()
+            .chain(&self.prerequisite)
+            .chain(&self.supersedes)
+            .chain(&self.superseded_by)
+    }
+
+    /// Relations of the document at `slug` with slugs trimmed (a leading
+    /// `/docs/` or `/` is dropped), blanks, duplicates and self-references
+    /// removed.
+    pub fn normalized(&self, slug: &str) -> Self {
+        let clean = |slugs: &[String]| {
+            let mut cleaned: Vec<String> = Vec::with_capacity(slugs.len());
+            for target in slugs {
+                let target = target.trim();
+                let target = target.strip_prefix("/docs/").unwrap_or(target);
+                let target = target.trim_matches('/');
+                if !target.is_empty() && target != slug && !cleaned.iter().any(|t| t == target) {
+                    cleaned.push(target.to_string());
+                }
+            }
+            cleaned
+        };
+        Self {
+            see_also: clean(&self.see_also),
+            prerequisite: clean(&self.prerequisite),
+            supersedes: clean(&self.supersedes),
+            superseded_by: clean(&self.superseded_by),
+        }
+    }
+}
+
+/// Changes applied to several documents at once by
+/// `PATCH /api/v1/documents:batch`. Unset fields are left alone; `delete`
+/// cannot be combined with the other changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentBatchUpdate {
+    pub slugs: Vec<String>,
+    #[serde(default)]
+    pub service_owner: Option<String>,
+    #[serde(default)]
+    pub access_level: Option<String>,
+    #[serde(default)]
+    pub add_tags: Vec<String>,
+    #[serde(default)]
+    pub remove_tags: Vec<String>,
+    /// New parent in the navigation tree; an empty string moves the
+    /// documents to the top level.
+    #[serde(default)]
+    pub parent_slug: Option<String>,
+    /// Archive the documents, as a sync with `archive_missing` does.
+    #[serde(default)]
+    pub delete: bool,
+}
+
+/// Outcome of a [`DocumentBatchUpdate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentBatchResult {
+    /// Slugs of the changed documents.
+    pub updated: Vec<String>,
+    /// Requested slugs with no active document.
+    pub not_found: Vec<String>,
+}
+
+/// Active (non-archived) documents and content bytes of one service owner.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnerUsage {
+    pub service_owner: String,
+    pub document_count: u64,
+    pub storage_bytes: u64,
+}
+
+/// A service owner's usage next to its configured quota, for the admin usage view.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnerQuotaUsage {
+    pub service_owner: String,
+    pub document_count: u64,
+    pub storage_bytes: u64,
+    /// `None` when the owner's document count is unlimited.
+    pub max_documents: Option<u64>,
+    /// `None` when the owner's storage is unlimited.
+    pub max_storage_bytes: Option<u64>,
+}
+
+/// Maximum number of contributors remembered per document.
+pub const MAX_CONTRIBUTORS: usize = 20;
+
+/// Put `author` at the front of an existing contributors list, dropping any
+/// earlier occurrence and capping the list at [`MAX_CONTRIBUTORS`].
+pub fn merge_contributors(previous: &[String], author: &str) -> Vec<String> {
+    std::iter::once(author.to_string())
+        .chain(previous.iter().filter(|c| *c != author).cloned())
+        .take(MAX_CONTRIBUTORS)
+        .collect()
 }
 
 /// Represents an API schema entry stored in MongoDB.
@@ -82,6 +243,29 @@ pub struct Schema {
     /// Tags used for filtering or grouping in the UI.
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Short summary of what the API is for.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Who to ask about the API: an email address or a URL (chat channel,
+    /// issue tracker).
+    #[serde(default)]
+    pub contact: Option<String>,
+    /// Repository holding the API definition.
+    #[serde(default)]
+    pub repository_url: Option<String>,
+    /// Access level (typically the owning team's) that callers must hold,
+    /// on top of each version's own level, to see the schema at all.
+    /// Admins are never restricted.
+    #[serde(default)]
+    pub owner_access_level: Option<String>,
+    /// When a version or the schema metadata last changed. `None` for
+    /// schemas not ingested since this field was added.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::db::auth_models::option_bson_datetime"
+    )]
+    pub updated_at: Option<DateTime<Utc>>,
     /// Versioned entries.
     pub versions: Vec<SchemaVersion>,
 }
@@ -98,6 +282,18 @@ pub struct SchemaEndpoint {
     pub summary: Option<String>,
 }
 
+impl SchemaEndpoint {
+    /// Whether the path or summary contains `query`, ignoring case.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.path.to_lowercase().contains(&query)
+            || self
+                .summary
+                .as_deref()
+                .is_some_and(|s| s.to_lowercase().contains(&query))
+    }
+}
+
 /// A single version of a schema artifact.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchemaVersion {
@@ -189,11 +385,30 @@ pub struct IngestRequest {
     /// Whether to hide from navigation (defaults to false).
     #[serde(default)]
     pub is_hidden: bool,
+    /// Whether search engines should skip the document (defaults to false).
+    #[serde(default)]
+    pub noindex: bool,
+    /// Access level required to edit the document in the web editor
+    /// (e.g. the owning team's level). Omit to let any writer edit it.
+    #[serde(default)]
+    pub edit_access_level: Option<String>,
+    /// Typed relations to other documents (`see_also`, `prerequisite`,
+    /// `supersedes`), by slug.
+    #[serde(default)]
+    pub relations: DocumentRelations,
     /// The relative path of the source file within the repository (e.g.,
     /// `docs/guides/intro.md`). Required for stable slug tracking across
     /// title changes. Used by the server to resolve the canonical slug for
     /// a document when the desired slug would differ from the stored one.
     pub source_path: String,
+    /// Only apply the ingest if the stored document has not been modified
+    /// after this time (`If-Unmodified-Since` semantics); otherwise `409`.
+    #[serde(default)]
+    pub expected_last_updated: Option<DateTime<Utc>>,
+    /// Only apply the ingest if the stored document's `content_hash` (its
+    /// revision id) equals this value; otherwise `409`.
+    #[serde(default)]
+    pub expected_content_hash: Option<String>,
 }
 
 /// The response from a successful ingest operation.
@@ -209,6 +424,33 @@ pub struct IngestResponse {
     /// `false` when the content hash and all metadata fields match the existing document.
     #[serde(default = "default_true")]
     pub changed: bool,
+    /// `true` when the request was a dry run and nothing was written.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    /// `true` when the slug is protected and the ingest was queued for
+    /// admin approval instead of being published.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pending_approval: bool,
+    /// Fields that changed (or would change, on a dry run); `["created"]` for new documents.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<String>,
+    /// Non-fatal problems found during validation (dry runs only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Style findings of the content, when linting is enabled for the slug.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lint: Vec<crate::rendering::lint::LintFinding>,
+    /// Words missing from the dictionary (dry runs only, when spell checking
+    /// is enabled).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub misspellings: Vec<crate::rendering::spelling::Misspelling>,
+    /// `last_updated` of the stored document after this request, to pass as
+    /// `expected_last_updated` on the next ingest. `None` when nothing is stored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<DateTime<Utc>>,
+    /// `content_hash` of the stored document after this request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
 }
 
 fn default_true() -> bool {
@@ -227,19 +469,15 @@ mod tests {
             summary: Some("How to deploy services to Kubernetes.".to_string()),
             s3_key: "docs/eng/deploy_v4.md".to_string(),
             access_level: "internal".to_string(),
-            is_draft: false,
             service_owner: "devops-team".to_string(),
             last_updated: Utc::now(),
             tags: vec!["k8s".to_string(), "cicd".to_string()],
             links_out: vec!["/docs/setup".to_string()],
-            backlinks: vec![],
             parent_slug: Some("engineering".to_string()),
             order: 10,
-            is_hidden: false,
             content_hash: Some("sha256:abc123".to_string()),
-            metadata_hash: None,
-            is_archived: false,
             source_path: Some("engineering/deployment-guide.md".to_string()),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&doc).unwrap();
@@ -284,6 +522,30 @@ mod tests {
         assert_eq!(doc.content_hash, None); // backward compat
         assert_eq!(doc.summary, None); // backward compat
         assert_eq!(doc.source_path, None); // backward compat
+        assert_eq!(doc.last_updated_by, None); // backward compat
+        assert!(doc.contributors.is_empty()); // backward compat
+    }
+
+    #[test]
+    fn test_merge_contributors_moves_author_to_front() {
+        let previous = vec!["alice".to_string(), "ci-token".to_string()];
+        assert_eq!(
+            merge_contributors(&previous, "ci-token"),
+            vec!["ci-token".to_string(), "alice".to_string()]
+        );
+        assert_eq!(
+            merge_contributors(&previous, "bob"),
+            vec![
+                "bob".to_string(),
+                "alice".to_string(),
+                "ci-token".to_string()
+            ]
+        );
+
+        let many: Vec<String> = (0..MAX_CONTRIBUTORS).map(|i| format!("user-{i}")).collect();
+        let merged = merge_contributors(&many, "newcomer");
+        assert_eq!(merged.len(), MAX_CONTRIBUTORS);
+        assert_eq!(merged[0], "newcomer");
     }
 
     #[test]
@@ -315,22 +577,12 @@ mod tests {
         let doc = Document {
             slug: "engineering/wip".to_string(),
             title: "Work in Progress".to_string(),
-            summary: None,
             s3_key: "docs/wip.md".to_string(),
             access_level: "internal".to_string(),
             is_draft: true,
             service_owner: "platform-team".to_string(),
             last_updated: Utc::now(),
-            tags: vec![],
-            links_out: vec![],
-            backlinks: vec![],
-            parent_slug: None,
-            order: 0,
-            is_hidden: false,
-            content_hash: None,
-            metadata_hash: None,
-            is_archived: false,
-            source_path: None,
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&doc).unwrap();
@@ -398,6 +650,8 @@ mod tests {
         assert_eq!(req.summary, None);
         assert!(!req.is_draft);
         assert_eq!(req.order, 0);
+        assert_eq!(req.expected_last_updated, None);
+        assert_eq!(req.expected_content_hash, None);
     }
 
     #[test]
@@ -407,9 +661,21 @@ mod tests {
             slug: "docs/hello".to_string(),
             s3_key: "docs/hello/v1.md".to_string(),
             changed: true,
+            dry_run: false,
+            pending_approval: false,
+            changes: vec!["created".to_string()],
+            warnings: vec![],
+            lint: vec![],
+            misspellings: vec![],
+            last_updated: None,
+            content_hash: None,
         };
         let json = serde_json::to_string(&resp).unwrap();
         assert!(json.contains("docs/hello"));
+        assert!(json.contains(r#""changes":["created"]"#));
+        // Dry-run-only fields are omitted from regular responses
+        assert!(!json.contains("dry_run"));
+        assert!(!json.contains("warnings"));
     }
 
     #[test]
@@ -439,6 +705,11 @@ mod tests {
             schema_type: "openapi".to_string(),
             service_owner: "payments".to_string(),
             tags: vec!["payments".to_string(), "api".to_string()],
+            description: Some("Card payments".to_string()),
+            contact: Some("payments@example.com".to_string()),
+            repository_url: Some("https://git.example.com/payments/api".to_string()),
+            owner_access_level: Some("team-payments".to_string()),
+            updated_at: None,
             versions: vec![
                 SchemaVersion {
                     version: "1.0.0".to_string(),
@@ -466,5 +737,9 @@ mod tests {
         let deserialized: Schema = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.versions.len(), 2);
         assert_eq!(deserialized.versions[1].status, "stable");
+        assert_eq!(
+            deserialized.owner_access_level.as_deref(),
+            Some("team-payments")
+        );
     }
 }
@@ -0,0 +1,272 @@
+//! Repository for the in-app notification inbox.
+//!
+//! Notifications are created through [`crate::notifications::Inbox`], which
+//! also applies the retention limits.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Kind of an approval requested from admins for a queued ingest.
+pub const APPROVAL_REQUESTED: &str = "approval.requested";
+/// Kind of the notice that a queued ingest was published.
+pub const APPROVAL_APPROVED: &str = "approval.approved";
+/// Kind of the notice that a queued ingest was discarded.
+pub const APPROVAL_REJECTED: &str = "approval.rejected";
+/// Kind of an `@mention` of the recipient.
+pub const MENTION: &str = "mention";
+/// Kind of the alert that an ingest or editor save was refused because its
+/// content contained credentials.
+pub const SECRET_DETECTED: &str = "secret.detected";
+
+/// An entry in a user's inbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub user_id: String,
+    /// What happened: a document activity kind (e.g. `document.updated`) for
+    /// watches, [`MENTION`], [`SECRET_DETECTED`], or one of the `approval.*`
+    /// kinds.
+    pub kind: String,
+    pub slug: String,
+    pub title: String,
+    /// Service token or user that caused the notification.
+    pub actor: String,
+    /// The watch target that matched, for document changes.
+    #[serde(default)]
+    pub watch_target: Option<String>,
+    /// The handle that was mentioned, for mentions.
+    #[serde(default)]
+    pub handle: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub read: bool,
+}
+
+impl Notification {
+    /// An unread notification of `kind` for `user_id`.
+    pub fn new(
+        user_id: impl Into<String>,
+        kind: impl Into<String>,
+        slug: impl Into<String>,
+        title: impl Into<String>,
+        actor: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: user_id.into(),
+            kind: kind.into(),
+            slug: slug.into(),
+            title: title.into(),
+            actor: actor.into(),
+            watch_target: None,
+            handle: None,
+            created_at: Utc::now(),
+            read: false,
+        }
+    }
+
+    /// Short past-tense label, e.g. `"updated"`.
+    pub fn action(&self) -> &'static str {
+        match self.kind.as_str() {
+            "document.created" => "created",
+            "document.archived" => "archived",
+            MENTION => "mentioned",
+            APPROVAL_REQUESTED => "submitted",
+            APPROVAL_APPROVED => "approved",
+            APPROVAL_REJECTED => "rejected",
+            SECRET_DETECTED => "blocked",
+            _ => "updated",
+        }
+    }
+
+    /// One-line description, e.g. `ci updated guides/setup ("Setup")`.
+    pub fn text(&self) -> String {
+        let target = format!("{} (\"{}\")", self.slug, self.title);
+        match self.kind.as_str() {
+            MENTION => format!(
+                "{} mentioned @{} in {target}",
+                self.actor,
+                self.handle.as_deref().unwrap_or_default()
+            ),
+            APPROVAL_REQUESTED => format!("{} submitted {target} for approval", self.actor),
+            SECRET_DETECTED => format!(
+                "{} tried to publish {target} with credentials in it",
+                self.actor
+            ),
+            _ => format!("{} {} {target}", self.actor, self.action()),
+        }
+    }
+
+    /// Where the notification leads: the approval queue for approval
+    /// requests, nowhere for rejected ingests and blocked content, the
+    /// document otherwise.
+    pub fn link(&self) -> Option<String> {
+        match self.kind.as_str() {
+            APPROVAL_REQUESTED => Some("/admin/approvals".to_string()),
+            APPROVAL_REJECTED | SECRET_DETECTED => None,
+            _ => Some(format!("/docs/{}", self.slug)),
+        }
+    }
+}
+
+/// Storage for notifications.
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    async fn create(&self, notification: Notification) -> Result<(), AppError>;
+
+    /// Drop the user's oldest notifications beyond the newest `keep`.
+    async fn trim(&self, user_id: &str, keep: u64) -> Result<(), AppError>;
+
+    /// Delete every notification created before `cutoff`. Returns how many
+    /// were deleted.
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, AppError>;
+
+    /// The user's notifications, newest first.
+    async fn list_for_user(&self, user_id: &str, limit: i64)
+        -> Result<Vec<Notification>, AppError>;
+
+    async fn count_unread(&self, user_id: &str) -> Result<u64, AppError>;
+
+    /// Mark one notification, or all of them when `id` is `None`, as read.
+    async fn mark_read(&self, user_id: &str, id: Option<&str>) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoNotificationRepository {
+    collection: mongodb::Collection<Notification>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoNotificationRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("notifications"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl NotificationRepository for MongoNotificationRepository {
+    async fn create(&self, notification: Notification) -> Result<(), AppError> {
+        self.collection.insert_one(notification).await?;
+        Ok(())
+    }
+
+    async fn trim(&self, user_id: &str, keep: u64) -> Result<(), AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+        use mongodb::options::FindOptions;
+
+        let options = FindOptions::builder()
+            .sort(doc! { "created_at": -1 })
+            .skip(keep)
+            .build();
+        let overflow: Vec<String> = self
+            .collection
+            .find(doc! { "user_id": user_id })
+            .with_options(options)
+            .await?
+            .map_ok(|n| n.id)
+            .try_collect()
+            .await?;
+        if !overflow.is_empty() {
+            self.collection
+                .delete_many(doc! { "_id": { "$in": overflow } })
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, AppError> {
+        use mongodb::bson::doc;
+
+        // `created_at` is stored as an RFC 3339 string in UTC, which sorts
+        // by time when formatted the same way
+        let cutoff = cutoff.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true);
+        let result = self
+            .collection
+            .delete_many(doc! { "created_at": { "$lt": cutoff } })
+            .await?;
+        Ok(result.deleted_count)
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: &str,
+        limit: i64,
+    ) -> Result<Vec<Notification>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+        use mongodb::options::FindOptions;
+
+        let options = FindOptions::builder()
+            .sort(doc! { "created_at": -1 })
+            .limit(limit)
+            .build();
+        let notifications = self
+            .collection
+            .find(doc! { "user_id": user_id })
+            .with_options(options)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(notifications)
+    }
+
+    async fn count_unread(&self, user_id: &str) -> Result<u64, AppError> {
+        use mongodb::bson::doc;
+
+        Ok(self
+            .collection
+            .count_documents(doc! { "user_id": user_id, "read": false })
+            .await?)
+    }
+
+    async fn mark_read(&self, user_id: &str, id: Option<&str>) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let mut filter = doc! { "user_id": user_id, "read": false };
+        if let Some(id) = id {
+            filter.insert("_id", id);
+        }
+        self.collection
+            .update_many(filter, doc! { "$set": { "read": true } })
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_and_link() {
+        let mut mention = Notification::new("u1", MENTION, "guides/intro", "Intro", "bob");
+        mention.handle = Some("sre".into());
+        assert_eq!(
+            mention.text(),
+            "bob mentioned @sre in guides/intro (\"Intro\")"
+        );
+        assert_eq!(mention.link().as_deref(), Some("/docs/guides/intro"));
+
+        let archived = Notification::new("u1", "document.archived", "a/b", "B", "ci");
+        assert_eq!(archived.text(), "ci archived a/b (\"B\")");
+
+        let request = Notification::new("u1", APPROVAL_REQUESTED, "a/b", "B", "ci");
+        assert_eq!(request.link().as_deref(), Some("/admin/approvals"));
+
+        let blocked = Notification::new("u1", SECRET_DETECTED, "a/b", "B", "ci");
+        assert_eq!(
+            blocked.text(),
+            "ci tried to publish a/b (\"B\") with credentials in it"
+        );
+        assert_eq!(blocked.link(), None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Repository for ingests held for admin approval.
+//!
+//! CI ingests to a protected slug prefix (see
+//! [`AppSettings::protected_prefixes`](crate::db::settings_repository::AppSettings::protected_prefixes))
+//! are not published: the request is stored here until an admin approves it
+//! (which runs the ingest) or rejects it.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::db::models::IngestRequest;
+use crate::error::AppError;
+
+/// An ingest waiting for approval. At most one per slug: a newer submission
+/// replaces the pending one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingIngest {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub slug: String,
+    /// The submitted request, with `service_token` cleared.
+    pub request: IngestRequest,
+    /// Name of the service token that submitted it.
+    pub submitted_by: String,
+    /// What the ingest would change, as reported by a dry run.
+    pub changes: Vec<String>,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Whether `slug` falls under one of `prefixes` (`"security/*"` covers
+/// `security` and everything below it; other entries match exactly).
+pub fn is_protected(prefixes: &[String], slug: &str) -> bool {
+    prefixes
+        .iter()
+        .any(|prefix| crate::db::service_token_models::scope_matches(prefix, slug))
+}
+
+/// Storage for pending ingests.
+#[async_trait]
+pub trait PendingIngestRepository: Send + Sync {
+    /// Queue `pending`, replacing any pending ingest for the same slug.
+    async fn submit(&self, pending: PendingIngest) -> Result<(), AppError>;
+
+    /// All pending ingests, oldest first.
+    async fn list_all(&self) -> Result<Vec<PendingIngest>, AppError>;
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<PendingIngest>, AppError>;
+
+    /// Remove a pending ingest. Returns `NotFound` if it does not exist.
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoPendingIngestRepository {
+    collection: mongodb::Collection<PendingIngest>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoPendingIngestRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("pending_ingests"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl PendingIngestRepository for MongoPendingIngestRepository {
+    async fn submit(&self, pending: PendingIngest) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.collection
+            .delete_many(doc! { "slug": &pending.slug })
+            .await?;
+        self.collection.insert_one(pending).await?;
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<PendingIngest>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+        use mongodb::options::FindOptions;
+
+        let options = FindOptions::builder()
+            .sort(doc! { "submitted_at": 1 })
+            .build();
+        let pending = self
+            .collection
+            .find(doc! {})
+            .with_options(options)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(pending)
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<PendingIngest>, AppError> {
+        use mongodb::bson::doc;
+
+        Ok(self.collection.find_one(doc! { "_id": id }).await?)
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let result = self.collection.delete_one(doc! { "_id": id }).await?;
+        if result.deleted_count == 0 {
+            return Err(AppError::NotFound(format!(
+                "Pending ingest '{id}' not found"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protected_prefixes_match_namespace_and_exact_slugs() {
+        let prefixes = vec!["security/*".to_string(), "legal/terms".to_string()];
+        assert!(is_protected(&prefixes, "security"));
+        assert!(is_protected(&prefixes, "security/incident-response"));
+        assert!(is_protected(&prefixes, "legal/terms"));
+        assert!(!is_protected(&prefixes, "legal/privacy"));
+        assert!(!is_protected(&prefixes, "security-team/notes"));
+        assert!(!is_protected(&[], "security/x"));
+    }
+}
@@ -0,0 +1,253 @@
+//! Repository for projects — namespaces that let one instance host several
+//! business units.
+//!
+//! A project owns the top-level slug segment matching its name: documents
+//! under `payments/…` and schemas named `payments/…` belong to project
+//! `payments`. Slugs of different projects therefore never collide, scoped
+//! service tokens can be tied to a single project, and search can be
+//! filtered by project.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// A project (namespace).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    /// Namespace identifier; also the top-level slug segment (e.g. `"payments"`).
+    #[serde(rename = "_id")]
+    pub name: String,
+    /// Display name, used as the project's navigation section title.
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// Access levels documents and schemas of this project may be published
+    /// with. Empty means any registered level.
+    #[serde(default)]
+    pub access_levels: Vec<String>,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Project {
+    /// Whether `slug` (a document slug or schema name) is in this project.
+    pub fn contains(&self, slug: &str) -> bool {
+        project_namespace(slug) == self.name
+    }
+
+    /// Whether content of this project may use `access_level`.
+    pub fn allows_access_level(&self, access_level: &str) -> bool {
+        self.access_levels.is_empty() || self.access_levels.iter().any(|l| l == access_level)
+    }
+}
+
+/// The namespace a slug belongs to: its first path segment.
+pub fn project_namespace(slug: &str) -> &str {
+    slug.split('/').next().unwrap_or_default()
+}
+
+/// Project names are a single slug segment: lowercase letters, digits and `-`.
+pub fn is_valid_project_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Resolve the scopes of a token bound to `project`.
+///
+/// Scopes must lie within the project namespace; no scopes means the whole
+/// project (`<project>/*`).
+pub fn project_token_scopes(project: &str, scopes: Vec<String>) -> Result<Vec<String>, AppError> {
+    if scopes.is_empty() {
+        return Ok(vec![format!("{project}/*")]);
+    }
+    match scopes
+        .iter()
+        .find(|s| project_namespace(s.trim_end_matches("/*")) != project)
+    {
+        Some(outside) => Err(AppError::BadRequest(format!(
+            "Scope '{outside}' is outside project '{project}'"
+        ))),
+        None => Ok(scopes),
+    }
+}
+
+/// Validate the project a new service token is bound to and resolve its
+/// scopes with [`project_token_scopes`]. A blank project leaves the scopes as given.
+pub async fn resolve_token_project(
+    repo: &dyn ProjectRepository,
+    project: Option<&str>,
+    scopes: Vec<String>,
+) -> Result<(Option<String>, Vec<String>), AppError> {
+    let Some(project) = project.map(str::trim).filter(|p| !p.is_empty()) else {
+        return Ok((None, scopes));
+    };
+    if repo.find_by_name(project).await?.is_none() {
+        return Err(AppError::BadRequest(format!(
+            "Unknown project: '{project}'"
+        )));
+    }
+    let scopes = project_token_scopes(project, scopes)?;
+    Ok((Some(project.to_string()), scopes))
+}
+
+/// Reject `access_level` when the project owning `slug` restricts its levels.
+pub async fn check_project_access_level(
+    repo: &dyn ProjectRepository,
+    slug: &str,
+    access_level: &str,
+) -> Result<(), AppError> {
+    if let Some(project) = repo.find_by_name(project_namespace(slug)).await? {
+        if !project.allows_access_level(access_level) {
+            return Err(AppError::BadRequest(format!(
+                "Access level '{access_level}' is not allowed in project '{}' (allowed: {})",
+                project.name,
+                project.access_levels.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// CRUD operations for projects.
+#[async_trait]
+pub trait ProjectRepository: Send + Sync {
+    /// List all projects, sorted by name.
+    async fn list_all(&self) -> Result<Vec<Project>, AppError>;
+
+    /// Find a project by name.
+    async fn find_by_name(&self, name: &str) -> Result<Option<Project>, AppError>;
+
+    /// Insert a project, or replace the existing one with the same name.
+    async fn upsert(&self, project: Project) -> Result<(), AppError>;
+
+    /// Delete a project. Its documents, schemas and tokens are kept. Returns
+    /// `NotFound` if it does not exist.
+    async fn delete(&self, name: &str) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoProjectRepository {
+    collection: mongodb::Collection<Project>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoProjectRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("projects"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl ProjectRepository for MongoProjectRepository {
+    async fn list_all(&self) -> Result<Vec<Project>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+        use mongodb::options::FindOptions;
+
+        let options = FindOptions::builder().sort(doc! { "_id": 1 }).build();
+        let projects = self
+            .collection
+            .find(doc! {})
+            .with_options(options)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(projects)
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Project>, AppError> {
+        use mongodb::bson::doc;
+
+        Ok(self.collection.find_one(doc! { "_id": name }).await?)
+    }
+
+    async fn upsert(&self, project: Project) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::ReplaceOptions;
+
+        self.collection
+            .replace_one(doc! { "_id": &project.name }, &project)
+            .with_options(ReplaceOptions::builder().upsert(true).build())
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let result = self.collection.delete_one(doc! { "_id": name }).await?;
+        if result.deleted_count == 0 {
+            return Err(AppError::NotFound(format!("project '{name}' not found")));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(access_levels: &[&str]) -> Project {
+        Project {
+            name: "payments".to_string(),
+            title: "Payments".to_string(),
+            description: String::new(),
+            access_levels: access_levels.iter().map(|l| l.to_string()).collect(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_project_namespace_and_membership() {
+        assert_eq!(project_namespace("payments/guides/intro"), "payments");
+        assert_eq!(project_namespace("payments"), "payments");
+
+        let p = project(&[]);
+        assert!(p.contains("payments/guides/intro"));
+        assert!(p.contains("payments"));
+        assert!(!p.contains("payments-legacy/intro"));
+        assert!(!p.contains("billing/payments"));
+    }
+
+    #[test]
+    fn test_project_name_validation() {
+        assert!(is_valid_project_name("payments"));
+        assert!(is_valid_project_name("bu-2"));
+        assert!(!is_valid_project_name(""));
+        assert!(!is_valid_project_name("-payments"));
+        assert!(!is_valid_project_name("Payments"));
+        assert!(!is_valid_project_name("pay/ments"));
+    }
+
+    #[test]
+    fn test_project_access_levels() {
+        assert!(project(&[]).allows_access_level("confidential"));
+        let restricted = project(&["internal"]);
+        assert!(restricted.allows_access_level("internal"));
+        assert!(!restricted.allows_access_level("public"));
+    }
+
+    #[test]
+    fn test_project_token_scopes() {
+        assert_eq!(
+            project_token_scopes("payments", vec![]).unwrap(),
+            vec!["payments/*"]
+        );
+        assert!(project_token_scopes(
+            "payments",
+            vec!["payments/api/*".to_string(), "payments".to_string()]
+        )
+        .is_ok());
+        assert!(project_token_scopes("payments", vec!["billing/*".to_string()]).is_err());
+        assert!(project_token_scopes("payments", vec!["*".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+//! Repository for uploads the malware scanner rejected, and for documents
+//! refused because they contain credentials.
+//!
+//! The rejected content is kept in storage under `quarantine/`, which is never
+//! served, so the security team can inspect it before an admin deletes it.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// An upload held back because the scanner found it infected, or a document
+/// held back by the secret guard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantinedUpload {
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Where the content is kept (`quarantine/<id>`).
+    pub s3_key: String,
+    /// Where the upload would have been stored (`assets/<key>`, `images/<file>`
+    /// or `docs/<slug>.md`).
+    pub target_key: String,
+    pub content_type: String,
+    pub size_bytes: u64,
+    /// Malware signature reported by the scanner, or the credentials found
+    /// (`private key on line 3`).
+    pub signature: String,
+    pub uploaded_by: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub quarantined_at: DateTime<Utc>,
+}
+
+/// Storage for quarantined uploads.
+#[async_trait]
+pub trait QuarantineRepository: Send + Sync {
+    async fn create(&self, upload: QuarantinedUpload) -> Result<(), AppError>;
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<QuarantinedUpload>, AppError>;
+
+    /// Most recently quarantined first.
+    async fn list_recent(&self, limit: i64) -> Result<Vec<QuarantinedUpload>, AppError>;
+
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoQuarantineRepository {
+    collection: mongodb::Collection<QuarantinedUpload>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoQuarantineRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("quarantined_uploads"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl QuarantineRepository for MongoQuarantineRepository {
+    async fn create(&self, upload: QuarantinedUpload) -> Result<(), AppError> {
+        self.collection.insert_one(upload).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<QuarantinedUpload>, AppError> {
+        use mongodb::bson::doc;
+
+        Ok(self.collection.find_one(doc! { "_id": id }).await?)
+    }
+
+    async fn list_recent(&self, limit: i64) -> Result<Vec<QuarantinedUpload>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let uploads = self
+            .collection
+            .find(doc! {})
+            .sort(doc! { "quarantined_at": -1 })
+            .limit(limit)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(uploads)
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.collection.delete_one(doc! { "_id": id }).await?;
+        Ok(())
+    }
+}
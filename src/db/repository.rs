@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 
-use crate::db::models::Document;
+use crate::db::models::{Document, OwnerUsage};
 use crate::error::AppError;
 
 /// Repository trait for document operations.
@@ -14,6 +14,25 @@ pub trait DocumentRepository: Send + Sync {
     /// Find a document by its slug.
     async fn find_by_slug(&self, slug: &str) -> Result<Option<Document>, AppError>;
 
+    /// Find the documents with any of the given slugs in one query, in no
+    /// particular order. Slugs without a document are skipped.
+    async fn find_by_slugs(&self, slugs: &[String]) -> Result<Vec<Document>, AppError>;
+
+    /// Find the documents declaring any typed relation to `slug`.
+    async fn find_related_to(&self, slug: &str) -> Result<Vec<Document>, AppError>;
+
+    /// Find the documents declaring they supersede any of `slugs`.
+    async fn find_superseding(&self, slugs: &[String]) -> Result<Vec<Document>, AppError>;
+
+    /// Find the documents embedding the attachment or image stored under
+    /// `s3_key`, archived ones included.
+    async fn find_by_asset_ref(&self, s3_key: &str) -> Result<Vec<Document>, AppError>;
+
+    /// Find the documents whose content is stored under `s3_key`, archived
+    /// ones included. More than one only for slugs colliding under the
+    /// `flat` key layout.
+    async fn find_by_s3_key(&self, s3_key: &str) -> Result<Vec<Document>, AppError>;
+
     /// List every document regardless of access level, draft, hidden, or archive state.
     ///
     /// This is intended for administrative maintenance jobs that must reconcile
@@ -57,10 +76,23 @@ pub trait DocumentRepository: Send + Sync {
     /// Set the `is_archived` flag on a document.
     async fn set_archived(&self, slug: &str, archived: bool) -> Result<(), AppError>;
 
+    /// Replace the `asset_refs` of a document, leaving the rest untouched.
+    async fn set_asset_refs(&self, slug: &str, asset_refs: &[String]) -> Result<(), AppError>;
+
+    /// Point a document at the object holding its content.
+    async fn set_s3_key(&self, slug: &str, s3_key: &str) -> Result<(), AppError>;
+
     /// Find a document by its source file path (e.g. `docs/guides/intro.md`).
     ///
     /// Returns `None` for documents ingested before `source_path` was introduced.
     async fn find_by_source_path(&self, source_path: &str) -> Result<Option<Document>, AppError>;
+
+    /// Count active documents and their content bytes per `service_owner`,
+    /// sorted by owner. `service_owner` restricts the result to one owner.
+    async fn usage_by_service_owner(
+        &self,
+        service_owner: Option<&str>,
+    ) -> Result<Vec<OwnerUsage>, AppError>;
 }
 
 /// MongoDB implementation of the DocumentRepository.
@@ -104,6 +136,84 @@ impl DocumentRepository for MongoDocumentRepository {
         Ok(self.collection.find_one(doc! { "slug": slug }).await?)
     }
 
+    async fn find_by_slugs(&self, slugs: &[String]) -> Result<Vec<Document>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        if slugs.is_empty() {
+            return Ok(vec![]);
+        }
+        let documents = self
+            .collection
+            .find(doc! { "slug": { "$in": slugs } })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(documents)
+    }
+
+    async fn find_related_to(&self, slug: &str) -> Result<Vec<Document>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let documents = self
+            .collection
+            .find(doc! {
+                "$or": [
+                    { "relations.see_also": slug },
+                    { "relations.prerequisite": slug },
+                    { "relations.supersedes": slug },
+                    { "relations.superseded_by": slug },
+                ]
+            })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(documents)
+    }
+
+    async fn find_superseding(&self, slugs: &[String]) -> Result<Vec<Document>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        if slugs.is_empty() {
+            return Ok(vec![]);
+        }
+        let documents = self
+            .collection
+            .find(doc! { "relations.supersedes": { "$in": slugs } })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(documents)
+    }
+
+    async fn find_by_asset_ref(&self, s3_key: &str) -> Result<Vec<Document>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let documents = self
+            .collection
+            .find(doc! { "asset_refs": s3_key })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(documents)
+    }
+
+    async fn find_by_s3_key(&self, s3_key: &str) -> Result<Vec<Document>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let documents = self
+            .collection
+            .find(doc! { "s3_key": s3_key })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(documents)
+    }
+
     async fn list_all(&self) -> Result<Vec<Document>, AppError> {
         use futures::TryStreamExt;
         use mongodb::bson::doc;
@@ -264,6 +374,27 @@ impl DocumentRepository for MongoDocumentRepository {
         Ok(())
     }
 
+    async fn set_asset_refs(&self, slug: &str, asset_refs: &[String]) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.collection
+            .update_one(
+                doc! { "slug": slug },
+                doc! { "$set": { "asset_refs": asset_refs } },
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn set_s3_key(&self, slug: &str, s3_key: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.collection
+            .update_one(doc! { "slug": slug }, doc! { "$set": { "s3_key": s3_key } })
+            .await?;
+        Ok(())
+    }
+
     async fn find_by_source_path(&self, source_path: &str) -> Result<Option<Document>, AppError> {
         use mongodb::bson::doc;
         Ok(self
@@ -271,11 +402,57 @@ impl DocumentRepository for MongoDocumentRepository {
             .find_one(doc! { "source_path": source_path })
             .await?)
     }
+
+    async fn usage_by_service_owner(
+        &self,
+        service_owner: Option<&str>,
+    ) -> Result<Vec<OwnerUsage>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        #[derive(serde::Deserialize)]
+        struct UsageRow {
+            #[serde(rename = "_id")]
+            service_owner: String,
+            document_count: i64,
+            storage_bytes: i64,
+        }
+
+        let mut filter = doc! { "is_archived": { "$ne": true } };
+        if let Some(owner) = service_owner {
+            filter.insert("service_owner", owner);
+        }
+        let pipeline = vec![
+            doc! { "$match": filter },
+            doc! { "$group": {
+                "_id": "$service_owner",
+                "document_count": { "$sum": 1 },
+                "storage_bytes": { "$sum": { "$ifNull": ["$content_size", 0] } },
+            } },
+            doc! { "$sort": { "_id": 1 } },
+        ];
+        let rows: Vec<UsageRow> = self
+            .collection
+            .aggregate(pipeline)
+            .with_type::<UsageRow>()
+            .await?
+            .try_collect()
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| OwnerUsage {
+                service_owner: row.service_owner,
+                document_count: row.document_count.max(0) as u64,
+                storage_bytes: row.storage_bytes.max(0) as u64,
+            })
+            .collect())
+    }
 }
 
 /// Escape special regex characters in a string for use in MongoDB regex queries.
 #[cfg(feature = "ssr")]
-fn regex_escape(s: &str) -> String {
+pub(crate) fn regex_escape(s: &str) -> String {
     let special = [
         '.', '*', '+', '?', '(', ')', '[', ']', '{', '}', '\\', '^', '$', '|',
     ];
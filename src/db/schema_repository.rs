@@ -1,14 +1,95 @@
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 use crate::db::models::{Schema, SchemaVersion};
 use crate::error::AppError;
 
+/// Upper bound on [`SchemaListParams::per_page`].
+pub const MAX_SCHEMAS_PER_PAGE: u64 = 100;
+
+/// Order of [`SchemaRepository::list_page`] results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaSort {
+    #[default]
+    Name,
+    /// Most recently updated first; schemas never updated since the field
+    /// was introduced come last.
+    UpdatedAt,
+}
+
+/// Filters and paging for [`SchemaRepository::list_page`].
+#[derive(Debug, Clone, Default)]
+pub struct SchemaListParams {
+    /// Levels the caller can read; `None` lists every schema (admins).
+    pub allowed_levels: Option<Vec<String>>,
+    pub schema_type: Option<String>,
+    pub service_owner: Option<String>,
+    pub tag: Option<String>,
+    /// Case-insensitive substring of the schema name.
+    pub name_query: Option<String>,
+    pub sort: SchemaSort,
+    /// Zero-based page index.
+    pub page: u64,
+    /// Items per page (capped at [`MAX_SCHEMAS_PER_PAGE`]); `None` returns
+    /// every match.
+    pub per_page: Option<u64>,
+}
+
+/// A page of schemas (without endpoint arrays) and the filter options
+/// available to the caller.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaPage {
+    pub items: Vec<Schema>,
+    /// Schemas matching the filters, across all pages.
+    pub total: u64,
+    /// Owners of every schema visible to the caller, sorted.
+    pub service_owners: Vec<String>,
+    /// Tags of every schema visible to the caller, sorted.
+    pub tags: Vec<String>,
+}
+
+impl SchemaListParams {
+    /// Whether callers with these params can see at least one non-archived
+    /// version of `schema`.
+    pub fn visible(&self, schema: &Schema) -> bool {
+        let readable = |level: &str| {
+            self.allowed_levels
+                .as_ref()
+                .is_none_or(|levels| levels.iter().any(|l| l == level))
+        };
+        schema.owner_access_level.as_deref().is_none_or(readable)
+            && schema
+                .versions
+                .iter()
+                .any(|v| !v.is_archived && readable(&v.access_level))
+    }
+
+    /// Whether a visible `schema` passes the type, owner, tag and name filters.
+    pub fn matches(&self, schema: &Schema) -> bool {
+        self.schema_type
+            .as_ref()
+            .is_none_or(|t| &schema.schema_type == t)
+            && self
+                .service_owner
+                .as_ref()
+                .is_none_or(|o| &schema.service_owner == o)
+            && self.tag.as_ref().is_none_or(|t| schema.tags.contains(t))
+            && self
+                .name_query
+                .as_ref()
+                .is_none_or(|q| schema.name.to_lowercase().contains(&q.to_lowercase()))
+    }
+}
+
 /// Minimal version info returned by `find_version_s3_key`.
 #[derive(Debug, Clone)]
 pub struct SchemaVersionRef {
     pub s3_key: String,
     pub access_level: String,
     pub is_archived: bool,
+    /// The schema's [`owner_access_level`](Schema::owner_access_level).
+    pub owner_access_level: Option<String>,
 }
 
 /// Repository trait for schema operations.
@@ -33,6 +114,10 @@ pub trait SchemaRepository: Send + Sync {
     /// pages that don't need the (potentially large) endpoints arrays.
     async fn list_summaries(&self) -> Result<Vec<Schema>, AppError>;
 
+    /// List one page of the schemas visible to the caller that match
+    /// `params`, without per-version endpoint data.
+    async fn list_page(&self, params: &SchemaListParams) -> Result<SchemaPage, AppError>;
+
     /// List non-archived schemas whose name matches the provided exact or prefix scope.
     async fn find_by_name_prefix(&self, prefix: &str) -> Result<Vec<Schema>, AppError>;
 
@@ -51,8 +136,8 @@ pub trait SchemaRepository: Send + Sync {
     /// Delete a schema by name.
     async fn delete(&self, name: &str) -> Result<(), AppError>;
 
-    /// Fetch only the s3_key, access_level and is_archived flag for a specific
-    /// version, without loading the rest of the schema document.
+    /// Fetch only the s3_key, access levels and is_archived flag for a
+    /// specific version, without loading the rest of the schema document.
     async fn find_version_s3_key(
         &self,
         schema_name: &str,
@@ -151,6 +236,102 @@ impl SchemaRepository for MongoSchemaRepository {
         Ok(schemas)
     }
 
+    async fn list_page(&self, params: &SchemaListParams) -> Result<SchemaPage, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::{doc, Bson};
+        use mongodb::options::FindOptions;
+
+        // Mirrors `SchemaListParams::visible`
+        let mut visible = doc! {};
+        match &params.allowed_levels {
+            None => {
+                visible.insert(
+                    "versions",
+                    doc! { "$elemMatch": { "is_archived": { "$ne": true } } },
+                );
+            }
+            Some(levels) => {
+                visible.insert(
+                    "versions",
+                    doc! { "$elemMatch": {
+                        "is_archived": { "$ne": true },
+                        "access_level": { "$in": levels },
+                    } },
+                );
+                visible.insert(
+                    "$or",
+                    vec![
+                        doc! { "owner_access_level": Bson::Null },
+                        doc! { "owner_access_level": { "$in": levels } },
+                    ],
+                );
+            }
+        }
+
+        let mut filter = visible.clone();
+        if let Some(schema_type) = &params.schema_type {
+            filter.insert("schema_type", schema_type);
+        }
+        if let Some(owner) = &params.service_owner {
+            filter.insert("service_owner", owner);
+        }
+        if let Some(tag) = &params.tag {
+            filter.insert("tags", tag);
+        }
+        if let Some(query) = &params.name_query {
+            filter.insert(
+                "name",
+                doc! { "$regex": regex_escape(query), "$options": "i" },
+            );
+        }
+
+        let sort = match params.sort {
+            SchemaSort::Name => doc! { "name": 1 },
+            SchemaSort::UpdatedAt => doc! { "updated_at": -1, "name": 1 },
+        };
+        let mut options = FindOptions::builder()
+            .sort(sort)
+            .projection(doc! { "versions.endpoints": 0 })
+            .build();
+        if let Some(per_page) = params.per_page {
+            let per_page = per_page.clamp(1, MAX_SCHEMAS_PER_PAGE);
+            options.skip = Some(params.page * per_page);
+            options.limit = Some(per_page as i64);
+        }
+
+        let total = self.collection.count_documents(filter.clone()).await?;
+        let items: Vec<Schema> = self
+            .collection
+            .find(filter)
+            .with_options(options)
+            .await?
+            .try_collect()
+            .await?;
+
+        let distinct_strings = |values: Vec<Bson>| {
+            let mut values: Vec<String> = values
+                .into_iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .filter(|v| !v.is_empty())
+                .collect();
+            values.sort();
+            values
+        };
+        let service_owners = distinct_strings(
+            self.collection
+                .distinct("service_owner", visible.clone())
+                .await?,
+        );
+        let tags = distinct_strings(self.collection.distinct("tags", visible).await?);
+
+        Ok(SchemaPage {
+            items,
+            total,
+            service_owners,
+            tags,
+        })
+    }
+
     async fn find_by_name_prefix(&self, prefix: &str) -> Result<Vec<Schema>, AppError> {
         use futures::TryStreamExt;
         use mongodb::bson::doc;
@@ -234,6 +415,7 @@ impl SchemaRepository for MongoSchemaRepository {
             })?;
 
         schema_version.is_archived = archived;
+        schema.updated_at = Some(chrono::Utc::now());
         self.create_or_update(schema).await
     }
 
@@ -262,11 +444,16 @@ impl SchemaRepository for MongoSchemaRepository {
         struct Projection {
             #[serde(default)]
             versions: Vec<SchemaVersion>,
+            #[serde(default)]
+            owner_access_level: Option<String>,
         }
 
         let col = self.collection.clone_with_type::<Projection>();
         let opts = FindOneOptions::builder()
-            .projection(doc! { "versions": { "$elemMatch": { "version": version } } })
+            .projection(doc! {
+                "versions": { "$elemMatch": { "version": version } },
+                "owner_access_level": 1,
+            })
             .build();
 
         let result = col
@@ -279,6 +466,7 @@ impl SchemaRepository for MongoSchemaRepository {
                 s3_key: v.s3_key,
                 access_level: v.access_level,
                 is_archived: v.is_archived,
+                owner_access_level: r.owner_access_level,
             })
         }))
     }
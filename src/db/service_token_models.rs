@@ -18,7 +18,8 @@ pub struct ServiceToken {
     /// - An exact slug (e.g. `"guidelines/protocols"`)
     /// - A prefix pattern ending with `/*` (e.g. `"protocols/*"`)
     pub allowed_scopes: Vec<String>,
-    /// Token type: `"service"` (scope-based CI/CD) or `"pat"` (inherits user permissions).
+    /// Token type: `"service"` (scope-based CI/CD), `"pat"` (inherits user
+    /// permissions) or `"bot"` (read-only access for chatbots and MCP servers).
     #[serde(default = "default_service")]
     pub token_type: String,
     /// For PATs: the user whose permissions this token inherits. Empty for service tokens.
@@ -39,6 +40,14 @@ pub struct ServiceToken {
     /// Whether this token is active. Deactivated tokens are rejected.
     #[serde(default = "default_true")]
     pub is_active: bool,
+    /// Project the token belongs to. Its scopes are confined to the project
+    /// namespace; `None` for instance-wide tokens.
+    #[serde(default)]
+    pub project: Option<String>,
+    /// For bot tokens: the access levels whose documents the token can read.
+    /// Empty for other token types.
+    #[serde(default)]
+    pub access_levels: Vec<String>,
 }
 
 fn default_true() -> bool {
@@ -54,6 +63,11 @@ impl ServiceToken {
     pub fn is_pat(&self) -> bool {
         self.token_type == "pat"
     }
+
+    /// Returns `true` if this is a read-only bot token.
+    pub fn is_bot(&self) -> bool {
+        self.token_type == "bot"
+    }
 }
 
 impl ServiceToken {
@@ -69,7 +83,7 @@ impl ServiceToken {
 }
 
 /// Check whether a single scope entry matches a slug.
-fn scope_matches(scope: &str, slug: &str) -> bool {
+pub fn scope_matches(scope: &str, slug: &str) -> bool {
     if let Some(prefix) = scope.strip_suffix("/*") {
         // Prefix scope: "protocols/*" matches "protocols", "protocols/x", "protocols/x/y"
         slug == prefix || slug.starts_with(&format!("{prefix}/"))
@@ -125,6 +139,8 @@ mod tests {
             created_at: Utc::now(),
             last_used_at: None,
             is_active: true,
+            project: None,
+            access_levels: vec![],
         }
     }
 
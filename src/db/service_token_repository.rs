@@ -28,7 +28,8 @@ pub trait ServiceTokenRepository: Send + Sync {
     async fn touch_last_used(&self, id: &str) -> Result<(), AppError>;
 
     /// Check whether any existing active token's scopes overlap with `scopes`.
-    /// Optionally exclude one token by ID (useful when updating an existing token).
+    /// Read-only bot tokens are ignored since they never write. Optionally
+    /// exclude one token by ID (useful when updating an existing token).
     async fn check_scope_overlap(
         &self,
         scopes: &[String],
@@ -178,7 +179,7 @@ impl ServiceTokenRepository for MongoServiceTokenRepository {
 
         use crate::db::service_token_models::scopes_overlap;
 
-        let mut filter = doc! { "is_active": true };
+        let mut filter = doc! { "is_active": true, "token_type": { "$ne": "bot" } };
         if let Some(id) = exclude_id {
             filter.insert("id", doc! { "$ne": id });
         }
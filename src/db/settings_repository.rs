@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +16,193 @@ pub struct AppSettings {
     /// Groups for the top-level navbar.
     #[serde(default)]
     pub navbar_groups: Vec<NavGroup>,
+    /// Instance branding (portal name, logo, colours, homepage text, footer).
+    #[serde(default)]
+    pub branding: BrandingSettings,
+    /// Admin-defined links (Grafana, runbooks, on-call…) shown next to the docs.
+    #[serde(default)]
+    pub nav_links: NavLinks,
+    /// Search relevance tuning applied to the Meilisearch index.
+    #[serde(default)]
+    pub search: SearchSettings,
+    /// Slug prefixes (`security/*`) or exact slugs whose service token
+    /// ingests wait for admin approval before publication.
+    #[serde(default)]
+    pub protected_prefixes: Vec<String>,
+    /// Subsystems switched on or off for this deployment.
+    #[serde(default)]
+    pub features: FeatureFlags,
+    /// Runbooks pinned during incidents, and whether one is under way.
+    #[serde(default)]
+    pub incident_mode: IncidentMode,
+    /// On-call schedules of the services, shown on their pages and runbooks.
+    #[serde(default)]
+    pub on_call: OnCallSettings,
+    /// Issue tracker whose issue keys are linked in documents and comments.
+    #[serde(default)]
+    pub issue_tracker: IssueTrackerSettings,
+    /// Permissions derived from LDAP group memberships.
+    #[serde(default)]
+    pub ldap: LdapSettings,
+    /// Links rendered as embedded widgets; `None` until seeded from
+    /// `rendering.embeds` at startup.
+    #[serde(default)]
+    pub embeds: Option<EmbedSettings>,
+}
+
+/// Trim, validate and deduplicate admin-entered protected prefixes.
+pub fn normalize_protected_prefixes(prefixes: Vec<String>) -> Result<Vec<String>, AppError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(prefixes.len());
+    for prefix in prefixes {
+        let prefix = prefix.trim().trim_start_matches('/').to_string();
+        if prefix.is_empty() {
+            continue;
+        }
+        let slug = prefix.strip_suffix("/*").unwrap_or(&prefix);
+        if slug.is_empty()
+            || slug.contains('*')
+            || slug.contains("..")
+            || slug.contains(char::is_whitespace)
+        {
+            return Err(AppError::BadRequest(format!(
+                "Invalid protected prefix '{prefix}': expected a slug or 'prefix/*'"
+            )));
+        }
+        if !normalized.contains(&prefix) {
+            normalized.push(prefix);
+        }
+    }
+    normalized.sort();
+    Ok(normalized)
+}
+
+/// Per-instance branding, editable from the admin settings page.
+///
+/// Every field is optional in storage so that instances which never touched
+/// the branding page keep the stock Lekton look.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BrandingSettings {
+    /// Name shown in the navbar, page titles and homepage hero.
+    pub portal_name: String,
+    /// Logo image URL; overrides the `public/logo*.svg` files when set.
+    pub logo_url: Option<String>,
+    /// Primary colour as a CSS hex value (e.g. `#4f46e5`).
+    pub primary_color: Option<String>,
+    /// Theme applied when the user has not picked one (`light` or `dark`).
+    pub default_theme: Option<String>,
+    /// Homepage hero headline; defaults to "Welcome to {portal_name}".
+    pub hero_title: Option<String>,
+    /// Homepage hero paragraph.
+    pub hero_subtitle: Option<String>,
+    /// Slug of a document rendered as the homepage body instead of the
+    /// built-in hero and feature cards.
+    pub homepage_slug: Option<String>,
+    /// Links rendered in the page footer.
+    pub footer_links: Vec<FooterLink>,
+    /// Where users ask for access to content above their level (a request
+    /// form, a ticket queue or a `mailto:` address), shown on the 403 page.
+    pub access_request_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct FooterLink {
+    pub label: String,
+    pub url: String,
+}
+
+impl Default for BrandingSettings {
+    fn default() -> Self {
+        Self {
+            portal_name: "Lekton".to_string(),
+            logo_url: None,
+            primary_color: None,
+            default_theme: None,
+            hero_title: None,
+            hero_subtitle: None,
+            homepage_slug: None,
+            footer_links: vec![],
+            access_request_url: None,
+        }
+    }
+}
+
+impl BrandingSettings {
+    /// Default `<meta name="description">` of portal pages: the portal name
+    /// followed by the homepage hero paragraph, or `fallback` when none is set.
+    pub fn meta_description(&self, fallback: &str) -> String {
+        let subtitle = self.hero_subtitle.as_deref().unwrap_or(fallback);
+        format!("{}: {subtitle}", self.portal_name)
+    }
+
+    /// Check user-provided values before they are persisted and injected
+    /// into every page.
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.portal_name.trim().is_empty() {
+            return Err(AppError::BadRequest("Portal name must not be empty".into()));
+        }
+        if let Some(color) = &self.primary_color {
+            if !is_hex_color(color) {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid primary color '{color}': expected #rgb or #rrggbb"
+                )));
+            }
+        }
+        if let Some(theme) = &self.default_theme {
+            if theme != "light" && theme != "dark" {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid default theme '{theme}': expected 'light' or 'dark'"
+                )));
+            }
+        }
+        if let Some(url) = &self.logo_url {
+            if !is_safe_url(url) {
+                return Err(AppError::BadRequest(format!("Invalid logo URL '{url}'")));
+            }
+        }
+        if let Some(slug) = &self.homepage_slug {
+            if slug.starts_with('/') || slug.ends_with('/') || slug.contains(char::is_whitespace) {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid homepage document slug '{slug}'"
+                )));
+            }
+        }
+        if let Some(url) = &self.access_request_url {
+            if !is_safe_url(url) && !url.starts_with("mailto:") {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid access request URL '{url}'"
+                )));
+            }
+        }
+        for link in &self.footer_links {
+            if link.label.trim().is_empty() {
+                return Err(AppError::BadRequest(
+                    "Footer link label must not be empty".into(),
+                ));
+            }
+            if !is_safe_url(&link.url) {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid footer link URL '{}'",
+                    link.url
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_hex_color(value: &str) -> bool {
+    value
+        .strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Only absolute http(s) URLs and site-relative paths are accepted, which
+/// rules out `javascript:` and `data:` URLs in admin-editable links.
+fn is_safe_url(value: &str) -> bool {
+    value.starts_with("https://")
+        || value.starts_with("http://")
+        || (value.starts_with('/') && !value.starts_with("//"))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -22,12 +211,587 @@ pub struct NavGroup {
     pub items: Vec<String>,
 }
 
+/// Custom links rendered in the navbar and/or docs sidebar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NavLinks {
+    pub show_in_navbar: bool,
+    pub show_in_sidebar: bool,
+    pub groups: Vec<NavLinkGroup>,
+}
+
+/// A titled group of links; an empty title renders the links ungrouped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct NavLinkGroup {
+    pub title: String,
+    pub links: Vec<NavLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct NavLink {
+    pub label: String,
+    pub url: String,
+}
+
+impl Default for NavLinks {
+    fn default() -> Self {
+        Self {
+            show_in_navbar: true,
+            show_in_sidebar: true,
+            groups: vec![],
+        }
+    }
+}
+
+impl NavLinks {
+    pub fn validate(&self) -> Result<(), AppError> {
+        for link in self.groups.iter().flat_map(|g| &g.links) {
+            if link.label.trim().is_empty() {
+                return Err(AppError::BadRequest("Link label must not be empty".into()));
+            }
+            if !is_safe_url(&link.url) {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid link URL '{}'",
+                    link.url
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Meilisearch relevance tuning, editable via `/api/v1/admin/search/settings`
+/// and applied by [`SearchService::configure_index`](crate::search::client::SearchService::configure_index).
+///
+/// The defaults leave Meilisearch's own behaviour untouched.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct SearchSettings {
+    /// Ranking rules in priority order: the built-in rules (`words`, `typo`,
+    /// `proximity`, `attribute`, `sort`, `exactness`) and `<attribute>:asc|desc`
+    /// on a sortable attribute. Empty keeps Meilisearch's default order.
+    pub ranking_rules: Vec<String>,
+    /// Groups of interchangeable words, e.g. `["k8s", "kubernetes"]`.
+    pub synonyms: Vec<Vec<String>>,
+    /// Words ignored in queries (e.g. `the`, `a`).
+    pub stop_words: Vec<String>,
+    pub typo_tolerance: TypoTolerance,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TypoTolerance {
+    pub enabled: bool,
+    /// Minimum word length accepting one typo.
+    pub min_word_size_one_typo: u8,
+    /// Minimum word length accepting two typos.
+    pub min_word_size_two_typos: u8,
+    /// Words that must be matched exactly (e.g. product names).
+    pub disable_on_words: Vec<String>,
+}
+
+impl Default for TypoTolerance {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_word_size_one_typo: 5,
+            min_word_size_two_typos: 9,
+            disable_on_words: vec![],
+        }
+    }
+}
+
+/// Ranking rules built into Meilisearch.
+const BUILTIN_RANKING_RULES: &[&str] = &[
+    "words",
+    "typo",
+    "proximity",
+    "attribute",
+    "sort",
+    "exactness",
+];
+
+impl SearchSettings {
+    /// Normalize user input (trim, lowercase words, drop blanks) and check it
+    /// can be applied to the index.
+    pub fn normalized(self) -> Result<Self, AppError> {
+        let words = |words: Vec<String>| -> Vec<String> {
+            let mut out: Vec<String> = Vec::new();
+            for word in words {
+                let word = word.trim().to_lowercase();
+                if !word.is_empty() && !out.contains(&word) {
+                    out.push(word);
+                }
+            }
+            out
+        };
+
+        let mut ranking_rules: Vec<String> = Vec::new();
+        for rule in self.ranking_rules {
+            let rule = rule.trim().to_string();
+            let valid = BUILTIN_RANKING_RULES.contains(&rule.as_str())
+                || rule.rsplit_once(':').is_some_and(|(attribute, order)| {
+                    crate::search::client::SORTABLE_ATTRIBUTES.contains(&attribute)
+                        && matches!(order, "asc" | "desc")
+                });
+            if !valid {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid ranking rule '{rule}': expected one of {} or <sortable attribute>:asc|desc",
+                    BUILTIN_RANKING_RULES.join(", ")
+                )));
+            }
+            if ranking_rules.contains(&rule) {
+                return Err(AppError::BadRequest(format!(
+                    "Duplicate ranking rule '{rule}'"
+                )));
+            }
+            ranking_rules.push(rule);
+        }
+
+        let mut synonyms = Vec::new();
+        for group in self.synonyms {
+            let group = words(group);
+            match group.len() {
+                0 => {}
+                1 => {
+                    return Err(AppError::BadRequest(format!(
+                        "Synonym group '{}' needs at least two words",
+                        group[0]
+                    )))
+                }
+                _ => synonyms.push(group),
+            }
+        }
+
+        let typo = self.typo_tolerance;
+        if typo.min_word_size_one_typo > typo.min_word_size_two_typos {
+            return Err(AppError::BadRequest(
+                "Minimum word size for one typo must not exceed the one for two typos".into(),
+            ));
+        }
+
+        Ok(Self {
+            ranking_rules,
+            synonyms,
+            stop_words: words(self.stop_words),
+            typo_tolerance: TypoTolerance {
+                disable_on_words: words(typo.disable_on_words),
+                ..typo
+            },
+        })
+    }
+
+    /// Synonyms as Meilisearch expects them: every word of a group maps to
+    /// the other words of all groups it belongs to.
+    pub fn synonym_map(&self) -> HashMap<String, Vec<String>> {
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+        for group in &self.synonyms {
+            for word in group {
+                let entry = map.entry(word.clone()).or_default();
+                for other in group.iter().filter(|o| *o != word) {
+                    if !entry.contains(other) {
+                        entry.push(other.clone());
+                    }
+                }
+            }
+        }
+        map
+    }
+}
+
+/// Runtime switches for optional subsystems, editable from the admin
+/// settings page. Everything is on by default; a subsystem that is not
+/// configured (no RAG, no Meilisearch) stays off whatever its flag says.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeatureFlags {
+    /// Web editor: the edit button, `/edit/*` and editor saves.
+    pub editor: bool,
+    /// AI assistant: the chat page and the chat and ask endpoints.
+    pub chat: bool,
+    /// Engine behind the search bar and `GET /api/v1/search`.
+    pub search_provider: SearchProvider,
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self {
+            editor: true,
+            chat: true,
+            search_provider: SearchProvider::default(),
+        }
+    }
+}
+
+/// Incident mode, switched on by an admin or an on-call responder. While
+/// it is active the runbooks are pinned to the top of the docs sidebar and
+/// the homepage, and pages are served uncached so runbook edits reach
+/// readers at once.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IncidentMode {
+    pub active: bool,
+    /// Slugs of the runbooks to pin, in order.
+    pub runbooks: Vec<String>,
+    /// Emails of the on-call responders who may switch incident mode on
+    /// and off, besides admins.
+    pub responders: Vec<String>,
+    /// Who last switched incident mode on, and when.
+    pub started_by: Option<String>,
+    #[serde(with = "crate::db::auth_models::option_bson_datetime")]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl IncidentMode {
+    /// Whether `email` belongs to an on-call responder.
+    pub fn is_responder(&self, email: &str) -> bool {
+        self.responders
+            .iter()
+            .any(|responder| responder.eq_ignore_ascii_case(email))
+    }
+}
+
+/// Trim and deduplicate admin-entered runbook slugs and responder emails,
+/// rejecting anything that isn't an email address among the latter.
+pub fn normalize_incident_config(
+    runbooks: Vec<String>,
+    responders: Vec<String>,
+) -> Result<(Vec<String>, Vec<String>), AppError> {
+    let mut slugs: Vec<String> = Vec::with_capacity(runbooks.len());
+    for runbook in &runbooks {
+        let runbook = runbook.trim();
+        let slug = runbook.strip_prefix("/docs/").unwrap_or(runbook);
+        let slug = slug.trim_matches('/');
+        if !slug.is_empty() && !slugs.iter().any(|s| s == slug) {
+            slugs.push(slug.to_string());
+        }
+    }
+
+    let mut emails: Vec<String> = Vec::with_capacity(responders.len());
+    for responder in &responders {
+        let email = responder.trim().to_lowercase();
+        if email.is_empty() {
+            continue;
+        }
+        if email.contains(char::is_whitespace) || !email.contains('@') {
+            return Err(AppError::BadRequest(format!(
+                "'{email}' is not an email address"
+            )));
+        }
+        if !emails.contains(&email) {
+            emails.push(email);
+        }
+    }
+    Ok((slugs, emails))
+}
+
+/// Paging service queried for the current on-call of each service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OnCallProvider {
+    /// No on-call integration.
+    #[default]
+    None,
+    #[serde(rename = "pagerduty")]
+    PagerDuty,
+    Opsgenie,
+}
+
+/// The on-call schedule of a service.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceSchedule {
+    /// Service owner of the documents, or service name of the changelog.
+    pub service: String,
+    /// Schedule ID (Opsgenie also accepts the schedule name).
+    pub schedule_id: String,
+}
+
+/// On-call integration: who is on call for each service, per the paging
+/// service's schedules.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OnCallSettings {
+    pub provider: OnCallProvider,
+    /// API token of the paging service; never sent to the browser.
+    pub api_token: String,
+    /// API base URL; empty for the provider's default (set it for the
+    /// Opsgenie EU region, `https://api.eu.opsgenie.com`).
+    pub api_url: String,
+    pub schedules: Vec<ServiceSchedule>,
+}
+
+impl OnCallSettings {
+    /// The schedule of `service`, when the integration is on.
+    pub fn schedule_for(&self, service: &str) -> Option<&str> {
+        if self.provider == OnCallProvider::None || self.api_token.is_empty() {
+            return None;
+        }
+        self.schedules
+            .iter()
+            .find(|s| s.service.eq_ignore_ascii_case(service))
+            .map(|s| s.schedule_id.as_str())
+    }
+}
+
+/// Trim admin-entered schedules, dropping blank lines and rejecting a
+/// service mapped twice.
+pub fn normalize_service_schedules(
+    schedules: Vec<ServiceSchedule>,
+) -> Result<Vec<ServiceSchedule>, AppError> {
+    let mut normalized: Vec<ServiceSchedule> = Vec::with_capacity(schedules.len());
+    for schedule in schedules {
+        let service = schedule.service.trim();
+        let schedule_id = schedule.schedule_id.trim();
+        if service.is_empty() && schedule_id.is_empty() {
+            continue;
+        }
+        if service.is_empty() || schedule_id.is_empty() {
+            return Err(AppError::BadRequest(
+                "Every schedule needs a service and a schedule ID".into(),
+            ));
+        }
+        if normalized
+            .iter()
+            .any(|s| s.service.eq_ignore_ascii_case(service))
+        {
+            return Err(AppError::BadRequest(format!(
+                "Service '{service}' is mapped more than once"
+            )));
+        }
+        normalized.push(ServiceSchedule {
+            service: service.to_string(),
+            schedule_id: schedule_id.to_string(),
+        });
+    }
+    Ok(normalized)
+}
+
+/// Issue tracker linked from issue keys such as `PROJ-123`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueTrackerProvider {
+    /// Issue keys stay plain text.
+    #[default]
+    None,
+    Jira,
+    Linear,
+}
+
+/// Issue tracker integration: which keys to link, and the credentials to
+/// look up their status.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IssueTrackerSettings {
+    pub provider: IssueTrackerProvider,
+    /// Jira site (`https://acme.atlassian.net`) or Linear workspace
+    /// (`https://linear.app/acme`) the links point to.
+    pub base_url: String,
+    /// Account email for Jira Cloud; empty to send the token as a bearer
+    /// token (Jira Data Center personal access tokens).
+    pub api_user: String,
+    /// API token for status lookups; never sent to the browser. Without
+    /// one, issue keys are linked without a status badge.
+    pub api_token: String,
+    /// Project keys (the `PROJ` of `PROJ-123`) to link.
+    pub projects: Vec<String>,
+}
+
+impl IssueTrackerSettings {
+    /// Link to an issue with `{key}` standing for its key, when issue keys
+    /// are linked.
+    pub fn url_template(&self) -> Option<String> {
+        let base = self.base_url.trim_end_matches('/');
+        if base.is_empty() || self.projects.is_empty() {
+            return None;
+        }
+        match self.provider {
+            IssueTrackerProvider::None => None,
+            IssueTrackerProvider::Jira => Some(format!("{base}/browse/{{key}}")),
+            IssueTrackerProvider::Linear => Some(format!("{base}/issue/{{key}}")),
+        }
+    }
+}
+
+/// Bare links to known tools rendered as embedded widgets.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmbedSettings {
+    /// Enabled providers: `youtube`, `figma`, `miro`, `grafana`. Empty keeps
+    /// plain links.
+    pub providers: Vec<String>,
+    /// Hosts (optionally `host:port`) whose Grafana links are embedded.
+    pub grafana_hosts: Vec<String>,
+}
+
+impl EmbedSettings {
+    /// Lowercase, trim and deduplicate admin-entered providers and hosts,
+    /// rejecting unknown providers and hosts with a scheme or path.
+    pub fn normalize(self) -> Result<Self, AppError> {
+        let mut providers: Vec<String> = Vec::with_capacity(self.providers.len());
+        for name in &self.providers {
+            let name = name.trim().to_ascii_lowercase();
+            if name.is_empty() {
+                continue;
+            }
+            let provider = crate::rendering::embeds::EmbedProvider::from_name(&name)
+                .ok_or_else(|| AppError::BadRequest(format!("Unknown embed provider '{name}'")))?;
+            let name = provider.name().to_string();
+            if !providers.contains(&name) {
+                providers.push(name);
+            }
+        }
+        let mut grafana_hosts: Vec<String> = Vec::with_capacity(self.grafana_hosts.len());
+        for host in &self.grafana_hosts {
+            let host = host.trim().to_ascii_lowercase();
+            if host.is_empty() {
+                continue;
+            }
+            if host.contains(['/', '@', '?', '#']) || host.contains(char::is_whitespace) {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid Grafana host '{host}': expected a host name, optionally with ':port'"
+                )));
+            }
+            if !grafana_hosts.contains(&host) {
+                grafana_hosts.push(host);
+            }
+        }
+        Ok(Self {
+            providers,
+            grafana_hosts,
+        })
+    }
+}
+
+/// Uppercase and deduplicate admin-entered project keys, rejecting
+/// anything that cannot start an issue key.
+pub fn normalize_issue_projects(projects: Vec<String>) -> Result<Vec<String>, AppError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(projects.len());
+    for project in &projects {
+        let project = project.trim().to_ascii_uppercase();
+        if project.is_empty() {
+            continue;
+        }
+        let valid = project.starts_with(|c: char| c.is_ascii_uppercase())
+            && project
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+        if !valid {
+            return Err(AppError::BadRequest(format!(
+                "'{project}' is not a project key: use letters, digits and underscores, starting with a letter"
+            )));
+        }
+        if !normalized.contains(&project) {
+            normalized.push(project);
+        }
+    }
+    Ok(normalized)
+}
+
+/// Access granted to the members of a directory group on LDAP sign-in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LdapGroupMapping {
+    /// Group DN (`cn=payments,ou=groups,dc=acme,dc=com`) or just its common
+    /// name (`payments`), compared case-insensitively.
+    pub group: String,
+    /// Access levels assigned to the group's members.
+    pub access_levels: Vec<String>,
+}
+
+/// How LDAP group memberships map to Lekton permissions. Applied on every
+/// LDAP sign-in, so changes in the directory follow the next login.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LdapSettings {
+    /// Group to access level mappings. When empty, access levels are left
+    /// to administrators (Admin → Users).
+    pub group_mappings: Vec<LdapGroupMapping>,
+    /// Groups whose members are administrators. When empty, the admin flag
+    /// is left to administrators.
+    pub admin_groups: Vec<String>,
+}
+
+impl LdapSettings {
+    /// Access levels mapped to any of `groups` (DNs), deduplicated, in
+    /// mapping order.
+    pub fn access_levels_for(&self, groups: &[String]) -> Vec<String> {
+        let mut levels: Vec<String> = Vec::new();
+        for mapping in &self.group_mappings {
+            if !groups.iter().any(|g| ldap_group_matches(&mapping.group, g)) {
+                continue;
+            }
+            for level in &mapping.access_levels {
+                if !levels.contains(level) {
+                    levels.push(level.clone());
+                }
+            }
+        }
+        levels
+    }
+
+    /// Whether a member of `groups` (DNs) is an administrator, or `None`
+    /// when no admin groups are configured.
+    pub fn is_admin_for(&self, groups: &[String]) -> Option<bool> {
+        if self.admin_groups.is_empty() {
+            return None;
+        }
+        Some(
+            self.admin_groups
+                .iter()
+                .any(|admin| groups.iter().any(|group| ldap_group_matches(admin, group))),
+        )
+    }
+}
+
+/// Whether the configured `pattern` (a DN or a common name) names the group
+/// with DN `group_dn`.
+pub fn ldap_group_matches(pattern: &str, group_dn: &str) -> bool {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return false;
+    }
+    if pattern.eq_ignore_ascii_case(group_dn.trim()) {
+        return true;
+    }
+    let first_rdn = group_dn.split(',').next().unwrap_or_default();
+    match first_rdn.split_once('=') {
+        Some((attribute, value)) => {
+            attribute.trim().eq_ignore_ascii_case("cn")
+                && value.trim().eq_ignore_ascii_case(pattern)
+        }
+        None => false,
+    }
+}
+
+/// Which engine answers search queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchProvider {
+    /// Full-text search blended with semantic search when embeddings are
+    /// configured, plain full-text search otherwise.
+    #[default]
+    Hybrid,
+    /// Meilisearch keyword matching only.
+    FullText,
+    /// Search is turned off.
+    Disabled,
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             key: "global".to_string(),
             custom_css: String::new(),
             navbar_groups: vec![],
+            branding: BrandingSettings::default(),
+            nav_links: NavLinks::default(),
+            search: SearchSettings::default(),
+            protected_prefixes: vec![],
+            features: FeatureFlags::default(),
+            incident_mode: IncidentMode::default(),
+            on_call: OnCallSettings::default(),
+            issue_tracker: IssueTrackerSettings::default(),
+            ldap: LdapSettings::default(),
+            embeds: None,
         }
     }
 }
@@ -40,6 +804,37 @@ pub trait SettingsRepository: Send + Sync {
 
     /// Update the custom CSS.
     async fn set_custom_css(&self, css: &str) -> Result<(), AppError>;
+
+    /// Replace the instance branding.
+    async fn set_branding(&self, branding: &BrandingSettings) -> Result<(), AppError>;
+
+    /// Replace the custom navigation links.
+    async fn set_nav_links(&self, links: &NavLinks) -> Result<(), AppError>;
+
+    /// Replace the search relevance tuning.
+    async fn set_search_settings(&self, search: &SearchSettings) -> Result<(), AppError>;
+
+    /// Replace the slug prefixes whose ingests need admin approval.
+    async fn set_protected_prefixes(&self, prefixes: &[String]) -> Result<(), AppError>;
+
+    /// Replace the subsystem feature flags.
+    async fn set_feature_flags(&self, features: &FeatureFlags) -> Result<(), AppError>;
+
+    /// Replace the incident mode state and runbooks.
+    async fn set_incident_mode(&self, incident_mode: &IncidentMode) -> Result<(), AppError>;
+
+    /// Replace the on-call integration settings.
+    async fn set_on_call(&self, on_call: &OnCallSettings) -> Result<(), AppError>;
+
+    /// Replace the issue tracker integration settings.
+    async fn set_issue_tracker(&self, issue_tracker: &IssueTrackerSettings)
+        -> Result<(), AppError>;
+
+    /// Replace the LDAP group mappings.
+    async fn set_ldap(&self, ldap: &LdapSettings) -> Result<(), AppError>;
+
+    /// Replace the embed providers and Grafana hosts.
+    async fn set_embeds(&self, embeds: &EmbedSettings) -> Result<(), AppError>;
 }
 
 /// MongoDB implementation of the SettingsRepository.
@@ -84,6 +879,199 @@ impl SettingsRepository for MongoSettingsRepository {
 
         Ok(())
     }
+
+    async fn set_branding(&self, branding: &BrandingSettings) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let branding = mongodb::bson::to_bson(branding)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize branding: {e}")))?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "branding": branding } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_nav_links(&self, links: &NavLinks) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let links = mongodb::bson::to_bson(links)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize nav links: {e}")))?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "nav_links": links } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_search_settings(&self, search: &SearchSettings) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let search = mongodb::bson::to_bson(search)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize search settings: {e}")))?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "search": search } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_protected_prefixes(&self, prefixes: &[String]) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "protected_prefixes": prefixes } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_feature_flags(&self, features: &FeatureFlags) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let features = mongodb::bson::to_bson(features)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize feature flags: {e}")))?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "features": features } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_incident_mode(&self, incident_mode: &IncidentMode) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let incident_mode = mongodb::bson::to_bson(incident_mode)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize incident mode: {e}")))?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "incident_mode": incident_mode } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_on_call(&self, on_call: &OnCallSettings) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let on_call = mongodb::bson::to_bson(on_call).map_err(|e| {
+            AppError::Internal(format!("Failed to serialize on-call settings: {e}"))
+        })?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "on_call": on_call } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_issue_tracker(
+        &self,
+        issue_tracker: &IssueTrackerSettings,
+    ) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let issue_tracker = mongodb::bson::to_bson(issue_tracker).map_err(|e| {
+            AppError::Internal(format!("Failed to serialize issue tracker settings: {e}"))
+        })?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "issue_tracker": issue_tracker } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_ldap(&self, ldap: &LdapSettings) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let ldap = mongodb::bson::to_bson(ldap)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize LDAP settings: {e}")))?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "ldap": ldap } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_embeds(&self, embeds: &EmbedSettings) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::UpdateOptions;
+
+        let embeds = mongodb::bson::to_bson(embeds)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize embed settings: {e}")))?;
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        self.collection
+            .update_one(
+                doc! { "key": "global" },
+                doc! { "$set": { "key": "global", "embeds": embeds } },
+            )
+            .with_options(options)
+            .await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -103,9 +1091,360 @@ mod tests {
             key: "global".to_string(),
             custom_css: ":root { --lekton-font-family: monospace; }".to_string(),
             navbar_groups: vec![],
+            branding: BrandingSettings::default(),
+            nav_links: NavLinks::default(),
+            search: SearchSettings::default(),
+            protected_prefixes: vec!["security/*".to_string()],
+            features: FeatureFlags::default(),
+            incident_mode: IncidentMode::default(),
+            on_call: OnCallSettings::default(),
+            issue_tracker: IssueTrackerSettings::default(),
+            ldap: LdapSettings::default(),
+            embeds: None,
         };
         let json = serde_json::to_string(&settings).unwrap();
         let deserialized: AppSettings = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.custom_css, settings.custom_css);
+        assert_eq!(deserialized.protected_prefixes, settings.protected_prefixes);
+    }
+
+    #[test]
+    fn test_protected_prefix_normalization() {
+        let prefixes = normalize_protected_prefixes(vec![
+            " /security/* ".into(),
+            "legal/terms".into(),
+            "".into(),
+            "security/*".into(),
+        ])
+        .unwrap();
+        assert_eq!(prefixes, vec!["legal/terms", "security/*"]);
+
+        for invalid in ["/*", "sec*/x", "a/../b", "has space/*"] {
+            assert!(
+                normalize_protected_prefixes(vec![invalid.into()]).is_err(),
+                "{invalid:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_settings_without_branding_use_defaults() {
+        let settings: AppSettings = serde_json::from_str(r#"{"key":"global"}"#).unwrap();
+        assert_eq!(settings.branding, BrandingSettings::default());
+        assert_eq!(settings.branding.portal_name, "Lekton");
+    }
+
+    #[test]
+    fn test_branding_meta_description() {
+        let default = BrandingSettings::default();
+        assert_eq!(
+            default.meta_description("Your developer portal."),
+            "Lekton: Your developer portal."
+        );
+
+        let custom = BrandingSettings {
+            portal_name: "Acme Docs".into(),
+            hero_subtitle: Some("Everything engineering at Acme.".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            custom.meta_description("Your developer portal."),
+            "Acme Docs: Everything engineering at Acme."
+        );
+    }
+
+    #[test]
+    fn test_feature_flags_default_to_enabled() {
+        let settings: AppSettings = serde_json::from_str(r#"{"key":"global"}"#).unwrap();
+        assert!(settings.features.editor);
+        assert!(settings.features.chat);
+        assert_eq!(settings.features.search_provider, SearchProvider::Hybrid);
+
+        let features: FeatureFlags =
+            serde_json::from_str(r#"{"chat":false,"search_provider":"full_text"}"#).unwrap();
+        assert!(features.editor);
+        assert!(!features.chat);
+        assert_eq!(features.search_provider, SearchProvider::FullText);
+    }
+
+    #[test]
+    fn test_branding_validation() {
+        let valid = BrandingSettings {
+            portal_name: "Acme Portal".into(),
+            logo_url: Some("/assets/logo.png".into()),
+            primary_color: Some("#4F46e5".into()),
+            default_theme: Some("dark".into()),
+            homepage_slug: Some("onboarding/welcome".into()),
+            footer_links: vec![FooterLink {
+                label: "Status".into(),
+                url: "https://status.example.com".into(),
+            }],
+            access_request_url: Some("mailto:docs-access@example.com".into()),
+            ..Default::default()
+        };
+        assert!(valid.validate().is_ok());
+
+        let invalid = [
+            BrandingSettings {
+                portal_name: "  ".into(),
+                ..Default::default()
+            },
+            BrandingSettings {
+                primary_color: Some("red; } body { display: none".into()),
+                ..Default::default()
+            },
+            BrandingSettings {
+                default_theme: Some("cupcake".into()),
+                ..Default::default()
+            },
+            BrandingSettings {
+                logo_url: Some("javascript:alert(1)".into()),
+                ..Default::default()
+            },
+            BrandingSettings {
+                homepage_slug: Some("/docs/welcome".into()),
+                ..Default::default()
+            },
+            BrandingSettings {
+                access_request_url: Some("javascript:alert(1)".into()),
+                ..Default::default()
+            },
+            BrandingSettings {
+                footer_links: vec![FooterLink {
+                    label: "Evil".into(),
+                    url: "//evil.example.com".into(),
+                }],
+                ..Default::default()
+            },
+        ];
+        for branding in invalid {
+            assert!(
+                branding.validate().is_err(),
+                "{branding:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_nav_links_validation() {
+        let mut links = NavLinks {
+            groups: vec![NavLinkGroup {
+                title: "Observability".into(),
+                links: vec![NavLink {
+                    label: "Grafana".into(),
+                    url: "https://grafana.example.com".into(),
+                }],
+            }],
+            ..Default::default()
+        };
+        assert!(links.validate().is_ok());
+
+        links.groups[0].links[0].url = "javascript:alert(1)".into();
+        assert!(links.validate().is_err());
+    }
+
+    #[test]
+    fn test_search_settings_normalization() {
+        let settings = SearchSettings {
+            ranking_rules: vec![
+                " attribute".into(),
+                "words".into(),
+                "last_updated:desc".into(),
+            ],
+            synonyms: vec![
+                vec!["K8s".into(), "kubernetes ".into(), "k8s".into()],
+                vec![],
+            ],
+            stop_words: vec!["The".into(), "".into()],
+            ..Default::default()
+        }
+        .normalized()
+        .unwrap();
+        assert_eq!(
+            settings.ranking_rules,
+            ["attribute", "words", "last_updated:desc"]
+        );
+        assert_eq!(settings.synonyms, [["k8s", "kubernetes"]]);
+        assert_eq!(settings.stop_words, ["the"]);
+        assert_eq!(settings.synonym_map()["k8s"], ["kubernetes"]);
+        assert_eq!(settings.synonym_map()["kubernetes"], ["k8s"]);
+
+        for invalid in [
+            SearchSettings {
+                ranking_rules: vec!["title:desc".into()],
+                ..Default::default()
+            },
+            SearchSettings {
+                ranking_rules: vec!["words".into(), "words".into()],
+                ..Default::default()
+            },
+            SearchSettings {
+                synonyms: vec![vec!["k8s".into()]],
+                ..Default::default()
+            },
+            SearchSettings {
+                typo_tolerance: TypoTolerance {
+                    min_word_size_one_typo: 10,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ] {
+            assert!(invalid.normalized().is_err());
+        }
+    }
+
+    #[test]
+    fn test_normalize_incident_config() {
+        let (runbooks, responders) = normalize_incident_config(
+            vec![
+                " /docs/runbooks/db-failover/ ".into(),
+                "runbooks/db-failover".into(),
+                "".into(),
+                "runbooks/pager".into(),
+            ],
+            vec![
+                "Oncall@Example.com".into(),
+                "oncall@example.com".into(),
+                " ".into(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(runbooks, ["runbooks/db-failover", "runbooks/pager"]);
+        assert_eq!(responders, ["oncall@example.com"]);
+
+        let mode = IncidentMode {
+            responders,
+            ..Default::default()
+        };
+        assert!(mode.is_responder("OnCall@example.com"));
+        assert!(!mode.is_responder("someone@example.com"));
+
+        assert!(normalize_incident_config(vec![], vec!["on call".into()]).is_err());
+    }
+
+    #[test]
+    fn test_on_call_schedules() {
+        let schedule = |service: &str, schedule_id: &str| ServiceSchedule {
+            service: service.into(),
+            schedule_id: schedule_id.into(),
+        };
+        let schedules = normalize_service_schedules(vec![
+            schedule(" payments-team ", " PABC123 "),
+            schedule("", ""),
+        ])
+        .unwrap();
+        assert_eq!(schedules, [schedule("payments-team", "PABC123")]);
+        assert!(normalize_service_schedules(vec![schedule("sre", "")]).is_err());
+        assert!(
+            normalize_service_schedules(vec![schedule("sre", "P1"), schedule("SRE", "P2"),])
+                .is_err()
+        );
+
+        let mut on_call = OnCallSettings {
+            provider: OnCallProvider::PagerDuty,
+            api_token: String::new(),
+            api_url: String::new(),
+            schedules,
+        };
+        assert_eq!(on_call.schedule_for("Payments-Team"), None);
+        on_call.api_token = "token".into();
+        assert_eq!(on_call.schedule_for("Payments-Team"), Some("PABC123"));
+        assert_eq!(on_call.schedule_for("sre"), None);
+    }
+
+    #[test]
+    fn test_issue_tracker_settings() {
+        assert_eq!(
+            normalize_issue_projects(vec![
+                " pay ".into(),
+                "".into(),
+                "PAY".into(),
+                "OPS_2".into()
+            ])
+            .unwrap(),
+            ["PAY", "OPS_2"]
+        );
+        assert!(normalize_issue_projects(vec!["2FA".into()]).is_err());
+        assert!(normalize_issue_projects(vec!["PAY-1".into()]).is_err());
+
+        let mut tracker = IssueTrackerSettings {
+            provider: IssueTrackerProvider::Jira,
+            base_url: "https://acme.atlassian.net/".into(),
+            projects: vec!["PAY".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            tracker.url_template().as_deref(),
+            Some("https://acme.atlassian.net/browse/{key}")
+        );
+        tracker.provider = IssueTrackerProvider::None;
+        assert_eq!(tracker.url_template(), None);
+    }
+
+    #[test]
+    fn test_embed_settings_normalization() {
+        let embeds = EmbedSettings {
+            providers: vec![
+                " YouTube ".into(),
+                "grafana".into(),
+                "".into(),
+                "youtube".into(),
+            ],
+            grafana_hosts: vec!["Grafana.internal:3000".into(), " ".into()],
+        }
+        .normalize()
+        .unwrap();
+        assert_eq!(embeds.providers, vec!["youtube", "grafana"]);
+        assert_eq!(embeds.grafana_hosts, vec!["grafana.internal:3000"]);
+
+        assert!(EmbedSettings {
+            providers: vec!["vimeo".into()],
+            ..Default::default()
+        }
+        .normalize()
+        .is_err());
+        assert!(EmbedSettings {
+            grafana_hosts: vec!["https://grafana.internal/".into()],
+            ..Default::default()
+        }
+        .normalize()
+        .is_err());
+    }
+
+    #[test]
+    fn test_ldap_group_mappings() {
+        let ldap = LdapSettings {
+            group_mappings: vec![
+                LdapGroupMapping {
+                    group: "Payments".into(),
+                    access_levels: vec!["internal".into(), "payments".into()],
+                },
+                LdapGroupMapping {
+                    group: "cn=sre,ou=groups,dc=acme,dc=com".into(),
+                    access_levels: vec!["internal".into(), "ops".into()],
+                },
+            ],
+            admin_groups: vec![],
+        };
+        let groups = vec![
+            "CN=payments,OU=Groups,DC=acme,DC=com".to_string(),
+            "cn=SRE,ou=groups,dc=acme,dc=com".to_string(),
+        ];
+        assert_eq!(
+            ldap.access_levels_for(&groups),
+            ["internal", "payments", "ops"]
+        );
+        assert!(ldap
+            .access_levels_for(&["cn=payments-ro,dc=acme".into()])
+            .is_empty());
+        assert_eq!(ldap.is_admin_for(&groups), None);
+
+        let ldap = LdapSettings {
+            admin_groups: vec!["sre".into()],
+            ..ldap
+        };
+        assert_eq!(ldap.is_admin_for(&groups), Some(true));
+        assert_eq!(ldap.is_admin_for(&groups[..1]), Some(false));
     }
 }
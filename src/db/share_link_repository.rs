@@ -0,0 +1,251 @@
+//! Repository for document share links and their access log.
+//!
+//! A share link grants read access to one restricted document to anyone
+//! holding its signed URL, until it expires or is revoked. Every use is
+//! recorded; access records expire after [`ACCESS_RETENTION_DAYS`].
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// How long access records are kept.
+pub const ACCESS_RETENTION_DAYS: u64 = 90;
+
+/// A signed, expiring link to one document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareLink {
+    /// Also the `jti` of the link's token.
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub slug: String,
+    /// Who the link is for, e.g. `"Acme contractors"`.
+    #[serde(default)]
+    pub note: String,
+    /// Email of the user who created the link.
+    pub created_by: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::db::auth_models::option_bson_datetime"
+    )]
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Email of the user who revoked the link.
+    #[serde(default)]
+    pub revoked_by: Option<String>,
+    #[serde(default)]
+    pub access_count: u64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::db::auth_models::option_bson_datetime"
+    )]
+    pub last_accessed_at: Option<DateTime<Utc>>,
+}
+
+impl ShareLink {
+    /// Whether the link still grants access at `now`.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+/// One use of a share link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareLinkAccess {
+    pub link_id: String,
+    pub slug: String,
+    /// Client address reported by the proxy (`X-Forwarded-For`), if any.
+    #[serde(default)]
+    pub forwarded_for: Option<String>,
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub accessed_at: DateTime<Utc>,
+}
+
+/// Storage for share links.
+#[async_trait]
+pub trait ShareLinkRepository: Send + Sync {
+    async fn create(&self, link: ShareLink) -> Result<(), AppError>;
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<ShareLink>, AppError>;
+
+    /// Newest first, optionally of one document only.
+    async fn list(&self, slug: Option<&str>) -> Result<Vec<ShareLink>, AppError>;
+
+    /// Revoke a link on behalf of `revoked_by`. Returns `NotFound` if it
+    /// does not exist; revoking twice keeps the first revocation.
+    async fn revoke(&self, id: &str, revoked_by: &str) -> Result<(), AppError>;
+
+    /// Log `access` and update its link's counters.
+    async fn record_access(&self, access: ShareLinkAccess) -> Result<(), AppError>;
+
+    /// Most recent uses of a link first.
+    async fn list_accesses(
+        &self,
+        link_id: &str,
+        limit: i64,
+    ) -> Result<Vec<ShareLinkAccess>, AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoShareLinkRepository {
+    links: mongodb::Collection<ShareLink>,
+    accesses: mongodb::Collection<ShareLinkAccess>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoShareLinkRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            links: db.collection("share_links"),
+            accesses: db.collection("share_link_accesses"),
+        }
+    }
+
+    /// Ensure the lookup indexes and the TTL index enforcing the access log
+    /// retention.
+    pub async fn ensure_indexes(&self) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::IndexOptions;
+        use mongodb::IndexModel;
+
+        self.links
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "slug": 1, "created_at": -1 })
+                    .build(),
+            )
+            .await
+            .map_err(|e| AppError::Database(format!("create share_links slug index: {e}")))?;
+
+        self.accesses
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "link_id": 1, "accessed_at": -1 })
+                    .build(),
+            )
+            .await
+            .map_err(|e| {
+                AppError::Database(format!("create share_link_accesses link index: {e}"))
+            })?;
+
+        self.accesses
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "accessed_at": 1 })
+                    .options(
+                        IndexOptions::builder()
+                            .expire_after(std::time::Duration::from_secs(
+                                ACCESS_RETENTION_DAYS * 24 * 60 * 60,
+                            ))
+                            .build(),
+                    )
+                    .build(),
+            )
+            .await
+            .map_err(|e| {
+                AppError::Database(format!("create share_link_accesses TTL index: {e}"))
+            })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl ShareLinkRepository for MongoShareLinkRepository {
+    async fn create(&self, link: ShareLink) -> Result<(), AppError> {
+        self.links.insert_one(link).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<ShareLink>, AppError> {
+        Ok(self
+            .links
+            .find_one(mongodb::bson::doc! { "_id": id })
+            .await?)
+    }
+
+    async fn list(&self, slug: Option<&str>) -> Result<Vec<ShareLink>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let filter = match slug {
+            Some(slug) => doc! { "slug": slug },
+            None => doc! {},
+        };
+        let links = self
+            .links
+            .find(filter)
+            .sort(doc! { "created_at": -1 })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(links)
+    }
+
+    async fn revoke(&self, id: &str, revoked_by: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let now = mongodb::bson::DateTime::from_chrono(Utc::now());
+        let result = self
+            .links
+            .update_one(
+                doc! { "_id": id },
+                vec![doc! { "$set": {
+                    "revoked_at": { "$ifNull": ["$revoked_at", now] },
+                    "revoked_by": { "$ifNull": ["$revoked_by", revoked_by] },
+                } }],
+            )
+            .await?;
+        if result.matched_count == 0 {
+            return Err(AppError::NotFound(format!("Share link '{id}' not found")));
+        }
+        Ok(())
+    }
+
+    async fn record_access(&self, access: ShareLinkAccess) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.links
+            .update_one(
+                doc! { "_id": &access.link_id },
+                doc! {
+                    "$inc": { "access_count": 1_i64 },
+                    "$set": {
+                        "last_accessed_at": mongodb::bson::DateTime::from_chrono(access.accessed_at),
+                    },
+                },
+            )
+            .await?;
+        self.accesses.insert_one(access).await?;
+        Ok(())
+    }
+
+    async fn list_accesses(
+        &self,
+        link_id: &str,
+        limit: i64,
+    ) -> Result<Vec<ShareLinkAccess>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let accesses = self
+            .accesses
+            .find(doc! { "link_id": link_id })
+            .sort(doc! { "accessed_at": -1 })
+            .limit(limit)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(accesses)
+    }
+}
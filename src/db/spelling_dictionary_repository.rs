@@ -0,0 +1,98 @@
+//! Repository for the organization's spelling dictionary — product names,
+//! jargon and other words the spell checker accepts on top of its Hunspell
+//! dictionary.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// A word accepted by the spell checker.
+///
+/// Words are unique and matched case-insensitively.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DictionaryWord {
+    /// The word as added (e.g. `"kubectl"`, `"Lekton"`).
+    pub word: String,
+    /// Email of the admin who added it.
+    #[serde(default)]
+    pub added_by: String,
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub added_at: DateTime<Utc>,
+}
+
+/// CRUD operations for the spelling dictionary.
+#[async_trait]
+pub trait SpellingDictionaryRepository: Send + Sync {
+    /// List all words, sorted alphabetically.
+    async fn list_all(&self) -> Result<Vec<DictionaryWord>, AppError>;
+
+    /// Add a word, or replace the existing entry with the same word
+    /// (compared case-insensitively).
+    async fn upsert(&self, word: DictionaryWord) -> Result<(), AppError>;
+
+    /// Delete a word. Returns `NotFound` if it does not exist.
+    async fn delete(&self, word: &str) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoSpellingDictionaryRepository {
+    collection: mongodb::Collection<DictionaryWord>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoSpellingDictionaryRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("spelling_dictionary"),
+        }
+    }
+
+    fn word_filter(word: &str) -> mongodb::bson::Document {
+        mongodb::bson::doc! {
+            "word": {
+                "$regex": format!("^{}$", crate::db::repository::regex_escape(word)),
+                "$options": "i",
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl SpellingDictionaryRepository for MongoSpellingDictionaryRepository {
+    async fn list_all(&self) -> Result<Vec<DictionaryWord>, AppError> {
+        use futures::TryStreamExt;
+
+        let mut words: Vec<DictionaryWord> = self
+            .collection
+            .find(mongodb::bson::doc! {})
+            .await?
+            .try_collect()
+            .await?;
+        words.sort_by_cached_key(|w| w.word.to_lowercase());
+        Ok(words)
+    }
+
+    async fn upsert(&self, word: DictionaryWord) -> Result<(), AppError> {
+        use mongodb::options::ReplaceOptions;
+
+        self.collection
+            .replace_one(Self::word_filter(&word.word), &word)
+            .with_options(ReplaceOptions::builder().upsert(true).build())
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, word: &str) -> Result<(), AppError> {
+        let result = self.collection.delete_one(Self::word_filter(word)).await?;
+        if result.deleted_count == 0 {
+            return Err(AppError::NotFound(format!(
+                "dictionary word '{word}' not found"
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! Repository for the bytes each uploader stored per UTC day, used to enforce
+//! `[quotas.uploads]`.
+//!
+//! Entries expire after [`RETENTION_DAYS`].
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// How long daily usage records are kept.
+pub const RETENTION_DAYS: u64 = 35;
+
+/// Upload usage of one uploader on one day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadUsage {
+    /// `<uploader>/<YYYY-MM-DD>`.
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// User email or service token name.
+    pub uploader: String,
+    /// Start of the UTC day.
+    #[serde(with = "bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub day: DateTime<Utc>,
+    pub bytes: i64,
+}
+
+/// Storage for daily upload usage.
+#[async_trait]
+pub trait UploadUsageRepository: Send + Sync {
+    /// Add `bytes` (negative to give them back) to the usage of `uploader` on
+    /// `day` and return the new total.
+    async fn add(&self, uploader: &str, day: NaiveDate, bytes: i64) -> Result<i64, AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoUploadUsageRepository {
+    collection: mongodb::Collection<UploadUsage>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoUploadUsageRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("upload_usage"),
+        }
+    }
+
+    /// Ensure the TTL index enforcing the retention.
+    pub async fn ensure_indexes(&self) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+        use mongodb::options::IndexOptions;
+        use mongodb::IndexModel;
+
+        self.collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "day": 1 })
+                    .options(
+                        IndexOptions::builder()
+                            .expire_after(std::time::Duration::from_secs(
+                                RETENTION_DAYS * 24 * 60 * 60,
+                            ))
+                            .build(),
+                    )
+                    .build(),
+            )
+            .await
+            .map_err(|e| AppError::Database(format!("create upload_usage TTL index: {e}")))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl UploadUsageRepository for MongoUploadUsageRepository {
+    async fn add(&self, uploader: &str, day: NaiveDate, bytes: i64) -> Result<i64, AppError> {
+        use mongodb::bson::{doc, DateTime as BsonDateTime};
+        use mongodb::options::{FindOneAndUpdateOptions, ReturnDocument};
+
+        let start = day.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+        let update = doc! {
+            "$setOnInsert": {
+                "uploader": uploader,
+                "day": BsonDateTime::from_chrono(start),
+            },
+            "$inc": { "bytes": bytes },
+        };
+        let options = FindOneAndUpdateOptions::builder()
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .build();
+
+        let usage = self
+            .collection
+            .find_one_and_update(doc! { "_id": format!("{uploader}/{day}") }, update)
+            .with_options(options)
+            .await?
+            .ok_or_else(|| {
+                AppError::Internal(format!(
+                    "upload usage upsert for '{uploader}' returned no document"
+                ))
+            })?;
+        Ok(usage.bytes)
+    }
+}
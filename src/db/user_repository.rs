@@ -4,7 +4,7 @@ use async_trait::async_trait;
 #[cfg(feature = "ssr")]
 use chrono::Utc;
 
-use crate::db::auth_models::{RefreshToken, User};
+use crate::db::auth_models::{LocalPassword, RefreshToken, User};
 use crate::error::AppError;
 
 /// Operations for users and refresh tokens.
@@ -62,6 +62,24 @@ pub trait UserRepository: Send + Sync {
     async fn list_users_with_assigned_level(&self, level_name: &str)
         -> Result<Vec<User>, AppError>;
 
+    /// Replace the password credentials of a local account.
+    async fn set_user_password(
+        &self,
+        user_id: &str,
+        password: LocalPassword,
+    ) -> Result<(), AppError>;
+
+    /// Update the display name and admin flag of a user.
+    async fn update_user_profile(
+        &self,
+        user_id: &str,
+        name: Option<String>,
+        is_admin: bool,
+    ) -> Result<(), AppError>;
+
+    /// Delete a user record. Refresh tokens must be revoked separately.
+    async fn delete_user(&self, user_id: &str) -> Result<(), AppError>;
+
     // ── Refresh tokens ───────────────────────────────────────────────────────
 
     /// Store a new refresh token.
@@ -226,6 +244,61 @@ impl UserRepository for MongoUserRepository {
         Ok(users)
     }
 
+    async fn set_user_password(
+        &self,
+        user_id: &str,
+        password: LocalPassword,
+    ) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let password = bson::to_bson(&password)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize password: {e}")))?;
+        let result = self
+            .users
+            .update_one(
+                doc! { "id": user_id },
+                doc! { "$set": { "password": password } },
+            )
+            .await?;
+
+        if result.matched_count == 0 {
+            return Err(AppError::NotFound(format!("User '{user_id}' not found")));
+        }
+        Ok(())
+    }
+
+    async fn update_user_profile(
+        &self,
+        user_id: &str,
+        name: Option<String>,
+        is_admin: bool,
+    ) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let result = self
+            .users
+            .update_one(
+                doc! { "id": user_id },
+                doc! { "$set": { "name": name, "is_admin": is_admin } },
+            )
+            .await?;
+
+        if result.matched_count == 0 {
+            return Err(AppError::NotFound(format!("User '{user_id}' not found")));
+        }
+        Ok(())
+    }
+
+    async fn delete_user(&self, user_id: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let result = self.users.delete_one(doc! { "id": user_id }).await?;
+        if result.deleted_count == 0 {
+            return Err(AppError::NotFound(format!("User '{user_id}' not found")));
+        }
+        Ok(())
+    }
+
     // ── Refresh tokens ───────────────────────────────────────────────────────
 
     async fn create_refresh_token(&self, token: RefreshToken) -> Result<(), AppError> {
@@ -292,6 +365,7 @@ mod tests {
             can_write_draft: false,
             created_at: Utc::now(),
             last_login_at: None,
+            password: None,
         }
     }
 
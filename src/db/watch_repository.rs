@@ -0,0 +1,170 @@
+//! Repository for document watches.
+//!
+//! A watch subscribes a user to changes of one document (`guides/setup`) or
+//! of a whole subtree (`guides/*`, which also covers `guides` itself).
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// A user's subscription to a document or subtree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Watch {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub user_id: String,
+    /// A slug, or a slug prefix followed by `/*`.
+    pub target: String,
+    /// Also notify by email (when email is configured).
+    #[serde(default)]
+    pub email: bool,
+    /// Also POST each notification to this URL.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The watch targets that cover `slug`: the slug itself and the subtree of
+/// every ancestor, e.g. `a/b` → `a/b`, `a/b/*`, `a/*`.
+pub fn matching_targets(slug: &str) -> Vec<String> {
+    let mut targets = vec![slug.to_string()];
+    let mut prefix = slug;
+    loop {
+        targets.push(format!("{prefix}/*"));
+        match prefix.rsplit_once('/') {
+            Some((parent, _)) => prefix = parent,
+            None => break,
+        }
+    }
+    targets
+}
+
+/// Validate and normalise a watch target.
+pub fn normalize_target(target: &str) -> Result<String, AppError> {
+    let target = target.trim().trim_matches('/');
+    let slug = target.strip_suffix("/*").unwrap_or(target);
+    if slug.is_empty() || slug.contains("..") || slug.contains('*') {
+        return Err(AppError::BadRequest(format!(
+            "Invalid watch target '{target}': use a slug or a slug prefix followed by '/*'"
+        )));
+    }
+    Ok(target.to_string())
+}
+
+/// Storage for watches.
+#[async_trait]
+pub trait WatchRepository: Send + Sync {
+    /// Store `watch`, replacing the user's existing watch of the same target.
+    async fn upsert(&self, watch: Watch) -> Result<(), AppError>;
+
+    /// The user's watches, ordered by target.
+    async fn list_for_user(&self, user_id: &str) -> Result<Vec<Watch>, AppError>;
+
+    /// Watches whose target covers `slug`, of all users.
+    async fn list_matching(&self, slug: &str) -> Result<Vec<Watch>, AppError>;
+
+    /// Remove one of the user's watches. Returns `NotFound` if the user has
+    /// no watch with this ID.
+    async fn delete(&self, user_id: &str, id: &str) -> Result<(), AppError>;
+}
+
+/// MongoDB implementation.
+#[cfg(feature = "ssr")]
+pub struct MongoWatchRepository {
+    collection: mongodb::Collection<Watch>,
+}
+
+#[cfg(feature = "ssr")]
+impl MongoWatchRepository {
+    pub fn new(db: &mongodb::Database) -> Self {
+        Self {
+            collection: db.collection("watches"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl WatchRepository for MongoWatchRepository {
+    async fn upsert(&self, watch: Watch) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        self.collection
+            .delete_many(doc! { "user_id": &watch.user_id, "target": &watch.target })
+            .await?;
+        self.collection.insert_one(watch).await?;
+        Ok(())
+    }
+
+    async fn list_for_user(&self, user_id: &str) -> Result<Vec<Watch>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+        use mongodb::options::FindOptions;
+
+        let options = FindOptions::builder().sort(doc! { "target": 1 }).build();
+        let watches = self
+            .collection
+            .find(doc! { "user_id": user_id })
+            .with_options(options)
+            .await?
+            .try_collect()
+            .await?;
+        Ok(watches)
+    }
+
+    async fn list_matching(&self, slug: &str) -> Result<Vec<Watch>, AppError> {
+        use futures::TryStreamExt;
+        use mongodb::bson::doc;
+
+        let watches = self
+            .collection
+            .find(doc! { "target": { "$in": matching_targets(slug) } })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(watches)
+    }
+
+    async fn delete(&self, user_id: &str, id: &str) -> Result<(), AppError> {
+        use mongodb::bson::doc;
+
+        let result = self
+            .collection
+            .delete_one(doc! { "_id": id, "user_id": user_id })
+            .await?;
+        if result.deleted_count == 0 {
+            return Err(AppError::NotFound(format!("Watch '{id}' not found")));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_targets() {
+        assert_eq!(
+            matching_targets("guides/setup/linux"),
+            [
+                "guides/setup/linux",
+                "guides/setup/linux/*",
+                "guides/setup/*",
+                "guides/*"
+            ]
+        );
+        assert_eq!(matching_targets("intro"), ["intro", "intro/*"]);
+    }
+
+    #[test]
+    fn test_normalize_target() {
+        assert_eq!(normalize_target(" /guides/* ").unwrap(), "guides/*");
+        assert_eq!(normalize_target("guides/setup").unwrap(), "guides/setup");
+        assert!(normalize_target("*").is_err());
+        assert!(normalize_target("guides/*/setup").is_err());
+        assert!(normalize_target("../secrets").is_err());
+    }
+}
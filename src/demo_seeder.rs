@@ -0,0 +1,340 @@
+//! Demo content seeded on startup when `DEMO_MODE=true` and the database has
+//! no documents yet: the guides in `demo/documents`, grouped under two
+//! section pages, and the sample schemas in `demo/schemas`.
+//!
+//! Content is written straight through the repositories, so seeding needs no
+//! service token. The slugs match `demo/load_demo.sh`, which can still be used
+//! to (re)load the same content, plus assets and prompts, over the API.
+
+use chrono::Utc;
+
+use crate::api::ingest::{compute_metadata_hash, content_hash, MetadataHashInput};
+use crate::db::models::{Document, Schema, SchemaVersion};
+use crate::db::repository::DocumentRepository;
+use crate::db::schema_repository::SchemaRepository;
+use crate::error::AppError;
+use crate::rendering::links::extract_internal_links;
+use crate::search::client::SearchService;
+use crate::storage::client::StorageClient;
+
+/// Recorded as the author of seeded content.
+const DEMO_AUTHOR: &str = "demo-seeder";
+
+struct DemoDoc {
+    slug: &'static str,
+    title: &'static str,
+    summary: &'static str,
+    parent_slug: Option<&'static str>,
+    order: u32,
+    access_level: &'static str,
+    service_owner: &'static str,
+    tags: &'static [&'static str],
+    content: &'static str,
+}
+
+const DEMO_DOCS: &[DemoDoc] = &[
+    DemoDoc {
+        slug: "guides",
+        title: "Guides",
+        summary: "Hands-on guides for using and running Lekton.",
+        parent_slug: None,
+        order: 1,
+        access_level: "public",
+        service_owner: "platform-team",
+        tags: &["guides"],
+        content: "# Guides\n\nHands-on guides for using and running Lekton.\n\n\
+                  - [Getting Started with Lekton](/docs/getting-started)\n\
+                  - [Deployment Guide](/docs/deployment-guide)\n",
+    },
+    DemoDoc {
+        slug: "getting-started",
+        title: "Getting Started with Lekton",
+        summary: "Install Lekton and publish your first document.",
+        parent_slug: Some("guides"),
+        order: 1,
+        access_level: "public",
+        service_owner: "platform-team",
+        tags: &["getting-started", "onboarding"],
+        content: include_str!("../demo/documents/getting-started.md"),
+    },
+    DemoDoc {
+        slug: "deployment-guide",
+        title: "Deployment Guide",
+        summary: "Deploy Lekton with Docker Compose or Kubernetes.",
+        parent_slug: Some("guides"),
+        order: 2,
+        access_level: "developer",
+        service_owner: "devops-team",
+        tags: &["deployment", "docker", "k8s"],
+        content: include_str!("../demo/documents/deployment-guide.md"),
+    },
+    DemoDoc {
+        slug: "engineering",
+        title: "Engineering",
+        summary: "How Lekton is built, its API and its security model.",
+        parent_slug: None,
+        order: 2,
+        access_level: "developer",
+        service_owner: "platform-team",
+        tags: &["engineering"],
+        content: "# Engineering\n\nHow Lekton is built, its API and its security model.\n\n\
+                  - [Architecture Overview](/docs/architecture)\n\
+                  - [API Reference](/docs/api-reference)\n\
+                  - [Security & RBAC](/docs/security-rbac)\n",
+    },
+    DemoDoc {
+        slug: "architecture",
+        title: "Architecture Overview",
+        summary: "Components of Lekton and how a document flows through them.",
+        parent_slug: Some("engineering"),
+        order: 1,
+        access_level: "developer",
+        service_owner: "platform-team",
+        tags: &["architecture", "design"],
+        content: include_str!("../demo/documents/architecture.md"),
+    },
+    DemoDoc {
+        slug: "api-reference",
+        title: "API Reference",
+        summary: "REST endpoints for ingesting documents, schemas and assets.",
+        parent_slug: Some("engineering"),
+        order: 2,
+        access_level: "developer",
+        service_owner: "platform-team",
+        tags: &["api", "rest", "reference"],
+        content: include_str!("../demo/documents/api-reference.md"),
+    },
+    DemoDoc {
+        slug: "security-rbac",
+        title: "Security & RBAC",
+        summary: "Access levels, roles and service tokens.",
+        parent_slug: Some("engineering"),
+        order: 3,
+        access_level: "architect",
+        service_owner: "security-team",
+        tags: &["security", "rbac", "auth"],
+        content: include_str!("../demo/documents/security-rbac.md"),
+    },
+];
+
+struct DemoSchema {
+    name: &'static str,
+    schema_type: &'static str,
+    version: &'static str,
+    status: &'static str,
+    service_owner: &'static str,
+    description: &'static str,
+    content: &'static str,
+}
+
+/// Versions of one schema are listed oldest first.
+const DEMO_SCHEMAS: &[DemoSchema] = &[
+    DemoSchema {
+        name: "payment-service-api",
+        schema_type: "openapi",
+        version: "1.0.0",
+        status: "deprecated",
+        service_owner: "payments-team",
+        description: "Card payments and refunds.",
+        content: include_str!("../demo/schemas/payment-service-api-v1.json"),
+    },
+    DemoSchema {
+        name: "payment-service-api",
+        schema_type: "openapi",
+        version: "2.0.0",
+        status: "stable",
+        service_owner: "payments-team",
+        description: "Card payments and refunds.",
+        content: include_str!("../demo/schemas/payment-service-api-v2.json"),
+    },
+    DemoSchema {
+        name: "inventory-service-api",
+        schema_type: "openapi",
+        version: "1.0.0",
+        status: "stable",
+        service_owner: "inventory-team",
+        description: "Stock levels and reservations.",
+        content: include_str!("../demo/schemas/inventory-service-api-v1.json"),
+    },
+    DemoSchema {
+        name: "order-events",
+        schema_type: "asyncapi",
+        version: "1.0.0",
+        status: "stable",
+        service_owner: "orders-team",
+        description: "Events published over the order lifecycle.",
+        content: include_str!("../demo/schemas/order-events-v1.json"),
+    },
+];
+
+/// Bundles the service references needed by [`seed_if_empty`].
+pub struct DemoSeedContext<'a> {
+    pub repo: &'a dyn DocumentRepository,
+    pub storage: &'a dyn StorageClient,
+    pub search: Option<&'a dyn SearchService>,
+    pub schema_repo: &'a dyn SchemaRepository,
+}
+
+/// Seed the demo content unless the database already has documents.
+/// Returns whether anything was seeded.
+pub async fn seed_if_empty(ctx: &DemoSeedContext<'_>) -> Result<bool, AppError> {
+    if !ctx.repo.list_all().await?.is_empty() {
+        return Ok(false);
+    }
+    seed_documents(ctx).await?;
+    seed_schemas(ctx).await?;
+    Ok(true)
+}
+
+async fn seed_documents(ctx: &DemoSeedContext<'_>) -> Result<(), AppError> {
+    let mut links = Vec::with_capacity(DEMO_DOCS.len());
+    for demo in DEMO_DOCS {
+        let tags: Vec<String> = demo.tags.iter().map(|t| t.to_string()).collect();
+        let s3_key = ctx.storage.key_layout().document_key(demo.slug);
+        ctx.storage
+            .put_object(&s3_key, demo.content.as_bytes().to_vec())
+            .await?;
+
+        let links_out = extract_internal_links(demo.content);
+        let doc = Document {
+            slug: demo.slug.to_string(),
+            title: demo.title.to_string(),
+            summary: Some(demo.summary.to_string()),
+            s3_key,
+            access_level: demo.access_level.to_string(),
+            service_owner: demo.service_owner.to_string(),
+            last_updated: Utc::now(),
+            tags: tags.clone(),
+            links_out: links_out.clone(),
+            parent_slug: demo.parent_slug.map(str::to_string),
+            order: demo.order,
+            content_hash: Some(content_hash(demo.content)),
+            metadata_hash: Some(compute_metadata_hash(MetadataHashInput {
+                title: demo.title,
+                summary: Some(demo.summary),
+                access_level: demo.access_level,
+                service_owner: demo.service_owner,
+                tags: &tags,
+                parent_slug: demo.parent_slug,
+                order: demo.order,
+                is_hidden: false,
+                noindex: false,
+                edit_access_level: None,
+                relations: &Default::default(),
+            })),
+            last_updated_by: Some(DEMO_AUTHOR.to_string()),
+            contributors: vec![DEMO_AUTHOR.to_string()],
+            content_size: demo.content.len() as u64,
+            preview: crate::api::ingest::ContentFormat::Markdown.preview(demo.content),
+            ..Default::default()
+        };
+
+        if let Some(search) = ctx.search {
+            let search_doc = crate::search::client::build_search_document(&doc, demo.content);
+            if let Err(e) = search.index_document(&search_doc).await {
+                tracing::warn!("Failed to index demo document '{}': {e}", demo.slug);
+            }
+        }
+        ctx.repo.create_or_update(doc).await?;
+        links.push((demo.slug, links_out));
+    }
+
+    // Backlinks only land on documents that exist, so they go in once all are seeded
+    for (slug, links_out) in links {
+        ctx.repo.update_backlinks(slug, &[], &links_out).await?;
+    }
+    Ok(())
+}
+
+async fn seed_schemas(ctx: &DemoSeedContext<'_>) -> Result<(), AppError> {
+    let mut schemas: Vec<Schema> = vec![];
+    for demo in DEMO_SCHEMAS {
+        let s3_key = format!("schemas/{}/{}.json", demo.name, demo.version);
+        ctx.storage
+            .put_object(&s3_key, demo.content.as_bytes().to_vec())
+            .await?;
+
+        let version = SchemaVersion {
+            version: demo.version.to_string(),
+            s3_key,
+            status: demo.status.to_string(),
+            access_level: "public".to_string(),
+            content_hash: Some(crate::api::schemas::compute_schema_content_hash(
+                demo.content,
+            )),
+            metadata_hash: Some(crate::api::schemas::compute_schema_metadata_hash(
+                demo.status,
+                "public",
+            )),
+            is_archived: false,
+            endpoints: crate::api::schemas::extract_schema_endpoints(
+                demo.schema_type,
+                demo.content,
+            ),
+        };
+        match schemas.iter_mut().find(|s| s.name == demo.name) {
+            Some(schema) => schema.versions.push(version),
+            None => schemas.push(Schema {
+                name: demo.name.to_string(),
+                schema_type: demo.schema_type.to_string(),
+                service_owner: demo.service_owner.to_string(),
+                tags: vec!["demo".to_string()],
+                description: Some(demo.description.to_string()),
+                contact: None,
+                repository_url: None,
+                owner_access_level: None,
+                updated_at: Some(Utc::now()),
+                versions: vec![version],
+            }),
+        }
+    }
+
+    for schema in schemas {
+        ctx.schema_repo.create_or_update(schema).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_docs_form_a_tree() {
+        for doc in DEMO_DOCS {
+            if let Some(parent) = doc.parent_slug {
+                let parent = DEMO_DOCS.iter().find(|d| d.slug == parent);
+                assert!(
+                    parent.is_some_and(|p| p.parent_slug.is_none()),
+                    "'{}' must have a seeded section as parent",
+                    doc.slug
+                );
+            }
+            // Attachment links point at files, not documents
+            for link in extract_internal_links(doc.content)
+                .into_iter()
+                .filter(|link| !link.starts_with("api/"))
+            {
+                assert!(
+                    DEMO_DOCS.iter().any(|d| d.slug == link),
+                    "'{}' links to unknown '{link}'",
+                    doc.slug
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_demo_schemas_expose_endpoints() {
+        for schema in DEMO_SCHEMAS {
+            let endpoints =
+                crate::api::schemas::extract_schema_endpoints(schema.schema_type, schema.content);
+            assert!(
+                !endpoints.is_empty(),
+                "{}@{} has no endpoints",
+                schema.name,
+                schema.version
+            );
+        }
+    }
+}
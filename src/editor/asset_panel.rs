@@ -1,6 +1,8 @@
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::i18n::use_i18n;
+
 /// Asset info DTO shared between client and server.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetInfo {
@@ -19,7 +21,7 @@ pub async fn list_all_assets() -> Result<Vec<AssetInfo>, ServerFnError> {
         .asset_repo
         .list_all()
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(assets
         .into_iter()
@@ -42,26 +44,26 @@ pub async fn delete_asset_by_key(key: String) -> Result<(), ServerFnError> {
         .asset_repo
         .find_by_key(&key)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .map_err(crate::error::AppError::into_server_fn_error)?
         .ok_or_else(|| ServerFnError::new(format!("Asset '{}' not found", key)))?;
 
     state
         .storage_client
         .delete_object(&asset.s3_key)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     state
         .asset_repo
         .delete(&key)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(())
 }
 
 /// Format file size in human-readable form.
-fn format_size(bytes: u64) -> String {
+pub(crate) fn format_size(bytes: u64) -> String {
     if bytes < 1024 {
         format!("{} B", bytes)
     } else if bytes < 1024 * 1024 {
@@ -82,6 +84,7 @@ pub fn AssetPanel(
     /// Signal to trigger the TipTap SetImage message from the panel.
     set_msg: WriteSignal<leptos_tiptap::TiptapInstanceMsg>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let (refresh_counter, set_refresh_counter) = signal(0u32);
 
     let assets_resource = Resource::new(move || refresh_counter.get(), |_| list_all_assets());
@@ -107,7 +110,7 @@ pub fn AssetPanel(
         <div class="collapse collapse-arrow bg-base-200 rounded-lg">
             <input type="checkbox" />
             <div class="collapse-title font-semibold">
-                "Assets"
+                {i18n.t("editor.assets")}
             </div>
             <div class="collapse-content space-y-3">
                 // Upload button
@@ -129,7 +132,7 @@ pub fn AssetPanel(
                             }
                         }
                     >
-                        "Upload Asset"
+                        {i18n.t("editor.upload_asset")}
                     </button>
                 </div>
 
@@ -139,7 +142,7 @@ pub fn AssetPanel(
                         assets_resource.get().map(|result| match result {
                             Ok(assets) if assets.is_empty() => {
                                 view! {
-                                    <p class="text-sm text-base-content/60">"No assets uploaded yet."</p>
+                                    <p class="text-sm text-base-content/60">{i18n.t("editor.no_assets")}</p>
                                 }.into_any()
                             }
                             Ok(assets) => {
@@ -148,10 +151,10 @@ pub fn AssetPanel(
                                         <table class="table table-sm">
                                             <thead>
                                                 <tr>
-                                                    <th>"Name"</th>
-                                                    <th>"Type"</th>
-                                                    <th>"Size"</th>
-                                                    <th>"Actions"</th>
+                                                    <th>{i18n.t("editor.asset_name")}</th>
+                                                    <th>{i18n.t("editor.asset_type")}</th>
+                                                    <th>{i18n.t("editor.asset_size")}</th>
+                                                    <th>{i18n.t("editor.asset_actions")}</th>
                                                 </tr>
                                             </thead>
                                             <tbody>
@@ -175,7 +178,7 @@ pub fn AssetPanel(
                                                                     Some(view! {
                                                                         <button
                                                                             class="btn btn-xs btn-ghost"
-                                                                            title="Insert into editor"
+                                                                            title=i18n.t("editor.insert_asset_title")
                                                                             on:click=move |_| {
                                                                                 set_msg.set(leptos_tiptap::TiptapInstanceMsg::SetImage(
                                                                                     leptos_tiptap::TiptapImageResource {
@@ -186,7 +189,7 @@ pub fn AssetPanel(
                                                                                 ));
                                                                             }
                                                                         >
-                                                                            "Insert"
+                                                                            {i18n.t("editor.insert_asset")}
                                                                         </button>
                                                                     })
                                                                 } else {
@@ -196,18 +199,18 @@ pub fn AssetPanel(
                                                                     href=url
                                                                     target="_blank"
                                                                     class="btn btn-xs btn-ghost"
-                                                                    title="Download"
+                                                                    title=i18n.t("editor.download_asset")
                                                                 >
-                                                                    "Download"
+                                                                    {i18n.t("editor.download_asset")}
                                                                 </a>
                                                                 <button
                                                                     class="btn btn-xs btn-ghost text-error"
-                                                                    title="Delete asset"
+                                                                    title=i18n.t("editor.delete_asset_title")
                                                                     on:click=move |_| {
                                                                         delete_action.dispatch(delete_key.clone());
                                                                     }
                                                                 >
-                                                                    "Delete"
+                                                                    {i18n.t("editor.delete_asset")}
                                                                 </button>
                                                             </td>
                                                         </tr>
@@ -220,7 +223,7 @@ pub fn AssetPanel(
                             }
                             Err(e) => {
                                 view! {
-                                    <p class="text-sm text-error">{format!("Error loading assets: {e}")}</p>
+                                    <p class="text-sm text-error">{i18n.tf("editor.assets_error", &[("error", &e.to_string())])}</p>
                                 }.into_any()
                             }
                         })
@@ -2,6 +2,12 @@ use leptos::prelude::*;
 use leptos_tiptap::*;
 
 use super::asset_panel::AssetPanel;
+#[cfg(feature = "hydrate")]
+use crate::app::{acquire_edit_lock, release_edit_lock};
+use crate::app::{check_spelling, EditLockStatus};
+use crate::components::{access_denied_level, focus_first, toolbar_arrow_keys, AccessDenied};
+use crate::db::models::DocumentRelations;
+use crate::i18n::use_i18n;
 
 #[cfg(feature = "hydrate")]
 use wasm_bindgen::prelude::*;
@@ -16,28 +22,47 @@ extern "C" {
     pub fn upload_asset_js() -> js_sys::Promise;
 }
 
-/// Server function to fetch document content for editing.
+/// Server function to fetch document content for editing: its title,
+/// rendered HTML and relations.
 #[server(GetDocContent, "/api")]
-pub async fn get_doc_content(slug: String) -> Result<Option<(String, String)>, ServerFnError> {
+pub async fn get_doc_content(
+    slug: String,
+) -> Result<Option<(String, String, DocumentRelations)>, ServerFnError> {
     use crate::rendering::markdown::render_markdown;
 
     let state = expect_context::<crate::app::AppState>();
+    crate::features::require_editor(&state)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    let ctx = crate::server::require_user_context(&state).await?;
 
     let doc = state
         .document_repo
         .find_by_slug(&slug)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     let Some(doc) = doc else {
         return Ok(None);
     };
 
+    if !ctx.can_read(&doc.access_level) {
+        return Err(ServerFnError::new(
+            crate::auth::models::access_denied_message(&doc.access_level),
+        ));
+    }
+    ensure_can_edit(
+        &ctx,
+        &doc.access_level,
+        doc.is_draft,
+        doc.edit_access_level.as_deref(),
+    )?;
+
     let content_bytes = state
         .storage_client
         .get_object(&doc.s3_key)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     let Some(content_bytes) = content_bytes else {
         return Ok(None);
@@ -48,7 +73,25 @@ pub async fn get_doc_content(slug: String) -> Result<Option<(String, String)>, S
 
     let html = render_markdown(&raw_markdown);
 
-    Ok(Some((doc.title, html)))
+    Ok(Some((doc.title, html, doc.relations)))
+}
+
+/// Reject editor reads and saves the caller's write permissions don't cover.
+#[cfg(feature = "ssr")]
+fn ensure_can_edit(
+    ctx: &crate::auth::models::UserContext,
+    access_level: &str,
+    is_draft: bool,
+    edit_level: Option<&str>,
+) -> Result<(), ServerFnError> {
+    if ctx.can_edit_document(access_level, is_draft, edit_level) {
+        return Ok(());
+    }
+    let message = match edit_level {
+        Some(level) => format!("Only members of '{level}' and admins may edit this document"),
+        None => "You do not have write access to this document".to_string(),
+    };
+    Err(crate::error::AppError::Forbidden(message).into_server_fn_error())
 }
 
 /// Server function to save edited document content.
@@ -57,107 +100,127 @@ pub async fn save_doc_content(
     slug: String,
     title: String,
     html_content: String,
+    relations: DocumentRelations,
 ) -> Result<String, ServerFnError> {
-    use chrono::Utc;
-
     let state = expect_context::<crate::app::AppState>();
+    crate::features::require_editor(&state)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    let ctx = crate::server::require_user_context(&state).await?;
 
     if slug.contains("..") || slug.starts_with('/') {
         return Err(ServerFnError::new("Invalid slug"));
     }
 
-    let links_out = crate::rendering::links::extract_internal_links_from_html(&html_content);
-
     let old_doc = state
         .document_repo
         .find_by_slug(&slug)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
-
-    let (
-        old_links,
-        access_level,
-        is_draft,
-        service_owner,
-        tags,
-        backlinks,
-        parent_slug,
-        order,
-        is_hidden,
-    ) = match old_doc {
-        Some(d) => (
-            d.links_out,
-            d.access_level,
-            d.is_draft,
-            d.service_owner,
-            d.tags,
-            d.backlinks,
-            d.parent_slug,
-            d.order,
-            d.is_hidden,
-        ),
-        None => (
-            vec![],
-            "public".to_string(),
-            false,
-            "web-editor".to_string(),
-            vec![],
-            vec![],
-            None,
-            0,
-            false,
-        ),
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    let revision = match &old_doc {
+        Some(d) => crate::api::ingest::DocumentRevision {
+            slug: slug.clone(),
+            title,
+            summary: d.summary.clone(),
+            access_level: d.access_level.clone(),
+            is_draft: d.is_draft,
+            service_owner: d.service_owner.clone(),
+            tags: d.tags.clone(),
+            parent_slug: d.parent_slug.clone(),
+            order: d.order,
+            is_hidden: d.is_hidden,
+            metadata_hash: None, // Populated on next lekton-sync run
+            source_path: None,   // Not known for editor-saved documents
+            edit_access_level: d.edit_access_level.clone(),
+            relations: relations.normalized(&slug),
+            content: html_content,
+            format: crate::api::ingest::ContentFormat::Html,
+            lint_findings: vec![], // Lint rules check Markdown sources only
+            noindex: d.noindex,
+        },
+        None => crate::api::ingest::DocumentRevision {
+            slug: slug.clone(),
+            title,
+            summary: None,
+            access_level: "public".to_string(),
+            is_draft: false,
+            service_owner: "web-editor".to_string(),
+            tags: vec![],
+            parent_slug: None,
+            order: 0,
+            is_hidden: false,
+            metadata_hash: None,
+            source_path: None,
+            edit_access_level: None,
+            relations: relations.normalized(&slug),
+            content: html_content,
+            format: crate::api::ingest::ContentFormat::Html,
+            lint_findings: vec![], // Lint rules check Markdown sources only
+            noindex: false,
+        },
     };
 
-    let s3_key = format!("docs/{}.md", slug.replace('/', "_"));
-
-    state
-        .storage_client
-        .put_object(&s3_key, html_content.clone().into_bytes())
-        .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
-
-    let doc = crate::db::models::Document {
-        slug: slug.clone(),
-        title,
-        summary: None,
-        s3_key,
-        access_level,
-        is_draft,
-        service_owner,
-        last_updated: Utc::now(),
-        tags,
-        links_out: links_out.clone(),
-        backlinks,
-        parent_slug,
-        order,
-        is_hidden,
-        content_hash: None,  // Editor saves don't compute content hash
-        metadata_hash: None, // Populated on next lekton-sync run
-        is_archived: false,
-        source_path: None, // Not known for editor-saved documents
+    ensure_can_edit(
+        &ctx,
+        &revision.access_level,
+        revision.is_draft,
+        revision.edit_access_level.as_deref(),
+    )?;
+    if let Some(guard) = state.secret_guard.as_deref() {
+        guard
+            .screen(
+                crate::secrets::Submission {
+                    slug: &slug,
+                    title: &revision.title,
+                    content: &revision.content,
+                    content_type: "text/html",
+                    submitted_by: &ctx.user.email,
+                },
+                false,
+            )
+            .await
+            .map_err(crate::error::AppError::into_server_fn_error)?;
+    }
+    crate::api::ingest::check_quota(
+        state.document_repo.as_ref(),
+        &state.quotas,
+        &revision.service_owner,
+        old_doc.as_ref(),
+        revision.content.len() as u64,
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    let updated_by = ctx
+        .user
+        .name
+        .clone()
+        .unwrap_or_else(|| ctx.user.email.clone());
+
+    let ingest_ctx = crate::api::ingest::IngestContext {
+        repo: state.document_repo.as_ref(),
+        storage: state.storage_client.as_ref(),
+        search: state.search_service.as_deref(),
+        access_level_repo: state.access_level_repo.as_ref(),
+        project_repo: Some(state.project_repo.as_ref()),
+        service_token_repo: state.service_token_repo.as_ref(),
+        version_repo: state.document_version_repo.as_ref(),
+        rag: state.rag_service.as_deref(),
+        events: Some(&state.activity_events),
+        legacy_token: None,
+        dry_run: false,
+        approval: None,
+        quotas: Some(&state.quotas),
+        taxonomy: Some(&state.taxonomy),
+        lint: None,
+        spell_checker: None,
+        secrets: None, // Screened above
+        mentions: state.mentions.as_deref(),
     };
-
-    let search_doc = state
-        .search_service
-        .as_ref()
-        .map(|_| crate::search::client::build_search_document(&doc, &html_content));
-
-    state
-        .document_repo
-        .create_or_update(doc)
+    crate::api::ingest::save_document(&ingest_ctx, old_doc, revision, updated_by)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
-
-    state
-        .document_repo
-        .update_backlinks(&slug, &old_links, &links_out)
-        .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
-
-    if let (Some(svc), Some(sdoc)) = (state.search_service.as_ref(), search_doc) {
-        let _ = svc.index_document(&sdoc).await;
-    }
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(format!("Document '{}' saved successfully", slug))
 }
@@ -165,6 +228,7 @@ pub async fn save_doc_content(
 /// The editor page component.
 #[component]
 pub fn EditorPage() -> impl IntoView {
+    let i18n = use_i18n();
     let params = leptos_router::hooks::use_params_map();
     let slug = move || params.read().get("slug").unwrap_or_default();
 
@@ -174,97 +238,248 @@ pub fn EditorPage() -> impl IntoView {
     let (msg, set_msg) = signal(TiptapInstanceMsg::Noop);
     let (value, set_value) = signal(String::new());
     let (title, set_title) = signal(String::new());
-    let (disabled, _set_disabled) = signal(false);
-    let (_selection, set_selection) = signal(TiptapSelectionState::default());
-    let (save_status, set_save_status) = signal(String::new());
+    // Related slugs, comma-separated: see also, prerequisites, supersedes,
+    // superseded by
+    let see_also = RwSignal::new(String::new());
+    let prerequisite = RwSignal::new(String::new());
+    let supersedes = RwSignal::new(String::new());
+    let superseded_by = RwSignal::new(String::new());
+    let (disabled, set_disabled) = signal(false);
+    let (selection, set_selection) = signal(TiptapSelectionState::default());
+    let toolbar_ref = NodeRef::<leptos::html::Div>::new();
+    let toasts = crate::components::use_toasts();
     let (saving, set_saving) = signal(false);
+    let lock_status = RwSignal::new(None::<EditLockStatus>);
+    let locked_out = move || lock_status.get().is_some_and(|s| !s.acquired);
+    Effect::new(move |_| set_disabled.set(locked_out()));
 
     let save_action = Action::new(move |_: &()| {
         let current_slug = slug();
         let current_title = title.get();
         let current_content = value.get();
+        let relations = DocumentRelations {
+            see_also: split_slugs(&see_also.get()),
+            prerequisite: split_slugs(&prerequisite.get()),
+            supersedes: split_slugs(&supersedes.get()),
+            superseded_by: split_slugs(&superseded_by.get()),
+        };
         async move {
             set_saving.set(true);
-            set_save_status.set(String::new());
-            match save_doc_content(current_slug, current_title, current_content).await {
-                Ok(msg) => set_save_status.set(msg),
-                Err(e) => set_save_status.set(format!("Error: {e}")),
-            }
+            let result =
+                save_doc_content(current_slug, current_title, current_content, relations).await;
             set_saving.set(false);
+            result
         }
     });
+    let spelling_action = Action::new(move |_: &()| {
+        let current_content = value.get();
+        async move { check_spelling(current_content).await }
+    });
+    Effect::new(move |_| match save_action.value().get() {
+        Some(Ok(_)) => toasts.success(i18n.tf("editor.saved", &[("slug", &slug())])),
+        Some(Err(e)) => toasts.error_with_retry(
+            i18n.tf("editor.save_error", &[("error", &e.to_string())]),
+            Callback::new(move |_| {
+                save_action.dispatch(());
+            }),
+        ),
+        None => {}
+    });
+
+    // Advisory lock: acquired when the editor opens and refreshed by a
+    // heartbeat. Sessions that cannot lock (e.g. anonymous) edit without it.
+    #[cfg(feature = "hydrate")]
+    let refresh_lock = move |slug: String, reload_on_acquire: bool| {
+        leptos::task::spawn_local(async move {
+            match acquire_edit_lock(slug).await {
+                Ok(status) => {
+                    if reload_on_acquire && status.acquired {
+                        // The previous holder may have saved in the meantime
+                        doc_resource.refetch();
+                    }
+                    lock_status.set(Some(status));
+                }
+                Err(_) => lock_status.set(None),
+            }
+        });
+    };
+
+    #[cfg(feature = "hydrate")]
+    {
+        let held_slug = StoredValue::new(None::<String>);
+
+        Effect::new(move |previous: Option<String>| {
+            let current = slug();
+            if let Some(previous) = previous.filter(|p| *p != current) {
+                leptos::task::spawn_local(async move {
+                    let _ = release_edit_lock(previous).await;
+                });
+            }
+            held_slug.set_value(Some(current.clone()));
+            refresh_lock(current.clone(), false);
+            current
+        });
+
+        let heartbeat = set_interval_with_handle(
+            move || {
+                if lock_status.get_untracked().is_some_and(|s| s.acquired) {
+                    if let Some(current) = held_slug.get_value() {
+                        refresh_lock(current, false);
+                    }
+                }
+            },
+            std::time::Duration::from_secs(crate::app::LOCK_HEARTBEAT_SECS),
+        )
+        .ok();
+
+        on_cleanup(move || {
+            if let Some(handle) = heartbeat {
+                handle.clear();
+            }
+            if let Some(current) = held_slug.try_get_value().flatten() {
+                leptos::task::spawn_local(async move {
+                    let _ = release_edit_lock(current).await;
+                });
+            }
+        });
+    }
 
     view! {
         <Suspense fallback=move || view! { <div class="loading loading-spinner loading-lg"></div> }>
             {move || {
                 doc_resource.get().map(|result| match result {
-                    Ok(Some((doc_title, html))) => {
+                    Ok(Some((doc_title, html, relations))) => {
                         set_title.set(doc_title);
                         set_value.set(html);
+                        see_also.set(relations.see_also.join(", "));
+                        prerequisite.set(relations.prerequisite.join(", "));
+                        supersedes.set(relations.supersedes.join(", "));
+                        superseded_by.set(relations.superseded_by.join(", "));
 
                         view! {
                             <div class="space-y-4">
+                                // Lock holder banner
+                                {move || lock_status.get().filter(|s| !s.acquired).map(|status| {
+                                    let expiry = if status.expires_in_secs > 0 {
+                                        i18n.tf("editor.lock_expires", &[("seconds", &status.expires_in_secs.to_string())])
+                                    } else {
+                                        i18n.t("editor.lock_expired").to_string()
+                                    };
+                                    let (before, rest) = i18n.around("editor.locked_by", "holder");
+                                    view! {
+                                        <div class="alert alert-warning">
+                                            <span>
+                                                {before}
+                                                <strong>{status.holder}</strong>
+                                                {rest.replace("{expiry}", &expiry)}
+                                            </span>
+                                            <button
+                                                class="btn btn-sm"
+                                                on:click=move |_| {
+                                                    #[cfg(feature = "hydrate")]
+                                                    refresh_lock(slug(), true);
+                                                }
+                                            >
+                                                {i18n.t("editor.take_over")}
+                                            </button>
+                                        </div>
+                                    }
+                                })}
+
                                 // Title input
                                 <div class="form-control">
                                     <label class="label">
-                                        <span class="label-text font-semibold">"Document Title"</span>
+                                        <span class="label-text font-semibold">{i18n.t("editor.doc_title")}</span>
                                     </label>
                                     <input
                                         type="text"
                                         class="input input-bordered w-full"
                                         prop:value=title
+                                        prop:disabled=disabled
                                         on:input=move |ev| {
                                             set_title.set(event_target_value(&ev));
                                         }
                                     />
                                 </div>
 
-                                // Toolbar
-                                <div class="flex flex-wrap gap-1 p-2 bg-base-200 rounded-lg">
-                                    <button class="btn btn-sm btn-ghost" title="Bold"
+                                // Relations
+                                <details class="collapse collapse-arrow border border-base-300 rounded-lg">
+                                    <summary class="collapse-title text-sm font-semibold">{i18n.t("editor.related")}</summary>
+                                    <div class="collapse-content grid gap-2 md:grid-cols-2">
+                                        <RelationInput label=i18n.t("editor.see_also") slugs=see_also disabled />
+                                        <RelationInput label=i18n.t("editor.prerequisites") slugs=prerequisite disabled />
+                                        <RelationInput label=i18n.t("editor.supersedes") slugs=supersedes disabled />
+                                        <RelationInput label=i18n.t("editor.superseded_by") slugs=superseded_by disabled />
+                                    </div>
+                                </details>
+
+                                // Toolbar (Alt+F10 from the editor, arrow keys within)
+                                <div
+                                    node_ref=toolbar_ref
+                                    role="toolbar"
+                                    aria-label=i18n.t("editor.toolbar")
+                                    aria-controls="lekton-editor"
+                                    class="flex flex-wrap gap-1 p-2 bg-base-200 rounded-lg"
+                                    on:keydown=move |ev| {
+                                        if let Some(toolbar) = toolbar_ref.get() {
+                                            toolbar_arrow_keys(&ev, &toolbar);
+                                        }
+                                    }
+                                >
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.bold") aria-label=i18n.t("editor.bold")
+                                        aria-pressed=move || selection.get().bold.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::Bold)>
                                         <strong>"B"</strong>
                                     </button>
-                                    <button class="btn btn-sm btn-ghost" title="Italic"
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.italic") aria-label=i18n.t("editor.italic")
+                                        aria-pressed=move || selection.get().italic.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::Italic)>
                                         <em>"I"</em>
                                     </button>
-                                    <button class="btn btn-sm btn-ghost" title="Strikethrough"
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.strike") aria-label=i18n.t("editor.strike")
+                                        aria-pressed=move || selection.get().strike.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::Strike)>
                                         <s>"S"</s>
                                     </button>
-                                    <div class="divider divider-horizontal mx-0"></div>
-                                    <button class="btn btn-sm btn-ghost" title="Heading 1"
+                                    <div class="divider divider-horizontal mx-0" aria-hidden="true"></div>
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.heading1") aria-label=i18n.t("editor.heading1")
+                                        aria-pressed=move || selection.get().h1.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::H1)>
                                         "H1"
                                     </button>
-                                    <button class="btn btn-sm btn-ghost" title="Heading 2"
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.heading2") aria-label=i18n.t("editor.heading2")
+                                        aria-pressed=move || selection.get().h2.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::H2)>
                                         "H2"
                                     </button>
-                                    <button class="btn btn-sm btn-ghost" title="Heading 3"
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.heading3") aria-label=i18n.t("editor.heading3")
+                                        aria-pressed=move || selection.get().h3.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::H3)>
                                         "H3"
                                     </button>
-                                    <div class="divider divider-horizontal mx-0"></div>
-                                    <button class="btn btn-sm btn-ghost" title="Bullet List"
+                                    <div class="divider divider-horizontal mx-0" aria-hidden="true"></div>
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.bullet_list") aria-label=i18n.t("editor.bullet_list")
+                                        aria-pressed=move || selection.get().bullet_list.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::BulletList)>
-                                        "List"
+                                        {i18n.t("editor.bullet_list_short")}
                                     </button>
-                                    <button class="btn btn-sm btn-ghost" title="Ordered List"
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.ordered_list") aria-label=i18n.t("editor.ordered_list")
+                                        aria-pressed=move || selection.get().ordered_list.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::OrderedList)>
-                                        "1. List"
+                                        {i18n.t("editor.ordered_list_short")}
                                     </button>
-                                    <button class="btn btn-sm btn-ghost" title="Blockquote"
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.blockquote") aria-label=i18n.t("editor.blockquote")
+                                        aria-pressed=move || selection.get().blockquote.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::Blockquote)>
-                                        "Quote"
+                                        {i18n.t("editor.blockquote_short")}
                                     </button>
-                                    <button class="btn btn-sm btn-ghost" title="Highlight"
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.highlight") aria-label=i18n.t("editor.highlight")
+                                        aria-pressed=move || selection.get().highlight.to_string()
                                         on:click=move |_| set_msg.set(TiptapInstanceMsg::Highlight)>
-                                        "HL"
+                                        {i18n.t("editor.highlight_short")}
                                     </button>
-                                    <div class="divider divider-horizontal mx-0"></div>
-                                    <button class="btn btn-sm btn-ghost" title="Insert Image"
+                                    <div class="divider divider-horizontal mx-0" aria-hidden="true"></div>
+                                    <button type="button" class="btn btn-sm btn-ghost" title=i18n.t("editor.insert_image") aria-label=i18n.t("editor.insert_image")
                                         on:click=move |_| {
                                             #[cfg(feature = "hydrate")]
                                             leptos::task::spawn_local(async {
@@ -273,12 +488,22 @@ pub fn EditorPage() -> impl IntoView {
                                                 ).await;
                                             });
                                         }>
-                                        "Img"
+                                        {i18n.t("editor.insert_image_short")}
                                     </button>
                                 </div>
 
                                 // Editor
-                                <div class="border border-base-300 rounded-lg min-h-[400px] p-4 bg-base-100 prose prose-lg max-w-none">
+                                <div
+                                    class="border border-base-300 rounded-lg min-h-[400px] p-4 bg-base-100 prose prose-lg max-w-none"
+                                    on:keydown=move |ev: leptos::web_sys::KeyboardEvent| {
+                                        if ev.alt_key() && ev.key() == "F10" {
+                                            if let Some(toolbar) = toolbar_ref.get() {
+                                                ev.prevent_default();
+                                                focus_first(&toolbar);
+                                            }
+                                        }
+                                    }
+                                >
                                     <TiptapInstance
                                         id=Signal::derive(|| "lekton-editor".to_string())
                                         msg=msg
@@ -300,27 +525,59 @@ pub fn EditorPage() -> impl IntoView {
                                 <div class="flex items-center gap-4">
                                     <button
                                         class="btn btn-primary"
-                                        prop:disabled=saving
+                                        prop:disabled=move || saving.get() || locked_out()
                                         on:click=move |_| { save_action.dispatch(()); }
                                     >
-                                        {move || if saving.get() { "Saving..." } else { "Save Document" }}
+                                        {move || if saving.get() { i18n.t("editor.saving") } else { i18n.t("editor.save") }}
+                                    </button>
+                                    <button
+                                        class="btn btn-ghost"
+                                        prop:disabled=move || spelling_action.pending().get()
+                                        on:click=move |_| { spelling_action.dispatch(()); }
+                                    >
+                                        {move || if spelling_action.pending().get() { i18n.t("editor.checking") } else { i18n.t("editor.check_spelling") }}
                                     </button>
                                     <a
                                         href=move || format!("/docs/{}", slug())
                                         class="btn btn-ghost"
                                     >
-                                        "Cancel"
+                                        {i18n.t("editor.cancel")}
                                     </a>
-                                    {move || {
-                                        let status = save_status.get();
-                                        if status.is_empty() {
-                                            view! { <span></span> }.into_any()
-                                        } else if status.starts_with("Error") {
-                                            view! { <span class="text-error">{status}</span> }.into_any()
-                                        } else {
-                                            view! { <span class="text-success">{status}</span> }.into_any()
-                                        }
-                                    }}
+                                </div>
+
+                                // Spell check results
+                                <div aria-live="polite">
+                                    {move || spelling_action.value().get().map(|result| match result {
+                                        Ok(misspellings) if misspellings.is_empty() => view! {
+                                            <div class="alert alert-success">
+                                                <span>{i18n.t("editor.no_misspellings")}</span>
+                                            </div>
+                                        }.into_any(),
+                                        Ok(misspellings) => view! {
+                                            <div class="alert alert-warning items-start">
+                                                <div>
+                                                    <p class="font-semibold">{i18n.tf("editor.misspellings", &[("count", &misspellings.len().to_string())])}</p>
+                                                    <ul class="text-sm mt-1">
+                                                        {misspellings.into_iter().map(|m| {
+                                                            let suggestions = if m.suggestions.is_empty() {
+                                                                String::new()
+                                                            } else {
+                                                                format!(" — {}", m.suggestions.join(", "))
+                                                            };
+                                                            view! {
+                                                                <li><span class="font-mono font-semibold">{m.word}</span>{suggestions}</li>
+                                                            }
+                                                        }).collect_view()}
+                                                    </ul>
+                                                </div>
+                                            </div>
+                                        }.into_any(),
+                                        Err(e) => view! {
+                                            <div class="alert alert-error">
+                                                <span>{i18n.tf("editor.spelling_failed", &[("error", &e.to_string())])}</span>
+                                            </div>
+                                        }.into_any(),
+                                    })}
                                 </div>
 
                                 // Asset panel
@@ -331,14 +588,17 @@ pub fn EditorPage() -> impl IntoView {
                     Ok(None) => {
                         view! {
                             <div class="alert alert-warning">
-                                <span>"Document not found. You can create a new document from this editor."</span>
+                                <span>{i18n.t("editor.not_found")}</span>
                             </div>
                         }.into_any()
                     }
                     Err(e) => {
+                        if let Some(required_level) = access_denied_level(&e) {
+                            return view! { <AccessDenied required_level /> }.into_any();
+                        }
                         view! {
                             <div class="alert alert-error">
-                                <span>{format!("Error loading document: {e}")}</span>
+                                <span>{i18n.tf("editor.load_error", &[("error", &e.to_string())])}</span>
                             </div>
                         }.into_any()
                     }
@@ -347,3 +607,36 @@ pub fn EditorPage() -> impl IntoView {
         </Suspense>
     }
 }
+
+/// Comma-separated slugs typed in a relation field.
+fn split_slugs(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|slug| !slug.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Text field for the comma-separated slugs of one relation.
+#[component]
+fn RelationInput(
+    label: &'static str,
+    slugs: RwSignal<String>,
+    disabled: ReadSignal<bool>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    view! {
+        <label class="form-control">
+            <span class="label-text text-xs">{label}</span>
+            <input
+                type="text"
+                class="input input-bordered input-sm w-full font-mono"
+                placeholder=i18n.t("editor.slugs_placeholder")
+                prop:value=slugs
+                prop:disabled=disabled
+                on:input=move |ev| slugs.set(event_target_value(&ev))
+            />
+        </label>
+    }
+}
@@ -0,0 +1,410 @@
+//! Notification emails sent through an SMTP relay (`[email]` config).
+//!
+//! | Email            | Sent to                          | When                                    |
+//! |------------------|----------------------------------|-----------------------------------------|
+//! | Owner digest     | `[email.owners]` of each owner   | `email_digest` scheduled job            |
+//! | Pending approval | `email.approval_recipients`      | An ingest is queued for admin approval  |
+//! | Mention          | Mentioned user or owner          | A document gains an `@mention`          |
+//! | Watch            | Users watching with email on     | A watched document changes              |
+//!
+//! Bodies are plain text rendered from the Tera templates in `templates/`.
+//! Delivery goes through the [`Mailer`] trait so it can be replaced in tests.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::{EmailConfig, SmtpSecurity};
+use crate::db::models::Document;
+use crate::db::notification_repository::Notification;
+use crate::db::pending_ingest_repository::PendingIngest;
+use crate::error::AppError;
+
+const OWNER_DIGEST_TEMPLATE: &str = "owner_digest.txt";
+const PENDING_APPROVAL_TEMPLATE: &str = "pending_approval.txt";
+const MENTION_TEMPLATE: &str = "mention.txt";
+const WATCH_TEMPLATE: &str = "watch.txt";
+
+/// A plain-text email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub to: Vec<String>,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Delivers emails.
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: &EmailMessage) -> Result<(), AppError>;
+}
+
+/// [`Mailer`] backed by an SMTP relay.
+pub struct SmtpMailer {
+    transport: lettre::AsyncSmtpTransport<lettre::Tokio1Executor>,
+    from: lettre::message::Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn from_config(config: &EmailConfig) -> Result<Self, AppError> {
+        use lettre::transport::smtp::authentication::Credentials;
+        use lettre::{AsyncSmtpTransport, Tokio1Executor};
+
+        let from = config.from.parse().map_err(|e| {
+            AppError::BadRequest(format!("Invalid email.from '{}': {e}", config.from))
+        })?;
+        let host = config.smtp_host.trim();
+        let relay_error = |e| AppError::BadRequest(format!("Invalid SMTP relay '{host}': {e}"));
+        let mut builder = match config.smtp_security {
+            SmtpSecurity::Starttls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host).map_err(relay_error)?
+            }
+            SmtpSecurity::Tls => {
+                AsyncSmtpTransport::<Tokio1Executor>::relay(host).map_err(relay_error)?
+            }
+            SmtpSecurity::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+        }
+        .port(config.smtp_port);
+        if !config.smtp_username.is_empty() {
+            builder = builder.credentials(Credentials::new(
+                config.smtp_username.clone(),
+                config.smtp_password.clone(),
+            ));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &EmailMessage) -> Result<(), AppError> {
+        use lettre::message::header::ContentType;
+        use lettre::AsyncTransport;
+
+        let mut builder = lettre::Message::builder()
+            .from(self.from.clone())
+            .subject(&message.subject)
+            .header(ContentType::TEXT_PLAIN);
+        for to in &message.to {
+            let mailbox = to
+                .parse()
+                .map_err(|e| AppError::BadRequest(format!("Invalid recipient '{to}': {e}")))?;
+            builder = builder.to(mailbox);
+        }
+        let email = builder
+            .body(message.body.clone())
+            .map_err(|e| AppError::Internal(format!("Failed to build email: {e}")))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| AppError::Unavailable(format!("SMTP delivery failed: {e}")))?;
+        Ok(())
+    }
+}
+
+/// A broken link as listed in an owner digest.
+#[derive(Debug, Serialize)]
+struct BrokenLink<'a> {
+    document: &'a str,
+    target: &'a str,
+}
+
+/// A stale document as listed in an owner digest.
+#[derive(Debug, Serialize)]
+struct StaleDocument<'a> {
+    slug: &'a str,
+    title: &'a str,
+    last_updated: String,
+}
+
+/// Where a mention was made, for [`EmailNotifier::send_mention`].
+#[derive(Debug, Clone, Copy)]
+pub struct MentionSource<'a> {
+    pub slug: &'a str,
+    pub title: &'a str,
+    /// Service token or user that made the change.
+    pub actor: &'a str,
+}
+
+/// Renders notification emails and hands them to a [`Mailer`].
+pub struct EmailNotifier {
+    mailer: Arc<dyn Mailer>,
+    templates: tera::Tera,
+    config: EmailConfig,
+}
+
+impl EmailNotifier {
+    pub fn new(mailer: Arc<dyn Mailer>, config: EmailConfig) -> Result<Self, AppError> {
+        let mut templates = tera::Tera::default();
+        templates
+            .add_raw_templates([
+                (
+                    OWNER_DIGEST_TEMPLATE,
+                    include_str!("templates/owner_digest.txt"),
+                ),
+                (
+                    PENDING_APPROVAL_TEMPLATE,
+                    include_str!("templates/pending_approval.txt"),
+                ),
+                (MENTION_TEMPLATE, include_str!("templates/mention.txt")),
+                (WATCH_TEMPLATE, include_str!("templates/watch.txt")),
+            ])
+            .map_err(|e| AppError::Internal(format!("Invalid email template: {e}")))?;
+        Ok(Self {
+            mailer,
+            templates,
+            config,
+        })
+    }
+
+    /// Notifier sending through the configured SMTP relay, or `None` when
+    /// `email.smtp_host` is empty.
+    pub fn from_config(config: &EmailConfig) -> Result<Option<Self>, AppError> {
+        if config.smtp_host.trim().is_empty() {
+            return Ok(None);
+        }
+        let mailer = SmtpMailer::from_config(config)?;
+        Self::new(Arc::new(mailer), config.clone()).map(Some)
+    }
+
+    /// Recipients configured for `owner` in `[email.owners]`.
+    pub fn owner_recipients(&self, owner: &str) -> &[String] {
+        self.config.owner_recipients(owner)
+    }
+
+    fn render(&self, template: &str, context: &tera::Context) -> Result<String, AppError> {
+        self.templates
+            .render(template, context)
+            .map_err(|e| AppError::Internal(format!("Failed to render {template}: {e}")))
+    }
+
+    /// Email the recipients of `owner` its broken links and stale documents.
+    ///
+    /// Returns `false` without sending when the owner has no recipients or
+    /// nothing to report.
+    pub async fn send_owner_digest(
+        &self,
+        owner: &str,
+        broken_links: &[(String, String)],
+        stale_documents: &[&Document],
+        stale_after_days: u32,
+    ) -> Result<bool, AppError> {
+        let recipients = self.config.owner_recipients(owner);
+        if recipients.is_empty() || (broken_links.is_empty() && stale_documents.is_empty()) {
+            return Ok(false);
+        }
+
+        let mut context = tera::Context::new();
+        context.insert("owner", owner);
+        context.insert(
+            "broken_links",
+            &broken_links
+                .iter()
+                .map(|(document, target)| BrokenLink { document, target })
+                .collect::<Vec<_>>(),
+        );
+        context.insert(
+            "stale_documents",
+            &stale_documents
+                .iter()
+                .map(|doc| StaleDocument {
+                    slug: &doc.slug,
+                    title: &doc.title,
+                    last_updated: format_date(doc.last_updated),
+                })
+                .collect::<Vec<_>>(),
+        );
+        context.insert("stale_after_days", &stale_after_days);
+
+        let message = EmailMessage {
+            to: recipients.to_vec(),
+            subject: format!(
+                "[Lekton] {owner}: {} broken links, {} stale documents",
+                broken_links.len(),
+                stale_documents.len()
+            ),
+            body: self.render(OWNER_DIGEST_TEMPLATE, &context)?,
+        };
+        self.mailer.send(&message).await?;
+        Ok(true)
+    }
+
+    /// Tell the approval recipients that `pending` waits for review.
+    /// Failures are logged; they must not fail the ingest.
+    pub async fn notify_pending_approval(&self, pending: &PendingIngest) {
+        if self.config.approval_recipients.is_empty() {
+            return;
+        }
+        if let Err(e) = self.send_pending_approval(pending).await {
+            tracing::warn!(slug = %pending.slug, "Failed to email approval notice: {e}");
+        }
+    }
+
+    async fn send_pending_approval(&self, pending: &PendingIngest) -> Result<(), AppError> {
+        let mut context = tera::Context::new();
+        context.insert("id", &pending.id);
+        context.insert("slug", &pending.slug);
+        context.insert("title", &pending.request.title);
+        context.insert("submitted_by", &pending.submitted_by);
+        context.insert("changes", &pending.changes);
+
+        let message = EmailMessage {
+            to: self.config.approval_recipients.clone(),
+            subject: format!("[Lekton] Approval needed: {}", pending.slug),
+            body: self.render(PENDING_APPROVAL_TEMPLATE, &context)?,
+        };
+        self.mailer.send(&message).await
+    }
+
+    /// Tell `recipients` that `@handle` was mentioned in `source`. `team`
+    /// marks handles resolved through `[email.owners]`.
+    pub async fn send_mention(
+        &self,
+        recipients: &[String],
+        handle: &str,
+        team: bool,
+        source: MentionSource<'_>,
+    ) -> Result<(), AppError> {
+        let mut context = tera::Context::new();
+        context.insert("handle", handle);
+        context.insert("team", &team);
+        context.insert("slug", source.slug);
+        context.insert("title", source.title);
+        context.insert("actor", source.actor);
+
+        let message = EmailMessage {
+            to: recipients.to_vec(),
+            subject: format!(
+                "[Lekton] {} mentioned @{handle} in {}",
+                source.actor, source.title
+            ),
+            body: self.render(MENTION_TEMPLATE, &context)?,
+        };
+        self.mailer.send(&message).await
+    }
+
+    /// Email `recipient` the change described by `notification`.
+    pub async fn send_watch_notice(
+        &self,
+        recipient: &str,
+        notification: &Notification,
+    ) -> Result<(), AppError> {
+        let action = notification.action();
+        let mut context = tera::Context::new();
+        context.insert("actor", &notification.actor);
+        context.insert("action", action);
+        context.insert("slug", &notification.slug);
+        context.insert("title", &notification.title);
+        context.insert(
+            "watch_target",
+            notification
+                .watch_target
+                .as_deref()
+                .unwrap_or(&notification.slug),
+        );
+
+        let message = EmailMessage {
+            to: vec![recipient.to_string()],
+            subject: format!(
+                "[Lekton] {} {action} {}",
+                notification.actor, notification.title
+            ),
+            body: self.render(WATCH_TEMPLATE, &context)?,
+        };
+        self.mailer.send(&message).await
+    }
+}
+
+fn format_date(value: DateTime<Utc>) -> String {
+    value.format("%Y-%m-%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockMailer;
+    use std::collections::HashMap;
+
+    fn notifier(mailer: Arc<MockMailer>) -> EmailNotifier {
+        let config = EmailConfig {
+            approval_recipients: vec!["admins@example.com".into()],
+            owners: HashMap::from([(
+                "Payments".to_string(),
+                vec!["payments@example.com".to_string()],
+            )]),
+            ..EmailConfig::default()
+        };
+        EmailNotifier::new(mailer, config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_owner_digest() {
+        let mailer = Arc::new(MockMailer::default());
+        let notifier = notifier(mailer.clone());
+        let broken = vec![("payments/api".to_string(), "payments/old".to_string())];
+
+        assert!(notifier
+            .send_owner_digest("payments", &broken, &[], 180)
+            .await
+            .unwrap());
+        // Unknown owners and empty digests are not sent
+        assert!(!notifier
+            .send_owner_digest("search", &broken, &[], 180)
+            .await
+            .unwrap());
+        assert!(!notifier
+            .send_owner_digest("payments", &[], &[], 180)
+            .await
+            .unwrap());
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, ["payments@example.com"]);
+        assert_eq!(
+            sent[0].subject,
+            "[Lekton] payments: 1 broken links, 0 stale documents"
+        );
+        assert!(sent[0].body.contains("  - payments/api → payments/old\n"));
+        assert!(!sent[0].body.contains("Not updated"));
+    }
+
+    #[tokio::test]
+    async fn test_pending_approval_notice() {
+        let mailer = Arc::new(MockMailer::default());
+        let notifier = notifier(mailer.clone());
+        let pending = PendingIngest {
+            id: "p-1".into(),
+            slug: "security/policy".into(),
+            request: serde_json::from_value(serde_json::json!({
+                "service_token": "",
+                "slug": "security/policy",
+                "title": "Security Policy",
+                "content": "# Policy",
+                "access_level": "internal",
+                "service_owner": "security",
+                "source_path": "security/policy.md",
+            }))
+            .unwrap(),
+            submitted_by: "ci-token".into(),
+            changes: vec!["content changed".into()],
+            submitted_at: Utc::now(),
+        };
+
+        notifier.notify_pending_approval(&pending).await;
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent[0].to, ["admins@example.com"]);
+        assert_eq!(sent[0].subject, "[Lekton] Approval needed: security/policy");
+        assert!(sent[0]
+            .body
+            .starts_with("ci-token submitted an update to security/policy (\"Security Policy\")"));
+        assert!(sent[0].body.contains("  - content changed\n"));
+    }
+}
@@ -21,10 +21,126 @@ pub enum AppError {
     #[error("Bad request: {0}")]
     BadRequest(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
+
+    /// Content rejected because it appears to contain credentials.
+    #[error("Secret detected: {0}")]
+    SecretDetected(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
+
+    #[error("Service unavailable: {0}")]
+    Unavailable(String),
 }
 
+impl AppError {
+    /// Stable machine-readable error code, returned as the `code` member of
+    /// problem responses so API clients can branch on the error type.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::Database(_) => "database_error",
+            AppError::Storage(_) => "storage_error",
+            AppError::Auth(_) => "unauthenticated",
+            AppError::NotFound(_) => "not_found",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::BadRequest(_) => "bad_request",
+            AppError::Conflict(_) => "conflict",
+            AppError::QuotaExceeded(_) => "quota_exceeded",
+            AppError::SecretDetected(_) => "secret_detected",
+            AppError::Internal(_) => "internal_error",
+            AppError::Unavailable(_) => "service_unavailable",
+        }
+    }
+
+    /// HTTP status code of the error.
+    pub fn status(&self) -> u16 {
+        match self {
+            AppError::Auth(_) => 401,
+            AppError::Forbidden(_) | AppError::QuotaExceeded(_) => 403,
+            AppError::NotFound(_) => 404,
+            AppError::BadRequest(_) | AppError::SecretDetected(_) => 400,
+            AppError::Conflict(_) => 409,
+            AppError::Unavailable(_) => 503,
+            AppError::Database(_) | AppError::Storage(_) | AppError::Internal(_) => 500,
+        }
+    }
+
+    /// Short, human-readable summary of the error type.
+    pub fn title(&self) -> &'static str {
+        match self {
+            AppError::Auth(_) => "Authentication required",
+            AppError::Forbidden(_) => "Forbidden",
+            AppError::NotFound(_) => "Not found",
+            AppError::BadRequest(_) => "Bad request",
+            AppError::Conflict(_) => "Conflict",
+            AppError::QuotaExceeded(_) => "Quota exceeded",
+            AppError::SecretDetected(_) => "Secret detected",
+            AppError::Unavailable(_) => "Service unavailable",
+            AppError::Database(_) | AppError::Storage(_) | AppError::Internal(_) => {
+                "Internal server error"
+            }
+        }
+    }
+
+    /// Detail safe to return to clients. Server-side failures only get a
+    /// generic text, since their message may reveal internals.
+    pub fn public_detail(&self) -> String {
+        match self {
+            AppError::Auth(msg)
+            | AppError::Forbidden(msg)
+            | AppError::NotFound(msg)
+            | AppError::BadRequest(msg)
+            | AppError::Conflict(msg)
+            | AppError::QuotaExceeded(msg)
+            | AppError::SecretDetected(msg) => msg.clone(),
+            AppError::Unavailable(_) => "Service temporarily unavailable".to_string(),
+            AppError::Database(_) | AppError::Storage(_) | AppError::Internal(_) => {
+                "Internal server error".to_string()
+            }
+        }
+    }
+
+    /// Log server-side failures, whose details are not returned to clients.
+    pub fn log(&self) {
+        match self {
+            AppError::Database(msg) => tracing::error!("Database error: {msg}"),
+            AppError::Storage(msg) => tracing::error!("Storage error: {msg}"),
+            AppError::Internal(msg) => tracing::error!("Internal error: {msg}"),
+            AppError::Unavailable(msg) => tracing::warn!("Service unavailable: {msg}"),
+            _ => {}
+        }
+    }
+
+    /// Convert into a server function error.
+    ///
+    /// Sets the HTTP status and the [`ERROR_CODE_HEADER`] of the server
+    /// function response, so failures are not all reported as a bare 500.
+    #[cfg(feature = "ssr")]
+    pub fn into_server_fn_error(self) -> leptos::prelude::ServerFnError {
+        use axum::http::{HeaderValue, StatusCode};
+
+        self.log();
+        if let Some(response) = leptos::prelude::use_context::<leptos_axum::ResponseOptions>() {
+            if let Ok(status) = StatusCode::from_u16(self.status()) {
+                response.set_status(status);
+            }
+            response.insert_header(
+                axum::http::HeaderName::from_static(ERROR_CODE_HEADER),
+                HeaderValue::from_static(self.code()),
+            );
+        }
+        leptos::prelude::ServerFnError::new(self.public_detail())
+    }
+}
+
+/// Response header carrying [`AppError::code`] on failed server function calls.
+pub const ERROR_CODE_HEADER: &str = "lekton-error-code";
+
 impl From<anyhow::Error> for AppError {
     fn from(err: anyhow::Error) -> Self {
         AppError::Internal(err.to_string())
@@ -0,0 +1,75 @@
+//! Runtime feature flags.
+//!
+//! Operators switch subsystems on and off per deployment from the admin
+//! settings page ([`FeatureFlags`] in the settings document) instead of
+//! building with different cargo features. The flags are read on every
+//! request, so changes apply without a restart:
+//!
+//! - `editor`: editor loads and saves are rejected and documents are
+//!   rendered without the edit button,
+//! - `chat`: the chat and ask endpoints are rejected and the chat links
+//!   are hidden,
+//! - `search_provider`: `hybrid` blends in semantic search when it is
+//!   configured, `full_text` uses Meilisearch alone and `disabled` rejects
+//!   queries and hides the search bar.
+//!
+//! The client reads the same flags through
+//! [`get_portal_config`](crate::server::portal_config::get_portal_config).
+
+use crate::app::AppState;
+use crate::db::settings_repository::{FeatureFlags, SearchProvider};
+use crate::error::AppError;
+use crate::search::client::{SearchFilter, SearchHit, SearchService};
+
+/// The current feature flags.
+pub async fn flags(state: &AppState) -> Result<FeatureFlags, AppError> {
+    Ok(state.settings_repo.get_settings().await?.features)
+}
+
+/// Fail unless the web editor is enabled.
+pub async fn require_editor(state: &AppState) -> Result<(), AppError> {
+    if flags(state).await?.editor {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden(
+            "The web editor is disabled on this portal".into(),
+        ))
+    }
+}
+
+/// Fail unless the AI assistant is enabled.
+pub async fn require_chat(state: &AppState) -> Result<(), AppError> {
+    if flags(state).await?.chat {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden(
+            "The AI assistant is disabled on this portal".into(),
+        ))
+    }
+}
+
+/// Run a search with the configured [`SearchProvider`].
+pub async fn search(
+    state: &AppState,
+    service: &dyn SearchService,
+    query: &str,
+    allowed_levels: Option<&[String]>,
+    include_draft: bool,
+    filter: &SearchFilter,
+) -> Result<Vec<SearchHit>, AppError> {
+    match flags(state).await?.search_provider {
+        SearchProvider::Hybrid => {
+            service
+                .search(query, allowed_levels, include_draft, filter)
+                .await
+        }
+        SearchProvider::FullText => {
+            service
+                .search_full_text(query, allowed_levels, include_draft, filter)
+                .await
+        }
+        SearchProvider::Disabled => Err(AppError::Forbidden(
+            "Search is disabled on this portal".into(),
+        )),
+    }
+}
@@ -0,0 +1,213 @@
+//! Response compression and `Cache-Control` policies.
+//!
+//! Responses are compressed with brotli or gzip, whichever the client prefers
+//! in `Accept-Encoding` (see [`compression_layer`]). Event streams, images and
+//! tiny bodies are sent as-is, and so are downloads that support byte ranges
+//! or are already compressed ([`UncompressedDownloads`]): compressing them
+//! would drop `Accept-Ranges` and break resumed downloads and media seeking.
+//!
+//! [`cache_headers`] picks the caching policy from the request path:
+//!
+//! | Path                              | `Cache-Control`                          |
+//! |-----------------------------------|------------------------------------------|
+//! | `/js/*.{js,mjs,css}?v=<version>`  | `public, max-age=31536000, immutable`    |
+//! | `/js/*.{js,mjs,css}`              | `public, max-age=3600`                   |
+//! | `/pkg/*`                          | `public, no-cache` (revalidated)         |
+//! | `/api/*`                          | `private, no-store`, unless already set  |
+//!
+//! The `/pkg` bundle is not fingerprinted, so browsers revalidate it on each
+//! load instead of running a stale WASM build after a deploy. API responses
+//! carry access-controlled content; handlers that can be cached (e.g. schema
+//! specs) set their own header.
+//!
+//! While incident mode is on, [`incident_cache_headers`] sends every
+//! response outside the `/pkg` and `/js` bundles with `no-store`, overriding
+//! the handler, so browsers and proxies always fetch the latest runbook
+//! edits.
+
+use axum::body::HttpBody;
+use axum::extract::{Request, State};
+use axum::http::header::{ACCEPT_RANGES, CACHE_CONTROL, CONTENT_RANGE, CONTENT_TYPE};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+use tower_http::compression::predicate::{And, DefaultPredicate, Predicate};
+use tower_http::compression::CompressionLayer;
+
+/// Content types that are already compressed, or binary formats that gain
+/// little from it.
+const PRECOMPRESSED_CONTENT_TYPES: [&str; 8] = [
+    "application/pdf",
+    "application/zip",
+    "application/gzip",
+    "application/x-7z-compressed",
+    "application/octet-stream",
+    "audio/",
+    "video/",
+    "font/woff",
+];
+
+/// Cache policy for a request, and whether it overrides a header set by the
+/// handler.
+fn cache_policy(path: &str, query: Option<&str>) -> Option<(HeaderValue, bool)> {
+    let is_js_asset = path.starts_with("/js/")
+        && (path.ends_with(".js") || path.ends_with(".mjs") || path.ends_with(".css"));
+
+    if is_js_asset {
+        let has_version = query.is_some_and(|q| q.contains("v="));
+        let value = if has_version {
+            HeaderValue::from_static("public, max-age=31536000, immutable")
+        } else {
+            HeaderValue::from_static("public, max-age=3600")
+        };
+        Some((value, true))
+    } else if path.starts_with("/pkg/") {
+        Some((HeaderValue::from_static("public, no-cache"), true))
+    } else if path.starts_with("/api/") {
+        Some((HeaderValue::from_static("private, no-store"), false))
+    } else {
+        None
+    }
+}
+
+/// Middleware setting `Cache-Control` according to the table above.
+pub async fn cache_headers(request: Request, next: Next) -> Response {
+    let policy = cache_policy(request.uri().path(), request.uri().query());
+    let mut response = next.run(request).await;
+
+    if let Some((value, overrides)) = policy {
+        if overrides || !response.headers().contains_key(CACHE_CONTROL) {
+            response.headers_mut().insert(CACHE_CONTROL, value);
+        }
+    }
+    response
+}
+
+/// Whether incident mode disables caching of `path`: everything but the
+/// application bundles.
+fn relaxed_during_incidents(path: &str) -> bool {
+    !path.starts_with("/pkg/") && !path.starts_with("/js/")
+}
+
+/// Middleware sending responses with `no-store` while incident mode is on.
+pub async fn incident_cache_headers(
+    State(state): State<crate::app::AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !relaxed_during_incidents(request.uri().path()) {
+        return next.run(request).await;
+    }
+    let active = match state.settings_repo.get_settings().await {
+        Ok(settings) => settings.incident_mode.active,
+        Err(e) => {
+            tracing::warn!("Failed to read incident mode: {e}");
+            false
+        }
+    };
+    let mut response = next.run(request).await;
+    if active {
+        response
+            .headers_mut()
+            .insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    }
+    response
+}
+
+/// Compression predicate skipping ranged and already compressed downloads.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UncompressedDownloads;
+
+impl Predicate for UncompressedDownloads {
+    fn should_compress<B>(&self, response: &axum::http::Response<B>) -> bool
+    where
+        B: HttpBody,
+    {
+        let headers = response.headers();
+        if headers.contains_key(ACCEPT_RANGES) || headers.contains_key(CONTENT_RANGE) {
+            return false;
+        }
+        let content_type = headers
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        !PRECOMPRESSED_CONTENT_TYPES
+            .iter()
+            .any(|t| content_type.starts_with(t))
+    }
+}
+
+/// Brotli/gzip compression negotiated through `Accept-Encoding`; with
+/// `enabled` off every response is passed through unencoded.
+pub fn compression_layer(
+    enabled: bool,
+) -> CompressionLayer<And<DefaultPredicate, UncompressedDownloads>> {
+    CompressionLayer::new()
+        .br(enabled)
+        .gzip(enabled)
+        .compress_when(DefaultPredicate::new().and(UncompressedDownloads))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(path: &str, query: Option<&str>) -> Option<(String, bool)> {
+        cache_policy(path, query)
+            .map(|(value, overrides)| (value.to_str().unwrap().to_string(), overrides))
+    }
+
+    #[test]
+    fn test_cache_policy_by_path() {
+        let immutable = "public, max-age=31536000, immutable".to_string();
+        assert_eq!(
+            policy("/js/scalar-standalone.js", Some("v=abc")),
+            Some((immutable, true))
+        );
+        assert_eq!(
+            policy("/js/asyncapi-default.min.css", None),
+            Some(("public, max-age=3600".to_string(), true))
+        );
+        assert_eq!(policy("/js/readme.txt", None), None);
+        assert_eq!(
+            policy("/pkg/lekton.wasm", None),
+            Some(("public, no-cache".to_string(), true))
+        );
+        assert_eq!(
+            policy("/api/v1/search", Some("q=x")),
+            Some(("private, no-store".to_string(), false))
+        );
+        assert_eq!(policy("/docs/intro", None), None);
+    }
+
+    #[test]
+    fn test_ranged_and_precompressed_downloads_are_not_compressed() {
+        let response = |headers: &[(axum::http::HeaderName, &'static str)]| {
+            let mut builder = axum::http::Response::builder();
+            for (name, value) in headers {
+                builder = builder.header(name, *value);
+            }
+            builder.body(axum::body::Body::empty()).unwrap()
+        };
+        let compressible = |headers| UncompressedDownloads.should_compress(&response(headers));
+
+        assert!(compressible(&[(CONTENT_TYPE, "text/html; charset=utf-8")]));
+        assert!(compressible(&[(CONTENT_TYPE, "application/json")]));
+        assert!(!compressible(&[
+            (CONTENT_TYPE, "text/plain"),
+            (ACCEPT_RANGES, "bytes")
+        ]));
+        assert!(!compressible(&[(CONTENT_RANGE, "bytes 0-9/100")]));
+        assert!(!compressible(&[(CONTENT_TYPE, "application/pdf")]));
+        assert!(!compressible(&[(CONTENT_TYPE, "video/mp4")]));
+    }
+
+    #[test]
+    fn test_incidents_relax_all_but_bundles() {
+        assert!(relaxed_during_incidents("/docs/runbooks/db-failover"));
+        assert!(relaxed_during_incidents("/api/v1/schemas/payments"));
+        assert!(relaxed_during_incidents("/"));
+        assert!(!relaxed_during_incidents("/pkg/lekton.wasm"));
+        assert!(!relaxed_during_incidents("/js/doc-enhancements.js"));
+    }
+}
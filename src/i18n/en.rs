@@ -0,0 +1,818 @@
+//! English catalog — the reference set of keys.
+
+super::catalog! {
+    "nav.docs" => "Docs",
+    "nav.registry" => "Registry",
+    "nav.chat" => "Chat",
+    "nav.admin" => "Admin",
+    "nav.more" => "More",
+    "nav.navigation" => "Navigation",
+    "nav.links" => "Links",
+    "nav.skip_to_content" => "Skip to content",
+    "nav.open_sidebar" => "Open navigation",
+    "nav.close_sidebar" => "Close navigation",
+    "nav.main" => "Main",
+    "search.button" => "Search documentation...",
+    "search.placeholder_short" => "Search docs...",
+    "search.min_chars" => "Type at least 2 characters to search...",
+    "search.no_results" => "No results found for \"{query}\"",
+    "search.error" => "Search error: {error}",
+    "search.failed" => "Search failed. Try again from the message below.",
+    "search.close_hint" => "Press ESC to close",
+    "search.filters_hint" => "tag: owner: type:doc|schema is:stale",
+    "search.active_filters" => "Active filters",
+    "toast.retry" => "Retry",
+    "toast.dismiss" => "Dismiss",
+    "error.title" => "Something went wrong",
+    "error.reload" => "Reload page",
+    "user.login" => "Log In",
+    "user.logout" => "Log Out",
+    "user.prompts" => "Prompt Library",
+    "user.profile" => "Profile & Tokens",
+    "user.theme" => "Theme",
+    "user.my_drafts" => "My drafts",
+    "user.notifications" => "Notifications",
+    "theme.light" => "Light mode (click for dark)",
+    "theme.dark" => "Dark mode (click for system)",
+    "theme.system" => "System theme (click for light)",
+    "language.label" => "Language",
+    "doc.print" => "Print",
+    "doc.print_title" => "Print or save as PDF",
+    "doc.edit" => "Edit",
+    "doc.sibling_nav" => "Pages in this section",
+    "doc.previous" => "Previous",
+    "doc.next" => "Next",
+    "doc.helpful_question" => "Was this page helpful?",
+    "doc.helpful_yes" => "Yes",
+    "doc.helpful_no" => "No",
+    "doc.helpful_editors_only" => "Ratings so far, shown to editors of this document",
+    "doc.helpful_comment_placeholder" => "What could be better? (optional)",
+    "doc.helpful_send" => "Send comment",
+    "doc.helpful_thanks" => "Thanks!",
+    "doc.watch" => "Watch",
+    "doc.unwatch" => "Unwatch",
+    "doc.watch_title" => "Get notified when this document changes",
+    "doc.share" => "Share",
+    "doc.share_title" => "Give people without an account read access to this page",
+    "doc.share_days" => "Valid for (days)",
+    "doc.share_note" => "Shared with (optional)",
+    "doc.share_create" => "Create link",
+    "doc.share_created" => "Copy this link now: it is not shown again.",
+    "doc.share_copy" => "Copy",
+    "doc.share_links" => "Active links",
+    "doc.share_link_status" => "expires {date} · {views} views",
+    "doc.share_revoke" => "Revoke",
+    "share_page.unavailable_title" => "Link unavailable",
+    "share_page.unavailable" => "This link is invalid, has expired or was revoked. Ask the person who shared it for a new one.",
+    "share_page.footer" => "Shared by {author} — this link expires on {date}.",
+    "print_page.unavailable_title" => "Document unavailable",
+    "print_page.unavailable" => "This document does not exist or you are not allowed to read it. If it is restricted, sign in to the portal and reload this page.",
+    "print_page.source" => "Source: {url}",
+    "doc.on_this_page" => "On This Page",
+    "doc.last_updated" => "Last updated: {date}",
+    "doc.last_updated_by" => "Last updated: {date} by {author}",
+    "doc.contributors" => "Contributors:",
+    "doc.edit_restricted" => "Edits restricted to {level}",
+    "doc.not_found" => "Document not found: {slug}",
+    "doc.load_error" => "Error loading document: {error}",
+    "doc.updated_notice" => "This page has been updated.",
+    "doc.refresh" => "Refresh",
+    "doc.deprecated_schemas" => "This page references deprecated APIs:",
+    "doc.deprecated_schema_use" => "migrate to {version}",
+    "doc.deprecated_schema_retired" => "no supported version left",
+    "doc.truncated" => "This document is too large to display in full; only its beginning is shown",
+    "doc.view_raw" => "View raw",
+    "doc.download" => "Download",
+    "doc.bundle_version" => "Version",
+    "doc.bundle_latest" => "latest",
+    "doc.bundle_prior_version" => "You are reading the documentation of version {version}.",
+    "doc.bundle_view_latest" => "View latest",
+    "doc.superseded_by" => "This page has been superseded by:",
+    "doc.relations.prerequisite" => "Read first",
+    "doc.relations.see_also" => "See also",
+    "doc.relations.supersedes" => "Replaces",
+    "doc.relations.prerequisite_of" => "Required reading for",
+    "nav.bundle_version" => "Version",
+    "search.deprecated_schemas" => "Deprecated API",
+    "search.superseded" => "Superseded",
+    "search.operations" => "API operations",
+    "home.welcome" => "Welcome to {portal}",
+    "home.subtitle" => "Your dynamic Internal Developer Portal. Search documentation, explore API schemas, and collaborate — all in one place.",
+    "home.get_started" => "Get Started",
+    "home.api_schemas" => "API Schemas",
+    "home.feature.docs.title" => "Dynamic Docs",
+    "home.feature.docs.body" => "CI/CD integration for live documentation updates. No rebuilds needed.",
+    "home.feature.rbac.title" => "Granular RBAC",
+    "home.feature.rbac.body" => "Role-based access control ensures sensitive docs are only visible to authorized users.",
+    "home.feature.registry.title" => "Schema Registry",
+    "home.feature.registry.body" => "Unified OpenAPI, AsyncAPI, and JSON Schema viewer with versioning.",
+    "incident.active" => "Incident mode is on — start from these runbooks:",
+    "incident.runbooks" => "Incident runbooks",
+    "incident.started_by" => "Started by {user} · {time}",
+    "incident.start" => "Start incident mode",
+    "incident.end" => "End incident mode",
+    "on_call.current" => "On call now:",
+    "on_call.nobody" => "Nobody is on call",
+    "on_call.schedule" => "Schedule",
+    "not_found.message" => "The page you are looking for does not exist.",
+    "not_found.back" => "Back to Home",
+    "nav.browse" => "Browse all documents",
+    "browse.title" => "Browse documentation",
+    "browse.subtitle" => "Every document you can read, with owners, tags and last update.",
+    "browse.filter_placeholder" => "Filter by title, slug, owner or tag...",
+    "browse.all_owners" => "All owners",
+    "browse.show_tree" => "Show tree",
+    "browse.documents" => "{shown} / {total} documents",
+    "browse.col.title" => "Title",
+    "browse.col.owner" => "Owner",
+    "browse.col.tags" => "Tags",
+    "browse.col.updated" => "Last updated",
+    "browse.draft" => "Draft",
+    "browse.my_drafts" => "My drafts",
+    "browse.empty" => "No documents match the current filters.",
+    "access.denied_title" => "Access denied",
+    "access.denied_text" => "You do not have permission to view this page. It requires the access level {level}",
+    "access.request" => "Request access",
+    "access.home" => "Back to home",
+    "nav.glossary" => "Glossary",
+    "glossary.title" => "Glossary",
+    "glossary.subtitle" => "Shared definitions of the terms used across the documentation.",
+    "glossary.filter_placeholder" => "Filter terms...",
+    "glossary.empty" => "No glossary terms found.",
+    "glossary.owner" => "Owner: {owner}",
+    "nav.changelog" => "Changelog",
+    "changelog.title" => "Changelog",
+    "changelog.subtitle" => "Release notes published by services, newest first.",
+    "changelog.all_services" => "← All services",
+    "changelog.empty" => "No changelog entries yet.",
+    "changelog.published_by" => "published by {name}",
+    "nav.collections" => "Collections",
+    "collections.title" => "Collections",
+    "collections.subtitle" => "Curated reading lists: open a document from a collection to step through it in order.",
+    "collections.empty" => "No collections yet.",
+    "collections.create" => "New collection",
+    "collections.edit" => "Edit collection",
+    "collections.all" => "← All collections",
+    "collections.curated_by" => "Curated by {owner}",
+    "collections.read" => "{progress} read",
+    "collections.start" => "Start reading",
+    "collections.continue" => "Continue reading",
+    "collections.no_documents" => "This collection has no documents you can read.",
+    "collections.document" => "Document",
+    "collections.mark_read" => "Read",
+    "collections.title_placeholder" => "Title, e.g. New joiner onboarding",
+    "collections.description_placeholder" => "Description",
+    "collections.slugs_placeholder" => "Document slugs, one per line, in reading order",
+    "collections.save" => "Save",
+    "collections.delete" => "Delete",
+    "login.redirecting" => "Redirecting to sign in...",
+    "login.redirect_fallback" => "If you are not redirected automatically, {link}.",
+    "login.redirect_link" => "click here",
+    "login.demo_title" => "Demo Login",
+    "login.demo_subtitle" => "Sign in with demo credentials to explore Lekton.",
+    "login.username" => "Username",
+    "login.password" => "Password",
+    "login.email" => "Email",
+    "login.role" => "Role",
+    "login.invalid_credentials" => "Invalid credentials",
+    "login.invalid_username" => "Invalid username or password",
+    "login.invalid_email" => "Invalid email or password",
+    "login.submit" => "Sign In",
+    "login.demo_accounts" => "Demo Accounts",
+    "login.role_developer" => "Developer",
+    "login.role_admin" => "Admin",
+    "login.role_public" => "Public",
+    "login.ldap_subtitle" => "Use your company directory account.",
+    "login.local_link" => "Sign in with a local account",
+    "login.forgot_password" => "Forgot your password? Ask an administrator to reset it.",
+    "profile.title" => "Profile",
+    "profile.role_admin" => "Admin",
+    "profile.role_user" => "User",
+    "profile.password_title" => "Password",
+    "profile.password_mismatch" => "The new passwords do not match",
+    "profile.password_change_required" => "Your password was set by an administrator. Choose a new one.",
+    "profile.password_changed" => "Password changed.",
+    "profile.current_password" => "Current password",
+    "profile.new_password" => "New password (at least 12 characters)",
+    "profile.confirm_password" => "Confirm new password",
+    "profile.change_password" => "Change password",
+    "profile.pats_title" => "Personal Access Tokens",
+    "profile.pats_intro" => "PATs allow IDE agents (Claude Code, Cursor, RooCode) to access documentation on your behalf via the MCP server. Each token inherits your permissions.",
+    "profile.pat_created" => "Token created — copy it now, it won't be shown again.",
+    "profile.pat_add_hint" => "Add it to Claude Code:",
+    "profile.dismiss" => "Dismiss",
+    "profile.no_pats" => "No personal access tokens yet.",
+    "profile.col_name" => "Name",
+    "profile.col_created" => "Created",
+    "profile.col_last_used" => "Last used",
+    "profile.col_status" => "Status",
+    "profile.create_pat" => "Create new token",
+    "profile.pat_name_placeholder" => "Token name (e.g. \"claude-code-work\")",
+    "profile.generate" => "Generate",
+    "profile.feedback_title" => "AI Feedback History",
+    "profile.feedback_intro" => "Feedback you gave on AI responses. You can review and delete any item.",
+    "profile.no_feedback" => "No feedback submitted yet.",
+    "profile.feedback_count_one" => "1 item",
+    "profile.feedback_count" => "{count} items",
+    "profile.helpful" => "Helpful",
+    "profile.not_helpful" => "Not helpful",
+    "profile.view_session" => "View session",
+    "profile.delete_feedback" => "Delete feedback",
+    "profile.never" => "Never",
+    "profile.active" => "Active",
+    "profile.inactive" => "Inactive",
+    "profile.deactivate" => "Deactivate",
+    "profile.activate" => "Activate",
+    "profile.delete_pat_confirm" => "Delete this token permanently?",
+    "profile.delete_permanently" => "Delete permanently",
+    "profile.delete" => "Delete",
+    "prompts.title" => "Prompt Library",
+    "prompts.intro" => "Published prompts can be added to your working context. Default primary prompts come from the shared library; favorites and hidden flags are personal preferences.",
+    "prompts.filter_placeholder" => "Search prompts by name, slug, owner, or tag",
+    "prompts.context_cost" => "Context cost: {cost}",
+    "prompts.cost" => "cost: {cost}",
+    "prompts.owner" => "owner: {owner}",
+    "prompts.favorite" => "Favorite in personal context",
+    "prompts.not_publishable" => "Not publishable to MCP",
+    "prompts.hide_primary" => "Hide shared primary prompt",
+    "prompts.not_primary" => "Not a shared primary prompt",
+    "notifications.title" => "Notifications",
+    "notifications.intro" => "Changes to the documents and sections you watch, mentions of you, and ingests waiting for or decided on approval. Watch a single page from its toolbar, or a whole section below.",
+    "notifications.inbox" => "Inbox",
+    "notifications.mark_all_read" => "Mark all as read",
+    "notifications.empty" => "No notifications yet.",
+    "notifications.watching_target" => "{time} · watching {target}",
+    "notifications.mark_read" => "Mark as read",
+    "notifications.watching" => "Watching",
+    "notifications.target" => "Document or section",
+    "notifications.webhook" => "Webhook URL (optional)",
+    "notifications.email" => "Also send an email",
+    "notifications.watch" => "Watch",
+    "notifications.no_watches" => "You are not watching anything.",
+    "notifications.col_target" => "Target",
+    "notifications.col_delivery" => "Delivery",
+    "notifications.col_since" => "Since",
+    "notifications.delivery_inbox" => "inbox",
+    "notifications.delivery_email" => "email",
+    "notifications.delivery_webhook" => "webhook",
+    "notifications.unwatch" => "Unwatch",
+    "editor.saved" => "Document '{slug}' saved successfully",
+    "editor.save_error" => "Error: {error}",
+    "editor.lock_expires" => "Their lock expires in {seconds}s unless their editor stays open.",
+    "editor.lock_expired" => "Their lock has expired.",
+    "editor.locked_by" => "{holder} is editing this document. {expiry}",
+    "editor.take_over" => "Take over",
+    "editor.doc_title" => "Document Title",
+    "editor.related" => "Related documents",
+    "editor.see_also" => "See also",
+    "editor.prerequisites" => "Prerequisites",
+    "editor.supersedes" => "Supersedes",
+    "editor.superseded_by" => "Superseded by",
+    "editor.slugs_placeholder" => "slug, other/slug",
+    "editor.toolbar" => "Formatting",
+    "editor.bold" => "Bold",
+    "editor.italic" => "Italic",
+    "editor.strike" => "Strikethrough",
+    "editor.heading1" => "Heading 1",
+    "editor.heading2" => "Heading 2",
+    "editor.heading3" => "Heading 3",
+    "editor.bullet_list" => "Bullet List",
+    "editor.bullet_list_short" => "List",
+    "editor.ordered_list" => "Ordered List",
+    "editor.ordered_list_short" => "1. List",
+    "editor.blockquote" => "Blockquote",
+    "editor.blockquote_short" => "Quote",
+    "editor.highlight" => "Highlight",
+    "editor.highlight_short" => "HL",
+    "editor.insert_image" => "Insert Image",
+    "editor.insert_image_short" => "Img",
+    "editor.saving" => "Saving...",
+    "editor.save" => "Save Document",
+    "editor.checking" => "Checking...",
+    "editor.check_spelling" => "Check spelling",
+    "editor.cancel" => "Cancel",
+    "editor.no_misspellings" => "No misspellings found.",
+    "editor.misspellings" => "{count} possible misspelling(s)",
+    "editor.spelling_failed" => "Spell check failed: {error}",
+    "editor.not_found" => "Document not found. You can create a new document from this editor.",
+    "editor.load_error" => "Error loading document: {error}",
+    "editor.assets" => "Assets",
+    "editor.upload_asset" => "Upload Asset",
+    "editor.no_assets" => "No assets uploaded yet.",
+    "editor.asset_name" => "Name",
+    "editor.asset_type" => "Type",
+    "editor.asset_size" => "Size",
+    "editor.asset_actions" => "Actions",
+    "editor.insert_asset_title" => "Insert into editor",
+    "editor.insert_asset" => "Insert",
+    "editor.download_asset" => "Download",
+    "editor.delete_asset_title" => "Delete asset",
+    "editor.delete_asset" => "Delete",
+    "editor.assets_error" => "Error loading assets: {error}",
+    "schemas.breadcrumb_home" => "Home",
+    "schemas.breadcrumb_schemas" => "Schemas",
+    "catalog.breadcrumb" => "Event catalog",
+    "catalog.title" => "Event Catalog",
+    "catalog.intro" => "Channels and topics of every AsyncAPI schema, with the services producing and consuming on each.",
+    "catalog.filter_placeholder" => "Filter channels or schemas",
+    "catalog.empty" => "No AsyncAPI channels registered yet. Ingest an AsyncAPI schema to populate the catalog.",
+    "catalog.no_match" => "No channels match this filter.",
+    "catalog.col_channel" => "Channel",
+    "catalog.col_producers" => "Producers",
+    "catalog.col_consumers" => "Consumers",
+    "catalog.load_error" => "Error loading the event catalog: {error}",
+    "contracts.title" => "Contract tests",
+    "contracts.consumer" => "Consumer",
+    "contracts.passed" => "passed",
+    "contracts.failed" => "failed",
+    "schemas.title" => "Schema Registry",
+    "schemas.intro" => "Browse and explore API schemas. Supports OpenAPI, AsyncAPI, and JSON Schema specifications.",
+    "schemas.search_placeholder" => "Search by name",
+    "schemas.type" => "Type",
+    "schemas.all_types" => "All types",
+    "schemas.owner_filter" => "Owner",
+    "schemas.all_owners" => "All owners",
+    "schemas.tag" => "Tag",
+    "schemas.all_tags" => "All tags",
+    "schemas.sort" => "Sort by",
+    "schemas.sort_name" => "Sort by name",
+    "schemas.sort_updated" => "Recently updated",
+    "schemas.no_match" => "No schemas match these filters.",
+    "schemas.empty" => "No schemas registered yet. Use the ingestion API to add schemas.",
+    "schemas.count_one" => "1 schema",
+    "schemas.count" => "{count} schemas",
+    "schemas.load_error" => "Error loading schemas: {error}",
+    "schemas.no_versions" => "no versions",
+    "schemas.version_count_one" => "1 version",
+    "schemas.version_count" => "{count} versions",
+    "schemas.updated" => "Updated {date}",
+    "schemas.owner" => "Owner: {owner}",
+    "schemas.latest_version" => "Latest version",
+    "schemas.contact" => "Contact: {contact}",
+    "schemas.repository" => "Repository",
+    "schemas.select_version" => "Select a version to view the schema specification.",
+    "schemas.content_error" => "Error loading schema content: {error}",
+    "schemas.schema_error" => "Error loading schema: {error}",
+    "schemas.specification" => "{spec} specification",
+    "schemas.channel" => "Channel",
+    "schemas.path" => "Path",
+    "schemas.operations" => "Operations",
+    "schemas.no_operations" => "No operations found in this specification.",
+    "schemas.operations_error" => "Error loading operations: {error}",
+    "schemas.method" => "Method",
+    "schemas.summary" => "Summary",
+    "schemas.operation_link" => "Link to this operation",
+    "schemas.snippets" => "Request snippets",
+    "schemas.copy" => "Copy",
+    "schemas.copied" => "Copied",
+    "schemas.version" => "Version:",
+    "schemas.download_title" => "Download the raw JSON/YAML file",
+    "schemas.download" => "Download raw",
+    "schemas.copy_permalink" => "Copy permalink",
+    "schemas.copy_curl" => "Copy as curl",
+    "schemas.loading_scalar" => "Loading API reference viewer...",
+    "schemas.loading_asyncapi" => "Loading AsyncAPI viewer...",
+    "admin.access_denied" => "Access denied. Admin privileges required.",
+    "admin.title.tokens" => "Service Tokens",
+    "admin.title.pats" => "Personal Access Tokens",
+    "admin.title.api_keys" => "API Keys",
+    "admin.title.approvals" => "Ingest Approvals",
+    "admin.title.documents" => "Documents",
+    "admin.title.share_links" => "Share Links",
+    "admin.title.usage" => "Usage & Quotas",
+    "admin.title.doc_quality" => "Doc Quality",
+    "admin.title.doc_ratings" => "Doc Ratings",
+    "admin.title.documentation_feedback" => "Documentation Feedback",
+    "admin.title.navigation" => "Navigation Setup",
+    "admin.title.links" => "Custom Links",
+    "admin.title.glossary" => "Glossary",
+    "admin.title.dictionary" => "Spelling Dictionary",
+    "admin.title.projects" => "Projects",
+    "admin.title.css" => "Visual Customization",
+    "admin.title.settings" => "Instance Branding",
+    "admin.title.features" => "Features",
+    "admin.title.incident_mode" => "Incident Mode",
+    "admin.title.on_call" => "On-call",
+    "admin.title.issue_tracker" => "Issue Tracker",
+    "admin.title.embeds" => "Embeds",
+    "admin.title.rag" => "Index Management",
+    "admin.title.access_levels" => "Access Levels",
+    "admin.title.users" => "User Management",
+    "admin.title.ldap" => "LDAP",
+    "admin.title.default" => "Administration",
+    "admin.subtitle.documentation_feedback" => "Review MCP-reported documentation gaps, resolve them, and keep the registry tidy.",
+    "admin.subtitle.access_levels" => "Manage content access levels and their inheritance hierarchy.",
+    "admin.subtitle.users" => "Assign access levels and permissions to registered users.",
+    "admin.subtitle.ldap" => "Access levels and admin rights granted by directory groups on LDAP sign-in.",
+    "admin.subtitle.api_keys" => "Read-only keys for embedding documentation in dashboards and internal tools.",
+    "admin.subtitle.approvals" => "Protected namespaces whose CI ingests wait for an admin before publication.",
+    "admin.subtitle.documents" => "Change the owner, access level, tags or parent of several documents at once, or delete them.",
+    "admin.subtitle.share_links" => "Signed links giving read access to one restricted document, until they expire or are revoked.",
+    "admin.subtitle.usage" => "Documents and storage consumed by each service owner.",
+    "admin.subtitle.doc_quality" => "Style findings reported by the Markdown lint on the last ingest of each document.",
+    "admin.subtitle.doc_ratings" => "How helpful readers found each service owner's documents, with their comments.",
+    "admin.subtitle.links" => "External links shown in the navbar and docs sidebar, next to the document tree.",
+    "admin.subtitle.glossary" => "Shared term definitions, shown as tooltips where the terms appear in documents.",
+    "admin.subtitle.dictionary" => "Product names and jargon the spell checker accepts in every document.",
+    "admin.subtitle.projects" => "Namespaces for business units sharing this instance, each owning its own slugs, tokens and access levels.",
+    "admin.subtitle.settings" => "Portal name, logo, colours, homepage text and footer links.",
+    "admin.subtitle.features" => "Turn the editor, the AI assistant and search on or off for this deployment.",
+    "admin.subtitle.incident_mode" => "Runbooks pinned to the sidebar and homepage during an incident, and who may switch incident mode on.",
+    "admin.subtitle.on_call" => "Show who is on call, from PagerDuty or Opsgenie, on service pages and runbooks.",
+    "admin.subtitle.embeds" => "Choose which tools' links are rendered as embedded widgets in documents.",
+    "admin.subtitle.issue_tracker" => "Link issue keys in documents and comments to Jira or Linear, with their current status.",
+    "admin.subtitle.rag" => "Rebuild derived search and retrieval indexes from the canonical document store.",
+    "admin.subtitle.default" => "Manage your instance configuration, service tokens, and theming.",
+    "admin.page_not_found" => "Page not found",
+    "admin.tokens.intro" => "Long-lived tokens for CI/CD pipelines and external service integrations. Each token is restricted to specific documentation scopes.",
+    "admin.tokens.loading" => "Loading tokens...",
+    "admin.tokens.load_error" => "Failed to load tokens: {error}",
+    "admin.tokens.empty" => "No service tokens yet",
+    "admin.tokens.empty_hint" => "Create your first token below to start automating document updates.",
+    "admin.common.name" => "Name",
+    "admin.common.scopes" => "Scopes",
+    "admin.tokens.write" => "Write",
+    "admin.common.status" => "Status",
+    "admin.common.created" => "Created",
+    "admin.tokens.usage" => "Usage",
+    "admin.common.actions" => "Actions",
+    "admin.common.never" => "Never",
+    "admin.tokens.more_scopes" => "+ {count} more",
+    "admin.tokens.badge_write" => "WRITE",
+    "admin.tokens.badge_read" => "READ",
+    "admin.common.active" => "active",
+    "admin.common.deactivated" => "deactivated",
+    "admin.tokens.deactivate_confirm" => "Are you sure you want to deactivate this token? This action cannot be undone.",
+    "admin.common.deactivate" => "Deactivate",
+    "admin.tokens.create_title" => "Create New Token",
+    "admin.tokens.create_intro" => "Configure a new scoped access token.",
+    "admin.tokens.token_name" => "Token Name",
+    "admin.tokens.name_placeholder" => "e.g. github-actions-ci",
+    "admin.tokens.name_hint" => "A descriptive name for identification.",
+    "admin.tokens.permissions" => "Permissions",
+    "admin.tokens.allow_write" => "Allow Write Access",
+    "admin.tokens.allow_write_hint" => "Permit updates and deletions via API.",
+    "admin.tokens.write_hint" => "Controls write permissions for this token.",
+    "admin.tokens.allowed_scopes" => "Allowed Scopes",
+    "admin.tokens.scopes_hint" => "Enter one path prefix per line.",
+    "admin.tokens.project" => "Project",
+    "admin.tokens.project_placeholder" => "e.g. payments (optional)",
+    "admin.tokens.project_hint" => "Confines the token to the project namespace; leave the scopes empty to grant the whole project.",
+    "admin.tokens.creating" => "Creating...",
+    "admin.tokens.create" => "Create Service Token",
+    "admin.api_keys.intro" => "Read-only keys for dashboards and internal tools that embed documentation server-to-server. A key can search and fetch published documents in its access levels, optionally limited to path prefixes, and never writes.",
+    "admin.api_keys.empty" => "No API keys yet.",
+    "admin.api_keys.access_levels" => "Access levels",
+    "admin.api_keys.last_used" => "Last used",
+    "admin.api_keys.all_documents" => "all documents",
+    "admin.api_keys.deactivate_confirm" => "Deactivate this API key? This action cannot be undone.",
+    "admin.api_keys.load_error" => "Failed to load API keys: {error}",
+    "admin.api_keys.create" => "Create API Key",
+    "admin.api_keys.name_placeholder" => "Name, e.g. ops-dashboard",
+    "admin.api_keys.scopes_placeholder" => "Optional path prefixes, one per line\ndocs/runbooks",
+    "admin.feedback.title" => "Documentation Feedback Registry",
+    "admin.feedback.intro" => "Admin-only registry of MCP-reported documentation gaps and improvement proposals. Use this to resolve, deduplicate, and prioritize documentation maintenance without turning Lekton into a ticket tracker.",
+    "admin.feedback.search" => "Search",
+    "admin.feedback.search_placeholder" => "Search title, summary, lekton://docs/ URI, or proposal",
+    "admin.feedback.kind" => "Kind",
+    "admin.feedback.all_kinds" => "All kinds",
+    "admin.feedback.missing_info" => "Missing info",
+    "admin.feedback.improvement" => "Improvement",
+    "admin.feedback.open" => "Open",
+    "admin.feedback.resolved" => "Resolved",
+    "admin.feedback.all_statuses" => "All statuses",
+    "admin.feedback.loading" => "Loading documentation feedback...",
+    "admin.feedback.load_error" => "Failed to load documentation feedback: {error}",
+    "admin.feedback.empty" => "No matching feedback items",
+    "admin.feedback.empty_hint" => "The registry is empty for the selected filters. MCP agents will populate it through the documentation feedback tools.",
+    "admin.feedback.total" => "{count} total item(s)",
+    "admin.common.page_of" => "Page {page} of {pages}",
+    "admin.common.previous" => "Previous",
+    "admin.common.next" => "Next",
+    "admin.feedback.related_resources" => "Related resources",
+    "admin.feedback.search_queries" => "Search queries",
+    "admin.feedback.user_goal" => "User goal",
+    "admin.feedback.missing_information" => "Missing information",
+    "admin.feedback.impact" => "Impact",
+    "admin.feedback.suggested_target_resource" => "Suggested target resource",
+    "admin.feedback.target_resource" => "Target resource",
+    "admin.feedback.problem_summary" => "Problem summary",
+    "admin.feedback.proposal" => "Proposal",
+    "admin.feedback.supporting_resources" => "Supporting resources",
+    "admin.feedback.expected_benefit" => "Expected benefit",
+    "admin.feedback.related_feedback_ids" => "Related feedback ids",
+    "admin.feedback.duplicate_of" => "Duplicate of",
+    "admin.feedback.resolution_note" => "Resolution note",
+    "admin.feedback.note_placeholder" => "Optional note describing how the item was resolved or why it was marked duplicate.",
+    "admin.feedback.duplicate_placeholder" => "Existing feedback id",
+    "admin.feedback.resolve" => "Resolve",
+    "admin.feedback.mark_duplicate" => "Mark Duplicate",
+    "admin.navigation.title" => "Navigation Ordering",
+    "admin.navigation.intro" => "Reorder sections and categories in the navigation. Drag items or use the arrow buttons to change their position. Documents within each section are always sorted by their own weight or alphabetically.",
+    "admin.navigation.loading" => "Loading navigation tree...",
+    "admin.navigation.empty" => "No sections found",
+    "admin.navigation.empty_hint" => "Sections will appear here once documents with hierarchical slugs are ingested.",
+    "admin.navigation.section" => "Section",
+    "admin.navigation.category" => "Category",
+    "admin.common.move_up" => "Move up",
+    "admin.common.move_down" => "Move down",
+    "admin.navigation.save" => "Save Order",
+    "admin.css.title" => "Theming & Custom CSS",
+    "admin.css.intro" => "Customize the visual appearance of your Lekton instance. The CSS below is injected into every page at runtime.",
+    "admin.branding.saved" => "{message}. Reload the page to see the changes everywhere.",
+    "admin.branding.title" => "Branding",
+    "admin.branding.intro" => "Customize how this portal presents itself. Empty fields fall back to the defaults.",
+    "admin.branding.portal_name" => "Portal name",
+    "admin.branding.logo_url" => "Logo URL",
+    "admin.branding.primary_color" => "Primary color",
+    "admin.branding.default_theme" => "Default theme",
+    "admin.branding.theme_system" => "Follow system preference",
+    "admin.branding.theme_light" => "Light",
+    "admin.branding.theme_dark" => "Dark",
+    "admin.branding.hero_title" => "Homepage headline",
+    "admin.branding.hero_title_placeholder" => "Welcome to Lekton",
+    "admin.branding.hero_subtitle" => "Homepage text",
+    "admin.branding.homepage" => "Homepage document",
+    "admin.branding.homepage_hint" => "Slug of a document to show as the homepage instead of the default hero and feature cards.",
+    "admin.branding.footer_links" => "Footer links",
+    "admin.branding.footer_links_hint" => "One link per line, as Label | URL.",
+    "admin.branding.access_request" => "Access request link",
+    "admin.branding.access_request_hint" => "Shown to users who open a document or schema above their access level.",
+    "admin.links.intro" => "Add links to dashboards, runbooks or on-call tools. Start a group with a [Group title] line, then add one Label | URL per line.",
+    "admin.links.show_in_navbar" => "Show in navbar",
+    "admin.links.show_in_sidebar" => "Show in docs sidebar",
+    "admin.usage.title" => "Usage per Service Owner",
+    "admin.usage.intro" => "Active documents and Markdown content size per service owner. Quotas are set under [quotas] in the server configuration; ingests beyond them are rejected.",
+    "admin.common.no_documents" => "No documents yet.",
+    "admin.usage.storage" => "Storage",
+    "admin.usage.load_error" => "Failed to load usage: {error}",
+    "admin.usage.unlimited" => "(unlimited)",
+    "admin.share_links.revoked_toast" => "Link revoked",
+    "admin.share_links.intro" => "Editors create links from the Share menu of a restricted document. Revoked links stop working immediately; accesses are kept for 90 days.",
+    "admin.share_links.empty" => "No share links yet.",
+    "admin.share_links.note" => "Note",
+    "admin.share_links.created_by" => "Created by",
+    "admin.share_links.expires" => "Expires",
+    "admin.share_links.views" => "Views",
+    "admin.share_links.active" => "Active",
+    "admin.share_links.revoked_by" => "Revoked by {user}",
+    "admin.share_links.revoked" => "Revoked",
+    "admin.share_links.expired" => "Expired",
+    "admin.share_links.last_viewed" => "Last viewed {date}",
+    "admin.share_links.access_log" => "Access log",
+    "admin.share_links.revoke" => "Revoke",
+    "admin.share_links.access_log_for" => "Access log — {slug}",
+    "admin.common.close" => "Close",
+    "admin.share_links.never_used" => "This link was never used.",
+    "admin.share_links.when" => "When",
+    "admin.share_links.forwarded_for" => "Forwarded for",
+    "admin.share_links.user_agent" => "User agent",
+    "admin.ratings.title" => "Reader Ratings",
+    "admin.ratings.intro" => "Signed-in readers answer \"Was this page helpful?\" at the bottom of each document, optionally with a comment. Select a service owner to list only its documents.",
+    "admin.ratings.empty" => "No ratings yet.",
+    "admin.ratings.rated_documents" => "Rated documents",
+    "admin.ratings.helpful" => "Helpful",
+    "admin.ratings.not_helpful" => "Not helpful",
+    "admin.ratings.helpful_percent" => "Helpful %",
+    "admin.ratings.comments_count" => "Comments ({count})",
+    "admin.ratings.comments_for" => "Comments — {slug}",
+    "admin.quality.title" => "Lint Findings",
+    "admin.quality.intro" => "Enable the rules under [lint] in the server configuration, with overrides per project under [lint.projects.<name>]. Findings never block an ingest.",
+    "admin.quality.rule" => "Rule",
+    "admin.quality.all_rules" => "All rules",
+    "admin.quality.empty" => "No findings.",
+    "admin.quality.findings" => "Findings",
+    "admin.quality.load_error" => "Failed to load the report: {error}",
+    "admin.documents.tags_required" => "Enter at least one tag",
+    "admin.documents.owner_required" => "Enter the new service owner",
+    "admin.documents.access_level_required" => "Enter the new access level",
+    "admin.documents.action_required" => "Choose an action",
+    "admin.documents.updated" => "Updated {count} document(s)",
+    "admin.documents.updated_not_found" => "Updated {count} document(s); not found: {slugs}",
+    "admin.documents.delete_confirm" => "Delete {count} document(s)? They are archived and disappear from navigation and search.",
+    "admin.documents.intro" => "Select documents and apply one change to all of them. Changes to documents synced from a repository are overwritten by the next ingest from that repository.",
+    "admin.documents.filter" => "Filter",
+    "admin.documents.filter_placeholder" => "Slug, title, owner or tag",
+    "admin.documents.action" => "Action",
+    "admin.documents.change_owner" => "Change owner",
+    "admin.documents.change_access_level" => "Change access level",
+    "admin.documents.add_tags" => "Add tags",
+    "admin.documents.remove_tags" => "Remove tags",
+    "admin.documents.move" => "Move under parent",
+    "admin.common.delete" => "Delete",
+    "admin.documents.value" => "Value",
+    "admin.documents.parent_placeholder" => "parent slug, empty for top level",
+    "admin.documents.apply" => "Apply to {count} selected",
+    "admin.documents.select_all" => "Select all shown",
+    "admin.documents.owner" => "Owner",
+    "admin.documents.access" => "Access",
+    "admin.documents.tags" => "Tags",
+    "admin.documents.parent" => "Parent",
+    "admin.documents.updated_at" => "Updated",
+    "admin.documents.draft" => "draft",
+    "admin.documents.hidden" => "hidden",
+    "admin.documents.load_error" => "Failed to load documents: {error}",
+    "admin.approvals.title" => "Pending Ingests",
+    "admin.approvals.intro" => "Documents submitted by CI to a protected namespace. Approving publishes the submitted version; rejecting discards it.",
+    "admin.approvals.empty" => "No ingests are waiting for approval.",
+    "admin.approvals.load_error" => "Failed to load pending ingests: {error}",
+    "admin.approvals.changes" => "Changes: {changes}",
+    "admin.approvals.approve" => "Approve",
+    "admin.approvals.reject" => "Reject",
+    "admin.approvals.content" => "Submitted content",
+    "admin.approvals.prefixes_title" => "Protected Namespaces",
+    "admin.approvals.prefixes_intro" => "One slug per line. A trailing /* protects the whole namespace (security/* covers security and every page below it). Edits in the web editor are not affected.",
+    "admin.incident.intro" => "While incident mode is on, the runbooks are pinned above the docs navigation and on the homepage, open runbooks reload as soon as they change and pages are served with no-store.",
+    "admin.incident.on" => "On",
+    "admin.incident.end" => "End incident mode",
+    "admin.incident.start" => "Start incident mode",
+    "admin.incident.runbooks" => "Runbooks",
+    "admin.incident.runbooks_hint" => "One slug per line, in the order to pin them. Readers only see the runbooks they can read.",
+    "admin.incident.responders" => "On-call responders",
+    "admin.incident.responders_hint" => "One email per line. Responders may switch incident mode on and off from the homepage; admins always can.",
+    "admin.on_call.intro" => "Signed-in readers see who is on call on the changelog page of a mapped service and on its runbooks (documents tagged runbook, or pinned in incident mode). Answers are cached for a minute.",
+    "admin.on_call.provider" => "Provider",
+    "admin.common.none" => "None",
+    "admin.common.api_token" => "API token",
+    "admin.common.token_saved" => "Saved — leave empty to keep it",
+    "admin.on_call.token_hint" => "A read-only PagerDuty REST API key, or an Opsgenie API key with read access.",
+    "admin.on_call.api_url" => "API URL",
+    "admin.on_call.api_url_hint" => "Leave empty for the provider's default API.",
+    "admin.on_call.schedules" => "Schedules",
+    "admin.on_call.schedules_hint" => "One \"service = schedule\" per line. Services match the changelog service names and document owners; Opsgenie schedules may be given by ID or name.",
+    "admin.issue_tracker.intro" => "Issue keys of the listed projects (e.g. PAY-42) become links in documents and rating comments. With an API token, signed-in readers also see each issue's status; statuses are cached for five minutes.",
+    "admin.issue_tracker.tracker" => "Tracker",
+    "admin.issue_tracker.url" => "Tracker URL",
+    "admin.issue_tracker.url_hint" => "The Jira site, or the Linear workspace (https://linear.app/acme).",
+    "admin.issue_tracker.user" => "API user",
+    "admin.issue_tracker.user_hint" => "Jira Cloud account email. Leave empty for Linear and Jira Data Center tokens.",
+    "admin.issue_tracker.token_hint" => "Read access is enough. Without a token, keys are linked without a status.",
+    "admin.issue_tracker.projects" => "Project keys",
+    "admin.issue_tracker.projects_hint" => "One per line. Only keys of these projects are linked, so terms like UTF-8 stay text.",
+    "admin.embeds.intro" => "A paragraph made of nothing but a link to an enabled tool is rendered as an embedded widget, with the link underneath. Raw iframes in documents may only load the embed URLs of enabled tools. Changes apply to pages rendered from now on.",
+    "admin.embeds.providers" => "Enabled tools",
+    "admin.embeds.grafana_hosts" => "Grafana hosts",
+    "admin.embeds.grafana_hosts_hint" => "One per line, optionally with :port. Snapshots on these hosts are embedded; with the Grafana proxy configured, so are panels and dashboards it allows.",
+    "admin.ldap.intro" => "Each LDAP sign-in replaces the user's access levels with those of their groups, and sets the admin flag from the admin groups. Leave a list empty to manage that part in User Management instead.",
+    "admin.ldap.not_configured" => "LDAP sign-in is not configured (auth.ldap.url). The mappings apply once it is.",
+    "admin.ldap.mappings" => "Group mappings",
+    "admin.ldap.mappings_hint" => "One \"group = access levels\" per line. Groups are given by DN or common name; access levels must exist.",
+    "admin.ldap.admin_groups" => "Admin groups",
+    "admin.ldap.admin_groups_hint" => "One per line. Members are administrators; everyone else signing in through LDAP is not.",
+    "admin.features.title" => "Feature Flags",
+    "admin.features.intro" => "Changes apply immediately, without a restart. Subsystems that are not configured on the server stay off whatever their flag says.",
+    "admin.features.editor" => "Web editor",
+    "admin.features.editor_hint" => "Edit buttons, the /edit pages and editor saves.",
+    "admin.features.chat" => "AI assistant",
+    "admin.features.chat_hint" => "The chat page and the chat and ask API endpoints.",
+    "admin.features.search" => "Search provider",
+    "admin.features.search_hint" => "Hybrid blends semantic matches into full-text results when embeddings are configured.",
+    "admin.features.search_hybrid" => "Hybrid",
+    "admin.features.search_full_text" => "Full-text only",
+    "admin.features.search_disabled" => "Disabled",
+    "admin.common.save_changes" => "Save Changes",
+    "admin.common.discard" => "Discard",
+    "admin.glossary.intro" => "The first occurrence of each term in a document links to its entry on /glossary, with the definition as a tooltip. Saving an existing term (case-insensitive) replaces it.",
+    "admin.glossary.term_placeholder" => "Term (e.g. SLO)",
+    "admin.glossary.owner_placeholder" => "Owner (team or person)",
+    "admin.glossary.definition_placeholder" => "Definition",
+    "admin.glossary.save" => "Save Term",
+    "admin.glossary.term" => "Term",
+    "admin.glossary.definition" => "Definition",
+    "admin.glossary.owner" => "Owner",
+    "admin.common.edit" => "Edit",
+    "admin.dictionary.title" => "Spelling Dictionary",
+    "admin.dictionary.intro" => "Words listed here are accepted on top of the Hunspell dictionary set under [spellcheck], in any capitalization. Misspellings are reported by dry-run ingests and by Check spelling in the editor.",
+    "admin.dictionary.word_placeholder" => "Word (e.g. kubectl)",
+    "admin.dictionary.add" => "Add Word",
+    "admin.dictionary.added_by" => "Added by {user} on {date}",
+    "admin.dictionary.remove" => "Remove",
+    "admin.projects.intro" => "A project owns the documents and schemas whose slug starts with its name (e.g. payments/…). Service tokens can be bound to a project, and the allowed access levels restrict what its content may be published with. Deleting a project keeps its content.",
+    "admin.projects.name_placeholder" => "Name (e.g. payments)",
+    "admin.projects.title_placeholder" => "Title (e.g. Payments)",
+    "admin.projects.levels_placeholder" => "Allowed access levels, comma-separated (empty = any)",
+    "admin.projects.description" => "Description",
+    "admin.projects.save" => "Save Project",
+    "admin.projects.title" => "Title",
+    "admin.projects.any_level" => "any",
+    "admin.created_token.title" => "Token Created",
+    "admin.created_token.subtitle" => "This is your only chance to copy it.",
+    "admin.created_token.warning" => "For security reasons, we do not store the raw token. If you lose it, you will need to deactivate it and create a new one.",
+    "admin.created_token.token" => "Generated Token",
+    "admin.created_token.copied" => "Copied",
+    "admin.created_token.copy" => "Copy",
+    "admin.created_token.name" => "Internal Name",
+    "admin.created_token.done" => "I have saved the token",
+    "admin.reindex.search_title" => "Meilisearch Re-index",
+    "admin.reindex.search_intro" => "Rebuild the full-text search index from MongoDB metadata and stored Markdown content.",
+    "admin.reindex.rag_title" => "RAG Re-index",
+    "admin.reindex.rag_intro" => "Re-embed all documents in the vector store. Use this after changing the embedding model.",
+    "admin.reindex.schema_title" => "Schema Endpoint Re-index",
+    "admin.reindex.schema_intro" => "Re-extract API operations (path, method, summary) from all schema versions stored in S3 and update the index in MongoDB. Run this to backfill schemas ingested before endpoint indexing was introduced.",
+    "admin.reindex.start" => "Start Re-index",
+    "admin.reindex.in_progress" => "Re-indexing in progress...",
+    "admin.common.service_owner" => "Service owner",
+    "admin.common.document" => "Document",
+    "admin.glossary.empty" => "No glossary terms yet.",
+    "admin.dictionary.empty" => "No words yet.",
+    "admin.projects.empty" => "No projects yet.",
+    "admin.pats.intro" => "PATs issued to users for IDE agent access (Claude Code, Cursor, etc.).",
+    "admin.pats.token_name" => "Token name",
+    "admin.pats.user" => "User",
+    "admin.pats.empty" => "No PATs found.",
+    "admin.pats.active" => "Active",
+    "admin.pats.inactive" => "Inactive",
+    "admin.pats.activate" => "Activate",
+    "admin.pats.total" => "{count} tokens total",
+    "admin.access_levels.intro" => "Define content access levels and their inheritance hierarchy. System levels (public, loggeduser) are injected automatically and cannot be deleted.",
+    "admin.access_levels.system" => "system",
+    "admin.access_levels.inherits" => "inherits:",
+    "admin.access_levels.label" => "Label",
+    "admin.access_levels.description" => "Description",
+    "admin.access_levels.inherits_from" => "Inherits from",
+    "admin.common.save" => "Save",
+    "admin.access_levels.new" => "+ New Access Level",
+    "admin.access_levels.create_title" => "Create Access Level",
+    "admin.access_levels.name" => "Name (slug)",
+    "admin.access_levels.name_placeholder" => "e.g. cloud-developer",
+    "admin.access_levels.inherits_from_names" => "Inherits from (comma-separated names)",
+    "admin.access_levels.inherits_placeholder" => "e.g. internal,developer",
+    "admin.common.create" => "Create",
+    "admin.common.cancel" => "Cancel",
+    "admin.users.created" => "User created. They will be asked to change the password after signing in.",
+    "admin.users.password_reset" => "Password reset. The user was signed out and must choose a new password.",
+    "admin.users.title" => "Users",
+    "admin.users.intro" => "Assign access levels and write permissions to registered users.",
+    "admin.users.add" => "Add user",
+    "admin.users.email" => "Email",
+    "admin.users.name_optional" => "Name (optional)",
+    "admin.users.initial_password" => "Initial password (at least 12 characters)",
+    "admin.users.administrator" => "Administrator",
+    "admin.users.admin_badge" => "admin",
+    "admin.users.local_badge" => "local",
+    "admin.users.no_levels" => "no levels assigned",
+    "admin.users.effective" => "effective: {levels}",
+    "admin.users.delete_confirm" => "Delete this user? Their sessions are revoked immediately.",
+    "admin.users.assigned_levels" => "Assigned access levels",
+    "admin.users.can_write" => "Can write",
+    "admin.users.read_drafts" => "Read drafts",
+    "admin.users.write_drafts" => "Write drafts",
+    "admin.users.temporary_password" => "New temporary password",
+    "admin.users.reset_password" => "Reset password",
+    "notifications.target_placeholder" => "guides/setup or guides/*",
+    "chat.sign_in_required" => "Sign in required",
+    "chat.sign_in_hint" => "Please log in to use the AI chat assistant.",
+    "chat.sign_in" => "Sign in",
+    "chat.title" => "AI Assistant",
+    "chat.intro" => "Ask me anything about the documentation and codebase.",
+    "chat.suggestion_about" => "What is Lekton?",
+    "chat.suggestion_oidc" => "How do I configure OIDC?",
+    "chat.input_placeholder" => "Type your message...",
+    "chat.newline_hint" => "Shift + Enter for new line",
+    "chat.disclaimer" => "AI responses may be inaccurate",
+    "chat.sources" => "Sources ({count})",
+    "chat.helpful" => "Helpful",
+    "chat.not_helpful" => "Not helpful",
+    "chat.remove_feedback" => "Remove feedback",
+    "chat.feedback_question" => "What was wrong? (optional)",
+    "chat.feedback_placeholder" => "Tell us what could be improved...",
+    "chat.cancel" => "Cancel",
+    "chat.submit" => "Submit",
+    "chat.sidebar_title" => "AI Chat",
+    "chat.new_chat" => "New Chat",
+    "chat.no_session" => "No chat session active",
+    "search.empty" => "No results found",
+    "sidebar.schemas" => "Schemas",
+    "sidebar.schema_overview" => "Overview",
+    "sidebar.no_schemas" => "No schemas found",
+    "sidebar.schemas_error" => "Error loading schemas",
+    "sidebar.events" => "Events",
+    "sidebar.event_catalog" => "Event catalog",
+    "admin.nav.title" => "Administration",
+    "admin.nav.tokens" => "Service Tokens",
+    "admin.nav.pats" => "Personal Tokens",
+    "admin.nav.api_keys" => "API Keys",
+    "admin.nav.approvals" => "Approvals",
+    "admin.nav.documents" => "Documents",
+    "admin.nav.share_links" => "Share Links",
+    "admin.nav.usage" => "Usage & Quotas",
+    "admin.nav.doc_quality" => "Doc Quality",
+    "admin.nav.doc_ratings" => "Doc Ratings",
+    "admin.nav.documentation_feedback" => "Documentation Feedback",
+    "admin.nav.access_levels" => "Access Levels",
+    "admin.nav.users" => "Users",
+    "admin.nav.ldap" => "LDAP",
+    "admin.nav.navigation" => "Nav Ordering",
+    "admin.nav.links" => "Custom Links",
+    "admin.nav.glossary" => "Glossary",
+    "admin.nav.dictionary" => "Dictionary",
+    "admin.nav.projects" => "Projects",
+    "admin.nav.css" => "Custom CSS",
+    "admin.nav.settings" => "Branding",
+    "admin.nav.features" => "Features",
+    "admin.nav.incident_mode" => "Incident Mode",
+    "admin.nav.on_call" => "On-call",
+    "admin.nav.issue_tracker" => "Issue Tracker",
+    "admin.nav.embeds" => "Embeds",
+    "admin.nav.rag" => "Indexes",
+}
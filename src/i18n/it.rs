@@ -0,0 +1,818 @@
+//! Italian catalog.
+
+super::catalog! {
+    "nav.docs" => "Documentazione",
+    "nav.registry" => "Registro",
+    "nav.chat" => "Chat",
+    "nav.admin" => "Amministrazione",
+    "nav.more" => "Altro",
+    "nav.navigation" => "Navigazione",
+    "nav.links" => "Collegamenti",
+    "nav.skip_to_content" => "Vai al contenuto",
+    "nav.open_sidebar" => "Apri la navigazione",
+    "nav.close_sidebar" => "Chiudi la navigazione",
+    "nav.main" => "Principale",
+    "search.button" => "Cerca nella documentazione...",
+    "search.placeholder_short" => "Cerca...",
+    "search.min_chars" => "Digita almeno 2 caratteri per cercare...",
+    "search.no_results" => "Nessun risultato per \"{query}\"",
+    "search.error" => "Errore di ricerca: {error}",
+    "search.failed" => "Ricerca non riuscita. Riprova dal messaggio qui sotto.",
+    "search.close_hint" => "Premi ESC per chiudere",
+    "search.filters_hint" => "tag: owner: type:doc|schema is:stale",
+    "search.active_filters" => "Filtri attivi",
+    "toast.retry" => "Riprova",
+    "toast.dismiss" => "Chiudi",
+    "error.title" => "Si è verificato un errore",
+    "error.reload" => "Ricarica la pagina",
+    "user.login" => "Accedi",
+    "user.logout" => "Esci",
+    "user.prompts" => "Libreria prompt",
+    "user.profile" => "Profilo e token",
+    "user.theme" => "Tema",
+    "user.my_drafts" => "Le mie bozze",
+    "user.notifications" => "Notifiche",
+    "theme.light" => "Tema chiaro (clic per scuro)",
+    "theme.dark" => "Tema scuro (clic per sistema)",
+    "theme.system" => "Tema di sistema (clic per chiaro)",
+    "language.label" => "Lingua",
+    "doc.print" => "Stampa",
+    "doc.print_title" => "Stampa o salva come PDF",
+    "doc.edit" => "Modifica",
+    "doc.sibling_nav" => "Pagine di questa sezione",
+    "doc.previous" => "Precedente",
+    "doc.next" => "Successivo",
+    "doc.helpful_question" => "Questa pagina ti è stata utile?",
+    "doc.helpful_yes" => "Sì",
+    "doc.helpful_no" => "No",
+    "doc.helpful_editors_only" => "Valutazioni finora, visibili a chi può modificare il documento",
+    "doc.helpful_comment_placeholder" => "Cosa si potrebbe migliorare? (facoltativo)",
+    "doc.helpful_send" => "Invia commento",
+    "doc.helpful_thanks" => "Grazie!",
+    "doc.watch" => "Segui",
+    "doc.unwatch" => "Non seguire più",
+    "doc.watch_title" => "Ricevi una notifica quando questo documento cambia",
+    "doc.share" => "Condividi",
+    "doc.share_title" => "Dai accesso in lettura a questa pagina a chi non ha un account",
+    "doc.share_days" => "Valido per (giorni)",
+    "doc.share_note" => "Condiviso con (facoltativo)",
+    "doc.share_create" => "Crea link",
+    "doc.share_created" => "Copia ora questo link: non verrà più mostrato.",
+    "doc.share_copy" => "Copia",
+    "doc.share_links" => "Link attivi",
+    "doc.share_link_status" => "scade {date} · {views} visualizzazioni",
+    "doc.share_revoke" => "Revoca",
+    "share_page.unavailable_title" => "Link non disponibile",
+    "share_page.unavailable" => "Questo link non è valido, è scaduto o è stato revocato. Chiedi un nuovo link a chi lo ha condiviso.",
+    "share_page.footer" => "Condiviso da {author} — questo link scade il {date}.",
+    "print_page.unavailable_title" => "Documento non disponibile",
+    "print_page.unavailable" => "Questo documento non esiste o non hai i permessi per leggerlo. Se è riservato, accedi al portale e ricarica questa pagina.",
+    "print_page.source" => "Fonte: {url}",
+    "doc.on_this_page" => "In questa pagina",
+    "doc.last_updated" => "Ultimo aggiornamento: {date}",
+    "doc.last_updated_by" => "Ultimo aggiornamento: {date} da {author}",
+    "doc.contributors" => "Autori:",
+    "doc.edit_restricted" => "Modifiche riservate a {level}",
+    "doc.not_found" => "Documento non trovato: {slug}",
+    "doc.load_error" => "Errore nel caricamento del documento: {error}",
+    "doc.updated_notice" => "Questa pagina è stata aggiornata.",
+    "doc.refresh" => "Aggiorna",
+    "doc.deprecated_schemas" => "Questa pagina fa riferimento ad API deprecate:",
+    "doc.deprecated_schema_use" => "passa a {version}",
+    "doc.deprecated_schema_retired" => "nessuna versione supportata",
+    "doc.truncated" => "Questo documento è troppo grande per essere mostrato per intero; ne è visibile solo l'inizio",
+    "doc.view_raw" => "Vedi sorgente",
+    "doc.download" => "Scarica",
+    "doc.bundle_version" => "Versione",
+    "doc.bundle_latest" => "più recente",
+    "doc.bundle_prior_version" => "Stai leggendo la documentazione della versione {version}.",
+    "doc.bundle_view_latest" => "Vai alla più recente",
+    "doc.superseded_by" => "Questa pagina è stata sostituita da:",
+    "doc.relations.prerequisite" => "Da leggere prima",
+    "doc.relations.see_also" => "Vedi anche",
+    "doc.relations.supersedes" => "Sostituisce",
+    "doc.relations.prerequisite_of" => "Lettura richiesta per",
+    "nav.bundle_version" => "Versione",
+    "search.deprecated_schemas" => "API deprecata",
+    "search.superseded" => "Sostituito",
+    "search.operations" => "Operazioni API",
+    "home.welcome" => "Benvenuto in {portal}",
+    "home.subtitle" => "Il tuo Internal Developer Portal dinamico. Cerca nella documentazione, esplora gli schemi API e collabora, tutto in un unico posto.",
+    "home.get_started" => "Inizia",
+    "home.api_schemas" => "Schemi API",
+    "home.feature.docs.title" => "Documentazione dinamica",
+    "home.feature.docs.body" => "Integrazione CI/CD per aggiornare la documentazione in tempo reale, senza rebuild.",
+    "home.feature.rbac.title" => "RBAC granulare",
+    "home.feature.rbac.body" => "Il controllo degli accessi basato sui ruoli rende visibili i documenti riservati solo agli utenti autorizzati.",
+    "home.feature.registry.title" => "Registro degli schemi",
+    "home.feature.registry.body" => "Visualizzatore unificato per OpenAPI, AsyncAPI e JSON Schema, con versioning.",
+    "incident.active" => "Modalità incidente attiva — parti da questi runbook:",
+    "incident.runbooks" => "Runbook per incidenti",
+    "incident.started_by" => "Avviata da {user} · {time}",
+    "incident.start" => "Avvia modalità incidente",
+    "incident.end" => "Termina modalità incidente",
+    "on_call.current" => "Reperibile ora:",
+    "on_call.nobody" => "Nessuno è reperibile",
+    "on_call.schedule" => "Turni",
+    "not_found.message" => "La pagina che stai cercando non esiste.",
+    "not_found.back" => "Torna alla home",
+    "nav.browse" => "Sfoglia tutti i documenti",
+    "browse.title" => "Sfoglia la documentazione",
+    "browse.subtitle" => "Tutti i documenti che puoi leggere, con responsabili, tag e ultimo aggiornamento.",
+    "browse.filter_placeholder" => "Filtra per titolo, slug, responsabile o tag...",
+    "browse.all_owners" => "Tutti i responsabili",
+    "browse.show_tree" => "Mostra albero",
+    "browse.documents" => "{shown} / {total} documenti",
+    "browse.col.title" => "Titolo",
+    "browse.col.owner" => "Responsabile",
+    "browse.col.tags" => "Tag",
+    "browse.col.updated" => "Ultimo aggiornamento",
+    "browse.draft" => "Bozza",
+    "browse.my_drafts" => "Le mie bozze",
+    "browse.empty" => "Nessun documento corrisponde ai filtri.",
+    "access.denied_title" => "Accesso negato",
+    "access.denied_text" => "Non hai i permessi per visualizzare questa pagina. Richiede il livello di accesso {level}",
+    "access.request" => "Richiedi accesso",
+    "access.home" => "Torna alla home",
+    "nav.glossary" => "Glossario",
+    "glossary.title" => "Glossario",
+    "glossary.subtitle" => "Definizioni condivise dei termini usati nella documentazione.",
+    "glossary.filter_placeholder" => "Filtra i termini...",
+    "glossary.empty" => "Nessun termine trovato.",
+    "glossary.owner" => "Responsabile: {owner}",
+    "nav.changelog" => "Changelog",
+    "changelog.title" => "Changelog",
+    "changelog.subtitle" => "Note di rilascio pubblicate dai servizi, dalle più recenti.",
+    "changelog.all_services" => "← Tutti i servizi",
+    "changelog.empty" => "Nessuna voce di changelog.",
+    "changelog.published_by" => "pubblicato da {name}",
+    "nav.collections" => "Raccolte",
+    "collections.title" => "Raccolte",
+    "collections.subtitle" => "Percorsi di lettura curati: apri un documento da una raccolta per leggerla in ordine.",
+    "collections.empty" => "Nessuna raccolta.",
+    "collections.create" => "Nuova raccolta",
+    "collections.edit" => "Modifica raccolta",
+    "collections.all" => "← Tutte le raccolte",
+    "collections.curated_by" => "A cura di {owner}",
+    "collections.read" => "{progress} letti",
+    "collections.start" => "Inizia a leggere",
+    "collections.continue" => "Continua a leggere",
+    "collections.no_documents" => "Questa raccolta non contiene documenti che puoi leggere.",
+    "collections.document" => "Documento",
+    "collections.mark_read" => "Letto",
+    "collections.title_placeholder" => "Titolo, ad es. Onboarding nuovi assunti",
+    "collections.description_placeholder" => "Descrizione",
+    "collections.slugs_placeholder" => "Slug dei documenti, uno per riga, in ordine di lettura",
+    "collections.save" => "Salva",
+    "collections.delete" => "Elimina",
+    "login.redirecting" => "Reindirizzamento all'accesso...",
+    "login.redirect_fallback" => "Se non vieni reindirizzato automaticamente, {link}.",
+    "login.redirect_link" => "fai clic qui",
+    "login.demo_title" => "Accesso demo",
+    "login.demo_subtitle" => "Accedi con le credenziali demo per esplorare Lekton.",
+    "login.username" => "Nome utente",
+    "login.password" => "Password",
+    "login.email" => "Email",
+    "login.role" => "Ruolo",
+    "login.invalid_credentials" => "Credenziali non valide",
+    "login.invalid_username" => "Nome utente o password non validi",
+    "login.invalid_email" => "Email o password non validi",
+    "login.submit" => "Accedi",
+    "login.demo_accounts" => "Account demo",
+    "login.role_developer" => "Sviluppatore",
+    "login.role_admin" => "Amministratore",
+    "login.role_public" => "Pubblico",
+    "login.ldap_subtitle" => "Usa l'account della directory aziendale.",
+    "login.local_link" => "Accedi con un account locale",
+    "login.forgot_password" => "Password dimenticata? Chiedi a un amministratore di reimpostarla.",
+    "profile.title" => "Profilo",
+    "profile.role_admin" => "Amministratore",
+    "profile.role_user" => "Utente",
+    "profile.password_title" => "Password",
+    "profile.password_mismatch" => "Le nuove password non coincidono",
+    "profile.password_change_required" => "La tua password è stata impostata da un amministratore. Scegline una nuova.",
+    "profile.password_changed" => "Password modificata.",
+    "profile.current_password" => "Password attuale",
+    "profile.new_password" => "Nuova password (almeno 12 caratteri)",
+    "profile.confirm_password" => "Conferma la nuova password",
+    "profile.change_password" => "Cambia password",
+    "profile.pats_title" => "Token di accesso personali",
+    "profile.pats_intro" => "I PAT permettono agli agenti degli IDE (Claude Code, Cursor, RooCode) di accedere alla documentazione per tuo conto tramite il server MCP. Ogni token eredita i tuoi permessi.",
+    "profile.pat_created" => "Token creato: copialo ora, non verrà più mostrato.",
+    "profile.pat_add_hint" => "Aggiungilo a Claude Code:",
+    "profile.dismiss" => "Chiudi",
+    "profile.no_pats" => "Nessun token di accesso personale.",
+    "profile.col_name" => "Nome",
+    "profile.col_created" => "Creato",
+    "profile.col_last_used" => "Ultimo utilizzo",
+    "profile.col_status" => "Stato",
+    "profile.create_pat" => "Crea un nuovo token",
+    "profile.pat_name_placeholder" => "Nome del token (ad es. \"claude-code-lavoro\")",
+    "profile.generate" => "Genera",
+    "profile.feedback_title" => "Cronologia dei feedback sull'IA",
+    "profile.feedback_intro" => "I feedback che hai dato alle risposte dell'IA. Puoi rivederli ed eliminarli.",
+    "profile.no_feedback" => "Nessun feedback inviato.",
+    "profile.feedback_count_one" => "1 elemento",
+    "profile.feedback_count" => "{count} elementi",
+    "profile.helpful" => "Utile",
+    "profile.not_helpful" => "Non utile",
+    "profile.view_session" => "Vedi la sessione",
+    "profile.delete_feedback" => "Elimina il feedback",
+    "profile.never" => "Mai",
+    "profile.active" => "Attivo",
+    "profile.inactive" => "Inattivo",
+    "profile.deactivate" => "Disattiva",
+    "profile.activate" => "Attiva",
+    "profile.delete_pat_confirm" => "Eliminare definitivamente questo token?",
+    "profile.delete_permanently" => "Elimina definitivamente",
+    "profile.delete" => "Elimina",
+    "prompts.title" => "Libreria prompt",
+    "prompts.intro" => "I prompt pubblicati possono essere aggiunti al tuo contesto di lavoro. I prompt primari predefiniti provengono dalla libreria condivisa; preferiti e prompt nascosti sono preferenze personali.",
+    "prompts.filter_placeholder" => "Cerca i prompt per nome, slug, responsabile o tag",
+    "prompts.context_cost" => "Costo di contesto: {cost}",
+    "prompts.cost" => "costo: {cost}",
+    "prompts.owner" => "responsabile: {owner}",
+    "prompts.favorite" => "Preferito nel contesto personale",
+    "prompts.not_publishable" => "Non pubblicabile su MCP",
+    "prompts.hide_primary" => "Nascondi il prompt primario condiviso",
+    "prompts.not_primary" => "Non è un prompt primario condiviso",
+    "notifications.title" => "Notifiche",
+    "notifications.intro" => "Modifiche ai documenti e alle sezioni che segui, menzioni che ti riguardano e ingest in attesa di approvazione o già decisi. Segui una singola pagina dalla sua barra degli strumenti, o un'intera sezione qui sotto.",
+    "notifications.inbox" => "Posta in arrivo",
+    "notifications.mark_all_read" => "Segna tutte come lette",
+    "notifications.empty" => "Nessuna notifica.",
+    "notifications.watching_target" => "{time} · segui {target}",
+    "notifications.mark_read" => "Segna come letta",
+    "notifications.watching" => "Elementi seguiti",
+    "notifications.target" => "Documento o sezione",
+    "notifications.webhook" => "URL del webhook (facoltativo)",
+    "notifications.email" => "Invia anche un'email",
+    "notifications.watch" => "Segui",
+    "notifications.no_watches" => "Non segui nulla.",
+    "notifications.col_target" => "Elemento",
+    "notifications.col_delivery" => "Consegna",
+    "notifications.col_since" => "Dal",
+    "notifications.delivery_inbox" => "posta in arrivo",
+    "notifications.delivery_email" => "email",
+    "notifications.delivery_webhook" => "webhook",
+    "notifications.unwatch" => "Non seguire più",
+    "editor.saved" => "Documento '{slug}' salvato",
+    "editor.save_error" => "Errore: {error}",
+    "editor.lock_expires" => "Il suo blocco scade tra {seconds}s se non tiene aperto l'editor.",
+    "editor.lock_expired" => "Il suo blocco è scaduto.",
+    "editor.locked_by" => "{holder} sta modificando questo documento. {expiry}",
+    "editor.take_over" => "Subentra",
+    "editor.doc_title" => "Titolo del documento",
+    "editor.related" => "Documenti correlati",
+    "editor.see_also" => "Vedi anche",
+    "editor.prerequisites" => "Prerequisiti",
+    "editor.supersedes" => "Sostituisce",
+    "editor.superseded_by" => "Sostituito da",
+    "editor.slugs_placeholder" => "slug, altro/slug",
+    "editor.toolbar" => "Formattazione",
+    "editor.bold" => "Grassetto",
+    "editor.italic" => "Corsivo",
+    "editor.strike" => "Barrato",
+    "editor.heading1" => "Titolo 1",
+    "editor.heading2" => "Titolo 2",
+    "editor.heading3" => "Titolo 3",
+    "editor.bullet_list" => "Elenco puntato",
+    "editor.bullet_list_short" => "Elenco",
+    "editor.ordered_list" => "Elenco numerato",
+    "editor.ordered_list_short" => "1. Elenco",
+    "editor.blockquote" => "Citazione",
+    "editor.blockquote_short" => "Cit.",
+    "editor.highlight" => "Evidenzia",
+    "editor.highlight_short" => "Evid.",
+    "editor.insert_image" => "Inserisci immagine",
+    "editor.insert_image_short" => "Img",
+    "editor.saving" => "Salvataggio...",
+    "editor.save" => "Salva documento",
+    "editor.checking" => "Controllo...",
+    "editor.check_spelling" => "Controlla l'ortografia",
+    "editor.cancel" => "Annulla",
+    "editor.no_misspellings" => "Nessun errore di ortografia.",
+    "editor.misspellings" => "{count} possibili errori di ortografia",
+    "editor.spelling_failed" => "Controllo ortografico non riuscito: {error}",
+    "editor.not_found" => "Documento non trovato. Puoi creare un nuovo documento da questo editor.",
+    "editor.load_error" => "Errore nel caricamento del documento: {error}",
+    "editor.assets" => "Allegati",
+    "editor.upload_asset" => "Carica allegato",
+    "editor.no_assets" => "Nessun allegato caricato.",
+    "editor.asset_name" => "Nome",
+    "editor.asset_type" => "Tipo",
+    "editor.asset_size" => "Dimensione",
+    "editor.asset_actions" => "Azioni",
+    "editor.insert_asset_title" => "Inserisci nell'editor",
+    "editor.insert_asset" => "Inserisci",
+    "editor.download_asset" => "Scarica",
+    "editor.delete_asset_title" => "Elimina l'allegato",
+    "editor.delete_asset" => "Elimina",
+    "editor.assets_error" => "Errore nel caricamento degli allegati: {error}",
+    "schemas.breadcrumb_home" => "Home",
+    "schemas.breadcrumb_schemas" => "Schemi",
+    "catalog.breadcrumb" => "Catalogo eventi",
+    "catalog.title" => "Catalogo eventi",
+    "catalog.intro" => "Canali e topic di ogni schema AsyncAPI, con i servizi che producono e consumano su ciascuno.",
+    "catalog.filter_placeholder" => "Filtra canali o schemi",
+    "catalog.empty" => "Nessun canale AsyncAPI registrato. Carica uno schema AsyncAPI per popolare il catalogo.",
+    "catalog.no_match" => "Nessun canale corrisponde al filtro.",
+    "catalog.col_channel" => "Canale",
+    "catalog.col_producers" => "Produttori",
+    "catalog.col_consumers" => "Consumatori",
+    "catalog.load_error" => "Errore nel caricamento del catalogo eventi: {error}",
+    "contracts.title" => "Test di contratto",
+    "contracts.consumer" => "Consumatore",
+    "contracts.passed" => "superato",
+    "contracts.failed" => "fallito",
+    "schemas.title" => "Registro degli schemi",
+    "schemas.intro" => "Sfoglia ed esplora gli schemi API. Supporta le specifiche OpenAPI, AsyncAPI e JSON Schema.",
+    "schemas.search_placeholder" => "Cerca per nome",
+    "schemas.type" => "Tipo",
+    "schemas.all_types" => "Tutti i tipi",
+    "schemas.owner_filter" => "Responsabile",
+    "schemas.all_owners" => "Tutti i responsabili",
+    "schemas.tag" => "Tag",
+    "schemas.all_tags" => "Tutti i tag",
+    "schemas.sort" => "Ordina per",
+    "schemas.sort_name" => "Ordina per nome",
+    "schemas.sort_updated" => "Aggiornati di recente",
+    "schemas.no_match" => "Nessuno schema corrisponde ai filtri.",
+    "schemas.empty" => "Nessuno schema registrato. Usa l'API di ingestione per aggiungerne.",
+    "schemas.count_one" => "1 schema",
+    "schemas.count" => "{count} schemi",
+    "schemas.load_error" => "Errore nel caricamento degli schemi: {error}",
+    "schemas.no_versions" => "nessuna versione",
+    "schemas.version_count_one" => "1 versione",
+    "schemas.version_count" => "{count} versioni",
+    "schemas.updated" => "Aggiornato il {date}",
+    "schemas.owner" => "Responsabile: {owner}",
+    "schemas.latest_version" => "Ultima versione",
+    "schemas.contact" => "Contatto: {contact}",
+    "schemas.repository" => "Repository",
+    "schemas.select_version" => "Seleziona una versione per visualizzare la specifica dello schema.",
+    "schemas.content_error" => "Errore nel caricamento del contenuto dello schema: {error}",
+    "schemas.schema_error" => "Errore nel caricamento dello schema: {error}",
+    "schemas.specification" => "Specifica {spec}",
+    "schemas.channel" => "Canale",
+    "schemas.path" => "Percorso",
+    "schemas.operations" => "Operazioni",
+    "schemas.no_operations" => "Nessuna operazione trovata in questa specifica.",
+    "schemas.operations_error" => "Errore nel caricamento delle operazioni: {error}",
+    "schemas.method" => "Metodo",
+    "schemas.summary" => "Descrizione",
+    "schemas.operation_link" => "Link a questa operazione",
+    "schemas.snippets" => "Esempi di richiesta",
+    "schemas.copy" => "Copia",
+    "schemas.copied" => "Copiato",
+    "schemas.version" => "Versione:",
+    "schemas.download_title" => "Scarica il file JSON/YAML originale",
+    "schemas.download" => "Scarica originale",
+    "schemas.copy_permalink" => "Copia permalink",
+    "schemas.copy_curl" => "Copia come curl",
+    "schemas.loading_scalar" => "Caricamento del visualizzatore API...",
+    "schemas.loading_asyncapi" => "Caricamento del visualizzatore AsyncAPI...",
+    "admin.access_denied" => "Accesso negato. Sono richiesti i privilegi di amministratore.",
+    "admin.title.tokens" => "Token di servizio",
+    "admin.title.pats" => "Token di accesso personali",
+    "admin.title.api_keys" => "Chiavi API",
+    "admin.title.approvals" => "Approvazioni di ingestione",
+    "admin.title.documents" => "Documenti",
+    "admin.title.share_links" => "Link di condivisione",
+    "admin.title.usage" => "Utilizzo e quote",
+    "admin.title.doc_quality" => "Qualità dei documenti",
+    "admin.title.doc_ratings" => "Valutazioni dei documenti",
+    "admin.title.documentation_feedback" => "Segnalazioni sulla documentazione",
+    "admin.title.navigation" => "Configurazione della navigazione",
+    "admin.title.links" => "Link personalizzati",
+    "admin.title.glossary" => "Glossario",
+    "admin.title.dictionary" => "Dizionario ortografico",
+    "admin.title.projects" => "Progetti",
+    "admin.title.css" => "Personalizzazione grafica",
+    "admin.title.settings" => "Branding dell'istanza",
+    "admin.title.features" => "Funzionalità",
+    "admin.title.incident_mode" => "Modalità incidente",
+    "admin.title.on_call" => "Reperibilità",
+    "admin.title.issue_tracker" => "Issue tracker",
+    "admin.title.embeds" => "Contenuti incorporati",
+    "admin.title.rag" => "Gestione degli indici",
+    "admin.title.access_levels" => "Livelli di accesso",
+    "admin.title.users" => "Gestione utenti",
+    "admin.title.ldap" => "LDAP",
+    "admin.title.default" => "Amministrazione",
+    "admin.subtitle.documentation_feedback" => "Esamina le lacune della documentazione segnalate via MCP, risolvile e mantieni ordinato il registro.",
+    "admin.subtitle.access_levels" => "Gestisci i livelli di accesso ai contenuti e la loro gerarchia di ereditarietà.",
+    "admin.subtitle.users" => "Assegna livelli di accesso e permessi agli utenti registrati.",
+    "admin.subtitle.ldap" => "Livelli di accesso e diritti di amministrazione concessi dai gruppi della directory all'accesso LDAP.",
+    "admin.subtitle.api_keys" => "Chiavi di sola lettura per incorporare la documentazione in dashboard e strumenti interni.",
+    "admin.subtitle.approvals" => "Namespace protetti le cui ingestioni da CI attendono un amministratore prima della pubblicazione.",
+    "admin.subtitle.documents" => "Cambia responsabile, livello di accesso, tag o genitore di più documenti alla volta, oppure eliminali.",
+    "admin.subtitle.share_links" => "Link firmati che danno accesso in lettura a un documento riservato, finché non scadono o vengono revocati.",
+    "admin.subtitle.usage" => "Documenti e spazio di archiviazione consumati da ciascun responsabile di servizio.",
+    "admin.subtitle.doc_quality" => "Problemi di stile segnalati dal lint Markdown all'ultima ingestione di ciascun documento.",
+    "admin.subtitle.doc_ratings" => "Quanto i lettori hanno trovato utili i documenti di ciascun responsabile di servizio, con i loro commenti.",
+    "admin.subtitle.links" => "Link esterni mostrati nella barra di navigazione e nella barra laterale, accanto all'albero dei documenti.",
+    "admin.subtitle.glossary" => "Definizioni di termini condivise, mostrate come tooltip dove i termini compaiono nei documenti.",
+    "admin.subtitle.dictionary" => "Nomi di prodotto e gergo accettati dal correttore ortografico in ogni documento.",
+    "admin.subtitle.projects" => "Namespace per le unità di business che condividono questa istanza, ciascuno con i propri slug, token e livelli di accesso.",
+    "admin.subtitle.settings" => "Nome del portale, logo, colori, testo della homepage e link del piè di pagina.",
+    "admin.subtitle.features" => "Attiva o disattiva l'editor, l'assistente AI e la ricerca per questa installazione.",
+    "admin.subtitle.incident_mode" => "Runbook fissati nella barra laterale e nella homepage durante un incidente, e chi può attivare la modalità incidente.",
+    "admin.subtitle.on_call" => "Mostra chi è reperibile, da PagerDuty o Opsgenie, nelle pagine dei servizi e nei runbook.",
+    "admin.subtitle.embeds" => "Scegli gli strumenti i cui link vengono mostrati come widget incorporati nei documenti.",
+    "admin.subtitle.issue_tracker" => "Collega le chiavi delle issue nei documenti e nei commenti a Jira o Linear, con il loro stato attuale.",
+    "admin.subtitle.rag" => "Ricostruisci gli indici derivati di ricerca e recupero dall'archivio canonico dei documenti.",
+    "admin.subtitle.default" => "Gestisci la configurazione dell'istanza, i token di servizio e il tema.",
+    "admin.page_not_found" => "Pagina non trovata",
+    "admin.tokens.intro" => "Token a lunga durata per pipeline CI/CD e integrazioni con servizi esterni. Ogni token è limitato a specifici ambiti della documentazione.",
+    "admin.tokens.loading" => "Caricamento dei token...",
+    "admin.tokens.load_error" => "Impossibile caricare i token: {error}",
+    "admin.tokens.empty" => "Nessun token di servizio",
+    "admin.tokens.empty_hint" => "Crea il primo token qui sotto per automatizzare gli aggiornamenti dei documenti.",
+    "admin.common.name" => "Nome",
+    "admin.common.scopes" => "Ambiti",
+    "admin.tokens.write" => "Scrittura",
+    "admin.common.status" => "Stato",
+    "admin.common.created" => "Creato",
+    "admin.tokens.usage" => "Utilizzo",
+    "admin.common.actions" => "Azioni",
+    "admin.common.never" => "Mai",
+    "admin.tokens.more_scopes" => "+ altri {count}",
+    "admin.tokens.badge_write" => "SCRITTURA",
+    "admin.tokens.badge_read" => "LETTURA",
+    "admin.common.active" => "attivo",
+    "admin.common.deactivated" => "disattivato",
+    "admin.tokens.deactivate_confirm" => "Disattivare davvero questo token? L'operazione non può essere annullata.",
+    "admin.common.deactivate" => "Disattiva",
+    "admin.tokens.create_title" => "Crea nuovo token",
+    "admin.tokens.create_intro" => "Configura un nuovo token di accesso con ambiti limitati.",
+    "admin.tokens.token_name" => "Nome del token",
+    "admin.tokens.name_placeholder" => "es. github-actions-ci",
+    "admin.tokens.name_hint" => "Un nome descrittivo per identificarlo.",
+    "admin.tokens.permissions" => "Permessi",
+    "admin.tokens.allow_write" => "Consenti la scrittura",
+    "admin.tokens.allow_write_hint" => "Permette aggiornamenti ed eliminazioni tramite API.",
+    "admin.tokens.write_hint" => "Controlla i permessi di scrittura di questo token.",
+    "admin.tokens.allowed_scopes" => "Ambiti consentiti",
+    "admin.tokens.scopes_hint" => "Inserisci un prefisso di percorso per riga.",
+    "admin.tokens.project" => "Progetto",
+    "admin.tokens.project_placeholder" => "es. payments (facoltativo)",
+    "admin.tokens.project_hint" => "Limita il token al namespace del progetto; lascia vuoti gli ambiti per concedere l'intero progetto.",
+    "admin.tokens.creating" => "Creazione...",
+    "admin.tokens.create" => "Crea token di servizio",
+    "admin.api_keys.intro" => "Chiavi di sola lettura per dashboard e strumenti interni che incorporano la documentazione da server a server. Una chiave può cercare e leggere i documenti pubblicati nei suoi livelli di accesso, eventualmente limitati a prefissi di percorso, e non scrive mai.",
+    "admin.api_keys.empty" => "Nessuna chiave API.",
+    "admin.api_keys.access_levels" => "Livelli di accesso",
+    "admin.api_keys.last_used" => "Ultimo utilizzo",
+    "admin.api_keys.all_documents" => "tutti i documenti",
+    "admin.api_keys.deactivate_confirm" => "Disattivare questa chiave API? L'operazione non può essere annullata.",
+    "admin.api_keys.load_error" => "Impossibile caricare le chiavi API: {error}",
+    "admin.api_keys.create" => "Crea chiave API",
+    "admin.api_keys.name_placeholder" => "Nome, es. ops-dashboard",
+    "admin.api_keys.scopes_placeholder" => "Prefissi di percorso facoltativi, uno per riga\ndocs/runbooks",
+    "admin.feedback.title" => "Registro delle segnalazioni sulla documentazione",
+    "admin.feedback.intro" => "Registro, riservato agli amministratori, delle lacune della documentazione e delle proposte di miglioramento segnalate via MCP. Usalo per risolvere, deduplicare e dare priorità alla manutenzione della documentazione senza trasformare Lekton in un sistema di ticket.",
+    "admin.feedback.search" => "Cerca",
+    "admin.feedback.search_placeholder" => "Cerca titolo, riepilogo, URI lekton://docs/ o proposta",
+    "admin.feedback.kind" => "Tipo",
+    "admin.feedback.all_kinds" => "Tutti i tipi",
+    "admin.feedback.missing_info" => "Informazioni mancanti",
+    "admin.feedback.improvement" => "Miglioramento",
+    "admin.feedback.open" => "Aperte",
+    "admin.feedback.resolved" => "Risolte",
+    "admin.feedback.all_statuses" => "Tutti gli stati",
+    "admin.feedback.loading" => "Caricamento delle segnalazioni...",
+    "admin.feedback.load_error" => "Impossibile caricare le segnalazioni: {error}",
+    "admin.feedback.empty" => "Nessuna segnalazione corrispondente",
+    "admin.feedback.empty_hint" => "Il registro è vuoto per i filtri selezionati. Gli agenti MCP lo popoleranno tramite gli strumenti di segnalazione della documentazione.",
+    "admin.feedback.total" => "{count} elementi in totale",
+    "admin.common.page_of" => "Pagina {page} di {pages}",
+    "admin.common.previous" => "Precedente",
+    "admin.common.next" => "Successiva",
+    "admin.feedback.related_resources" => "Risorse correlate",
+    "admin.feedback.search_queries" => "Query di ricerca",
+    "admin.feedback.user_goal" => "Obiettivo dell'utente",
+    "admin.feedback.missing_information" => "Informazioni mancanti",
+    "admin.feedback.impact" => "Impatto",
+    "admin.feedback.suggested_target_resource" => "Risorsa di destinazione suggerita",
+    "admin.feedback.target_resource" => "Risorsa di destinazione",
+    "admin.feedback.problem_summary" => "Riepilogo del problema",
+    "admin.feedback.proposal" => "Proposta",
+    "admin.feedback.supporting_resources" => "Risorse di supporto",
+    "admin.feedback.expected_benefit" => "Beneficio atteso",
+    "admin.feedback.related_feedback_ids" => "ID delle segnalazioni correlate",
+    "admin.feedback.duplicate_of" => "Duplicato di",
+    "admin.feedback.resolution_note" => "Nota di risoluzione",
+    "admin.feedback.note_placeholder" => "Nota facoltativa su come è stato risolto l'elemento o perché è stato segnato come duplicato.",
+    "admin.feedback.duplicate_placeholder" => "ID di una segnalazione esistente",
+    "admin.feedback.resolve" => "Risolvi",
+    "admin.feedback.mark_duplicate" => "Segna come duplicato",
+    "admin.navigation.title" => "Ordinamento della navigazione",
+    "admin.navigation.intro" => "Riordina sezioni e categorie nella navigazione. Trascina gli elementi o usa le frecce per cambiarne la posizione. I documenti all'interno di ogni sezione sono sempre ordinati per peso o alfabeticamente.",
+    "admin.navigation.loading" => "Caricamento dell'albero di navigazione...",
+    "admin.navigation.empty" => "Nessuna sezione trovata",
+    "admin.navigation.empty_hint" => "Le sezioni compariranno qui dopo l'ingestione di documenti con slug gerarchici.",
+    "admin.navigation.section" => "Sezione",
+    "admin.navigation.category" => "Categoria",
+    "admin.common.move_up" => "Sposta su",
+    "admin.common.move_down" => "Sposta giù",
+    "admin.navigation.save" => "Salva ordine",
+    "admin.css.title" => "Tema e CSS personalizzato",
+    "admin.css.intro" => "Personalizza l'aspetto della tua istanza Lekton. Il CSS qui sotto viene iniettato in ogni pagina in fase di esecuzione.",
+    "admin.branding.saved" => "{message}. Ricarica la pagina per vedere le modifiche ovunque.",
+    "admin.branding.title" => "Branding",
+    "admin.branding.intro" => "Personalizza come si presenta questo portale. I campi vuoti usano i valori predefiniti.",
+    "admin.branding.portal_name" => "Nome del portale",
+    "admin.branding.logo_url" => "URL del logo",
+    "admin.branding.primary_color" => "Colore principale",
+    "admin.branding.default_theme" => "Tema predefinito",
+    "admin.branding.theme_system" => "Segui la preferenza di sistema",
+    "admin.branding.theme_light" => "Chiaro",
+    "admin.branding.theme_dark" => "Scuro",
+    "admin.branding.hero_title" => "Titolo della homepage",
+    "admin.branding.hero_title_placeholder" => "Benvenuto in Lekton",
+    "admin.branding.hero_subtitle" => "Testo della homepage",
+    "admin.branding.homepage" => "Documento della homepage",
+    "admin.branding.homepage_hint" => "Slug di un documento da mostrare come homepage al posto dell'intestazione e delle schede predefinite.",
+    "admin.branding.footer_links" => "Link del piè di pagina",
+    "admin.branding.footer_links_hint" => "Un link per riga, nel formato Etichetta | URL.",
+    "admin.branding.access_request" => "Link per la richiesta di accesso",
+    "admin.branding.access_request_hint" => "Mostrato agli utenti che aprono un documento o uno schema oltre il loro livello di accesso.",
+    "admin.links.intro" => "Aggiungi link a dashboard, runbook o strumenti di reperibilità. Inizia un gruppo con una riga [Titolo del gruppo], poi aggiungi un Etichetta | URL per riga.",
+    "admin.links.show_in_navbar" => "Mostra nella barra di navigazione",
+    "admin.links.show_in_sidebar" => "Mostra nella barra laterale della documentazione",
+    "admin.usage.title" => "Utilizzo per responsabile di servizio",
+    "admin.usage.intro" => "Documenti attivi e dimensione del contenuto Markdown per responsabile di servizio. Le quote si impostano in [quotas] nella configurazione del server; le ingestioni oltre le quote vengono rifiutate.",
+    "admin.common.no_documents" => "Nessun documento.",
+    "admin.usage.storage" => "Spazio",
+    "admin.usage.load_error" => "Impossibile caricare l'utilizzo: {error}",
+    "admin.usage.unlimited" => "(illimitato)",
+    "admin.share_links.revoked_toast" => "Link revocato",
+    "admin.share_links.intro" => "Gli editor creano i link dal menu Condividi di un documento riservato. I link revocati smettono subito di funzionare; gli accessi sono conservati per 90 giorni.",
+    "admin.share_links.empty" => "Nessun link di condivisione.",
+    "admin.share_links.note" => "Nota",
+    "admin.share_links.created_by" => "Creato da",
+    "admin.share_links.expires" => "Scadenza",
+    "admin.share_links.views" => "Visualizzazioni",
+    "admin.share_links.active" => "Attivo",
+    "admin.share_links.revoked_by" => "Revocato da {user}",
+    "admin.share_links.revoked" => "Revocato",
+    "admin.share_links.expired" => "Scaduto",
+    "admin.share_links.last_viewed" => "Ultima visualizzazione {date}",
+    "admin.share_links.access_log" => "Registro accessi",
+    "admin.share_links.revoke" => "Revoca",
+    "admin.share_links.access_log_for" => "Registro accessi — {slug}",
+    "admin.common.close" => "Chiudi",
+    "admin.share_links.never_used" => "Questo link non è mai stato usato.",
+    "admin.share_links.when" => "Quando",
+    "admin.share_links.forwarded_for" => "Inoltrato per",
+    "admin.share_links.user_agent" => "User agent",
+    "admin.ratings.title" => "Valutazioni dei lettori",
+    "admin.ratings.intro" => "I lettori autenticati rispondono a \"Questa pagina ti è stata utile?\" in fondo a ogni documento, eventualmente con un commento. Seleziona un responsabile di servizio per elencarne solo i documenti.",
+    "admin.ratings.empty" => "Nessuna valutazione.",
+    "admin.ratings.rated_documents" => "Documenti valutati",
+    "admin.ratings.helpful" => "Utile",
+    "admin.ratings.not_helpful" => "Non utile",
+    "admin.ratings.helpful_percent" => "% utile",
+    "admin.ratings.comments_count" => "Commenti ({count})",
+    "admin.ratings.comments_for" => "Commenti — {slug}",
+    "admin.quality.title" => "Problemi rilevati dal lint",
+    "admin.quality.intro" => "Abilita le regole in [lint] nella configurazione del server, con eccezioni per progetto in [lint.projects.<name>]. I problemi rilevati non bloccano mai un'ingestione.",
+    "admin.quality.rule" => "Regola",
+    "admin.quality.all_rules" => "Tutte le regole",
+    "admin.quality.empty" => "Nessun problema rilevato.",
+    "admin.quality.findings" => "Problemi",
+    "admin.quality.load_error" => "Impossibile caricare il report: {error}",
+    "admin.documents.tags_required" => "Inserisci almeno un tag",
+    "admin.documents.owner_required" => "Inserisci il nuovo responsabile di servizio",
+    "admin.documents.access_level_required" => "Inserisci il nuovo livello di accesso",
+    "admin.documents.action_required" => "Scegli un'azione",
+    "admin.documents.updated" => "{count} documenti aggiornati",
+    "admin.documents.updated_not_found" => "{count} documenti aggiornati; non trovati: {slugs}",
+    "admin.documents.delete_confirm" => "Eliminare {count} documenti? Vengono archiviati e scompaiono dalla navigazione e dalla ricerca.",
+    "admin.documents.intro" => "Seleziona i documenti e applica a tutti una stessa modifica. Le modifiche ai documenti sincronizzati da un repository vengono sovrascritte dalla successiva ingestione da quel repository.",
+    "admin.documents.filter" => "Filtro",
+    "admin.documents.filter_placeholder" => "Slug, titolo, responsabile o tag",
+    "admin.documents.action" => "Azione",
+    "admin.documents.change_owner" => "Cambia responsabile",
+    "admin.documents.change_access_level" => "Cambia livello di accesso",
+    "admin.documents.add_tags" => "Aggiungi tag",
+    "admin.documents.remove_tags" => "Rimuovi tag",
+    "admin.documents.move" => "Sposta sotto un genitore",
+    "admin.common.delete" => "Elimina",
+    "admin.documents.value" => "Valore",
+    "admin.documents.parent_placeholder" => "slug del genitore, vuoto per il livello principale",
+    "admin.documents.apply" => "Applica ai {count} selezionati",
+    "admin.documents.select_all" => "Seleziona tutti quelli mostrati",
+    "admin.documents.owner" => "Responsabile",
+    "admin.documents.access" => "Accesso",
+    "admin.documents.tags" => "Tag",
+    "admin.documents.parent" => "Genitore",
+    "admin.documents.updated_at" => "Aggiornato",
+    "admin.documents.draft" => "bozza",
+    "admin.documents.hidden" => "nascosto",
+    "admin.documents.load_error" => "Impossibile caricare i documenti: {error}",
+    "admin.approvals.title" => "Ingestioni in attesa",
+    "admin.approvals.intro" => "Documenti inviati dalla CI a un namespace protetto. Approvare pubblica la versione inviata; rifiutare la scarta.",
+    "admin.approvals.empty" => "Nessuna ingestione in attesa di approvazione.",
+    "admin.approvals.load_error" => "Impossibile caricare le ingestioni in attesa: {error}",
+    "admin.approvals.changes" => "Modifiche: {changes}",
+    "admin.approvals.approve" => "Approva",
+    "admin.approvals.reject" => "Rifiuta",
+    "admin.approvals.content" => "Contenuto inviato",
+    "admin.approvals.prefixes_title" => "Namespace protetti",
+    "admin.approvals.prefixes_intro" => "Uno slug per riga. Un /* finale protegge l'intero namespace (security/* copre security e ogni pagina sottostante). Le modifiche nell'editor web non sono interessate.",
+    "admin.incident.intro" => "Mentre la modalità incidente è attiva, i runbook sono fissati sopra la navigazione della documentazione e nella homepage, i runbook aperti si ricaricano appena cambiano e le pagine sono servite con no-store.",
+    "admin.incident.on" => "Attiva",
+    "admin.incident.end" => "Termina la modalità incidente",
+    "admin.incident.start" => "Avvia la modalità incidente",
+    "admin.incident.runbooks" => "Runbook",
+    "admin.incident.runbooks_hint" => "Uno slug per riga, nell'ordine in cui fissarli. I lettori vedono solo i runbook che possono leggere.",
+    "admin.incident.responders" => "Responsabili reperibili",
+    "admin.incident.responders_hint" => "Un'email per riga. I responsabili possono attivare e disattivare la modalità incidente dalla homepage; gli amministratori possono sempre farlo.",
+    "admin.on_call.intro" => "I lettori autenticati vedono chi è reperibile nella pagina del changelog di un servizio mappato e nei suoi runbook (documenti con tag runbook, o fissati in modalità incidente). Le risposte sono memorizzate per un minuto.",
+    "admin.on_call.provider" => "Fornitore",
+    "admin.common.none" => "Nessuno",
+    "admin.common.api_token" => "Token API",
+    "admin.common.token_saved" => "Salvato — lascia vuoto per mantenerlo",
+    "admin.on_call.token_hint" => "Una chiave REST API PagerDuty di sola lettura, o una chiave API Opsgenie con accesso in lettura.",
+    "admin.on_call.api_url" => "URL dell'API",
+    "admin.on_call.api_url_hint" => "Lascia vuoto per usare l'API predefinita del fornitore.",
+    "admin.on_call.schedules" => "Turni",
+    "admin.on_call.schedules_hint" => "Un \"servizio = turno\" per riga. I servizi corrispondono ai nomi dei servizi del changelog e ai responsabili dei documenti; i turni Opsgenie possono essere indicati per ID o per nome.",
+    "admin.issue_tracker.intro" => "Le chiavi delle issue dei progetti elencati (es. PAY-42) diventano link nei documenti e nei commenti alle valutazioni. Con un token API, i lettori autenticati vedono anche lo stato di ogni issue; gli stati sono memorizzati per cinque minuti.",
+    "admin.issue_tracker.tracker" => "Tracker",
+    "admin.issue_tracker.url" => "URL del tracker",
+    "admin.issue_tracker.url_hint" => "Il sito Jira, o il workspace Linear (https://linear.app/acme).",
+    "admin.issue_tracker.user" => "Utente API",
+    "admin.issue_tracker.user_hint" => "Email dell'account Jira Cloud. Lascia vuoto per i token Linear e Jira Data Center.",
+    "admin.issue_tracker.token_hint" => "Basta l'accesso in lettura. Senza token, le chiavi vengono collegate senza stato.",
+    "admin.issue_tracker.projects" => "Chiavi dei progetti",
+    "admin.issue_tracker.projects_hint" => "Una per riga. Vengono collegate solo le chiavi di questi progetti, così termini come UTF-8 restano testo.",
+    "admin.embeds.intro" => "Un paragrafo composto solo da un link a uno strumento abilitato viene mostrato come widget incorporato, con il link sotto. Gli iframe nei documenti possono caricare solo gli URL di incorporamento degli strumenti abilitati. Le modifiche valgono per le pagine mostrate da ora in poi.",
+    "admin.embeds.providers" => "Strumenti abilitati",
+    "admin.embeds.grafana_hosts" => "Host Grafana",
+    "admin.embeds.grafana_hosts_hint" => "Uno per riga, eventualmente con :porta. Gli snapshot su questi host vengono incorporati; con il proxy Grafana configurato, anche i pannelli e le dashboard che consente.",
+    "admin.ldap.intro" => "Ogni accesso LDAP sostituisce i livelli di accesso dell'utente con quelli dei suoi gruppi e imposta il flag di amministratore in base ai gruppi di amministrazione. Lascia vuota una lista per gestire quella parte in Gestione utenti.",
+    "admin.ldap.not_configured" => "L'accesso LDAP non è configurato (auth.ldap.url). Le mappature si applicheranno quando lo sarà.",
+    "admin.ldap.mappings" => "Mappature dei gruppi",
+    "admin.ldap.mappings_hint" => "Un \"gruppo = livelli di accesso\" per riga. I gruppi si indicano per DN o nome comune; i livelli di accesso devono esistere.",
+    "admin.ldap.admin_groups" => "Gruppi di amministrazione",
+    "admin.ldap.admin_groups_hint" => "Uno per riga. I membri sono amministratori; chiunque altro acceda tramite LDAP non lo è.",
+    "admin.features.title" => "Flag delle funzionalità",
+    "admin.features.intro" => "Le modifiche si applicano subito, senza riavvio. I sottosistemi non configurati sul server restano spenti qualunque sia il loro flag.",
+    "admin.features.editor" => "Editor web",
+    "admin.features.editor_hint" => "I pulsanti di modifica, le pagine /edit e i salvataggi dell'editor.",
+    "admin.features.chat" => "Assistente AI",
+    "admin.features.chat_hint" => "La pagina della chat e gli endpoint API chat e ask.",
+    "admin.features.search" => "Motore di ricerca",
+    "admin.features.search_hint" => "La modalità ibrida unisce le corrispondenze semantiche ai risultati full-text quando gli embedding sono configurati.",
+    "admin.features.search_hybrid" => "Ibrida",
+    "admin.features.search_full_text" => "Solo full-text",
+    "admin.features.search_disabled" => "Disattivata",
+    "admin.common.save_changes" => "Salva modifiche",
+    "admin.common.discard" => "Annulla modifiche",
+    "admin.glossary.intro" => "La prima occorrenza di ogni termine in un documento rimanda alla sua voce in /glossary, con la definizione come tooltip. Salvare un termine esistente (senza distinzione tra maiuscole e minuscole) lo sostituisce.",
+    "admin.glossary.term_placeholder" => "Termine (es. SLO)",
+    "admin.glossary.owner_placeholder" => "Responsabile (team o persona)",
+    "admin.glossary.definition_placeholder" => "Definizione",
+    "admin.glossary.save" => "Salva termine",
+    "admin.glossary.term" => "Termine",
+    "admin.glossary.definition" => "Definizione",
+    "admin.glossary.owner" => "Responsabile",
+    "admin.common.edit" => "Modifica",
+    "admin.dictionary.title" => "Dizionario ortografico",
+    "admin.dictionary.intro" => "Le parole elencate qui sono accettate in aggiunta al dizionario Hunspell impostato in [spellcheck], con qualsiasi combinazione di maiuscole. Gli errori di ortografia sono segnalati dalle ingestioni di prova e da Controlla ortografia nell'editor.",
+    "admin.dictionary.word_placeholder" => "Parola (es. kubectl)",
+    "admin.dictionary.add" => "Aggiungi parola",
+    "admin.dictionary.added_by" => "Aggiunta da {user} il {date}",
+    "admin.dictionary.remove" => "Rimuovi",
+    "admin.projects.intro" => "Un progetto possiede i documenti e gli schemi il cui slug inizia con il suo nome (es. payments/…). I token di servizio possono essere associati a un progetto, e i livelli di accesso consentiti limitano quelli con cui il suo contenuto può essere pubblicato. Eliminare un progetto ne conserva il contenuto.",
+    "admin.projects.name_placeholder" => "Nome (es. payments)",
+    "admin.projects.title_placeholder" => "Titolo (es. Payments)",
+    "admin.projects.levels_placeholder" => "Livelli di accesso consentiti, separati da virgole (vuoto = tutti)",
+    "admin.projects.description" => "Descrizione",
+    "admin.projects.save" => "Salva progetto",
+    "admin.projects.title" => "Titolo",
+    "admin.projects.any_level" => "tutti",
+    "admin.created_token.title" => "Token creato",
+    "admin.created_token.subtitle" => "È la tua unica occasione per copiarlo.",
+    "admin.created_token.warning" => "Per motivi di sicurezza non conserviamo il token in chiaro. Se lo perdi, dovrai disattivarlo e crearne uno nuovo.",
+    "admin.created_token.token" => "Token generato",
+    "admin.created_token.copied" => "Copiato",
+    "admin.created_token.copy" => "Copia",
+    "admin.created_token.name" => "Nome interno",
+    "admin.created_token.done" => "Ho salvato il token",
+    "admin.reindex.search_title" => "Reindicizzazione Meilisearch",
+    "admin.reindex.search_intro" => "Ricostruisci l'indice di ricerca full-text dai metadati MongoDB e dal contenuto Markdown archiviato.",
+    "admin.reindex.rag_title" => "Reindicizzazione RAG",
+    "admin.reindex.rag_intro" => "Ricalcola gli embedding di tutti i documenti nel vector store. Usalo dopo aver cambiato il modello di embedding.",
+    "admin.reindex.schema_title" => "Reindicizzazione degli endpoint degli schemi",
+    "admin.reindex.schema_intro" => "Estrai di nuovo le operazioni API (percorso, metodo, descrizione) da tutte le versioni degli schemi archiviate in S3 e aggiorna l'indice in MongoDB. Eseguilo per recuperare gli schemi ingeriti prima dell'introduzione dell'indicizzazione degli endpoint.",
+    "admin.reindex.start" => "Avvia reindicizzazione",
+    "admin.reindex.in_progress" => "Reindicizzazione in corso...",
+    "admin.common.service_owner" => "Responsabile di servizio",
+    "admin.common.document" => "Documento",
+    "admin.glossary.empty" => "Nessun termine nel glossario.",
+    "admin.dictionary.empty" => "Nessuna parola.",
+    "admin.projects.empty" => "Nessun progetto.",
+    "admin.pats.intro" => "PAT rilasciati agli utenti per l'accesso degli agenti IDE (Claude Code, Cursor, ecc.).",
+    "admin.pats.token_name" => "Nome del token",
+    "admin.pats.user" => "Utente",
+    "admin.pats.empty" => "Nessun PAT trovato.",
+    "admin.pats.active" => "Attivo",
+    "admin.pats.inactive" => "Inattivo",
+    "admin.pats.activate" => "Attiva",
+    "admin.pats.total" => "{count} token in totale",
+    "admin.access_levels.intro" => "Definisci i livelli di accesso ai contenuti e la loro gerarchia di ereditarietà. I livelli di sistema (public, loggeduser) sono aggiunti automaticamente e non possono essere eliminati.",
+    "admin.access_levels.system" => "sistema",
+    "admin.access_levels.inherits" => "eredita:",
+    "admin.access_levels.label" => "Etichetta",
+    "admin.access_levels.description" => "Descrizione",
+    "admin.access_levels.inherits_from" => "Eredita da",
+    "admin.common.save" => "Salva",
+    "admin.access_levels.new" => "+ Nuovo livello di accesso",
+    "admin.access_levels.create_title" => "Crea livello di accesso",
+    "admin.access_levels.name" => "Nome (slug)",
+    "admin.access_levels.name_placeholder" => "es. cloud-developer",
+    "admin.access_levels.inherits_from_names" => "Eredita da (nomi separati da virgole)",
+    "admin.access_levels.inherits_placeholder" => "es. internal,developer",
+    "admin.common.create" => "Crea",
+    "admin.common.cancel" => "Annulla",
+    "admin.users.created" => "Utente creato. Gli verrà chiesto di cambiare la password dopo l'accesso.",
+    "admin.users.password_reset" => "Password reimpostata. L'utente è stato disconnesso e deve scegliere una nuova password.",
+    "admin.users.title" => "Utenti",
+    "admin.users.intro" => "Assegna livelli di accesso e permessi di scrittura agli utenti registrati.",
+    "admin.users.add" => "Aggiungi utente",
+    "admin.users.email" => "Email",
+    "admin.users.name_optional" => "Nome (facoltativo)",
+    "admin.users.initial_password" => "Password iniziale (almeno 12 caratteri)",
+    "admin.users.administrator" => "Amministratore",
+    "admin.users.admin_badge" => "admin",
+    "admin.users.local_badge" => "locale",
+    "admin.users.no_levels" => "nessun livello assegnato",
+    "admin.users.effective" => "effettivi: {levels}",
+    "admin.users.delete_confirm" => "Eliminare questo utente? Le sue sessioni vengono revocate immediatamente.",
+    "admin.users.assigned_levels" => "Livelli di accesso assegnati",
+    "admin.users.can_write" => "Può scrivere",
+    "admin.users.read_drafts" => "Legge le bozze",
+    "admin.users.write_drafts" => "Scrive le bozze",
+    "admin.users.temporary_password" => "Nuova password temporanea",
+    "admin.users.reset_password" => "Reimposta password",
+    "notifications.target_placeholder" => "guides/setup o guides/*",
+    "chat.sign_in_required" => "Accesso richiesto",
+    "chat.sign_in_hint" => "Accedi per usare l'assistente AI.",
+    "chat.sign_in" => "Accedi",
+    "chat.title" => "Assistente AI",
+    "chat.intro" => "Chiedimi qualsiasi cosa sulla documentazione e sul codice.",
+    "chat.suggestion_about" => "Cos'è Lekton?",
+    "chat.suggestion_oidc" => "Come configuro OIDC?",
+    "chat.input_placeholder" => "Scrivi un messaggio...",
+    "chat.newline_hint" => "Maiusc + Invio per andare a capo",
+    "chat.disclaimer" => "Le risposte dell'AI potrebbero essere imprecise",
+    "chat.sources" => "Fonti ({count})",
+    "chat.helpful" => "Utile",
+    "chat.not_helpful" => "Non utile",
+    "chat.remove_feedback" => "Rimuovi il feedback",
+    "chat.feedback_question" => "Cosa non andava? (facoltativo)",
+    "chat.feedback_placeholder" => "Dicci cosa si potrebbe migliorare...",
+    "chat.cancel" => "Annulla",
+    "chat.submit" => "Invia",
+    "chat.sidebar_title" => "Chat AI",
+    "chat.new_chat" => "Nuova chat",
+    "chat.no_session" => "Nessuna sessione di chat attiva",
+    "search.empty" => "Nessun risultato",
+    "sidebar.schemas" => "Schemi",
+    "sidebar.schema_overview" => "Panoramica",
+    "sidebar.no_schemas" => "Nessuno schema trovato",
+    "sidebar.schemas_error" => "Errore nel caricamento degli schemi",
+    "sidebar.events" => "Eventi",
+    "sidebar.event_catalog" => "Catalogo eventi",
+    "admin.nav.title" => "Amministrazione",
+    "admin.nav.tokens" => "Token di servizio",
+    "admin.nav.pats" => "Token personali",
+    "admin.nav.api_keys" => "Chiavi API",
+    "admin.nav.approvals" => "Approvazioni",
+    "admin.nav.documents" => "Documenti",
+    "admin.nav.share_links" => "Link di condivisione",
+    "admin.nav.usage" => "Utilizzo e quote",
+    "admin.nav.doc_quality" => "Qualità dei documenti",
+    "admin.nav.doc_ratings" => "Valutazioni dei documenti",
+    "admin.nav.documentation_feedback" => "Feedback sulla documentazione",
+    "admin.nav.access_levels" => "Livelli di accesso",
+    "admin.nav.users" => "Utenti",
+    "admin.nav.ldap" => "LDAP",
+    "admin.nav.navigation" => "Ordine di navigazione",
+    "admin.nav.links" => "Link personalizzati",
+    "admin.nav.glossary" => "Glossario",
+    "admin.nav.dictionary" => "Dizionario",
+    "admin.nav.projects" => "Progetti",
+    "admin.nav.css" => "CSS personalizzato",
+    "admin.nav.settings" => "Branding",
+    "admin.nav.features" => "Funzionalità",
+    "admin.nav.incident_mode" => "Modalità incidente",
+    "admin.nav.on_call" => "Reperibilità",
+    "admin.nav.issue_tracker" => "Issue tracker",
+    "admin.nav.embeds" => "Contenuti incorporati",
+    "admin.nav.rag" => "Indici",
+}
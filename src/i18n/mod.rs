@@ -0,0 +1,250 @@
+//! UI localization.
+//!
+//! Strings are looked up by dotted key (e.g. `"nav.registry"`) in a per-locale
+//! catalog; keys missing from a catalog fall back to English so a partially
+//! translated locale never shows blanks. Each key is a whole message: values
+//! go in `{name}` placeholders ([`I18n::tf`]), never concatenated with other
+//! keys, so translations can reorder them. The active locale is negotiated on
+//! the server from the `lekton_locale` cookie (set by the language switcher)
+//! or the `Accept-Language` header, and provided to components as [`I18n`].
+//!
+//! Adding a language means adding a catalog module and a [`Locale`] variant.
+
+/// Define a catalog module's `lookup` from `"key" => "message",` entries,
+/// and its `ENTRIES` table for the catalog tests.
+macro_rules! catalog {
+    ($($key:literal => $message:literal,)*) => {
+        #[cfg(test)]
+        pub(super) const ENTRIES: &[(&str, &str)] = &[$(($key, $message)),*];
+
+        pub(super) fn lookup(key: &str) -> Option<&'static str> {
+            Some(match key {
+                $($key => $message,)*
+                _ => return None,
+            })
+        }
+    };
+}
+use catalog;
+
+mod en;
+mod it;
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Cookie holding the user's explicit language choice.
+pub const LOCALE_COOKIE: &str = "lekton_locale";
+
+/// Supported UI languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    En,
+    It,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::It];
+
+    /// BCP 47 language code, as used in cookies and `<html lang>`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::It => "it",
+        }
+    }
+
+    /// Language name in its own language, for the switcher.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::It => "Italiano",
+        }
+    }
+
+    /// Parse a language tag (`it`, `it-IT`, `IT_ch`…) by its primary subtag.
+    pub fn from_code(tag: &str) -> Option<Self> {
+        let primary = tag
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        Self::ALL.into_iter().find(|l| l.code() == primary)
+    }
+}
+
+/// Pick the locale for a request: an explicit cookie wins, then the
+/// highest-weighted supported `Accept-Language` entry, then English.
+pub fn negotiate(cookie: Option<&str>, accept_language: Option<&str>) -> Locale {
+    if let Some(locale) = cookie.and_then(Locale::from_code) {
+        return locale;
+    }
+
+    let mut candidates: Vec<(f32, Locale)> = accept_language
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let locale = Locale::from_code(parts.next()?)?;
+            let q = parts
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (q > 0.0).then_some((q, locale))
+        })
+        .collect();
+    // Stable sort keeps header order among equal weights
+    candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+    candidates.first().map(|(_, l)| *l).unwrap_or_default()
+}
+
+/// Look up `key` in the catalog for `locale`, falling back to English and
+/// finally to the key itself.
+pub fn translate(locale: Locale, key: &'static str) -> &'static str {
+    let localized = match locale {
+        Locale::En => None,
+        Locale::It => it::lookup(key),
+    };
+    localized.or_else(|| en::lookup(key)).unwrap_or(key)
+}
+
+/// Translate `key` and substitute its `{name}` placeholders with `args`.
+pub fn translate_with(locale: Locale, key: &'static str, args: &[(&str, &str)]) -> String {
+    args.iter().fold(
+        translate(locale, key).to_string(),
+        |message, (name, value)| message.replace(&format!("{{{name}}}"), value),
+    )
+}
+
+/// Active-locale context, provided by `App`.
+#[derive(Clone, Copy)]
+pub struct I18n(pub Signal<Locale>);
+
+impl I18n {
+    /// Translate `key` for the active locale (reactive).
+    pub fn t(&self, key: &'static str) -> &'static str {
+        translate(self.0.get(), key)
+    }
+
+    /// Translate `key` without tracking the locale, for event handlers and
+    /// actions.
+    pub fn t_untracked(&self, key: &'static str) -> &'static str {
+        translate(self.0.get_untracked(), key)
+    }
+
+    /// Translate `key` and fill its `{name}` placeholders (reactive).
+    pub fn tf(&self, key: &'static str, args: &[(&str, &str)]) -> String {
+        translate_with(self.0.get(), key, args)
+    }
+
+    /// [`I18n::tf`] without tracking the locale.
+    pub fn tf_untracked(&self, key: &'static str, args: &[(&str, &str)]) -> String {
+        translate_with(self.0.get_untracked(), key, args)
+    }
+
+    /// Split the message of `key` around its `{name}` placeholder, for
+    /// values rendered with their own markup (e.g. a badge).
+    pub fn around(&self, key: &'static str, name: &str) -> (&'static str, &'static str) {
+        let message = self.t(key);
+        message
+            .split_once(&format!("{{{name}}}"))
+            .unwrap_or((message, ""))
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.0.get()
+    }
+}
+
+/// Access the active locale; defaults to English outside of `App`.
+pub fn use_i18n() -> I18n {
+    use_context::<I18n>().unwrap_or(I18n(Signal::stored(Locale::En)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_from_code_uses_primary_subtag() {
+        assert_eq!(Locale::from_code("it"), Some(Locale::It));
+        assert_eq!(Locale::from_code("it-CH"), Some(Locale::It));
+        assert_eq!(Locale::from_code("EN_us"), Some(Locale::En));
+        assert_eq!(Locale::from_code("de"), None);
+    }
+
+    #[test]
+    fn negotiate_prefers_cookie_then_weighted_header() {
+        assert_eq!(negotiate(Some("it"), Some("en-US,en;q=0.9")), Locale::It);
+        assert_eq!(negotiate(None, Some("de-DE,it;q=0.8,en;q=0.5")), Locale::It);
+        assert_eq!(negotiate(None, Some("en;q=0.4, it;q=0.7")), Locale::It);
+        assert_eq!(negotiate(Some("xx"), Some("fr")), Locale::En);
+        assert_eq!(negotiate(None, Some("it;q=0")), Locale::En);
+        assert_eq!(negotiate(None, None), Locale::En);
+    }
+
+    #[test]
+    fn translate_falls_back_to_english_then_key() {
+        assert_eq!(translate(Locale::It, "nav.more"), "Altro");
+        assert_eq!(translate(Locale::En, "nav.more"), "More");
+        assert_eq!(translate(Locale::It, "missing.key"), "missing.key");
+    }
+
+    #[test]
+    fn translate_with_fills_placeholders() {
+        assert_eq!(
+            translate_with(Locale::En, "search.no_results", &[("query", "kafka")]),
+            "No results found for \"kafka\""
+        );
+        assert_eq!(
+            translate_with(Locale::It, "search.no_results", &[("query", "kafka")]),
+            "Nessun risultato per \"kafka\""
+        );
+    }
+
+    fn placeholders(message: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = message
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn catalogs_have_unique_keys() {
+        for entries in [en::ENTRIES, it::ENTRIES] {
+            let mut keys: Vec<&str> = entries.iter().map(|(key, _)| *key).collect();
+            keys.sort_unstable();
+            let total = keys.len();
+            keys.dedup();
+            assert_eq!(keys.len(), total, "duplicate catalog key");
+        }
+    }
+
+    #[test]
+    fn italian_catalog_translates_every_english_key() {
+        for (key, english) in en::ENTRIES {
+            let italian = it::lookup(key);
+            assert!(italian.is_some(), "'{key}' missing from Italian catalog");
+            assert_eq!(
+                placeholders(italian.unwrap()),
+                placeholders(english),
+                "'{key}' placeholders differ from English"
+            );
+        }
+    }
+
+    #[test]
+    fn italian_catalog_only_contains_known_keys() {
+        for (key, _) in it::ENTRIES {
+            assert!(
+                en::lookup(key).is_some(),
+                "'{key}' missing from English catalog"
+            );
+        }
+    }
+}
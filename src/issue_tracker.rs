@@ -0,0 +1,300 @@
+//! Issue status lookups in Jira or Linear.
+//!
+//! Admins pick the tracker and its project keys under **Admin → Issue
+//! Tracker** ([`IssueTrackerSettings`] in the settings document). Before a
+//! document or a comment is rendered, [`IssueTracker::statuses`] looks up
+//! the issues it mentions:
+//!
+//! - Jira: `GET /rest/api/2/issue/{key}?fields=summary,status`, using the
+//!   status category for the badge colour;
+//! - Linear: the GraphQL `issue` query, using the workflow state type.
+//!
+//! Answers, including unknown issues and failed lookups, are kept for
+//! [`CACHE_TTL`], so busy pages neither slow down on the tracker nor use up
+//! its rate limits.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use crate::db::settings_repository::{IssueTrackerProvider, IssueTrackerSettings};
+use crate::error::AppError;
+use crate::rendering::issues::{IssueState, IssueStatus};
+
+/// How long a looked-up status is reused.
+pub const CACHE_TTL: Duration = Duration::from_secs(300);
+/// Most issues looked up for one render; further keys are linked without
+/// a status.
+const MAX_LOOKUPS: usize = 50;
+/// Longest wait for the tracker.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+const LINEAR_API: &str = "https://api.linear.app/graphql";
+
+/// Cached issue status lookups.
+pub struct IssueTracker {
+    client: reqwest::Client,
+    cache: Mutex<HashMap<String, (Instant, Option<IssueStatus>)>>,
+}
+
+impl Default for IssueTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IssueTracker {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Statuses of the `keys` the tracker knows. Empty without an API token.
+    pub async fn statuses(
+        &self,
+        settings: &IssueTrackerSettings,
+        keys: &[String],
+    ) -> HashMap<String, IssueStatus> {
+        let mut statuses = HashMap::new();
+        if settings.provider == IssueTrackerProvider::None || settings.api_token.is_empty() {
+            return statuses;
+        }
+
+        let mut missing = Vec::new();
+        {
+            let cache = self.cache.lock().unwrap();
+            for key in keys.iter().take(MAX_LOOKUPS) {
+                match cache.get(key) {
+                    Some((fetched, status)) if fetched.elapsed() < CACHE_TTL => {
+                        if let Some(status) = status {
+                            statuses.insert(key.clone(), status.clone());
+                        }
+                    }
+                    _ => missing.push(key),
+                }
+            }
+        }
+
+        let lookups = missing.iter().map(|key| self.lookup(settings, key));
+        let results = futures::future::join_all(lookups).await;
+        let mut cache = self.cache.lock().unwrap();
+        for (key, result) in missing.into_iter().zip(results) {
+            let status = result.unwrap_or_else(|e| {
+                tracing::warn!(key = %key, "Issue status lookup failed: {e}");
+                None
+            });
+            if let Some(status) = &status {
+                statuses.insert(key.clone(), status.clone());
+            }
+            cache.insert(key.clone(), (Instant::now(), status));
+        }
+        statuses
+    }
+
+    /// Forget cached statuses, after the settings change.
+    pub fn clear(&self) {
+        self.cache.lock().unwrap().clear();
+    }
+
+    async fn lookup(
+        &self,
+        settings: &IssueTrackerSettings,
+        key: &str,
+    ) -> Result<Option<IssueStatus>, AppError> {
+        match settings.provider {
+            IssueTrackerProvider::None => Ok(None),
+            IssueTrackerProvider::Jira => self.jira(settings, key).await,
+            IssueTrackerProvider::Linear => self.linear(settings, key).await,
+        }
+    }
+
+    async fn jira(
+        &self,
+        settings: &IssueTrackerSettings,
+        key: &str,
+    ) -> Result<Option<IssueStatus>, AppError> {
+        let url = format!(
+            "{}/rest/api/2/issue/{}",
+            settings.base_url.trim_end_matches('/'),
+            urlencoding::encode(key)
+        );
+        let request = self.client.get(url).query(&[("fields", "summary,status")]);
+        let request = if settings.api_user.is_empty() {
+            request.bearer_auth(&settings.api_token)
+        } else {
+            request.basic_auth(&settings.api_user, Some(&settings.api_token))
+        };
+        let response = request
+            .send()
+            .await
+            .map_err(|e| AppError::Unavailable(format!("Jira issue lookup: {e}")))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body: JiraIssue = response
+            .error_for_status()
+            .map_err(|e| AppError::Unavailable(format!("Jira issue lookup: {e}")))?
+            .json()
+            .await
+            .map_err(|e| AppError::Unavailable(format!("Jira issue lookup: {e}")))?;
+        Ok(Some(jira_status(body)))
+    }
+
+    async fn linear(
+        &self,
+        settings: &IssueTrackerSettings,
+        key: &str,
+    ) -> Result<Option<IssueStatus>, AppError> {
+        let query = serde_json::json!({
+            "query": "query Issue($id: String!) { issue(id: $id) { title state { name type } } }",
+            "variables": { "id": key },
+        });
+        let body: LinearResponse = self
+            .client
+            .post(LINEAR_API)
+            .header(reqwest::header::AUTHORIZATION, &settings.api_token)
+            .json(&query)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| AppError::Unavailable(format!("Linear issue lookup: {e}")))?
+            .json()
+            .await
+            .map_err(|e| AppError::Unavailable(format!("Linear issue lookup: {e}")))?;
+        Ok(linear_status(body))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssue {
+    fields: JiraFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraFields {
+    #[serde(default)]
+    summary: String,
+    status: JiraStatus,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraStatus {
+    name: String,
+    #[serde(default, rename = "statusCategory")]
+    status_category: Option<JiraStatusCategory>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraStatusCategory {
+    key: String,
+}
+
+/// The status of a Jira issue; the category `new`, `indeterminate` or
+/// `done` picks the state.
+fn jira_status(issue: JiraIssue) -> IssueStatus {
+    let state = match issue
+        .fields
+        .status
+        .status_category
+        .as_ref()
+        .map(|c| c.key.as_str())
+    {
+        Some("done") => IssueState::Done,
+        Some("indeterminate") => IssueState::InProgress,
+        _ => IssueState::Open,
+    };
+    IssueStatus {
+        summary: issue.fields.summary,
+        status: issue.fields.status.name,
+        state,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LinearResponse {
+    #[serde(default)]
+    data: Option<LinearData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LinearData {
+    issue: Option<LinearIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LinearIssue {
+    #[serde(default)]
+    title: String,
+    state: LinearState,
+}
+
+#[derive(Debug, Deserialize)]
+struct LinearState {
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+/// The status of a Linear issue, `None` for an unknown issue (Linear
+/// answers those with a GraphQL error and no data).
+fn linear_status(body: LinearResponse) -> Option<IssueStatus> {
+    let issue = body.data?.issue?;
+    let state = match issue.state.kind.as_str() {
+        "completed" | "canceled" => IssueState::Done,
+        "started" => IssueState::InProgress,
+        _ => IssueState::Open,
+    };
+    Some(IssueStatus {
+        summary: issue.title,
+        status: issue.state.name,
+        state,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jira_status_category() {
+        let issue: JiraIssue = serde_json::from_value(serde_json::json!({
+            "key": "PAY-42",
+            "fields": {
+                "summary": "Retry failed payouts",
+                "status": { "name": "In Review", "statusCategory": { "key": "indeterminate" } }
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            jira_status(issue),
+            IssueStatus {
+                summary: "Retry failed payouts".into(),
+                status: "In Review".into(),
+                state: IssueState::InProgress,
+            }
+        );
+    }
+
+    #[test]
+    fn test_linear_status() {
+        let body: LinearResponse = serde_json::from_value(serde_json::json!({
+            "data": { "issue": { "title": "Dark mode", "state": { "name": "Shipped", "type": "completed" } } }
+        }))
+        .unwrap();
+        assert_eq!(linear_status(body).unwrap().state, IssueState::Done);
+
+        let unknown: LinearResponse = serde_json::from_value(serde_json::json!({
+            "errors": [{ "message": "Entity not found: Issue" }],
+            "data": null
+        }))
+        .unwrap();
+        assert_eq!(linear_status(unknown), None);
+    }
+}
@@ -1 +1,111 @@
+//! Background jobs.
+//!
+//! Work that should not block a request is submitted as a [`Job`] to the
+//! [`JobQueue`] in `AppState`. The default [`LocalJobQueue`] runs it on a task
+//! of the submitting process; with Redis coordination
+//! ([`crate::coordination`]) jobs go to a queue shared by all replicas.
+
 pub mod recompute_access_levels;
+pub mod scheduler;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::db::access_level_repository::AccessLevelRepository;
+use crate::db::user_repository::UserRepository;
+use crate::error::AppError;
+
+/// A unit of background work. Serialized as JSON when queued in Redis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "job", rename_all = "snake_case")]
+pub enum Job {
+    /// Recompute `effective_access_levels` of the users assigned `level`
+    /// after its inheritance chain changed.
+    RecomputeAccessLevels { level: String },
+}
+
+/// Runs [`Job`]s against the repositories they need.
+#[derive(Clone)]
+pub struct JobRunner {
+    access_level_repo: Arc<dyn AccessLevelRepository>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl JobRunner {
+    pub fn new(
+        access_level_repo: Arc<dyn AccessLevelRepository>,
+        user_repo: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            access_level_repo,
+            user_repo,
+        }
+    }
+
+    pub async fn run(&self, job: &Job) -> Result<(), AppError> {
+        match job {
+            Job::RecomputeAccessLevels { level } => {
+                recompute_access_levels::recompute_for_level(
+                    level,
+                    &*self.access_level_repo,
+                    &*self.user_repo,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Run `job`, logging failures instead of returning them.
+    pub async fn run_logged(&self, job: Job) {
+        if let Err(e) = self.run(&job).await {
+            tracing::error!(?job, error = %e, "Background job failed");
+        }
+    }
+}
+
+/// Where jobs are submitted.
+#[async_trait]
+pub trait JobQueue: Send + Sync {
+    /// Queue `job`. Returns once it is accepted, not when it has run;
+    /// failures of the job itself are logged by whoever runs it.
+    async fn enqueue(&self, job: Job);
+}
+
+/// Runs each job on a new task of this process.
+pub struct LocalJobQueue {
+    runner: JobRunner,
+}
+
+impl LocalJobQueue {
+    pub fn new(runner: JobRunner) -> Self {
+        Self { runner }
+    }
+}
+
+#[async_trait]
+impl JobQueue for LocalJobQueue {
+    async fn enqueue(&self, job: Job) {
+        let runner = self.runner.clone();
+        tokio::spawn(async move { runner.run_logged(job).await });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_job_json_shape() {
+        let job = Job::RecomputeAccessLevels {
+            level: "internal".into(),
+        };
+        let json = serde_json::to_value(&job).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "job": "recompute_access_levels", "level": "internal" })
+        );
+        assert_eq!(serde_json::from_value::<Job>(json).unwrap(), job);
+    }
+}
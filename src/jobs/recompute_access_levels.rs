@@ -1,39 +1,20 @@
 //! Background job: recompute `effective_access_levels` for all users whose
 //! `assigned_access_levels` contain a level whose inheritance chain was modified.
 //!
-//! Called (via `tokio::spawn`) whenever an access level's `inherits_from` changes.
-//! Errors are logged but not propagated — the caller should not block on this job.
-
-use std::sync::Arc;
+//! Queued as [`Job::RecomputeAccessLevels`](crate::jobs::Job::RecomputeAccessLevels)
+//! whenever an access level's `inherits_from` changes; the caller does not
+//! wait for it.
 
 use crate::db::access_level_repository::AccessLevelRepository;
 use crate::db::user_repository::UserRepository;
 
-/// Spawn a background task that recomputes effective access levels for all users
-/// transitively affected by a change to `changed_level_name`.
+/// Recompute effective access levels for all users transitively affected by
+/// a change to `changed_level_name`.
 ///
-/// The task finds every user that has `changed_level_name` in their
-/// `assigned_access_levels`, recomputes their effective set through the current
-/// DAG, and persists the result.
-pub fn spawn_recompute_for_level(
-    changed_level_name: String,
-    access_level_repo: Arc<dyn AccessLevelRepository>,
-    user_repo: Arc<dyn UserRepository>,
-) {
-    tokio::spawn(async move {
-        if let Err(e) =
-            recompute_for_level(&changed_level_name, &*access_level_repo, &*user_repo).await
-        {
-            tracing::error!(
-                level = %changed_level_name,
-                error = %e,
-                "Failed to recompute effective access levels after hierarchy change"
-            );
-        }
-    });
-}
-
-async fn recompute_for_level(
+/// Finds every user that has `changed_level_name` in their
+/// `assigned_access_levels`, recomputes their effective set through the
+/// current DAG, and persists the result.
+pub(crate) async fn recompute_for_level(
     changed_level_name: &str,
     access_level_repo: &dyn AccessLevelRepository,
     user_repo: &dyn UserRepository,
@@ -150,6 +131,7 @@ mod tests {
                 can_write_draft: false,
                 created_at: Utc::now(),
                 last_login_at: None,
+                password: None,
             };
             Self {
                 users: Mutex::new(vec![user]),
@@ -231,6 +213,24 @@ mod tests {
                 .cloned()
                 .collect())
         }
+        async fn set_user_password(
+            &self,
+            _: &str,
+            _: crate::db::auth_models::LocalPassword,
+        ) -> Result<(), AppError> {
+            Ok(())
+        }
+        async fn update_user_profile(
+            &self,
+            _: &str,
+            _: Option<String>,
+            _: bool,
+        ) -> Result<(), AppError> {
+            Ok(())
+        }
+        async fn delete_user(&self, _: &str) -> Result<(), AppError> {
+            Ok(())
+        }
         async fn create_refresh_token(
             &self,
             _: crate::db::auth_models::RefreshToken,
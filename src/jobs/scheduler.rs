@@ -0,0 +1,542 @@
+//! Periodic maintenance jobs run on cron schedules (`[scheduler]` config).
+//!
+//! | Job                     | Does                                                        |
+//! |-------------------------|-------------------------------------------------------------|
+//! | `link_check`            | Lists internal links to missing or archived documents       |
+//! | `stale_docs`            | Lists documents not updated for `stale_after_days`          |
+//! | `search_reconciliation` | Repairs the search index against the database              |
+//! | `digest`                | Posts the latest link check and stale findings to a webhook |
+//! | `email_digest`          | Emails each service owner its broken links and stale docs   |
+//! | `notification_cleanup`  | Deletes notifications older than the retention period       |
+//!
+//! Every run is recorded in the job history ([`JobRunRepository`]), which
+//! also makes sure that only one replica runs each scheduled occurrence.
+//! Admins can list runs and start a job on demand through the admin API.
+//!
+//! [`JobRunRepository`]: crate::db::job_run_repository::JobRunRepository
+
+use std::str::FromStr;
+use std::sync::atomic::Ordering;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::config::SchedulerConfig;
+use crate::db::job_run_repository::{JobRun, JobRunStatus};
+use crate::db::models::Document;
+use crate::error::AppError;
+
+/// `triggered_by` of runs started by the scheduler.
+pub const SCHEDULER: &str = "scheduler";
+
+/// How long the digest webhook may take to answer.
+const DIGEST_TIMEOUT_SECS: u64 = 30;
+
+/// A periodic job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledJob {
+    LinkCheck,
+    StaleDocs,
+    SearchReconciliation,
+    Digest,
+    EmailDigest,
+    NotificationCleanup,
+}
+
+impl ScheduledJob {
+    pub const ALL: [ScheduledJob; 6] = [
+        ScheduledJob::LinkCheck,
+        ScheduledJob::StaleDocs,
+        ScheduledJob::SearchReconciliation,
+        ScheduledJob::Digest,
+        ScheduledJob::EmailDigest,
+        ScheduledJob::NotificationCleanup,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ScheduledJob::LinkCheck => "link_check",
+            ScheduledJob::StaleDocs => "stale_docs",
+            ScheduledJob::SearchReconciliation => "search_reconciliation",
+            ScheduledJob::Digest => "digest",
+            ScheduledJob::EmailDigest => "email_digest",
+            ScheduledJob::NotificationCleanup => "notification_cleanup",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|job| job.as_str() == value)
+    }
+
+    /// The configured cron expression; empty when the job is disabled.
+    pub fn schedule(self, config: &SchedulerConfig) -> &str {
+        match self {
+            ScheduledJob::LinkCheck => &config.link_check,
+            ScheduledJob::StaleDocs => &config.stale_docs,
+            ScheduledJob::SearchReconciliation => &config.search_reconciliation,
+            ScheduledJob::Digest => &config.digest,
+            ScheduledJob::EmailDigest => &config.email_digest,
+            ScheduledJob::NotificationCleanup => &config.notification_cleanup,
+        }
+        .trim()
+    }
+}
+
+/// Parse a cron expression; `Ok(None)` for an empty (disabled) schedule.
+pub fn parse_schedule(expression: &str) -> Result<Option<cron::Schedule>, AppError> {
+    if expression.trim().is_empty() {
+        return Ok(None);
+    }
+    cron::Schedule::from_str(expression.trim())
+        .map(Some)
+        .map_err(|e| AppError::BadRequest(format!("Invalid cron expression '{expression}': {e}")))
+}
+
+/// Next occurrence of `job`, `None` when it is not scheduled.
+pub fn next_run(job: ScheduledJob, config: &SchedulerConfig) -> Option<DateTime<Utc>> {
+    parse_schedule(job.schedule(config))
+        .ok()
+        .flatten()
+        .and_then(|schedule| schedule.upcoming(Utc).next())
+}
+
+/// Outcome of a successful job.
+struct JobReport {
+    summary: String,
+    details: Vec<String>,
+}
+
+impl JobReport {
+    fn new(summary: impl Into<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            details: Vec::new(),
+        }
+    }
+}
+
+/// `(document, target)` pairs of internal links from active documents to
+/// documents that do not exist or are archived. Links to the schema viewer
+/// are not document links and are skipped.
+pub fn find_broken_links(documents: &[Document]) -> Vec<(String, String)> {
+    let active: std::collections::HashSet<&str> = documents
+        .iter()
+        .filter(|doc| !doc.is_archived)
+        .map(|doc| doc.slug.as_str())
+        .collect();
+
+    let mut broken: Vec<(String, String)> = documents
+        .iter()
+        .filter(|doc| !doc.is_archived)
+        .flat_map(|doc| {
+            doc.links_out
+                .iter()
+                .filter(|target| {
+                    !active.contains(target.as_str()) && !target.starts_with("schemas/")
+                })
+                .map(|target| (doc.slug.clone(), target.clone()))
+        })
+        .collect();
+    broken.sort();
+    broken
+}
+
+/// Active documents last updated more than `max_age_days` before `now`,
+/// oldest first.
+pub fn find_stale_documents(
+    documents: &[Document],
+    now: DateTime<Utc>,
+    max_age_days: u32,
+) -> Vec<&Document> {
+    let cutoff = now - chrono::Duration::days(i64::from(max_age_days));
+    let mut stale: Vec<&Document> = documents
+        .iter()
+        .filter(|doc| !doc.is_archived && doc.last_updated < cutoff)
+        .collect();
+    stale.sort_by_key(|doc| doc.last_updated);
+    stale
+}
+
+/// Run `job` now, recording it in the job history.
+///
+/// Returns `Conflict` when the occurrence was already claimed (by another
+/// replica, or a manual run in the same second). A failing job is recorded
+/// as `failed` and returned, not turned into an error.
+pub async fn run_job(
+    state: &AppState,
+    job: ScheduledJob,
+    scheduled_for: DateTime<Utc>,
+    triggered_by: &str,
+) -> Result<JobRun, AppError> {
+    let mut run = JobRun::start(job.as_str(), scheduled_for, triggered_by);
+    if !state.job_run_repo.claim(&run).await? {
+        return Err(AppError::Conflict(format!(
+            "Job '{}' is already running for this occurrence",
+            job.as_str()
+        )));
+    }
+
+    match execute(state, job).await {
+        Ok(report) => {
+            run.status = JobRunStatus::Succeeded;
+            run.summary = report.summary;
+            run.details = report.details;
+        }
+        Err(e) => {
+            e.log();
+            run.status = JobRunStatus::Failed;
+            run.summary = format!("Failed: {}", e.public_detail());
+            run.error = Some(e.to_string());
+        }
+    }
+    run.finished_at = Some(Utc::now());
+    state.job_run_repo.finish(&run).await?;
+    Ok(run)
+}
+
+async fn execute(state: &AppState, job: ScheduledJob) -> Result<JobReport, AppError> {
+    match job {
+        ScheduledJob::LinkCheck => link_check(state).await,
+        ScheduledJob::StaleDocs => stale_docs(state).await,
+        ScheduledJob::SearchReconciliation => search_reconciliation(state).await,
+        ScheduledJob::Digest => digest(state).await,
+        ScheduledJob::EmailDigest => email_digest(state).await,
+        ScheduledJob::NotificationCleanup => notification_cleanup(state).await,
+    }
+}
+
+async fn link_check(state: &AppState) -> Result<JobReport, AppError> {
+    let documents = state.document_repo.list_all().await?;
+    let broken = find_broken_links(&documents);
+
+    let mut sources: Vec<&str> = broken.iter().map(|(slug, _)| slug.as_str()).collect();
+    sources.dedup();
+    Ok(JobReport {
+        summary: format!(
+            "{} broken links in {} documents",
+            broken.len(),
+            sources.len()
+        ),
+        details: broken
+            .into_iter()
+            .map(|(slug, target)| format!("{slug} → {target}"))
+            .collect(),
+    })
+}
+
+async fn stale_docs(state: &AppState) -> Result<JobReport, AppError> {
+    let documents = state.document_repo.list_all().await?;
+    let max_age_days = state.scheduler.stale_after_days;
+    let stale = find_stale_documents(&documents, Utc::now(), max_age_days);
+
+    Ok(JobReport {
+        summary: format!(
+            "{} documents not updated for {max_age_days} days",
+            stale.len()
+        ),
+        details: stale
+            .into_iter()
+            .map(|doc| {
+                format!(
+                    "{} (last updated {}, {})",
+                    doc.slug,
+                    doc.last_updated.format("%Y-%m-%d"),
+                    doc.service_owner
+                )
+            })
+            .collect(),
+    })
+}
+
+async fn search_reconciliation(state: &AppState) -> Result<JobReport, AppError> {
+    let (Some(search), Some(reindex)) = (&state.search_service, &state.search_reindex_state) else {
+        return Ok(JobReport::new("Skipped: search is disabled"));
+    };
+    if reindex
+        .is_running
+        .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+        .is_err()
+    {
+        return Ok(JobReport::new(
+            "Skipped: a search reindex is already running",
+        ));
+    }
+
+    let repaired = crate::search::reindex::run_reconciliation(
+        reindex.clone(),
+        state.document_repo.clone(),
+        state.storage_client.clone(),
+        search.clone(),
+    )
+    .await
+    .ok_or_else(|| {
+        AppError::Unavailable("Search index could not be compared with the database".into())
+    })?;
+    Ok(JobReport::new(format!("{repaired} index entries repaired")))
+}
+
+/// Latest findings of a job, as included in the digest.
+#[derive(Debug, Serialize)]
+struct DigestSection {
+    summary: String,
+    details: Vec<String>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+async fn digest(state: &AppState) -> Result<JobReport, AppError> {
+    let url = state.scheduler.digest_webhook_url.trim();
+    if url.is_empty() {
+        return Err(AppError::BadRequest(
+            "scheduler.digest_webhook_url is not configured".into(),
+        ));
+    }
+
+    let mut sections = serde_json::Map::new();
+    let mut lines = Vec::new();
+    for job in [ScheduledJob::LinkCheck, ScheduledJob::StaleDocs] {
+        let Some(run) = state.job_run_repo.last_succeeded(job.as_str()).await? else {
+            continue;
+        };
+        lines.push(format!("{}: {}", job.as_str(), run.summary));
+        let section = DigestSection {
+            summary: run.summary,
+            details: run.details,
+            finished_at: run.finished_at,
+        };
+        sections.insert(
+            job.as_str().to_string(),
+            serde_json::to_value(section).map_err(|e| AppError::Internal(e.to_string()))?,
+        );
+    }
+    if lines.is_empty() {
+        return Ok(JobReport::new("Skipped: no findings to report yet"));
+    }
+
+    let text = format!("Documentation digest\n{}", lines.join("\n"));
+    sections.insert("text".to_string(), serde_json::Value::String(text));
+
+    reqwest::Client::new()
+        .post(url)
+        .timeout(std::time::Duration::from_secs(DIGEST_TIMEOUT_SECS))
+        .json(&sections)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(|e| AppError::Unavailable(format!("Digest webhook failed: {e}")))?;
+
+    Ok(JobReport {
+        summary: "Digest sent".to_string(),
+        details: lines,
+    })
+}
+
+/// Broken links and stale documents grouped by the `service_owner` of the
+/// document they were found in.
+#[derive(Debug, Default)]
+pub struct OwnerFindings<'a> {
+    pub broken_links: Vec<(String, String)>,
+    pub stale_documents: Vec<&'a Document>,
+}
+
+/// Group link check and stale document findings by service owner.
+pub fn findings_by_owner<'a>(
+    documents: &'a [Document],
+    broken_links: Vec<(String, String)>,
+    stale_documents: Vec<&'a Document>,
+) -> std::collections::BTreeMap<&'a str, OwnerFindings<'a>> {
+    let owners: std::collections::HashMap<&str, &str> = documents
+        .iter()
+        .map(|doc| (doc.slug.as_str(), doc.service_owner.as_str()))
+        .collect();
+
+    let mut findings: std::collections::BTreeMap<&str, OwnerFindings> = Default::default();
+    for (slug, target) in broken_links {
+        if let Some(&owner) = owners.get(slug.as_str()) {
+            findings
+                .entry(owner)
+                .or_default()
+                .broken_links
+                .push((slug, target));
+        }
+    }
+    for doc in stale_documents {
+        findings
+            .entry(doc.service_owner.as_str())
+            .or_default()
+            .stale_documents
+            .push(doc);
+    }
+    findings
+}
+
+async fn email_digest(state: &AppState) -> Result<JobReport, AppError> {
+    let Some(email) = &state.email else {
+        return Err(AppError::BadRequest(
+            "email.smtp_host is not configured".into(),
+        ));
+    };
+
+    let documents = state.document_repo.list_all().await?;
+    let max_age_days = state.scheduler.stale_after_days;
+    let findings = findings_by_owner(
+        &documents,
+        find_broken_links(&documents),
+        find_stale_documents(&documents, Utc::now(), max_age_days),
+    );
+
+    let mut details = Vec::new();
+    for (owner, found) in findings {
+        let sent = email
+            .send_owner_digest(
+                owner,
+                &found.broken_links,
+                &found.stale_documents,
+                max_age_days,
+            )
+            .await?;
+        if sent {
+            details.push(format!(
+                "{owner}: {} broken links, {} stale documents",
+                found.broken_links.len(),
+                found.stale_documents.len()
+            ));
+        }
+    }
+    Ok(JobReport {
+        summary: format!("Digest emailed to {} owners", details.len()),
+        details,
+    })
+}
+
+async fn notification_cleanup(state: &AppState) -> Result<JobReport, AppError> {
+    Ok(match state.inbox.prune_expired(Utc::now()).await? {
+        Some(deleted) => JobReport::new(format!("Deleted {deleted} expired notifications")),
+        None => JobReport::new("Notification retention is disabled"),
+    })
+}
+
+/// Start a timer task for every job with a schedule. Jobs with an invalid
+/// cron expression are logged and left unscheduled.
+pub fn spawn(state: AppState) {
+    for job in ScheduledJob::ALL {
+        match parse_schedule(job.schedule(&state.scheduler)) {
+            Ok(Some(schedule)) => {
+                tracing::info!(job = job.as_str(), schedule = %schedule, "Scheduling job");
+                tokio::spawn(run_on_schedule(state.clone(), job, schedule));
+            }
+            Ok(None) => {}
+            Err(e) => tracing::error!(job = job.as_str(), "Job not scheduled: {e}"),
+        }
+    }
+}
+
+async fn run_on_schedule(state: AppState, job: ScheduledJob, schedule: cron::Schedule) {
+    while let Some(next) = schedule.upcoming(Utc).next() {
+        let wait = (next - Utc::now()).to_std().unwrap_or_default();
+        tokio::time::sleep(wait).await;
+
+        match run_job(&state, job, next, SCHEDULER).await {
+            Ok(run) => tracing::info!(
+                job = job.as_str(),
+                status = ?run.status,
+                summary = %run.summary,
+                "Scheduled job finished"
+            ),
+            // Another replica claimed this occurrence
+            Err(AppError::Conflict(_)) => {}
+            Err(e) => tracing::error!(job = job.as_str(), "Failed to record job run: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    fn doc(slug: &str, links_out: &[&str], is_archived: bool, age_days: i64) -> Document {
+        Document {
+            title: format!("Title {slug}"),
+            access_level: "internal".to_string(),
+            service_owner: "platform".to_string(),
+            last_updated: Utc::now() - chrono::Duration::days(age_days),
+            links_out: links_out.iter().map(|l| l.to_string()).collect(),
+            is_archived,
+            ..testing::document(slug)
+        }
+    }
+
+    #[test]
+    fn test_find_broken_links() {
+        let documents = vec![
+            doc(
+                "guides/intro",
+                &["guides/setup", "guides/missing", "schemas/payments"],
+                false,
+                0,
+            ),
+            doc("guides/setup", &["old/page"], false, 0),
+            doc("old/page", &["guides/missing"], true, 0),
+        ];
+        assert_eq!(
+            find_broken_links(&documents),
+            vec![
+                ("guides/intro".to_string(), "guides/missing".to_string()),
+                ("guides/setup".to_string(), "old/page".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_stale_documents() {
+        let documents = vec![
+            doc("fresh", &[], false, 10),
+            doc("old", &[], false, 200),
+            doc("older", &[], false, 400),
+            doc("archived", &[], true, 400),
+        ];
+        let stale: Vec<&str> = find_stale_documents(&documents, Utc::now(), 180)
+            .into_iter()
+            .map(|doc| doc.slug.as_str())
+            .collect();
+        assert_eq!(stale, ["older", "old"]);
+    }
+
+    #[test]
+    fn test_findings_by_owner() {
+        let mut payments = doc("payments/api", &["payments/old"], false, 400);
+        payments.service_owner = "payments".to_string();
+        let documents = vec![payments, doc("guides/intro", &[], false, 400)];
+
+        let findings = findings_by_owner(
+            &documents,
+            find_broken_links(&documents),
+            find_stale_documents(&documents, Utc::now(), 180),
+        );
+        let owners: Vec<&str> = findings.keys().copied().collect();
+        assert_eq!(owners, ["payments", "platform"]);
+        assert_eq!(
+            findings["payments"].broken_links,
+            [("payments/api".to_string(), "payments/old".to_string())]
+        );
+        assert_eq!(findings["payments"].stale_documents.len(), 1);
+        assert!(findings["platform"].broken_links.is_empty());
+    }
+
+    #[test]
+    fn test_parse_schedule() {
+        assert!(parse_schedule("").unwrap().is_none());
+        assert!(parse_schedule("0 0 2 * * *").unwrap().is_some());
+        assert!(matches!(
+            parse_schedule("every night"),
+            Err(AppError::BadRequest(_))
+        ));
+        assert_eq!(
+            ScheduledJob::parse("stale_docs"),
+            Some(ScheduledJob::StaleDocs)
+        );
+        assert_eq!(ScheduledJob::parse("backup"), None);
+    }
+}
@@ -7,24 +7,62 @@ pub mod auth;
 pub mod components;
 #[cfg(feature = "ssr")]
 pub mod config;
+#[cfg(feature = "ssr")]
+pub mod config_check;
+#[cfg(feature = "ssr")]
+pub mod coordination;
 pub mod db;
+#[cfg(feature = "ssr")]
+pub mod demo_seeder;
 pub mod editor;
+#[cfg(feature = "ssr")]
+pub mod email;
 pub mod error;
 #[cfg(feature = "ssr")]
+pub mod features;
+#[cfg(feature = "ssr")]
+pub mod http_policy;
+pub mod i18n;
+#[cfg(feature = "ssr")]
+pub mod issue_tracker;
+#[cfg(feature = "ssr")]
 pub mod jobs;
+#[cfg(feature = "load-test")]
+pub mod load_seeder;
 #[cfg(feature = "ssr")]
 pub mod mcp;
+#[cfg(feature = "ssr")]
+pub mod mentions;
+#[cfg(feature = "ssr")]
+pub mod notifications;
+#[cfg(feature = "ssr")]
+pub mod on_call;
 pub mod pages;
 pub mod rag;
+pub mod relations;
 pub mod rendering;
+#[cfg(feature = "ssr")]
+pub mod resilience;
+#[cfg(feature = "ssr")]
+pub mod scanning;
 pub mod schema;
 pub mod search;
+#[cfg(feature = "ssr")]
+pub mod secrets;
 pub mod server;
 #[cfg(feature = "ssr")]
+pub mod spellcheck;
+#[cfg(feature = "ssr")]
 pub mod static_assets;
 pub mod storage;
+#[cfg(feature = "ssr")]
+pub mod telemetry;
 #[cfg(test)]
 pub mod test_utils;
+#[cfg(all(feature = "ssr", any(test, feature = "testing")))]
+pub mod testing;
+#[cfg(feature = "ssr")]
+pub mod watches;
 
 /// Client-side hydration entry point.
 ///
@@ -0,0 +1,692 @@
+//! `lekton seed` (cargo feature `load-test`): fabricate a large data set for
+//! performance work on navigation, search and listings.
+//!
+//! Documents form a tree under one root page (`load` by default): a handful
+//! of sections, each with up to ten children per level. Pages link to their
+//! parent and to a few random pages, so backlinks and relations have data.
+//! Schemas are OpenAPI and AsyncAPI specs with one to three versions and up
+//! to a few dozen operations.
+//!
+//! Like [`crate::demo_seeder`], content is written straight through the
+//! repositories and storage. The same `--seed` produces the same data set,
+//! so running it again overwrites the previous run instead of adding to it.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use futures::{StreamExt, TryStreamExt};
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::api::ingest::{compute_metadata_hash, content_hash, MetadataHashInput};
+use crate::config::KeyLayout;
+use crate::db::models::{Document, Schema, SchemaVersion};
+use crate::demo_seeder::DemoSeedContext;
+use crate::error::AppError;
+
+/// Recorded as the author of generated content.
+const LOAD_AUTHOR: &str = "load-seeder";
+/// Sections directly under the root page.
+const SECTIONS: usize = 8;
+/// Children of every section and page, level by level.
+const FAN_OUT: usize = 10;
+/// Objects written at the same time.
+const CONCURRENCY: usize = 16;
+
+const TOPICS: &[&str] = &[
+    "payments",
+    "checkout",
+    "inventory",
+    "shipping",
+    "billing",
+    "identity",
+    "search",
+    "notifications",
+    "analytics",
+    "catalog",
+    "pricing",
+    "ledger",
+    "onboarding",
+    "reporting",
+    "gateway",
+    "scheduler",
+];
+
+const ASPECTS: &[&str] = &[
+    "overview",
+    "architecture",
+    "runbook",
+    "api",
+    "deployment",
+    "monitoring",
+    "troubleshooting",
+    "faq",
+    "migration",
+    "security",
+    "data-model",
+    "release-notes",
+];
+
+const NOUNS: &[&str] = &[
+    "service",
+    "request",
+    "queue",
+    "cluster",
+    "replica",
+    "latency",
+    "budget",
+    "tenant",
+    "account",
+    "order",
+    "invoice",
+    "event",
+    "consumer",
+    "producer",
+    "retry",
+    "timeout",
+    "cache",
+    "index",
+    "shard",
+    "backup",
+    "rollout",
+    "canary",
+    "alert",
+    "dashboard",
+    "token",
+    "session",
+    "schema",
+    "contract",
+    "release",
+    "incident",
+    "owner",
+    "team",
+];
+
+const FILLERS: &[&str] = &[
+    "handles",
+    "stores",
+    "publishes",
+    "validates",
+    "routes",
+    "scales",
+    "reports",
+    "the",
+    "each",
+    "every",
+    "a",
+    "with",
+    "for",
+    "after",
+    "before",
+    "during",
+];
+
+/// What `lekton seed` generates.
+#[derive(Clone, Debug)]
+pub struct SeedOptions {
+    pub docs: usize,
+    pub schemas: usize,
+    /// Slug of the root page and prefix of every generated slug and schema.
+    pub prefix: String,
+    /// Seed of the random generator.
+    pub seed: u64,
+}
+
+/// Counts of what was written.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SeedReport {
+    pub docs: usize,
+    pub schemas: usize,
+    pub schema_versions: usize,
+}
+
+struct FakeDoc {
+    doc: Document,
+    content: String,
+}
+
+fn title_case(words: &str) -> String {
+    words
+        .split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn sentence(rng: &mut StdRng) -> String {
+    let len = rng.random_range(8..16);
+    let words: Vec<&str> = (0..len)
+        .map(|i| {
+            let words = if i % 2 == 0 { FILLERS } else { NOUNS };
+            *words.choose(rng).unwrap()
+        })
+        .collect();
+    let mut sentence = words.join(" ");
+    sentence[..1].make_ascii_uppercase();
+    sentence.push('.');
+    sentence
+}
+
+fn paragraph(rng: &mut StdRng) -> String {
+    let len = rng.random_range(3..6);
+    (0..len)
+        .map(|_| sentence(rng))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parent of page `i`, pages numbered level by level from 0 (the sections).
+fn parent_index(i: usize) -> Option<usize> {
+    (i >= SECTIONS).then(|| (i - SECTIONS) / FAN_OUT)
+}
+
+/// The root page plus `count - 1` pages under it.
+fn generate_documents(
+    count: usize,
+    prefix: &str,
+    layout: KeyLayout,
+    rng: &mut StdRng,
+) -> Vec<FakeDoc> {
+    if count == 0 {
+        return vec![];
+    }
+    let pages = count - 1;
+
+    let mut slugs: Vec<String> = Vec::with_capacity(pages);
+    let mut titles: Vec<String> = Vec::with_capacity(pages);
+    for i in 0..pages {
+        let topic = *TOPICS.choose(rng).unwrap();
+        let (segment, title) = match parent_index(i) {
+            None => (format!("{topic}-{i}"), title_case(topic)),
+            Some(_) => {
+                let aspect = *ASPECTS.choose(rng).unwrap();
+                (
+                    format!("{aspect}-{i}"),
+                    format!("{} {}", title_case(topic), title_case(aspect)),
+                )
+            }
+        };
+        let parent = parent_index(i).map_or(prefix, |p| slugs[p].as_str());
+        let slug = format!("{parent}/{segment}");
+        slugs.push(slug);
+        titles.push(title);
+    }
+
+    let mut links: Vec<Vec<usize>> = Vec::with_capacity(pages);
+    for _ in 0..pages {
+        let mut targets: Vec<usize> = (0..rng.random_range(0..4))
+            .map(|_| rng.random_range(0..pages))
+            .collect();
+        targets.sort();
+        targets.dedup();
+        links.push(targets);
+    }
+    let root_content = format!(
+        "# Load test data\n\nGenerated by `lekton seed`.\n\n{}\n",
+        (0..SECTIONS.min(pages))
+            .map(|i| format!("- [{}](/docs/{})", titles[i], slugs[i]))
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+    let mut docs = vec![fake_doc(
+        layout,
+        prefix,
+        "Load test data",
+        None,
+        0,
+        "public",
+        vec![],
+        root_content,
+        (0..SECTIONS.min(pages)).map(|i| slugs[i].clone()).collect(),
+    )];
+
+    for i in 0..pages {
+        let parent_slug = parent_index(i).map_or(prefix.to_string(), |p| slugs[p].clone());
+        let mut content = format!("# {}\n\n{}\n\n", titles[i], paragraph(rng));
+        for heading in ["Overview", "Details"] {
+            content.push_str(&format!("## {heading}\n\n{}\n\n", paragraph(rng)));
+        }
+        for _ in 0..rng.random_range(3..7) {
+            content.push_str(&format!("- {}\n", sentence(rng)));
+        }
+        content.push_str(&format!(
+            "\n```yaml\nservice: {}\nreplicas: {}\ntimeout_ms: {}\n```\n\n## Related\n\n- [Parent](/docs/{parent_slug})\n",
+            slugs[i].rsplit('/').next().unwrap_or_default(),
+            rng.random_range(1..10),
+            rng.random_range(100..5000),
+        ));
+        let mut links_out = vec![parent_slug.clone()];
+        for &target in links[i]
+            .iter()
+            .filter(|&&t| t != i && slugs[t] != parent_slug)
+        {
+            content.push_str(&format!(
+                "- [{}](/docs/{})\n",
+                titles[target], slugs[target]
+            ));
+            links_out.push(slugs[target].clone());
+        }
+
+        let access_level = if rng.random_bool(0.7) {
+            "public"
+        } else {
+            "internal"
+        };
+        let tags = vec![
+            slugs[i]
+                .trim_start_matches(prefix)
+                .split(['/', '-'])
+                .find(|s| !s.is_empty())
+                .unwrap_or("load")
+                .to_string(),
+            ASPECTS.choose(rng).unwrap().to_string(),
+        ];
+        docs.push(fake_doc(
+            layout,
+            &slugs[i],
+            &titles[i],
+            Some(parent_slug),
+            (i % FAN_OUT) as u32,
+            access_level,
+            tags,
+            content,
+            links_out,
+        ));
+    }
+
+    let mut backlinks: HashMap<String, Vec<String>> = HashMap::new();
+    for fake in &docs {
+        for target in &fake.doc.links_out {
+            backlinks
+                .entry(target.clone())
+                .or_default()
+                .push(fake.doc.slug.clone());
+        }
+    }
+    for fake in &mut docs {
+        fake.doc.backlinks = backlinks.remove(&fake.doc.slug).unwrap_or_default();
+    }
+    docs
+}
+
+#[allow(clippy::too_many_arguments)]
+fn fake_doc(
+    layout: KeyLayout,
+    slug: &str,
+    title: &str,
+    parent_slug: Option<String>,
+    order: u32,
+    access_level: &str,
+    tags: Vec<String>,
+    content: String,
+    links_out: Vec<String>,
+) -> FakeDoc {
+    let summary = content
+        .lines()
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.chars().take(160).collect::<String>());
+    let doc = Document {
+        slug: slug.to_string(),
+        title: title.to_string(),
+        summary: summary.clone(),
+        s3_key: layout.document_key(slug),
+        access_level: access_level.to_string(),
+        service_owner: "load-test".to_string(),
+        last_updated: Utc::now(),
+        tags: tags.clone(),
+        links_out,
+        parent_slug: parent_slug.clone(),
+        order,
+        content_hash: Some(content_hash(&content)),
+        metadata_hash: Some(compute_metadata_hash(MetadataHashInput {
+            title,
+            summary: summary.as_deref(),
+            access_level,
+            service_owner: "load-test",
+            tags: &tags,
+            parent_slug: parent_slug.as_deref(),
+            order,
+            is_hidden: false,
+            noindex: false,
+            edit_access_level: None,
+            relations: &Default::default(),
+        })),
+        last_updated_by: Some(LOAD_AUTHOR.to_string()),
+        contributors: vec![LOAD_AUTHOR.to_string()],
+        content_size: content.len() as u64,
+        preview: crate::api::ingest::ContentFormat::Markdown.preview(&content),
+        ..Default::default()
+    };
+    FakeDoc { doc, content }
+}
+
+fn openapi_spec(title: &str, version: &str, rng: &mut StdRng) -> serde_json::Value {
+    let mut paths = serde_json::Map::new();
+    for _ in 0..rng.random_range(3..20) {
+        let resource = format!("{}s", NOUNS.choose(rng).unwrap());
+        let mut item = serde_json::Map::new();
+        for method in ["get", "post", "put", "delete"] {
+            if method == "get" || rng.random_bool(0.5) {
+                item.insert(
+                    method.to_string(),
+                    serde_json::json!({
+                        "summary": sentence(rng),
+                        "responses": { "200": { "description": "OK" } },
+                    }),
+                );
+            }
+        }
+        paths.insert(format!("/{resource}/{{id}}"), item.into());
+    }
+    serde_json::json!({
+        "openapi": "3.0.3",
+        "info": { "title": title, "version": version },
+        "paths": paths,
+    })
+}
+
+fn asyncapi_spec(title: &str, version: &str, rng: &mut StdRng) -> serde_json::Value {
+    let mut channels = serde_json::Map::new();
+    for _ in 0..rng.random_range(2..12) {
+        let topic = format!(
+            "{}.{}",
+            TOPICS.choose(rng).unwrap(),
+            NOUNS.choose(rng).unwrap()
+        );
+        let action = if rng.random_bool(0.5) {
+            "publish"
+        } else {
+            "subscribe"
+        };
+        channels.insert(
+            topic,
+            serde_json::json!({ action: { "summary": sentence(rng) } }),
+        );
+    }
+    serde_json::json!({
+        "asyncapi": "2.6.0",
+        "info": { "title": title, "version": version },
+        "channels": channels,
+    })
+}
+
+/// `count` schemas, each with its versions' `(key, content)`.
+fn generate_schemas(
+    count: usize,
+    prefix: &str,
+    rng: &mut StdRng,
+) -> Vec<(Schema, Vec<(String, String)>)> {
+    let mut schemas = Vec::with_capacity(count);
+    for i in 0..count {
+        let topic = *TOPICS.choose(rng).unwrap();
+        let (schema_type, suffix) = if rng.random_bool(0.8) {
+            ("openapi", "api")
+        } else {
+            ("asyncapi", "events")
+        };
+        let name = format!("{prefix}-{topic}-{suffix}-{i}");
+        let title = format!("{} {}", title_case(topic), title_case(suffix));
+
+        let version_count = rng.random_range(1..4);
+        let mut versions = vec![];
+        let mut objects = vec![];
+        for v in 0..version_count {
+            let version = format!("{}.0.0", v + 1);
+            let status = if v + 1 == version_count {
+                "stable"
+            } else {
+                "deprecated"
+            };
+            let spec = match schema_type {
+                "openapi" => openapi_spec(&title, &version, rng),
+                _ => asyncapi_spec(&title, &version, rng),
+            };
+            let content = serde_json::to_string_pretty(&spec).unwrap_or_default();
+            let s3_key = format!("schemas/{name}/{version}.json");
+            versions.push(SchemaVersion {
+                version,
+                s3_key: s3_key.clone(),
+                status: status.to_string(),
+                access_level: "public".to_string(),
+                content_hash: Some(crate::api::schemas::compute_schema_content_hash(&content)),
+                metadata_hash: Some(crate::api::schemas::compute_schema_metadata_hash(
+                    status, "public",
+                )),
+                is_archived: false,
+                endpoints: crate::api::schemas::extract_schema_endpoints(schema_type, &content),
+            });
+            objects.push((s3_key, content));
+        }
+
+        schemas.push((
+            Schema {
+                name,
+                schema_type: schema_type.to_string(),
+                service_owner: "load-test".to_string(),
+                tags: vec![topic.to_string(), "load-test".to_string()],
+                description: Some(sentence(rng)),
+                contact: None,
+                repository_url: None,
+                owner_access_level: None,
+                updated_at: Some(Utc::now()),
+                versions,
+            },
+            objects,
+        ));
+    }
+    schemas
+}
+
+/// Generate the data set of `options` and write it through `ctx`.
+pub async fn seed(
+    ctx: &DemoSeedContext<'_>,
+    options: &SeedOptions,
+) -> Result<SeedReport, AppError> {
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let docs = generate_documents(
+        options.docs,
+        &options.prefix,
+        ctx.storage.key_layout(),
+        &mut rng,
+    );
+    let schemas = generate_schemas(options.schemas, &options.prefix, &mut rng);
+
+    let mut report = SeedReport::default();
+    let mut written = futures::stream::iter(docs)
+        .map(|fake| async move {
+            ctx.storage
+                .put_object(&fake.doc.s3_key, fake.content.as_bytes().to_vec())
+                .await?;
+            if let Some(search) = ctx.search {
+                let search_doc =
+                    crate::search::client::build_search_document(&fake.doc, &fake.content);
+                if let Err(e) = search.index_document(&search_doc).await {
+                    tracing::warn!("Failed to index '{}': {e}", fake.doc.slug);
+                }
+            }
+            ctx.repo.create_or_update(fake.doc).await
+        })
+        .buffer_unordered(CONCURRENCY);
+    while written.try_next().await?.is_some() {
+        report.docs += 1;
+        if report.docs % 1000 == 0 {
+            tracing::info!("Seeded {} of {} documents", report.docs, options.docs);
+        }
+    }
+
+    let mut written = futures::stream::iter(schemas)
+        .map(|(schema, objects)| async move {
+            let versions = objects.len();
+            for (key, content) in objects {
+                ctx.storage.put_object(&key, content.into_bytes()).await?;
+            }
+            ctx.schema_repo.create_or_update(schema).await?;
+            Ok::<_, AppError>(versions)
+        })
+        .buffer_unordered(CONCURRENCY);
+    while let Some(versions) = written.try_next().await? {
+        report.schemas += 1;
+        report.schema_versions += versions;
+    }
+    Ok(report)
+}
+
+/// Run the generator against the configured MongoDB, bucket and
+/// Meilisearch and print what was written. Returns whether it completed.
+pub async fn run(options: SeedOptions, index: bool) -> bool {
+    use crate::db::repository::MongoDocumentRepository;
+    use crate::db::schema_repository::MongoSchemaRepository;
+    use crate::search::client::MeilisearchService;
+    use crate::storage::client::RoutedStorage;
+
+    let config = match crate::config::AppConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {e}");
+            return false;
+        }
+    };
+
+    let db = match mongodb::Client::with_uri_str(config.database.connection_uri()).await {
+        Ok(client) => client.database(&config.database.name),
+        Err(e) => {
+            eprintln!("Cannot connect to MongoDB: {e}");
+            return false;
+        }
+    };
+    let storage = match RoutedStorage::from_app_config(&config.storage).await {
+        Ok(storage) => storage,
+        Err(e) => {
+            eprintln!("Cannot create the S3 client: {e}");
+            return false;
+        }
+    };
+    let search = if index {
+        match MeilisearchService::from_app_config(&config.search) {
+            Ok(search) => Some(search),
+            Err(e) => {
+                eprintln!("Search indexing skipped: {e}");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let repo = MongoDocumentRepository::new(&db);
+    let schema_repo = MongoSchemaRepository::new(&db);
+    let ctx = DemoSeedContext {
+        repo: &repo,
+        storage: &storage,
+        search: search
+            .as_ref()
+            .map(|s| s as &dyn crate::search::client::SearchService),
+        schema_repo: &schema_repo,
+    };
+    match seed(&ctx, &options).await {
+        Ok(report) => {
+            println!(
+                "Seeded {} document(s) under /docs/{} and {} schema(s) with {} version(s)",
+                report.docs, options.prefix, report.schemas, report.schema_versions
+            );
+            true
+        }
+        Err(e) => {
+            eprintln!("Seeding failed: {e}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::repository::DocumentRepository;
+    use crate::db::schema_repository::SchemaRepository;
+    use crate::storage::client::StorageClient;
+    use crate::testing::{InMemoryDocumentRepository, InMemorySchemaRepository, InMemoryStorage};
+
+    #[test]
+    fn test_generated_documents_form_a_tree() {
+        let docs = generate_documents(
+            500,
+            "load",
+            KeyLayout::default(),
+            &mut StdRng::seed_from_u64(7),
+        );
+        assert_eq!(docs.len(), 500);
+
+        let slugs: std::collections::HashSet<&str> =
+            docs.iter().map(|d| d.doc.slug.as_str()).collect();
+        assert_eq!(slugs.len(), docs.len());
+        for fake in &docs[1..] {
+            let parent = fake.doc.parent_slug.as_deref().unwrap();
+            assert!(slugs.contains(parent));
+            assert!(fake.doc.slug.starts_with(&format!("{parent}/")));
+            for link in &fake.doc.links_out {
+                assert!(slugs.contains(link.as_str()), "unknown link {link}");
+            }
+        }
+        // Deep enough to exercise nested navigation
+        assert!(docs.iter().any(|d| d.doc.slug.matches('/').count() >= 3));
+    }
+
+    #[test]
+    fn test_generation_is_reproducible() {
+        let a = generate_documents(
+            50,
+            "load",
+            KeyLayout::default(),
+            &mut StdRng::seed_from_u64(1),
+        );
+        let b = generate_documents(
+            50,
+            "load",
+            KeyLayout::default(),
+            &mut StdRng::seed_from_u64(1),
+        );
+        assert!(a
+            .iter()
+            .zip(&b)
+            .all(|(a, b)| a.doc.slug == b.doc.slug && a.content == b.content));
+    }
+
+    #[tokio::test]
+    async fn test_seed_writes_documents_and_schemas() {
+        let repo = InMemoryDocumentRepository::new();
+        let schema_repo = InMemorySchemaRepository::new();
+        let storage = InMemoryStorage::new();
+        let ctx = DemoSeedContext {
+            repo: &repo,
+            storage: &storage,
+            search: None,
+            schema_repo: &schema_repo,
+        };
+        let options = SeedOptions {
+            docs: 120,
+            schemas: 10,
+            prefix: "perf".to_string(),
+            seed: 42,
+        };
+
+        let report = seed(&ctx, &options).await.unwrap();
+        assert_eq!(report.docs, 120);
+        assert_eq!(report.schemas, 10);
+        assert_eq!(repo.list_all().await.unwrap().len(), 120);
+
+        let root = repo.find_by_slug("perf").await.unwrap().unwrap();
+        assert!(storage.get_object(&root.s3_key).await.unwrap().is_some());
+        let schemas = schema_repo.list_all().await.unwrap();
+        assert_eq!(schemas.len(), 10);
+        assert!(schemas
+            .iter()
+            .all(|s| s.versions.iter().all(|v| !v.endpoints.is_empty())));
+    }
+}
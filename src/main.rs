@@ -17,39 +17,54 @@ async fn mjs_content_type(
     response
 }
 
-/// Middleware that sets `Cache-Control` headers for static JS/CSS assets under `/js/`.
-/// Assets requested with a `?v=` version query get a 1-year immutable cache.
-/// Assets without a version query get a 1-hour cache (safe without fingerprinting).
+/// Lekton server. Without a subcommand, starts serving.
 #[cfg(feature = "ssr")]
-async fn static_cache_headers(
-    request: axum::extract::Request,
-    next: axum::middleware::Next,
-) -> axum::response::Response {
-    let path = request.uri().path().to_owned();
-    let has_version = request.uri().query().is_some_and(|q| q.contains("v="));
-
-    let is_js_asset = path.starts_with("/js/")
-        && (path.ends_with(".js") || path.ends_with(".mjs") || path.ends_with(".css"));
-
-    let mut response = next.run(request).await;
-
-    if is_js_asset {
-        let cache_header = if has_version {
-            axum::http::HeaderValue::from_static("public, max-age=31536000, immutable")
-        } else {
-            axum::http::HeaderValue::from_static("public, max-age=3600")
-        };
-        response
-            .headers_mut()
-            .insert(axum::http::header::CACHE_CONTROL, cache_header);
-    }
+#[derive(clap::Parser)]
+#[command(name = "lekton", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
 
-    response
+#[cfg(feature = "ssr")]
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Validate the configuration, print it with secrets redacted and check
+    /// connectivity to MongoDB, S3, Meilisearch and the OAuth2/OIDC provider
+    CheckConfig,
+    /// Move document and prompt objects to the keys of the configured
+    /// `storage.key_layout`
+    MigrateStorageKeys {
+        /// Only print what would be moved
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Generate a large, hierarchical data set of documents and schemas for
+    /// performance work
+    #[cfg(feature = "load-test")]
+    Seed {
+        /// Number of documents, including the root page
+        #[arg(long, default_value_t = 1000)]
+        docs: usize,
+        /// Number of schemas, each with one to three versions
+        #[arg(long, default_value_t = 50)]
+        schemas: usize,
+        /// Slug of the root page, and prefix of every generated slug and schema name
+        #[arg(long, default_value = "load")]
+        prefix: String,
+        /// Seed of the random generator; the same seed generates the same data
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Don't index the documents in Meilisearch
+        #[arg(long)]
+        no_index: bool,
+    },
 }
 
 #[cfg(feature = "ssr")]
 #[tokio::main]
 async fn main() {
+    use axum::http::HeaderName;
     use axum::middleware;
     use axum::Router;
     use lekton::api;
@@ -58,24 +73,41 @@ async fn main() {
     use lekton::auth::token_service::TokenService;
     use lekton::db::access_level_repository::MongoAccessLevelRepository;
     use lekton::db::asset_repository::MongoAssetRepository;
+    use lekton::db::bundle_repository::MongoBundleRepository;
+    use lekton::db::changelog_repository::MongoChangelogRepository;
+    use lekton::db::contract_test_repository::MongoContractTestRepository;
     use lekton::db::document_version_repository::MongoDocumentVersionRepository;
     use lekton::db::documentation_feedback_repository::MongoDocumentationFeedbackRepository;
+    use lekton::db::edit_lock_repository::MongoEditLockRepository;
+    use lekton::db::glossary_repository::MongoGlossaryRepository;
+    use lekton::db::job_run_repository::MongoJobRunRepository;
     use lekton::db::navigation_order_repository::MongoNavigationOrderRepository;
+    use lekton::db::notification_repository::MongoNotificationRepository;
+    use lekton::db::pending_ingest_repository::MongoPendingIngestRepository;
+    use lekton::db::project_repository::MongoProjectRepository;
     use lekton::db::prompt_repository::MongoPromptRepository;
     use lekton::db::prompt_version_repository::MongoPromptVersionRepository;
     use lekton::db::repository::MongoDocumentRepository;
     use lekton::db::schema_repository::MongoSchemaRepository;
     use lekton::db::service_token_repository::MongoServiceTokenRepository;
     use lekton::db::settings_repository::MongoSettingsRepository;
+    use lekton::db::spelling_dictionary_repository::MongoSpellingDictionaryRepository;
     use lekton::db::user_prompt_preference_repository::MongoUserPromptPreferenceRepository;
     use lekton::db::user_repository::MongoUserRepository;
+    use lekton::db::watch_repository::MongoWatchRepository;
+    use lekton::resilience::{
+        ResiliencePolicy, ResilientAssetRepository, ResilientDocumentRepository,
+        ResilientDocumentVersionRepository, ResilientSchemaRepository, ResilientSearch,
+        ResilientSettingsRepository, ResilientStorage,
+    };
     use lekton::search::client::{MeilisearchService, SearchService as _};
-    use lekton::storage::client::S3StorageClient;
+    use lekton::storage::client::RoutedStorage;
     use leptos::prelude::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
     use std::net::SocketAddr;
     use std::sync::Arc;
     use std::time::Duration;
+    use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
     use tower_http::services::ServeDir;
 
     // Install a default rustls CryptoProvider before any TLS connections are made.
@@ -85,15 +117,55 @@ async fn main() {
         .install_default()
         .expect("Failed to install rustls CryptoProvider");
 
-    // Load configuration first — fast-fail on bad config before anything else starts.
-    let config =
-        lekton::config::AppConfig::load().expect("Failed to load application configuration");
+    let dotenv_path = lekton::config::load_dotenv();
+    let cli = <Cli as clap::Parser>::parse();
+    match cli.command {
+        Some(Command::CheckConfig) => {
+            let ok = lekton::config_check::run(dotenv_path.as_deref()).await;
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+        Some(Command::MigrateStorageKeys { dry_run }) => {
+            let ok = lekton::storage::migrate::run(dry_run).await;
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+        #[cfg(feature = "load-test")]
+        Some(Command::Seed {
+            docs,
+            schemas,
+            prefix,
+            seed,
+            no_index,
+        }) => {
+            let options = lekton::load_seeder::SeedOptions {
+                docs,
+                schemas,
+                prefix,
+                seed,
+            };
+            let ok = lekton::load_seeder::run(options, !no_index).await;
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+        None => {}
+    }
 
-    if config.rag.is_enabled() {
-        config.rag.validate().expect("Invalid RAG configuration");
-        if config.rag.hybrid_search_enabled && config.search.url.is_empty() {
-            panic!("rag.hybrid_search_enabled = true requires search.url to be set");
+    // Load configuration first — fast-fail on bad config before anything else
+    // starts, reporting every problem at once.
+    let config = match lekton::config::AppConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {e}");
+            eprintln!("Run `lekton check-config` for a full report.");
+            std::process::exit(1);
+        }
+    };
+    let problems = lekton::config_check::validate(&config);
+    if !problems.is_empty() {
+        eprintln!("Invalid configuration:");
+        for problem in &problems {
+            eprintln!("  - {problem}");
         }
+        eprintln!("Run `lekton check-config` for a full report.");
+        std::process::exit(1);
     }
 
     // Debug config loading
@@ -108,13 +180,8 @@ async fn main() {
     use std::io::Write;
     std::io::stdout().flush().ok();
 
-    // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| config.server.log_filter.as_str().into()),
-        )
-        .init();
+    // Initialize tracing (and OTLP export when configured)
+    let _telemetry = lekton::telemetry::init(&config.server.log_filter, &config.telemetry);
 
     tracing::info!("Starting Lekton server...");
 
@@ -122,19 +189,21 @@ async fn main() {
     let demo_mode = config.auth.demo_mode;
 
     if demo_mode {
-        if config.auth.jwt_secret.is_some() && !config.auth.allow_demo_in_production {
-            panic!(
-                "auth.demo_mode is enabled but auth.jwt_secret is set, which suggests a \
-                 production environment. Set auth.allow_demo_in_production = true (or \
-                 LKN__AUTH__ALLOW_DEMO_IN_PRODUCTION=true) to override this safety check."
-            );
-        }
-
         tracing::warn!(
             "⚠️  DEMO MODE ENABLED — built-in credentials are active. Do NOT use in production!"
         );
     }
 
+    // Local accounts are served by the regular (JWT) auth routes, not demo auth
+    let local_accounts = config.auth.local_accounts && !demo_mode;
+    if config.auth.local_accounts && demo_mode {
+        tracing::warn!("auth.local_accounts is ignored in demo mode");
+    }
+    let ldap_auth = config.auth.ldap.is_enabled() && !demo_mode;
+    if config.auth.ldap.is_enabled() && demo_mode {
+        tracing::warn!("auth.ldap is ignored in demo mode");
+    }
+
     // Load Leptos options from Cargo.toml metadata
     let conf =
         get_configuration(None).expect("Failed to load Leptos configuration from Cargo.toml");
@@ -144,39 +213,66 @@ async fn main() {
 
     // Compute asset fingerprints for cache-busting versioned URLs.
     lekton::static_assets::init(&site_root);
+    let spec_viewer_assets =
+        lekton::static_assets::spec_viewer_assets(&config.server.spec_viewer_assets);
+    if spec_viewer_assets.is_none() {
+        tracing::info!("Schema viewer bundles not available — using the built-in viewer");
+    }
+
+    // Install the Markdown rendering pipeline before anything is rendered.
+    let render_pipeline = lekton::rendering::pipeline::RenderPipeline::standard();
+    for name in config.lint.unknown_rules() {
+        tracing::warn!("Unknown lint rule '{name}' in lint.rules — ignored");
+    }
 
     // Connect to MongoDB
-    // Inject credentials into the URI if provided separately.
-    // Using the `url` crate for correct percent-encoding and authority handling.
-    let mongo_uri = match (&config.database.username, &config.database.password) {
-        (Some(user), Some(pass)) if !user.is_empty() => {
-            match url::Url::parse(&config.database.uri) {
-                Ok(mut parsed) => {
-                    let _ = parsed.set_username(user);
-                    let _ = parsed.set_password(Some(pass));
-                    parsed.to_string()
-                }
-                Err(e) => {
-                    tracing::warn!(error = %e, "Could not parse MongoDB URI to inject credentials; using URI as-is");
-                    config.database.uri.clone()
-                }
-            }
-        }
-        _ => config.database.uri.clone(),
-    };
+    let mongo_uri = config.database.connection_uri();
 
-    let mongo_client = mongodb::Client::with_uri_str(&mongo_uri)
+    // Bound server selection and connection time so an unreachable MongoDB
+    // fails requests quickly; values set in the URI take precedence.
+    let mut mongo_options = mongodb::options::ClientOptions::parse(&mongo_uri)
         .await
-        .expect("Failed to connect to MongoDB");
+        .expect("Failed to parse MongoDB URI");
+    let mongo_timeout = std::time::Duration::from_millis(config.resilience.timeout_ms);
+    mongo_options
+        .server_selection_timeout
+        .get_or_insert(mongo_timeout);
+    mongo_options.connect_timeout.get_or_insert(mongo_timeout);
+    let mongo_client =
+        mongodb::Client::with_options(mongo_options).expect("Failed to connect to MongoDB");
     let mongo_db = mongo_client.database(&config.database.name);
+    // The repositories on the ingest and page render paths share one breaker.
+    let mongo_policy = ResiliencePolicy::new("mongo", &config.resilience);
+    let mut circuit_breakers = vec![mongo_policy.breaker()];
     let document_repo: Arc<dyn lekton::db::repository::DocumentRepository> =
-        Arc::new(MongoDocumentRepository::new(&mongo_db));
+        Arc::new(ResilientDocumentRepository::new(
+            Arc::new(MongoDocumentRepository::new(&mongo_db)),
+            mongo_policy.clone(),
+        ));
     let schema_repo: Arc<dyn lekton::db::schema_repository::SchemaRepository> =
-        Arc::new(MongoSchemaRepository::new(&mongo_db));
+        Arc::new(ResilientSchemaRepository::new(
+            Arc::new(MongoSchemaRepository::new(&mongo_db)),
+            mongo_policy.clone(),
+        ));
     let settings_repo: Arc<dyn lekton::db::settings_repository::SettingsRepository> =
-        Arc::new(MongoSettingsRepository::new(&mongo_db));
+        Arc::new(ResilientSettingsRepository::new(
+            Arc::new(MongoSettingsRepository::new(&mongo_db)),
+            mongo_policy.clone(),
+        ));
+    // `rendering.embeds` seeds the embed settings; admins edit them afterwards.
+    if let Err(e) = lekton::server::embeds::seed_embed_settings(
+        settings_repo.as_ref(),
+        &config.rendering.embeds,
+    )
+    .await
+    {
+        tracing::warn!("Failed to seed embed settings from rendering.embeds: {e}");
+    }
     let asset_repo: Arc<dyn lekton::db::asset_repository::AssetRepository> =
-        Arc::new(MongoAssetRepository::new(&mongo_db));
+        Arc::new(ResilientAssetRepository::new(
+            Arc::new(MongoAssetRepository::new(&mongo_db)),
+            mongo_policy.clone(),
+        ));
     let user_repo: Arc<dyn lekton::db::user_repository::UserRepository> =
         Arc::new(MongoUserRepository::new(&mongo_db));
     let access_level_repo: Arc<dyn lekton::db::access_level_repository::AccessLevelRepository> =
@@ -185,7 +281,10 @@ async fn main() {
         Arc::new(MongoServiceTokenRepository::new(&mongo_db));
     let document_version_repo: Arc<
         dyn lekton::db::document_version_repository::DocumentVersionRepository,
-    > = Arc::new(MongoDocumentVersionRepository::new(&mongo_db));
+    > = Arc::new(ResilientDocumentVersionRepository::new(
+        Arc::new(MongoDocumentVersionRepository::new(&mongo_db)),
+        mongo_policy,
+    ));
     let prompt_repo: Arc<dyn lekton::db::prompt_repository::PromptRepository> =
         Arc::new(MongoPromptRepository::new(&mongo_db));
     let prompt_version_repo: Arc<
@@ -220,6 +319,68 @@ async fn main() {
     let documentation_feedback_repo: Arc<
         dyn lekton::db::documentation_feedback_repository::DocumentationFeedbackRepository,
     > = Arc::new(documentation_feedback_repo_impl);
+    let glossary_repo: Arc<dyn lekton::db::glossary_repository::GlossaryRepository> =
+        Arc::new(MongoGlossaryRepository::new(&mongo_db));
+    let spelling_dictionary_repo: Arc<
+        dyn lekton::db::spelling_dictionary_repository::SpellingDictionaryRepository,
+    > = Arc::new(MongoSpellingDictionaryRepository::new(&mongo_db));
+    let changelog_repo: Arc<dyn lekton::db::changelog_repository::ChangelogRepository> =
+        Arc::new(MongoChangelogRepository::new(&mongo_db));
+    let contract_test_repo: Arc<dyn lekton::db::contract_test_repository::ContractTestRepository> =
+        Arc::new(MongoContractTestRepository::new(&mongo_db));
+    let bundle_repo: Arc<dyn lekton::db::bundle_repository::BundleRepository> =
+        Arc::new(MongoBundleRepository::new(&mongo_db));
+    let project_repo: Arc<dyn lekton::db::project_repository::ProjectRepository> =
+        Arc::new(MongoProjectRepository::new(&mongo_db));
+    let edit_lock_repo: Arc<dyn lekton::db::edit_lock_repository::EditLockRepository> =
+        Arc::new(MongoEditLockRepository::new(&mongo_db));
+    let pending_ingest_repo: Arc<
+        dyn lekton::db::pending_ingest_repository::PendingIngestRepository,
+    > = Arc::new(MongoPendingIngestRepository::new(&mongo_db));
+    let job_run_repo: Arc<dyn lekton::db::job_run_repository::JobRunRepository> =
+        Arc::new(MongoJobRunRepository::new(&mongo_db));
+    let watch_repo: Arc<dyn lekton::db::watch_repository::WatchRepository> =
+        Arc::new(MongoWatchRepository::new(&mongo_db));
+    let notification_repo: Arc<dyn lekton::db::notification_repository::NotificationRepository> =
+        Arc::new(MongoNotificationRepository::new(&mongo_db));
+    let asset_download_repo_impl =
+        lekton::db::asset_download_repository::MongoAssetDownloadRepository::new(&mongo_db);
+    if let Err(e) = asset_download_repo_impl.ensure_indexes().await {
+        tracing::warn!("Failed to create asset download indexes: {e}");
+    }
+    let asset_download_repo: Arc<
+        dyn lekton::db::asset_download_repository::AssetDownloadRepository,
+    > = Arc::new(asset_download_repo_impl);
+    let quarantine_repo: Arc<dyn lekton::db::quarantine_repository::QuarantineRepository> =
+        Arc::new(lekton::db::quarantine_repository::MongoQuarantineRepository::new(&mongo_db));
+    let share_link_repo_impl =
+        lekton::db::share_link_repository::MongoShareLinkRepository::new(&mongo_db);
+    if let Err(e) = share_link_repo_impl.ensure_indexes().await {
+        tracing::warn!("Failed to create share link indexes: {e}");
+    }
+    let share_link_repo: Arc<dyn lekton::db::share_link_repository::ShareLinkRepository> =
+        Arc::new(share_link_repo_impl);
+    let collection_repo_impl =
+        lekton::db::collection_repository::MongoCollectionRepository::new(&mongo_db);
+    if let Err(e) = collection_repo_impl.ensure_indexes().await {
+        tracing::warn!("Failed to create collection indexes: {e}");
+    }
+    let collection_repo: Arc<dyn lekton::db::collection_repository::CollectionRepository> =
+        Arc::new(collection_repo_impl);
+    let doc_rating_repo_impl =
+        lekton::db::doc_rating_repository::MongoDocRatingRepository::new(&mongo_db);
+    if let Err(e) = doc_rating_repo_impl.ensure_indexes().await {
+        tracing::warn!("Failed to create doc rating indexes: {e}");
+    }
+    let doc_rating_repo: Arc<dyn lekton::db::doc_rating_repository::DocRatingRepository> =
+        Arc::new(doc_rating_repo_impl);
+    let upload_usage_repo_impl =
+        lekton::db::upload_usage_repository::MongoUploadUsageRepository::new(&mongo_db);
+    if let Err(e) = upload_usage_repo_impl.ensure_indexes().await {
+        tracing::warn!("Failed to create upload usage indexes: {e}");
+    }
+    let upload_usage_repo: Arc<dyn lekton::db::upload_usage_repository::UploadUsageRepository> =
+        Arc::new(upload_usage_repo_impl);
     let embedding_cache_repo: Option<
         Arc<dyn lekton::db::embedding_cache_repository::EmbeddingCacheRepository>,
     > = if config.rag.is_enabled() {
@@ -244,26 +405,67 @@ async fn main() {
         tracing::warn!("Failed to seed default access levels: {e}");
     }
 
-    tracing::info!("Connected to MongoDB at {}", mongo_uri);
-
-    // Connect to S3
-    let storage_client: Arc<dyn lekton::storage::client::StorageClient> = Arc::new(
-        S3StorageClient::from_app_config(&config.storage)
+    // Bootstrap the first local administrator
+    if local_accounts {
+        if let (Some(email), Some(password)) = (
+            config.auth.local_admin_email.as_deref(),
+            config.auth.local_admin_password.as_deref(),
+        ) {
+            match lekton::auth::local_accounts::ensure_bootstrap_admin(
+                user_repo.as_ref(),
+                email,
+                password,
+            )
             .await
-            .expect("Failed to initialize S3 client"),
+            {
+                Ok(true) => tracing::info!("Created local administrator {email}"),
+                Ok(false) => {}
+                Err(e) => tracing::error!("Failed to create local administrator {email}: {e}"),
+            }
+        }
+    }
+
+    tracing::info!(
+        "Connected to MongoDB at {}",
+        lekton::config_check::redact_url(&mongo_uri)
     );
 
+    // Connect to S3
+    let storage_policy = ResiliencePolicy::new("storage", &config.resilience);
+    circuit_breakers.push(storage_policy.breaker());
+    let storage_client: Arc<dyn lekton::storage::client::StorageClient> =
+        Arc::new(ResilientStorage::new(
+            Arc::new(
+                RoutedStorage::from_app_config(&config.storage)
+                    .await
+                    .expect("Failed to initialize S3 client"),
+            ),
+            storage_policy,
+        ));
+
     tracing::info!("S3 storage client initialized");
 
     // Initialize Meilisearch (optional — app works without it)
     let search_service: Option<Arc<dyn lekton::search::client::SearchService>> =
         match MeilisearchService::from_app_config(&config.search) {
             Ok(service) => {
-                if let Err(e) = service.configure_index().await {
+                let search_settings = match settings_repo.get_settings().await {
+                    Ok(settings) => settings.search,
+                    Err(e) => {
+                        tracing::warn!("Failed to load search settings: {e} — using defaults");
+                        Default::default()
+                    }
+                };
+                if let Err(e) = service.configure_index(&search_settings).await {
                     tracing::warn!("Failed to configure Meilisearch index: {e}");
                 }
                 tracing::info!("Meilisearch search service initialized");
-                Some(Arc::new(service))
+                let search_policy = ResiliencePolicy::new("search", &config.resilience);
+                circuit_breakers.push(search_policy.breaker());
+                Some(Arc::new(ResilientSearch::new(
+                    Arc::new(service),
+                    search_policy,
+                )))
             }
             Err(e) => {
                 tracing::warn!("Meilisearch not available: {e} — search will be disabled");
@@ -277,9 +479,44 @@ async fn main() {
     } else {
         None
     };
+    if let (Some(search), Some(reindex)) = (&search_service, &search_reindex_state) {
+        if config.search.reconcile_on_startup {
+            reindex
+                .is_running
+                .store(true, std::sync::atomic::Ordering::Release);
+            tokio::spawn(lekton::search::reindex::run_reconciliation(
+                reindex.clone(),
+                document_repo.clone(),
+                storage_client.clone(),
+                search.clone(),
+            ));
+        }
+    }
+    // Seed demo content into an empty database
+    if demo_mode {
+        let seeded = lekton::demo_seeder::seed_if_empty(&lekton::demo_seeder::DemoSeedContext {
+            repo: document_repo.as_ref(),
+            storage: storage_client.as_ref(),
+            search: search_service.as_deref(),
+            schema_repo: schema_repo.as_ref(),
+        })
+        .await;
+        match seeded {
+            Ok(true) => tracing::info!("Seeded demo documents and schemas"),
+            Ok(false) => {}
+            Err(e) => tracing::warn!("Failed to seed demo content: {e}"),
+        }
+    }
     let schema_endpoint_reindex_state =
         Arc::new(lekton::schema::reindex::SchemaEndpointReindexState::default());
 
+    // Cross-replica activity relay and job queue (Redis, when configured)
+    let coordination = lekton::coordination::init(
+        &config.coordination,
+        lekton::jobs::JobRunner::new(access_level_repo.clone(), user_repo.clone()),
+    )
+    .await;
+
     // Service token for API authentication
     let service_token = match config.auth.service_token.as_deref() {
         Some(token) if !token.is_empty() => token.to_string(),
@@ -287,21 +524,20 @@ async fn main() {
             tracing::warn!("auth.service_token not set — using insecure default (demo mode only)");
             "dev-token".to_string()
         }
-        _ => {
-            panic!("auth.service_token is required in production (set LKN__AUTH__SERVICE_TOKEN)");
-        }
+        _ => unreachable!("auth.service_token is checked by config_check::validate"),
     };
 
     // JWT token service
     let token_service = Arc::new(match TokenService::from_app_config(&config.auth) {
         Ok(ts) => ts,
         Err(_) if demo_mode => {
-            tracing::warn!("auth.jwt_secret not set — using insecure dev key (demo mode only)");
-            TokenService::new("dev-insecure-secret-change-in-production!!", 900, 30)
-        }
-        Err(e) => {
-            panic!("auth.jwt_secret is required in production: {e}");
+            // A random key keeps demo sessions unforgeable; they end on restart
+            tracing::warn!(
+                "auth.jwt_secret not set — using a random per-process key (demo mode only)"
+            );
+            TokenService::new(&TokenService::generate_opaque_token(), 900, 30)
         }
+        Err(e) => unreachable!("auth.jwt_secret is checked by config_check::validate: {e}"),
     });
 
     // OAuth2 / OIDC auth provider (optional — server starts without auth if not configured)
@@ -421,6 +657,88 @@ async fn main() {
         (None, None, None, None)
     };
 
+    // Blend semantic similarity over the RAG chunk embeddings into documentation search.
+    let search_service = match (search_service, &embedding_service, &vector_store) {
+        (Some(text), Some(embedding), Some(vectorstore)) if config.rag.semantic_search_enabled => {
+            tracing::info!("Semantic search enabled");
+            Some(Arc::new(lekton::search::hybrid::HybridSearchService::new(
+                text,
+                embedding.clone(),
+                vectorstore.clone(),
+            ))
+                as Arc<dyn lekton::search::client::SearchService>)
+        }
+        (search_service, _, _) => {
+            if config.rag.semantic_search_enabled {
+                tracing::warn!(
+                    "semantic_search_enabled = true but search or RAG is not available — \
+                     using full-text search only"
+                );
+            }
+            search_service
+        }
+    };
+
+    // Notification emails
+    let email = match lekton::email::EmailNotifier::from_config(&config.email) {
+        Ok(Some(notifier)) => {
+            tracing::info!(smtp_host = %config.email.smtp_host, "Email notifications enabled");
+            Some(Arc::new(notifier))
+        }
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!("Email notifications disabled: {e}");
+            None
+        }
+    };
+    // Upload malware scanning
+    let upload_scanner = lekton::scanning::UploadScanner::from_config(
+        &config.scanner,
+        quarantine_repo.clone(),
+        storage_client.clone(),
+    )
+    .map(Arc::new);
+    if upload_scanner.is_some() {
+        tracing::info!(kind = ?config.scanner.kind, "Upload malware scanning enabled");
+    }
+    // Spell checking
+    let spell_checker = match lekton::spellcheck::SpellChecker::from_config(
+        &config.spellcheck,
+        spelling_dictionary_repo.clone(),
+    ) {
+        Ok(Some(checker)) => {
+            tracing::info!(dictionary = %config.spellcheck.dictionary, "Spell checking enabled");
+            Some(Arc::new(checker))
+        }
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!("Spell checking disabled: {e}");
+            None
+        }
+    };
+    let inbox = Arc::new(lekton::notifications::Inbox::new(
+        notification_repo.clone(),
+        user_repo.clone(),
+        config.notifications,
+    ));
+    // Secret scanning of ingested content
+    let secret_guard = lekton::secrets::SecretGuard::from_config(
+        &config.secrets,
+        quarantine_repo.clone(),
+        storage_client.clone(),
+        Some(inbox.clone()),
+    )
+    .map(Arc::new);
+    if secret_guard.is_some() {
+        tracing::info!(action = ?config.secrets.action, "Secret scanning enabled");
+    }
+    let mentions = Some(Arc::new(lekton::mentions::MentionNotifier::new(
+        user_repo.clone(),
+        inbox.clone(),
+        email.clone(),
+        config.email.clone(),
+    )));
+
     // Build application state
     let app_state = lekton::app::AppState {
         document_repo,
@@ -437,6 +755,8 @@ async fn main() {
         prompt_version_repo,
         user_prompt_preference_repo,
         demo_mode,
+        local_accounts,
+        ldap: ldap_auth.then(|| Arc::new(config.auth.ldap.clone())),
         user_repo,
         access_level_repo,
         navigation_order_repo,
@@ -454,11 +774,93 @@ async fn main() {
         schema_endpoint_reindex_state,
         feedback_repo,
         documentation_feedback_repo,
+        glossary_repo,
+        spelling_dictionary_repo,
+        changelog_repo,
+        contract_test_repo,
+        bundle_repo,
+        project_repo,
+        edit_lock_repo,
+        pending_ingest_repo,
+        job_run_repo,
+        watch_repo,
+        notification_repo,
+        asset_download_repo,
+        quarantine_repo,
+        share_link_repo,
+        collection_repo,
+        doc_rating_repo,
+        upload_usage_repo,
+        activity_events: coordination.activity_events,
+        job_queue: coordination.job_queue,
         embedding_cache_repo,
         insecure_cookies: config.server.insecure_cookies,
+        demo_session: lekton::auth::demo_auth::DemoSessionConfig::from_auth_config(&config.auth),
         max_attachment_size_bytes: config.server.max_attachment_size_mb * 1024 * 1024,
+        max_render_bytes: config.rendering.max_render_bytes,
+        render_pipeline: Arc::new(render_pipeline),
+        asset_refs_ready: Default::default(),
+        github: if config.integrations.github.is_enabled() {
+            Some(Arc::new(config.integrations.github))
+        } else {
+            None
+        },
+        spec_viewer_assets,
+        circuit_breakers,
+        quotas: Arc::new(config.quotas),
+        taxonomy: Arc::new(config.taxonomy),
+        lint: Arc::new(config.lint),
+        share_links: Arc::new(config.share_links),
+        embed: Arc::new(config.embed),
+        scheduler: Arc::new(config.scheduler),
+        email,
+        mentions,
+        inbox,
+        upload_scanner,
+        spell_checker,
+        secret_guard,
+        on_call: Arc::new(lekton::on_call::OnCallDirectory::new()),
+        issue_tracker: Arc::new(lekton::issue_tracker::IssueTracker::new()),
+        try_it: if config.integrations.try_it.is_enabled() {
+            Some(Arc::new(config.integrations.try_it))
+        } else {
+            None
+        },
+        grafana: if config.integrations.grafana.is_enabled() {
+            Some(Arc::new(config.integrations.grafana))
+        } else {
+            None
+        },
     };
 
+    // Periodic maintenance jobs (link check, stale docs, ...)
+    lekton::jobs::scheduler::spawn(app_state.clone());
+    // References of documents saved before they were tracked
+    lekton::api::asset_access::spawn_backfill(app_state.clone());
+
+    // Follow writes that bypass the API
+    if config.database.watch_changes {
+        lekton::db::change_streams::ChangeWatcher {
+            db: mongo_db.clone(),
+            document_repo: app_state.document_repo.clone(),
+            storage: app_state.storage_client.clone(),
+            search: app_state.search_service.clone(),
+            search_reindex_state: app_state.search_reindex_state.clone(),
+            events: app_state.activity_events.clone(),
+            debounce: Duration::from_millis(config.database.change_debounce_ms),
+        }
+        .spawn();
+    }
+
+    // Notify watchers of document changes
+    lekton::watches::WatchNotifier::new(
+        app_state.watch_repo.clone(),
+        app_state.inbox.clone(),
+        app_state.user_repo.clone(),
+        app_state.email.clone(),
+    )
+    .spawn(&app_state.activity_events);
+
     // Generate the Leptos route list for SSR
     let routes = generate_route_list(App);
 
@@ -500,10 +902,30 @@ async fn main() {
             "/api/v1/ingest",
             axum::routing::post(api::ingest::ingest_handler),
         )
+        .route(
+            "/api/v1/docs/events",
+            axum::routing::get(api::events::document_events_handler),
+        )
+        .route(
+            "/api/v1/events/stream",
+            axum::routing::get(api::events::activity_stream_handler),
+        )
         .route(
             "/api/v1/search",
             axum::routing::get(api::search::search_handler),
         )
+        .route(
+            "/api/v1/bot/search",
+            axum::routing::get(api::bot::search_handler),
+        )
+        .route(
+            "/api/v1/bot/docs/{*slug}",
+            axum::routing::get(api::bot::get_document_handler),
+        )
+        .route(
+            "/api/v1/bot/relations/{*slug}",
+            axum::routing::get(api::bot::get_relations_handler),
+        )
         .route(
             "/api/v1/image/{filename}",
             axum::routing::get(api::upload::serve_image_handler),
@@ -522,6 +944,14 @@ async fn main() {
             axum::routing::get(api::schemas::get_schema_route_handler),
         )
         .route("/api/v1/sync", axum::routing::post(api::sync::sync_handler))
+        .route(
+            "/api/v1/changelog",
+            axum::routing::post(api::changelog::changelog_handler),
+        )
+        .route(
+            "/api/v1/contract-tests",
+            axum::routing::post(api::contract_tests::contract_test_handler),
+        )
         .route(
             "/api/v1/prompts/ingest",
             axum::routing::post(api::prompts::prompt_ingest_handler),
@@ -534,6 +964,18 @@ async fn main() {
             "/api/v1/assets",
             axum::routing::get(api::assets::list_assets_handler),
         )
+        .route(
+            "/api/v1/integrations/github/webhook",
+            axum::routing::post(api::github::github_webhook_handler),
+        )
+        .route(
+            "/api/v1/try-it",
+            axum::routing::any(api::try_it::try_it_proxy_handler),
+        )
+        .route(
+            "/grafana/render/{*path}",
+            axum::routing::get(api::grafana::grafana_render_handler),
+        )
         // Admin API
         .route(
             "/api/v1/admin/access-levels",
@@ -547,11 +989,18 @@ async fn main() {
         )
         .route(
             "/api/v1/admin/users",
-            axum::routing::get(api::admin::list_users_handler),
+            axum::routing::get(api::admin::list_users_handler)
+                .post(api::admin::create_user_handler),
         )
         .route(
             "/api/v1/admin/users/{user_id}",
-            axum::routing::get(api::admin::get_user_handler),
+            axum::routing::get(api::admin::get_user_handler)
+                .put(api::admin::update_user_handler)
+                .delete(api::admin::delete_user_handler),
+        )
+        .route(
+            "/api/v1/admin/users/{user_id}/password",
+            axum::routing::put(api::admin::reset_user_password_handler),
         )
         .route(
             "/api/v1/admin/users/{user_id}/access-levels",
@@ -566,6 +1015,85 @@ async fn main() {
             "/api/v1/admin/service-tokens/{id}",
             axum::routing::delete(api::admin::deactivate_service_token_handler),
         )
+        .route(
+            "/api/v1/admin/usage",
+            axum::routing::get(api::admin::owner_usage_handler),
+        )
+        .route(
+            "/api/v1/documents:batch",
+            axum::routing::patch(api::documents::batch_update_handler),
+        )
+        .route(
+            "/api/v1/documents/{*path}",
+            axum::routing::get(api::documents::document_resource_handler),
+        )
+        .route(
+            "/share/{token}",
+            axum::routing::get(api::share::shared_document_handler),
+        )
+        .route(
+            "/embed/docs/{*slug}",
+            axum::routing::get(api::embed::embed_document_handler),
+        )
+        .route(
+            "/api/v1/oembed",
+            axum::routing::get(api::embed::oembed_handler),
+        )
+        .route(
+            "/api/v1/admin/images/orphaned",
+            axum::routing::get(api::image_cleanup::list_orphaned_images_handler)
+                .delete(api::image_cleanup::delete_orphaned_images_handler),
+        )
+        .route(
+            "/api/v1/admin/asset-downloads",
+            axum::routing::get(api::asset_access::list_asset_downloads_handler),
+        )
+        .route(
+            "/api/v1/admin/quarantine",
+            axum::routing::get(api::quarantine::list_quarantine_handler),
+        )
+        .route(
+            "/api/v1/admin/quarantine/{id}",
+            axum::routing::delete(api::quarantine::delete_quarantined_handler),
+        )
+        .route(
+            "/api/v1/admin/tags/merge",
+            axum::routing::post(api::admin::merge_tags_handler),
+        )
+        .route(
+            "/api/v1/admin/protected-prefixes",
+            axum::routing::get(api::approvals::get_protected_prefixes_handler)
+                .put(api::approvals::put_protected_prefixes_handler),
+        )
+        .route(
+            "/api/v1/admin/pending-ingests",
+            axum::routing::get(api::approvals::list_pending_ingests_handler),
+        )
+        .route(
+            "/api/v1/admin/pending-ingests/{id}",
+            axum::routing::get(api::approvals::get_pending_ingest_handler)
+                .delete(api::approvals::reject_pending_ingest_handler),
+        )
+        .route(
+            "/api/v1/admin/pending-ingests/{id}/approve",
+            axum::routing::post(api::approvals::approve_pending_ingest_handler),
+        )
+        .route(
+            "/api/v1/admin/jobs",
+            axum::routing::get(api::jobs::list_jobs_handler),
+        )
+        .route(
+            "/api/v1/admin/jobs/runs",
+            axum::routing::get(api::jobs::list_job_runs_handler),
+        )
+        .route(
+            "/api/v1/admin/jobs/{job}/run",
+            axum::routing::post(api::jobs::run_job_handler),
+        )
+        .route(
+            "/api/v1/admin/import/backstage",
+            axum::routing::post(api::backstage::backstage_import_handler),
+        )
         // PAT management (user self-service + admin)
         .route(
             "/api/v1/user/pats",
@@ -601,6 +1129,11 @@ async fn main() {
             "/api/v1/admin/search/reindex/status",
             axum::routing::get(api::search::reindex_status_handler),
         )
+        .route(
+            "/api/v1/admin/search/settings",
+            axum::routing::get(api::search::get_search_settings_handler)
+                .put(api::search::put_search_settings_handler),
+        )
         .route(
             "/api/v1/admin/schemas/reindex-endpoints",
             axum::routing::post(api::schemas::trigger_schema_endpoint_reindex_handler),
@@ -617,6 +1150,7 @@ async fn main() {
             "/api/v1/rag/chat",
             axum::routing::post(api::rag::chat_handler),
         )
+        .route("/api/v1/ask", axum::routing::post(api::rag::ask_handler))
         .route(
             "/api/v1/rag/sessions",
             axum::routing::get(api::rag::list_sessions_handler),
@@ -671,6 +1205,31 @@ async fn main() {
             .route("/auth/me", axum::routing::get(auth_api::me_handler));
 
         tracing::info!("OAuth2/OIDC auth routes mounted: /auth/login, /auth/callback, /auth/refresh, /auth/logout, /auth/me");
+
+        if local_accounts {
+            use lekton::auth::local_accounts;
+
+            app = app
+                .route(
+                    "/auth/local/login",
+                    axum::routing::post(local_accounts::login_handler),
+                )
+                .route(
+                    "/auth/local/password",
+                    axum::routing::post(local_accounts::change_password_handler),
+                );
+
+            tracing::info!("Local account routes mounted: /auth/local/login, /auth/local/password");
+        }
+
+        if ldap_auth {
+            app = app.route(
+                "/auth/ldap/login",
+                axum::routing::post(lekton::auth::ldap::login_handler),
+            );
+
+            tracing::info!("LDAP auth route mounted: /auth/ldap/login");
+        }
     }
 
     // MCP server (requires RAG — needs embedding + vectorstore)
@@ -797,6 +1356,10 @@ async fn main() {
         }
     };
 
+    // Response compression (brotli/gzip), unless disabled
+    let compression = lekton::http_policy::compression_layer(config.server.compression);
+
+    let x_request_id = HeaderName::from_static(lekton::telemetry::REQUEST_ID_HEADER);
     let app = app
         // Leptos SSR routes
         .leptos_routes(&app_state, routes, {
@@ -805,10 +1368,30 @@ async fn main() {
         })
         // Static files (including custom.css)
         .fallback_service(ServeDir::new(&site_root))
-        .layer(middleware::from_fn(static_cache_headers))
+        // Print view at /docs/<slug>/print, ahead of the app's /docs/*slug route
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            api::print::print_view,
+        ))
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            lekton::auth::demo_auth::renew_session,
+        ))
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            lekton::http_policy::incident_cache_headers,
+        ))
+        .layer(middleware::from_fn(lekton::http_policy::cache_headers))
         .layer(middleware::from_fn(mjs_content_type))
+        .layer(compression)
         .layer(cors)
         .layer(tower_governor::GovernorLayer::new(governor_conf))
+        // Request IDs: assigned first, scoped for problem responses, traced
+        // and echoed back in the response
+        .layer(middleware::from_fn(lekton::telemetry::request_id_scope))
+        .layer(PropagateRequestIdLayer::new(x_request_id.clone()))
+        .layer(lekton::telemetry::trace_layer())
+        .layer(SetRequestIdLayer::new(x_request_id, MakeRequestUuid))
         .with_state(app_state);
 
     // Start the server
@@ -42,65 +42,72 @@ pub async fn pat_auth_middleware(
     next: Next,
 ) -> Result<Response, StatusCode> {
     let raw_token = extract_bearer(&headers).ok_or(StatusCode::UNAUTHORIZED)?;
+    let user_ctx = auth.user_context_for_pat(raw_token).await?;
 
-    let token_hash = TokenService::hash_token(raw_token);
+    request.extensions_mut().insert(user_ctx);
+    Ok(next.run(request).await)
+}
 
-    let token = auth
-        .service_token_repo
-        .find_by_hash(&token_hash)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+impl McpAuthState {
+    /// Validate a raw PAT and resolve the permissions of the user it acts for.
+    pub async fn user_context_for_pat(&self, raw_token: &str) -> Result<UserContext, StatusCode> {
+        let token_hash = TokenService::hash_token(raw_token);
 
-    if !token.is_active || !token.is_pat() {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+        let token = self
+            .service_token_repo
+            .find_by_hash(&token_hash)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .ok_or(StatusCode::UNAUTHORIZED)?;
 
-    // Update last_used_at (fire-and-forget — don't block the request)
-    let repo = auth.service_token_repo.clone();
-    let token_id = token.id.clone();
-    tokio::spawn(async move {
-        let _ = repo.touch_last_used(&token_id).await;
-    });
+        if !token.is_active || !token.is_pat() {
+            return Err(StatusCode::UNAUTHORIZED);
+        }
 
-    let user_ctx = match token.user_id.as_deref() {
-        // PAT linked to a real user — load user document for effective levels
-        Some(user_id) => {
-            let user = auth
-                .user_repo
-                .find_user_by_id(user_id)
-                .await
-                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-                .ok_or(StatusCode::UNAUTHORIZED)?;
+        // Update last_used_at (fire-and-forget — don't block the request)
+        let repo = self.service_token_repo.clone();
+        let token_id = token.id.clone();
+        tokio::spawn(async move {
+            let _ = repo.touch_last_used(&token_id).await;
+        });
 
-            let auth_user = AuthenticatedUser {
-                user_id: user.id.clone(),
-                email: user.email.clone(),
-                name: user.name.clone(),
-                is_admin: user.is_admin,
-            };
-            UserContext::from_user_doc(auth_user, &user)
-        }
-        // Admin PAT — no user_id, full access to all documents
-        None => UserContext {
-            user: AuthenticatedUser {
-                user_id: token.id.clone(),
-                email: format!("pat:{}@lekton", token.name),
-                name: Some(token.name.clone()),
-                is_admin: true,
-            },
-            effective_access_levels: vec![],
-            can_write: true,
-            can_read_draft: true,
-            can_write_draft: true,
-        },
-    };
+        let user_ctx = match token.user_id.as_deref() {
+            // PAT linked to a real user — load user document for effective levels
+            Some(user_id) => {
+                let user = self
+                    .user_repo
+                    .find_user_by_id(user_id)
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                    .ok_or(StatusCode::UNAUTHORIZED)?;
 
-    request.extensions_mut().insert(user_ctx);
-    Ok(next.run(request).await)
+                let auth_user = AuthenticatedUser {
+                    user_id: user.id.clone(),
+                    email: user.email.clone(),
+                    name: user.name.clone(),
+                    is_admin: user.is_admin,
+                };
+                UserContext::from_user_doc(auth_user, &user)
+            }
+            // Admin PAT — no user_id, full access to all documents
+            None => UserContext {
+                user: AuthenticatedUser {
+                    user_id: token.id.clone(),
+                    email: format!("pat:{}@lekton", token.name),
+                    name: Some(token.name.clone()),
+                    is_admin: true,
+                },
+                effective_access_levels: vec![],
+                can_write: true,
+                can_read_draft: true,
+                can_write_draft: true,
+            },
+        };
+        Ok(user_ctx)
+    }
 }
 
-fn extract_bearer(headers: &HeaderMap) -> Option<&str> {
+pub(crate) fn extract_bearer(headers: &HeaderMap) -> Option<&str> {
     headers
         .get("authorization")
         .and_then(|v| v.to_str().ok())
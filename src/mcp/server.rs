@@ -328,11 +328,19 @@ fn validate_docs_resource_uris(uris: &[String]) -> Result<(), McpError> {
     Ok(())
 }
 
-fn can_read_schema_version(user_ctx: &UserContext, version: &SchemaVersion) -> bool {
+fn can_read_schema_version(
+    user_ctx: &UserContext,
+    schema: &Schema,
+    version: &SchemaVersion,
+) -> bool {
     if user_ctx.user.is_admin {
         return true;
     }
     user_ctx.can_read(&version.access_level)
+        && schema
+            .owner_access_level
+            .as_deref()
+            .is_none_or(|level| user_ctx.can_read(level))
 }
 
 fn schema_version_summary(v: &SchemaVersion) -> serde_json::Value {
@@ -348,16 +356,6 @@ fn schema_version_summary(v: &SchemaVersion) -> serde_json::Value {
     })
 }
 
-fn endpoint_matches_query(endpoint: &crate::db::models::SchemaEndpoint, query: &str) -> bool {
-    let q = query.to_lowercase();
-    endpoint.path.to_lowercase().contains(&q)
-        || endpoint
-            .summary
-            .as_deref()
-            .map(|s| s.to_lowercase().contains(&q))
-            .unwrap_or(false)
-}
-
 fn schema_list_entry(schema: &Schema, visible_versions: &[&SchemaVersion]) -> serde_json::Value {
     let latest = visible_versions
         .iter()
@@ -370,6 +368,8 @@ fn schema_list_entry(schema: &Schema, visible_versions: &[&SchemaVersion]) -> se
         "schema_type": schema.schema_type,
         "service_owner": schema.service_owner,
         "tags": schema.tags,
+        "description": schema.description,
+        "contact": schema.contact,
         "latest_version": latest,
         "version_count": visible_versions.len(),
     })
@@ -380,6 +380,10 @@ fn schema_matches_query(schema: &Schema, query: &str) -> bool {
     schema.name.to_lowercase().contains(&q)
         || schema.service_owner.to_lowercase().contains(&q)
         || schema.tags.iter().any(|t| t.to_lowercase().contains(&q))
+        || schema
+            .description
+            .as_deref()
+            .is_some_and(|d| d.to_lowercase().contains(&q))
 }
 
 fn feedback_summary_entry(feedback: &DocumentationFeedback) -> serde_json::Value {
@@ -507,7 +511,7 @@ impl LektonMcpServer {
                 let visible: Vec<&SchemaVersion> = schema
                     .versions
                     .iter()
-                    .filter(|v| !v.is_archived && can_read_schema_version(&user_ctx, v))
+                    .filter(|v| !v.is_archived && can_read_schema_version(&user_ctx, schema, v))
                     .collect();
 
                 if visible.is_empty() {
@@ -527,7 +531,7 @@ impl LektonMcpServer {
     /// Searches schemas by name, service owner, or tag.
     #[tool(
         name = "search_schemas",
-        description = "Searches the schema registry by matching the query (case-insensitive) against schema name, service owner, and tags. Returns a ranked list of matching schemas visible to the authenticated user."
+        description = "Searches the schema registry by matching the query (case-insensitive) against schema name, service owner, tags, and description. Returns a ranked list of matching schemas visible to the authenticated user."
     )]
     async fn search_schemas(
         &self,
@@ -550,7 +554,7 @@ impl LektonMcpServer {
                 let visible: Vec<&SchemaVersion> = schema
                     .versions
                     .iter()
-                    .filter(|v| !v.is_archived && can_read_schema_version(&user_ctx, v))
+                    .filter(|v| !v.is_archived && can_read_schema_version(&user_ctx, schema, v))
                     .collect();
                 if visible.is_empty() {
                     return None;
@@ -590,7 +594,7 @@ impl LektonMcpServer {
         let visible_versions: Vec<serde_json::Value> = schema
             .versions
             .iter()
-            .filter(|v| !v.is_archived && can_read_schema_version(&user_ctx, v))
+            .filter(|v| !v.is_archived && can_read_schema_version(&user_ctx, &schema, v))
             .map(schema_version_summary)
             .collect();
 
@@ -606,6 +610,9 @@ impl LektonMcpServer {
             "schema_type": schema.schema_type,
             "service_owner": schema.service_owner,
             "tags": schema.tags,
+            "description": schema.description,
+            "contact": schema.contact,
+            "repository_url": schema.repository_url,
             "versions": visible_versions,
         });
 
@@ -650,7 +657,7 @@ impl LektonMcpServer {
                 )
             })?;
 
-        if version.is_archived || !can_read_schema_version(&user_ctx, version) {
+        if version.is_archived || !can_read_schema_version(&user_ctx, &schema, version) {
             return Err(McpError::invalid_params(
                 format!(
                     "Version '{}' not found for schema '{}'",
@@ -722,13 +729,9 @@ impl LektonMcpServer {
             for version in schema
                 .versions
                 .iter()
-                .filter(|v| !v.is_archived && can_read_schema_version(&user_ctx, v))
+                .filter(|v| !v.is_archived && can_read_schema_version(&user_ctx, schema, v))
             {
-                for endpoint in version
-                    .endpoints
-                    .iter()
-                    .filter(|e| endpoint_matches_query(e, query))
-                {
+                for endpoint in version.endpoints.iter().filter(|e| e.matches(query)) {
                     results.push(serde_json::json!({
                         "schema_name": schema.name,
                         "schema_type": schema.schema_type,
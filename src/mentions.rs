@@ -0,0 +1,188 @@
+//! Notifications for `@mentions` in document content.
+//!
+//! Writes (API ingests, GitHub syncs, web editor saves) report the mentions
+//! that were not in the previous revision of a document. Each handle is
+//! resolved to:
+//!
+//! - a user, when it equals the local part of the user's email
+//!   (`@jane.doe` → `jane.doe@example.com`), or
+//! - a team, when it names a service owner with recipients in
+//!   `[email.owners]` (`@payments-team`).
+//!
+//! Recipients with an account get an entry in their in-app inbox, and
+//! everyone is emailed when email is configured; unknown handles are
+//! ignored. Mention extraction itself lives in [`crate::rendering::mentions`].
+
+use std::sync::Arc;
+
+use crate::config::EmailConfig;
+use crate::db::notification_repository::{Notification, MENTION};
+use crate::db::user_repository::UserRepository;
+use crate::email::{EmailNotifier, MentionSource};
+use crate::error::AppError;
+use crate::notifications::Inbox;
+
+/// Handles in `current` that are not in `previous`.
+pub fn new_mentions(previous: &[String], current: Vec<String>) -> Vec<String> {
+    current
+        .into_iter()
+        .filter(|handle| !previous.contains(handle))
+        .collect()
+}
+
+/// Whether `handle` names the owner of `email`.
+fn is_user_handle(handle: &str, email: &str) -> bool {
+    email
+        .split_once('@')
+        .is_some_and(|(local, _)| local.eq_ignore_ascii_case(handle))
+}
+
+/// Resolves mentions and notifies the people they refer to.
+#[derive(Clone)]
+pub struct MentionNotifier {
+    user_repo: Arc<dyn UserRepository>,
+    inbox: Arc<Inbox>,
+    email: Option<Arc<EmailNotifier>>,
+    /// `[email]` settings, whose `owners` resolve team handles.
+    config: EmailConfig,
+}
+
+impl MentionNotifier {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        inbox: Arc<Inbox>,
+        email: Option<Arc<EmailNotifier>>,
+        config: EmailConfig,
+    ) -> Self {
+        Self {
+            user_repo,
+            inbox,
+            email,
+            config,
+        }
+    }
+
+    /// Notify everyone mentioned by `handles` in `source`. Failures are
+    /// logged; they must not fail the write.
+    pub async fn notify(&self, handles: &[String], source: MentionSource<'_>) {
+        if handles.is_empty() {
+            return;
+        }
+        if let Err(e) = self.send(handles, source).await {
+            tracing::warn!(slug = source.slug, "Failed to notify mentions: {e}");
+        }
+    }
+
+    async fn send(&self, handles: &[String], source: MentionSource<'_>) -> Result<(), AppError> {
+        let users = self.user_repo.list_users().await?;
+        for handle in handles {
+            let (recipients, team) = match users.iter().find(|u| is_user_handle(handle, &u.email)) {
+                Some(user) => (vec![user.email.clone()], false),
+                None => (self.config.owner_recipients(handle).to_vec(), true),
+            };
+            // Don't notify people about their own edits
+            let recipients: Vec<String> = recipients
+                .into_iter()
+                .filter(|r| !r.eq_ignore_ascii_case(source.actor))
+                .collect();
+            if recipients.is_empty() {
+                tracing::debug!(handle = %handle, "Ignoring mention of unknown handle");
+                continue;
+            }
+            for user in users
+                .iter()
+                .filter(|u| recipients.iter().any(|r| r.eq_ignore_ascii_case(&u.email)))
+            {
+                let mut notification =
+                    Notification::new(&user.id, MENTION, source.slug, source.title, source.actor);
+                notification.handle = Some(handle.clone());
+                self.inbox.deliver(notification).await?;
+            }
+            if let Some(email) = &self.email {
+                email
+                    .send_mention(&recipients, handle, team, source)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NotificationConfig;
+    use crate::test_utils::{
+        make_user, MockMailer, MockNotificationRepository, MockUserRepository,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_new_mentions() {
+        let previous = vec!["jane".to_string()];
+        let current = vec!["jane".to_string(), "sre".to_string()];
+        assert_eq!(new_mentions(&previous, current), ["sre"]);
+    }
+
+    #[tokio::test]
+    async fn test_notify_resolves_users_and_teams() {
+        let users = MockUserRepository::default();
+        users.users.lock().unwrap().extend([
+            make_user("Jane.Doe@example.com"),
+            make_user("bob@example.com"),
+            make_user("oncall@example.com"),
+        ]);
+        let users = Arc::new(users);
+        let notifications = Arc::new(MockNotificationRepository::default());
+        let inbox = Inbox::new(
+            notifications.clone(),
+            users.clone(),
+            NotificationConfig::default(),
+        );
+        let mailer = Arc::new(MockMailer::default());
+        let config = EmailConfig {
+            owners: HashMap::from([("sre".to_string(), vec!["oncall@example.com".to_string()])]),
+            ..EmailConfig::default()
+        };
+        let email = Arc::new(EmailNotifier::new(mailer.clone(), config.clone()).unwrap());
+        let notifier = MentionNotifier::new(users, Arc::new(inbox), Some(email), config);
+
+        let handles = ["jane.doe", "sre", "bob", "nobody"].map(String::from);
+        notifier
+            .notify(
+                &handles,
+                MentionSource {
+                    slug: "guides/intro",
+                    title: "Intro",
+                    actor: "bob@example.com",
+                },
+            )
+            .await;
+
+        let sent = mailer.sent.lock().unwrap();
+        let recipients: Vec<&[String]> = sent.iter().map(|m| m.to.as_slice()).collect();
+        assert_eq!(
+            recipients,
+            [["Jane.Doe@example.com"], ["oncall@example.com"]]
+        );
+        assert_eq!(
+            sent[0].subject,
+            "[Lekton] bob@example.com mentioned @jane.doe in Intro"
+        );
+        assert!(sent[1].body.contains("listed for sre"));
+        assert!(!sent[0].body.contains("listed for"));
+
+        let inbox = notifications.notifications.lock().unwrap();
+        let notified: Vec<(&str, Option<&str>)> = inbox
+            .iter()
+            .map(|n| (n.user_id.as_str(), n.handle.as_deref()))
+            .collect();
+        assert_eq!(
+            notified,
+            [
+                ("Jane.Doe@example.com", Some("jane.doe")),
+                ("oncall@example.com", Some("sre"))
+            ]
+        );
+    }
+}
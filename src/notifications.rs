@@ -0,0 +1,258 @@
+//! In-app notification inbox.
+//!
+//! Notifications reach a user's inbox from:
+//!
+//! - watches ([`crate::watches`]): changes to watched documents,
+//! - mentions ([`crate::mentions`]): `@handle`s that resolve to the user,
+//! - the approval queue: every admin is asked to review an ingest queued
+//!   for approval, and the owner of the token that submitted it learns
+//!   whether it was approved or rejected,
+//! - the secret guard ([`crate::secrets`]): every admin is alerted when
+//!   content with credentials is refused.
+//!
+//! Each user keeps the newest `[notifications] max_per_user` notifications;
+//! the `notification_cleanup` job deletes those older than `retention_days`.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::auth::models::AuthenticatedUser;
+use crate::config::NotificationConfig;
+use crate::db::notification_repository::{
+    Notification, NotificationRepository, APPROVAL_APPROVED, APPROVAL_REJECTED, APPROVAL_REQUESTED,
+    SECRET_DETECTED,
+};
+use crate::db::pending_ingest_repository::PendingIngest;
+use crate::db::user_repository::UserRepository;
+use crate::error::AppError;
+
+/// Delivers notifications and enforces the retention limits.
+pub struct Inbox {
+    repo: Arc<dyn NotificationRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    config: NotificationConfig,
+}
+
+impl Inbox {
+    pub fn new(
+        repo: Arc<dyn NotificationRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        config: NotificationConfig,
+    ) -> Self {
+        Self {
+            repo,
+            user_repo,
+            config,
+        }
+    }
+
+    /// Add `notification` to its recipient's inbox, dropping their oldest
+    /// beyond `max_per_user`.
+    pub async fn deliver(&self, notification: Notification) -> Result<(), AppError> {
+        let user_id = notification.user_id.clone();
+        self.repo.create(notification).await?;
+        self.repo
+            .trim(&user_id, u64::from(self.config.max_per_user))
+            .await
+    }
+
+    /// Ask every admin to review the queued `pending` ingest. Failures are
+    /// logged; they must not fail the ingest.
+    pub async fn request_approval(&self, pending: &PendingIngest) {
+        let notified = self
+            .notify_admins(
+                APPROVAL_REQUESTED,
+                &pending.slug,
+                &pending.request.title,
+                &pending.submitted_by,
+            )
+            .await;
+        if let Err(e) = notified {
+            tracing::warn!(slug = %pending.slug, "Failed to notify admins of pending ingest: {e}");
+        }
+    }
+
+    /// Alert every admin that `actor` tried to publish credentials to
+    /// `slug`. Failures are logged.
+    pub async fn secret_detected(&self, slug: &str, title: &str, actor: &str) {
+        if let Err(e) = self
+            .notify_admins(SECRET_DETECTED, slug, title, actor)
+            .await
+        {
+            tracing::warn!(slug = %slug, "Failed to alert admins of blocked credentials: {e}");
+        }
+    }
+
+    async fn notify_admins(
+        &self,
+        kind: &str,
+        slug: &str,
+        title: &str,
+        actor: &str,
+    ) -> Result<(), AppError> {
+        let admins = self.user_repo.list_users().await?;
+        for admin in admins.into_iter().filter(|u| u.is_admin) {
+            self.deliver(Notification::new(admin.id, kind, slug, title, actor))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Tell `owner` (the user behind the token that submitted `pending`)
+    /// that `decided_by` approved or rejected it. Owners deciding on their
+    /// own submissions are not notified. Failures are logged.
+    pub async fn approval_decided(
+        &self,
+        owner: &str,
+        pending: &PendingIngest,
+        approved: bool,
+        decided_by: &AuthenticatedUser,
+    ) {
+        if owner == decided_by.user_id {
+            return;
+        }
+        let kind = if approved {
+            APPROVAL_APPROVED
+        } else {
+            APPROVAL_REJECTED
+        };
+        let notification = Notification::new(
+            owner,
+            kind,
+            &pending.slug,
+            &pending.request.title,
+            &decided_by.email,
+        );
+        if let Err(e) = self.deliver(notification).await {
+            tracing::warn!(slug = %pending.slug, "Failed to notify approval outcome: {e}");
+        }
+    }
+
+    /// Delete notifications older than `retention_days` before `now`.
+    /// Returns `None` when age-based retention is disabled.
+    pub async fn prune_expired(&self, now: DateTime<Utc>) -> Result<Option<u64>, AppError> {
+        if self.config.retention_days == 0 {
+            return Ok(None);
+        }
+        let cutoff = now - chrono::Duration::days(i64::from(self.config.retention_days));
+        self.repo.delete_older_than(cutoff).await.map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::IngestRequest;
+    use crate::test_utils::{make_user, MockNotificationRepository, MockUserRepository};
+    use crate::testing;
+
+    fn pending(submitted_by: &str) -> PendingIngest {
+        PendingIngest {
+            id: "p1".into(),
+            slug: "security/policy".into(),
+            request: IngestRequest {
+                title: "Policy".into(),
+                content: "# Policy".into(),
+                service_owner: "security".into(),
+                source_path: "policy.md".into(),
+                ..testing::ingest_request("security/policy")
+            },
+            submitted_by: submitted_by.into(),
+            changes: vec![],
+            submitted_at: Utc::now(),
+        }
+    }
+
+    fn admin(email: &str) -> AuthenticatedUser {
+        AuthenticatedUser {
+            user_id: email.into(),
+            email: email.into(),
+            name: None,
+            is_admin: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deliver_keeps_newest() {
+        let repo = Arc::new(MockNotificationRepository::default());
+        let inbox = Inbox::new(
+            repo.clone(),
+            Arc::new(MockUserRepository::default()),
+            NotificationConfig {
+                max_per_user: 2,
+                retention_days: 0,
+            },
+        );
+        for slug in ["a", "b", "c"] {
+            inbox
+                .deliver(Notification::new(
+                    "u1",
+                    "document.updated",
+                    slug,
+                    slug,
+                    "ci",
+                ))
+                .await
+                .unwrap();
+        }
+        let slugs: Vec<String> = repo
+            .list_for_user("u1", 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|n| n.slug)
+            .collect();
+        assert_eq!(slugs, ["c", "b"]);
+        assert_eq!(inbox.prune_expired(Utc::now()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_approval_requests_and_outcomes() {
+        let users = MockUserRepository::default();
+        let mut root = make_user("root@example.com");
+        root.is_admin = true;
+        users
+            .users
+            .lock()
+            .unwrap()
+            .extend([root, make_user("dev@example.com")]);
+        let repo = Arc::new(MockNotificationRepository::default());
+        let inbox = Inbox::new(repo.clone(), Arc::new(users), NotificationConfig::default());
+
+        inbox.request_approval(&pending("dev-pat")).await;
+        let requests = repo.list_for_user("root@example.com", 10).await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].text(),
+            "dev-pat submitted security/policy (\"Policy\") for approval"
+        );
+        assert!(repo
+            .list_for_user("dev@example.com", 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let root = admin("root@example.com");
+        inbox
+            .approval_decided("dev@example.com", &pending("dev-pat"), false, &root)
+            .await;
+        let outcomes = repo.list_for_user("dev@example.com", 10).await.unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].kind, APPROVAL_REJECTED);
+        assert_eq!(outcomes[0].actor, "root@example.com");
+        assert_eq!(outcomes[0].link(), None);
+
+        // Deciding on your own submission notifies nobody
+        inbox
+            .approval_decided("root@example.com", &pending("root-pat"), true, &root)
+            .await;
+        assert_eq!(
+            repo.list_for_user("root@example.com", 10)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Current on-call lookups in PagerDuty or Opsgenie.
+//!
+//! Admins map services to on-call schedules under **Admin → On-call**
+//! ([`OnCallSettings`] in the settings document). [`OnCallDirectory::lookup`]
+//! asks the paging service who is on call now:
+//!
+//! - PagerDuty: `GET /oncalls` for the schedule, keeping the first
+//!   escalation level;
+//! - Opsgenie: `GET /v2/schedules/{id}/on-calls`, flattened to the
+//!   recipients' emails.
+//!
+//! Answers are kept for [`CACHE_TTL`], so busy pages don't use up the API
+//! rate limits.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use crate::db::settings_repository::{OnCallProvider, OnCallSettings};
+use crate::error::AppError;
+use crate::server::on_call::{OnCallContact, OnCallInfo};
+
+/// How long an answer of the paging service is reused.
+pub const CACHE_TTL: Duration = Duration::from_secs(60);
+/// Longest wait for the paging service.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+const PAGERDUTY_API: &str = "https://api.pagerduty.com";
+const OPSGENIE_API: &str = "https://api.opsgenie.com";
+
+/// Cached on-call lookups.
+pub struct OnCallDirectory {
+    client: reqwest::Client,
+    cache: Mutex<HashMap<(OnCallProvider, String), (Instant, OnCallInfo)>>,
+}
+
+impl Default for OnCallDirectory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OnCallDirectory {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Who is on call for `service`, or `None` when the service has no
+    /// schedule or the integration is off.
+    pub async fn lookup(
+        &self,
+        settings: &OnCallSettings,
+        service: &str,
+    ) -> Result<Option<OnCallInfo>, AppError> {
+        let Some(schedule_id) = settings.schedule_for(service) else {
+            return Ok(None);
+        };
+        let key = (settings.provider, schedule_id.to_string());
+        if let Some((fetched, info)) = self.cache.lock().unwrap().get(&key) {
+            if fetched.elapsed() < CACHE_TTL {
+                return Ok(Some(info.clone()));
+            }
+        }
+
+        let info = match settings.provider {
+            OnCallProvider::None => return Ok(None),
+            OnCallProvider::PagerDuty => self.pagerduty(settings, schedule_id).await?,
+            OnCallProvider::Opsgenie => self.opsgenie(settings, schedule_id).await?,
+        };
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(key, (Instant::now(), info.clone()));
+        Ok(Some(info))
+    }
+
+    /// Forget cached answers, after the settings change.
+    pub fn clear(&self) {
+        self.cache.lock().unwrap().clear();
+    }
+
+    async fn pagerduty(
+        &self,
+        settings: &OnCallSettings,
+        schedule_id: &str,
+    ) -> Result<OnCallInfo, AppError> {
+        let url = format!("{}/oncalls", api_url(settings, PAGERDUTY_API));
+        let response = self
+            .client
+            .get(url)
+            .query(&[
+                ("schedule_ids[]", schedule_id),
+                ("include[]", "users"),
+                ("earliest", "true"),
+            ])
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("Token token={}", settings.api_token),
+            )
+            .header(
+                reqwest::header::ACCEPT,
+                "application/vnd.pagerduty+json;version=2",
+            )
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| AppError::Unavailable(format!("PagerDuty on-call lookup: {e}")))?;
+        let body: PagerDutyOnCalls = response
+            .json()
+            .await
+            .map_err(|e| AppError::Unavailable(format!("PagerDuty on-call lookup: {e}")))?;
+        Ok(pagerduty_info(body))
+    }
+
+    async fn opsgenie(
+        &self,
+        settings: &OnCallSettings,
+        schedule_id: &str,
+    ) -> Result<OnCallInfo, AppError> {
+        let url = format!(
+            "{}/v2/schedules/{}/on-calls",
+            api_url(settings, OPSGENIE_API),
+            urlencoding::encode(schedule_id)
+        );
+        let identifier_type = if is_uuid(schedule_id) { "id" } else { "name" };
+        let response = self
+            .client
+            .get(url)
+            .query(&[
+                ("scheduleIdentifierType", identifier_type),
+                ("flat", "true"),
+            ])
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("GenieKey {}", settings.api_token),
+            )
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| AppError::Unavailable(format!("Opsgenie on-call lookup: {e}")))?;
+        let body: OpsgenieOnCalls = response
+            .json()
+            .await
+            .map_err(|e| AppError::Unavailable(format!("Opsgenie on-call lookup: {e}")))?;
+        Ok(opsgenie_info(body))
+    }
+}
+
+fn api_url<'a>(settings: &'a OnCallSettings, default: &'a str) -> &'a str {
+    let url = settings.api_url.trim().trim_end_matches('/');
+    if url.is_empty() {
+        default
+    } else {
+        url
+    }
+}
+
+/// Whether `value` looks like an Opsgenie schedule ID rather than a name.
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.chars().enumerate().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+#[derive(Debug, Deserialize)]
+struct PagerDutyOnCalls {
+    #[serde(default)]
+    oncalls: Vec<PagerDutyOnCall>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PagerDutyOnCall {
+    #[serde(default)]
+    escalation_level: u32,
+    user: PagerDutyUser,
+    #[serde(default)]
+    schedule: Option<PagerDutyReference>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PagerDutyUser {
+    #[serde(default, alias = "name")]
+    summary: String,
+    #[serde(default)]
+    email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PagerDutyReference {
+    #[serde(default)]
+    html_url: Option<String>,
+}
+
+/// The first escalation level of a PagerDuty `/oncalls` answer.
+fn pagerduty_info(body: PagerDutyOnCalls) -> OnCallInfo {
+    let first_level = body.oncalls.iter().map(|o| o.escalation_level).min();
+    let mut info = OnCallInfo::default();
+    for oncall in body.oncalls {
+        if Some(oncall.escalation_level) != first_level {
+            continue;
+        }
+        if info.schedule_url.is_none() {
+            info.schedule_url = oncall.schedule.and_then(|s| s.html_url);
+        }
+        let contact = OnCallContact {
+            name: oncall.user.summary,
+            email: oncall.user.email,
+        };
+        if !info.contacts.contains(&contact) {
+            info.contacts.push(contact);
+        }
+    }
+    info
+}
+
+#[derive(Debug, Deserialize)]
+struct OpsgenieOnCalls {
+    data: OpsgenieOnCallData,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpsgenieOnCallData {
+    #[serde(default, rename = "onCallRecipients")]
+    on_call_recipients: Vec<String>,
+}
+
+/// The recipients of a flat Opsgenie on-calls answer, named by email.
+fn opsgenie_info(body: OpsgenieOnCalls) -> OnCallInfo {
+    OnCallInfo {
+        contacts: body
+            .data
+            .on_call_recipients
+            .into_iter()
+            .map(|email| OnCallContact {
+                name: email.clone(),
+                email: Some(email),
+            })
+            .collect(),
+        schedule_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pagerduty_keeps_first_escalation_level() {
+        let body: PagerDutyOnCalls = serde_json::from_value(serde_json::json!({
+            "oncalls": [
+                {
+                    "escalation_level": 2,
+                    "user": { "summary": "Manager", "email": "manager@example.com" },
+                    "schedule": { "html_url": "https://acme.pagerduty.com/schedules/P2" }
+                },
+                {
+                    "escalation_level": 1,
+                    "user": { "summary": "Jane Doe", "email": "jane@example.com" },
+                    "schedule": { "html_url": "https://acme.pagerduty.com/schedules/P1" }
+                },
+                {
+                    "escalation_level": 1,
+                    "user": { "summary": "Jane Doe", "email": "jane@example.com" },
+                    "schedule": null
+                }
+            ]
+        }))
+        .unwrap();
+        let info = pagerduty_info(body);
+        assert_eq!(
+            info.contacts,
+            [OnCallContact {
+                name: "Jane Doe".into(),
+                email: Some("jane@example.com".into()),
+            }]
+        );
+        assert_eq!(
+            info.schedule_url.as_deref(),
+            Some("https://acme.pagerduty.com/schedules/P1")
+        );
+    }
+
+    #[test]
+    fn test_opsgenie_recipients() {
+        let body: OpsgenieOnCalls = serde_json::from_value(serde_json::json!({
+            "data": {
+                "_parent": { "id": "d875alp4-9b4e-4219-a803-0c26936d18de", "name": "SRE" },
+                "onCallRecipients": ["oncall@example.com"]
+            }
+        }))
+        .unwrap();
+        let info = opsgenie_info(body);
+        assert_eq!(info.contacts.len(), 1);
+        assert_eq!(
+            info.contacts[0].email.as_deref(),
+            Some("oncall@example.com")
+        );
+        assert!(is_uuid("4a2c2b4e-9b4e-4219-a803-0c26936d18de"));
+        assert!(!is_uuid("SRE schedule"));
+    }
+}
@@ -6,17 +6,34 @@ use leptos_router::params::Params;
 use crate::app::deactivate_service_token;
 #[allow(unused_imports)]
 use crate::app::{
-    admin_list_pats, admin_toggle_pat, create_admin_access_level, create_service_token,
-    delete_admin_access_level, get_custom_css, get_navigation, get_navigation_order,
-    get_rag_reindex_status, get_schema_endpoint_reindex_status, get_search_reindex_status,
-    list_admin_access_levels, list_admin_users, list_documentation_feedback, list_service_tokens,
-    mark_documentation_feedback_duplicate, resolve_documentation_feedback, save_custom_css,
-    save_navigation_order, set_admin_user_access_levels, trigger_rag_reindex,
-    trigger_schema_endpoint_reindex, trigger_search_reindex, update_admin_access_level,
-    AccessLevelInfo, CreateTokenResult, DocumentationFeedbackAdminItem,
-    DocumentationFeedbackAdminListResult, NavItem, NavigationOrderEntry, ServiceTokenInfo,
+    add_dictionary_word, admin_list_pats, admin_toggle_pat, approve_pending_ingest,
+    batch_update_admin_documents, create_admin_access_level, create_admin_local_user,
+    create_read_api_key, create_service_token, delete_admin_access_level, delete_admin_user,
+    delete_glossary_term, delete_project, get_branding, get_custom_css, get_doc_quality_report,
+    get_doc_rating_report, get_embed_settings, get_feature_flags, get_incident_config,
+    get_incident_status, get_is_local_accounts, get_issue_tracker_config, get_ldap_group_config,
+    get_nav_links, get_navigation, get_navigation_order, get_on_call_config, get_owner_usage,
+    get_protected_prefixes, get_rag_reindex_status, get_schema_endpoint_reindex_status,
+    get_search_reindex_status, list_admin_access_levels, list_admin_documents, list_admin_users,
+    list_dictionary_words, list_doc_rating_comments, list_documentation_feedback, list_glossary,
+    list_pending_ingests, list_projects, list_read_api_keys, list_service_tokens,
+    list_share_link_accesses, list_share_links, mark_documentation_feedback_duplicate,
+    reject_pending_ingest, remove_dictionary_word, reset_admin_user_password,
+    resolve_documentation_feedback, revoke_share_link, save_branding, save_custom_css,
+    save_embed_settings, save_feature_flags, save_glossary_term, save_incident_config,
+    save_issue_tracker_config, save_ldap_group_config, save_nav_links, save_navigation_order,
+    save_on_call_config, save_project, save_protected_prefixes, set_admin_user_access_levels,
+    set_incident_active, trigger_rag_reindex, trigger_schema_endpoint_reindex,
+    trigger_search_reindex, update_admin_access_level, update_admin_user, AccessLevelInfo,
+    AdminDocumentInfo, BrandingSettings, CreateTokenResult, DocQualityEntry,
+    DocumentationFeedbackAdminItem, DocumentationFeedbackAdminListResult, FeatureFlags, FooterLink,
+    IssueTrackerProvider, LdapGroupMapping, NavItem, NavLink, NavLinkGroup, NavLinks,
+    NavigationOrderEntry, OnCallProvider, PendingIngestInfo, SearchProvider, ServiceSchedule,
+    ServiceTokenInfo, ShareLinkInfo,
 };
 use crate::auth::refresh_client::with_auth_retry;
+use crate::components::use_toasts;
+use crate::i18n::use_i18n;
 
 #[derive(Params, PartialEq, Clone, Debug)]
 pub struct AdminParams {
@@ -26,6 +43,7 @@ pub struct AdminParams {
 /// Admin settings page with service token management and theming.
 #[component]
 pub fn AdminSettingsPage() -> impl IntoView {
+    let i18n = use_i18n();
     let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
 
     let is_admin = move || {
@@ -47,13 +65,13 @@ pub fn AdminSettingsPage() -> impl IntoView {
     view! {
         <Show
             when=is_admin
-            fallback=|| view! {
+            fallback=move || view! {
                 <div class="flex items-center justify-center min-h-[50vh]">
                     <div class="alert alert-error max-w-md shadow-lg border-none bg-error/10 text-error">
                         <svg xmlns="http://www.w3.org/2000/svg" class="h-6 w-6 shrink-0 stroke-current text-error" fill="none" viewBox="0 0 24 24">
                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M10 14l2-2m0 0l2-2m-2 2l-2-2m2 2l2 2m7-2a9 9 0 11-18 0 9 9 0 0118 0z" />
                         </svg>
-                        <span class="font-medium">"Access denied. Admin privileges required."</span>
+                        <span class="font-medium">{i18n.t("admin.access_denied")}</span>
                     </div>
                 </div>
             }
@@ -68,6 +86,7 @@ pub fn AdminSettingsPage() -> impl IntoView {
 /// Inner content, rendered only for admins.
 #[component]
 fn AdminSettingsContent(section: impl Fn() -> String + Send + Sync + 'static) -> impl IntoView {
+    let i18n = use_i18n();
     // Created token (shown once in modal)
     let (created_token, set_created_token) = signal(Option::<CreateTokenResult>::None);
 
@@ -87,27 +106,63 @@ fn AdminSettingsContent(section: impl Fn() -> String + Send + Sync + 'static) ->
                    {move || {
                        let current_section = section();
                        let title = match current_section.as_str() {
-                           "tokens" => "Service Tokens",
-                           "pats" => "Personal Access Tokens",
-                           "documentation-feedback" => "Documentation Feedback",
-                           "navigation" => "Navigation Setup",
-                           "css" => "Visual Customization",
-                           "rag" => "Index Management",
-                           "access-levels" => "Access Levels",
-                           "users" => "User Management",
-                           _ => "Administration",
+                           "tokens" => "admin.title.tokens",
+                           "pats" => "admin.title.pats",
+                           "api-keys" => "admin.title.api_keys",
+                           "approvals" => "admin.title.approvals",
+                           "documents" => "admin.title.documents",
+                           "share-links" => "admin.title.share_links",
+                           "usage" => "admin.title.usage",
+                           "doc-quality" => "admin.title.doc_quality",
+                           "doc-ratings" => "admin.title.doc_ratings",
+                           "documentation-feedback" => "admin.title.documentation_feedback",
+                           "navigation" => "admin.title.navigation",
+                           "links" => "admin.title.links",
+                           "glossary" => "admin.title.glossary",
+                           "dictionary" => "admin.title.dictionary",
+                           "projects" => "admin.title.projects",
+                           "css" => "admin.title.css",
+                           "settings" => "admin.title.settings",
+                           "features" => "admin.title.features",
+                           "incident-mode" => "admin.title.incident_mode",
+                           "on-call" => "admin.title.on_call",
+                           "issue-tracker" => "admin.title.issue_tracker",
+                           "embeds" => "admin.title.embeds",
+                           "rag" => "admin.title.rag",
+                           "access-levels" => "admin.title.access_levels",
+                           "users" => "admin.title.users",
+                           "ldap" => "admin.title.ldap",
+                           _ => "admin.title.default",
                        };
                        let subtitle = match current_section.as_str() {
-                           "documentation-feedback" => "Review MCP-reported documentation gaps, resolve them, and keep the registry tidy.",
-                           "access-levels" => "Manage content access levels and their inheritance hierarchy.",
-                           "users" => "Assign access levels and permissions to registered users.",
-                           "rag" => "Rebuild derived search and retrieval indexes from the canonical document store.",
-                           _ => "Manage your instance configuration, service tokens, and theming.",
+                           "documentation-feedback" => "admin.subtitle.documentation_feedback",
+                           "access-levels" => "admin.subtitle.access_levels",
+                           "users" => "admin.subtitle.users",
+                           "ldap" => "admin.subtitle.ldap",
+                           "api-keys" => "admin.subtitle.api_keys",
+                           "approvals" => "admin.subtitle.approvals",
+                           "documents" => "admin.subtitle.documents",
+                           "share-links" => "admin.subtitle.share_links",
+                           "usage" => "admin.subtitle.usage",
+                           "doc-quality" => "admin.subtitle.doc_quality",
+                           "doc-ratings" => "admin.subtitle.doc_ratings",
+                           "links" => "admin.subtitle.links",
+                           "glossary" => "admin.subtitle.glossary",
+                           "dictionary" => "admin.subtitle.dictionary",
+                           "projects" => "admin.subtitle.projects",
+                           "settings" => "admin.subtitle.settings",
+                           "features" => "admin.subtitle.features",
+                           "incident-mode" => "admin.subtitle.incident_mode",
+                           "on-call" => "admin.subtitle.on_call",
+                           "issue-tracker" => "admin.subtitle.issue_tracker",
+                           "embeds" => "admin.subtitle.embeds",
+                           "rag" => "admin.subtitle.rag",
+                           _ => "admin.subtitle.default",
                        };
                        view! {
                            <>
-                               <h1 class="text-4xl font-extrabold tracking-tight">{title}</h1>
-                               <p class="text-base-content/60 mt-1">{subtitle}</p>
+                               <h1 class="text-4xl font-extrabold tracking-tight">{i18n.t(title)}</h1>
+                               <p class="text-base-content/60 mt-1">{i18n.t(subtitle)}</p>
                            </>
                        }
                    }}
@@ -118,9 +173,31 @@ fn AdminSettingsContent(section: impl Fn() -> String + Send + Sync + 'static) ->
                 {move || match section2().as_str() {
                     "tokens" => view! { <ServiceTokenManager set_created_token=set_created_token /> }.into_any(),
                     "pats" => view! { <AdminPatManager /> }.into_any(),
+                    "api-keys" => view! { <ApiKeyManager set_created_token=set_created_token /> }.into_any(),
+                    "documents" => view! { <DocumentBulkEditor /> }.into_any(),
+                    "share-links" => view! { <ShareLinksAdmin /> }.into_any(),
+                    "usage" => view! { <OwnerUsagePanel /> }.into_any(),
+                    "doc-quality" => view! { <DocQualityReport /> }.into_any(),
+                    "doc-ratings" => view! { <DocRatingsReport /> }.into_any(),
+                    "approvals" => view! {
+                        <div class="space-y-6">
+                            <PendingIngestQueue />
+                            <ProtectedPrefixesEditor />
+                        </div>
+                    }.into_any(),
                     "documentation-feedback" => view! { <DocumentationFeedbackAdminPanel /> }.into_any(),
                     "navigation" => view! { <NavigationOrderEditor /> }.into_any(),
+                    "links" => view! { <NavLinksEditor /> }.into_any(),
+                    "glossary" => view! { <GlossaryEditor /> }.into_any(),
+                    "dictionary" => view! { <SpellingDictionaryEditor /> }.into_any(),
+                    "projects" => view! { <ProjectEditor /> }.into_any(),
                     "css" => view! { <CustomCssEditor /> }.into_any(),
+                    "settings" => view! { <BrandingEditor /> }.into_any(),
+                    "features" => view! { <FeatureFlagsEditor /> }.into_any(),
+                    "incident-mode" => view! { <IncidentModeEditor /> }.into_any(),
+                    "on-call" => view! { <OnCallEditor /> }.into_any(),
+                    "issue-tracker" => view! { <IssueTrackerEditor /> }.into_any(),
+                    "embeds" => view! { <EmbedsEditor /> }.into_any(),
                     "rag" => view! {
                         <div class="space-y-6">
                             <SearchReindexSection />
@@ -130,7 +207,8 @@ fn AdminSettingsContent(section: impl Fn() -> String + Send + Sync + 'static) ->
                     }.into_any(),
                     "access-levels" => view! { <AccessLevelManager /> }.into_any(),
                     "users" => view! { <UserManager /> }.into_any(),
-                    _ => view! { <div class="alert alert-warning">"Page not found"</div> }.into_any(),
+                    "ldap" => view! { <LdapGroupsEditor /> }.into_any(),
+                    _ => view! { <div class="alert alert-warning">{i18n.t("admin.page_not_found")}</div> }.into_any(),
                 }}
             </div>
         </div>
@@ -143,6 +221,7 @@ fn AdminSettingsContent(section: impl Fn() -> String + Send + Sync + 'static) ->
 /// Component managing service tokens.
 #[component]
 fn ServiceTokenManager(set_created_token: WriteSignal<Option<CreateTokenResult>>) -> impl IntoView {
+    let i18n = use_i18n();
     // Signal to trigger token list reload
     let (refresh_counter, set_refresh_counter) = signal(0u32);
 
@@ -162,10 +241,10 @@ fn ServiceTokenManager(set_created_token: WriteSignal<Option<CreateTokenResult>>
                         <svg class="w-6 h-6 text-primary" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 7a2 2 0 012 2m4 0a6 6 0 01-7.743 5.743L11 17H9v2H7v2H4a1 1 0 01-1-1v-2.586a1 1 0 01.293-.707l5.964-5.964A6 6 0 1121 9z"></path>
                         </svg>
-                        <h2 class="card-title text-2xl">"Service Tokens"</h2>
+                        <h2 class="card-title text-2xl">{i18n.t("admin.title.tokens")}</h2>
                     </div>
                     <p class="text-base-content/60">
-                        "Long-lived tokens for CI/CD pipelines and external service integrations. Each token is restricted to specific documentation scopes."
+                        {i18n.t("admin.tokens.intro")}
                     </p>
                 </div>
 
@@ -173,7 +252,7 @@ fn ServiceTokenManager(set_created_token: WriteSignal<Option<CreateTokenResult>>
                     <Suspense fallback=move || view! {
                         <div class="flex flex-col items-center justify-center py-12 gap-4">
                             <span class="loading loading-spinner loading-lg text-primary"></span>
-                            <p class="text-sm font-medium animate-pulse">"Loading tokens..."</p>
+                            <p class="text-sm font-medium animate-pulse">{i18n.t("admin.tokens.loading")}</p>
                         </div>
                     }>
                         {move || tokens_resource.get().map(|result| match result {
@@ -181,7 +260,7 @@ fn ServiceTokenManager(set_created_token: WriteSignal<Option<CreateTokenResult>>
                             Err(e) => view! {
                                 <div class="alert alert-error shadow-sm border-none bg-error/10 text-error">
                                     <svg xmlns="http://www.w3.org/2000/svg" class="stroke-current shrink-0 h-6 w-6" fill="none" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M10 14l2-2m0 0l2-2m-2 2l-2-2m2 2l2 2m7-2a9 9 0 11-18 0 9 9 0 0118 0z" /></svg>
-                                    <span>{format!("Failed to load tokens: {e}")}</span>
+                                    <span>{i18n.tf("admin.tokens.load_error", &[("error", &e.to_string())])}</span>
                                 </div>
                             }.into_any(),
                         })}
@@ -207,6 +286,7 @@ fn TokenTable(
     tokens: Vec<ServiceTokenInfo>,
     trigger_refresh: impl Fn() + Copy + Send + Sync + 'static,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     if tokens.is_empty() {
         return view! {
             <div class="flex flex-col items-center justify-center py-10 px-4 text-center border-2 border-dashed border-base-300 rounded-xl bg-base-200/20">
@@ -215,8 +295,8 @@ fn TokenTable(
                         <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 7a2 2 0 012 2m4 0a6 6 0 01-7.743 5.743L11 17H9v2H7v2H4a1 1 0 01-1-1v-2.586a1 1 0 01.293-.707l5.964-5.964A6 6 0 1121 9z"></path>
                     </svg>
                 </div>
-                <h3 class="font-bold text-lg text-base-content/70">"No service tokens yet"</h3>
-                <p class="text-sm text-base-content/50 max-w-xs mt-1">"Create your first token below to start automating document updates."</p>
+                <h3 class="font-bold text-lg text-base-content/70">{i18n.t("admin.tokens.empty")}</h3>
+                <p class="text-sm text-base-content/50 max-w-xs mt-1">{i18n.t("admin.tokens.empty_hint")}</p>
             </div>
         }
         .into_any();
@@ -227,13 +307,13 @@ fn TokenTable(
             <table class="table table-zebra w-full overflow-hidden">
                 <thead>
                     <tr class="bg-base-200/50">
-                        <th class="py-4">"Name"</th>
-                        <th>"Scopes"</th>
-                        <th class="text-center">"Write"</th>
-                        <th class="text-center">"Status"</th>
-                        <th>"Created"</th>
-                        <th>"Usage"</th>
-                        <th class="text-right">"Actions"</th>
+                        <th class="py-4">{i18n.t("admin.common.name")}</th>
+                        <th>{i18n.t("admin.common.scopes")}</th>
+                        <th class="text-center">{i18n.t("admin.tokens.write")}</th>
+                        <th class="text-center">{i18n.t("admin.common.status")}</th>
+                        <th>{i18n.t("admin.common.created")}</th>
+                        <th>{i18n.t("admin.tokens.usage")}</th>
+                        <th class="text-right">{i18n.t("admin.common.actions")}</th>
                     </tr>
                 </thead>
                 <tbody class="divide-y divide-base-200">
@@ -254,6 +334,7 @@ fn TokenRow(
     token: ServiceTokenInfo,
     trigger_refresh: impl Fn() + Copy + Send + Sync + 'static,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let id = token.id.clone();
     let name = token.name.clone();
     let scopes: Vec<String> = token.allowed_scopes.clone();
@@ -261,9 +342,10 @@ fn TokenRow(
     let last_used = token
         .last_used_at
         .clone()
-        .unwrap_or_else(|| "Never".to_string());
+        .unwrap_or_else(|| i18n.t("admin.common.never").to_string());
     let is_active = token.is_active;
     let can_write = token.can_write;
+    let project = token.project.clone();
 
     let (deactivating, set_deactivating) = signal(false);
 
@@ -283,14 +365,17 @@ fn TokenRow(
 
     view! {
         <tr class=move || if !is_active { "opacity-40 grayscale italic" } else { "hover:bg-base-200/30 transition-colors" }>
-            <td class="font-bold text-sm min-w-[140px]">{name}</td>
+            <td class="font-bold text-sm min-w-[140px]">
+                {name}
+                {project.map(|p| view! { <span class="badge badge-secondary badge-outline badge-xs ml-2 font-mono">{p}</span> })}
+            </td>
             <td class="max-w-[200px]">
                 <div class="flex flex-wrap gap-1">
                     {scopes.into_iter().take(3).map(|scope| {
                         view! { <span class="badge badge-outline badge-xs px-2 py-2 font-mono">{scope}</span> }
                     }).collect::<Vec<_>>()}
                     {if token.allowed_scopes.len() > 3 {
-                        view! { <span class="text-[10px] text-base-content/40 ml-1">" + "{token.allowed_scopes.len() - 3}" more"</span> }.into_any()
+                        view! { <span class="text-[10px] text-base-content/40 ml-1">{i18n.tf("admin.tokens.more_scopes", &[("count", &(token.allowed_scopes.len() - 3).to_string())])}</span> }.into_any()
                     } else {
                         view! { <span></span> }.into_any()
                     }}
@@ -298,16 +383,16 @@ fn TokenRow(
             </td>
             <td class="text-center">
                 {if can_write {
-                    view! { <div class="badge badge-success badge-outline badge-sm font-bold text-[10px]">"WRITE"</div> }.into_any()
+                    view! { <div class="badge badge-success badge-outline badge-sm font-bold text-[10px]">{i18n.t("admin.tokens.badge_write")}</div> }.into_any()
                 } else {
-                    view! { <div class="badge badge-ghost badge-outline badge-sm font-bold text-[10px] opacity-40">"READ"</div> }.into_any()
+                    view! { <div class="badge badge-ghost badge-outline badge-sm font-bold text-[10px] opacity-40">{i18n.t("admin.tokens.badge_read")}</div> }.into_any()
                 }}
             </td>
             <td class="text-center">
                 {if is_active {
-                    view! { <span class="badge badge-primary badge-sm font-medium">"active"</span> }.into_any()
+                    view! { <span class="badge badge-primary badge-sm font-medium">{i18n.t("admin.common.active")}</span> }.into_any()
                 } else {
-                    view! { <span class="badge badge-ghost badge-sm text-xs font-medium">"deactivated"</span> }.into_any()
+                    view! { <span class="badge badge-ghost badge-sm text-xs font-medium">{i18n.t("admin.common.deactivated")}</span> }.into_any()
                 }}
             </td>
             <td class="text-xs text-base-content/60">{created_at}</td>
@@ -325,13 +410,13 @@ fn TokenRow(
                         on:click=move |_| {
                             #[cfg(feature = "hydrate")]
                             {
-                                if window().confirm_with_message("Are you sure you want to deactivate this token? This action cannot be undone.").unwrap_or(false) {
+                                if window().confirm_with_message(i18n.t_untracked("admin.tokens.deactivate_confirm")).unwrap_or(false) {
                                     deactivate_action.dispatch(());
                                 }
                             }
                         }
                     >
-                        {move || if deactivating.get() { "..." } else { "Deactivate" }}
+                        {move || if deactivating.get() { "..." } else { i18n.t("admin.common.deactivate") }}
                     </button>
                 </Show>
             </td>
@@ -344,8 +429,10 @@ fn TokenRow(
 fn CreateTokenForm(
     on_created: impl Fn(CreateTokenResult) + Copy + Send + Sync + 'static,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let (name, set_name) = signal(String::new());
     let (scopes, set_scopes) = signal(String::new());
+    let (project, set_project) = signal(String::new());
     let (can_write, set_can_write) = signal(true);
     let (error, set_error) = signal(Option::<String>::None);
     let (submitting, set_submitting) = signal(false);
@@ -353,12 +440,18 @@ fn CreateTokenForm(
     let submit_action = Action::new_local(move |_: &()| {
         let name_val = name.get_untracked();
         let scopes_val = scopes.get_untracked();
+        let project_val = project.get_untracked();
         let can_write_val = can_write.get_untracked();
         async move {
             set_error.set(None);
             set_submitting.set(true);
             let result = with_auth_retry(|| {
-                create_service_token(name_val.clone(), scopes_val.clone(), can_write_val)
+                create_service_token(
+                    name_val.clone(),
+                    scopes_val.clone(),
+                    can_write_val,
+                    project_val.clone(),
+                )
             })
             .await;
             set_submitting.set(false);
@@ -366,6 +459,7 @@ fn CreateTokenForm(
                 Ok(token_result) => {
                     set_name.set(String::new());
                     set_scopes.set(String::new());
+                    set_project.set(String::new());
                     set_can_write.set(true);
                     on_created(token_result);
                 }
@@ -379,8 +473,8 @@ fn CreateTokenForm(
     view! {
         <div class="flex flex-col gap-6">
             <div>
-                <h3 class="font-bold text-lg">"Create New Token"</h3>
-                <p class="text-sm text-base-content/50">"Configure a new scoped access token."</p>
+                <h3 class="font-bold text-lg">{i18n.t("admin.tokens.create_title")}</h3>
+                <p class="text-sm text-base-content/50">{i18n.t("admin.tokens.create_intro")}</p>
             </div>
 
             <Show when=move || error.get().is_some()>
@@ -393,23 +487,23 @@ fn CreateTokenForm(
             <div class="grid grid-cols-1 md:grid-cols-2 gap-x-8 gap-y-6">
                 <div class="form-control w-full">
                     <label class="label pt-0">
-                        <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60">"Token Name"</span>
+                        <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60">{i18n.t("admin.tokens.token_name")}</span>
                     </label>
                     <input
                         type="text"
-                        placeholder="e.g. github-actions-ci"
+                        placeholder=i18n.t("admin.tokens.name_placeholder")
                         class="input input-bordered focus:input-primary transition-all shadow-sm"
                         prop:value=move || name.get()
                         on:input=move |ev| set_name.set(event_target_value(&ev))
                     />
                     <label class="label">
-                      <span class="label-text-alt text-base-content/40 italic">"A descriptive name for identification."</span>
+                      <span class="label-text-alt text-base-content/40 italic">{i18n.t("admin.tokens.name_hint")}</span>
                     </label>
                 </div>
 
                 <div class="form-control w-full">
                     <label class="label pt-0">
-                        <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60">"Permissions"</span>
+                        <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60">{i18n.t("admin.tokens.permissions")}</span>
                     </label>
                     <div class="bg-base-100 rounded-lg border border-base-300 px-3 shadow-sm flex items-center min-h-12">
                       <label class="cursor-pointer flex items-center gap-4 w-full">
@@ -420,20 +514,20 @@ fn CreateTokenForm(
                               on:change=move |ev| set_can_write.set(event_target_checked(&ev))
                           />
                           <div>
-                            <span class="label-text font-bold block mb-0.5">"Allow Write Access"</span>
-                            <span class="label-text-alt text-base-content/40">"Permit updates and deletions via API."</span>
+                            <span class="label-text font-bold block mb-0.5">{i18n.t("admin.tokens.allow_write")}</span>
+                            <span class="label-text-alt text-base-content/40">{i18n.t("admin.tokens.allow_write_hint")}</span>
                           </div>
                       </label>
                     </div>
                     <label class="label">
-                      <span class="label-text-alt text-base-content/40 italic">"Controls write permissions for this token."</span>
+                      <span class="label-text-alt text-base-content/40 italic">{i18n.t("admin.tokens.write_hint")}</span>
                     </label>
                 </div>
             </div>
 
             <div class="form-control">
                 <label class="label pt-0">
-                    <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60">"Allowed Scopes"</span>
+                    <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60">{i18n.t("admin.tokens.allowed_scopes")}</span>
                 </label>
                 <textarea
                     class="textarea textarea-bordered h-32 font-mono text-sm leading-relaxed focus:textarea-primary transition-all shadow-sm"
@@ -444,26 +538,42 @@ fn CreateTokenForm(
                 <label class="label">
                     <span class="label-text-alt text-base-content/50 bg-base-300/30 px-2 py-1 rounded inline-flex items-center gap-1.5">
                         <svg class="w-3.5 h-3.5" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z"></path></svg>
-                        "Enter one path prefix per line."
+                        {i18n.t("admin.tokens.scopes_hint")}
                     </span>
                 </label>
             </div>
 
+            <div class="form-control">
+                <label class="label pt-0">
+                    <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60">{i18n.t("admin.tokens.project")}</span>
+                </label>
+                <input
+                    type="text"
+                    placeholder=i18n.t("admin.tokens.project_placeholder")
+                    class="input input-bordered font-mono focus:input-primary transition-all shadow-sm"
+                    prop:value=move || project.get()
+                    on:input=move |ev| set_project.set(event_target_value(&ev))
+                />
+                <label class="label">
+                    <span class="label-text-alt text-base-content/40 italic">{i18n.t("admin.tokens.project_hint")}</span>
+                </label>
+            </div>
+
             <div class="flex justify-end pt-2">
                 <button
                     class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
-                    disabled=move || submitting.get() || name.get().trim().is_empty() || scopes.get().trim().is_empty()
+                    disabled=move || submitting.get() || name.get().trim().is_empty() || (scopes.get().trim().is_empty() && project.get().trim().is_empty())
                     on:click=move |_| { submit_action.dispatch(()); }
                 >
                     {move || if submitting.get() {
                         view! {
                           <span class="loading loading-spinner loading-sm"></span>
-                          "Creating..."
+                          {i18n.t("admin.tokens.creating")}
                         }.into_any()
                     } else {
                         view! {
                           <svg class="w-5 h-5 mr-1" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M12 6v6m0 0v6m0-6h6m-6 0H6"></path></svg>
-                          "Create Service Token"
+                          {i18n.t("admin.tokens.create")}
                         }.into_any()
                     }}
                 </button>
@@ -472,8 +582,197 @@ fn CreateTokenForm(
     }
 }
 
+/// Component managing read-only API keys for server-to-server embedding.
+#[allow(unused_variables)]
+#[component]
+fn ApiKeyManager(set_created_token: WriteSignal<Option<CreateTokenResult>>) -> impl IntoView {
+    let i18n = use_i18n();
+    let (refresh, set_refresh) = signal(0u32);
+
+    let keys_resource = LocalResource::new(move || {
+        let _ = refresh.get();
+        with_auth_retry(list_read_api_keys)
+    });
+    let levels_resource = LocalResource::new(move || with_auth_retry(list_admin_access_levels));
+
+    let name = RwSignal::new(String::new());
+    let levels = RwSignal::new(Vec::<String>::new());
+    let scopes = RwSignal::new(String::new());
+    let error_msg = RwSignal::new(Option::<String>::None);
+
+    let create_action = Action::new_local(move |_: &()| async move {
+        error_msg.set(None);
+        let result = with_auth_retry(|| {
+            create_read_api_key(
+                name.get_untracked(),
+                levels.get_untracked(),
+                scopes.get_untracked(),
+            )
+        })
+        .await;
+        match result {
+            Ok(created) => {
+                name.set(String::new());
+                levels.set(Vec::new());
+                scopes.set(String::new());
+                set_created_token.set(Some(created));
+                set_refresh.update(|c| *c += 1);
+            }
+            Err(e) => error_msg.set(Some(e.to_string())),
+        }
+    });
+
+    let deactivate_action = Action::new_local(move |id: &String| {
+        let id = id.clone();
+        async move {
+            match with_auth_retry(|| deactivate_service_token(id.clone())).await {
+                Ok(()) => set_refresh.update(|c| *c += 1),
+                Err(e) => error_msg.set(Some(e.to_string())),
+            }
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.api_keys")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.api_keys.intro")}
+                    </p>
+                </div>
+
+                <Show when=move || error_msg.get().is_some()>
+                    <div class="alert alert-error text-sm">{move || error_msg.get().unwrap_or_default()}</div>
+                </Show>
+
+                <Suspense fallback=move || view! { <span class="loading loading-spinner"></span> }>
+                    {move || keys_resource.get().map(|result| match result {
+                        Ok(keys) if keys.is_empty() => view! {
+                            <p class="text-sm text-base-content/50 italic">{i18n.t("admin.api_keys.empty")}</p>
+                        }.into_any(),
+                        Ok(keys) => view! {
+                            <div class="overflow-x-auto rounded-lg border border-base-200">
+                                <table class="table table-zebra w-full">
+                                    <thead>
+                                        <tr class="bg-base-200/50">
+                                            <th>{i18n.t("admin.common.name")}</th>
+                                            <th>{i18n.t("admin.api_keys.access_levels")}</th>
+                                            <th>{i18n.t("admin.common.scopes")}</th>
+                                            <th>{i18n.t("admin.common.created")}</th>
+                                            <th>{i18n.t("admin.api_keys.last_used")}</th>
+                                            <th class="text-right">{i18n.t("admin.common.actions")}</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {keys.into_iter().map(|key| {
+                                            let id = key.id.clone();
+                                            let is_active = key.is_active;
+                                            view! {
+                                                <tr class=if is_active { "" } else { "opacity-40 italic" }>
+                                                    <td class="font-bold text-sm">{key.name}</td>
+                                                    <td>
+                                                        <div class="flex flex-wrap gap-1">
+                                                            {key.access_levels.into_iter().map(|l| view! {
+                                                                <span class="badge badge-outline badge-xs font-mono">{l}</span>
+                                                            }).collect::<Vec<_>>()}
+                                                        </div>
+                                                    </td>
+                                                    <td class="text-xs font-mono">
+                                                        {if key.allowed_scopes.is_empty() {
+                                                            i18n.t("admin.api_keys.all_documents").to_string()
+                                                        } else {
+                                                            key.allowed_scopes.join(", ")
+                                                        }}
+                                                    </td>
+                                                    <td class="text-xs text-base-content/60">{key.created_at}</td>
+                                                    <td class="text-xs text-base-content/60">{key.last_used_at.unwrap_or_else(|| i18n.t("admin.common.never").to_string())}</td>
+                                                    <td class="text-right">
+                                                        {if is_active {
+                                                            view! {
+                                                                <button class="btn btn-ghost btn-xs text-error"
+                                                                    on:click=move |_| {
+                                                                        #[cfg(feature = "hydrate")]
+                                                                        {
+                                                                            if window().confirm_with_message(i18n.t_untracked("admin.api_keys.deactivate_confirm")).unwrap_or(false) {
+                                                                                deactivate_action.dispatch(id.clone());
+                                                                            }
+                                                                        }
+                                                                    }
+                                                                >{i18n.t("admin.common.deactivate")}</button>
+                                                            }.into_any()
+                                                        } else {
+                                                            view! { <span class="badge badge-ghost badge-sm">{i18n.t("admin.common.deactivated")}</span> }.into_any()
+                                                        }}
+                                                    </td>
+                                                </tr>
+                                            }
+                                        }).collect::<Vec<_>>()}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any(),
+                        Err(e) => view! {
+                            <div class="alert alert-error text-sm">{i18n.tf("admin.api_keys.load_error", &[("error", &e.to_string())])}</div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+
+                <div class="border-t border-base-200 pt-6 flex flex-col gap-4">
+                    <h3 class="font-bold text-lg">{i18n.t("admin.api_keys.create")}</h3>
+                    <input type="text" class="input input-bordered input-sm" placeholder=i18n.t("admin.api_keys.name_placeholder")
+                        prop:value=move || name.get()
+                        on:input=move |e| name.set(event_target_value(&e))
+                    />
+                    <div>
+                        <span class="label-text text-xs font-medium">{i18n.t("admin.api_keys.access_levels")}</span>
+                        <div class="flex gap-3 flex-wrap mt-2">
+                            {move || levels_resource.get().and_then(|r| r.ok()).unwrap_or_default().into_iter().map(|l| {
+                                let lname = l.name.clone();
+                                let lname2 = lname.clone();
+                                view! {
+                                    <label class="flex items-center gap-1 cursor-pointer">
+                                        <input
+                                            type="checkbox"
+                                            class="checkbox checkbox-xs"
+                                            prop:checked=move || levels.get().contains(&lname)
+                                            on:change=move |e| {
+                                                let checked = event_target_checked(&e);
+                                                levels.update(|v| {
+                                                    if checked {
+                                                        if !v.contains(&lname2) { v.push(lname2.clone()); }
+                                                    } else {
+                                                        v.retain(|x| x != &lname2);
+                                                    }
+                                                });
+                                            }
+                                        />
+                                        <span class="text-xs font-mono">{l.name}</span>
+                                    </label>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                    <textarea class="textarea textarea-bordered h-24 font-mono text-sm"
+                        placeholder=i18n.t("admin.api_keys.scopes_placeholder")
+                        prop:value=move || scopes.get()
+                        on:input=move |e| scopes.set(event_target_value(&e))
+                    ></textarea>
+                    <div class="flex justify-end">
+                        <button class="btn btn-primary btn-sm"
+                            disabled=move || name.get().trim().is_empty() || levels.get().is_empty()
+                            on:click=move |_| { create_action.dispatch(()); }
+                        >{i18n.t("admin.api_keys.create")}</button>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
 #[component]
 fn DocumentationFeedbackAdminPanel() -> impl IntoView {
+    let i18n = use_i18n();
     let (refresh_counter, set_refresh_counter) = signal(0u32);
     let (page, set_page) = signal(0u64);
     let (query, set_query) = signal(String::new());
@@ -511,21 +810,21 @@ fn DocumentationFeedbackAdminPanel() -> impl IntoView {
                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2z"></path>
                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M8 10h8M8 14h6"></path>
                         </svg>
-                        <h2 class="card-title text-2xl">"Documentation Feedback Registry"</h2>
+                        <h2 class="card-title text-2xl">{i18n.t("admin.feedback.title")}</h2>
                     </div>
                     <p class="text-base-content/60">
-                        "Admin-only registry of MCP-reported documentation gaps and improvement proposals. Use this to resolve, deduplicate, and prioritize documentation maintenance without turning Lekton into a ticket tracker."
+                        {i18n.t("admin.feedback.intro")}
                     </p>
                 </div>
 
                 <div class="px-8 pb-6">
                     <div class="grid grid-cols-1 gap-4 md:grid-cols-4 md:items-end">
                         <label class="form-control md:col-span-2">
-                            <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">"Search"</span>
+                            <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.feedback.search")}</span>
                             <input
                                 type="text"
                                 class="input input-bordered h-12 focus:input-primary"
-                                placeholder="Search title, summary, lekton://docs/ URI, or proposal"
+                                placeholder=i18n.t("admin.feedback.search_placeholder")
                                 prop:value=move || query.get()
                                 on:input=move |ev| {
                                     set_page.set(0);
@@ -535,7 +834,7 @@ fn DocumentationFeedbackAdminPanel() -> impl IntoView {
                         </label>
 
                         <label class="form-control">
-                            <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">"Kind"</span>
+                            <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.feedback.kind")}</span>
                             <select
                                 class="select select-bordered h-12 focus:select-primary"
                                 prop:value=move || kind_filter.get()
@@ -544,14 +843,14 @@ fn DocumentationFeedbackAdminPanel() -> impl IntoView {
                                     set_kind_filter.set(event_target_value(&ev));
                                 }
                             >
-                                <option value="">"All kinds"</option>
-                                <option value="missing_info">"Missing info"</option>
-                                <option value="improvement">"Improvement"</option>
+                                <option value="">{i18n.t("admin.feedback.all_kinds")}</option>
+                                <option value="missing_info">{i18n.t("admin.feedback.missing_info")}</option>
+                                <option value="improvement">{i18n.t("admin.feedback.improvement")}</option>
                             </select>
                         </label>
 
                         <label class="form-control">
-                            <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">"Status"</span>
+                            <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.common.status")}</span>
                             <select
                                 class="select select-bordered h-12 focus:select-primary"
                                 prop:value=move || status_filter.get()
@@ -560,9 +859,9 @@ fn DocumentationFeedbackAdminPanel() -> impl IntoView {
                                     set_status_filter.set(event_target_value(&ev));
                                 }
                             >
-                                <option value="open">"Open"</option>
-                                <option value="resolved">"Resolved"</option>
-                                <option value="">"All statuses"</option>
+                                <option value="open">{i18n.t("admin.feedback.open")}</option>
+                                <option value="resolved">{i18n.t("admin.feedback.resolved")}</option>
+                                <option value="">{i18n.t("admin.feedback.all_statuses")}</option>
                             </select>
                         </label>
                     </div>
@@ -572,7 +871,7 @@ fn DocumentationFeedbackAdminPanel() -> impl IntoView {
                     <Suspense fallback=move || view! {
                         <div class="flex flex-col items-center justify-center py-12 gap-4">
                             <span class="loading loading-spinner loading-lg text-primary"></span>
-                            <p class="text-sm font-medium animate-pulse">"Loading documentation feedback..."</p>
+                            <p class="text-sm font-medium animate-pulse">{i18n.t("admin.feedback.loading")}</p>
                         </div>
                     }>
                         {move || list_resource.get().map(|result| match result {
@@ -586,7 +885,7 @@ fn DocumentationFeedbackAdminPanel() -> impl IntoView {
                             }.into_any(),
                             Err(e) => view! {
                                 <div class="alert alert-error shadow-sm border-none bg-error/10 text-error">
-                                    <span>{format!("Failed to load documentation feedback: {e}")}</span>
+                                    <span>{i18n.tf("admin.feedback.load_error", &[("error", &e.to_string())])}</span>
                                 </div>
                             }.into_any(),
                         })}
@@ -605,12 +904,13 @@ fn DocumentationFeedbackList(
     set_page: WriteSignal<u64>,
     trigger_refresh: impl Fn() + Copy + Send + Sync + 'static,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     if result.items.is_empty() {
         return view! {
             <div class="flex flex-col items-center justify-center py-10 px-4 text-center border-2 border-dashed border-base-300 rounded-xl bg-base-200/20">
-                <h3 class="font-bold text-lg text-base-content/70">"No matching feedback items"</h3>
+                <h3 class="font-bold text-lg text-base-content/70">{i18n.t("admin.feedback.empty")}</h3>
                 <p class="text-sm text-base-content/50 max-w-xl mt-1">
-                    "The registry is empty for the selected filters. MCP agents will populate it through the documentation feedback tools."
+                    {i18n.t("admin.feedback.empty_hint")}
                 </p>
             </div>
         }.into_any();
@@ -623,8 +923,8 @@ fn DocumentationFeedbackList(
     view! {
         <div class="space-y-5">
             <div class="flex flex-wrap items-center justify-between gap-2 text-sm text-base-content/60">
-                <span>{format!("{} total item(s)", result.total)}</span>
-                <span>{format!("Page {} of {}", result.page + 1, total_pages)}</span>
+                <span>{i18n.tf("admin.feedback.total", &[("count", &result.total.to_string())])}</span>
+                <span>{i18n.tf("admin.common.page_of", &[("page", &(result.page + 1).to_string()), ("pages", &total_pages.to_string())])}</span>
             </div>
 
             <div class="space-y-4">
@@ -645,14 +945,14 @@ fn DocumentationFeedbackList(
                         }
                     })
                 >
-                    "Previous"
+                    {i18n.t("admin.common.previous")}
                 </button>
                 <button
                     class="btn btn-outline btn-sm"
                     disabled=!has_next
                     on:click=move |_| set_page.update(|value| *value += 1)
                 >
-                    "Next"
+                    {i18n.t("admin.common.next")}
                 </button>
             </div>
         </div>
@@ -664,6 +964,7 @@ fn DocumentationFeedbackCard(
     item: DocumentationFeedbackAdminItem,
     trigger_refresh: impl Fn() + Copy + Send + Sync + 'static,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let status_is_open = item.status == "open";
     let item_id_for_resolve = item.id.clone();
     let item_id_for_duplicate = item.id.clone();
@@ -715,76 +1016,85 @@ fn DocumentationFeedbackCard(
     let detail_sections = {
         let mut sections = Vec::new();
 
-        if let Some(view) =
-            documentation_feedback_detail_view("Related resources", item.related_resources.clone())
-        {
+        if let Some(view) = documentation_feedback_detail_view(
+            "admin.feedback.related_resources",
+            item.related_resources.clone(),
+        ) {
             sections.push(view);
         }
-        if let Some(view) =
-            documentation_feedback_detail_view("Search queries", item.search_queries.clone())
-        {
+        if let Some(view) = documentation_feedback_detail_view(
+            "admin.feedback.search_queries",
+            item.search_queries.clone(),
+        ) {
             sections.push(view);
         }
         if let Some(view) =
-            documentation_feedback_optional_view("User goal", item.user_goal.clone())
+            documentation_feedback_optional_view("admin.feedback.user_goal", item.user_goal.clone())
         {
             sections.push(view);
         }
         if let Some(view) = documentation_feedback_optional_view(
-            "Missing information",
+            "admin.feedback.missing_information",
             item.missing_information.clone(),
         ) {
             sections.push(view);
         }
-        if let Some(view) = documentation_feedback_optional_view("Impact", item.impact.clone()) {
+        if let Some(view) =
+            documentation_feedback_optional_view("admin.feedback.impact", item.impact.clone())
+        {
             sections.push(view);
         }
         if let Some(view) = documentation_feedback_optional_view(
-            "Suggested target resource",
+            "admin.feedback.suggested_target_resource",
             item.suggested_target_resource.clone(),
         ) {
             sections.push(view);
         }
         if let Some(view) = documentation_feedback_optional_view(
-            "Target resource",
+            "admin.feedback.target_resource",
             item.target_resource_uri.clone(),
         ) {
             sections.push(view);
         }
-        if let Some(view) =
-            documentation_feedback_optional_view("Problem summary", item.problem_summary.clone())
-        {
+        if let Some(view) = documentation_feedback_optional_view(
+            "admin.feedback.problem_summary",
+            item.problem_summary.clone(),
+        ) {
             sections.push(view);
         }
-        if let Some(view) = documentation_feedback_optional_view("Proposal", item.proposal.clone())
+        if let Some(view) =
+            documentation_feedback_optional_view("admin.feedback.proposal", item.proposal.clone())
         {
             sections.push(view);
         }
         if let Some(view) = documentation_feedback_detail_view(
-            "Supporting resources",
+            "admin.feedback.supporting_resources",
             item.supporting_resources.clone(),
         ) {
             sections.push(view);
         }
-        if let Some(view) =
-            documentation_feedback_optional_view("Expected benefit", item.expected_benefit.clone())
-        {
+        if let Some(view) = documentation_feedback_optional_view(
+            "admin.feedback.expected_benefit",
+            item.expected_benefit.clone(),
+        ) {
             sections.push(view);
         }
         if let Some(view) = documentation_feedback_detail_view(
-            "Related feedback ids",
+            "admin.feedback.related_feedback_ids",
             item.related_feedback_ids.clone(),
         ) {
             sections.push(view);
         }
-        if let Some(view) =
-            documentation_feedback_optional_view("Duplicate of", item.duplicate_of.clone())
-        {
+        if let Some(view) = documentation_feedback_optional_view(
+            "admin.feedback.duplicate_of",
+            item.duplicate_of.clone(),
+        ) {
             sections.push(view);
         }
-        if let Some(view) =
-            documentation_feedback_optional_view("Resolution note", item.resolution_note.clone())
-        {
+        if let Some(view) = documentation_feedback_optional_view(
+            "admin.feedback.resolution_note",
+            item.resolution_note.clone(),
+        ) {
             sections.push(view);
         }
 
@@ -834,10 +1144,10 @@ fn DocumentationFeedbackCard(
                     <div class="border-t border-base-200 pt-5">
                         <div class="grid grid-cols-1 gap-4 xl:grid-cols-[minmax(0,1fr)_20rem]">
                             <label class="form-control">
-                                <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">"Resolution note"</span>
+                                <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.feedback.resolution_note")}</span>
                                 <textarea
                                     class="textarea textarea-bordered min-h-28 focus:textarea-primary"
-                                    placeholder="Optional note describing how the item was resolved or why it was marked duplicate."
+                                    placeholder=i18n.t("admin.feedback.note_placeholder")
                                     prop:value=move || resolution_note.get()
                                     on:input=move |ev| set_resolution_note.set(event_target_value(&ev))
                                 ></textarea>
@@ -845,11 +1155,11 @@ fn DocumentationFeedbackCard(
 
                             <div class="flex h-full flex-col gap-3">
                                 <label class="form-control flex-1">
-                                    <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">"Duplicate of"</span>
+                                    <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.feedback.duplicate_of")}</span>
                                     <input
                                         type="text"
                                         class="input input-bordered h-12 focus:input-primary font-mono"
-                                        placeholder="Existing feedback id"
+                                        placeholder=i18n.t("admin.feedback.duplicate_placeholder")
                                         prop:value=move || duplicate_of.get()
                                         on:input=move |ev| set_duplicate_of.set(event_target_value(&ev))
                                     />
@@ -860,7 +1170,7 @@ fn DocumentationFeedbackCard(
                                         class="btn btn-outline sm:flex-1 xl:flex-none"
                                         on:click=move |_| { resolve_action.dispatch(()); }
                                     >
-                                        "Resolve"
+                                        {i18n.t("admin.feedback.resolve")}
                                     </button>
 
                                     <button
@@ -868,7 +1178,7 @@ fn DocumentationFeedbackCard(
                                         disabled=move || duplicate_of.get().trim().is_empty()
                                         on:click=move |_| { duplicate_action.dispatch(()); }
                                     >
-                                        "Mark Duplicate"
+                                        {i18n.t("admin.feedback.mark_duplicate")}
                                     </button>
                                 </div>
                             </div>
@@ -880,12 +1190,17 @@ fn DocumentationFeedbackCard(
     }
 }
 
-fn documentation_feedback_detail_view(title: &'static str, values: Vec<String>) -> Option<AnyView> {
+/// Labelled list of `values` under the `title_key` catalog message.
+fn documentation_feedback_detail_view(
+    title_key: &'static str,
+    values: Vec<String>,
+) -> Option<AnyView> {
     if values.is_empty() {
         return None;
     }
 
-    let multiline = title == "Search queries";
+    let multiline = title_key == "admin.feedback.search_queries";
+    let title = use_i18n().t(title_key);
 
     Some(view! {
         <div class="space-y-2">
@@ -914,10 +1229,11 @@ fn documentation_feedback_detail_view(title: &'static str, values: Vec<String>)
 }
 
 fn documentation_feedback_optional_view(
-    title: &'static str,
+    title_key: &'static str,
     value: Option<String>,
 ) -> Option<AnyView> {
     let value = value.filter(|value| !value.trim().is_empty())?;
+    let title = use_i18n().t(title_key);
 
     Some(view! {
         <div class="space-y-2">
@@ -952,10 +1268,11 @@ fn collect_sections(items: &[NavItem], level: u32, out: &mut Vec<OrderableItem>)
 /// Admin component for reordering navigation sections and categories.
 #[component]
 fn NavigationOrderEditor() -> impl IntoView {
+    let i18n = use_i18n();
     let (items, set_items) = signal(Vec::<OrderableItem>::new());
     let (original_slugs, set_original_slugs) = signal(Vec::<String>::new());
     let (saving, set_saving) = signal(false);
-    let (message, set_message) = signal(Option::<(bool, String)>::None);
+    let toasts = use_toasts();
     let (dragging_idx, set_dragging_idx) = signal(Option::<usize>::None);
 
     // Load nav tree and existing weights
@@ -997,7 +1314,6 @@ fn NavigationOrderEditor() -> impl IntoView {
         let current_items = items.get_untracked();
         async move {
             set_saving.set(true);
-            set_message.set(None);
 
             // Build entries with weights based on position within each level
             let entries: Vec<NavigationOrderEntry> = current_items
@@ -1015,10 +1331,10 @@ fn NavigationOrderEditor() -> impl IntoView {
             match result {
                 Ok(msg) => {
                     set_original_slugs.set(current_items.iter().map(|s| s.slug.clone()).collect());
-                    set_message.set(Some((true, msg)));
+                    toasts.success(msg);
                 }
                 Err(e) => {
-                    set_message.set(Some((false, e.to_string())));
+                    toasts.error(e.to_string());
                 }
             }
         }
@@ -1146,10 +1462,10 @@ fn NavigationOrderEditor() -> impl IntoView {
                         <svg class="w-6 h-6 text-primary" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 10h16M4 14h16M4 18h16"></path>
                         </svg>
-                        <h2 class="card-title text-2xl">"Navigation Ordering"</h2>
+                        <h2 class="card-title text-2xl">{i18n.t("admin.navigation.title")}</h2>
                     </div>
                     <p class="text-base-content/60">
-                        "Reorder sections and categories in the navigation. Drag items or use the arrow buttons to change their position. Documents within each section are always sorted by their own weight or alphabetically."
+                        {i18n.t("admin.navigation.intro")}
                     </p>
                 </div>
 
@@ -1157,7 +1473,7 @@ fn NavigationOrderEditor() -> impl IntoView {
                     <Suspense fallback=move || view! {
                         <div class="flex flex-col items-center justify-center py-12 gap-4">
                             <span class="loading loading-spinner loading-lg text-primary"></span>
-                            <p class="text-sm font-medium animate-pulse">"Loading navigation tree..."</p>
+                            <p class="text-sm font-medium animate-pulse">{i18n.t("admin.navigation.loading")}</p>
                         </div>
                     }>
                         {move || {
@@ -1170,8 +1486,8 @@ fn NavigationOrderEditor() -> impl IntoView {
                                                 <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 10h16M4 14h16M4 18h16"></path>
                                             </svg>
                                         </div>
-                                        <h3 class="font-bold text-lg text-base-content/70">"No sections found"</h3>
-                                        <p class="text-sm text-base-content/50 max-w-xs mt-1">"Sections will appear here once documents with hierarchical slugs are ingested."</p>
+                                        <h3 class="font-bold text-lg text-base-content/70">{i18n.t("admin.navigation.empty")}</h3>
+                                        <p class="text-sm text-base-content/50 max-w-xs mt-1">{i18n.t("admin.navigation.empty_hint")}</p>
                                     </div>
                                 }.into_any();
                             }
@@ -1182,7 +1498,11 @@ fn NavigationOrderEditor() -> impl IntoView {
                                         let indent = item.level;
                                         let slug = item.slug.clone();
                                         let title = item.title.clone();
-                                        let level_label = if indent == 0 { "Section" } else { "Category" };
+                                        let level_label = if indent == 0 {
+                                            i18n.t("admin.navigation.section")
+                                        } else {
+                                            i18n.t("admin.navigation.category")
+                                        };
 
                                         view! {
                                             <div
@@ -1214,7 +1534,7 @@ fn NavigationOrderEditor() -> impl IntoView {
                                                 <div class="flex gap-1 flex-shrink-0">
                                                     <button
                                                         class="btn btn-ghost btn-xs btn-square"
-                                                        title="Move up"
+                                                        title=i18n.t("admin.common.move_up")
                                                         on:click=move |_| move_item(idx, -1)
                                                     >
                                                         <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
@@ -1223,7 +1543,7 @@ fn NavigationOrderEditor() -> impl IntoView {
                                                     </button>
                                                     <button
                                                         class="btn btn-ghost btn-xs btn-square"
-                                                        title="Move down"
+                                                        title=i18n.t("admin.common.move_down")
                                                         on:click=move |_| move_item(idx, 1)
                                                     >
                                                         <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
@@ -1241,27 +1561,7 @@ fn NavigationOrderEditor() -> impl IntoView {
                 </div>
 
                 <div class="bg-base-200/30 p-8 pt-6 border-t border-base-200">
-                    <div class="flex flex-col sm:flex-row sm:items-center justify-between gap-4">
-                        <div class="min-h-[2.5rem]">
-                            <Show when=move || message.get().is_some()>
-                                {move || {
-                                    let (success, text) =
-                                        message.get().unwrap_or((false, String::new()));
-                                    let alert_class = if success { "alert-success bg-success/10 text-success" } else { "alert-error bg-error/10 text-error" };
-                                    view! {
-                                        <div class=format!("alert {alert_class} py-2 px-4 shadow-sm border-none flex items-center gap-2 text-sm font-semibold")>
-                                            {if success {
-                                                view! { <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M5 13l4 4L19 7"></path></svg> }.into_any()
-                                            } else {
-                                                view! { <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M6 18L18 6M6 6l12 12"></path></svg> }.into_any()
-                                            }}
-                                            <span>{text}</span>
-                                        </div>
-                                    }
-                                }}
-                            </Show>
-                        </div>
-
+                    <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
                         <div class="flex flex-col sm:flex-row gap-3 w-full sm:w-auto">
                             <button
                                 class="btn btn-ghost w-full sm:w-auto"
@@ -1272,7 +1572,7 @@ fn NavigationOrderEditor() -> impl IntoView {
                                     let _ = order_resource.get();
                                 }
                             >
-                                "Discard"
+                                {i18n.t("admin.common.discard")}
                             </button>
                             <button
                                 class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
@@ -1282,7 +1582,7 @@ fn NavigationOrderEditor() -> impl IntoView {
                                 {move || if saving.get() {
                                     view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
                                 } else {
-                                    view! { "Save Order" }.into_any()
+                                    view! { {i18n.t("admin.navigation.save")} }.into_any()
                                 }}
                             </button>
                         </div>
@@ -1369,10 +1669,11 @@ fn reorder_by_weights(
 /// Component for editing custom application CSS.
 #[component]
 fn CustomCssEditor() -> impl IntoView {
+    let i18n = use_i18n();
     let (css, set_css) = signal(String::new());
     let (original_css, set_original_css) = signal(String::new());
     let (saving, set_saving) = signal(false);
-    let (message, set_message) = signal(Option::<(bool, String)>::None);
+    let toasts = use_toasts();
 
     let load_resource = LocalResource::new(|| with_auth_retry(get_custom_css));
 
@@ -1387,16 +1688,15 @@ fn CustomCssEditor() -> impl IntoView {
         let new_css = new_css.clone();
         async move {
             set_saving.set(true);
-            set_message.set(None);
             let result = with_auth_retry(|| save_custom_css(new_css.clone())).await;
             set_saving.set(false);
             match result {
                 Ok(msg) => {
                     set_original_css.set(new_css);
-                    set_message.set(Some((true, msg)));
+                    toasts.success(msg);
                 }
                 Err(e) => {
-                    set_message.set(Some((false, e.to_string())));
+                    toasts.error(e.to_string());
                 }
             }
         }
@@ -1412,10 +1712,10 @@ fn CustomCssEditor() -> impl IntoView {
                         <svg class="w-6 h-6 text-primary" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M7 21a4 4 0 01-4-4V5a2 2 0 012-2h4a2 2 0 012 2v12a4 4 0 01-4 4zm0 0h12a2 2 0 002-2v-4a2 2 0 00-2-2h-2.343M11 7.343l1.172-1.172a4 4 0 115.656 5.656L15 13"></path>
                         </svg>
-                        <h2 class="card-title text-2xl">"Theming & Custom CSS"</h2>
+                        <h2 class="card-title text-2xl">{i18n.t("admin.css.title")}</h2>
                     </div>
                     <p class="text-base-content/60">
-                        "Customize the visual appearance of your Lekton instance. The CSS below is injected into every page at runtime."
+                        {i18n.t("admin.css.intro")}
                     </p>
                 </div>
 
@@ -1429,34 +1729,14 @@ fn CustomCssEditor() -> impl IntoView {
                         ></textarea>
                     </div>
 
-                    <div class="flex flex-col sm:flex-row sm:items-center justify-between gap-4">
-                        <div class="min-h-[2.5rem]">
-                            <Show when=move || message.get().is_some()>
-                                {move || {
-                                    let (success, text) =
-                                        message.get().unwrap_or((false, String::new()));
-                                    let alert_class = if success { "alert-success bg-success/10 text-success" } else { "alert-error bg-error/10 text-error" };
-                                    view! {
-                                        <div class=format!("alert {alert_class} py-2 px-4 shadow-sm border-none flex items-center gap-2 text-sm font-semibold")>
-                                            {if success {
-                                                view! { <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M5 13l4 4L19 7"></path></svg> }.into_any()
-                                            } else {
-                                                view! { <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M6 18L18 6M6 6l12 12"></path></svg> }.into_any()
-                                            }}
-                                            <span>{text}</span>
-                                        </div>
-                                    }
-                                }}
-                            </Show>
-                        </div>
-
+                    <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
                         <div class="flex flex-col sm:flex-row gap-3 w-full sm:w-auto">
                             <button
                                 class="btn btn-ghost w-full sm:w-auto"
                                 disabled=move || !has_changes() || saving.get()
                                 on:click=move |_| set_css.set(original_css.get())
                             >
-                                "Discard"
+                                {i18n.t("admin.common.discard")}
                             </button>
                             <button
                                 class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
@@ -1466,7 +1746,7 @@ fn CustomCssEditor() -> impl IntoView {
                                 {move || if saving.get() {
                                     view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
                                 } else {
-                                    view! { "Save Changes" }.into_any()
+                                    view! { {i18n.t("admin.common.save_changes")} }.into_any()
                                 }}
                             </button>
                         </div>
@@ -1477,139 +1757,2778 @@ fn CustomCssEditor() -> impl IntoView {
     }
 }
 
-/// Modal shown once after creating a token, displaying the raw token value.
-#[component]
-fn CreatedTokenModal(
-    token: ReadSignal<Option<CreateTokenResult>>,
-    set_token: WriteSignal<Option<CreateTokenResult>>,
-) -> impl IntoView {
-    let (copied, set_copied) = signal(false);
-
-    view! {
-        <Show when=move || token.get().is_some()>
-            <div class="fixed inset-0 z-[200] flex items-center justify-center bg-black/60 backdrop-blur-sm animate-in fade-in duration-300">
-                <div class="bg-base-100 rounded-3xl shadow-2xl w-full max-w-xl mx-4 p-10 relative overflow-hidden animate-in zoom-in-95 duration-300">
-                    <div class="absolute top-0 inset-x-0 h-2 bg-warning"></div>
-
-                    <div class="mb-8 flex items-center gap-4">
-                        <div class="w-12 h-12 bg-warning/10 rounded-2xl flex items-center justify-center text-warning">
-                          <svg xmlns="http://www.w3.org/2000/svg" class="h-8 w-8" fill="none" viewBox="0 0 24 24" stroke="currentColor">
-                              <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-2.5L13.732 4c-.77-.833-1.964-.833-2.732 0L4.082 16.5c-.77.833.192 2.5 1.732 2.5z" />
-                          </svg>
-                        </div>
-                        <div>
-                          <h3 class="font-black text-3xl tracking-tight">"Token Created"</h3>
-                          <p class="text-base-content/60 font-medium">"This is your only chance to copy it."</p>
-                        </div>
-                    </div>
-
-                    <div class="bg-warning/10 border border-warning/20 rounded-2xl p-6 mb-8 flex items-start gap-4">
-                        <div class="text-warning mt-1">
-                          <svg class="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z"></path></svg>
-                        </div>
-                        <p class="text-base-content text-sm font-semibold leading-relaxed">
-                          "For security reasons, we do not store the raw token. If you lose it, you will need to deactivate it and create a new one."
-                        </p>
-                    </div>
-
-                    {move || token.get().map(|t| {
-                        let raw = t.raw_token.clone();
-                        #[cfg(feature = "hydrate")]
-                        let raw_for_copy = t.raw_token.clone();
-                        let name = t.name.clone();
-                        let scopes_str = t.allowed_scopes.join(", ");
-                        view! {
-                            <div class="space-y-6">
-                                <div class="form-control">
-                                    <label class="label pt-0"><span class="label-text font-bold text-xs uppercase tracking-widest text-base-content/50">"Generated Token"</span></label>
-                                    <div class="relative group">
-                                        <input
-                                            type="text"
-                                            readonly
-                                            class="input input-bordered w-full font-mono text-lg py-8 pr-16 bg-base-200/50 border-base-300 focus:outline-none focus:border-warning/50 selection:bg-warning/20"
-                                            value=raw
-                                        />
-                                        <button
-                                            class="btn btn-warning shadow-lg shadow-warning/20 absolute right-2 top-1/2 -translate-y-1/2 normal-case font-bold"
-                                            on:click=move |_| {
-                                                #[cfg(feature = "hydrate")]
-                                                {
-                                                    let raw = raw_for_copy.clone();
-                                                    let _ = js_sys::eval(&format!(
-                                                        "navigator.clipboard.writeText('{}')",
-                                                        raw.replace('\'', "\\'")
-                                                    ));
-                                                    set_copied.set(true);
-                                                }
-                                            }
-                                        >
-                                            {move || if copied.get() {
-                                              view! { <span class="flex items-center gap-1"><svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M5 13l4 4L19 7"></path></svg>"Copied"</span> }.into_any()
-                                            } else {
-                                              view! { <span class="flex items-center gap-1"><svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M8 5H6a2 2 0 00-2 2v12a2 2 0 002 2h10a2 2 0 002-2v-1M8 5a2 2 0 002 2h2a2 2 0 002-2M8 5a2 2 0 002 2h2a2 2 0 002-2M8 5a2 2 0 012-2h2a2 2 0 012 2m0 0h2a2 2 0 012 2v3m2 4H10m0 0l3-3m-3 3l3 3"></path></svg>"Copy"</span> }.into_any()
-                                            }}
-                                        </button>
-                                    </div>
-                                </div>
-
-                                <div class="grid grid-cols-2 gap-8 py-6 border-y border-base-200">
-                                    <div>
-                                      <p class="text-[10px] font-black uppercase text-base-content/40 tracking-widest mb-1">"Internal Name"</p>
-                                      <p class="font-bold text-base">{name}</p>
-                                    </div>
-                                    <div>
-                                      <p class="text-[10px] font-black uppercase text-base-content/40 tracking-widest mb-1">"Scopes"</p>
-                                      <p class="font-mono text-xs truncate" title=scopes_str.clone()>{scopes_str.clone()}</p>
-                                    </div>
-                                </div>
-                            </div>
-                        }
-                    })}
-
-                    <div class="flex justify-end pt-8">
-                        <button
-                            class="btn btn-ghost hover:bg-base-200 w-full sm:w-64 font-bold"
-                            on:click=move |_| {
-                                set_token.set(None);
-                                set_copied.set(false);
-                            }
-                        >
-                            "I have saved the token"
-                        </button>
-                    </div>
-                </div>
-            </div>
-        </Show>
-    }
+/// Parse footer links from the "Label | URL" per-line editor format.
+fn parse_footer_links(text: &str) -> Vec<FooterLink> {
+    text.lines()
+        .filter_map(|line| {
+            let (label, url) = line.split_once('|')?;
+            let (label, url) = (label.trim(), url.trim());
+            (!label.is_empty() || !url.is_empty()).then(|| FooterLink {
+                label: label.to_string(),
+                url: url.to_string(),
+            })
+        })
+        .collect()
 }
 
-// ── Index Rebuilds ───────────────────────────────────────────────────────────
+fn format_footer_links(links: &[FooterLink]) -> String {
+    links
+        .iter()
+        .map(|l| format!("{} | {}", l.label, l.url))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
+/// Component for editing instance branding (name, logo, colours, homepage, footer).
 #[component]
-fn SearchReindexSection() -> impl IntoView {
-    let (poll_counter, set_poll_counter) = signal(0u32);
-    let (is_polling, set_is_polling) = signal(false);
-
-    let status_resource = LocalResource::new(move || {
-        let _ = poll_counter.get();
-        with_auth_retry(get_search_reindex_status)
-    });
+fn BrandingEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (portal_name, set_portal_name) = signal(String::new());
+    let (logo_url, set_logo_url) = signal(String::new());
+    let (primary_color, set_primary_color) = signal(String::new());
+    let (default_theme, set_default_theme) = signal(String::new());
+    let (hero_title, set_hero_title) = signal(String::new());
+    let (hero_subtitle, set_hero_subtitle) = signal(String::new());
+    let (homepage_slug, set_homepage_slug) = signal(String::new());
+    let (footer_links, set_footer_links) = signal(String::new());
+    let (access_request_url, set_access_request_url) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
 
-    let trigger_action = Action::new_local(move |_: &()| async move {
-        let result = with_auth_retry(trigger_search_reindex).await;
-        set_is_polling.set(true);
-        set_poll_counter.update(|c| *c += 1);
-        result
-    });
+    let load_resource = LocalResource::new(|| with_auth_retry(get_branding));
 
-    #[cfg(feature = "hydrate")]
-    Effect::new(move || {
-        if is_polling.get() {
-            use leptos::task::spawn_local;
-            spawn_local(async move {
-                gloo_timers::future::TimeoutFuture::new(2000).await;
-                set_poll_counter.update(|c| *c += 1);
-            });
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(branding)) = load_resource.get() {
+            set_portal_name.set(branding.portal_name);
+            set_logo_url.set(branding.logo_url.unwrap_or_default());
+            set_primary_color.set(branding.primary_color.unwrap_or_default());
+            set_default_theme.set(branding.default_theme.unwrap_or_default());
+            set_hero_title.set(branding.hero_title.unwrap_or_default());
+            set_hero_subtitle.set(branding.hero_subtitle.unwrap_or_default());
+            set_homepage_slug.set(branding.homepage_slug.unwrap_or_default());
+            set_footer_links.set(format_footer_links(&branding.footer_links));
+            set_access_request_url.set(branding.access_request_url.unwrap_or_default());
+        }
+    });
+
+    let save_action = Action::new_local(move |branding: &BrandingSettings| {
+        let branding = branding.clone();
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| save_branding(branding.clone())).await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => {
+                    toasts.success(i18n.tf_untracked("admin.branding.saved", &[("message", &msg)]))
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let on_save = move |_| {
+        let optional = |v: String| (!v.trim().is_empty()).then_some(v);
+        save_action.dispatch(BrandingSettings {
+            portal_name: portal_name.get(),
+            logo_url: optional(logo_url.get()),
+            primary_color: optional(primary_color.get()),
+            default_theme: optional(default_theme.get()),
+            hero_title: optional(hero_title.get()),
+            hero_subtitle: optional(hero_subtitle.get()),
+            homepage_slug: optional(homepage_slug.get()),
+            footer_links: parse_footer_links(&footer_links.get()),
+            access_request_url: optional(access_request_url.get()),
+        });
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.branding.title")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.branding.intro")}
+                    </p>
+                </div>
+
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    <label class="form-control">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.portal_name")}</span>
+                        <input
+                            type="text"
+                            class="input input-bordered"
+                            placeholder="Lekton"
+                            prop:value=move || portal_name.get()
+                            on:input=move |ev| set_portal_name.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.logo_url")}</span>
+                        <input
+                            type="text"
+                            class="input input-bordered"
+                            placeholder="https://example.com/logo.svg"
+                            prop:value=move || logo_url.get()
+                            on:input=move |ev| set_logo_url.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.primary_color")}</span>
+                        <div class="flex gap-2">
+                            <input
+                                type="color"
+                                class="h-12 w-12 cursor-pointer rounded border border-base-300"
+                                prop:value=move || {
+                                    let c = primary_color.get();
+                                    if c.len() == 7 { c } else { "#000000".to_string() }
+                                }
+                                on:input=move |ev| set_primary_color.set(event_target_value(&ev))
+                            />
+                            <input
+                                type="text"
+                                class="input input-bordered flex-1 font-mono"
+                                placeholder="#4f46e5"
+                                prop:value=move || primary_color.get()
+                                on:input=move |ev| set_primary_color.set(event_target_value(&ev))
+                            />
+                        </div>
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.default_theme")}</span>
+                        <select
+                            class="select select-bordered"
+                            prop:value=move || default_theme.get()
+                            on:change=move |ev| set_default_theme.set(event_target_value(&ev))
+                        >
+                            <option value="">{i18n.t("admin.branding.theme_system")}</option>
+                            <option value="light">{i18n.t("admin.branding.theme_light")}</option>
+                            <option value="dark">{i18n.t("admin.branding.theme_dark")}</option>
+                        </select>
+                    </label>
+                    <label class="form-control md:col-span-2">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.hero_title")}</span>
+                        <input
+                            type="text"
+                            class="input input-bordered"
+                            placeholder=i18n.t("admin.branding.hero_title_placeholder")
+                            prop:value=move || hero_title.get()
+                            on:input=move |ev| set_hero_title.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="form-control md:col-span-2">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.hero_subtitle")}</span>
+                        <textarea
+                            class="textarea textarea-bordered h-24"
+                            prop:value=move || hero_subtitle.get()
+                            on:input=move |ev| set_hero_subtitle.set(event_target_value(&ev))
+                        ></textarea>
+                    </label>
+                    <label class="form-control md:col-span-2">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.homepage")}</span>
+                        <input
+                            type="text"
+                            class="input input-bordered font-mono"
+                            placeholder="onboarding/welcome"
+                            prop:value=move || homepage_slug.get()
+                            on:input=move |ev| set_homepage_slug.set(event_target_value(&ev))
+                        />
+                        <span class="label-text-alt text-base-content/50 mt-1">{i18n.t("admin.branding.homepage_hint")}</span>
+                    </label>
+                    <label class="form-control md:col-span-2">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.footer_links")}</span>
+                        <textarea
+                            class="textarea textarea-bordered h-28 font-mono text-sm"
+                            placeholder={"Status | https://status.example.com\nSupport | /docs/support"}
+                            prop:value=move || footer_links.get()
+                            on:input=move |ev| set_footer_links.set(event_target_value(&ev))
+                        ></textarea>
+                        <span class="label-text-alt text-base-content/50 mt-1">{i18n.t("admin.branding.footer_links_hint")}</span>
+                    </label>
+                    <label class="form-control md:col-span-2">
+                        <span class="label-text font-medium mb-1">{i18n.t("admin.branding.access_request")}</span>
+                        <input
+                            type="text"
+                            class="input input-bordered"
+                            placeholder="mailto:docs-access@example.com"
+                            prop:value=move || access_request_url.get()
+                            on:input=move |ev| set_access_request_url.set(event_target_value(&ev))
+                        />
+                        <span class="label-text-alt text-base-content/50 mt-1">{i18n.t("admin.branding.access_request_hint")}</span>
+                    </label>
+                </div>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=on_save
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Parse link groups from the editor format: `[Group title]` lines start a
+/// group, `Label | URL` lines add a link. Links before any header are ungrouped.
+fn parse_nav_link_groups(text: &str) -> Vec<NavLinkGroup> {
+    let mut groups: Vec<NavLinkGroup> = Vec::new();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Some(title) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            groups.push(NavLinkGroup {
+                title: title.trim().to_string(),
+                links: vec![],
+            });
+        } else if let Some((label, url)) = line.split_once('|') {
+            if groups.is_empty() {
+                groups.push(NavLinkGroup::default());
+            }
+            groups.last_mut().unwrap().links.push(NavLink {
+                label: label.trim().to_string(),
+                url: url.trim().to_string(),
+            });
+        }
+    }
+    groups.retain(|g| !g.links.is_empty());
+    groups
+}
+
+fn format_nav_link_groups(groups: &[NavLinkGroup]) -> String {
+    let mut out = Vec::new();
+    for group in groups {
+        if !group.title.is_empty() {
+            out.push(format!("[{}]", group.title));
+        }
+        out.extend(
+            group
+                .links
+                .iter()
+                .map(|l| format!("{} | {}", l.label, l.url)),
+        );
+    }
+    out.join("\n")
+}
+
+/// Component for editing admin-defined navbar/sidebar links.
+#[component]
+fn NavLinksEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (text, set_text) = signal(String::new());
+    let (show_in_navbar, set_show_in_navbar) = signal(true);
+    let (show_in_sidebar, set_show_in_sidebar) = signal(true);
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let load_resource = LocalResource::new(|| with_auth_retry(get_nav_links));
+
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(links)) = load_resource.get() {
+            set_text.set(format_nav_link_groups(&links.groups));
+            set_show_in_navbar.set(links.show_in_navbar);
+            set_show_in_sidebar.set(links.show_in_sidebar);
+        }
+    });
+
+    let save_action = Action::new_local(move |links: &NavLinks| {
+        let links = links.clone();
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| save_nav_links(links.clone())).await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => toasts.success(msg),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let on_save = move |_| {
+        save_action.dispatch(NavLinks {
+            show_in_navbar: show_in_navbar.get(),
+            show_in_sidebar: show_in_sidebar.get(),
+            groups: parse_nav_link_groups(&text.get()),
+        });
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.links")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.links.intro")}
+                    </p>
+                </div>
+
+                <textarea
+                    class="textarea textarea-bordered h-64 font-mono text-sm leading-relaxed"
+                    placeholder={"[Observability]\nGrafana | https://grafana.example.com\nPagerDuty | https://example.pagerduty.com\n\n[Operations]\nIncident runbooks | /docs/runbooks"}
+                    prop:value=move || text.get()
+                    on:input=move |ev| set_text.set(event_target_value(&ev))
+                ></textarea>
+
+                <div class="flex flex-wrap gap-6">
+                    <label class="label cursor-pointer gap-3">
+                        <input
+                            type="checkbox"
+                            class="checkbox checkbox-primary checkbox-sm"
+                            prop:checked=move || show_in_navbar.get()
+                            on:change=move |ev| set_show_in_navbar.set(event_target_checked(&ev))
+                        />
+                        <span class="label-text">{i18n.t("admin.links.show_in_navbar")}</span>
+                    </label>
+                    <label class="label cursor-pointer gap-3">
+                        <input
+                            type="checkbox"
+                            class="checkbox checkbox-primary checkbox-sm"
+                            prop:checked=move || show_in_sidebar.get()
+                            on:change=move |ev| set_show_in_sidebar.set(event_target_checked(&ev))
+                        />
+                        <span class="label-text">{i18n.t("admin.links.show_in_sidebar")}</span>
+                    </label>
+                </div>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=on_save
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Documents and storage per service owner against their quotas.
+#[component]
+fn OwnerUsagePanel() -> impl IntoView {
+    let i18n = use_i18n();
+    let usage_resource = LocalResource::new(|| with_auth_retry(get_owner_usage));
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.usage.title")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.usage.intro")}
+                    </p>
+                </div>
+
+                <Suspense fallback=move || view! {
+                    <div class="flex justify-center py-8">
+                        <span class="loading loading-spinner loading-lg text-primary"></span>
+                    </div>
+                }>
+                    {move || usage_resource.get().map(|result| match result {
+                        Ok(usage) if usage.is_empty() => view! {
+                            <p class="text-sm text-base-content/60 italic">{i18n.t("admin.common.no_documents")}</p>
+                        }.into_any(),
+                        Ok(usage) => view! {
+                            <div class="overflow-x-auto">
+                                <table class="table table-sm">
+                                    <thead>
+                                        <tr>
+                                            <th>{i18n.t("admin.common.service_owner")}</th>
+                                            <th>{i18n.t("admin.title.documents")}</th>
+                                            <th>{i18n.t("admin.usage.storage")}</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {usage.into_iter().map(|owner| view! {
+                                            <tr>
+                                                <td class="font-mono text-sm">{owner.service_owner}</td>
+                                                <td>
+                                                    <UsageGauge
+                                                        used=owner.document_count
+                                                        limit=owner.max_documents
+                                                        label=owner.document_count.to_string()
+                                                        limit_label=owner.max_documents.map(|max| max.to_string())
+                                                    />
+                                                </td>
+                                                <td>
+                                                    <UsageGauge
+                                                        used=owner.storage_bytes
+                                                        limit=owner.max_storage_bytes
+                                                        label=crate::editor::asset_panel::format_size(owner.storage_bytes)
+                                                        limit_label=owner.max_storage_bytes.map(crate::editor::asset_panel::format_size)
+                                                    />
+                                                </td>
+                                            </tr>
+                                        }).collect_view()}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any(),
+                        Err(e) => view! {
+                            <div class="alert alert-error shadow-sm border-none bg-error/10 text-error">
+                                <span>{i18n.tf("admin.usage.load_error", &[("error", &e.to_string())])}</span>
+                            </div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+/// Every share link, with revocation and the access log of each.
+#[component]
+fn ShareLinksAdmin() -> impl IntoView {
+    let i18n = use_i18n();
+    let toasts = use_toasts();
+    let links_resource = LocalResource::new(|| with_auth_retry(|| list_share_links(None)));
+    let (selected, set_selected) = signal(None::<ShareLinkInfo>);
+
+    let accesses_resource = LocalResource::new(move || {
+        let link = selected.get();
+        async move {
+            match link {
+                Some(link) => with_auth_retry(|| list_share_link_accesses(link.id.clone()))
+                    .await
+                    .map(Some),
+                None => Ok(None),
+            }
+        }
+    });
+
+    let revoke_action = Action::new_local(move |id: &String| {
+        let id = id.clone();
+        async move {
+            match with_auth_retry(|| revoke_share_link(id.clone())).await {
+                Ok(()) => {
+                    toasts.success(i18n.t_untracked("admin.share_links.revoked_toast"));
+                    links_resource.refetch();
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.share_links")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.share_links.intro")}
+                    </p>
+                </div>
+
+                <Suspense fallback=move || view! {
+                    <div class="flex justify-center py-8">
+                        <span class="loading loading-spinner loading-lg text-primary"></span>
+                    </div>
+                }>
+                    {move || links_resource.get().map(|result| match result {
+                        Ok(links) if links.is_empty() => view! {
+                            <p class="text-sm text-base-content/60 italic">{i18n.t("admin.share_links.empty")}</p>
+                        }.into_any(),
+                        Ok(links) => view! {
+                            <div class="overflow-x-auto">
+                                <table class="table table-sm">
+                                    <thead>
+                                        <tr>
+                                            <th>{i18n.t("admin.common.document")}</th>
+                                            <th>{i18n.t("admin.share_links.note")}</th>
+                                            <th>{i18n.t("admin.share_links.created_by")}</th>
+                                            <th>{i18n.t("admin.share_links.expires")}</th>
+                                            <th>{i18n.t("admin.common.status")}</th>
+                                            <th>{i18n.t("admin.share_links.views")}</th>
+                                            <th></th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {links.into_iter().map(|link| {
+                                            let revoke_id = link.id.clone();
+                                            let show_link = link.clone();
+                                            let status = if link.active {
+                                                view! { <span class="badge badge-success badge-sm">{i18n.t("admin.share_links.active")}</span> }.into_any()
+                                            } else if let Some(by) = link.revoked_by.clone() {
+                                                view! { <span class="badge badge-ghost badge-sm" title=i18n.tf("admin.share_links.revoked_by", &[("user", &by)])>{i18n.t("admin.share_links.revoked")}</span> }.into_any()
+                                            } else {
+                                                view! { <span class="badge badge-ghost badge-sm">{i18n.t("admin.share_links.expired")}</span> }.into_any()
+                                            };
+                                            let last_accessed = link
+                                                .last_accessed_at
+                                                .clone()
+                                                .map(|at| i18n.tf("admin.share_links.last_viewed", &[("date", &at)]))
+                                                .unwrap_or_default();
+                                            view! {
+                                                <tr>
+                                                    <td>
+                                                        <a href=format!("/docs/{}", link.slug) class="link link-hover font-mono text-sm">{link.slug.clone()}</a>
+                                                    </td>
+                                                    <td class="text-sm">{link.note.clone()}</td>
+                                                    <td class="font-mono text-sm">{link.created_by.clone()}</td>
+                                                    <td class="text-sm whitespace-nowrap">{link.expires_at.clone()}</td>
+                                                    <td>{status}</td>
+                                                    <td class="text-sm" title=last_accessed>{link.access_count}</td>
+                                                    <td class="text-right whitespace-nowrap">
+                                                        <button
+                                                            class="btn btn-ghost btn-xs"
+                                                            on:click=move |_| set_selected.set(Some(show_link.clone()))
+                                                        >
+                                                            {i18n.t("admin.share_links.access_log")}
+                                                        </button>
+                                                        {link.active.then(|| view! {
+                                                            <button
+                                                                class="btn btn-ghost btn-xs text-error"
+                                                                on:click=move |_| { revoke_action.dispatch(revoke_id.clone()); }
+                                                            >
+                                                                {i18n.t("admin.share_links.revoke")}
+                                                            </button>
+                                                        })}
+                                                    </td>
+                                                </tr>
+                                            }
+                                        }).collect_view()}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any(),
+                        Err(e) => view! {
+                            <div class="alert alert-error">{e.to_string()}</div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+
+                {move || selected.get().map(|link| view! {
+                    <div class="border-t border-base-200 pt-6 space-y-3">
+                        <div class="flex items-center justify-between">
+                            <h3 class="font-bold">
+                                {i18n.tf("admin.share_links.access_log_for", &[("slug", &link.slug)])}
+                                {(!link.note.is_empty()).then(|| view! {
+                                    <span class="font-normal text-base-content/60">{format!(" ({})", link.note)}</span>
+                                })}
+                            </h3>
+                            <button class="btn btn-ghost btn-xs" on:click=move |_| set_selected.set(None)>{i18n.t("admin.common.close")}</button>
+                        </div>
+                        <Suspense fallback=|| view! { <div class="skeleton h-20 w-full" /> }>
+                            {move || accesses_resource.get().map(|result| match result {
+                                Ok(Some(accesses)) if accesses.is_empty() => view! {
+                                    <p class="text-sm text-base-content/60 italic">{i18n.t("admin.share_links.never_used")}</p>
+                                }.into_any(),
+                                Ok(Some(accesses)) => view! {
+                                    <table class="table table-xs">
+                                        <thead>
+                                            <tr>
+                                                <th>{i18n.t("admin.share_links.when")}</th>
+                                                <th>{i18n.t("admin.share_links.forwarded_for")}</th>
+                                                <th>{i18n.t("admin.share_links.user_agent")}</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            {accesses.into_iter().map(|access| view! {
+                                                <tr>
+                                                    <td class="whitespace-nowrap">{access.accessed_at}</td>
+                                                    <td class="font-mono">{access.forwarded_for.unwrap_or_default()}</td>
+                                                    <td class="text-base-content/60">{access.user_agent.unwrap_or_default()}</td>
+                                                </tr>
+                                            }).collect_view()}
+                                        </tbody>
+                                    </table>
+                                }.into_any(),
+                                Ok(None) => view! { <span></span> }.into_any(),
+                                Err(e) => view! {
+                                    <div class="alert alert-error">{e.to_string()}</div>
+                                }.into_any(),
+                            })}
+                        </Suspense>
+                    </div>
+                })}
+            </div>
+        </div>
+    }
+}
+
+/// "Was this helpful?" totals per service owner and document, least
+/// helpful first, with the comments left on each document.
+#[component]
+fn DocRatingsReport() -> impl IntoView {
+    let i18n = use_i18n();
+    let report_resource = LocalResource::new(|| with_auth_retry(get_doc_rating_report));
+    let (owner_filter, set_owner_filter) = signal(None::<String>);
+    let (selected, set_selected) = signal(None::<String>);
+
+    let comments_resource = LocalResource::new(move || {
+        let slug = selected.get();
+        async move {
+            match slug {
+                Some(slug) => with_auth_retry(|| list_doc_rating_comments(slug.clone()))
+                    .await
+                    .map(Some),
+                None => Ok(None),
+            }
+        }
+    });
+
+    let percent = |counts: crate::app::RatingCounts| {
+        counts
+            .helpful_percent()
+            .map(|p| format!("{p}%"))
+            .unwrap_or_default()
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.ratings.title")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.ratings.intro")}
+                    </p>
+                </div>
+
+                <Suspense fallback=move || view! {
+                    <div class="flex justify-center py-8">
+                        <span class="loading loading-spinner loading-lg text-primary"></span>
+                    </div>
+                }>
+                    {move || report_resource.get().map(|result| match result {
+                        Ok(report) if report.documents.is_empty() => view! {
+                            <p class="text-sm text-base-content/60 italic">{i18n.t("admin.ratings.empty")}</p>
+                        }.into_any(),
+                        Ok(report) => {
+                            let filter = owner_filter.get();
+                            let documents: Vec<_> = report
+                                .documents
+                                .into_iter()
+                                .filter(|doc| filter.as_ref().is_none_or(|owner| *owner == doc.service_owner))
+                                .collect();
+                            view! {
+                                <div class="overflow-x-auto">
+                                    <table class="table table-sm">
+                                        <thead>
+                                            <tr>
+                                                <th>{i18n.t("admin.common.service_owner")}</th>
+                                                <th>{i18n.t("admin.ratings.rated_documents")}</th>
+                                                <th>{i18n.t("admin.ratings.helpful")}</th>
+                                                <th>{i18n.t("admin.ratings.not_helpful")}</th>
+                                                <th>{i18n.t("admin.ratings.helpful_percent")}</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            {report.owners.into_iter().map(|owner| {
+                                                let name = owner.service_owner.clone();
+                                                let active = filter.as_ref() == Some(&owner.service_owner);
+                                                view! {
+                                                    <tr class="hover cursor-pointer" class:bg-base-200=active
+                                                        on:click=move |_| set_owner_filter.set((!active).then(|| name.clone()))
+                                                    >
+                                                        <td class="font-mono text-sm">{owner.service_owner}</td>
+                                                        <td>{owner.document_count}</td>
+                                                        <td>{owner.counts.helpful}</td>
+                                                        <td>{owner.counts.not_helpful}</td>
+                                                        <td class="font-semibold">{percent(owner.counts)}</td>
+                                                    </tr>
+                                                }
+                                            }).collect_view()}
+                                        </tbody>
+                                    </table>
+                                </div>
+                                <div class="overflow-x-auto">
+                                    <table class="table table-sm">
+                                        <thead>
+                                            <tr>
+                                                <th>{i18n.t("admin.common.document")}</th>
+                                                <th>{i18n.t("admin.common.service_owner")}</th>
+                                                <th>{i18n.t("admin.ratings.helpful")}</th>
+                                                <th>{i18n.t("admin.ratings.not_helpful")}</th>
+                                                <th>{i18n.t("admin.ratings.helpful_percent")}</th>
+                                                <th></th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            {documents.into_iter().map(|doc| {
+                                                let slug = doc.slug.clone();
+                                                view! {
+                                                    <tr>
+                                                        <td>
+                                                            <a href=format!("/docs/{}", doc.slug) class="link link-hover" title=doc.slug.clone()>{doc.title}</a>
+                                                        </td>
+                                                        <td class="font-mono text-sm">{doc.service_owner}</td>
+                                                        <td>{doc.counts.helpful}</td>
+                                                        <td>{doc.counts.not_helpful}</td>
+                                                        <td class="font-semibold">{percent(doc.counts)}</td>
+                                                        <td class="text-right">
+                                                            {(doc.comment_count > 0).then(|| view! {
+                                                                <button
+                                                                    class="btn btn-ghost btn-xs"
+                                                                    on:click=move |_| set_selected.set(Some(slug.clone()))
+                                                                >
+                                                                    {i18n.tf("admin.ratings.comments_count", &[("count", &doc.comment_count.to_string())])}
+                                                                </button>
+                                                            })}
+                                                        </td>
+                                                    </tr>
+                                                }
+                                            }).collect_view()}
+                                        </tbody>
+                                    </table>
+                                </div>
+                            }.into_any()
+                        }
+                        Err(e) => view! {
+                            <div class="alert alert-error">{e.to_string()}</div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+
+                {move || selected.get().map(|slug| view! {
+                    <div class="border-t border-base-200 pt-6 space-y-3">
+                        <div class="flex items-center justify-between">
+                            <h3 class="font-bold">{i18n.tf("admin.ratings.comments_for", &[("slug", &slug)])}</h3>
+                            <button class="btn btn-ghost btn-xs" on:click=move |_| set_selected.set(None)>{i18n.t("admin.common.close")}</button>
+                        </div>
+                        <Suspense fallback=|| view! { <div class="skeleton h-20 w-full" /> }>
+                            {move || comments_resource.get().map(|result| match result {
+                                Ok(Some(comments)) => view! {
+                                    <ul class="space-y-3">
+                                        {comments.into_iter().map(|comment| view! {
+                                            <li class="text-sm">
+                                                <div class="flex items-center gap-2 text-base-content/60">
+                                                    {if comment.helpful {
+                                                        view! { <span class="badge badge-success badge-sm">{i18n.t("admin.ratings.helpful")}</span> }.into_any()
+                                                    } else {
+                                                        view! { <span class="badge badge-warning badge-sm">{i18n.t("admin.ratings.not_helpful")}</span> }.into_any()
+                                                    }}
+                                                    <span class="font-mono">{comment.user_email}</span>
+                                                    <span>{comment.updated_at}</span>
+                                                </div>
+                                                <p class="mt-1 whitespace-pre-line" inner_html=comment.comment_html></p>
+                                            </li>
+                                        }).collect_view()}
+                                    </ul>
+                                }.into_any(),
+                                Ok(None) => view! { <span></span> }.into_any(),
+                                Err(e) => view! {
+                                    <div class="alert alert-error">{e.to_string()}</div>
+                                }.into_any(),
+                            })}
+                        </Suspense>
+                    </div>
+                })}
+            </div>
+        </div>
+    }
+}
+
+/// Documents with lint findings, filterable by rule.
+#[component]
+fn DocQualityReport() -> impl IntoView {
+    use crate::rendering::lint::LintRule;
+
+    let i18n = use_i18n();
+    let report_resource = LocalResource::new(|| with_auth_retry(get_doc_quality_report));
+    let (rule_filter, set_rule_filter) = signal(String::new());
+
+    let matches = move |entry: &DocQualityEntry| {
+        let rule = rule_filter.get();
+        rule.is_empty() || entry.findings.iter().any(|f| f.rule.name() == rule)
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div class="flex flex-wrap items-end justify-between gap-4">
+                    <div>
+                        <h2 class="card-title text-2xl">{i18n.t("admin.quality.title")}</h2>
+                        <p class="text-base-content/60">
+                            {i18n.t("admin.quality.intro")}
+                        </p>
+                    </div>
+                    <label class="form-control">
+                        <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.quality.rule")}</span>
+                        <select
+                            class="select select-bordered select-sm"
+                            prop:value=move || rule_filter.get()
+                            on:change=move |ev| set_rule_filter.set(event_target_value(&ev))
+                        >
+                            <option value="">{i18n.t("admin.quality.all_rules")}</option>
+                            {LintRule::ALL.into_iter().map(|rule| view! {
+                                <option value=rule.name()>{rule.name()}</option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                </div>
+
+                <Suspense fallback=move || view! {
+                    <div class="flex justify-center py-8">
+                        <span class="loading loading-spinner loading-lg text-primary"></span>
+                    </div>
+                }>
+                    {move || report_resource.get().map(|result| match result {
+                        Ok(entries) if entries.is_empty() => view! {
+                            <p class="text-sm text-base-content/60 italic">{i18n.t("admin.quality.empty")}</p>
+                        }.into_any(),
+                        Ok(entries) => view! {
+                            <div class="overflow-x-auto">
+                                <table class="table table-sm">
+                                    <thead>
+                                        <tr>
+                                            <th>{i18n.t("admin.common.document")}</th>
+                                            <th>{i18n.t("admin.common.service_owner")}</th>
+                                            <th>{i18n.t("admin.quality.findings")}</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {entries.into_iter().filter(matches).map(|entry| view! {
+                                            <tr class="align-top">
+                                                <td>
+                                                    <a href=format!("/docs/{}", entry.slug) class="link link-hover font-medium">{entry.title}</a>
+                                                    <div class="font-mono text-xs text-base-content/50">{entry.slug}</div>
+                                                </td>
+                                                <td class="font-mono text-sm">{entry.service_owner}</td>
+                                                <td>
+                                                    <ul class="text-sm space-y-0.5">
+                                                        {entry.findings.into_iter().map(|finding| view! {
+                                                            <li>
+                                                                <span class="font-mono text-base-content/50">{format!("L{} ", finding.line)}</span>
+                                                                {finding.message}
+                                                                " "
+                                                                <span class="badge badge-ghost badge-sm font-mono">{finding.rule.name()}</span>
+                                                            </li>
+                                                        }).collect_view()}
+                                                    </ul>
+                                                </td>
+                                            </tr>
+                                        }).collect_view()}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any(),
+                        Err(e) => view! {
+                            <div class="alert alert-error shadow-sm border-none bg-error/10 text-error">
+                                <span>{i18n.tf("admin.quality.load_error", &[("error", &e.to_string())])}</span>
+                            </div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn UsageGauge(
+    used: u64,
+    limit: Option<u64>,
+    label: String,
+    limit_label: Option<String>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    match (limit, limit_label) {
+        (Some(limit), Some(limit_label)) => {
+            let percent = (used.saturating_mul(100) / limit.max(1)).min(100);
+            let color = if percent >= 90 {
+                "progress-error"
+            } else if percent >= 75 {
+                "progress-warning"
+            } else {
+                "progress-primary"
+            };
+            view! {
+                <div class="flex flex-col gap-1 min-w-40">
+                    <span class="text-sm">{format!("{label} / {limit_label}")}</span>
+                    <progress class=format!("progress {color} w-full") value=percent.to_string() max="100"></progress>
+                </div>
+            }
+            .into_any()
+        }
+        _ => view! {
+            <span class="text-sm">{label} <span class="text-base-content/50">" "{i18n.t("admin.usage.unlimited")}</span></span>
+        }
+        .into_any(),
+    }
+}
+
+/// Build the batch update for one bulk action of the documents table.
+/// `value` is the new owner, access level or parent, or a comma-separated
+/// tag list. Errors are catalog keys of the validation message.
+fn document_batch_update(
+    action: &str,
+    value: &str,
+    slugs: Vec<String>,
+) -> Result<crate::db::models::DocumentBatchUpdate, &'static str> {
+    let value = value.trim();
+    let tags = || -> Result<Vec<String>, &'static str> {
+        let tags: Vec<String> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect();
+        if tags.is_empty() {
+            Err("admin.documents.tags_required")
+        } else {
+            Ok(tags)
+        }
+    };
+    let mut update = crate::db::models::DocumentBatchUpdate {
+        slugs,
+        ..Default::default()
+    };
+    match action {
+        "owner" if value.is_empty() => return Err("admin.documents.owner_required"),
+        "owner" => update.service_owner = Some(value.to_string()),
+        "access_level" if value.is_empty() => return Err("admin.documents.access_level_required"),
+        "access_level" => update.access_level = Some(value.to_string()),
+        "add_tags" => update.add_tags = tags()?,
+        "remove_tags" => update.remove_tags = tags()?,
+        "move" => update.parent_slug = Some(value.to_string()),
+        "delete" => update.delete = true,
+        _ => return Err("admin.documents.action_required"),
+    }
+    Ok(update)
+}
+
+/// Table of every active document with bulk actions on the selected rows.
+#[component]
+fn DocumentBulkEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (refresh_counter, set_refresh_counter) = signal(0u32);
+    let (filter, set_filter) = signal(String::new());
+    let selected = RwSignal::new(Vec::<String>::new());
+    let (bulk_action, set_bulk_action) = signal("owner".to_string());
+    let (value, set_value) = signal(String::new());
+    let toasts = use_toasts();
+
+    let docs_resource = LocalResource::new(move || {
+        let _ = refresh_counter.get();
+        with_auth_retry(list_admin_documents)
+    });
+
+    let matches = move |doc: &AdminDocumentInfo| {
+        let filter = filter.get().to_lowercase();
+        filter.is_empty()
+            || doc.slug.to_lowercase().contains(&filter)
+            || doc.title.to_lowercase().contains(&filter)
+            || doc.service_owner.to_lowercase().contains(&filter)
+            || doc.tags.iter().any(|t| t.to_lowercase().contains(&filter))
+    };
+    let visible_slugs = move || -> Vec<String> {
+        docs_resource
+            .get()
+            .and_then(Result::ok)
+            .map(|docs| {
+                docs.iter()
+                    .filter(|d| matches(d))
+                    .map(|d| d.slug.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let apply_action = Action::new_local(move |update: &crate::db::models::DocumentBatchUpdate| {
+        let update = update.clone();
+        async move {
+            match with_auth_retry(|| batch_update_admin_documents(update.clone())).await {
+                Ok(result) => {
+                    let count = result.updated.len().to_string();
+                    toasts.success(if result.not_found.is_empty() {
+                        i18n.tf_untracked("admin.documents.updated", &[("count", &count)])
+                    } else {
+                        i18n.tf_untracked(
+                            "admin.documents.updated_not_found",
+                            &[("count", &count), ("slugs", &result.not_found.join(", "))],
+                        )
+                    });
+                    selected.set(vec![]);
+                    set_value.set(String::new());
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+            set_refresh_counter.update(|value| *value += 1);
+        }
+    });
+
+    let on_apply = move |_| {
+        let slugs = selected.get_untracked();
+        match document_batch_update(&bulk_action.get_untracked(), &value.get_untracked(), slugs) {
+            Ok(update) => {
+                if update.delete
+                    && !window()
+                        .confirm_with_message(&i18n.tf_untracked(
+                            "admin.documents.delete_confirm",
+                            &[("count", &update.slugs.len().to_string())],
+                        ))
+                        .unwrap_or(false)
+                {
+                    return;
+                }
+                apply_action.dispatch(update);
+            }
+            Err(key) => toasts.error(i18n.t_untracked(key)),
+        }
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.documents")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.documents.intro")}
+                    </p>
+                </div>
+
+                <div class="flex flex-col lg:flex-row gap-3 lg:items-end">
+                    <label class="form-control flex-1">
+                        <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.documents.filter")}</span>
+                        <input
+                            type="text"
+                            class="input input-bordered input-sm"
+                            placeholder=i18n.t("admin.documents.filter_placeholder")
+                            prop:value=move || filter.get()
+                            on:input=move |ev| set_filter.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.documents.action")}</span>
+                        <select
+                            class="select select-bordered select-sm"
+                            prop:value=move || bulk_action.get()
+                            on:change=move |ev| set_bulk_action.set(event_target_value(&ev))
+                        >
+                            <option value="owner">{i18n.t("admin.documents.change_owner")}</option>
+                            <option value="access_level">{i18n.t("admin.documents.change_access_level")}</option>
+                            <option value="add_tags">{i18n.t("admin.documents.add_tags")}</option>
+                            <option value="remove_tags">{i18n.t("admin.documents.remove_tags")}</option>
+                            <option value="move">{i18n.t("admin.documents.move")}</option>
+                            <option value="delete">{i18n.t("admin.common.delete")}</option>
+                        </select>
+                    </label>
+                    <Show when=move || bulk_action.get() != "delete">
+                        <label class="form-control flex-1">
+                            <span class="label-text font-bold text-xs uppercase tracking-wider text-base-content/60 mb-2">{i18n.t("admin.documents.value")}</span>
+                            <input
+                                type="text"
+                                class="input input-bordered input-sm font-mono"
+                                placeholder=move || match bulk_action.get().as_str() {
+                                    "owner" => "payments-team",
+                                    "access_level" => "internal",
+                                    "move" => i18n.t("admin.documents.parent_placeholder"),
+                                    _ => "tag-a, tag-b",
+                                }
+                                prop:value=move || value.get()
+                                on:input=move |ev| set_value.set(event_target_value(&ev))
+                            />
+                        </label>
+                    </Show>
+                    <button
+                        class=move || if bulk_action.get() == "delete" { "btn btn-error btn-sm" } else { "btn btn-primary btn-sm" }
+                        disabled=move || selected.with(Vec::is_empty) || apply_action.pending().get()
+                        on:click=on_apply
+                    >
+                        {move || i18n.tf("admin.documents.apply", &[("count", &selected.with(Vec::len).to_string())])}
+                    </button>
+                </div>
+
+
+                <Suspense fallback=move || view! {
+                    <div class="flex justify-center py-8">
+                        <span class="loading loading-spinner loading-lg text-primary"></span>
+                    </div>
+                }>
+                    {move || docs_resource.get().map(|result| match result {
+                        Ok(docs) if docs.is_empty() => view! {
+                            <p class="text-sm text-base-content/60 italic">{i18n.t("admin.common.no_documents")}</p>
+                        }.into_any(),
+                        Ok(docs) => view! {
+                            <div class="overflow-x-auto max-h-[36rem]">
+                                <table class="table table-sm table-pin-rows">
+                                    <thead>
+                                        <tr>
+                                            <th>
+                                                <input
+                                                    type="checkbox"
+                                                    class="checkbox checkbox-xs"
+                                                    title=i18n.t("admin.documents.select_all")
+                                                    prop:checked=move || {
+                                                        let shown = visible_slugs();
+                                                        !shown.is_empty() && selected.with(|s| shown.iter().all(|slug| s.contains(slug)))
+                                                    }
+                                                    on:change=move |ev| {
+                                                        let shown = visible_slugs();
+                                                        if event_target_checked(&ev) {
+                                                            selected.update(|s| {
+                                                                for slug in shown {
+                                                                    if !s.contains(&slug) {
+                                                                        s.push(slug);
+                                                                    }
+                                                                }
+                                                            });
+                                                        } else {
+                                                            selected.update(|s| s.retain(|slug| !shown.contains(slug)));
+                                                        }
+                                                    }
+                                                />
+                                            </th>
+                                            <th>{i18n.t("admin.common.document")}</th>
+                                            <th>{i18n.t("admin.documents.owner")}</th>
+                                            <th>{i18n.t("admin.documents.access")}</th>
+                                            <th>{i18n.t("admin.documents.tags")}</th>
+                                            <th>{i18n.t("admin.documents.parent")}</th>
+                                            <th>{i18n.t("admin.documents.updated_at")}</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {docs.into_iter().map(|doc| {
+                                            let shown = {
+                                                let doc = doc.clone();
+                                                move || matches(&doc)
+                                            };
+                                            let slug = doc.slug.clone();
+                                            let toggle_slug = doc.slug.clone();
+                                            view! {
+                                                <tr class:hidden=move || !shown()>
+                                                    <td>
+                                                        <input
+                                                            type="checkbox"
+                                                            class="checkbox checkbox-xs"
+                                                            prop:checked=move || selected.with(|s| s.contains(&slug))
+                                                            on:change=move |ev| {
+                                                                let slug = toggle_slug.clone();
+                                                                if event_target_checked(&ev) {
+                                                                    selected.update(|s| if !s.contains(&slug) { s.push(slug) });
+                                                                } else {
+                                                                    selected.update(|s| s.retain(|other| *other != slug));
+                                                                }
+                                                            }
+                                                        />
+                                                    </td>
+                                                    <td class="min-w-48">
+                                                        <a href=format!("/docs/{}", doc.slug) class="link link-hover font-medium">{doc.title}</a>
+                                                        <div class="text-xs font-mono text-base-content/60">{doc.slug.clone()}</div>
+                                                        {doc.is_draft.then(|| view! { <span class="badge badge-ghost badge-xs mr-1">{i18n.t("admin.documents.draft")}</span> })}
+                                                        {doc.is_hidden.then(|| view! { <span class="badge badge-ghost badge-xs">{i18n.t("admin.documents.hidden")}</span> })}
+                                                    </td>
+                                                    <td class="font-mono text-xs">{doc.service_owner}</td>
+                                                    <td class="font-mono text-xs">{doc.access_level}</td>
+                                                    <td class="text-xs">{doc.tags.join(", ")}</td>
+                                                    <td class="font-mono text-xs">{doc.parent_slug.unwrap_or_default()}</td>
+                                                    <td class="text-xs whitespace-nowrap">{doc.last_updated}</td>
+                                                </tr>
+                                            }
+                                        }).collect_view()}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any(),
+                        Err(e) => view! {
+                            <div class="alert alert-error shadow-sm border-none bg-error/10 text-error">
+                                <span>{i18n.tf("admin.documents.load_error", &[("error", &e.to_string())])}</span>
+                            </div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+/// Ingests to protected slugs waiting for approval, with their content for review.
+#[component]
+fn PendingIngestQueue() -> impl IntoView {
+    let i18n = use_i18n();
+    let (refresh_counter, set_refresh_counter) = signal(0u32);
+    let toasts = use_toasts();
+
+    let list_resource = LocalResource::new(move || {
+        let _ = refresh_counter.get();
+        with_auth_retry(list_pending_ingests)
+    });
+
+    let decide_action = Action::new_local(move |(id, approve): &(String, bool)| {
+        let id = id.clone();
+        let approve = *approve;
+        async move {
+            let result = if approve {
+                with_auth_retry(|| approve_pending_ingest(id.clone())).await
+            } else {
+                with_auth_retry(|| reject_pending_ingest(id.clone())).await
+            };
+            match result {
+                Ok(msg) => toasts.success(msg),
+                Err(e) => toasts.error(e.to_string()),
+            }
+            set_refresh_counter.update(|value| *value += 1);
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.approvals.title")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.approvals.intro")}
+                    </p>
+                </div>
+
+
+                <Suspense fallback=move || view! {
+                    <div class="flex justify-center py-8">
+                        <span class="loading loading-spinner loading-lg text-primary"></span>
+                    </div>
+                }>
+                    {move || list_resource.get().map(|result| match result {
+                        Ok(pending) if pending.is_empty() => view! {
+                            <p class="text-sm text-base-content/60 italic">{i18n.t("admin.approvals.empty")}</p>
+                        }.into_any(),
+                        Ok(pending) => view! {
+                            <div class="space-y-4">
+                                {pending.into_iter().map(|item| view! {
+                                    <PendingIngestCard item=item decide=decide_action />
+                                }).collect_view()}
+                            </div>
+                        }.into_any(),
+                        Err(e) => view! {
+                            <div class="alert alert-error shadow-sm border-none bg-error/10 text-error">
+                                <span>{i18n.tf("admin.approvals.load_error", &[("error", &e.to_string())])}</span>
+                            </div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn PendingIngestCard(item: PendingIngestInfo, decide: Action<(String, bool), ()>) -> impl IntoView {
+    let i18n = use_i18n();
+    let approve_id = item.id.clone();
+    let reject_id = item.id.clone();
+    let changes = item.changes.join(", ");
+
+    view! {
+        <div class="border border-base-200 rounded-xl p-4 space-y-3">
+            <div class="flex flex-col sm:flex-row sm:items-start justify-between gap-3">
+                <div class="min-w-0">
+                    <p class="font-semibold truncate">{item.title}</p>
+                    <p class="text-xs font-mono text-base-content/60 truncate">{item.slug}</p>
+                    <p class="text-xs text-base-content/60 mt-1">
+                        {format!("{} · {} · {}", item.submitted_by, item.submitted_at, item.access_level)}
+                    </p>
+                    <p class="text-xs text-base-content/60">{i18n.tf("admin.approvals.changes", &[("changes", &changes)])}</p>
+                </div>
+                <div class="flex gap-2 shrink-0">
+                    <button
+                        class="btn btn-success btn-sm"
+                        disabled=move || decide.pending().get()
+                        on:click=move |_| { decide.dispatch((approve_id.clone(), true)); }
+                    >
+                        {i18n.t("admin.approvals.approve")}
+                    </button>
+                    <button
+                        class="btn btn-ghost btn-sm text-error"
+                        disabled=move || decide.pending().get()
+                        on:click=move |_| { decide.dispatch((reject_id.clone(), false)); }
+                    >
+                        {i18n.t("admin.approvals.reject")}
+                    </button>
+                </div>
+            </div>
+            <details class="collapse collapse-arrow bg-base-200/50">
+                <summary class="collapse-title text-sm font-medium">{i18n.t("admin.approvals.content")}</summary>
+                <div class="collapse-content">
+                    <pre class="text-xs whitespace-pre-wrap max-h-96 overflow-auto">{item.content}</pre>
+                </div>
+            </details>
+        </div>
+    }
+}
+
+/// Editor for the slug prefixes whose ingests need approval.
+#[component]
+fn ProtectedPrefixesEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (text, set_text) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let load_resource = LocalResource::new(|| with_auth_retry(get_protected_prefixes));
+
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(prefixes)) = load_resource.get() {
+            set_text.set(prefixes.join("\n"));
+        }
+    });
+
+    let save_action = Action::new_local(move |prefixes: &Vec<String>| {
+        let prefixes = prefixes.clone();
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| save_protected_prefixes(prefixes.clone())).await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => toasts.success(msg),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let on_save = move |_| {
+        save_action.dispatch(text.get().lines().map(str::to_string).collect());
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.approvals.prefixes_title")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.approvals.prefixes_intro")}
+                    </p>
+                </div>
+
+                <textarea
+                    class="textarea textarea-bordered h-40 font-mono text-sm leading-relaxed"
+                    placeholder={"security/*\nlegal/terms-of-service"}
+                    prop:value=move || text.get()
+                    on:input=move |ev| set_text.set(event_target_value(&ev))
+                ></textarea>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=on_save
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Component editing the incident runbooks and responders, and switching
+/// incident mode on and off.
+#[component]
+fn IncidentModeEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (runbooks, set_runbooks) = signal(String::new());
+    let (responders, set_responders) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let load_resource = LocalResource::new(|| with_auth_retry(get_incident_config));
+    let status_resource = LocalResource::new(|| with_auth_retry(get_incident_status));
+
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(config)) = load_resource.get() {
+            set_runbooks.set(config.runbooks.join("\n"));
+            set_responders.set(config.responders.join("\n"));
+        }
+    });
+
+    let save_action = Action::new_local(move |_: &()| {
+        let runbooks: Vec<String> = runbooks
+            .get_untracked()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        let responders: Vec<String> = responders
+            .get_untracked()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        async move {
+            set_saving.set(true);
+            let result =
+                with_auth_retry(|| save_incident_config(runbooks.clone(), responders.clone()))
+                    .await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => {
+                    toasts.success(msg);
+                    status_resource.refetch();
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let toggle_action = Action::new_local(move |active: &bool| {
+        let active = *active;
+        async move {
+            match with_auth_retry(|| set_incident_active(active)).await {
+                Ok(()) => status_resource.refetch(),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div class="flex flex-col sm:flex-row sm:items-center justify-between gap-4">
+                    <div>
+                        <h2 class="card-title text-2xl">{i18n.t("admin.title.incident_mode")}</h2>
+                        <p class="text-base-content/60">
+                            {i18n.t("admin.incident.intro")}
+                        </p>
+                    </div>
+                    {move || status_resource.get().and_then(|res| res.ok()).map(|status| {
+                        let active = status.active;
+                        view! {
+                            <div class="flex items-center gap-3 shrink-0">
+                                {active.then(|| view! {
+                                    <span class="badge badge-error">
+                                        {i18n.t("admin.incident.on")}
+                                        {status.started_by.clone().map(|by| format!(" · {by}"))}
+                                    </span>
+                                })}
+                                <button
+                                    class=if active { "btn btn-sm" } else { "btn btn-error btn-sm" }
+                                    disabled=move || toggle_action.pending().get()
+                                    on:click=move |_| { toggle_action.dispatch(!active); }
+                                >
+                                    {if active {
+                                        i18n.t("admin.incident.end")
+                                    } else {
+                                        i18n.t("admin.incident.start")
+                                    }}
+                                </button>
+                            </div>
+                        }
+                    })}
+                </div>
+
+                <div class="grid gap-4 md:grid-cols-2">
+                    <label class="form-control">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.incident.runbooks")}</span>
+                        <textarea
+                            class="textarea textarea-bordered h-40 font-mono text-sm leading-relaxed"
+                            placeholder={"runbooks/db-failover\nrunbooks/incident-comms"}
+                            prop:value=move || runbooks.get()
+                            on:input=move |ev| set_runbooks.set(event_target_value(&ev))
+                        ></textarea>
+                        <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.incident.runbooks_hint")}</span>
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.incident.responders")}</span>
+                        <textarea
+                            class="textarea textarea-bordered h-40 font-mono text-sm leading-relaxed"
+                            placeholder="oncall@example.com"
+                            prop:value=move || responders.get()
+                            on:input=move |ev| set_responders.set(event_target_value(&ev))
+                        ></textarea>
+                        <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.incident.responders_hint")}</span>
+                    </label>
+                </div>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=move |_| { save_action.dispatch(()); }
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Parse service schedules from the "service = schedule" per-line editor format.
+fn parse_service_schedules(text: &str) -> Vec<ServiceSchedule> {
+    text.lines()
+        .filter_map(|line| {
+            let (service, schedule_id) = line.split_once('=')?;
+            let (service, schedule_id) = (service.trim(), schedule_id.trim());
+            (!service.is_empty() || !schedule_id.is_empty()).then(|| ServiceSchedule {
+                service: service.to_string(),
+                schedule_id: schedule_id.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn format_service_schedules(schedules: &[ServiceSchedule]) -> String {
+    schedules
+        .iter()
+        .map(|s| format!("{} = {}", s.service, s.schedule_id))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Component mapping services to PagerDuty or Opsgenie on-call schedules.
+#[component]
+fn OnCallEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (provider, set_provider) = signal(OnCallProvider::None);
+    let (api_token, set_api_token) = signal(String::new());
+    let (token_set, set_token_set) = signal(false);
+    let (api_url, set_api_url) = signal(String::new());
+    let (schedules, set_schedules) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let load_resource = LocalResource::new(|| with_auth_retry(get_on_call_config));
+
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(config)) = load_resource.get() {
+            set_provider.set(config.provider);
+            set_token_set.set(config.token_set);
+            set_api_url.set(config.api_url);
+            set_schedules.set(format_service_schedules(&config.schedules));
+        }
+    });
+
+    let save_action = Action::new_local(move |_: &()| {
+        let provider = provider.get_untracked();
+        let token = api_token.get_untracked();
+        let url = api_url.get_untracked();
+        let schedules = parse_service_schedules(&schedules.get_untracked());
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| {
+                save_on_call_config(provider, token.clone(), url.clone(), schedules.clone())
+            })
+            .await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => {
+                    toasts.success(msg);
+                    if !token.trim().is_empty() {
+                        set_token_set.set(true);
+                        set_api_token.set(String::new());
+                    }
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.on_call")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.on_call.intro")}
+                    </p>
+                </div>
+
+                <div class="grid gap-4 md:grid-cols-2">
+                    <label class="form-control">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.on_call.provider")}</span>
+                        <select
+                            class="select select-bordered"
+                            on:change=move |ev| set_provider.set(match event_target_value(&ev).as_str() {
+                                "pagerduty" => OnCallProvider::PagerDuty,
+                                "opsgenie" => OnCallProvider::Opsgenie,
+                                _ => OnCallProvider::None,
+                            })
+                        >
+                            <option value="none" selected=move || provider.get() == OnCallProvider::None>{i18n.t("admin.common.none")}</option>
+                            <option value="pagerduty" selected=move || provider.get() == OnCallProvider::PagerDuty>"PagerDuty"</option>
+                            <option value="opsgenie" selected=move || provider.get() == OnCallProvider::Opsgenie>"Opsgenie"</option>
+                        </select>
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.common.api_token")}</span>
+                        <input
+                            type="password"
+                            class="input input-bordered font-mono"
+                            autocomplete="off"
+                            placeholder=move || if token_set.get() { i18n.t("admin.common.token_saved") } else { "" }
+                            prop:value=move || api_token.get()
+                            on:input=move |ev| set_api_token.set(event_target_value(&ev))
+                        />
+                        <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.on_call.token_hint")}</span>
+                    </label>
+                    <label class="form-control md:col-span-2">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.on_call.api_url")}</span>
+                        <input
+                            type="url"
+                            class="input input-bordered font-mono"
+                            placeholder="https://api.eu.opsgenie.com"
+                            prop:value=move || api_url.get()
+                            on:input=move |ev| set_api_url.set(event_target_value(&ev))
+                        />
+                        <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.on_call.api_url_hint")}</span>
+                    </label>
+                </div>
+
+                <label class="form-control">
+                    <span class="label-text font-semibold mb-1">{i18n.t("admin.on_call.schedules")}</span>
+                    <textarea
+                        class="textarea textarea-bordered h-40 font-mono text-sm leading-relaxed"
+                        placeholder={"payments-api = PABC123\nsearch = Search on-call"}
+                        prop:value=move || schedules.get()
+                        on:input=move |ev| set_schedules.set(event_target_value(&ev))
+                    ></textarea>
+                    <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.on_call.schedules_hint")}</span>
+                </label>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=move |_| { save_action.dispatch(()); }
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Component linking issue keys to Jira or Linear.
+#[component]
+fn IssueTrackerEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (provider, set_provider) = signal(IssueTrackerProvider::None);
+    let (base_url, set_base_url) = signal(String::new());
+    let (api_user, set_api_user) = signal(String::new());
+    let (api_token, set_api_token) = signal(String::new());
+    let (token_set, set_token_set) = signal(false);
+    let (projects, set_projects) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let load_resource = LocalResource::new(|| with_auth_retry(get_issue_tracker_config));
+
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(config)) = load_resource.get() {
+            set_provider.set(config.provider);
+            set_base_url.set(config.base_url);
+            set_api_user.set(config.api_user);
+            set_token_set.set(config.token_set);
+            set_projects.set(config.projects.join("\n"));
+        }
+    });
+
+    let save_action = Action::new_local(move |_: &()| {
+        let provider = provider.get_untracked();
+        let url = base_url.get_untracked();
+        let user = api_user.get_untracked();
+        let token = api_token.get_untracked();
+        let projects: Vec<String> = projects
+            .get_untracked()
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(str::to_string)
+            .collect();
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| {
+                save_issue_tracker_config(
+                    provider,
+                    url.clone(),
+                    user.clone(),
+                    token.clone(),
+                    projects.clone(),
+                )
+            })
+            .await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => {
+                    toasts.success(msg);
+                    if !token.trim().is_empty() {
+                        set_token_set.set(true);
+                        set_api_token.set(String::new());
+                    }
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.issue_tracker")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.issue_tracker.intro")}
+                    </p>
+                </div>
+
+                <div class="grid gap-4 md:grid-cols-2">
+                    <label class="form-control">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.issue_tracker.tracker")}</span>
+                        <select
+                            class="select select-bordered"
+                            on:change=move |ev| set_provider.set(match event_target_value(&ev).as_str() {
+                                "jira" => IssueTrackerProvider::Jira,
+                                "linear" => IssueTrackerProvider::Linear,
+                                _ => IssueTrackerProvider::None,
+                            })
+                        >
+                            <option value="none" selected=move || provider.get() == IssueTrackerProvider::None>{i18n.t("admin.common.none")}</option>
+                            <option value="jira" selected=move || provider.get() == IssueTrackerProvider::Jira>"Jira"</option>
+                            <option value="linear" selected=move || provider.get() == IssueTrackerProvider::Linear>"Linear"</option>
+                        </select>
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.issue_tracker.url")}</span>
+                        <input
+                            type="url"
+                            class="input input-bordered font-mono"
+                            placeholder="https://acme.atlassian.net"
+                            prop:value=move || base_url.get()
+                            on:input=move |ev| set_base_url.set(event_target_value(&ev))
+                        />
+                        <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.issue_tracker.url_hint")}</span>
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.issue_tracker.user")}</span>
+                        <input
+                            type="email"
+                            class="input input-bordered"
+                            placeholder="bot@example.com"
+                            prop:value=move || api_user.get()
+                            on:input=move |ev| set_api_user.set(event_target_value(&ev))
+                        />
+                        <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.issue_tracker.user_hint")}</span>
+                    </label>
+                    <label class="form-control">
+                        <span class="label-text font-semibold mb-1">{i18n.t("admin.common.api_token")}</span>
+                        <input
+                            type="password"
+                            class="input input-bordered font-mono"
+                            autocomplete="off"
+                            placeholder=move || if token_set.get() { i18n.t("admin.common.token_saved") } else { "" }
+                            prop:value=move || api_token.get()
+                            on:input=move |ev| set_api_token.set(event_target_value(&ev))
+                        />
+                        <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.issue_tracker.token_hint")}</span>
+                    </label>
+                </div>
+
+                <label class="form-control">
+                    <span class="label-text font-semibold mb-1">{i18n.t("admin.issue_tracker.projects")}</span>
+                    <textarea
+                        class="textarea textarea-bordered h-32 font-mono text-sm leading-relaxed"
+                        placeholder={"PAY\nOPS"}
+                        prop:value=move || projects.get()
+                        on:input=move |ev| set_projects.set(event_target_value(&ev))
+                    ></textarea>
+                    <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.issue_tracker.projects_hint")}</span>
+                </label>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=move |_| { save_action.dispatch(()); }
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Embed providers offered on the embeds page, as stored in the settings.
+const EMBED_PROVIDERS: &[(&str, &str)] = &[
+    ("youtube", "YouTube"),
+    ("figma", "Figma"),
+    ("miro", "Miro"),
+    ("grafana", "Grafana"),
+];
+
+/// Component choosing the tools whose links are embedded.
+#[component]
+fn EmbedsEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (providers, set_providers) = signal(Vec::<String>::new());
+    let (grafana_hosts, set_grafana_hosts) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let load_resource = LocalResource::new(|| with_auth_retry(get_embed_settings));
+
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(settings)) = load_resource.get() {
+            set_providers.set(settings.providers);
+            set_grafana_hosts.set(settings.grafana_hosts.join("\n"));
+        }
+    });
+
+    let save_action = Action::new_local(move |_: &()| {
+        let providers = providers.get_untracked();
+        let hosts: Vec<String> = grafana_hosts
+            .get_untracked()
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(str::to_string)
+            .collect();
+        async move {
+            set_saving.set(true);
+            let result =
+                with_auth_retry(|| save_embed_settings(providers.clone(), hosts.clone())).await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => toasts.success(msg),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.embeds")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.embeds.intro")}
+                    </p>
+                </div>
+
+                <div class="form-control">
+                    <span class="label-text font-semibold mb-2">{i18n.t("admin.embeds.providers")}</span>
+                    <div class="flex flex-wrap gap-6">
+                        {EMBED_PROVIDERS.iter().map(|&(name, label)| view! {
+                            <label class="flex items-center gap-2 cursor-pointer">
+                                <input
+                                    type="checkbox"
+                                    class="checkbox checkbox-sm"
+                                    prop:checked=move || providers.with(|p| p.iter().any(|p| p == name))
+                                    on:change=move |ev| {
+                                        let checked = event_target_checked(&ev);
+                                        set_providers.update(|p| {
+                                            p.retain(|p| p != name);
+                                            if checked {
+                                                p.push(name.to_string());
+                                            }
+                                        });
+                                    }
+                                />
+                                <span>{label}</span>
+                            </label>
+                        }).collect_view()}
+                    </div>
+                </div>
+
+                <label class="form-control">
+                    <span class="label-text font-semibold mb-1">{i18n.t("admin.embeds.grafana_hosts")}</span>
+                    <textarea
+                        class="textarea textarea-bordered h-24 font-mono text-sm leading-relaxed"
+                        placeholder="grafana.internal.acme.com"
+                        prop:value=move || grafana_hosts.get()
+                        on:input=move |ev| set_grafana_hosts.set(event_target_value(&ev))
+                    ></textarea>
+                    <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.embeds.grafana_hosts_hint")}</span>
+                </label>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=move |_| { save_action.dispatch(()); }
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Parse LDAP group mappings from the "group = level, level" per-line editor format.
+fn parse_ldap_group_mappings(text: &str) -> Vec<LdapGroupMapping> {
+    text.lines()
+        .filter_map(|line| {
+            let (group, levels) = line.rsplit_once('=')?;
+            let group = group.trim();
+            (!group.is_empty()).then(|| LdapGroupMapping {
+                group: group.to_string(),
+                access_levels: levels
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|level| !level.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+fn format_ldap_group_mappings(mappings: &[LdapGroupMapping]) -> String {
+    mappings
+        .iter()
+        .map(|m| format!("{} = {}", m.group, m.access_levels.join(", ")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Component mapping LDAP groups to access levels and admin rights.
+#[component]
+fn LdapGroupsEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (enabled, set_enabled) = signal(true);
+    let (mappings, set_mappings) = signal(String::new());
+    let (admin_groups, set_admin_groups) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let load_resource = LocalResource::new(|| with_auth_retry(get_ldap_group_config));
+
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(config)) = load_resource.get() {
+            set_enabled.set(config.enabled);
+            set_mappings.set(format_ldap_group_mappings(&config.group_mappings));
+            set_admin_groups.set(config.admin_groups.join("\n"));
+        }
+    });
+
+    let save_action = Action::new_local(move |_: &()| {
+        let mappings = parse_ldap_group_mappings(&mappings.get_untracked());
+        let admin_groups: Vec<String> = admin_groups
+            .get_untracked()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        async move {
+            set_saving.set(true);
+            let result =
+                with_auth_retry(|| save_ldap_group_config(mappings.clone(), admin_groups.clone()))
+                    .await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => toasts.success(msg),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.ldap")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.ldap.intro")}
+                    </p>
+                </div>
+
+                <Show when=move || !enabled.get()>
+                    <div class="alert alert-warning">
+                        <span>{i18n.t("admin.ldap.not_configured")}</span>
+                    </div>
+                </Show>
+
+                <label class="form-control">
+                    <span class="label-text font-semibold mb-1">{i18n.t("admin.ldap.mappings")}</span>
+                    <textarea
+                        class="textarea textarea-bordered h-40 font-mono text-sm leading-relaxed"
+                        placeholder={"payments = internal, payments\ncn=sre,ou=groups,dc=acme,dc=com = internal, ops"}
+                        prop:value=move || mappings.get()
+                        on:input=move |ev| set_mappings.set(event_target_value(&ev))
+                    ></textarea>
+                    <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.ldap.mappings_hint")}</span>
+                </label>
+
+                <label class="form-control">
+                    <span class="label-text font-semibold mb-1">{i18n.t("admin.ldap.admin_groups")}</span>
+                    <textarea
+                        class="textarea textarea-bordered h-24 font-mono text-sm leading-relaxed"
+                        placeholder="lekton-admins"
+                        prop:value=move || admin_groups.get()
+                        on:input=move |ev| set_admin_groups.set(event_target_value(&ev))
+                    ></textarea>
+                    <span class="label-text-alt text-base-content/60 mt-1">{i18n.t("admin.ldap.admin_groups_hint")}</span>
+                </label>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=move |_| { save_action.dispatch(()); }
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Component toggling optional subsystems at runtime.
+#[component]
+fn FeatureFlagsEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (flags, set_flags) = signal(Option::<FeatureFlags>::None);
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let load_resource = LocalResource::new(|| with_auth_retry(get_feature_flags));
+
+    let _ = Effect::new(move |_| {
+        if let Some(Ok(loaded)) = load_resource.get() {
+            set_flags.set(Some(loaded));
+        }
+    });
+
+    let save_action = Action::new_local(move |features: &FeatureFlags| {
+        let features = features.clone();
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| save_feature_flags(features.clone())).await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => toasts.success(msg),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let on_save = move |_| {
+        if let Some(features) = flags.get() {
+            save_action.dispatch(features);
+        }
+    };
+
+    let toggle = move |label: &'static str,
+                       hint: &'static str,
+                       get: fn(&FeatureFlags) -> bool,
+                       set: fn(&mut FeatureFlags, bool)| {
+        view! {
+            <label class="label cursor-pointer justify-between gap-6 py-3">
+                <div>
+                    <span class="label-text font-semibold">{i18n.t(label)}</span>
+                    <p class="text-sm text-base-content/60">{i18n.t(hint)}</p>
+                </div>
+                <input
+                    type="checkbox"
+                    class="toggle toggle-primary"
+                    prop:checked=move || flags.get().as_ref().is_some_and(get)
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        set_flags.update(|f| {
+                            if let Some(f) = f {
+                                set(f, checked);
+                            }
+                        });
+                    }
+                />
+            </label>
+        }
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.features.title")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.features.intro")}
+                    </p>
+                </div>
+
+                <Show
+                    when=move || flags.get().is_some()
+                    fallback=|| view! { <div class="skeleton h-48 w-full rounded-2xl" /> }
+                >
+                    <div class="divide-y divide-base-200">
+                        {toggle(
+                            "admin.features.editor",
+                            "admin.features.editor_hint",
+                            |f| f.editor,
+                            |f, on| f.editor = on,
+                        )}
+                        {toggle(
+                            "admin.features.chat",
+                            "admin.features.chat_hint",
+                            |f| f.chat,
+                            |f, on| f.chat = on,
+                        )}
+                        <label class="form-control py-3">
+                            <span class="label-text font-semibold">{i18n.t("admin.features.search")}</span>
+                            <p class="text-sm text-base-content/60 mb-2">
+                                {i18n.t("admin.features.search_hint")}
+                            </p>
+                            <select
+                                class="select select-bordered select-sm w-full sm:w-64"
+                                on:change=move |ev| {
+                                    let provider = match event_target_value(&ev).as_str() {
+                                        "full_text" => SearchProvider::FullText,
+                                        "disabled" => SearchProvider::Disabled,
+                                        _ => SearchProvider::Hybrid,
+                                    };
+                                    set_flags.update(|f| {
+                                        if let Some(f) = f {
+                                            f.search_provider = provider;
+                                        }
+                                    });
+                                }
+                            >
+                                {[
+                                    (SearchProvider::Hybrid, "hybrid", "admin.features.search_hybrid"),
+                                    (SearchProvider::FullText, "full_text", "admin.features.search_full_text"),
+                                    (SearchProvider::Disabled, "disabled", "admin.features.search_disabled"),
+                                ].into_iter().map(|(provider, value, label)| view! {
+                                    <option
+                                        value=value
+                                        selected=move || flags.get().is_some_and(|f| f.search_provider == provider)
+                                    >
+                                        {i18n.t(label)}
+                                    </option>
+                                }).collect::<Vec<_>>()}
+                            </select>
+                        </label>
+                    </div>
+                </Show>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get() || flags.get().is_none()
+                        on:click=on_save
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.common.save_changes")} }.into_any()
+                        }}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Component for managing glossary terms.
+#[component]
+fn GlossaryEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (term, set_term) = signal(String::new());
+    let (definition, set_definition) = signal(String::new());
+    let (owner, set_owner) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let terms_resource = LocalResource::new(|| with_auth_retry(list_glossary));
+
+    let save_action = Action::new_local(move |entry: &(String, String, String)| {
+        let (term, definition, owner) = entry.clone();
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| {
+                save_glossary_term(term.clone(), definition.clone(), owner.clone())
+            })
+            .await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => {
+                    toasts.success(msg);
+                    set_term.set(String::new());
+                    set_definition.set(String::new());
+                    set_owner.set(String::new());
+                    terms_resource.refetch();
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let delete_action = Action::new_local(move |term: &String| {
+        let term = term.clone();
+        async move {
+            match with_auth_retry(|| delete_glossary_term(term.clone())).await {
+                Ok(()) => terms_resource.refetch(),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let on_save = move |_| {
+        save_action.dispatch((term.get(), definition.get(), owner.get()));
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.glossary")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.glossary.intro")}
+                    </p>
+                </div>
+
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    <input
+                        type="text"
+                        class="input input-bordered"
+                        placeholder=i18n.t("admin.glossary.term_placeholder")
+                        prop:value=move || term.get()
+                        on:input=move |ev| set_term.set(event_target_value(&ev))
+                    />
+                    <input
+                        type="text"
+                        class="input input-bordered"
+                        placeholder=i18n.t("admin.glossary.owner_placeholder")
+                        prop:value=move || owner.get()
+                        on:input=move |ev| set_owner.set(event_target_value(&ev))
+                    />
+                    <textarea
+                        class="textarea textarea-bordered md:col-span-2 h-24"
+                        placeholder=i18n.t("admin.glossary.definition_placeholder")
+                        prop:value=move || definition.get()
+                        on:input=move |ev| set_definition.set(event_target_value(&ev))
+                    ></textarea>
+                </div>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=on_save
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.glossary.save")} }.into_any()
+                        }}
+                    </button>
+                </div>
+
+                <Suspense fallback=|| view! { <div class="skeleton h-40 w-full" /> }>
+                    {move || terms_resource.get().map(|res| match res {
+                        Err(e) => view! {
+                            <div class="alert alert-error">{e.to_string()}</div>
+                        }.into_any(),
+                        Ok(terms) => view! {
+                            <div class="overflow-x-auto rounded-lg border border-base-200">
+                                <table class="table table-sm">
+                                    <thead>
+                                        <tr class="bg-base-200/50">
+                                            <th>{i18n.t("admin.glossary.term")}</th>
+                                            <th>{i18n.t("admin.glossary.definition")}</th>
+                                            <th>{i18n.t("admin.glossary.owner")}</th>
+                                            <th></th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {if terms.is_empty() {
+                                            view! {
+                                                <tr>
+                                                    <td colspan="4" class="text-center py-8 text-base-content/40">{i18n.t("admin.glossary.empty")}</td>
+                                                </tr>
+                                            }.into_any()
+                                        } else {
+                                            terms.into_iter().map(|entry| {
+                                                let edit = entry.clone();
+                                                let delete_term = entry.term.clone();
+                                                view! {
+                                                    <tr>
+                                                        <td class="font-semibold whitespace-nowrap">{entry.term}</td>
+                                                        <td class="text-sm">{entry.definition}</td>
+                                                        <td class="text-sm text-base-content/70">{entry.owner}</td>
+                                                        <td class="whitespace-nowrap text-right">
+                                                            <button
+                                                                class="btn btn-ghost btn-xs"
+                                                                on:click=move |_| {
+                                                                    set_term.set(edit.term.clone());
+                                                                    set_definition.set(edit.definition.clone());
+                                                                    set_owner.set(edit.owner.clone());
+                                                                }
+                                                            >
+                                                                {i18n.t("admin.common.edit")}
+                                                            </button>
+                                                            <button
+                                                                class="btn btn-ghost btn-xs text-error"
+                                                                on:click=move |_| { delete_action.dispatch(delete_term.clone()); }
+                                                            >
+                                                                {i18n.t("admin.common.delete")}
+                                                            </button>
+                                                        </td>
+                                                    </tr>
+                                                }
+                                            }).collect::<Vec<_>>().into_any()
+                                        }}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+/// Component for managing the words accepted by the spell checker.
+#[component]
+fn SpellingDictionaryEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (word, set_word) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let words_resource = LocalResource::new(|| with_auth_retry(list_dictionary_words));
+
+    let add_action = Action::new_local(move |word: &String| {
+        let word = word.clone();
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| add_dictionary_word(word.clone())).await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => {
+                    toasts.success(msg);
+                    set_word.set(String::new());
+                    words_resource.refetch();
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let remove_action = Action::new_local(move |word: &String| {
+        let word = word.clone();
+        async move {
+            match with_auth_retry(|| remove_dictionary_word(word.clone())).await {
+                Ok(()) => words_resource.refetch(),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.dictionary.title")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.dictionary.intro")}
+                    </p>
+                </div>
+
+                <form
+                    class="flex flex-col sm:flex-row gap-4"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        add_action.dispatch(word.get());
+                    }
+                >
+                    <input
+                        type="text"
+                        class="input input-bordered flex-1"
+                        placeholder=i18n.t("admin.dictionary.word_placeholder")
+                        prop:value=move || word.get()
+                        on:input=move |ev| set_word.set(event_target_value(&ev))
+                    />
+                    <button
+                        type="submit"
+                        class="btn btn-primary sm:w-48 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.dictionary.add")} }.into_any()
+                        }}
+                    </button>
+                </form>
+
+                <Suspense fallback=|| view! { <div class="skeleton h-40 w-full" /> }>
+                    {move || words_resource.get().map(|res| match res {
+                        Err(e) => view! {
+                            <div class="alert alert-error">{e.to_string()}</div>
+                        }.into_any(),
+                        Ok(words) if words.is_empty() => view! {
+                            <p class="text-center py-8 text-base-content/40">{i18n.t("admin.dictionary.empty")}</p>
+                        }.into_any(),
+                        Ok(words) => view! {
+                            <div class="flex flex-wrap gap-2">
+                                {words.into_iter().map(|entry| {
+                                    let remove_word = entry.word.clone();
+                                    let added = i18n.tf(
+                                        "admin.dictionary.added_by",
+                                        &[("user", &entry.added_by), ("date", &entry.added_at.format("%Y-%m-%d").to_string())],
+                                    );
+                                    view! {
+                                        <span class="badge badge-lg gap-1 font-mono" title=added>
+                                            {entry.word}
+                                            <button
+                                                class="btn btn-ghost btn-xs btn-circle"
+                                                aria-label=i18n.t("admin.dictionary.remove")
+                                                on:click=move |_| { remove_action.dispatch(remove_word.clone()); }
+                                            >
+                                                "✕"
+                                            </button>
+                                        </span>
+                                    }
+                                }).collect_view()}
+                            </div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+/// Admin editor for projects.
+#[component]
+fn ProjectEditor() -> impl IntoView {
+    let i18n = use_i18n();
+    let (name, set_name) = signal(String::new());
+    let (title, set_title) = signal(String::new());
+    let (description, set_description) = signal(String::new());
+    let (access_levels, set_access_levels) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let toasts = use_toasts();
+
+    let projects_resource = LocalResource::new(|| with_auth_retry(list_projects));
+
+    let save_action = Action::new_local(move |entry: &(String, String, String, String)| {
+        let (name, title, description, access_levels) = entry.clone();
+        async move {
+            set_saving.set(true);
+            let result = with_auth_retry(|| {
+                save_project(
+                    name.clone(),
+                    title.clone(),
+                    description.clone(),
+                    access_levels.clone(),
+                )
+            })
+            .await;
+            set_saving.set(false);
+            match result {
+                Ok(msg) => {
+                    toasts.success(msg);
+                    set_name.set(String::new());
+                    set_title.set(String::new());
+                    set_description.set(String::new());
+                    set_access_levels.set(String::new());
+                    projects_resource.refetch();
+                }
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let delete_action = Action::new_local(move |name: &String| {
+        let name = name.clone();
+        async move {
+            match with_auth_retry(|| delete_project(name.clone())).await {
+                Ok(()) => projects_resource.refetch(),
+                Err(e) => toasts.error(e.to_string()),
+            }
+        }
+    });
+
+    let on_save = move |_| {
+        save_action.dispatch((
+            name.get(),
+            title.get(),
+            description.get(),
+            access_levels.get(),
+        ));
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl border border-base-200">
+            <div class="card-body p-8 gap-6">
+                <div>
+                    <h2 class="card-title text-2xl">{i18n.t("admin.title.projects")}</h2>
+                    <p class="text-base-content/60">
+                        {i18n.t("admin.projects.intro")}
+                    </p>
+                </div>
+
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    <input
+                        type="text"
+                        class="input input-bordered font-mono"
+                        placeholder=i18n.t("admin.projects.name_placeholder")
+                        prop:value=move || name.get()
+                        on:input=move |ev| set_name.set(event_target_value(&ev))
+                    />
+                    <input
+                        type="text"
+                        class="input input-bordered"
+                        placeholder=i18n.t("admin.projects.title_placeholder")
+                        prop:value=move || title.get()
+                        on:input=move |ev| set_title.set(event_target_value(&ev))
+                    />
+                    <input
+                        type="text"
+                        class="input input-bordered md:col-span-2"
+                        placeholder=i18n.t("admin.projects.levels_placeholder")
+                        prop:value=move || access_levels.get()
+                        on:input=move |ev| set_access_levels.set(event_target_value(&ev))
+                    />
+                    <textarea
+                        class="textarea textarea-bordered md:col-span-2 h-20"
+                        placeholder=i18n.t("admin.projects.description")
+                        prop:value=move || description.get()
+                        on:input=move |ev| set_description.set(event_target_value(&ev))
+                    ></textarea>
+                </div>
+
+                <div class="flex flex-col sm:flex-row sm:items-center justify-end gap-4">
+                    <button
+                        class="btn btn-primary w-full sm:w-64 shadow-lg shadow-primary/20"
+                        disabled=move || saving.get()
+                        on:click=on_save
+                    >
+                        {move || if saving.get() {
+                            view! { <span class="loading loading-spinner loading-sm"></span> }.into_any()
+                        } else {
+                            view! { {i18n.t("admin.projects.save")} }.into_any()
+                        }}
+                    </button>
+                </div>
+
+                <Suspense fallback=|| view! { <div class="skeleton h-40 w-full" /> }>
+                    {move || projects_resource.get().map(|res| match res {
+                        Err(e) => view! {
+                            <div class="alert alert-error">{e.to_string()}</div>
+                        }.into_any(),
+                        Ok(projects) => view! {
+                            <div class="overflow-x-auto rounded-lg border border-base-200">
+                                <table class="table table-sm">
+                                    <thead>
+                                        <tr class="bg-base-200/50">
+                                            <th>{i18n.t("admin.common.name")}</th>
+                                            <th>{i18n.t("admin.projects.title")}</th>
+                                            <th>{i18n.t("admin.api_keys.access_levels")}</th>
+                                            <th></th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {if projects.is_empty() {
+                                            view! {
+                                                <tr>
+                                                    <td colspan="4" class="text-center py-8 text-base-content/40">{i18n.t("admin.projects.empty")}</td>
+                                                </tr>
+                                            }.into_any()
+                                        } else {
+                                            projects.into_iter().map(|project| {
+                                                let edit = project.clone();
+                                                let delete_name = project.name.clone();
+                                                let levels = if project.access_levels.is_empty() {
+                                                    i18n.t("admin.projects.any_level").to_string()
+                                                } else {
+                                                    project.access_levels.join(", ")
+                                                };
+                                                view! {
+                                                    <tr>
+                                                        <td class="font-mono font-semibold whitespace-nowrap">{project.name}</td>
+                                                        <td class="text-sm">
+                                                            {project.title}
+                                                            <div class="text-xs text-base-content/50">{project.description}</div>
+                                                        </td>
+                                                        <td class="text-sm text-base-content/70">{levels}</td>
+                                                        <td class="whitespace-nowrap text-right">
+                                                            <button
+                                                                class="btn btn-ghost btn-xs"
+                                                                on:click=move |_| {
+                                                                    set_name.set(edit.name.clone());
+                                                                    set_title.set(edit.title.clone());
+                                                                    set_description.set(edit.description.clone());
+                                                                    set_access_levels.set(edit.access_levels.join(", "));
+                                                                }
+                                                            >
+                                                                {i18n.t("admin.common.edit")}
+                                                            </button>
+                                                            <button
+                                                                class="btn btn-ghost btn-xs text-error"
+                                                                on:click=move |_| { delete_action.dispatch(delete_name.clone()); }
+                                                            >
+                                                                {i18n.t("admin.common.delete")}
+                                                            </button>
+                                                        </td>
+                                                    </tr>
+                                                }
+                                            }).collect::<Vec<_>>().into_any()
+                                        }}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+/// Modal shown once after creating a token, displaying the raw token value.
+#[component]
+fn CreatedTokenModal(
+    token: ReadSignal<Option<CreateTokenResult>>,
+    set_token: WriteSignal<Option<CreateTokenResult>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let (copied, set_copied) = signal(false);
+
+    view! {
+        <Show when=move || token.get().is_some()>
+            <div class="fixed inset-0 z-[200] flex items-center justify-center bg-black/60 backdrop-blur-sm animate-in fade-in duration-300">
+                <div class="bg-base-100 rounded-3xl shadow-2xl w-full max-w-xl mx-4 p-10 relative overflow-hidden animate-in zoom-in-95 duration-300">
+                    <div class="absolute top-0 inset-x-0 h-2 bg-warning"></div>
+
+                    <div class="mb-8 flex items-center gap-4">
+                        <div class="w-12 h-12 bg-warning/10 rounded-2xl flex items-center justify-center text-warning">
+                          <svg xmlns="http://www.w3.org/2000/svg" class="h-8 w-8" fill="none" viewBox="0 0 24 24" stroke="currentColor">
+                              <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-2.5L13.732 4c-.77-.833-1.964-.833-2.732 0L4.082 16.5c-.77.833.192 2.5 1.732 2.5z" />
+                          </svg>
+                        </div>
+                        <div>
+                          <h3 class="font-black text-3xl tracking-tight">{i18n.t("admin.created_token.title")}</h3>
+                          <p class="text-base-content/60 font-medium">{i18n.t("admin.created_token.subtitle")}</p>
+                        </div>
+                    </div>
+
+                    <div class="bg-warning/10 border border-warning/20 rounded-2xl p-6 mb-8 flex items-start gap-4">
+                        <div class="text-warning mt-1">
+                          <svg class="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z"></path></svg>
+                        </div>
+                        <p class="text-base-content text-sm font-semibold leading-relaxed">
+                          {i18n.t("admin.created_token.warning")}
+                        </p>
+                    </div>
+
+                    {move || token.get().map(|t| {
+                        let raw = t.raw_token.clone();
+                        #[cfg(feature = "hydrate")]
+                        let raw_for_copy = t.raw_token.clone();
+                        let name = t.name.clone();
+                        let scopes_str = t.allowed_scopes.join(", ");
+                        view! {
+                            <div class="space-y-6">
+                                <div class="form-control">
+                                    <label class="label pt-0"><span class="label-text font-bold text-xs uppercase tracking-widest text-base-content/50">{i18n.t("admin.created_token.token")}</span></label>
+                                    <div class="relative group">
+                                        <input
+                                            type="text"
+                                            readonly
+                                            class="input input-bordered w-full font-mono text-lg py-8 pr-16 bg-base-200/50 border-base-300 focus:outline-none focus:border-warning/50 selection:bg-warning/20"
+                                            value=raw
+                                        />
+                                        <button
+                                            class="btn btn-warning shadow-lg shadow-warning/20 absolute right-2 top-1/2 -translate-y-1/2 normal-case font-bold"
+                                            on:click=move |_| {
+                                                #[cfg(feature = "hydrate")]
+                                                {
+                                                    let raw = raw_for_copy.clone();
+                                                    let _ = js_sys::eval(&format!(
+                                                        "navigator.clipboard.writeText('{}')",
+                                                        raw.replace('\'', "\\'")
+                                                    ));
+                                                    set_copied.set(true);
+                                                }
+                                            }
+                                        >
+                                            {move || if copied.get() {
+                                              view! { <span class="flex items-center gap-1"><svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M5 13l4 4L19 7"></path></svg>{i18n.t("admin.created_token.copied")}</span> }.into_any()
+                                            } else {
+                                              view! { <span class="flex items-center gap-1"><svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M8 5H6a2 2 0 00-2 2v12a2 2 0 002 2h10a2 2 0 002-2v-1M8 5a2 2 0 002 2h2a2 2 0 002-2M8 5a2 2 0 002 2h2a2 2 0 002-2M8 5a2 2 0 012-2h2a2 2 0 012 2m0 0h2a2 2 0 012 2v3m2 4H10m0 0l3-3m-3 3l3 3"></path></svg>{i18n.t("admin.created_token.copy")}</span> }.into_any()
+                                            }}
+                                        </button>
+                                    </div>
+                                </div>
+
+                                <div class="grid grid-cols-2 gap-8 py-6 border-y border-base-200">
+                                    <div>
+                                      <p class="text-[10px] font-black uppercase text-base-content/40 tracking-widest mb-1">{i18n.t("admin.created_token.name")}</p>
+                                      <p class="font-bold text-base">{name}</p>
+                                    </div>
+                                    <div>
+                                      <p class="text-[10px] font-black uppercase text-base-content/40 tracking-widest mb-1">{i18n.t("admin.common.scopes")}</p>
+                                      <p class="font-mono text-xs truncate" title=scopes_str.clone()>{scopes_str.clone()}</p>
+                                    </div>
+                                </div>
+                            </div>
+                        }
+                    })}
+
+                    <div class="flex justify-end pt-8">
+                        <button
+                            class="btn btn-ghost hover:bg-base-200 w-full sm:w-64 font-bold"
+                            on:click=move |_| {
+                                set_token.set(None);
+                                set_copied.set(false);
+                            }
+                        >
+                            {i18n.t("admin.created_token.done")}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+// ── Index Rebuilds ───────────────────────────────────────────────────────────
+
+#[component]
+fn SearchReindexSection() -> impl IntoView {
+    let i18n = use_i18n();
+    let (poll_counter, set_poll_counter) = signal(0u32);
+    let (is_polling, set_is_polling) = signal(false);
+
+    let status_resource = LocalResource::new(move || {
+        let _ = poll_counter.get();
+        with_auth_retry(get_search_reindex_status)
+    });
+
+    let trigger_action = Action::new_local(move |_: &()| async move {
+        let result = with_auth_retry(trigger_search_reindex).await;
+        set_is_polling.set(true);
+        set_poll_counter.update(|c| *c += 1);
+        result
+    });
+
+    #[cfg(feature = "hydrate")]
+    Effect::new(move || {
+        if is_polling.get() {
+            use leptos::task::spawn_local;
+            spawn_local(async move {
+                gloo_timers::future::TimeoutFuture::new(2000).await;
+                set_poll_counter.update(|c| *c += 1);
+            });
         }
     });
 
@@ -1636,9 +4555,9 @@ fn SearchReindexSection() -> impl IntoView {
                                         <div class="p-8">
                                             <div class="flex items-center gap-3 mb-2">
                                                 <svg class="w-6 h-6 text-primary" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><circle cx="11" cy="11" r="8"/><path d="m21 21-4.3-4.3"/></svg>
-                                                <h2 class="text-2xl font-bold">"Meilisearch Re-index"</h2>
+                                                <h2 class="text-2xl font-bold">{i18n.t("admin.reindex.search_title")}</h2>
                                             </div>
-                                            <p class="text-base-content/60">"Rebuild the full-text search index from MongoDB metadata and stored Markdown content."</p>
+                                            <p class="text-base-content/60">{i18n.t("admin.reindex.search_intro")}</p>
                                         </div>
                                         <div class="px-8 pb-8">
                                             <SearchReindexControls
@@ -1665,6 +4584,7 @@ fn SearchReindexControls(
     trigger_action: Action<(), Result<String, ServerFnError>>,
     is_polling: ReadSignal<bool>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let _ = is_polling;
     let is_running = Signal::derive(move || {
         status_resource
@@ -1695,12 +4615,12 @@ fn SearchReindexControls(
                     }>
                         <span class="loading loading-spinner loading-sm"></span>
                     </Show>
-                    "Start Re-index"
+                    {i18n.t("admin.reindex.start")}
                 </button>
             }>
                 <div class="space-y-2">
                     <div class="flex items-center justify-between">
-                        <span class="text-sm font-medium">"Re-indexing in progress..."</span>
+                        <span class="text-sm font-medium">{i18n.t("admin.reindex.in_progress")}</span>
                         <span class="text-sm text-base-content/60">{move || format!("{}%", progress.get())}</span>
                     </div>
                     <progress
@@ -1729,6 +4649,7 @@ fn SearchReindexControls(
 /// RAG re-index section — visible only when RAG is enabled.
 #[component]
 fn RagReindexSection() -> impl IntoView {
+    let i18n = use_i18n();
     let (poll_counter, set_poll_counter) = signal(0u32);
     let (is_polling, set_is_polling) = signal(false);
 
@@ -1782,9 +4703,9 @@ fn RagReindexSection() -> impl IntoView {
                                         <div class="p-8">
                                             <div class="flex items-center gap-3 mb-2">
                                                 <svg class="w-6 h-6 text-secondary" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><circle cx="12" cy="12" r="10"/><path d="M12 6v6l4 2"/></svg>
-                                                <h2 class="text-2xl font-bold">"RAG Re-index"</h2>
+                                                <h2 class="text-2xl font-bold">{i18n.t("admin.reindex.rag_title")}</h2>
                                             </div>
-                                            <p class="text-base-content/60">"Re-embed all documents in the vector store. Use this after changing the embedding model."</p>
+                                            <p class="text-base-content/60">{i18n.t("admin.reindex.rag_intro")}</p>
                                         </div>
                                         <div class="px-8 pb-8">
                                             <RagReindexControls
@@ -1811,6 +4732,7 @@ fn RagReindexControls(
     trigger_action: Action<(), Result<String, ServerFnError>>,
     is_polling: ReadSignal<bool>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let _ = is_polling; // used by caller for reactive polling, not needed in view
     let is_running = Signal::derive(move || {
         status_resource
@@ -1841,12 +4763,12 @@ fn RagReindexControls(
                     }>
                         <span class="loading loading-spinner loading-sm"></span>
                     </Show>
-                    "Start Re-index"
+                    {i18n.t("admin.reindex.start")}
                 </button>
             }>
                 <div class="space-y-2">
                     <div class="flex items-center justify-between">
-                        <span class="text-sm font-medium">"Re-indexing in progress..."</span>
+                        <span class="text-sm font-medium">{i18n.t("admin.reindex.in_progress")}</span>
                         <span class="text-sm text-base-content/60">{move || format!("{}%", progress.get())}</span>
                     </div>
                     <progress
@@ -1876,6 +4798,7 @@ fn RagReindexControls(
 /// Schema endpoint re-index section — always visible to admins.
 #[component]
 fn SchemaEndpointReindexSection() -> impl IntoView {
+    let i18n = use_i18n();
     let (poll_counter, set_poll_counter) = signal(0u32);
     let (is_polling, set_is_polling) = signal(false);
 
@@ -1921,9 +4844,9 @@ fn SchemaEndpointReindexSection() -> impl IntoView {
                                     <div class="p-8">
                                         <div class="flex items-center gap-3 mb-2">
                                             <svg class="w-6 h-6 text-accent" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M14.5 2H6a2 2 0 0 0-2 2v16a2 2 0 0 0 2 2h12a2 2 0 0 0 2-2V7.5L14.5 2z"/><polyline points="14 2 14 8 20 8"/><line x1="16" y1="13" x2="8" y2="13"/><line x1="16" y1="17" x2="8" y2="17"/><line x1="10" y1="9" x2="8" y2="9"/></svg>
-                                            <h2 class="text-2xl font-bold">"Schema Endpoint Re-index"</h2>
+                                            <h2 class="text-2xl font-bold">{i18n.t("admin.reindex.schema_title")}</h2>
                                         </div>
-                                        <p class="text-base-content/60">"Re-extract API operations (path, method, summary) from all schema versions stored in S3 and update the index in MongoDB. Run this to backfill schemas ingested before endpoint indexing was introduced."</p>
+                                        <p class="text-base-content/60">{i18n.t("admin.reindex.schema_intro")}</p>
                                     </div>
                                     <div class="px-8 pb-8">
                                         <SchemaEndpointReindexControls
@@ -1949,6 +4872,7 @@ fn SchemaEndpointReindexControls(
     trigger_action: Action<(), Result<String, ServerFnError>>,
     is_polling: ReadSignal<bool>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let _ = is_polling;
     let is_running = Signal::derive(move || {
         status_resource
@@ -1979,12 +4903,12 @@ fn SchemaEndpointReindexControls(
                     }>
                         <span class="loading loading-spinner loading-sm"></span>
                     </Show>
-                    "Start Re-index"
+                    {i18n.t("admin.reindex.start")}
                 </button>
             }>
                 <div class="space-y-2">
                     <div class="flex items-center justify-between">
-                        <span class="text-sm font-medium">"Re-indexing in progress..."</span>
+                        <span class="text-sm font-medium">{i18n.t("admin.reindex.in_progress")}</span>
                         <span class="text-sm text-base-content/60">{move || format!("{}%", progress.get())}</span>
                     </div>
                     <progress
@@ -2017,6 +4941,7 @@ const ADMIN_PAT_PER_PAGE: u64 = 20;
 /// Admin section: paginated list of all PATs with user resolution and toggle.
 #[component]
 fn AdminPatManager() -> impl IntoView {
+    let i18n = use_i18n();
     let page = RwSignal::new(1u64);
 
     let pats_resource = LocalResource::new(move || {
@@ -2041,9 +4966,9 @@ fn AdminPatManager() -> impl IntoView {
         <div class="space-y-4">
             <div class="flex items-center justify-between">
                 <div>
-                    <h2 class="text-lg font-semibold">"Personal Access Tokens"</h2>
+                    <h2 class="text-lg font-semibold">{i18n.t("admin.title.pats")}</h2>
                     <p class="text-sm text-base-content/60 mt-1">
-                        "PATs issued to users for IDE agent access (Claude Code, Cursor, etc.)."
+                        {i18n.t("admin.pats.intro")}
                     </p>
                 </div>
             </div>
@@ -2060,11 +4985,11 @@ fn AdminPatManager() -> impl IntoView {
                                 <table class="table table-sm">
                                     <thead>
                                         <tr class="bg-base-200/50">
-                                            <th>"Token name"</th>
-                                            <th>"User"</th>
-                                            <th>"Created"</th>
-                                            <th>"Last used"</th>
-                                            <th>"Status"</th>
+                                            <th>{i18n.t("admin.pats.token_name")}</th>
+                                            <th>{i18n.t("admin.pats.user")}</th>
+                                            <th>{i18n.t("admin.common.created")}</th>
+                                            <th>{i18n.t("admin.api_keys.last_used")}</th>
+                                            <th>{i18n.t("admin.common.status")}</th>
                                             <th></th>
                                         </tr>
                                     </thead>
@@ -2072,7 +4997,7 @@ fn AdminPatManager() -> impl IntoView {
                                         {if pats.is_empty() {
                                             view! {
                                                 <tr>
-                                                    <td colspan="6" class="text-center py-8 text-base-content/40">"No PATs found."</td>
+                                                    <td colspan="6" class="text-center py-8 text-base-content/40">{i18n.t("admin.pats.empty")}</td>
                                                 </tr>
                                             }.into_any()
                                         } else {
@@ -2090,13 +5015,13 @@ fn AdminPatManager() -> impl IntoView {
                                                         </td>
                                                         <td class="text-sm text-base-content/60">{pat.created_at}</td>
                                                         <td class="text-sm text-base-content/60">
-                                                            {pat.last_used_at.unwrap_or_else(|| "Never".to_string())}
+                                                            {pat.last_used_at.unwrap_or_else(|| i18n.t("admin.common.never").to_string())}
                                                         </td>
                                                         <td>
                                                             {if is_active {
-                                                                view! { <span class="badge badge-success badge-sm">"Active"</span> }.into_any()
+                                                                view! { <span class="badge badge-success badge-sm">{i18n.t("admin.pats.active")}</span> }.into_any()
                                                             } else {
-                                                                view! { <span class="badge badge-ghost badge-sm">"Inactive"</span> }.into_any()
+                                                                view! { <span class="badge badge-ghost badge-sm">{i18n.t("admin.pats.inactive")}</span> }.into_any()
                                                             }}
                                                         </td>
                                                         <td>
@@ -2104,7 +5029,11 @@ fn AdminPatManager() -> impl IntoView {
                                                                 class="btn btn-xs btn-ghost"
                                                                 on:click=move |_| { toggle_action.dispatch((id.clone(), !is_active)); }
                                                             >
-                                                                {if is_active { "Deactivate" } else { "Activate" }}
+                                                                {if is_active {
+                                                                    i18n.t("admin.common.deactivate")
+                                                                } else {
+                                                                    i18n.t("admin.pats.activate")
+                                                                }}
                                                             </button>
                                                         </td>
                                                     </tr>
@@ -2120,7 +5049,7 @@ fn AdminPatManager() -> impl IntoView {
                                 view! {
                                     <div class="flex justify-between items-center pt-2">
                                         <span class="text-sm text-base-content/60">
-                                            {format!("{total} tokens total")}
+                                            {i18n.tf("admin.pats.total", &[("count", &total.to_string())])}
                                         </span>
                                         <div class="join">
                                             <button
@@ -2154,6 +5083,7 @@ fn AdminPatManager() -> impl IntoView {
 
 #[component]
 fn AccessLevelManager() -> impl IntoView {
+    let i18n = use_i18n();
     let (refresh, set_refresh) = signal(0u32);
 
     let levels_resource = LocalResource::new(move || {
@@ -2226,10 +5156,9 @@ fn AccessLevelManager() -> impl IntoView {
         <div class="card bg-base-100 shadow-xl border border-base-200 overflow-hidden">
             <div class="card-body p-0">
                 <div class="p-8 pb-4">
-                    <h2 class="card-title text-2xl mb-1">"Access Levels"</h2>
+                    <h2 class="card-title text-2xl mb-1">{i18n.t("admin.title.access_levels")}</h2>
                     <p class="text-base-content/60 text-sm">
-                        "Define content access levels and their inheritance hierarchy. "
-                        "System levels (public, loggeduser) are injected automatically and cannot be deleted."
+                        {i18n.t("admin.access_levels.intro")}
                     </p>
                 </div>
 
@@ -2275,7 +5204,7 @@ fn AccessLevelManager() -> impl IntoView {
                                                                     <span class="font-mono text-sm font-semibold">{level.name.clone()}</span>
                                                                     <span class="text-base-content/60 text-sm">{level.label.clone()}</span>
                                                                     {if level.is_system {
-                                                                        view! { <span class="badge badge-warning badge-sm">"system"</span> }.into_any()
+                                                                        view! { <span class="badge badge-warning badge-sm">{i18n.t("admin.access_levels.system")}</span> }.into_any()
                                                                     } else {
                                                                         view! { <span /> }.into_any()
                                                                     }}
@@ -2284,7 +5213,7 @@ fn AccessLevelManager() -> impl IntoView {
                                                                     let chips = level.inherits_from.clone();
                                                                     view! {
                                                                         <div class="flex gap-1 mt-1 flex-wrap">
-                                                                            <span class="text-xs text-base-content/50">"inherits:"</span>
+                                                                            <span class="text-xs text-base-content/50">{i18n.t("admin.access_levels.inherits")}</span>
                                                                             {chips.into_iter().map(|p| view! {
                                                                                 <span class="badge badge-ghost badge-xs">{p}</span>
                                                                             }).collect::<Vec<_>>()}
@@ -2308,7 +5237,7 @@ fn AccessLevelManager() -> impl IntoView {
                                                                         }
                                                                     }
                                                                 >
-                                                                    {move || if is_editing.get() { "✕" } else { "Edit" }}
+                                                                    {move || if is_editing.get() { "✕" } else { i18n.t("admin.common.edit") }}
                                                                 </button>
                                                                 {if !level.is_system {
                                                                     let del_name = name_for_del.clone();
@@ -2322,7 +5251,7 @@ fn AccessLevelManager() -> impl IntoView {
                                                                                     set_refresh.update(|c| *c += 1);
                                                                                 });
                                                                             }
-                                                                        >"Delete"</button>
+                                                                        >{i18n.t("admin.common.delete")}</button>
                                                                     }.into_any()
                                                                 } else {
                                                                     view! { <span /> }.into_any()
@@ -2334,7 +5263,7 @@ fn AccessLevelManager() -> impl IntoView {
                                                             <div class="border-t border-base-200 p-4 bg-base-50 space-y-3">
                                                                 <div class="grid grid-cols-1 gap-3 sm:grid-cols-2">
                                                                     <label class="form-control">
-                                                                        <span class="label-text text-xs font-medium">"Label"</span>
+                                                                        <span class="label-text text-xs font-medium">{i18n.t("admin.access_levels.label")}</span>
                                                                         <input
                                                                             type="text"
                                                                             class="input input-sm input-bordered mt-1"
@@ -2343,7 +5272,7 @@ fn AccessLevelManager() -> impl IntoView {
                                                                         />
                                                                     </label>
                                                                     <label class="form-control">
-                                                                        <span class="label-text text-xs font-medium">"Description"</span>
+                                                                        <span class="label-text text-xs font-medium">{i18n.t("admin.access_levels.description")}</span>
                                                                         <input
                                                                             type="text"
                                                                             class="input input-sm input-bordered mt-1"
@@ -2353,7 +5282,7 @@ fn AccessLevelManager() -> impl IntoView {
                                                                     </label>
                                                                 </div>
                                                                 <div>
-                                                                    <span class="label-text text-xs font-medium">"Inherits from"</span>
+                                                                    <span class="label-text text-xs font-medium">{i18n.t("admin.access_levels.inherits_from")}</span>
                                                                     <div class="flex gap-3 flex-wrap mt-1">
                                                                         {all_levels.iter().filter(|l| l.name != name).map(|l| {
                                                                             let lname = l.name.clone();
@@ -2384,7 +5313,7 @@ fn AccessLevelManager() -> impl IntoView {
                                                                 <button
                                                                     class="btn btn-primary btn-sm"
                                                                     on:click=move |_| { save_edit_action.dispatch(()); }
-                                                                >"Save"</button>
+                                                                >{i18n.t("admin.common.save")}</button>
                                                             </div>
                                                         </Show>
                                                     </div>
@@ -2406,24 +5335,24 @@ fn AccessLevelManager() -> impl IntoView {
                         <button
                             class="btn btn-primary btn-sm"
                             on:click=move |_| show_create.set(true)
-                        >"+ New Access Level"</button>
+                        >{i18n.t("admin.access_levels.new")}</button>
                     </Show>
                     <Show when=move || show_create.get()>
                         <div class="space-y-3">
-                            <h3 class="font-semibold text-sm">"Create Access Level"</h3>
+                            <h3 class="font-semibold text-sm">{i18n.t("admin.access_levels.create_title")}</h3>
                             <div class="grid grid-cols-1 gap-3 sm:grid-cols-3">
                                 <label class="form-control">
-                                    <span class="label-text text-xs font-medium">"Name (slug)"</span>
+                                    <span class="label-text text-xs font-medium">{i18n.t("admin.access_levels.name")}</span>
                                     <input
                                         type="text"
                                         class="input input-sm input-bordered mt-1"
-                                        placeholder="e.g. cloud-developer"
+                                        placeholder=i18n.t("admin.access_levels.name_placeholder")
                                         prop:value=move || new_name.get()
                                         on:input=move |e| new_name.set(event_target_value(&e))
                                     />
                                 </label>
                                 <label class="form-control">
-                                    <span class="label-text text-xs font-medium">"Label"</span>
+                                    <span class="label-text text-xs font-medium">{i18n.t("admin.access_levels.label")}</span>
                                     <input
                                         type="text"
                                         class="input input-sm input-bordered mt-1"
@@ -2432,7 +5361,7 @@ fn AccessLevelManager() -> impl IntoView {
                                     />
                                 </label>
                                 <label class="form-control">
-                                    <span class="label-text text-xs font-medium">"Description"</span>
+                                    <span class="label-text text-xs font-medium">{i18n.t("admin.access_levels.description")}</span>
                                     <input
                                         type="text"
                                         class="input input-sm input-bordered mt-1"
@@ -2442,11 +5371,11 @@ fn AccessLevelManager() -> impl IntoView {
                                 </label>
                             </div>
                             <div>
-                                <span class="label-text text-xs font-medium">"Inherits from (comma-separated names)"</span>
+                                <span class="label-text text-xs font-medium">{i18n.t("admin.access_levels.inherits_from_names")}</span>
                                 <input
                                     type="text"
                                     class="input input-sm input-bordered mt-1 w-full"
-                                    placeholder="e.g. internal,developer"
+                                    placeholder=i18n.t("admin.access_levels.inherits_placeholder")
                                     on:input=move |e| {
                                         let val = event_target_value(&e);
                                         new_inherits.set(
@@ -2456,8 +5385,8 @@ fn AccessLevelManager() -> impl IntoView {
                                 />
                             </div>
                             <div class="flex gap-2">
-                                <button class="btn btn-primary btn-sm" on:click=move |_| { create_action.dispatch(()); }>"Create"</button>
-                                <button class="btn btn-ghost btn-sm" on:click=move |_| show_create.set(false)>"Cancel"</button>
+                                <button class="btn btn-primary btn-sm" on:click=move |_| { create_action.dispatch(()); }>{i18n.t("admin.common.create")}</button>
+                                <button class="btn btn-ghost btn-sm" on:click=move |_| show_create.set(false)>{i18n.t("admin.common.cancel")}</button>
                             </div>
                         </div>
                     </Show>
@@ -2469,8 +5398,10 @@ fn AccessLevelManager() -> impl IntoView {
 
 // ── User Manager ──────────────────────────────────────────────────────────────
 
+#[allow(unused_variables)]
 #[component]
 fn UserManager() -> impl IntoView {
+    let i18n = use_i18n();
     let (refresh, set_refresh) = signal(0u32);
 
     let users_resource = LocalResource::new(move || {
@@ -2479,28 +5410,63 @@ fn UserManager() -> impl IntoView {
     });
 
     let levels_resource = LocalResource::new(move || with_auth_retry(list_admin_access_levels));
+    let local_accounts_resource = LocalResource::new(get_is_local_accounts);
+    let local_accounts = move || {
+        local_accounts_resource
+            .get()
+            .and_then(|res| res.ok())
+            .unwrap_or(false)
+    };
 
     let editing = RwSignal::new(Option::<String>::None);
+    let edit_name = RwSignal::new(String::new());
+    let edit_is_admin = RwSignal::new(false);
     let edit_assigned = RwSignal::new(Vec::<String>::new());
     let edit_can_write = RwSignal::new(false);
     let edit_can_read_draft = RwSignal::new(false);
     let edit_can_write_draft = RwSignal::new(false);
+    let edit_password = RwSignal::new(String::new());
     let error_msg = RwSignal::new(Option::<String>::None);
+    let info_msg = RwSignal::new(Option::<String>::None);
+
+    let show_create = RwSignal::new(false);
+    let new_email = RwSignal::new(String::new());
+    let new_name = RwSignal::new(String::new());
+    let new_password = RwSignal::new(String::new());
+    let new_is_admin = RwSignal::new(false);
+
+    let optional_name = |name: String| {
+        let name = name.trim().to_string();
+        (!name.is_empty()).then_some(name)
+    };
 
     let save_action = Action::new_local(move |_: &()| async move {
         let Some(user_id) = editing.get_untracked() else {
             return;
         };
         let result = with_auth_retry(|| {
-            set_admin_user_access_levels(
+            update_admin_user(
                 user_id.clone(),
-                edit_assigned.get_untracked(),
-                edit_can_write.get_untracked(),
-                edit_can_read_draft.get_untracked(),
-                edit_can_write_draft.get_untracked(),
+                optional_name(edit_name.get_untracked()),
+                edit_is_admin.get_untracked(),
             )
         })
         .await;
+        let result = match result {
+            Ok(()) => {
+                with_auth_retry(|| {
+                    set_admin_user_access_levels(
+                        user_id.clone(),
+                        edit_assigned.get_untracked(),
+                        edit_can_write.get_untracked(),
+                        edit_can_read_draft.get_untracked(),
+                        edit_can_write_draft.get_untracked(),
+                    )
+                })
+                .await
+            }
+            Err(e) => Err(e),
+        };
         match result {
             Ok(_) => {
                 editing.set(None);
@@ -2511,14 +5477,80 @@ fn UserManager() -> impl IntoView {
         }
     });
 
+    let create_action = Action::new_local(move |_: &()| async move {
+        let result = with_auth_retry(|| {
+            create_admin_local_user(
+                new_email.get_untracked(),
+                optional_name(new_name.get_untracked()),
+                new_password.get_untracked(),
+                new_is_admin.get_untracked(),
+            )
+        })
+        .await;
+        match result {
+            Ok(()) => {
+                show_create.set(false);
+                new_email.set(String::new());
+                new_name.set(String::new());
+                new_password.set(String::new());
+                new_is_admin.set(false);
+                error_msg.set(None);
+                info_msg.set(Some(i18n.t_untracked("admin.users.created").into()));
+                set_refresh.update(|c| *c += 1);
+            }
+            Err(e) => error_msg.set(Some(e.to_string())),
+        }
+    });
+
+    let reset_action = Action::new_local(move |_: &()| async move {
+        let Some(user_id) = editing.get_untracked() else {
+            return;
+        };
+        let result = with_auth_retry(|| {
+            reset_admin_user_password(user_id.clone(), edit_password.get_untracked())
+        })
+        .await;
+        match result {
+            Ok(()) => {
+                edit_password.set(String::new());
+                error_msg.set(None);
+                info_msg.set(Some(i18n.t_untracked("admin.users.password_reset").into()));
+            }
+            Err(e) => error_msg.set(Some(e.to_string())),
+        }
+    });
+
+    let delete_action = Action::new_local(move |user_id: &String| {
+        let user_id = user_id.clone();
+        async move {
+            match with_auth_retry(|| delete_admin_user(user_id.clone())).await {
+                Ok(()) => {
+                    editing.set(None);
+                    error_msg.set(None);
+                    set_refresh.update(|c| *c += 1);
+                }
+                Err(e) => error_msg.set(Some(e.to_string())),
+            }
+        }
+    });
+
     view! {
         <div class="card bg-base-100 shadow-xl border border-base-200 overflow-hidden">
             <div class="card-body p-0">
                 <div class="p-8 pb-4">
-                    <h2 class="card-title text-2xl mb-1">"Users"</h2>
-                    <p class="text-base-content/60 text-sm">
-                        "Assign access levels and write permissions to registered users."
-                    </p>
+                    <div class="flex items-start justify-between gap-4">
+                        <div>
+                            <h2 class="card-title text-2xl mb-1">{i18n.t("admin.users.title")}</h2>
+                            <p class="text-base-content/60 text-sm">
+                                {i18n.t("admin.users.intro")}
+                            </p>
+                        </div>
+                        <Show when=local_accounts>
+                            <button class="btn btn-primary btn-sm" on:click=move |_| show_create.update(|v| *v = !*v)>
+                                {i18n.t("admin.users.add")}
+                            </button>
+                        </Show>
+                    </div>
                 </div>
 
                 {move || error_msg.get().map(|e| view! {
@@ -2527,6 +5559,43 @@ fn UserManager() -> impl IntoView {
                         <button class="btn btn-ghost btn-xs" on:click=move |_| error_msg.set(None)>"✕"</button>
                     </div>
                 })}
+                {move || info_msg.get().map(|m| view! {
+                    <div class="mx-8 alert alert-success text-sm">
+                        <span>{m}</span>
+                        <button class="btn btn-ghost btn-xs" on:click=move |_| info_msg.set(None)>"✕"</button>
+                    </div>
+                })}
+
+                <Show when=move || show_create.get()>
+                    <div class="mx-8 mt-2 p-4 border border-base-200 rounded-xl space-y-3">
+                        <div class="grid grid-cols-1 md:grid-cols-2 gap-3">
+                            <input type="email" class="input input-bordered input-sm" placeholder=i18n.t("admin.users.email")
+                                prop:value=move || new_email.get()
+                                on:input=move |e| new_email.set(event_target_value(&e))
+                            />
+                            <input type="text" class="input input-bordered input-sm" placeholder=i18n.t("admin.users.name_optional")
+                                prop:value=move || new_name.get()
+                                on:input=move |e| new_name.set(event_target_value(&e))
+                            />
+                            <input type="password" class="input input-bordered input-sm" placeholder=i18n.t("admin.users.initial_password")
+                                autocomplete="new-password"
+                                prop:value=move || new_password.get()
+                                on:input=move |e| new_password.set(event_target_value(&e))
+                            />
+                            <label class="flex items-center gap-2 cursor-pointer">
+                                <input type="checkbox" class="checkbox checkbox-xs"
+                                    prop:checked=move || new_is_admin.get()
+                                    on:change=move |e| new_is_admin.set(event_target_checked(&e))
+                                />
+                                <span class="text-xs">{i18n.t("admin.users.administrator")}</span>
+                            </label>
+                        </div>
+                        <div class="flex gap-2">
+                            <button class="btn btn-primary btn-sm" on:click=move |_| { create_action.dispatch(()); }>{i18n.t("admin.common.create")}</button>
+                            <button class="btn btn-ghost btn-sm" on:click=move |_| show_create.set(false)>{i18n.t("admin.common.cancel")}</button>
+                        </div>
+                    </div>
+                </Show>
 
                 <div class="px-8 py-4 space-y-3">
                     <Suspense fallback=move || view! {
@@ -2546,6 +5615,8 @@ fn UserManager() -> impl IntoView {
                                         children=move |user| {
                                             let uid = user.id.clone();
                                             let uid_for_memo = uid.clone();
+                                            let delete_uid = uid.clone();
+                                            let is_local = user.is_local;
                                             let is_editing = Memo::new(move |_| {
                                                 editing.get().as_deref() == Some(uid_for_memo.as_str())
                                             });
@@ -2558,12 +5629,15 @@ fn UserManager() -> impl IntoView {
                                                             <div class="flex items-center gap-2 flex-wrap">
                                                                 <span class="font-medium text-sm">{user.email.clone()}</span>
                                                                 {if user.is_admin {
-                                                                    view! { <span class="badge badge-primary badge-sm">"admin"</span> }.into_any()
+                                                                    view! { <span class="badge badge-primary badge-sm">{i18n.t("admin.users.admin_badge")}</span> }.into_any()
+                                                                } else { view! { <span /> }.into_any() }}
+                                                                {if user.is_local {
+                                                                    view! { <span class="badge badge-ghost badge-sm">{i18n.t("admin.users.local_badge")}</span> }.into_any()
                                                                 } else { view! { <span /> }.into_any() }}
                                                             </div>
                                                             <div class="flex gap-1 mt-1 flex-wrap">
                                                                 {if user.assigned_access_levels.is_empty() {
-                                                                    view! { <span class="text-xs text-base-content/40 italic">"no levels assigned"</span> }.into_any()
+                                                                    view! { <span class="text-xs text-base-content/40 italic">{i18n.t("admin.users.no_levels")}</span> }.into_any()
                                                                 } else {
                                                                     view! {
                                                                         <>{user.assigned_access_levels.iter().map(|l| view! {
@@ -2576,8 +5650,7 @@ fn UserManager() -> impl IntoView {
                                                                 let eff = user.effective_access_levels.clone();
                                                                 view! {
                                                                     <div class="text-xs text-base-content/50 mt-1">
-                                                                        "effective: "
-                                                                        {eff.join(", ")}
+                                                                        {i18n.tf("admin.users.effective", &[("levels", &eff.join(", "))])}
                                                                     </div>
                                                                 }.into_any()
                                                             } else { view! { <span /> }.into_any() }}
@@ -2590,6 +5663,9 @@ fn UserManager() -> impl IntoView {
                                                                         editing.set(None);
                                                                     } else {
                                                                         editing.set(Some(uid.clone()));
+                                                                        edit_name.set(user.name.clone().unwrap_or_default());
+                                                                        edit_is_admin.set(user.is_admin);
+                                                                        edit_password.set(String::new());
                                                                         edit_assigned.set(user.assigned_access_levels.clone());
                                                                         edit_can_write.set(user.can_write);
                                                                         edit_can_read_draft.set(user.can_read_draft);
@@ -2597,15 +5673,39 @@ fn UserManager() -> impl IntoView {
                                                                     }
                                                                 }
                                                             >
-                                                                {move || if is_editing.get() { "✕" } else { "Edit" }}
+                                                                {move || if is_editing.get() { "✕" } else { i18n.t("admin.common.edit") }}
                                                             </button>
+                                                            <button
+                                                                class="btn btn-ghost btn-xs text-error hover:bg-error/10"
+                                                                on:click=move |_| {
+                                                                    #[cfg(feature = "hydrate")]
+                                                                    {
+                                                                        if window().confirm_with_message(i18n.t_untracked("admin.users.delete_confirm")).unwrap_or(false) {
+                                                                            delete_action.dispatch(delete_uid.clone());
+                                                                        }
+                                                                    }
+                                                                }
+                                                            >{i18n.t("admin.common.delete")}</button>
                                                         </div>
                                                     </div>
 
                                                     <Show when=move || is_editing.get()>
                                                         <div class="border-t border-base-200 p-4 bg-base-50 space-y-3">
+                                                            <div class="flex gap-4 items-center flex-wrap">
+                                                                <input type="text" class="input input-bordered input-sm" placeholder=i18n.t("admin.common.name")
+                                                                    prop:value=move || edit_name.get()
+                                                                    on:input=move |e| edit_name.set(event_target_value(&e))
+                                                                />
+                                                                <label class="flex items-center gap-2 cursor-pointer">
+                                                                    <input type="checkbox" class="checkbox checkbox-xs"
+                                                                        prop:checked=move || edit_is_admin.get()
+                                                                        on:change=move |e| edit_is_admin.set(event_target_checked(&e))
+                                                                    />
+                                                                    <span class="text-xs">{i18n.t("admin.users.administrator")}</span>
+                                                                </label>
+                                                            </div>
                                                             <div>
-                                                                <span class="label-text text-xs font-medium">"Assigned access levels"</span>
+                                                                <span class="label-text text-xs font-medium">{i18n.t("admin.users.assigned_levels")}</span>
                                                                 <div class="flex gap-3 flex-wrap mt-2">
                                                                     {all_levels.iter().filter(|l| !l.is_system).map(|l| {
                                                                         let lname = l.name.clone();
@@ -2639,27 +5739,41 @@ fn UserManager() -> impl IntoView {
                                                                         prop:checked=move || edit_can_write.get()
                                                                         on:change=move |e| edit_can_write.set(event_target_checked(&e))
                                                                     />
-                                                                    <span class="text-xs">"Can write"</span>
+                                                                    <span class="text-xs">{i18n.t("admin.users.can_write")}</span>
                                                                 </label>
                                                                 <label class="flex items-center gap-2 cursor-pointer">
                                                                     <input type="checkbox" class="checkbox checkbox-xs"
                                                                         prop:checked=move || edit_can_read_draft.get()
                                                                         on:change=move |e| edit_can_read_draft.set(event_target_checked(&e))
                                                                     />
-                                                                    <span class="text-xs">"Read drafts"</span>
+                                                                    <span class="text-xs">{i18n.t("admin.users.read_drafts")}</span>
                                                                 </label>
                                                                 <label class="flex items-center gap-2 cursor-pointer">
                                                                     <input type="checkbox" class="checkbox checkbox-xs"
                                                                         prop:checked=move || edit_can_write_draft.get()
                                                                         on:change=move |e| edit_can_write_draft.set(event_target_checked(&e))
                                                                     />
-                                                                    <span class="text-xs">"Write drafts"</span>
+                                                                    <span class="text-xs">{i18n.t("admin.users.write_drafts")}</span>
                                                                 </label>
                                                             </div>
                                                             <button
                                                                 class="btn btn-primary btn-sm"
                                                                 on:click=move |_| { save_action.dispatch(()); }
-                                                            >"Save"</button>
+                                                            >{i18n.t("admin.common.save")}</button>
+                                                            <Show when=move || is_local>
+                                                                <div class="flex gap-2 items-center pt-2 border-t border-base-200">
+                                                                    <input type="password" class="input input-bordered input-sm"
+                                                                        placeholder=i18n.t("admin.users.temporary_password")
+                                                                        autocomplete="new-password"
+                                                                        prop:value=move || edit_password.get()
+                                                                        on:input=move |e| edit_password.set(event_target_value(&e))
+                                                                    />
+                                                                    <button
+                                                                        class="btn btn-outline btn-sm"
+                                                                        on:click=move |_| { reset_action.dispatch(()); }
+                                                                    >{i18n.t("admin.users.reset_password")}</button>
+                                                                </div>
+                                                            </Show>
                                                         </div>
                                                     </Show>
                                                 </div>
@@ -2675,3 +5789,111 @@ fn UserManager() -> impl IntoView {
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn footer_links_round_trip_through_editor_format() {
+        let links = parse_footer_links(
+            "Status | https://status.example.com\n\n  Support|/docs/support  \nno separator",
+        );
+        assert_eq!(
+            links,
+            vec![
+                FooterLink {
+                    label: "Status".into(),
+                    url: "https://status.example.com".into(),
+                },
+                FooterLink {
+                    label: "Support".into(),
+                    url: "/docs/support".into(),
+                },
+            ]
+        );
+        assert_eq!(parse_footer_links(&format_footer_links(&links)), links);
+    }
+
+    #[test]
+    fn nav_link_groups_parse_headers_and_ungrouped_links() {
+        let groups = parse_nav_link_groups(
+            "Status | https://status.example.com\n[Observability]\nGrafana | https://grafana.example.com\n[Empty]\n",
+        );
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].title, "");
+        assert_eq!(groups[0].links[0].label, "Status");
+        assert_eq!(groups[1].title, "Observability");
+        assert_eq!(groups[1].links[0].url, "https://grafana.example.com");
+        assert_eq!(
+            parse_nav_link_groups(&format_nav_link_groups(&groups)),
+            groups
+        );
+    }
+
+    #[test]
+    fn service_schedules_round_trip_through_editor_format() {
+        let schedules = parse_service_schedules(
+            "payments-api = PABC123\n\n  search=Search on-call  \nno separator",
+        );
+        assert_eq!(
+            schedules,
+            vec![
+                ServiceSchedule {
+                    service: "payments-api".into(),
+                    schedule_id: "PABC123".into(),
+                },
+                ServiceSchedule {
+                    service: "search".into(),
+                    schedule_id: "Search on-call".into(),
+                },
+            ]
+        );
+        assert_eq!(
+            parse_service_schedules(&format_service_schedules(&schedules)),
+            schedules
+        );
+    }
+
+    #[test]
+    fn ldap_group_mappings_round_trip_through_editor_format() {
+        let mappings = parse_ldap_group_mappings(
+            "payments = internal, payments\n\ncn=sre,ou=groups,dc=acme,dc=com=ops,\nno separator",
+        );
+        assert_eq!(
+            mappings,
+            vec![
+                LdapGroupMapping {
+                    group: "payments".into(),
+                    access_levels: vec!["internal".into(), "payments".into()],
+                },
+                LdapGroupMapping {
+                    group: "cn=sre,ou=groups,dc=acme,dc=com".into(),
+                    access_levels: vec!["ops".into()],
+                },
+            ]
+        );
+        assert_eq!(
+            parse_ldap_group_mappings(&format_ldap_group_mappings(&mappings)),
+            mappings
+        );
+    }
+
+    #[test]
+    fn document_batch_update_maps_bulk_actions() {
+        let slugs = vec!["guides/setup".to_string()];
+        let update = document_batch_update("add_tags", " k8s, ,setup ", slugs.clone()).unwrap();
+        assert_eq!(update.add_tags, ["k8s", "setup"]);
+        assert_eq!(update.slugs, slugs);
+
+        let update = document_batch_update("move", "", slugs.clone()).unwrap();
+        assert_eq!(update.parent_slug.as_deref(), Some(""));
+        assert!(
+            document_batch_update("delete", "ignored", slugs.clone())
+                .unwrap()
+                .delete
+        );
+        assert!(document_batch_update("owner", " ", slugs.clone()).is_err());
+        assert!(document_batch_update("remove_tags", ",", slugs).is_err());
+    }
+}
@@ -0,0 +1,399 @@
+use std::collections::{HashMap, HashSet};
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
+use serde::{Deserialize, Serialize};
+
+use crate::app::get_browse_entries;
+use crate::auth::refresh_client::with_auth_retry;
+use crate::i18n::use_i18n;
+
+/// One row of the `/browse` catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BrowseEntry {
+    pub slug: String,
+    pub title: String,
+    /// Effective parent: the explicit `parent_slug`, or the slug prefix.
+    pub parent_slug: Option<String>,
+    /// Nesting depth in the document tree (set by [`tree_order`]).
+    pub depth: usize,
+    pub tags: Vec<String>,
+    pub service_owner: String,
+    pub access_level: String,
+    pub is_draft: bool,
+    /// The caller is one of the document's contributors.
+    #[serde(default)]
+    pub is_mine: bool,
+    /// ISO date (`YYYY-MM-DD`), which also sorts chronologically.
+    pub last_updated: String,
+}
+
+/// Order entries depth-first through the document tree, children sorted by
+/// title, and record each entry's depth.
+///
+/// Entries are attached to their nearest *listed* ancestor, so a document
+/// whose parent is not visible to the caller still shows up (as a root).
+pub fn tree_order(entries: Vec<BrowseEntry>) -> Vec<BrowseEntry> {
+    let listed: HashSet<String> = entries.iter().map(|e| e.slug.clone()).collect();
+
+    let mut children: HashMap<Option<String>, Vec<BrowseEntry>> = HashMap::new();
+    for entry in entries {
+        let mut parent = entry.parent_slug.clone();
+        while let Some(p) = parent.as_deref() {
+            if listed.contains(p) && p != entry.slug {
+                break;
+            }
+            parent = p.rsplit_once('/').map(|(prefix, _)| prefix.to_string());
+        }
+        children.entry(parent).or_default().push(entry);
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_by_cached_key(|e| (e.title.to_lowercase(), e.slug.clone()));
+    }
+
+    fn visit(
+        parent: Option<String>,
+        depth: usize,
+        children: &mut HashMap<Option<String>, Vec<BrowseEntry>>,
+        out: &mut Vec<BrowseEntry>,
+    ) {
+        for mut entry in children.remove(&parent).unwrap_or_default() {
+            entry.depth = depth;
+            let slug = entry.slug.clone();
+            out.push(entry);
+            visit(Some(slug), depth + 1, children, out);
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(listed.len());
+    visit(None, 0, &mut children, &mut ordered);
+    // Explicit parent_slug cycles are unreachable from the roots; list them flat
+    let mut rest: Vec<BrowseEntry> = children.into_values().flatten().collect();
+    rest.sort_by_cached_key(|e| e.slug.clone());
+    ordered.extend(rest.into_iter().map(|e| BrowseEntry { depth: 0, ..e }));
+    ordered
+}
+
+/// Column the catalog is sorted by. `Tree` keeps the document hierarchy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowseSort {
+    Tree,
+    Title,
+    Owner,
+    Updated,
+}
+
+/// Keep entries whose title, slug, owner or tags contain `query`
+/// (case-insensitive) and, when given, that belong to `owner`.
+pub fn filter_entries(entries: &[BrowseEntry], query: &str, owner: &str) -> Vec<BrowseEntry> {
+    let query = query.trim().to_lowercase();
+    entries
+        .iter()
+        .filter(|e| owner.is_empty() || e.service_owner == owner)
+        .filter(|e| {
+            query.is_empty()
+                || e.title.to_lowercase().contains(&query)
+                || e.slug.to_lowercase().contains(&query)
+                || e.service_owner.to_lowercase().contains(&query)
+                || e.tags.iter().any(|t| t.to_lowercase().contains(&query))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Sort tree-ordered entries by `key`. Ties keep tree order.
+pub fn sort_entries(entries: &mut [BrowseEntry], key: BrowseSort, ascending: bool) {
+    match key {
+        BrowseSort::Tree => return,
+        BrowseSort::Title => entries.sort_by_cached_key(|e| e.title.to_lowercase()),
+        BrowseSort::Owner => entries.sort_by_cached_key(|e| e.service_owner.to_lowercase()),
+        BrowseSort::Updated => entries.sort_by(|a, b| a.last_updated.cmp(&b.last_updated)),
+    }
+    if !ascending {
+        entries.reverse();
+    }
+}
+
+/// Catalog of every document the caller can read, with owner, tags and
+/// last-updated columns. `?drafts=mine` starts on the caller's own drafts.
+#[component]
+pub fn BrowsePage() -> impl IntoView {
+    let i18n = use_i18n();
+    let entries_resource = LocalResource::new(|| with_auth_retry(get_browse_entries));
+    let query_map = use_query_map();
+
+    let query = RwSignal::new(String::new());
+    let owner = RwSignal::new(String::new());
+    let my_drafts =
+        RwSignal::new(query_map.with_untracked(|q| q.get("drafts").as_deref() == Some("mine")));
+    let sort = RwSignal::new((BrowseSort::Tree, true));
+
+    view! {
+        <div class="max-w-6xl mx-auto">
+            <h1 class="text-3xl font-bold">{i18n.t("browse.title")}</h1>
+            <p class="text-base-content/60 mt-2 mb-6">{i18n.t("browse.subtitle")}</p>
+            <Suspense fallback=move || view! {
+                <div class="flex justify-center py-12">
+                    <span class="loading loading-spinner loading-lg"></span>
+                </div>
+            }>
+                {move || {
+                    entries_resource.get().map(|result| match result {
+                        Ok(entries) => {
+                            let mut owners: Vec<String> = entries.iter()
+                                .map(|e| e.service_owner.clone())
+                                .filter(|o| !o.is_empty())
+                                .collect();
+                            owners.sort();
+                            owners.dedup();
+                            let total = entries.len();
+
+                            let rows = Memo::new(move |_| {
+                                let mut rows = filter_entries(&entries, &query.get(), &owner.get());
+                                if my_drafts.get() {
+                                    rows.retain(|e| e.is_draft && e.is_mine);
+                                }
+                                let (key, ascending) = sort.get();
+                                sort_entries(&mut rows, key, ascending);
+                                rows
+                            });
+
+                            view! {
+                                <div class="flex flex-wrap items-center gap-3 mb-4">
+                                    <input
+                                        type="text"
+                                        class="input input-bordered input-sm w-full sm:w-72"
+                                        placeholder=i18n.t("browse.filter_placeholder")
+                                        prop:value=query
+                                        on:input=move |ev| query.set(event_target_value(&ev))
+                                    />
+                                    <select
+                                        class="select select-bordered select-sm"
+                                        on:change=move |ev| owner.set(event_target_value(&ev))
+                                    >
+                                        <option value="">{i18n.t("browse.all_owners")}</option>
+                                        {owners.into_iter().map(|o| view! {
+                                            <option value=o.clone()>{o.clone()}</option>
+                                        }).collect::<Vec<_>>()}
+                                    </select>
+                                    <label class="label cursor-pointer gap-2">
+                                        <input
+                                            type="checkbox"
+                                            class="toggle toggle-sm toggle-warning"
+                                            prop:checked=my_drafts
+                                            on:change=move |ev| my_drafts.set(event_target_checked(&ev))
+                                        />
+                                        <span class="text-sm">{i18n.t("browse.my_drafts")}</span>
+                                    </label>
+                                    <Show when=move || sort.get().0 != BrowseSort::Tree>
+                                        <button
+                                            class="btn btn-ghost btn-sm"
+                                            on:click=move |_| sort.set((BrowseSort::Tree, true))
+                                        >
+                                            {i18n.t("browse.show_tree")}
+                                        </button>
+                                    </Show>
+                                    <span class="text-sm text-base-content/50 ml-auto">
+                                        {move || i18n.tf("browse.documents", &[("shown", &rows.read().len().to_string()), ("total", &total.to_string())])}
+                                    </span>
+                                </div>
+                                <div class="overflow-x-auto">
+                                    <table class="table table-sm">
+                                        <thead>
+                                            <tr>
+                                                <SortHeader label=i18n.t("browse.col.title") column=BrowseSort::Title sort />
+                                                <SortHeader label=i18n.t("browse.col.owner") column=BrowseSort::Owner sort />
+                                                <th>{i18n.t("browse.col.tags")}</th>
+                                                <SortHeader label=i18n.t("browse.col.updated") column=BrowseSort::Updated sort />
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            <For
+                                                each=move || rows.get()
+                                                key=|e| e.slug.clone()
+                                                children=move |entry| view! { <BrowseRow entry query sort /> }
+                                            />
+                                        </tbody>
+                                    </table>
+                                    <Show when=move || rows.read().is_empty()>
+                                        <p class="text-center text-base-content/50 py-8">{i18n.t("browse.empty")}</p>
+                                    </Show>
+                                </div>
+                            }.into_any()
+                        }
+                        Err(e) => view! {
+                            <div class="alert alert-error">
+                                <span>{e.to_string()}</span>
+                            </div>
+                        }.into_any(),
+                    })
+                }}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Clickable column header; a second click on the active column flips the
+/// direction.
+#[component]
+fn SortHeader(
+    label: &'static str,
+    column: BrowseSort,
+    sort: RwSignal<(BrowseSort, bool)>,
+) -> impl IntoView {
+    let on_click = move |_| {
+        sort.update(|(current, ascending)| {
+            if *current == column {
+                *ascending = !*ascending;
+            } else {
+                *current = column;
+                // Most recent first is the useful default for dates
+                *ascending = column != BrowseSort::Updated;
+            }
+        })
+    };
+
+    view! {
+        <th>
+            <button class="flex items-center gap-1 hover:text-primary" on:click=on_click>
+                {label}
+                {move || {
+                    let (current, ascending) = sort.get();
+                    (current == column).then_some(if ascending { "▲" } else { "▼" })
+                }}
+            </button>
+        </th>
+    }
+}
+
+#[component]
+fn BrowseRow(
+    entry: BrowseEntry,
+    query: RwSignal<String>,
+    sort: RwSignal<(BrowseSort, bool)>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let depth = entry.depth;
+    let indent = move || {
+        let depth = if sort.get().0 == BrowseSort::Tree {
+            depth
+        } else {
+            0
+        };
+        format!("padding-left: {}rem", depth as f32 * 1.25)
+    };
+
+    view! {
+        <tr class="hover">
+            <td>
+                <div style=indent class="flex items-center gap-2">
+                    <a href=format!("/docs/{}", entry.slug) class="link link-hover font-medium">
+                        {entry.title}
+                    </a>
+                    {entry.is_draft.then(|| view! {
+                        <span class="badge badge-warning badge-xs">{i18n.t("browse.draft")}</span>
+                    })}
+                    {(entry.access_level != "public").then(|| view! {
+                        <span class="badge badge-ghost badge-xs">{entry.access_level.clone()}</span>
+                    })}
+                </div>
+            </td>
+            <td class="text-base-content/70">{entry.service_owner}</td>
+            <td>
+                <div class="flex flex-wrap gap-1">
+                    {entry.tags.into_iter().map(|tag| {
+                        let filter = tag.clone();
+                        view! {
+                            <button
+                                class="badge badge-outline badge-sm hover:badge-primary"
+                                on:click=move |_| query.set(filter.clone())
+                            >
+                                {tag}
+                            </button>
+                        }
+                    }).collect::<Vec<_>>()}
+                </div>
+            </td>
+            <td class="text-base-content/70 whitespace-nowrap">{entry.last_updated}</td>
+        </tr>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(slug: &str, title: &str, parent: Option<&str>) -> BrowseEntry {
+        BrowseEntry {
+            slug: slug.into(),
+            title: title.into(),
+            parent_slug: parent.map(Into::into),
+            depth: 0,
+            tags: vec![],
+            service_owner: String::new(),
+            access_level: "public".into(),
+            is_draft: false,
+            is_mine: false,
+            last_updated: String::new(),
+        }
+    }
+
+    fn slugs(entries: &[BrowseEntry]) -> Vec<(&str, usize)> {
+        entries.iter().map(|e| (e.slug.as_str(), e.depth)).collect()
+    }
+
+    #[test]
+    fn tree_order_nests_under_nearest_listed_ancestor() {
+        let ordered = tree_order(vec![
+            entry("ops/runbooks/db", "Database", Some("ops/runbooks")),
+            entry("guides", "Guides", None),
+            entry("ops", "Operations", None),
+            entry("guides/intro", "Intro", Some("guides")),
+            entry("adr", "ADRs", None),
+        ]);
+        assert_eq!(
+            slugs(&ordered),
+            vec![
+                ("adr", 0),
+                ("guides", 0),
+                ("guides/intro", 1),
+                ("ops", 0),
+                ("ops/runbooks/db", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn tree_order_keeps_entries_caught_in_parent_cycles() {
+        let ordered = tree_order(vec![
+            entry("a", "A", Some("b")),
+            entry("b", "B", Some("a")),
+            entry("c", "C", None),
+        ]);
+        assert_eq!(slugs(&ordered), vec![("c", 0), ("a", 0), ("b", 0)]);
+    }
+
+    #[test]
+    fn filter_and_sort_entries() {
+        let mut a = entry("a", "Alpha", None);
+        a.service_owner = "platform".into();
+        a.tags = vec!["Kubernetes".into()];
+        a.last_updated = "2026-01-02".into();
+        let mut b = entry("b", "beta", None);
+        b.service_owner = "payments".into();
+        b.last_updated = "2026-03-01".into();
+        let entries = vec![a, b];
+
+        assert_eq!(filter_entries(&entries, "kube", "").len(), 1);
+        assert_eq!(filter_entries(&entries, "", "payments")[0].slug, "b");
+        assert_eq!(filter_entries(&entries, "alpha", "payments").len(), 0);
+
+        let mut rows = entries.clone();
+        sort_entries(&mut rows, BrowseSort::Updated, false);
+        assert_eq!(rows[0].slug, "b");
+        sort_entries(&mut rows, BrowseSort::Title, true);
+        assert_eq!(rows[0].slug, "a");
+        sort_entries(&mut rows, BrowseSort::Tree, false);
+        assert_eq!(rows[0].slug, "a");
+    }
+}
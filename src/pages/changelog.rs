@@ -0,0 +1,153 @@
+use leptos::prelude::*;
+
+use crate::app::{get_service_on_call, list_changelog, ChangelogItem};
+use crate::auth::refresh_client::with_auth_retry;
+use crate::components::OnCallCard;
+use crate::i18n::use_i18n;
+
+/// Group consecutive items by release date, preserving order.
+pub fn group_by_date(items: Vec<ChangelogItem>) -> Vec<(String, Vec<ChangelogItem>)> {
+    let mut groups: Vec<(String, Vec<ChangelogItem>)> = Vec::new();
+    for item in items {
+        match groups.last_mut() {
+            Some((date, group)) if *date == item.date => group.push(item),
+            _ => groups.push((item.date.clone(), vec![item])),
+        }
+    }
+    groups
+}
+
+/// Changelog of all services, newest first.
+#[component]
+pub fn ChangelogPage() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="max-w-4xl mx-auto">
+            <h1 class="text-3xl font-bold">{i18n.t("changelog.title")}</h1>
+            <p class="text-base-content/60 mt-2 mb-8">{i18n.t("changelog.subtitle")}</p>
+            <ChangelogTimeline service=Signal::stored(None) />
+        </div>
+    }
+}
+
+/// Changelog of a single service (`/changelog/<service>`).
+#[component]
+pub fn ServiceChangelogPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let params = leptos_router::hooks::use_params_map();
+    let service = Signal::derive(move || params.read().get("service"));
+    let on_call = LocalResource::new(move || {
+        let service = service.get().unwrap_or_default();
+        async move { with_auth_retry(|| get_service_on_call(service.clone())).await }
+    });
+
+    view! {
+        <div class="max-w-4xl mx-auto">
+            <a href="/changelog" class="text-sm text-base-content/60 hover:text-primary">
+                {i18n.t("changelog.all_services")}
+            </a>
+            <h1 class="text-3xl font-bold mt-2 mb-8">
+                {move || service.get().unwrap_or_default()}
+            </h1>
+            {move || {
+                on_call
+                    .get()
+                    .and_then(|result| result.ok().flatten())
+                    .map(|info| view! { <OnCallCard info /> })
+            }}
+            <ChangelogTimeline service=service />
+        </div>
+    }
+}
+
+#[component]
+fn ChangelogTimeline(service: Signal<Option<String>>) -> impl IntoView {
+    let i18n = use_i18n();
+    let entries = Resource::new(move || service.get(), list_changelog);
+    let show_service = move || service.get().is_none();
+
+    view! {
+        <Suspense fallback=move || view! {
+            <div class="flex justify-center py-12">
+                <span class="loading loading-spinner loading-lg"></span>
+            </div>
+        }>
+            {move || entries.get().map(|result| match result {
+                Ok(items) if items.is_empty() => view! {
+                    <p class="text-base-content/50 py-8">{i18n.t("changelog.empty")}</p>
+                }.into_any(),
+                Ok(items) => view! {
+                    <div class="space-y-10">
+                        {group_by_date(items).into_iter().map(|(date, group)| view! {
+                            <section>
+                                <h2 class="text-sm font-semibold uppercase tracking-wide text-base-content/50 border-b border-base-200 pb-2 mb-4">
+                                    {date}
+                                </h2>
+                                <div class="space-y-6">
+                                    {group.into_iter().map(|item| view! {
+                                        <ChangelogEntryCard item=item show_service=show_service() />
+                                    }).collect::<Vec<_>>()}
+                                </div>
+                            </section>
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }.into_any(),
+                Err(e) => view! {
+                    <div class="alert alert-error">
+                        <span>{e.to_string()}</span>
+                    </div>
+                }.into_any(),
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn ChangelogEntryCard(item: ChangelogItem, show_service: bool) -> impl IntoView {
+    let i18n = use_i18n();
+    let service_href = format!("/changelog/{}", item.service);
+
+    view! {
+        <article>
+            <div class="flex items-baseline gap-3 flex-wrap">
+                {show_service.then(|| view! {
+                    <a href=service_href class="font-semibold hover:text-primary">{item.service.clone()}</a>
+                })}
+                <span class="badge badge-outline badge-sm font-mono">{item.version}</span>
+                <span class="text-xs text-base-content/40">
+                    {i18n.tf("changelog.published_by", &[("name", &item.published_by)])}
+                </span>
+            </div>
+            <div class="prose max-w-none mt-2" inner_html=item.html></div>
+        </article>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(service: &str, date: &str) -> ChangelogItem {
+        ChangelogItem {
+            service: service.into(),
+            version: "1.0.0".into(),
+            date: date.into(),
+            html: String::new(),
+            published_by: "ci".into(),
+        }
+    }
+
+    #[test]
+    fn test_group_by_date_keeps_order() {
+        let groups = group_by_date(vec![
+            item("a", "2026-05-04"),
+            item("b", "2026-05-04"),
+            item("a", "2026-04-30"),
+        ]);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "2026-05-04");
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[1].1[0].date, "2026-04-30");
+    }
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::components::MarkdownContent;
 use crate::db::chat_models::SourceReference;
+use crate::i18n::use_i18n;
 use crate::rendering::markdown::render_markdown;
 
 /// Feedback state on a single assistant message.
@@ -84,14 +85,15 @@ pub fn ChatPage() -> impl IntoView {
 
     let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
     let is_logged_in = move || current_user.map(|sig| sig.get().is_some()).unwrap_or(false);
+    let i18n = use_i18n();
 
     view! {
-        <Show when=is_logged_in fallback=|| view! {
+        <Show when=is_logged_in fallback=move || view! {
             <div class="flex items-center justify-center min-h-[60vh]">
                 <div class="text-center space-y-4">
-                    <h2 class="text-2xl font-bold">"Sign in required"</h2>
-                    <p class="text-base-content/60">"Please log in to use the AI chat assistant."</p>
-                    <a href="/login" class="btn btn-primary">"Sign in"</a>
+                    <h2 class="text-2xl font-bold">{move || i18n.t("chat.sign_in_required")}</h2>
+                    <p class="text-base-content/60">{move || i18n.t("chat.sign_in_hint")}</p>
+                    <a href="/login?next=/chat" class="btn btn-primary">{move || i18n.t("chat.sign_in")}</a>
                 </div>
             </div>
         }>
@@ -114,6 +116,7 @@ fn ChatContent() -> impl IntoView {
     let streaming_content = context.streaming_content;
     let streaming_sources = context.streaming_sources;
     let error_msg = context.error_msg;
+    let i18n = use_i18n();
 
     let (input, set_input) = signal(String::new());
     let textarea_ref = NodeRef::<leptos::html::Textarea>::new();
@@ -204,17 +207,17 @@ fn ChatContent() -> impl IntoView {
                                     <svg class="w-8 h-8" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2z"/></svg>
                                 </div>
                                 <div class="space-y-2">
-                                    <h2 class="text-2xl font-bold tracking-tight">"AI Assistant"</h2>
-                                    <p class="text-base-content/50">"Ask me anything about the documentation and codebase."</p>
+                                    <h2 class="text-2xl font-bold tracking-tight">{move || i18n.t("chat.title")}</h2>
+                                    <p class="text-base-content/50">{move || i18n.t("chat.intro")}</p>
                                 </div>
                                 <div class="grid grid-cols-1 gap-2">
                                     <button class="btn btn-outline btn-sm font-normal normal-case border-base-300 hover:bg-base-200 hover:border-base-300 text-base-content/70"
-                                        on:click={move |_| { set_input.set("What is Lekton?".to_string()); send_message(); }}>
-                                        "What is Lekton?"
+                                        on:click={move |_| { set_input.set(i18n.t_untracked("chat.suggestion_about").to_string()); send_message(); }}>
+                                        {move || i18n.t("chat.suggestion_about")}
                                     </button>
                                     <button class="btn btn-outline btn-sm font-normal normal-case border-base-300 hover:bg-base-200 hover:border-base-300 text-base-content/70"
-                                        on:click={move |_| { set_input.set("How do I configure OIDC?".to_string()); send_message(); }}>
-                                        "How do I configure OIDC?"
+                                        on:click={move |_| { set_input.set(i18n.t_untracked("chat.suggestion_oidc").to_string()); send_message(); }}>
+                                        {move || i18n.t("chat.suggestion_oidc")}
                                     </button>
                                 </div>
                             </div>
@@ -349,7 +352,7 @@ fn ChatContent() -> impl IntoView {
                             <textarea
                                 class="w-full bg-transparent outline-none border-0 resize-none text-sm text-base-content placeholder:text-base-content/40 leading-6 overflow-y-hidden"
                                 style="height: 24px; min-height: 24px;"
-                                placeholder="Type your message..."
+                                placeholder=move || i18n.t("chat.input_placeholder")
                                 node_ref=textarea_ref
                                 prop:value=move || input.get()
                                 on:input=move |ev| {
@@ -395,8 +398,8 @@ fn ChatContent() -> impl IntoView {
                             </button>
                         </div>
                         <div class="mt-2 flex justify-between px-2">
-                            <span class="text-[10px] text-base-content/30 italic">"Shift + Enter for new line"</span>
-                            <span class="text-[10px] text-base-content/30">"AI responses may be inaccurate"</span>
+                            <span class="text-[10px] text-base-content/30 italic">{move || i18n.t("chat.newline_hint")}</span>
+                            <span class="text-[10px] text-base-content/30">{move || i18n.t("chat.disclaimer")}</span>
                         </div>
                     </div>
                 </div>
@@ -407,12 +410,13 @@ fn ChatContent() -> impl IntoView {
 
 #[component]
 fn SourceReferencesBlock(sources: Vec<SourceReference>) -> impl IntoView {
-    let count = sources.len();
+    let i18n = use_i18n();
+    let count = sources.len().to_string();
 
     view! {
         <details class="rounded-xl border border-base-300/80 bg-base-100/70 overflow-hidden">
             <summary class="cursor-pointer list-none px-3 py-2 text-xs font-semibold uppercase tracking-[0.18em] text-base-content/60">
-                {format!("Sources ({count})")}
+                {move || i18n.tf("chat.sources", &[("count", &count)])}
             </summary>
             <div class="flex flex-col gap-2 border-t border-base-300/80 px-3 py-3">
                 <For
@@ -478,6 +482,7 @@ fn MessageFeedbackBar(
 ) -> impl IntoView {
     // All signals are Copy — safe to capture in multiple closures.
     let feedback = RwSignal::new(initial_feedback);
+    let i18n = use_i18n();
     let show_comment_box = RwSignal::new(false);
     let comment_input = RwSignal::new(String::new());
 
@@ -536,7 +541,7 @@ fn MessageFeedbackBar(
                             }
                         }
                     }
-                    title="Helpful"
+                    title=move || i18n.t("chat.helpful")
                 >
                     <svg class="w-3.5 h-3.5" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">
                         <path d="M14 9V5a3 3 0 0 0-3-3l-4 9v11h11.28a2 2 0 0 0 2-1.7l1.38-9a2 2 0 0 0-2-2.3H14Z"/>
@@ -575,7 +580,7 @@ fn MessageFeedbackBar(
                             );
                         }
                     }
-                    title="Not helpful"
+                    title=move || i18n.t("chat.not_helpful")
                 >
                     <svg class="w-3.5 h-3.5" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">
                         <path d="M10 15v4a3 3 0 0 0 3 3l4-9V2H5.72a2 2 0 0 0-2 1.7l-1.38 9a2 2 0 0 0 2 2.3H10Z"/>
@@ -588,7 +593,7 @@ fn MessageFeedbackBar(
                 <Show when=move || feedback.get().is_some() fallback=|| ()>
                     {move || feedback.get().map(|fb| {
                         let is_pos = fb.rating == "positive";
-                        let label = if is_pos { "Helpful" } else { "Not helpful" };
+                        let label = i18n.t(if is_pos { "chat.helpful" } else { "chat.not_helpful" });
                         let badge_class = format!(
                             "badge badge-xs gap-1 {}",
                             if is_pos { "badge-success badge-soft" } else { "badge-error badge-soft" }
@@ -611,7 +616,7 @@ fn MessageFeedbackBar(
                                             });
                                         }
                                     }
-                                    title="Remove feedback"
+                                    title=move || i18n.t("chat.remove_feedback")
                                 >
                                     <svg class="w-2.5 h-2.5" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="3"><line x1="18" y1="6" x2="6" y2="18"/><line x1="6" y1="6" x2="18" y2="18"/></svg>
                                 </button>
@@ -624,10 +629,10 @@ fn MessageFeedbackBar(
             // Negative comment box (shown when thumbs-down clicked and no feedback set yet)
             <Show when=move || show_comment_box.get() fallback=|| ()>
                 <div class="flex flex-col gap-2 p-2 bg-base-200/50 rounded-lg border border-base-300 max-w-sm">
-                    <p class="text-[11px] text-base-content/60">"What was wrong? (optional)"</p>
+                    <p class="text-[11px] text-base-content/60">{move || i18n.t("chat.feedback_question")}</p>
                     <textarea
                         class="textarea textarea-sm textarea-bordered text-xs resize-none bg-base-100 min-h-[52px]"
-                        placeholder="Tell us what could be improved..."
+                        placeholder=move || i18n.t("chat.feedback_placeholder")
                         prop:value=move || comment_input.get()
                         on:input=move |ev| comment_input.set(event_target_value(&ev))
                         rows=2
@@ -637,7 +642,7 @@ fn MessageFeedbackBar(
                             class="btn btn-ghost btn-xs"
                             on:click=move |_| { show_comment_box.set(false); comment_input.set(String::new()); }
                         >
-                            "Cancel"
+                            {move || i18n.t("chat.cancel")}
                         </button>
                         <button
                             class="btn btn-error btn-xs"
@@ -663,7 +668,7 @@ fn MessageFeedbackBar(
                                 }
                             }
                         >
-                            "Submit"
+                            {move || i18n.t("chat.submit")}
                         </button>
                     </div>
                 </div>
@@ -0,0 +1,365 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+
+use crate::app::{
+    delete_collection, get_collection, list_collections, save_collection, set_collection_progress,
+    CollectionSummary, CollectionView,
+};
+use crate::auth::refresh_client::with_auth_retry;
+use crate::i18n::use_i18n;
+
+/// Slugs typed one per line, in order.
+pub fn parse_slug_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.trim().trim_start_matches("/docs/").trim_matches('/'))
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Progress bar label, e.g. `3 / 5`.
+fn progress_label(summary: &CollectionSummary) -> String {
+    format!("{} / {}", summary.read_count, summary.document_count)
+}
+
+/// Every collection the reader can see, with their progress, and a form
+/// to create one.
+#[component]
+pub fn CollectionsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
+    let signed_in = move || current_user.is_some_and(|u| u.get().is_some());
+    let navigate = use_navigate();
+    let created = RwSignal::new(None::<String>);
+    Effect::new(move |_| {
+        if let Some(id) = created.get() {
+            navigate(&format!("/collections/{id}"), Default::default());
+        }
+    });
+    let on_created = Callback::new(move |id: String| created.set(Some(id)));
+    let collections = RwSignal::new(None::<Result<Vec<CollectionSummary>, String>>);
+
+    let load = Action::new_local(move |_: &()| async move {
+        collections.set(Some(
+            with_auth_retry(list_collections)
+                .await
+                .map_err(|e| e.to_string()),
+        ));
+    });
+    Effect::new(move |_| {
+        load.dispatch(());
+    });
+
+    view! {
+        <div class="max-w-4xl mx-auto space-y-8">
+            <div>
+                <h1 class="text-3xl font-bold">{i18n.t("collections.title")}</h1>
+                <p class="text-base-content/60 mt-2">{i18n.t("collections.subtitle")}</p>
+            </div>
+            {move || match collections.get() {
+                None => view! { <div class="skeleton h-32 w-full rounded-2xl" /> }.into_any(),
+                Some(Err(e)) => view! {
+                    <div class="alert alert-error">
+                        <span>{e}</span>
+                    </div>
+                }.into_any(),
+                Some(Ok(items)) if items.is_empty() => view! {
+                    <p class="text-base-content/50 py-8">{i18n.t("collections.empty")}</p>
+                }.into_any(),
+                Some(Ok(items)) => view! {
+                    <ul class="divide-y divide-base-200 rounded-box border border-base-200">
+                        {items.into_iter().map(|summary| {
+                            let progress = progress_label(&summary);
+                            let max = summary.document_count.max(1);
+                            view! {
+                                <li class="flex items-center justify-between gap-4 px-4 py-3">
+                                    <div class="min-w-0">
+                                        <a href=format!("/collections/{}", summary.id) class="link link-hover font-semibold">
+                                            {summary.title.clone()}
+                                        </a>
+                                        <p class="text-sm text-base-content/60 truncate">{summary.description.clone()}</p>
+                                    </div>
+                                    <Show when=signed_in>
+                                        <div class="flex items-center gap-2 shrink-0 text-sm text-base-content/60">
+                                            <progress class="progress progress-primary w-24" value=summary.read_count max=max></progress>
+                                            <span>{progress.clone()}</span>
+                                        </div>
+                                    </Show>
+                                </li>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                }.into_any(),
+            }}
+            <Show when=signed_in>
+                <section class="space-y-4">
+                    <h2 class="text-xl font-semibold">{i18n.t("collections.create")}</h2>
+                    <CollectionForm collection=None on_saved=on_created />
+                </section>
+            </Show>
+        </div>
+    }
+}
+
+/// A collection (`/collections/<id>`): its documents in reading order.
+#[component]
+pub fn CollectionPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let params = leptos_router::hooks::use_params_map();
+    let id = move || params.read().get("id").unwrap_or_default();
+    let collection = RwSignal::new(None::<Result<CollectionView, String>>);
+
+    let load = Action::new_local(move |id: &String| {
+        let id = id.clone();
+        async move {
+            collection.set(Some(
+                with_auth_retry(|| get_collection(id.clone()))
+                    .await
+                    .map_err(|e| e.to_string()),
+            ));
+        }
+    });
+    Effect::new(move |_| {
+        load.dispatch(id());
+    });
+
+    let toggle = Action::new_local(move |(slug, read): &(String, bool)| {
+        let (slug, read) = (slug.clone(), *read);
+        let id = params.read_untracked().get("id").unwrap_or_default();
+        async move {
+            if with_auth_retry(|| set_collection_progress(id.clone(), slug.clone(), read))
+                .await
+                .is_ok()
+            {
+                collection.update(|current| {
+                    if let Some(Ok(current)) = current {
+                        for item in current.items.iter_mut().filter(|item| item.slug == slug) {
+                            item.read = read;
+                        }
+                        current.summary.read_count =
+                            current.items.iter().filter(|item| item.read).count();
+                    }
+                });
+            }
+        }
+    });
+
+    view! {
+        <div class="max-w-4xl mx-auto space-y-8">
+            <a href="/collections" class="text-sm text-base-content/60 hover:text-primary">
+                {i18n.t("collections.all")}
+            </a>
+            {move || match collection.get() {
+                None => view! { <div class="skeleton h-32 w-full rounded-2xl" /> }.into_any(),
+                Some(Err(e)) => view! {
+                    <div class="alert alert-error">
+                        <span>{e}</span>
+                    </div>
+                }.into_any(),
+                Some(Ok(current)) => {
+                    let summary = current.summary.clone();
+                    let query = format!("?collection={}", summary.id);
+                    let signed_in = current.signed_in;
+                    let first_unread = current.items.iter().find(|item| !item.read).cloned();
+                    view! {
+                        <div>
+                            <h1 class="text-3xl font-bold">{summary.title.clone()}</h1>
+                            <p class="text-base-content/60 mt-2">{summary.description.clone()}</p>
+                            <p class="text-sm text-base-content/50 mt-2">
+                                {i18n.tf("collections.curated_by", &[("owner", &summary.owner_email)])}
+                                {signed_in.then(|| format!(" · {}", i18n.tf("collections.read", &[("progress", &progress_label(&summary))])))}
+                            </p>
+                        </div>
+                        {first_unread.map(|item| view! {
+                            <a href=format!("/docs/{}{query}", item.slug) class="btn btn-primary btn-sm">
+                                {if summary.read_count == 0 { i18n.t("collections.start") } else { i18n.t("collections.continue") }}
+                            </a>
+                        })}
+                        {if current.items.is_empty() {
+                            view! {
+                                <p class="text-base-content/50">{i18n.t("collections.no_documents")}</p>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <ol class="divide-y divide-base-200 rounded-box border border-base-200">
+                                    {current.items.iter().enumerate().map(|(index, item)| {
+                                        let slug = item.slug.clone();
+                                        let read = item.read;
+                                        view! {
+                                            <li class="flex items-center gap-4 px-4 py-3">
+                                                <span class="text-base-content/40 font-mono text-sm w-6 text-right">{index + 1}</span>
+                                                <a href=format!("/docs/{}{query}", item.slug) class="link link-hover flex-1 min-w-0 truncate">
+                                                    {item.title.clone()}
+                                                </a>
+                                                {signed_in.then(|| view! {
+                                                    <input
+                                                        type="checkbox"
+                                                        class="checkbox checkbox-sm checkbox-primary"
+                                                        title=i18n.t("collections.mark_read")
+                                                        prop:checked=read
+                                                        on:change=move |ev| { toggle.dispatch((slug.clone(), event_target_checked(&ev))); }
+                                                    />
+                                                })}
+                                            </li>
+                                        }
+                                    }).collect::<Vec<_>>()}
+                                </ol>
+                            }.into_any()
+                        }}
+                        {summary.can_edit.then(|| view! {
+                            <section class="space-y-4">
+                                <h2 class="text-xl font-semibold">{i18n.t("collections.edit")}</h2>
+                                <CollectionForm
+                                    collection=Some(current.clone())
+                                    on_saved=Callback::new(move |id: String| { load.dispatch(id); })
+                                />
+                            </section>
+                        })}
+                    }.into_any()
+                }
+            }}
+        </div>
+    }
+}
+
+/// Create a collection, or edit and delete `collection`. `on_saved` gets
+/// the ID of the saved collection.
+#[component]
+fn CollectionForm(collection: Option<CollectionView>, on_saved: Callback<String>) -> impl IntoView {
+    let i18n = use_i18n();
+    let navigate = use_navigate();
+    let id = collection.as_ref().map(|c| c.summary.id.clone());
+    let title = RwSignal::new(
+        collection
+            .as_ref()
+            .map(|c| c.summary.title.clone())
+            .unwrap_or_default(),
+    );
+    let description = RwSignal::new(
+        collection
+            .as_ref()
+            .map(|c| c.summary.description.clone())
+            .unwrap_or_default(),
+    );
+    let slugs = RwSignal::new(
+        collection
+            .as_ref()
+            .map(|c| {
+                c.items
+                    .iter()
+                    .map(|item| item.slug.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .unwrap_or_default(),
+    );
+    let error = RwSignal::new(None::<String>);
+
+    let save = {
+        let id = id.clone();
+        Action::new_local(move |_: &()| {
+            let id = id.clone();
+            let title = title.get_untracked();
+            let description = description.get_untracked();
+            let slugs = parse_slug_lines(&slugs.get_untracked());
+            async move {
+                match with_auth_retry(|| {
+                    save_collection(
+                        id.clone(),
+                        title.clone(),
+                        description.clone(),
+                        slugs.clone(),
+                    )
+                })
+                .await
+                {
+                    Ok(saved) => {
+                        error.set(None);
+                        on_saved.run(saved);
+                    }
+                    Err(err) => error.set(Some(err.to_string())),
+                }
+            }
+        })
+    };
+    let remove = {
+        let id = id.clone();
+        Action::new_local(move |_: &()| {
+            let id = id.clone().unwrap_or_default();
+            let navigate = navigate.clone();
+            async move {
+                match with_auth_retry(|| delete_collection(id.clone())).await {
+                    Ok(()) => navigate("/collections", Default::default()),
+                    Err(err) => error.set(Some(err.to_string())),
+                }
+            }
+        })
+    };
+    let editing = id.is_some();
+
+    view! {
+        <form
+            class="card bg-base-200 border border-base-300/60"
+            on:submit=move |ev| {
+                ev.prevent_default();
+                save.dispatch(());
+            }
+        >
+            <div class="card-body gap-3">
+                <input
+                    type="text"
+                    class="input input-bordered input-sm w-full"
+                    placeholder=i18n.t("collections.title_placeholder")
+                    required
+                    prop:value=title
+                    on:input=move |ev| title.set(event_target_value(&ev))
+                />
+                <input
+                    type="text"
+                    class="input input-bordered input-sm w-full"
+                    placeholder=i18n.t("collections.description_placeholder")
+                    prop:value=description
+                    on:input=move |ev| description.set(event_target_value(&ev))
+                />
+                <textarea
+                    class="textarea textarea-bordered textarea-sm w-full font-mono"
+                    rows="6"
+                    placeholder=i18n.t("collections.slugs_placeholder")
+                    prop:value=slugs
+                    on:input=move |ev| slugs.set(event_target_value(&ev))
+                ></textarea>
+                {move || error.get().map(|e| view! {
+                    <div class="alert alert-error text-sm"><span>{e}</span></div>
+                })}
+                <div class="flex justify-end gap-2">
+                    {editing.then(|| view! {
+                        <button
+                            type="button"
+                            class="btn btn-ghost btn-sm text-error"
+                            disabled=move || remove.pending().get()
+                            on:click=move |_| { remove.dispatch(()); }
+                        >
+                            {i18n.t("collections.delete")}
+                        </button>
+                    })}
+                    <button type="submit" class="btn btn-primary btn-sm" disabled=move || save.pending().get()>
+                        {i18n.t("collections.save")}
+                    </button>
+                </div>
+            </div>
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_slug_lines_accepts_paths() {
+        assert_eq!(
+            parse_slug_lines("onboarding/setup\n\n  /docs/onboarding/access/ \n/runbooks/pager\n"),
+            vec!["onboarding/setup", "onboarding/access", "runbooks/pager"]
+        );
+    }
+}
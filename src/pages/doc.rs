@@ -1,9 +1,16 @@
 use leptos::prelude::*;
+use leptos_meta::{Link, Meta, Title};
 use serde::{Deserialize, Serialize};
 
-use crate::app::get_doc_html;
-use crate::auth::refresh_client::with_auth_retry;
-use crate::components::MarkdownContent;
+use crate::app::{
+    create_share_link, get_collection_nav, get_doc_html, get_doc_meta, get_doc_on_call,
+    get_doc_rating, get_document_watch, list_share_links, rate_document, revoke_share_link,
+    set_collection_progress, unwatch_document, watch_document, CollectionNav, DocRatingStatus,
+};
+use crate::auth::refresh_client::{is_auth_error, refresh_on_auth_error, with_auth_retry};
+use crate::components::{access_denied_level, AccessDenied, MarkdownContent, OnCallCard};
+use crate::i18n::use_i18n;
+use crate::relations::{RelationPanels, SupersededNotice};
 
 /// Data returned for rendering a document page.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,11 +20,244 @@ pub struct DocPageData {
     pub headings: Vec<crate::rendering::markdown::TocHeading>,
     pub last_updated: String,
     pub tags: Vec<String>,
+    /// Author of the most recent change, when known.
+    pub last_updated_by: Option<String>,
+    /// Distinct revision authors, most recent first.
+    pub contributors: Vec<String>,
+    /// The caller may edit this document (shows the edit button).
+    #[serde(default)]
+    pub can_edit: bool,
+    /// The caller may create share links to this restricted document.
+    #[serde(default)]
+    pub can_share: bool,
+    /// Access level required to edit, when edits are restricted to a team.
+    #[serde(default)]
+    pub edit_access_level: Option<String>,
+    /// Deprecated schemas the document links to.
+    #[serde(default)]
+    pub deprecated_schemas: Vec<crate::schema::deprecation::SchemaDeprecationNotice>,
+    /// Versions of the doc bundle the document belongs to, if any.
+    #[serde(default)]
+    pub bundle: Option<DocBundleVersions>,
+    /// `(slug, title)` of the readable documents at the slug's ancestor
+    /// paths, labelling the breadcrumbs.
+    #[serde(default)]
+    pub ancestor_titles: Vec<(String, String)>,
+    /// Related documents the reader can see.
+    #[serde(default)]
+    pub relations: crate::relations::DocRelations,
+    /// Size in bytes of the full document when only a preview of it was
+    /// rendered (see `rendering.max_render_bytes`).
+    #[serde(default)]
+    pub truncated_from: Option<u64>,
+    /// `(slug, title)` of the documents before and after this one under its
+    /// parent, in sidebar order.
+    #[serde(default)]
+    pub previous_doc: Option<(String, String)>,
+    #[serde(default)]
+    pub next_doc: Option<(String, String)>,
+}
+
+/// Published versions of a doc bundle, as seen from one of its pages.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocBundleVersions {
+    /// Slug root of the bundle.
+    pub root: String,
+    /// Page path within the bundle, the same in every version.
+    pub page: String,
+    /// Version being read; `None` for the latest.
+    pub current: Option<String>,
+    /// Source version of the latest docs, when recorded.
+    pub latest: Option<String>,
+    /// Prior versions, newest first.
+    pub versions: Vec<String>,
+}
+
+impl DocBundleVersions {
+    /// Link to this page in `version` (`None` for the latest).
+    pub fn href(&self, version: Option<&str>) -> String {
+        format!(
+            "/docs/{}",
+            crate::db::bundle_repository::bundle_slug(&self.root, version, &self.page)
+        )
+    }
+}
+
+/// Dropdown switching between the versions of the doc bundle a page
+/// belongs to.
+#[component]
+fn BundleVersionSwitcher(bundle: DocBundleVersions) -> impl IntoView {
+    let i18n = use_i18n();
+    let label = bundle
+        .current
+        .clone()
+        .unwrap_or_else(|| i18n.t("doc.bundle_latest").to_string());
+    let latest_label = match &bundle.latest {
+        Some(source) => format!("{} ({source})", i18n.t("doc.bundle_latest")),
+        None => i18n.t("doc.bundle_latest").to_string(),
+    };
+    let latest_href = bundle.href(None);
+    let latest_class = if bundle.current.is_none() {
+        "active"
+    } else {
+        ""
+    };
+    view! {
+        <div class="dropdown dropdown-end">
+            <div
+                tabindex="0"
+                role="button"
+                class="btn btn-ghost btn-sm gap-1.5 text-base-content/60"
+                title=i18n.t("doc.bundle_version")
+            >
+                <span class="font-mono text-xs">{label}</span>
+                <svg class="w-3 h-3" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M19 9l-7 7-7-7"></path>
+                </svg>
+            </div>
+            <ul tabindex="0" class="dropdown-content menu bg-base-100 rounded-box z-[1] w-56 p-2 shadow border border-base-200 mt-2">
+                <li class="menu-title">{i18n.t("doc.bundle_version")}</li>
+                <li><a href=latest_href class=latest_class>{latest_label}</a></li>
+                {bundle.versions.iter().map(|version| {
+                    let href = bundle.href(Some(version.as_str()));
+                    let class = if bundle.current.as_ref() == Some(version) { "active font-mono" } else { "font-mono" };
+                    view! { <li><a href=href class=class>{version.clone()}</a></li> }
+                }).collect::<Vec<_>>()}
+            </ul>
+        </div>
+    }
+}
+
+/// Notice above a document rendered only in part, linking to its full text.
+#[component]
+fn TruncatedDocumentNotice(slug: String, size: u64) -> impl IntoView {
+    let i18n = use_i18n();
+    let raw_href = format!("/api/v1/documents/{slug}/raw");
+    let download_href = format!("{raw_href}?download=true");
+    view! {
+        <div role="alert" class="alert alert-info mb-6 print:hidden">
+            <span>
+                {i18n.t("doc.truncated")} " ("
+                {crate::editor::asset_panel::format_size(size)}
+                ")."
+            </span>
+            <div class="flex gap-2">
+                <a href=raw_href class="btn btn-sm" target="_blank" rel="noopener">
+                    {i18n.t("doc.view_raw")}
+                </a>
+                <a href=download_href class="btn btn-sm" rel="external">
+                    {i18n.t("doc.download")}
+                </a>
+            </div>
+        </div>
+    }
+}
+
+/// Banner shown on the pages of a prior bundle version.
+#[component]
+fn PriorBundleVersionNotice(bundle: DocBundleVersions) -> impl IntoView {
+    let i18n = use_i18n();
+    let version = bundle.current.clone().unwrap_or_default();
+    view! {
+        <div role="alert" class="alert alert-info mb-6 print:hidden">
+            <span>
+                {i18n.around("doc.bundle_prior_version", "version").0}
+                <span class="font-mono font-semibold">{version}</span>
+                {i18n.around("doc.bundle_prior_version", "version").1}
+            </span>
+            <a href=bundle.href(None) class="btn btn-sm">{i18n.t("doc.bundle_view_latest")}</a>
+        </div>
+    }
+}
+
+/// Warning listing the deprecated schemas a document links to, with the
+/// version to migrate to when there is one.
+#[component]
+fn DeprecatedSchemasNotice(
+    notices: Vec<crate::schema::deprecation::SchemaDeprecationNotice>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    view! {
+        <div role="alert" class="alert alert-warning mb-6 items-start print:hidden">
+            <svg class="w-5 h-5 shrink-0 mt-0.5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2"
+                    d="M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-3L13.732 4c-.77-1.333-2.694-1.333-3.464 0L3.34 16c-.77 1.333.192 3 1.732 3z">
+                </path>
+            </svg>
+            <div>
+                <p class="font-semibold">{i18n.t("doc.deprecated_schemas")}</p>
+                <ul class="text-sm mt-1 space-y-0.5">
+                    {notices.into_iter().map(|notice| {
+                        let href = notice.href();
+                        let label = match &notice.version {
+                            Some(version) => format!("{} {version}", notice.schema),
+                            None => notice.schema.clone(),
+                        };
+                        view! {
+                            <li>
+                                <span class="font-mono">{label}</span>
+                                {match notice.replacement.clone() {
+                                    Some(version) => view! {
+                                        " — " {i18n.around("doc.deprecated_schema_use", "version").0}
+                                        <a href=href class="link font-mono">{version}</a>
+                                        {i18n.around("doc.deprecated_schema_use", "version").1}
+                                    }.into_any(),
+                                    None => view! {
+                                        " — " <a href=href class="link">{i18n.t("doc.deprecated_schema_retired")}</a>
+                                    }.into_any(),
+                                }}
+                            </li>
+                        }
+                    }).collect::<Vec<_>>()}
+                </ul>
+            </div>
+        </div>
+    }
+}
+
+/// Title and description used for the document's `<head>` meta tags.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocPageMeta {
+    pub title: String,
+    pub description: String,
+    /// oEmbed endpoint describing the document, when embedding is enabled.
+    #[serde(default)]
+    pub oembed_url: Option<String>,
+    /// The document asks search engines not to index it.
+    #[serde(default)]
+    pub noindex: bool,
+}
+
+/// Per-document `<title>`, OpenGraph/Twitter card and robots tags.
+#[component]
+fn DocMetaTags(meta: DocPageMeta) -> impl IntoView {
+    let portal_name = use_context::<crate::app::InstanceBranding>()
+        .map(|b| b.0.get_untracked().portal_name)
+        .unwrap_or_else(|| "Lekton".to_string());
+    let page_title = format!("{} — {}", meta.title, portal_name);
+    let description = meta.description;
+
+    view! {
+        <Title text=page_title />
+        <Meta property="og:type" content="article" />
+        <Meta property="og:site_name" content=portal_name />
+        <Meta property="og:title" content=meta.title.clone() />
+        <Meta property="og:description" content=description.clone() />
+        <Meta name="twitter:card" content="summary" />
+        <Meta name="twitter:title" content=meta.title />
+        <Meta name="twitter:description" content=description />
+        {meta.oembed_url.map(|href| view! {
+            <Link rel="alternate" type_="application/json+oembed" href=href />
+        })}
+        {meta.noindex.then(|| view! { <Meta name="robots" content="noindex, nofollow" /> })}
+    }
 }
 
 /// Breadcrumbs component to show document hierarchy based on slug.
+/// Ancestors are labelled with their document title when one exists.
 #[component]
-fn Breadcrumbs(slug: String) -> impl IntoView {
+fn Breadcrumbs(slug: String, ancestor_titles: Vec<(String, String)>) -> impl IntoView {
+    let i18n = use_i18n();
     let parts: Vec<&str> = slug.split('/').collect();
 
     let breadcrumb_items: Vec<_> = parts
@@ -26,6 +266,9 @@ fn Breadcrumbs(slug: String) -> impl IntoView {
         .map(|(idx, part)| {
             let is_last = idx == parts.len() - 1;
             let path = parts[..=idx].join("/");
+            if let Some((_, title)) = ancestor_titles.iter().find(|(slug, _)| *slug == path) {
+                return (path, title.clone(), is_last);
+            }
             let label = part
                 .split('-')
                 .map(|word| {
@@ -46,7 +289,7 @@ fn Breadcrumbs(slug: String) -> impl IntoView {
         <div class="breadcrumbs text-sm">
             <ul>
                 <li>
-                    <a href="/" class="hover:underline">"Docs"</a>
+                    <a href="/" class="hover:underline">{move || i18n.t("nav.docs")}</a>
                 </li>
                 {breadcrumb_items.into_iter().map(|(path, label, is_last)| {
                     if is_last {
@@ -66,9 +309,524 @@ fn Breadcrumbs(slug: String) -> impl IntoView {
     }
 }
 
+/// Opens the print view of the document (`crate::api::print`) in a new
+/// tab, which lays it out for paper and opens the browser print dialog once
+/// its images and diagrams have loaded.
+#[component]
+fn PrintButton(slug: String) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <a
+            href=format!("/docs/{slug}/print")
+            target="_blank"
+            rel="noopener"
+            class="btn btn-ghost btn-sm gap-1.5 text-base-content/60 hover:text-primary"
+            title=i18n.t("doc.print_title")
+        >
+            <svg class="w-3.5 h-3.5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2"
+                    d="M17 17h2a2 2 0 002-2v-4a2 2 0 00-2-2H5a2 2 0 00-2 2v4a2 2 0 002 2h2m2 4h6a2 2 0 002-2v-4a2 2 0 00-2-2H9a2 2 0 00-2 2v4a2 2 0 002 2zm8-12V5a2 2 0 00-2-2H9a2 2 0 00-2 2v4h10z">
+                </path>
+            </svg>
+            {i18n.t("doc.print")}
+        </a>
+    }
+}
+
+/// Toggles the caller's watch of this document; changes then show up in the
+/// `/notifications` inbox. Hidden for anonymous visitors.
+#[component]
+fn WatchButton(slug: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
+    let signed_in = move || current_user.is_some_and(|u| u.get().is_some());
+
+    let watch = RwSignal::new(None::<crate::app::WatchInfo>);
+    let lookup_slug = slug.clone();
+    let load = Action::new_local(move |_: &()| {
+        let slug = lookup_slug.clone();
+        async move {
+            if let Ok(current) = with_auth_retry(|| get_document_watch(slug.clone())).await {
+                watch.set(current);
+            }
+        }
+    });
+    Effect::new(move |_| {
+        if signed_in() {
+            load.dispatch(());
+        }
+    });
+
+    let toggle = Action::new_local(move |_: &()| {
+        let slug = slug.clone();
+        let current = watch.get_untracked();
+        async move {
+            match current {
+                Some(existing) => {
+                    if with_auth_retry(|| unwatch_document(existing.id.clone()))
+                        .await
+                        .is_ok()
+                    {
+                        watch.set(None);
+                    }
+                }
+                None => {
+                    if let Ok(created) =
+                        with_auth_retry(|| watch_document(slug.clone(), false, None)).await
+                    {
+                        watch.set(Some(created));
+                    }
+                }
+            }
+        }
+    });
+
+    view! {
+        <Show when=signed_in>
+            <button
+                class="btn btn-ghost btn-sm gap-1.5 text-base-content/60 hover:text-primary"
+                class:text-primary=move || watch.get().is_some()
+                title=i18n.t("doc.watch_title")
+                disabled=move || toggle.pending().get()
+                on:click=move |_| { toggle.dispatch(()); }
+            >
+                <svg class="w-3.5 h-3.5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2"
+                        d="M15 17h5l-1.405-1.405A2.032 2.032 0 0118 14.158V11a6.002 6.002 0 00-4-5.659V5a2 2 0 10-4 0v.341C7.67 6.165 6 8.388 6 11v3.159c0 .538-.214 1.055-.595 1.436L4 17h5m6 0v1a3 3 0 11-6 0v-1m6 0H9">
+                    </path>
+                </svg>
+                {move || if watch.get().is_some() { i18n.t("doc.unwatch") } else { i18n.t("doc.watch") }}
+            </button>
+        </Show>
+    }
+}
+
+/// Creates and revokes share links: signed, expiring URLs giving people
+/// without an account read access to this restricted document.
+#[component]
+fn ShareButton(slug: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let days = RwSignal::new(7u32);
+    let note = RwSignal::new(String::new());
+    let created_url = RwSignal::new(None::<String>);
+    let error = RwSignal::new(None::<String>);
+    let links = RwSignal::new(Vec::<crate::app::ShareLinkInfo>::new());
+
+    let list_slug = slug.clone();
+    let load = Action::new_local(move |_: &()| {
+        let slug = list_slug.clone();
+        async move {
+            if let Ok(list) = with_auth_retry(|| list_share_links(Some(slug.clone()))).await {
+                links.set(list.into_iter().filter(|link| link.active).collect());
+            }
+        }
+    });
+    let create = Action::new_local(move |_: &()| {
+        let slug = slug.clone();
+        let days = days.get_untracked();
+        let note_text = note.get_untracked();
+        async move {
+            match with_auth_retry(|| create_share_link(slug.clone(), days, note_text.clone())).await
+            {
+                Ok(created) => {
+                    #[cfg(feature = "hydrate")]
+                    let url = leptos::web_sys::window()
+                        .and_then(|w| w.location().origin().ok())
+                        .map(|origin| format!("{origin}{}", created.path))
+                        .unwrap_or(created.path);
+                    #[cfg(not(feature = "hydrate"))]
+                    let url = created.path;
+                    created_url.set(Some(url));
+                    error.set(None);
+                    note.set(String::new());
+                    links.update(|list| list.insert(0, created.link));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        }
+    });
+    let revoke = Action::new_local(move |id: &String| {
+        let id = id.clone();
+        async move {
+            match with_auth_retry(|| revoke_share_link(id.clone())).await {
+                Ok(()) => links.update(|list| list.retain(|link| link.id != id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        }
+    });
+    let copy = move |_| {
+        #[cfg(feature = "hydrate")]
+        if let Some(url) = created_url.get_untracked() {
+            // JSON strings are valid JS string literals
+            let literal = serde_json::to_string(&url).unwrap_or_default();
+            let _ = js_sys::eval(&format!("navigator.clipboard.writeText({literal})"));
+        }
+    };
+
+    view! {
+        <div class="dropdown dropdown-end">
+            <div
+                tabindex="0"
+                role="button"
+                class="btn btn-ghost btn-sm gap-1.5 text-base-content/60 hover:text-primary"
+                title=i18n.t("doc.share_title")
+                on:focus=move |_| { load.dispatch(()); }
+            >
+                <svg class="w-3.5 h-3.5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2"
+                        d="M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1">
+                    </path>
+                </svg>
+                {i18n.t("doc.share")}
+            </div>
+            <div tabindex="0" class="dropdown-content bg-base-100 rounded-box z-[1] w-80 p-4 shadow border border-base-200 mt-2 space-y-3">
+                <p class="text-xs text-base-content/60">{i18n.t("doc.share_title")}</p>
+                <label class="form-control">
+                    <span class="label-text text-xs">{i18n.t("doc.share_days")}</span>
+                    <input
+                        type="number"
+                        min="1"
+                        class="input input-bordered input-sm"
+                        prop:value=move || days.get().to_string()
+                        on:input=move |ev| {
+                            if let Ok(value) = event_target_value(&ev).parse() {
+                                days.set(value);
+                            }
+                        }
+                    />
+                </label>
+                <label class="form-control">
+                    <span class="label-text text-xs">{i18n.t("doc.share_note")}</span>
+                    <input
+                        type="text"
+                        class="input input-bordered input-sm"
+                        prop:value=move || note.get()
+                        on:input=move |ev| note.set(event_target_value(&ev))
+                    />
+                </label>
+                <button
+                    class="btn btn-primary btn-sm w-full"
+                    disabled=move || create.pending().get()
+                    on:click=move |_| { create.dispatch(()); }
+                >
+                    {i18n.t("doc.share_create")}
+                </button>
+                {move || error.get().map(|message| view! {
+                    <div class="alert alert-error text-xs py-2">{message}</div>
+                })}
+                {move || created_url.get().map(|url| view! {
+                    <div class="space-y-1">
+                        <p class="text-xs text-warning">{i18n.t("doc.share_created")}</p>
+                        <div class="join w-full">
+                            <input type="text" readonly class="input input-bordered input-sm join-item w-full font-mono text-xs" value=url />
+                            <button class="btn btn-sm join-item" on:click=copy>{i18n.t("doc.share_copy")}</button>
+                        </div>
+                    </div>
+                })}
+                <Show when=move || !links.get().is_empty()>
+                    <div class="border-t border-base-200 pt-2">
+                        <p class="text-xs font-semibold text-base-content/60 mb-1">{i18n.t("doc.share_links")}</p>
+                        <ul class="space-y-1">
+                            <For
+                                each=move || links.get()
+                                key=|link| link.id.clone()
+                                children=move |link| {
+                                    let id = link.id.clone();
+                                    let who = if link.note.is_empty() { link.created_by.clone() } else { link.note.clone() };
+                                    view! {
+                                        <li class="flex items-center justify-between gap-2 text-xs">
+                                            <span class="truncate" title=who.clone()>
+                                                {who.clone()}
+                                                <span class="text-base-content/50">
+                                                    {format!(" · {}", i18n.tf("doc.share_link_status", &[("date", &link.expires_at), ("views", &link.access_count.to_string())]))}
+                                                </span>
+                                            </span>
+                                            <button
+                                                class="btn btn-ghost btn-xs text-error"
+                                                on:click=move |_| { revoke.dispatch(id.clone()); }
+                                            >
+                                                {i18n.t("doc.share_revoke")}
+                                            </button>
+                                        </li>
+                                    }
+                                }
+                            />
+                        </ul>
+                    </div>
+                </Show>
+            </div>
+        </div>
+    }
+}
+
 /// Table of Contents component for document navigation.
+/// "Was this helpful?" buttons for signed-in readers, with an optional
+/// comment once they have rated. Editors also see the totals.
+#[component]
+fn HelpfulWidget(slug: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
+    let signed_in = move || current_user.is_some_and(|u| u.get().is_some());
+
+    let status = RwSignal::new(DocRatingStatus::default());
+    let comment = RwSignal::new(String::new());
+    let comment_sent = RwSignal::new(false);
+    let lookup_slug = slug.clone();
+    let load = Action::new_local(move |_: &()| {
+        let slug = lookup_slug.clone();
+        async move {
+            if let Ok(current) = with_auth_retry(|| get_doc_rating(slug.clone())).await {
+                comment.set(current.comment.clone());
+                status.set(current);
+            }
+        }
+    });
+    Effect::new(move |_| {
+        if signed_in() {
+            load.dispatch(());
+        }
+    });
+
+    let rate = Action::new_local(move |(helpful, with_comment): &(bool, bool)| {
+        let (helpful, with_comment) = (*helpful, *with_comment);
+        let slug = slug.clone();
+        // The comment is only sent with the form; a new vote keeps the saved one
+        let text = if with_comment {
+            comment.get_untracked()
+        } else {
+            status.get_untracked().comment
+        };
+        async move {
+            if with_auth_retry(|| rate_document(slug.clone(), helpful, text.clone()))
+                .await
+                .is_ok()
+            {
+                comment_sent.set(with_comment);
+                load.dispatch(());
+            }
+        }
+    });
+
+    let vote_button = move |helpful: bool, label: &'static str, icon: &'static str| {
+        view! {
+            <button
+                class="btn btn-sm gap-1.5"
+                class:btn-primary=move || status.get().mine == Some(helpful)
+                class:btn-ghost=move || status.get().mine != Some(helpful)
+                aria-pressed=move || (status.get().mine == Some(helpful)).to_string()
+                disabled=move || rate.pending().get()
+                on:click=move |_| { rate.dispatch((helpful, false)); }
+            >
+                <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d=icon></path>
+                </svg>
+                {label}
+            </button>
+        }
+    };
+
+    view! {
+        <Show when=signed_in>
+            <section class="mt-12 rounded-box border border-base-200 p-4 space-y-3 print:hidden">
+                <div class="flex flex-wrap items-center gap-3">
+                    <span class="font-semibold">{i18n.t("doc.helpful_question")}</span>
+                    {vote_button(true, i18n.t("doc.helpful_yes"), "M14 10h4.764a2 2 0 011.789 2.894l-3.5 7A2 2 0 0115.263 21h-4.017c-.163 0-.326-.02-.485-.06L7 20m7-10V5a2 2 0 00-2-2h-.095c-.5 0-.905.405-.905.905 0 .714-.211 1.412-.608 2.006L7 11v9m7-10h-2M7 20H5a2 2 0 01-2-2v-6a2 2 0 012-2h2.5")}
+                    {vote_button(false, i18n.t("doc.helpful_no"), "M10 14H5.236a2 2 0 01-1.789-2.894l3.5-7A2 2 0 018.736 3h4.018a2 2 0 01.485.06l3.76.94m-7 10v5a2 2 0 002 2h.096c.5 0 .905-.405.905-.904 0-.715.211-1.413.608-2.008L17 13V4m-7 10h2m5-10h2a2 2 0 012 2v6a2 2 0 01-2 2h-2.5")}
+                    {move || status.get().counts.map(|counts| view! {
+                        <span class="text-sm text-base-content/50 ml-auto" title=i18n.t("doc.helpful_editors_only")>
+                            {format!(
+                                "{} {} / {} {}",
+                                counts.helpful,
+                                i18n.t("doc.helpful_yes"),
+                                counts.not_helpful,
+                                i18n.t("doc.helpful_no"),
+                            )}
+                        </span>
+                    })}
+                </div>
+                {move || status.get().mine.map(|helpful| view! {
+                    <form
+                        class="flex flex-col gap-2 sm:flex-row sm:items-start"
+                        on:submit=move |ev| {
+                            ev.prevent_default();
+                            rate.dispatch((helpful, true));
+                        }
+                    >
+                        <textarea
+                            class="textarea textarea-bordered textarea-sm flex-1"
+                            rows="2"
+                            maxlength="1000"
+                            placeholder=i18n.t("doc.helpful_comment_placeholder")
+                            prop:value=comment
+                            on:input=move |ev| {
+                                comment_sent.set(false);
+                                comment.set(event_target_value(&ev));
+                            }
+                        ></textarea>
+                        <button type="submit" class="btn btn-sm" disabled=move || rate.pending().get()>
+                            {move || if comment_sent.get() { i18n.t("doc.helpful_thanks") } else { i18n.t("doc.helpful_send") }}
+                        </button>
+                    </form>
+                })}
+            </section>
+        </Show>
+    }
+}
+
+/// Links to the previous and next documents under the same parent, for
+/// reading a guide page after page. Documents opened from a collection get
+/// the collection's links instead.
+#[component]
+fn SiblingNav(
+    previous_doc: Option<(String, String)>,
+    next_doc: Option<(String, String)>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let query = leptos_router::hooks::use_query_map();
+    let in_collection = move || {
+        query
+            .read()
+            .get("collection")
+            .is_some_and(|id| !id.is_empty())
+    };
+    let has_links = previous_doc.is_some() || next_doc.is_some();
+    let card = move |(slug, title): (String, String), label: &'static str, align: &'static str| {
+        view! {
+            <a
+                href=format!("/docs/{slug}")
+                class=format!("card card-compact w-1/2 min-w-0 border border-base-200 hover:border-primary/40 transition-colors {align}")
+            >
+                <div class="card-body">
+                    <span class="text-xs text-base-content/50">{label}</span>
+                    <span class="font-semibold text-primary truncate">{title}</span>
+                </div>
+            </a>
+        }
+    };
+    view! {
+        <Show when=move || has_links && !in_collection()>
+            <nav class="flex gap-4 mt-12 print:hidden" aria-label=i18n.t("doc.sibling_nav")>
+                {previous_doc.clone().map(|doc| card(doc, i18n.t("doc.previous"), "text-left"))}
+                // Keep the next card on the right when there is no previous one
+                {next_doc.clone().map(|doc| card(doc, i18n.t("doc.next"), "text-right ml-auto"))}
+            </nav>
+        </Show>
+    }
+}
+
+/// Who is on call for the service owning a runbook document.
+#[component]
+fn DocOnCall(slug: String) -> impl IntoView {
+    let on_call = LocalResource::new(move || {
+        let slug = slug.clone();
+        async move { with_auth_retry(|| get_doc_on_call(slug.clone())).await }
+    });
+    move || {
+        on_call
+            .get()
+            .and_then(|result| result.ok().flatten())
+            .map(|info| view! { <OnCallCard info /> })
+    }
+}
+
+/// Previous and next links when the document is opened from a collection
+/// (`?collection=<id>`), with the reader's progress through it.
+#[component]
+fn CollectionFooter(slug: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let query = leptos_router::hooks::use_query_map();
+    let collection = move || query.read().get("collection").filter(|id| !id.is_empty());
+
+    let nav = RwSignal::new(None::<CollectionNav>);
+    let lookup_slug = slug.clone();
+    let load = Action::new_local(move |id: &String| {
+        let id = id.clone();
+        let slug = lookup_slug.clone();
+        async move {
+            match with_auth_retry(|| get_collection_nav(id.clone(), slug.clone())).await {
+                Ok(current) => nav.set(current),
+                Err(err) => tracing::error!("Failed to load collection navigation: {err}"),
+            }
+        }
+    });
+    Effect::new(move |_| match collection() {
+        Some(id) => {
+            load.dispatch(id);
+        }
+        None => nav.set(None),
+    });
+
+    let toggle = Action::new_local(move |read: &bool| {
+        let read = *read;
+        let slug = slug.clone();
+        let current = nav.get_untracked();
+        async move {
+            let Some(current) = current else { return };
+            if with_auth_retry(|| set_collection_progress(current.id.clone(), slug.clone(), read))
+                .await
+                .is_ok()
+            {
+                nav.update(|nav| {
+                    if let Some(nav) = nav {
+                        nav.read = Some(read);
+                    }
+                });
+            }
+        }
+    });
+
+    move || {
+        nav.get().map(|nav| {
+            let query = format!("?collection={}", nav.id);
+            let link = move |item: Option<crate::app::CollectionItem>, label: &'static str| {
+                item.map(|item| {
+                    view! {
+                        <a href=format!("/docs/{}{query}", item.slug) class="btn btn-ghost btn-sm max-w-xs">
+                            <span class="truncate">{format!("{label} {}", item.title)}</span>
+                        </a>
+                    }
+                })
+            };
+            let previous = link(nav.previous.clone(), "←");
+            let next = link(nav.next.clone(), "→");
+            view! {
+                <nav class="card bg-base-200 border border-base-300/60 mt-12 print:hidden" aria-label=i18n.t("collections.title")>
+                    <div class="card-body p-4 gap-3">
+                        <div class="flex items-center justify-between gap-4 text-sm">
+                            <a href=format!("/collections/{}", nav.id) class="link link-hover font-semibold truncate">
+                                {nav.title.clone()}
+                            </a>
+                            <span class="text-base-content/60 shrink-0">
+                                {format!("{} {} / {}", i18n.t("collections.document"), nav.position, nav.total)}
+                            </span>
+                        </div>
+                        <div class="flex items-center justify-between gap-2">
+                            <div>{previous}</div>
+                            {nav.read.map(|read| view! {
+                                <label class="label cursor-pointer gap-2 text-sm">
+                                    <input
+                                        type="checkbox"
+                                        class="checkbox checkbox-sm checkbox-primary"
+                                        prop:checked=read
+                                        disabled=move || toggle.pending().get()
+                                        on:change=move |ev| { toggle.dispatch(event_target_checked(&ev)); }
+                                    />
+                                    {i18n.t("collections.mark_read")}
+                                </label>
+                            })}
+                            <div>{next}</div>
+                        </div>
+                    </div>
+                </nav>
+            }
+        })
+    }
+}
+
 #[component]
 fn TableOfContents(headings: Vec<crate::rendering::markdown::TocHeading>) -> impl IntoView {
+    let i18n = use_i18n();
     if headings.is_empty() {
         return view! {
             <div></div>
@@ -77,8 +835,8 @@ fn TableOfContents(headings: Vec<crate::rendering::markdown::TocHeading>) -> imp
     }
 
     view! {
-        <nav class="sticky top-20 hidden xl:block w-64 ml-8">
-            <div class="text-sm font-semibold mb-4">"On This Page"</div>
+        <nav data-toc class="sticky top-20 hidden xl:block print:hidden w-64 ml-8">
+            <div class="text-sm font-semibold mb-4">{i18n.t("doc.on_this_page")}</div>
             <ul class="space-y-2 text-sm">
                 {headings.into_iter().map(|heading| {
                     let indent_class = if heading.level == 3 {
@@ -92,7 +850,7 @@ fn TableOfContents(headings: Vec<crate::rendering::markdown::TocHeading>) -> imp
                         <li class=indent_class>
                             <a
                                 href=href
-                                class="text-base-content/70 hover:text-primary transition-colors"
+                                class="toc-link text-base-content/70 hover:text-primary transition-colors"
                             >
                                 {heading.text}
                             </a>
@@ -105,18 +863,119 @@ fn TableOfContents(headings: Vec<crate::rendering::markdown::TocHeading>) -> imp
     .into_any()
 }
 
+/// Toast shown when the open document is rewritten (re-ingest or editor save),
+/// fed by the `/api/v1/docs/events` SSE stream. In incident mode the
+/// document reloads at once instead.
+#[component]
+fn DocUpdateToast(#[prop(into)] slug: Signal<String>, on_refresh: Callback<()>) -> impl IntoView {
+    let i18n = use_i18n();
+    let (updated, set_updated) = signal(false);
+    let portal = use_context::<crate::app::PortalFeatures>().map(|features| features.0);
+    let incident_mode = move || portal.is_some_and(|portal| portal.get().incident_mode);
+    Effect::new(move |_| {
+        if updated.get() && portal.is_some_and(|portal| portal.get_untracked().incident_mode) {
+            set_updated.set(false);
+            on_refresh.run(());
+        }
+    });
+
+    #[cfg(feature = "hydrate")]
+    {
+        use wasm_bindgen::prelude::*;
+
+        let source = StoredValue::new_local(None::<web_sys::EventSource>);
+        Effect::new(move |_| {
+            let url = format!(
+                "/api/v1/docs/events?slug={}",
+                String::from(js_sys::encode_uri_component(&slug.get()))
+            );
+            set_updated.set(false);
+            source.update_value(|current| {
+                if let Some(previous) = current.take() {
+                    previous.close();
+                }
+                let Ok(events) = web_sys::EventSource::new(&url) else {
+                    return;
+                };
+                let on_update = Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |_| {
+                    set_updated.set(true);
+                });
+                let _ = events.add_event_listener_with_callback(
+                    "updated",
+                    on_update.as_ref().unchecked_ref(),
+                );
+                on_update.forget();
+                *current = Some(events);
+            });
+        });
+        on_cleanup(move || {
+            source.try_update_value(|current| {
+                if let Some(events) = current.take() {
+                    events.close();
+                }
+            });
+        });
+    }
+    // The server render has no event stream to subscribe to
+    #[cfg(not(feature = "hydrate"))]
+    let _ = slug;
+
+    view! {
+        <Show when=move || updated.get() && !incident_mode()>
+            <div class="toast toast-end z-50 print:hidden">
+                <div class="alert alert-info shadow-lg">
+                    <span>{i18n.t("doc.updated_notice")}</span>
+                    <button
+                        class="btn btn-sm btn-primary"
+                        on:click=move |_| {
+                            set_updated.set(false);
+                            on_refresh.run(());
+                        }
+                    >
+                        {i18n.t("doc.refresh")}
+                    </button>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
 /// Document viewer page — renders markdown content fetched from S3.
 #[component]
 pub fn DocPage() -> impl IntoView {
     let params = leptos_router::hooks::use_params_map();
     let slug = move || params.read().get("slug").unwrap_or_default();
+    let i18n = use_i18n();
 
-    let doc_resource = LocalResource::new(move || {
-        let slug = slug();
-        with_auth_retry(move || get_doc_html(slug.clone()))
-    });
+    // Loaded while server rendering: with out-of-order streaming the shell
+    // and title are sent at once and the content follows when S3 returns it.
+    let doc_resource = Resource::new(slug, get_doc_html);
+    refresh_on_auth_error(
+        move || matches!(doc_resource.get(), Some(Err(e)) if is_auth_error(&e)),
+        move || doc_resource.refetch(),
+    );
+
+    // Blocking so the tags are part of the server-rendered <head>, where
+    // link unfurlers and crawlers (which don't run WASM) can see them.
+    let meta_resource = Resource::new_blocking(slug, get_doc_meta);
 
     view! {
+        <DocUpdateToast
+            slug=Signal::derive(slug)
+            on_refresh=Callback::new(move |_| {
+                doc_resource.refetch();
+                meta_resource.refetch();
+            })
+        />
+        <Suspense>
+            {move || Suspend::new(async move {
+                meta_resource
+                    .await
+                    .ok()
+                    .flatten()
+                    .map(|meta| view! { <DocMetaTags meta /> })
+            })}
+        </Suspense>
         <Suspense fallback=move || view! {
             <div class="flex justify-center py-12">
                 <span class="loading loading-spinner loading-lg"></span>
@@ -125,35 +984,54 @@ pub fn DocPage() -> impl IntoView {
             {move || {
                 doc_resource.get().map(|result| match result {
                     Ok(Some(data)) => {
+                        // Heading permalinks and TOC scroll tracking (public/js/doc-enhancements.js)
+                        #[cfg(feature = "hydrate")]
+                        Effect::new(move |_| {
+                            let _ = js_sys::eval("window.lektonEnhanceDoc && window.lektonEnhanceDoc()");
+                        });
                         let current_slug = slug();
+                        let edit_href = format!("/edit/{current_slug}");
                         let has_tags = !data.tags.is_empty();
                         let tags = data.tags.clone();
-                        let current_user = use_context::<Signal<Option<crate::auth::models::AuthenticatedUser>>>();
-                        let can_edit = move || {
-                            current_user
-                                .and_then(|s| s.get())
-                                .map(|u| u.is_admin)
-                                .unwrap_or(false)
-                        };
+                        let has_contributors = !data.contributors.is_empty();
+                        let contributors = data.contributors.clone();
+                        let edit_level = data.edit_access_level.clone();
+                        let can_edit = data.can_edit;
+                        let can_share = data.can_share;
+                        let deprecated_schemas = data.deprecated_schemas.clone();
+                        let bundle = data.bundle.clone();
+                        let prior_bundle = bundle.clone().filter(|b| b.current.is_some());
+                        let ancestor_titles = data.ancestor_titles.clone();
+                        let superseded_by = data.relations.superseded_by.clone();
+                        let relations = data.relations.clone();
+                        let truncated_from = data.truncated_from;
+                        let previous_doc = data.previous_doc.clone();
+                        let next_doc = data.next_doc.clone();
                         view! {
                             <div class="flex gap-8 items-start">
                                 <div class="flex-1 min-w-0">
-                                    // Breadcrumb row + edit button — single meta strip
-                                    <div class="flex items-center justify-between gap-4 mb-5">
-                                        <Breadcrumbs slug=current_slug.clone() />
-                                        <Show when=can_edit>
-                                            <a
-                                                href={let s = current_slug.clone(); move || format!("/edit/{}", s)}
-                                                class="btn btn-ghost btn-sm flex-shrink-0 gap-1.5 text-base-content/60 hover:text-primary"
-                                            >
-                                                <svg class="w-3.5 h-3.5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-                                                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2"
-                                                        d="M15.232 5.232l3.536 3.536m-2.036-5.036a2.5 2.5 0 113.536 3.536L6.5 21.036H3v-3.572L16.732 3.732z">
-                                                    </path>
-                                                </svg>
-                                                "Edit"
-                                            </a>
-                                        </Show>
+                                    // Breadcrumb row + print/edit actions — single meta strip
+                                    <div class="flex items-center justify-between gap-4 mb-5 print:hidden">
+                                        <Breadcrumbs slug=current_slug.clone() ancestor_titles />
+                                        <div class="flex items-center gap-1 flex-shrink-0">
+                                            {bundle.map(|bundle| view! { <BundleVersionSwitcher bundle /> })}
+                                            <PrintButton slug=current_slug.clone() />
+                                            <WatchButton slug=current_slug.clone() />
+                                            {can_share.then(|| view! { <ShareButton slug=current_slug.clone() /> })}
+                                            <Show when=move || can_edit>
+                                                <a
+                                                    href=edit_href.clone()
+                                                    class="btn btn-ghost btn-sm flex-shrink-0 gap-1.5 text-base-content/60 hover:text-primary"
+                                                >
+                                                    <svg class="w-3.5 h-3.5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                                        <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2"
+                                                            d="M15.232 5.232l3.536 3.536m-2.036-5.036a2.5 2.5 0 113.536 3.536L6.5 21.036H3v-3.572L16.732 3.732z">
+                                                        </path>
+                                                    </svg>
+                                                    {i18n.t("doc.edit")}
+                                                </a>
+                                            </Show>
+                                        </div>
                                     </div>
                                     // Tags — shown between breadcrumb and content
                                     <Show when=move || has_tags>
@@ -166,10 +1044,25 @@ pub fn DocPage() -> impl IntoView {
                                             }).collect::<Vec<_>>()}
                                         </div>
                                     </Show>
+                                    <DocOnCall slug=current_slug.clone() />
+                                    {prior_bundle.map(|bundle| view! { <PriorBundleVersionNotice bundle /> })}
+                                    {(!superseded_by.is_empty()).then(|| view! {
+                                        <SupersededNotice superseded_by />
+                                    })}
+                                    {(!deprecated_schemas.is_empty()).then(|| view! {
+                                        <DeprecatedSchemasNotice notices=deprecated_schemas />
+                                    })}
+                                    {truncated_from.map(|size| view! {
+                                        <TruncatedDocumentNotice slug=current_slug.clone() size />
+                                    })}
                                     // The markdown H1 serves as the page title — no separate h1 here
                                     <article class="prose prose-lg max-w-none">
                                         <MarkdownContent html=data.html />
                                     </article>
+                                    {(!relations.is_empty()).then(|| view! { <RelationPanels relations /> })}
+                                    <HelpfulWidget slug=current_slug.clone() />
+                                    <CollectionFooter slug=current_slug.clone() />
+                                    <SiblingNav previous_doc next_doc />
                                     // Last Updated footer
                                     <div class="divider mt-12"></div>
                                     <div class="flex items-center gap-2 text-sm text-base-content/50 pb-4">
@@ -178,8 +1071,31 @@ pub fn DocPage() -> impl IntoView {
                                                 d="M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z">
                                             </path>
                                         </svg>
-                                        <span>"Last updated: " {data.last_updated}</span>
+                                        <span>
+                                            {match data.last_updated_by {
+                                                Some(author) => i18n.tf("doc.last_updated_by", &[("date", &data.last_updated), ("author", &author)]),
+                                                None => i18n.tf("doc.last_updated", &[("date", &data.last_updated)]),
+                                            }}
+                                        </span>
                                     </div>
+                                    <Show when=move || has_contributors>
+                                        <div class="flex flex-wrap items-center gap-2 text-sm text-base-content/50 pb-4">
+                                            <span>{i18n.t("doc.contributors")}</span>
+                                            {contributors.iter().map(|name| {
+                                                let name = name.clone();
+                                                view! {
+                                                    <span class="badge badge-ghost badge-sm">{name}</span>
+                                                }
+                                            }).collect::<Vec<_>>()}
+                                        </div>
+                                    </Show>
+                                    {edit_level.map(|level| view! {
+                                        <div class="flex items-center gap-2 text-sm text-base-content/50 pb-4 print:hidden">
+                                            <span>{i18n.around("doc.edit_restricted", "level").0}</span>
+                                            <span class="badge badge-ghost badge-sm font-mono">{level}</span>
+                                            {i18n.around("doc.edit_restricted", "level").1}
+                                        </div>
+                                    })}
                                 </div>
                                 <TableOfContents headings=data.headings />
                             </div>
@@ -188,14 +1104,17 @@ pub fn DocPage() -> impl IntoView {
                     Ok(None) => {
                         view! {
                             <div class="alert alert-warning">
-                                <span>{format!("Document '{}' not found.", slug())}</span>
+                                <span>{i18n.tf("doc.not_found", &[("slug", &slug())])}</span>
                             </div>
                         }.into_any()
                     }
                     Err(e) => {
+                        if let Some(required_level) = access_denied_level(&e) {
+                            return view! { <AccessDenied required_level /> }.into_any();
+                        }
                         view! {
                             <div class="alert alert-error">
-                                <span>{format!("Error loading document: {e}")}</span>
+                                <span>{i18n.tf("doc.load_error", &[("error", &e.to_string())])}</span>
                             </div>
                         }.into_any()
                     }
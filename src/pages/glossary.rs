@@ -0,0 +1,73 @@
+use leptos::prelude::*;
+
+use crate::app::{list_glossary, GlossaryTerm};
+use crate::i18n::use_i18n;
+
+/// Glossary page: every term with its definition and owner. Document
+/// tooltips link to `/glossary#<anchor>`.
+#[component]
+pub fn GlossaryPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let terms_resource = Resource::new(|| (), |_| list_glossary());
+    let query = RwSignal::new(String::new());
+
+    view! {
+        <div class="max-w-4xl mx-auto">
+            <h1 class="text-3xl font-bold">{i18n.t("glossary.title")}</h1>
+            <p class="text-base-content/60 mt-2 mb-6">{i18n.t("glossary.subtitle")}</p>
+            <input
+                type="text"
+                class="input input-bordered input-sm w-full sm:w-72 mb-6"
+                placeholder=i18n.t("glossary.filter_placeholder")
+                prop:value=query
+                on:input=move |ev| query.set(event_target_value(&ev))
+            />
+            <Suspense fallback=move || view! {
+                <div class="flex justify-center py-12">
+                    <span class="loading loading-spinner loading-lg"></span>
+                </div>
+            }>
+                {move || terms_resource.get().map(|result| match result {
+                    Ok(terms) => {
+                        let q = query.get().trim().to_lowercase();
+                        let terms: Vec<GlossaryTerm> = terms
+                            .into_iter()
+                            .filter(|t| {
+                                q.is_empty()
+                                    || t.term.to_lowercase().contains(&q)
+                                    || t.definition.to_lowercase().contains(&q)
+                            })
+                            .collect();
+                        if terms.is_empty() {
+                            return view! {
+                                <p class="text-base-content/50 py-8">{i18n.t("glossary.empty")}</p>
+                            }.into_any();
+                        }
+                        view! {
+                            <dl class="divide-y divide-base-200">
+                                {terms.into_iter().map(|t| view! {
+                                    <div id=t.anchor() class="glossary-entry py-4 scroll-mt-20">
+                                        <dt class="text-lg font-semibold">
+                                            <a href=format!("#{}", t.anchor()) class="hover:text-primary">{t.term.clone()}</a>
+                                        </dt>
+                                        <dd class="mt-1 text-base-content/80">{t.definition.clone()}</dd>
+                                        {(!t.owner.is_empty()).then(|| view! {
+                                            <dd class="mt-1 text-sm text-base-content/50">
+                                                {i18n.tf("glossary.owner", &[("owner", &t.owner)])}
+                                            </dd>
+                                        })}
+                                    </div>
+                                }).collect::<Vec<_>>()}
+                            </dl>
+                        }.into_any()
+                    }
+                    Err(e) => view! {
+                        <div class="alert alert-error">
+                            <span>{e.to_string()}</span>
+                        </div>
+                    }.into_any(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
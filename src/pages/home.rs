@@ -1,22 +1,83 @@
 use leptos::prelude::*;
 
+use crate::app::{get_doc_html, get_incident_status, set_incident_active};
+use crate::auth::refresh_client::with_auth_retry;
+use crate::components::MarkdownContent;
+use crate::i18n::use_i18n;
+
 /// Home page component.
+///
+/// Renders the admin-designated homepage document when one is configured and
+/// readable by the current user, otherwise the built-in landing page.
 #[component]
 pub fn HomePage() -> impl IntoView {
+    let branding = expect_context::<crate::app::InstanceBranding>().0;
+    let homepage_slug = Memo::new(move |_| branding.get().homepage_slug);
+
+    let homepage_doc = LocalResource::new(move || {
+        let slug = homepage_slug.get();
+        async move {
+            match slug {
+                Some(slug) => with_auth_retry(move || get_doc_html(slug.clone()))
+                    .await
+                    .ok()
+                    .flatten(),
+                None => None,
+            }
+        }
+    });
+
+    view! {
+        <IncidentPanel />
+        <Suspense fallback=move || view! {
+            <div class="flex justify-center py-12">
+                <span class="loading loading-spinner loading-lg"></span>
+            </div>
+        }>
+            {move || {
+                homepage_doc.get().map(|doc| match doc {
+                    Some(data) => view! {
+                        <article class="prose prose-lg max-w-none">
+                            <MarkdownContent html=data.html />
+                        </article>
+                    }.into_any(),
+                    None => view! { <DefaultHome /> }.into_any(),
+                })
+            }}
+        </Suspense>
+    }
+}
+
+/// Built-in landing page: hero plus feature cards.
+#[component]
+fn DefaultHome() -> impl IntoView {
+    let branding = expect_context::<crate::app::InstanceBranding>().0;
+    let i18n = use_i18n();
+    let hero_title = move || {
+        let branding = branding.get();
+        branding
+            .hero_title
+            .unwrap_or_else(|| i18n.tf("home.welcome", &[("portal", &branding.portal_name)]))
+    };
+    let hero_subtitle = move || {
+        branding
+            .get()
+            .hero_subtitle
+            .unwrap_or_else(|| i18n.t("home.subtitle").to_string())
+    };
+
     view! {
         <div class="hero min-h-[60vh]">
             <div class="hero-content text-center">
                 <div class="max-w-2xl">
-                    <h1 class="text-5xl font-bold">"Welcome to Lekton"</h1>
-                    <p class="py-6 text-lg text-base-content/70">
-                        "Your dynamic Internal Developer Portal. Search documentation, explore API schemas, and collaborate — all in one place."
-                    </p>
+                    <h1 class="text-5xl font-bold">{hero_title}</h1>
+                    <p class="py-6 text-lg text-base-content/70">{hero_subtitle}</p>
                     <div class="flex gap-4 justify-center">
                         <a href="/docs/getting-started" class="btn btn-primary btn-lg">
-                            "Get Started"
+                            {i18n.t("home.get_started")}
                         </a>
                         <a href="/docs/api-reference" class="btn btn-outline btn-lg">
-                            "API Schemas"
+                            {i18n.t("home.api_schemas")}
                         </a>
                     </div>
                 </div>
@@ -26,18 +87,18 @@ pub fn HomePage() -> impl IntoView {
         // Feature cards
         <div class="grid grid-cols-1 md:grid-cols-3 gap-6 mt-8">
             <FeatureCard
-                title="Dynamic Docs"
-                description="CI/CD integration for live documentation updates. No rebuilds needed."
+                title=i18n.t("home.feature.docs.title")
+                description=i18n.t("home.feature.docs.body")
                 icon="📝"
             />
             <FeatureCard
-                title="Granular RBAC"
-                description="Role-based access control ensures sensitive docs are only visible to authorized users."
+                title=i18n.t("home.feature.rbac.title")
+                description=i18n.t("home.feature.rbac.body")
                 icon="🔒"
             />
             <FeatureCard
-                title="Schema Registry"
-                description="Unified OpenAPI, AsyncAPI, and JSON Schema viewer with versioning."
+                title=i18n.t("home.feature.registry.title")
+                description=i18n.t("home.feature.registry.body")
                 icon="📡"
             />
         </div>
@@ -61,3 +122,81 @@ fn FeatureCard(
         </div>
     }
 }
+
+/// Pinned runbooks while incident mode is on, and the switch for admins and
+/// on-call responders.
+#[component]
+fn IncidentPanel() -> impl IntoView {
+    let i18n = use_i18n();
+    let status = LocalResource::new(|| with_auth_retry(get_incident_status));
+    let error = RwSignal::new(None::<String>);
+
+    let toggle = Action::new_local(move |active: &bool| {
+        let active = *active;
+        async move {
+            match with_auth_retry(|| set_incident_active(active)).await {
+                // Reload so the sidebar and the page caching follow
+                Ok(()) =>
+                {
+                    #[cfg(feature = "hydrate")]
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.location().reload();
+                    }
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        }
+    });
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || status.get().and_then(|res| res.ok()).map(|status| {
+                if status.active {
+                    let started = status
+                        .started_by
+                        .clone()
+                        .map(|by| i18n.tf("incident.started_by", &[("user", &by), ("time", &status.started_at.clone().unwrap_or_default())]));
+                    view! {
+                        <div role="alert" class="alert alert-error mb-8 items-start">
+                            <div class="flex-1 space-y-2">
+                                <p class="font-semibold">{i18n.t("incident.active")}</p>
+                                {started.map(|started| view! { <p class="text-sm opacity-80">{started}</p> })}
+                                <ul class="list-disc list-inside">
+                                    {status.runbooks.into_iter().map(|runbook| view! {
+                                        <li><a href=format!("/docs/{}", runbook.slug) class="link font-medium">{runbook.title}</a></li>
+                                    }).collect::<Vec<_>>()}
+                                </ul>
+                            </div>
+                            {status.can_toggle.then(|| view! {
+                                <button
+                                    class="btn btn-sm"
+                                    disabled=move || toggle.pending().get()
+                                    on:click=move |_| { toggle.dispatch(false); }
+                                >
+                                    {i18n.t("incident.end")}
+                                </button>
+                            })}
+                        </div>
+                    }.into_any()
+                } else if status.can_toggle {
+                    view! {
+                        <div class="flex justify-end mb-4">
+                            <button
+                                class="btn btn-outline btn-error btn-sm"
+                                disabled=move || toggle.pending().get()
+                                on:click=move |_| { toggle.dispatch(true); }
+                            >
+                                {i18n.t("incident.start")}
+                            </button>
+                        </div>
+                    }.into_any()
+                } else {
+                    ().into_any()
+                }
+            })}
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error text-sm mb-4"><span>{e}</span></div>
+            })}
+        </Suspense>
+    }
+}
@@ -1,24 +1,59 @@
 use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
 
-use crate::app::IsDemoMode;
+use crate::app::{get_is_ldap_auth, get_is_local_accounts, IsDemoMode};
+use crate::auth::redirect::{login_path, oauth_login_path, safe_next};
+use crate::i18n::use_i18n;
 
 /// Login page.
 ///
 /// In demo mode, shows a username/password form with demo credentials.
+/// With LDAP enabled, shows a directory username/password form, linking to
+/// the local account form (`?method=local`) when local accounts are enabled too.
+/// With local accounts enabled, shows an email/password form.
 /// In OAuth2/OIDC mode, redirects the browser to `/auth/login` which starts
 /// the external provider flow.
+///
+/// After a successful login the user lands on the `?next=` page, if it is a
+/// local path, or on the home page.
 #[component]
 pub fn LoginPage() -> impl IntoView {
     let is_demo_mode = use_context::<IsDemoMode>()
         .expect("LoginPage must be inside App")
         .0;
+    let local_accounts_resource = LocalResource::new(get_is_local_accounts);
+    let ldap_resource = LocalResource::new(get_is_ldap_auth);
+    let query = use_query_map();
+    let use_local = move || query.with(|q| q.get("method")).as_deref() == Some("local");
+    let next = move || {
+        query
+            .with(|q| q.get("next"))
+            .and_then(|next| safe_next(&next))
+            .unwrap_or_else(|| "/".to_string())
+    };
 
     view! {
         {move || {
             if is_demo_mode.get() {
-                view! { <DemoLoginForm /> }.into_any()
-            } else {
-                view! { <OAuthRedirect /> }.into_any()
+                return view! { <DemoLoginForm next=next() /> }.into_any();
+            }
+            match (ldap_resource.get(), local_accounts_resource.get()) {
+                (Some(ldap), Some(local)) => {
+                    let (ldap, local) = (matches!(ldap, Ok(true)), matches!(local, Ok(true)));
+                    if ldap && !(local && use_local()) {
+                        view! { <LdapLoginForm next=next() local_accounts=local /> }.into_any()
+                    } else if local {
+                        view! { <LocalLoginForm next=next() /> }.into_any()
+                    } else {
+                        view! { <OAuthRedirect next=next() /> }.into_any()
+                    }
+                }
+                _ => view! {
+                    <div class="flex justify-center py-16">
+                        <span class="loading loading-spinner loading-lg"></span>
+                    </div>
+                }
+                .into_any(),
             }
         }}
     }
@@ -27,15 +62,20 @@ pub fn LoginPage() -> impl IntoView {
 /// Redirects to `/auth/login` to start the OAuth2/OIDC flow.
 /// Shows a brief loading state while the redirect is set up.
 #[component]
-fn OAuthRedirect() -> impl IntoView {
-    Effect::new(|_| {
+fn OAuthRedirect(next: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let target = oauth_login_path(&next);
+    let redirect_target = target.clone();
+    Effect::new(move |_| {
         #[cfg(feature = "hydrate")]
         {
             use leptos::web_sys::window;
             if let Some(w) = window() {
-                let _ = w.location().replace("/auth/login");
+                let _ = w.location().replace(&redirect_target);
             }
         }
+        #[cfg(not(feature = "hydrate"))]
+        let _ = &redirect_target;
     });
 
     view! {
@@ -43,10 +83,11 @@ fn OAuthRedirect() -> impl IntoView {
             <div class="hero-content text-center">
                 <div>
                     <span class="loading loading-spinner loading-lg"></span>
-                    <p class="mt-4 text-base-content/70">"Redirecting to sign in..."</p>
+                    <p class="mt-4 text-base-content/70">{i18n.t("login.redirecting")}</p>
                     <p class="text-sm mt-4">
-                        "If you are not redirected automatically, "
-                        <a href="/auth/login" rel="external" class="link link-primary">"click here"</a>.
+                        {i18n.around("login.redirect_fallback", "link").0}
+                        <a href=target rel="external" class="link link-primary">{i18n.t("login.redirect_link")}</a>
+                        {i18n.around("login.redirect_fallback", "link").1}
                     </p>
                 </div>
             </div>
@@ -56,21 +97,22 @@ fn OAuthRedirect() -> impl IntoView {
 
 /// Demo mode login form with hardcoded test credentials.
 #[component]
-fn DemoLoginForm() -> impl IntoView {
+fn DemoLoginForm(next: String) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="hero min-h-[60vh]">
             <div class="hero-content">
                 <div class="card bg-base-100 shadow-2xl w-full max-w-md">
                     <div class="card-body">
-                        <h2 class="card-title text-2xl justify-center">"Demo Login"</h2>
+                        <h2 class="card-title text-2xl justify-center">{i18n.t("login.demo_title")}</h2>
                         <p class="text-center text-base-content/70 text-sm">
-                            "Sign in with demo credentials to explore Lekton."
+                            {i18n.t("login.demo_subtitle")}
                         </p>
 
-                        <form id="login-form" class="mt-4">
+                        <form id="login-form" class="mt-4" data-next=next>
                             <div class="form-control">
                                 <label class="label">
-                                    <span class="label-text">"Username"</span>
+                                    <span class="label-text">{i18n.t("login.username")}</span>
                                 </label>
                                 <input
                                     id="login-username"
@@ -83,7 +125,7 @@ fn DemoLoginForm() -> impl IntoView {
                             </div>
                             <div class="form-control mt-2">
                                 <label class="label">
-                                    <span class="label-text">"Password"</span>
+                                    <span class="label-text">{i18n.t("login.password")}</span>
                                 </label>
                                 <input
                                     id="login-password"
@@ -95,38 +137,38 @@ fn DemoLoginForm() -> impl IntoView {
                                 />
                             </div>
                             <div id="login-error" class="alert alert-error mt-4 hidden">
-                                <span>"Invalid credentials"</span>
+                                <span>{i18n.t("login.invalid_credentials")}</span>
                             </div>
                             <div class="form-control mt-6">
-                                <button type="submit" class="btn btn-primary">"Sign In"</button>
+                                <button type="submit" class="btn btn-primary">{i18n.t("login.submit")}</button>
                             </div>
                         </form>
 
-                        <div class="divider">"Demo Accounts"</div>
+                        <div class="divider">{i18n.t("login.demo_accounts")}</div>
                         <div class="overflow-x-auto">
                             <table class="table table-sm">
                                 <thead>
                                     <tr>
-                                        <th>"Username"</th>
-                                        <th>"Password"</th>
-                                        <th>"Role"</th>
+                                        <th>{i18n.t("login.username")}</th>
+                                        <th>{i18n.t("login.password")}</th>
+                                        <th>{i18n.t("login.role")}</th>
                                     </tr>
                                 </thead>
                                 <tbody>
                                     <tr>
                                         <td><code>"demo"</code></td>
                                         <td><code>"demo"</code></td>
-                                        <td><span class="badge badge-info">"Developer"</span></td>
+                                        <td><span class="badge badge-info">{i18n.t("login.role_developer")}</span></td>
                                     </tr>
                                     <tr>
                                         <td><code>"admin"</code></td>
                                         <td><code>"admin"</code></td>
-                                        <td><span class="badge badge-error">"Admin"</span></td>
+                                        <td><span class="badge badge-error">{i18n.t("login.role_admin")}</span></td>
                                     </tr>
                                     <tr>
                                         <td><code>"public"</code></td>
                                         <td><code>"public"</code></td>
-                                        <td><span class="badge badge-ghost">"Public"</span></td>
+                                        <td><span class="badge badge-ghost">{i18n.t("login.role_public")}</span></td>
                                     </tr>
                                 </tbody>
                             </table>
@@ -140,3 +182,130 @@ fn DemoLoginForm() -> impl IntoView {
         <script src="/js/login.js" defer></script>
     }
 }
+
+/// Username/password form for directory (LDAP) accounts.
+#[component]
+fn LdapLoginForm(next: String, local_accounts: bool) -> impl IntoView {
+    let i18n = use_i18n();
+    let local_login = {
+        let path = login_path(&next);
+        let separator = if path.contains('?') { '&' } else { '?' };
+        format!("{path}{separator}method=local")
+    };
+
+    view! {
+        <div class="hero min-h-[60vh]">
+            <div class="hero-content">
+                <div class="card bg-base-100 shadow-2xl w-full max-w-md">
+                    <div class="card-body">
+                        <h2 class="card-title text-2xl justify-center">{i18n.t("login.submit")}</h2>
+                        <p class="text-center text-base-content/70 text-sm">
+                            {i18n.t("login.ldap_subtitle")}
+                        </p>
+
+                        <form id="login-form" class="mt-4" data-endpoint="/auth/ldap/login" data-next=next>
+                            <div class="form-control">
+                                <label class="label">
+                                    <span class="label-text">{i18n.t("login.username")}</span>
+                                </label>
+                                <input
+                                    id="login-username"
+                                    type="text"
+                                    name="username"
+                                    autocomplete="username"
+                                    class="input input-bordered"
+                                    required
+                                />
+                            </div>
+                            <div class="form-control mt-2">
+                                <label class="label">
+                                    <span class="label-text">{i18n.t("login.password")}</span>
+                                </label>
+                                <input
+                                    id="login-password"
+                                    type="password"
+                                    name="password"
+                                    autocomplete="current-password"
+                                    class="input input-bordered"
+                                    required
+                                />
+                            </div>
+                            <div id="login-error" class="alert alert-error mt-4 hidden">
+                                <span>{i18n.t("login.invalid_username")}</span>
+                            </div>
+                            <div class="form-control mt-6">
+                                <button type="submit" class="btn btn-primary">{i18n.t("login.submit")}</button>
+                            </div>
+                        </form>
+
+                        <Show when=move || local_accounts>
+                            <p class="text-center text-sm text-base-content/60 mt-2">
+                                <a href=local_login.clone() class="link">{i18n.t("login.local_link")}</a>
+                            </p>
+                        </Show>
+                    </div>
+                </div>
+            </div>
+        </div>
+
+        <script src="/js/login.js" defer></script>
+    }
+}
+
+/// Email/password form for local accounts.
+#[component]
+fn LocalLoginForm(next: String) -> impl IntoView {
+    let i18n = use_i18n();
+    view! {
+        <div class="hero min-h-[60vh]">
+            <div class="hero-content">
+                <div class="card bg-base-100 shadow-2xl w-full max-w-md">
+                    <div class="card-body">
+                        <h2 class="card-title text-2xl justify-center">{i18n.t("login.submit")}</h2>
+
+                        <form id="login-form" class="mt-4" data-endpoint="/auth/local/login" data-next=next>
+                            <div class="form-control">
+                                <label class="label">
+                                    <span class="label-text">{i18n.t("login.email")}</span>
+                                </label>
+                                <input
+                                    id="login-username"
+                                    type="email"
+                                    name="email"
+                                    autocomplete="username"
+                                    class="input input-bordered"
+                                    required
+                                />
+                            </div>
+                            <div class="form-control mt-2">
+                                <label class="label">
+                                    <span class="label-text">{i18n.t("login.password")}</span>
+                                </label>
+                                <input
+                                    id="login-password"
+                                    type="password"
+                                    name="password"
+                                    autocomplete="current-password"
+                                    class="input input-bordered"
+                                    required
+                                />
+                            </div>
+                            <div id="login-error" class="alert alert-error mt-4 hidden">
+                                <span>{i18n.t("login.invalid_email")}</span>
+                            </div>
+                            <div class="form-control mt-6">
+                                <button type="submit" class="btn btn-primary">{i18n.t("login.submit")}</button>
+                            </div>
+                        </form>
+
+                        <p class="text-center text-sm text-base-content/60 mt-2">
+                            {i18n.t("login.forgot_password")}
+                        </p>
+                    </div>
+                </div>
+            </div>
+        </div>
+
+        <script src="/js/login.js" defer></script>
+    }
+}
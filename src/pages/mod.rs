@@ -1,17 +1,27 @@
 mod admin_settings;
+mod browse;
+mod changelog;
 pub mod chat;
+mod collections;
 mod doc;
+mod glossary;
 mod home;
 mod login;
 mod not_found;
+mod notifications;
 mod profile;
 mod prompts;
 
 pub use admin_settings::*;
+pub use browse::*;
+pub use changelog::*;
 pub use chat::*;
+pub use collections::*;
 pub use doc::*;
+pub use glossary::*;
 pub use home::*;
 pub use login::*;
 pub use not_found::*;
+pub use notifications::*;
 pub use profile::*;
 pub use prompts::*;
@@ -1,15 +1,18 @@
 use leptos::prelude::*;
 
+use crate::i18n::use_i18n;
+
 /// 404 Not Found page.
 #[component]
 pub fn NotFound() -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="hero min-h-[60vh]">
             <div class="hero-content text-center">
                 <div class="max-w-md">
                     <h1 class="text-9xl font-bold text-primary">"404"</h1>
-                    <p class="py-6 text-xl">"The page you are looking for does not exist."</p>
-                    <a href="/" class="btn btn-primary">"Back to Home"</a>
+                    <p class="py-6 text-xl">{i18n.t("not_found.message")}</p>
+                    <a href="/" class="btn btn-primary">{i18n.t("not_found.back")}</a>
                 </div>
             </div>
         </div>
@@ -0,0 +1,316 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+
+use crate::app::{
+    get_current_user, list_notifications, list_watches, mark_notifications_read, unwatch_document,
+    watch_document, NotificationInbox, WatchList,
+};
+use crate::auth::refresh_client::{with_auth_bootstrap, with_auth_retry};
+use crate::i18n::use_i18n;
+
+#[component]
+pub fn NotificationsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let user_resource = LocalResource::new(|| with_auth_bootstrap(get_current_user));
+    let navigate = use_navigate();
+
+    Effect::new(move |_| {
+        if let Some(Ok(None)) = user_resource.get() {
+            navigate(
+                &crate::auth::redirect::login_path("/notifications"),
+                Default::default(),
+            );
+        }
+    });
+
+    view! {
+        <div class="container mx-auto max-w-5xl px-4 py-8 space-y-10">
+            <div>
+                <h1 class="text-3xl font-bold">{i18n.t("notifications.title")}</h1>
+                <p class="text-base-content/60 mt-2 max-w-3xl">
+                    {i18n.t("notifications.intro")}
+                </p>
+            </div>
+            <InboxPanel />
+            <WatchesPanel />
+        </div>
+    }
+}
+
+#[component]
+fn InboxPanel() -> impl IntoView {
+    let i18n = use_i18n();
+    let inbox = RwSignal::new(None::<NotificationInbox>);
+
+    let load = Action::new_local(move |_: &()| async move {
+        match with_auth_retry(list_notifications).await {
+            Ok(value) => inbox.set(Some(value)),
+            Err(err) => tracing::error!("Failed to load notifications: {err}"),
+        }
+    });
+    let mark_read = Action::new_local(move |id: &Option<String>| {
+        let id = id.clone();
+        async move {
+            if with_auth_retry(|| mark_notifications_read(id.clone()))
+                .await
+                .is_ok()
+            {
+                load.dispatch(());
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        load.dispatch(());
+    });
+
+    view! {
+        <section class="space-y-4">
+            <div class="flex items-center justify-between gap-4">
+                <h2 class="text-xl font-semibold flex items-center gap-2">
+                    {i18n.t("notifications.inbox")}
+                    {move || inbox.get().filter(|i| i.unread > 0).map(|i| view! {
+                        <span class="badge badge-primary badge-sm">{i.unread}</span>
+                    })}
+                </h2>
+                <button
+                    class="btn btn-ghost btn-sm"
+                    disabled=move || inbox.get().is_none_or(|i| i.unread == 0)
+                    on:click=move |_| { mark_read.dispatch(None); }
+                >
+                    {i18n.t("notifications.mark_all_read")}
+                </button>
+            </div>
+            <Show
+                when=move || inbox.get().is_some()
+                fallback=|| view! { <div class="skeleton h-32 w-full rounded-2xl" /> }
+            >
+                {move || {
+                    let items = inbox.get().map(|i| i.items).unwrap_or_default();
+                    if items.is_empty() {
+                        return view! {
+                            <p class="text-base-content/60">{i18n.t("notifications.empty")}</p>
+                        }.into_any();
+                    }
+                    view! {
+                        <ul class="divide-y divide-base-200 rounded-box border border-base-200">
+                            {items.into_iter().map(|item| {
+                                let id = item.id.clone();
+                                let unread = !item.read;
+                                let text = item.text.clone();
+                                let context = match &item.watch_target {
+                                    Some(target) => i18n.tf("notifications.watching_target", &[("time", &item.created_at), ("target", target)]),
+                                    None => item.created_at.clone(),
+                                };
+                                view! {
+                                    <li class="flex items-start justify-between gap-4 px-4 py-3" class:bg-base-200=unread>
+                                        <div class="min-w-0">
+                                            <div class="flex items-center gap-2">
+                                                <span class="badge badge-outline badge-sm">{item.action.clone()}</span>
+                                                {match item.link.clone() {
+                                                    Some(href) => view! {
+                                                        <a href=href class="link link-hover truncate" class:font-semibold=unread>
+                                                            {text}
+                                                        </a>
+                                                    }.into_any(),
+                                                    None => view! {
+                                                        <span class="truncate" class:font-semibold=unread>{text}</span>
+                                                    }.into_any(),
+                                                }}
+                                            </div>
+                                            <p class="text-sm text-base-content/60 mt-1">{context}</p>
+                                        </div>
+                                        <Show when=move || unread>
+                                            <button
+                                                class="btn btn-ghost btn-xs flex-shrink-0"
+                                                on:click={
+                                                    let id = id.clone();
+                                                    move |_| { mark_read.dispatch(Some(id.clone())); }
+                                                }
+                                            >
+                                                {i18n.t("notifications.mark_read")}
+                                            </button>
+                                        </Show>
+                                    </li>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any()
+                }}
+            </Show>
+        </section>
+    }
+}
+
+#[component]
+fn WatchesPanel() -> impl IntoView {
+    let i18n = use_i18n();
+    let watches = RwSignal::new(None::<WatchList>);
+    let target = RwSignal::new(String::new());
+    let email = RwSignal::new(false);
+    let webhook_url = RwSignal::new(String::new());
+    let error = RwSignal::new(None::<String>);
+
+    let load = Action::new_local(move |_: &()| async move {
+        match with_auth_retry(list_watches).await {
+            Ok(value) => watches.set(Some(value)),
+            Err(err) => tracing::error!("Failed to load watches: {err}"),
+        }
+    });
+    let add = Action::new_local(move |_: &()| {
+        let target_value = target.get_untracked();
+        let email_value = email.get_untracked();
+        let webhook_value = Some(webhook_url.get_untracked()).filter(|url| !url.trim().is_empty());
+        async move {
+            match with_auth_retry(|| {
+                watch_document(target_value.clone(), email_value, webhook_value.clone())
+            })
+            .await
+            {
+                Ok(_) => {
+                    error.set(None);
+                    target.set(String::new());
+                    webhook_url.set(String::new());
+                    load.dispatch(());
+                }
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        }
+    });
+    let remove = Action::new_local(move |id: &String| {
+        let id = id.clone();
+        async move {
+            if with_auth_retry(|| unwatch_document(id.clone()))
+                .await
+                .is_ok()
+            {
+                load.dispatch(());
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        load.dispatch(());
+    });
+
+    let email_enabled = move || watches.get().is_some_and(|w| w.email_enabled);
+
+    view! {
+        <section class="space-y-4">
+            <h2 class="text-xl font-semibold">{i18n.t("notifications.watching")}</h2>
+            <form
+                class="card bg-base-200 border border-base-300/60"
+                on:submit=move |ev| {
+                    ev.prevent_default();
+                    add.dispatch(());
+                }
+            >
+                <div class="card-body gap-3">
+                    <div class="grid gap-3 md:grid-cols-2">
+                        <label class="form-control">
+                            <span class="label-text mb-1">{i18n.t("notifications.target")}</span>
+                            <input
+                                type="text"
+                                class="input input-bordered input-sm font-mono"
+                                placeholder=i18n.t("notifications.target_placeholder")
+                                prop:value=move || target.get()
+                                on:input=move |ev| target.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="form-control">
+                            <span class="label-text mb-1">{i18n.t("notifications.webhook")}</span>
+                            <input
+                                type="url"
+                                class="input input-bordered input-sm"
+                                placeholder="https://hooks.example.com/..."
+                                prop:value=move || webhook_url.get()
+                                on:input=move |ev| webhook_url.set(event_target_value(&ev))
+                            />
+                        </label>
+                    </div>
+                    <div class="flex flex-wrap items-center justify-between gap-3">
+                        <Show when=email_enabled fallback=|| view! { <span /> }>
+                            <label class="label cursor-pointer justify-start gap-2">
+                                <input
+                                    type="checkbox"
+                                    class="checkbox checkbox-sm"
+                                    prop:checked=move || email.get()
+                                    on:change=move |ev| email.set(event_target_checked(&ev))
+                                />
+                                <span class="label-text">{i18n.t("notifications.email")}</span>
+                            </label>
+                        </Show>
+                        <button
+                            type="submit"
+                            class="btn btn-primary btn-sm"
+                            disabled=move || target.get().trim().is_empty() || add.pending().get()
+                        >
+                            {i18n.t("notifications.watch")}
+                        </button>
+                    </div>
+                    {move || error.get().map(|e| view! {
+                        <div class="alert alert-error py-2"><span>{e}</span></div>
+                    })}
+                </div>
+            </form>
+            <Show
+                when=move || watches.get().is_some()
+                fallback=|| view! { <div class="skeleton h-24 w-full rounded-2xl" /> }
+            >
+                {move || {
+                    let list = watches.get().map(|w| w.watches).unwrap_or_default();
+                    if list.is_empty() {
+                        return view! {
+                            <p class="text-base-content/60">{i18n.t("notifications.no_watches")}</p>
+                        }.into_any();
+                    }
+                    view! {
+                        <div class="overflow-x-auto">
+                            <table class="table table-sm">
+                                <thead>
+                                    <tr>
+                                        <th>{i18n.t("notifications.col_target")}</th>
+                                        <th>{i18n.t("notifications.col_delivery")}</th>
+                                        <th>{i18n.t("notifications.col_since")}</th>
+                                        <th></th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {list.into_iter().map(|watch| {
+                                        let id = watch.id.clone();
+                                        let mut delivery = vec![i18n.t("notifications.delivery_inbox")];
+                                        if watch.email {
+                                            delivery.push(i18n.t("notifications.delivery_email"));
+                                        }
+                                        if watch.webhook_url.is_some() {
+                                            delivery.push(i18n.t("notifications.delivery_webhook"));
+                                        }
+                                        view! {
+                                            <tr>
+                                                <td class="font-mono">{watch.target.clone()}</td>
+                                                <td>
+                                                    <span title=watch.webhook_url.clone().unwrap_or_default()>
+                                                        {delivery.join(", ")}
+                                                    </span>
+                                                </td>
+                                                <td class="text-base-content/60">{watch.created_at.clone()}</td>
+                                                <td class="text-right">
+                                                    <button
+                                                        class="btn btn-ghost btn-xs text-error"
+                                                        on:click=move |_| { remove.dispatch(id.clone()); }
+                                                    >
+                                                        {i18n.t("notifications.unwatch")}
+                                                    </button>
+                                                </td>
+                                            </tr>
+                                        }
+                                    }).collect::<Vec<_>>()}
+                                </tbody>
+                            </table>
+                        </div>
+                    }.into_any()
+                }}
+            </Show>
+        </section>
+    }
+}
@@ -2,27 +2,33 @@ use leptos::prelude::*;
 use leptos_router::hooks::use_navigate;
 
 use crate::app::{
-    create_user_pat, delete_user_feedback, delete_user_pat, get_current_user, list_user_feedback,
-    list_user_pats, toggle_user_pat, CreatePatResult, FeedbackInfo, FeedbackListResult, PatInfo,
+    change_own_password, create_user_pat, delete_user_feedback, delete_user_pat, get_current_user,
+    get_own_password_status, list_user_feedback, list_user_pats, toggle_user_pat, CreatePatResult,
+    FeedbackInfo, FeedbackListResult, PatInfo,
 };
 use crate::auth::refresh_client::{with_auth_bootstrap, with_auth_retry};
+use crate::i18n::use_i18n;
 
 /// User profile page — shows account info and PAT management.
 #[component]
 pub fn ProfilePage() -> impl IntoView {
+    let i18n = use_i18n();
     let user_resource = LocalResource::new(|| with_auth_bootstrap(get_current_user));
     let navigate = use_navigate();
 
     // Redirect to login if not authenticated
     Effect::new(move |_| {
         if let Some(Ok(None)) = user_resource.get() {
-            navigate("/login", Default::default());
+            navigate(
+                &crate::auth::redirect::login_path("/profile"),
+                Default::default(),
+            );
         }
     });
 
     view! {
         <div class="container mx-auto max-w-3xl px-4 py-8">
-            <h1 class="text-2xl font-bold mb-6">"Profile"</h1>
+            <h1 class="text-2xl font-bold mb-6">{i18n.t("profile.title")}</h1>
 
             // User info card
             <Suspense fallback=|| view! { <div class="skeleton h-20 w-full mb-6" /> }>
@@ -49,9 +55,9 @@ pub fn ProfilePage() -> impl IntoView {
                                         <p class="text-sm text-base-content/60">{user.email.clone()}</p>
                                     </div>
                                     {if user.is_admin {
-                                        view! { <span class="badge badge-error ml-auto">"Admin"</span> }.into_any()
+                                        view! { <span class="badge badge-error ml-auto">{i18n.t("profile.role_admin")}</span> }.into_any()
                                     } else {
-                                        view! { <span class="badge badge-ghost ml-auto">"User"</span> }.into_any()
+                                        view! { <span class="badge badge-ghost ml-auto">{i18n.t("profile.role_user")}</span> }.into_any()
                                     }}
                                 </div>
                             </div>
@@ -61,6 +67,9 @@ pub fn ProfilePage() -> impl IntoView {
                 })}
             </Suspense>
 
+            // Password (local accounts only)
+            <PasswordSection />
+
             // PAT section
             <PatSection />
 
@@ -72,8 +81,103 @@ pub fn ProfilePage() -> impl IntoView {
     }
 }
 
+/// Password change form, shown for local accounts.
+#[component]
+fn PasswordSection() -> impl IntoView {
+    let i18n = use_i18n();
+    let (refresh, set_refresh) = signal(0u32);
+    let status_resource = LocalResource::new(move || {
+        let _ = refresh.get();
+        with_auth_retry(get_own_password_status)
+    });
+
+    let current_password = RwSignal::new(String::new());
+    let new_password = RwSignal::new(String::new());
+    let confirm_password = RwSignal::new(String::new());
+    let error_msg = RwSignal::new(Option::<String>::None);
+    let saved = RwSignal::new(false);
+
+    let change_action = Action::new_local(move |_: &()| async move {
+        saved.set(false);
+        if new_password.get_untracked() != confirm_password.get_untracked() {
+            error_msg.set(Some(i18n.t_untracked("profile.password_mismatch").into()));
+            return;
+        }
+        let result = with_auth_retry(|| {
+            change_own_password(
+                current_password.get_untracked(),
+                new_password.get_untracked(),
+            )
+        })
+        .await;
+        match result {
+            Ok(()) => {
+                error_msg.set(None);
+                saved.set(true);
+                current_password.set(String::new());
+                new_password.set(String::new());
+                confirm_password.set(String::new());
+                set_refresh.update(|c| *c += 1);
+            }
+            Err(e) => error_msg.set(Some(e.to_string())),
+        }
+    });
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || status_resource.get().and_then(|res| res.ok()).flatten().map(|status| view! {
+                <div class="mb-8">
+                    <h2 class="text-xl font-semibold mb-4">{i18n.t("profile.password_title")}</h2>
+                    {status.change_required.then(|| view! {
+                        <div class="alert alert-warning mb-4 text-sm">
+                            <span>{i18n.t("profile.password_change_required")}</span>
+                        </div>
+                    })}
+                    {move || error_msg.get().map(|e| view! {
+                        <div class="alert alert-error mb-4 text-sm"><span>{e}</span></div>
+                    })}
+                    {move || saved.get().then(|| view! {
+                        <div class="alert alert-success mb-4 text-sm"><span>{i18n.t("profile.password_changed")}</span></div>
+                    })}
+                    <div class="flex flex-col gap-3 max-w-sm">
+                        <input
+                            type="password"
+                            class="input input-bordered input-sm"
+                            placeholder=i18n.t("profile.current_password")
+                            autocomplete="current-password"
+                            prop:value=move || current_password.get()
+                            on:input=move |e| current_password.set(event_target_value(&e))
+                        />
+                        <input
+                            type="password"
+                            class="input input-bordered input-sm"
+                            placeholder=i18n.t("profile.new_password")
+                            autocomplete="new-password"
+                            prop:value=move || new_password.get()
+                            on:input=move |e| new_password.set(event_target_value(&e))
+                        />
+                        <input
+                            type="password"
+                            class="input input-bordered input-sm"
+                            placeholder=i18n.t("profile.confirm_password")
+                            autocomplete="new-password"
+                            prop:value=move || confirm_password.get()
+                            on:input=move |e| confirm_password.set(event_target_value(&e))
+                        />
+                        <button
+                            class="btn btn-primary btn-sm self-start"
+                            on:click=move |_| { change_action.dispatch(()); }
+                        >{i18n.t("profile.change_password")}</button>
+                    </div>
+                </div>
+            })}
+        </Suspense>
+    }
+}
+
 #[component]
 fn PatSection() -> impl IntoView {
+    let i18n = use_i18n();
     let pats = RwSignal::new(Vec::<PatInfo>::new());
     let new_token = RwSignal::new(None::<CreatePatResult>);
 
@@ -96,10 +200,9 @@ fn PatSection() -> impl IntoView {
 
     view! {
         <div>
-            <h2 class="text-xl font-semibold mb-4">"Personal Access Tokens"</h2>
+            <h2 class="text-xl font-semibold mb-4">{i18n.t("profile.pats_title")}</h2>
             <p class="text-base-content/60 text-sm mb-6">
-                "PATs allow IDE agents (Claude Code, Cursor, RooCode) to access documentation
-                 on your behalf via the MCP server. Each token inherits your permissions."
+                {i18n.t("profile.pats_intro")}
             </p>
 
             // Show newly created token (one-time display)
@@ -109,12 +212,12 @@ fn PatSection() -> impl IntoView {
                         <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M9 12l2 2 4-4m6 2a9 9 0 11-18 0 9 9 0 0118 0z" />
                     </svg>
                     <div class="flex-1">
-                        <p class="font-semibold">"Token created — copy it now, it won't be shown again."</p>
+                        <p class="font-semibold">{i18n.t("profile.pat_created")}</p>
                         <code class="block mt-2 p-2 bg-success/20 rounded text-sm break-all select-all">
                             {result.raw_token.clone()}
                         </code>
                         <p class="text-xs mt-2 opacity-70">
-                            "Add it to Claude Code: "
+                            {i18n.t("profile.pat_add_hint")} " "
                             <code class="bg-success/20 px-1 rounded">
                                 {format!("claude mcp add-json docs '{{\"type\":\"http\",\"url\":\"http://localhost:3000/mcp\",\"headers\":{{\"Authorization\":\"Bearer {}\"}}}}' ", result.raw_token)}
                             </code>
@@ -124,7 +227,7 @@ fn PatSection() -> impl IntoView {
                         class="btn btn-sm btn-ghost"
                         on:click=move |_| new_token.set(None)
                     >
-                        "Dismiss"
+                        {i18n.t("profile.dismiss")}
                     </button>
                 </div>
             })}
@@ -139,7 +242,7 @@ fn PatSection() -> impl IntoView {
                     if tokens.is_empty() {
                         view! {
                             <div class="text-center py-8 text-base-content/50">
-                                <p>"No personal access tokens yet."</p>
+                                <p>{i18n.t("profile.no_pats")}</p>
                             </div>
                         }.into_any()
                     } else {
@@ -148,10 +251,10 @@ fn PatSection() -> impl IntoView {
                                 <table class="table table-sm">
                                     <thead>
                                         <tr>
-                                            <th>"Name"</th>
-                                            <th>"Created"</th>
-                                            <th>"Last used"</th>
-                                            <th>"Status"</th>
+                                            <th>{i18n.t("profile.col_name")}</th>
+                                            <th>{i18n.t("profile.col_created")}</th>
+                                            <th>{i18n.t("profile.col_last_used")}</th>
+                                            <th>{i18n.t("profile.col_status")}</th>
                                             <th></th>
                                         </tr>
                                     </thead>
@@ -178,6 +281,7 @@ fn PatSection() -> impl IntoView {
 fn CreatePatForm(
     on_created: impl Fn(CreatePatResult) + 'static + Copy + Send + Sync,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let name = RwSignal::new(String::new());
     let error = RwSignal::new(None::<String>);
     let loading = RwSignal::new(false);
@@ -201,14 +305,14 @@ fn CreatePatForm(
     view! {
         <div class="card bg-base-200">
             <div class="card-body py-4">
-                <h3 class="card-title text-base">"Create new token"</h3>
+                <h3 class="card-title text-base">{i18n.t("profile.create_pat")}</h3>
                 {move || error.get().map(|e| view! {
                     <div class="alert alert-error py-2 text-sm">{e}</div>
                 })}
                 <div class="flex gap-2">
                     <input
                         type="text"
-                        placeholder="Token name (e.g. \"claude-code-work\")"
+                        placeholder=i18n.t("profile.pat_name_placeholder")
                         class="input input-bordered flex-1"
                         prop:value=move || name.get()
                         on:input=move |ev| name.set(event_target_value(&ev))
@@ -226,7 +330,7 @@ fn CreatePatForm(
                         {move || if loading.get() {
                             view! { <span class="loading loading-spinner loading-sm" /> }.into_any()
                         } else {
-                            view! { "Generate" }.into_any()
+                            view! { {i18n.t("profile.generate")} }.into_any()
                         }}
                     </button>
                 </div>
@@ -239,6 +343,7 @@ fn CreatePatForm(
 
 #[component]
 fn FeedbackSection() -> impl IntoView {
+    let i18n = use_i18n();
     let page = RwSignal::new(0u64);
     let per_page = 10u64;
     let feedback: RwSignal<Option<FeedbackListResult>> = RwSignal::new(None);
@@ -262,9 +367,9 @@ fn FeedbackSection() -> impl IntoView {
 
     view! {
         <div>
-            <h2 class="text-xl font-semibold mb-2">"AI Feedback History"</h2>
+            <h2 class="text-xl font-semibold mb-2">{i18n.t("profile.feedback_title")}</h2>
             <p class="text-base-content/60 text-sm mb-6">
-                "Feedback you gave on AI responses. You can review and delete any item."
+                {i18n.t("profile.feedback_intro")}
             </p>
 
             {move || {
@@ -275,7 +380,7 @@ fn FeedbackSection() -> impl IntoView {
                     None => view! { <div class="skeleton h-32 w-full rounded-xl" /> }.into_any(),
                     Some(result) if result.items.is_empty() => view! {
                         <div class="text-center py-8 text-base-content/50 border border-dashed border-base-300 rounded-xl">
-                            <p>"No feedback submitted yet."</p>
+                            <p>{i18n.t("profile.no_feedback")}</p>
                         </div>
                     }.into_any(),
                     Some(result) => {
@@ -297,7 +402,11 @@ fn FeedbackSection() -> impl IntoView {
                                 view! {
                                     <div class="flex items-center justify-between mt-4">
                                         <span class="text-sm text-base-content/50">
-                                            {format!("{total} item{}", if total == 1 { "" } else { "s" })}
+                                            {if total == 1 {
+                                                i18n.t("profile.feedback_count_one").to_string()
+                                            } else {
+                                                i18n.tf("profile.feedback_count", &[("count", &total.to_string())])
+                                            }}
                                         </span>
                                         <div class="join">
                                             <button
@@ -337,6 +446,7 @@ fn FeedbackRow(
     feedback: RwSignal<Option<FeedbackListResult>>,
     load: Action<u64, ()>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let msg_id = fb.message_id.clone();
     let is_positive = fb.rating == "positive";
 
@@ -385,14 +495,14 @@ fn FeedbackRow(
                         "badge badge-xs {}",
                         if is_positive { "badge-success badge-soft" } else { "badge-error badge-soft" }
                     )>
-                        {if is_positive { "Helpful" } else { "Not helpful" }}
+                        {if is_positive { i18n.t("profile.helpful") } else { i18n.t("profile.not_helpful") }}
                     </span>
                     <span class="text-xs text-base-content/40">{fb.created_at.clone()}</span>
                     <a
                         href=format!("/chat?session={}", fb.session_id)
                         class="text-xs text-primary hover:underline"
                     >
-                        "View session"
+                        {i18n.t("profile.view_session")}
                     </a>
                 </div>
                 {fb.comment.as_ref().map(|c| view! {
@@ -404,7 +514,7 @@ fn FeedbackRow(
             <button
                 class="btn btn-ghost btn-xs text-error opacity-50 hover:opacity-100 flex-shrink-0"
                 on:click=move |_| { delete.dispatch(()); }
-                title="Delete feedback"
+                title=i18n.t("profile.delete_feedback")
             >
                 <svg class="w-3.5 h-3.5" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><polyline points="3 6 5 6 21 6"/><path d="M19 6l-1 14a2 2 0 0 1-2 2H8a2 2 0 0 1-2-2L5 6"/><path d="M10 11v6"/><path d="M14 11v6"/><path d="M9 6V4a1 1 0 0 1 1-1h4a1 1 0 0 1 1 1v2"/></svg>
             </button>
@@ -418,6 +528,7 @@ fn PatRow(
     pats: RwSignal<Vec<PatInfo>>,
     load_pats: Action<(), ()>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let _ = pats; // prop passed by parent but not used in this component's view
     let id = token.id.clone();
     let is_active = token.is_active;
@@ -454,13 +565,13 @@ fn PatRow(
             <td class="font-mono text-sm">{token.name}</td>
             <td class="text-sm text-base-content/60">{token.created_at}</td>
             <td class="text-sm text-base-content/60">
-                {token.last_used_at.unwrap_or_else(|| "Never".to_string())}
+                {token.last_used_at.unwrap_or_else(|| i18n.t("profile.never").to_string())}
             </td>
             <td>
                 {if is_active {
-                    view! { <span class="badge badge-success badge-sm">"Active"</span> }.into_any()
+                    view! { <span class="badge badge-success badge-sm">{i18n.t("profile.active")}</span> }.into_any()
                 } else {
-                    view! { <span class="badge badge-ghost badge-sm">"Inactive"</span> }.into_any()
+                    view! { <span class="badge badge-ghost badge-sm">{i18n.t("profile.inactive")}</span> }.into_any()
                 }}
             </td>
             <td>
@@ -468,9 +579,9 @@ fn PatRow(
                     <button
                         class=move || if is_active { "btn btn-xs btn-ghost" } else { "btn btn-xs btn-ghost text-success" }
                         on:click=move |_| { toggle.dispatch(!is_active); }
-                        title=if is_active { "Deactivate" } else { "Activate" }
+                        title=if is_active { i18n.t("profile.deactivate") } else { i18n.t("profile.activate") }
                     >
-                        {if is_active { "Deactivate" } else { "Activate" }}
+                        {if is_active { i18n.t("profile.deactivate") } else { i18n.t("profile.activate") }}
                     </button>
                     <button
                         class="btn btn-xs btn-ghost text-error"
@@ -478,14 +589,14 @@ fn PatRow(
                             #[cfg(feature = "hydrate")]
                             {
                                 use leptos::web_sys::window;
-                                if window().and_then(|w| w.confirm_with_message("Delete this token permanently?").ok()).unwrap_or(false) {
+                                if window().and_then(|w| w.confirm_with_message(i18n.t_untracked("profile.delete_pat_confirm")).ok()).unwrap_or(false) {
                                     delete.dispatch(());
                                 }
                             }
                         }
-                        title="Delete permanently"
+                        title=i18n.t("profile.delete_permanently")
                     >
-                        "Delete"
+                        {i18n.t("profile.delete")}
                     </button>
                 </div>
             </td>
@@ -6,23 +6,28 @@ use crate::app::{
     PromptLibraryState,
 };
 use crate::auth::refresh_client::{with_auth_bootstrap, with_auth_retry};
+use crate::i18n::use_i18n;
 
 #[component]
 pub fn PromptsPage() -> impl IntoView {
+    let i18n = use_i18n();
     let user_resource = LocalResource::new(|| with_auth_bootstrap(get_current_user));
     let navigate = use_navigate();
 
     Effect::new(move |_| {
         if let Some(Ok(None)) = user_resource.get() {
-            navigate("/login", Default::default());
+            navigate(
+                &crate::auth::redirect::login_path("/prompts"),
+                Default::default(),
+            );
         }
     });
 
     view! {
         <div class="container mx-auto max-w-6xl px-4 py-8">
-            <h1 class="text-3xl font-bold">"Prompt Library"</h1>
+            <h1 class="text-3xl font-bold">{i18n.t("prompts.title")}</h1>
             <p class="text-base-content/60 mt-2 mb-6 max-w-3xl">
-                "Published prompts can be added to your working context. Default primary prompts come from the shared library; favorites and hidden flags are personal preferences."
+                {i18n.t("prompts.intro")}
             </p>
             <PromptLibraryPanel />
         </div>
@@ -31,6 +36,7 @@ pub fn PromptsPage() -> impl IntoView {
 
 #[component]
 fn PromptLibraryPanel() -> impl IntoView {
+    let i18n = use_i18n();
     let state = RwSignal::new(None::<PromptLibraryState>);
     let filter = RwSignal::new(String::new());
     let saving_slug = RwSignal::new(None::<String>);
@@ -51,7 +57,7 @@ fn PromptLibraryPanel() -> impl IntoView {
             <input
                 type="text"
                 class="input input-bordered w-full md:max-w-md"
-                placeholder="Search prompts by name, slug, owner, or tag"
+                placeholder=i18n.t("prompts.filter_placeholder")
                 prop:value=move || filter.get()
                 on:input=move |ev| filter.set(event_target_value(&ev))
             />
@@ -82,7 +88,7 @@ fn PromptLibraryPanel() -> impl IntoView {
                     view! {
                         <div class="flex flex-wrap items-start gap-3">
                             <div class="badge badge-outline badge-lg">
-                                {format!("Context cost: {}", library.estimated_context_cost)}
+                                {i18n.tf("prompts.context_cost", &[("cost", &library.estimated_context_cost.to_string())])}
                             </div>
                             <Show
                                 when=move || has_warning
@@ -133,7 +139,7 @@ fn PromptLibraryPanel() -> impl IntoView {
                                                             <h2 class="card-title text-xl">{item.name.clone()}</h2>
                                                             <span class="badge badge-outline">{item.status.clone()}</span>
                                                             <span class="badge badge-ghost">{item.access_level.clone()}</span>
-                                                            <span class="badge badge-ghost">{format!("cost: {}", item.context_cost)}</span>
+                                                            <span class="badge badge-ghost">{i18n.tf("prompts.cost", &[("cost", &item.context_cost.to_string())])}</span>
                                                         </div>
                                                         <p class="text-sm text-base-content/60">{item.slug.clone()}</p>
                                                         <p>{item.description.clone()}</p>
@@ -147,7 +153,7 @@ fn PromptLibraryPanel() -> impl IntoView {
                                                 </div>
 
                                                 <div class="flex flex-wrap gap-2 text-sm">
-                                                    <span class="badge badge-outline">{format!("owner: {}", item.owner.clone())}</span>
+                                                    <span class="badge badge-outline">{i18n.tf("prompts.owner", &[("owner", &item.owner)])}</span>
                                                     {item.tags.iter().map(|tag| view! {
                                                         <span class="badge badge-neutral badge-outline">{tag.clone()}</span>
                                                     }).collect::<Vec<_>>()}
@@ -167,9 +173,9 @@ fn PromptLibraryPanel() -> impl IntoView {
                                                         />
                                                         <span class="label-text">
                                                             {if item.publish_to_mcp {
-                                                                "Favorite in personal context"
+                                                                i18n.t("prompts.favorite")
                                                             } else {
-                                                                "Not publishable to MCP"
+                                                                i18n.t("prompts.not_publishable")
                                                             }}
                                                         </span>
                                                     </label>
@@ -187,9 +193,9 @@ fn PromptLibraryPanel() -> impl IntoView {
                                                         />
                                                         <span class="label-text">
                                                             {if item.default_primary {
-                                                                "Hide shared primary prompt"
+                                                                i18n.t("prompts.hide_primary")
                                                             } else {
-                                                                "Not a shared primary prompt"
+                                                                i18n.t("prompts.not_primary")
                                                             }}
                                                         </span>
                                                     </label>
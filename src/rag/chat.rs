@@ -88,6 +88,30 @@ pub struct RetrievalOutput {
     pub post_rerank: Vec<VectorSearchResult>,
 }
 
+/// Instructions appended to the system prompt by [`ChatService::answer`].
+const CITATION_INSTRUCTIONS: &str = "Each context entry starts with a citation number in \
+square brackets. Cite the entries supporting each statement inline using those numbers, \
+e.g. [1] or [1][3]. Do not cite entries you did not use.";
+
+/// A documentation answer with the sources it may cite.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Answer {
+    /// Markdown answer with inline `[n]` citation markers. Empty when no
+    /// accessible documentation matched the question.
+    pub answer: String,
+    pub citations: Vec<Citation>,
+}
+
+/// A source referenced as `[number]` in an [`Answer`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Citation {
+    pub number: usize,
+    /// Link to the cited document section (e.g. `/docs/payments/retries#backoff`).
+    pub url: String,
+    #[serde(flatten)]
+    pub source: SourceReference,
+}
+
 /// A token event yielded by the streaming chat response.
 #[derive(Debug, serde::Serialize)]
 #[serde(tag = "type")]
@@ -283,7 +307,7 @@ impl ChatService {
             .await?;
         let source_references = build_source_references(&search_results);
 
-        // 7. Build context string from search results
+        // 7-8. Build context string from search results and render the system prompt
         let context = search_results
             .iter()
             .map(|r| {
@@ -294,15 +318,7 @@ impl ChatService {
             })
             .collect::<Vec<_>>()
             .join("\n\n---\n\n");
-
-        // 8. Render system prompt via Tera
-        let mut tera_ctx = tera::Context::new();
-        tera_ctx.insert("context", &context);
-        tera_ctx.insert("question", &user_message);
-        let system_prompt = self
-            .tera
-            .render(&self.system_template_name, &tera_ctx)
-            .map_err(|e| AppError::Internal(format!("tera render failed: {e}")))?;
+        let system_prompt = self.render_system_prompt(&context, &user_message)?;
 
         // 9. Build message array: system prompt + history + current user message
         let mut messages: Vec<ChatCompletionRequestMessage> = Vec::new();
@@ -458,6 +474,86 @@ impl ChatService {
         Ok(Box::pin(event_stream))
     }
 
+    /// Answer a single question without a chat session: retrieve the chunks
+    /// visible to `user_ctx`, ask the LLM (non-streaming) to answer citing
+    /// them as `[n]`, and return the answer with the numbered citations.
+    ///
+    /// Nothing is persisted. When no accessible chunk matches, the LLM is not
+    /// called and an empty answer with no citations is returned.
+    #[tracing::instrument(skip(self, user_ctx, question))]
+    pub async fn answer(&self, user_ctx: &UserContext, question: &str) -> Result<Answer, AppError> {
+        let request_id = Uuid::new_v4().to_string();
+        let retrieval = self
+            .retrieve_only(user_ctx, question, &[], &request_id)
+            .await?;
+        let search_results = self
+            .expand_results_to_parent(user_ctx, retrieval.post_rerank, &request_id)
+            .await?;
+        if search_results.is_empty() {
+            return Ok(Answer {
+                answer: String::new(),
+                citations: vec![],
+            });
+        }
+
+        let (context, citations) = numbered_context(&search_results);
+        let system_prompt = self.render_system_prompt(&context, question)?;
+        let request = CreateChatCompletionRequest {
+            messages: vec![
+                ChatCompletionRequestMessage::System(ChatCompletionRequestSystemMessage {
+                    content: ChatCompletionRequestSystemMessageContent::Text(system_prompt),
+                    name: None,
+                }),
+                ChatCompletionRequestMessage::System(ChatCompletionRequestSystemMessage {
+                    content: ChatCompletionRequestSystemMessageContent::Text(
+                        CITATION_INSTRUCTIONS.to_string(),
+                    ),
+                    name: None,
+                }),
+                ChatCompletionRequestMessage::User(ChatCompletionRequestUserMessage {
+                    content: ChatCompletionRequestUserMessageContent::Text(question.to_string()),
+                    name: None,
+                }),
+            ],
+            model: self.chat_model.clone(),
+            ..Default::default()
+        };
+
+        let llm_client = self
+            .llm_provider
+            .get_client_with_headers(&self.chat_headers)
+            .await?;
+        let response = llm_client.chat().create(request).await.map_err(|e| {
+            AppError::Internal(format!("LLM request failed: {}", format_llm_error(&e)))
+        })?;
+        let answer = response
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|c| c.message.content)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default();
+
+        tracing::debug!(
+            request_id = %request_id,
+            citations = citations.len(),
+            response = %preview_text(&answer, 4_000),
+            "RAG: answered question"
+        );
+
+        Ok(Answer { answer, citations })
+    }
+
+    /// Render the configured system prompt template.
+    fn render_system_prompt(&self, context: &str, question: &str) -> Result<String, AppError> {
+        let mut tera_ctx = tera::Context::new();
+        tera_ctx.insert("context", context);
+        tera_ctx.insert("question", question);
+        self.tera
+            .render(&self.system_template_name, &tera_ctx)
+            .map_err(|e| AppError::Internal(format!("tera render failed: {e}")))
+    }
+
     async fn expand_results_to_parent(
         &self,
         user_ctx: &UserContext,
@@ -614,8 +710,13 @@ impl ChatService {
             .collect();
 
         let (vector_results_nested, text_slugs) = if let Some(ref svc) = self.search_service {
-            let text_future =
-                svc.search(&retrieval_query, allowed_levels.as_deref(), include_draft);
+            let filter = crate::search::client::SearchFilter::default();
+            let text_future = svc.search(
+                &retrieval_query,
+                allowed_levels.as_deref(),
+                include_draft,
+                &filter,
+            );
             let (vector_list, text_result) =
                 tokio::join!(futures::future::join_all(vector_searches), text_future);
             let slugs: Vec<String> = text_result
@@ -1010,6 +1111,39 @@ fn summarize_search_results(
         .collect()
 }
 
+/// Build the LLM context with one `[n]` entry per chunk, where `n` numbers
+/// the distinct document sections, and the matching citations.
+fn numbered_context(results: &[VectorSearchResult]) -> (String, Vec<Citation>) {
+    let citations: Vec<Citation> = build_source_references(results)
+        .into_iter()
+        .enumerate()
+        .map(|(i, source)| Citation {
+            number: i + 1,
+            url: match source.section_anchor.as_deref() {
+                Some(anchor) => format!("/docs/{}#{}", source.document_slug, anchor),
+                None => format!("/docs/{}", source.document_slug),
+            },
+            source,
+        })
+        .collect();
+
+    let context = results
+        .iter()
+        .filter_map(|r| {
+            let number = citations.iter().find(|c| {
+                c.source.document_slug == r.document_slug
+                    && c.source.section_anchor.as_deref().unwrap_or_default() == r.section_anchor
+            })?;
+            Some(format!(
+                "[{}] {} ({})\n{}",
+                number.number, r.document_title, r.document_slug, r.chunk_text
+            ))
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n");
+    (context, citations)
+}
+
 fn build_source_references(
     results: &[crate::rag::vectorstore::VectorSearchResult],
 ) -> Vec<SourceReference> {
@@ -1146,6 +1280,33 @@ mod tests {
         assert_eq!(sources[1].document_slug, "docs/b");
     }
 
+    #[test]
+    fn numbered_context_numbers_sections_and_links_them() {
+        let chunk = |id: &str, slug: &str, anchor: &str, score: f32| VectorSearchResult {
+            point_id: id.into(),
+            chunk_text: format!("text {id}"),
+            document_slug: slug.into(),
+            document_title: format!("Title {slug}"),
+            chunk_index: 0,
+            section_path: vec![],
+            section_anchor: anchor.into(),
+            score,
+        };
+        let (context, citations) = numbered_context(&[
+            chunk("p1", "docs/a", "setup", 0.9),
+            chunk("p2", "docs/b", "", 0.8),
+            chunk("p3", "docs/a", "setup", 0.7),
+        ]);
+
+        assert_eq!(citations.len(), 2);
+        assert_eq!(citations[0].number, 1);
+        assert_eq!(citations[0].url, "/docs/docs/a#setup");
+        assert_eq!(citations[1].url, "/docs/docs/b");
+        assert!(context.starts_with("[1] Title docs/a (docs/a)\ntext p1"));
+        assert!(context.contains("[2] Title docs/b (docs/b)\ntext p2"));
+        assert!(context.contains("[1] Title docs/a (docs/a)\ntext p3"));
+    }
+
     #[test]
     fn build_source_references_keeps_distinct_sections_from_same_document() {
         let sources = build_source_references(&[
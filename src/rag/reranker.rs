@@ -173,6 +173,7 @@ mod tests {
             chunk_overlap_tokens: 64,
             expand_to_parent: false,
             hybrid_search_enabled: false,
+            semantic_search_enabled: false,
             reranker_url: url.to_string(),
             reranker_model: String::new(),
             reranker_api_key: String::new(),
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use crate::rag::vectorstore::VectorSearchResult;
 
 /// Standard RRF constant. Higher values reduce the impact of rank differences.
-const RRF_K: usize = 60;
+pub(crate) const RRF_K: usize = 60;
 
 /// Re-rank `vector_results` using Reciprocal Rank Fusion with a text-search
 /// result list as the second signal.
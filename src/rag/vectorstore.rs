@@ -459,6 +459,7 @@ mod tests {
             chunk_overlap_tokens: 64,
             expand_to_parent: false,
             hybrid_search_enabled: false,
+            semantic_search_enabled: false,
             reranker_url: String::new(),
             reranker_model: String::new(),
             reranker_api_key: String::new(),
@@ -0,0 +1,368 @@
+//! Typed relations between documents — `see_also`, `prerequisite`,
+//! `supersedes` and `superseded_by` — declared in the document metadata
+//! (see [`DocumentRelations`](crate::db::models::DocumentRelations)),
+//! resolved in both directions for the panels of the document page and the
+//! `GET /api/v1/bot/relations/{*slug}` endpoint. Superseded documents also
+//! sort last in search results.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::db::models::Document;
+#[cfg(feature = "ssr")]
+use crate::db::repository::DocumentRepository;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+use crate::i18n::use_i18n;
+#[cfg(feature = "ssr")]
+use crate::search::client::SearchHit;
+
+/// A related document the reader can open.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedDoc {
+    pub slug: String,
+    pub title: String,
+}
+
+/// The relations of one document, restricted to documents the reader can
+/// see. Declared relations keep their order; incoming ones are sorted by
+/// title.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocRelations {
+    /// Declared by either document.
+    pub see_also: Vec<RelatedDoc>,
+    /// Documents to read first.
+    pub prerequisite: Vec<RelatedDoc>,
+    /// Declared by either document.
+    pub supersedes: Vec<RelatedDoc>,
+    /// Documents listing this one as a prerequisite.
+    pub prerequisite_of: Vec<RelatedDoc>,
+    /// Declared by either document.
+    pub superseded_by: Vec<RelatedDoc>,
+}
+
+impl DocRelations {
+    pub fn is_empty(&self) -> bool {
+        self.see_also.is_empty()
+            && self.prerequisite.is_empty()
+            && self.supersedes.is_empty()
+            && self.prerequisite_of.is_empty()
+            && self.superseded_by.is_empty()
+    }
+}
+
+/// Resolve the relations of `doc`: the documents it declares and those
+/// declaring it, keeping only unarchived documents `can_read` accepts.
+#[cfg(feature = "ssr")]
+pub async fn resolve_relations(
+    repo: &dyn DocumentRepository,
+    doc: &Document,
+    can_read: impl Fn(&Document) -> bool,
+) -> Result<DocRelations, AppError> {
+    let visible =
+        |other: &Document| !other.is_archived && other.slug != doc.slug && can_read(other);
+    let related = |other: &Document| RelatedDoc {
+        slug: other.slug.clone(),
+        title: other.title.clone(),
+    };
+
+    let mut targets: Vec<String> = doc.relations.slugs().cloned().collect();
+    targets.sort();
+    targets.dedup();
+    let declared: Vec<Document> = repo
+        .find_by_slugs(&targets)
+        .await?
+        .into_iter()
+        .filter(|other| visible(other))
+        .collect();
+    let resolve = |slugs: &[String]| -> Vec<RelatedDoc> {
+        slugs
+            .iter()
+            .filter_map(|slug| declared.iter().find(|d| d.slug == *slug))
+            .map(related)
+            .collect()
+    };
+
+    let mut relations = DocRelations {
+        see_also: resolve(&doc.relations.see_also),
+        prerequisite: resolve(&doc.relations.prerequisite),
+        supersedes: resolve(&doc.relations.supersedes),
+        superseded_by: resolve(&doc.relations.superseded_by),
+        ..Default::default()
+    };
+    let push_new = |list: &mut Vec<RelatedDoc>, other: &Document| {
+        if !list.iter().any(|r| r.slug == other.slug) {
+            list.push(related(other));
+        }
+    };
+
+    let mut incoming: Vec<Document> = repo
+        .find_related_to(&doc.slug)
+        .await?
+        .into_iter()
+        .filter(|other| visible(other))
+        .collect();
+    incoming.sort_by(|a, b| a.title.cmp(&b.title));
+    for other in &incoming {
+        if other.relations.supersedes.contains(&doc.slug) {
+            push_new(&mut relations.superseded_by, other);
+        }
+        if other.relations.superseded_by.contains(&doc.slug) {
+            push_new(&mut relations.supersedes, other);
+        }
+        if other.relations.prerequisite.contains(&doc.slug) {
+            relations.prerequisite_of.push(related(other));
+        }
+        if other.relations.see_also.contains(&doc.slug) {
+            push_new(&mut relations.see_also, other);
+        }
+    }
+    Ok(relations)
+}
+
+/// Flag the hits for superseded documents — those with a replacement
+/// `can_read` accepts, declared by either document — and move them after
+/// the others, keeping the order within each group, so readers find the
+/// current procedure first.
+#[cfg(feature = "ssr")]
+pub async fn demote_superseded_hits(
+    repo: &dyn DocumentRepository,
+    hits: &mut [SearchHit],
+    can_read: impl Fn(&Document) -> bool,
+) -> Result<(), AppError> {
+    if hits.is_empty() {
+        return Ok(());
+    }
+    let slugs: Vec<String> = hits.iter().map(|hit| hit.slug.clone()).collect();
+    let found = repo.find_by_slugs(&slugs).await?;
+
+    let mut replacements: Vec<String> = found
+        .iter()
+        .flat_map(|doc| doc.relations.superseded_by.iter().cloned())
+        .collect();
+    replacements.sort();
+    replacements.dedup();
+    let declared: Vec<Document> = repo
+        .find_by_slugs(&replacements)
+        .await?
+        .into_iter()
+        .filter(|other| !other.is_archived && can_read(other))
+        .collect();
+    let superseding: Vec<Document> = repo
+        .find_superseding(&slugs)
+        .await?
+        .into_iter()
+        .filter(|other| !other.is_archived && can_read(other))
+        .collect();
+
+    for hit in hits.iter_mut() {
+        let replaced_by_declared =
+            found
+                .iter()
+                .find(|doc| doc.slug == hit.slug)
+                .is_some_and(|doc| {
+                    doc.relations
+                        .superseded_by
+                        .iter()
+                        .any(|target| declared.iter().any(|d| d.slug == *target))
+                });
+        hit.superseded = replaced_by_declared
+            || superseding.iter().any(|other| {
+                other.slug != hit.slug && other.relations.supersedes.contains(&hit.slug)
+            });
+    }
+    hits.sort_by_key(|hit| hit.superseded);
+    Ok(())
+}
+
+/// Notice shown above the content of a document that has been superseded.
+#[component]
+pub fn SupersededNotice(superseded_by: Vec<RelatedDoc>) -> impl IntoView {
+    let i18n = use_i18n();
+    view! {
+        <div role="alert" class="alert alert-info mb-6 print:hidden">
+            <span>
+                {i18n.t("doc.superseded_by")} " "
+                <RelatedLinks docs=superseded_by />
+            </span>
+        </div>
+    }
+}
+
+/// Panels listing the related documents below the content; renders nothing
+/// without relations.
+#[component]
+pub fn RelationPanels(relations: DocRelations) -> impl IntoView {
+    let i18n = use_i18n();
+    let panels = [
+        ("doc.relations.prerequisite", relations.prerequisite),
+        ("doc.relations.see_also", relations.see_also),
+        ("doc.relations.supersedes", relations.supersedes),
+        ("doc.relations.prerequisite_of", relations.prerequisite_of),
+    ];
+    view! {
+        <div class="grid gap-3 md:grid-cols-2 mt-10 print:hidden">
+            {panels.into_iter().filter(|(_, docs)| !docs.is_empty()).map(|(label, docs)| view! {
+                <div class="border border-base-300 rounded-lg p-3">
+                    <div class="text-sm font-semibold mb-1">{i18n.t(label)}</div>
+                    <ul class="text-sm space-y-0.5">
+                        {docs.into_iter().map(|doc| view! {
+                            <li><a href=format!("/docs/{}", doc.slug) class="link link-hover">{doc.title}</a></li>
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                </div>
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
+
+#[component]
+fn RelatedLinks(docs: Vec<RelatedDoc>) -> impl IntoView {
+    let count = docs.len();
+    docs.into_iter()
+        .enumerate()
+        .map(|(i, doc)| {
+            view! {
+                <a href=format!("/docs/{}", doc.slug) class="link font-medium">{doc.title}</a>
+                {(i + 1 < count).then_some(", ")}
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::DocumentRelations;
+    use crate::testing::{self, InMemoryDocumentRepository};
+
+    fn doc(slug: &str, relations: DocumentRelations) -> Document {
+        Document {
+            title: slug.to_uppercase(),
+            relations,
+            ..testing::document(slug)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_relations_in_both_directions() {
+        let setup = doc(
+            "setup",
+            DocumentRelations {
+                see_also: vec!["faq".into(), "missing".into()],
+                prerequisite: vec!["intro".into()],
+                supersedes: vec!["setup-v1".into()],
+                superseded_by: vec![],
+            },
+        );
+        let mut secret = doc(
+            "secret",
+            DocumentRelations {
+                see_also: vec!["setup".into()],
+                ..Default::default()
+            },
+        );
+        secret.access_level = "internal".into();
+        let repo = InMemoryDocumentRepository::with_documents(vec![
+            setup.clone(),
+            doc("faq", Default::default()),
+            doc("intro", Default::default()),
+            doc("setup-v1", Default::default()),
+            doc(
+                "operations",
+                DocumentRelations {
+                    see_also: vec!["setup".into()],
+                    prerequisite: vec!["setup".into()],
+                    ..Default::default()
+                },
+            ),
+            secret,
+        ]);
+
+        let relations = resolve_relations(&repo, &setup, |d| d.access_level == "public")
+            .await
+            .unwrap();
+        let slugs = |docs: &[RelatedDoc]| docs.iter().map(|d| d.slug.clone()).collect::<Vec<_>>();
+        assert_eq!(slugs(&relations.see_also), ["faq", "operations"]);
+        assert_eq!(slugs(&relations.prerequisite), ["intro"]);
+        assert_eq!(slugs(&relations.supersedes), ["setup-v1"]);
+        assert_eq!(slugs(&relations.prerequisite_of), ["operations"]);
+
+        let old = repo.find_by_slug("setup-v1").await.unwrap().unwrap();
+        let relations = resolve_relations(&repo, &old, |_| true).await.unwrap();
+        assert_eq!(slugs(&relations.superseded_by), ["setup"]);
+    }
+
+    #[tokio::test]
+    async fn test_demote_superseded_hits() {
+        let mut legacy = doc(
+            "deploy-legacy",
+            DocumentRelations {
+                superseded_by: vec!["deploy".into()],
+                ..Default::default()
+            },
+        );
+        legacy.title = "Legacy deploy".into();
+        let mut hidden_replacement = doc(
+            "backup-v2",
+            DocumentRelations {
+                supersedes: vec!["backup".into()],
+                ..Default::default()
+            },
+        );
+        hidden_replacement.access_level = "internal".into();
+        let repo = InMemoryDocumentRepository::with_documents(vec![
+            legacy,
+            doc("deploy", Default::default()),
+            doc(
+                "rollback",
+                DocumentRelations {
+                    supersedes: vec!["rollback-v1".into()],
+                    ..Default::default()
+                },
+            ),
+            doc("rollback-v1", Default::default()),
+            doc("backup", Default::default()),
+            hidden_replacement,
+        ]);
+        let hit = |slug: &str| SearchHit {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            tags: vec![],
+            content_preview: String::new(),
+            deprecated_schemas: vec![],
+            superseded: false,
+        };
+        let mut hits = vec![
+            hit("deploy-legacy"),
+            hit("rollback-v1"),
+            hit("backup"),
+            hit("deploy"),
+        ];
+
+        demote_superseded_hits(&repo, &mut hits, |d| d.access_level == "public")
+            .await
+            .unwrap();
+        let order: Vec<(&str, bool)> = hits
+            .iter()
+            .map(|h| (h.slug.as_str(), h.superseded))
+            .collect();
+        assert_eq!(
+            order,
+            [
+                ("backup", false),
+                ("deploy", false),
+                ("deploy-legacy", true),
+                ("rollback-v1", true),
+            ]
+        );
+
+        let legacy = repo.find_by_slug("deploy-legacy").await.unwrap().unwrap();
+        let relations = resolve_relations(&repo, &legacy, |_| true).await.unwrap();
+        assert_eq!(relations.superseded_by[0].slug, "deploy");
+        let deploy = repo.find_by_slug("deploy").await.unwrap().unwrap();
+        let relations = resolve_relations(&repo, &deploy, |_| true).await.unwrap();
+        assert_eq!(relations.supersedes[0].slug, "deploy-legacy");
+    }
+}
@@ -0,0 +1,637 @@
+//! Embedded widgets for bare links to known tools.
+//!
+//! A paragraph made of nothing but a URL (`https://youtu.be/…` or
+//! `<https://youtu.be/…>`) pointing at an enabled provider is rendered as a
+//! responsive iframe with the original link underneath. Links inside running
+//! text are left alone.
+//!
+//! Admins enable providers on the admin settings page (seeded from
+//! `rendering.embeds`). The enabled [`Embeds`] are read from the settings at
+//! render time and passed in [`RenderContext::embeds`] to the standard
+//! pipeline's [`EmbedLinks`] stage; sanitization then restricts
+//! `<iframe src>` to the embed URLs enabled providers produce, so raw
+//! iframes in Markdown cannot point anywhere else.
+//!
+//! With the Grafana proxy configured ([`Embeds::with_grafana_proxy`]), links
+//! to panels and dashboards of the allow-listed Grafana dashboards are
+//! embedded as images rendered by Grafana and served from
+//! [`GRAFANA_PROXY_PATH`], which adds a read-only token, so readers see them
+//! without a Grafana login. Only the image is served from the portal's
+//! origin, never Grafana's HTML or scripts.
+
+use pulldown_cmark::{Event, LinkType, Tag, TagEnd};
+use url::Url;
+
+use crate::db::settings_repository::EmbedSettings;
+use crate::rendering::markdown::escape_html;
+use crate::rendering::pipeline::{EventTransform, RenderContext};
+
+/// Path under which the portal serves rendered Grafana images
+/// (`crate::api::grafana`).
+pub const GRAFANA_PROXY_PATH: &str = "/grafana/render/";
+
+/// Query parameters passed on to Grafana's image renderer. Anything else,
+/// template variables included, is dropped, so a reader can only render the
+/// queries saved in the dashboard.
+const GRAFANA_RENDER_PARAMS: &[&str] = &[
+    "orgId",
+    "panelId",
+    "viewPanel",
+    "from",
+    "to",
+    "width",
+    "height",
+    "tz",
+    "theme",
+];
+
+/// A Grafana dashboard (`d/<uid>`) or panel (`d-solo/<uid>`) rendered to an
+/// image by the Grafana proxy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrafanaRender {
+    /// UID of the dashboard.
+    pub uid: String,
+    /// Path below Grafana's `/render/`, e.g. `d-solo/abc/payments`.
+    pub path: String,
+    /// The [`GRAFANA_RENDER_PARAMS`] of the request, URL-encoded.
+    pub query: String,
+}
+
+impl GrafanaRender {
+    /// Parse a dashboard or panel path, optionally below a sub-path
+    /// (`/grafana/d/abc/payments`), keeping only the allowed query parameters.
+    pub fn parse(path: &str, query: Option<&str>) -> Option<Self> {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let start = segments.iter().position(|s| matches!(*s, "d" | "d-solo"))?;
+        let (kind, uid, slug) = match &segments[start..] {
+            [kind, uid] => (*kind, *uid, None),
+            [kind, uid, slug] => (*kind, *uid, Some(*slug)),
+            _ => return None,
+        };
+        if !is_grafana_id(uid) || slug.is_some_and(|slug| !is_grafana_id(slug)) {
+            return None;
+        }
+
+        let mut params = url::form_urlencoded::Serializer::new(String::new());
+        let mut has_size = false;
+        for (key, value) in url::form_urlencoded::parse(query.unwrap_or_default().as_bytes()) {
+            if GRAFANA_RENDER_PARAMS.contains(&key.as_ref()) {
+                has_size |= key == "width";
+                params.append_pair(&key, &value);
+            }
+        }
+        if !has_size {
+            params.append_pair("width", "1000");
+            params.append_pair("height", "500");
+        }
+
+        Some(Self {
+            uid: uid.to_string(),
+            path: match slug {
+                Some(slug) => format!("{kind}/{uid}/{slug}"),
+                None => format!("{kind}/{uid}"),
+            },
+            query: params.finish(),
+        })
+    }
+
+    /// The portal URL of the rendered image.
+    pub fn proxy_src(&self) -> String {
+        format!("{GRAFANA_PROXY_PATH}{}?{}", self.path, self.query)
+    }
+}
+
+/// A tool whose links can be embedded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedProvider {
+    YouTube,
+    Figma,
+    Miro,
+    /// Grafana snapshots (`/dashboard/snapshot/<key>`) on configured hosts.
+    Grafana,
+    /// Grafana dashboards and panels on configured hosts, rendered to
+    /// images by the Grafana proxy; enabled by `grafana` when the proxy is
+    /// configured.
+    GrafanaProxy,
+}
+
+impl EmbedProvider {
+    /// Parse a provider name as used in [`EmbedSettings::providers`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "youtube" => Some(Self::YouTube),
+            "figma" => Some(Self::Figma),
+            "miro" => Some(Self::Miro),
+            "grafana" => Some(Self::Grafana),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::YouTube => "youtube",
+            Self::Figma => "figma",
+            Self::Miro => "miro",
+            Self::Grafana | Self::GrafanaProxy => "grafana",
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            Self::YouTube => "YouTube video",
+            Self::Figma => "Figma design",
+            Self::Miro => "Miro board",
+            Self::Grafana => "Grafana snapshot",
+            Self::GrafanaProxy => "Grafana panel",
+        }
+    }
+}
+
+/// The enabled embed providers.
+#[derive(Debug, Clone)]
+pub struct Embeds {
+    providers: Vec<EmbedProvider>,
+    grafana_hosts: Vec<String>,
+    /// UIDs of the dashboards embedded through the Grafana proxy.
+    grafana_dashboards: Vec<String>,
+}
+
+impl Embeds {
+    /// `grafana_hosts` entries are host names, optionally with a `:port`.
+    pub fn new(providers: Vec<EmbedProvider>, grafana_hosts: Vec<String>) -> Self {
+        Self {
+            providers,
+            grafana_hosts: grafana_hosts
+                .into_iter()
+                .map(|h| h.trim().to_ascii_lowercase())
+                .filter(|h| !h.is_empty())
+                .collect(),
+            grafana_dashboards: vec![],
+        }
+    }
+
+    /// The providers and hosts enabled in the settings; unknown provider
+    /// names are ignored.
+    pub fn from_settings(settings: &EmbedSettings) -> Self {
+        Self::new(
+            settings
+                .providers
+                .iter()
+                .filter_map(|name| EmbedProvider::from_name(name))
+                .collect(),
+            settings.grafana_hosts.clone(),
+        )
+    }
+
+    /// Also embed Grafana dashboards (`/d/…`) and panels (`/d-solo/…`) of
+    /// the `dashboards` (UIDs) as images rendered through the proxy at
+    /// [`GRAFANA_PROXY_PATH`]; needs the `grafana` provider.
+    pub fn with_grafana_proxy(mut self, dashboards: Vec<String>) -> Self {
+        if self.providers.contains(&EmbedProvider::Grafana) {
+            self.providers.push(EmbedProvider::GrafanaProxy);
+            self.grafana_dashboards = dashboards;
+        }
+        self
+    }
+
+    /// Whether no provider is enabled.
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    fn enabled(&self, provider: EmbedProvider) -> bool {
+        self.providers.contains(&provider)
+    }
+
+    /// The provider and iframe URL for a link, if it points at an enabled provider.
+    pub fn embed_src(&self, link: &str) -> Option<(EmbedProvider, String)> {
+        let url = Url::parse(link.trim()).ok()?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return None;
+        }
+        let host = url.host_str()?.to_ascii_lowercase();
+        let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+
+        match host.as_str() {
+            "youtube.com" | "www.youtube.com" | "m.youtube.com" | "youtu.be"
+                if self.enabled(EmbedProvider::YouTube) =>
+            {
+                let id = match (host.as_str(), segments.as_slice()) {
+                    ("youtu.be", [id]) => id.to_string(),
+                    (_, ["watch"]) => url
+                        .query_pairs()
+                        .find(|(k, _)| k == "v")
+                        .map(|(_, v)| v.into_owned())?,
+                    (_, ["embed" | "shorts" | "live", id]) => id.to_string(),
+                    _ => return None,
+                };
+                is_youtube_id(&id).then(|| {
+                    (
+                        EmbedProvider::YouTube,
+                        format!("https://www.youtube-nocookie.com/embed/{id}"),
+                    )
+                })
+            }
+            "figma.com" | "www.figma.com" if self.enabled(EmbedProvider::Figma) => {
+                match segments.first() {
+                    Some(&("file" | "design" | "proto" | "board")) if segments.len() >= 2 => {
+                        let src = Url::parse_with_params(
+                            "https://www.figma.com/embed",
+                            &[("embed_host", "lekton"), ("url", url.as_str())],
+                        )
+                        .ok()?;
+                        Some((EmbedProvider::Figma, src.to_string()))
+                    }
+                    _ => None,
+                }
+            }
+            "miro.com" | "www.miro.com" if self.enabled(EmbedProvider::Miro) => {
+                match segments.as_slice() {
+                    ["app", "board", id] if is_miro_id(id) => Some((
+                        EmbedProvider::Miro,
+                        format!("https://miro.com/app/live-embed/{id}/"),
+                    )),
+                    _ => None,
+                }
+            }
+            _ if self.is_grafana_snapshot(&url) => Some((EmbedProvider::Grafana, url.to_string())),
+            _ => self
+                .grafana_proxy_src(&url)
+                .map(|src| (EmbedProvider::GrafanaProxy, src)),
+        }
+    }
+
+    /// Whether `src` is an iframe URL that [`embed_src`](Self::embed_src) can produce.
+    pub fn is_embed_src(&self, src: &str) -> bool {
+        let Ok(url) = Url::parse(src) else {
+            return false;
+        };
+        let https = url.scheme() == "https";
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|s| s.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+
+        match url.host_str() {
+            Some("www.youtube-nocookie.com") => {
+                https
+                    && self.enabled(EmbedProvider::YouTube)
+                    && matches!(segments.as_slice(), ["embed", id] if is_youtube_id(id))
+            }
+            Some("www.figma.com") => {
+                https && self.enabled(EmbedProvider::Figma) && segments == ["embed"]
+            }
+            Some("miro.com") => {
+                https
+                    && self.enabled(EmbedProvider::Miro)
+                    && matches!(segments.as_slice(), ["app", "live-embed", id] if is_miro_id(id))
+            }
+            _ => self.is_grafana_snapshot(&url),
+        }
+    }
+
+    fn is_grafana_snapshot(&self, url: &Url) -> bool {
+        self.enabled(EmbedProvider::Grafana)
+            && self.is_grafana_host(url)
+            && url.path().starts_with("/dashboard/snapshot/")
+    }
+
+    fn is_grafana_host(&self, url: &Url) -> bool {
+        if !matches!(url.scheme(), "http" | "https") {
+            return false;
+        }
+        let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+            return false;
+        };
+        let host = host.to_ascii_lowercase();
+        self.grafana_hosts
+            .iter()
+            .any(|entry| *entry == host || *entry == format!("{host}:{port}"))
+    }
+
+    /// The proxied image URL of a link to a panel or dashboard of one of
+    /// the allow-listed dashboards.
+    fn grafana_proxy_src(&self, url: &Url) -> Option<String> {
+        if !self.enabled(EmbedProvider::GrafanaProxy) || !self.is_grafana_host(url) {
+            return None;
+        }
+        let render = GrafanaRender::parse(url.path(), url.query())?;
+        self.grafana_dashboards
+            .contains(&render.uid)
+            .then(|| render.proxy_src())
+    }
+
+    fn embed_html(provider: EmbedProvider, src: &str, link: &str) -> String {
+        if provider == EmbedProvider::GrafanaProxy {
+            return format!(
+                "<div class=\"embed embed-{name}\"><a href=\"{link}\">\
+                 <img src=\"{src}\" alt=\"{title}\"></a>\
+                 <a class=\"embed-link\" href=\"{link}\">{link}</a></div>",
+                name = provider.name(),
+                src = escape_html(src),
+                title = provider.title(),
+                link = escape_html(link),
+            );
+        }
+        format!(
+            "<div class=\"embed embed-{name}\"><div class=\"embed-frame\">\
+             <iframe src=\"{src}\" title=\"{title}\" loading=\"lazy\" allowfullscreen></iframe>\
+             </div><a class=\"embed-link\" href=\"{link}\">{link}</a></div>",
+            name = provider.name(),
+            src = escape_html(src),
+            title = provider.title(),
+            link = escape_html(link),
+        )
+    }
+
+    /// Replace paragraphs made of a single embeddable link with its widget.
+    fn embed_links<'a>(&self, events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+        let mut out = Vec::with_capacity(events.len());
+        let mut paragraph: Option<Vec<Event<'a>>> = None;
+        for event in events {
+            match event {
+                Event::Start(Tag::Paragraph) => {
+                    if let Some(buffered) = paragraph.replace(vec![event]) {
+                        out.extend(buffered);
+                    }
+                }
+                Event::End(TagEnd::Paragraph) => match paragraph.take() {
+                    Some(mut buffered) => {
+                        let embed = bare_link(&buffered[1..]).and_then(|link| {
+                            let (provider, src) = self.embed_src(&link)?;
+                            Some(Self::embed_html(provider, &src, &link))
+                        });
+                        match embed {
+                            Some(html) => out.push(Event::Html(html.into())),
+                            None => {
+                                buffered.push(event);
+                                out.extend(buffered);
+                            }
+                        }
+                    }
+                    None => out.push(event),
+                },
+                other => match paragraph.as_mut() {
+                    Some(buffered) => buffered.push(other),
+                    None => out.push(other),
+                },
+            }
+        }
+        if let Some(buffered) = paragraph {
+            out.extend(buffered);
+        }
+        out
+    }
+}
+
+/// Replaces paragraphs made of a single embeddable link with the widget of
+/// [`RenderContext::embeds`]; an [`EventTransform`] named `"embeds"`.
+pub struct EmbedLinks;
+
+impl EventTransform for EmbedLinks {
+    fn name(&self) -> &str {
+        "embeds"
+    }
+
+    fn transform<'a>(&self, events: Vec<Event<'a>>, ctx: &RenderContext<'_>) -> Vec<Event<'a>> {
+        match ctx.embeds {
+            Some(embeds) => embeds.embed_links(events),
+            None => events,
+        }
+    }
+}
+
+/// The URL a paragraph consists of, either as plain text (possibly split
+/// over several text events) or as a single autolink.
+fn bare_link(events: &[Event<'_>]) -> Option<String> {
+    match events {
+        [Event::Start(Tag::Link {
+            link_type: LinkType::Autolink,
+            dest_url,
+            ..
+        }), inner @ .., Event::End(TagEnd::Link)]
+            if inner.iter().all(|e| matches!(e, Event::Text(_))) =>
+        {
+            Some(dest_url.to_string())
+        }
+        [] => None,
+        _ => {
+            let mut text = String::new();
+            for event in events {
+                match event {
+                    Event::Text(t) => text.push_str(t),
+                    _ => return None,
+                }
+            }
+            let text = text.trim();
+            (text.starts_with("https://") || text.starts_with("http://")).then(|| text.to_string())
+        }
+    }
+}
+
+fn is_youtube_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= 16
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Grafana UIDs and dashboard slugs: letters, digits, `-` and `_`.
+fn is_grafana_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= 64
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn is_miro_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '=' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::pipeline::RenderPipeline;
+
+    fn embeds() -> Embeds {
+        Embeds::from_settings(&EmbedSettings {
+            providers: vec![
+                "youtube".into(),
+                "figma".into(),
+                "miro".into(),
+                "grafana".into(),
+            ],
+            grafana_hosts: vec!["grafana.internal".into()],
+        })
+    }
+
+    fn render_with(raw: &str, embeds: &Embeds) -> String {
+        RenderPipeline::standard().render(
+            raw,
+            &RenderContext {
+                embeds: Some(embeds),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn render(raw: &str) -> String {
+        render_with(raw, &embeds())
+    }
+
+    #[test]
+    fn test_embed_src_per_provider() {
+        let embeds = Embeds::new(
+            vec![
+                EmbedProvider::YouTube,
+                EmbedProvider::Figma,
+                EmbedProvider::Miro,
+            ],
+            vec![],
+        );
+        for link in [
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42",
+            "https://youtu.be/dQw4w9WgXcQ",
+        ] {
+            assert_eq!(
+                embeds.embed_src(link).unwrap().1,
+                "https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ"
+            );
+        }
+        let (provider, src) = embeds
+            .embed_src("https://www.figma.com/design/AbC123/Checkout?node-id=1-2")
+            .unwrap();
+        assert_eq!(provider, EmbedProvider::Figma);
+        assert!(src.starts_with("https://www.figma.com/embed?embed_host=lekton&url=https%3A%2F%2F"));
+        assert_eq!(
+            embeds
+                .embed_src("https://miro.com/app/board/uXjVK1a2b3c=/")
+                .unwrap()
+                .1,
+            "https://miro.com/app/live-embed/uXjVK1a2b3c=/"
+        );
+
+        // Disabled providers and unrelated pages are not embedded
+        assert!(embeds
+            .embed_src("https://grafana.internal/dashboard/snapshot/abc")
+            .is_none());
+        assert!(embeds.embed_src("https://www.youtube.com/feed").is_none());
+        assert!(embeds.embed_src("https://www.figma.com/pricing").is_none());
+    }
+
+    #[test]
+    fn test_bare_links_become_iframes() {
+        let html = render("Demo:\n\nhttps://youtu.be/dQw4w9WgXcQ\n\n<https://grafana.internal/dashboard/snapshot/k3y>");
+        assert!(html.contains(
+            "<iframe src=\"https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ\" title=\"YouTube video\""
+        ));
+        assert!(html.contains(
+            "<iframe src=\"https://grafana.internal/dashboard/snapshot/k3y\" title=\"Grafana snapshot\""
+        ));
+        assert!(html.contains("<p>Demo:</p>"));
+
+        // Links in running text stay links
+        let html = render("Watch https://youtu.be/dQw4w9WgXcQ first.");
+        assert!(!html.contains("<iframe"));
+    }
+
+    #[test]
+    fn test_grafana_render_keeps_allowed_params() {
+        let render = GrafanaRender::parse(
+            "/grafana/d-solo/abc/payments",
+            Some("orgId=1&panelId=2&var-sql=drop&from=now-6h&width=800&height=300"),
+        )
+        .unwrap();
+        assert_eq!(render.uid, "abc");
+        assert_eq!(render.path, "d-solo/abc/payments");
+        assert_eq!(
+            render.proxy_src(),
+            "/grafana/render/d-solo/abc/payments?orgId=1&panelId=2&from=now-6h&width=800&height=300"
+        );
+
+        assert_eq!(
+            GrafanaRender::parse("/d/abc", None).unwrap().query,
+            "width=1000&height=500"
+        );
+        assert!(GrafanaRender::parse("/api/ds/query", None).is_none());
+        assert!(GrafanaRender::parse("/d/abc/payments/../../api", None).is_none());
+        assert!(GrafanaRender::parse("/d/a%2Fb", None).is_none());
+    }
+
+    #[test]
+    fn test_grafana_dashboards_embedded_through_proxy() {
+        let embeds = Embeds::new(
+            vec![EmbedProvider::Grafana],
+            vec!["grafana.internal".into()],
+        );
+        let link = "https://grafana.internal/grafana/d-solo/abc/payments?orgId=1&panelId=2";
+        assert!(embeds.embed_src(link).is_none());
+
+        let embeds = embeds.with_grafana_proxy(vec!["abc".into()]);
+        assert_eq!(
+            embeds.embed_src(link),
+            Some((
+                EmbedProvider::GrafanaProxy,
+                "/grafana/render/d-solo/abc/payments?orgId=1&panelId=2&width=1000&height=500"
+                    .to_string()
+            ))
+        );
+        // Dashboards outside the allow-list and other hosts stay links
+        assert!(embeds
+            .embed_src("https://grafana.internal/d/other/billing")
+            .is_none());
+        assert!(embeds
+            .embed_src("https://grafana.example.com/grafana/d/abc")
+            .is_none());
+
+        // Rendered as an image, never framed from the portal origin
+        let html = render_with(link, &embeds);
+        assert!(html.contains("<img src=\"/grafana/render/d-solo/abc/payments?"));
+        assert!(!html.contains("<iframe"));
+        assert!(!Embeds::new(vec![EmbedProvider::Grafana], vec![])
+            .with_grafana_proxy(vec!["abc".into()])
+            .is_embed_src("/grafana/render/d-solo/abc/payments"));
+    }
+
+    #[test]
+    fn test_raw_iframes_limited_to_embed_urls() {
+        let html = render("<iframe src=\"https://evil.example.com/\"></iframe>");
+        assert!(!html.contains("evil.example.com"));
+
+        let html =
+            render("<iframe src=\"https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ\"></iframe>");
+        assert!(html.contains("src=\"https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ\""));
+
+        // Without embeds iframes are stripped entirely
+        let html = RenderPipeline::standard().render(
+            "<iframe src=\"https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ\"></iframe>",
+            &RenderContext::default(),
+        );
+        assert!(!html.contains("<iframe"));
+    }
+
+    #[test]
+    fn test_disabled_providers_follow_the_settings() {
+        let embeds = Embeds::from_settings(&EmbedSettings {
+            providers: vec!["figma".into()],
+            grafana_hosts: vec![],
+        });
+        let html = render_with("https://youtu.be/dQw4w9WgXcQ", &embeds);
+        assert!(!html.contains("<iframe"));
+        let html = render_with(
+            "<iframe src=\"https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ\"></iframe>",
+            &embeds,
+        );
+        assert!(!html.contains("youtube-nocookie"));
+
+        // Links stay links when rendering without embeds
+        let html = RenderPipeline::standard()
+            .render("https://youtu.be/dQw4w9WgXcQ", &RenderContext::default());
+        assert!(html.contains("<p>https://youtu.be/dQw4w9WgXcQ</p>"));
+    }
+}
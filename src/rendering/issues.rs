@@ -0,0 +1,282 @@
+//! Issue keys (`PROJ-123`) linked to the issue tracker, with status badges.
+//!
+//! Only keys of the configured tracker projects are linked, so `UTF-8` or
+//! `SHA-256` stay text. Keys inside code, links and headings are left
+//! alone. Statuses are looked up by the server before rendering
+//! (see [`crate::issue_tracker`]); keys without one get a plain link.
+
+use std::collections::HashMap;
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+use crate::rendering::markdown::escape_html;
+use crate::rendering::pipeline::{EventTransform, RenderContext};
+
+/// Where an issue is in its workflow, which picks the badge colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueState {
+    Open,
+    InProgress,
+    Done,
+}
+
+/// Current status of an issue in the tracker.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueStatus {
+    pub summary: String,
+    /// Status name in the tracker's workflow (e.g. "In Review").
+    pub status: String,
+    pub state: IssueState,
+}
+
+/// Issue keys to link while rendering.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueLinks {
+    /// Link to an issue, with `{key}` standing for the issue key.
+    pub url_template: String,
+    /// Project keys to recognize, uppercase.
+    pub projects: Vec<String>,
+    /// Statuses of the keys known to the tracker.
+    pub statuses: HashMap<String, IssueStatus>,
+}
+
+/// Links issue keys (see [`link_issue_keys`]).
+pub struct IssueKeys;
+
+impl EventTransform for IssueKeys {
+    fn name(&self) -> &str {
+        "issue-keys"
+    }
+
+    fn transform<'a>(&self, events: Vec<Event<'a>>, ctx: &RenderContext<'_>) -> Vec<Event<'a>> {
+        match ctx.issues {
+            Some(links) => link_issue_keys(events, links),
+            None => events,
+        }
+    }
+}
+
+/// Replace the issue keys in text outside code, links and headings with
+/// links to the tracker.
+fn link_issue_keys<'a>(events: Vec<Event<'a>>, links: &IssueLinks) -> Vec<Event<'a>> {
+    if links.projects.is_empty() {
+        return events;
+    }
+    let mut skip_depth = 0usize;
+    let mut out = Vec::with_capacity(events.len());
+    for event in events {
+        match &event {
+            Event::Start(
+                Tag::Link { .. } | Tag::Image { .. } | Tag::Heading { .. } | Tag::CodeBlock(_),
+            ) => skip_depth += 1,
+            Event::End(TagEnd::Link | TagEnd::Image | TagEnd::Heading(_) | TagEnd::CodeBlock) => {
+                skip_depth = skip_depth.saturating_sub(1)
+            }
+            Event::Text(text) if skip_depth == 0 => {
+                let mut rest = 0;
+                for (start, end) in find_issue_keys(text, &links.projects) {
+                    if start > rest {
+                        out.push(Event::Text(text[rest..start].to_string().into()));
+                    }
+                    // One event, so later transforms don't annotate the key
+                    out.push(Event::InlineHtml(
+                        issue_link_html(&text[start..end], links).into(),
+                    ));
+                    rest = end;
+                }
+                if rest > 0 {
+                    if rest < text.len() {
+                        out.push(Event::Text(text[rest..].to_string().into()));
+                    }
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        out.push(event);
+    }
+    out
+}
+
+/// Escape plain text (e.g. a comment) as HTML, linking its issue keys.
+pub fn linkify_issue_keys(text: &str, links: &IssueLinks) -> String {
+    let mut html = String::with_capacity(text.len());
+    let mut rest = 0;
+    for (start, end) in find_issue_keys(text, &links.projects) {
+        html.push_str(&escape_html(&text[rest..start]));
+        html.push_str(&issue_link_html(&text[start..end], links));
+        rest = end;
+    }
+    html.push_str(&escape_html(&text[rest..]));
+    html
+}
+
+/// Distinct issue keys of `projects` in `markdown`, where they are linked.
+pub fn extract_issue_keys(markdown: &str, projects: &[String]) -> Vec<String> {
+    let mut keys = Vec::new();
+    if projects.is_empty() {
+        return keys;
+    }
+    let mut skip_depth = 0usize;
+    for event in Parser::new_ext(markdown, Options::empty()) {
+        match event {
+            Event::Start(
+                Tag::Link { .. } | Tag::Image { .. } | Tag::Heading { .. } | Tag::CodeBlock(_),
+            ) => skip_depth += 1,
+            Event::End(TagEnd::Link | TagEnd::Image | TagEnd::Heading(_) | TagEnd::CodeBlock) => {
+                skip_depth = skip_depth.saturating_sub(1)
+            }
+            Event::Text(text) if skip_depth == 0 => {
+                push_keys(&text, projects, &mut keys);
+            }
+            _ => {}
+        }
+    }
+    keys
+}
+
+/// Distinct issue keys of `projects` in plain text.
+pub fn extract_text_issue_keys(text: &str, projects: &[String]) -> Vec<String> {
+    let mut keys = Vec::new();
+    push_keys(text, projects, &mut keys);
+    keys
+}
+
+fn push_keys(text: &str, projects: &[String], keys: &mut Vec<String>) {
+    for (start, end) in find_issue_keys(text, projects) {
+        let key = &text[start..end];
+        if !keys.iter().any(|k| k == key) {
+            keys.push(key.to_string());
+        }
+    }
+}
+
+/// Byte ranges of the issue keys of `projects` in `text`: a project key,
+/// a dash and a number, not part of a longer word.
+fn find_issue_keys(text: &str, projects: &[String]) -> Vec<(usize, usize)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let bytes = text.as_bytes();
+    let mut ranges = Vec::new();
+    let mut previous: Option<char> = None;
+    for (start, c) in text.char_indices() {
+        let at_boundary = !previous.is_some_and(|p| is_word(p) || p == '-');
+        previous = Some(c);
+        if !at_boundary || !c.is_ascii_uppercase() {
+            continue;
+        }
+        let Some(dash) = text[start..]
+            .find(|c: char| !(c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'))
+            .map(|len| start + len)
+            .filter(|&dash| bytes[dash] == b'-')
+        else {
+            continue;
+        };
+        let digits = text[dash + 1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len() - dash - 1);
+        let end = dash + 1 + digits;
+        if digits == 0
+            || text[end..].starts_with(is_word)
+            || !projects.iter().any(|p| *p == text[start..dash])
+        {
+            continue;
+        }
+        ranges.push((start, end));
+    }
+    ranges
+}
+
+/// Link to the issue `key`, followed by its status badge when known.
+fn issue_link_html(key: &str, links: &IssueLinks) -> String {
+    let key_html = escape_html(key);
+    let href = escape_html(&links.url_template.replace("{key}", key));
+    match links.statuses.get(key) {
+        Some(status) => {
+            let badge = match status.state {
+                IssueState::Open => "badge-ghost",
+                IssueState::InProgress => "badge-info",
+                IssueState::Done => "badge-success",
+            };
+            format!(
+                "<a class=\"issue-link\" href=\"{href}\" title=\"{}\">{key_html}</a> <span class=\"issue-status badge badge-sm {badge}\">{}</span>",
+                escape_html(&status.summary),
+                escape_html(&status.status)
+            )
+        }
+        None => format!("<a class=\"issue-link\" href=\"{href}\">{key_html}</a>"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::pipeline::RenderPipeline;
+
+    fn links() -> IssueLinks {
+        IssueLinks {
+            url_template: "https://jira.acme.com/browse/{key}".into(),
+            projects: vec!["PAY".into(), "OPS".into()],
+            statuses: HashMap::from([(
+                "PAY-42".to_string(),
+                IssueStatus {
+                    summary: "Retry failed payouts".into(),
+                    status: "In Review".into(),
+                    state: IssueState::InProgress,
+                },
+            )]),
+        }
+    }
+
+    #[test]
+    fn test_find_issue_keys_of_configured_projects_only() {
+        let projects = links().projects;
+        let text = "PAY-42, OPS-7. UTF-8 XPAY-1 PAY-42a PAY- ABC-PAY-3 (OPS-12)";
+        let keys: Vec<&str> = find_issue_keys(text, &projects)
+            .into_iter()
+            .map(|(start, end)| &text[start..end])
+            .collect();
+        assert_eq!(keys, ["PAY-42", "OPS-7", "OPS-12"]);
+    }
+
+    #[test]
+    fn test_issue_keys_linked_outside_code_and_links() {
+        let links = links();
+        let ctx = RenderContext {
+            issues: Some(&links),
+            ..Default::default()
+        };
+        let html = RenderPipeline::standard().render(
+            "Fixed in PAY-42 and OPS-7, not `PAY-1` or [PAY-2](https://x.test).",
+            &ctx,
+        );
+        assert!(html.contains(
+            "<a class=\"issue-link\" href=\"https://jira.acme.com/browse/PAY-42\" title=\"Retry failed payouts\" rel=\"noopener noreferrer\">PAY-42</a> <span class=\"issue-status badge badge-sm badge-info\">In Review</span>"
+        ));
+        assert!(html.contains(
+            "<a class=\"issue-link\" href=\"https://jira.acme.com/browse/OPS-7\" rel=\"noopener noreferrer\">OPS-7</a>,"
+        ));
+        assert!(html.contains("<code>PAY-1</code>"));
+        assert!(!html.contains("browse/PAY-2"));
+
+        let plain = RenderPipeline::standard().render("Fixed in PAY-42", &Default::default());
+        assert_eq!(plain.trim(), "<p>Fixed in PAY-42</p>");
+    }
+
+    #[test]
+    fn test_extract_and_linkify_issue_keys() {
+        let links = links();
+        assert_eq!(
+            extract_issue_keys(
+                "PAY-42 again PAY-42, [OPS-1](https://x.test)\n\n```\nOPS-2\n```\nOPS-3",
+                &links.projects
+            ),
+            ["PAY-42", "OPS-3"]
+        );
+        assert_eq!(
+            linkify_issue_keys("<b>OPS-7</b>", &links),
+            "&lt;b&gt;<a class=\"issue-link\" href=\"https://jira.acme.com/browse/OPS-7\">OPS-7</a>&lt;/b&gt;"
+        );
+    }
+}
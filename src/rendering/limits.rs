@@ -0,0 +1,98 @@
+//! Size and content-type guards applied before a stored document is rendered.
+//!
+//! Content larger than `rendering.max_render_bytes` is rendered as a preview
+//! cut at a line boundary, with the full text left to the raw download.
+//! Content that is not UTF-8 text is rejected with an explanation instead of
+//! a decoder error.
+
+/// Default render limit: 1 MiB of Markdown.
+pub const DEFAULT_MAX_RENDER_BYTES: usize = 1024 * 1024;
+
+/// Bytes inspected for NUL characters when telling binary files from text.
+const BINARY_SNIFF_BYTES: usize = 8 * 1024;
+
+/// Why stored content cannot be rendered as Markdown.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ContentError {
+    #[error("the stored content is a binary file, not Markdown text")]
+    Binary,
+    #[error("the stored content is not valid UTF-8 text (invalid byte at offset {offset})")]
+    InvalidUtf8 { offset: usize },
+}
+
+/// Check that stored document content is text that can be rendered.
+pub fn check_text(bytes: &[u8]) -> Result<(), ContentError> {
+    if bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+        return Err(ContentError::Binary);
+    }
+    std::str::from_utf8(bytes)
+        .map(|_| ())
+        .map_err(|e| ContentError::InvalidUtf8 {
+            offset: e.valid_up_to(),
+        })
+}
+
+/// Decode stored document content as Markdown text.
+pub fn decode_text(bytes: Vec<u8>) -> Result<String, ContentError> {
+    check_text(&bytes)?;
+    String::from_utf8(bytes).map_err(|e| ContentError::InvalidUtf8 {
+        offset: e.utf8_error().valid_up_to(),
+    })
+}
+
+/// The part of `text` to render when it exceeds `max_bytes`: everything up
+/// to the last line break within the limit (or the last character boundary
+/// when a single line is longer). `None` when `text` fits or `max_bytes` is 0.
+pub fn preview(text: &str, max_bytes: usize) -> Option<&str> {
+    if max_bytes == 0 || text.len() <= max_bytes {
+        return None;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let head = &text[..end];
+    Some(match head.rfind('\n') {
+        Some(newline) if newline > 0 => &head[..newline],
+        _ => head,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_utf8_text() {
+        assert_eq!(
+            decode_text("# Città".as_bytes().to_vec()).unwrap(),
+            "# Città"
+        );
+    }
+
+    #[test]
+    fn rejects_binary_and_invalid_utf8() {
+        assert_eq!(
+            decode_text(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec()),
+            Err(ContentError::Binary)
+        );
+        assert_eq!(
+            decode_text(b"# Title\n\xff\xfe".to_vec()),
+            Err(ContentError::InvalidUtf8 { offset: 8 })
+        );
+    }
+
+    #[test]
+    fn preview_cuts_at_the_last_line_within_the_limit() {
+        let text = "line one\nline two\nline three\n";
+        assert_eq!(preview(text, 100), None);
+        assert_eq!(preview(text, 0), None);
+        assert_eq!(preview(text, 20), Some("line one\nline two"));
+    }
+
+    #[test]
+    fn preview_respects_char_boundaries_on_long_lines() {
+        // 'à' is two bytes; a limit of 3 falls inside the second one
+        assert_eq!(preview("ààà", 3), Some("à"));
+    }
+}
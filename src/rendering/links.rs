@@ -89,7 +89,7 @@ pub struct LinkValidationResult {
 
 /// Validate all internal links in markdown content against the document repository.
 ///
-/// Extracts internal links, checks each against the repository,
+/// Extracts internal links, looks them all up in one repository query,
 /// and returns categorized results.
 #[cfg(feature = "ssr")]
 pub async fn validate_links(
@@ -97,15 +97,16 @@ pub async fn validate_links(
     repo: &dyn crate::db::repository::DocumentRepository,
 ) -> Result<LinkValidationResult, crate::error::AppError> {
     let all_links = extract_internal_links(markdown);
-    let mut broken_links = Vec::new();
-    let mut valid_links = Vec::new();
-
-    for link in &all_links {
-        match repo.find_by_slug(link).await? {
-            Some(_) => valid_links.push(link.clone()),
-            None => broken_links.push(link.clone()),
-        }
-    }
+    let existing: Vec<String> = repo
+        .find_by_slugs(&all_links)
+        .await?
+        .into_iter()
+        .map(|doc| doc.slug)
+        .collect();
+    let (valid_links, broken_links): (Vec<String>, Vec<String>) = all_links
+        .iter()
+        .cloned()
+        .partition(|link| existing.contains(link));
 
     Ok(LinkValidationResult {
         all_links,
@@ -0,0 +1,269 @@
+//! Style checks on Markdown sources.
+//!
+//! [`lint_markdown`] runs the enabled [`LintRule`]s over a document and
+//! returns its findings with 1-based line numbers. Findings never block an
+//! ingest: they are returned in the `IngestResponse`, recorded on the
+//! document and listed on the admin doc quality report.
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+/// Findings reported per document at most; the rest are dropped.
+pub const MAX_FINDINGS: usize = 100;
+
+/// A style rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    /// The document has no level-1 heading.
+    MissingH1,
+    /// A heading is more than one level deeper than the one before it.
+    HeadingOrder,
+    /// A line is longer than the configured maximum (code blocks and tables
+    /// excepted).
+    LineLength,
+    /// A URL in running text that is not written as a link.
+    BareUrl,
+    /// A `TODO`, `FIXME` or `XXX` marker outside code.
+    Todo,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 5] = [
+        Self::MissingH1,
+        Self::HeadingOrder,
+        Self::LineLength,
+        Self::BareUrl,
+        Self::Todo,
+    ];
+
+    /// Parse a rule name as used in `lint.rules`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        Self::ALL.into_iter().find(|rule| rule.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MissingH1 => "missing_h1",
+            Self::HeadingOrder => "heading_order",
+            Self::LineLength => "line_length",
+            Self::BareUrl => "bare_url",
+            Self::Todo => "todo",
+        }
+    }
+}
+
+/// A problem found in a document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintFinding {
+    pub rule: LintRule,
+    /// 1-based line of the problem.
+    pub line: usize,
+    pub message: String,
+}
+
+/// Which rules to run, and their settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintOptions {
+    pub rules: Vec<LintRule>,
+    /// Longest allowed line, in characters.
+    pub max_line_length: usize,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self {
+            rules: LintRule::ALL.to_vec(),
+            max_line_length: 120,
+        }
+    }
+}
+
+const TODO_MARKERS: [&str; 3] = ["TODO", "FIXME", "XXX"];
+
+/// Check `markdown` against the enabled rules. Findings are ordered by line.
+pub fn lint_markdown(markdown: &str, options: &LintOptions) -> Vec<LintFinding> {
+    let enabled = |rule| options.rules.contains(&rule);
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(markdown.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset);
+
+    let mut findings = Vec::new();
+    // Byte ranges of code blocks and tables, exempt from `line_length`
+    let mut exempt = Vec::new();
+    let mut has_h1 = false;
+    let mut previous_level = None;
+    let mut in_link = 0usize;
+    let mut in_code_block = false;
+
+    let parser_options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+    for (event, range) in Parser::new_ext(markdown, parser_options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                let level = level as usize;
+                has_h1 |= level == 1;
+                if enabled(LintRule::HeadingOrder) {
+                    if let Some(previous) = previous_level.filter(|&p| level > p + 1) {
+                        findings.push(LintFinding {
+                            rule: LintRule::HeadingOrder,
+                            line: line_of(range.start),
+                            message: format!("Heading level {level} follows level {previous}"),
+                        });
+                    }
+                }
+                previous_level = Some(level);
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                in_code_block = true;
+                exempt.push(range);
+            }
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Start(Tag::Table(_)) => exempt.push(range),
+            Event::Start(Tag::Link { .. }) => in_link += 1,
+            Event::End(TagEnd::Link) => in_link = in_link.saturating_sub(1),
+            Event::Text(text) if !in_code_block => {
+                let line = line_of(range.start);
+                if enabled(LintRule::BareUrl) && in_link == 0 {
+                    if let Some(url) = text
+                        .split_whitespace()
+                        .find(|word| word.starts_with("http://") || word.starts_with("https://"))
+                    {
+                        findings.push(LintFinding {
+                            rule: LintRule::BareUrl,
+                            line,
+                            message: format!("Bare URL {url}; write it as a link"),
+                        });
+                    }
+                }
+                if enabled(LintRule::Todo) {
+                    push_todo(&mut findings, &text, line);
+                }
+            }
+            Event::Html(html) | Event::InlineHtml(html) if enabled(LintRule::Todo) => {
+                push_todo(&mut findings, &html, line_of(range.start));
+            }
+            _ => {}
+        }
+    }
+
+    if enabled(LintRule::MissingH1) && !has_h1 {
+        findings.push(LintFinding {
+            rule: LintRule::MissingH1,
+            line: 1,
+            message: "The document has no level-1 heading".to_string(),
+        });
+    }
+
+    if enabled(LintRule::LineLength) && options.max_line_length > 0 {
+        for (index, start) in line_starts.iter().enumerate() {
+            if exempt.iter().any(|range| range.contains(start)) {
+                continue;
+            }
+            let end = line_starts
+                .get(index + 1)
+                .map_or(markdown.len(), |&next| next - 1);
+            let length = markdown[*start..end].trim_end_matches('\r').chars().count();
+            if length > options.max_line_length {
+                findings.push(LintFinding {
+                    rule: LintRule::LineLength,
+                    line: index + 1,
+                    message: format!(
+                        "Line is {length} characters long (maximum {})",
+                        options.max_line_length
+                    ),
+                });
+            }
+        }
+    }
+
+    findings.sort_by_key(|finding| finding.line);
+    findings.truncate(MAX_FINDINGS);
+    findings
+}
+
+fn push_todo(findings: &mut Vec<LintFinding>, text: &str, line: usize) {
+    let marker = text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .find(|word| TODO_MARKERS.contains(word));
+    if let Some(marker) = marker {
+        findings.push(LintFinding {
+            rule: LintRule::Todo,
+            line,
+            message: format!("{marker} marker left in the text"),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(markdown: &str) -> Vec<(LintRule, usize)> {
+        lint_markdown(markdown, &LintOptions::default())
+            .into_iter()
+            .map(|finding| (finding.rule, finding.line))
+            .collect()
+    }
+
+    #[test]
+    fn clean_document_has_no_findings() {
+        let markdown =
+            "# Guide\n\nSee [the docs](https://example.com).\n\n## Setup\n\n### Step one\n";
+        assert!(rules(markdown).is_empty());
+    }
+
+    #[test]
+    fn reports_missing_h1_and_skipped_heading_levels() {
+        assert_eq!(
+            rules("## Setup\n\n#### Details\n"),
+            vec![(LintRule::MissingH1, 1), (LintRule::HeadingOrder, 3)]
+        );
+    }
+
+    #[test]
+    fn reports_bare_urls_but_not_links_or_code() {
+        let markdown = "# Guide\n\nOpen https://example.com now.\n\n<https://example.com>\n\n`https://example.com`\n\n```\nhttps://example.com\n```\n";
+        assert_eq!(rules(markdown), vec![(LintRule::BareUrl, 3)]);
+    }
+
+    #[test]
+    fn reports_todo_markers_outside_code() {
+        let markdown = "# Guide\n\nTODO: explain rollbacks.\n\n<!-- FIXME later -->\n\n```\n// TODO in code\n```\n\nTodos are fine.\n";
+        assert_eq!(
+            rules(markdown),
+            vec![(LintRule::Todo, 3), (LintRule::Todo, 5)]
+        );
+    }
+
+    #[test]
+    fn reports_long_lines_outside_code_blocks_and_tables() {
+        let long = "word ".repeat(30);
+        let markdown = format!(
+            "# Guide\n\n{long}\n\n```\n{long}\n```\n\n| a | b |\n|---|---|\n| {long} | x |\n"
+        );
+        assert_eq!(rules(&markdown), vec![(LintRule::LineLength, 3)]);
+    }
+
+    #[test]
+    fn runs_only_enabled_rules() {
+        let options = LintOptions {
+            rules: vec![LintRule::Todo],
+            max_line_length: 120,
+        };
+        let findings = lint_markdown("## TODO\n", &options);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, LintRule::Todo);
+    }
+
+    #[test]
+    fn parses_rule_names() {
+        assert_eq!(LintRule::from_name("bare_url"), Some(LintRule::BareUrl));
+        assert_eq!(LintRule::from_name(" TODO "), Some(LintRule::Todo));
+        assert_eq!(LintRule::from_name("spelling"), None);
+    }
+}
@@ -1,7 +1,8 @@
-use ammonia::Builder;
-use pulldown_cmark::{html, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 use serde::{Deserialize, Serialize};
 
+use crate::rendering::pipeline::{EventTransform, HtmlTransform, RenderContext, RenderPipeline};
+
 /// Represents a heading in the document for table of contents.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TocHeading {
@@ -13,75 +14,330 @@ pub struct TocHeading {
     pub id: String,
 }
 
+/// A glossary term to annotate in rendered documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlossaryTooltip {
+    pub term: String,
+    pub definition: String,
+    /// Anchor of the term on the glossary page (`/glossary#<anchor>`).
+    pub anchor: String,
+}
+
 /// Render a raw Markdown string to sanitized HTML with heading anchor IDs.
 ///
 /// Supports GitHub Flavored Markdown (GFM) features: tables,
 /// footnotes, strikethrough, task lists, and smart punctuation.
 /// Automatically adds IDs to h2-h6 headings for anchor navigation.
+/// Consecutive fenced code blocks tagged `tab=<label>` are grouped into a
+/// tabbed widget (see [`group_code_tabs`]).
+///
+/// Rendering goes through [`RenderPipeline::standard`]; server views of
+/// stored documents use the deployment's pipeline instead
+/// ([`AppState::render_pipeline`](crate::app::AppState::render_pipeline)).
 pub fn render_markdown(raw: &str) -> String {
-    let options = Options::ENABLE_TABLES
-        | Options::ENABLE_FOOTNOTES
-        | Options::ENABLE_STRIKETHROUGH
-        | Options::ENABLE_TASKLISTS
-        | Options::ENABLE_SMART_PUNCTUATION
-        | Options::ENABLE_HEADING_ATTRIBUTES;
+    render_markdown_with_glossary(raw, &[])
+}
 
-    let parser = Parser::new_ext(raw, options);
+/// Like [`render_markdown`], additionally wrapping the first occurrence of
+/// each glossary term in a tooltip link to its definition
+/// (see [`annotate_glossary_terms`]).
+pub fn render_markdown_with_glossary(raw: &str, glossary: &[GlossaryTooltip]) -> String {
+    RenderPipeline::standard().render(
+        raw,
+        &RenderContext {
+            glossary,
+            ..Default::default()
+        },
+    )
+}
+
+/// Renders fenced `mermaid` blocks as `<pre class="mermaid">` for the
+/// client-side diagram renderer.
+pub struct MermaidBlocks;
 
-    let mut in_mermaid = false;
-    let transformed = parser.flat_map(|event| -> Vec<Event<'_>> {
-        if in_mermaid {
-            match event {
-                Event::End(TagEnd::CodeBlock) => {
-                    in_mermaid = false;
-                    vec![Event::Html("</pre>".into())]
+impl EventTransform for MermaidBlocks {
+    fn name(&self) -> &str {
+        "mermaid"
+    }
+
+    fn transform<'a>(&self, events: Vec<Event<'a>>, _ctx: &RenderContext<'_>) -> Vec<Event<'a>> {
+        let mut in_mermaid = false;
+        events
+            .into_iter()
+            .flat_map(|event| -> Vec<Event<'a>> {
+                if in_mermaid {
+                    match event {
+                        Event::End(TagEnd::CodeBlock) => {
+                            in_mermaid = false;
+                            vec![Event::Html("</pre>".into())]
+                        }
+                        Event::Text(text) => vec![Event::Html(escape_html(&text).into())],
+                        _ => vec![],
+                    }
+                } else {
+                    match event {
+                        Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(ref lang)))
+                            if lang.as_ref() == "mermaid" =>
+                        {
+                            in_mermaid = true;
+                            vec![Event::Html("<pre class=\"mermaid\">".into())]
+                        }
+                        other => vec![other],
+                    }
                 }
-                Event::Text(text) => vec![Event::Html(escape_html(&text).into())],
-                _ => vec![],
+            })
+            .collect()
+    }
+}
+
+/// Annotates the glossary terms of the [`RenderContext`]
+/// (see [`annotate_glossary_terms`]).
+pub struct GlossaryTerms;
+
+impl EventTransform for GlossaryTerms {
+    fn name(&self) -> &str {
+        "glossary"
+    }
+
+    fn transform<'a>(&self, events: Vec<Event<'a>>, ctx: &RenderContext<'_>) -> Vec<Event<'a>> {
+        annotate_glossary_terms(events, ctx.glossary)
+    }
+}
+
+/// Groups `tab=`-tagged code blocks (see [`group_code_tabs`]).
+pub struct CodeTabs;
+
+impl EventTransform for CodeTabs {
+    fn name(&self) -> &str {
+        "code-tabs"
+    }
+
+    fn transform<'a>(&self, events: Vec<Event<'a>>, _ctx: &RenderContext<'_>) -> Vec<Event<'a>> {
+        group_code_tabs(events)
+    }
+}
+
+/// Adds slug IDs to h2-h6 headings for anchor navigation.
+pub struct HeadingIds;
+
+impl HtmlTransform for HeadingIds {
+    fn name(&self) -> &str {
+        "heading-ids"
+    }
+
+    fn transform(&self, html: String, _ctx: &RenderContext<'_>) -> String {
+        add_heading_ids_simple(&html)
+    }
+}
+
+/// Split a fenced code block info string such as `bash tab=curl` or
+/// `python tab="Python 3"` into the language part and the tab label.
+///
+/// Returns `None` when the block has no (non-empty) `tab=` attribute.
+fn parse_tab_info(info: &str) -> Option<(String, String)> {
+    let idx = info.find("tab=")?;
+    if idx > 0 && !info[..idx].ends_with(char::is_whitespace) {
+        return None;
+    }
+    let rest = &info[idx + "tab=".len()..];
+    let (label, after) = match rest.strip_prefix('"') {
+        Some(quoted) => {
+            let end = quoted.find('"')?;
+            (&quoted[..end], &quoted[end + 1..])
+        }
+        None => {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            (&rest[..end], &rest[end..])
+        }
+    };
+    if label.trim().is_empty() {
+        return None;
+    }
+    let lang = format!("{} {}", info[..idx].trim(), after.trim())
+        .trim()
+        .to_string();
+    Some((lang, label.trim().to_string()))
+}
+
+fn tab_info(event: &Event<'_>) -> Option<(String, String)> {
+    match event {
+        Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => parse_tab_info(info),
+        _ => None,
+    }
+}
+
+/// Group runs of consecutive `tab=`-tagged fenced code blocks into a tabbed
+/// widget. The first tab is active by default; switching is handled by
+/// `public/js/doc-enhancements.js`. A lone tagged block still renders as a
+/// single-tab group so that authors see the label they wrote.
+fn group_code_tabs(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    // Pre-compute the labels of each group so the tab bar can be emitted
+    // before the panels.
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    let mut in_group = false;
+    let mut in_block = false;
+    for event in &events {
+        if let Some((_, label)) = tab_info(event) {
+            if !in_group {
+                groups.push(Vec::new());
+                in_group = true;
             }
-        } else {
-            match event {
-                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(ref lang)))
-                    if lang.as_ref() == "mermaid" =>
-                {
-                    in_mermaid = true;
-                    vec![Event::Html("<pre class=\"mermaid\">".into())]
+            groups.last_mut().unwrap().push(label);
+            in_block = true;
+        } else if matches!(event, Event::End(TagEnd::CodeBlock)) && in_block {
+            in_block = false;
+        } else if !in_block {
+            in_group = false;
+        }
+    }
+
+    let mut out = Vec::with_capacity(events.len());
+    let mut group_idx = 0;
+    let mut tab_idx = 0;
+    let mut in_block = false;
+    let mut iter = events.into_iter().peekable();
+    while let Some(event) = iter.next() {
+        if let Some((lang, _)) = tab_info(&event) {
+            if tab_idx == 0 {
+                let mut nav =
+                    String::from("<div class=\"code-tabs\"><div class=\"code-tabs-nav\">");
+                for (i, label) in groups[group_idx].iter().enumerate() {
+                    let active = if i == 0 { " active" } else { "" };
+                    nav.push_str(&format!(
+                        "<button type=\"button\" class=\"code-tab{active}\" data-tab=\"{i}\">{}</button>",
+                        escape_html(label)
+                    ));
                 }
-                other => vec![other],
+                nav.push_str("</div>");
+                out.push(Event::Html(nav.into()));
             }
+            let active = if tab_idx == 0 { " active" } else { "" };
+            out.push(Event::Html(
+                format!("<div class=\"code-tab-panel{active}\" data-tab=\"{tab_idx}\">").into(),
+            ));
+            out.push(Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(
+                lang.into(),
+            ))));
+            in_block = true;
+            continue;
         }
-    });
 
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, transformed);
+        if in_block && matches!(event, Event::End(TagEnd::CodeBlock)) {
+            out.push(event);
+            out.push(Event::Html("</div>".into()));
+            in_block = false;
+            tab_idx += 1;
+            if iter.peek().and_then(tab_info).is_none() {
+                out.push(Event::Html("</div>".into()));
+                group_idx += 1;
+                tab_idx = 0;
+            }
+            continue;
+        }
 
-    // Post-process to add IDs to headings, then sanitize to strip any raw HTML from the source
-    sanitize_html(&add_heading_ids_simple(&html_output))
+        out.push(event);
+    }
+    out
 }
 
-/// Sanitize HTML to prevent XSS, while preserving safe GFM-generated attributes.
+/// Wrap the first occurrence of each glossary term in a link to its
+/// `/glossary` entry, with the definition as a hover tooltip.
 ///
-/// Extends ammonia's default allowlist with:
-/// - `class` on `<pre>` and `<code>` (mermaid blocks and syntax highlighting)
-/// - `id` on headings (anchor navigation)
-/// - `<input>` with `type`/`disabled`/`checked` (GFM task list checkboxes)
-fn sanitize_html(html: &str) -> String {
-    Builder::default()
-        .add_tag_attributes("pre", &["class"])
-        .add_tag_attributes("code", &["class"])
-        .add_tag_attributes("h1", &["id"])
-        .add_tag_attributes("h2", &["id"])
-        .add_tag_attributes("h3", &["id"])
-        .add_tag_attributes("h4", &["id"])
-        .add_tag_attributes("h5", &["id"])
-        .add_tag_attributes("h6", &["id"])
-        .add_tags(&["input"])
-        .add_tag_attributes("input", &["type", "disabled", "checked"])
-        .clean(html)
-        .to_string()
+/// Matching is ASCII case-insensitive on whole words, longest term first, and
+/// skips code, links, images and headings.
+fn annotate_glossary_terms<'a>(
+    events: Vec<Event<'a>>,
+    glossary: &[GlossaryTooltip],
+) -> Vec<Event<'a>> {
+    let mut pending: Vec<&GlossaryTooltip> = glossary
+        .iter()
+        .filter(|g| !g.term.trim().is_empty())
+        .collect();
+    if pending.is_empty() {
+        return events;
+    }
+    pending.sort_by_key(|g| std::cmp::Reverse(g.term.len()));
+
+    let mut skip_depth = 0usize;
+    let mut out = Vec::with_capacity(events.len());
+    for event in events {
+        match &event {
+            Event::Start(
+                Tag::Link { .. } | Tag::Image { .. } | Tag::Heading { .. } | Tag::CodeBlock(_),
+            ) => skip_depth += 1,
+            Event::End(TagEnd::Link | TagEnd::Image | TagEnd::Heading(_) | TagEnd::CodeBlock) => {
+                skip_depth = skip_depth.saturating_sub(1)
+            }
+            Event::Text(text) if skip_depth == 0 && !pending.is_empty() => {
+                annotate_text(text, &mut pending, &mut out);
+                continue;
+            }
+            _ => {}
+        }
+        out.push(event);
+    }
+    out
+}
+
+fn annotate_text(text: &str, pending: &mut Vec<&GlossaryTooltip>, out: &mut Vec<Event<'_>>) {
+    let mut rest = text;
+    loop {
+        let haystack = rest.to_ascii_lowercase();
+        // Earliest match wins; on a tie the longer term (sorted first) does
+        let found = pending
+            .iter()
+            .enumerate()
+            .filter_map(|(i, g)| {
+                find_word(&haystack, &g.term.to_ascii_lowercase()).map(|pos| (pos, i))
+            })
+            .min();
+        let Some((pos, idx)) = found else { break };
+
+        let entry = pending.remove(idx);
+        let end = pos + entry.term.len();
+        if pos > 0 {
+            out.push(Event::Text(rest[..pos].to_string().into()));
+        }
+        out.push(Event::InlineHtml(
+            format!(
+                "<a class=\"glossary-term tooltip\" data-tip=\"{}\" href=\"/glossary#{}\">",
+                escape_html(&entry.definition),
+                escape_html(&entry.anchor)
+            )
+            .into(),
+        ));
+        out.push(Event::Text(rest[pos..end].to_string().into()));
+        out.push(Event::InlineHtml("</a>".into()));
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        out.push(Event::Text(rest.to_string().into()));
+    }
+}
+
+/// Byte offset of the first whole-word occurrence of `needle` in `haystack`.
+fn find_word(haystack: &str, needle: &str) -> Option<usize> {
+    let mut from = 0;
+    while let Some(offset) = haystack[from..].find(needle) {
+        let start = from + offset;
+        let end = start + needle.len();
+        let boundary_before = haystack[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        let boundary_after = haystack[end..]
+            .chars()
+            .next()
+            .is_none_or(|c| !c.is_alphanumeric());
+        if boundary_before && boundary_after {
+            return Some(start);
+        }
+        from = start + haystack[start..].chars().next().map_or(1, char::len_utf8);
+    }
+    None
 }
 
-fn escape_html(s: &str) -> String {
+pub(crate) fn escape_html(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -357,6 +613,46 @@ cargo install lekton
         assert!(result.contains("<li>Fast</li>"));
     }
 
+    #[test]
+    fn test_parse_tab_info() {
+        assert_eq!(
+            parse_tab_info("bash tab=curl"),
+            Some(("bash".into(), "curl".into()))
+        );
+        assert_eq!(
+            parse_tab_info("python tab=\"Python 3\""),
+            Some(("python".into(), "Python 3".into()))
+        );
+        assert_eq!(parse_tab_info("rust"), None);
+        assert_eq!(parse_tab_info("rust notab=x"), None);
+        assert_eq!(parse_tab_info("rust tab="), None);
+    }
+
+    #[test]
+    fn test_code_tabs_grouped() {
+        let input = "```bash tab=curl\ncurl /api\n```\n\n```python tab=Python\nrequests.get('/api')\n```\n\nAfter.\n\n```rust\nfn main() {}\n```";
+        let result = render_markdown(input);
+        assert_eq!(result.matches("class=\"code-tabs\"").count(), 1);
+        assert!(result.contains(
+            "<button type=\"button\" class=\"code-tab active\" data-tab=\"0\">curl</button>"
+        ));
+        assert!(result
+            .contains("<button type=\"button\" class=\"code-tab\" data-tab=\"1\">Python</button>"));
+        assert!(result.contains("<div class=\"code-tab-panel active\" data-tab=\"0\">"));
+        assert!(result.contains("<code class=\"language-bash\">"));
+        assert!(result.contains("<code class=\"language-python\">"));
+        // The untagged block after the paragraph stays outside the group
+        let group_end = result.find("After.").unwrap();
+        assert!(result.find("language-rust").unwrap() > group_end);
+    }
+
+    #[test]
+    fn test_code_tabs_separate_groups() {
+        let input = "```sh tab=a\nx\n```\n\nText\n\n```sh tab=b\ny\n```";
+        let result = render_markdown(input);
+        assert_eq!(result.matches("class=\"code-tabs\"").count(), 2);
+    }
+
     #[test]
     fn test_heading_ids_added() {
         let input = "## Hello World\n\n### Using Code";
@@ -464,4 +760,43 @@ Even more content.
         assert_eq!(slugify("Using `cargo`"), "using-cargo");
         assert_eq!(slugify("Section 2.1"), "section-2-1");
     }
+
+    fn glossary() -> Vec<GlossaryTooltip> {
+        vec![
+            GlossaryTooltip {
+                term: "SLO".into(),
+                definition: "Service level \"objective\"".into(),
+                anchor: "slo".into(),
+            },
+            GlossaryTooltip {
+                term: "error budget".into(),
+                definition: "Allowed unreliability".into(),
+                anchor: "error-budget".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_glossary_wraps_first_occurrence_only() {
+        let result = render_markdown_with_glossary(
+            "Each slo has an Error Budget. SLOs and the SLO again.",
+            &glossary(),
+        );
+        assert!(result.contains(
+            "<a class=\"glossary-term tooltip\" data-tip=\"Service level &quot;objective&quot;\" href=\"/glossary#slo\" rel=\"noopener noreferrer\">slo</a>"
+        ));
+        assert!(result.contains(
+            "href=\"/glossary#error-budget\" rel=\"noopener noreferrer\">Error Budget</a>"
+        ));
+        assert_eq!(result.matches("glossary-term").count(), 2);
+        assert!(result.contains("SLOs and the SLO again."));
+    }
+
+    #[test]
+    fn test_glossary_skips_code_links_and_headings() {
+        let input = "## SLO\n\n`SLO` and [SLO](/docs/slo)\n\n```\nSLO\n```\n\nFinally SLO.";
+        let result = render_markdown_with_glossary(input, &glossary());
+        assert_eq!(result.matches("glossary-term").count(), 1);
+        assert!(result.contains("Finally <a class=\"glossary-term tooltip\""));
+    }
 }
@@ -0,0 +1,95 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// Extract `@handle` mentions from markdown content.
+///
+/// A handle is made of ASCII letters, digits, `.`, `_` and `-` and must not
+/// follow a letter or digit, so email addresses are not mistaken for
+/// mentions. Mentions inside code spans and code blocks are ignored.
+///
+/// Returns a deduplicated list of lowercase handles, without the `@`.
+pub fn extract_mentions(markdown: &str) -> Vec<String> {
+    let mut mentions = Vec::new();
+    let mut in_code_block = false;
+    // The parser may split a run of text (e.g. at `_`), so scan whole runs
+    let mut text = String::new();
+
+    for event in Parser::new_ext(markdown, Options::empty()) {
+        match event {
+            Event::Text(t) if !in_code_block => {
+                text.push_str(&t);
+                continue;
+            }
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            _ => {}
+        }
+        scan_mentions(&text, &mut mentions);
+        text.clear();
+    }
+    scan_mentions(&text, &mut mentions);
+    mentions
+}
+
+/// Extract `@handle` mentions from rendered HTML content (e.g. from TipTap).
+///
+/// Same rules as [`extract_mentions`], applied to the raw HTML.
+pub fn extract_mentions_from_html(html: &str) -> Vec<String> {
+    let mut mentions = Vec::new();
+    scan_mentions(html, &mut mentions);
+    mentions
+}
+
+fn is_handle_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')
+}
+
+fn scan_mentions(text: &str, mentions: &mut Vec<String>) {
+    let mut previous: Option<char> = None;
+    for (i, c) in text.char_indices() {
+        let starts_mention =
+            c == '@' && !previous.is_some_and(|p| p.is_alphanumeric() || is_handle_char(p));
+        previous = Some(c);
+        if !starts_mention {
+            continue;
+        }
+
+        let rest = &text[i + 1..];
+        let end = rest.find(|c| !is_handle_char(c)).unwrap_or(rest.len());
+        // Trailing punctuation ends the sentence, not the handle
+        let handle = rest[..end].trim_end_matches(['.', '-']);
+        if handle.is_empty() {
+            continue;
+        }
+        let handle = handle.to_ascii_lowercase();
+        if !mentions.contains(&handle) {
+            mentions.push(handle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_mentions() {
+        let md = "Ping @Jane.Doe, @payments-team and @ops_oncall. Thanks @jane.doe!";
+        assert_eq!(
+            extract_mentions(md),
+            ["jane.doe", "payments-team", "ops_oncall"]
+        );
+    }
+
+    #[test]
+    fn test_extract_mentions_ignores_emails_and_code() {
+        let md = "Mail ops@example.com, run `npm i @scope/pkg`\n\n```\n@decorator\n```\n\n(@sre)";
+        assert_eq!(extract_mentions(md), ["sre"]);
+    }
+
+    #[test]
+    fn test_extract_mentions_from_html() {
+        let html =
+            "<p>Hi <strong>@jane</strong>, see <a href=\"mailto:ops@example.com\">ops</a></p>";
+        assert_eq!(extract_mentions_from_html(html), ["jane"]);
+    }
+}
@@ -1,2 +1,9 @@
+pub mod embeds;
+pub mod issues;
+pub mod limits;
 pub mod links;
+pub mod lint;
 pub mod markdown;
+pub mod mentions;
+pub mod pipeline;
+pub mod spelling;
@@ -0,0 +1,407 @@
+//! Composable Markdown rendering pipeline.
+//!
+//! Rendering runs in fixed phases, each made of registered transforms:
+//!
+//! 1. **Parse** — the source is parsed into a `pulldown-cmark` event stream.
+//! 2. **Directives** — `{{name:argument}}` placeholders in text are replaced
+//!    by the HTML of the matching [`Directive`] (none are registered by default).
+//! 3. **Event transforms** — [`EventTransform`]s rewrite the event stream in
+//!    registration order (mermaid blocks, issue keys, glossary terms, code tabs,
+//!    embeds).
+//! 4. **HTML transforms** — [`HtmlTransform`]s post-process the rendered
+//!    HTML (heading anchor IDs).
+//! 5. **Sanitization** — the result is cleaned with ammonia; the allowlist
+//!    can be extended for the markup custom transforms emit, and `<iframe>`s
+//!    are kept only for the embed URLs of [`RenderContext::embeds`].
+//!
+//! [`RenderPipeline::standard`] is the pipeline Lekton ships with. A
+//! deployment embedding the crate can build on it and hand it to the server
+//! as [`AppState::render_pipeline`](crate::app::AppState::render_pipeline)
+//! to add custom directives without patching the crate:
+//!
+//! ```ignore
+//! struct Jira;
+//!
+//! impl Directive for Jira {
+//!     fn name(&self) -> &str {
+//!         "jira"
+//!     }
+//!     fn render(&self, key: &str) -> Option<String> {
+//!         Some(format!(
+//!             "<a class=\"badge\" href=\"https://jira.acme.com/browse/{key}\">{key}</a>"
+//!         ))
+//!     }
+//! }
+//!
+//! let render_pipeline = Arc::new(RenderPipeline::standard().with_directive(Jira));
+//! ```
+//!
+//! Markdown rendered in the browser (chat answers, editor preview) goes
+//! through the standard pipeline.
+
+use std::sync::Arc;
+
+use ammonia::Builder;
+use pulldown_cmark::{html, Event, Options, Parser, Tag, TagEnd};
+
+use crate::rendering::embeds::{EmbedLinks, Embeds};
+use crate::rendering::issues::{IssueKeys, IssueLinks};
+use crate::rendering::markdown::{
+    CodeTabs, GlossaryTerms, GlossaryTooltip, HeadingIds, MermaidBlocks,
+};
+
+/// Per-render inputs available to every transform.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderContext<'a> {
+    /// Glossary terms to annotate (empty when not applicable).
+    pub glossary: &'a [GlossaryTooltip],
+    /// Issue keys to link to the tracker (`None` leaves them as text).
+    pub issues: Option<&'a IssueLinks>,
+    /// Links to render as embedded widgets (`None` leaves them as links).
+    pub embeds: Option<&'a Embeds>,
+}
+
+/// A rewrite of the Markdown event stream.
+pub trait EventTransform: Send + Sync {
+    /// Short identifier, used by [`RenderPipeline::without`].
+    fn name(&self) -> &str;
+
+    fn transform<'a>(&self, events: Vec<Event<'a>>, ctx: &RenderContext<'_>) -> Vec<Event<'a>>;
+}
+
+/// A rewrite of the rendered HTML, applied before sanitization.
+pub trait HtmlTransform: Send + Sync {
+    /// Short identifier, used by [`RenderPipeline::without`].
+    fn name(&self) -> &str;
+
+    fn transform(&self, html: String, ctx: &RenderContext<'_>) -> String;
+}
+
+/// An inline `{{name:argument}}` placeholder expanded to HTML.
+///
+/// Directives are not expanded inside code. The emitted markup goes through
+/// sanitization: extend the allowlist with
+/// [`RenderPipeline::allow_tag_attributes`] if it needs more than
+/// ammonia's defaults.
+pub trait Directive: Send + Sync {
+    /// The name before the colon (e.g. `"jira"` for `{{jira:KEY}}`).
+    fn name(&self) -> &str;
+
+    /// HTML replacing the placeholder, or `None` to leave it as text.
+    fn render(&self, argument: &str) -> Option<String>;
+}
+
+/// An ordered set of transforms turning Markdown into sanitized HTML.
+#[derive(Clone)]
+pub struct RenderPipeline {
+    options: Options,
+    directives: Vec<Arc<dyn Directive>>,
+    event_transforms: Vec<Arc<dyn EventTransform>>,
+    html_transforms: Vec<Arc<dyn HtmlTransform>>,
+    extra_tags: Vec<String>,
+    extra_tag_attributes: Vec<(String, Vec<String>)>,
+    attribute_filters: Vec<Arc<AttributeFilter>>,
+}
+
+/// Predicate on `(element, attribute, value)`; `false` drops the attribute.
+type AttributeFilter = dyn Fn(&str, &str, &str) -> bool + Send + Sync;
+
+impl Default for RenderPipeline {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl RenderPipeline {
+    /// A pipeline with no transforms: plain GFM rendering plus sanitization.
+    pub fn empty() -> Self {
+        Self {
+            options: Options::ENABLE_TABLES
+                | Options::ENABLE_FOOTNOTES
+                | Options::ENABLE_STRIKETHROUGH
+                | Options::ENABLE_TASKLISTS
+                | Options::ENABLE_SMART_PUNCTUATION
+                | Options::ENABLE_HEADING_ATTRIBUTES,
+            directives: Vec::new(),
+            event_transforms: Vec::new(),
+            html_transforms: Vec::new(),
+            extra_tags: Vec::new(),
+            extra_tag_attributes: Vec::new(),
+            attribute_filters: Vec::new(),
+        }
+    }
+
+    /// The pipeline used by [`render_markdown`](crate::rendering::markdown::render_markdown)
+    /// unless another one is [`install`]ed.
+    pub fn standard() -> Self {
+        Self::empty()
+            .with_event_transform(MermaidBlocks)
+            .with_event_transform(IssueKeys)
+            .with_event_transform(GlossaryTerms)
+            .with_event_transform(CodeTabs)
+            .with_event_transform(EmbedLinks)
+            .with_html_transform(HeadingIds)
+    }
+
+    /// Register a `{{name:argument}}` directive.
+    pub fn with_directive(mut self, directive: impl Directive + 'static) -> Self {
+        self.directives.push(Arc::new(directive));
+        self
+    }
+
+    /// Append an event-stream transform; transforms run in registration order.
+    pub fn with_event_transform(mut self, transform: impl EventTransform + 'static) -> Self {
+        self.event_transforms.push(Arc::new(transform));
+        self
+    }
+
+    /// Append an HTML transform; transforms run in registration order.
+    pub fn with_html_transform(mut self, transform: impl HtmlTransform + 'static) -> Self {
+        self.html_transforms.push(Arc::new(transform));
+        self
+    }
+
+    /// Remove the event and HTML transforms called `name`.
+    pub fn without(mut self, name: &str) -> Self {
+        self.event_transforms.retain(|t| t.name() != name);
+        self.html_transforms.retain(|t| t.name() != name);
+        self
+    }
+
+    /// Allow `tag` (and `attributes` on it) through sanitization.
+    pub fn allow_tag_attributes(mut self, tag: &str, attributes: &[&str]) -> Self {
+        self.extra_tags.push(tag.to_string());
+        self.extra_tag_attributes.push((
+            tag.to_string(),
+            attributes.iter().map(|a| a.to_string()).collect(),
+        ));
+        self
+    }
+
+    /// Drop attributes for which `filter(element, attribute, value)` is
+    /// `false` during sanitization, e.g. to restrict the URLs a newly
+    /// allowed tag may load. Every registered filter must accept an attribute.
+    pub fn filter_attributes(
+        mut self,
+        filter: impl Fn(&str, &str, &str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.attribute_filters.push(Arc::new(filter));
+        self
+    }
+
+    /// Render `raw` Markdown to sanitized HTML.
+    pub fn render(&self, raw: &str, ctx: &RenderContext<'_>) -> String {
+        let mut events: Vec<Event<'_>> = Parser::new_ext(raw, self.options).collect();
+        if !self.directives.is_empty() {
+            events = expand_directives(events, &self.directives);
+        }
+        for transform in &self.event_transforms {
+            events = transform.transform(events, ctx);
+        }
+
+        let mut html_output = String::new();
+        html::push_html(&mut html_output, events.into_iter());
+
+        for transform in &self.html_transforms {
+            html_output = transform.transform(html_output, ctx);
+        }
+        self.sanitize(&html_output, ctx)
+    }
+
+    /// Sanitize HTML to prevent XSS, while preserving safe GFM-generated attributes.
+    ///
+    /// Extends ammonia's default allowlist with:
+    /// - `class` on `<pre>` and `<code>` (mermaid blocks and syntax highlighting)
+    /// - `class`/`data-tab` on `<div>` and `<button>` (code tab groups)
+    /// - `class`/`data-tip` on `<a>` (glossary tooltips)
+    /// - `class` on `<span>` (issue status badges)
+    /// - `id` on headings (anchor navigation)
+    /// - `<input>` with `type`/`disabled`/`checked` (GFM task list checkboxes)
+    /// - `<iframe>` whose `src` is an embed URL of `ctx.embeds` (embedded widgets)
+    /// - anything registered with [`allow_tag_attributes`](Self::allow_tag_attributes),
+    ///   subject to the [`filter_attributes`](Self::filter_attributes) filters
+    fn sanitize(&self, html: &str, ctx: &RenderContext<'_>) -> String {
+        let mut builder = Builder::default();
+        builder
+            .add_tag_attributes("pre", &["class"])
+            .add_tag_attributes("code", &["class"])
+            .add_tag_attributes("div", &["class", "data-tab"])
+            .add_tags(&["button"])
+            .add_tag_attributes("button", &["class", "type", "data-tab"])
+            .add_tag_attributes("a", &["class", "data-tip"])
+            .add_tag_attributes("span", &["class"])
+            .add_tag_attributes("h1", &["id"])
+            .add_tag_attributes("h2", &["id"])
+            .add_tag_attributes("h3", &["id"])
+            .add_tag_attributes("h4", &["id"])
+            .add_tag_attributes("h5", &["id"])
+            .add_tag_attributes("h6", &["id"])
+            .add_tags(&["input"])
+            .add_tag_attributes("input", &["type", "disabled", "checked"]);
+        builder.add_tags(self.extra_tags.iter().map(String::as_str));
+        for (tag, attributes) in &self.extra_tag_attributes {
+            builder.add_tag_attributes(tag.as_str(), attributes.iter().map(String::as_str));
+        }
+        let mut filters = self.attribute_filters.clone();
+        if let Some(embeds) = ctx.embeds {
+            let embeds = embeds.clone();
+            builder
+                .add_tags(&["iframe"])
+                .add_tag_attributes("iframe", &["src", "title", "loading", "allowfullscreen"]);
+            filters.push(Arc::new(
+                move |element: &str, attribute: &str, value: &str| {
+                    element != "iframe" || attribute != "src" || embeds.is_embed_src(value)
+                },
+            ));
+        }
+        if !filters.is_empty() {
+            builder.attribute_filter(move |element, attribute, value| {
+                filters
+                    .iter()
+                    .all(|f| f(element, attribute, value))
+                    .then_some(value.into())
+            });
+        }
+        builder.clean(html).to_string()
+    }
+}
+
+/// Replace `{{name:argument}}` placeholders in text (outside code blocks)
+/// with the HTML of the matching directive. Unknown names are left as text.
+fn expand_directives<'a>(
+    events: Vec<Event<'a>>,
+    directives: &[Arc<dyn Directive>],
+) -> Vec<Event<'a>> {
+    let mut out = Vec::with_capacity(events.len());
+    let mut in_code_block = false;
+    let mut text = String::new();
+
+    let flush = |text: &mut String, out: &mut Vec<Event<'a>>| {
+        if !text.is_empty() {
+            expand_text(&std::mem::take(text), directives, out);
+        }
+    };
+
+    for event in events {
+        match event {
+            // The parser may split one run of text into several events
+            Event::Text(t) if !in_code_block => text.push_str(&t),
+            other => {
+                flush(&mut text, &mut out);
+                match &other {
+                    Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+                    Event::End(TagEnd::CodeBlock) => in_code_block = false,
+                    _ => {}
+                }
+                out.push(other);
+            }
+        }
+    }
+    flush(&mut text, &mut out);
+    out
+}
+
+fn expand_text<'a>(text: &str, directives: &[Arc<dyn Directive>], out: &mut Vec<Event<'a>>) {
+    let mut rest = text;
+    let mut pending = String::new();
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start..].find("}}") else {
+            break;
+        };
+        let inner = &rest[start + 2..start + len];
+        let html = inner.split_once(':').and_then(|(name, argument)| {
+            directives
+                .iter()
+                .find(|d| d.name() == name.trim())
+                .and_then(|d| d.render(argument.trim()))
+        });
+        match html {
+            Some(html) => {
+                pending.push_str(&rest[..start]);
+                if !pending.is_empty() {
+                    out.push(Event::Text(std::mem::take(&mut pending).into()));
+                }
+                out.push(Event::InlineHtml(html.into()));
+            }
+            None => pending.push_str(&rest[..start + len + 2]),
+        }
+        rest = &rest[start + len + 2..];
+    }
+    pending.push_str(rest);
+    if !pending.is_empty() {
+        out.push(Event::Text(pending.into()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::markdown::escape_html;
+
+    struct Jira;
+
+    impl Directive for Jira {
+        fn name(&self) -> &str {
+            "jira"
+        }
+
+        fn render(&self, key: &str) -> Option<String> {
+            (!key.is_empty()).then(|| {
+                format!(
+                    "<a class=\"badge\" href=\"https://jira.acme.com/browse/{0}\">{0}</a>",
+                    escape_html(key)
+                )
+            })
+        }
+    }
+
+    struct Shout;
+
+    impl HtmlTransform for Shout {
+        fn name(&self) -> &str {
+            "shout"
+        }
+
+        fn transform(&self, html: String, _: &RenderContext<'_>) -> String {
+            html.replace("<p>", "<p class=\"shout\">")
+        }
+    }
+
+    #[test]
+    fn test_directive_expanded_in_text_only() {
+        let pipeline = RenderPipeline::standard().with_directive(Jira);
+        let html = pipeline.render(
+            "Fixed in {{jira:PAY-42}}, see {{unknown:x}}.\n\n```\n{{jira:PAY-1}}\n```",
+            &RenderContext::default(),
+        );
+        assert!(html.contains(
+            "Fixed in <a class=\"badge\" href=\"https://jira.acme.com/browse/PAY-42\" rel=\"noopener noreferrer\">PAY-42</a>, see {{unknown:x}}."
+        ));
+        assert!(html.contains("<code>{{jira:PAY-1}}\n</code>"));
+    }
+
+    #[test]
+    fn test_custom_transforms_and_allowlist() {
+        let ctx = RenderContext::default();
+
+        let pipeline = RenderPipeline::standard().with_html_transform(Shout);
+        assert_eq!(pipeline.render("hi", &ctx).trim(), "<p>hi</p>");
+
+        let pipeline = pipeline.allow_tag_attributes("p", &["class"]);
+        assert_eq!(
+            pipeline.render("hi", &ctx).trim(),
+            "<p class=\"shout\">hi</p>"
+        );
+
+        let pipeline = RenderPipeline::standard().without("heading-ids");
+        assert_eq!(pipeline.render("## A b", &ctx).trim(), "<h2>A b</h2>");
+    }
+
+    #[test]
+    fn test_standard_pipeline_is_the_default() {
+        assert_eq!(
+            RenderPipeline::standard().render("## Setup", &RenderContext::default()),
+            crate::rendering::markdown::render_markdown("## Setup")
+        );
+    }
+}
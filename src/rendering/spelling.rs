@@ -0,0 +1,187 @@
+//! Words to spell-check in document sources.
+//!
+//! [`markdown_words`] and [`html_words`] pick the prose out of a document,
+//! skipping code, URLs, paths, acronyms and identifiers, for the server's
+//! spell checker (see `crate::spellcheck`).
+
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+/// Misspellings reported per document at most; the rest are dropped.
+pub const MAX_MISSPELLINGS: usize = 100;
+
+/// A word missing from the dictionary and the organization's word list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misspelling {
+    pub word: String,
+    /// 1-based line of the word.
+    pub line: usize,
+    /// Dictionary words close to it, best first.
+    #[serde(default)]
+    pub suggestions: Vec<String>,
+}
+
+/// A word to check, with its 1-based line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Word<'a> {
+    pub text: &'a str,
+    pub line: usize,
+}
+
+/// Words of the running text of `markdown`, outside code.
+pub fn markdown_words(markdown: &str) -> Vec<Word<'_>> {
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(markdown.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset);
+
+    let mut words = Vec::new();
+    let mut in_code_block = false;
+    for (event, range) in Parser::new(markdown).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            // Check the source span so words borrow from `markdown`; escapes
+            // and entities are seen as written
+            Event::Text(_) if !in_code_block => {
+                push_words(&mut words, &markdown[range.clone()], line_of(range.start));
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+/// Words of the text of `html`, as saved by the web editor, outside `<code>`
+/// and `<pre>`. Each block element counts as a line.
+pub fn html_words(html: &str) -> Vec<Word<'_>> {
+    let mut words = Vec::new();
+    let mut line = 1;
+    let mut code_depth = 0usize;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(open) = rest.find('<') else {
+            if code_depth == 0 {
+                push_words(&mut words, rest, line);
+            }
+            break;
+        };
+        if code_depth == 0 {
+            push_words(&mut words, &rest[..open], line);
+        }
+        let Some(close) = rest[open..].find('>') else {
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match name.as_str() {
+            "code" | "pre" if closing => code_depth = code_depth.saturating_sub(1),
+            "code" | "pre" => code_depth += 1,
+            "p" | "br" | "li" | "div" | "tr" | "blockquote" | "h1" | "h2" | "h3" | "h4" | "h5"
+            | "h6"
+                if closing || name == "br" =>
+            {
+                line += 1
+            }
+            _ => {}
+        }
+        rest = &rest[open + close + 1..];
+    }
+    words
+}
+
+/// Split `text` into checkable words.
+fn push_words<'a>(words: &mut Vec<Word<'a>>, text: &'a str, line: usize) {
+    for token in text.split_whitespace() {
+        // URLs, paths, emails, file names, identifiers and versions
+        if token.contains("://")
+            || token.contains(['/', '@', '_', '\\', '`', '&', '<', '>'])
+            || token.contains(|c: char| c.is_ascii_digit())
+            || token
+                .trim_end_matches(['.', ',', ':', ';', '!', '?', ')'])
+                .contains('.')
+        {
+            continue;
+        }
+        let parts = token
+            .split(|c: char| !(c.is_alphabetic() || c == '\'' || c == '’'))
+            .map(|part| part.trim_matches(['\'', '’']));
+        for part in parts {
+            if is_checkable(part) {
+                words.push(Word { text: part, line });
+            }
+        }
+    }
+}
+
+/// Whether `word` is prose: not a single letter, an acronym (`API`) or a
+/// mixed-case name (`GitHub`, `iOS`).
+fn is_checkable(word: &str) -> bool {
+    let mut chars = word.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let rest = chars.as_str();
+    first.is_alphabetic() && !rest.is_empty() && !rest.chars().any(char::is_uppercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(words: Vec<Word<'_>>) -> Vec<(&str, usize)> {
+        words.into_iter().map(|w| (w.text, w.line)).collect()
+    }
+
+    #[test]
+    fn markdown_words_skip_code_urls_and_names() {
+        let markdown = "# Deploy guide\n\nRun `kubectl apply` on the API, see https://example.com or config.toml.\n\n```\nnot prose here\n```\n\nGitHub isn't [linked](https://example.com).\n";
+        assert_eq!(
+            texts(markdown_words(markdown)),
+            vec![
+                ("Deploy", 1),
+                ("guide", 1),
+                ("Run", 3),
+                ("on", 3),
+                ("the", 3),
+                ("see", 3),
+                ("or", 3),
+                ("isn't", 9),
+                ("linked", 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn html_words_count_blocks_as_lines_and_skip_code() {
+        let html = "<h1>Runbok</h1><p>Restart the <code>api-server</code> pod.<br>Then wait.</p><pre><code>echo done</code></pre><p>Fine</p>";
+        assert_eq!(
+            texts(html_words(html)),
+            vec![
+                ("Runbok", 1),
+                ("Restart", 2),
+                ("the", 2),
+                ("pod", 2),
+                ("Then", 3),
+                ("wait", 3),
+                ("Fine", 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn single_letters_acronyms_and_mixed_case_are_not_checked() {
+        assert!(is_checkable("runbook"));
+        assert!(is_checkable("Runbook"));
+        assert!(!is_checkable("a"));
+        assert!(!is_checkable("SLO"));
+        assert!(!is_checkable("iOS"));
+        assert!(!is_checkable(""));
+    }
+}
@@ -0,0 +1,954 @@
+//! Retries, timeouts and circuit breaking for downstream services.
+//!
+//! A [`ResiliencePolicy`] runs each call with a per-attempt timeout, retries
+//! transient failures with jittered exponential backoff and trips a
+//! [`CircuitBreaker`] after repeated failures, so a slow or unreachable
+//! dependency fails fast instead of stalling every request that touches it.
+//! [`ResilientStorage`] and [`ResilientSearch`] apply a policy to the S3 and
+//! Meilisearch clients, the `Resilient*Repository` decorators share one
+//! `mongo` policy across the MongoDB repositories on the ingest and page
+//! render paths; the breaker states are reported by `/readyz`.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::Instrument;
+
+use crate::config::ResilienceConfig;
+use crate::db::asset_repository::AssetRepository;
+use crate::db::document_version_repository::{DocumentVersion, DocumentVersionRepository};
+use crate::db::models::{Asset, Document, OwnerUsage, Schema, SchemaVersion};
+use crate::db::repository::DocumentRepository;
+use crate::db::schema_repository::{
+    SchemaListParams, SchemaPage, SchemaRepository, SchemaVersionRef,
+};
+use crate::db::settings_repository::{
+    AppSettings, BrandingSettings, EmbedSettings, FeatureFlags, IncidentMode, IssueTrackerSettings,
+    LdapSettings, NavLinks, OnCallSettings, SearchSettings, SettingsRepository,
+};
+use crate::error::AppError;
+use crate::search::client::{SearchDocument, SearchFilter, SearchHit, SearchService};
+use crate::storage::client::{StorageClient, StoredObject};
+
+/// Observable state of a [`CircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Calls flow normally.
+    Closed,
+    /// Calls are rejected without reaching the service.
+    Open,
+    /// The open period has elapsed; a single trial call is let through.
+    HalfOpen,
+}
+
+#[derive(Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// A half-open trial call is in flight.
+    probing: bool,
+}
+
+/// Whether a call may reach the service.
+#[derive(Debug, PartialEq, Eq)]
+enum Admission {
+    Allowed,
+    /// The half-open trial call; everything else is rejected until it ends.
+    Probe,
+    Rejected,
+}
+
+/// Opens after `failure_threshold` consecutive failed attempts and rejects
+/// calls for `open_duration`. Then a single trial call is let through: its
+/// success closes the circuit again, its failure re-opens it.
+pub struct CircuitBreaker {
+    name: &'static str,
+    failure_threshold: u32,
+    open_duration: Duration,
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    pub fn new(name: &'static str, failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            name,
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn state(&self) -> CircuitState {
+        Self::circuit_state(&self.state.lock().unwrap(), self.open_duration)
+    }
+
+    fn circuit_state(state: &BreakerState, open_duration: Duration) -> CircuitState {
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(at) if at.elapsed() < open_duration => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    fn admit(&self) -> Admission {
+        let mut state = self.state.lock().unwrap();
+        match Self::circuit_state(&state, self.open_duration) {
+            CircuitState::Closed => Admission::Allowed,
+            CircuitState::Open => Admission::Rejected,
+            CircuitState::HalfOpen if state.probing => Admission::Rejected,
+            CircuitState::HalfOpen => {
+                state.probing = true;
+                Admission::Probe
+            }
+        }
+    }
+
+    /// Release the trial slot of a probe that ended without an outcome
+    /// (cancelled), so the next call can probe instead.
+    fn end_probe(&self) {
+        self.state.lock().unwrap().probing = false;
+    }
+
+    fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.opened_at.is_some() {
+            tracing::info!(service = self.name, "Circuit closed");
+        }
+        *state = BreakerState::default();
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.consecutive_failures >= self.failure_threshold {
+            if state.opened_at.is_none() {
+                tracing::warn!(
+                    service = self.name,
+                    failures = state.consecutive_failures,
+                    "Circuit opened"
+                );
+            }
+            state.opened_at = Some(Instant::now());
+        }
+        state.probing = false;
+    }
+}
+
+/// Ends the half-open probe when the probing call finishes or is dropped.
+struct ProbeGuard<'a>(&'a CircuitBreaker);
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        self.0.end_probe();
+    }
+}
+
+/// Timeout, retry and circuit-breaker policy for one downstream service.
+#[derive(Clone)]
+pub struct ResiliencePolicy {
+    timeout: Duration,
+    max_retries: u32,
+    retry_base_delay: Duration,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl ResiliencePolicy {
+    pub fn new(service: &'static str, config: &ResilienceConfig) -> Self {
+        Self {
+            timeout: Duration::from_millis(config.timeout_ms),
+            max_retries: config.max_retries,
+            retry_base_delay: Duration::from_millis(config.retry_base_delay_ms),
+            breaker: Arc::new(CircuitBreaker::new(
+                service,
+                config.failure_threshold,
+                Duration::from_secs(config.open_duration_secs),
+            )),
+        }
+    }
+
+    pub fn breaker(&self) -> Arc<CircuitBreaker> {
+        self.breaker.clone()
+    }
+
+    /// Run `call`, retrying timeouts and server-side errors.
+    ///
+    /// Client errors (not found, bad request, …) are returned immediately and
+    /// count as a healthy response. Fails with [`AppError::Unavailable`] while
+    /// the circuit is open or when the last attempt timed out.
+    pub async fn call<T, F, Fut>(&self, operation: &str, call: F) -> Result<T, AppError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AppError>>,
+    {
+        self.run(operation, self.max_retries, call).await
+    }
+
+    /// Like [`call`](Self::call) but without retries, for writes that are not
+    /// safe to repeat (inserts): a timed-out attempt may still have applied.
+    pub async fn call_once<T, F, Fut>(&self, operation: &str, call: F) -> Result<T, AppError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AppError>>,
+    {
+        self.run(operation, 0, call).await
+    }
+
+    async fn run<T, F, Fut>(
+        &self,
+        operation: &str,
+        max_retries: u32,
+        mut call: F,
+    ) -> Result<T, AppError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AppError>>,
+    {
+        let service = self.breaker.name();
+        let mut attempt = 0;
+        loop {
+            let _probe = match self.breaker.admit() {
+                Admission::Rejected => {
+                    return Err(AppError::Unavailable(format!("{service} circuit is open")));
+                }
+                Admission::Probe => Some(ProbeGuard(&self.breaker)),
+                Admission::Allowed => None,
+            };
+
+            let span = tracing::info_span!("downstream_call", service, operation, attempt);
+            let result = match tokio::time::timeout(self.timeout, call())
+                .instrument(span)
+                .await
+            {
+                Ok(result) => result,
+                Err(_) => Err(AppError::Unavailable(format!(
+                    "{service} {operation} timed out after {} ms",
+                    self.timeout.as_millis()
+                ))),
+            };
+
+            match result {
+                Err(e) if is_transient(&e) => {
+                    self.breaker.record_failure();
+                    if attempt >= max_retries {
+                        return Err(e);
+                    }
+                    tracing::debug!(service, operation, attempt, "Retrying after error: {e}");
+                    tokio::time::sleep(backoff(self.retry_base_delay, attempt)).await;
+                    attempt += 1;
+                }
+                result => {
+                    self.breaker.record_success();
+                    return result;
+                }
+            }
+        }
+    }
+}
+
+/// Errors worth retrying: the service failed or did not answer in time.
+fn is_transient(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::Database(_)
+            | AppError::Storage(_)
+            | AppError::Internal(_)
+            | AppError::Unavailable(_)
+    )
+}
+
+/// Full-jitter exponential backoff: a random delay up to `base * 2^attempt`.
+fn backoff(base: Duration, attempt: u32) -> Duration {
+    use rand::Rng;
+
+    let cap = base.saturating_mul(1 << attempt.min(10));
+    let millis = cap.as_millis() as u64;
+    Duration::from_millis(rand::rng().random_range(0..=millis))
+}
+
+/// [`StorageClient`] decorator applying a [`ResiliencePolicy`].
+pub struct ResilientStorage {
+    inner: Arc<dyn StorageClient>,
+    policy: ResiliencePolicy,
+}
+
+impl ResilientStorage {
+    pub fn new(inner: Arc<dyn StorageClient>, policy: ResiliencePolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl StorageClient for ResilientStorage {
+    async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), AppError> {
+        self.policy
+            .call("put_object", || self.inner.put_object(key, content.clone()))
+            .await
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+        self.policy
+            .call("get_object", || self.inner.get_object(key))
+            .await
+    }
+
+    async fn get_object_range(
+        &self,
+        key: &str,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Vec<u8>>, AppError> {
+        self.policy
+            .call("get_object_range", || {
+                self.inner.get_object_range(key, start, end)
+            })
+            .await
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), AppError> {
+        self.policy
+            .call("delete_object", || self.inner.delete_object(key))
+            .await
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<StoredObject>, AppError> {
+        self.policy
+            .call("list_objects", || self.inner.list_objects(prefix))
+            .await
+    }
+
+    fn key_layout(&self) -> crate::config::KeyLayout {
+        self.inner.key_layout()
+    }
+}
+
+/// [`SearchService`] decorator applying a [`ResiliencePolicy`].
+pub struct ResilientSearch {
+    inner: Arc<dyn SearchService>,
+    policy: ResiliencePolicy,
+}
+
+impl ResilientSearch {
+    pub fn new(inner: Arc<dyn SearchService>, policy: ResiliencePolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl SearchService for ResilientSearch {
+    async fn index_document(&self, doc: &SearchDocument) -> Result<(), AppError> {
+        self.policy
+            .call("index_document", || self.inner.index_document(doc))
+            .await
+    }
+
+    async fn delete_document(&self, slug: &str) -> Result<(), AppError> {
+        self.policy
+            .call("delete_document", || self.inner.delete_document(slug))
+            .await
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        allowed_levels: Option<&[String]>,
+        include_draft: bool,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SearchHit>, AppError> {
+        self.policy
+            .call("search", || {
+                self.inner
+                    .search(query, allowed_levels, include_draft, filter)
+            })
+            .await
+    }
+
+    async fn configure_index(&self, settings: &SearchSettings) -> Result<(), AppError> {
+        self.policy
+            .call("configure_index", || self.inner.configure_index(settings))
+            .await
+    }
+
+    async fn list_indexed_documents(&self) -> Result<Vec<SearchDocument>, AppError> {
+        self.policy
+            .call("list_indexed_documents", || {
+                self.inner.list_indexed_documents()
+            })
+            .await
+    }
+}
+
+/// [`DocumentRepository`] decorator applying the `mongo` [`ResiliencePolicy`].
+pub struct ResilientDocumentRepository {
+    inner: Arc<dyn DocumentRepository>,
+    policy: ResiliencePolicy,
+}
+
+impl ResilientDocumentRepository {
+    pub fn new(inner: Arc<dyn DocumentRepository>, policy: ResiliencePolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl DocumentRepository for ResilientDocumentRepository {
+    async fn create_or_update(&self, doc: Document) -> Result<(), AppError> {
+        self.policy
+            .call("create_or_update", || {
+                self.inner.create_or_update(doc.clone())
+            })
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Document>, AppError> {
+        self.policy
+            .call("find_by_slug", || self.inner.find_by_slug(slug))
+            .await
+    }
+
+    async fn find_by_slugs(&self, slugs: &[String]) -> Result<Vec<Document>, AppError> {
+        self.policy
+            .call("find_by_slugs", || self.inner.find_by_slugs(slugs))
+            .await
+    }
+
+    async fn find_related_to(&self, slug: &str) -> Result<Vec<Document>, AppError> {
+        self.policy
+            .call("find_related_to", || self.inner.find_related_to(slug))
+            .await
+    }
+
+    async fn find_superseding(&self, slugs: &[String]) -> Result<Vec<Document>, AppError> {
+        self.policy
+            .call("find_superseding", || self.inner.find_superseding(slugs))
+            .await
+    }
+
+    async fn find_by_asset_ref(&self, s3_key: &str) -> Result<Vec<Document>, AppError> {
+        self.policy
+            .call("find_by_asset_ref", || self.inner.find_by_asset_ref(s3_key))
+            .await
+    }
+
+    async fn find_by_s3_key(&self, s3_key: &str) -> Result<Vec<Document>, AppError> {
+        self.policy
+            .call("find_by_s3_key", || self.inner.find_by_s3_key(s3_key))
+            .await
+    }
+
+    async fn list_all(&self) -> Result<Vec<Document>, AppError> {
+        self.policy.call("list_all", || self.inner.list_all()).await
+    }
+
+    async fn list_by_access_levels(
+        &self,
+        allowed_levels: Option<&[String]>,
+        include_draft: bool,
+    ) -> Result<Vec<Document>, AppError> {
+        self.policy
+            .call("list_by_access_levels", || {
+                self.inner
+                    .list_by_access_levels(allowed_levels, include_draft)
+            })
+            .await
+    }
+
+    async fn update_backlinks(
+        &self,
+        source_slug: &str,
+        old_links: &[String],
+        new_links: &[String],
+    ) -> Result<(), AppError> {
+        self.policy
+            .call("update_backlinks", || {
+                self.inner
+                    .update_backlinks(source_slug, old_links, new_links)
+            })
+            .await
+    }
+
+    async fn find_by_slug_prefix(&self, prefix: &str) -> Result<Vec<Document>, AppError> {
+        self.policy
+            .call("find_by_slug_prefix", || {
+                self.inner.find_by_slug_prefix(prefix)
+            })
+            .await
+    }
+
+    async fn set_archived(&self, slug: &str, archived: bool) -> Result<(), AppError> {
+        self.policy
+            .call("set_archived", || self.inner.set_archived(slug, archived))
+            .await
+    }
+
+    async fn set_asset_refs(&self, slug: &str, asset_refs: &[String]) -> Result<(), AppError> {
+        self.policy
+            .call("set_asset_refs", || {
+                self.inner.set_asset_refs(slug, asset_refs)
+            })
+            .await
+    }
+
+    async fn set_s3_key(&self, slug: &str, s3_key: &str) -> Result<(), AppError> {
+        self.policy
+            .call("set_s3_key", || self.inner.set_s3_key(slug, s3_key))
+            .await
+    }
+
+    async fn find_by_source_path(&self, source_path: &str) -> Result<Option<Document>, AppError> {
+        self.policy
+            .call("find_by_source_path", || {
+                self.inner.find_by_source_path(source_path)
+            })
+            .await
+    }
+
+    async fn usage_by_service_owner(
+        &self,
+        service_owner: Option<&str>,
+    ) -> Result<Vec<OwnerUsage>, AppError> {
+        self.policy
+            .call("usage_by_service_owner", || {
+                self.inner.usage_by_service_owner(service_owner)
+            })
+            .await
+    }
+}
+
+/// [`SchemaRepository`] decorator applying the `mongo` [`ResiliencePolicy`].
+pub struct ResilientSchemaRepository {
+    inner: Arc<dyn SchemaRepository>,
+    policy: ResiliencePolicy,
+}
+
+impl ResilientSchemaRepository {
+    pub fn new(inner: Arc<dyn SchemaRepository>, policy: ResiliencePolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl SchemaRepository for ResilientSchemaRepository {
+    async fn create_or_update(&self, schema: Schema) -> Result<(), AppError> {
+        self.policy
+            .call("create_or_update", || {
+                self.inner.create_or_update(schema.clone())
+            })
+            .await
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Schema>, AppError> {
+        self.policy
+            .call("find_by_name", || self.inner.find_by_name(name))
+            .await
+    }
+
+    async fn find_by_name_summary(&self, name: &str) -> Result<Option<Schema>, AppError> {
+        self.policy
+            .call("find_by_name_summary", || {
+                self.inner.find_by_name_summary(name)
+            })
+            .await
+    }
+
+    async fn list_all(&self) -> Result<Vec<Schema>, AppError> {
+        self.policy.call("list_all", || self.inner.list_all()).await
+    }
+
+    async fn list_summaries(&self) -> Result<Vec<Schema>, AppError> {
+        self.policy
+            .call("list_summaries", || self.inner.list_summaries())
+            .await
+    }
+
+    async fn list_page(&self, params: &SchemaListParams) -> Result<SchemaPage, AppError> {
+        self.policy
+            .call("list_page", || self.inner.list_page(params))
+            .await
+    }
+
+    async fn find_by_name_prefix(&self, prefix: &str) -> Result<Vec<Schema>, AppError> {
+        self.policy
+            .call("find_by_name_prefix", || {
+                self.inner.find_by_name_prefix(prefix)
+            })
+            .await
+    }
+
+    async fn add_version(&self, schema_name: &str, version: SchemaVersion) -> Result<(), AppError> {
+        // Not retried: a timed-out push may have applied, and repeating it
+        // would then fail as a duplicate version.
+        self.policy
+            .call_once("add_version", || {
+                self.inner.add_version(schema_name, version.clone())
+            })
+            .await
+    }
+
+    async fn set_version_archived(
+        &self,
+        schema_name: &str,
+        version: &str,
+        archived: bool,
+    ) -> Result<(), AppError> {
+        self.policy
+            .call("set_version_archived", || {
+                self.inner
+                    .set_version_archived(schema_name, version, archived)
+            })
+            .await
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), AppError> {
+        self.policy.call("delete", || self.inner.delete(name)).await
+    }
+
+    async fn find_version_s3_key(
+        &self,
+        schema_name: &str,
+        version: &str,
+    ) -> Result<Option<SchemaVersionRef>, AppError> {
+        self.policy
+            .call("find_version_s3_key", || {
+                self.inner.find_version_s3_key(schema_name, version)
+            })
+            .await
+    }
+}
+
+/// [`SettingsRepository`] decorator applying the `mongo` [`ResiliencePolicy`].
+pub struct ResilientSettingsRepository {
+    inner: Arc<dyn SettingsRepository>,
+    policy: ResiliencePolicy,
+}
+
+impl ResilientSettingsRepository {
+    pub fn new(inner: Arc<dyn SettingsRepository>, policy: ResiliencePolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl SettingsRepository for ResilientSettingsRepository {
+    async fn get_settings(&self) -> Result<AppSettings, AppError> {
+        self.policy
+            .call("get_settings", || self.inner.get_settings())
+            .await
+    }
+
+    async fn set_custom_css(&self, css: &str) -> Result<(), AppError> {
+        self.policy
+            .call("set_custom_css", || self.inner.set_custom_css(css))
+            .await
+    }
+
+    async fn set_branding(&self, branding: &BrandingSettings) -> Result<(), AppError> {
+        self.policy
+            .call("set_branding", || self.inner.set_branding(branding))
+            .await
+    }
+
+    async fn set_nav_links(&self, links: &NavLinks) -> Result<(), AppError> {
+        self.policy
+            .call("set_nav_links", || self.inner.set_nav_links(links))
+            .await
+    }
+
+    async fn set_search_settings(&self, search: &SearchSettings) -> Result<(), AppError> {
+        self.policy
+            .call("set_search_settings", || {
+                self.inner.set_search_settings(search)
+            })
+            .await
+    }
+
+    async fn set_protected_prefixes(&self, prefixes: &[String]) -> Result<(), AppError> {
+        self.policy
+            .call("set_protected_prefixes", || {
+                self.inner.set_protected_prefixes(prefixes)
+            })
+            .await
+    }
+
+    async fn set_feature_flags(&self, features: &FeatureFlags) -> Result<(), AppError> {
+        self.policy
+            .call("set_feature_flags", || {
+                self.inner.set_feature_flags(features)
+            })
+            .await
+    }
+
+    async fn set_incident_mode(&self, incident_mode: &IncidentMode) -> Result<(), AppError> {
+        self.policy
+            .call("set_incident_mode", || {
+                self.inner.set_incident_mode(incident_mode)
+            })
+            .await
+    }
+
+    async fn set_on_call(&self, on_call: &OnCallSettings) -> Result<(), AppError> {
+        self.policy
+            .call("set_on_call", || self.inner.set_on_call(on_call))
+            .await
+    }
+
+    async fn set_issue_tracker(
+        &self,
+        issue_tracker: &IssueTrackerSettings,
+    ) -> Result<(), AppError> {
+        self.policy
+            .call("set_issue_tracker", || {
+                self.inner.set_issue_tracker(issue_tracker)
+            })
+            .await
+    }
+
+    async fn set_ldap(&self, ldap: &LdapSettings) -> Result<(), AppError> {
+        self.policy
+            .call("set_ldap", || self.inner.set_ldap(ldap))
+            .await
+    }
+
+    async fn set_embeds(&self, embeds: &EmbedSettings) -> Result<(), AppError> {
+        self.policy
+            .call("set_embeds", || self.inner.set_embeds(embeds))
+            .await
+    }
+}
+
+/// [`AssetRepository`] decorator applying the `mongo` [`ResiliencePolicy`].
+pub struct ResilientAssetRepository {
+    inner: Arc<dyn AssetRepository>,
+    policy: ResiliencePolicy,
+}
+
+impl ResilientAssetRepository {
+    pub fn new(inner: Arc<dyn AssetRepository>, policy: ResiliencePolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl AssetRepository for ResilientAssetRepository {
+    async fn create_or_update(&self, asset: Asset) -> Result<(), AppError> {
+        self.policy
+            .call("create_or_update", || {
+                self.inner.create_or_update(asset.clone())
+            })
+            .await
+    }
+
+    async fn find_by_key(&self, key: &str) -> Result<Option<Asset>, AppError> {
+        self.policy
+            .call("find_by_key", || self.inner.find_by_key(key))
+            .await
+    }
+
+    async fn list_all(&self) -> Result<Vec<Asset>, AppError> {
+        self.policy.call("list_all", || self.inner.list_all()).await
+    }
+
+    async fn list_by_prefix(&self, prefix: &str) -> Result<Vec<Asset>, AppError> {
+        self.policy
+            .call("list_by_prefix", || self.inner.list_by_prefix(prefix))
+            .await
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        self.policy.call("delete", || self.inner.delete(key)).await
+    }
+}
+
+/// [`DocumentVersionRepository`] decorator applying the `mongo`
+/// [`ResiliencePolicy`].
+pub struct ResilientDocumentVersionRepository {
+    inner: Arc<dyn DocumentVersionRepository>,
+    policy: ResiliencePolicy,
+}
+
+impl ResilientDocumentVersionRepository {
+    pub fn new(inner: Arc<dyn DocumentVersionRepository>, policy: ResiliencePolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl DocumentVersionRepository for ResilientDocumentVersionRepository {
+    async fn create(&self, version: DocumentVersion) -> Result<(), AppError> {
+        // Not retried: a timed-out insert may have applied.
+        self.policy
+            .call_once("create", || self.inner.create(version.clone()))
+            .await
+    }
+
+    async fn find_latest(&self, slug: &str) -> Result<Option<DocumentVersion>, AppError> {
+        self.policy
+            .call("find_latest", || self.inner.find_latest(slug))
+            .await
+    }
+
+    async fn list_by_slug(&self, slug: &str) -> Result<Vec<DocumentVersion>, AppError> {
+        self.policy
+            .call("list_by_slug", || self.inner.list_by_slug(slug))
+            .await
+    }
+
+    async fn next_version_number(&self, slug: &str) -> Result<u64, AppError> {
+        self.policy
+            .call("next_version_number", || {
+                self.inner.next_version_number(slug)
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn policy(max_retries: u32, failure_threshold: u32) -> ResiliencePolicy {
+        ResiliencePolicy::new(
+            "test",
+            &ResilienceConfig {
+                timeout_ms: 50,
+                max_retries,
+                retry_base_delay_ms: 1,
+                failure_threshold,
+                open_duration_secs: 60,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors_until_success() {
+        let policy = policy(2, 10);
+        let attempts = AtomicU32::new(0);
+
+        let result = policy
+            .call("op", || async {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(AppError::Storage("boom".into()))
+                } else {
+                    Ok("done")
+                }
+            })
+            .await;
+
+        assert_eq!(result, Ok("done"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(policy.breaker().state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let policy = policy(2, 10);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), _> = policy
+            .call("op", || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(AppError::NotFound("missing".into()))
+            })
+            .await;
+
+        assert_eq!(result, Err(AppError::NotFound("missing".into())));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn slow_calls_time_out_and_open_the_circuit() {
+        let policy = policy(1, 2);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), _> = policy
+            .call("op", || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            })
+            .await;
+
+        assert!(matches!(result, Err(AppError::Unavailable(msg)) if msg.contains("timed out")));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(policy.breaker().state(), CircuitState::Open);
+
+        // Open circuit fails fast without calling the service
+        let result: Result<(), _> = policy
+            .call("op", || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+        assert!(matches!(result, Err(AppError::Unavailable(msg)) if msg.contains("open")));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn breaker_half_opens_after_open_duration_and_closes_on_success() {
+        let breaker = CircuitBreaker::new("test", 1, Duration::ZERO);
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert_eq!(breaker.admit(), Admission::Probe);
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.admit(), Admission::Allowed);
+    }
+
+    #[test]
+    fn half_open_breaker_lets_a_single_probe_through() {
+        let breaker = CircuitBreaker::new("test", 1, Duration::ZERO);
+        breaker.record_failure();
+
+        assert_eq!(breaker.admit(), Admission::Probe);
+        assert_eq!(breaker.admit(), Admission::Rejected);
+
+        // A failed probe re-opens the circuit; the next half-open period
+        // admits a new probe.
+        breaker.record_failure();
+        assert_eq!(breaker.admit(), Admission::Probe);
+    }
+
+    #[test]
+    fn cancelled_probe_frees_the_trial_slot() {
+        let breaker = CircuitBreaker::new("test", 1, Duration::ZERO);
+        breaker.record_failure();
+
+        assert_eq!(breaker.admit(), Admission::Probe);
+        drop(ProbeGuard(&breaker));
+        assert_eq!(breaker.admit(), Admission::Probe);
+    }
+
+    #[tokio::test]
+    async fn inserts_are_not_retried() {
+        let policy = policy(2, 10);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), _> = policy
+            .call_once("op", || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(AppError::Database("boom".into()))
+            })
+            .await;
+
+        assert_eq!(result, Err(AppError::Database("boom".into())));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn backoff_stays_within_exponential_cap() {
+        let base = Duration::from_millis(100);
+        for attempt in 0..4 {
+            assert!(backoff(base, attempt) <= base * (1 << attempt));
+        }
+    }
+}
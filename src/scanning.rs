@@ -0,0 +1,348 @@
+//! Malware scanning of uploads before they are stored (`[scanner]` config).
+//!
+//! Attachments (`PUT /api/v1/assets/{*key}`), editor uploads and images
+//! (`POST /api/v1/upload-image`) go through [`UploadScanner::screen`]:
+//!
+//! - clean files are stored as usual;
+//! - infected files are rejected with `400`, and their content is kept under
+//!   `quarantine/` with a [`QuarantinedUpload`] record listed in the admin API;
+//! - when the scanner is unreachable or fails the upload is rejected with
+//!   `503`, or stored unscanned with `scanner.fail_open`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::config::{ScannerConfig, ScannerKind};
+use crate::db::quarantine_repository::{QuarantineRepository, QuarantinedUpload};
+use crate::error::AppError;
+use crate::storage::client::StorageClient;
+
+/// Bytes sent to clamd per `INSTREAM` chunk.
+const CLAMD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Outcome of a scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Clean,
+    /// Infected, with the signature the scanner matched.
+    Infected(String),
+}
+
+/// Checks file content for malware.
+#[async_trait]
+pub trait Scanner: Send + Sync {
+    async fn scan(&self, data: &[u8]) -> Result<Verdict, AppError>;
+}
+
+/// [`Scanner`] backed by a clamd daemon, streaming the content with the
+/// `INSTREAM` command.
+pub struct ClamAvScanner {
+    address: String,
+    timeout: Duration,
+}
+
+impl ClamAvScanner {
+    pub fn new(address: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            address: address.into(),
+            timeout,
+        }
+    }
+
+    async fn instream(&self, data: &[u8]) -> std::io::Result<String> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut stream = tokio::net::TcpStream::connect(&self.address).await?;
+        stream.write_all(b"zINSTREAM\0").await?;
+        for chunk in data.chunks(CLAMD_CHUNK_SIZE) {
+            stream
+                .write_all(&(chunk.len() as u32).to_be_bytes())
+                .await?;
+            stream.write_all(chunk).await?;
+        }
+        stream.write_all(&0u32.to_be_bytes()).await?;
+
+        let mut reply = Vec::new();
+        stream.read_to_end(&mut reply).await?;
+        Ok(String::from_utf8_lossy(&reply).into_owned())
+    }
+}
+
+#[async_trait]
+impl Scanner for ClamAvScanner {
+    async fn scan(&self, data: &[u8]) -> Result<Verdict, AppError> {
+        let reply = tokio::time::timeout(self.timeout, self.instream(data))
+            .await
+            .map_err(|_| AppError::Unavailable("clamd did not answer in time".into()))?
+            .map_err(|e| AppError::Unavailable(format!("clamd at {}: {e}", self.address)))?;
+        parse_clamd_reply(&reply)
+    }
+}
+
+/// Parse a clamd `INSTREAM` reply such as `stream: OK` or
+/// `stream: Eicar-Signature FOUND`.
+pub fn parse_clamd_reply(reply: &str) -> Result<Verdict, AppError> {
+    let reply = reply.trim_end_matches(['\0', '\n']).trim();
+    let result = reply.strip_prefix("stream:").unwrap_or(reply).trim();
+    if result == "OK" {
+        Ok(Verdict::Clean)
+    } else if let Some(signature) = result.strip_suffix("FOUND") {
+        Ok(Verdict::Infected(signature.trim().to_string()))
+    } else {
+        Err(AppError::Unavailable(format!("clamd failed: {reply}")))
+    }
+}
+
+/// [`Scanner`] posting the content to an HTTP service, which answers
+/// `{"clean": true}` or `{"clean": false, "signature": "..."}`.
+pub struct HttpScanner {
+    client: reqwest::Client,
+    url: String,
+    token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct HttpVerdict {
+    clean: bool,
+    #[serde(default)]
+    signature: Option<String>,
+}
+
+impl HttpScanner {
+    pub fn new(url: impl Into<String>, token: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(timeout)
+                .build()
+                .unwrap_or_default(),
+            url: url.into(),
+            token: token.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Scanner for HttpScanner {
+    async fn scan(&self, data: &[u8]) -> Result<Verdict, AppError> {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(data.to_vec());
+        if !self.token.is_empty() {
+            request = request.bearer_auth(&self.token);
+        }
+        let verdict: HttpVerdict = request
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| AppError::Unavailable(format!("Upload scanner failed: {e}")))?
+            .json()
+            .await
+            .map_err(|e| AppError::Unavailable(format!("Invalid upload scanner answer: {e}")))?;
+        Ok(if verdict.clean {
+            Verdict::Clean
+        } else {
+            Verdict::Infected(verdict.signature.unwrap_or_else(|| "unknown".to_string()))
+        })
+    }
+}
+
+/// An upload about to be stored.
+pub struct Upload<'a> {
+    /// Where it will be stored (`assets/<key>` or `images/<file>`).
+    pub target_key: &'a str,
+    pub content_type: &'a str,
+    pub uploaded_by: &'a str,
+    pub data: &'a [u8],
+}
+
+/// Screens uploads and quarantines infected ones; see the module docs.
+pub struct UploadScanner {
+    scanner: Arc<dyn Scanner>,
+    fail_open: bool,
+    quarantine: Arc<dyn QuarantineRepository>,
+    storage: Arc<dyn StorageClient>,
+}
+
+impl UploadScanner {
+    pub fn new(
+        scanner: Arc<dyn Scanner>,
+        fail_open: bool,
+        quarantine: Arc<dyn QuarantineRepository>,
+        storage: Arc<dyn StorageClient>,
+    ) -> Self {
+        Self {
+            scanner,
+            fail_open,
+            quarantine,
+            storage,
+        }
+    }
+
+    /// The configured scanner; `None` when scanning is disabled.
+    pub fn from_config(
+        config: &ScannerConfig,
+        quarantine: Arc<dyn QuarantineRepository>,
+        storage: Arc<dyn StorageClient>,
+    ) -> Option<Self> {
+        let timeout = Duration::from_secs(config.timeout_secs);
+        let scanner: Arc<dyn Scanner> = match config.kind {
+            ScannerKind::None => return None,
+            ScannerKind::Clamav => Arc::new(ClamAvScanner::new(&config.clamav_address, timeout)),
+            ScannerKind::Http => Arc::new(HttpScanner::new(
+                &config.http_url,
+                &config.http_token,
+                timeout,
+            )),
+        };
+        Some(Self::new(scanner, config.fail_open, quarantine, storage))
+    }
+
+    /// Scan `upload`. Returns an error when it must not be stored.
+    pub async fn screen(&self, upload: Upload<'_>) -> Result<(), AppError> {
+        let signature = match self.scanner.scan(upload.data).await {
+            Ok(Verdict::Clean) => return Ok(()),
+            Ok(Verdict::Infected(signature)) => signature,
+            Err(e) if self.fail_open => {
+                tracing::warn!(
+                    target_key = upload.target_key,
+                    "Upload stored unscanned, the scanner failed: {e}"
+                );
+                return Ok(());
+            }
+            Err(e) => {
+                e.log();
+                return Err(AppError::Unavailable(
+                    "Uploads cannot be scanned for malware right now".into(),
+                ));
+            }
+        };
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let s3_key = format!("quarantine/{id}");
+        self.storage
+            .put_object(&s3_key, upload.data.to_vec())
+            .await?;
+        self.quarantine
+            .create(QuarantinedUpload {
+                id,
+                s3_key,
+                target_key: upload.target_key.to_string(),
+                content_type: upload.content_type.to_string(),
+                size_bytes: upload.data.len() as u64,
+                signature: signature.clone(),
+                uploaded_by: upload.uploaded_by.to_string(),
+                quarantined_at: chrono::Utc::now(),
+            })
+            .await?;
+        tracing::warn!(
+            target_key = upload.target_key,
+            uploaded_by = upload.uploaded_by,
+            signature = %signature,
+            "Quarantined infected upload"
+        );
+        Err(AppError::BadRequest(format!(
+            "Upload rejected: malware detected ({signature})"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use crate::testing::InMemoryStorage;
+
+    struct FixedScanner(Result<Verdict, String>);
+
+    #[async_trait]
+    impl Scanner for FixedScanner {
+        async fn scan(&self, _: &[u8]) -> Result<Verdict, AppError> {
+            self.0.clone().map_err(AppError::Unavailable)
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryQuarantine(Mutex<Vec<QuarantinedUpload>>);
+
+    #[async_trait]
+    impl QuarantineRepository for MemoryQuarantine {
+        async fn create(&self, upload: QuarantinedUpload) -> Result<(), AppError> {
+            self.0.lock().unwrap().push(upload);
+            Ok(())
+        }
+        async fn find_by_id(&self, id: &str) -> Result<Option<QuarantinedUpload>, AppError> {
+            Ok(self.0.lock().unwrap().iter().find(|u| u.id == id).cloned())
+        }
+        async fn list_recent(&self, _: i64) -> Result<Vec<QuarantinedUpload>, AppError> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+        async fn delete(&self, id: &str) -> Result<(), AppError> {
+            self.0.lock().unwrap().retain(|u| u.id != id);
+            Ok(())
+        }
+    }
+
+    fn upload(data: &[u8]) -> Upload<'_> {
+        Upload {
+            target_key: "assets/ops/tool.exe",
+            content_type: "application/octet-stream",
+            uploaded_by: "ci",
+            data,
+        }
+    }
+
+    #[test]
+    fn test_parse_clamd_reply() {
+        assert_eq!(parse_clamd_reply("stream: OK\0").unwrap(), Verdict::Clean);
+        assert_eq!(
+            parse_clamd_reply("stream: Eicar-Signature FOUND\0").unwrap(),
+            Verdict::Infected("Eicar-Signature".to_string())
+        );
+        assert!(parse_clamd_reply("INSTREAM size limit exceeded. ERROR\0").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_screen_quarantines_infected_uploads() {
+        let quarantine = Arc::new(MemoryQuarantine::default());
+        let storage = Arc::new(InMemoryStorage::new());
+        let scanner = UploadScanner::new(
+            Arc::new(FixedScanner(Ok(Verdict::Infected("Eicar".into())))),
+            false,
+            quarantine.clone(),
+            storage.clone(),
+        );
+
+        let err = scanner.screen(upload(b"X5O!P%@AP")).await.unwrap_err();
+        assert!(matches!(err, AppError::BadRequest(msg) if msg.contains("Eicar")));
+
+        let held = quarantine.0.lock().unwrap().clone();
+        assert_eq!(held.len(), 1);
+        assert_eq!(held[0].target_key, "assets/ops/tool.exe");
+        let objects = storage.objects.lock().unwrap();
+        assert_eq!(objects.keys().collect::<Vec<_>>(), vec![&held[0].s3_key]);
+    }
+
+    #[tokio::test]
+    async fn test_screen_failures_follow_fail_open() {
+        let screen = |fail_open| {
+            UploadScanner::new(
+                Arc::new(FixedScanner(Err("connection refused".into()))),
+                fail_open,
+                Arc::new(MemoryQuarantine::default()),
+                Arc::new(InMemoryStorage::new()),
+            )
+        };
+
+        assert!(matches!(
+            screen(false).screen(upload(b"data")).await,
+            Err(AppError::Unavailable(_))
+        ));
+        assert!(screen(true).screen(upload(b"data")).await.is_ok());
+    }
+}
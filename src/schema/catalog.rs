@@ -0,0 +1,295 @@
+//! Event catalog: the channels (topics) of every AsyncAPI schema, with the
+//! schemas producing and consuming on each.
+//!
+//! Channels and operations are extracted from AsyncAPI specs at ingest (see
+//! [`crate::api::schemas::extract_schema_endpoints`]); the catalog reads the
+//! latest version of each schema the caller can see. Operation actions map to
+//! roles from the point of view of the schema's application:
+//!
+//! - AsyncAPI 2: `subscribe` (others subscribe to what the application sends)
+//!   makes it a producer, `publish` a consumer,
+//! - AsyncAPI 3: `send` makes it a producer, `receive` a consumer.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::db::models::Schema;
+use crate::i18n::use_i18n;
+
+/// A schema producing or consuming on a channel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventParticipant {
+    pub schema: String,
+    pub version: String,
+    pub service_owner: String,
+    /// Summary of the operation, when the spec has one.
+    pub summary: Option<String>,
+}
+
+/// A channel and the schemas using it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventChannel {
+    pub channel: String,
+    pub producers: Vec<EventParticipant>,
+    pub consumers: Vec<EventParticipant>,
+}
+
+#[cfg(feature = "ssr")]
+enum Role {
+    Producer,
+    Consumer,
+}
+
+#[cfg(feature = "ssr")]
+fn role(action: &str) -> Option<Role> {
+    match action {
+        "subscribe" | "send" => Some(Role::Producer),
+        "publish" | "receive" => Some(Role::Consumer),
+        _ => None,
+    }
+}
+
+/// Build the catalog from `schemas` as seen by callers with `allowed_levels`,
+/// sorted by channel.
+#[cfg(feature = "ssr")]
+pub fn build_event_catalog(
+    schemas: &[Schema],
+    allowed_levels: Option<&[String]>,
+) -> Vec<EventChannel> {
+    use std::collections::BTreeMap;
+
+    let mut channels: BTreeMap<String, EventChannel> = BTreeMap::new();
+    for schema in schemas.iter().filter(|s| s.schema_type == "asyncapi") {
+        let Some(latest) = crate::api::schemas::latest_visible_version(schema, allowed_levels)
+        else {
+            continue;
+        };
+        for endpoint in &latest.endpoints {
+            let Some(role) = role(&endpoint.method) else {
+                continue;
+            };
+            let entry = channels
+                .entry(endpoint.path.clone())
+                .or_insert_with(|| EventChannel {
+                    channel: endpoint.path.clone(),
+                    producers: vec![],
+                    consumers: vec![],
+                });
+            let participants = match role {
+                Role::Producer => &mut entry.producers,
+                Role::Consumer => &mut entry.consumers,
+            };
+            if participants.iter().any(|p| p.schema == schema.name) {
+                continue;
+            }
+            participants.push(EventParticipant {
+                schema: schema.name.clone(),
+                version: latest.version.clone(),
+                service_owner: schema.service_owner.clone(),
+                summary: endpoint.summary.clone(),
+            });
+        }
+    }
+    channels.into_values().collect()
+}
+
+/// Server function returning the event catalog visible to the caller.
+#[server(GetEventCatalog, "/api")]
+pub async fn get_event_catalog() -> Result<Vec<EventChannel>, ServerFnError> {
+    let state = expect_context::<crate::app::AppState>();
+    let (allowed_levels, _) = crate::server::request_document_visibility(&state).await?;
+    let schemas = state
+        .schema_repo
+        .list_all()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(build_event_catalog(&schemas, allowed_levels.as_deref()))
+}
+
+/// Event catalog page — channels across all AsyncAPI schemas.
+#[component]
+pub fn EventCatalogPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let catalog = Resource::new(|| (), |_| get_event_catalog());
+    let filter = RwSignal::new(String::new());
+
+    view! {
+        <div>
+            <div class="breadcrumbs text-sm mb-4">
+                <ul>
+                    <li><a href="/">{i18n.t("schemas.breadcrumb_home")}</a></li>
+                    <li><a href="/schemas">{i18n.t("schemas.breadcrumb_schemas")}</a></li>
+                    <li>{i18n.t("catalog.breadcrumb")}</li>
+                </ul>
+            </div>
+            <h1 class="text-3xl font-bold mb-6">{i18n.t("catalog.title")}</h1>
+            <p class="text-base-content/70 mb-8">
+                {i18n.t("catalog.intro")}
+            </p>
+            <input
+                type="search"
+                class="input input-bordered input-sm w-full sm:w-80 mb-6"
+                placeholder=i18n.t("catalog.filter_placeholder")
+                prop:value=move || filter.get()
+                on:input=move |ev| filter.set(event_target_value(&ev))
+            />
+
+            <Suspense fallback=move || view! {
+                <div class="flex justify-center py-12">
+                    <span class="loading loading-spinner loading-lg"></span>
+                </div>
+            }>
+                {move || catalog.get().map(|result| match result {
+                    Ok(channels) if channels.is_empty() => view! {
+                        <div class="alert alert-info">
+                            <span>{i18n.t("catalog.empty")}</span>
+                        </div>
+                    }.into_any(),
+                    Ok(channels) => {
+                        let query = filter.get().to_lowercase();
+                        let channels: Vec<EventChannel> = channels
+                            .into_iter()
+                            .filter(|c| {
+                                query.is_empty()
+                                    || c.channel.to_lowercase().contains(&query)
+                                    || c.producers.iter().chain(&c.consumers).any(|p| {
+                                        p.schema.to_lowercase().contains(&query)
+                                            || p.service_owner.to_lowercase().contains(&query)
+                                    })
+                            })
+                            .collect();
+                        if channels.is_empty() {
+                            return view! {
+                                <p class="text-base-content/60">{i18n.t("catalog.no_match")}</p>
+                            }.into_any();
+                        }
+                        view! {
+                            <div class="overflow-x-auto">
+                                <table class="table">
+                                    <thead>
+                                        <tr>
+                                            <th>{i18n.t("catalog.col_channel")}</th>
+                                            <th>{i18n.t("catalog.col_producers")}</th>
+                                            <th>{i18n.t("catalog.col_consumers")}</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {channels.into_iter().map(|channel| view! {
+                                            <tr class="align-top">
+                                                <td class="font-mono text-sm">{channel.channel}</td>
+                                                <td><Participants participants=channel.producers /></td>
+                                                <td><Participants participants=channel.consumers /></td>
+                                            </tr>
+                                        }).collect::<Vec<_>>()}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any()
+                    }
+                    Err(e) => view! {
+                        <div class="alert alert-error">
+                            <span>{i18n.tf("catalog.load_error", &[("error", &e.to_string())])}</span>
+                        </div>
+                    }.into_any(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Links to the schemas on one side of a channel.
+#[component]
+fn Participants(participants: Vec<EventParticipant>) -> impl IntoView {
+    if participants.is_empty() {
+        return view! { <span class="text-base-content/40">"—"</span> }.into_any();
+    }
+    view! {
+        <ul class="space-y-1">
+            {participants.into_iter().map(|p| {
+                let href = format!("/schemas/{}?version={}", p.schema, p.version);
+                view! {
+                    <li>
+                        <a href=href class="link link-hover font-medium">{p.schema}</a>
+                        {(!p.service_owner.is_empty()).then(|| view! {
+                            <span class="text-xs text-base-content/50">{format!(" · {}", p.service_owner)}</span>
+                        })}
+                        {p.summary.map(|summary| view! {
+                            <p class="text-xs text-base-content/60">{summary}</p>
+                        })}
+                    </li>
+                }
+            }).collect::<Vec<_>>()}
+        </ul>
+    }
+    .into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::{SchemaEndpoint, SchemaVersion};
+    use crate::testing;
+
+    fn endpoint(method: &str, path: &str) -> SchemaEndpoint {
+        SchemaEndpoint {
+            method: method.into(),
+            path: path.into(),
+            summary: None,
+        }
+    }
+
+    fn schema(name: &str, access_level: &str, endpoints: Vec<SchemaEndpoint>) -> Schema {
+        let version = SchemaVersion {
+            access_level: access_level.into(),
+            endpoints,
+            ..testing::schema_version(name, "1.0.0")
+        };
+        Schema {
+            schema_type: "asyncapi".into(),
+            service_owner: "events".into(),
+            ..testing::schema(name, vec![version])
+        }
+    }
+
+    #[test]
+    fn test_build_event_catalog() {
+        let schemas = vec![
+            schema(
+                "orders",
+                "public",
+                vec![
+                    endpoint("subscribe", "orders.created"),
+                    endpoint("publish", "payments.settled"),
+                ],
+            ),
+            schema(
+                "payments",
+                "public",
+                vec![endpoint("send", "payments.settled")],
+            ),
+            schema(
+                "audit",
+                "internal",
+                vec![endpoint("receive", "orders.created")],
+            ),
+        ];
+
+        let public = vec!["public".to_string()];
+        let catalog = build_event_catalog(&schemas, Some(&public));
+        assert_eq!(
+            catalog
+                .iter()
+                .map(|c| c.channel.as_str())
+                .collect::<Vec<_>>(),
+            ["orders.created", "payments.settled"]
+        );
+        assert_eq!(catalog[0].producers[0].schema, "orders");
+        assert!(catalog[0].consumers.is_empty());
+        assert_eq!(catalog[1].producers[0].schema, "payments");
+        assert_eq!(catalog[1].consumers[0].schema, "orders");
+
+        let catalog = build_event_catalog(&schemas, None);
+        assert_eq!(catalog[0].consumers[0].schema, "audit");
+    }
+}
@@ -1,7 +1,17 @@
 use leptos::prelude::*;
 use leptos_meta::Link;
 
-use crate::api::schemas::{SchemaDetail, SchemaListItem, SchemaVersionInfo};
+use crate::api::schemas::{
+    contact_href, operation_anchor, schema_raw_path, SchemaDetail, SchemaListItem, SchemaListQuery,
+    SchemaListResult, SchemaOperationHit, SchemaVersionInfo,
+};
+use crate::components::{access_denied_level, AccessDenied};
+use crate::db::models::SchemaEndpoint;
+use crate::db::schema_repository::SchemaSort;
+use crate::i18n::use_i18n;
+use crate::schema::contracts::ContractMatrixPanel;
+use crate::schema::snippets::OperationSnippets;
+use serde::{Deserialize, Serialize};
 
 /// Server function to list all schemas.
 #[server(ListSchemas, "/api")]
@@ -10,21 +20,92 @@ pub async fn list_schemas() -> Result<Vec<SchemaListItem>, ServerFnError> {
     let (allowed_levels, _) = crate::server::request_document_visibility(&state).await?;
     crate::api::schemas::process_list_schemas(state.schema_repo.as_ref(), allowed_levels.as_deref())
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))
+        .map_err(crate::error::AppError::into_server_fn_error)
 }
 
-/// Server function to get schema details.
+/// Server function to list one filtered, sorted page of schemas.
+#[server(ListSchemaPage, "/api")]
+pub async fn list_schema_page(query: SchemaListQuery) -> Result<SchemaListResult, ServerFnError> {
+    let state = expect_context::<crate::app::AppState>();
+    let (allowed_levels, _) = crate::server::request_document_visibility(&state).await?;
+    crate::api::schemas::process_list_schemas_page(
+        state.schema_repo.as_ref(),
+        query,
+        allowed_levels.as_deref(),
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+/// Server function to get schema details. `name` may also be a viewer deep
+/// link path, `<name>/<version>`; an exact schema name wins.
 #[server(GetSchemaDetail, "/api")]
 pub async fn get_schema_detail(name: String) -> Result<SchemaDetail, ServerFnError> {
+    use crate::api::schemas::process_get_schema;
+    use crate::error::AppError;
+
+    let state = expect_context::<crate::app::AppState>();
+    let (allowed_levels, _) = crate::server::request_document_visibility(&state).await?;
+    let repo = state.schema_repo.as_ref();
+    let result = match process_get_schema(repo, &name, allowed_levels.as_deref()).await {
+        Err(AppError::NotFound(msg)) => match name.rsplit_once('/') {
+            Some((schema, version)) => {
+                match process_get_schema(repo, schema, allowed_levels.as_deref()).await {
+                    Ok(detail) if detail.versions.iter().any(|v| v.version == version) => {
+                        Ok(detail)
+                    }
+                    _ => Err(AppError::NotFound(msg)),
+                }
+            }
+            None => Err(AppError::NotFound(msg)),
+        },
+        result => result,
+    };
+    match result {
+        Err(crate::error::AppError::NotFound(msg)) => {
+            // Existing schema restricted to its owner, or whose versions are
+            // all above the caller's level
+            let schema = state
+                .schema_repo
+                .find_by_name_summary(&name)
+                .await
+                .map_err(crate::error::AppError::into_server_fn_error)?;
+            let mut levels: Vec<String> = schema
+                .into_iter()
+                .flat_map(|s| {
+                    s.owner_access_level
+                        .into_iter()
+                        .chain(s.versions.into_iter().map(|v| v.access_level))
+                })
+                .collect();
+            levels.sort();
+            levels.dedup();
+            if levels.is_empty() {
+                return Err(crate::error::AppError::NotFound(msg).into_server_fn_error());
+            }
+            Err(ServerFnError::new(
+                crate::auth::models::access_denied_message(&levels.join(", ")),
+            ))
+        }
+        result => result.map_err(crate::error::AppError::into_server_fn_error),
+    }
+}
+
+/// Server function searching the operations of the latest version of every
+/// schema, for the search modal.
+#[server(SearchSchemaOperations, "/api")]
+pub async fn search_schema_operations(
+    query: String,
+) -> Result<Vec<SchemaOperationHit>, ServerFnError> {
     let state = expect_context::<crate::app::AppState>();
     let (allowed_levels, _) = crate::server::request_document_visibility(&state).await?;
-    crate::api::schemas::process_get_schema(
+    crate::api::schemas::process_search_operations(
         state.schema_repo.as_ref(),
-        &name,
+        &query,
         allowed_levels.as_deref(),
     )
     .await
-    .map_err(|e| ServerFnError::new(e.to_string()))
+    .map_err(crate::error::AppError::into_server_fn_error)
 }
 
 /// Server function to get raw schema content for a specific version.
@@ -40,57 +121,272 @@ pub async fn get_schema_content(name: String, version: String) -> Result<String,
         allowed_levels.as_deref(),
     )
     .await
-    .map_err(|e| ServerFnError::new(e.to_string()))
+    .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+/// Server function to generate request snippets for every operation of an
+/// OpenAPI schema version.
+#[server(GetSchemaSnippets, "/api")]
+pub async fn get_schema_snippets(
+    name: String,
+    version: String,
+) -> Result<Vec<OperationSnippets>, ServerFnError> {
+    let state = expect_context::<crate::app::AppState>();
+    let (allowed_levels, _) = crate::server::request_document_visibility(&state).await?;
+    let content = crate::api::schemas::process_get_schema_content(
+        state.schema_repo.as_ref(),
+        state.storage_client.as_ref(),
+        &name,
+        &version,
+        allowed_levels.as_deref(),
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(crate::schema::snippets::generate_openapi_snippets(&content))
+}
+
+/// Server function listing the operations of a schema version, for the
+/// built-in viewer.
+#[server(GetSchemaOutline, "/api")]
+pub async fn get_schema_outline(
+    name: String,
+    version: String,
+    schema_type: String,
+) -> Result<Vec<SchemaEndpoint>, ServerFnError> {
+    let state = expect_context::<crate::app::AppState>();
+    let (allowed_levels, _) = crate::server::request_document_visibility(&state).await?;
+    let content = crate::api::schemas::process_get_schema_content(
+        state.schema_repo.as_ref(),
+        state.storage_client.as_ref(),
+        &name,
+        &version,
+        allowed_levels.as_deref(),
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(crate::api::schemas::extract_schema_endpoints(
+        &schema_type,
+        &content,
+    ))
 }
 
-/// Schema list page — shows all registered schemas.
+/// URLs of the interactive schema viewer bundles.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpecViewerAssets {
+    pub scalar_js: String,
+    pub scalar_css: String,
+    pub asyncapi_js: String,
+    pub asyncapi_css: String,
+}
+
+/// Instance-level options of the schema viewer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpecViewerSettings {
+    /// Whether Scalar sends "Try it" requests through `/api/v1/try-it`.
+    pub try_it_proxy: bool,
+    /// Where the interactive viewers are loaded from; `None` selects the
+    /// built-in viewer.
+    pub assets: Option<SpecViewerAssets>,
+}
+
+/// Server function returning the schema viewer settings.
+#[server(GetSpecViewerSettings, "/api")]
+pub async fn get_spec_viewer_settings() -> Result<SpecViewerSettings, ServerFnError> {
+    let state = expect_context::<crate::app::AppState>();
+    Ok(SpecViewerSettings {
+        try_it_proxy: state.try_it.is_some(),
+        assets: state.spec_viewer_assets.clone(),
+    })
+}
+
+/// Schemas shown per page of the schema list.
+const SCHEMAS_PER_PAGE: u64 = 24;
+
+/// Schema list page — searchable, filterable and paginated.
 #[component]
 pub fn SchemaListPage() -> impl IntoView {
-    let schemas_resource = Resource::new(|| (), |_| list_schemas());
+    let i18n = use_i18n();
+    let search = RwSignal::new(String::new());
+    let schema_type = RwSignal::new(String::new());
+    let owner = RwSignal::new(String::new());
+    let tag = RwSignal::new(String::new());
+    let sort = RwSignal::new(SchemaSort::Name);
+    let page = RwSignal::new(0u64);
+
+    let schemas_resource = Resource::new(
+        move || {
+            let selected = |signal: RwSignal<String>| Some(signal.get()).filter(|v| !v.is_empty());
+            SchemaListQuery {
+                q: selected(search),
+                schema_type: selected(schema_type),
+                owner: selected(owner),
+                tag: selected(tag),
+                sort: sort.get(),
+                page: page.get(),
+                per_page: Some(SCHEMAS_PER_PAGE),
+            }
+        },
+        list_schema_page,
+    );
+    // Filter options from the last load, kept while the next one is pending.
+    let options = RwSignal::new((Vec::<String>::new(), Vec::<String>::new()));
+    Effect::new(move |_| {
+        if let Some(Ok(result)) = schemas_resource.get() {
+            options.set((result.service_owners, result.tags));
+        }
+    });
+    // Changing a filter starts over from the first page.
+    let set_filter = move |signal: RwSignal<String>, value: String| {
+        signal.set(value);
+        page.set(0);
+    };
+    let filtered = move || {
+        !search.get().is_empty()
+            || !schema_type.get().is_empty()
+            || !owner.get().is_empty()
+            || !tag.get().is_empty()
+    };
 
     view! {
         <div>
-            <h1 class="text-3xl font-bold mb-6">"Schema Registry"</h1>
+            <h1 class="text-3xl font-bold mb-6">{i18n.t("schemas.title")}</h1>
             <p class="text-base-content/70 mb-8">
-                "Browse and explore API schemas. Supports OpenAPI, AsyncAPI, and JSON Schema specifications."
+                {i18n.t("schemas.intro")}
             </p>
 
-            <Suspense fallback=move || view! {
+            <div class="flex flex-wrap items-end gap-3 mb-6">
+                <input
+                    type="search"
+                    class="input input-bordered input-sm w-full sm:w-64"
+                    placeholder=i18n.t("schemas.search_placeholder")
+                    prop:value=move || search.get()
+                    on:input=move |ev| set_filter(search, event_target_value(&ev))
+                />
+                <select
+                    class="select select-bordered select-sm"
+                    aria-label=i18n.t("schemas.type")
+                    prop:value=move || schema_type.get()
+                    on:change=move |ev| set_filter(schema_type, event_target_value(&ev))
+                >
+                    <option value="">{i18n.t("schemas.all_types")}</option>
+                    <option value="openapi">"OpenAPI"</option>
+                    <option value="asyncapi">"AsyncAPI"</option>
+                    <option value="jsonschema">"JSON Schema"</option>
+                </select>
+                <select
+                    class="select select-bordered select-sm"
+                    aria-label=i18n.t("schemas.owner_filter")
+                    prop:value=move || owner.get()
+                    on:change=move |ev| set_filter(owner, event_target_value(&ev))
+                >
+                    <option value="">{i18n.t("schemas.all_owners")}</option>
+                    {move || options.get().0.into_iter().map(|o| view! {
+                        <option value=o.clone() selected=owner.get_untracked() == o>{o.clone()}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+                <select
+                    class="select select-bordered select-sm"
+                    aria-label=i18n.t("schemas.tag")
+                    prop:value=move || tag.get()
+                    on:change=move |ev| set_filter(tag, event_target_value(&ev))
+                >
+                    <option value="">{i18n.t("schemas.all_tags")}</option>
+                    {move || options.get().1.into_iter().map(|t| view! {
+                        <option value=t.clone() selected=tag.get_untracked() == t>{t.clone()}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+                <select
+                    class="select select-bordered select-sm sm:ml-auto"
+                    aria-label=i18n.t("schemas.sort")
+                    on:change=move |ev| {
+                        sort.set(if event_target_value(&ev) == "updated_at" {
+                            SchemaSort::UpdatedAt
+                        } else {
+                            SchemaSort::Name
+                        });
+                        page.set(0);
+                    }
+                >
+                    <option value="name" selected=move || sort.get() == SchemaSort::Name>{i18n.t("schemas.sort_name")}</option>
+                    <option value="updated_at" selected=move || sort.get() == SchemaSort::UpdatedAt>{i18n.t("schemas.sort_updated")}</option>
+                </select>
+            </div>
+
+            <Transition fallback=move || view! {
                 <div class="flex justify-center py-12">
                     <span class="loading loading-spinner loading-lg"></span>
                 </div>
             }>
                 {move || {
                     schemas_resource.get().map(|result| match result {
-                        Ok(schemas) if schemas.is_empty() => {
+                        Ok(result) if result.items.is_empty() && filtered() => {
+                            view! {
+                                <div class="alert">
+                                    <span>{i18n.t("schemas.no_match")}</span>
+                                </div>
+                            }.into_any()
+                        }
+                        Ok(result) if result.items.is_empty() => {
                             view! {
                                 <div class="alert alert-info">
                                     <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="stroke-current shrink-0 w-6 h-6">
                                         <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z"></path>
                                     </svg>
-                                    <span>"No schemas registered yet. Use the ingestion API to add schemas."</span>
+                                    <span>{i18n.t("schemas.empty")}</span>
                                 </div>
                             }.into_any()
                         }
-                        Ok(schemas) => {
+                        Ok(result) => {
+                            let total = result.total;
+                            let total_pages = total.div_ceil(result.per_page.max(1));
                             view! {
                                 <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-6">
-                                    {schemas.into_iter().map(|schema| {
+                                    {result.items.into_iter().map(|schema| {
                                         view! { <SchemaCard schema=schema /> }
                                     }).collect::<Vec<_>>()}
                                 </div>
+                                <div class="flex items-center justify-between mt-6">
+                                    <span class="text-sm text-base-content/50">
+                                        {if total == 1 {
+                                            i18n.t("schemas.count_one").to_string()
+                                        } else {
+                                            i18n.tf("schemas.count", &[("count", &total.to_string())])
+                                        }}
+                                    </span>
+                                    {(total_pages > 1).then(|| view! {
+                                        <div class="join">
+                                            <button
+                                                class="join-item btn btn-sm"
+                                                disabled=move || page.get() == 0
+                                                on:click=move |_| page.update(|p| *p = p.saturating_sub(1))
+                                            >
+                                                "«"
+                                            </button>
+                                            <button class="join-item btn btn-sm btn-disabled">
+                                                {move || format!("{} / {}", page.get() + 1, total_pages)}
+                                            </button>
+                                            <button
+                                                class="join-item btn btn-sm"
+                                                disabled=move || page.get() + 1 >= total_pages
+                                                on:click=move |_| page.update(|p| *p += 1)
+                                            >
+                                                "»"
+                                            </button>
+                                        </div>
+                                    })}
+                                </div>
                             }.into_any()
                         }
                         Err(e) => {
                             view! {
                                 <div class="alert alert-error">
-                                    <span>{format!("Error loading schemas: {e}")}</span>
+                                    <span>{i18n.tf("schemas.load_error", &[("error", &e.to_string())])}</span>
                                 </div>
                             }.into_any()
                         }
                     })
                 }}
-            </Suspense>
+            </Transition>
         </div>
     }
 }
@@ -98,6 +394,7 @@ pub fn SchemaListPage() -> impl IntoView {
 /// Card component for a schema in the list view.
 #[component]
 fn SchemaCard(schema: SchemaListItem) -> impl IntoView {
+    let i18n = use_i18n();
     let badge_class = match schema.schema_type.as_str() {
         "openapi" => "badge-primary",
         "asyncapi" => "badge-secondary",
@@ -113,7 +410,10 @@ fn SchemaCard(schema: SchemaListItem) -> impl IntoView {
     };
 
     let href = format!("/schemas/{}", schema.name);
-    let version_text = schema.latest_version.as_deref().unwrap_or("no versions");
+    let version_text = schema
+        .latest_version
+        .as_deref()
+        .unwrap_or(i18n.t("schemas.no_versions"));
 
     view! {
         <a href=href class="card bg-base-100 border border-base-200/50 hover:border-primary/30 shadow-sm hover:shadow-md transition-all cursor-pointer group">
@@ -124,11 +424,34 @@ fn SchemaCard(schema: SchemaListItem) -> impl IntoView {
                 <div class="flex items-center gap-2 mt-2">
                     <span class=format!("badge {} badge-sm font-semibold", badge_class)>{type_label.to_string()}</span>
                     <span class="text-sm text-base-content/50">
-                        {format!("{} version{}", schema.version_count, if schema.version_count == 1 { "" } else { "s" })}
+                        {if schema.version_count == 1 {
+                            i18n.t("schemas.version_count_one").to_string()
+                        } else {
+                            i18n.tf("schemas.version_count", &[("count", &schema.version_count.to_string())])
+                        }}
                     </span>
+                    {schema.updated_at.map(|at| view! {
+                        <span class="text-sm text-base-content/50 ml-auto">
+                            {i18n.tf("schemas.updated", &[("date", &at.format("%Y-%m-%d").to_string())])}
+                        </span>
+                    })}
                 </div>
+                {schema.description.map(|description| view! {
+                    <p class="text-sm text-base-content/70 mt-3 line-clamp-2">{description}</p>
+                })}
+                // The card is itself a link, so the contact is shown as text only.
+                {(!schema.service_owner.is_empty() || schema.contact.is_some()).then(|| {
+                    let owner = [Some(schema.service_owner).filter(|o| !o.is_empty()), schema.contact]
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .join(" · ");
+                    view! {
+                        <p class="text-xs text-base-content/50 mt-2 truncate">{i18n.tf("schemas.owner", &[("owner", &owner)])}</p>
+                    }
+                })}
                 <div class="mt-4 pt-4 border-t border-base-200 flex items-center justify-between text-sm">
-                    <span class="text-base-content/60">"Latest version"</span>
+                    <span class="text-base-content/60">{i18n.t("schemas.latest_version")}</span>
                     <span class="font-mono bg-base-200 px-2 py-1 rounded text-xs text-base-content/80 font-medium">{version_text.to_string()}</span>
                 </div>
             </div>
@@ -136,16 +459,67 @@ fn SchemaCard(schema: SchemaListItem) -> impl IntoView {
     }
 }
 
+/// Description, owner, contact and repository of a schema, shown under the
+/// viewer header. Renders nothing when none are set.
+#[component]
+fn SchemaAbout(detail: SchemaDetail) -> impl IntoView {
+    let i18n = use_i18n();
+    let has_links = !detail.service_owner.is_empty()
+        || detail.contact.is_some()
+        || detail.repository_url.is_some()
+        || !detail.tags.is_empty();
+    view! {
+        {detail.description.map(|description| view! {
+            <p class="text-base-content/70 max-w-3xl -mt-2 mb-4">{description}</p>
+        })}
+        {has_links.then(|| view! {
+            <div class="flex flex-wrap items-center gap-x-6 gap-y-2 text-sm mb-6">
+                {(!detail.service_owner.is_empty()).then(|| view! {
+                    <span>
+                        <span class="text-base-content/50">{i18n.around("schemas.owner", "owner").0}</span>
+                        <span class="font-medium">{detail.service_owner.clone()}</span>
+                    </span>
+                })}
+                {detail.contact.map(|contact| view! {
+                    <span>
+                        <span class="text-base-content/50">{i18n.around("schemas.contact", "contact").0}</span>
+                        <a href=contact_href(&contact) class="link link-primary">{contact.clone()}</a>
+                    </span>
+                })}
+                {detail.repository_url.map(|url| view! {
+                    <a href=url.clone() class="link link-primary" target="_blank" rel="noopener noreferrer">
+                        {i18n.t("schemas.repository")}
+                    </a>
+                })}
+                {(!detail.tags.is_empty()).then(|| view! {
+                    <span class="flex flex-wrap gap-1">
+                        {detail.tags.into_iter().map(|tag| view! {
+                            <span class="badge badge-ghost badge-sm">{tag}</span>
+                        }).collect::<Vec<_>>()}
+                    </span>
+                })}
+            </div>
+        })}
+    }
+}
+
 /// Schema viewer page — displays a schema with version selector and spec viewer.
 #[component]
 pub fn SchemaViewerPage() -> impl IntoView {
+    let i18n = use_i18n();
     let params = leptos_router::hooks::use_params_map();
     let name = move || params.read().get("name").unwrap_or_default();
+    // `?version=` preselects a version, e.g. from a documentation link, as
+    // does the `/schemas/<name>/<version>` form of operation deep links.
+    let query = leptos_router::hooks::use_query_map();
+    let linked_version = move || query.read().get("version");
 
     #[allow(clippy::redundant_closure)]
     let schema_resource = Resource::new(move || name(), |name| get_schema_detail(name));
 
     let (selected_version, set_selected_version) = signal(String::new());
+    // Schema name without the deep link version, once the detail has loaded.
+    let (resolved_name, set_resolved_name) = signal(String::new());
 
     // Reset version selection whenever the route points to a different schema.
     Effect::new(move |_| {
@@ -160,15 +534,17 @@ pub fn SchemaViewerPage() -> impl IntoView {
         move || name(),
         |name| async move {
             let detail = get_schema_detail(name.clone()).await?;
-            let default_ver = detail
-                .versions
-                .iter()
-                .rev()
-                .find(|v| v.status == "stable")
-                .or(detail.versions.last())
-                .map(|v| v.version.clone());
+            let default_ver = path_version(&name, &detail.name).or_else(|| {
+                detail
+                    .versions
+                    .iter()
+                    .rev()
+                    .find(|v| v.status == "stable")
+                    .or(detail.versions.last())
+                    .map(|v| v.version.clone())
+            });
             match default_ver {
-                Some(ver) => get_schema_content(name, ver.clone())
+                Some(ver) => get_schema_content(detail.name, ver.clone())
                     .await
                     .map(|c| Some((ver, c))),
                 None => Ok(None),
@@ -176,11 +552,13 @@ pub fn SchemaViewerPage() -> impl IntoView {
         },
     );
 
+    let viewer_settings = Resource::new(|| (), |_| get_spec_viewer_settings());
+
     // When schema loads, select the latest stable version by default
     let content_resource = Resource::new(
-        move || (name(), selected_version.get()),
+        move || (resolved_name.get(), selected_version.get()),
         move |(name, version)| async move {
-            if version.is_empty() {
+            if name.is_empty() || version.is_empty() {
                 return Ok(None);
             }
             // Reuse the prefetched content when the auto-selected version matches.
@@ -205,17 +583,24 @@ pub fn SchemaViewerPage() -> impl IntoView {
                         let schema_name = detail.name.clone();
                         let schema_type = detail.schema_type.clone();
                         let versions = detail.versions.clone();
+                        let about = view! { <SchemaAbout detail=detail.clone() /> };
+                        if resolved_name.get_untracked() != detail.name {
+                            set_resolved_name.set(detail.name.clone());
+                        }
 
-                        // Auto-select latest stable version on first load or when the
-                        // previously selected version doesn't exist on the new schema.
+                        // Auto-select the linked version, else the latest stable one, on
+                        // first load or when the previously selected version doesn't
+                        // exist on the new schema.
                         let selected = selected_version.get();
                         let selection_missing =
                             !selected.is_empty() && !versions.iter().any(|v| v.version == selected);
                         if (selected.is_empty() || selection_missing) && !versions.is_empty() {
+                            let linked =
+                                path_version(&name(), &schema_name).or_else(linked_version);
                             let default_ver = versions
                                 .iter()
-                                .rev()
-                                .find(|v| v.status == "stable")
+                                .find(|v| linked.as_deref() == Some(v.version.as_str()))
+                                .or_else(|| versions.iter().rev().find(|v| v.status == "stable"))
                                 .or(versions.last())
                                 .map(|v| v.version.clone())
                                 .unwrap_or_default();
@@ -241,8 +626,8 @@ pub fn SchemaViewerPage() -> impl IntoView {
                                 // Breadcrumbs
                                 <div class="breadcrumbs text-sm mb-4">
                                     <ul>
-                                        <li><a href="/">"Home"</a></li>
-                                        <li><a href="/schemas">"Schemas"</a></li>
+                                        <li><a href="/">{i18n.t("schemas.breadcrumb_home")}</a></li>
+                                        <li><a href="/schemas">{i18n.t("schemas.breadcrumb_schemas")}</a></li>
                                         <li>{schema_name.clone()}</li>
                                     </ul>
                                 </div>
@@ -254,17 +639,24 @@ pub fn SchemaViewerPage() -> impl IntoView {
                                         <span class=format!("badge {}", badge_class)>{type_label.to_string()}</span>
                                     </div>
 
-                                    // Version selector dropdown
-                                    <VersionSelector
-                                        versions=versions.clone()
-                                        selected=selected_version
-                                        set_selected=set_selected_version
-                                    />
+                                    <div class="flex flex-wrap items-center gap-2">
+                                        // Version selector dropdown
+                                        <VersionSelector
+                                            versions=versions.clone()
+                                            selected=selected_version
+                                            set_selected=set_selected_version
+                                        />
+                                        <VersionActions name=schema_name.clone() selected=selected_version />
+                                    </div>
                                 </div>
 
+                                {about}
+
                                 // Version status badges
                                 <VersionStatusBar versions=versions />
 
+                                <ContractMatrixPanel name=schema_name.clone() />
+
                                 // Spec content viewer
                                 <div class="mt-6">
                                     <Suspense fallback=move || view! {
@@ -276,24 +668,52 @@ pub fn SchemaViewerPage() -> impl IntoView {
                                             content_resource.get().map(|result| match result {
                                                 Ok(Some(content)) => {
                                                     let st = schema_type.clone();
+                                                    let settings = viewer_settings
+                                                        .get()
+                                                        .and_then(|res| res.ok())
+                                                        .unwrap_or_default();
+                                                    let version = selected_version.get_untracked();
+                                                    let name = resolved_name.get_untracked();
+                                                    // Scalar has no outline of its own to deep link into
+                                                    let index = (st == "openapi" && settings.assets.is_some()).then(|| view! {
+                                                        <div class="mb-4">
+                                                            <OperationIndex
+                                                                name=name.clone()
+                                                                version=version.clone()
+                                                                schema_type=st.clone()
+                                                                collapsed=true
+                                                            />
+                                                        </div>
+                                                    });
+                                                    let snippets = (st == "openapi").then(|| view! {
+                                                        <RequestSnippets
+                                                            name=name.clone()
+                                                            version=version.clone()
+                                                        />
+                                                    });
                                                     view! {
+                                                        {index}
                                                         <SpecViewer
+                                                            name=name
+                                                            version=version.clone()
                                                             content=content
                                                             schema_type=st
+                                                            settings=settings
                                                         />
+                                                        {snippets}
                                                     }.into_any()
                                                 }
                                                 Ok(None) => {
                                                     view! {
                                                         <div class="alert alert-info">
-                                                            <span>"Select a version to view the schema specification."</span>
+                                                            <span>{i18n.t("schemas.select_version")}</span>
                                                         </div>
                                                     }.into_any()
                                                 }
                                                 Err(e) => {
                                                     view! {
                                                         <div class="alert alert-error">
-                                                            <span>{format!("Error loading schema content: {e}")}</span>
+                                                            <span>{i18n.tf("schemas.content_error", &[("error", &e.to_string())])}</span>
                                                         </div>
                                                     }.into_any()
                                                 }
@@ -305,9 +725,12 @@ pub fn SchemaViewerPage() -> impl IntoView {
                         }.into_any()
                     }
                     Err(e) => {
+                        if let Some(required_level) = access_denied_level(&e) {
+                            return view! { <AccessDenied required_level /> }.into_any();
+                        }
                         view! {
                             <div class="alert alert-error">
-                                <span>{format!("Error loading schema: {e}")}</span>
+                                <span>{i18n.tf("schemas.schema_error", &[("error", &e.to_string())])}</span>
                             </div>
                         }.into_any()
                     }
@@ -317,6 +740,234 @@ pub fn SchemaViewerPage() -> impl IntoView {
     }
 }
 
+/// Version named by a `/schemas/<name>/<version>` viewer path, once `path`
+/// resolved to the schema `name`.
+fn path_version(path: &str, name: &str) -> Option<String> {
+    path.strip_prefix(name)?
+        .strip_prefix('/')
+        .filter(|version| !version.is_empty())
+        .map(str::to_string)
+}
+
+/// Viewer used when the interactive bundles are unavailable (air-gapped
+/// instances without bundled assets): the operations outline extracted
+/// server-side, followed by the raw specification.
+#[component]
+fn BuiltinSpecViewer(
+    name: String,
+    version: String,
+    content: String,
+    schema_type: String,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let spec_label = match schema_type.as_str() {
+        "asyncapi" => "AsyncAPI",
+        _ => "OpenAPI",
+    };
+
+    view! {
+        <div class="space-y-4">
+            <OperationIndex name=name version=version schema_type=schema_type collapsed=false />
+            <div class="border border-base-300 rounded-lg">
+                <div class="p-2 bg-base-200 border-b border-base-300 rounded-t-lg">
+                    <span class="text-sm font-semibold">{i18n.tf("schemas.specification", &[("spec", spec_label)])}</span>
+                </div>
+                <pre class="p-4 overflow-auto text-sm max-h-[80vh]">
+                    <code>{content}</code>
+                </pre>
+            </div>
+        </div>
+    }
+}
+
+/// Operations of a schema version, one row per operation with the
+/// [`operation_anchor`] as its id so `#get-/users/{id}` deep links scroll to
+/// it. When `collapsed`, the index starts closed and opens for a deep link.
+#[component]
+fn OperationIndex(
+    name: String,
+    version: String,
+    schema_type: String,
+    collapsed: bool,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let path_label = match schema_type.as_str() {
+        "asyncapi" => i18n.t("schemas.channel"),
+        _ => i18n.t("schemas.path"),
+    };
+    let outline_resource = Resource::new(
+        move || (name.clone(), version.clone(), schema_type.clone()),
+        |(name, version, schema_type)| get_schema_outline(name, version, schema_type),
+    );
+
+    view! {
+        <details class="border border-base-300 rounded-lg overflow-hidden" open=!collapsed>
+            <summary class="p-2 bg-base-200 border-b border-base-300 cursor-pointer">
+                <span class="text-sm font-semibold">{i18n.t("schemas.operations")}</span>
+            </summary>
+            <Suspense fallback=|| view! {
+                <div class="flex justify-center py-6">
+                    <span class="loading loading-spinner"></span>
+                </div>
+            }>
+                {move || outline_resource.get().map(|result| match result {
+                    Ok(endpoints) if !endpoints.is_empty() => view! {
+                        <OperationRows endpoints=endpoints path_label=path_label />
+                    }.into_any(),
+                    Ok(_) => view! {
+                        <p class="p-4 text-sm text-base-content/60">{i18n.t("schemas.no_operations")}</p>
+                    }.into_any(),
+                    Err(e) => view! {
+                        <p class="p-4 text-sm text-error">{i18n.tf("schemas.operations_error", &[("error", &e.to_string())])}</p>
+                    }.into_any(),
+                })}
+            </Suspense>
+        </details>
+    }
+}
+
+#[component]
+fn OperationRows(endpoints: Vec<SchemaEndpoint>, path_label: &'static str) -> impl IntoView {
+    let i18n = use_i18n();
+    #[cfg(feature = "hydrate")]
+    {
+        // Scroll to the deep-linked operation once its row is in the DOM.
+        Effect::new(move |_| {
+            let _ = js_sys::eval("let h=decodeURIComponent(window.location.hash.slice(1));if(h){let el=document.getElementById(h);if(el){let d=el.closest('details');if(d)d.open=true;el.classList.add('bg-base-200');el.scrollIntoView({behavior:'smooth',block:'center'})}}");
+        });
+    }
+
+    view! {
+        <table class="table table-sm">
+            <thead>
+                <tr>
+                    <th>{i18n.t("schemas.method")}</th>
+                    <th>{path_label}</th>
+                    <th>{i18n.t("schemas.summary")}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {endpoints.into_iter().map(|endpoint| {
+                    let anchor = operation_anchor(&endpoint.method, &endpoint.path);
+                    view! {
+                        <tr id=anchor.clone() class="scroll-mt-20">
+                            <td>
+                                <a href=format!("#{anchor}") class="badge badge-sm badge-outline font-mono" title=i18n.t("schemas.operation_link")>
+                                    {endpoint.method}
+                                </a>
+                            </td>
+                            <td class="font-mono text-sm">{endpoint.path}</td>
+                            <td class="text-sm text-base-content/70">{endpoint.summary.unwrap_or_default()}</td>
+                        </tr>
+                    }
+                }).collect::<Vec<_>>()}
+            </tbody>
+        </table>
+    }
+}
+
+/// Copy-paste request snippets for every operation of an OpenAPI version,
+/// with one language tab shared by all operations.
+#[component]
+fn RequestSnippets(name: String, version: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let snippets_resource = Resource::new(
+        move || (name.clone(), version.clone()),
+        |(name, version)| get_schema_snippets(name, version),
+    );
+    let (language, set_language) = signal("curl".to_string());
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || snippets_resource.get().map(|result| match result {
+                Ok(operations) if !operations.is_empty() => {
+                    let tabs = operations[0]
+                        .snippets
+                        .iter()
+                        .map(|s| {
+                            let lang = s.language.clone();
+                            let lang_for_class = lang.clone();
+                            view! {
+                                <button
+                                    role="tab"
+                                    class=move || if language.get() == lang_for_class { "tab tab-active" } else { "tab" }
+                                    on:click=move |_| set_language.set(lang.clone())
+                                >
+                                    {s.label.clone()}
+                                </button>
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    view! {
+                        <div class="mt-8">
+                            <div class="flex flex-wrap items-center justify-between gap-3 mb-3">
+                                <h2 class="text-xl font-semibold">{i18n.t("schemas.snippets")}</h2>
+                                <div role="tablist" class="tabs tabs-box tabs-sm">{tabs}</div>
+                            </div>
+                            <div class="space-y-2">
+                                {operations
+                                    .into_iter()
+                                    .map(|operation| view! { <OperationSnippet operation=operation language=language /> })
+                                    .collect::<Vec<_>>()}
+                            </div>
+                        </div>
+                    }
+                    .into_any()
+                }
+                // Snippets are an extra: errors leave the spec viewer alone
+                _ => ().into_any(),
+            })}
+        </Suspense>
+    }
+}
+
+/// One collapsible operation with its snippet in the selected language.
+#[component]
+fn OperationSnippet(operation: OperationSnippets, language: ReadSignal<String>) -> impl IntoView {
+    let i18n = use_i18n();
+    let (copied, set_copied) = signal(false);
+    let snippets = operation.snippets;
+    let code = move || {
+        snippets
+            .iter()
+            .find(|s| s.language == language.get())
+            .map(|s| s.code.clone())
+            .unwrap_or_default()
+    };
+    let code_for_copy = code.clone();
+
+    view! {
+        <details class="rounded-lg border border-base-300 overflow-hidden">
+            <summary class="cursor-pointer px-4 py-2 bg-base-200/50 flex items-center gap-3">
+                <span class="badge badge-sm badge-outline font-mono">{operation.method}</span>
+                <span class="font-mono text-sm">{operation.path}</span>
+                {operation.summary.map(|summary| view! {
+                    <span class="text-sm text-base-content/60 truncate">{summary}</span>
+                })}
+            </summary>
+            <div class="relative">
+                <button
+                    class="btn btn-xs btn-ghost absolute right-2 top-2"
+                    on:click=move |_| {
+                        #[cfg(feature = "hydrate")]
+                        {
+                            // A JSON string is a valid JS string literal
+                            let literal = serde_json::to_string(&code_for_copy()).unwrap_or_default();
+                            let _ = js_sys::eval(&format!("navigator.clipboard.writeText({literal})"));
+                            set_copied.set(true);
+                        }
+                        #[cfg(not(feature = "hydrate"))]
+                        let _ = (&code_for_copy, set_copied);
+                    }
+                >
+                    {move || if copied.get() { i18n.t("schemas.copied") } else { i18n.t("schemas.copy") }}
+                </button>
+                <pre class="p-4 bg-base-200 overflow-auto text-sm"><code>{code}</code></pre>
+            </div>
+        </details>
+    }
+}
+
 /// Version selector dropdown.
 #[component]
 fn VersionSelector(
@@ -324,9 +975,10 @@ fn VersionSelector(
     selected: ReadSignal<String>,
     set_selected: WriteSignal<String>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="flex items-center gap-2">
-            <label class="text-sm font-semibold text-base-content/70">"Version:"</label>
+            <label class="text-sm font-semibold text-base-content/70">{i18n.t("schemas.version")}</label>
             <select
                 class="select select-bordered select-sm"
                 on:change=move |ev| {
@@ -351,6 +1003,72 @@ fn VersionSelector(
     }
 }
 
+/// Download, permalink and curl actions for the selected version.
+#[component]
+fn VersionActions(name: String, selected: ReadSignal<String>) -> impl IntoView {
+    let i18n = use_i18n();
+    let (copied, set_copied) = signal(None::<&'static str>);
+    let raw_path = {
+        let name = name.clone();
+        move || schema_raw_path(&name, &selected.get())
+    };
+    let permalink_path = move || format!("/schemas/{}?version={}", name, selected.get());
+
+    // Copy `prefix + <absolute URL of path> + suffix`.
+    let copy = move |what: &'static str,
+                     path: String,
+                     prefix: &'static str,
+                     suffix: &'static str| {
+        #[cfg(feature = "hydrate")]
+        {
+            // JSON strings are valid JS string literals
+            let [prefix, path, suffix] = [prefix, path.as_str(), suffix]
+                .map(|s| serde_json::to_string(s).unwrap_or_default());
+            let _ = js_sys::eval(&format!(
+                "navigator.clipboard.writeText({prefix} + window.location.origin + {path} + {suffix})"
+            ));
+            set_copied.set(Some(what));
+        }
+        #[cfg(not(feature = "hydrate"))]
+        let _ = (what, path, prefix, suffix, set_copied);
+    };
+    let copy_permalink = move |_| copy("permalink", permalink_path(), "", "");
+    let copy_curl = {
+        let raw_path = raw_path.clone();
+        move |_| copy("curl", raw_path(), "curl -fsSL '", "'")
+    };
+    let label = move |what: &'static str, key: &'static str| {
+        move || {
+            if copied.get() == Some(what) {
+                i18n.t("schemas.copied")
+            } else {
+                i18n.t(key)
+            }
+        }
+    };
+
+    view! {
+        <Show when=move || !selected.get().is_empty()>
+            <div class="join">
+                <a
+                    class="btn btn-sm join-item"
+                    href=raw_path.clone()
+                    download=""
+                    title=i18n.t("schemas.download_title")
+                >
+                    {i18n.t("schemas.download")}
+                </a>
+                <button class="btn btn-sm join-item" on:click=copy_permalink.clone()>
+                    {label("permalink", "schemas.copy_permalink")}
+                </button>
+                <button class="btn btn-sm join-item" on:click=copy_curl.clone()>
+                    {label("curl", "schemas.copy_curl")}
+                </button>
+            </div>
+        </Show>
+    }
+}
+
 /// Status bar showing all versions with their status.
 #[component]
 fn VersionStatusBar(versions: Vec<SchemaVersionInfo>) -> impl IntoView {
@@ -379,35 +1097,45 @@ fn VersionStatusBar(versions: Vec<SchemaVersionInfo>) -> impl IntoView {
 }
 
 /// Spec viewer component that renders the schema content.
-/// Uses Scalar CDN for OpenAPI, syntax-highlighted pre block for others.
+/// Uses Scalar for OpenAPI and AsyncAPI React for AsyncAPI, loaded from the
+/// configured asset source, or [`BuiltinSpecViewer`] when none is available;
+/// syntax-highlighted pre block for others. With `settings.try_it_proxy`,
+/// Scalar sends test requests through `/api/v1/try-it`.
 #[component]
-fn SpecViewer(content: String, schema_type: String) -> impl IntoView {
-    match schema_type.as_str() {
-        "openapi" => {
+fn SpecViewer(
+    name: String,
+    version: String,
+    content: String,
+    schema_type: String,
+    settings: SpecViewerSettings,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    match (schema_type.as_str(), settings.assets) {
+        ("openapi" | "asyncapi", None) => view! {
+            <BuiltinSpecViewer
+                name=name
+                version=version
+                content=content
+                schema_type=schema_type.clone()
+            />
+        }
+        .into_any(),
+        ("openapi", Some(assets)) => {
             let escaped_content = content
                 .replace('\\', "\\\\")
                 .replace('`', "\\`")
                 .replace("${", "\\${");
 
-            let scalar_js = {
-                #[cfg(feature = "ssr")]
-                {
-                    crate::static_assets::versioned_url("/js/scalar-standalone.js")
-                }
-                #[cfg(not(feature = "ssr"))]
-                {
-                    "/js/scalar-standalone.js".to_string()
-                }
-            };
-            let scalar_css = {
-                #[cfg(feature = "ssr")]
-                {
-                    crate::static_assets::versioned_url("/js/scalar-style.css")
-                }
-                #[cfg(not(feature = "ssr"))]
-                {
-                    "/js/scalar-style.css".to_string()
-                }
+            let SpecViewerAssets {
+                scalar_js,
+                scalar_css,
+                ..
+            } = assets;
+
+            let proxy_option = if settings.try_it_proxy {
+                "proxyUrl: '/api/v1/try-it',"
+            } else {
+                ""
             };
 
             let script = format!(
@@ -431,6 +1159,10 @@ fn SpecViewer(content: String, schema_type: String) -> impl IntoView {
                         script.onload = function() {{
                             renderScalar(el);
                         }};
+                        // Unreachable asset source: fall back to the raw spec
+                        script.onerror = function() {{
+                            renderScalar(el);
+                        }};
                         document.head.appendChild(script);
                     }} else {{
                         renderScalar(el);
@@ -444,12 +1176,14 @@ fn SpecViewer(content: String, schema_type: String) -> impl IntoView {
                                 }},
                                 theme: 'none',
                                 showSidebar: false,
+                                {proxy_option}
                             }});
                         }} else if (window.ScalarApiReference) {{
                             window.ScalarApiReference(targetEl, {{
                                 spec: {{
                                     content: `{escaped_content}`,
                                 }},
+                                {proxy_option}
                             }});
                         }} else {{
                             targetEl.innerHTML = '<pre class="p-4 bg-base-200 rounded-lg overflow-auto text-sm"><code>' +
@@ -491,7 +1225,7 @@ fn SpecViewer(content: String, schema_type: String) -> impl IntoView {
                     <div id="scalar-api-reference" class="scalar-app min-h-[600px]">
                         <div class="flex justify-center items-center py-12">
                             <span class="loading loading-spinner loading-lg text-primary"></span>
-                            <span class="ml-3 text-base-content/70">"Loading API reference viewer..."</span>
+                            <span class="ml-3 text-base-content/70">{i18n.t("schemas.loading_scalar")}</span>
                         </div>
                     </div>
                     <script>{script}</script>
@@ -499,32 +1233,17 @@ fn SpecViewer(content: String, schema_type: String) -> impl IntoView {
             }
             .into_any()
         }
-        "asyncapi" => {
+        ("asyncapi", Some(assets)) => {
             let escaped_content = content
                 .replace('\\', "\\\\")
                 .replace('`', "\\`")
                 .replace("${", "\\${");
 
-            let asyncapi_js = {
-                #[cfg(feature = "ssr")]
-                {
-                    crate::static_assets::versioned_url("/js/asyncapi-standalone.js")
-                }
-                #[cfg(not(feature = "ssr"))]
-                {
-                    "/js/asyncapi-standalone.js".to_string()
-                }
-            };
-            let asyncapi_css = {
-                #[cfg(feature = "ssr")]
-                {
-                    crate::static_assets::versioned_url("/js/asyncapi-default.min.css")
-                }
-                #[cfg(not(feature = "ssr"))]
-                {
-                    "/js/asyncapi-default.min.css".to_string()
-                }
-            };
+            let SpecViewerAssets {
+                asyncapi_js,
+                asyncapi_css,
+                ..
+            } = assets;
 
             // Injected after the stylesheet loads so it always wins the cascade
             let script = format!(
@@ -593,6 +1312,10 @@ fn SpecViewer(content: String, schema_type: String) -> impl IntoView {
                             injectTheme();
                             renderAsyncApi(container);
                         }};
+                        // Unreachable asset source: fall back to the raw spec
+                        script.onerror = function() {{
+                            renderAsyncApi(container);
+                        }};
                         document.head.appendChild(script);
                     }} else {{
                         injectTheme();
@@ -622,7 +1345,7 @@ fn SpecViewer(content: String, schema_type: String) -> impl IntoView {
                     <div id="asyncapi-viewer" class="min-h-[600px]">
                         <div class="flex justify-center items-center py-12">
                             <span class="loading loading-spinner loading-lg"></span>
-                            <span class="ml-3">"Loading AsyncAPI viewer..."</span>
+                            <span class="ml-3">{i18n.t("schemas.loading_asyncapi")}</span>
                         </div>
                     </div>
                     <script>{script}</script>
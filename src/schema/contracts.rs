@@ -0,0 +1,247 @@
+//! Compatibility matrix of a schema: the latest contract-test result of each
+//! consumer (and tool) against each version, as published from CI through
+//! `POST /api/v1/contract-tests`.
+
+use chrono::{DateTime, Utc};
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::db::contract_test_repository::ContractTestOutcome;
+#[cfg(feature = "ssr")]
+use crate::db::contract_test_repository::ContractTestReport;
+use crate::i18n::use_i18n;
+
+/// One cell of the matrix.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractResult {
+    pub outcome: ContractTestOutcome,
+    pub passed: Option<u32>,
+    pub failed: Option<u32>,
+    pub report_url: Option<String>,
+    pub reported_at: DateTime<Utc>,
+}
+
+impl ContractResult {
+    /// Tooltip: check counts, when reported, and the report date.
+    pub fn details(&self) -> String {
+        let counts = match (self.passed, self.failed) {
+            (Some(passed), Some(failed)) => format!("{passed} passed, {failed} failed · "),
+            (Some(passed), None) => format!("{passed} passed · "),
+            (None, Some(failed)) => format!("{failed} failed · "),
+            (None, None) => String::new(),
+        };
+        format!("{counts}{}", self.reported_at.format("%Y-%m-%d %H:%M UTC"))
+    }
+}
+
+/// Results of one consumer and tool, one per matrix version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractMatrixRow {
+    pub consumer: String,
+    pub tool: String,
+    pub results: Vec<Option<ContractResult>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContractMatrix {
+    /// Versions with at least one result, in the schema's version order.
+    pub versions: Vec<String>,
+    /// Rows sorted by consumer, then tool.
+    pub rows: Vec<ContractMatrixRow>,
+}
+
+/// Build the matrix of `reports` restricted to `versions`, the versions the
+/// reader can see in display order.
+#[cfg(feature = "ssr")]
+pub fn build_contract_matrix(
+    versions: &[String],
+    reports: Vec<ContractTestReport>,
+) -> ContractMatrix {
+    use std::collections::BTreeMap;
+
+    let reports: Vec<ContractTestReport> = reports
+        .into_iter()
+        .filter(|r| versions.contains(&r.version))
+        .collect();
+    let versions: Vec<String> = versions
+        .iter()
+        .filter(|v| reports.iter().any(|r| &r.version == *v))
+        .cloned()
+        .collect();
+
+    let mut rows: BTreeMap<(String, String), Vec<Option<ContractResult>>> = BTreeMap::new();
+    for report in reports {
+        let column = versions
+            .iter()
+            .position(|v| *v == report.version)
+            .expect("filtered to matrix versions");
+        let results = rows
+            .entry((report.consumer, report.tool))
+            .or_insert_with(|| vec![None; versions.len()]);
+        results[column] = Some(ContractResult {
+            outcome: report.outcome,
+            passed: report.passed,
+            failed: report.failed,
+            report_url: report.report_url,
+            reported_at: report.reported_at,
+        });
+    }
+
+    ContractMatrix {
+        versions,
+        rows: rows
+            .into_iter()
+            .map(|((consumer, tool), results)| ContractMatrixRow {
+                consumer,
+                tool,
+                results,
+            })
+            .collect(),
+    }
+}
+
+/// Server function returning the compatibility matrix of a schema, over the
+/// versions visible to the caller.
+#[server(GetContractMatrix, "/api")]
+pub async fn get_contract_matrix(name: String) -> Result<ContractMatrix, ServerFnError> {
+    let state = expect_context::<crate::app::AppState>();
+    let (allowed_levels, _) = crate::server::request_document_visibility(&state).await?;
+    let detail = crate::api::schemas::process_get_schema(
+        state.schema_repo.as_ref(),
+        &name,
+        allowed_levels.as_deref(),
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)?;
+    let reports = state
+        .contract_test_repo
+        .list_for_schema(&detail.name)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    let versions: Vec<String> = detail.versions.into_iter().map(|v| v.version).collect();
+    Ok(build_contract_matrix(&versions, reports))
+}
+
+/// Contract-test results of a schema; renders nothing until CI has
+/// published some.
+#[component]
+pub fn ContractMatrixPanel(name: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let matrix = Resource::new(move || name.clone(), get_contract_matrix);
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || matrix.get().and_then(|result| result.ok()).filter(|m| !m.rows.is_empty()).map(|matrix| {
+                let versions = matrix.versions;
+                view! {
+                    <div class="mt-6 border border-base-300 rounded-lg overflow-x-auto">
+                        <div class="p-2 bg-base-200 border-b border-base-300">
+                            <span class="text-sm font-semibold">{i18n.t("contracts.title")}</span>
+                        </div>
+                        <table class="table table-sm">
+                            <thead>
+                                <tr>
+                                    <th>{i18n.t("contracts.consumer")}</th>
+                                    {versions.into_iter().map(|v| view! { <th class="font-mono">{v}</th> }).collect::<Vec<_>>()}
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {matrix.rows.into_iter().map(|row| view! {
+                                    <tr>
+                                        <td>
+                                            <span class="font-medium">{row.consumer}</span>
+                                            <span class="text-xs text-base-content/50">{format!(" · {}", row.tool)}</span>
+                                        </td>
+                                        {row.results.into_iter().map(|cell| view! {
+                                            <td>{cell.map(|result| view! { <ContractResultBadge result /> })}</td>
+                                        }).collect::<Vec<_>>()}
+                                    </tr>
+                                }).collect::<Vec<_>>()}
+                            </tbody>
+                        </table>
+                    </div>
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn ContractResultBadge(result: ContractResult) -> impl IntoView {
+    let i18n = use_i18n();
+    let (class, label) = match result.outcome {
+        ContractTestOutcome::Passed => ("badge badge-sm badge-success", i18n.t("contracts.passed")),
+        ContractTestOutcome::Failed => ("badge badge-sm badge-error", i18n.t("contracts.failed")),
+    };
+    let details = result.details();
+    match result.report_url {
+        Some(url) => view! {
+            <a href=url target="_blank" rel="noopener noreferrer" class=class title=details>{label}</a>
+        }
+        .into_any(),
+        None => view! { <span class=class title=details>{label}</span> }.into_any(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(version: &str, consumer: &str, outcome: ContractTestOutcome) -> ContractTestReport {
+        ContractTestReport {
+            schema: "payments".into(),
+            version: version.into(),
+            consumer: consumer.into(),
+            tool: "pact".into(),
+            outcome,
+            passed: Some(12),
+            failed: None,
+            report_url: None,
+            published_by: "ci".into(),
+            reported_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_build_contract_matrix() {
+        let versions = ["1.0.0".to_string(), "2.0.0".into(), "3.0.0".into()];
+        let matrix = build_contract_matrix(
+            &versions,
+            vec![
+                report("2.0.0", "orders", ContractTestOutcome::Failed),
+                report("1.0.0", "orders", ContractTestOutcome::Passed),
+                report("3.0.0", "checkout", ContractTestOutcome::Passed),
+                // Not visible to the reader
+                report("4.0.0", "audit", ContractTestOutcome::Passed),
+            ],
+        );
+
+        assert_eq!(matrix.versions, versions);
+        let consumers: Vec<&str> = matrix.rows.iter().map(|r| r.consumer.as_str()).collect();
+        assert_eq!(consumers, ["checkout", "orders"]);
+        let outcomes: Vec<Option<ContractTestOutcome>> = matrix.rows[1]
+            .results
+            .iter()
+            .map(|r| r.as_ref().map(|r| r.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            [
+                Some(ContractTestOutcome::Passed),
+                Some(ContractTestOutcome::Failed),
+                None
+            ]
+        );
+        assert!(matrix.rows[0].results[2]
+            .as_ref()
+            .unwrap()
+            .details()
+            .starts_with("12 passed · "));
+
+        let matrix = build_contract_matrix(
+            &versions[..1],
+            vec![report("2.0.0", "orders", ContractTestOutcome::Passed)],
+        );
+        assert_eq!(matrix, ContractMatrix::default());
+    }
+}
@@ -0,0 +1,257 @@
+//! Deprecation notices for schemas referenced from documents.
+//!
+//! Documents reference schemas by linking to the schema viewer, either
+//! `/schemas/<name>` or `/schemas/<name>?version=<version>`. A reference is
+//! deprecated when the linked version is, or, for a link without a version,
+//! when every version the reader can see is. Notices are computed when the
+//! document is read or found by search, so they follow the schema's current
+//! statuses without re-ingesting the document.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use std::collections::HashMap;
+
+#[cfg(feature = "ssr")]
+use crate::db::models::Schema;
+#[cfg(feature = "ssr")]
+use crate::db::repository::DocumentRepository;
+#[cfg(feature = "ssr")]
+use crate::db::schema_repository::SchemaRepository;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+#[cfg(feature = "ssr")]
+use crate::search::client::SearchHit;
+
+/// A deprecated schema (or schema version) referenced by a document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDeprecationNotice {
+    pub schema: String,
+    /// The linked version; `None` when the link targets the whole schema.
+    pub version: Option<String>,
+    /// Latest version that is not deprecated, to migrate to.
+    pub replacement: Option<String>,
+}
+
+impl SchemaDeprecationNotice {
+    /// Viewer link for the replacement, or the schema when there is none.
+    pub fn href(&self) -> String {
+        match &self.replacement {
+            Some(version) => format!("/schemas/{}?version={version}", self.schema),
+            None => format!("/schemas/{}", self.schema),
+        }
+    }
+}
+
+/// Schema references (name and optional version) among a document's
+/// normalized outgoing links (see [`crate::rendering::links::normalize_link`]).
+pub fn schema_references(links_out: &[String]) -> Vec<(String, Option<String>)> {
+    let mut references = Vec::new();
+    for link in links_out {
+        let Some(target) = link.strip_prefix("schemas/") else {
+            continue;
+        };
+        let (name, query) = target.split_once('?').unwrap_or((target, ""));
+        let name = name.trim_end_matches('/');
+        if name.is_empty() {
+            continue;
+        }
+        let version = query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("version="))
+            .filter(|v| !v.is_empty())
+            .map(str::to_string);
+        let reference = (name.to_string(), version);
+        if !references.contains(&reference) {
+            references.push(reference);
+        }
+    }
+    references
+}
+
+/// The notice for a reference to `version` (or the whole schema) of
+/// `schema`, as seen by a reader with `allowed_levels`. Versions the reader
+/// cannot see are ignored.
+#[cfg(feature = "ssr")]
+pub fn deprecation_notice(
+    schema: &Schema,
+    version: Option<&str>,
+    allowed_levels: Option<&[String]>,
+) -> Option<SchemaDeprecationNotice> {
+    let visible = crate::api::schemas::visible_versions(schema, allowed_levels);
+    let replacement = visible
+        .iter()
+        .rfind(|v| v.status != "deprecated")
+        .map(|v| v.version.clone());
+    let deprecated = match version {
+        Some(version) => visible
+            .iter()
+            .any(|v| v.version == version && v.status == "deprecated"),
+        None => !visible.is_empty() && replacement.is_none(),
+    };
+    deprecated.then(|| SchemaDeprecationNotice {
+        schema: schema.name.clone(),
+        version: version.map(str::to_string),
+        replacement,
+    })
+}
+
+/// Looks up referenced schemas once per request.
+#[cfg(feature = "ssr")]
+struct NoticeResolver<'a> {
+    schema_repo: &'a dyn SchemaRepository,
+    allowed_levels: Option<&'a [String]>,
+    schemas: HashMap<String, Option<Schema>>,
+}
+
+#[cfg(feature = "ssr")]
+impl<'a> NoticeResolver<'a> {
+    fn new(schema_repo: &'a dyn SchemaRepository, allowed_levels: Option<&'a [String]>) -> Self {
+        Self {
+            schema_repo,
+            allowed_levels,
+            schemas: HashMap::new(),
+        }
+    }
+
+    async fn notices(
+        &mut self,
+        links_out: &[String],
+    ) -> Result<Vec<SchemaDeprecationNotice>, AppError> {
+        let mut notices = Vec::new();
+        for (name, version) in schema_references(links_out) {
+            if !self.schemas.contains_key(&name) {
+                let schema = self.schema_repo.find_by_name_summary(&name).await?;
+                self.schemas.insert(name.clone(), schema);
+            }
+            if let Some(notice) = self.schemas[&name].as_ref().and_then(|schema| {
+                deprecation_notice(schema, version.as_deref(), self.allowed_levels)
+            }) {
+                notices.push(notice);
+            }
+        }
+        Ok(notices)
+    }
+}
+
+/// Notices for the schemas a document links to.
+#[cfg(feature = "ssr")]
+pub async fn document_notices(
+    schema_repo: &dyn SchemaRepository,
+    links_out: &[String],
+    allowed_levels: Option<&[String]>,
+) -> Result<Vec<SchemaDeprecationNotice>, AppError> {
+    NoticeResolver::new(schema_repo, allowed_levels)
+        .notices(links_out)
+        .await
+}
+
+/// Fill [`SearchHit::deprecated_schemas`] for hits whose document links to
+/// a deprecated schema. Failures are logged; they must not fail the search.
+#[cfg(feature = "ssr")]
+pub async fn annotate_search_hits(
+    document_repo: &dyn DocumentRepository,
+    schema_repo: &dyn SchemaRepository,
+    hits: &mut [SearchHit],
+    allowed_levels: Option<&[String]>,
+) {
+    if let Err(e) = try_annotate_search_hits(document_repo, schema_repo, hits, allowed_levels).await
+    {
+        tracing::warn!("Failed to check search hits for deprecated schemas: {e}");
+    }
+}
+
+#[cfg(feature = "ssr")]
+async fn try_annotate_search_hits(
+    document_repo: &dyn DocumentRepository,
+    schema_repo: &dyn SchemaRepository,
+    hits: &mut [SearchHit],
+    allowed_levels: Option<&[String]>,
+) -> Result<(), AppError> {
+    let mut resolver = NoticeResolver::new(schema_repo, allowed_levels);
+    let slugs: Vec<String> = hits.iter().map(|hit| hit.slug.clone()).collect();
+    let docs = document_repo.find_by_slugs(&slugs).await?;
+    for hit in hits.iter_mut() {
+        let Some(doc) = docs.iter().find(|doc| doc.slug == hit.slug) else {
+            continue;
+        };
+        let mut names: Vec<String> = resolver
+            .notices(&doc.links_out)
+            .await?
+            .into_iter()
+            .map(|notice| notice.schema)
+            .collect();
+        names.dedup();
+        hit.deprecated_schemas = names;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::SchemaVersion;
+    use crate::testing;
+
+    fn version(version: &str, status: &str, access_level: &str) -> SchemaVersion {
+        SchemaVersion {
+            status: status.into(),
+            access_level: access_level.into(),
+            ..testing::schema_version("payments", version)
+        }
+    }
+
+    fn schema(versions: Vec<SchemaVersion>) -> Schema {
+        testing::schema("payments", versions)
+    }
+
+    #[test]
+    fn test_schema_references() {
+        let links = [
+            "guides/setup".to_string(),
+            "schemas/payments/ledger".to_string(),
+            "schemas/payments/ledger?version=1.0.0".to_string(),
+            "schemas/orders?tab=raw&version=2.0.0".to_string(),
+            "schemas/payments/ledger".to_string(),
+            "schemas/".to_string(),
+        ];
+        assert_eq!(
+            schema_references(&links),
+            [
+                ("payments/ledger".to_string(), None),
+                ("payments/ledger".to_string(), Some("1.0.0".to_string())),
+                ("orders".to_string(), Some("2.0.0".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deprecation_notice() {
+        let public = vec!["public".to_string()];
+        let mixed = schema(vec![
+            version("1.0.0", "deprecated", "public"),
+            version("2.0.0", "stable", "public"),
+            version("3.0.0", "beta", "internal"),
+        ]);
+        assert_eq!(deprecation_notice(&mixed, None, Some(&public)), None);
+        assert_eq!(
+            deprecation_notice(&mixed, Some("1.0.0"), Some(&public)),
+            Some(SchemaDeprecationNotice {
+                schema: "payments".into(),
+                version: Some("1.0.0".into()),
+                replacement: Some("2.0.0".into()),
+            })
+        );
+        assert_eq!(deprecation_notice(&mixed, Some("2.0.0"), None), None);
+
+        let retired = schema(vec![
+            version("1.0.0", "deprecated", "public"),
+            version("2.0.0", "stable", "internal"),
+        ]);
+        let notice = deprecation_notice(&retired, None, Some(&public)).unwrap();
+        assert_eq!(notice.replacement, None);
+        assert_eq!(notice.href(), "/schemas/payments");
+        // Readers of the internal version have something to migrate to
+        assert_eq!(deprecation_notice(&retired, None, None), None);
+    }
+}
@@ -1,3 +1,7 @@
+pub mod catalog;
 pub mod component;
+pub mod contracts;
+pub mod deprecation;
 #[cfg(feature = "ssr")]
 pub mod reindex;
+pub mod snippets;
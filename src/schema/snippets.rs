@@ -0,0 +1,765 @@
+//! Request snippets for OpenAPI operations.
+//!
+//! The schema viewer shows, for every operation of an OpenAPI document, a
+//! ready-to-run request in curl, Rust (`reqwest`), Python (`requests`) and
+//! TypeScript (`fetch`). Snippets are generated server-side from the parsed
+//! spec: the URL comes from the first server entry, path parameters with an
+//! example are substituted, required query and header parameters are filled
+//! with sample values, JSON request bodies are taken from the spec examples
+//! (or sampled from the schema), and the first security requirement becomes
+//! a placeholder credential (`YOUR_TOKEN`, `YOUR_API_KEY`, …).
+
+use serde::{Deserialize, Serialize};
+
+/// A request snippet in one language.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CodeSnippet {
+    /// Language identifier (`curl`, `rust`, `python`, `typescript`).
+    pub language: String,
+    /// Label shown on the language tab.
+    pub label: String,
+    pub code: String,
+}
+
+/// The snippets for one OpenAPI operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationSnippets {
+    /// Upper-case HTTP method.
+    pub method: String,
+    /// Path template as declared in the spec (e.g. `/payments/{id}`).
+    pub path: String,
+    pub summary: Option<String>,
+    pub snippets: Vec<CodeSnippet>,
+}
+
+#[cfg(feature = "ssr")]
+use serde_json::Value;
+
+#[cfg(feature = "ssr")]
+const METHODS: &[&str] = &["get", "post", "put", "delete", "patch", "options", "head"];
+
+/// Maximum `$ref`/nesting depth followed while sampling schemas.
+#[cfg(feature = "ssr")]
+const MAX_SAMPLE_DEPTH: usize = 8;
+
+/// A concrete request derived from an operation, rendered by each language.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, PartialEq)]
+struct RequestSpec {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<Value>,
+}
+
+/// Generate snippets for every operation of an OpenAPI (3.x or Swagger 2)
+/// document given as JSON or YAML. Unparseable content yields no snippets.
+#[cfg(feature = "ssr")]
+pub fn generate_openapi_snippets(content: &str) -> Vec<OperationSnippets> {
+    let spec: Value = if let Ok(v) = serde_json::from_str(content) {
+        v
+    } else if let Ok(v) = serde_yaml::from_str::<Value>(content) {
+        v
+    } else {
+        return vec![];
+    };
+
+    let Some(paths) = spec.get("paths").and_then(|p| p.as_object()) else {
+        return vec![];
+    };
+    let base_url = base_url(&spec);
+
+    let mut operations = vec![];
+    for (path, path_item) in paths {
+        let path_item = resolve(&spec, path_item);
+        for method in METHODS {
+            let Some(operation) = path_item.get(method) else {
+                continue;
+            };
+            let request = build_request(&spec, &base_url, method, path, path_item, operation);
+            operations.push(OperationSnippets {
+                method: method.to_uppercase(),
+                path: path.clone(),
+                summary: operation
+                    .get("summary")
+                    .and_then(|s| s.as_str())
+                    .filter(|s| !s.is_empty())
+                    .map(|s| s.to_string()),
+                snippets: vec![
+                    snippet("curl", "curl", render_curl(&request)),
+                    snippet("rust", "Rust", render_rust(&request)),
+                    snippet("python", "Python", render_python(&request)),
+                    snippet("typescript", "TypeScript", render_typescript(&request)),
+                ],
+            });
+        }
+    }
+    operations
+}
+
+#[cfg(feature = "ssr")]
+fn snippet(language: &str, label: &str, code: String) -> CodeSnippet {
+    CodeSnippet {
+        language: language.to_string(),
+        label: label.to_string(),
+        code,
+    }
+}
+
+/// Follow a local `$ref` (`#/components/...`), up to [`MAX_SAMPLE_DEPTH`] hops.
+#[cfg(feature = "ssr")]
+fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    let mut current = value;
+    for _ in 0..MAX_SAMPLE_DEPTH {
+        match current
+            .get("$ref")
+            .and_then(|r| r.as_str())
+            .and_then(|r| r.strip_prefix('#'))
+            .and_then(|pointer| spec.pointer(pointer))
+        {
+            Some(target) => current = target,
+            None => break,
+        }
+    }
+    current
+}
+
+/// Base URL of the API: the first OpenAPI 3 server (with variable defaults
+/// substituted) or the Swagger 2 `schemes`/`host`/`basePath`.
+#[cfg(feature = "ssr")]
+fn base_url(spec: &Value) -> String {
+    const FALLBACK: &str = "https://api.example.com";
+
+    if let Some(server) = spec
+        .get("servers")
+        .and_then(|s| s.as_array())
+        .and_then(|s| s.first())
+    {
+        let mut url = server
+            .get("url")
+            .and_then(|u| u.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if let Some(variables) = server.get("variables").and_then(|v| v.as_object()) {
+            for (name, variable) in variables {
+                if let Some(default) = variable.get("default").and_then(|d| d.as_str()) {
+                    url = url.replace(&format!("{{{name}}}"), default);
+                }
+            }
+        }
+        // Relative server URLs are resolved against a placeholder host
+        if url.starts_with('/') {
+            url = format!("{FALLBACK}{url}");
+        }
+        if !url.is_empty() {
+            return url.trim_end_matches('/').to_string();
+        }
+    }
+
+    if let Some(host) = spec.get("host").and_then(|h| h.as_str()) {
+        let scheme = spec
+            .get("schemes")
+            .and_then(|s| s.as_array())
+            .and_then(|s| s.first())
+            .and_then(|s| s.as_str())
+            .unwrap_or("https");
+        let base_path = spec
+            .get("basePath")
+            .and_then(|b| b.as_str())
+            .unwrap_or_default();
+        return format!("{scheme}://{host}{}", base_path.trim_end_matches('/'));
+    }
+
+    FALLBACK.to_string()
+}
+
+#[cfg(feature = "ssr")]
+fn build_request(
+    spec: &Value,
+    base_url: &str,
+    method: &str,
+    path: &str,
+    path_item: &Value,
+    operation: &Value,
+) -> RequestSpec {
+    // Operation parameters override path-level ones with the same name and location
+    let mut parameters: Vec<&Value> = vec![];
+    let declared = [path_item, operation]
+        .into_iter()
+        .filter_map(|v| v.get("parameters").and_then(|p| p.as_array()))
+        .flatten()
+        .map(|p| resolve(spec, p));
+    for param in declared {
+        parameters.retain(|p| p.get("name") != param.get("name") || p.get("in") != param.get("in"));
+        parameters.push(param);
+    }
+
+    let mut resolved_path = path.to_string();
+    let mut query = vec![];
+    let mut headers = vec![];
+    let mut body = None;
+    for param in parameters {
+        let name = param
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or_default();
+        let required = param
+            .get("required")
+            .and_then(|r| r.as_bool())
+            .unwrap_or(false);
+        match param.get("in").and_then(|i| i.as_str()) {
+            Some("path") => {
+                if let Some(example) = parameter_example(spec, param) {
+                    resolved_path =
+                        resolved_path.replace(&format!("{{{name}}}"), &value_to_text(&example));
+                }
+            }
+            Some("query") if required => {
+                let value =
+                    parameter_example(spec, param).unwrap_or_else(|| parameter_sample(spec, param));
+                query.push((name.to_string(), value_to_text(&value)));
+            }
+            Some("header") if required => {
+                let value =
+                    parameter_example(spec, param).unwrap_or_else(|| parameter_sample(spec, param));
+                headers.push((name.to_string(), value_to_text(&value)));
+            }
+            // Swagger 2 body parameter
+            Some("body") => {
+                body = param.get("schema").map(|s| sample_schema(spec, s, 0));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((name, value)) = security_header(spec, operation) {
+        headers.push((name, value));
+    }
+
+    if body.is_none() {
+        body = operation
+            .get("requestBody")
+            .map(|b| resolve(spec, b))
+            .and_then(|b| json_body(spec, b));
+    }
+    if body.is_some() {
+        headers.push(("Content-Type".to_string(), "application/json".to_string()));
+    }
+
+    let mut url = format!("{base_url}{resolved_path}");
+    if !query.is_empty() {
+        let encoded: Vec<String> = query
+            .iter()
+            .map(|(name, value)| {
+                url::form_urlencoded::Serializer::new(String::new())
+                    .append_pair(name, value)
+                    .finish()
+            })
+            .collect();
+        url = format!("{url}?{}", encoded.join("&"));
+    }
+
+    RequestSpec {
+        method: method.to_uppercase(),
+        url,
+        headers,
+        body,
+    }
+}
+
+/// The example declared on a parameter or its schema.
+#[cfg(feature = "ssr")]
+fn parameter_example(spec: &Value, param: &Value) -> Option<Value> {
+    if let Some(example) = param.get("example") {
+        return Some(example.clone());
+    }
+    if let Some(example) = param
+        .get("examples")
+        .and_then(|e| e.as_object())
+        .and_then(|e| e.values().next())
+        .and_then(|e| resolve(spec, e).get("value"))
+    {
+        return Some(example.clone());
+    }
+    let schema = param.get("schema").map(|s| resolve(spec, s))?;
+    schema
+        .get("example")
+        .or_else(|| schema.get("default"))
+        .cloned()
+}
+
+/// A sample value for a parameter without example, from its schema (OpenAPI 3)
+/// or its inline type (Swagger 2).
+#[cfg(feature = "ssr")]
+fn parameter_sample(spec: &Value, param: &Value) -> Value {
+    sample_schema(spec, param.get("schema").unwrap_or(param), 0)
+}
+
+/// The JSON example of a request body: its `example`, the first of its
+/// `examples`, or a sample of its schema. `None` without a JSON media type.
+#[cfg(feature = "ssr")]
+fn json_body(spec: &Value, request_body: &Value) -> Option<Value> {
+    let content = request_body.get("content")?.as_object()?;
+    let media = content.get("application/json").or_else(|| {
+        content
+            .iter()
+            .find(|(media_type, _)| media_type.ends_with("+json"))
+            .map(|(_, media)| media)
+    })?;
+
+    if let Some(example) = media.get("example") {
+        return Some(example.clone());
+    }
+    if let Some(example) = media
+        .get("examples")
+        .and_then(|e| e.as_object())
+        .and_then(|e| e.values().next())
+        .and_then(|e| resolve(spec, e).get("value"))
+    {
+        return Some(example.clone());
+    }
+    media.get("schema").map(|s| sample_schema(spec, s, 0))
+}
+
+/// Build a sample value matching a JSON schema.
+#[cfg(feature = "ssr")]
+fn sample_schema(spec: &Value, schema: &Value, depth: usize) -> Value {
+    if depth > MAX_SAMPLE_DEPTH {
+        return Value::Null;
+    }
+    let schema = resolve(spec, schema);
+
+    if let Some(example) = schema.get("example").or_else(|| schema.get("default")) {
+        return example.clone();
+    }
+    if let Some(first) = schema
+        .get("enum")
+        .and_then(|e| e.as_array())
+        .and_then(|e| e.first())
+    {
+        return first.clone();
+    }
+    if let Some(parts) = schema.get("allOf").and_then(|a| a.as_array()) {
+        let mut merged = serde_json::Map::new();
+        for part in parts {
+            if let Value::Object(fields) = sample_schema(spec, part, depth + 1) {
+                merged.extend(fields);
+            }
+        }
+        return Value::Object(merged);
+    }
+    if let Some(first) = ["oneOf", "anyOf"]
+        .iter()
+        .filter_map(|k| schema.get(*k).and_then(|v| v.as_array()))
+        .find_map(|variants| variants.first())
+    {
+        return sample_schema(spec, first, depth + 1);
+    }
+
+    // OpenAPI 3.1 allows a list of types, e.g. ["string", "null"]
+    let schema_type = match schema.get("type") {
+        Some(Value::String(t)) => Some(t.as_str()),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(|t| t.as_str())
+            .find(|t| *t != "null"),
+        _ => None,
+    };
+    match schema_type {
+        Some("object") | None if schema.get("properties").is_some() => {
+            let properties = schema
+                .get("properties")
+                .and_then(|p| p.as_object())
+                .map(|props| {
+                    props
+                        .iter()
+                        .map(|(name, prop)| (name.clone(), sample_schema(spec, prop, depth + 1)))
+                        .collect()
+                })
+                .unwrap_or_default();
+            Value::Object(properties)
+        }
+        Some("object") => Value::Object(serde_json::Map::new()),
+        Some("array") => Value::Array(
+            schema
+                .get("items")
+                .map(|items| vec![sample_schema(spec, items, depth + 1)])
+                .unwrap_or_default(),
+        ),
+        Some("string") => Value::String(
+            match schema.get("format").and_then(|f| f.as_str()) {
+                Some("date-time") => "2024-01-01T00:00:00Z",
+                Some("date") => "2024-01-01",
+                Some("email") => "user@example.com",
+                Some("uuid") => "00000000-0000-0000-0000-000000000000",
+                Some("uri") | Some("url") => "https://example.com",
+                _ => "string",
+            }
+            .to_string(),
+        ),
+        Some("integer") | Some("number") => Value::from(0),
+        Some("boolean") => Value::Bool(true),
+        _ => Value::Null,
+    }
+}
+
+/// The header carrying a placeholder credential for the first security
+/// requirement of the operation (or of the document).
+#[cfg(feature = "ssr")]
+fn security_header(spec: &Value, operation: &Value) -> Option<(String, String)> {
+    let requirement = operation
+        .get("security")
+        .or_else(|| spec.get("security"))?
+        .as_array()?
+        .first()?
+        .as_object()?;
+    let scheme_name = requirement.keys().next()?;
+    let scheme = spec
+        .pointer(&format!("/components/securitySchemes/{scheme_name}"))
+        .or_else(|| spec.pointer(&format!("/securityDefinitions/{scheme_name}")))
+        .map(|s| resolve(spec, s))?;
+
+    let authorization = |value: &str| Some(("Authorization".to_string(), value.to_string()));
+    match scheme.get("type").and_then(|t| t.as_str())? {
+        "http" => match scheme
+            .get("scheme")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("basic") => authorization("Basic YOUR_CREDENTIALS"),
+            _ => authorization("Bearer YOUR_TOKEN"),
+        },
+        "basic" => authorization("Basic YOUR_CREDENTIALS"),
+        "oauth2" | "openIdConnect" => authorization("Bearer YOUR_TOKEN"),
+        "apiKey" if scheme.get("in").and_then(|i| i.as_str()) == Some("header") => Some((
+            scheme.get("name")?.as_str()?.to_string(),
+            "YOUR_API_KEY".to_string(),
+        )),
+        _ => None,
+    }
+}
+
+/// Text form of a parameter value: strings unquoted, anything else as JSON.
+#[cfg(feature = "ssr")]
+fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Indent every line but the first, for values nested inside a call.
+#[cfg(feature = "ssr")]
+fn indent_continuation(text: &str, prefix: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 {
+                line.to_string()
+            } else {
+                format!("{prefix}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A double-quoted string literal, valid in Rust, Python and TypeScript.
+#[cfg(feature = "ssr")]
+fn quoted(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_default()
+}
+
+#[cfg(feature = "ssr")]
+fn pretty_json(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+#[cfg(feature = "ssr")]
+fn render_curl(request: &RequestSpec) -> String {
+    let shell_quote = |s: &str| format!("'{}'", s.replace('\'', r"'\''"));
+
+    let mut lines = vec![if request.method == "GET" {
+        format!("curl {}", shell_quote(&request.url))
+    } else {
+        format!("curl -X {} {}", request.method, shell_quote(&request.url))
+    }];
+    for (name, value) in &request.headers {
+        lines.push(format!("  -H {}", shell_quote(&format!("{name}: {value}"))));
+    }
+    if let Some(body) = &request.body {
+        lines.push(format!("  -d {}", shell_quote(&pretty_json(body))));
+    }
+    lines.join(" \\\n")
+}
+
+#[cfg(feature = "ssr")]
+fn render_rust(request: &RequestSpec) -> String {
+    let builder = match request.method.as_str() {
+        "GET" | "POST" | "PUT" | "DELETE" | "PATCH" | "HEAD" => format!(
+            ".{}({})",
+            request.method.to_lowercase(),
+            quoted(&request.url)
+        ),
+        other => format!(
+            ".request(reqwest::Method::{other}, {})",
+            quoted(&request.url)
+        ),
+    };
+
+    let mut code =
+        format!("let client = reqwest::Client::new();\nlet response = client\n    {builder}\n");
+    for (name, value) in &request.headers {
+        // `.json()` sets the content type itself
+        if request.body.is_some() && name == "Content-Type" {
+            continue;
+        }
+        code.push_str(&format!(
+            "    .header({}, {})\n",
+            quoted(name),
+            quoted(value)
+        ));
+    }
+    if let Some(body) = &request.body {
+        code.push_str(&format!(
+            "    .json(&serde_json::json!({}))\n",
+            indent_continuation(&pretty_json(body), "    ")
+        ));
+    }
+    code.push_str("    .send()\n    .await?;\nprintln!(\"{}\", response.text().await?);");
+    code
+}
+
+#[cfg(feature = "ssr")]
+fn render_python(request: &RequestSpec) -> String {
+    let mut code = format!(
+        "import requests\n\nresponse = requests.{}(\n    {},\n",
+        request.method.to_lowercase(),
+        quoted(&request.url)
+    );
+    let headers: Vec<&(String, String)> = request
+        .headers
+        .iter()
+        // `json=` sets the content type itself
+        .filter(|(name, _)| request.body.is_none() || name != "Content-Type")
+        .collect();
+    if !headers.is_empty() {
+        code.push_str("    headers={\n");
+        for (name, value) in headers {
+            code.push_str(&format!("        {}: {},\n", quoted(name), quoted(value)));
+        }
+        code.push_str("    },\n");
+    }
+    if let Some(body) = &request.body {
+        code.push_str(&format!("    json={},\n", python_literal(body, 1)));
+    }
+    code.push_str(")\nprint(response.status_code, response.text)");
+    code
+}
+
+/// Python literal for a JSON value (`None`/`True`/`False` instead of
+/// `null`/`true`/`false`), indented by four spaces per `level`.
+#[cfg(feature = "ssr")]
+fn python_literal(value: &Value, level: usize) -> String {
+    let indent = "    ".repeat(level + 1);
+    let closing = "    ".repeat(level);
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => quoted(s),
+        Value::Array(items) if items.is_empty() => "[]".to_string(),
+        Value::Array(items) => {
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| format!("{indent}{},\n", python_literal(item, level + 1)))
+                .collect();
+            format!("[\n{}{closing}]", items.concat())
+        }
+        Value::Object(fields) if fields.is_empty() => "{}".to_string(),
+        Value::Object(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(k, v)| format!("{indent}{}: {},\n", quoted(k), python_literal(v, level + 1)))
+                .collect();
+            format!("{{\n{}{closing}}}", fields.concat())
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn render_typescript(request: &RequestSpec) -> String {
+    let mut code = format!(
+        "const response = await fetch({}, {{\n  method: {},\n",
+        quoted(&request.url),
+        quoted(&request.method)
+    );
+    if !request.headers.is_empty() {
+        code.push_str("  headers: {\n");
+        for (name, value) in &request.headers {
+            code.push_str(&format!("    {}: {},\n", quoted(name), quoted(value)));
+        }
+        code.push_str("  },\n");
+    }
+    if let Some(body) = &request.body {
+        code.push_str(&format!(
+            "  body: JSON.stringify({}),\n",
+            indent_continuation(&pretty_json(body), "  ")
+        ));
+    }
+    code.push_str("});\nconsole.log(response.status, await response.text());");
+    code
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+servers:
+  - url: https://{env}.payments.acme.com/v1
+    variables:
+      env:
+        default: api
+components:
+  securitySchemes:
+    bearer:
+      type: http
+      scheme: bearer
+  schemas:
+    Payment:
+      type: object
+      properties:
+        amount:
+          type: integer
+        currency:
+          type: string
+          enum: [EUR, USD]
+        capture:
+          type: boolean
+security:
+  - bearer: []
+paths:
+  /payments/{id}:
+    parameters:
+      - name: id
+        in: path
+        required: true
+        schema:
+          type: string
+          example: pay_123
+    get:
+      summary: Get a payment
+      parameters:
+        - name: expand
+          in: query
+          required: true
+          schema:
+            type: string
+            example: customer details
+        - name: page
+          in: query
+          schema:
+            type: integer
+  /payments:
+    post:
+      security: []
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/Payment'
+      responses:
+        '201':
+          description: Created
+"##;
+
+    fn code<'a>(operation: &'a OperationSnippets, language: &str) -> &'a str {
+        &operation
+            .snippets
+            .iter()
+            .find(|s| s.language == language)
+            .unwrap()
+            .code
+    }
+
+    #[test]
+    fn test_request_built_from_spec() {
+        let operations = generate_openapi_snippets(SPEC);
+        assert_eq!(operations.len(), 2);
+
+        let get = operations.iter().find(|o| o.method == "GET").unwrap();
+        assert_eq!(get.path, "/payments/{id}");
+        assert_eq!(get.summary.as_deref(), Some("Get a payment"));
+        assert_eq!(
+            code(get, "curl"),
+            "curl 'https://api.payments.acme.com/v1/payments/pay_123?expand=customer+details' \\\n  -H 'Authorization: Bearer YOUR_TOKEN'"
+        );
+
+        let post = operations.iter().find(|o| o.method == "POST").unwrap();
+        let curl = code(post, "curl");
+        assert!(curl.starts_with("curl -X POST 'https://api.payments.acme.com/v1/payments'"));
+        assert!(
+            !curl.contains("Authorization"),
+            "security: [] disables auth"
+        );
+        assert!(curl.contains("-H 'Content-Type: application/json'"));
+        assert!(curl.contains(r#""currency": "EUR""#));
+    }
+
+    #[test]
+    fn test_language_renderers() {
+        let operations = generate_openapi_snippets(SPEC);
+        let post = operations.iter().find(|o| o.method == "POST").unwrap();
+        assert_eq!(
+            post.snippets
+                .iter()
+                .map(|s| s.language.as_str())
+                .collect::<Vec<_>>(),
+            vec!["curl", "rust", "python", "typescript"]
+        );
+
+        let rust = code(post, "rust");
+        assert!(rust.contains(".post(\"https://api.payments.acme.com/v1/payments\")"));
+        assert!(rust.contains(".json(&serde_json::json!({"));
+        assert!(!rust.contains("Content-Type"));
+
+        let python = code(post, "python");
+        assert!(python.contains("response = requests.post("));
+        assert!(python.contains("\"capture\": True,"));
+
+        let typescript = code(post, "typescript");
+        assert!(typescript.contains("method: \"POST\","));
+        assert!(typescript.contains("\"Content-Type\": \"application/json\","));
+        assert!(typescript.contains("body: JSON.stringify({"));
+    }
+
+    #[test]
+    fn test_swagger2_body_and_api_key() {
+        let spec = r#"{
+            "swagger": "2.0",
+            "host": "legacy.acme.com",
+            "basePath": "/api",
+            "schemes": ["http"],
+            "securityDefinitions": {"key": {"type": "apiKey", "in": "header", "name": "X-Api-Key"}},
+            "security": [{"key": []}],
+            "paths": {"/items": {"put": {"parameters": [
+                {"name": "item", "in": "body", "schema": {"type": "object", "properties": {"tags": {"type": "array", "items": {"type": "string"}}}}}
+            ]}}}
+        }"#;
+        let operations = generate_openapi_snippets(spec);
+        let curl = code(&operations[0], "curl");
+        assert!(curl.starts_with("curl -X PUT 'http://legacy.acme.com/api/items'"));
+        assert!(curl.contains("-H 'X-Api-Key: YOUR_API_KEY'"));
+        assert!(curl.contains("\"tags\": [\n    \"string\"\n  ]"));
+    }
+
+    #[test]
+    fn test_invalid_spec_yields_no_snippets() {
+        assert!(generate_openapi_snippets("not: [valid").is_empty());
+        assert!(generate_openapi_snippets("{}").is_empty());
+    }
+}
@@ -1,8 +1,12 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
+use crate::db::settings_repository::SearchSettings;
 use crate::error::AppError;
 
+/// Attributes that can be sorted on, and used in custom ranking rules.
+pub const SORTABLE_ATTRIBUTES: &[&str] = &["last_updated"];
+
 /// A document representation optimized for the search index.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchDocument {
@@ -16,6 +20,11 @@ pub struct SearchDocument {
     pub access_level: String,
     /// Whether the document is a draft.
     pub is_draft: bool,
+    /// Whether the document is hidden from navigation and search. Hidden
+    /// documents are normally removed from the index; the flag also keeps
+    /// them out of results should an entry linger.
+    #[serde(default)]
+    pub is_hidden: bool,
     /// The team/service that owns this document.
     pub service_owner: String,
     /// Tags for categorization.
@@ -24,6 +33,14 @@ pub struct SearchDocument {
     pub content_preview: String,
     /// Last updated as Unix timestamp (seconds).
     pub last_updated: i64,
+    /// Project namespace: the first slug segment.
+    #[serde(default)]
+    pub project: String,
+    /// Hash of the markdown content the entry was built from (see
+    /// [`Document::content_hash`](crate::db::models::Document::content_hash)),
+    /// used to detect stale entries.
+    #[serde(default)]
+    pub content_hash: Option<String>,
 }
 
 /// A search result returned to the client.
@@ -33,6 +50,45 @@ pub struct SearchHit {
     pub title: String,
     pub tags: Vec<String>,
     pub content_preview: String,
+    /// Schemas the document links to that are deprecated for the caller
+    /// (see [`crate::schema::deprecation`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deprecated_schemas: Vec<String>,
+    /// Whether the document has been superseded by another the caller can
+    /// read (see [`crate::relations::demote_superseded_hits`]).
+    #[serde(default)]
+    pub superseded: bool,
+}
+
+/// Restrictions on the documents a search returns.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchFilter {
+    /// Project namespace: the first slug segment.
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Tags every result must carry.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub service_owner: Option<String>,
+    /// Only documents last updated before this Unix timestamp (seconds).
+    #[serde(default)]
+    pub updated_before: Option<i64>,
+}
+
+impl SearchFilter {
+    /// Results of one project, or of all projects when `project` is `None`.
+    pub fn project(project: Option<&str>) -> Self {
+        Self {
+            project: project.map(str::to_string),
+            ..Self::default()
+        }
+    }
+
+    /// Whether the filter restricts document metadata other than the project.
+    pub fn restricts_metadata(&self) -> bool {
+        !self.tags.is_empty() || self.service_owner.is_some() || self.updated_before.is_some()
+    }
 }
 
 /// Trait for search operations, enabling mock testing.
@@ -49,16 +105,36 @@ pub trait SearchService: Send + Sync {
     /// - `allowed_levels`: the access level names the caller can read.
     ///   `None` means admin (no level restriction).
     /// - `include_draft`: whether to include draft documents.
+    /// - `filter`: project, tags, owner and age restrictions.
     async fn search(
         &self,
         query: &str,
         allowed_levels: Option<&[String]>,
         include_draft: bool,
+        filter: &SearchFilter,
     ) -> Result<Vec<SearchHit>, AppError>;
 
-    /// Configure the search index (filterable/searchable attributes).
-    /// Should be called once on startup.
-    async fn configure_index(&self) -> Result<(), AppError>;
+    /// Like [`search`](Self::search), but with keyword matching only.
+    /// Services that blend in other rankings override this to skip them.
+    async fn search_full_text(
+        &self,
+        query: &str,
+        allowed_levels: Option<&[String]>,
+        include_draft: bool,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SearchHit>, AppError> {
+        self.search(query, allowed_levels, include_draft, filter)
+            .await
+    }
+
+    /// Configure the search index (filterable/searchable attributes) and
+    /// apply the relevance tuning in `settings`.
+    /// Should be called on startup and whenever the tuning changes.
+    async fn configure_index(&self, settings: &SearchSettings) -> Result<(), AppError>;
+
+    /// List every document currently stored in the index. A missing index
+    /// yields an empty list.
+    async fn list_indexed_documents(&self) -> Result<Vec<SearchDocument>, AppError>;
 }
 
 /// Meilisearch implementation of the SearchService.
@@ -99,6 +175,15 @@ impl MeilisearchService {
         })
     }
 
+    /// Check that the server is reachable and healthy.
+    pub async fn check_health(&self) -> Result<(), AppError> {
+        self.client
+            .health()
+            .await
+            .map_err(|e| AppError::Internal(format!("Meilisearch health check failed: {e}")))?;
+        Ok(())
+    }
+
     fn index(&self) -> meilisearch_sdk::indexes::Index {
         self.client.index(&self.index_name)
     }
@@ -131,6 +216,7 @@ impl SearchService for MeilisearchService {
         query: &str,
         allowed_levels: Option<&[String]>,
         include_draft: bool,
+        filter: &SearchFilter,
     ) -> Result<Vec<SearchHit>, AppError> {
         // Build a Meilisearch filter expression.
         let mut filters: Vec<String> = Vec::new();
@@ -149,6 +235,22 @@ impl SearchService for MeilisearchService {
             filters.push("is_draft = false".to_string());
         }
 
+        // `!=` also matches entries indexed before the flag existed
+        filters.push("is_hidden != true".to_string());
+
+        if let Some(project) = &filter.project {
+            filters.push(format!("project = {}", serde_json::json!(project)));
+        }
+        for tag in &filter.tags {
+            filters.push(format!("tags = {}", serde_json::json!(tag)));
+        }
+        if let Some(owner) = &filter.service_owner {
+            filters.push(format!("service_owner = {}", serde_json::json!(owner)));
+        }
+        if let Some(before) = filter.updated_before {
+            filters.push(format!("last_updated < {before}"));
+        }
+
         let filter_str = filters.join(" AND ");
 
         let index = self.index();
@@ -172,17 +274,29 @@ impl SearchService for MeilisearchService {
                 title: hit.result.title,
                 tags: hit.result.tags,
                 content_preview: hit.result.content_preview,
+                deprecated_schemas: vec![],
+                superseded: false,
             })
             .collect();
 
         Ok(hits)
     }
 
-    async fn configure_index(&self) -> Result<(), AppError> {
+    async fn configure_index(&self, settings: &SearchSettings) -> Result<(), AppError> {
+        use meilisearch_sdk::settings::{MinWordSizeForTypos, TypoToleranceSettings};
+
         let index = self.index();
 
         let _: meilisearch_sdk::task_info::TaskInfo = index
-            .set_filterable_attributes(["access_level", "is_draft", "service_owner", "tags"])
+            .set_filterable_attributes([
+                "access_level",
+                "is_draft",
+                "is_hidden",
+                "service_owner",
+                "tags",
+                "project",
+                "last_updated",
+            ])
             .await
             .map_err(|e| AppError::Internal(format!("Meilisearch config error: {e}")))?;
 
@@ -192,12 +306,75 @@ impl SearchService for MeilisearchService {
             .map_err(|e| AppError::Internal(format!("Meilisearch config error: {e}")))?;
 
         let _: meilisearch_sdk::task_info::TaskInfo = index
-            .set_sortable_attributes(["last_updated"])
+            .set_sortable_attributes(SORTABLE_ATTRIBUTES)
+            .await
+            .map_err(|e| AppError::Internal(format!("Meilisearch config error: {e}")))?;
+
+        let _: meilisearch_sdk::task_info::TaskInfo = if settings.ranking_rules.is_empty() {
+            index.reset_ranking_rules().await
+        } else {
+            index.set_ranking_rules(&settings.ranking_rules).await
+        }
+        .map_err(|e| AppError::Internal(format!("Meilisearch config error: {e}")))?;
+
+        let _: meilisearch_sdk::task_info::TaskInfo = index
+            .set_synonyms(&settings.synonym_map())
+            .await
+            .map_err(|e| AppError::Internal(format!("Meilisearch config error: {e}")))?;
+
+        let _: meilisearch_sdk::task_info::TaskInfo = index
+            .set_stop_words(&settings.stop_words)
+            .await
+            .map_err(|e| AppError::Internal(format!("Meilisearch config error: {e}")))?;
+
+        let typo = &settings.typo_tolerance;
+        let _: meilisearch_sdk::task_info::TaskInfo = index
+            .set_typo_tolerance(&TypoToleranceSettings {
+                enabled: Some(typo.enabled),
+                disable_on_attributes: None,
+                disable_on_words: Some(typo.disable_on_words.clone()),
+                disable_on_numbers: None,
+                min_word_size_for_typos: Some(MinWordSizeForTypos {
+                    one_typo: Some(typo.min_word_size_one_typo),
+                    two_typos: Some(typo.min_word_size_two_typos),
+                }),
+            })
             .await
             .map_err(|e| AppError::Internal(format!("Meilisearch config error: {e}")))?;
 
         Ok(())
     }
+
+    async fn list_indexed_documents(&self) -> Result<Vec<SearchDocument>, AppError> {
+        use meilisearch_sdk::documents::DocumentsQuery;
+        use meilisearch_sdk::errors::{Error, ErrorCode};
+
+        const PAGE_SIZE: usize = 1000;
+
+        let index = self.index();
+        let mut documents = Vec::new();
+        loop {
+            let mut query = DocumentsQuery::new(&index);
+            query.with_limit(PAGE_SIZE).with_offset(documents.len());
+            let page = match query.execute::<SearchDocument>().await {
+                Ok(page) => page,
+                Err(Error::Meilisearch(e)) if matches!(e.error_code, ErrorCode::IndexNotFound) => {
+                    return Ok(vec![]);
+                }
+                Err(e) => {
+                    return Err(AppError::Internal(format!(
+                        "Meilisearch list documents error: {e}"
+                    )))
+                }
+            };
+
+            let fetched = page.results.len();
+            documents.extend(page.results);
+            if fetched < PAGE_SIZE {
+                return Ok(documents);
+            }
+        }
+    }
 }
 
 /// Convert a slug to a valid Meilisearch document ID.
@@ -222,15 +399,34 @@ pub fn build_search_document(
         title: doc.title.clone(),
         access_level: doc.access_level.clone(),
         is_draft: doc.is_draft,
+        is_hidden: doc.is_hidden,
         service_owner: doc.service_owner.clone(),
         tags: doc.tags.clone(),
         content_preview: preview,
         last_updated: doc.last_updated.timestamp(),
+        project: crate::db::project_repository::project_namespace(&doc.slug).to_string(),
+        content_hash: doc.content_hash.clone(),
+    }
+}
+
+/// Bring the index entry of a saved document up to date: index it with
+/// `raw_content`, or remove it when the document is hidden or archived.
+pub async fn sync_search_document(
+    search: &dyn SearchService,
+    doc: &crate::db::models::Document,
+    raw_content: &str,
+) -> Result<(), AppError> {
+    if doc.is_hidden || doc.is_archived {
+        search.delete_document(&doc.slug).await
+    } else {
+        search
+            .index_document(&build_search_document(doc, raw_content))
+            .await
     }
 }
 
 /// Strip basic markdown syntax for a content preview.
-fn strip_markdown_for_preview(raw: &str, max_len: usize) -> String {
+pub(crate) fn strip_markdown_for_preview(raw: &str, max_len: usize) -> String {
     use pulldown_cmark::{Event, Options, Parser};
 
     let options = Options::ENABLE_TABLES
@@ -244,7 +440,7 @@ fn strip_markdown_for_preview(raw: &str, max_len: usize) -> String {
     for event in parser {
         match event {
             Event::Text(t) | Event::Code(t) => {
-                if !text.is_empty() && !text.ends_with(' ') {
+                if !text.is_empty() && !text.ends_with(' ') && !t.starts_with(' ') {
                     text.push(' ');
                 }
                 text.push_str(&t);
@@ -291,60 +487,30 @@ mod tests {
 
     #[test]
     fn test_build_search_document() {
-        use chrono::Utc;
-
         let doc = crate::db::models::Document {
-            slug: "getting-started".to_string(),
             title: "Getting Started".to_string(),
-            summary: None,
-            s3_key: "docs/getting-started.md".to_string(),
-            access_level: "public".to_string(),
-            is_draft: false,
             service_owner: "platform".to_string(),
-            last_updated: Utc::now(),
             tags: vec!["intro".to_string()],
-            links_out: vec![],
-            backlinks: vec![],
-            parent_slug: None,
-            order: 0,
-            is_hidden: false,
-            content_hash: None,
-            metadata_hash: None,
-            is_archived: false,
-            source_path: None,
+            ..crate::testing::document("getting-started")
         };
 
         let search_doc = build_search_document(&doc, "# Getting Started\n\nWelcome to Lekton.");
         assert_eq!(search_doc.slug, "getting-started");
+        assert_eq!(search_doc.project, "getting-started");
         assert_eq!(search_doc.access_level, "public");
         assert!(!search_doc.is_draft);
+        assert!(!search_doc.is_hidden);
         assert!(search_doc.content_preview.contains("Getting Started"));
         assert!(search_doc.content_preview.contains("Welcome to Lekton"));
     }
 
     #[test]
     fn test_build_search_document_preserves_draft_flag() {
-        use chrono::Utc;
-
         let doc = crate::db::models::Document {
-            slug: "wip-doc".to_string(),
             title: "WIP".to_string(),
-            summary: None,
-            s3_key: "docs/wip.md".to_string(),
             access_level: "internal".to_string(),
             is_draft: true,
-            service_owner: "team".to_string(),
-            last_updated: Utc::now(),
-            tags: vec![],
-            links_out: vec![],
-            backlinks: vec![],
-            parent_slug: None,
-            order: 0,
-            is_hidden: false,
-            content_hash: None,
-            metadata_hash: None,
-            is_archived: false,
-            source_path: None,
+            ..crate::testing::document("wip-doc")
         };
 
         let search_doc = build_search_document(&doc, "# WIP content");
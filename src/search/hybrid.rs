@@ -0,0 +1,254 @@
+//! Semantic search blended into full-text search.
+//!
+//! [`HybridSearchService`] wraps the Meilisearch [`SearchService`] and, for
+//! every query, also runs a vector search over the document chunks that the
+//! RAG pipeline embeds at ingest time. Both rankings are merged per document
+//! with Reciprocal Rank Fusion, so documents that match a vaguely-worded
+//! query only semantically still surface, while exact keyword matches keep
+//! their place. Indexing and configuration are delegated to the wrapped
+//! service; the chunk embeddings are maintained by the RAG service.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::db::project_repository::project_namespace;
+use crate::db::settings_repository::SearchSettings;
+use crate::error::AppError;
+use crate::rag::embedding::EmbeddingService;
+use crate::rag::rrf::RRF_K;
+use crate::rag::vectorstore::{VectorSearchResult, VectorStore};
+use crate::search::client::{
+    strip_markdown_for_preview, SearchDocument, SearchFilter, SearchHit, SearchService,
+};
+
+/// Maximum number of results returned, matching the full-text search limit.
+const MAX_RESULTS: usize = 20;
+
+/// Chunks fetched from the vector store per query. Several chunks usually
+/// belong to the same document, so this is well above [`MAX_RESULTS`].
+const CHUNK_CANDIDATES: usize = 60;
+
+pub struct HybridSearchService {
+    text: Arc<dyn SearchService>,
+    embedding: Arc<dyn EmbeddingService>,
+    vectorstore: Arc<dyn VectorStore>,
+}
+
+impl HybridSearchService {
+    pub fn new(
+        text: Arc<dyn SearchService>,
+        embedding: Arc<dyn EmbeddingService>,
+        vectorstore: Arc<dyn VectorStore>,
+    ) -> Self {
+        Self {
+            text,
+            embedding,
+            vectorstore,
+        }
+    }
+
+    /// Documents ranked by their best matching chunk.
+    async fn semantic_search(
+        &self,
+        query: &str,
+        allowed_levels: Option<&[String]>,
+        include_draft: bool,
+        project: Option<&str>,
+    ) -> Result<Vec<SearchHit>, AppError> {
+        let vector = self
+            .embedding
+            .embed(&[query.to_string()])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AppError::Internal("Embedding service returned no vector".into()))?;
+
+        let chunks = self
+            .vectorstore
+            .search(vector, CHUNK_CANDIDATES, allowed_levels, include_draft)
+            .await?;
+        Ok(documents_from_chunks(chunks, project))
+    }
+}
+
+#[async_trait]
+impl SearchService for HybridSearchService {
+    async fn index_document(&self, doc: &SearchDocument) -> Result<(), AppError> {
+        self.text.index_document(doc).await
+    }
+
+    async fn delete_document(&self, slug: &str) -> Result<(), AppError> {
+        self.text.delete_document(slug).await
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        allowed_levels: Option<&[String]>,
+        include_draft: bool,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SearchHit>, AppError> {
+        // Chunks carry no tags, owner or dates to filter on
+        if query.trim().is_empty() || filter.restricts_metadata() {
+            return self
+                .text
+                .search(query, allowed_levels, include_draft, filter)
+                .await;
+        }
+
+        let (text_hits, semantic_hits) = tokio::join!(
+            self.text
+                .search(query, allowed_levels, include_draft, filter),
+            self.semantic_search(
+                query,
+                allowed_levels,
+                include_draft,
+                filter.project.as_deref()
+            ),
+        );
+        let text_hits = text_hits?;
+        match semantic_hits {
+            Ok(semantic_hits) => Ok(fuse_hits(text_hits, semantic_hits)),
+            Err(e) => {
+                tracing::warn!("Semantic search failed, returning full-text results only: {e}");
+                Ok(text_hits)
+            }
+        }
+    }
+
+    async fn search_full_text(
+        &self,
+        query: &str,
+        allowed_levels: Option<&[String]>,
+        include_draft: bool,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SearchHit>, AppError> {
+        self.text
+            .search(query, allowed_levels, include_draft, filter)
+            .await
+    }
+
+    async fn configure_index(&self, settings: &SearchSettings) -> Result<(), AppError> {
+        self.text.configure_index(settings).await
+    }
+
+    async fn list_indexed_documents(&self) -> Result<Vec<SearchDocument>, AppError> {
+        self.text.list_indexed_documents().await
+    }
+}
+
+/// Collapse chunk results into one hit per document, keeping the order of
+/// each document's best chunk and using that chunk as the preview.
+fn documents_from_chunks(chunks: Vec<VectorSearchResult>, project: Option<&str>) -> Vec<SearchHit> {
+    let mut hits: Vec<SearchHit> = Vec::new();
+    for chunk in chunks {
+        if project.is_some_and(|p| project_namespace(&chunk.document_slug) != p) {
+            continue;
+        }
+        if hits.iter().any(|h| h.slug == chunk.document_slug) {
+            continue;
+        }
+        hits.push(SearchHit {
+            slug: chunk.document_slug,
+            title: chunk.document_title,
+            tags: vec![],
+            content_preview: strip_markdown_for_preview(&chunk.chunk_text, 200),
+            deprecated_schemas: vec![],
+            superseded: false,
+        });
+    }
+    hits
+}
+
+/// Merge two document rankings with Reciprocal Rank Fusion.
+///
+/// Documents found by both searches keep the full-text hit (which carries
+/// tags and the document's own preview).
+fn fuse_hits(text_hits: Vec<SearchHit>, semantic_hits: Vec<SearchHit>) -> Vec<SearchHit> {
+    let mut scored: HashMap<String, (SearchHit, f64)> = HashMap::new();
+    for (rank, hit) in text_hits.into_iter().enumerate() {
+        let score = 1.0 / (RRF_K + rank + 1) as f64;
+        scored.insert(hit.slug.clone(), (hit, score));
+    }
+    for (rank, hit) in semantic_hits.into_iter().enumerate() {
+        let score = 1.0 / (RRF_K + rank + 1) as f64;
+        scored
+            .entry(hit.slug.clone())
+            .and_modify(|(_, s)| *s += score)
+            .or_insert((hit, score));
+    }
+
+    let mut scored: Vec<(SearchHit, f64)> = scored.into_values().collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.slug.cmp(&b.0.slug)));
+    scored
+        .into_iter()
+        .take(MAX_RESULTS)
+        .map(|(hit, _)| hit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(slug: &str) -> SearchHit {
+        SearchHit {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            tags: vec!["text".to_string()],
+            content_preview: String::new(),
+            deprecated_schemas: vec![],
+            superseded: false,
+        }
+    }
+
+    fn chunk(slug: &str, text: &str) -> VectorSearchResult {
+        VectorSearchResult {
+            point_id: String::new(),
+            chunk_text: text.to_string(),
+            document_slug: slug.to_string(),
+            document_title: format!("Title {slug}"),
+            chunk_index: 0,
+            section_path: vec![],
+            section_anchor: String::new(),
+            score: 0.5,
+        }
+    }
+
+    #[test]
+    fn documents_from_chunks_keeps_best_chunk_per_document() {
+        let chunks = vec![
+            chunk("payments/retries", "**Retry** policy"),
+            chunk("billing/invoices", "Invoices"),
+            chunk("payments/retries", "Backoff"),
+            chunk("payments/webhooks", "Webhooks"),
+        ];
+
+        let hits = documents_from_chunks(chunks.clone(), None);
+        let slugs: Vec<&str> = hits.iter().map(|h| h.slug.as_str()).collect();
+        assert_eq!(
+            slugs,
+            ["payments/retries", "billing/invoices", "payments/webhooks"]
+        );
+        assert_eq!(hits[0].content_preview, "Retry policy");
+
+        let hits = documents_from_chunks(chunks, Some("payments"));
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| h.slug.starts_with("payments/")));
+    }
+
+    #[test]
+    fn fuse_hits_promotes_documents_found_by_both() {
+        let text = vec![hit("a"), hit("b")];
+        let mut semantic = vec![hit("c"), hit("b")];
+        semantic[1].tags.clear();
+
+        let fused = fuse_hits(text, semantic);
+        let slugs: Vec<&str> = fused.iter().map(|h| h.slug.as_str()).collect();
+        assert_eq!(slugs, ["b", "a", "c"]);
+        // The full-text hit wins for documents found by both
+        assert_eq!(fused[0].tags, ["text"]);
+    }
+}
@@ -1,5 +1,8 @@
 pub mod client;
 #[cfg(feature = "ssr")]
+pub mod hybrid;
+pub mod query;
+#[cfg(feature = "ssr")]
 pub mod reindex;
 #[cfg(feature = "ssr")]
 pub mod tenant_token;
@@ -0,0 +1,173 @@
+//! Quick filters typed into the search box.
+//!
+//! `tag:k8s`, `owner:payments`, `type:schema` and `is:stale` are taken out
+//! of the query text in the browser and sent as [`QuickFilters`]; anything
+//! else, including unknown prefixes such as `http:`, stays part of the text.
+
+use serde::{Deserialize, Serialize};
+
+use crate::search::client::SearchFilter;
+
+/// The kind of results a search lists (`type:`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultType {
+    /// Documents only.
+    Doc,
+    /// Schema operations only.
+    Schema,
+}
+
+impl ResultType {
+    fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "doc" | "docs" | "document" | "documents" => Some(Self::Doc),
+            "schema" | "schemas" | "api" | "operation" | "operations" => Some(Self::Schema),
+            _ => None,
+        }
+    }
+}
+
+/// Filters parsed from the search box.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuickFilters {
+    /// `tag:` values; results carry all of them.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// `owner:` value, matched against the service owner.
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub result_type: Option<ResultType>,
+    /// `is:stale`: documents not updated for the scheduler's
+    /// `stale_after_days`.
+    #[serde(default)]
+    pub stale: bool,
+}
+
+impl QuickFilters {
+    /// Whether any filter restricts the documents listed, so that a search
+    /// without text is still meaningful.
+    pub fn restricts_documents(&self) -> bool {
+        !self.tags.is_empty() || self.owner.is_some() || self.stale
+    }
+
+    /// The filters, as typed, e.g. `["tag:k8s", "is:stale"]`.
+    pub fn labels(&self) -> Vec<String> {
+        let mut labels: Vec<String> = self.tags.iter().map(|tag| format!("tag:{tag}")).collect();
+        if let Some(owner) = &self.owner {
+            labels.push(format!("owner:{owner}"));
+        }
+        match self.result_type {
+            Some(ResultType::Doc) => labels.push("type:doc".into()),
+            Some(ResultType::Schema) => labels.push("type:schema".into()),
+            None => {}
+        }
+        if self.stale {
+            labels.push("is:stale".into());
+        }
+        labels
+    }
+
+    /// The document filter these amount to within `project`. Stale
+    /// documents are those last updated more than `stale_after_days` before
+    /// `now` (Unix seconds).
+    pub fn to_search_filter(
+        &self,
+        project: Option<&str>,
+        stale_after_days: u32,
+        now: i64,
+    ) -> SearchFilter {
+        SearchFilter {
+            project: project.map(str::to_string),
+            tags: self.tags.clone(),
+            service_owner: self.owner.clone(),
+            updated_before: self
+                .stale
+                .then(|| now - i64::from(stale_after_days) * 24 * 60 * 60),
+        }
+    }
+}
+
+/// A search box input split into free text and filters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedQuery {
+    pub text: String,
+    pub filters: QuickFilters,
+}
+
+/// Split `input` into free text and quick filters. A later `owner:` or
+/// `type:` replaces an earlier one; `tag:` accumulates.
+pub fn parse_query(input: &str) -> ParsedQuery {
+    let mut filters = QuickFilters::default();
+    let mut text: Vec<&str> = Vec::new();
+    for word in input.split_whitespace() {
+        let Some((key, value)) = word.split_once(':').filter(|(_, v)| !v.is_empty()) else {
+            text.push(word);
+            continue;
+        };
+        match key.to_lowercase().as_str() {
+            "tag" => {
+                if !filters.tags.iter().any(|t| t.eq_ignore_ascii_case(value)) {
+                    filters.tags.push(value.to_string());
+                }
+            }
+            "owner" => filters.owner = Some(value.to_string()),
+            "type" if ResultType::parse(value).is_some() => {
+                filters.result_type = ResultType::parse(value);
+            }
+            "is" if value.eq_ignore_ascii_case("stale") => filters.stale = true,
+            _ => text.push(word),
+        }
+    }
+    ParsedQuery {
+        text: text.join(" "),
+        filters,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query_extracts_filters() {
+        let parsed = parse_query("restart tag:k8s pods owner:payments TAG:ops is:stale type:docs");
+        assert_eq!(parsed.text, "restart pods");
+        assert_eq!(parsed.filters.tags, vec!["k8s", "ops"]);
+        assert_eq!(parsed.filters.owner.as_deref(), Some("payments"));
+        assert_eq!(parsed.filters.result_type, Some(ResultType::Doc));
+        assert!(parsed.filters.stale);
+        assert_eq!(
+            parsed.filters.labels(),
+            vec![
+                "tag:k8s",
+                "tag:ops",
+                "owner:payments",
+                "type:doc",
+                "is:stale"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_query_keeps_unknown_prefixes_as_text() {
+        let parsed = parse_query("http://example.com tag: type:video is:open note:x");
+        assert_eq!(
+            parsed.text,
+            "http://example.com tag: type:video is:open note:x"
+        );
+        assert_eq!(parsed.filters, QuickFilters::default());
+        assert!(!parsed.filters.restricts_documents());
+    }
+
+    #[test]
+    fn test_to_search_filter_converts_stale_to_a_cutoff() {
+        let filters = parse_query("tag:k8s is:stale").filters;
+        let filter = filters.to_search_filter(Some("payments"), 10, 1_000_000);
+        assert_eq!(filter.project.as_deref(), Some("payments"));
+        assert_eq!(filter.tags, vec!["k8s"]);
+        assert_eq!(filter.updated_before, Some(1_000_000 - 864_000));
+        assert!(filter.restricts_metadata());
+    }
+}
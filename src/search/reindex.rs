@@ -1,8 +1,11 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 
+use crate::db::models::Document;
 use crate::db::repository::DocumentRepository;
-use crate::search::client::{build_search_document, SearchService};
+use crate::db::settings_repository::SearchSettings;
+use crate::search::client::{build_search_document, SearchDocument, SearchService};
 use crate::storage::client::StorageClient;
 
 /// Shared state for tracking a background full-text search re-index operation.
@@ -23,10 +26,11 @@ pub async fn run_reindex(
     document_repo: Arc<dyn DocumentRepository>,
     storage: Arc<dyn StorageClient>,
     search: Arc<dyn SearchService>,
+    settings: SearchSettings,
 ) {
     reindex.progress.store(0, Ordering::Relaxed);
 
-    if let Err(e) = search.configure_index().await {
+    if let Err(e) = search.configure_index(&settings).await {
         tracing::warn!("Search reindex: failed to configure Meilisearch index: {e}");
     }
 
@@ -58,31 +62,183 @@ pub async fn run_reindex(
             continue;
         }
 
-        let content = match storage.get_object(&doc.s3_key).await {
-            Ok(Some(bytes)) => String::from_utf8_lossy(&bytes).into_owned(),
-            Ok(None) => {
-                tracing::warn!(slug = %doc.slug, "Search reindex: content not found in storage, skipping");
-                update_progress(&reindex, i, total);
-                continue;
-            }
+        index_from_storage(doc, storage.as_ref(), search.as_ref()).await;
+        update_progress(&reindex, i, total);
+    }
+
+    tracing::info!(total, "Search reindex: complete");
+    reindex.progress.store(100, Ordering::Relaxed);
+    reindex.is_running.store(false, Ordering::Release);
+}
+
+/// Bring the search index in line with MongoDB without rebuilding it.
+///
+/// Compares the index entries with the document metadata and content hashes,
+/// indexes visible documents that are missing or stale and deletes entries for
+/// documents that no longer exist or are hidden or archived. Run at startup so
+/// a restored Meilisearch volume or a missed index write does not silently
+/// hide documents from search.
+pub async fn run_reconciliation(
+    reindex: Arc<SearchReindexState>,
+    document_repo: Arc<dyn DocumentRepository>,
+    storage: Arc<dyn StorageClient>,
+    search: Arc<dyn SearchService>,
+) -> Option<usize> {
+    reindex.progress.store(0, Ordering::Relaxed);
+
+    let (documents, indexed) =
+        match tokio::try_join!(document_repo.list_all(), search.list_indexed_documents()) {
+            Ok(lists) => lists,
             Err(e) => {
-                tracing::warn!(slug = %doc.slug, "Search reindex: failed to read from storage: {e}");
-                update_progress(&reindex, i, total);
-                continue;
+                tracing::error!(
+                    "Search reconciliation: failed to compare index with database: {e}"
+                );
+                reindex.is_running.store(false, Ordering::Release);
+                return None;
             }
         };
 
-        let search_doc = build_search_document(doc, &content);
-        if let Err(e) = search.index_document(&search_doc).await {
-            tracing::warn!(slug = %doc.slug, "Search reindex: failed to index document: {e}");
-        }
+    let plan = plan_reconciliation(&documents, &indexed);
+    let total = plan.to_index.len() + plan.to_delete.len();
+    if total == 0 {
+        tracing::info!(
+            indexed = indexed.len(),
+            "Search reconciliation: index is up to date"
+        );
+    } else {
+        tracing::info!(
+            missing_or_stale = plan.to_index.len(),
+            orphaned = plan.to_delete.len(),
+            "Search reconciliation: starting"
+        );
+    }
 
+    for (i, slug) in plan.to_delete.iter().enumerate() {
+        if let Err(e) = search.delete_document(slug).await {
+            tracing::warn!(%slug, "Search reconciliation: failed to delete orphaned entry: {e}");
+        }
         update_progress(&reindex, i, total);
     }
+    let deleted = plan.to_delete.len();
+    for (i, doc) in plan.to_index.into_iter().enumerate() {
+        index_from_storage(doc, storage.as_ref(), search.as_ref()).await;
+        update_progress(&reindex, deleted + i, total);
+    }
 
-    tracing::info!(total, "Search reindex: complete");
+    if total > 0 {
+        tracing::info!(total, "Search reconciliation: complete");
+    }
     reindex.progress.store(100, Ordering::Relaxed);
     reindex.is_running.store(false, Ordering::Release);
+    Some(total)
+}
+
+/// Reconcile the index entries of `slugs` only, e.g. after those documents
+/// were changed in MongoDB. Returns the number of entries written or deleted.
+pub async fn reconcile_documents(
+    document_repo: &dyn DocumentRepository,
+    storage: &dyn StorageClient,
+    search: &dyn SearchService,
+    slugs: &[String],
+) -> Result<usize, crate::error::AppError> {
+    let (documents, indexed) = tokio::try_join!(
+        document_repo.find_by_slugs(slugs),
+        search.list_indexed_documents()
+    )?;
+    let indexed: Vec<SearchDocument> = indexed
+        .into_iter()
+        .filter(|entry| slugs.contains(&entry.slug))
+        .collect();
+
+    let plan = plan_reconciliation(&documents, &indexed);
+    let total = plan.to_index.len() + plan.to_delete.len();
+    for slug in &plan.to_delete {
+        if let Err(e) = search.delete_document(slug).await {
+            tracing::warn!(%slug, "Search reconciliation: failed to delete entry: {e}");
+        }
+    }
+    for doc in plan.to_index {
+        index_from_storage(doc, storage, search).await;
+    }
+    Ok(total)
+}
+
+/// Changes needed to make the index match the database.
+struct ReconciliationPlan<'a> {
+    /// Visible documents that are missing from the index or out of date.
+    to_index: Vec<&'a Document>,
+    /// Slugs of index entries with no visible document.
+    to_delete: Vec<String>,
+}
+
+fn plan_reconciliation<'a>(
+    documents: &'a [Document],
+    indexed: &[SearchDocument],
+) -> ReconciliationPlan<'a> {
+    let entries: HashMap<&str, &SearchDocument> =
+        indexed.iter().map(|e| (e.slug.as_str(), e)).collect();
+    let visible: Vec<&Document> = documents
+        .iter()
+        .filter(|doc| !doc.is_archived && !doc.is_hidden)
+        .collect();
+
+    let to_index = visible
+        .iter()
+        .filter(|doc| {
+            entries
+                .get(doc.slug.as_str())
+                .is_none_or(|entry| !is_current(entry, doc))
+        })
+        .copied()
+        .collect();
+
+    let visible_slugs: HashSet<&str> = visible.iter().map(|doc| doc.slug.as_str()).collect();
+    let to_delete = indexed
+        .iter()
+        .filter(|entry| !visible_slugs.contains(entry.slug.as_str()))
+        .map(|entry| entry.slug.clone())
+        .collect();
+
+    ReconciliationPlan {
+        to_index,
+        to_delete,
+    }
+}
+
+/// Whether an index entry reflects the document's current metadata and content.
+fn is_current(entry: &SearchDocument, doc: &Document) -> bool {
+    entry.title == doc.title
+        && entry.access_level == doc.access_level
+        && entry.is_draft == doc.is_draft
+        && entry.is_hidden == doc.is_hidden
+        && entry.service_owner == doc.service_owner
+        && entry.tags == doc.tags
+        && entry.last_updated == doc.last_updated.timestamp()
+        && entry.content_hash == doc.content_hash
+}
+
+/// Index one document from its stored markdown, logging (not returning) failures.
+pub(crate) async fn index_from_storage(
+    doc: &Document,
+    storage: &dyn StorageClient,
+    search: &dyn SearchService,
+) {
+    let content = match storage.get_object(&doc.s3_key).await {
+        Ok(Some(bytes)) => String::from_utf8_lossy(&bytes).into_owned(),
+        Ok(None) => {
+            tracing::warn!(slug = %doc.slug, "Search reindex: content not found in storage, skipping");
+            return;
+        }
+        Err(e) => {
+            tracing::warn!(slug = %doc.slug, "Search reindex: failed to read from storage: {e}");
+            return;
+        }
+    };
+
+    let search_doc = build_search_document(doc, &content);
+    if let Err(e) = search.index_document(&search_doc).await {
+        tracing::warn!(slug = %doc.slug, "Search reindex: failed to index document: {e}");
+    }
 }
 
 fn update_progress(reindex: &SearchReindexState, index: usize, total: usize) {
@@ -94,93 +250,23 @@ fn update_progress(reindex: &SearchReindexState, index: usize, total: usize) {
 mod tests {
     use super::*;
 
-    use std::collections::HashMap;
     use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
     use std::sync::Mutex;
 
     use async_trait::async_trait;
-    use chrono::Utc;
 
     use crate::db::models::Document;
     use crate::error::AppError;
-    use crate::search::client::{SearchDocument, SearchHit};
-
-    struct MockDocumentRepo {
-        documents: Vec<Document>,
-    }
-
-    #[async_trait]
-    impl DocumentRepository for MockDocumentRepo {
-        async fn create_or_update(&self, _: Document) -> Result<(), AppError> {
-            Ok(())
-        }
-
-        async fn find_by_slug(&self, _: &str) -> Result<Option<Document>, AppError> {
-            Ok(None)
-        }
-
-        async fn list_all(&self) -> Result<Vec<Document>, AppError> {
-            Ok(self.documents.clone())
-        }
-
-        async fn list_by_access_levels(
-            &self,
-            _: Option<&[String]>,
-            _: bool,
-        ) -> Result<Vec<Document>, AppError> {
-            Ok(self.documents.clone())
-        }
-
-        async fn update_backlinks(
-            &self,
-            _: &str,
-            _: &[String],
-            _: &[String],
-        ) -> Result<(), AppError> {
-            Ok(())
-        }
-
-        async fn find_by_slug_prefix(&self, _: &str) -> Result<Vec<Document>, AppError> {
-            Ok(self.documents.clone())
-        }
-
-        async fn set_archived(&self, _: &str, _: bool) -> Result<(), AppError> {
-            Ok(())
-        }
-        async fn find_by_source_path(&self, _: &str) -> Result<Option<Document>, AppError> {
-            Ok(None)
-        }
-    }
-
-    #[derive(Default)]
-    struct MockStorage {
-        objects: Mutex<HashMap<String, Vec<u8>>>,
-    }
-
-    #[async_trait]
-    impl StorageClient for MockStorage {
-        async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), AppError> {
-            self.objects
-                .lock()
-                .unwrap()
-                .insert(key.to_string(), content);
-            Ok(())
-        }
-
-        async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
-            Ok(self.objects.lock().unwrap().get(key).cloned())
-        }
-
-        async fn delete_object(&self, _: &str) -> Result<(), AppError> {
-            Ok(())
-        }
-    }
+    use crate::search::client::{SearchDocument, SearchFilter, SearchHit};
+    use crate::testing::{self, InMemoryDocumentRepository, InMemoryStorage};
 
+    /// Records index changes; `existing` is the index content it reports.
     #[derive(Default)]
     struct RecordingSearch {
         configured: AtomicBool,
         indexed: Mutex<Vec<SearchDocument>>,
         deleted: Mutex<Vec<String>>,
+        existing: Vec<SearchDocument>,
     }
 
     #[async_trait]
@@ -200,36 +286,30 @@ mod tests {
             _: &str,
             _: Option<&[String]>,
             _: bool,
+            _: &SearchFilter,
         ) -> Result<Vec<SearchHit>, AppError> {
             Ok(vec![])
         }
 
-        async fn configure_index(&self) -> Result<(), AppError> {
+        async fn configure_index(&self, _: &SearchSettings) -> Result<(), AppError> {
             self.configured.store(true, Ordering::Relaxed);
             Ok(())
         }
+
+        async fn list_indexed_documents(&self) -> Result<Vec<SearchDocument>, AppError> {
+            Ok(self.existing.clone())
+        }
     }
 
     fn make_doc(slug: &str, is_hidden: bool, is_archived: bool) -> Document {
         Document {
-            slug: slug.to_string(),
             title: format!("Title {slug}"),
-            summary: None,
-            s3_key: format!("docs/{}.md", slug.replace('/', "_")),
             access_level: "internal".to_string(),
-            is_draft: false,
             service_owner: "platform".to_string(),
-            last_updated: Utc::now(),
             tags: vec!["tag".to_string()],
-            links_out: vec![],
-            backlinks: vec![],
-            parent_slug: None,
-            order: 0,
             is_hidden,
-            content_hash: None,
-            metadata_hash: None,
             is_archived,
-            source_path: None,
+            ..testing::document(slug)
         }
     }
 
@@ -239,10 +319,12 @@ mod tests {
         let hidden = make_doc("docs/hidden", true, false);
         let archived = make_doc("docs/archived", false, true);
 
-        let repo = Arc::new(MockDocumentRepo {
-            documents: vec![active.clone(), hidden.clone(), archived.clone()],
-        });
-        let storage = Arc::new(MockStorage::default());
+        let repo = Arc::new(InMemoryDocumentRepository::with_documents(vec![
+            active.clone(),
+            hidden.clone(),
+            archived.clone(),
+        ]));
+        let storage = Arc::new(InMemoryStorage::new());
         storage
             .put_object(&active.s3_key, b"# Active\n\nVisible content".to_vec())
             .await
@@ -253,7 +335,14 @@ mod tests {
             progress: AtomicU32::new(0),
         });
 
-        run_reindex(state.clone(), repo, storage, search.clone()).await;
+        run_reindex(
+            state.clone(),
+            repo,
+            storage,
+            search.clone(),
+            SearchSettings::default(),
+        )
+        .await;
 
         assert!(!state.is_running.load(Ordering::Acquire));
         assert_eq!(state.progress.load(Ordering::Relaxed), 100);
@@ -267,4 +356,123 @@ mod tests {
         let deleted = search.deleted.lock().unwrap();
         assert_eq!(&*deleted, &vec![hidden.slug.clone(), archived.slug.clone()]);
     }
+
+    #[test]
+    fn plan_reconciliation_finds_missing_stale_and_orphaned_entries() {
+        let current = make_doc("docs/current", false, false);
+        let stale = make_doc("docs/stale", false, false);
+        let missing = make_doc("docs/missing", false, false);
+        let hidden = make_doc("docs/hidden", true, false);
+        let documents = vec![
+            current.clone(),
+            stale.clone(),
+            missing.clone(),
+            hidden.clone(),
+        ];
+
+        let mut stale_entry = build_search_document(&stale, "");
+        stale_entry.access_level = "public".to_string();
+        let indexed = vec![
+            build_search_document(&current, ""),
+            stale_entry,
+            build_search_document(&hidden, ""),
+            build_search_document(&make_doc("docs/deleted", false, false), ""),
+        ];
+
+        let plan = plan_reconciliation(&documents, &indexed);
+        let to_index: Vec<&str> = plan.to_index.iter().map(|d| d.slug.as_str()).collect();
+        assert_eq!(to_index, ["docs/stale", "docs/missing"]);
+        assert_eq!(plan.to_delete, ["docs/hidden", "docs/deleted"]);
+    }
+
+    #[test]
+    fn content_hash_change_makes_entry_stale() {
+        let mut doc = make_doc("docs/a", false, false);
+        doc.content_hash = Some("sha256:old".to_string());
+        let entry = build_search_document(&doc, "");
+        assert!(is_current(&entry, &doc));
+
+        doc.content_hash = Some("sha256:new".to_string());
+        assert!(!is_current(&entry, &doc));
+    }
+
+    #[tokio::test]
+    async fn reconciliation_only_touches_out_of_sync_entries() {
+        let current = make_doc("docs/current", false, false);
+        let missing = make_doc("docs/missing", false, false);
+
+        let repo = Arc::new(InMemoryDocumentRepository::with_documents(vec![
+            current.clone(),
+            missing.clone(),
+        ]));
+        let storage = Arc::new(InMemoryStorage::new());
+        storage
+            .put_object(&missing.s3_key, b"# Missing".to_vec())
+            .await
+            .unwrap();
+        let search = Arc::new(RecordingSearch {
+            existing: vec![
+                build_search_document(&current, ""),
+                build_search_document(&make_doc("docs/orphan", false, false), ""),
+            ],
+            ..Default::default()
+        });
+        let state = Arc::new(SearchReindexState {
+            is_running: AtomicBool::new(true),
+            progress: AtomicU32::new(0),
+        });
+
+        run_reconciliation(state.clone(), repo, storage, search.clone()).await;
+
+        assert!(!state.is_running.load(Ordering::Acquire));
+        assert_eq!(state.progress.load(Ordering::Relaxed), 100);
+        assert!(!search.configured.load(Ordering::Relaxed));
+
+        let indexed = search.indexed.lock().unwrap();
+        assert_eq!(indexed.len(), 1);
+        assert_eq!(indexed[0].slug, missing.slug);
+        assert_eq!(
+            &*search.deleted.lock().unwrap(),
+            &vec!["docs/orphan".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn reconcile_documents_only_touches_the_given_slugs() {
+        let changed = make_doc("docs/changed", false, false);
+        let now_hidden = make_doc("docs/now-hidden", true, false);
+        let untouched = make_doc("docs/untouched", false, false);
+
+        let repo = InMemoryDocumentRepository::with_documents(vec![
+            changed.clone(),
+            now_hidden.clone(),
+            untouched.clone(),
+        ]);
+        let storage = InMemoryStorage::new();
+        storage
+            .put_object(&changed.s3_key, b"# Changed".to_vec())
+            .await
+            .unwrap();
+        let mut stale_entry = build_search_document(&changed, "");
+        stale_entry.title = "Old title".to_string();
+        let search = RecordingSearch {
+            existing: vec![
+                stale_entry,
+                build_search_document(&now_hidden, ""),
+                build_search_document(&make_doc("docs/orphan", false, false), ""),
+            ],
+            ..Default::default()
+        };
+
+        let slugs = vec![changed.slug.clone(), now_hidden.slug.clone()];
+        let touched = reconcile_documents(&repo, &storage, &search, &slugs)
+            .await
+            .unwrap();
+
+        assert_eq!(touched, 2);
+        let indexed = search.indexed.lock().unwrap();
+        assert_eq!(indexed.len(), 1);
+        assert_eq!(indexed[0].title, changed.title);
+        assert_eq!(&*search.deleted.lock().unwrap(), &vec![now_hidden.slug]);
+    }
 }
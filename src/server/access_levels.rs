@@ -24,7 +24,7 @@ pub async fn list_admin_access_levels() -> Result<Vec<AccessLevelInfo>, ServerFn
         .access_level_repo
         .list_all()
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(levels
         .into_iter()
@@ -67,7 +67,7 @@ pub async fn create_admin_access_level(
         .access_level_repo
         .create(level)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))
+        .map_err(crate::error::AppError::into_server_fn_error)
 }
 
 #[server(UpdateAdminAccessLevel, "/api")]
@@ -84,7 +84,7 @@ pub async fn update_admin_access_level(
         .access_level_repo
         .find_by_name(&name)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .map_err(crate::error::AppError::into_server_fn_error)?
         .ok_or_else(|| ServerFnError::new(format!("Access level '{name}' not found")))?;
 
     let inheritance_changed = existing.inherits_from != inherits_from;
@@ -102,14 +102,15 @@ pub async fn update_admin_access_level(
         .access_level_repo
         .update(updated)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     if inheritance_changed {
-        crate::jobs::recompute_access_levels::spawn_recompute_for_level(
-            existing.name,
-            state.access_level_repo.clone(),
-            state.user_repo.clone(),
-        );
+        state
+            .job_queue
+            .enqueue(crate::jobs::Job::RecomputeAccessLevels {
+                level: existing.name,
+            })
+            .await;
     }
 
     Ok(())
@@ -124,5 +125,5 @@ pub async fn delete_admin_access_level(name: String) -> Result<(), ServerFnError
         .access_level_repo
         .delete(&name)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))
+        .map_err(crate::error::AppError::into_server_fn_error)
 }
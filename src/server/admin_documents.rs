@@ -0,0 +1,105 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+use crate::db::models::{DocumentBatchResult, DocumentBatchUpdate};
+#[cfg(feature = "ssr")]
+use crate::server::require_admin_user;
+
+/// A row of the admin documents table.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminDocumentInfo {
+    pub slug: String,
+    pub title: String,
+    pub service_owner: String,
+    pub access_level: String,
+    pub tags: Vec<String>,
+    pub parent_slug: Option<String>,
+    pub is_draft: bool,
+    pub is_hidden: bool,
+    pub last_updated: String,
+}
+
+/// Every active document, sorted by slug.
+#[server(ListAdminDocuments, "/api")]
+pub async fn list_admin_documents() -> Result<Vec<AdminDocumentInfo>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let mut docs = state
+        .document_repo
+        .list_all()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    docs.retain(|d| !d.is_archived);
+    docs.sort_by(|a, b| a.slug.cmp(&b.slug));
+
+    Ok(docs
+        .into_iter()
+        .map(|d| AdminDocumentInfo {
+            slug: d.slug,
+            title: d.title,
+            service_owner: d.service_owner,
+            access_level: d.access_level,
+            tags: d.tags,
+            parent_slug: d.parent_slug,
+            is_draft: d.is_draft,
+            is_hidden: d.is_hidden,
+            last_updated: d.last_updated.format("%Y-%m-%d %H:%M").to_string(),
+        })
+        .collect())
+}
+
+/// A document with style findings, for the doc quality report.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DocQualityEntry {
+    pub slug: String,
+    pub title: String,
+    pub service_owner: String,
+    pub findings: Vec<crate::rendering::lint::LintFinding>,
+}
+
+/// Active documents whose last ingest reported lint findings, those with
+/// the most findings first.
+#[server(GetDocQualityReport, "/api")]
+pub async fn get_doc_quality_report() -> Result<Vec<DocQualityEntry>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let docs = state
+        .document_repo
+        .list_all()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    let mut entries: Vec<DocQualityEntry> = docs
+        .into_iter()
+        .filter(|d| !d.is_archived && !d.lint_findings.is_empty())
+        .map(|d| DocQualityEntry {
+            slug: d.slug,
+            title: d.title,
+            service_owner: d.service_owner,
+            findings: d.lint_findings,
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        b.findings
+            .len()
+            .cmp(&a.findings.len())
+            .then_with(|| a.slug.cmp(&b.slug))
+    });
+    Ok(entries)
+}
+
+#[server(BatchUpdateDocuments, "/api")]
+pub async fn batch_update_admin_documents(
+    update: DocumentBatchUpdate,
+) -> Result<DocumentBatchResult, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_admin_user(&state).await?;
+    let actor = user.name.clone().unwrap_or_else(|| user.email.clone());
+
+    crate::api::documents::batch_update_documents(&state, update, &actor)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
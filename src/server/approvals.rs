@@ -0,0 +1,97 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_admin_user;
+
+/// An ingest to a protected slug waiting for admin approval.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingIngestInfo {
+    pub id: String,
+    pub slug: String,
+    pub title: String,
+    pub access_level: String,
+    pub submitted_by: String,
+    pub submitted_at: String,
+    pub changes: Vec<String>,
+    /// Submitted Markdown, shown for review before approving.
+    pub content: String,
+}
+
+#[server(ListPendingIngests, "/api")]
+pub async fn list_pending_ingests() -> Result<Vec<PendingIngestInfo>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let pending = state
+        .pending_ingest_repo
+        .list_all()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    Ok(pending
+        .into_iter()
+        .map(|p| PendingIngestInfo {
+            id: p.id,
+            slug: p.slug,
+            title: p.request.title,
+            access_level: p.request.access_level,
+            submitted_by: p.submitted_by,
+            submitted_at: p.submitted_at.format("%Y-%m-%d %H:%M").to_string(),
+            changes: p.changes,
+            content: p.request.content,
+        })
+        .collect())
+}
+
+#[server(ApprovePendingIngest, "/api")]
+pub async fn approve_pending_ingest(id: String) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_admin_user(&state).await?;
+
+    let response = crate::api::approvals::approve_pending_ingest(&state, &id, &user)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(format!("Published '{}'", response.slug))
+}
+
+#[server(RejectPendingIngest, "/api")]
+pub async fn reject_pending_ingest(id: String) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_admin_user(&state).await?;
+
+    crate::api::approvals::reject_pending_ingest(&state, &id, &user)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok("Pending ingest rejected".to_string())
+}
+
+#[server(GetProtectedPrefixes, "/api")]
+pub async fn get_protected_prefixes() -> Result<Vec<String>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let settings = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(settings.protected_prefixes)
+}
+
+#[server(SaveProtectedPrefixes, "/api")]
+pub async fn save_protected_prefixes(prefixes: Vec<String>) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let prefixes = crate::db::settings_repository::normalize_protected_prefixes(prefixes)
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    state
+        .settings_repo
+        .set_protected_prefixes(&prefixes)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok("Protected prefixes saved successfully".to_string())
+}
@@ -23,12 +23,8 @@ pub async fn get_current_user(
     }
 
     if state.demo_mode {
-        if let Some(cookie) = jar.get("lekton_demo_user") {
-            if let Ok(user) =
-                serde_json::from_str::<crate::auth::models::AuthenticatedUser>(cookie.value())
-            {
-                return Ok(Some(user));
-            }
+        if let Some(user) = crate::auth::demo_auth::session_user(&state.token_service, &jar) {
+            return Ok(Some(user));
         }
     }
 
@@ -47,10 +43,27 @@ pub async fn get_is_demo_mode() -> Result<bool, ServerFnError> {
     Ok(state.demo_mode)
 }
 
+/// Whether the login page offers the local account (email + password) form.
+#[server(GetIsLocalAccounts, "/api")]
+pub async fn get_is_local_accounts() -> Result<bool, ServerFnError> {
+    let state = expect_context::<AppState>();
+    Ok(state.local_accounts)
+}
+
+/// Whether the login page offers the directory (LDAP) sign-in form.
+#[server(GetIsLdapAuth, "/api")]
+pub async fn get_is_ldap_auth() -> Result<bool, ServerFnError> {
+    let state = expect_context::<AppState>();
+    Ok(state.ldap.is_some())
+}
+
+/// Whether the chat is available: RAG is configured and the chat feature
+/// flag is on.
 #[server(GetIsRagEnabled, "/api")]
 pub async fn get_is_rag_enabled() -> Result<bool, ServerFnError> {
-    let state = expect_context::<AppState>();
-    Ok(state.rag_service.is_some() && state.chat_service.is_some())
+    Ok(crate::server::portal_config::get_portal_config()
+        .await?
+        .chat_enabled)
 }
 
 #[server(LogoutUser, "/api")]
@@ -0,0 +1,55 @@
+use leptos::prelude::*;
+
+pub use crate::db::settings_repository::{BrandingSettings, FooterLink};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_admin_user;
+
+#[server(GetBranding, "/api")]
+pub async fn get_branding() -> Result<BrandingSettings, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let settings = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(settings.branding)
+}
+
+#[server(SaveBranding, "/api")]
+pub async fn save_branding(branding: BrandingSettings) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let branding = BrandingSettings {
+        portal_name: branding.portal_name.trim().to_string(),
+        logo_url: non_empty(branding.logo_url),
+        primary_color: non_empty(branding.primary_color),
+        default_theme: non_empty(branding.default_theme),
+        hero_title: non_empty(branding.hero_title),
+        hero_subtitle: non_empty(branding.hero_subtitle),
+        homepage_slug: non_empty(branding.homepage_slug),
+        footer_links: branding.footer_links,
+        access_request_url: non_empty(branding.access_request_url),
+    };
+    branding
+        .validate()
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    state
+        .settings_repo
+        .set_branding(&branding)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok("Branding saved successfully".to_string())
+}
+
+/// Treat blank form inputs as "not set".
+#[cfg(feature = "ssr")]
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
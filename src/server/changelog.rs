@@ -0,0 +1,58 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::request_document_visibility;
+
+/// Maximum number of entries returned by [`list_changelog`].
+#[cfg(feature = "ssr")]
+const CHANGELOG_LIMIT: i64 = 200;
+
+/// A changelog entry with its notes rendered to HTML.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChangelogItem {
+    pub service: String,
+    pub version: String,
+    /// Release date as `YYYY-MM-DD`.
+    pub date: String,
+    pub html: String,
+    pub published_by: String,
+}
+
+/// List changelog entries visible to the current user, newest first.
+///
+/// With `service` set, only that service's entries are returned.
+#[server(ListChangelog, "/api")]
+pub async fn list_changelog(service: Option<String>) -> Result<Vec<ChangelogItem>, ServerFnError> {
+    let state = expect_context::<AppState>();
+
+    // Changelog entries have no draft state, so only the access levels matter
+    let (allowed_levels, _) = request_document_visibility(&state).await?;
+    let entries = state
+        .changelog_repo
+        .list(
+            allowed_levels.as_deref(),
+            service.as_deref().filter(|s| !s.is_empty()),
+            CHANGELOG_LIMIT,
+        )
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    let embeds = crate::server::embeds::embeds(&state).await;
+    let ctx = crate::rendering::pipeline::RenderContext {
+        embeds: embeds.as_ref(),
+        ..Default::default()
+    };
+    Ok(entries
+        .into_iter()
+        .map(|entry| ChangelogItem {
+            html: state.render_pipeline.render(&entry.notes, &ctx),
+            date: entry.date.format("%Y-%m-%d").to_string(),
+            service: entry.service,
+            version: entry.version,
+            published_by: entry.published_by,
+        })
+        .collect())
+}
@@ -0,0 +1,446 @@
+//! Collections: ordered reading lists such as "New joiner onboarding", with
+//! each reader's progress through them.
+//!
+//! Any signed-in user can create a collection; its owner and admins can
+//! change or delete it. Readers only see the documents they could open, so
+//! a collection may look shorter to some readers than to others.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+
+/// Longest collection title accepted.
+#[cfg(feature = "ssr")]
+const MAX_TITLE_LEN: usize = 100;
+/// Longest collection description accepted.
+#[cfg(feature = "ssr")]
+const MAX_DESCRIPTION_LEN: usize = 1000;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CollectionSummary {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub owner_email: String,
+    /// Documents of the collection the caller can read.
+    pub document_count: usize,
+    /// Of those, the ones the caller marked as read.
+    pub read_count: usize,
+    pub can_edit: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CollectionItem {
+    pub slug: String,
+    pub title: String,
+    pub read: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CollectionView {
+    pub summary: CollectionSummary,
+    /// Readable documents, in reading order.
+    pub items: Vec<CollectionItem>,
+    /// Whether the caller is signed in and can track their progress.
+    pub signed_in: bool,
+}
+
+/// Where a document sits in a collection, for the footer of document pages.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CollectionNav {
+    pub id: String,
+    pub title: String,
+    /// 1-based position of the document among the readable ones.
+    pub position: usize,
+    pub total: usize,
+    pub previous: Option<CollectionItem>,
+    pub next: Option<CollectionItem>,
+    /// Whether the caller read the document; `None` for anonymous readers.
+    pub read: Option<bool>,
+}
+
+/// The caller's document visibility and, if signed in, the caller.
+#[cfg(feature = "ssr")]
+async fn reader(
+    state: &AppState,
+) -> Result<
+    (
+        (Option<Vec<String>>, bool),
+        Option<crate::auth::models::AuthenticatedUser>,
+    ),
+    ServerFnError,
+> {
+    let visibility = crate::server::request_document_visibility(state).await?;
+    let user = crate::server::require_any_user(state).await.ok();
+    Ok((visibility, user))
+}
+
+/// The documents among `slugs` the caller can read, keyed by slug.
+#[cfg(feature = "ssr")]
+async fn readable_documents(
+    state: &AppState,
+    visibility: &(Option<Vec<String>>, bool),
+    slugs: &[String],
+) -> Result<std::collections::HashMap<String, crate::db::models::Document>, ServerFnError> {
+    let (allowed_levels, include_draft) = visibility;
+    let docs = state
+        .document_repo
+        .find_by_slugs(slugs)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok(docs
+        .into_iter()
+        .filter(|doc| {
+            !doc.is_archived
+                && crate::app::doc_is_accessible(
+                    &doc.access_level,
+                    doc.is_draft,
+                    allowed_levels.as_deref(),
+                    *include_draft,
+                )
+        })
+        .map(|doc| (doc.slug.clone(), doc))
+        .collect())
+}
+
+/// The caller's read documents of each collection they started.
+#[cfg(feature = "ssr")]
+async fn read_slugs(
+    state: &AppState,
+    user: Option<&crate::auth::models::AuthenticatedUser>,
+) -> Result<std::collections::HashMap<String, Vec<String>>, ServerFnError> {
+    let Some(user) = user else {
+        return Ok(Default::default());
+    };
+    let progress = state
+        .collection_repo
+        .list_progress(&user.user_id)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok(progress
+        .into_iter()
+        .map(|p| (p.collection_id, p.read))
+        .collect())
+}
+
+#[cfg(feature = "ssr")]
+fn collection_items(
+    collection: &crate::db::collection_repository::Collection,
+    docs: &std::collections::HashMap<String, crate::db::models::Document>,
+    read: &[String],
+) -> Vec<CollectionItem> {
+    collection
+        .slugs
+        .iter()
+        .filter_map(|slug| docs.get(slug))
+        .map(|doc| CollectionItem {
+            slug: doc.slug.clone(),
+            title: doc.title.clone(),
+            read: read.contains(&doc.slug),
+        })
+        .collect()
+}
+
+#[cfg(feature = "ssr")]
+fn collection_summary(
+    collection: &crate::db::collection_repository::Collection,
+    items: &[CollectionItem],
+    user: Option<&crate::auth::models::AuthenticatedUser>,
+) -> CollectionSummary {
+    CollectionSummary {
+        id: collection.id.clone(),
+        title: collection.title.clone(),
+        description: collection.description.clone(),
+        owner_email: collection.owner_email.clone(),
+        document_count: items.len(),
+        read_count: items.iter().filter(|item| item.read).count(),
+        can_edit: user.is_some_and(|u| u.is_admin || u.user_id == collection.owner_id),
+    }
+}
+
+#[cfg(feature = "ssr")]
+async fn find_collection(
+    state: &AppState,
+    id: &str,
+) -> Result<crate::db::collection_repository::Collection, ServerFnError> {
+    state
+        .collection_repo
+        .find(id)
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Collection '{id}' not found")).into_server_fn_error()
+        })
+}
+
+/// The collection `id` and the caller, if the caller may change it.
+#[cfg(feature = "ssr")]
+async fn editable_collection(
+    state: &AppState,
+    id: &str,
+) -> Result<
+    (
+        crate::db::collection_repository::Collection,
+        crate::auth::models::AuthenticatedUser,
+    ),
+    ServerFnError,
+> {
+    let user = crate::server::require_any_user(state).await?;
+    let collection = find_collection(state, id).await?;
+    if !user.is_admin && user.user_id != collection.owner_id {
+        return Err(AppError::Forbidden(
+            "Only the owner of this collection and admins can change it".into(),
+        )
+        .into_server_fn_error());
+    }
+    Ok((collection, user))
+}
+
+/// Every collection with at least one document the caller can read, plus
+/// the caller's own, sorted by title.
+#[server(ListCollections, "/api")]
+pub async fn list_collections() -> Result<Vec<CollectionSummary>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let (visibility, user) = reader(&state).await?;
+
+    let collections = state
+        .collection_repo
+        .list_all()
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    let mut slugs: Vec<String> = collections
+        .iter()
+        .flat_map(|c| c.slugs.iter().cloned())
+        .collect();
+    slugs.sort();
+    slugs.dedup();
+    let docs = readable_documents(&state, &visibility, &slugs).await?;
+    let progress = read_slugs(&state, user.as_ref()).await?;
+
+    Ok(collections
+        .iter()
+        .map(|collection| {
+            let read = progress
+                .get(&collection.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let items = collection_items(collection, &docs, read);
+            collection_summary(collection, &items, user.as_ref())
+        })
+        .filter(|summary| summary.document_count > 0 || summary.can_edit)
+        .collect())
+}
+
+#[server(GetCollection, "/api")]
+pub async fn get_collection(id: String) -> Result<CollectionView, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let (visibility, user) = reader(&state).await?;
+
+    let collection = find_collection(&state, &id).await?;
+    let docs = readable_documents(&state, &visibility, &collection.slugs).await?;
+    let progress = read_slugs(&state, user.as_ref()).await?;
+    let read = progress
+        .get(&collection.id)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let items = collection_items(&collection, &docs, read);
+
+    Ok(CollectionView {
+        summary: collection_summary(&collection, &items, user.as_ref()),
+        items,
+        signed_in: user.is_some(),
+    })
+}
+
+/// Create a collection (`id` = `None`) or replace one. New collections get
+/// their URL from the title. Returns the collection ID.
+#[server(SaveCollection, "/api")]
+pub async fn save_collection(
+    id: Option<String>,
+    title: String,
+    description: String,
+    slugs: Vec<String>,
+) -> Result<String, ServerFnError> {
+    use crate::db::collection_repository::{collection_id, Collection, MAX_COLLECTION_DOCUMENTS};
+
+    let state = expect_context::<AppState>();
+    let (existing, user) = match &id {
+        Some(id) => {
+            let (collection, user) = editable_collection(&state, id).await?;
+            (Some(collection), user)
+        }
+        None => (None, crate::server::require_any_user(&state).await?),
+    };
+
+    let title = title.trim().to_string();
+    if title.is_empty() || title.chars().count() > MAX_TITLE_LEN {
+        return Err(AppError::BadRequest(format!(
+            "Titles must be 1 to {MAX_TITLE_LEN} characters"
+        ))
+        .into_server_fn_error());
+    }
+    let description = description.trim().to_string();
+    if description.chars().count() > MAX_DESCRIPTION_LEN {
+        return Err(AppError::BadRequest(format!(
+            "Descriptions must be at most {MAX_DESCRIPTION_LEN} characters"
+        ))
+        .into_server_fn_error());
+    }
+    let mut ordered: Vec<String> = Vec::new();
+    for slug in slugs {
+        let slug = slug.trim().trim_matches('/').to_string();
+        if !slug.is_empty() && !ordered.contains(&slug) {
+            ordered.push(slug);
+        }
+    }
+    if ordered.len() > MAX_COLLECTION_DOCUMENTS {
+        return Err(AppError::BadRequest(format!(
+            "Collections can list at most {MAX_COLLECTION_DOCUMENTS} documents"
+        ))
+        .into_server_fn_error());
+    }
+
+    // Owners may only add documents they can read themselves; documents
+    // already listed stay even if they became unreadable to the editor.
+    let visibility = crate::server::request_document_visibility(&state).await?;
+    let docs = readable_documents(&state, &visibility, &ordered).await?;
+    let unknown: Vec<&str> = ordered
+        .iter()
+        .filter(|slug| {
+            !docs.contains_key(*slug) && !existing.as_ref().is_some_and(|c| c.slugs.contains(*slug))
+        })
+        .map(String::as_str)
+        .collect();
+    if !unknown.is_empty() {
+        return Err(
+            AppError::BadRequest(format!("Documents not found: {}", unknown.join(", ")))
+                .into_server_fn_error(),
+        );
+    }
+
+    let now = chrono::Utc::now();
+    match existing {
+        Some(existing) => {
+            let id = existing.id.clone();
+            state
+                .collection_repo
+                .update(Collection {
+                    title,
+                    description,
+                    slugs: ordered,
+                    updated_at: now,
+                    ..existing
+                })
+                .await
+                .map_err(AppError::into_server_fn_error)?;
+            tracing::info!(collection = %id, updated_by = %user.email, "Collection updated");
+            Ok(id)
+        }
+        None => {
+            let id = collection_id(&title);
+            if id.is_empty() {
+                return Err(AppError::BadRequest(
+                    "Titles must contain at least one letter or digit".into(),
+                )
+                .into_server_fn_error());
+            }
+            state
+                .collection_repo
+                .insert(Collection {
+                    id: id.clone(),
+                    title,
+                    description,
+                    slugs: ordered,
+                    owner_id: user.user_id,
+                    owner_email: user.email.clone(),
+                    updated_at: now,
+                })
+                .await
+                .map_err(AppError::into_server_fn_error)?;
+            tracing::info!(collection = %id, created_by = %user.email, "Collection created");
+            Ok(id)
+        }
+    }
+}
+
+#[server(DeleteCollection, "/api")]
+pub async fn delete_collection(id: String) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let (_, user) = editable_collection(&state, &id).await?;
+
+    state
+        .collection_repo
+        .delete(&id)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    tracing::info!(collection = %id, deleted_by = %user.email, "Collection deleted");
+    Ok(())
+}
+
+/// Mark a document of a collection as read or unread for the caller.
+#[server(SetCollectionProgress, "/api")]
+pub async fn set_collection_progress(
+    id: String,
+    slug: String,
+    read: bool,
+) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = crate::server::require_any_user(&state).await?;
+
+    let collection = find_collection(&state, &id).await?;
+    if !collection.slugs.contains(&slug) {
+        return Err(AppError::BadRequest(format!(
+            "Document '{slug}' is not part of collection '{id}'"
+        ))
+        .into_server_fn_error());
+    }
+    state
+        .collection_repo
+        .set_read(&user.user_id, &id, &slug, read)
+        .await
+        .map_err(AppError::into_server_fn_error)
+}
+
+/// The previous and next documents around `slug` in `collection`, or `None`
+/// when the collection does not exist or the document is not part of it.
+#[server(GetCollectionNav, "/api")]
+pub async fn get_collection_nav(
+    collection: String,
+    slug: String,
+) -> Result<Option<CollectionNav>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let (visibility, user) = reader(&state).await?;
+
+    let Some(collection) = state
+        .collection_repo
+        .find(&collection)
+        .await
+        .map_err(AppError::into_server_fn_error)?
+    else {
+        return Ok(None);
+    };
+    let docs = readable_documents(&state, &visibility, &collection.slugs).await?;
+    let progress = read_slugs(&state, user.as_ref()).await?;
+    let read = progress
+        .get(&collection.id)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let items = collection_items(&collection, &docs, read);
+    let Some(index) = items.iter().position(|item| item.slug == slug) else {
+        return Ok(None);
+    };
+
+    Ok(Some(CollectionNav {
+        id: collection.id,
+        title: collection.title,
+        position: index + 1,
+        total: items.len(),
+        previous: index.checked_sub(1).map(|i| items[i].clone()),
+        next: items.get(index + 1).cloned(),
+        read: user.map(|_| items[index].read),
+    }))
+}
@@ -10,7 +10,7 @@ pub async fn get_custom_css() -> Result<String, ServerFnError> {
         .settings_repo
         .get_settings()
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     Ok(settings.custom_css)
 }
 
@@ -21,6 +21,6 @@ pub async fn save_custom_css(css: String) -> Result<String, ServerFnError> {
         .settings_repo
         .set_custom_css(&css)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     Ok("Custom CSS saved successfully".to_string())
 }
@@ -0,0 +1,278 @@
+//! "Was this helpful?" ratings at the bottom of documents.
+//!
+//! Signed-in readers rate a document up or down, optionally with a comment.
+//! Editors of a document see its totals; admins see them per service owner
+//! and document, with the comments.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use crate::db::doc_rating_repository::RatingCounts;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+
+/// Longest comment accepted with a rating.
+#[cfg(feature = "ssr")]
+const MAX_COMMENT_LEN: usize = 1000;
+/// Comments listed per document.
+#[cfg(feature = "ssr")]
+const COMMENT_LIMIT: i64 = 100;
+
+/// The caller's view of a document's ratings.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DocRatingStatus {
+    /// The caller's rating: `Some(true)` for helpful.
+    pub mine: Option<bool>,
+    pub comment: String,
+    /// Totals, for editors of the document only.
+    pub counts: Option<RatingCounts>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OwnerRatingRow {
+    pub service_owner: String,
+    pub counts: RatingCounts,
+    /// Rated documents of the owner.
+    pub document_count: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DocRatingRow {
+    pub slug: String,
+    pub title: String,
+    pub service_owner: String,
+    pub counts: RatingCounts,
+    pub comment_count: u64,
+}
+
+/// Ratings per service owner and per document, least helpful first.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DocRatingReport {
+    pub owners: Vec<OwnerRatingRow>,
+    pub documents: Vec<DocRatingRow>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DocRatingComment {
+    pub helpful: bool,
+    pub comment: String,
+    /// The comment as HTML, with its issue keys linked.
+    pub comment_html: String,
+    pub user_email: String,
+    pub updated_at: String,
+}
+
+/// The document at `slug`, if the caller can read it.
+#[cfg(feature = "ssr")]
+async fn readable_document(
+    state: &AppState,
+    slug: &str,
+) -> Result<crate::db::models::Document, ServerFnError> {
+    let (allowed_levels, include_draft) = crate::server::request_document_visibility(state).await?;
+    state
+        .document_repo
+        .find_by_slug(slug)
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .filter(|doc| {
+            !doc.is_archived
+                && crate::app::doc_is_accessible(
+                    &doc.access_level,
+                    doc.is_draft,
+                    allowed_levels.as_deref(),
+                    include_draft,
+                )
+        })
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Document '{slug}' not found")).into_server_fn_error()
+        })
+}
+
+/// Least helpful first; unrated sort last.
+#[cfg(feature = "ssr")]
+fn by_helpfulness(a: &RatingCounts, b: &RatingCounts) -> std::cmp::Ordering {
+    a.helpful_percent()
+        .unwrap_or(u64::MAX)
+        .cmp(&b.helpful_percent().unwrap_or(u64::MAX))
+        .then(b.total().cmp(&a.total()))
+}
+
+/// The caller's rating of `slug`, and its totals for editors. Anonymous
+/// readers get an empty status.
+#[server(GetDocRating, "/api")]
+pub async fn get_doc_rating(slug: String) -> Result<DocRatingStatus, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let doc = readable_document(&state, &slug).await?;
+    let Ok(ctx) = crate::server::require_user_context(&state).await else {
+        return Ok(DocRatingStatus::default());
+    };
+
+    let mine = state
+        .doc_rating_repo
+        .find(&doc.slug, &ctx.user.user_id)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    let counts = if ctx.can_edit_document(
+        &doc.access_level,
+        doc.is_draft,
+        doc.edit_access_level.as_deref(),
+    ) {
+        Some(
+            state
+                .doc_rating_repo
+                .counts(&doc.slug)
+                .await
+                .map_err(AppError::into_server_fn_error)?,
+        )
+    } else {
+        None
+    };
+    Ok(DocRatingStatus {
+        mine: mine.as_ref().map(|r| r.helpful),
+        comment: mine.and_then(|r| r.comment).unwrap_or_default(),
+        counts,
+    })
+}
+
+/// Rate `slug` as helpful or not, replacing the caller's previous rating.
+#[server(RateDocument, "/api")]
+pub async fn rate_document(
+    slug: String,
+    helpful: bool,
+    comment: String,
+) -> Result<(), ServerFnError> {
+    use crate::db::doc_rating_repository::DocRating;
+
+    let state = expect_context::<AppState>();
+    let user = crate::server::require_any_user(&state).await?;
+    let doc = readable_document(&state, &slug).await?;
+
+    let comment = comment.trim().to_string();
+    if comment.chars().count() > MAX_COMMENT_LEN {
+        return Err(AppError::BadRequest(format!(
+            "Comments must be at most {MAX_COMMENT_LEN} characters"
+        ))
+        .into_server_fn_error());
+    }
+    state
+        .doc_rating_repo
+        .upsert(DocRating {
+            slug: doc.slug,
+            user_id: user.user_id,
+            user_email: user.email,
+            helpful,
+            comment: Some(comment).filter(|c| !c.is_empty()),
+            updated_at: chrono::Utc::now(),
+        })
+        .await
+        .map_err(AppError::into_server_fn_error)
+}
+
+/// Ratings of active documents per service owner and per document (admin
+/// only).
+#[server(GetDocRatingReport, "/api")]
+pub async fn get_doc_rating_report() -> Result<DocRatingReport, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let rated = state
+        .doc_rating_repo
+        .counts_by_document()
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    let slugs: Vec<String> = rated.iter().map(|r| r.slug.clone()).collect();
+    let docs: std::collections::HashMap<String, crate::db::models::Document> = state
+        .document_repo
+        .find_by_slugs(&slugs)
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .into_iter()
+        .filter(|doc| !doc.is_archived)
+        .map(|doc| (doc.slug.clone(), doc))
+        .collect();
+
+    let mut documents: Vec<DocRatingRow> = rated
+        .into_iter()
+        .filter_map(|r| {
+            let doc = docs.get(&r.slug)?;
+            Some(DocRatingRow {
+                title: doc.title.clone(),
+                service_owner: doc.service_owner.clone(),
+                slug: r.slug,
+                counts: r.counts,
+                comment_count: r.comment_count,
+            })
+        })
+        .collect();
+    documents.sort_by(|a, b| by_helpfulness(&a.counts, &b.counts).then(a.slug.cmp(&b.slug)));
+
+    let mut owners: Vec<OwnerRatingRow> = Vec::new();
+    for row in &documents {
+        match owners
+            .iter_mut()
+            .find(|o| o.service_owner == row.service_owner)
+        {
+            Some(owner) => {
+                owner.counts.add(row.counts);
+                owner.document_count += 1;
+            }
+            None => owners.push(OwnerRatingRow {
+                service_owner: row.service_owner.clone(),
+                counts: row.counts,
+                document_count: 1,
+            }),
+        }
+    }
+    owners.sort_by(|a, b| {
+        by_helpfulness(&a.counts, &b.counts).then(a.service_owner.cmp(&b.service_owner))
+    });
+
+    Ok(DocRatingReport { owners, documents })
+}
+
+/// Most recent comments left with ratings of `slug` (admin only).
+#[server(ListDocRatingComments, "/api")]
+pub async fn list_doc_rating_comments(
+    slug: String,
+) -> Result<Vec<DocRatingComment>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let ratings = state
+        .doc_rating_repo
+        .list_comments(&slug, COMMENT_LIMIT)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    let issues = crate::server::issue_tracker::issue_links(&state, |projects| {
+        let mut keys = Vec::new();
+        for comment in ratings.iter().filter_map(|r| r.comment.as_deref()) {
+            for key in crate::rendering::issues::extract_text_issue_keys(comment, projects) {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+        keys
+    })
+    .await;
+    Ok(ratings
+        .into_iter()
+        .map(|rating| {
+            let comment = rating.comment.unwrap_or_default();
+            let comment_html = match &issues {
+                Some(links) => crate::rendering::issues::linkify_issue_keys(&comment, links),
+                None => crate::rendering::markdown::escape_html(&comment),
+            };
+            DocRatingComment {
+                helpful: rating.helpful,
+                comment,
+                comment_html,
+                user_email: rating.user_email,
+                updated_at: rating.updated_at.format("%Y-%m-%d %H:%M").to_string(),
+            }
+        })
+        .collect())
+}
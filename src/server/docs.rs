@@ -9,7 +9,8 @@ use crate::server::request_document_visibility;
 pub async fn get_doc_html(
     slug: String,
 ) -> Result<Option<crate::pages::DocPageData>, ServerFnError> {
-    use crate::rendering::markdown::{extract_headings, render_markdown};
+    use crate::rendering::markdown::extract_headings;
+    use crate::rendering::pipeline::RenderContext;
 
     let state = expect_context::<AppState>();
 
@@ -17,7 +18,7 @@ pub async fn get_doc_html(
         .document_repo
         .find_by_slug(&slug)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     let Some(doc) = doc else {
         let (allowed_levels, include_draft) = request_document_visibility(&state).await?;
@@ -25,7 +26,7 @@ pub async fn get_doc_html(
             .document_repo
             .list_by_access_levels(allowed_levels.as_deref(), include_draft)
             .await
-            .map_err(|e| ServerFnError::new(e.to_string()))?;
+            .map_err(crate::error::AppError::into_server_fn_error)?;
 
         let mut children: Vec<_> = all_docs
             .iter()
@@ -102,6 +103,24 @@ pub async fn get_doc_html(
                 headings: vec![],
                 last_updated: chrono::Utc::now().format("%B %d, %Y").to_string(),
                 tags: vec![],
+                last_updated_by: None,
+                contributors: vec![],
+                can_edit: false,
+                can_share: false,
+                edit_access_level: None,
+                deprecated_schemas: vec![],
+                bundle: None,
+                ancestor_titles: ancestor_titles(
+                    &state,
+                    &slug,
+                    allowed_levels.as_deref(),
+                    include_draft,
+                )
+                .await,
+                relations: Default::default(),
+                truncated_from: None,
+                previous_doc: None,
+                next_doc: None,
             }));
         }
 
@@ -135,6 +154,24 @@ pub async fn get_doc_html(
             headings: vec![],
             last_updated: chrono::Utc::now().format("%B %d, %Y").to_string(),
             tags: vec![],
+            last_updated_by: None,
+            contributors: vec![],
+            can_edit: false,
+            can_share: false,
+            edit_access_level: None,
+            deprecated_schemas: vec![],
+            bundle: None,
+            ancestor_titles: ancestor_titles(
+                &state,
+                &slug,
+                allowed_levels.as_deref(),
+                include_draft,
+            )
+            .await,
+            relations: Default::default(),
+            truncated_from: None,
+            previous_doc: None,
+            next_doc: None,
         }));
     };
 
@@ -145,23 +182,106 @@ pub async fn get_doc_html(
         allowed_levels.as_deref(),
         include_draft,
     ) {
-        return Ok(None);
+        // Drafts stay hidden; a level the caller lacks gets the 403 view
+        let level_readable = allowed_levels
+            .as_deref()
+            .is_none_or(|levels| levels.contains(&doc.access_level));
+        if level_readable {
+            return Ok(None);
+        }
+        return Err(ServerFnError::new(
+            crate::auth::models::access_denied_message(&doc.access_level),
+        ));
     }
 
+    let editor_enabled = crate::features::flags(&state)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?
+        .editor;
+    let is_doc_editor = crate::server::require_user_context(&state)
+        .await
+        .is_ok_and(|ctx| {
+            ctx.can_edit_document(
+                &doc.access_level,
+                doc.is_draft,
+                doc.edit_access_level.as_deref(),
+            )
+        });
+    let can_edit = editor_enabled && is_doc_editor;
+    // Share links work without the editor; public documents need none
+    let can_share = state.share_links.enabled && is_doc_editor && doc.access_level != "public";
+
     let content_bytes = state
         .storage_client
         .get_object(&doc.s3_key)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     let Some(content_bytes) = content_bytes else {
         return Ok(None);
     };
 
-    let raw = String::from_utf8(content_bytes).map_err(|e| ServerFnError::new(e.to_string()))?;
+    let raw = crate::rendering::limits::decode_text(content_bytes).map_err(|e| {
+        tracing::warn!(slug = %doc.slug, s3_key = %doc.s3_key, "Cannot render document: {e}");
+        ServerFnError::new(format!("Cannot display this document: {e}"))
+    })?;
+    let preview = crate::rendering::limits::preview(&raw, state.max_render_bytes);
+    let truncated_from = preview.is_some().then_some(raw.len() as u64);
+    let rendered = preview.unwrap_or(&raw);
 
-    let html = render_markdown(&raw);
-    let headings = extract_headings(&raw);
+    let glossary = match state.glossary_repo.list_all().await {
+        Ok(terms) => crate::server::glossary::tooltips(terms),
+        Err(e) => {
+            tracing::warn!("Failed to load glossary, rendering without tooltips: {e}");
+            vec![]
+        }
+    };
+    let deprecated_schemas = crate::schema::deprecation::document_notices(
+        state.schema_repo.as_ref(),
+        &doc.links_out,
+        allowed_levels.as_deref(),
+    )
+    .await
+    .unwrap_or_else(|e| {
+        tracing::warn!(slug = %doc.slug, "Failed to check linked schemas for deprecation: {e}");
+        vec![]
+    });
+    let bundle = match state.bundle_repo.list_all().await {
+        Ok(bundles) => bundle_versions(&bundles, &doc.slug),
+        Err(e) => {
+            tracing::warn!(slug = %doc.slug, "Failed to load doc bundles: {e}");
+            None
+        }
+    };
+    let relations = crate::relations::resolve_relations(state.document_repo.as_ref(), &doc, |d| {
+        doc_is_accessible(
+            &d.access_level,
+            d.is_draft,
+            allowed_levels.as_deref(),
+            include_draft,
+        )
+    })
+    .await
+    .unwrap_or_else(|e| {
+        tracing::warn!(slug = %doc.slug, "Failed to resolve document relations: {e}");
+        Default::default()
+    });
+    let (previous_doc, next_doc) =
+        neighbours(&state, &doc.slug, allowed_levels.as_deref(), include_draft).await;
+    let issues = crate::server::issue_tracker::issue_links(&state, |projects| {
+        crate::rendering::issues::extract_issue_keys(rendered, projects)
+    })
+    .await;
+    let embeds = crate::server::embeds::embeds(&state).await;
+    let html = state.render_pipeline.render(
+        rendered,
+        &RenderContext {
+            glossary: &glossary,
+            issues: issues.as_ref(),
+            embeds: embeds.as_ref(),
+        },
+    );
+    let headings = extract_headings(rendered);
     let last_updated = doc.last_updated.format("%B %d, %Y").to_string();
 
     Ok(Some(crate::pages::DocPageData {
@@ -170,5 +290,368 @@ pub async fn get_doc_html(
         headings,
         last_updated,
         tags: doc.tags,
+        last_updated_by: doc.last_updated_by,
+        contributors: doc.contributors,
+        can_edit,
+        can_share,
+        edit_access_level: doc.edit_access_level,
+        deprecated_schemas,
+        bundle,
+        ancestor_titles: ancestor_titles(&state, &slug, allowed_levels.as_deref(), include_draft)
+            .await,
+        relations,
+        truncated_from,
+        previous_doc,
+        next_doc,
     }))
 }
+
+/// Titles of the documents at the ancestor paths of `slug` that the caller
+/// can read, fetched in one query.
+#[cfg(feature = "ssr")]
+async fn ancestor_titles(
+    state: &AppState,
+    slug: &str,
+    allowed_levels: Option<&[String]>,
+    include_draft: bool,
+) -> Vec<(String, String)> {
+    let parts: Vec<&str> = slug.split('/').collect();
+    let ancestors: Vec<String> = (1..parts.len()).map(|n| parts[..n].join("/")).collect();
+    if ancestors.is_empty() {
+        return vec![];
+    }
+    match state.document_repo.find_by_slugs(&ancestors).await {
+        Ok(docs) => docs
+            .into_iter()
+            .filter(|doc| {
+                !doc.is_archived
+                    && doc_is_accessible(
+                        &doc.access_level,
+                        doc.is_draft,
+                        allowed_levels,
+                        include_draft,
+                    )
+            })
+            .map(|doc| (doc.slug, doc.title))
+            .collect(),
+        Err(e) => {
+            tracing::warn!(slug, "Failed to load breadcrumb titles: {e}");
+            vec![]
+        }
+    }
+}
+
+/// `(slug, title)` of the readable documents before and after `slug` under
+/// its parent, in sidebar order.
+#[cfg(feature = "ssr")]
+async fn neighbours(
+    state: &AppState,
+    slug: &str,
+    allowed_levels: Option<&[String]>,
+    include_draft: bool,
+) -> (Option<(String, String)>, Option<(String, String)>) {
+    let (docs, weights) = tokio::join!(
+        state
+            .document_repo
+            .list_by_access_levels(allowed_levels, include_draft),
+        state.navigation_order_repo.list_all(),
+    );
+    let docs = match docs {
+        Ok(docs) => docs,
+        Err(e) => {
+            tracing::warn!(slug, "Failed to load sibling documents: {e}");
+            return (None, None);
+        }
+    };
+    let weights = weights
+        .map(|entries| entries.into_iter().map(|e| (e.slug, e.weight)).collect())
+        .unwrap_or_else(|e| {
+            tracing::warn!(slug, "Failed to load navigation order: {e}");
+            Default::default()
+        });
+    sibling_neighbours(&docs, &weights, slug)
+}
+
+/// The parent a document is listed under in the sidebar: its `parent_slug`,
+/// or else the path above its slug.
+#[cfg(feature = "ssr")]
+fn nav_parent(doc: &crate::db::models::Document) -> Option<&str> {
+    doc.parent_slug
+        .as_deref()
+        .or_else(|| doc.slug.rsplit_once('/').map(|(parent, _)| parent))
+}
+
+/// Slug and title of a neighbouring document.
+#[cfg(feature = "ssr")]
+type Neighbour = Option<(String, String)>;
+
+/// The documents before and after `slug` among those sharing its parent,
+/// ordered like the sidebar: documents by `order`, sections (documents with
+/// children) by their navigation weight, then by title. Top-level documents
+/// have no neighbours.
+#[cfg(feature = "ssr")]
+fn sibling_neighbours(
+    docs: &[crate::db::models::Document],
+    weights: &std::collections::HashMap<String, i32>,
+    slug: &str,
+) -> (Neighbour, Neighbour) {
+    let Some(parent) = docs
+        .iter()
+        .find(|doc| doc.slug == slug)
+        .and_then(nav_parent)
+    else {
+        return (None, None);
+    };
+    let is_section = |slug: &str| docs.iter().any(|doc| nav_parent(doc) == Some(slug));
+    let mut siblings: Vec<&crate::db::models::Document> = docs
+        .iter()
+        .filter(|doc| !doc.is_archived && nav_parent(doc) == Some(parent))
+        .collect();
+    siblings.sort_by_cached_key(|doc| {
+        let key = if is_section(&doc.slug) {
+            weights.get(&doc.slug).copied().unwrap_or(i32::MAX)
+        } else {
+            doc.order as i32
+        };
+        (key, doc.title.to_lowercase())
+    });
+
+    let Some(index) = siblings.iter().position(|doc| doc.slug == slug) else {
+        return (None, None);
+    };
+    let link = |doc: &&crate::db::models::Document| (doc.slug.clone(), doc.title.clone());
+    (
+        index.checked_sub(1).and_then(|i| siblings.get(i)).map(link),
+        siblings.get(index + 1).map(link),
+    )
+}
+
+/// The versions of the bundle `slug` belongs to: the bundle of a prior
+/// version's page, or the bundle with the longest root containing it.
+/// Bundles without prior versions have nothing to switch to.
+#[cfg(feature = "ssr")]
+fn bundle_versions(
+    bundles: &[crate::db::bundle_repository::DocBundle],
+    slug: &str,
+) -> Option<crate::pages::DocBundleVersions> {
+    use crate::db::bundle_repository::split_versioned_slug;
+
+    let (bundle, current, page) = match split_versioned_slug(slug) {
+        Some((root, version, page)) => (
+            bundles.iter().find(|b| b.root == root)?,
+            Some(version.to_string()),
+            page,
+        ),
+        None => {
+            let bundle = bundles
+                .iter()
+                .filter(|b| slug == b.root || slug.starts_with(&format!("{}/", b.root)))
+                .max_by_key(|b| b.root.len())?;
+            let page = slug[bundle.root.len()..].trim_start_matches('/');
+            (bundle, None, page)
+        }
+    };
+    if bundle.versions.is_empty() && current.is_none() {
+        return None;
+    }
+
+    Some(crate::pages::DocBundleVersions {
+        root: bundle.root.clone(),
+        page: page.to_string(),
+        current,
+        latest: bundle.latest.as_ref().map(|v| v.version.clone()),
+        versions: bundle.versions.iter().map(|v| v.version.clone()).collect(),
+    })
+}
+
+/// Meta tags of `doc` for a caller with the given visibility, or `None` when
+/// the caller may not read it. The description is the front-matter summary,
+/// falling back to the content preview stored at ingest time.
+#[cfg(feature = "ssr")]
+fn doc_page_meta(
+    doc: crate::db::models::Document,
+    allowed_levels: Option<&[String]>,
+    include_draft: bool,
+) -> Option<crate::pages::DocPageMeta> {
+    if !doc_is_accessible(
+        &doc.access_level,
+        doc.is_draft,
+        allowed_levels,
+        include_draft,
+    ) {
+        return None;
+    }
+    let description = doc
+        .summary
+        .filter(|s| !s.trim().is_empty())
+        .unwrap_or(doc.preview);
+    Some(crate::pages::DocPageMeta {
+        title: doc.title,
+        description: description.trim().to_string(),
+        oembed_url: None,
+        noindex: doc.noindex,
+    })
+}
+
+#[server(GetDocMeta, "/api")]
+pub async fn get_doc_meta(
+    slug: String,
+) -> Result<Option<crate::pages::DocPageMeta>, ServerFnError> {
+    let state = expect_context::<AppState>();
+
+    let Some(doc) = state
+        .document_repo
+        .find_by_slug(&slug)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?
+    else {
+        return Ok(None);
+    };
+
+    let (allowed_levels, include_draft) = request_document_visibility(&state).await?;
+    let Some(mut meta) = doc_page_meta(doc, allowed_levels.as_deref(), include_draft) else {
+        return Ok(None);
+    };
+
+    let headers: axum::http::HeaderMap = leptos_axum::extract().await?;
+    meta.oembed_url = crate::api::embed::oembed_discovery_url(&state, &headers, &slug);
+    Ok(Some(meta))
+}
+
+#[server(GetBrowseEntries, "/api")]
+pub async fn get_browse_entries() -> Result<Vec<crate::pages::BrowseEntry>, ServerFnError> {
+    let state = expect_context::<AppState>();
+
+    let (allowed_levels, include_draft) = request_document_visibility(&state).await?;
+    let docs = state
+        .document_repo
+        .list_by_access_levels(allowed_levels.as_deref(), include_draft)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    // Web editor saves record the display name, falling back to the email
+    let me: Vec<String> = match crate::server::require_any_user(&state).await {
+        Ok(user) => user.name.into_iter().chain(Some(user.email)).collect(),
+        Err(_) => vec![],
+    };
+
+    let entries = docs
+        .into_iter()
+        .filter(|doc| !doc.is_archived)
+        .map(|doc| {
+            let parent_slug = doc.parent_slug.or_else(|| {
+                doc.slug
+                    .rsplit_once('/')
+                    .map(|(parent, _)| parent.to_string())
+            });
+            crate::pages::BrowseEntry {
+                parent_slug,
+                depth: 0,
+                tags: doc.tags,
+                service_owner: doc.service_owner,
+                access_level: doc.access_level,
+                is_draft: doc.is_draft,
+                is_mine: doc.contributors.iter().any(|c| me.contains(c)),
+                last_updated: doc.last_updated.format("%Y-%m-%d").to_string(),
+                slug: doc.slug,
+                title: doc.title,
+            }
+        })
+        .collect();
+
+    Ok(crate::pages::tree_order(entries))
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::db::models::Document;
+    use crate::testing;
+
+    fn doc(slug: &str, parent_slug: Option<&str>, order: u32) -> Document {
+        Document {
+            title: slug.rsplit('/').next().unwrap_or(slug).to_string(),
+            parent_slug: parent_slug.map(str::to_string),
+            order,
+            ..testing::document(slug)
+        }
+    }
+
+    #[test]
+    fn test_doc_page_meta_prefers_the_summary_over_the_preview() {
+        let page = Document {
+            title: "Deploying".into(),
+            summary: Some("How we ship.".into()),
+            preview: "Deploying Run just deploy.".into(),
+            ..testing::document("guide/deploy")
+        };
+        let meta = doc_page_meta(page.clone(), None, false).unwrap();
+        assert_eq!(meta.title, "Deploying");
+        assert_eq!(meta.description, "How we ship.");
+
+        let blank_summary = Document {
+            summary: Some("  ".into()),
+            ..page
+        };
+        let meta = doc_page_meta(blank_summary, None, false).unwrap();
+        assert_eq!(meta.description, "Deploying Run just deploy.");
+    }
+
+    #[test]
+    fn test_doc_page_meta_hides_unreadable_documents() {
+        let public = vec!["public".to_string()];
+        let restricted = Document {
+            access_level: "internal".into(),
+            summary: Some("Internal incident notes".into()),
+            ..testing::document("ops/incidents")
+        };
+        let draft = Document {
+            is_draft: true,
+            preview: "Unreleased pricing".into(),
+            ..testing::document("pricing")
+        };
+
+        assert!(doc_page_meta(restricted.clone(), Some(&public), false).is_none());
+        assert!(doc_page_meta(draft.clone(), Some(&public), false).is_none());
+        assert!(doc_page_meta(restricted, Some(&["internal".to_string()]), false).is_some());
+        assert!(doc_page_meta(draft.clone(), Some(&public), true).is_some());
+        // Admins (no level restriction) still need draft visibility
+        assert!(doc_page_meta(draft, None, false).is_none());
+    }
+
+    #[test]
+    fn test_sibling_neighbours_follow_sidebar_order() {
+        let mut archived = doc("guide/old", None, 2);
+        archived.is_archived = true;
+        let docs = vec![
+            doc("guide", None, 0),
+            doc("guide/deploy", None, 3),
+            doc("guide/install", None, 1),
+            doc("setup-notes", Some("guide"), 2),
+            archived,
+            doc("guide/install/linux", None, 0),
+        ];
+        let weights = std::collections::HashMap::new();
+        let link = |slug: &str| {
+            Some((
+                slug.to_string(),
+                slug.rsplit('/').next().unwrap().to_string(),
+            ))
+        };
+
+        // `guide/install` is a section without a weight, so it sorts last
+        assert_eq!(
+            sibling_neighbours(&docs, &weights, "setup-notes"),
+            (None, link("guide/deploy"))
+        );
+        assert_eq!(
+            sibling_neighbours(&docs, &weights, "guide/install"),
+            (link("guide/deploy"), None)
+        );
+        assert_eq!(sibling_neighbours(&docs, &weights, "guide"), (None, None));
+        assert_eq!(
+            sibling_neighbours(&docs, &weights, "guide/install/linux"),
+            (None, None)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_any_user;
+
+/// How long an editor lock lives without a heartbeat.
+#[cfg(feature = "ssr")]
+const LOCK_TTL_SECS: i64 = 90;
+
+/// Interval at which an open editor refreshes its lock; well under the TTL
+/// so a single missed heartbeat does not release it.
+pub const LOCK_HEARTBEAT_SECS: u64 = 30;
+
+/// Lock state as seen by the caller.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EditLockStatus {
+    /// `true` if the caller holds the lock.
+    pub acquired: bool,
+    /// Display name of the holder.
+    pub holder: String,
+    /// Seconds until the lock expires (`0` if already expired).
+    pub expires_in_secs: i64,
+}
+
+/// Acquire or refresh the editor lock on `slug`.
+///
+/// Called when the editor opens and then as a heartbeat. If another user
+/// holds an unexpired lock, returns their name with `acquired: false`; the
+/// caller may retry (take over) once it has expired.
+#[server(AcquireEditLock, "/api")]
+pub async fn acquire_edit_lock(slug: String) -> Result<EditLockStatus, ServerFnError> {
+    use crate::db::edit_lock_repository::LockAttempt;
+
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+    let user_name = user.name.clone().unwrap_or_else(|| user.email.clone());
+
+    let attempt = state
+        .edit_lock_repo
+        .acquire(
+            &slug,
+            &user.user_id,
+            &user_name,
+            chrono::Duration::seconds(LOCK_TTL_SECS),
+        )
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    let (acquired, lock) = match attempt {
+        LockAttempt::Acquired(lock) => (true, lock),
+        LockAttempt::Held(lock) => (false, lock),
+    };
+    Ok(EditLockStatus {
+        acquired,
+        holder: lock.user_name,
+        expires_in_secs: (lock.expires_at - chrono::Utc::now()).num_seconds().max(0),
+    })
+}
+
+/// Release the caller's editor lock on `slug`, if held.
+#[server(ReleaseEditLock, "/api")]
+pub async fn release_edit_lock(slug: String) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    state
+        .edit_lock_repo
+        .release(&slug, &user.user_id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
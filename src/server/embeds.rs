@@ -0,0 +1,136 @@
+//! Embedded widgets: the providers and Grafana hosts admins enable, read
+//! from the settings whenever a document is rendered (see
+//! [`crate::rendering::embeds`]).
+
+use leptos::prelude::*;
+
+pub use crate::db::settings_repository::EmbedSettings;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::db::settings_repository::SettingsRepository;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+#[cfg(feature = "ssr")]
+use crate::rendering::embeds::{EmbedProvider, Embeds};
+
+/// The embeds enabled in the settings, with the dashboards of the Grafana
+/// proxy; `None` when no provider is enabled.
+#[cfg(feature = "ssr")]
+pub async fn embeds(state: &AppState) -> Option<Embeds> {
+    let settings = match state.settings_repo.get_settings().await {
+        Ok(settings) => settings.embeds?,
+        Err(e) => {
+            tracing::warn!("Failed to load embed settings: {e}");
+            return None;
+        }
+    };
+    let embeds = Embeds::from_settings(&settings);
+    if embeds.is_empty() {
+        return None;
+    }
+    Some(match &state.grafana {
+        Some(grafana) => {
+            embeds.with_grafana_proxy(grafana.dashboards.iter().map(|d| d.uid.clone()).collect())
+        }
+        None => embeds,
+    })
+}
+
+/// Store `rendering.embeds` as the embed settings unless they have been
+/// saved before, so the configuration only provides the initial values and
+/// the admin page owns them afterwards.
+#[cfg(feature = "ssr")]
+pub async fn seed_embed_settings(
+    repo: &dyn SettingsRepository,
+    config: &crate::config::EmbedsConfig,
+) -> Result<(), AppError> {
+    if repo.get_settings().await?.embeds.is_some() {
+        return Ok(());
+    }
+    let providers = config
+        .providers
+        .iter()
+        .filter(|name| {
+            let known = EmbedProvider::from_name(name).is_some();
+            if !known {
+                tracing::warn!("Unknown embed provider '{name}' in rendering.embeds — ignored");
+            }
+            known
+        })
+        .cloned()
+        .collect();
+    let embeds = EmbedSettings {
+        providers,
+        grafana_hosts: config.grafana_hosts.clone(),
+    }
+    .normalize()?;
+    repo.set_embeds(&embeds).await
+}
+
+#[server(GetEmbedSettings, "/api")]
+pub async fn get_embed_settings() -> Result<EmbedSettings, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    Ok(state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .embeds
+        .unwrap_or_default())
+}
+
+/// Save the enabled embed providers and Grafana hosts; documents rendered
+/// afterwards use them.
+#[server(SaveEmbedSettings, "/api")]
+pub async fn save_embed_settings(
+    providers: Vec<String>,
+    grafana_hosts: Vec<String>,
+) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let embeds = EmbedSettings {
+        providers,
+        grafana_hosts,
+    }
+    .normalize()
+    .map_err(AppError::into_server_fn_error)?;
+    state
+        .settings_repo
+        .set_embeds(&embeds)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok("Embed settings saved successfully".to_string())
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::config::EmbedsConfig;
+    use crate::testing::InMemorySettingsRepository;
+
+    #[tokio::test]
+    async fn test_seed_takes_the_configuration_once() {
+        let repo = InMemorySettingsRepository::new();
+        let config = EmbedsConfig {
+            providers: vec!["YouTube".into(), "vimeo".into()],
+            grafana_hosts: vec!["grafana.internal".into()],
+        };
+        seed_embed_settings(&repo, &config).await.unwrap();
+        let seeded = repo.get_settings().await.unwrap().embeds.unwrap();
+        assert_eq!(seeded.providers, vec!["youtube"]);
+        assert_eq!(seeded.grafana_hosts, vec!["grafana.internal"]);
+
+        // Settings saved from the admin page win over the configuration
+        repo.set_embeds(&EmbedSettings::default()).await.unwrap();
+        seed_embed_settings(&repo, &config).await.unwrap();
+        assert_eq!(
+            repo.get_settings().await.unwrap().embeds,
+            Some(EmbedSettings::default())
+        );
+    }
+}
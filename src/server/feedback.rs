@@ -111,7 +111,7 @@ pub async fn list_user_feedback(
     let result = fb_repo
         .list_user_feedback(&user.user_id, params)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     let items = result
         .items
@@ -152,7 +152,7 @@ pub async fn delete_user_feedback(message_id: String) -> Result<(), ServerFnErro
     fb_repo
         .delete_feedback(&message_id, &user.user_id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     Ok(())
 }
 
@@ -191,7 +191,7 @@ pub async fn list_documentation_feedback(
             per_page: per_page.clamp(1, 50),
         })
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(DocumentationFeedbackAdminListResult {
         items: result
@@ -220,7 +220,7 @@ pub async fn resolve_documentation_feedback(
             resolution_note.filter(|value| !value.trim().is_empty()),
         )
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))
+        .map_err(crate::error::AppError::into_server_fn_error)
 }
 
 #[server(MarkDocumentationFeedbackDuplicate, "/api")]
@@ -246,7 +246,7 @@ pub async fn mark_documentation_feedback_duplicate(
         .documentation_feedback_repo
         .find_by_id(&duplicate_of)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .map_err(crate::error::AppError::into_server_fn_error)?
         .ok_or_else(|| ServerFnError::new("Duplicate target not found"))?;
 
     state
@@ -257,5 +257,5 @@ pub async fn mark_documentation_feedback_duplicate(
             resolution_note.filter(|value| !value.trim().is_empty()),
         )
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))
+        .map_err(crate::error::AppError::into_server_fn_error)
 }
@@ -0,0 +1,82 @@
+use leptos::prelude::*;
+
+pub use crate::db::glossary_repository::GlossaryTerm;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_admin_user;
+
+/// Maximum length of a glossary definition, which is shown in a tooltip.
+#[cfg(feature = "ssr")]
+const MAX_DEFINITION_LEN: usize = 500;
+
+#[server(ListGlossary, "/api")]
+pub async fn list_glossary() -> Result<Vec<GlossaryTerm>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    state
+        .glossary_repo
+        .list_all()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+#[server(SaveGlossaryTerm, "/api")]
+pub async fn save_glossary_term(
+    term: String,
+    definition: String,
+    owner: String,
+) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let term = term.trim().to_string();
+    let definition = definition.trim().to_string();
+    if term.is_empty() || definition.is_empty() {
+        return Err(ServerFnError::new("Term and definition are required"));
+    }
+    if definition.chars().count() > MAX_DEFINITION_LEN {
+        return Err(ServerFnError::new(format!(
+            "Definition must be at most {MAX_DEFINITION_LEN} characters"
+        )));
+    }
+
+    state
+        .glossary_repo
+        .upsert(GlossaryTerm {
+            term: term.clone(),
+            definition,
+            owner: owner.trim().to_string(),
+            updated_at: chrono::Utc::now(),
+        })
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(format!("Term '{term}' saved"))
+}
+
+#[server(DeleteGlossaryTerm, "/api")]
+pub async fn delete_glossary_term(term: String) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    state
+        .glossary_repo
+        .delete(&term)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+/// Convert stored terms into the renderer's tooltip annotations.
+#[cfg(feature = "ssr")]
+pub(crate) fn tooltips(
+    terms: Vec<GlossaryTerm>,
+) -> Vec<crate::rendering::markdown::GlossaryTooltip> {
+    terms
+        .into_iter()
+        .map(|t| crate::rendering::markdown::GlossaryTooltip {
+            anchor: t.anchor(),
+            term: t.term,
+            definition: t.definition,
+        })
+        .collect()
+}
@@ -0,0 +1,176 @@
+//! Incident mode: runbooks pinned to the docs sidebar and the homepage, and
+//! pages served uncached, while an incident is under way.
+//!
+//! Admins pick the runbooks and the on-call responders; either may switch
+//! incident mode on and off.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::relations::RelatedDoc;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+
+/// Incident mode as the caller sees it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IncidentStatus {
+    pub active: bool,
+    /// Pinned runbooks the caller can read, while incident mode is on.
+    pub runbooks: Vec<RelatedDoc>,
+    pub started_by: Option<String>,
+    pub started_at: Option<String>,
+    /// The caller may switch incident mode on and off.
+    pub can_toggle: bool,
+}
+
+/// Runbooks and responders, as edited on the admin page.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IncidentConfig {
+    pub runbooks: Vec<String>,
+    pub responders: Vec<String>,
+}
+
+/// Whether the caller is an admin or an on-call responder.
+#[cfg(feature = "ssr")]
+async fn can_toggle(
+    state: &AppState,
+    incident_mode: &crate::db::settings_repository::IncidentMode,
+) -> Option<crate::auth::models::AuthenticatedUser> {
+    let user = crate::server::require_any_user(state).await.ok()?;
+    (user.is_admin || incident_mode.is_responder(&user.email)).then_some(user)
+}
+
+/// Whether incident mode is on, with the runbooks to pin.
+#[server(GetIncidentStatus, "/api")]
+pub async fn get_incident_status() -> Result<IncidentStatus, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let incident_mode = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .incident_mode;
+    let can_toggle = can_toggle(&state, &incident_mode).await.is_some();
+    if !incident_mode.active {
+        return Ok(IncidentStatus {
+            can_toggle,
+            ..Default::default()
+        });
+    }
+
+    let (allowed_levels, include_draft) =
+        crate::server::request_document_visibility(&state).await?;
+    let docs = state
+        .document_repo
+        .find_by_slugs(&incident_mode.runbooks)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    let runbooks = incident_mode
+        .runbooks
+        .iter()
+        .filter_map(|slug| docs.iter().find(|doc| doc.slug == *slug))
+        .filter(|doc| {
+            !doc.is_archived
+                && crate::app::doc_is_accessible(
+                    &doc.access_level,
+                    doc.is_draft,
+                    allowed_levels.as_deref(),
+                    include_draft,
+                )
+        })
+        .map(|doc| RelatedDoc {
+            slug: doc.slug.clone(),
+            title: doc.title.clone(),
+        })
+        .collect();
+    Ok(IncidentStatus {
+        active: true,
+        runbooks,
+        started_by: incident_mode.started_by,
+        started_at: incident_mode
+            .started_at
+            .map(|at| at.format("%Y-%m-%d %H:%M UTC").to_string()),
+        can_toggle,
+    })
+}
+
+/// Switch incident mode on or off (admins and on-call responders).
+#[server(SetIncidentActive, "/api")]
+pub async fn set_incident_active(active: bool) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let mut incident_mode = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .incident_mode;
+    let Some(user) = can_toggle(&state, &incident_mode).await else {
+        return Err(AppError::Forbidden(
+            "Only admins and on-call responders can switch incident mode".into(),
+        )
+        .into_server_fn_error());
+    };
+    if incident_mode.active == active {
+        return Ok(());
+    }
+
+    incident_mode.active = active;
+    if active {
+        incident_mode.started_by = Some(user.email.clone());
+        incident_mode.started_at = Some(chrono::Utc::now());
+    }
+    state
+        .settings_repo
+        .set_incident_mode(&incident_mode)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    tracing::info!(user = %user.email, active, "Incident mode switched");
+    Ok(())
+}
+
+#[server(GetIncidentConfig, "/api")]
+pub async fn get_incident_config() -> Result<IncidentConfig, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let incident_mode = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .incident_mode;
+    Ok(IncidentConfig {
+        runbooks: incident_mode.runbooks,
+        responders: incident_mode.responders,
+    })
+}
+
+#[server(SaveIncidentConfig, "/api")]
+pub async fn save_incident_config(
+    runbooks: Vec<String>,
+    responders: Vec<String>,
+) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let (runbooks, responders) =
+        crate::db::settings_repository::normalize_incident_config(runbooks, responders)
+            .map_err(AppError::into_server_fn_error)?;
+    let mut incident_mode = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .incident_mode;
+    incident_mode.runbooks = runbooks;
+    incident_mode.responders = responders;
+    state
+        .settings_repo
+        .set_incident_mode(&incident_mode)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok("Incident mode settings saved successfully".to_string())
+}
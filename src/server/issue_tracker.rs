@@ -0,0 +1,120 @@
+//! Issue tracker links: issue keys in documents and rating comments are
+//! linked to Jira or Linear, with status badges for signed-in readers (see
+//! [`crate::issue_tracker`] and [`crate::rendering::issues`]).
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use crate::db::settings_repository::IssueTrackerProvider;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+#[cfg(feature = "ssr")]
+use crate::rendering::issues::IssueLinks;
+
+/// The issue tracker integration as edited on the admin page; the API
+/// token itself is never sent back.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueTrackerConfig {
+    pub provider: IssueTrackerProvider,
+    pub base_url: String,
+    pub api_user: String,
+    pub token_set: bool,
+    pub projects: Vec<String>,
+}
+
+/// Links for the issue keys that `keys_in` finds for the configured
+/// projects, with their statuses when the caller is signed in; `None` when
+/// issue keys are not linked.
+#[cfg(feature = "ssr")]
+pub async fn issue_links(
+    state: &AppState,
+    keys_in: impl FnOnce(&[String]) -> Vec<String>,
+) -> Option<IssueLinks> {
+    let settings = match state.settings_repo.get_settings().await {
+        Ok(settings) => settings.issue_tracker,
+        Err(e) => {
+            tracing::warn!("Failed to load issue tracker settings: {e}");
+            return None;
+        }
+    };
+    let url_template = settings.url_template()?;
+    let keys = keys_in(&settings.projects);
+    let statuses = if !keys.is_empty() && crate::server::require_any_user(state).await.is_ok() {
+        state.issue_tracker.statuses(&settings, &keys).await
+    } else {
+        Default::default()
+    };
+    Some(IssueLinks {
+        url_template,
+        projects: settings.projects,
+        statuses,
+    })
+}
+
+#[server(GetIssueTrackerConfig, "/api")]
+pub async fn get_issue_tracker_config() -> Result<IssueTrackerConfig, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let issue_tracker = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .issue_tracker;
+    Ok(IssueTrackerConfig {
+        provider: issue_tracker.provider,
+        base_url: issue_tracker.base_url,
+        api_user: issue_tracker.api_user,
+        token_set: !issue_tracker.api_token.is_empty(),
+        projects: issue_tracker.projects,
+    })
+}
+
+/// Save the issue tracker integration. An empty `api_token` keeps the
+/// saved one.
+#[server(SaveIssueTrackerConfig, "/api")]
+pub async fn save_issue_tracker_config(
+    provider: IssueTrackerProvider,
+    base_url: String,
+    api_user: String,
+    api_token: String,
+    projects: Vec<String>,
+) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let projects = crate::db::settings_repository::normalize_issue_projects(projects)
+        .map_err(AppError::into_server_fn_error)?;
+    let base_url = base_url.trim().trim_end_matches('/').to_string();
+    if !base_url.is_empty() && !base_url.starts_with("https://") && !base_url.starts_with("http://")
+    {
+        return Err(AppError::BadRequest(
+            "The tracker URL must start with https:// or http://".into(),
+        )
+        .into_server_fn_error());
+    }
+    let mut issue_tracker = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .issue_tracker;
+    issue_tracker.provider = provider;
+    issue_tracker.base_url = base_url;
+    issue_tracker.api_user = api_user.trim().to_string();
+    if !api_token.trim().is_empty() {
+        issue_tracker.api_token = api_token.trim().to_string();
+    }
+    issue_tracker.projects = projects;
+    state
+        .settings_repo
+        .set_issue_tracker(&issue_tracker)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    state.issue_tracker.clear();
+    Ok("Issue tracker settings saved successfully".to_string())
+}
@@ -0,0 +1,97 @@
+//! LDAP group mappings: which access levels, and whether admin rights,
+//! directory groups grant on LDAP sign-in (see [`crate::auth::ldap`]).
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use crate::db::settings_repository::LdapGroupMapping;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+
+/// The LDAP group mappings as edited on the admin page.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LdapGroupConfig {
+    /// LDAP sign-in is configured (`auth.ldap.url`).
+    pub enabled: bool,
+    pub group_mappings: Vec<LdapGroupMapping>,
+    pub admin_groups: Vec<String>,
+}
+
+#[server(GetLdapGroupConfig, "/api")]
+pub async fn get_ldap_group_config() -> Result<LdapGroupConfig, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let ldap = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .ldap;
+    Ok(LdapGroupConfig {
+        enabled: state.ldap.is_some(),
+        group_mappings: ldap.group_mappings,
+        admin_groups: ldap.admin_groups,
+    })
+}
+
+/// Save the LDAP group mappings. They apply from each user's next sign-in.
+#[server(SaveLdapGroupConfig, "/api")]
+pub async fn save_ldap_group_config(
+    group_mappings: Vec<LdapGroupMapping>,
+    admin_groups: Vec<String>,
+) -> Result<String, ServerFnError> {
+    use crate::db::settings_repository::LdapSettings;
+
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let mut mappings: Vec<LdapGroupMapping> = Vec::with_capacity(group_mappings.len());
+    for mapping in group_mappings {
+        let group = mapping.group.trim().to_string();
+        if group.is_empty() {
+            continue;
+        }
+        let mut access_levels: Vec<String> = Vec::new();
+        for level in mapping.access_levels {
+            let level = level.trim().to_string();
+            if level.is_empty() || access_levels.contains(&level) {
+                continue;
+            }
+            if !state
+                .access_level_repo
+                .exists(&level)
+                .await
+                .map_err(AppError::into_server_fn_error)?
+            {
+                return Err(
+                    AppError::BadRequest(format!("Access level '{level}' does not exist"))
+                        .into_server_fn_error(),
+                );
+            }
+            access_levels.push(level);
+        }
+        mappings.push(LdapGroupMapping {
+            group,
+            access_levels,
+        });
+    }
+    let admin_groups = admin_groups
+        .into_iter()
+        .map(|group| group.trim().to_string())
+        .filter(|group| !group.is_empty())
+        .collect();
+
+    state
+        .settings_repo
+        .set_ldap(&LdapSettings {
+            group_mappings: mappings,
+            admin_groups,
+        })
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok("LDAP group mappings saved successfully".to_string())
+}
@@ -0,0 +1,20 @@
+use leptos::prelude::*;
+
+pub use crate::i18n::Locale;
+
+/// Resolve the UI locale for the current request from the language cookie or
+/// the `Accept-Language` header.
+#[server(GetLocale, "/api")]
+pub async fn get_locale() -> Result<Locale, ServerFnError> {
+    use crate::i18n::{negotiate, LOCALE_COOKIE};
+    use axum::http::{header::ACCEPT_LANGUAGE, HeaderMap};
+    use axum_extra::extract::CookieJar;
+
+    let jar: CookieJar = leptos_axum::extract().await?;
+    let headers: HeaderMap = leptos_axum::extract().await?;
+
+    let cookie = jar.get(LOCALE_COOKIE).map(|c| c.value().to_string());
+    let accept_language = headers.get(ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok());
+
+    Ok(negotiate(cookie.as_deref(), accept_language))
+}
@@ -1,18 +1,41 @@
 pub mod access_levels;
+pub mod admin_documents;
+pub mod approvals;
 pub mod auth_fns;
+pub mod branding;
+pub mod changelog;
+pub mod collections;
 pub mod custom_css;
+pub mod doc_ratings;
 pub mod docs;
+pub mod edit_locks;
+pub mod embeds;
 pub mod feedback;
+pub mod glossary;
+pub mod incident_mode;
+pub mod issue_tracker;
+pub mod ldap;
+pub mod locale;
 pub mod nav;
+pub mod notifications;
+pub mod on_call;
 pub mod pats;
+pub mod portal_config;
+pub mod projects;
 pub mod prompts;
 pub mod reindex;
 pub mod search;
 pub mod service_tokens;
+pub mod share_links;
+pub mod spelling;
+pub mod usage;
 pub mod users;
+pub mod watches;
 
 #[cfg(feature = "ssr")]
-pub(crate) use helpers::{request_document_visibility, require_admin_user, require_any_user};
+pub(crate) use helpers::{
+    request_document_visibility, require_admin_user, require_any_user, require_user_context,
+};
 
 #[cfg(feature = "ssr")]
 mod helpers {
@@ -42,7 +65,7 @@ mod helpers {
                 .user_repo
                 .find_user_by_id(&auth_user.user_id)
                 .await
-                .map_err(|e| ServerFnError::new(e.to_string()))?;
+                .map_err(crate::error::AppError::into_server_fn_error)?;
             let ctx = match user_doc {
                 Some(u) => UserContext::from_user_doc(auth_user, &u),
                 None => UserContext {
@@ -57,15 +80,13 @@ mod helpers {
         }
 
         if state.demo_mode {
-            if let Some(cookie) = jar.get("lekton_demo_user") {
-                if let Ok(demo_user) =
-                    serde_json::from_str::<crate::auth::models::AuthenticatedUser>(cookie.value())
-                {
-                    if demo_user.is_admin {
-                        return Ok((None, true));
-                    } else {
-                        return Ok((Some(vec!["public".to_string()]), false));
-                    }
+            if let Some(demo_user) =
+                crate::auth::demo_auth::session_user(&state.token_service, &jar)
+            {
+                if demo_user.is_admin {
+                    return Ok((None, true));
+                } else {
+                    return Ok((Some(vec!["public".to_string()]), false));
                 }
             }
         }
@@ -100,15 +121,11 @@ mod helpers {
         }
 
         if state.demo_mode {
-            if let Some(cookie) = jar.get("lekton_demo_user") {
-                if let Ok(user) =
-                    serde_json::from_str::<crate::auth::models::AuthenticatedUser>(cookie.value())
-                {
-                    if user.is_admin {
-                        return Ok(user);
-                    }
-                    return Err(ServerFnError::new("Admin privileges required"));
+            if let Some(user) = crate::auth::demo_auth::session_user(&state.token_service, &jar) {
+                if user.is_admin {
+                    return Ok(user);
                 }
+                return Err(ServerFnError::new("Admin privileges required"));
             }
         }
 
@@ -135,12 +152,8 @@ mod helpers {
         }
 
         if state.demo_mode {
-            if let Some(cookie) = jar.get("lekton_demo_user") {
-                if let Ok(user) =
-                    serde_json::from_str::<crate::auth::models::AuthenticatedUser>(cookie.value())
-                {
-                    return Ok(user);
-                }
+            if let Some(user) = crate::auth::demo_auth::session_user(&state.token_service, &jar) {
+                return Ok(user);
             }
         }
 
@@ -148,4 +161,29 @@ mod helpers {
             crate::auth::models::UNAUTHORIZED_SENTINEL,
         ))
     }
+
+    /// The caller with their stored permissions. Demo users have no stored
+    /// record and get no permissions beyond what `is_admin` implies.
+    pub(crate) async fn require_user_context(
+        state: &AppState,
+    ) -> Result<crate::auth::models::UserContext, ServerFnError> {
+        use crate::auth::models::UserContext;
+
+        let user = require_any_user(state).await?;
+        let record = state
+            .user_repo
+            .find_user_by_id(&user.user_id)
+            .await
+            .map_err(crate::error::AppError::into_server_fn_error)?;
+        Ok(match record {
+            Some(u) => UserContext::from_user_doc(user, &u),
+            None => UserContext {
+                user,
+                effective_access_levels: vec![],
+                can_write: false,
+                can_read_draft: false,
+                can_write_draft: false,
+            },
+        })
+    }
 }
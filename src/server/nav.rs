@@ -10,6 +10,8 @@ use crate::server::require_admin_user;
 
 pub use crate::db::navigation_order_repository::NavigationOrderEntry;
 
+pub use crate::db::settings_repository::{NavLink, NavLinkGroup, NavLinks};
+
 use crate::db::settings_repository::NavGroup;
 
 #[server(GetNavigation, "/api")]
@@ -19,14 +21,21 @@ pub async fn get_navigation() -> Result<Vec<NavItem>, ServerFnError> {
     let state = expect_context::<AppState>();
 
     let (allowed_levels, include_draft) = request_document_visibility(&state).await?;
-    let (docs, nav_order_entries) = tokio::join!(
+    let (docs, nav_order_entries, projects) = tokio::join!(
         state
             .document_repo
             .list_by_access_levels(allowed_levels.as_deref(), include_draft),
         state.navigation_order_repo.list_all(),
+        state.project_repo.list_all(),
     );
-    let docs = docs.map_err(|e| ServerFnError::new(e.to_string()))?;
-    let nav_order_entries = nav_order_entries.map_err(|e| ServerFnError::new(e.to_string()))?;
+    let docs = docs.map_err(crate::error::AppError::into_server_fn_error)?;
+    let nav_order_entries =
+        nav_order_entries.map_err(crate::error::AppError::into_server_fn_error)?;
+    let project_titles: HashMap<String, String> = projects
+        .map_err(crate::error::AppError::into_server_fn_error)?
+        .into_iter()
+        .map(|p| (p.name, p.title))
+        .collect();
 
     let nav_weights: HashMap<String, i32> = nav_order_entries
         .into_iter()
@@ -64,17 +73,23 @@ pub async fn get_navigation() -> Result<Vec<NavItem>, ServerFnError> {
         while let Some(parent_slug) = current_parent {
             if !items_by_slug.contains_key(&parent_slug) {
                 let title_part = parent_slug.split('/').next_back().unwrap_or(&parent_slug);
-                let title = title_part
-                    .split('-')
-                    .map(|w| {
-                        let mut c = w.chars();
-                        match c.next() {
-                            None => String::new(),
-                            Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join(" ");
+                // Project sections are titled after the project
+                let title = project_titles
+                    .get(&parent_slug)
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        title_part
+                            .split('-')
+                            .map(|w| {
+                                let mut c = w.chars();
+                                match c.next() {
+                                    None => String::new(),
+                                    Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    });
 
                 let next_parent = if let Some((p, _)) = parent_slug.rsplit_once('/') {
                     Some(p.to_string())
@@ -164,10 +179,37 @@ pub async fn get_navbar_groups() -> Result<Vec<NavGroup>, ServerFnError> {
         .settings_repo
         .get_settings()
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     Ok(settings.navbar_groups)
 }
 
+#[server(GetNavLinks, "/api")]
+pub async fn get_nav_links() -> Result<NavLinks, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let settings = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(settings.nav_links)
+}
+
+#[server(SaveNavLinks, "/api")]
+pub async fn save_nav_links(links: NavLinks) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    links
+        .validate()
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    state
+        .settings_repo
+        .set_nav_links(&links)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok("Navigation links saved successfully".to_string())
+}
+
 #[server(GetNavigationOrder, "/api")]
 pub async fn get_navigation_order() -> Result<Vec<NavigationOrderEntry>, ServerFnError> {
     let state = expect_context::<AppState>();
@@ -177,7 +219,7 @@ pub async fn get_navigation_order() -> Result<Vec<NavigationOrderEntry>, ServerF
         .navigation_order_repo
         .list_all()
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))
+        .map_err(crate::error::AppError::into_server_fn_error)
 }
 
 #[server(SaveNavigationOrder, "/api")]
@@ -191,7 +233,7 @@ pub async fn save_navigation_order(
         .navigation_order_repo
         .replace_all(entries)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok("Navigation order saved successfully".to_string())
 }
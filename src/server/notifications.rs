@@ -0,0 +1,93 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_any_user;
+
+/// Notifications shown in the inbox.
+#[cfg(feature = "ssr")]
+const INBOX_PAGE_SIZE: i64 = 50;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NotificationInfo {
+    pub id: String,
+    /// `document.updated`, `mention`, `approval.requested`, ...
+    pub kind: String,
+    /// Short label, e.g. `"updated"` or `"mentioned"`.
+    pub action: String,
+    /// One-line description.
+    pub text: String,
+    /// Page the notification leads to, if any.
+    pub link: Option<String>,
+    pub watch_target: Option<String>,
+    pub created_at: String,
+    pub read: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NotificationInbox {
+    pub items: Vec<NotificationInfo>,
+    pub unread: u64,
+}
+
+#[server(ListNotifications, "/api")]
+pub async fn list_notifications() -> Result<NotificationInbox, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    let notifications = state
+        .notification_repo
+        .list_for_user(&user.user_id, INBOX_PAGE_SIZE)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    let unread = state
+        .notification_repo
+        .count_unread(&user.user_id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    Ok(NotificationInbox {
+        items: notifications
+            .into_iter()
+            .map(|n| NotificationInfo {
+                action: n.action().to_string(),
+                text: n.text(),
+                link: n.link(),
+                id: n.id,
+                kind: n.kind,
+                watch_target: n.watch_target,
+                created_at: n.created_at.format("%Y-%m-%d %H:%M").to_string(),
+                read: n.read,
+            })
+            .collect(),
+        unread,
+    })
+}
+
+/// Unread notifications of the caller, for the navbar bell.
+#[server(CountUnreadNotifications, "/api")]
+pub async fn count_unread_notifications() -> Result<u64, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    state
+        .notification_repo
+        .count_unread(&user.user_id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+/// Mark one notification, or all of them when `id` is `None`, as read.
+#[server(MarkNotificationsRead, "/api")]
+pub async fn mark_notifications_read(id: Option<String>) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    state
+        .notification_repo
+        .mark_read(&user.user_id, id.as_deref())
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
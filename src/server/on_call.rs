@@ -0,0 +1,174 @@
+//! Current on-call contacts of services, from PagerDuty or Opsgenie (see
+//! [`crate::on_call`]), for service changelog pages and runbook documents.
+//!
+//! Contacts are shown to signed-in readers only.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use crate::db::settings_repository::{OnCallProvider, ServiceSchedule};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+
+/// Someone on call.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnCallContact {
+    pub name: String,
+    pub email: Option<String>,
+}
+
+/// Who is on call for a service.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OnCallInfo {
+    pub contacts: Vec<OnCallContact>,
+    /// The schedule in the paging service, when it links to one.
+    pub schedule_url: Option<String>,
+}
+
+/// The on-call integration as edited on the admin page; the API token
+/// itself is never sent back.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OnCallConfig {
+    pub provider: OnCallProvider,
+    pub token_set: bool,
+    pub api_url: String,
+    pub schedules: Vec<ServiceSchedule>,
+}
+
+/// Whether a document is a runbook: tagged `runbook`, or pinned in
+/// incident mode.
+#[cfg(feature = "ssr")]
+fn is_runbook(doc: &crate::db::models::Document, incident_runbooks: &[String]) -> bool {
+    doc.tags
+        .iter()
+        .any(|tag| tag.eq_ignore_ascii_case("runbook") || tag.eq_ignore_ascii_case("runbooks"))
+        || incident_runbooks.contains(&doc.slug)
+}
+
+/// Look up the on-call of `service` for a signed-in caller. Lookup
+/// failures are logged and shown as no on-call.
+#[cfg(feature = "ssr")]
+async fn lookup(
+    state: &AppState,
+    settings: &crate::db::settings_repository::AppSettings,
+    service: &str,
+) -> Option<OnCallInfo> {
+    crate::server::require_any_user(state).await.ok()?;
+    match state.on_call.lookup(&settings.on_call, service).await {
+        Ok(info) => info,
+        Err(e) => {
+            tracing::warn!(service, "On-call lookup failed: {e}");
+            None
+        }
+    }
+}
+
+/// Who is on call for `service`, if it has a schedule.
+#[server(GetServiceOnCall, "/api")]
+pub async fn get_service_on_call(service: String) -> Result<Option<OnCallInfo>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let settings = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok(lookup(&state, &settings, &service).await)
+}
+
+/// Who is on call for the service owning the runbook at `slug`; `None`
+/// for other documents.
+#[server(GetDocOnCall, "/api")]
+pub async fn get_doc_on_call(slug: String) -> Result<Option<OnCallInfo>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let (allowed_levels, include_draft) =
+        crate::server::request_document_visibility(&state).await?;
+    let Some(doc) = state
+        .document_repo
+        .find_by_slug(&slug)
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .filter(|doc| {
+            !doc.is_archived
+                && crate::app::doc_is_accessible(
+                    &doc.access_level,
+                    doc.is_draft,
+                    allowed_levels.as_deref(),
+                    include_draft,
+                )
+        })
+    else {
+        return Ok(None);
+    };
+    let settings = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    if !is_runbook(&doc, &settings.incident_mode.runbooks) {
+        return Ok(None);
+    }
+    Ok(lookup(&state, &settings, &doc.service_owner).await)
+}
+
+#[server(GetOnCallConfig, "/api")]
+pub async fn get_on_call_config() -> Result<OnCallConfig, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let on_call = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .on_call;
+    Ok(OnCallConfig {
+        provider: on_call.provider,
+        token_set: !on_call.api_token.is_empty(),
+        api_url: on_call.api_url,
+        schedules: on_call.schedules,
+    })
+}
+
+/// Save the on-call integration. An empty `api_token` keeps the saved one.
+#[server(SaveOnCallConfig, "/api")]
+pub async fn save_on_call_config(
+    provider: OnCallProvider,
+    api_token: String,
+    api_url: String,
+    schedules: Vec<ServiceSchedule>,
+) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let schedules = crate::db::settings_repository::normalize_service_schedules(schedules)
+        .map_err(AppError::into_server_fn_error)?;
+    let api_url = api_url.trim().to_string();
+    if !api_url.is_empty() && !api_url.starts_with("https://") {
+        return Err(
+            AppError::BadRequest("The API URL must start with https://".into())
+                .into_server_fn_error(),
+        );
+    }
+    let mut on_call = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .on_call;
+    on_call.provider = provider;
+    if !api_token.trim().is_empty() {
+        on_call.api_token = api_token.trim().to_string();
+    }
+    on_call.api_url = api_url;
+    on_call.schedules = schedules;
+    state
+        .settings_repo
+        .set_on_call(&on_call)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    state.on_call.clear();
+    Ok("On-call settings saved successfully".to_string())
+}
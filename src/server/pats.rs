@@ -42,7 +42,7 @@ pub async fn list_user_pats() -> Result<Vec<PatInfo>, ServerFnError> {
         .service_token_repo
         .list_by_user_id(&user.user_id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(tokens
         .into_iter()
@@ -86,13 +86,15 @@ pub async fn create_user_pat(name: String) -> Result<CreatePatResult, ServerFnEr
         created_at: chrono::Utc::now(),
         last_used_at: None,
         is_active: true,
+        project: None,
+        access_levels: vec![],
     };
 
     state
         .service_token_repo
         .create(token)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(CreatePatResult {
         id,
@@ -110,7 +112,7 @@ pub async fn toggle_user_pat(id: String, active: bool) -> Result<(), ServerFnErr
         .service_token_repo
         .find_by_id(&id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .map_err(crate::error::AppError::into_server_fn_error)?
         .ok_or_else(|| ServerFnError::new("PAT not found"))?;
 
     if token.user_id.as_deref() != Some(&user.user_id) {
@@ -121,7 +123,7 @@ pub async fn toggle_user_pat(id: String, active: bool) -> Result<(), ServerFnErr
         .service_token_repo
         .set_active(&id, active)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     Ok(())
 }
 
@@ -134,7 +136,7 @@ pub async fn delete_user_pat(id: String) -> Result<(), ServerFnError> {
         .service_token_repo
         .delete_pat(&id, &user.user_id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     Ok(())
 }
 
@@ -151,7 +153,7 @@ pub async fn admin_list_pats(
         .service_token_repo
         .list_pats_paginated(page, per_page)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     let mut email_map: std::collections::HashMap<String, String> = std::collections::HashMap::new();
     for token in &tokens {
@@ -197,7 +199,7 @@ pub async fn admin_toggle_pat(id: String, active: bool) -> Result<(), ServerFnEr
         .service_token_repo
         .find_by_id(&id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .map_err(crate::error::AppError::into_server_fn_error)?
         .ok_or_else(|| ServerFnError::new("PAT not found"))?;
 
     if !token.is_pat() {
@@ -208,6 +210,6 @@ pub async fn admin_toggle_pat(id: String, active: bool) -> Result<(), ServerFnEr
         .service_token_repo
         .set_active(&id, active)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     Ok(())
 }
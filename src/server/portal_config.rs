@@ -0,0 +1,82 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use crate::db::settings_repository::{FeatureFlags, SearchProvider};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_admin_user;
+
+/// Subsystems the UI may offer on this deployment: the admin feature flags
+/// combined with what the server has configured.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortalConfig {
+    pub editor_enabled: bool,
+    /// RAG chat is configured and the chat flag is on.
+    pub chat_enabled: bool,
+    /// A search backend is configured and search is not disabled.
+    pub search_enabled: bool,
+    pub search_provider: SearchProvider,
+    /// Incident mode is on: open documents reload as soon as they change.
+    #[serde(default)]
+    pub incident_mode: bool,
+}
+
+/// Used until the configuration has loaded: chat stays hidden, as it
+/// needs RAG to be configured.
+impl Default for PortalConfig {
+    fn default() -> Self {
+        Self {
+            editor_enabled: true,
+            chat_enabled: false,
+            search_enabled: true,
+            search_provider: SearchProvider::default(),
+            incident_mode: false,
+        }
+    }
+}
+
+#[server(GetPortalConfig, "/api")]
+pub async fn get_portal_config() -> Result<PortalConfig, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let settings = state
+        .settings_repo
+        .get_settings()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    let features = settings.features;
+
+    let rag_configured = state.rag_service.is_some() && state.chat_service.is_some();
+    Ok(PortalConfig {
+        editor_enabled: features.editor,
+        chat_enabled: rag_configured && features.chat,
+        search_enabled: state.search_service.is_some()
+            && features.search_provider != SearchProvider::Disabled,
+        search_provider: features.search_provider,
+        incident_mode: settings.incident_mode.active,
+    })
+}
+
+#[server(GetFeatureFlags, "/api")]
+pub async fn get_feature_flags() -> Result<FeatureFlags, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    crate::features::flags(&state)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+#[server(SaveFeatureFlags, "/api")]
+pub async fn save_feature_flags(features: FeatureFlags) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    state
+        .settings_repo
+        .set_feature_flags(&features)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok("Feature flags saved successfully".to_string())
+}
@@ -0,0 +1,85 @@
+use leptos::prelude::*;
+
+pub use crate::db::project_repository::Project;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_admin_user;
+
+#[server(ListProjects, "/api")]
+pub async fn list_projects() -> Result<Vec<Project>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    state
+        .project_repo
+        .list_all()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+#[server(SaveProject, "/api")]
+pub async fn save_project(
+    name: String,
+    title: String,
+    description: String,
+    access_levels: String,
+) -> Result<String, ServerFnError> {
+    use crate::db::project_repository::is_valid_project_name;
+
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let name = name.trim().to_string();
+    if !is_valid_project_name(&name) {
+        return Err(ServerFnError::new(
+            "Project name must be a single slug segment (lowercase letters, digits and -)",
+        ));
+    }
+    let title = match title.trim() {
+        "" => name.clone(),
+        title => title.to_string(),
+    };
+
+    let access_levels: Vec<String> = access_levels
+        .split(',')
+        .map(|l| l.trim().to_lowercase())
+        .filter(|l| !l.is_empty())
+        .collect();
+    for level in &access_levels {
+        let exists = state
+            .access_level_repo
+            .exists(level)
+            .await
+            .map_err(crate::error::AppError::into_server_fn_error)?;
+        if !exists {
+            return Err(ServerFnError::new(format!(
+                "Unknown access level: '{level}'"
+            )));
+        }
+    }
+
+    state
+        .project_repo
+        .upsert(Project {
+            name: name.clone(),
+            title,
+            description: description.trim().to_string(),
+            access_levels,
+            updated_at: chrono::Utc::now(),
+        })
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(format!("Project '{name}' saved"))
+}
+
+#[server(DeleteProject, "/api")]
+pub async fn delete_project(name: String) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    state
+        .project_repo
+        .delete(&name)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
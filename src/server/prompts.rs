@@ -42,7 +42,7 @@ async fn prompt_visibility_for_user(
         .user_repo
         .find_user_by_id(&user.user_id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     let user_ctx = match user_doc {
         Some(u) => crate::auth::models::UserContext::from_user_doc(user.clone(), &u),
         None => crate::auth::models::UserContext {
@@ -146,12 +146,12 @@ pub async fn get_prompt_library_state() -> Result<PromptLibraryState, ServerFnEr
         .prompt_repo
         .list_by_access_levels(levels.as_deref(), include_draft)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     let preferences = state
         .user_prompt_preference_repo
         .list_by_user_id(&user.user_id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(build_prompt_library_state(prompts, preferences))
 }
@@ -170,7 +170,7 @@ pub async fn save_prompt_preference(
         .prompt_repo
         .find_by_slug(&prompt_slug)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .map_err(crate::error::AppError::into_server_fn_error)?
         .ok_or_else(|| ServerFnError::new("Prompt not found"))?;
 
     let allowed = user.is_admin
@@ -198,18 +198,18 @@ pub async fn save_prompt_preference(
         .user_prompt_preference_repo
         .upsert(preference)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     let prompts = state
         .prompt_repo
         .list_by_access_levels(levels.as_deref(), include_draft)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     let preferences = state
         .user_prompt_preference_repo
         .list_by_user_id(&user.user_id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(build_prompt_library_state(prompts, preferences))
 }
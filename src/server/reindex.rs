@@ -97,14 +97,28 @@ pub async fn trigger_search_reindex() -> Result<String, ServerFnError> {
         return Err(ServerFnError::new("Search re-index is already in progress"));
     }
 
+    let settings = match state.settings_repo.get_settings().await {
+        Ok(settings) => settings.search,
+        Err(e) => {
+            reindex.is_running.store(false, Ordering::Release);
+            return Err(e.into_server_fn_error());
+        }
+    };
+
     let reindex_clone = reindex.clone();
     let document_repo = state.document_repo.clone();
     let storage = state.storage_client.clone();
     let search_clone = search.clone();
 
     tokio::spawn(async move {
-        crate::search::reindex::run_reindex(reindex_clone, document_repo, storage, search_clone)
-            .await;
+        crate::search::reindex::run_reindex(
+            reindex_clone,
+            document_repo,
+            storage,
+            search_clone,
+            settings,
+        )
+        .await;
     });
 
     Ok("Search re-index started".to_string())
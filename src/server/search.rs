@@ -3,11 +3,16 @@ use leptos::prelude::*;
 #[cfg(feature = "ssr")]
 use crate::app::AppState;
 use crate::search::client::SearchHit;
+use crate::search::query::QuickFilters;
 #[cfg(feature = "ssr")]
 use crate::server::request_document_visibility;
 
 #[server(SearchDocs, "/api")]
-pub async fn search_docs(query: String) -> Result<Vec<SearchHit>, ServerFnError> {
+pub async fn search_docs(
+    query: String,
+    project: Option<String>,
+    filters: QuickFilters,
+) -> Result<Vec<SearchHit>, ServerFnError> {
     let state = expect_context::<AppState>();
 
     let search_service = state
@@ -16,10 +21,44 @@ pub async fn search_docs(query: String) -> Result<Vec<SearchHit>, ServerFnError>
         .ok_or_else(|| ServerFnError::new("Search not available"))?;
 
     let (allowed_levels, include_draft) = request_document_visibility(&state).await?;
-    let results = search_service
-        .search(&query, allowed_levels.as_deref(), include_draft)
-        .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let filter = filters.to_search_filter(
+        project.as_deref(),
+        state.scheduler.stale_after_days,
+        chrono::Utc::now().timestamp(),
+    );
+    let mut results = crate::features::search(
+        &state,
+        search_service.as_ref(),
+        &query,
+        allowed_levels.as_deref(),
+        include_draft,
+        &filter,
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)?;
+    crate::schema::deprecation::annotate_search_hits(
+        state.document_repo.as_ref(),
+        state.schema_repo.as_ref(),
+        &mut results,
+        allowed_levels.as_deref(),
+    )
+    .await;
+    if let Err(e) = crate::relations::demote_superseded_hits(
+        state.document_repo.as_ref(),
+        &mut results,
+        |doc| {
+            crate::app::doc_is_accessible(
+                &doc.access_level,
+                doc.is_draft,
+                allowed_levels.as_deref(),
+                include_draft,
+            )
+        },
+    )
+    .await
+    {
+        tracing::warn!("Failed to check search hits for superseded documents: {e}");
+    }
 
     Ok(results)
 }
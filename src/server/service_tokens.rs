@@ -15,6 +15,7 @@ pub struct ServiceTokenInfo {
     pub is_active: bool,
     pub created_at: String,
     pub last_used_at: Option<String>,
+    pub project: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -34,10 +35,11 @@ pub async fn list_service_tokens() -> Result<Vec<ServiceTokenInfo>, ServerFnErro
         .service_token_repo
         .list_all()
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(tokens
         .into_iter()
+        .filter(|t| !t.is_bot())
         .map(|t| ServiceTokenInfo {
             id: t.id,
             name: t.name,
@@ -48,15 +50,120 @@ pub async fn list_service_tokens() -> Result<Vec<ServiceTokenInfo>, ServerFnErro
             last_used_at: t
                 .last_used_at
                 .map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
+            project: t.project,
         })
         .collect())
 }
 
+/// A read-only API key for server-to-server search and document fetches.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReadApiKeyInfo {
+    pub id: String,
+    pub name: String,
+    pub access_levels: Vec<String>,
+    pub allowed_scopes: Vec<String>,
+    pub is_active: bool,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+}
+
+#[server(ListReadApiKeys, "/api")]
+pub async fn list_read_api_keys() -> Result<Vec<ReadApiKeyInfo>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    let tokens = state
+        .service_token_repo
+        .list_all()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    Ok(tokens
+        .into_iter()
+        .filter(|t| t.is_bot())
+        .map(|t| ReadApiKeyInfo {
+            id: t.id,
+            name: t.name,
+            access_levels: t.access_levels,
+            allowed_scopes: t.allowed_scopes,
+            is_active: t.is_active,
+            created_at: t.created_at.format("%Y-%m-%d %H:%M").to_string(),
+            last_used_at: t
+                .last_used_at
+                .map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
+        })
+        .collect())
+}
+
+/// Create a read-only API key bound to `access_levels`. `scopes` holds one
+/// optional path prefix per line; when empty the key reads every document in
+/// its levels.
+#[server(CreateReadApiKey, "/api")]
+pub async fn create_read_api_key(
+    name: String,
+    access_levels: Vec<String>,
+    scopes: String,
+) -> Result<CreateTokenResult, ServerFnError> {
+    use crate::auth::token_service::TokenService;
+
+    let state = expect_context::<AppState>();
+    let user = require_admin_user(&state).await?;
+
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(ServerFnError::new("API key name cannot be empty"));
+    }
+
+    let access_levels = crate::api::bot::resolve_key_access_levels(
+        state.access_level_repo.as_ref(),
+        &access_levels,
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)?;
+    let allowed_scopes: Vec<String> = scopes
+        .lines()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect();
+
+    let raw_token = TokenService::generate_opaque_token();
+    let token = crate::db::service_token_models::ServiceToken {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.clone(),
+        token_hash: TokenService::hash_token(&raw_token),
+        allowed_scopes: allowed_scopes.clone(),
+        token_type: "bot".to_string(),
+        user_id: None,
+        can_write: false,
+        created_by: user.user_id,
+        created_at: chrono::Utc::now(),
+        last_used_at: None,
+        is_active: true,
+        project: None,
+        access_levels,
+    };
+    let id = token.id.clone();
+
+    state
+        .service_token_repo
+        .create(token)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+
+    Ok(CreateTokenResult {
+        id,
+        name,
+        raw_token,
+        allowed_scopes,
+    })
+}
+
 #[server(CreateServiceToken, "/api")]
 pub async fn create_service_token(
     name: String,
     scopes: String,
     can_write: bool,
+    project: String,
 ) -> Result<CreateTokenResult, ServerFnError> {
     use crate::auth::token_service::TokenService;
 
@@ -73,6 +180,13 @@ pub async fn create_service_token(
         .map(|l| l.trim().to_string())
         .filter(|l| !l.is_empty())
         .collect();
+    let (project, allowed_scopes) = crate::db::project_repository::resolve_token_project(
+        state.project_repo.as_ref(),
+        Some(&project),
+        allowed_scopes,
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)?;
     if allowed_scopes.is_empty() {
         return Err(ServerFnError::new("At least one scope is required"));
     }
@@ -81,7 +195,7 @@ pub async fn create_service_token(
         .service_token_repo
         .check_scope_overlap(&allowed_scopes, None)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
     if has_overlap {
         return Err(ServerFnError::new(
             "Scopes overlap with an existing service token",
@@ -103,13 +217,15 @@ pub async fn create_service_token(
         created_at: chrono::Utc::now(),
         last_used_at: None,
         is_active: true,
+        project,
+        access_levels: vec![],
     };
 
     state
         .service_token_repo
         .create(token)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(CreateTokenResult {
         id: uuid::Uuid::new_v4().to_string(),
@@ -128,7 +244,7 @@ pub async fn deactivate_service_token(id: String) -> Result<(), ServerFnError> {
         .service_token_repo
         .deactivate(&id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(())
 }
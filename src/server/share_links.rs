@@ -0,0 +1,253 @@
+//! Share links: signed, expiring URLs giving read access to one restricted
+//! document without an account (see [`crate::api::share`]).
+//!
+//! Editors of a document create and revoke its links; admins list and
+//! revoke every link and see who used them.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::error::AppError;
+
+/// Longest note accepted on a share link.
+#[cfg(feature = "ssr")]
+const MAX_NOTE_LEN: usize = 200;
+/// Accesses listed per link.
+#[cfg(feature = "ssr")]
+const ACCESS_LOG_LIMIT: i64 = 100;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShareLinkInfo {
+    pub id: String,
+    pub slug: String,
+    /// Who the link is for.
+    pub note: String,
+    pub created_by: String,
+    pub created_at: String,
+    pub expires_at: String,
+    /// Neither expired nor revoked.
+    pub active: bool,
+    pub revoked_by: Option<String>,
+    pub access_count: u64,
+    pub last_accessed_at: Option<String>,
+}
+
+/// A new share link, with its URL path (`/share/<token>`). The token is not
+/// stored: this is the only time it is shown.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreatedShareLink {
+    pub link: ShareLinkInfo,
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShareLinkAccessInfo {
+    pub accessed_at: String,
+    pub forwarded_for: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[cfg(feature = "ssr")]
+fn share_link_info(link: crate::db::share_link_repository::ShareLink) -> ShareLinkInfo {
+    let format = |at: chrono::DateTime<chrono::Utc>| at.format("%Y-%m-%d %H:%M").to_string();
+    ShareLinkInfo {
+        active: link.is_active(chrono::Utc::now()),
+        id: link.id,
+        slug: link.slug,
+        note: link.note,
+        created_by: link.created_by,
+        created_at: format(link.created_at),
+        expires_at: format(link.expires_at),
+        revoked_by: link.revoked_by,
+        access_count: link.access_count,
+        last_accessed_at: link.last_accessed_at.map(format),
+    }
+}
+
+/// The document at `slug` and the caller, if the caller may manage its
+/// share links: share links are enabled and the caller can edit it.
+#[cfg(feature = "ssr")]
+async fn shareable_document(
+    state: &AppState,
+    slug: &str,
+) -> Result<
+    (
+        crate::db::models::Document,
+        crate::auth::models::AuthenticatedUser,
+    ),
+    ServerFnError,
+> {
+    if !state.share_links.enabled {
+        return Err(ServerFnError::new(
+            "Share links are disabled on this server",
+        ));
+    }
+    let ctx = crate::server::require_user_context(state).await?;
+    let doc = state
+        .document_repo
+        .find_by_slug(slug)
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .filter(|doc| !doc.is_archived)
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Document '{slug}' not found")).into_server_fn_error()
+        })?;
+    if !ctx.can_edit_document(
+        &doc.access_level,
+        doc.is_draft,
+        doc.edit_access_level.as_deref(),
+    ) {
+        return Err(
+            AppError::Forbidden("Only editors of this document can share it".into())
+                .into_server_fn_error(),
+        );
+    }
+    Ok((doc, ctx.user))
+}
+
+/// Create a link to `slug` valid for `days`, for the recipients described
+/// by `note`.
+#[server(CreateShareLink, "/api")]
+pub async fn create_share_link(
+    slug: String,
+    days: u32,
+    note: String,
+) -> Result<CreatedShareLink, ServerFnError> {
+    use crate::db::share_link_repository::ShareLink;
+
+    let state = expect_context::<AppState>();
+    let (doc, user) = shareable_document(&state, &slug).await?;
+
+    if doc.access_level == "public" {
+        return Err(AppError::BadRequest(
+            "Public documents can be shared with their normal URL".into(),
+        )
+        .into_server_fn_error());
+    }
+    let max_days = state.share_links.max_days;
+    if days == 0 || days > max_days {
+        return Err(
+            AppError::BadRequest(format!("Links can be valid for 1 to {max_days} days"))
+                .into_server_fn_error(),
+        );
+    }
+    let note = note.trim().to_string();
+    if note.chars().count() > MAX_NOTE_LEN {
+        return Err(AppError::BadRequest(format!(
+            "Notes must be at most {MAX_NOTE_LEN} characters"
+        ))
+        .into_server_fn_error());
+    }
+
+    let now = chrono::Utc::now();
+    let link = ShareLink {
+        id: uuid::Uuid::new_v4().to_string(),
+        slug: doc.slug,
+        note,
+        created_by: user.email,
+        created_at: now,
+        expires_at: now + chrono::Duration::days(i64::from(days)),
+        revoked_at: None,
+        revoked_by: None,
+        access_count: 0,
+        last_accessed_at: None,
+    };
+    let token = state
+        .token_service
+        .generate_share_token(&link.id, &link.slug, link.expires_at)
+        .map_err(AppError::into_server_fn_error)?;
+    state
+        .share_link_repo
+        .create(link.clone())
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    tracing::info!(
+        link_id = %link.id,
+        slug = %link.slug,
+        created_by = %link.created_by,
+        expires_at = %link.expires_at,
+        "Share link created"
+    );
+
+    Ok(CreatedShareLink {
+        link: share_link_info(link),
+        path: format!("/share/{token}"),
+    })
+}
+
+/// Links to `slug` for its editors, or every link for admins when `slug`
+/// is `None`. Newest first.
+#[server(ListShareLinks, "/api")]
+pub async fn list_share_links(slug: Option<String>) -> Result<Vec<ShareLinkInfo>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    match &slug {
+        Some(slug) => {
+            shareable_document(&state, slug).await?;
+        }
+        None => {
+            crate::server::require_admin_user(&state).await?;
+        }
+    }
+
+    let links = state
+        .share_link_repo
+        .list(slug.as_deref())
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok(links.into_iter().map(share_link_info).collect())
+}
+
+/// Revoke a link. Editors of its document and admins may revoke it.
+#[server(RevokeShareLink, "/api")]
+pub async fn revoke_share_link(id: String) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let link = state
+        .share_link_repo
+        .find_by_id(&id)
+        .await
+        .map_err(AppError::into_server_fn_error)?
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Share link '{id}' not found")).into_server_fn_error()
+        })?;
+    let user = match shareable_document(&state, &link.slug).await {
+        Ok((_, user)) => user,
+        // Admins can revoke links whatever became of the document
+        Err(e) => crate::server::require_admin_user(&state)
+            .await
+            .map_err(|_| e)?,
+    };
+
+    state
+        .share_link_repo
+        .revoke(&id, &user.email)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    tracing::info!(link_id = %id, slug = %link.slug, revoked_by = %user.email, "Share link revoked");
+    Ok(())
+}
+
+/// Most recent uses of a link (admin only).
+#[server(ListShareLinkAccesses, "/api")]
+pub async fn list_share_link_accesses(
+    id: String,
+) -> Result<Vec<ShareLinkAccessInfo>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::server::require_admin_user(&state).await?;
+
+    let accesses = state
+        .share_link_repo
+        .list_accesses(&id, ACCESS_LOG_LIMIT)
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok(accesses
+        .into_iter()
+        .map(|access| ShareLinkAccessInfo {
+            accessed_at: access.accessed_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            forwarded_for: access.forwarded_for,
+            user_agent: access.user_agent,
+        })
+        .collect())
+}
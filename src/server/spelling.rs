@@ -0,0 +1,85 @@
+use leptos::prelude::*;
+
+pub use crate::db::spelling_dictionary_repository::DictionaryWord;
+pub use crate::rendering::spelling::Misspelling;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_admin_user;
+
+/// Longest word accepted in the spelling dictionary.
+#[cfg(feature = "ssr")]
+const MAX_WORD_LEN: usize = 64;
+
+#[server(ListDictionaryWords, "/api")]
+pub async fn list_dictionary_words() -> Result<Vec<DictionaryWord>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    state
+        .spelling_dictionary_repo
+        .list_all()
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+#[server(AddDictionaryWord, "/api")]
+pub async fn add_dictionary_word(word: String) -> Result<String, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_admin_user(&state).await?;
+
+    let word = word.trim().to_string();
+    if word.is_empty() || word.contains(char::is_whitespace) {
+        return Err(ServerFnError::new("Enter a single word"));
+    }
+    if word.chars().count() > MAX_WORD_LEN {
+        return Err(ServerFnError::new(format!(
+            "Words must be at most {MAX_WORD_LEN} characters"
+        )));
+    }
+
+    state
+        .spelling_dictionary_repo
+        .upsert(DictionaryWord {
+            word: word.clone(),
+            added_by: user.email,
+            added_at: chrono::Utc::now(),
+        })
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(format!("'{word}' added to the dictionary"))
+}
+
+#[server(RemoveDictionaryWord, "/api")]
+pub async fn remove_dictionary_word(word: String) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    state
+        .spelling_dictionary_repo
+        .delete(&word)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+/// Misspellings in editor content (HTML), for the editor's **Check
+/// spelling** action.
+#[server(CheckSpelling, "/api")]
+pub async fn check_spelling(html_content: String) -> Result<Vec<Misspelling>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    crate::features::require_editor(&state)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    crate::server::require_any_user(&state).await?;
+
+    let Some(checker) = state.spell_checker.as_ref() else {
+        return Err(ServerFnError::new(
+            "Spell checking is not enabled on this server",
+        ));
+    };
+    checker
+        .check_html(&html_content)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
@@ -0,0 +1,17 @@
+use leptos::prelude::*;
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+use crate::db::models::OwnerQuotaUsage;
+#[cfg(feature = "ssr")]
+use crate::server::require_admin_user;
+
+#[server(GetOwnerUsage, "/api")]
+pub async fn get_owner_usage() -> Result<Vec<OwnerQuotaUsage>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    crate::api::admin::owner_quota_usage(&state)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
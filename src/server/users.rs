@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "ssr")]
 use crate::app::AppState;
 #[cfg(feature = "ssr")]
-use crate::server::require_admin_user;
+use crate::server::{require_admin_user, require_any_user};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdminUserInfo {
@@ -18,6 +18,8 @@ pub struct AdminUserInfo {
     pub can_read_draft: bool,
     pub can_write_draft: bool,
     pub last_login_at: Option<String>,
+    /// Local account (email + password) rather than an OIDC/OAuth2 identity.
+    pub is_local: bool,
 }
 
 #[server(ListAdminUsers, "/api")]
@@ -29,7 +31,7 @@ pub async fn list_admin_users() -> Result<Vec<AdminUserInfo>, ServerFnError> {
         .user_repo
         .list_users()
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     Ok(users
         .into_iter()
@@ -43,6 +45,7 @@ pub async fn list_admin_users() -> Result<Vec<AdminUserInfo>, ServerFnError> {
             can_write: u.can_write,
             can_read_draft: u.can_read_draft,
             can_write_draft: u.can_write_draft,
+            is_local: u.provider_type == crate::auth::local_accounts::PROVIDER_TYPE,
             last_login_at: u
                 .last_login_at
                 .map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
@@ -66,7 +69,7 @@ pub async fn set_admin_user_access_levels(
             .access_level_repo
             .exists(level)
             .await
-            .map_err(|e| ServerFnError::new(e.to_string()))?
+            .map_err(crate::error::AppError::into_server_fn_error)?
         {
             return Err(ServerFnError::new(format!(
                 "Access level '{level}' does not exist"
@@ -78,7 +81,7 @@ pub async fn set_admin_user_access_levels(
         .access_level_repo
         .compute_effective_levels(&assigned)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        .map_err(crate::error::AppError::into_server_fn_error)?;
 
     state
         .user_repo
@@ -91,5 +94,153 @@ pub async fn set_admin_user_access_levels(
             can_write_draft,
         )
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+#[server(CreateAdminLocalUser, "/api")]
+pub async fn create_admin_local_user(
+    email: String,
+    name: Option<String>,
+    password: String,
+    is_admin: bool,
+) -> Result<(), ServerFnError> {
+    use crate::auth::local_accounts::{create_local_user, NewLocalUser};
+
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+    if !state.local_accounts {
+        return Err(crate::error::AppError::BadRequest(
+            "Local accounts are disabled (auth.local_accounts)".into(),
+        )
+        .into_server_fn_error());
+    }
+
+    create_local_user(
+        state.user_repo.as_ref(),
+        NewLocalUser {
+            email,
+            name,
+            password,
+            is_admin,
+        },
+        true,
+    )
+    .await
+    .map(|_| ())
+    .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+#[server(UpdateAdminUser, "/api")]
+pub async fn update_admin_user(
+    user_id: String,
+    name: Option<String>,
+    is_admin: bool,
+) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let caller = require_admin_user(&state).await?;
+
+    crate::api::admin::update_user(
+        state.user_repo.as_ref(),
+        &caller,
+        &user_id,
+        crate::api::admin::UpdateUserRequest { name, is_admin },
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+#[server(DeleteAdminUser, "/api")]
+pub async fn delete_admin_user(user_id: String) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let caller = require_admin_user(&state).await?;
+
+    crate::api::admin::delete_user(state.user_repo.as_ref(), &caller, &user_id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+#[server(ResetAdminUserPassword, "/api")]
+pub async fn reset_admin_user_password(
+    user_id: String,
+    password: String,
+) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    require_admin_user(&state).await?;
+
+    crate::auth::local_accounts::reset_password(state.user_repo.as_ref(), &user_id, password)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+/// Password state of the caller's account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordStatus {
+    /// The password was set by an administrator and must be changed.
+    pub change_required: bool,
+}
+
+/// `None` unless the caller signed in with a local account.
+#[server(GetOwnPasswordStatus, "/api")]
+pub async fn get_own_password_status() -> Result<Option<PasswordStatus>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    let record = state
+        .user_repo
+        .find_user_by_id(&user.user_id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(record.and_then(|u| u.password).map(|p| PasswordStatus {
+        change_required: p.change_required,
+    }))
+}
+
+#[server(ChangeOwnPassword, "/api")]
+pub async fn change_own_password(
+    current_password: String,
+    new_password: String,
+) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    crate::auth::local_accounts::change_password(
+        state.user_repo.as_ref(),
+        &user.user_id,
+        current_password,
+        new_password,
+    )
+    .await
+    .map_err(crate::error::AppError::into_server_fn_error)
+}
+
+/// Access granted to the caller, shown in the navbar user menu.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OwnAccess {
+    /// Effective access levels, sorted (`public` is implicit and omitted).
+    pub access_levels: Vec<String>,
+    pub can_read_draft: bool,
+}
+
+#[server(GetOwnAccess, "/api")]
+pub async fn get_own_access() -> Result<OwnAccess, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    let record = state
+        .user_repo
+        .find_user_by_id(&user.user_id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    let (mut access_levels, can_read_draft) = match record {
+        Some(u) => (u.effective_access_levels, u.can_read_draft),
+        // Demo users have no stored record
+        None => (vec![], false),
+    };
+    access_levels.retain(|level| level != "public");
+    access_levels.sort();
+    access_levels.dedup();
+    Ok(OwnAccess {
+        access_levels,
+        can_read_draft: can_read_draft || user.is_admin,
+    })
 }
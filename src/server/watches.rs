@@ -0,0 +1,123 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::app::AppState;
+#[cfg(feature = "ssr")]
+use crate::server::require_any_user;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WatchInfo {
+    pub id: String,
+    /// A slug, or a slug prefix followed by `/*`.
+    pub target: String,
+    pub email: bool,
+    pub webhook_url: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WatchList {
+    pub watches: Vec<WatchInfo>,
+    /// Whether email notifications can be delivered.
+    pub email_enabled: bool,
+}
+
+#[cfg(feature = "ssr")]
+fn watch_info(watch: crate::db::watch_repository::Watch) -> WatchInfo {
+    WatchInfo {
+        id: watch.id,
+        target: watch.target,
+        email: watch.email,
+        webhook_url: watch.webhook_url,
+        created_at: watch.created_at.format("%Y-%m-%d %H:%M").to_string(),
+    }
+}
+
+#[server(ListWatches, "/api")]
+pub async fn list_watches() -> Result<WatchList, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    let watches = state
+        .watch_repo
+        .list_for_user(&user.user_id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(WatchList {
+        watches: watches.into_iter().map(watch_info).collect(),
+        email_enabled: state.email.is_some(),
+    })
+}
+
+/// The caller's watch of exactly `slug`, if any.
+#[server(GetDocumentWatch, "/api")]
+pub async fn get_document_watch(slug: String) -> Result<Option<WatchInfo>, ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    let watches = state
+        .watch_repo
+        .list_for_user(&user.user_id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)?;
+    Ok(watches
+        .into_iter()
+        .find(|w| w.target == slug)
+        .map(watch_info))
+}
+
+/// Watch a document (`target` = slug) or a subtree (`target` = `prefix/*`).
+/// Watching a target again replaces its settings.
+#[server(WatchDocument, "/api")]
+pub async fn watch_document(
+    target: String,
+    email: bool,
+    webhook_url: Option<String>,
+) -> Result<WatchInfo, ServerFnError> {
+    use crate::error::AppError;
+
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    let target = crate::db::watch_repository::normalize_target(&target)
+        .map_err(AppError::into_server_fn_error)?;
+    let webhook_url = webhook_url
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty());
+    if let Some(url) = &webhook_url {
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err(AppError::BadRequest(
+                "Webhook URL must start with http:// or https://".into(),
+            )
+            .into_server_fn_error());
+        }
+    }
+
+    let watch = crate::db::watch_repository::Watch {
+        id: uuid::Uuid::new_v4().to_string(),
+        user_id: user.user_id,
+        target,
+        email,
+        webhook_url,
+        created_at: chrono::Utc::now(),
+    };
+    state
+        .watch_repo
+        .upsert(watch.clone())
+        .await
+        .map_err(AppError::into_server_fn_error)?;
+    Ok(watch_info(watch))
+}
+
+#[server(UnwatchDocument, "/api")]
+pub async fn unwatch_document(id: String) -> Result<(), ServerFnError> {
+    let state = expect_context::<AppState>();
+    let user = require_any_user(&state).await?;
+
+    state
+        .watch_repo
+        .delete(&user.user_id, &id)
+        .await
+        .map_err(crate::error::AppError::into_server_fn_error)
+}
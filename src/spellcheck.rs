@@ -0,0 +1,184 @@
+//! Spell checking of documents (`[spellcheck]` config).
+//!
+//! Words are checked against a Hunspell dictionary loaded at startup and the
+//! organization's word list managed under **Admin → Dictionary**. Misspellings
+//! are reported by dry-run ingests (`?dry_run=true`) and by the editor's
+//! **Check spelling** action; they never block a publication.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::config::SpellcheckConfig;
+use crate::db::spelling_dictionary_repository::SpellingDictionaryRepository;
+use crate::error::AppError;
+use crate::rendering::spelling::{Misspelling, Word, MAX_MISSPELLINGS};
+
+/// Checks document text against the dictionary and the organization's words.
+pub struct SpellChecker {
+    dictionary: Arc<spellbook::Dictionary>,
+    words: Arc<dyn SpellingDictionaryRepository>,
+    max_suggestions: usize,
+}
+
+impl SpellChecker {
+    pub fn new(
+        dictionary: spellbook::Dictionary,
+        words: Arc<dyn SpellingDictionaryRepository>,
+        max_suggestions: usize,
+    ) -> Self {
+        Self {
+            dictionary: Arc::new(dictionary),
+            words,
+            max_suggestions,
+        }
+    }
+
+    /// Load the configured dictionary, or `None` when spell checking is off.
+    pub fn from_config(
+        config: &SpellcheckConfig,
+        words: Arc<dyn SpellingDictionaryRepository>,
+    ) -> Result<Option<Self>, AppError> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let read = |extension: &str| {
+            let path = format!("{}.{extension}", config.dictionary);
+            std::fs::read_to_string(&path)
+                .map_err(|e| AppError::Internal(format!("Cannot read {path}: {e}")))
+        };
+        let dictionary = spellbook::Dictionary::new(&read("aff")?, &read("dic")?).map_err(|e| {
+            AppError::Internal(format!(
+                "Invalid Hunspell dictionary {}: {e}",
+                config.dictionary
+            ))
+        })?;
+        Ok(Some(Self::new(dictionary, words, config.max_suggestions)))
+    }
+
+    /// Misspellings in a Markdown source.
+    pub async fn check_markdown(&self, markdown: &str) -> Result<Vec<Misspelling>, AppError> {
+        self.check(crate::rendering::spelling::markdown_words(markdown))
+            .await
+    }
+
+    /// Misspellings in HTML saved by the web editor.
+    pub async fn check_html(&self, html: &str) -> Result<Vec<Misspelling>, AppError> {
+        self.check(crate::rendering::spelling::html_words(html))
+            .await
+    }
+
+    async fn check(&self, words: Vec<Word<'_>>) -> Result<Vec<Misspelling>, AppError> {
+        let accepted: HashSet<String> = self
+            .words
+            .list_all()
+            .await?
+            .into_iter()
+            .map(|w| w.word.to_lowercase())
+            .collect();
+        let words: Vec<(String, usize)> = words
+            .into_iter()
+            .map(|w| (w.text.to_string(), w.line))
+            .collect();
+
+        // Suggestions take a few milliseconds per word
+        let dictionary = self.dictionary.clone();
+        let max_suggestions = self.max_suggestions;
+        tokio::task::spawn_blocking(move || {
+            find_misspellings(&dictionary, &accepted, &words, max_suggestions)
+        })
+        .await
+        .map_err(|e| AppError::Internal(format!("Spell check failed: {e}")))
+    }
+}
+
+/// Words of `words` that neither `dictionary` nor `accepted` (lowercase)
+/// know, each reported once per line.
+fn find_misspellings(
+    dictionary: &spellbook::Dictionary,
+    accepted: &HashSet<String>,
+    words: &[(String, usize)],
+    max_suggestions: usize,
+) -> Vec<Misspelling> {
+    let mut misspellings: Vec<Misspelling> = Vec::new();
+    let mut suggestions_of = std::collections::HashMap::<&str, Vec<String>>::new();
+    for (word, line) in words {
+        if misspellings.len() >= MAX_MISSPELLINGS {
+            break;
+        }
+        let reported = misspellings
+            .iter()
+            .any(|m| m.line == *line && m.word == *word);
+        if reported || accepted.contains(&word.to_lowercase()) || dictionary.check(word) {
+            continue;
+        }
+        let suggestions = suggestions_of
+            .entry(word.as_str())
+            .or_insert_with(|| {
+                let mut suggestions = Vec::new();
+                if max_suggestions > 0 {
+                    dictionary.suggest(word, &mut suggestions);
+                    suggestions.truncate(max_suggestions);
+                }
+                suggestions
+            })
+            .clone();
+        misspellings.push(Misspelling {
+            word: word.clone(),
+            line: *line,
+            suggestions,
+        });
+    }
+    misspellings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "SET UTF-8\nTRY esianrtolcdugmphbyfvkwz\n\nSFX S Y 1\nSFX S 0 s .\n";
+    const DIC: &str = "4\nrestart/S\nthe\npod/S\nguide/S\n";
+
+    fn words(text: &str) -> Vec<(String, usize)> {
+        crate::rendering::spelling::markdown_words(text)
+            .into_iter()
+            .map(|w| (w.text.to_string(), w.line))
+            .collect()
+    }
+
+    #[test]
+    fn reports_unknown_words_with_suggestions() {
+        let dictionary = spellbook::Dictionary::new(AFF, DIC).unwrap();
+        let found = find_misspellings(
+            &dictionary,
+            &HashSet::new(),
+            &words("# Guides\n\nRestart the pods, the podd.\n"),
+            3,
+        );
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].word, "podd");
+        assert_eq!(found[0].line, 3);
+        assert!(found[0].suggestions.contains(&"pod".to_string()));
+    }
+
+    #[test]
+    fn accepts_organization_words_case_insensitively() {
+        let dictionary = spellbook::Dictionary::new(AFF, DIC).unwrap();
+        let accepted = HashSet::from(["kubectl".to_string()]);
+        let found = find_misspellings(
+            &dictionary,
+            &accepted,
+            &words("Restart the Kubectl pod, kubectl pod.\n"),
+            3,
+        );
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn reports_a_word_once_per_line() {
+        let dictionary = spellbook::Dictionary::new(AFF, DIC).unwrap();
+        let found = find_misspellings(&dictionary, &HashSet::new(), &words("teh teh\n\nteh\n"), 0);
+        let lines: Vec<usize> = found.iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![1, 3]);
+        assert!(found[0].suggestions.is_empty());
+    }
+}
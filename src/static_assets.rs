@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::sync::OnceLock;
 use std::time::UNIX_EPOCH;
 
+use crate::schema::component::SpecViewerAssets;
+
 static ASSET_VERSIONS: OnceLock<HashMap<&'static str, String>> = OnceLock::new();
 
 const TRACKED_ASSETS: &[&str] = &[
@@ -37,6 +39,44 @@ pub fn versioned_url(path: &'static str) -> String {
     }
 }
 
+/// Whether a tracked asset exists under `site_root`. Only valid after [`init`].
+pub fn is_available(path: &'static str) -> bool {
+    ASSET_VERSIONS.get().is_some_and(|m| m.contains_key(path))
+}
+
+/// Resolve where the schema viewer loads its bundles from, per
+/// `server.spec_viewer_assets`. `None` means the built-in viewer: either
+/// `"none"` was configured or the bundled copies are missing (e.g. built
+/// without the `schema-viewers` feature).
+pub fn spec_viewer_assets(assets_url: &str) -> Option<SpecViewerAssets> {
+    const BUNDLED: [&str; 4] = [
+        "/js/scalar-standalone.js",
+        "/js/scalar-style.css",
+        "/js/asyncapi-standalone.js",
+        "/js/asyncapi-default.min.css",
+    ];
+
+    match assets_url.trim() {
+        "none" => None,
+        "" if BUNDLED.iter().all(|&asset| is_available(asset)) => Some(SpecViewerAssets {
+            scalar_js: versioned_url(BUNDLED[0]),
+            scalar_css: versioned_url(BUNDLED[1]),
+            asyncapi_js: versioned_url(BUNDLED[2]),
+            asyncapi_css: versioned_url(BUNDLED[3]),
+        }),
+        "" => None,
+        base => {
+            let base = base.trim_end_matches('/');
+            Some(SpecViewerAssets {
+                scalar_js: format!("{base}/scalar-standalone.js"),
+                scalar_css: format!("{base}/scalar-style.css"),
+                asyncapi_js: format!("{base}/asyncapi-standalone.js"),
+                asyncapi_css: format!("{base}/asyncapi-default.min.css"),
+            })
+        }
+    }
+}
+
 fn mtime_version(path: &str) -> Option<String> {
     std::fs::metadata(path).ok()?.modified().ok().map(|t| {
         t.duration_since(UNIX_EPOCH)
@@ -45,3 +85,23 @@ fn mtime_version(path: &str) -> Option<String> {
             .to_string()
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_viewer_asset_sources() {
+        assert_eq!(spec_viewer_assets("none"), None);
+
+        let mirrored = spec_viewer_assets("https://mirror.acme.internal/lekton/").unwrap();
+        assert_eq!(
+            mirrored.scalar_js,
+            "https://mirror.acme.internal/lekton/scalar-standalone.js"
+        );
+        assert_eq!(
+            mirrored.asyncapi_css,
+            "https://mirror.acme.internal/lekton/asyncapi-default.min.css"
+        );
+    }
+}
@@ -1,7 +1,50 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::error::AppError;
 
+/// An object found by [`StorageClient::list_objects`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredObject {
+    pub key: String,
+    pub size_bytes: u64,
+    /// `None` when the store doesn't track it.
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+/// Kind of stored content; each may live in its own bucket
+/// (`[storage.schemas]`, `[storage.uploads]`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentClass {
+    /// Documents, prompts and their archived versions.
+    Documents,
+    /// Schema specs (`schemas/`).
+    Schemas,
+    /// Attachments, images and quarantined uploads (`assets/`, `images/`,
+    /// `quarantine/`).
+    Uploads,
+}
+
+impl ContentClass {
+    pub const ALL: [ContentClass; 3] = [Self::Documents, Self::Schemas, Self::Uploads];
+
+    /// The class of the object stored at `key`.
+    pub fn of_key(key: &str) -> Self {
+        if key.starts_with("schemas/") {
+            Self::Schemas
+        } else if ["assets/", "images/", "quarantine/"]
+            .iter()
+            .any(|prefix| key.starts_with(prefix))
+        {
+            Self::Uploads
+        } else {
+            Self::Documents
+        }
+    }
+}
+
 /// Trait for blob storage operations (S3-compatible).
 ///
 /// Abstracted as a trait so tests can use a mock without a real S3 instance.
@@ -13,8 +56,37 @@ pub trait StorageClient: Send + Sync {
     /// Retrieve content by key. Returns `None` if the object doesn't exist.
     async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, AppError>;
 
+    /// Retrieve bytes `start..=end` of an object (`end` is clamped to its
+    /// size). Returns `None` if the object doesn't exist.
+    ///
+    /// The default reads the whole object and slices it; S3 fetches only the
+    /// requested range.
+    async fn get_object_range(
+        &self,
+        key: &str,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Vec<u8>>, AppError> {
+        Ok(self.get_object(key).await?.map(|data| {
+            let len = data.len() as u64;
+            if start >= len {
+                return Vec::new();
+            }
+            data[start as usize..=end.min(len - 1) as usize].to_vec()
+        }))
+    }
+
     /// Delete an object by key.
     async fn delete_object(&self, key: &str) -> Result<(), AppError>;
+
+    /// List the objects whose key starts with `prefix`.
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<StoredObject>, AppError>;
+
+    /// How document and prompt slugs map to keys (`storage.key_layout`).
+    #[cfg(feature = "ssr")]
+    fn key_layout(&self) -> crate::config::KeyLayout {
+        crate::config::KeyLayout::default()
+    }
 }
 
 /// S3 implementation of StorageClient.
@@ -24,6 +96,10 @@ pub trait StorageClient: Send + Sync {
 pub struct S3StorageClient {
     client: aws_sdk_s3::Client,
     bucket: String,
+    /// Prepended to every key (`storage.key_prefix`); empty or ending in `/`.
+    key_prefix: String,
+    key_layout: crate::config::KeyLayout,
+    encryption: crate::config::EncryptionConfig,
 }
 
 #[cfg(feature = "ssr")]
@@ -32,6 +108,9 @@ impl S3StorageClient {
     ///
     /// AWS credentials (`AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`, `AWS_REGION`)
     /// are still read by the `aws-config` crate from the environment / credential chain.
+    ///
+    /// The client stores documents; see [`Self::for_class`] for the other
+    /// content classes.
     pub async fn from_app_config(storage: &crate::config::StorageConfig) -> Result<Self, AppError> {
         let bucket = storage.bucket.clone();
         if bucket.is_empty() {
@@ -61,37 +140,111 @@ impl S3StorageClient {
 
         let client = aws_sdk_s3::Client::from_conf(s3_config_builder.build());
 
-        Ok(Self { client, bucket })
+        Ok(Self::new(client, bucket)
+            .with_key_prefix(&storage.key_prefix)
+            .with_key_layout(storage.key_layout)
+            .with_encryption(storage.encryption.clone()))
+    }
+
+    /// A client for the bucket and key prefix of `class`, sharing this
+    /// client's connection.
+    pub fn for_class(&self, storage: &crate::config::StorageConfig, class: ContentClass) -> Self {
+        let (bucket, key_prefix) = storage.location(class);
+        Self::new(self.client.clone(), bucket.to_string())
+            .with_key_prefix(key_prefix)
+            .with_key_layout(self.key_layout)
+            .with_encryption(self.encryption.clone())
+    }
+
+    pub fn bucket(&self) -> &str {
+        &self.bucket
     }
 
     /// Create with explicit values (useful for testing / DI).
     pub fn new(client: aws_sdk_s3::Client, bucket: String) -> Self {
-        Self { client, bucket }
+        Self {
+            client,
+            bucket,
+            key_prefix: String::new(),
+            key_layout: Default::default(),
+            encryption: Default::default(),
+        }
     }
-}
 
-#[cfg(feature = "ssr")]
-#[async_trait]
-impl StorageClient for S3StorageClient {
-    async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), AppError> {
+    /// Lay document and prompt keys out with `layout`.
+    pub fn with_key_layout(mut self, layout: crate::config::KeyLayout) -> Self {
+        self.key_layout = layout;
+        self
+    }
+
+    /// Request server-side encryption of the objects written.
+    pub fn with_encryption(mut self, encryption: crate::config::EncryptionConfig) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
+    /// Store every object under `prefix` (a `/` is appended when missing).
+    pub fn with_key_prefix(mut self, prefix: &str) -> Self {
+        let prefix = prefix.trim_matches('/');
+        self.key_prefix = if prefix.is_empty() {
+            String::new()
+        } else {
+            format!("{prefix}/")
+        };
+        self
+    }
+
+    /// The bucket key of `key`.
+    fn full_key(&self, key: &str) -> String {
+        format!("{}{key}", self.key_prefix)
+    }
+
+    /// Add the configured server-side encryption to a `PutObject` request.
+    fn encrypted(
+        &self,
+        request: aws_sdk_s3::operation::put_object::builders::PutObjectFluentBuilder,
+    ) -> aws_sdk_s3::operation::put_object::builders::PutObjectFluentBuilder {
+        use crate::config::EncryptionMode;
+        use aws_sdk_s3::types::ServerSideEncryption;
+
+        match self.encryption.mode {
+            EncryptionMode::None => request,
+            EncryptionMode::S3 => request.server_side_encryption(ServerSideEncryption::Aes256),
+            EncryptionMode::Kms => request
+                .server_side_encryption(ServerSideEncryption::AwsKms)
+                .set_ssekms_key_id(self.encryption.kms_key_id.clone()),
+        }
+    }
+
+    /// Check that the bucket exists and the credentials can access it.
+    pub async fn check_bucket(&self) -> Result<(), AppError> {
         self.client
-            .put_object()
+            .head_bucket()
             .bucket(&self.bucket)
-            .key(key)
-            .body(content.into())
             .send()
             .await
-            .map_err(|e| AppError::Storage(format!("Failed to put object '{}': {}", key, e)))?;
-
+            .map_err(|e| {
+                AppError::Storage(format!(
+                    "Cannot access bucket '{}': {}",
+                    self.bucket,
+                    e.into_service_error()
+                ))
+            })?;
         Ok(())
     }
 
-    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+    /// `GetObject`, optionally limited to an HTTP `range` (e.g. `bytes=0-1023`).
+    async fn fetch_object(
+        &self,
+        key: &str,
+        range: Option<String>,
+    ) -> Result<Option<Vec<u8>>, AppError> {
         match self
             .client
             .get_object()
             .bucket(&self.bucket)
-            .key(key)
+            .key(self.full_key(key))
+            .set_range(range)
             .send()
             .await
         {
@@ -116,16 +269,249 @@ impl StorageClient for S3StorageClient {
             }
         }
     }
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl StorageClient for S3StorageClient {
+    async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), AppError> {
+        let request = self
+            .client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(self.full_key(key))
+            .body(content.into());
+        self.encrypted(request)
+            .send()
+            .await
+            .map_err(|e| AppError::Storage(format!("Failed to put object '{}': {}", key, e)))?;
+
+        Ok(())
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+        self.fetch_object(key, None).await
+    }
+
+    async fn get_object_range(
+        &self,
+        key: &str,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Vec<u8>>, AppError> {
+        self.fetch_object(key, Some(format!("bytes={start}-{end}")))
+            .await
+    }
 
     async fn delete_object(&self, key: &str) -> Result<(), AppError> {
         self.client
             .delete_object()
             .bucket(&self.bucket)
-            .key(key)
+            .key(self.full_key(key))
             .send()
             .await
             .map_err(|e| AppError::Storage(format!("Failed to delete object '{}': {}", key, e)))?;
 
         Ok(())
     }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<StoredObject>, AppError> {
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .prefix(self.full_key(prefix))
+            .into_paginator()
+            .send();
+        let mut objects = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|e| {
+                AppError::Storage(format!(
+                    "Failed to list objects under '{}': {}",
+                    prefix,
+                    e.into_service_error()
+                ))
+            })?;
+            for object in page.contents() {
+                let Some(key) = object
+                    .key()
+                    .and_then(|key| key.strip_prefix(self.key_prefix.as_str()))
+                else {
+                    continue;
+                };
+                objects.push(StoredObject {
+                    key: key.to_string(),
+                    size_bytes: object.size().unwrap_or(0).max(0) as u64,
+                    last_modified: object
+                        .last_modified()
+                        .and_then(|t| DateTime::from_timestamp(t.secs(), t.subsec_nanos())),
+                });
+            }
+        }
+        Ok(objects)
+    }
+
+    fn key_layout(&self) -> crate::config::KeyLayout {
+        self.key_layout
+    }
+}
+
+/// [`StorageClient`] sending each [`ContentClass`] to its own store.
+pub struct RoutedStorage {
+    documents: Arc<dyn StorageClient>,
+    schemas: Arc<dyn StorageClient>,
+    uploads: Arc<dyn StorageClient>,
+}
+
+impl RoutedStorage {
+    /// Route every class to `store`.
+    pub fn new(store: Arc<dyn StorageClient>) -> Self {
+        Self {
+            documents: store.clone(),
+            schemas: store.clone(),
+            uploads: store,
+        }
+    }
+
+    /// Route `class` to `store`.
+    pub fn with_class(mut self, class: ContentClass, store: Arc<dyn StorageClient>) -> Self {
+        match class {
+            ContentClass::Documents => self.documents = store,
+            ContentClass::Schemas => self.schemas = store,
+            ContentClass::Uploads => self.uploads = store,
+        }
+        self
+    }
+
+    /// One S3 client per content class, using the buckets and key prefixes
+    /// of `[storage]`, `[storage.schemas]` and `[storage.uploads]`.
+    #[cfg(feature = "ssr")]
+    pub async fn from_app_config(storage: &crate::config::StorageConfig) -> Result<Self, AppError> {
+        let documents = S3StorageClient::from_app_config(storage).await?;
+        let schemas = documents.for_class(storage, ContentClass::Schemas);
+        let uploads = documents.for_class(storage, ContentClass::Uploads);
+        Ok(Self::new(Arc::new(documents))
+            .with_class(ContentClass::Schemas, Arc::new(schemas))
+            .with_class(ContentClass::Uploads, Arc::new(uploads)))
+    }
+
+    fn store(&self, key: &str) -> &dyn StorageClient {
+        match ContentClass::of_key(key) {
+            ContentClass::Documents => self.documents.as_ref(),
+            ContentClass::Schemas => self.schemas.as_ref(),
+            ContentClass::Uploads => self.uploads.as_ref(),
+        }
+    }
+}
+
+#[async_trait]
+impl StorageClient for RoutedStorage {
+    async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), AppError> {
+        self.store(key).put_object(key, content).await
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+        self.store(key).get_object(key).await
+    }
+
+    async fn get_object_range(
+        &self,
+        key: &str,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Vec<u8>>, AppError> {
+        self.store(key).get_object_range(key, start, end).await
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), AppError> {
+        self.store(key).delete_object(key).await
+    }
+
+    /// Lists the store of the class `prefix` belongs to.
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<StoredObject>, AppError> {
+        self.store(prefix).list_objects(prefix).await
+    }
+
+    #[cfg(feature = "ssr")]
+    fn key_layout(&self) -> crate::config::KeyLayout {
+        self.documents.key_layout()
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::testing::InMemoryStorage;
+
+    #[tokio::test]
+    async fn test_routed_storage_sends_classes_to_their_store() {
+        let documents = Arc::new(InMemoryStorage::new());
+        let uploads = Arc::new(InMemoryStorage::new());
+        let storage = RoutedStorage::new(documents.clone())
+            .with_class(ContentClass::Uploads, uploads.clone());
+
+        for key in [
+            "docs/intro.md",
+            "schemas/payments/1.0.0.json",
+            "images/1_logo.png",
+        ] {
+            storage.put_object(key, b"x".to_vec()).await.unwrap();
+        }
+
+        let mut stored: Vec<String> = documents.objects.lock().unwrap().keys().cloned().collect();
+        stored.sort();
+        assert_eq!(stored, vec!["docs/intro.md", "schemas/payments/1.0.0.json"]);
+        assert_eq!(storage.list_objects("images/").await.unwrap().len(), 1);
+        assert!(uploads
+            .objects
+            .lock()
+            .unwrap()
+            .contains_key("images/1_logo.png"));
+    }
+
+    fn s3_client(encryption: crate::config::EncryptionConfig) -> S3StorageClient {
+        let config = aws_sdk_s3::Config::builder()
+            .behavior_version(aws_sdk_s3::config::BehaviorVersion::latest())
+            .region(aws_sdk_s3::config::Region::new("eu-west-1"))
+            .build();
+        S3StorageClient::new(aws_sdk_s3::Client::from_conf(config), "docs".into())
+            .with_encryption(encryption)
+    }
+
+    #[test]
+    fn test_put_object_requests_configured_encryption() {
+        use crate::config::{EncryptionConfig, EncryptionMode};
+        use aws_sdk_s3::types::ServerSideEncryption;
+
+        let cases = [
+            (EncryptionMode::None, Some("ignored"), None, None),
+            (
+                EncryptionMode::S3,
+                Some("ignored"),
+                Some(ServerSideEncryption::Aes256),
+                None,
+            ),
+            (
+                EncryptionMode::Kms,
+                None,
+                Some(ServerSideEncryption::AwsKms),
+                None,
+            ),
+            (
+                EncryptionMode::Kms,
+                Some("alias/lekton"),
+                Some(ServerSideEncryption::AwsKms),
+                Some("alias/lekton"),
+            ),
+        ];
+        for (mode, kms_key_id, sse, key_id) in cases {
+            let client = s3_client(EncryptionConfig {
+                mode,
+                kms_key_id: kms_key_id.map(String::from),
+            });
+            let request = client.encrypted(client.client.put_object());
+            assert_eq!(request.get_server_side_encryption(), &sse, "{mode:?}");
+            assert_eq!(request.get_ssekms_key_id().as_deref(), key_id, "{mode:?}");
+        }
+    }
 }
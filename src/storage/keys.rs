@@ -0,0 +1,127 @@
+//! Object keys of documents, prompts and their archived versions.
+//!
+//! The layout (`storage.key_layout`) decides how slugs become keys:
+//!
+//! | Layout   | Document `guides/setup`              | Version 3                                |
+//! |----------|--------------------------------------|------------------------------------------|
+//! | `path`   | `docs/guides/setup.md`               | `history/docs/guides/setup/3.md`         |
+//! | `hashed` | `docs/guides_setup-<hash>.md`        | `docs/history/guides_setup-<hash>/3.md`  |
+//! | `flat`   | `docs/guides_setup.md`               | `docs/history/guides_setup/3.md`         |
+//!
+//! The layout is carried by the storage client
+//! ([`StorageClient::key_layout`](crate::storage::client::StorageClient::key_layout)).
+//! Prompts follow the same scheme under `prompts/` (`.yaml`). Documents and
+//! prompts remember their key, so changing the layout only affects what is
+//! written next (a re-ingest moves the object and deletes the old one once no
+//! colliding slug uses it); `lekton migrate-storage-keys` moves existing
+//! objects (see [`crate::storage::migrate`]).
+//!
+//! `storage.key_prefix` is applied by the S3 client to every key, including
+//! assets, images and schemas unless `[storage.schemas]` or
+//! `[storage.uploads]` set their own.
+
+use sha2::{Digest, Sha256};
+
+use crate::config::KeyLayout;
+
+impl KeyLayout {
+    /// The name used in the configuration.
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyLayout::Path => "path",
+            KeyLayout::Hashed => "hashed",
+            KeyLayout::Flat => "flat",
+        }
+    }
+
+    /// The slug as a key fragment.
+    fn slug_segment(self, slug: &str) -> String {
+        match self {
+            KeyLayout::Path => slug.to_string(),
+            KeyLayout::Flat => slug.replace('/', "_"),
+            KeyLayout::Hashed => {
+                let digest = Sha256::digest(slug.as_bytes());
+                let hash: String = digest[..8].iter().map(|b| format!("{b:02x}")).collect();
+                format!("{}-{hash}", slug.replace('/', "_"))
+            }
+        }
+    }
+
+    /// Where archived versions of `kind` (`docs` or `prompts`) go. With the
+    /// `path` layout, `<kind>/history/` could clash with a slug starting
+    /// with `history/`, so versions get their own prefix.
+    fn history_prefix(self, kind: &str) -> String {
+        match self {
+            KeyLayout::Path => format!("history/{kind}"),
+            KeyLayout::Hashed | KeyLayout::Flat => format!("{kind}/history"),
+        }
+    }
+
+    pub fn document_key(self, slug: &str) -> String {
+        format!("docs/{}.md", self.slug_segment(slug))
+    }
+
+    pub fn document_version_key(self, slug: &str, version: u64) -> String {
+        format!(
+            "{}/{}/{version}.md",
+            self.history_prefix("docs"),
+            self.slug_segment(slug)
+        )
+    }
+
+    pub fn prompt_key(self, slug: &str) -> String {
+        format!("prompts/{}.yaml", self.slug_segment(slug))
+    }
+
+    pub fn prompt_version_key(self, slug: &str, version: u64) -> String {
+        format!(
+            "{}/{}/{version}.yaml",
+            self.history_prefix("prompts"),
+            self.slug_segment(slug)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layouts_map_slugs_to_keys() {
+        assert_eq!(
+            KeyLayout::Path.document_key("guides/setup"),
+            "docs/guides/setup.md"
+        );
+        assert_eq!(
+            KeyLayout::Path.document_version_key("guides/setup", 3),
+            "history/docs/guides/setup/3.md"
+        );
+        assert_eq!(
+            KeyLayout::Flat.document_key("guides/setup"),
+            "docs/guides_setup.md"
+        );
+        assert_eq!(
+            KeyLayout::Flat.prompt_version_key("review/rfc", 2),
+            "prompts/history/review_rfc/2.yaml"
+        );
+
+        let hashed = KeyLayout::Hashed.document_key("guides/setup");
+        assert!(hashed.starts_with("docs/guides_setup-") && hashed.ends_with(".md"));
+        assert_eq!(hashed.len(), "docs/guides_setup-.md".len() + 16);
+    }
+
+    #[test]
+    fn test_path_and_hashed_layouts_avoid_flat_collisions() {
+        for layout in [KeyLayout::Path, KeyLayout::Hashed] {
+            assert_ne!(layout.document_key("a/b_c"), layout.document_key("a_b/c"));
+            assert_ne!(
+                layout.document_key("history/a/1"),
+                layout.document_version_key("a", 1)
+            );
+        }
+        assert_eq!(
+            KeyLayout::Flat.document_key("a/b_c"),
+            KeyLayout::Flat.document_key("a_b/c")
+        );
+    }
+}
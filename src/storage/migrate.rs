@@ -0,0 +1,257 @@
+//! `lekton migrate-storage-keys`: move document and prompt objects to the
+//! keys of the configured `storage.key_layout` (see [`crate::storage::keys`]).
+//!
+//! Each object is copied to its new key, the record is pointed at it and the
+//! old object is deleted. Archived versions keep their keys (their records
+//! store them). Documents that shared a key under the `flat` layout (e.g.
+//! `a/b_c` and `a_b/c`) all receive the content last written there and are
+//! reported, so they can be re-ingested from their source.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::config::{AppConfig, KeyLayout};
+use crate::db::prompt_repository::PromptRepository;
+use crate::db::repository::DocumentRepository;
+use crate::error::AppError;
+use crate::storage::client::StorageClient;
+
+/// Outcome of a migration (or of a dry run).
+#[derive(Debug, Default)]
+pub struct MigrationReport {
+    /// `(slug, old key, new key)` of every moved document and prompt.
+    pub moved: Vec<(String, String, String)>,
+    /// Records whose object was missing from the bucket.
+    pub missing: Vec<String>,
+    /// Slugs that shared their old key with another document.
+    pub collisions: Vec<Vec<String>>,
+}
+
+/// Move every document (archived ones included) to the key of `layout`.
+pub async fn migrate_document_keys(
+    repo: &dyn DocumentRepository,
+    storage: &dyn StorageClient,
+    layout: KeyLayout,
+    dry_run: bool,
+    report: &mut MigrationReport,
+) -> Result<(), AppError> {
+    let documents = repo.list_all().await?;
+    let mut by_old_key: HashMap<String, Vec<String>> = HashMap::new();
+    for doc in &documents {
+        by_old_key
+            .entry(doc.s3_key.clone())
+            .or_default()
+            .push(doc.slug.clone());
+    }
+    let new_keys: HashSet<String> = documents
+        .iter()
+        .map(|doc| layout.document_key(&doc.slug))
+        .collect();
+
+    for (old_key, mut slugs) in by_old_key {
+        slugs.sort();
+        let moves: Vec<(String, String)> = slugs
+            .iter()
+            .map(|slug| (slug.clone(), layout.document_key(slug)))
+            .filter(|(_, new_key)| *new_key != old_key)
+            .collect();
+        if slugs.len() > 1 {
+            report.collisions.push(slugs.clone());
+        }
+        if moves.is_empty() {
+            continue;
+        }
+
+        let content = if dry_run {
+            None
+        } else {
+            match storage.get_object(&old_key).await? {
+                Some(content) => Some(content),
+                None => {
+                    report.missing.extend(slugs);
+                    continue;
+                }
+            }
+        };
+        for (slug, new_key) in moves {
+            if let Some(content) = &content {
+                storage.put_object(&new_key, content.clone()).await?;
+                repo.set_s3_key(&slug, &new_key).await?;
+            }
+            report.moved.push((slug, old_key.clone(), new_key));
+        }
+        // The old key may be the new key of another document
+        if content.is_some() && !new_keys.contains(&old_key) {
+            storage.delete_object(&old_key).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Move every active prompt to the key of `layout`.
+pub async fn migrate_prompt_keys(
+    repo: &dyn PromptRepository,
+    storage: &dyn StorageClient,
+    layout: KeyLayout,
+    dry_run: bool,
+    report: &mut MigrationReport,
+) -> Result<(), AppError> {
+    for prompt in repo.find_by_slug_prefix("").await? {
+        let new_key = layout.prompt_key(&prompt.slug);
+        if new_key == prompt.s3_key {
+            continue;
+        }
+        let old_key = prompt.s3_key.clone();
+        if !dry_run {
+            let Some(content) = storage.get_object(&old_key).await? else {
+                report.missing.push(prompt.slug);
+                continue;
+            };
+            storage.put_object(&new_key, content).await?;
+            let slug = prompt.slug.clone();
+            repo.create_or_update(crate::db::prompt_models::Prompt {
+                s3_key: new_key.clone(),
+                ..prompt
+            })
+            .await?;
+            storage.delete_object(&old_key).await?;
+            report.moved.push((slug, old_key, new_key));
+        } else {
+            report.moved.push((prompt.slug, old_key, new_key));
+        }
+    }
+    Ok(())
+}
+
+/// Run the migration against the configured MongoDB and bucket and print
+/// the report. Returns whether it completed.
+pub async fn run(dry_run: bool) -> bool {
+    use crate::db::prompt_repository::MongoPromptRepository;
+    use crate::db::repository::MongoDocumentRepository;
+    use crate::storage::client::S3StorageClient;
+
+    let config = match AppConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {e}");
+            return false;
+        }
+    };
+    let layout = config.storage.key_layout;
+
+    let db = match mongodb::Client::with_uri_str(config.database.connection_uri()).await {
+        Ok(client) => client.database(&config.database.name),
+        Err(e) => {
+            eprintln!("Cannot connect to MongoDB: {e}");
+            return false;
+        }
+    };
+    let storage = match S3StorageClient::from_app_config(&config.storage).await {
+        Ok(storage) => storage,
+        Err(e) => {
+            eprintln!("Cannot create the S3 client: {e}");
+            return false;
+        }
+    };
+
+    let mut report = MigrationReport::default();
+    let documents = MongoDocumentRepository::new(&db);
+    let prompts = MongoPromptRepository::new(&db);
+    let result = async {
+        migrate_document_keys(&documents, &storage, layout, dry_run, &mut report).await?;
+        migrate_prompt_keys(&prompts, &storage, layout, dry_run, &mut report).await
+    }
+    .await;
+
+    let verb = if dry_run { "Would move" } else { "Moved" };
+    for (slug, old_key, new_key) in &report.moved {
+        println!("{verb} {slug}: {old_key} -> {new_key}");
+    }
+    for slug in &report.missing {
+        println!("Missing object for {slug}");
+    }
+    for slugs in &report.collisions {
+        println!(
+            "Shared key, re-ingest to restore their content: {}",
+            slugs.join(", ")
+        );
+    }
+    println!(
+        "{verb} {} object(s) to the '{}' layout",
+        report.moved.len(),
+        layout.name()
+    );
+
+    match result {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("Migration failed: {e}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::Document;
+    use crate::testing::{self, InMemoryDocumentRepository, InMemoryStorage};
+
+    fn doc(slug: &str) -> Document {
+        Document {
+            s3_key: KeyLayout::Flat.document_key(slug),
+            access_level: "internal".to_string(),
+            ..testing::document(slug)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_migrate_flat_documents_to_path_layout() {
+        let repo = InMemoryDocumentRepository::with_documents(vec![
+            doc("guides/setup"),
+            doc("a/b_c"),
+            doc("a_b/c"),
+            doc("intro"),
+            doc("ops/runbook"),
+        ]);
+        let storage = InMemoryStorage::new();
+        for (key, content) in [
+            ("docs/guides_setup.md", "setup"),
+            ("docs/a_b_c.md", "abc"),
+            ("docs/intro.md", "intro"),
+        ] {
+            storage.put_object(key, content.into()).await.unwrap();
+        }
+
+        let mut report = MigrationReport::default();
+        migrate_document_keys(&repo, &storage, KeyLayout::Path, true, &mut report)
+            .await
+            .unwrap();
+        assert_eq!(report.moved.len(), 4);
+        assert!(storage
+            .get_object("docs/guides/setup.md")
+            .await
+            .unwrap()
+            .is_none());
+
+        let mut report = MigrationReport::default();
+        migrate_document_keys(&repo, &storage, KeyLayout::Path, false, &mut report)
+            .await
+            .unwrap();
+        assert_eq!(report.moved.len(), 3);
+        assert_eq!(report.missing, vec!["ops/runbook".to_string()]);
+        assert_eq!(
+            report.collisions,
+            vec![vec!["a/b_c".to_string(), "a_b/c".to_string()]]
+        );
+
+        let setup = repo.find_by_slug("guides/setup").await.unwrap().unwrap();
+        assert_eq!(setup.s3_key, "docs/guides/setup.md");
+        assert_eq!(
+            storage.get_object(&setup.s3_key).await.unwrap(),
+            Some(b"setup".to_vec())
+        );
+        assert!(storage.get_object("docs/a/b_c.md").await.unwrap().is_some());
+        assert!(storage.get_object("docs/a_b/c.md").await.unwrap().is_some());
+        assert!(storage.get_object("docs/a_b_c.md").await.unwrap().is_none());
+    }
+}
@@ -1 +1,5 @@
 pub mod client;
+#[cfg(feature = "ssr")]
+pub mod keys;
+#[cfg(feature = "ssr")]
+pub mod migrate;
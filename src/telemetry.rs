@@ -0,0 +1,178 @@
+//! Request tracing: correlation IDs, HTTP spans and optional OTLP export.
+//!
+//! Every request gets an `x-request-id` (the caller's, or a new UUID) that is
+//! echoed in the response, recorded on the request span and included in
+//! problem responses. Downstream calls made while handling the request
+//! (S3 and Meilisearch through [`crate::resilience`], MongoDB commands through
+//! the driver's `mongodb.command` events) are recorded inside that span, so a
+//! failed ingest can be followed end to end. With the `otel` feature and
+//! `telemetry.otlp_endpoint` set, spans are also exported over OTLP/HTTP.
+
+use axum::body::Body;
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use tower_http::classify::{ServerErrorsAsFailures, SharedClassifier};
+use tower_http::trace::TraceLayer;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+use crate::config::TelemetryConfig;
+
+/// Header carrying the request correlation ID.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Correlation ID of the request being handled, if any.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Middleware making the request ID available to [`current_request_id`].
+///
+/// Must run inside `SetRequestIdLayer`, which assigns the ID.
+pub async fn request_id_scope(request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    REQUEST_ID.scope(request_id, next.run(request)).await
+}
+
+type MakeSpan = fn(&Request<Body>) -> tracing::Span;
+
+/// HTTP trace layer recording one span per request, tagged with its ID.
+pub fn trace_layer() -> TraceLayer<SharedClassifier<ServerErrorsAsFailures>, MakeSpan> {
+    TraceLayer::new_for_http().make_span_with(request_span as MakeSpan)
+}
+
+fn request_span(request: &Request<Body>) -> tracing::Span {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    tracing::info_span!(
+        "http_request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        request_id,
+    )
+}
+
+/// Keeps the trace exporter alive; flushes pending spans when dropped.
+pub struct TelemetryGuard {
+    #[cfg(feature = "otel")]
+    provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "otel")]
+        if let Some(provider) = self.provider.take() {
+            if let Err(e) = provider.shutdown() {
+                eprintln!("Failed to flush traces: {e}");
+            }
+        }
+    }
+}
+
+/// Install the global tracing subscriber: `log_filter` (unless `RUST_LOG` is
+/// set), formatted output and, when configured, the OTLP exporter.
+pub fn init(log_filter: &str, config: &TelemetryConfig) -> TelemetryGuard {
+    let filter =
+        tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| log_filter.into());
+
+    #[cfg(feature = "otel")]
+    {
+        let (otel_layer, provider) = match otlp_layer(config) {
+            Ok(Some((layer, provider))) => (Some(layer), Some(provider)),
+            Ok(None) => (None, None),
+            Err(e) => {
+                eprintln!("Failed to initialize OTLP exporter: {e}");
+                (None, None)
+            }
+        };
+        tracing_subscriber::registry()
+            .with(otel_layer)
+            .with(filter)
+            .with(tracing_subscriber::fmt::layer())
+            .init();
+        if provider.is_some() {
+            tracing::info!(endpoint = %config.otlp_endpoint, "Exporting traces over OTLP");
+        }
+        TelemetryGuard { provider }
+    }
+
+    #[cfg(not(feature = "otel"))]
+    {
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(tracing_subscriber::fmt::layer())
+            .init();
+        if !config.otlp_endpoint.is_empty() {
+            tracing::warn!(
+                "telemetry.otlp_endpoint is set but this build lacks the `otel` feature — traces are not exported"
+            );
+        }
+        TelemetryGuard {}
+    }
+}
+
+#[cfg(feature = "otel")]
+type OtlpLayer = tracing_opentelemetry::OpenTelemetryLayer<
+    tracing_subscriber::Registry,
+    opentelemetry_sdk::trace::Tracer,
+>;
+
+#[cfg(feature = "otel")]
+fn otlp_layer(
+    config: &TelemetryConfig,
+) -> Result<Option<(OtlpLayer, opentelemetry_sdk::trace::SdkTracerProvider)>, String> {
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_otlp::WithExportConfig;
+
+    if config.otlp_endpoint.is_empty() {
+        return Ok(None);
+    }
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(&config.otlp_endpoint)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            opentelemetry_sdk::Resource::builder()
+                .with_service_name(config.service_name.clone())
+                .build(),
+        )
+        .build();
+    let tracer = provider.tracer("lekton");
+    opentelemetry::global::set_tracer_provider(provider.clone());
+
+    Ok(Some((
+        tracing_opentelemetry::layer().with_tracer(tracer),
+        provider,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn request_id_is_visible_inside_scope_only() {
+        assert_eq!(current_request_id(), None);
+        let id = REQUEST_ID
+            .scope("req-1".to_string(), async { current_request_id() })
+            .await;
+        assert_eq!(id.as_deref(), Some("req-1"));
+    }
+}
@@ -1,66 +1,40 @@
 //! Shared mock implementations for unit tests.
 //!
 //! This module provides reusable in-memory mocks for the core traits,
-//! eliminating duplication across test modules.
+//! eliminating duplication across test modules. Mocks useful outside this
+//! crate live in [`crate::testing`].
 
 use async_trait::async_trait;
-use std::collections::HashMap;
 use std::sync::Mutex;
 
 use crate::error::AppError;
 
-// ── MockStorage ────────────────────────────────────────────────────────────
-
-/// In-memory mock for [`StorageClient`](crate::storage::client::StorageClient).
-pub struct MockStorage {
-    pub objects: Mutex<HashMap<String, Vec<u8>>>,
-    /// Number of `put_object` calls (for verifying upload was skipped/performed).
-    pub put_count: std::sync::atomic::AtomicU32,
-}
-
-impl MockStorage {
-    pub fn new() -> Self {
-        Self {
-            objects: Mutex::new(HashMap::new()),
-            put_count: std::sync::atomic::AtomicU32::new(0),
-        }
-    }
-}
-
-impl Default for MockStorage {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[async_trait]
-impl crate::storage::client::StorageClient for MockStorage {
-    async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), AppError> {
-        self.put_count
-            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-        self.objects
-            .lock()
-            .unwrap()
-            .insert(key.to_string(), content);
-        Ok(())
-    }
-
-    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
-        Ok(self.objects.lock().unwrap().get(key).cloned())
-    }
-
-    async fn delete_object(&self, key: &str) -> Result<(), AppError> {
-        self.objects.lock().unwrap().remove(key);
-        Ok(())
-    }
-}
-
 // ── MockUserRepository ─────────────────────────────────────────────────────
 
 use crate::db::auth_models::RefreshToken;
 use crate::db::user_repository::UserRepository;
 use chrono::Utc;
 
+/// A user without permissions, identified by `email`.
+pub fn make_user(email: &str) -> crate::db::auth_models::User {
+    crate::db::auth_models::User {
+        id: email.to_string(),
+        email: email.to_string(),
+        name: None,
+        provider_sub: format!("sub-{email}"),
+        provider_type: "oidc".to_string(),
+        is_admin: false,
+        assigned_access_levels: vec![],
+        effective_access_levels: vec![],
+        can_write: false,
+        can_read_draft: false,
+        can_write_draft: false,
+        created_at: Utc::now(),
+        last_login_at: None,
+        password: None,
+    }
+}
+
 /// In-memory mock for [`UserRepository`].
 #[derive(Default)]
 pub struct MockUserRepository {
@@ -175,6 +149,46 @@ impl UserRepository for MockUserRepository {
             .collect())
     }
 
+    async fn set_user_password(
+        &self,
+        user_id: &str,
+        password: crate::db::auth_models::LocalPassword,
+    ) -> Result<(), AppError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .iter_mut()
+            .find(|u| u.id == user_id)
+            .ok_or_else(|| AppError::NotFound(format!("User '{user_id}' not found")))?;
+        user.password = Some(password);
+        Ok(())
+    }
+
+    async fn update_user_profile(
+        &self,
+        user_id: &str,
+        name: Option<String>,
+        is_admin: bool,
+    ) -> Result<(), AppError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .iter_mut()
+            .find(|u| u.id == user_id)
+            .ok_or_else(|| AppError::NotFound(format!("User '{user_id}' not found")))?;
+        user.name = name;
+        user.is_admin = is_admin;
+        Ok(())
+    }
+
+    async fn delete_user(&self, user_id: &str) -> Result<(), AppError> {
+        let mut users = self.users.lock().unwrap();
+        let before = users.len();
+        users.retain(|u| u.id != user_id);
+        if users.len() == before {
+            return Err(AppError::NotFound(format!("User '{user_id}' not found")));
+        }
+        Ok(())
+    }
+
     async fn create_refresh_token(&self, token: RefreshToken) -> Result<(), AppError> {
         self.tokens.lock().unwrap().push(token);
         Ok(())
@@ -213,3 +227,103 @@ impl UserRepository for MockUserRepository {
         Ok(())
     }
 }
+
+// ── MockNotificationRepository ─────────────────────────────────────────────
+
+use crate::db::notification_repository::{Notification, NotificationRepository};
+
+/// In-memory mock for [`NotificationRepository`]; notifications are kept in
+/// creation order.
+#[derive(Default)]
+pub struct MockNotificationRepository {
+    pub notifications: Mutex<Vec<Notification>>,
+}
+
+#[async_trait]
+impl NotificationRepository for MockNotificationRepository {
+    async fn create(&self, notification: Notification) -> Result<(), AppError> {
+        self.notifications.lock().unwrap().push(notification);
+        Ok(())
+    }
+
+    async fn trim(&self, user_id: &str, keep: u64) -> Result<(), AppError> {
+        let mut notifications = self.notifications.lock().unwrap();
+        let owned = notifications
+            .iter()
+            .filter(|n| n.user_id == user_id)
+            .count();
+        let mut excess = owned.saturating_sub(keep as usize);
+        notifications.retain(|n| {
+            if excess > 0 && n.user_id == user_id {
+                excess -= 1;
+                return false;
+            }
+            true
+        });
+        Ok(())
+    }
+
+    async fn delete_older_than(
+        &self,
+        cutoff: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, AppError> {
+        let mut notifications = self.notifications.lock().unwrap();
+        let before = notifications.len();
+        notifications.retain(|n| n.created_at >= cutoff);
+        Ok((before - notifications.len()) as u64)
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: &str,
+        limit: i64,
+    ) -> Result<Vec<Notification>, AppError> {
+        Ok(self
+            .notifications
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|n| n.user_id == user_id)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn count_unread(&self, user_id: &str) -> Result<u64, AppError> {
+        Ok(self
+            .notifications
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|n| n.user_id == user_id && !n.read)
+            .count() as u64)
+    }
+
+    async fn mark_read(&self, user_id: &str, id: Option<&str>) -> Result<(), AppError> {
+        for n in self.notifications.lock().unwrap().iter_mut() {
+            if n.user_id == user_id && id.is_none_or(|id| n.id == id) {
+                n.read = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ── MockMailer ─────────────────────────────────────────────────────────────
+
+/// Records sent emails for [`Mailer`](crate::email::Mailer).
+#[cfg(feature = "ssr")]
+#[derive(Default)]
+pub struct MockMailer {
+    pub sent: Mutex<Vec<crate::email::EmailMessage>>,
+}
+
+#[cfg(feature = "ssr")]
+#[async_trait]
+impl crate::email::Mailer for MockMailer {
+    async fn send(&self, message: &crate::email::EmailMessage) -> Result<(), AppError> {
+        self.sent.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+}
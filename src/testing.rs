@@ -0,0 +1,980 @@
+//! In-memory implementations of the storage, repository and search traits,
+//! for tests that should not need MongoDB, S3 or Meilisearch, and fixtures
+//! of the stored models.
+//!
+//! Built for this crate's unit tests and, with the `testing` feature, for
+//! downstream integrations and server-function tests.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+use crate::db::access_level_repository::{AccessLevelRepository, DEFAULT_ACCESS_LEVELS};
+use crate::db::auth_models::AccessLevelEntity;
+use crate::db::models::{Document, IngestRequest, OwnerUsage, Schema, SchemaVersion};
+use crate::db::repository::DocumentRepository;
+use crate::db::schema_repository::{
+    SchemaListParams, SchemaPage, SchemaRepository, SchemaSort, SchemaVersionRef,
+};
+use crate::db::service_token_models::{scopes_overlap, ServiceToken};
+use crate::db::service_token_repository::ServiceTokenRepository;
+use crate::db::settings_repository::{
+    AppSettings, BrandingSettings, EmbedSettings, FeatureFlags, IncidentMode, IssueTrackerSettings,
+    LdapSettings, NavLinks, OnCallSettings, SearchSettings, SettingsRepository,
+};
+use crate::error::AppError;
+use crate::search::client::{SearchDocument, SearchFilter, SearchHit, SearchService};
+use crate::storage::client::{StorageClient, StoredObject};
+
+// ── InMemoryStorage ────────────────────────────────────────────────────────
+
+/// In-memory [`StorageClient`].
+#[derive(Default)]
+pub struct InMemoryStorage {
+    pub objects: Mutex<HashMap<String, Vec<u8>>>,
+    /// Number of `put_object` calls (for verifying upload was skipped/performed).
+    pub put_count: AtomicU32,
+}
+
+impl InMemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl StorageClient for InMemoryStorage {
+    async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), AppError> {
+        self.put_count.fetch_add(1, Ordering::Relaxed);
+        self.objects
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), content);
+        Ok(())
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+        Ok(self.objects.lock().unwrap().get(key).cloned())
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), AppError> {
+        self.objects.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<StoredObject>, AppError> {
+        Ok(self
+            .objects
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, data)| StoredObject {
+                key: key.clone(),
+                size_bytes: data.len() as u64,
+                last_modified: None,
+            })
+            .collect())
+    }
+}
+
+// ── InMemoryDocumentRepository ─────────────────────────────────────────────
+
+/// In-memory [`DocumentRepository`].
+#[derive(Default)]
+pub struct InMemoryDocumentRepository {
+    pub documents: Mutex<Vec<Document>>,
+}
+
+impl InMemoryDocumentRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A repository holding `documents`.
+    pub fn with_documents(documents: Vec<Document>) -> Self {
+        Self {
+            documents: Mutex::new(documents),
+        }
+    }
+}
+
+#[async_trait]
+impl DocumentRepository for InMemoryDocumentRepository {
+    async fn create_or_update(&self, doc: Document) -> Result<(), AppError> {
+        let mut docs = self.documents.lock().unwrap();
+        docs.retain(|d| d.slug != doc.slug);
+        docs.push(doc);
+        Ok(())
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|d| d.slug == slug)
+            .cloned())
+    }
+
+    async fn find_by_slugs(&self, slugs: &[String]) -> Result<Vec<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| slugs.contains(&d.slug))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_related_to(&self, slug: &str) -> Result<Vec<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| d.relations.slugs().any(|s| s == slug))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_superseding(&self, slugs: &[String]) -> Result<Vec<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| d.relations.supersedes.iter().any(|s| slugs.contains(s)))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_asset_ref(&self, s3_key: &str) -> Result<Vec<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| d.asset_refs.iter().any(|r| r == s3_key))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_s3_key(&self, s3_key: &str) -> Result<Vec<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| d.s3_key == s3_key)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_all(&self) -> Result<Vec<Document>, AppError> {
+        Ok(self.documents.lock().unwrap().clone())
+    }
+
+    async fn list_by_access_levels(
+        &self,
+        allowed_levels: Option<&[String]>,
+        include_draft: bool,
+    ) -> Result<Vec<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| {
+                let level_ok = allowed_levels
+                    .map(|lvls| lvls.contains(&d.access_level))
+                    .unwrap_or(true);
+                let draft_ok = include_draft || !d.is_draft;
+                level_ok && draft_ok
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn update_backlinks(
+        &self,
+        source_slug: &str,
+        old_links: &[String],
+        new_links: &[String],
+    ) -> Result<(), AppError> {
+        let mut docs = self.documents.lock().unwrap();
+
+        let removed: Vec<String> = old_links
+            .iter()
+            .filter(|l| !new_links.contains(l))
+            .cloned()
+            .collect();
+
+        for doc in docs.iter_mut() {
+            if removed.contains(&doc.slug) {
+                doc.backlinks.retain(|b| b != source_slug);
+            }
+        }
+
+        let added: Vec<String> = new_links
+            .iter()
+            .filter(|l| !old_links.contains(l))
+            .cloned()
+            .collect();
+
+        for doc in docs.iter_mut() {
+            if added.contains(&doc.slug) && !doc.backlinks.contains(&source_slug.to_string()) {
+                doc.backlinks.push(source_slug.to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_slug_prefix(&self, prefix: &str) -> Result<Vec<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| {
+                !d.is_archived
+                    && (prefix.is_empty()
+                        || d.slug == prefix
+                        || d.slug.starts_with(&format!("{prefix}/")))
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn set_archived(&self, slug: &str, archived: bool) -> Result<(), AppError> {
+        let mut docs = self.documents.lock().unwrap();
+        if let Some(doc) = docs.iter_mut().find(|d| d.slug == slug) {
+            doc.is_archived = archived;
+        }
+        Ok(())
+    }
+    async fn set_asset_refs(&self, slug: &str, asset_refs: &[String]) -> Result<(), AppError> {
+        let mut docs = self.documents.lock().unwrap();
+        if let Some(doc) = docs.iter_mut().find(|d| d.slug == slug) {
+            doc.asset_refs = asset_refs.to_vec();
+        }
+        Ok(())
+    }
+    async fn set_s3_key(&self, slug: &str, s3_key: &str) -> Result<(), AppError> {
+        let mut docs = self.documents.lock().unwrap();
+        if let Some(doc) = docs.iter_mut().find(|d| d.slug == slug) {
+            doc.s3_key = s3_key.to_string();
+        }
+        Ok(())
+    }
+    async fn find_by_source_path(&self, source_path: &str) -> Result<Option<Document>, AppError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|d| d.source_path.as_deref() == Some(source_path))
+            .cloned())
+    }
+
+    async fn usage_by_service_owner(
+        &self,
+        service_owner: Option<&str>,
+    ) -> Result<Vec<OwnerUsage>, AppError> {
+        let mut usage: Vec<OwnerUsage> = vec![];
+        for doc in self.documents.lock().unwrap().iter() {
+            if doc.is_archived || service_owner.is_some_and(|o| o != doc.service_owner) {
+                continue;
+            }
+            match usage
+                .iter_mut()
+                .find(|u| u.service_owner == doc.service_owner)
+            {
+                Some(u) => {
+                    u.document_count += 1;
+                    u.storage_bytes += doc.content_size;
+                }
+                None => usage.push(OwnerUsage {
+                    service_owner: doc.service_owner.clone(),
+                    document_count: 1,
+                    storage_bytes: doc.content_size,
+                }),
+            }
+        }
+        Ok(usage)
+    }
+}
+
+// ── InMemorySchemaRepository ───────────────────────────────────────────────
+
+/// In-memory [`SchemaRepository`].
+#[derive(Default)]
+pub struct InMemorySchemaRepository {
+    pub schemas: Mutex<Vec<Schema>>,
+}
+
+impl InMemorySchemaRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SchemaRepository for InMemorySchemaRepository {
+    async fn create_or_update(&self, schema: Schema) -> Result<(), AppError> {
+        let mut schemas = self.schemas.lock().unwrap();
+        schemas.retain(|s| s.name != schema.name);
+        schemas.push(schema);
+        Ok(())
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Schema>, AppError> {
+        Ok(self
+            .schemas
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.name == name)
+            .cloned())
+    }
+
+    async fn find_by_name_summary(&self, name: &str) -> Result<Option<Schema>, AppError> {
+        Ok(self
+            .schemas
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.name == name)
+            .cloned()
+            .map(|mut s| {
+                for v in s.versions.iter_mut() {
+                    v.endpoints = vec![];
+                }
+                s
+            }))
+    }
+
+    async fn list_all(&self) -> Result<Vec<Schema>, AppError> {
+        Ok(self.schemas.lock().unwrap().clone())
+    }
+
+    async fn list_summaries(&self) -> Result<Vec<Schema>, AppError> {
+        Ok(self
+            .schemas
+            .lock()
+            .unwrap()
+            .iter()
+            .cloned()
+            .map(|mut s| {
+                for v in s.versions.iter_mut() {
+                    v.endpoints = vec![];
+                }
+                s
+            })
+            .collect())
+    }
+
+    async fn list_page(&self, params: &SchemaListParams) -> Result<SchemaPage, AppError> {
+        let visible: Vec<Schema> = self
+            .list_summaries()
+            .await?
+            .into_iter()
+            .filter(|s| params.visible(s))
+            .collect();
+        let mut service_owners: Vec<String> = visible
+            .iter()
+            .map(|s| s.service_owner.clone())
+            .filter(|o| !o.is_empty())
+            .collect();
+        service_owners.sort();
+        service_owners.dedup();
+        let mut tags: Vec<String> = visible.iter().flat_map(|s| s.tags.clone()).collect();
+        tags.sort();
+        tags.dedup();
+
+        let mut items: Vec<Schema> = visible.into_iter().filter(|s| params.matches(s)).collect();
+        match params.sort {
+            SchemaSort::Name => items.sort_by(|a, b| a.name.cmp(&b.name)),
+            SchemaSort::UpdatedAt => items.sort_by(|a, b| {
+                b.updated_at
+                    .cmp(&a.updated_at)
+                    .then_with(|| a.name.cmp(&b.name))
+            }),
+        }
+        let total = items.len() as u64;
+        if let Some(per_page) = params.per_page {
+            items = items
+                .into_iter()
+                .skip((params.page * per_page) as usize)
+                .take(per_page as usize)
+                .collect();
+        }
+        Ok(SchemaPage {
+            items,
+            total,
+            service_owners,
+            tags,
+        })
+    }
+
+    async fn find_by_name_prefix(&self, prefix: &str) -> Result<Vec<Schema>, AppError> {
+        let schemas = self.schemas.lock().unwrap();
+        Ok(schemas
+            .iter()
+            .filter(|schema| {
+                prefix.is_empty()
+                    || schema.name == prefix
+                    || schema.name.starts_with(&format!("{prefix}/"))
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn add_version(&self, schema_name: &str, version: SchemaVersion) -> Result<(), AppError> {
+        let mut schemas = self.schemas.lock().unwrap();
+        let schema = schemas
+            .iter_mut()
+            .find(|s| s.name == schema_name)
+            .ok_or_else(|| AppError::NotFound(format!("Schema '{}' not found", schema_name)))?;
+
+        if schema.versions.iter().any(|v| v.version == version.version) {
+            return Err(AppError::BadRequest(format!(
+                "Version '{}' already exists",
+                version.version
+            )));
+        }
+
+        schema.versions.push(version);
+        Ok(())
+    }
+
+    async fn set_version_archived(
+        &self,
+        schema_name: &str,
+        version: &str,
+        archived: bool,
+    ) -> Result<(), AppError> {
+        let mut schemas = self.schemas.lock().unwrap();
+        let schema = schemas
+            .iter_mut()
+            .find(|s| s.name == schema_name)
+            .ok_or_else(|| AppError::NotFound(format!("Schema '{}' not found", schema_name)))?;
+        let version = schema
+            .versions
+            .iter_mut()
+            .find(|v| v.version == version)
+            .ok_or_else(|| AppError::NotFound("Version not found".into()))?;
+        version.is_archived = archived;
+        Ok(())
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), AppError> {
+        let mut schemas = self.schemas.lock().unwrap();
+        let len_before = schemas.len();
+        schemas.retain(|s| s.name != name);
+        if schemas.len() == len_before {
+            return Err(AppError::NotFound(format!("Schema '{}' not found", name)));
+        }
+        Ok(())
+    }
+
+    async fn find_version_s3_key(
+        &self,
+        schema_name: &str,
+        version: &str,
+    ) -> Result<Option<SchemaVersionRef>, AppError> {
+        Ok(self
+            .schemas
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.name == schema_name)
+            .and_then(|s| {
+                s.versions
+                    .iter()
+                    .find(|v| v.version == version)
+                    .map(|v| SchemaVersionRef {
+                        s3_key: v.s3_key.clone(),
+                        access_level: v.access_level.clone(),
+                        is_archived: v.is_archived,
+                        owner_access_level: s.owner_access_level.clone(),
+                    })
+            }))
+    }
+}
+
+// ── InMemoryAccessLevelRepository ──────────────────────────────────────────
+
+/// In-memory [`AccessLevelRepository`]. Updates skip the cycle check of the
+/// MongoDB implementation.
+#[derive(Default)]
+pub struct InMemoryAccessLevelRepository {
+    pub levels: Mutex<Vec<AccessLevelEntity>>,
+}
+
+impl InMemoryAccessLevelRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A repository holding the [`DEFAULT_ACCESS_LEVELS`].
+    pub fn with_defaults() -> Self {
+        Self::with_levels(
+            DEFAULT_ACCESS_LEVELS
+                .iter()
+                .map(
+                    |(name, label, description, inherits_from, is_system)| AccessLevelEntity {
+                        name: name.to_string(),
+                        label: label.to_string(),
+                        description: description.to_string(),
+                        inherits_from: inherits_from.iter().map(|s| s.to_string()).collect(),
+                        is_system: *is_system,
+                        created_at: chrono::Utc::now(),
+                    },
+                )
+                .collect(),
+        )
+    }
+
+    /// A repository holding `levels`.
+    pub fn with_levels(levels: Vec<AccessLevelEntity>) -> Self {
+        Self {
+            levels: Mutex::new(levels),
+        }
+    }
+}
+
+#[async_trait]
+impl AccessLevelRepository for InMemoryAccessLevelRepository {
+    async fn create(&self, level: AccessLevelEntity) -> Result<(), AppError> {
+        let mut levels = self.levels.lock().unwrap();
+        if levels.iter().any(|l| l.name == level.name) {
+            return Err(AppError::Conflict(format!(
+                "Access level '{}' already exists",
+                level.name
+            )));
+        }
+        levels.push(level);
+        Ok(())
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<AccessLevelEntity>, AppError> {
+        Ok(self
+            .levels
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|l| l.name == name)
+            .cloned())
+    }
+
+    async fn list_all(&self) -> Result<Vec<AccessLevelEntity>, AppError> {
+        let mut levels = self.levels.lock().unwrap().clone();
+        levels.sort_by(|a, b| b.is_system.cmp(&a.is_system).then(a.name.cmp(&b.name)));
+        Ok(levels)
+    }
+
+    async fn update(&self, level: AccessLevelEntity) -> Result<(), AppError> {
+        let mut levels = self.levels.lock().unwrap();
+        let existing = levels
+            .iter_mut()
+            .find(|l| l.name == level.name)
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Access level '{}' not found", level.name))
+            })?;
+        *existing = level;
+        Ok(())
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), AppError> {
+        let mut levels = self.levels.lock().unwrap();
+        let level = levels
+            .iter()
+            .find(|l| l.name == name)
+            .ok_or_else(|| AppError::NotFound(format!("Access level '{name}' not found")))?;
+        if level.is_system {
+            return Err(AppError::Forbidden(format!(
+                "Cannot delete system access level '{name}'"
+            )));
+        }
+        levels.retain(|l| l.name != name);
+        Ok(())
+    }
+
+    async fn exists(&self, name: &str) -> Result<bool, AppError> {
+        Ok(self.levels.lock().unwrap().iter().any(|l| l.name == name))
+    }
+
+    async fn seed_defaults(&self) -> Result<(), AppError> {
+        let mut levels = self.levels.lock().unwrap();
+        if levels.is_empty() {
+            *levels = Self::with_defaults().levels.into_inner().unwrap();
+        }
+        Ok(())
+    }
+
+    async fn compute_effective_levels(&self, roots: &[String]) -> Result<Vec<String>, AppError> {
+        let levels = self.levels.lock().unwrap();
+        let mut effective: Vec<String> = Vec::new();
+        let mut stack = roots.to_vec();
+        while let Some(name) = stack.pop() {
+            if effective.contains(&name) {
+                continue;
+            }
+            if let Some(level) = levels.iter().find(|l| l.name == name) {
+                stack.extend(level.inherits_from.iter().cloned());
+            }
+            effective.push(name);
+        }
+        Ok(effective)
+    }
+}
+
+// ── InMemoryServiceTokenRepository ─────────────────────────────────────────
+
+/// In-memory [`ServiceTokenRepository`].
+#[derive(Default)]
+pub struct InMemoryServiceTokenRepository {
+    pub tokens: Mutex<Vec<ServiceToken>>,
+}
+
+impl InMemoryServiceTokenRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A repository holding `tokens`.
+    pub fn with_tokens(tokens: Vec<ServiceToken>) -> Self {
+        Self {
+            tokens: Mutex::new(tokens),
+        }
+    }
+
+    fn update(&self, id: &str, apply: impl FnOnce(&mut ServiceToken)) -> Result<(), AppError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let token = tokens
+            .iter_mut()
+            .find(|t| t.id == id)
+            .ok_or_else(|| AppError::NotFound(format!("Service token '{id}' not found")))?;
+        apply(token);
+        Ok(())
+    }
+
+    /// PATs matching `filter`, newest first.
+    fn pats(&self, filter: impl Fn(&ServiceToken) -> bool) -> Vec<ServiceToken> {
+        let mut pats: Vec<ServiceToken> = self
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|t| t.is_pat() && filter(t))
+            .cloned()
+            .collect();
+        pats.sort_by_key(|t| std::cmp::Reverse(t.created_at));
+        pats
+    }
+}
+
+#[async_trait]
+impl ServiceTokenRepository for InMemoryServiceTokenRepository {
+    async fn create(&self, token: ServiceToken) -> Result<(), AppError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        if tokens
+            .iter()
+            .any(|t| t.name == token.name || t.token_hash == token.token_hash)
+        {
+            return Err(AppError::Conflict(format!(
+                "Service token '{}' already exists",
+                token.name
+            )));
+        }
+        tokens.push(token);
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<ServiceToken>, AppError> {
+        Ok(self
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<ServiceToken>, AppError> {
+        Ok(self
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.name == name)
+            .cloned())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<ServiceToken>, AppError> {
+        Ok(self
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.id == id)
+            .cloned())
+    }
+
+    async fn list_all(&self) -> Result<Vec<ServiceToken>, AppError> {
+        let mut tokens = self.tokens.lock().unwrap().clone();
+        tokens.sort_by_key(|t| std::cmp::Reverse(t.created_at));
+        Ok(tokens)
+    }
+
+    async fn deactivate(&self, id: &str) -> Result<(), AppError> {
+        self.update(id, |t| t.is_active = false)
+    }
+
+    async fn touch_last_used(&self, id: &str) -> Result<(), AppError> {
+        // Unknown ids are ignored, like the MongoDB update
+        let _ = self.update(id, |t| t.last_used_at = Some(chrono::Utc::now()));
+        Ok(())
+    }
+
+    async fn check_scope_overlap(
+        &self,
+        scopes: &[String],
+        exclude_id: Option<&str>,
+    ) -> Result<bool, AppError> {
+        Ok(self.tokens.lock().unwrap().iter().any(|t| {
+            t.is_active
+                && !t.is_bot()
+                && exclude_id != Some(t.id.as_str())
+                && scopes_overlap(scopes, &t.allowed_scopes)
+        }))
+    }
+
+    async fn set_active(&self, id: &str, active: bool) -> Result<(), AppError> {
+        self.update(id, |t| t.is_active = active)
+    }
+
+    async fn list_by_user_id(&self, user_id: &str) -> Result<Vec<ServiceToken>, AppError> {
+        Ok(self.pats(|t| t.user_id.as_deref() == Some(user_id)))
+    }
+
+    async fn list_pats_paginated(
+        &self,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<ServiceToken>, u64), AppError> {
+        let pats = self.pats(|_| true);
+        let total = pats.len() as u64;
+        let page = pats
+            .into_iter()
+            .skip((page.saturating_sub(1) * per_page) as usize)
+            .take(per_page as usize)
+            .collect();
+        Ok((page, total))
+    }
+
+    async fn delete_pat(&self, id: &str, user_id: &str) -> Result<(), AppError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let token = tokens
+            .iter()
+            .find(|t| t.id == id && t.is_pat())
+            .ok_or_else(|| AppError::NotFound(format!("PAT '{id}' not found")))?;
+        if token.user_id.as_deref() != Some(user_id) {
+            return Err(AppError::Forbidden("You do not own this token".into()));
+        }
+        tokens.retain(|t| t.id != id);
+        Ok(())
+    }
+}
+
+// ── InMemorySettingsRepository ─────────────────────────────────────────────
+
+/// In-memory [`SettingsRepository`].
+#[derive(Default)]
+pub struct InMemorySettingsRepository {
+    pub settings: Mutex<AppSettings>,
+}
+
+impl InMemorySettingsRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A repository holding `settings`.
+    pub fn with_settings(settings: AppSettings) -> Self {
+        Self {
+            settings: Mutex::new(settings),
+        }
+    }
+
+    fn update(&self, apply: impl FnOnce(&mut AppSettings)) -> Result<(), AppError> {
+        apply(&mut self.settings.lock().unwrap());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SettingsRepository for InMemorySettingsRepository {
+    async fn get_settings(&self) -> Result<AppSettings, AppError> {
+        Ok(self.settings.lock().unwrap().clone())
+    }
+
+    async fn set_custom_css(&self, css: &str) -> Result<(), AppError> {
+        self.update(|s| s.custom_css = css.to_string())
+    }
+
+    async fn set_branding(&self, branding: &BrandingSettings) -> Result<(), AppError> {
+        self.update(|s| s.branding = branding.clone())
+    }
+
+    async fn set_nav_links(&self, links: &NavLinks) -> Result<(), AppError> {
+        self.update(|s| s.nav_links = links.clone())
+    }
+
+    async fn set_search_settings(&self, search: &SearchSettings) -> Result<(), AppError> {
+        self.update(|s| s.search = search.clone())
+    }
+
+    async fn set_protected_prefixes(&self, prefixes: &[String]) -> Result<(), AppError> {
+        self.update(|s| s.protected_prefixes = prefixes.to_vec())
+    }
+
+    async fn set_feature_flags(&self, features: &FeatureFlags) -> Result<(), AppError> {
+        self.update(|s| s.features = features.clone())
+    }
+
+    async fn set_incident_mode(&self, incident_mode: &IncidentMode) -> Result<(), AppError> {
+        self.update(|s| s.incident_mode = incident_mode.clone())
+    }
+
+    async fn set_on_call(&self, on_call: &OnCallSettings) -> Result<(), AppError> {
+        self.update(|s| s.on_call = on_call.clone())
+    }
+
+    async fn set_issue_tracker(
+        &self,
+        issue_tracker: &IssueTrackerSettings,
+    ) -> Result<(), AppError> {
+        self.update(|s| s.issue_tracker = issue_tracker.clone())
+    }
+
+    async fn set_ldap(&self, ldap: &LdapSettings) -> Result<(), AppError> {
+        self.update(|s| s.ldap = ldap.clone())
+    }
+
+    async fn set_embeds(&self, embeds: &EmbedSettings) -> Result<(), AppError> {
+        self.update(|s| s.embeds = Some(embeds.clone()))
+    }
+}
+
+// ── NoopSearchService ──────────────────────────────────────────────────────
+
+/// [`SearchService`] that indexes nothing and finds nothing.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopSearchService;
+
+#[async_trait]
+impl SearchService for NoopSearchService {
+    async fn index_document(&self, _: &SearchDocument) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    async fn delete_document(&self, _: &str) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _: &str,
+        _: Option<&[String]>,
+        _: bool,
+        _: &SearchFilter,
+    ) -> Result<Vec<SearchHit>, AppError> {
+        Ok(vec![])
+    }
+
+    async fn configure_index(&self, _: &SearchSettings) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    async fn list_indexed_documents(&self) -> Result<Vec<SearchDocument>, AppError> {
+        Ok(vec![])
+    }
+}
+
+// ── Fixtures ───────────────────────────────────────────────────────────────
+
+/// A published public document at `slug`, stored under `docs/{slug}.md` and
+/// updated now. Tests set the fields they care about with struct update
+/// syntax: `Document { access_level: "internal".into(), ..document("a") }`.
+pub fn document(slug: &str) -> Document {
+    Document {
+        slug: slug.to_string(),
+        title: slug.to_string(),
+        s3_key: format!("docs/{slug}.md"),
+        access_level: "public".to_string(),
+        service_owner: "team".to_string(),
+        last_updated: chrono::Utc::now(),
+        ..Default::default()
+    }
+}
+
+/// An OpenAPI schema `name` owned by `team`, with `versions`.
+pub fn schema(name: &str, versions: Vec<SchemaVersion>) -> Schema {
+    Schema {
+        name: name.to_string(),
+        schema_type: "openapi".to_string(),
+        service_owner: "team".to_string(),
+        tags: vec![],
+        description: None,
+        contact: None,
+        repository_url: None,
+        owner_access_level: None,
+        updated_at: None,
+        versions,
+    }
+}
+
+/// A stable public version of schema `name`, without endpoints.
+pub fn schema_version(name: &str, version: &str) -> SchemaVersion {
+    SchemaVersion {
+        version: version.to_string(),
+        s3_key: format!("schemas/{name}/{version}.json"),
+        status: "stable".to_string(),
+        access_level: "public".to_string(),
+        content_hash: None,
+        metadata_hash: None,
+        is_archived: false,
+        endpoints: vec![],
+    }
+}
+
+/// An ingest of a public document at `slug` from `{slug}.md`, without a
+/// service token.
+pub fn ingest_request(slug: &str) -> IngestRequest {
+    IngestRequest {
+        service_token: String::new(),
+        slug: slug.to_string(),
+        title: slug.to_string(),
+        summary: None,
+        content: format!("# {slug}"),
+        access_level: "public".to_string(),
+        is_draft: false,
+        service_owner: "team".to_string(),
+        tags: vec![],
+        parent_slug: None,
+        order: 0,
+        is_hidden: false,
+        noindex: false,
+        edit_access_level: None,
+        relations: Default::default(),
+        source_path: format!("{slug}.md"),
+        expected_last_updated: None,
+        expected_content_hash: None,
+    }
+}
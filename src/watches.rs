@@ -0,0 +1,331 @@
+//! Notifications for watched documents.
+//!
+//! Users watch a document or a subtree ([`Watch`]). Every document created,
+//! updated or archived on this replica is matched against the watches, and
+//! each watcher that may read the document gets:
+//!
+//! - an entry in their in-app inbox ([`Inbox`]),
+//! - an email, when the watch asks for it and email is configured,
+//! - a JSON `POST` to the watch's webhook URL, when set.
+//!
+//! Users are not notified of their own changes. Only events published by
+//! this replica are handled ([`ActivityEvents::subscribe_local`]), so each
+//! change is notified once however many replicas run.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+use crate::api::events::{ActivityEvent, ActivityEvents, ActivityKind};
+use crate::auth::models::{AuthenticatedUser, UserContext};
+use crate::db::auth_models::User;
+use crate::db::notification_repository::Notification;
+use crate::db::user_repository::UserRepository;
+use crate::db::watch_repository::{Watch, WatchRepository};
+use crate::email::EmailNotifier;
+use crate::error::AppError;
+use crate::notifications::Inbox;
+
+/// How long a watch webhook may take to answer.
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// Body of watch webhook requests.
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    /// Human-readable summary, for chat webhooks.
+    text: String,
+    event: &'a str,
+    slug: &'a str,
+    title: &'a str,
+    actor: &'a str,
+    watch_target: &'a str,
+    timestamp: chrono::DateTime<Utc>,
+}
+
+/// Whether `user` made the change (matched by email or display name).
+fn is_actor(user: &User, actor: &str) -> bool {
+    user.email.eq_ignore_ascii_case(actor) || user.name.as_deref() == Some(actor)
+}
+
+/// Whether `user` may read the document of `event`.
+fn can_read(user: &User, event: &ActivityEvent) -> bool {
+    let auth_user = AuthenticatedUser {
+        user_id: user.id.clone(),
+        email: user.email.clone(),
+        name: user.name.clone(),
+        is_admin: user.is_admin,
+    };
+    let (allowed_levels, include_draft) =
+        UserContext::from_user_doc(auth_user, user).document_visibility();
+    event.visible_to(allowed_levels.as_deref(), include_draft)
+}
+
+/// Matches document changes against watches and notifies the watchers.
+#[derive(Clone)]
+pub struct WatchNotifier {
+    watch_repo: Arc<dyn WatchRepository>,
+    inbox: Arc<Inbox>,
+    user_repo: Arc<dyn UserRepository>,
+    email: Option<Arc<EmailNotifier>>,
+    http: reqwest::Client,
+}
+
+impl WatchNotifier {
+    pub fn new(
+        watch_repo: Arc<dyn WatchRepository>,
+        inbox: Arc<Inbox>,
+        user_repo: Arc<dyn UserRepository>,
+        email: Option<Arc<EmailNotifier>>,
+    ) -> Self {
+        Self {
+            watch_repo,
+            inbox,
+            user_repo,
+            email,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Handle the events published on this replica until `events` closes.
+    pub fn spawn(self, events: &ActivityEvents) {
+        let mut receiver = events.subscribe_local();
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => {
+                        if let Err(e) = self.notify(&event).await {
+                            tracing::warn!(slug = %event.target, "Failed to notify watchers: {e}");
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(skipped, "Watch notifications skipped events");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Notify the watchers of the document changed by `event`. Returns the
+    /// number of users notified.
+    pub async fn notify(&self, event: &ActivityEvent) -> Result<usize, AppError> {
+        if !matches!(
+            event.kind,
+            ActivityKind::DocumentCreated
+                | ActivityKind::DocumentUpdated
+                | ActivityKind::DocumentArchived
+        ) {
+            return Ok(0);
+        }
+
+        let mut by_user: BTreeMap<String, Vec<Watch>> = BTreeMap::new();
+        for watch in self.watch_repo.list_matching(&event.target).await? {
+            by_user
+                .entry(watch.user_id.clone())
+                .or_default()
+                .push(watch);
+        }
+
+        let mut notified = 0;
+        for (user_id, watches) in by_user {
+            let Some(user) = self.user_repo.find_user_by_id(&user_id).await? else {
+                continue;
+            };
+            if is_actor(&user, &event.actor) || !can_read(&user, event) {
+                continue;
+            }
+            self.notify_user(&user, &watches, event).await?;
+            notified += 1;
+        }
+        Ok(notified)
+    }
+
+    async fn notify_user(
+        &self,
+        user: &User,
+        watches: &[Watch],
+        event: &ActivityEvent,
+    ) -> Result<(), AppError> {
+        // Report the most specific matching watch
+        let watch_target = watches
+            .iter()
+            .map(|w| w.target.as_str())
+            .max_by_key(|target| target.len())
+            .unwrap_or_default();
+        let mut notification = Notification::new(
+            &user.id,
+            event.kind.as_str(),
+            &event.target,
+            event.title.as_deref().unwrap_or(&event.target),
+            &event.actor,
+        );
+        notification.watch_target = Some(watch_target.to_string());
+        self.inbox.deliver(notification.clone()).await?;
+
+        if let Some(email) = self
+            .email
+            .as_ref()
+            .filter(|_| watches.iter().any(|w| w.email))
+        {
+            if let Err(e) = email.send_watch_notice(&user.email, &notification).await {
+                tracing::warn!(user = %user.email, "Failed to email watch notice: {e}");
+            }
+        }
+
+        let mut webhooks: Vec<&str> = watches
+            .iter()
+            .filter_map(|w| w.webhook_url.as_deref())
+            .collect();
+        webhooks.sort_unstable();
+        webhooks.dedup();
+        for url in webhooks {
+            let payload = WebhookPayload {
+                text: notification.text(),
+                event: &notification.kind,
+                slug: &notification.slug,
+                title: &notification.title,
+                actor: &notification.actor,
+                watch_target,
+                timestamp: event.timestamp,
+            };
+            if let Err(e) = self
+                .http
+                .post(url)
+                .timeout(std::time::Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+                .json(&payload)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+            {
+                tracing::warn!(user = %user.email, "Watch webhook failed: {e}");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::EmailConfig;
+    use crate::config::NotificationConfig;
+    use crate::test_utils::{
+        make_user, MockMailer, MockNotificationRepository, MockUserRepository,
+    };
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockWatchRepo {
+        watches: Mutex<Vec<Watch>>,
+    }
+
+    #[async_trait::async_trait]
+    impl WatchRepository for MockWatchRepo {
+        async fn upsert(&self, watch: Watch) -> Result<(), AppError> {
+            self.watches.lock().unwrap().push(watch);
+            Ok(())
+        }
+
+        async fn list_for_user(&self, user_id: &str) -> Result<Vec<Watch>, AppError> {
+            Ok(self
+                .watches
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| w.user_id == user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_matching(&self, slug: &str) -> Result<Vec<Watch>, AppError> {
+            let targets = crate::db::watch_repository::matching_targets(slug);
+            Ok(self
+                .watches
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| targets.contains(&w.target))
+                .cloned()
+                .collect())
+        }
+
+        async fn delete(&self, _user_id: &str, _id: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    fn watch(user_id: &str, target: &str, email: bool) -> Watch {
+        Watch {
+            id: format!("{user_id}:{target}"),
+            user_id: user_id.to_string(),
+            target: target.to_string(),
+            email,
+            webhook_url: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notify_watchers() {
+        let users = MockUserRepository::default();
+        let mut ops = make_user("ops@example.com");
+        ops.effective_access_levels = vec!["internal".to_string()];
+        users.users.lock().unwrap().extend([
+            ops,
+            make_user("guest@example.com"),
+            make_user("ci@example.com"),
+        ]);
+        let watches = MockWatchRepo::default();
+        watches.watches.lock().unwrap().extend([
+            watch("ops@example.com", "runbooks/*", false),
+            watch("ops@example.com", "runbooks/db", true),
+            watch("guest@example.com", "runbooks/*", true),
+            watch("ci@example.com", "runbooks/*", true),
+            watch("ops@example.com", "guides/*", true),
+        ]);
+        let users = Arc::new(users);
+        let notifications = Arc::new(MockNotificationRepository::default());
+        let inbox = Inbox::new(
+            notifications.clone(),
+            users.clone(),
+            NotificationConfig::default(),
+        );
+        let mailer = Arc::new(MockMailer::default());
+        let email = EmailNotifier::new(mailer.clone(), EmailConfig::default()).unwrap();
+        let notifier = WatchNotifier::new(
+            Arc::new(watches),
+            Arc::new(inbox),
+            users,
+            Some(Arc::new(email)),
+        );
+
+        let event = ActivityEvent {
+            kind: ActivityKind::DocumentUpdated,
+            target: "runbooks/db".into(),
+            version: None,
+            title: Some("Database".into()),
+            access_level: "internal".into(),
+            owner_access_level: None,
+            is_draft: false,
+            actor: "ci@example.com".into(),
+            timestamp: Utc::now(),
+        };
+        // The guest can't read internal documents and ci made the change
+        assert_eq!(notifier.notify(&event).await.unwrap(), 1);
+
+        let stored = notifications.notifications.lock().unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].user_id, "ops@example.com");
+        assert_eq!(stored[0].watch_target.as_deref(), Some("runbooks/db"));
+        assert_eq!(
+            stored[0].text(),
+            "ci@example.com updated runbooks/db (\"Database\")"
+        );
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, ["ops@example.com"]);
+    }
+}
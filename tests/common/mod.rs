@@ -1,45 +1,73 @@
 use std::sync::Arc;
 
-use axum::routing::{get, post};
+use axum::routing::{get, patch, post};
 use axum::Router;
+use leptos_axum::{generate_route_list, LeptosRoutes};
 use testcontainers::runners::AsyncRunner;
 use testcontainers::ContainerAsync;
 use testcontainers_modules::meilisearch::Meilisearch;
 use testcontainers_modules::minio::MinIO;
 use testcontainers_modules::mongo::Mongo;
 
+use lekton::api::events::ActivityEvents;
 use lekton::app::AppState;
 use lekton::auth::models::AuthenticatedUser;
 use lekton::auth::token_service::TokenService;
 use lekton::db::access_level_repository::{AccessLevelRepository, MongoAccessLevelRepository};
+use lekton::db::asset_download_repository::{
+    AssetDownloadRepository, MongoAssetDownloadRepository,
+};
 use lekton::db::asset_repository::{AssetRepository, MongoAssetRepository};
 use lekton::db::auth_models::User;
+use lekton::db::bundle_repository::{BundleRepository, MongoBundleRepository};
+use lekton::db::changelog_repository::{ChangelogRepository, MongoChangelogRepository};
+use lekton::db::collection_repository::{CollectionRepository, MongoCollectionRepository};
+use lekton::db::contract_test_repository::{ContractTestRepository, MongoContractTestRepository};
+use lekton::db::doc_rating_repository::{DocRatingRepository, MongoDocRatingRepository};
 use lekton::db::document_version_repository::{
     DocumentVersionRepository, MongoDocumentVersionRepository,
 };
 use lekton::db::documentation_feedback_repository::{
     DocumentationFeedbackRepository, MongoDocumentationFeedbackRepository,
 };
+use lekton::db::edit_lock_repository::{EditLockRepository, MongoEditLockRepository};
+use lekton::db::glossary_repository::{GlossaryRepository, MongoGlossaryRepository};
+use lekton::db::job_run_repository::{JobRunRepository, MongoJobRunRepository};
 use lekton::db::navigation_order_repository::{
     MongoNavigationOrderRepository, NavigationOrderRepository,
 };
+use lekton::db::notification_repository::{MongoNotificationRepository, NotificationRepository};
+use lekton::db::pending_ingest_repository::{
+    MongoPendingIngestRepository, PendingIngestRepository,
+};
+use lekton::db::project_repository::{MongoProjectRepository, ProjectRepository};
 use lekton::db::prompt_repository::{MongoPromptRepository, PromptRepository};
 use lekton::db::prompt_version_repository::{
     MongoPromptVersionRepository, PromptVersionRepository,
 };
+use lekton::db::quarantine_repository::{MongoQuarantineRepository, QuarantineRepository};
 use lekton::db::repository::{DocumentRepository, MongoDocumentRepository};
 use lekton::db::schema_repository::{MongoSchemaRepository, SchemaRepository};
 use lekton::db::service_token_repository::{MongoServiceTokenRepository, ServiceTokenRepository};
 use lekton::db::settings_repository::{MongoSettingsRepository, SettingsRepository};
+use lekton::db::share_link_repository::{MongoShareLinkRepository, ShareLinkRepository};
+use lekton::db::spelling_dictionary_repository::{
+    MongoSpellingDictionaryRepository, SpellingDictionaryRepository,
+};
+use lekton::db::upload_usage_repository::{MongoUploadUsageRepository, UploadUsageRepository};
 use lekton::db::user_prompt_preference_repository::{
     MongoUserPromptPreferenceRepository, UserPromptPreferenceRepository,
 };
 use lekton::db::user_repository::{MongoUserRepository, UserRepository};
+use lekton::db::watch_repository::{MongoWatchRepository, WatchRepository};
 use lekton::search::client::{MeilisearchService, SearchService};
 use lekton::storage::client::{S3StorageClient, StorageClient};
 
 /// Holds running containers and provides the Axum router for integration tests.
 ///
+/// The router serves the API routes and the Leptos SSR pages (with their
+/// server functions), like the production binary minus static files.
+///
 /// Containers are kept alive for as long as this struct lives. When dropped,
 /// containers are stopped and cleaned up automatically.
 pub struct TestEnv {
@@ -60,6 +88,24 @@ pub struct TestEnv {
     pub user_prompt_preference_repo: Arc<dyn UserPromptPreferenceRepository>,
     pub navigation_order_repo: Arc<dyn NavigationOrderRepository>,
     pub documentation_feedback_repo: Arc<dyn DocumentationFeedbackRepository>,
+    pub glossary_repo: Arc<dyn GlossaryRepository>,
+    pub spelling_dictionary_repo: Arc<dyn SpellingDictionaryRepository>,
+    pub changelog_repo: Arc<dyn ChangelogRepository>,
+    pub contract_test_repo: Arc<dyn ContractTestRepository>,
+    pub bundle_repo: Arc<dyn BundleRepository>,
+    pub project_repo: Arc<dyn ProjectRepository>,
+    pub edit_lock_repo: Arc<dyn EditLockRepository>,
+    pub pending_ingest_repo: Arc<dyn PendingIngestRepository>,
+    pub job_run_repo: Arc<dyn JobRunRepository>,
+    pub watch_repo: Arc<dyn WatchRepository>,
+    pub notification_repo: Arc<dyn NotificationRepository>,
+    pub asset_download_repo: Arc<dyn AssetDownloadRepository>,
+    pub quarantine_repo: Arc<dyn QuarantineRepository>,
+    pub share_link_repo: Arc<dyn ShareLinkRepository>,
+    pub collection_repo: Arc<dyn CollectionRepository>,
+    pub doc_rating_repo: Arc<dyn DocRatingRepository>,
+    pub upload_usage_repo: Arc<dyn UploadUsageRepository>,
+    pub activity_events: ActivityEvents,
     pub storage: Arc<dyn StorageClient>,
     pub search: Arc<dyn SearchService>,
     pub token_service: Arc<TokenService>,
@@ -111,6 +157,40 @@ impl TestEnv {
             Arc::new(MongoNavigationOrderRepository::new(&mongo_db));
         let documentation_feedback_repo: Arc<dyn DocumentationFeedbackRepository> =
             Arc::new(MongoDocumentationFeedbackRepository::new(&mongo_db));
+        let glossary_repo: Arc<dyn GlossaryRepository> =
+            Arc::new(MongoGlossaryRepository::new(&mongo_db));
+        let spelling_dictionary_repo: Arc<dyn SpellingDictionaryRepository> =
+            Arc::new(MongoSpellingDictionaryRepository::new(&mongo_db));
+        let changelog_repo: Arc<dyn ChangelogRepository> =
+            Arc::new(MongoChangelogRepository::new(&mongo_db));
+        let contract_test_repo: Arc<dyn ContractTestRepository> =
+            Arc::new(MongoContractTestRepository::new(&mongo_db));
+        let bundle_repo: Arc<dyn BundleRepository> =
+            Arc::new(MongoBundleRepository::new(&mongo_db));
+        let project_repo: Arc<dyn ProjectRepository> =
+            Arc::new(MongoProjectRepository::new(&mongo_db));
+        let activity_events = ActivityEvents::new();
+        let edit_lock_repo: Arc<dyn EditLockRepository> =
+            Arc::new(MongoEditLockRepository::new(&mongo_db));
+        let pending_ingest_repo: Arc<dyn PendingIngestRepository> =
+            Arc::new(MongoPendingIngestRepository::new(&mongo_db));
+        let job_run_repo: Arc<dyn JobRunRepository> =
+            Arc::new(MongoJobRunRepository::new(&mongo_db));
+        let watch_repo: Arc<dyn WatchRepository> = Arc::new(MongoWatchRepository::new(&mongo_db));
+        let notification_repo: Arc<dyn NotificationRepository> =
+            Arc::new(MongoNotificationRepository::new(&mongo_db));
+        let asset_download_repo: Arc<dyn AssetDownloadRepository> =
+            Arc::new(MongoAssetDownloadRepository::new(&mongo_db));
+        let quarantine_repo: Arc<dyn QuarantineRepository> =
+            Arc::new(MongoQuarantineRepository::new(&mongo_db));
+        let share_link_repo: Arc<dyn ShareLinkRepository> =
+            Arc::new(MongoShareLinkRepository::new(&mongo_db));
+        let collection_repo: Arc<dyn CollectionRepository> =
+            Arc::new(MongoCollectionRepository::new(&mongo_db));
+        let doc_rating_repo: Arc<dyn DocRatingRepository> =
+            Arc::new(MongoDocRatingRepository::new(&mongo_db));
+        let upload_usage_repo: Arc<dyn UploadUsageRepository> =
+            Arc::new(MongoUploadUsageRepository::new(&mongo_db));
         access_level_repo
             .seed_defaults()
             .await
@@ -159,7 +239,7 @@ impl TestEnv {
         let meili_service = MeilisearchService::new(&meili_url, None::<String>)
             .expect("Failed to create MeilisearchService");
         meili_service
-            .configure_index()
+            .configure_index(&Default::default())
             .await
             .expect("Failed to configure Meilisearch index");
         let search: Arc<dyn SearchService> = Arc::new(meili_service);
@@ -174,6 +254,11 @@ impl TestEnv {
             3600,
             30,
         ));
+        let inbox = Arc::new(lekton::notifications::Inbox::new(
+            notification_repo.clone(),
+            user_repo.clone(),
+            Default::default(),
+        ));
 
         let app_state = AppState {
             document_repo: repo.clone(),
@@ -189,6 +274,8 @@ impl TestEnv {
             prompt_version_repo: prompt_version_repo.clone(),
             user_prompt_preference_repo: user_prompt_preference_repo.clone(),
             demo_mode: true,
+            local_accounts: true,
+            ldap: None,
             leptos_options,
             user_repo: user_repo.clone(),
             access_level_repo: access_level_repo.clone(),
@@ -196,7 +283,11 @@ impl TestEnv {
             token_service: token_service.clone(),
             auth_provider: None,
             insecure_cookies: true,
+            demo_session: lekton::auth::demo_auth::DemoSessionConfig::default(),
             max_attachment_size_bytes: 5242880,
+            max_render_bytes: lekton::rendering::limits::DEFAULT_MAX_RENDER_BYTES,
+            render_pipeline: Arc::new(lekton::rendering::pipeline::RenderPipeline::standard()),
+            asset_refs_ready: Arc::new(true.into()),
             rag_service: None,
             reindex_state: None,
             search_reindex_state: None,
@@ -204,16 +295,82 @@ impl TestEnv {
             chat_service: None,
             feedback_repo: None,
             documentation_feedback_repo: documentation_feedback_repo.clone(),
+            glossary_repo: glossary_repo.clone(),
+            spelling_dictionary_repo: spelling_dictionary_repo.clone(),
+            changelog_repo: changelog_repo.clone(),
+            contract_test_repo: contract_test_repo.clone(),
+            bundle_repo: bundle_repo.clone(),
+            project_repo: project_repo.clone(),
+            edit_lock_repo: edit_lock_repo.clone(),
+            pending_ingest_repo: pending_ingest_repo.clone(),
+            job_run_repo: job_run_repo.clone(),
+            watch_repo: watch_repo.clone(),
+            notification_repo: notification_repo.clone(),
+            asset_download_repo: asset_download_repo.clone(),
+            quarantine_repo: quarantine_repo.clone(),
+            share_link_repo: share_link_repo.clone(),
+            collection_repo: collection_repo.clone(),
+            doc_rating_repo: doc_rating_repo.clone(),
+            upload_usage_repo: upload_usage_repo.clone(),
+            activity_events: activity_events.clone(),
             embedding_cache_repo: None,
             schema_endpoint_reindex_state: Arc::new(
                 lekton::schema::reindex::SchemaEndpointReindexState::default(),
             ),
+            github: None,
+            try_it: None,
+            grafana: None,
+            spec_viewer_assets: None,
+            circuit_breakers: vec![],
+            quotas: Arc::new(Default::default()),
+            taxonomy: Arc::new(Default::default()),
+            lint: Arc::new(Default::default()),
+            share_links: Arc::new(Default::default()),
+            embed: Arc::new(Default::default()),
+            scheduler: Arc::new(Default::default()),
+            email: None,
+            mentions: Some(Arc::new(lekton::mentions::MentionNotifier::new(
+                user_repo.clone(),
+                inbox.clone(),
+                None,
+                Default::default(),
+            ))),
+            inbox: inbox.clone(),
+            upload_scanner: None,
+            spell_checker: None,
+            secret_guard: None,
+            on_call: Arc::new(lekton::on_call::OnCallDirectory::new()),
+            issue_tracker: Arc::new(lekton::issue_tracker::IssueTracker::new()),
+            job_queue: Arc::new(lekton::jobs::LocalJobQueue::new(
+                lekton::jobs::JobRunner::new(access_level_repo.clone(), user_repo.clone()),
+            )),
         };
 
-        // --- Build Router (API routes only, no Leptos SSR) ---
+        lekton::watches::WatchNotifier::new(watch_repo.clone(), inbox, user_repo.clone(), None)
+            .spawn(&activity_events);
+
+        // --- Build Router (API routes and Leptos SSR pages) ---
+        let leptos_routes = generate_route_list(lekton::app::App);
         let router = Router::new()
             .route("/api/v1/ingest", post(lekton::api::ingest::ingest_handler))
+            .route(
+                "/api/v1/docs/events",
+                get(lekton::api::events::document_events_handler),
+            )
+            .route(
+                "/api/v1/events/stream",
+                get(lekton::api::events::activity_stream_handler),
+            )
             .route("/api/v1/search", get(lekton::api::search::search_handler))
+            .route("/api/v1/bot/search", get(lekton::api::bot::search_handler))
+            .route(
+                "/api/v1/bot/docs/{*slug}",
+                get(lekton::api::bot::get_document_handler),
+            )
+            .route(
+                "/api/v1/bot/relations/{*slug}",
+                get(lekton::api::bot::get_relations_handler),
+            )
             .route(
                 "/api/v1/upload-image",
                 post(lekton::api::upload::upload_image_handler),
@@ -240,6 +397,14 @@ impl TestEnv {
                 post(lekton::api::assets::editor_upload_asset_handler),
             )
             .route("/api/v1/sync", post(lekton::api::sync::sync_handler))
+            .route(
+                "/api/v1/changelog",
+                post(lekton::api::changelog::changelog_handler),
+            )
+            .route(
+                "/api/v1/contract-tests",
+                post(lekton::api::contract_tests::contract_test_handler),
+            )
             .route(
                 "/api/v1/prompts/ingest",
                 post(lekton::api::prompts::prompt_ingest_handler),
@@ -271,11 +436,18 @@ impl TestEnv {
             )
             .route(
                 "/api/v1/admin/users",
-                get(lekton::api::admin::list_users_handler),
+                get(lekton::api::admin::list_users_handler)
+                    .post(lekton::api::admin::create_user_handler),
             )
             .route(
                 "/api/v1/admin/users/{user_id}",
-                get(lekton::api::admin::get_user_handler),
+                get(lekton::api::admin::get_user_handler)
+                    .put(lekton::api::admin::update_user_handler)
+                    .delete(lekton::api::admin::delete_user_handler),
+            )
+            .route(
+                "/api/v1/admin/users/{user_id}/password",
+                axum::routing::put(lekton::api::admin::reset_user_password_handler),
             )
             .route(
                 "/api/v1/admin/users/{user_id}/access-levels",
@@ -290,10 +462,96 @@ impl TestEnv {
                 "/api/v1/admin/service-tokens/{id}",
                 axum::routing::delete(lekton::api::admin::deactivate_service_token_handler),
             )
+            .route(
+                "/api/v1/admin/usage",
+                get(lekton::api::admin::owner_usage_handler),
+            )
+            .route(
+                "/api/v1/documents:batch",
+                patch(lekton::api::documents::batch_update_handler),
+            )
+            .route(
+                "/api/v1/documents/{*path}",
+                get(lekton::api::documents::document_resource_handler),
+            )
+            .route(
+                "/share/{token}",
+                get(lekton::api::share::shared_document_handler),
+            )
+            .route(
+                "/embed/docs/{*slug}",
+                get(lekton::api::embed::embed_document_handler),
+            )
+            .route("/api/v1/oembed", get(lekton::api::embed::oembed_handler))
+            .route(
+                "/api/v1/admin/images/orphaned",
+                get(lekton::api::image_cleanup::list_orphaned_images_handler)
+                    .delete(lekton::api::image_cleanup::delete_orphaned_images_handler),
+            )
+            .route(
+                "/api/v1/admin/asset-downloads",
+                get(lekton::api::asset_access::list_asset_downloads_handler),
+            )
+            .route(
+                "/api/v1/admin/quarantine",
+                get(lekton::api::quarantine::list_quarantine_handler),
+            )
+            .route(
+                "/api/v1/admin/quarantine/{id}",
+                axum::routing::delete(lekton::api::quarantine::delete_quarantined_handler),
+            )
+            .route(
+                "/api/v1/admin/tags/merge",
+                post(lekton::api::admin::merge_tags_handler),
+            )
+            .route(
+                "/api/v1/admin/protected-prefixes",
+                get(lekton::api::approvals::get_protected_prefixes_handler)
+                    .put(lekton::api::approvals::put_protected_prefixes_handler),
+            )
+            .route(
+                "/api/v1/admin/pending-ingests",
+                get(lekton::api::approvals::list_pending_ingests_handler),
+            )
+            .route(
+                "/api/v1/admin/pending-ingests/{id}",
+                get(lekton::api::approvals::get_pending_ingest_handler)
+                    .delete(lekton::api::approvals::reject_pending_ingest_handler),
+            )
+            .route(
+                "/api/v1/admin/pending-ingests/{id}/approve",
+                post(lekton::api::approvals::approve_pending_ingest_handler),
+            )
+            .route(
+                "/api/v1/admin/jobs",
+                get(lekton::api::jobs::list_jobs_handler),
+            )
+            .route(
+                "/api/v1/admin/jobs/runs",
+                get(lekton::api::jobs::list_job_runs_handler),
+            )
+            .route(
+                "/api/v1/admin/jobs/{job}/run",
+                post(lekton::api::jobs::run_job_handler),
+            )
+            .route(
+                "/api/v1/admin/search/settings",
+                get(lekton::api::search::get_search_settings_handler)
+                    .put(lekton::api::search::put_search_settings_handler),
+            )
             // Auth OIDC routes (refresh, me, logout — work without auth_provider)
             .route("/auth/refresh", post(lekton::api::auth::refresh_handler))
             .route("/auth/logout", post(lekton::api::auth::logout_handler))
             .route("/auth/me", get(lekton::api::auth::me_handler))
+            // Local account routes
+            .route(
+                "/auth/local/login",
+                post(lekton::auth::local_accounts::login_handler),
+            )
+            .route(
+                "/auth/local/password",
+                post(lekton::auth::local_accounts::change_password_handler),
+            )
             // Demo auth routes
             .route(
                 "/api/auth/login",
@@ -304,6 +562,18 @@ impl TestEnv {
                 "/api/auth/logout",
                 post(lekton::auth::demo_auth::logout_handler),
             )
+            .leptos_routes(&app_state, leptos_routes, {
+                let options = app_state.leptos_options.clone();
+                move || lekton::app::shell(options.clone())
+            })
+            .layer(axum::middleware::from_fn_with_state(
+                app_state.clone(),
+                lekton::api::print::print_view,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                app_state.clone(),
+                lekton::auth::demo_auth::renew_session,
+            ))
             .with_state(app_state);
 
         Self {
@@ -324,6 +594,24 @@ impl TestEnv {
             user_prompt_preference_repo,
             navigation_order_repo,
             documentation_feedback_repo,
+            glossary_repo,
+            spelling_dictionary_repo,
+            changelog_repo,
+            contract_test_repo,
+            bundle_repo,
+            project_repo,
+            edit_lock_repo,
+            pending_ingest_repo,
+            job_run_repo,
+            watch_repo,
+            notification_repo,
+            asset_download_repo,
+            quarantine_repo,
+            share_link_repo,
+            collection_repo,
+            doc_rating_repo,
+            upload_usage_repo,
+            activity_events,
             storage,
             search,
             token_service,
@@ -366,6 +654,7 @@ impl TestEnv {
             can_write_draft: false,
             created_at: chrono::Utc::now(),
             last_login_at: None,
+            password: None,
         };
         self.user_repo
             .create_user(user)
@@ -419,6 +708,8 @@ impl TestEnv {
             created_at: chrono::Utc::now(),
             last_used_at: None,
             is_active: true,
+            project: None,
+            access_levels: vec![],
         };
         self.service_token_repo
             .create(token)
@@ -454,6 +745,27 @@ impl TestEnv {
             .await
     }
 
+    /// Poll until `user_id` has at least `count` notifications; they are
+    /// delivered by background tasks.
+    pub async fn wait_for_notifications(
+        &self,
+        user_id: &str,
+        count: usize,
+    ) -> Vec<lekton::db::notification_repository::Notification> {
+        for _ in 0..50 {
+            let notifications = self
+                .notification_repo
+                .list_for_user(user_id, 10)
+                .await
+                .unwrap();
+            if notifications.len() >= count {
+                return notifications;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        panic!("expected {count} notifications for {user_id}");
+    }
+
     /// Helper: wait for Meilisearch to process pending tasks (async indexing).
     pub async fn wait_for_search_indexing(&self) {
         // Meilisearch processes tasks asynchronously. A short delay is the
@@ -488,6 +800,8 @@ pub fn server_without_search(env: &TestEnv) -> axum_test::TestServer {
         prompt_version_repo: env.prompt_version_repo.clone(),
         user_prompt_preference_repo: env.user_prompt_preference_repo.clone(),
         demo_mode: true,
+        local_accounts: true,
+        ldap: None,
         leptos_options,
         user_repo: env.user_repo.clone(),
         access_level_repo: env.access_level_repo.clone(),
@@ -495,7 +809,11 @@ pub fn server_without_search(env: &TestEnv) -> axum_test::TestServer {
         token_service,
         auth_provider: None,
         insecure_cookies: true,
+        demo_session: lekton::auth::demo_auth::DemoSessionConfig::default(),
         max_attachment_size_bytes: 5242880,
+        max_render_bytes: lekton::rendering::limits::DEFAULT_MAX_RENDER_BYTES,
+        render_pipeline: Arc::new(lekton::rendering::pipeline::RenderPipeline::standard()),
+        asset_refs_ready: Arc::new(true.into()),
         rag_service: None,
         reindex_state: None,
         search_reindex_state: None,
@@ -503,10 +821,54 @@ pub fn server_without_search(env: &TestEnv) -> axum_test::TestServer {
         chat_service: None,
         feedback_repo: None,
         documentation_feedback_repo: env.documentation_feedback_repo.clone(),
+        glossary_repo: env.glossary_repo.clone(),
+        spelling_dictionary_repo: env.spelling_dictionary_repo.clone(),
+        changelog_repo: env.changelog_repo.clone(),
+        contract_test_repo: env.contract_test_repo.clone(),
+        bundle_repo: env.bundle_repo.clone(),
+        project_repo: env.project_repo.clone(),
+        edit_lock_repo: env.edit_lock_repo.clone(),
+        pending_ingest_repo: env.pending_ingest_repo.clone(),
+        job_run_repo: env.job_run_repo.clone(),
+        watch_repo: env.watch_repo.clone(),
+        notification_repo: env.notification_repo.clone(),
+        asset_download_repo: env.asset_download_repo.clone(),
+        quarantine_repo: env.quarantine_repo.clone(),
+        share_link_repo: env.share_link_repo.clone(),
+        collection_repo: env.collection_repo.clone(),
+        doc_rating_repo: env.doc_rating_repo.clone(),
+        upload_usage_repo: env.upload_usage_repo.clone(),
+        activity_events: env.activity_events.clone(),
         embedding_cache_repo: None,
         schema_endpoint_reindex_state: Arc::new(
             lekton::schema::reindex::SchemaEndpointReindexState::default(),
         ),
+        github: None,
+        try_it: None,
+        grafana: None,
+        spec_viewer_assets: None,
+        circuit_breakers: vec![],
+        quotas: Arc::new(Default::default()),
+        taxonomy: Arc::new(Default::default()),
+        lint: Arc::new(Default::default()),
+        share_links: Arc::new(Default::default()),
+        embed: Arc::new(Default::default()),
+        scheduler: Arc::new(Default::default()),
+        email: None,
+        mentions: None,
+        inbox: Arc::new(lekton::notifications::Inbox::new(
+            env.notification_repo.clone(),
+            env.user_repo.clone(),
+            Default::default(),
+        )),
+        upload_scanner: None,
+        spell_checker: None,
+        secret_guard: None,
+        on_call: Arc::new(lekton::on_call::OnCallDirectory::new()),
+        issue_tracker: Arc::new(lekton::issue_tracker::IssueTracker::new()),
+        job_queue: Arc::new(lekton::jobs::LocalJobQueue::new(
+            lekton::jobs::JobRunner::new(env.access_level_repo.clone(), env.user_repo.clone()),
+        )),
     };
 
     let router = Router::new()
@@ -0,0 +1,141 @@
+mod common;
+
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::http::Request;
+use futures::StreamExt;
+use lekton::api::events::ActivityKind;
+use tokio::sync::broadcast::error::TryRecvError;
+use tower::ServiceExt;
+
+/// Helper: ingest version 1.0.0 of an OpenAPI schema.
+async fn ingest_schema(
+    server: &axum_test::TestServer,
+    name: &str,
+    owner_access_level: Option<&str>,
+) {
+    server
+        .post("/api/v1/schemas")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "name": name,
+            "schema_type": "openapi",
+            "version": "1.0.0",
+            "status": "stable",
+            "access_level": "public",
+            "owner_access_level": owner_access_level,
+            "service_owner": "platform",
+            "tags": [],
+            "content": r#"{"openapi": "3.0.0", "info": {"title": "API", "version": "1.0.0"}, "paths": {}}"#,
+        }))
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn ingest_publishes_only_when_document_changes() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let mut events = env.activity_events.subscribe();
+
+    env.ingest(&server, "runbooks/db", "DB runbook", "# Step 1", "public")
+        .await
+        .assert_status_ok();
+    let created = events.try_recv().unwrap();
+    assert_eq!(created.kind, ActivityKind::DocumentCreated);
+    assert_eq!(created.target, "runbooks/db");
+    assert_eq!(created.access_level, "public");
+    assert_eq!(created.actor, "legacy");
+
+    // Re-ingesting identical content is a no-op and must not notify anyone
+    env.ingest(&server, "runbooks/db", "DB runbook", "# Step 1", "public")
+        .await
+        .assert_status_ok();
+    assert!(matches!(events.try_recv(), Err(TryRecvError::Empty)));
+
+    env.ingest(
+        &server,
+        "runbooks/db",
+        "DB runbook",
+        "# Step 1\n# Step 2",
+        "internal",
+    )
+    .await
+    .assert_status_ok();
+    let updated = events.try_recv().unwrap();
+    assert_eq!(updated.kind, ActivityKind::DocumentUpdated);
+    assert_eq!(updated.access_level, "internal");
+}
+
+#[tokio::test]
+async fn schema_ingest_publishes_event() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let mut events = env.activity_events.subscribe();
+
+    server
+        .post("/api/v1/schemas")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "name": "payments-api",
+            "schema_type": "openapi",
+            "version": "1.0.0",
+            "status": "stable",
+            "access_level": "public",
+            "service_owner": "platform",
+            "tags": [],
+            "content": r#"{"openapi": "3.0.0", "info": {"title": "Payments", "version": "1.0.0"}, "paths": {}}"#,
+        }))
+        .await
+        .assert_status_ok();
+
+    let event = events.try_recv().unwrap();
+    assert_eq!(event.kind, ActivityKind::SchemaIngested);
+    assert_eq!(event.target, "payments-api");
+    assert_eq!(event.version.as_deref(), Some("1.0.0"));
+}
+
+#[tokio::test]
+async fn activity_stream_rejects_unknown_kinds() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    server
+        .get("/api/v1/events/stream")
+        .add_query_param("kinds", "document.deleted")
+        .await
+        .assert_status_bad_request();
+}
+
+#[tokio::test]
+async fn activity_stream_hides_schemas_with_a_restricted_owner() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let response = env
+        .router
+        .clone()
+        .oneshot(
+            Request::get("/api/v1/events/stream?kinds=schema.ingested")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    let mut stream = response.into_body().into_data_stream();
+
+    // Public version, but only internal callers can read the schema
+    ingest_schema(&server, "ledger-api", Some("internal")).await;
+    ingest_schema(&server, "payments-api", None).await;
+
+    let chunk = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("no event received")
+        .unwrap()
+        .unwrap();
+    let chunk = String::from_utf8_lossy(&chunk);
+    assert!(chunk.contains("payments-api"), "unexpected event: {chunk}");
+    assert!(!chunk.contains("ledger-api"));
+}
@@ -543,3 +543,137 @@ async fn admin_non_admin_cannot_manage_tokens() {
 
     response.assert_status_forbidden();
 }
+
+// ── Tags ────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn merge_tags_rewrites_documents() {
+    use lekton::db::repository::DocumentRepository;
+
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("admin-1", "admin@test.com", true)
+        .await;
+    let user = env.create_test_user("user-1", "user@test.com", false).await;
+
+    let slug = format!("merge-tags-{}", uuid::Uuid::new_v4());
+    server
+        .post("/api/v1/ingest")
+        .json(&json!({
+            "service_token": "test-token",
+            "slug": slug,
+            "source_path": format!("docs/{slug}.md"),
+            "title": "Cluster setup",
+            "content": "# Cluster setup",
+            "access_level": "public",
+            "service_owner": "test-team",
+            "tags": ["K8s", "setup", "k8s"]
+        }))
+        .await
+        .assert_status_ok();
+
+    let request = json!({ "tags": ["K8s", "k8s"], "into": "kubernetes" });
+    server
+        .post("/api/v1/admin/tags/merge")
+        .add_cookie(env.auth_cookie(&user))
+        .json(&request)
+        .await
+        .assert_status_forbidden();
+
+    let response = server
+        .post("/api/v1/admin/tags/merge")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&request)
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["updated"], json!([slug]));
+
+    let doc = env.repo.find_by_slug(&slug).await.unwrap().unwrap();
+    assert_eq!(doc.tags, ["kubernetes", "setup"]);
+}
+
+#[tokio::test]
+async fn batch_update_changes_selected_documents() {
+    use lekton::db::repository::DocumentRepository;
+
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("admin-1", "admin@test.com", true)
+        .await;
+    let user = env.create_test_user("user-1", "user@test.com", false).await;
+
+    let prefix = format!("batch-{}", uuid::Uuid::new_v4());
+    let slugs: Vec<String> = ["parent", "a", "b"]
+        .iter()
+        .map(|name| format!("{prefix}/{name}"))
+        .collect();
+    for slug in &slugs {
+        server
+            .post("/api/v1/ingest")
+            .json(&json!({
+                "service_token": "test-token",
+                "slug": slug,
+                "source_path": format!("docs/{slug}.md"),
+                "title": slug,
+                "content": format!("# {slug}"),
+                "access_level": "public",
+                "service_owner": "test-team",
+                "tags": ["old", "keep"]
+            }))
+            .await
+            .assert_status_ok();
+    }
+    let children = vec![slugs[1].clone(), slugs[2].clone()];
+
+    let request = json!({
+        "slugs": [children[0], children[1], format!("{prefix}/missing")],
+        "service_owner": "platform-team",
+        "access_level": "internal",
+        "add_tags": ["new"],
+        "remove_tags": ["old"],
+        "parent_slug": slugs[0]
+    });
+    server
+        .patch("/api/v1/documents:batch")
+        .add_cookie(env.auth_cookie(&user))
+        .json(&request)
+        .await
+        .assert_status_forbidden();
+
+    let response = server
+        .patch("/api/v1/documents:batch")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&request)
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["updated"], json!(children));
+    assert_eq!(body["not_found"], json!([format!("{prefix}/missing")]));
+
+    let doc = env.repo.find_by_slug(&children[0]).await.unwrap().unwrap();
+    assert_eq!(doc.service_owner, "platform-team");
+    assert_eq!(doc.access_level, "internal");
+    assert_eq!(doc.tags, ["keep", "new"]);
+    assert_eq!(doc.parent_slug.as_deref(), Some(slugs[0].as_str()));
+
+    // Moving the parent under its own child is rejected
+    server
+        .patch("/api/v1/documents:batch")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "slugs": [slugs[0]], "parent_slug": children[0] }))
+        .await
+        .assert_status_bad_request();
+
+    let response = server
+        .patch("/api/v1/documents:batch")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "slugs": [children[1]], "delete": true }))
+        .await;
+    response.assert_status_ok();
+    let doc = env.repo.find_by_slug(&children[1]).await.unwrap().unwrap();
+    assert!(doc.is_archived);
+}
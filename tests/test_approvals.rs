@@ -0,0 +1,196 @@
+mod common;
+
+use serde_json::json;
+
+#[tokio::test]
+async fn ingest_to_protected_prefix_waits_for_approval() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("approver", "approver@test.com", true)
+        .await;
+
+    let response = server
+        .put("/api/v1/admin/protected-prefixes")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "prefixes": ["/security/*", " "] }))
+        .await;
+    assert_eq!(
+        response.json::<serde_json::Value>()["prefixes"],
+        json!(["security/*"])
+    );
+
+    let response = env
+        .ingest(
+            &server,
+            "security/incident-response",
+            "IR",
+            "# IR",
+            "public",
+        )
+        .await;
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["pending_approval"], true);
+    assert!(env
+        .repo
+        .find_by_slug("security/incident-response")
+        .await
+        .unwrap()
+        .is_none());
+
+    // Unprotected slugs are published directly
+    let body: serde_json::Value = env
+        .ingest(&server, "guides/intro", "Intro", "# Intro", "public")
+        .await
+        .json();
+    assert!(body.get("pending_approval").is_none());
+
+    let pending: Vec<serde_json::Value> = server
+        .get("/api/v1/admin/pending-ingests")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0]["slug"], "security/incident-response");
+    assert_eq!(pending[0]["submitted_by"], "legacy");
+    assert_eq!(pending[0]["request"]["service_token"], "");
+    let id = pending[0]["_id"].as_str().unwrap().to_string();
+
+    server
+        .post(&format!("/api/v1/admin/pending-ingests/{id}/approve"))
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .assert_status_ok();
+
+    let doc = env
+        .repo
+        .find_by_slug("security/incident-response")
+        .await
+        .unwrap()
+        .expect("approved document should be published");
+    assert_eq!(doc.title, "IR");
+    let pending: Vec<serde_json::Value> = server
+        .get("/api/v1/admin/pending-ingests")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert!(pending.is_empty());
+}
+
+#[tokio::test]
+async fn rejected_ingest_is_discarded() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("approver", "approver@test.com", true)
+        .await;
+    let user = env
+        .create_test_user("plain-user", "plain@test.com", false)
+        .await;
+
+    server
+        .put("/api/v1/admin/protected-prefixes")
+        .add_cookie(env.auth_cookie(&user))
+        .json(&json!({ "prefixes": ["legal/*"] }))
+        .await
+        .assert_status_forbidden();
+    server
+        .put("/api/v1/admin/protected-prefixes")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "prefixes": ["legal/*"] }))
+        .await
+        .assert_status_ok();
+
+    env.ingest(&server, "legal/terms", "Terms", "# Terms", "public")
+        .await
+        .assert_status_ok();
+    let pending: Vec<serde_json::Value> = server
+        .get("/api/v1/admin/pending-ingests")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    let id = pending[0]["_id"].as_str().unwrap().to_string();
+
+    server
+        .get("/api/v1/admin/pending-ingests")
+        .add_cookie(env.auth_cookie(&user))
+        .await
+        .assert_status_forbidden();
+
+    server
+        .delete(&format!("/api/v1/admin/pending-ingests/{id}"))
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+    server
+        .post(&format!("/api/v1/admin/pending-ingests/{id}/approve"))
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .assert_status_not_found();
+    assert!(env
+        .repo
+        .find_by_slug("legal/terms")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn approval_requests_and_outcomes_reach_the_inbox() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("approver", "approver@test.com", true)
+        .await;
+    // Owner of the service token (its `created_by`)
+    env.create_test_user("test-admin", "token-owner@test.com", false)
+        .await;
+    let token = env
+        .create_service_token("legal-ci", vec!["legal/*".to_string()], true)
+        .await;
+
+    server
+        .put("/api/v1/admin/protected-prefixes")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "prefixes": ["legal/*"] }))
+        .await
+        .assert_status_ok();
+    server
+        .post("/api/v1/ingest")
+        .json(&json!({
+            "service_token": token,
+            "slug": "legal/terms",
+            "source_path": "docs/legal/terms.md",
+            "title": "Terms",
+            "content": "# Terms",
+            "access_level": "public",
+            "service_owner": "legal",
+        }))
+        .await
+        .assert_status_ok();
+
+    let requests = env.wait_for_notifications("approver", 1).await;
+    assert_eq!(requests[0].kind, "approval.requested");
+    assert_eq!(requests[0].actor, "legal-ci");
+    assert_eq!(requests[0].link().as_deref(), Some("/admin/approvals"));
+
+    let pending: Vec<serde_json::Value> = server
+        .get("/api/v1/admin/pending-ingests")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    let id = pending[0]["_id"].as_str().unwrap();
+    server
+        .post(&format!("/api/v1/admin/pending-ingests/{id}/approve"))
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .assert_status_ok();
+
+    let outcomes = env.wait_for_notifications("test-admin", 1).await;
+    assert_eq!(outcomes[0].kind, "approval.approved");
+    assert_eq!(outcomes[0].actor, "approver@test.com");
+    assert_eq!(
+        outcomes[0].text(),
+        "approver@test.com approved legal/terms (\"Terms\")"
+    );
+}
@@ -146,6 +146,9 @@ async fn upload_asset_with_nested_key() {
 async fn serve_asset_returns_content() {
     let env = common::TestEnv::start().await;
     let server = env.server();
+    let reader = env
+        .create_test_user("reader", "reader@test.com", false)
+        .await;
 
     let content = b"PDF content here";
     upload_asset(
@@ -157,7 +160,10 @@ async fn serve_asset_returns_content() {
     )
     .await;
 
-    let response = server.get("/api/v1/assets/docs/manual.pdf").await;
+    let response = server
+        .get("/api/v1/assets/docs/manual.pdf")
+        .add_cookie(env.auth_cookie(&reader))
+        .await;
     response.assert_status_ok();
 
     let headers = response.headers();
@@ -167,6 +173,88 @@ async fn serve_asset_returns_content() {
     assert_eq!(body.as_ref(), content);
 }
 
+#[tokio::test]
+async fn serve_asset_honors_range_requests() {
+    use axum::http::header::{ACCEPT_RANGES, CONTENT_RANGE, RANGE};
+    use axum::http::{HeaderValue, StatusCode};
+
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let reader = env
+        .create_test_user("reader", "reader@test.com", false)
+        .await;
+
+    let content = b"PDF content here";
+    upload_asset(
+        &server,
+        "docs/manual.pdf",
+        content,
+        "application/pdf",
+        "test-token",
+    )
+    .await;
+
+    let response = server
+        .get("/api/v1/assets/docs/manual.pdf")
+        .add_cookie(env.auth_cookie(&reader))
+        .add_header(RANGE, HeaderValue::from_static("bytes=4-10"))
+        .await;
+    response.assert_status(StatusCode::PARTIAL_CONTENT);
+    assert_eq!(
+        response.headers().get(CONTENT_RANGE).unwrap(),
+        "bytes 4-10/16"
+    );
+    assert_eq!(response.headers().get(ACCEPT_RANGES).unwrap(), "bytes");
+    assert_eq!(response.into_bytes().as_ref(), b"content");
+
+    let response = server
+        .get("/api/v1/assets/docs/manual.pdf")
+        .add_cookie(env.auth_cookie(&reader))
+        .add_header(RANGE, HeaderValue::from_static("bytes=100-"))
+        .await;
+    response.assert_status(StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(response.headers().get(CONTENT_RANGE).unwrap(), "bytes */16");
+}
+
+#[tokio::test]
+async fn serve_asset_range_requests_survive_compression() {
+    use axum::http::header::{ACCEPT_ENCODING, ACCEPT_RANGES, CONTENT_ENCODING, RANGE};
+    use axum::http::{HeaderValue, StatusCode};
+
+    let env = common::TestEnv::start().await;
+    // The production router compresses responses
+    let server = axum_test::TestServer::builder().build(
+        env.router
+            .clone()
+            .layer(lekton::http_policy::compression_layer(true)),
+    );
+    let reader = env
+        .create_test_user("reader", "reader@test.com", false)
+        .await;
+
+    // Compressible type, large enough for the default predicate
+    let content = "0123456789".repeat(100);
+    upload_asset(
+        &server,
+        "logs/build.txt",
+        content.as_bytes(),
+        "text/plain",
+        "test-token",
+    )
+    .await;
+
+    let response = server
+        .get("/api/v1/assets/logs/build.txt")
+        .add_cookie(env.auth_cookie(&reader))
+        .add_header(ACCEPT_ENCODING, HeaderValue::from_static("gzip"))
+        .add_header(RANGE, HeaderValue::from_static("bytes=10-19"))
+        .await;
+    response.assert_status(StatusCode::PARTIAL_CONTENT);
+    assert!(response.headers().get(CONTENT_ENCODING).is_none());
+    assert_eq!(response.headers().get(ACCEPT_RANGES).unwrap(), "bytes");
+    assert_eq!(response.into_bytes().as_ref(), b"0123456789");
+}
+
 #[tokio::test]
 async fn serve_asset_not_found() {
     let env = common::TestEnv::start().await;
@@ -176,6 +264,103 @@ async fn serve_asset_not_found() {
     response.assert_status_not_found();
 }
 
+#[tokio::test]
+async fn serve_asset_enforces_embedding_document_access_and_audits_downloads() {
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("asset-admin", "asset-admin@test.com", true)
+        .await;
+
+    upload_asset(
+        &server,
+        "ops/runbook.pdf",
+        b"secret",
+        "application/pdf",
+        "test-token",
+    )
+    .await;
+    upload_asset(&server, "ops/logo.png", b"logo", "image/png", "test-token").await;
+    env.ingest(
+        &server,
+        "ops/runbook",
+        "Runbook",
+        "[Runbook](/api/v1/assets/ops/runbook.pdf) ![Logo](/api/v1/assets/ops/logo.png)",
+        "internal",
+    )
+    .await
+    .assert_status_ok();
+    env.ingest(
+        &server,
+        "about",
+        "About",
+        "![Logo](/api/v1/assets/ops/logo.png)",
+        "public",
+    )
+    .await
+    .assert_status_ok();
+
+    // Embedded by an internal document only
+    server
+        .get("/api/v1/assets/ops/runbook.pdf")
+        .await
+        .assert_status_not_found();
+    let response = server
+        .get("/api/v1/assets/ops/runbook.pdf")
+        .add_cookie(env.auth_cookie(&admin))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.into_bytes().as_ref(), b"secret");
+
+    // Also embedded by a public document: the internal one still applies
+    server
+        .get("/api/v1/assets/ops/logo.png")
+        .await
+        .assert_status_not_found();
+
+    let response = server
+        .get("/api/v1/admin/asset-downloads")
+        .add_cookie(env.auth_cookie(&admin))
+        .await;
+    response.assert_status_ok();
+    let downloads: Vec<serde_json::Value> = response.json();
+    assert_eq!(downloads.len(), 1);
+    assert_eq!(downloads[0]["s3_key"], "assets/ops/runbook.pdf");
+    assert_eq!(downloads[0]["slug"], "ops/runbook");
+    assert_eq!(downloads[0]["downloaded_by"], "asset-admin@test.com");
+}
+
+#[tokio::test]
+async fn serve_asset_denies_unreferenced_files_to_anonymous_callers() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let reader = env
+        .create_test_user("reader", "reader@test.com", false)
+        .await;
+
+    upload_asset(
+        &server,
+        "drafts/plan.pdf",
+        b"private",
+        "application/pdf",
+        "test-token",
+    )
+    .await;
+
+    // No document embeds it: never public
+    server
+        .get("/api/v1/assets/drafts/plan.pdf")
+        .await
+        .assert_status_not_found();
+    let response = server
+        .get("/api/v1/assets/drafts/plan.pdf")
+        .add_cookie(env.auth_cookie(&reader))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.into_bytes().as_ref(), b"private");
+}
+
 #[tokio::test]
 async fn list_assets_returns_all() {
     let env = common::TestEnv::start().await;
@@ -282,6 +467,9 @@ async fn delete_asset_rejects_invalid_token() {
 async fn editor_upload_creates_asset() {
     let env = common::TestEnv::start().await;
     let server = env.server();
+    let editor = env
+        .create_test_user("editor", "editor@test.com", false)
+        .await;
 
     let png_bytes: Vec<u8> = vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
 
@@ -294,6 +482,7 @@ async fn editor_upload_creates_asset() {
 
     let response = server
         .post("/api/v1/editor/upload-asset")
+        .add_cookie(env.auth_cookie(&editor))
         .multipart(form)
         .await;
 
@@ -323,7 +512,7 @@ async fn editor_upload_creates_asset() {
 
     // Verify asset exists in repo
     let asset = env.asset_repo.find_by_key(key).await.unwrap().unwrap();
-    assert_eq!(asset.uploaded_by, "web-editor");
+    assert_eq!(asset.uploaded_by, "editor@test.com");
     assert_eq!(asset.content_type, "image/png");
 
     // Verify content in S3
@@ -340,6 +529,9 @@ async fn editor_upload_creates_asset() {
 async fn editor_upload_serves_correctly() {
     let env = common::TestEnv::start().await;
     let server = env.server();
+    let editor = env
+        .create_test_user("editor", "editor@test.com", false)
+        .await;
 
     let content = b"test file content";
     let form = MultipartForm::new().add_part(
@@ -351,6 +543,7 @@ async fn editor_upload_serves_correctly() {
 
     let upload_response = server
         .post("/api/v1/editor/upload-asset")
+        .add_cookie(env.auth_cookie(&editor))
         .multipart(form)
         .await;
 
@@ -358,7 +551,7 @@ async fn editor_upload_serves_correctly() {
     let url = body["url"].as_str().unwrap();
 
     // Serve it back
-    let response = server.get(url).await;
+    let response = server.get(url).add_cookie(env.auth_cookie(&editor)).await;
     response.assert_status_ok();
 
     let headers = response.headers();
@@ -372,11 +565,15 @@ async fn editor_upload_serves_correctly() {
 async fn editor_upload_missing_file_field() {
     let env = common::TestEnv::start().await;
     let server = env.server_permissive();
+    let editor = env
+        .create_test_user("editor", "editor@test.com", false)
+        .await;
 
     let form = MultipartForm::new().add_text("wrong_field", "some data");
 
     let response = server
         .post("/api/v1/editor/upload-asset")
+        .add_cookie(env.auth_cookie(&editor))
         .multipart(form)
         .await;
 
@@ -151,3 +151,55 @@ async fn full_auth_flow() {
     let response = server.get("/api/auth/me").await;
     response.assert_status_unauthorized();
 }
+
+#[tokio::test]
+async fn forged_demo_cookie_is_rejected() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+
+    // The pre-signing format: a plain JSON user
+    let forged = serde_json::json!({
+        "user_id": "demo-admin",
+        "email": "admin@demo.lekton.dev",
+        "name": "Demo Admin",
+        "is_admin": true
+    })
+    .to_string();
+
+    server
+        .get("/api/auth/me")
+        .add_cookie(cookie::Cookie::new("lekton_demo_user", forged))
+        .await
+        .assert_status_unauthorized();
+    server
+        .get("/api/auth/me")
+        .add_cookie(cookie::Cookie::new("lekton_demo_user", "a.b.c"))
+        .await
+        .assert_status_unauthorized();
+}
+
+#[tokio::test]
+async fn demo_session_cookie_expires_and_is_hardened() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let response = server
+        .post("/api/auth/login")
+        .json(&serde_json::json!({
+            "username": "demo",
+            "password": "demo"
+        }))
+        .await;
+
+    let session = response
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .find(|v| v.starts_with("lekton_demo_user="))
+        .expect("session cookie")
+        .to_string();
+    assert!(session.contains("HttpOnly"));
+    assert!(session.contains("SameSite=Strict"));
+    assert!(session.contains("Max-Age=28800"));
+}
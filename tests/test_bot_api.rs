@@ -0,0 +1,346 @@
+mod common;
+
+use serde_json::json;
+
+async fn create_bot_token(
+    env: &common::TestEnv,
+    server: &axum_test::TestServer,
+    body: serde_json::Value,
+) -> String {
+    let admin = env
+        .create_test_user("bot-admin", "bot-admin@test.com", true)
+        .await;
+    let response = server
+        .post("/api/v1/admin/service-tokens")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&body)
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let body: serde_json::Value = response.json();
+    body["raw_token"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn bot_fetches_documents_within_its_levels_and_scopes() {
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+
+    env.ingest(
+        &server,
+        "security/signing-keys",
+        "Signing keys",
+        "# Rotating the signing key\n\nRun `lekton keys rotate`.",
+        "internal",
+    )
+    .await
+    .assert_status_ok();
+    env.ingest(
+        &server,
+        "security/incidents",
+        "Incidents",
+        "# Incidents",
+        "architect",
+    )
+    .await
+    .assert_status_ok();
+    env.ingest(
+        &server,
+        "payments/retries",
+        "Retries",
+        "# Retries",
+        "public",
+    )
+    .await
+    .assert_status_ok();
+
+    let raw = create_bot_token(
+        &env,
+        &server,
+        json!({
+            "name": "slack-bot",
+            "token_type": "bot",
+            "access_levels": ["internal"],
+            "allowed_scopes": ["security/*"]
+        }),
+    )
+    .await;
+
+    let response = server
+        .get("/api/v1/bot/docs/security/signing-keys")
+        .authorization_bearer(&raw)
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["title"], "Signing keys");
+    assert_eq!(body["url"], "/docs/security/signing-keys");
+    assert_eq!(body["format"], "markdown");
+    assert!(body["content"]
+        .as_str()
+        .unwrap()
+        .starts_with("# Rotating the signing key"));
+
+    let response = server
+        .get("/api/v1/bot/docs/security/signing-keys")
+        .add_query_param("format", "html")
+        .authorization_bearer(&raw)
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert!(body["content"].as_str().unwrap().contains("<h1"));
+
+    // Outside the token's access levels
+    server
+        .get("/api/v1/bot/docs/security/incidents")
+        .authorization_bearer(&raw)
+        .await
+        .assert_status_not_found();
+
+    // Outside the token's scopes
+    server
+        .get("/api/v1/bot/docs/payments/retries")
+        .authorization_bearer(&raw)
+        .await
+        .assert_status_not_found();
+}
+
+#[tokio::test]
+async fn raw_markdown_endpoint_accepts_bot_tokens_within_scope() {
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+
+    env.ingest(
+        &server,
+        "security/rotation",
+        "Rotation",
+        "# Rotation\n\nRotate quarterly.",
+        "internal",
+    )
+    .await
+    .assert_status_ok();
+    env.ingest(&server, "payments/fees", "Fees", "# Fees", "internal")
+        .await
+        .assert_status_ok();
+
+    let raw = create_bot_token(
+        &env,
+        &server,
+        json!({
+            "name": "lint-bot",
+            "token_type": "bot",
+            "access_levels": ["internal"],
+            "allowed_scopes": ["security/*"]
+        }),
+    )
+    .await;
+
+    let response = server
+        .get("/api/v1/documents/security/rotation/raw")
+        .authorization_bearer(&raw)
+        .await;
+    response.assert_status_ok();
+    assert_eq!(
+        response.header("content-type"),
+        "text/markdown; charset=utf-8"
+    );
+    assert_eq!(response.text(), "# Rotation\n\nRotate quarterly.");
+
+    // Within the token's access levels, but outside its scopes
+    server
+        .get("/api/v1/documents/payments/fees/raw")
+        .authorization_bearer(&raw)
+        .await
+        .assert_status_not_found();
+
+    // Anonymous callers only see public documents
+    server
+        .get("/api/v1/documents/security/rotation/raw")
+        .await
+        .assert_status_not_found();
+
+    // Only `<slug>/raw` is routed
+    server
+        .get("/api/v1/documents/security/rotation")
+        .authorization_bearer(&raw)
+        .await
+        .assert_status_not_found();
+}
+
+#[tokio::test]
+async fn bot_api_rejects_missing_and_non_bot_tokens() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+
+    server
+        .get("/api/v1/bot/docs/any/doc")
+        .await
+        .assert_status_unauthorized();
+
+    let service = env
+        .create_service_token("ci-token", vec!["any/*".to_string()], true)
+        .await;
+    server
+        .get("/api/v1/bot/docs/any/doc")
+        .authorization_bearer(&service)
+        .await
+        .assert_status_forbidden();
+}
+
+#[tokio::test]
+async fn bot_tokens_require_existing_access_levels_and_no_write() {
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("bot-admin", "bot-admin@test.com", true)
+        .await;
+
+    for body in [
+        json!({ "name": "no-levels", "token_type": "bot" }),
+        json!({ "name": "unknown", "token_type": "bot", "access_levels": ["nope"] }),
+        json!({
+            "name": "writer",
+            "token_type": "bot",
+            "access_levels": ["public"],
+            "can_write": true
+        }),
+    ] {
+        server
+            .post("/api/v1/admin/service-tokens")
+            .add_cookie(env.auth_cookie(&admin))
+            .json(&body)
+            .await
+            .assert_status_bad_request();
+    }
+}
+
+#[tokio::test]
+async fn bot_lists_document_relations_it_can_read() {
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+
+    let relate = |slug: &str, level: &str, relations: serde_json::Value| {
+        json!({
+            "service_token": "test-token",
+            "slug": slug,
+            "source_path": format!("docs/{slug}.md"),
+            "title": slug,
+            "content": format!("# {slug}"),
+            "access_level": level,
+            "service_owner": "test-team",
+            "relations": relations
+        })
+    };
+    for body in [
+        relate("guides/setup-v1", "public", json!({})),
+        relate("guides/intro", "public", json!({})),
+        relate(
+            "guides/setup",
+            "public",
+            json!({
+                "prerequisite": ["/docs/guides/intro"],
+                "supersedes": ["guides/setup-v1", "guides/setup"]
+            }),
+        ),
+        relate(
+            "guides/internal-notes",
+            "internal",
+            json!({ "see_also": ["guides/setup"] }),
+        ),
+    ] {
+        server
+            .post("/api/v1/ingest")
+            .json(&body)
+            .await
+            .assert_status_ok();
+    }
+
+    let raw = create_bot_token(
+        &env,
+        &server,
+        json!({ "name": "relations-bot", "token_type": "bot", "access_levels": ["public"] }),
+    )
+    .await;
+
+    let response = server
+        .get("/api/v1/bot/relations/guides/setup")
+        .authorization_bearer(&raw)
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["prerequisite"][0]["slug"], "guides/intro");
+    // The self-reference is dropped on ingest
+    assert_eq!(
+        body["supersedes"],
+        json!([{ "slug": "guides/setup-v1", "title": "guides/setup-v1" }])
+    );
+    // The internal document is not visible to the key
+    assert_eq!(body["see_also"], json!([]));
+
+    let response = server
+        .get("/api/v1/bot/relations/guides/setup-v1")
+        .authorization_bearer(&raw)
+        .await;
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["superseded_by"][0]["slug"], "guides/setup");
+
+    server
+        .get("/api/v1/bot/relations/guides/internal-notes")
+        .authorization_bearer(&raw)
+        .await
+        .assert_status_not_found();
+}
+
+#[tokio::test]
+async fn document_relations_follow_the_caller_access_levels() {
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+
+    for (slug, level, relations) in [
+        ("guides/setup", "public", json!({})),
+        (
+            "guides/internal-notes",
+            "internal",
+            json!({ "see_also": ["guides/setup"] }),
+        ),
+    ] {
+        server
+            .post("/api/v1/ingest")
+            .json(&json!({
+                "service_token": "test-token",
+                "slug": slug,
+                "source_path": format!("docs/{slug}.md"),
+                "title": slug,
+                "content": format!("# {slug}"),
+                "access_level": level,
+                "service_owner": "test-team",
+                "relations": relations
+            }))
+            .await
+            .assert_status_ok();
+    }
+
+    // Anonymous callers only see public documents
+    let response = server.get("/api/v1/documents/guides/setup/relations").await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["see_also"], json!([]));
+    server
+        .get("/api/v1/documents/guides/internal-notes/relations")
+        .await
+        .assert_status_not_found();
+
+    let admin = env
+        .create_test_user("admin-1", "admin@test.com", true)
+        .await;
+    let response = server
+        .get("/api/v1/documents/guides/setup/relations")
+        .add_cookie(env.auth_cookie(&admin))
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["see_also"][0]["slug"], "guides/internal-notes");
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use lekton::api::changelog::ChangelogResponse;
+
+/// Helper: publish a changelog entry via the API.
+async fn publish(
+    server: &axum_test::TestServer,
+    service: &str,
+    version: &str,
+    date: &str,
+    notes: &str,
+    access_level: &str,
+) -> axum_test::TestResponse {
+    server
+        .post("/api/v1/changelog")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "service": service,
+            "version": version,
+            "date": date,
+            "notes": notes,
+            "access_level": access_level,
+        }))
+        .await
+}
+
+#[tokio::test]
+async fn changelog_publish_lists_entries_newest_first() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let service = format!("svc-{}", uuid::Uuid::new_v4());
+
+    let response = publish(
+        &server,
+        &service,
+        "1.0.0",
+        "2026-04-01",
+        "- Initial",
+        "public",
+    )
+    .await;
+    let body: ChangelogResponse = response.json();
+    assert_eq!(body.service, service);
+    assert_eq!(body.version, "1.0.0");
+
+    publish(
+        &server,
+        &service,
+        "1.1.0",
+        "2026-05-01",
+        "- Added X",
+        "public",
+    )
+    .await
+    .assert_status_ok();
+
+    let entries = env
+        .changelog_repo
+        .list(None, Some(&service), 10)
+        .await
+        .unwrap();
+    let versions: Vec<&str> = entries.iter().map(|e| e.version.as_str()).collect();
+    assert_eq!(versions, vec!["1.1.0", "1.0.0"]);
+    assert_eq!(entries[0].published_by, "legacy");
+}
+
+#[tokio::test]
+async fn changelog_republish_replaces_version() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let service = format!("svc-{}", uuid::Uuid::new_v4());
+
+    publish(
+        &server,
+        &service,
+        "2.0.0",
+        "2026-05-01",
+        "- Draft notes",
+        "public",
+    )
+    .await
+    .assert_status_ok();
+    publish(
+        &server,
+        &service,
+        "2.0.0",
+        "2026-05-02",
+        "- Final notes",
+        "public",
+    )
+    .await
+    .assert_status_ok();
+
+    let entries = env
+        .changelog_repo
+        .list(None, Some(&service), 10)
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].notes, "- Final notes");
+    assert_eq!(entries[0].date.to_string(), "2026-05-02");
+}
+
+#[tokio::test]
+async fn changelog_list_respects_access_levels() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let service = format!("svc-{}", uuid::Uuid::new_v4());
+
+    publish(
+        &server,
+        &service,
+        "1.0.0",
+        "2026-05-01",
+        "- Public",
+        "public",
+    )
+    .await
+    .assert_status_ok();
+    publish(
+        &server,
+        &service,
+        "1.0.1",
+        "2026-05-02",
+        "- Internal",
+        "internal",
+    )
+    .await
+    .assert_status_ok();
+
+    let public = env
+        .changelog_repo
+        .list(Some(&["public".to_string()]), Some(&service), 10)
+        .await
+        .unwrap();
+    assert_eq!(public.len(), 1);
+    assert_eq!(public[0].version, "1.0.0");
+}
+
+#[tokio::test]
+async fn changelog_rejects_invalid_requests() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    publish(
+        &server,
+        "bad service",
+        "1.0.0",
+        "2026-05-01",
+        "- x",
+        "public",
+    )
+    .await
+    .assert_status_bad_request();
+    publish(
+        &server,
+        "svc",
+        "1.0.0",
+        "2026-05-01",
+        "- x",
+        "no-such-level",
+    )
+    .await
+    .assert_status_bad_request();
+    publish(&server, "svc", "1.0.0", "2026-05-01", "   ", "public")
+        .await
+        .assert_status_bad_request();
+
+    server
+        .post("/api/v1/changelog")
+        .json(&serde_json::json!({
+            "service_token": "wrong-token",
+            "service": "svc",
+            "version": "1.0.0",
+            "notes": "- x",
+        }))
+        .await
+        .assert_status_unauthorized();
+}
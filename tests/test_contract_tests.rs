@@ -0,0 +1,109 @@
+mod common;
+
+use lekton::api::contract_tests::ContractTestResponse;
+use lekton::db::contract_test_repository::ContractTestOutcome;
+
+/// Helper: publish a contract-test report via the API.
+async fn report(
+    server: &axum_test::TestServer,
+    token: &str,
+    schema: &str,
+    version: &str,
+    consumer: &str,
+    outcome: &str,
+) -> axum_test::TestResponse {
+    server
+        .post("/api/v1/contract-tests")
+        .json(&serde_json::json!({
+            "service_token": token,
+            "schema": schema,
+            "version": version,
+            "consumer": consumer,
+            "tool": "Pact",
+            "outcome": outcome,
+            "passed": 10,
+            "failed": 0,
+            "report_url": "https://ci.example.com/runs/42",
+        }))
+        .await
+}
+
+async fn ingest_schema(server: &axum_test::TestServer, name: &str, version: &str) {
+    server
+        .post("/api/v1/schemas")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "name": name,
+            "schema_type": "openapi",
+            "version": version,
+            "content": r#"{"openapi": "3.0.0", "info": {"title": "Test API", "version": "1.0.0"}, "paths": {}}"#,
+        }))
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn contract_test_report_replaces_previous_result() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let schema = format!("api-{}", uuid::Uuid::new_v4());
+    ingest_schema(&server, &schema, "1.0.0").await;
+
+    let body: ContractTestResponse =
+        report(&server, "test-token", &schema, "1.0.0", "orders", "failed")
+            .await
+            .json();
+    assert_eq!(body.consumer, "orders");
+    report(&server, "test-token", &schema, "1.0.0", "orders", "passed")
+        .await
+        .assert_status_ok();
+
+    let reports = env
+        .contract_test_repo
+        .list_for_schema(&schema)
+        .await
+        .unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].outcome, ContractTestOutcome::Passed);
+    assert_eq!(reports[0].tool, "pact");
+    assert_eq!(reports[0].passed, Some(10));
+    assert_eq!(reports[0].published_by, "legacy");
+}
+
+#[tokio::test]
+async fn contract_test_report_is_validated() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let schema = format!("api-{}", uuid::Uuid::new_v4());
+    ingest_schema(&server, &schema, "1.0.0").await;
+
+    // Unknown version
+    report(&server, "test-token", &schema, "9.9.9", "orders", "passed")
+        .await
+        .assert_status_not_found();
+    report(
+        &server,
+        "test-token",
+        &schema,
+        "1.0.0",
+        "bad name",
+        "passed",
+    )
+    .await
+    .assert_status_bad_request();
+
+    // Tokens must be scoped to the schema
+    let token = env
+        .create_service_token("other-ci", vec!["other/*".to_string()], true)
+        .await;
+    report(&server, &token, &schema, "1.0.0", "orders", "passed")
+        .await
+        .assert_status_forbidden();
+
+    assert!(env
+        .contract_test_repo
+        .list_for_schema(&schema)
+        .await
+        .unwrap()
+        .is_empty());
+}
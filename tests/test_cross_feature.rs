@@ -216,6 +216,9 @@ async fn ingest_doc_then_update_content() {
 async fn asset_lifecycle_with_document() {
     let env = common::TestEnv::start().await;
     let server = env.server();
+    let reader = env
+        .create_test_user("reader", "reader@test.com", false)
+        .await;
 
     // Upload an asset via the API (multipart form like the existing upload pattern)
     let form = MultipartForm::new()
@@ -234,8 +237,11 @@ async fn asset_lifecycle_with_document() {
 
     response.assert_status_ok();
 
-    // Serve the asset back
-    let response = server.get("/api/v1/assets/project-x/config.yaml").await;
+    // Serve the asset back; no document embeds it yet, so it needs a session
+    let response = server
+        .get("/api/v1/assets/project-x/config.yaml")
+        .add_cookie(env.auth_cookie(&reader))
+        .await;
     response.assert_status_ok();
     let body = response.text();
     assert_eq!(body, "asset-content-here");
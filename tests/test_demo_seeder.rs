@@ -0,0 +1,44 @@
+mod common;
+
+use lekton::demo_seeder::{seed_if_empty, DemoSeedContext};
+
+#[tokio::test]
+async fn demo_seeder_fills_an_empty_database_once() {
+    let env = common::TestEnv::start().await;
+    let ctx = DemoSeedContext {
+        repo: env.repo.as_ref(),
+        storage: env.storage.as_ref(),
+        search: Some(env.search.as_ref()),
+        schema_repo: env.schema_repo.as_ref(),
+    };
+
+    assert!(seed_if_empty(&ctx).await.unwrap());
+
+    let doc = env
+        .repo
+        .find_by_slug("architecture")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(doc.parent_slug.as_deref(), Some("engineering"));
+    assert!(doc.backlinks.contains(&"getting-started".to_string()));
+    let content = env.storage.get_object(&doc.s3_key).await.unwrap().unwrap();
+    assert!(String::from_utf8(content).unwrap().starts_with('#'));
+
+    let payments = env
+        .schema_repo
+        .find_by_name("payment-service-api")
+        .await
+        .unwrap()
+        .unwrap();
+    let versions: Vec<&str> = payments
+        .versions
+        .iter()
+        .map(|v| v.version.as_str())
+        .collect();
+    assert_eq!(versions, ["1.0.0", "2.0.0"]);
+    assert!(!payments.versions[1].endpoints.is_empty());
+
+    // A database with documents is left alone
+    assert!(!seed_if_empty(&ctx).await.unwrap());
+}
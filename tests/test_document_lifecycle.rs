@@ -1,6 +1,7 @@
 mod common;
 
 use lekton::db::repository::DocumentRepository;
+use lekton::search::client::SearchFilter;
 use lekton::storage::client::StorageClient;
 
 #[tokio::test]
@@ -32,7 +33,7 @@ async fn ingest_then_search_then_retrieve() {
     ];
     let results = env
         .search
-        .search(&keyword, Some(&allowed), false)
+        .search(&keyword, Some(&allowed), false, &SearchFilter::default())
         .await
         .unwrap();
     assert!(
@@ -213,6 +214,31 @@ async fn backlink_graph_consistency() {
     );
 }
 
+#[tokio::test]
+async fn find_by_slugs_returns_existing_documents() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let doc_a = format!("batch-a-{}", uuid::Uuid::new_v4());
+    let doc_b = format!("batch-b-{}", uuid::Uuid::new_v4());
+    env.ingest(&server, &doc_a, "Doc A", "# Doc A", "public")
+        .await;
+    env.ingest(&server, &doc_b, "Doc B", "# Doc B", "public")
+        .await;
+
+    let mut titles: Vec<String> = env
+        .repo
+        .find_by_slugs(&[doc_a.clone(), format!("missing-{doc_a}"), doc_b.clone()])
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|doc| doc.title)
+        .collect();
+    titles.sort();
+    assert_eq!(titles, ["Doc A", "Doc B"]);
+    assert!(env.repo.find_by_slugs(&[]).await.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn access_level_enforcement() {
     let env = common::TestEnv::start().await;
@@ -0,0 +1,79 @@
+mod common;
+
+use chrono::Duration;
+use lekton::db::edit_lock_repository::LockAttempt;
+
+#[tokio::test]
+async fn edit_lock_is_exclusive_until_released() {
+    let env = common::TestEnv::start().await;
+    let ttl = Duration::seconds(60);
+
+    let first = env
+        .edit_lock_repo
+        .acquire("guides/setup", "u1", "Alice", ttl)
+        .await
+        .unwrap();
+    assert!(matches!(first, LockAttempt::Acquired(ref l) if l.user_name == "Alice"));
+
+    // Heartbeat from the holder refreshes the lock
+    let refreshed = env
+        .edit_lock_repo
+        .acquire("guides/setup", "u1", "Alice", ttl)
+        .await
+        .unwrap();
+    assert!(matches!(refreshed, LockAttempt::Acquired(_)));
+
+    let second = env
+        .edit_lock_repo
+        .acquire("guides/setup", "u2", "Bob", ttl)
+        .await
+        .unwrap();
+    assert!(matches!(second, LockAttempt::Held(ref l) if l.user_id == "u1"));
+
+    // Releasing someone else's lock is a no-op
+    env.edit_lock_repo
+        .release("guides/setup", "u2")
+        .await
+        .unwrap();
+    let still_held = env
+        .edit_lock_repo
+        .acquire("guides/setup", "u2", "Bob", ttl)
+        .await
+        .unwrap();
+    assert!(matches!(still_held, LockAttempt::Held(_)));
+
+    env.edit_lock_repo
+        .release("guides/setup", "u1")
+        .await
+        .unwrap();
+    let after_release = env
+        .edit_lock_repo
+        .acquire("guides/setup", "u2", "Bob", ttl)
+        .await
+        .unwrap();
+    assert!(matches!(after_release, LockAttempt::Acquired(ref l) if l.user_id == "u2"));
+}
+
+#[tokio::test]
+async fn expired_edit_lock_can_be_taken_over() {
+    let env = common::TestEnv::start().await;
+
+    // A negative TTL produces an already-expired lock
+    env.edit_lock_repo
+        .acquire("runbooks/db", "u1", "Alice", Duration::seconds(-1))
+        .await
+        .unwrap();
+
+    let takeover = env
+        .edit_lock_repo
+        .acquire("runbooks/db", "u2", "Bob", Duration::seconds(60))
+        .await
+        .unwrap();
+    match takeover {
+        LockAttempt::Acquired(lock) => {
+            assert_eq!(lock.user_id, "u2");
+            assert_eq!(lock.user_name, "Bob");
+        }
+        LockAttempt::Held(lock) => panic!("expected takeover, lock held by {}", lock.user_name),
+    }
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use lekton::db::glossary_repository::GlossaryTerm;
+use lekton::error::AppError;
+
+fn term(term: &str, definition: &str) -> GlossaryTerm {
+    GlossaryTerm {
+        term: term.to_string(),
+        definition: definition.to_string(),
+        owner: "platform".to_string(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn glossary_upsert_replaces_term_case_insensitively() {
+    let env = common::TestEnv::start().await;
+
+    env.glossary_repo
+        .upsert(term("SLO", "Service level objective"))
+        .await
+        .unwrap();
+    env.glossary_repo
+        .upsert(term("Error budget", "Allowed unreliability"))
+        .await
+        .unwrap();
+    env.glossary_repo
+        .upsert(term("slo", "Target for a service level indicator"))
+        .await
+        .unwrap();
+
+    let terms = env.glossary_repo.list_all().await.unwrap();
+    assert_eq!(terms.len(), 2);
+    assert_eq!(terms[0].term, "Error budget");
+    assert_eq!(terms[1].term, "slo");
+    assert_eq!(terms[1].definition, "Target for a service level indicator");
+}
+
+#[tokio::test]
+async fn glossary_delete_term() {
+    let env = common::TestEnv::start().await;
+
+    env.glossary_repo
+        .upsert(term("C++ ABI", "Binary interface"))
+        .await
+        .unwrap();
+    env.glossary_repo.delete("c++ abi").await.unwrap();
+    assert!(env.glossary_repo.list_all().await.unwrap().is_empty());
+
+    let err = env.glossary_repo.delete("c++ abi").await.unwrap_err();
+    assert!(matches!(err, AppError::NotFound(_)));
+}
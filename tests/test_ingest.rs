@@ -1,6 +1,7 @@
 mod common;
 
 use lekton::db::repository::DocumentRepository;
+use lekton::search::client::SearchFilter;
 use lekton::storage::client::StorageClient;
 
 #[tokio::test]
@@ -58,6 +59,11 @@ async fn ingest_rejects_invalid_token() {
         .await;
 
     response.assert_status_unauthorized();
+    assert_eq!(response.header("content-type"), "application/problem+json");
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["code"], "unauthenticated");
+    assert_eq!(body["status"], 401);
+    assert_eq!(body["type"], "urn:lekton:problem:unauthenticated");
 }
 
 #[tokio::test]
@@ -108,6 +114,31 @@ async fn ingest_rejects_invalid_access_level() {
     response.assert_status_bad_request();
 }
 
+#[tokio::test]
+async fn ingest_rejects_unknown_edit_access_level() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+
+    let response = server
+        .post("/api/v1/ingest")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "slug": "test-doc",
+            "source_path": "docs/test-doc.md",
+            "title": "Test",
+            "content": "content",
+            "access_level": "public",
+            "edit_access_level": "no-such-team",
+            "service_owner": "team",
+            "tags": [],
+            "order": 0,
+            "is_hidden": false
+        }))
+        .await;
+
+    response.assert_status_bad_request();
+}
+
 #[tokio::test]
 async fn ingest_upsert_updates_existing() {
     let env = common::TestEnv::start().await;
@@ -257,7 +288,12 @@ async fn ingest_indexes_in_meilisearch() {
     // Search should find the document
     let results = env
         .search
-        .search("Unique Searchable", Some(&["public".to_string()]), false)
+        .search(
+            "Unique Searchable",
+            Some(&["public".to_string()]),
+            false,
+            &SearchFilter::default(),
+        )
         .await
         .unwrap();
 
@@ -267,3 +303,80 @@ async fn ingest_indexes_in_meilisearch() {
         results
     );
 }
+
+#[tokio::test]
+async fn ingest_dry_run_does_not_persist() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let slug = format!("ingest-dry-run-{}", uuid::Uuid::new_v4());
+
+    let response = server
+        .post("/api/v1/ingest?dry_run=true")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "slug": slug,
+            "source_path": "docs/dry-run.md",
+            "title": "Dry Run",
+            "content": "See [missing](/docs/does-not-exist-dry-run)",
+            "access_level": "public",
+            "service_owner": "team",
+            "tags": [],
+            "order": 0,
+            "is_hidden": false
+        }))
+        .await;
+
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["dry_run"], true);
+    assert_eq!(body["changes"], serde_json::json!(["created"]));
+    assert_eq!(body["warnings"].as_array().map(Vec::len), Some(1));
+
+    assert!(env.repo.find_by_slug(&slug).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn ingest_returns_conflict_when_document_changed_since_expected() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let slug = format!("ingest-conflict-{}", uuid::Uuid::new_v4());
+    let request = |content: &str, expected: &serde_json::Value| {
+        serde_json::json!({
+            "service_token": "test-token",
+            "slug": slug,
+            "source_path": format!("docs/{slug}.md"),
+            "title": "Conflict",
+            "content": content,
+            "access_level": "public",
+            "service_owner": "team",
+            "expected_last_updated": expected,
+        })
+    };
+
+    let first = server
+        .post("/api/v1/ingest")
+        .json(&request("# First", &serde_json::Value::Null))
+        .await;
+    first.assert_status_ok();
+    let seen = first.json::<serde_json::Value>()["last_updated"].clone();
+    assert!(seen.is_string());
+
+    server
+        .post("/api/v1/ingest")
+        .json(&request("# Pipeline A", &seen))
+        .await
+        .assert_status_ok();
+
+    let stale = serde_json::json!("2000-01-01T00:00:00Z");
+    let response = server
+        .post("/api/v1/ingest")
+        .json(&request("# Pipeline B", &stale))
+        .await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+
+    let doc = env.repo.find_by_slug(&slug).await.unwrap().unwrap();
+    let content = env.storage.get_object(&doc.s3_key).await.unwrap().unwrap();
+    assert_eq!(String::from_utf8(content).unwrap(), "# Pipeline A");
+}
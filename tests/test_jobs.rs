@@ -0,0 +1,144 @@
+mod common;
+
+#[tokio::test]
+async fn link_check_run_is_recorded_in_history() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("jobs-admin", "jobs-admin@test.com", true)
+        .await;
+    let user = env
+        .create_test_user("jobs-user", "jobs-user@test.com", false)
+        .await;
+
+    env.ingest(
+        &server,
+        "guides/intro",
+        "Intro",
+        "See [setup](/docs/guides/setup) and [gone](/docs/guides/gone).",
+        "public",
+    )
+    .await
+    .assert_status_ok();
+    env.ingest(&server, "guides/setup", "Setup", "# Setup", "public")
+        .await
+        .assert_status_ok();
+
+    server
+        .post("/api/v1/admin/jobs/link_check/run")
+        .add_cookie(env.auth_cookie(&user))
+        .await
+        .assert_status_forbidden();
+    server
+        .post("/api/v1/admin/jobs/backup/run")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .assert_status_not_found();
+
+    let run: serde_json::Value = server
+        .post("/api/v1/admin/jobs/link_check/run")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert_eq!(run["status"], "succeeded");
+    assert_eq!(run["triggered_by"], "jobs-admin@test.com");
+    assert_eq!(
+        run["details"],
+        serde_json::json!(["guides/intro → guides/gone"])
+    );
+
+    let runs: Vec<serde_json::Value> = server
+        .get("/api/v1/admin/jobs/runs?job=link_check")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0]["summary"], "1 broken links in 1 documents");
+
+    let jobs: Vec<serde_json::Value> = server
+        .get("/api/v1/admin/jobs")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    let link_check = jobs.iter().find(|j| j["job"] == "link_check").unwrap();
+    assert_eq!(link_check["last_run"]["_id"], run["_id"]);
+    assert!(link_check["next_run"].is_null());
+}
+
+#[tokio::test]
+async fn digest_without_webhook_is_recorded_as_failed() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("jobs-admin", "jobs-admin@test.com", true)
+        .await;
+
+    let run: serde_json::Value = server
+        .post("/api/v1/admin/jobs/digest/run")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert_eq!(run["status"], "failed");
+    assert!(run["summary"]
+        .as_str()
+        .unwrap()
+        .contains("digest_webhook_url"));
+}
+
+#[tokio::test]
+async fn email_digest_without_smtp_is_recorded_as_failed() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("jobs-admin", "jobs-admin@test.com", true)
+        .await;
+
+    let run: serde_json::Value = server
+        .post("/api/v1/admin/jobs/email_digest/run")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert_eq!(run["status"], "failed");
+    assert!(run["summary"].as_str().unwrap().contains("email.smtp_host"));
+}
+
+#[tokio::test]
+async fn notification_cleanup_deletes_expired_notifications() {
+    use lekton::db::notification_repository::Notification;
+
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("jobs-admin", "jobs-admin@test.com", true)
+        .await;
+
+    let mut expired = Notification::new("jobs-admin", "mention", "guides/old", "Old", "ci");
+    expired.created_at = chrono::Utc::now() - chrono::Duration::days(120);
+    env.notification_repo.create(expired).await.unwrap();
+    env.notification_repo
+        .create(Notification::new(
+            "jobs-admin",
+            "mention",
+            "guides/new",
+            "New",
+            "ci",
+        ))
+        .await
+        .unwrap();
+
+    let run: serde_json::Value = server
+        .post("/api/v1/admin/jobs/notification_cleanup/run")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert_eq!(run["status"], "succeeded");
+    assert_eq!(run["summary"], "Deleted 1 expired notifications");
+
+    let remaining = env
+        .notification_repo
+        .list_for_user("jobs-admin", 10)
+        .await
+        .unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].slug, "guides/new");
+}
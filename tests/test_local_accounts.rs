@@ -0,0 +1,138 @@
+mod common;
+
+use serde_json::json;
+
+#[tokio::test]
+async fn admin_created_user_signs_in_and_changes_password() {
+    let env = common::TestEnv::start().await;
+    let admin_server = env.server_permissive();
+    let admin = env
+        .create_test_user("local-admin", "local-admin@test.com", true)
+        .await;
+
+    let response = admin_server
+        .post("/api/v1/admin/users")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({
+            "email": "Writer@Test.com",
+            "name": "Writer",
+            "password": "initial password"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let created: serde_json::Value = response.json();
+    assert_eq!(created["email"], "writer@test.com");
+    assert_eq!(created["provider_type"], "local");
+    assert!(created.get("password").is_none(), "hash must not leak");
+
+    // Sign in: token cookies are set and the password must be changed
+    let server = env.server_permissive();
+    let response = server
+        .post("/auth/local/login")
+        .json(&json!({ "email": "writer@test.com", "password": "initial password" }))
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["password_change_required"], true);
+
+    let response = server.get("/auth/me").await;
+    response.assert_status_ok();
+    let me: serde_json::Value = response.json();
+    assert_eq!(me["user"]["email"], "writer@test.com");
+
+    server
+        .post("/auth/local/password")
+        .json(&json!({
+            "current_password": "initial password",
+            "new_password": "my own password"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+
+    let response = server
+        .post("/auth/local/login")
+        .json(&json!({ "username": "writer@test.com", "password": "my own password" }))
+        .await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["password_change_required"], false);
+
+    env.server_permissive()
+        .post("/auth/local/login")
+        .json(&json!({ "email": "writer@test.com", "password": "initial password" }))
+        .await
+        .assert_status_unauthorized();
+}
+
+#[tokio::test]
+async fn admin_resets_password_and_deletes_user() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("local-admin", "local-admin@test.com", true)
+        .await;
+
+    let response = server
+        .post("/api/v1/admin/users")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "email": "reader@test.com", "password": "initial password" }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let user_id = response.json::<serde_json::Value>()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    server
+        .put(&format!("/api/v1/admin/users/{user_id}/password"))
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "password": "short" }))
+        .await
+        .assert_status_bad_request();
+    server
+        .put(&format!("/api/v1/admin/users/{user_id}/password"))
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "password": "temporary password" }))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+
+    env.server_permissive()
+        .post("/auth/local/login")
+        .json(&json!({ "email": "reader@test.com", "password": "temporary password" }))
+        .await
+        .assert_status_ok();
+
+    // Admins cannot delete themselves
+    server
+        .delete("/api/v1/admin/users/local-admin")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .assert_status_bad_request();
+
+    server
+        .delete(&format!("/api/v1/admin/users/{user_id}"))
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+    env.server_permissive()
+        .post("/auth/local/login")
+        .json(&json!({ "email": "reader@test.com", "password": "temporary password" }))
+        .await
+        .assert_status_unauthorized();
+}
+
+#[tokio::test]
+async fn non_admins_cannot_manage_users() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let user = env
+        .create_test_user("plain-user", "plain@test.com", false)
+        .await;
+
+    server
+        .post("/api/v1/admin/users")
+        .add_cookie(env.auth_cookie(&user))
+        .json(&json!({ "email": "x@test.com", "password": "initial password" }))
+        .await
+        .assert_status_forbidden();
+}
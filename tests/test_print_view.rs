@@ -0,0 +1,57 @@
+mod common;
+
+#[tokio::test]
+async fn print_view_follows_the_document_access_level() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let slug = format!("print-{}", uuid::Uuid::new_v4());
+    env.ingest(
+        &server,
+        &slug,
+        "Runbook",
+        "# Runbook\n\n![Topology](/api/v1/image/topology.png)\n\nSteps for the on-call engineer.",
+        "internal",
+    )
+    .await
+    .assert_status_ok();
+    let path = format!("/docs/{slug}/print");
+
+    // Anonymous readers only see public documents
+    let response = server.get(&path).await;
+    response.assert_status_not_found();
+    assert!(!response.text().contains("Steps for the on-call engineer."));
+
+    let admin = env
+        .create_test_user("print-admin", "print-admin@test.com", true)
+        .await;
+    let response = server.get(&path).add_cookie(env.auth_cookie(&admin)).await;
+    response.assert_status_ok();
+    assert_eq!(response.header("x-robots-tag"), "noindex, nofollow");
+    let html = response.text();
+    assert!(html.contains("<title>Runbook</title>"));
+    assert!(html.contains("Steps for the on-call engineer."));
+    assert!(html.contains("<img src=\"/api/v1/image/topology.png\""));
+    assert!(html.contains("<script src=\"/js/print-view.js\" defer></script>"));
+    assert!(html.contains(&format!("Source: /docs/{slug}")));
+}
+
+#[tokio::test]
+async fn documents_whose_slug_ends_in_print_keep_their_page() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let parent = format!("handbook-{}", uuid::Uuid::new_v4());
+    for slug in [parent.clone(), format!("{parent}/print")] {
+        env.ingest(
+            &server,
+            &slug,
+            "Printing",
+            "# Printing\n\nPrinters.",
+            "public",
+        )
+        .await
+        .assert_status_ok();
+    }
+
+    let response = server.get(&format!("/docs/{parent}/print")).await;
+    assert!(!response.text().contains("/js/print-view.js"));
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use lekton::db::project_repository::Project;
+use lekton::error::AppError;
+use serde_json::json;
+
+fn project(name: &str, access_levels: &[&str]) -> Project {
+    Project {
+        name: name.to_string(),
+        title: format!("Project {name}"),
+        description: String::new(),
+        access_levels: access_levels.iter().map(|l| l.to_string()).collect(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+fn ingest_body(token: &str, slug: &str, access_level: &str) -> serde_json::Value {
+    json!({
+        "service_token": token,
+        "slug": slug,
+        "source_path": format!("docs/{slug}.md"),
+        "title": "Project doc",
+        "content": "# Project doc",
+        "access_level": access_level,
+        "service_owner": "team",
+    })
+}
+
+#[tokio::test]
+async fn project_repository_upsert_list_delete() {
+    let env = common::TestEnv::start().await;
+
+    env.project_repo
+        .upsert(project("payments", &[]))
+        .await
+        .unwrap();
+    env.project_repo
+        .upsert(project("billing", &["internal"]))
+        .await
+        .unwrap();
+    env.project_repo
+        .upsert(project("payments", &["public"]))
+        .await
+        .unwrap();
+
+    let projects = env.project_repo.list_all().await.unwrap();
+    let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["billing", "payments"]);
+    assert_eq!(projects[1].access_levels, vec!["public"]);
+
+    env.project_repo.delete("billing").await.unwrap();
+    assert!(env
+        .project_repo
+        .find_by_name("billing")
+        .await
+        .unwrap()
+        .is_none());
+    let err = env.project_repo.delete("billing").await.unwrap_err();
+    assert!(matches!(err, AppError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn ingest_enforces_project_access_levels() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    env.project_repo
+        .upsert(project("payments", &["internal"]))
+        .await
+        .unwrap();
+
+    server
+        .post("/api/v1/ingest")
+        .json(&ingest_body("test-token", "payments/runbook", "public"))
+        .await
+        .assert_status_bad_request();
+    server
+        .post("/api/v1/ingest")
+        .json(&ingest_body("test-token", "payments/runbook", "internal"))
+        .await
+        .assert_status_ok();
+
+    // Slugs outside the project are unaffected
+    server
+        .post("/api/v1/ingest")
+        .json(&ingest_body(
+            "test-token",
+            "payments-legacy/runbook",
+            "public",
+        ))
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn project_tokens_are_confined_to_their_project() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("project-admin", "project-admin@test.com", true)
+        .await;
+
+    env.project_repo
+        .upsert(project("payments", &[]))
+        .await
+        .unwrap();
+
+    let response = server
+        .post("/api/v1/admin/service-tokens")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "name": "payments-ci", "project": "payments", "can_write": true }))
+        .await;
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["project"], "payments");
+    assert_eq!(body["allowed_scopes"], json!(["payments/*"]));
+    let raw_token = body["raw_token"].as_str().unwrap();
+
+    server
+        .post("/api/v1/ingest")
+        .json(&ingest_body(raw_token, "payments/intro", "public"))
+        .await
+        .assert_status_ok();
+    server
+        .post("/api/v1/ingest")
+        .json(&ingest_body(raw_token, "billing/intro", "public"))
+        .await
+        .assert_status_forbidden();
+
+    // Scopes outside the project and unknown projects are rejected
+    server
+        .post("/api/v1/admin/service-tokens")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({
+            "name": "payments-wide",
+            "project": "payments",
+            "allowed_scopes": ["billing/*"],
+            "can_write": true
+        }))
+        .await
+        .assert_status_bad_request();
+    server
+        .post("/api/v1/admin/service-tokens")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&json!({ "name": "ghost-ci", "project": "ghost", "can_write": true }))
+        .await
+        .assert_status_bad_request();
+}
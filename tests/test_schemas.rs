@@ -543,3 +543,179 @@ async fn schema_raw_api_supports_names_with_slashes() {
         .text();
     assert!(content.contains("openapi"));
 }
+
+#[tokio::test]
+async fn schema_owner_access_level_hides_schema_from_other_callers() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("schema-admin", "schema-admin@test.com", true)
+        .await;
+
+    let name = format!("owned-api-{}", uuid::Uuid::new_v4());
+    server
+        .post("/api/v1/schemas")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "name": name,
+            "schema_type": "openapi",
+            "version": "1.0.0",
+            "access_level": "public",
+            "owner_access_level": "internal",
+            "content": openapi_spec(),
+        }))
+        .await
+        .assert_status_ok();
+
+    let list: Vec<SchemaListItem> = server.get("/api/v1/schemas").await.json();
+    assert!(!list.iter().any(|s| s.name == name));
+    server
+        .get(&format!("/api/v1/schemas/{}", name))
+        .await
+        .assert_status_not_found();
+    server
+        .get(&format!("/api/v1/schemas/{}/1.0.0", name))
+        .await
+        .assert_status_not_found();
+
+    let detail: SchemaDetail = server
+        .get(&format!("/api/v1/schemas/{}", name))
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert_eq!(detail.owner_access_level.as_deref(), Some("internal"));
+    assert_eq!(detail.versions[0].access_level, "public");
+}
+
+#[tokio::test]
+async fn schema_contact_metadata_is_returned_and_validated() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+
+    let name = format!("contact-api-{}", uuid::Uuid::new_v4());
+    let ingest = |contact: &str| {
+        serde_json::json!({
+            "service_token": "test-token",
+            "name": name,
+            "schema_type": "openapi",
+            "version": "1.0.0",
+            "service_owner": "payments",
+            "description": "Card payments",
+            "contact": contact,
+            "repository_url": "https://git.example.com/payments/api",
+            "content": openapi_spec(),
+        })
+    };
+    server
+        .post("/api/v1/schemas")
+        .json(&ingest("payments@example.com"))
+        .await
+        .assert_status_ok();
+    server
+        .post("/api/v1/schemas")
+        .json(&ingest("javascript:alert(1)"))
+        .await
+        .assert_status_bad_request();
+
+    let list: Vec<SchemaListItem> = server.get("/api/v1/schemas").await.json();
+    let item = list.iter().find(|s| s.name == name).unwrap();
+    assert_eq!(item.description.as_deref(), Some("Card payments"));
+    assert_eq!(item.contact.as_deref(), Some("payments@example.com"));
+
+    let detail: SchemaDetail = server
+        .get(&format!("/api/v1/schemas/{}", name))
+        .await
+        .json();
+    assert_eq!(
+        detail.repository_url.as_deref(),
+        Some("https://git.example.com/payments/api")
+    );
+}
+
+#[tokio::test]
+async fn list_schemas_filters_and_paginates() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+
+    let owner = format!("team-{}", uuid::Uuid::new_v4());
+    for name in ["alpha", "beta", "gamma"] {
+        server
+            .post("/api/v1/schemas")
+            .json(&serde_json::json!({
+                "service_token": "test-token",
+                "name": format!("{owner}/{name}"),
+                "schema_type": "openapi",
+                "version": "1.0.0",
+                "service_owner": owner,
+                "tags": [name],
+                "content": openapi_spec(),
+            }))
+            .await
+            .assert_status_ok();
+    }
+
+    let response = server
+        .get("/api/v1/schemas")
+        .add_query_param("owner", &owner)
+        .add_query_param("per_page", 2)
+        .add_query_param("page", 1)
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.headers().get("x-total-count").unwrap(), "3");
+    let list: Vec<SchemaListItem> = response.json();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].name, format!("{owner}/gamma"));
+    assert!(list[0].updated_at.is_some());
+
+    let list: Vec<SchemaListItem> = server
+        .get("/api/v1/schemas")
+        .add_query_param("owner", &owner)
+        .add_query_param("tag", "beta")
+        .add_query_param("q", "BET")
+        .await
+        .json();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].name, format!("{owner}/beta"));
+}
+
+#[tokio::test]
+async fn schema_version_raw_download_sets_file_headers() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+
+    let name = format!("team/raw-api-{}", uuid::Uuid::new_v4());
+    server
+        .post("/api/v1/schemas")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "name": name,
+            "schema_type": "openapi",
+            "version": "1.0.0",
+            "content": openapi_spec(),
+        }))
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .get(&format!("/api/v1/schemas/{}/1.0.0/raw", name))
+        .await;
+    response.assert_status_ok();
+    let headers = response.headers();
+    assert_eq!(
+        headers.get("content-type").unwrap(),
+        "application/json; charset=utf-8"
+    );
+    let disposition = headers
+        .get("content-disposition")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(disposition.starts_with("attachment; filename=\"raw-api-"));
+    assert!(disposition.ends_with("-1.0.0.json\""));
+    assert!(response.text().contains("openapi"));
+
+    server
+        .get(&format!("/api/v1/schemas/{}/9.9.9/raw", name))
+        .await
+        .assert_status_not_found();
+}
@@ -224,6 +224,57 @@ async fn search_excludes_archived_documents() {
     );
 }
 
+#[tokio::test]
+async fn search_excludes_hidden_documents() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let slug = format!("search-hidden-{}", uuid::Uuid::new_v4());
+    let keyword = format!("hiddenkw{}", uuid::Uuid::new_v4().simple());
+    let title = format!("Hidden test {keyword}");
+    let content = format!("# Hidden test {keyword}\n\nThis document will be hidden.");
+
+    env.ingest(&server, &slug, &title, &content, "public").await;
+    env.wait_for_search_indexing().await;
+
+    let search = || {
+        server
+            .get("/api/v1/search")
+            .add_query_param("q", &keyword)
+            .add_query_param("access_levels", "public")
+    };
+    let results: Vec<serde_json::Value> = search().await.json();
+    assert!(
+        results.iter().any(|r| r["slug"].as_str() == Some(&slug)),
+        "Document should appear in search before hiding"
+    );
+
+    // Re-ingest the same content with the hidden flag set
+    server
+        .post("/api/v1/ingest")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "slug": slug,
+            "source_path": format!("docs/{}.md", slug),
+            "title": title,
+            "content": content,
+            "access_level": "public",
+            "service_owner": "test-team",
+            "tags": ["test"],
+            "order": 0,
+            "is_hidden": true
+        }))
+        .await
+        .assert_status_ok();
+    env.wait_for_search_indexing().await;
+
+    let results: Vec<serde_json::Value> = search().await.json();
+    assert!(
+        !results.iter().any(|r| r["slug"].as_str() == Some(&slug)),
+        "Hidden document must not appear in search results"
+    );
+}
+
 #[tokio::test]
 async fn search_fails_when_service_unavailable() {
     let env = common::TestEnv::start().await;
@@ -236,3 +287,247 @@ async fn search_fails_when_service_unavailable() {
 
     response.assert_status_internal_server_error();
 }
+
+#[tokio::test]
+async fn search_settings_synonyms_apply_to_queries() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("search-admin", "search-admin@test.com", true)
+        .await;
+    let user = env
+        .create_test_user("search-user", "search-user@test.com", false)
+        .await;
+
+    let settings = serde_json::json!({
+        "ranking_rules": ["words", "attribute", "typo", "proximity", "sort", "exactness"],
+        "synonyms": [["K8s", "kubernetes"]],
+        "stop_words": ["the"],
+    });
+    server
+        .put("/api/v1/admin/search/settings")
+        .add_cookie(env.auth_cookie(&user))
+        .json(&settings)
+        .await
+        .assert_status_forbidden();
+    server
+        .put("/api/v1/admin/search/settings")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&serde_json::json!({ "ranking_rules": ["popularity:desc"] }))
+        .await
+        .assert_status_bad_request();
+
+    let response = server
+        .put("/api/v1/admin/search/settings")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&settings)
+        .await;
+    response.assert_status_ok();
+    let saved: serde_json::Value = response.json();
+    assert_eq!(
+        saved["synonyms"],
+        serde_json::json!([["k8s", "kubernetes"]])
+    );
+    assert_eq!(saved["typo_tolerance"]["enabled"], true);
+
+    let fetched: serde_json::Value = server
+        .get("/api/v1/admin/search/settings")
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .json();
+    assert_eq!(fetched, saved);
+
+    let slug = format!("search-synonym-{}", uuid::Uuid::new_v4());
+    env.ingest(
+        &server,
+        &slug,
+        "Kubernetes Upgrade Runbook",
+        "# Kubernetes Upgrade\n\nSteps to upgrade the cluster.",
+        "public",
+    )
+    .await;
+    env.wait_for_search_indexing().await;
+
+    let results: Vec<serde_json::Value> = server
+        .get("/api/v1/search")
+        .add_query_param("q", "k8s upgrade")
+        .add_query_param("access_levels", "public")
+        .await
+        .json();
+    assert!(
+        results.iter().any(|r| r["slug"].as_str() == Some(&slug)),
+        "Synonym should match the document. Results: {:?}",
+        results
+    );
+}
+
+#[tokio::test]
+async fn search_with_api_key_is_bound_to_the_key_access_levels() {
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("key-admin", "key-admin@test.com", true)
+        .await;
+
+    let keyword = format!("apikey{}", uuid::Uuid::new_v4().simple());
+    let internal_slug = format!("search-key-internal-{}", uuid::Uuid::new_v4());
+    let arch_slug = format!("search-key-arch-{}", uuid::Uuid::new_v4());
+    env.ingest(
+        &server,
+        &internal_slug,
+        &format!("Internal {keyword}"),
+        &format!("# Internal {keyword}"),
+        "internal",
+    )
+    .await;
+    env.ingest(
+        &server,
+        &arch_slug,
+        &format!("Architect {keyword}"),
+        &format!("# Architect {keyword}"),
+        "architect",
+    )
+    .await;
+    env.wait_for_search_indexing().await;
+
+    let response = server
+        .post("/api/v1/admin/service-tokens")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&serde_json::json!({
+            "name": "ops-dashboard",
+            "token_type": "bot",
+            "access_levels": ["internal"]
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let key = response.json::<serde_json::Value>()["raw_token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // The `access_levels` query parameter cannot widen the key's levels
+    let response = server
+        .get("/api/v1/search")
+        .add_query_param("q", &keyword)
+        .add_query_param("access_levels", "architect")
+        .authorization_bearer(&key)
+        .await;
+    response.assert_status_ok();
+    let results: Vec<serde_json::Value> = response.json();
+    assert!(results
+        .iter()
+        .any(|r| r["slug"].as_str() == Some(&internal_slug)));
+    assert!(!results
+        .iter()
+        .any(|r| r["slug"].as_str() == Some(&arch_slug)));
+
+    // Ingest tokens are not read keys
+    let service = env
+        .create_service_token("ci-token", vec!["any/*".to_string()], true)
+        .await;
+    server
+        .get("/api/v1/search")
+        .add_query_param("q", &keyword)
+        .authorization_bearer(&service)
+        .await
+        .assert_status_forbidden();
+    server
+        .get("/api/v1/search")
+        .add_query_param("q", &keyword)
+        .authorization_bearer("not-a-key")
+        .await
+        .assert_status_unauthorized();
+}
+
+#[tokio::test]
+async fn search_follows_the_search_provider_feature_flag() {
+    use lekton::db::settings_repository::{FeatureFlags, SearchProvider, SettingsRepository};
+
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+
+    let slug = format!("search-provider-{}", uuid::Uuid::new_v4());
+    env.ingest(
+        &server,
+        &slug,
+        "Incident Response Playbook",
+        "# Incident Response\n\nWho to page during an outage.",
+        "public",
+    )
+    .await;
+    env.wait_for_search_indexing().await;
+
+    env.settings_repo
+        .set_feature_flags(&FeatureFlags {
+            search_provider: SearchProvider::FullText,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let response = server
+        .get("/api/v1/search")
+        .add_query_param("q", "Incident Response")
+        .add_query_param("access_levels", "public")
+        .await;
+    response.assert_status_ok();
+    let results: Vec<serde_json::Value> = response.json();
+    assert!(results.iter().any(|r| r["slug"].as_str() == Some(&slug)));
+
+    env.settings_repo
+        .set_feature_flags(&FeatureFlags {
+            search_provider: SearchProvider::Disabled,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    server
+        .get("/api/v1/search")
+        .add_query_param("q", "Incident Response")
+        .add_query_param("access_levels", "public")
+        .await
+        .assert_status_forbidden();
+}
+
+#[tokio::test]
+async fn search_flags_documents_linking_deprecated_schemas() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let schema = format!("legacy-api-{}", uuid::Uuid::new_v4());
+    server
+        .post("/api/v1/schemas")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "name": schema,
+            "schema_type": "openapi",
+            "version": "1.0.0",
+            "status": "deprecated",
+            "content": r#"{"openapi": "3.0.0", "info": {"title": "Legacy", "version": "1.0.0"}, "paths": {}}"#,
+        }))
+        .await
+        .assert_status_ok();
+
+    let slug = format!("legacy-integration-{}", uuid::Uuid::new_v4());
+    env.ingest(
+        &server,
+        &slug,
+        "Legacy Billing Integration",
+        &format!("# Legacy Billing Integration\n\nCall the [legacy API](/schemas/{schema})."),
+        "public",
+    )
+    .await;
+    env.wait_for_search_indexing().await;
+
+    let results: Vec<serde_json::Value> = server
+        .get("/api/v1/search")
+        .add_query_param("q", "Legacy Billing Integration")
+        .add_query_param("access_levels", "public")
+        .await
+        .json();
+    let hit = results
+        .iter()
+        .find(|r| r["slug"].as_str() == Some(&slug))
+        .expect("document should be found");
+    assert_eq!(hit["deprecated_schemas"], serde_json::json!([schema]));
+}
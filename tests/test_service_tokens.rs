@@ -25,6 +25,8 @@ async fn create_and_find_by_hash() {
         created_at: chrono::Utc::now(),
         last_used_at: None,
         is_active: true,
+        project: None,
+        access_levels: vec![],
     };
 
     env.service_token_repo.create(token).await.unwrap();
@@ -83,6 +85,8 @@ async fn create_rejects_duplicate_name() {
         created_at: chrono::Utc::now(),
         last_used_at: None,
         is_active: true,
+        project: None,
+        access_levels: vec![],
     };
 
     let result = env.service_token_repo.create(token).await;
@@ -1,6 +1,8 @@
 mod common;
 
-use lekton::db::settings_repository::SettingsRepository;
+use lekton::db::settings_repository::{
+    BrandingSettings, FeatureFlags, FooterLink, SearchProvider, SettingsRepository,
+};
 
 #[tokio::test]
 async fn settings_default_returns_empty_css() {
@@ -56,3 +58,53 @@ async fn settings_clear_custom_css() {
     let settings = env.settings_repo.get_settings().await.unwrap();
     assert!(settings.custom_css.is_empty());
 }
+
+#[tokio::test]
+async fn settings_set_branding_preserves_custom_css() {
+    let env = common::TestEnv::start().await;
+
+    env.settings_repo
+        .set_custom_css("body { color: red; }")
+        .await
+        .unwrap();
+
+    let branding = BrandingSettings {
+        portal_name: "Acme Portal".to_string(),
+        primary_color: Some("#4f46e5".to_string()),
+        footer_links: vec![FooterLink {
+            label: "Status".to_string(),
+            url: "https://status.example.com".to_string(),
+        }],
+        ..Default::default()
+    };
+    env.settings_repo.set_branding(&branding).await.unwrap();
+
+    let settings = env.settings_repo.get_settings().await.unwrap();
+    assert_eq!(settings.branding, branding);
+    assert_eq!(settings.custom_css, "body { color: red; }");
+}
+
+#[tokio::test]
+async fn settings_set_feature_flags_preserves_branding() {
+    let env = common::TestEnv::start().await;
+
+    let branding = BrandingSettings {
+        portal_name: "Acme Portal".to_string(),
+        ..Default::default()
+    };
+    env.settings_repo.set_branding(&branding).await.unwrap();
+
+    let features = FeatureFlags {
+        editor: false,
+        chat: true,
+        search_provider: SearchProvider::FullText,
+    };
+    env.settings_repo
+        .set_feature_flags(&features)
+        .await
+        .unwrap();
+
+    let settings = env.settings_repo.get_settings().await.unwrap();
+    assert_eq!(settings.features, features);
+    assert_eq!(settings.branding, branding);
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use axum_test::multipart::{MultipartForm, Part};
+use lekton::db::share_link_repository::ShareLink;
+
+/// Ingest an internal document and share it, returning the share URL path.
+async fn share(
+    env: &common::TestEnv,
+    server: &axum_test::TestServer,
+    slug: &str,
+    noindex: bool,
+) -> String {
+    server
+        .post("/api/v1/ingest")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "slug": slug,
+            "source_path": format!("docs/{slug}.md"),
+            "title": "Contractor Runbook",
+            "content": "# Runbook\n\nSteps for the contractors.",
+            "access_level": "internal",
+            "service_owner": "test-team",
+            "noindex": noindex
+        }))
+        .await
+        .assert_status_ok();
+    share_link(env, slug).await
+}
+
+/// Share the existing document `slug`, returning the share URL path.
+async fn share_link(env: &common::TestEnv, slug: &str) -> String {
+    let now = chrono::Utc::now();
+    let link = ShareLink {
+        id: uuid::Uuid::new_v4().to_string(),
+        slug: slug.to_string(),
+        note: "Acme contractors".into(),
+        created_by: "editor@test.com".into(),
+        created_at: now,
+        expires_at: now + chrono::Duration::days(1),
+        revoked_at: None,
+        revoked_by: None,
+        access_count: 0,
+        last_accessed_at: None,
+    };
+    let token = env
+        .token_service
+        .generate_share_token(&link.id, &link.slug, link.expires_at)
+        .unwrap();
+    env.share_link_repo.create(link).await.unwrap();
+    format!("/share/{token}")
+}
+
+#[tokio::test]
+async fn shared_documents_are_never_indexed() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    for noindex in [false, true] {
+        let slug = format!("shared-{noindex}-{}", uuid::Uuid::new_v4());
+        let path = share(&env, &server, &slug, noindex).await;
+
+        let response = server.get(&path).await;
+        response.assert_status_ok();
+        assert_eq!(response.header("x-robots-tag"), "noindex, nofollow");
+        let html = response.text();
+        assert!(html.contains("Steps for the contractors."));
+        assert!(html.contains(r#"<meta name="robots" content="noindex, nofollow"/>"#));
+    }
+}
+
+#[tokio::test]
+async fn unavailable_share_links_are_not_indexed() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+
+    let response = server.get("/share/not-a-token").await;
+    response.assert_status_not_found();
+    assert_eq!(response.header("x-robots-tag"), "noindex, nofollow");
+}
+
+#[tokio::test]
+async fn shared_pages_serve_the_files_the_document_embeds() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    for (key, content) in [("ops/runbook.pdf", "runbook"), ("ops/secret.pdf", "secret")] {
+        let form = MultipartForm::new()
+            .add_text("service_token", "test-token")
+            .add_part(
+                "file",
+                Part::bytes(content.as_bytes().to_vec())
+                    .file_name("file")
+                    .mime_type("application/pdf"),
+            );
+        server
+            .put(&format!("/api/v1/assets/{key}"))
+            .multipart(form)
+            .await
+            .assert_status_ok();
+    }
+    env.ingest(
+        &server,
+        "ops/contractors",
+        "Contractors",
+        "[Runbook](/api/v1/assets/ops/runbook.pdf)",
+        "internal",
+    )
+    .await
+    .assert_status_ok();
+    env.ingest(
+        &server,
+        "ops/secrets",
+        "Secrets",
+        "[Secret](/api/v1/assets/ops/secret.pdf)",
+        "internal",
+    )
+    .await
+    .assert_status_ok();
+
+    let path = share_link(&env, "ops/contractors").await;
+    let token = path.trim_start_matches("/share/");
+    let html = server.get(&path).await.text();
+    assert!(html.contains(&format!(
+        "href=\"/api/v1/assets/ops/runbook.pdf?share={token}\""
+    )));
+
+    let response = server
+        .get("/api/v1/assets/ops/runbook.pdf")
+        .add_query_param("share", token)
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.into_bytes().as_ref(), b"runbook");
+
+    // Only the files of the shared document, and only with a valid token
+    server
+        .get("/api/v1/assets/ops/secret.pdf")
+        .add_query_param("share", token)
+        .await
+        .assert_status_not_found();
+    server
+        .get("/api/v1/assets/ops/runbook.pdf")
+        .add_query_param("share", "not-a-token")
+        .await
+        .assert_status_not_found();
+    server
+        .get("/api/v1/assets/ops/runbook.pdf")
+        .await
+        .assert_status_not_found();
+}
@@ -0,0 +1,258 @@
+mod common;
+
+fn openapi_spec() -> String {
+    r#"{"openapi": "3.0.0", "info": {"title": "Test API", "version": "1.0.0"}, "paths": {}}"#
+        .to_string()
+}
+
+#[tokio::test]
+async fn doc_page_renders_document_on_the_server() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let slug = format!("ssr-doc-{}", uuid::Uuid::new_v4());
+    env.ingest(
+        &server,
+        &slug,
+        "Server Rendered Guide",
+        "# Getting started\n\nRendered before any WASM runs.",
+        "public",
+    )
+    .await;
+
+    let response = server.get(&format!("/docs/{slug}")).await;
+    response.assert_status_ok();
+    let html = response.text();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("Server Rendered Guide"));
+    assert!(html.contains("Rendered before any WASM runs."));
+    assert!(html.contains(r#"id="main-content""#));
+}
+
+#[tokio::test]
+async fn doc_page_reports_missing_document() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let slug = format!("ssr-missing-{}", uuid::Uuid::new_v4());
+    let html = server.get(&format!("/docs/{slug}")).await.text();
+    assert!(html.contains(&format!("Document not found: {slug}")));
+}
+
+#[tokio::test]
+async fn doc_page_enforces_access_level() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("ssr-admin", "ssr-admin@test.com", true)
+        .await;
+
+    let slug = format!("ssr-internal-{}", uuid::Uuid::new_v4());
+    env.ingest(
+        &server,
+        &slug,
+        "Internal Runbook",
+        "# Runbook\n\nOnly for internal readers.",
+        "internal",
+    )
+    .await;
+
+    let anonymous = server.get(&format!("/docs/{slug}")).await.text();
+    assert!(anonymous.contains("Access denied"));
+    assert!(!anonymous.contains("Only for internal readers."));
+
+    let authorized = server
+        .get(&format!("/docs/{slug}"))
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .text();
+    assert!(authorized.contains("Only for internal readers."));
+    assert!(!authorized.contains("Access denied"));
+}
+
+#[tokio::test]
+async fn schema_page_renders_schema_on_the_server() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let name = format!("ssr-api-{}", uuid::Uuid::new_v4());
+    server
+        .post("/api/v1/schemas")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "name": name,
+            "schema_type": "openapi",
+            "version": "1.0.0",
+            "status": "stable",
+            "access_level": "public",
+            "service_owner": "platform",
+            "content": openapi_spec(),
+        }))
+        .await;
+
+    let html = server.get(&format!("/schemas/{name}")).await.text();
+    assert!(html.contains(&name));
+    assert!(html.contains("OpenAPI"));
+    assert!(html.contains("1.0.0"));
+}
+
+#[tokio::test]
+async fn schema_page_enforces_owner_access_level() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("ssr-schema-admin", "ssr-schema-admin@test.com", true)
+        .await;
+
+    let name = format!("ssr-owned-api-{}", uuid::Uuid::new_v4());
+    server
+        .post("/api/v1/schemas")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "name": name,
+            "schema_type": "openapi",
+            "version": "1.0.0",
+            "access_level": "public",
+            "owner_access_level": "internal",
+            "content": openapi_spec(),
+        }))
+        .await;
+
+    let anonymous = server.get(&format!("/schemas/{name}")).await.text();
+    assert!(anonymous.contains("Access denied"));
+
+    let authorized = server
+        .get(&format!("/schemas/{name}"))
+        .add_cookie(env.auth_cookie(&admin))
+        .await
+        .text();
+    assert!(authorized.contains("OpenAPI"));
+    assert!(!authorized.contains("Access denied"));
+}
+
+#[tokio::test]
+async fn login_page_renders_demo_form_with_safe_next() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let html = server.get("/login?next=/docs/getting-started").await.text();
+    assert!(html.contains("Demo Login"));
+    assert!(html.contains(r#"data-next="/docs/getting-started""#));
+
+    // Off-site targets fall back to the home page
+    let html = server.get("/login?next=https://evil.example").await.text();
+    assert!(html.contains(r#"data-next="/""#));
+}
+
+#[tokio::test]
+async fn doc_page_previews_oversized_document() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let slug = format!("ssr-large-{}", uuid::Uuid::new_v4());
+    let mut content = String::from("# Large Export\n\nFirst paragraph.\n\n");
+    while content.len() <= lekton::rendering::limits::DEFAULT_MAX_RENDER_BYTES {
+        content.push_str("A generated line of filler text for the size limit.\n");
+    }
+    content.push_str("\nTrailing marker beyond the limit.\n");
+    env.ingest(&server, &slug, "Large Export", &content, "public")
+        .await;
+
+    let html = server.get(&format!("/docs/{slug}")).await.text();
+    assert!(html.contains("First paragraph."));
+    assert!(html.contains("only its beginning is shown"));
+    assert!(html.contains(&format!("/api/v1/documents/{slug}/raw?download=true")));
+    assert!(!html.contains("Trailing marker beyond the limit."));
+
+    let raw = server.get(&format!("/api/v1/documents/{slug}/raw")).await;
+    raw.assert_status_ok();
+    assert_eq!(raw.header("content-type"), "text/markdown; charset=utf-8");
+    assert!(raw.text().ends_with("Trailing marker beyond the limit.\n"));
+}
+
+#[tokio::test]
+async fn doc_page_reports_binary_content() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let slug = format!("ssr-binary-{}", uuid::Uuid::new_v4());
+    env.ingest(&server, &slug, "Binary", "# Placeholder", "public")
+        .await;
+    let doc = env.repo.find_by_slug(&slug).await.unwrap().unwrap();
+    env.storage
+        .put_object(&doc.s3_key, b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec())
+        .await
+        .unwrap();
+
+    let html = server.get(&format!("/docs/{slug}")).await.text();
+    assert!(html.contains("the stored content is a binary file, not Markdown text"));
+
+    let raw = server.get(&format!("/api/v1/documents/{slug}/raw")).await;
+    raw.assert_status_ok();
+    assert_eq!(raw.header("content-type"), "application/octet-stream");
+    assert!(raw
+        .header("content-disposition")
+        .to_str()
+        .unwrap()
+        .starts_with("attachment"));
+}
+
+#[tokio::test]
+async fn raw_document_enforces_access_level() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    let admin = env
+        .create_test_user("raw-admin", "raw-admin@test.com", true)
+        .await;
+
+    let slug = format!("raw-internal-{}", uuid::Uuid::new_v4());
+    env.ingest(&server, &slug, "Internal", "# Internal notes", "internal")
+        .await;
+
+    server
+        .get(&format!("/api/v1/documents/{slug}/raw"))
+        .await
+        .assert_status_not_found();
+
+    let response = server
+        .get(&format!("/api/v1/documents/{slug}/raw?download=true"))
+        .add_cookie(env.auth_cookie(&admin))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(
+        response.header("content-disposition"),
+        format!("attachment; filename=\"{slug}.md\"")
+    );
+    assert_eq!(response.text(), "# Internal notes");
+}
+
+#[tokio::test]
+async fn doc_page_asks_robots_to_skip_noindex_documents() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+
+    let ingest = |slug: &str, noindex: bool| {
+        server.post("/api/v1/ingest").json(&serde_json::json!({
+            "service_token": "test-token",
+            "slug": slug,
+            "source_path": format!("docs/{slug}.md"),
+            "title": "Crawled Guide",
+            "content": "# Guide\n\nIndexing depends on the flag.",
+            "access_level": "public",
+            "service_owner": "test-team",
+            "noindex": noindex
+        }))
+    };
+
+    let indexed = format!("ssr-indexed-{}", uuid::Uuid::new_v4());
+    ingest(&indexed, false).await.assert_status_ok();
+    let html = server.get(&format!("/docs/{indexed}")).await.text();
+    assert!(html.contains("Indexing depends on the flag."));
+    assert!(!html.contains(r#"name="robots""#));
+
+    let hidden = format!("ssr-noindex-{}", uuid::Uuid::new_v4());
+    ingest(&hidden, true).await.assert_status_ok();
+    let html = server.get(&format!("/docs/{hidden}")).await.text();
+    assert!(html.contains("Indexing depends on the flag."));
+    assert!(html.contains(r#"name="robots" content="noindex, nofollow""#));
+}
@@ -1,7 +1,5 @@
 mod common;
 
-use lekton::db::repository::DocumentRepository;
-
 #[tokio::test]
 async fn sync_identifies_new_docs_to_upload() {
     let env = common::TestEnv::start().await;
@@ -229,3 +227,47 @@ async fn sync_rejects_invalid_token() {
 
     response.assert_status_unauthorized();
 }
+
+#[tokio::test]
+async fn sync_records_bundle_versions() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let root = format!("bundle-{}", uuid::Uuid::new_v4());
+
+    for (version, latest) in [("1.x", false), ("abc123", true)] {
+        let slug = if latest {
+            format!("{root}/setup")
+        } else {
+            format!("{root}/@{version}/setup")
+        };
+        server
+            .post("/api/v1/sync")
+            .json(&serde_json::json!({
+                "service_token": "test-token",
+                "documents": [
+                    { "slug": slug, "source_path": "docs/setup.md", "content_hash": "sha256:abc" }
+                ],
+                "bundle": { "root": root, "version": version, "latest": latest }
+            }))
+            .await
+            .assert_status_ok();
+    }
+
+    let bundle = env.bundle_repo.find(&root).await.unwrap().unwrap();
+    assert_eq!(bundle.latest.unwrap().version, "abc123");
+    assert_eq!(bundle.versions.len(), 1);
+    assert_eq!(bundle.versions[0].version, "1.x");
+
+    // Slugs outside the bundle version are rejected
+    server
+        .post("/api/v1/sync")
+        .json(&serde_json::json!({
+            "service_token": "test-token",
+            "documents": [
+                { "slug": format!("{root}/setup"), "source_path": "docs/setup.md", "content_hash": "sha256:abc" }
+            ],
+            "bundle": { "root": root, "version": "2.x" }
+        }))
+        .await
+        .assert_status_bad_request();
+}
@@ -6,6 +6,9 @@ use axum_test::multipart::{MultipartForm, Part};
 async fn upload_image_success() {
     let env = common::TestEnv::start().await;
     let server = env.server();
+    let uploader = env
+        .create_test_user("uploader", "uploader@test.com", false)
+        .await;
 
     // Create a minimal 1x1 PNG
     let png_bytes: Vec<u8> = vec![
@@ -27,7 +30,11 @@ async fn upload_image_success() {
             .mime_type("image/png"),
     );
 
-    let response = server.post("/api/v1/upload-image").multipart(form).await;
+    let response = server
+        .post("/api/v1/upload-image")
+        .add_cookie(env.auth_cookie(&uploader))
+        .multipart(form)
+        .await;
 
     response.assert_status_ok();
 
@@ -49,6 +56,9 @@ async fn upload_image_success() {
 async fn upload_rejects_non_image() {
     let env = common::TestEnv::start().await;
     let server = env.server_permissive();
+    let uploader = env
+        .create_test_user("uploader", "uploader@test.com", false)
+        .await;
 
     let form = MultipartForm::new().add_part(
         "file",
@@ -57,7 +67,11 @@ async fn upload_rejects_non_image() {
             .mime_type("text/plain"),
     );
 
-    let response = server.post("/api/v1/upload-image").multipart(form).await;
+    let response = server
+        .post("/api/v1/upload-image")
+        .add_cookie(env.auth_cookie(&uploader))
+        .multipart(form)
+        .await;
 
     response.assert_status_bad_request();
 }
@@ -66,6 +80,9 @@ async fn upload_rejects_non_image() {
 async fn upload_missing_file_field() {
     let env = common::TestEnv::start().await;
     let server = env.server_permissive();
+    let uploader = env
+        .create_test_user("uploader", "uploader@test.com", false)
+        .await;
 
     let form = MultipartForm::new().add_part(
         "wrong_field",
@@ -74,15 +91,54 @@ async fn upload_missing_file_field() {
             .mime_type("image/png"),
     );
 
-    let response = server.post("/api/v1/upload-image").multipart(form).await;
+    let response = server
+        .post("/api/v1/upload-image")
+        .add_cookie(env.auth_cookie(&uploader))
+        .multipart(form)
+        .await;
 
     response.assert_status_bad_request();
 }
 
+#[tokio::test]
+async fn upload_requires_session_or_service_token() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let image = || {
+        MultipartForm::new().add_part(
+            "file",
+            Part::bytes(b"png".to_vec())
+                .file_name("anonymous.png")
+                .mime_type("image/png"),
+        )
+    };
+
+    let response = server.post("/api/v1/upload-image").multipart(image()).await;
+    response.assert_status_unauthorized();
+
+    let response = server
+        .post("/api/v1/upload-image")
+        .multipart(image().add_text("service_token", "wrong-token"))
+        .await;
+    response.assert_status_unauthorized();
+
+    let token = env
+        .create_service_token("docs-ci", vec!["*".to_string()], true)
+        .await;
+    let response = server
+        .post("/api/v1/upload-image")
+        .multipart(image().add_text("service_token", token))
+        .await;
+    response.assert_status_ok();
+}
+
 #[tokio::test]
 async fn serve_image_returns_correct_content_type() {
     let env = common::TestEnv::start().await;
     let server = env.server();
+    let uploader = env
+        .create_test_user("uploader", "uploader@test.com", false)
+        .await;
 
     // Upload a PNG
     let png_bytes: Vec<u8> = vec![
@@ -100,13 +156,17 @@ async fn serve_image_returns_correct_content_type() {
             .mime_type("image/png"),
     );
 
-    let upload_response = server.post("/api/v1/upload-image").multipart(form).await;
+    let upload_response = server
+        .post("/api/v1/upload-image")
+        .add_cookie(env.auth_cookie(&uploader))
+        .multipart(form)
+        .await;
 
     let body: serde_json::Value = upload_response.json();
     let url = body["url"].as_str().unwrap();
 
     // Serve the image back
-    let response = server.get(url).await;
+    let response = server.get(url).add_cookie(env.auth_cookie(&uploader)).await;
     response.assert_status_ok();
 
     let content_type = response
@@ -118,6 +178,53 @@ async fn serve_image_returns_correct_content_type() {
     assert_eq!(content_type, "image/png");
 }
 
+#[tokio::test]
+async fn serve_image_honors_range_requests() {
+    use axum::http::header::{ACCEPT_RANGES, CONTENT_RANGE, RANGE};
+    use axum::http::{HeaderValue, StatusCode};
+
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let uploader = env
+        .create_test_user("uploader", "uploader@test.com", false)
+        .await;
+
+    let form = MultipartForm::new().add_part(
+        "file",
+        Part::bytes(b"0123456789".to_vec())
+            .file_name("range.png")
+            .mime_type("image/png"),
+    );
+    let body: serde_json::Value = server
+        .post("/api/v1/upload-image")
+        .add_cookie(env.auth_cookie(&uploader))
+        .multipart(form)
+        .await
+        .json();
+    let url = body["url"].as_str().unwrap();
+
+    let response = server
+        .get(url)
+        .add_cookie(env.auth_cookie(&uploader))
+        .add_header(RANGE, HeaderValue::from_static("bytes=2-4"))
+        .await;
+    response.assert_status(StatusCode::PARTIAL_CONTENT);
+    assert_eq!(
+        response.headers().get(CONTENT_RANGE).unwrap(),
+        "bytes 2-4/10"
+    );
+    assert_eq!(response.headers().get(ACCEPT_RANGES).unwrap(), "bytes");
+    assert_eq!(response.into_bytes().as_ref(), b"234");
+
+    let response = server
+        .get(url)
+        .add_cookie(env.auth_cookie(&uploader))
+        .add_header(RANGE, HeaderValue::from_static("bytes=10-"))
+        .await;
+    response.assert_status(StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(response.headers().get(CONTENT_RANGE).unwrap(), "bytes */10");
+}
+
 #[tokio::test]
 async fn serve_nonexistent_image() {
     let env = common::TestEnv::start().await;
@@ -127,3 +234,78 @@ async fn serve_nonexistent_image() {
 
     response.assert_status_not_found();
 }
+
+#[tokio::test]
+async fn orphaned_images_are_reported_and_deleted() {
+    let env = common::TestEnv::start().await;
+    env.access_level_repo.seed_defaults().await.unwrap();
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("image-admin", "image-admin@test.com", true)
+        .await;
+
+    let mut urls = Vec::new();
+    for name in ["kept.png", "orphan.png"] {
+        let form = MultipartForm::new().add_part(
+            "file",
+            Part::bytes(b"png".to_vec())
+                .file_name(name)
+                .mime_type("image/png"),
+        );
+        let response = server
+            .post("/api/v1/upload-image")
+            .add_cookie(env.auth_cookie(&admin))
+            .multipart(form)
+            .await;
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        urls.push(body["url"].as_str().unwrap().to_string());
+    }
+    env.ingest(
+        &server,
+        "guides/diagram",
+        "Diagram",
+        &format!("![Diagram]({})", urls[0]),
+        "public",
+    )
+    .await
+    .assert_status_ok();
+    let kept = format!("images/{}", urls[0].trim_start_matches("/api/v1/image/"));
+    let orphan = format!("images/{}", urls[1].trim_start_matches("/api/v1/image/"));
+
+    // Fresh uploads are within the default grace period
+    let response = server
+        .get("/api/v1/admin/images/orphaned")
+        .add_cookie(env.auth_cookie(&admin))
+        .await;
+    response.assert_status_ok();
+    let report: serde_json::Value = response.json();
+    assert_eq!(report["images"], serde_json::json!([]));
+
+    let response = server
+        .get("/api/v1/admin/images/orphaned?older_than_hours=0")
+        .add_cookie(env.auth_cookie(&admin))
+        .await;
+    response.assert_status_ok();
+    let report: serde_json::Value = response.json();
+    let reported: Vec<&str> = report["images"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|image| image["s3_key"].as_str().unwrap())
+        .collect();
+    assert_eq!(reported, vec![orphan.as_str()]);
+
+    let response = server
+        .delete("/api/v1/admin/images/orphaned")
+        .add_cookie(env.auth_cookie(&admin))
+        .json(&serde_json::json!({ "keys": [kept, orphan], "older_than_hours": 0 }))
+        .await;
+    response.assert_status_ok();
+    let result: serde_json::Value = response.json();
+    assert_eq!(result["deleted"], serde_json::json!([orphan]));
+    assert_eq!(result["kept"], serde_json::json!([kept]));
+
+    server.get(&urls[0]).await.assert_status_ok();
+    server.get(&urls[1]).await.assert_status_not_found();
+}
@@ -0,0 +1,43 @@
+mod common;
+
+#[tokio::test]
+async fn admin_usage_reports_documents_and_bytes_per_owner() {
+    let env = common::TestEnv::start().await;
+    let server = env.server_permissive();
+    let admin = env
+        .create_test_user("usage-admin", "usage-admin@test.com", true)
+        .await;
+    let user = env
+        .create_test_user("usage-user", "usage-user@test.com", false)
+        .await;
+
+    env.ingest(&server, "usage/one", "One", "# One", "public")
+        .await
+        .assert_status_ok();
+    env.ingest(&server, "usage/two", "Two", "# Two, longer", "public")
+        .await
+        .assert_status_ok();
+
+    server
+        .get("/api/v1/admin/usage")
+        .add_cookie(env.auth_cookie(&user))
+        .await
+        .assert_status_forbidden();
+
+    let response = server
+        .get("/api/v1/admin/usage")
+        .add_cookie(env.auth_cookie(&admin))
+        .await;
+    response.assert_status_ok();
+    let usage: Vec<serde_json::Value> = response.json();
+    let owner = usage
+        .iter()
+        .find(|u| u["service_owner"] == "test-team")
+        .expect("test-team usage");
+    assert_eq!(owner["document_count"], 2);
+    assert_eq!(
+        owner["storage_bytes"],
+        ("# One".len() + "# Two, longer".len()) as u64
+    );
+    assert!(owner["max_documents"].is_null());
+}
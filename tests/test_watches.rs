@@ -0,0 +1,64 @@
+mod common;
+
+use lekton::db::watch_repository::Watch;
+
+#[tokio::test]
+async fn watchers_are_notified_of_readable_changes() {
+    let env = common::TestEnv::start().await;
+    let server = env.server();
+    env.create_test_user("watcher", "watcher@test.com", false)
+        .await;
+    env.watch_repo
+        .upsert(Watch {
+            id: "w-1".into(),
+            user_id: "watcher".into(),
+            target: "guides/*".into(),
+            email: false,
+            webhook_url: None,
+            created_at: chrono::Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    // Internal documents are not readable by the watcher
+    env.ingest(&server, "guides/secret", "Secret", "# Secret", "internal")
+        .await
+        .assert_status_ok();
+    env.ingest(&server, "guides/intro", "Intro", "# Intro", "public")
+        .await
+        .assert_status_ok();
+    env.ingest(&server, "runbooks/db", "Database", "# Database", "public")
+        .await
+        .assert_status_ok();
+
+    // Events are handled in order, so the internal document was already
+    // skipped once the public one is in the inbox
+    let notifications = env.wait_for_notifications("watcher", 1).await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].slug, "guides/intro");
+    assert_eq!(notifications[0].title, "Intro");
+    assert_eq!(notifications[0].action(), "created");
+    assert_eq!(notifications[0].watch_target.as_deref(), Some("guides/*"));
+    assert_eq!(
+        env.notification_repo.count_unread("watcher").await.unwrap(),
+        1
+    );
+
+    env.notification_repo
+        .mark_read("watcher", None)
+        .await
+        .unwrap();
+    assert_eq!(
+        env.notification_repo.count_unread("watcher").await.unwrap(),
+        0
+    );
+
+    env.watch_repo.delete("watcher", "w-1").await.unwrap();
+    assert!(env.watch_repo.delete("watcher", "w-1").await.is_err());
+    assert!(env
+        .watch_repo
+        .list_for_user("watcher")
+        .await
+        .unwrap()
+        .is_empty());
+}